@@ -3,6 +3,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use encode_unicode::StrExt;
 use utf8char::{iter::Utf8CharIter, Utf8Char};
 
+#[expect(dead_code, reason = "the benchmark using it is commented out")]
 fn codepoint_len_bmi(byte: u8) -> u8 {
     (byte.leading_ones().saturating_sub(1) + 1) as u8
 }
@@ -10,8 +11,11 @@ fn codepoint_len_bmi(byte: u8) -> u8 {
 pub fn bench(c: &mut Criterion) {
     let rch = bb(char::from_u32(43242).unwrap());
     let mut buf = [0; 4];
+    #[expect(unused_variables, reason = "the benchmarks using it are commented out")]
     let st = &*bb(rch.encode_utf8(&mut buf));
+    #[expect(unused_variables, reason = "the benchmarks using it are commented out")]
     let utf8char = bb(Utf8Char::from_char(rch));
+    #[expect(unused_variables, reason = "the benchmarks using it are commented out")]
     let nonempty = bb("\u{ff00}fsgsdg");
     let mut large = (char::MIN..=char::MAX).collect::<String>();
     large.truncate(1_000_000);
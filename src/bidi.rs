@@ -0,0 +1,1510 @@
+//! Bidirectional text, as defined in [UAX #9](https://www.unicode.org/reports/tr9/)
+//!
+//! Besides the bidi properties of a single `Utf8Char`, this module implements the Unicode
+//! Bidirectional Algorithm for a paragraph of text: a [`Paragraph`] resolves the embedding level of
+//! every codepoint, and [`Paragraph::visual_runs`] reorders a line of it for display.
+//!
+//! The algorithm needs to remember a level for every codepoint, so without an allocator to ask the
+//! caller provides the memory, as a buffer of one [`Level`] per byte of the paragraph:
+//!
+//! ```
+//! use utf8char::bidi::{Level, Paragraph};
+//!
+//! let text = "\u{5E9}\u{5DC}\u{5D5}\u{5DD} world";
+//! let mut levels = [Level::LTR; 64];
+//! let paragraph = Paragraph::new(text, None, &mut levels);
+//!
+//! // the paragraph starts with a Hebrew letter, so it is right to left
+//! assert!(paragraph.level().is_rtl());
+//! assert_eq!(
+//!     paragraph.visual_runs(0..text.len()).to_string(),
+//!     "world \u{5DD}\u{5D5}\u{5DC}\u{5E9}"
+//! );
+//! ```
+
+use core::{fmt, iter::FusedIterator, ops::Range};
+
+use crate::{
+    grapheme::graphemes,
+    tables::bidi::{BIDI_CLASS, BIDI_MIRRORED, BRACKETS, MIRRORING_GLYPHS},
+    Utf8Char, Utf8CharInner, Utf8CharIter,
+};
+
+/// The `Bidi_Class` property of a codepoint, the directionality the Unicode Bidirectional Algorithm
+/// starts out with
+///
+/// Variants are named after the long property value aliases.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BidiClass {
+    /// `L`: strong left to right, like Latin letters
+    LeftToRight,
+    /// `R`: strong right to left, like Hebrew letters
+    RightToLeft,
+    /// `AL`: strong right to left, like Arabic letters
+    ArabicLetter,
+    /// `EN`: European digits and the like
+    EuropeanNumber,
+    /// `ES`: plus and minus signs
+    EuropeanSeparator,
+    /// `ET`: signs that go with European numbers, like currency and degree signs
+    EuropeanTerminator,
+    /// `AN`: Arabic-Indic digits and the like
+    ArabicNumber,
+    /// `CS`: separators within numbers, like commas, periods and colons
+    CommonSeparator,
+    /// `NSM`: nonspacing marks, which take the direction of the codepoint before them
+    NonspacingMark,
+    /// `BN`: codepoints without any influence on direction, like most format codepoints
+    BoundaryNeutral,
+    /// `B`: paragraph separators
+    ParagraphSeparator,
+    /// `S`: segment separators, like tabs
+    SegmentSeparator,
+    /// `WS`: whitespace
+    WhiteSpace,
+    /// `ON`: all other neutral codepoints, like most punctuation and symbols
+    OtherNeutral,
+    /// `LRE`: U+202A LEFT-TO-RIGHT EMBEDDING
+    LeftToRightEmbedding,
+    /// `LRO`: U+202D LEFT-TO-RIGHT OVERRIDE
+    LeftToRightOverride,
+    /// `RLE`: U+202B RIGHT-TO-LEFT EMBEDDING
+    RightToLeftEmbedding,
+    /// `RLO`: U+202E RIGHT-TO-LEFT OVERRIDE
+    RightToLeftOverride,
+    /// `PDF`: U+202C POP DIRECTIONAL FORMATTING
+    PopDirectionalFormat,
+    /// `LRI`: U+2066 LEFT-TO-RIGHT ISOLATE
+    LeftToRightIsolate,
+    /// `RLI`: U+2067 RIGHT-TO-LEFT ISOLATE
+    RightToLeftIsolate,
+    /// `FSI`: U+2068 FIRST STRONG ISOLATE
+    FirstStrongIsolate,
+    /// `PDI`: U+2069 POP DIRECTIONAL ISOLATE
+    PopDirectionalIsolate,
+}
+
+use BidiClass::{
+    ArabicLetter as AL, ArabicNumber as AN, BoundaryNeutral as BN, CommonSeparator as CS,
+    EuropeanNumber as EN, EuropeanSeparator as ES, EuropeanTerminator as ET,
+    FirstStrongIsolate as FSI, LeftToRight as L, LeftToRightEmbedding as LRE,
+    LeftToRightIsolate as LRI, LeftToRightOverride as LRO, NonspacingMark as NSM,
+    OtherNeutral as ON, ParagraphSeparator as B, PopDirectionalFormat as PDF,
+    PopDirectionalIsolate as PDI, RightToLeft as R, RightToLeftEmbedding as RLE,
+    RightToLeftIsolate as RLI, RightToLeftOverride as RLO, SegmentSeparator as S, WhiteSpace as WS,
+};
+
+impl BidiClass {
+    /// Returns whether codepoints of this class are left out of level resolution by rule X9
+    const fn is_removed(self) -> bool {
+        matches!(self, RLE | LRE | RLO | LRO | PDF | BN)
+    }
+
+    /// Returns whether this class starts an isolate
+    const fn is_isolate_initiator(self) -> bool {
+        matches!(self, LRI | RLI | FSI)
+    }
+
+    /// Returns whether this class is a neutral or isolate formatting class, `NI` in rules N1 and N2
+    const fn is_neutral(self) -> bool {
+        matches!(self, B | S | WS | ON | LRI | RLI | FSI | PDI)
+    }
+
+    /// Returns the strong direction of a resolved class for rules N0 to N2, where numbers count as
+    /// right to left
+    const fn strong(self) -> Option<Self> {
+        match self {
+            L => Some(L),
+            R | AL | EN | AN => Some(R),
+            _ => None,
+        }
+    }
+}
+
+/// The direction of a paragraph
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Left to right, like Latin text
+    LeftToRight,
+    /// Right to left, like Hebrew and Arabic text
+    RightToLeft,
+}
+
+/// The deepest explicit embedding level, `max_depth` in UAX #9
+const MAX_DEPTH: u8 = 125;
+
+/// Whether a codepoint is a bracket rule BD16 paired with another one
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Bracket {
+    /// Not a paired bracket
+    None,
+    /// The opening bracket of a pair
+    Open,
+    /// The closing bracket of a pair
+    Close,
+}
+
+/// An embedding level, which tells the direction of a codepoint and how deeply it is nested in
+/// text of the other direction: even levels are left to right and odd levels right to left.
+///
+/// A [`Paragraph`] is resolved into a buffer of one `Level` per byte. Besides the level, a `Level`
+/// has room for the state the algorithm keeps while resolving, which is what lets it work without
+/// allocating. Levels compare by their value only.
+#[derive(Copy, Clone)]
+pub struct Level {
+    /// The embedding level, at most `MAX_DEPTH + 1`
+    value: u8,
+    /// While resolving, the bidi class of the codepoint this byte starts as rules change it
+    class: BidiClass,
+    /// While resolving, whether the codepoint this byte starts is a paired bracket
+    bracket: Bracket,
+}
+
+impl Level {
+    /// Level 0, the level of a left to right paragraph
+    pub const LTR: Self = Self::from_value(0);
+
+    /// Level 1, the level of a right to left paragraph
+    pub const RTL: Self = Self::from_value(1);
+
+    /// The highest level the algorithm resolves to
+    pub const MAX: Self = Self::from_value(MAX_DEPTH + 1);
+
+    /// Creates a level from its value, without scratch state
+    const fn from_value(value: u8) -> Self {
+        Self {
+            value,
+            class: L,
+            bracket: Bracket::None,
+        }
+    }
+
+    /// Creates a level from its value, returns None if it is above [`Level::MAX`]
+    #[must_use]
+    pub const fn new(value: u8) -> Option<Self> {
+        if value > Self::MAX.value {
+            return None;
+        }
+
+        Some(Self::from_value(value))
+    }
+
+    /// Returns the value of this level
+    #[must_use]
+    pub const fn value(self) -> u8 {
+        self.value
+    }
+
+    /// Returns whether text at this level is right to left, that is whether the level is odd
+    #[must_use]
+    pub const fn is_rtl(self) -> bool {
+        self.value % 2 == 1
+    }
+
+    /// Returns the direction of text at this level
+    #[must_use]
+    pub const fn direction(self) -> Direction {
+        if self.is_rtl() {
+            Direction::RightToLeft
+        } else {
+            Direction::LeftToRight
+        }
+    }
+}
+
+impl Default for Level {
+    fn default() -> Self {
+        Self::LTR
+    }
+}
+
+impl PartialEq for Level {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for Level {}
+
+impl PartialOrd for Level {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Level {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl core::hash::Hash for Level {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl fmt::Debug for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Level").field(&self.value).finish()
+    }
+}
+
+impl Utf8Char {
+    /// Returns the `Bidi_Class` of this codepoint
+    ///
+    /// ```
+    /// use utf8char::{bidi::BidiClass, utf8char};
+    ///
+    /// assert_eq!(utf8char!("a").bidi_class(), BidiClass::LeftToRight);
+    /// assert_eq!(utf8char!("\u{5D0}").bidi_class(), BidiClass::RightToLeft);
+    /// assert_eq!(utf8char!("\u{661}").bidi_class(), BidiClass::ArabicNumber);
+    /// assert_eq!(utf8char!(" ").bidi_class(), BidiClass::WhiteSpace);
+    /// ```
+    #[must_use]
+    pub const fn bidi_class(self) -> BidiClass {
+        BIDI_CLASS.get(self)
+    }
+
+    /// Returns whether this codepoint has the `Bidi_Mirrored` property, it is displayed mirrored
+    /// in right to left text
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert!(utf8char!("(").is_bidi_mirrored());
+    /// assert!(utf8char!("\u{2264}").is_bidi_mirrored());
+    /// assert!(!utf8char!("a").is_bidi_mirrored());
+    /// ```
+    #[must_use]
+    pub const fn is_bidi_mirrored(self) -> bool {
+        BIDI_MIRRORED.contains(self)
+    }
+
+    /// Returns the `Bidi_Mirroring_Glyph` of this codepoint, the codepoint whose glyph is the
+    /// mirror image of this one, if there is one
+    ///
+    /// Some mirrored codepoints, like `'∛'`, have no such counterpart and must be mirrored by the
+    /// font instead.
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(utf8char!("(").bidi_mirroring_glyph(), Some(utf8char!(")")));
+    /// assert_eq!(utf8char!("\u{2264}").bidi_mirroring_glyph(), Some(utf8char!("\u{2265}")));
+    /// assert_eq!(utf8char!("\u{221B}").bidi_mirroring_glyph(), None);
+    /// ```
+    #[must_use]
+    pub const fn bidi_mirroring_glyph(self) -> Option<Self> {
+        let bits = self.to_bits();
+        let (mut lo, mut hi) = (0, MIRRORING_GLYPHS.len());
+
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            let (from, to) = MIRRORING_GLYPHS[mid];
+
+            if from == bits {
+                // SAFETY: MIRRORING_GLYPHS holds the to_bits representation of valid codepoints
+                return Some(Self(unsafe {
+                    Utf8CharInner::from_utf8char_array(to.to_be_bytes())
+                }));
+            } else if from < bits {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+
+        None
+    }
+
+    /// Returns the bracket this one pairs with and whether this one opens, for paired brackets.
+    /// The pair is canonically decomposed, so `'〈'` and `'〈'` pair with the same bracket
+    fn bidi_paired_bracket(self) -> Option<(Self, bool)> {
+        let idx = BRACKETS
+            .binary_search_by_key(&self.to_bits(), |&(bracket, ..)| bracket)
+            .ok()?;
+        let (_, pair, open) = BRACKETS[idx];
+
+        // SAFETY: BRACKETS holds the to_bits representation of valid codepoints
+        let pair = Self(unsafe { Utf8CharInner::from_utf8char_array(pair.to_be_bytes()) });
+
+        pair.decompose_canonical().next().map(|pair| (pair, open))
+    }
+
+    /// Returns this codepoint, or the one it canonically decomposes to if that is a single
+    /// codepoint, to compare brackets by
+    fn canonical_bracket(self) -> Self {
+        let mut decomposition = self.decompose_canonical();
+
+        match (decomposition.next(), decomposition.next()) {
+            (Some(ch), None) => ch,
+            _ => self,
+        }
+    }
+}
+
+/// An entry of the directional status stack of rules X1 to X8
+#[derive(Copy, Clone)]
+struct Status {
+    /// The embedding level
+    level: u8,
+    /// The class codepoints are overridden to, if any
+    override_class: Option<BidiClass>,
+    /// Whether the entry was pushed by an isolate initiator
+    isolate: bool,
+}
+
+/// The state of resolving the levels of a paragraph. Positions are the byte offsets of codepoints,
+/// and the level and class of a codepoint are kept in the slot of its first byte
+struct Resolver<'slice> {
+    /// The paragraph
+    text: &'slice str,
+    /// One slot per byte of the paragraph
+    slots: &'slice mut [Level],
+    /// The paragraph embedding level
+    level: u8,
+}
+
+impl Resolver<'_> {
+    /// Returns the codepoint at a position
+    fn ch(&self, pos: usize) -> Utf8Char {
+        Utf8Char::containing(self.text, pos).1
+    }
+
+    /// Returns the position after the codepoint at a position
+    fn after(&self, pos: usize) -> usize {
+        pos + self.ch(pos).len_utf8() as usize
+    }
+
+    /// Returns the bidi class a codepoint started out with
+    fn original(&self, pos: usize) -> BidiClass {
+        self.ch(pos).bidi_class()
+    }
+
+    /// Returns the class of a codepoint as resolved so far
+    fn class(&self, pos: usize) -> BidiClass {
+        self.slots[pos].class
+    }
+
+    /// Returns the embedding level of a codepoint
+    fn level_of(&self, pos: usize) -> u8 {
+        self.slots[pos].value
+    }
+
+    /// Returns the first codepoint from `pos` on that is not removed by rule X9
+    fn kept_from(&self, mut pos: usize) -> Option<usize> {
+        while pos < self.text.len() {
+            if !self.original(pos).is_removed() {
+                return Some(pos);
+            }
+
+            pos = self.after(pos);
+        }
+
+        None
+    }
+
+    /// Returns the last codepoint before `pos` that is not removed by rule X9
+    fn kept_before(&self, mut pos: usize) -> Option<usize> {
+        while pos > 0 {
+            pos = Utf8Char::containing(self.text, pos - 1).0;
+
+            if !self.original(pos).is_removed() {
+                return Some(pos);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the direction of the first strong codepoint from `pos` on, skipping isolates, up to
+    /// the end of the paragraph or, if `in_isolate`, the PDI closing the isolate `pos` is in (rules
+    /// P2 and P3)
+    fn first_strong(&self, mut pos: usize, in_isolate: bool) -> Option<Direction> {
+        let mut depth = 0_usize;
+
+        while pos < self.text.len() {
+            match self.original(pos) {
+                L if depth == 0 => return Some(Direction::LeftToRight),
+                R | AL if depth == 0 => return Some(Direction::RightToLeft),
+                LRI | RLI | FSI => depth += 1,
+                PDI if depth > 0 => depth -= 1,
+                PDI if in_isolate => return None,
+                B => return None,
+                _ => {}
+            }
+
+            pos = self.after(pos);
+        }
+
+        None
+    }
+
+    /// Returns the PDI matching an isolate initiator, as defined by BD9
+    fn matching_pdi(&self, initiator: usize) -> Option<usize> {
+        let mut depth = 0_usize;
+        let mut pos = self.after(initiator);
+
+        while pos < self.text.len() {
+            match self.original(pos) {
+                LRI | RLI | FSI => depth += 1,
+                PDI if depth == 0 => return Some(pos),
+                PDI => depth -= 1,
+                B => return None,
+                _ => {}
+            }
+
+            pos = self.after(pos);
+        }
+
+        None
+    }
+
+    /// Returns the isolate initiator matching a PDI, as defined by BD9
+    fn matching_initiator(&self, pdi: usize) -> Option<usize> {
+        let mut depth = 0_usize;
+        let mut pos = pdi;
+
+        while pos > 0 {
+            pos = Utf8Char::containing(self.text, pos - 1).0;
+
+            match self.original(pos) {
+                PDI => depth += 1,
+                LRI | RLI | FSI if depth == 0 => return Some(pos),
+                LRI | RLI | FSI => depth -= 1,
+                B => return None,
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    /// Returns the codepoint after `pos` in its isolating run sequence (BD13)
+    fn next_in_sequence(&self, pos: usize) -> Option<usize> {
+        let level = self.level_of(pos);
+
+        match self.kept_from(self.after(pos)) {
+            Some(next) if self.level_of(next) == level => Some(next),
+            _ if self.original(pos).is_isolate_initiator() => self
+                .matching_pdi(pos)
+                .filter(|&pdi| self.level_of(pdi) == level),
+            _ => None,
+        }
+    }
+
+    /// Returns the codepoint before `pos` in its isolating run sequence (BD13)
+    fn prev_in_sequence(&self, pos: usize) -> Option<usize> {
+        let level = self.level_of(pos);
+
+        match self.kept_before(pos) {
+            Some(prev) if self.level_of(prev) == level => Some(prev),
+            _ if self.original(pos) == PDI => self
+                .matching_initiator(pos)
+                .filter(|&initiator| self.level_of(initiator) == level),
+            _ => None,
+        }
+    }
+
+    /// Assigns explicit levels and directions, rules X1 to X8
+    fn explicit(&mut self) {
+        let mut stack = [Status {
+            level: self.level,
+            override_class: None,
+            isolate: false,
+        }; MAX_DEPTH as usize + 2];
+        let mut depth = 1;
+        let (mut overflow_isolates, mut overflow_embeddings, mut valid_isolates) = (0, 0, 0);
+
+        let mut pos = 0;
+
+        while pos < self.text.len() {
+            let class = self.original(pos);
+            let top = stack[depth - 1];
+
+            self.slots[pos] = Level {
+                value: top.level,
+                class,
+                bracket: Bracket::None,
+            };
+
+            match class {
+                RLE | LRE | RLO | LRO => {
+                    let level = next_level(top.level, matches!(class, RLE | RLO));
+
+                    if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                        stack[depth] = Status {
+                            level,
+                            override_class: match class {
+                                RLO => Some(R),
+                                LRO => Some(L),
+                                _ => None,
+                            },
+                            isolate: false,
+                        };
+                        depth += 1;
+                    } else if overflow_isolates == 0 {
+                        overflow_embeddings += 1;
+                    }
+                }
+                RLI | LRI | FSI => {
+                    if let Some(override_class) = top.override_class {
+                        self.slots[pos].class = override_class;
+                    }
+
+                    let rtl = match class {
+                        RLI => true,
+                        LRI => false,
+                        _ => {
+                            self.first_strong(self.after(pos), true) == Some(Direction::RightToLeft)
+                        }
+                    };
+                    let level = next_level(top.level, rtl);
+
+                    if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                        valid_isolates += 1;
+                        stack[depth] = Status {
+                            level,
+                            override_class: None,
+                            isolate: true,
+                        };
+                        depth += 1;
+                    } else {
+                        overflow_isolates += 1;
+                    }
+                }
+                PDI => {
+                    if overflow_isolates > 0 {
+                        overflow_isolates -= 1;
+                    } else if valid_isolates > 0 {
+                        overflow_embeddings = 0;
+
+                        while !stack[depth - 1].isolate {
+                            depth -= 1;
+                        }
+
+                        depth -= 1;
+                        valid_isolates -= 1;
+                    }
+
+                    let top = stack[depth - 1];
+
+                    self.slots[pos].value = top.level;
+
+                    if let Some(override_class) = top.override_class {
+                        self.slots[pos].class = override_class;
+                    }
+                }
+                PDF => {
+                    if overflow_isolates > 0 {
+                    } else if overflow_embeddings > 0 {
+                        overflow_embeddings -= 1;
+                    } else if !top.isolate && depth >= 2 {
+                        depth -= 1;
+                    }
+                }
+                B => {
+                    // a paragraph separator ends every embedding
+                    self.slots[pos].value = self.level;
+                    depth = 1;
+                    (overflow_isolates, overflow_embeddings, valid_isolates) = (0, 0, 0);
+                }
+                BN => {}
+                _ => {
+                    if let Some(override_class) = top.override_class {
+                        self.slots[pos].class = override_class;
+                    }
+                }
+            }
+
+            pos = self.after(pos);
+        }
+    }
+
+    /// Resolves the isolating run sequence starting at `head`, rules W1 to N2
+    fn sequence(&mut self, head: usize) {
+        let level = self.level_of(head);
+        let embedding = if level % 2 == 1 { R } else { L };
+
+        // the start and end of sequence types of rule X10
+        let before = self
+            .kept_before(head)
+            .map_or(self.level, |pos| self.level_of(pos));
+        let sos = if level.max(before) % 2 == 1 { R } else { L };
+
+        let mut last = head;
+
+        while let Some(next) = self.next_in_sequence(last) {
+            last = next;
+        }
+
+        let after = match self.kept_from(self.after(last)) {
+            Some(pos) if !self.original(last).is_isolate_initiator() => self.level_of(pos),
+            _ => self.level,
+        };
+        let eos = if level.max(after) % 2 == 1 { R } else { L };
+
+        self.weak(head, sos);
+        self.brackets(head, sos, embedding);
+        self.neutral(head, sos, eos, embedding);
+    }
+
+    /// Resolves weak types, rules W1 to W7
+    fn weak(&mut self, head: usize, sos: BidiClass) {
+        // W1 to W3
+        let (mut prev, mut strong) = (sos, sos);
+        let mut cur = Some(head);
+
+        while let Some(pos) = cur {
+            let mut class = self.class(pos);
+
+            if class == NSM {
+                class = if matches!(prev, LRI | RLI | FSI | PDI) {
+                    ON
+                } else {
+                    prev
+                };
+            }
+
+            prev = class;
+
+            if class == EN && strong == AL {
+                class = AN;
+            } else if matches!(class, L | R | AL) {
+                strong = class;
+            }
+
+            self.slots[pos].class = if class == AL { R } else { class };
+            cur = self.next_in_sequence(pos);
+        }
+
+        // W4
+        let mut prev = sos;
+        let mut cur = Some(head);
+
+        while let Some(pos) = cur {
+            let next = self.next_in_sequence(pos);
+            let next_class = next.map(|next| self.class(next));
+
+            match (prev, self.class(pos), next_class) {
+                (EN, ES | CS, Some(EN)) => self.slots[pos].class = EN,
+                (AN, CS, Some(AN)) => self.slots[pos].class = AN,
+                _ => {}
+            }
+
+            prev = self.class(pos);
+            cur = next;
+        }
+
+        // W5
+        let mut prev = sos;
+        let mut cur = Some(head);
+
+        while let Some(pos) = cur {
+            if self.class(pos) != ET {
+                prev = self.class(pos);
+                cur = self.next_in_sequence(pos);
+                continue;
+            }
+
+            let mut end = Some(pos);
+
+            while let Some(et) = end.filter(|&et| self.class(et) == ET) {
+                end = self.next_in_sequence(et);
+            }
+
+            if prev == EN || end.is_some_and(|end| self.class(end) == EN) {
+                self.fill(pos, end, EN);
+                prev = EN;
+            } else {
+                prev = ET;
+            }
+
+            cur = end;
+        }
+
+        // W6 and W7
+        let mut strong = sos;
+        let mut cur = Some(head);
+
+        while let Some(pos) = cur {
+            match self.class(pos) {
+                ES | ET | CS => self.slots[pos].class = ON,
+                EN if strong == L => self.slots[pos].class = L,
+                class @ (L | R) => strong = class,
+                _ => {}
+            }
+
+            cur = self.next_in_sequence(pos);
+        }
+    }
+
+    /// Sets the class of the codepoints from `start` up to `end` in a sequence
+    fn fill(&mut self, start: usize, end: Option<usize>, class: BidiClass) {
+        let mut cur = Some(start);
+
+        while let Some(pos) = cur.filter(|&pos| Some(pos) != end) {
+            self.slots[pos].class = class;
+            cur = self.next_in_sequence(pos);
+        }
+    }
+
+    /// Resolves paired brackets, rule N0
+    fn brackets(&mut self, head: usize, sos: BidiClass, embedding: BidiClass) {
+        /// The most brackets BD16 keeps open at once
+        const MAX_OPEN: usize = 63;
+
+        // BD16: pair up brackets, marking the pairs in their slots
+        let mut stack = [(Utf8Char::MIN, 0); MAX_OPEN];
+        let mut open = 0;
+        let mut cur = Some(head);
+
+        while let Some(pos) = cur {
+            cur = self.next_in_sequence(pos);
+
+            if self.class(pos) != ON {
+                continue;
+            }
+
+            let ch = self.ch(pos);
+
+            match ch.bidi_paired_bracket() {
+                Some((_, true)) if open == MAX_OPEN => break,
+                Some((pair, true)) => {
+                    stack[open] = (pair, pos);
+                    open += 1;
+                }
+                Some((_, false)) => {
+                    let ch = ch.canonical_bracket();
+
+                    if let Some(idx) = stack[..open].iter().rposition(|&(pair, _)| pair == ch) {
+                        self.slots[stack[idx].1].bracket = Bracket::Open;
+                        self.slots[pos].bracket = Bracket::Close;
+                        open = idx;
+                    }
+                }
+                None => {}
+            }
+        }
+
+        // resolve the pairs in the order of their opening brackets
+        let mut cur = Some(head);
+
+        while let Some(pos) = cur {
+            if self.slots[pos].bracket == Bracket::Open {
+                self.bracket_pair(pos, sos, embedding);
+            }
+
+            cur = self.next_in_sequence(pos);
+        }
+    }
+
+    /// Resolves the bracket pair opened at `open`, rule N0
+    fn bracket_pair(&mut self, open: usize, sos: BidiClass, embedding: BidiClass) {
+        let (mut depth, mut close) = (0_usize, open);
+        let (mut matching, mut opposite) = (false, false);
+
+        // find the closing bracket, and the strong types between the brackets
+        while let Some(pos) = self.next_in_sequence(close) {
+            close = pos;
+
+            match self.slots[pos].bracket {
+                Bracket::Open => depth += 1,
+                Bracket::Close if depth == 0 => break,
+                Bracket::Close => depth -= 1,
+                Bracket::None => {}
+            }
+
+            match self.class(pos).strong() {
+                Some(strong) if strong == embedding => matching = true,
+                Some(_) => opposite = true,
+                None => {}
+            }
+        }
+
+        let class = if matching {
+            embedding
+        } else if opposite {
+            // the strong type before the brackets decides
+            let mut context = sos;
+            let mut cur = self.prev_in_sequence(open);
+
+            while let Some(pos) = cur {
+                if let Some(strong) = self.class(pos).strong() {
+                    context = strong;
+                    break;
+                }
+
+                cur = self.prev_in_sequence(pos);
+            }
+
+            context
+        } else {
+            return;
+        };
+
+        for bracket in [open, close] {
+            self.slots[bracket].class = class;
+
+            // nonspacing marks after a bracket take its new type too
+            let mut cur = self.next_in_sequence(bracket);
+
+            while let Some(pos) = cur.filter(|&pos| self.original(pos) == NSM) {
+                self.slots[pos].class = class;
+                cur = self.next_in_sequence(pos);
+            }
+        }
+    }
+
+    /// Resolves neutral and isolate formatting types, rules N1 and N2
+    fn neutral(&mut self, head: usize, sos: BidiClass, eos: BidiClass, embedding: BidiClass) {
+        let mut prev = sos;
+        let mut cur = Some(head);
+
+        while let Some(pos) = cur {
+            if !self.class(pos).is_neutral() {
+                prev = self.class(pos).strong().unwrap_or(embedding);
+                cur = self.next_in_sequence(pos);
+                continue;
+            }
+
+            let mut end = Some(pos);
+
+            while let Some(neutral) = end.filter(|&neutral| self.class(neutral).is_neutral()) {
+                end = self.next_in_sequence(neutral);
+            }
+
+            let next = end.map_or(eos, |end| self.class(end).strong().unwrap_or(embedding));
+
+            self.fill(pos, end, if prev == next { prev } else { embedding });
+            cur = end;
+        }
+    }
+
+    /// Resolves the levels of the paragraph
+    fn resolve(&mut self) {
+        self.explicit();
+
+        // resolve every isolating run sequence, from its first codepoint
+        let mut pos = 0;
+
+        while let Some(head) = self.kept_from(pos) {
+            if self.prev_in_sequence(head).is_none() {
+                self.sequence(head);
+            }
+
+            pos = self.after(head);
+        }
+
+        // I1 and I2, and removed codepoints take the level of the codepoint before them
+        let (mut pos, mut prev) = (0, self.level);
+
+        while pos < self.text.len() {
+            let removed = self.original(pos).is_removed();
+            let slot = &mut self.slots[pos];
+
+            if removed {
+                slot.value = prev;
+            } else {
+                slot.value += match (slot.value % 2 == 1, slot.class) {
+                    (false, R) | (true, L | EN | AN) => 1,
+                    (false, AN | EN) => 2,
+                    _ => 0,
+                };
+            }
+
+            prev = slot.value;
+            pos = self.after(pos);
+        }
+
+        // L1: separators, and whitespace before them or at the end of the paragraph, reset to the
+        // paragraph level
+        let mut trailing = true;
+        let mut end = self.text.len();
+
+        for ch in Utf8CharIter::new(self.text).rev() {
+            let pos = end - ch.len_utf8() as usize;
+
+            match ch.bidi_class() {
+                S | B => {
+                    self.slots[pos].value = self.level;
+                    trailing = true;
+                }
+                class if is_whitespace_like(class) => {
+                    if trailing {
+                        self.slots[pos].value = self.level;
+                    }
+                }
+                _ => trailing = false,
+            }
+
+            // every byte of a codepoint gets its level
+            let value = self.slots[pos].value;
+
+            for slot in &mut self.slots[pos..end] {
+                *slot = Level::from_value(value);
+            }
+
+            end = pos;
+        }
+    }
+}
+
+/// Returns the least level above `level` of the wanted direction
+const fn next_level(level: u8, rtl: bool) -> u8 {
+    if rtl {
+        (level + 1) | 1
+    } else {
+        (level + 2) & !1
+    }
+}
+
+/// Returns whether codepoints of a class are reset along with trailing whitespace by rule L1
+const fn is_whitespace_like(class: BidiClass) -> bool {
+    matches!(class, WS | LRI | RLI | FSI | PDI) || class.is_removed()
+}
+
+/// A paragraph of text with the embedding level of every codepoint resolved by the Unicode
+/// Bidirectional Algorithm
+///
+/// Created by [`Paragraph::new`].
+#[derive(Clone, Debug)]
+pub struct Paragraph<'slice> {
+    /// The text of the paragraph
+    text: &'slice str,
+    /// The resolved level of every byte of the text
+    levels: &'slice [Level],
+    /// The paragraph embedding level
+    level: Level,
+}
+
+impl<'slice> Paragraph<'slice> {
+    /// Resolves the embedding levels of a paragraph of text into `levels`, one per byte.
+    ///
+    /// The paragraph direction is detected from its first strong codepoint when `direction` is
+    /// None, defaulting to left to right. The text is taken to be one paragraph, paragraph
+    /// separators inside it only reset the explicit embeddings before them.
+    ///
+    /// # Panics
+    /// Panics if `levels` is shorter than the text
+    ///
+    /// ```
+    /// use utf8char::bidi::{Direction, Level, Paragraph};
+    ///
+    /// let text = "abc \u{5D0}\u{5D1}";
+    /// let mut levels = [Level::LTR; 16];
+    /// let paragraph = Paragraph::new(text, Some(Direction::LeftToRight), &mut levels);
+    ///
+    /// let values = paragraph.levels().iter().map(|level| level.value());
+    ///
+    /// // every Hebrew letter is 2 bytes
+    /// assert!(values.eq([0, 0, 0, 0, 1, 1, 1, 1]));
+    /// ```
+    #[must_use]
+    pub fn new(
+        text: &'slice str,
+        direction: Option<Direction>,
+        levels: &'slice mut [Level],
+    ) -> Self {
+        assert!(
+            levels.len() >= text.len(),
+            "a paragraph needs a level for every byte"
+        );
+
+        let slots = &mut levels[..text.len()];
+        let mut resolver = Resolver {
+            text,
+            slots,
+            level: 0,
+        };
+
+        let direction = direction.or_else(|| resolver.first_strong(0, false));
+        resolver.level = u8::from(direction == Some(Direction::RightToLeft));
+        resolver.resolve();
+
+        Self {
+            text,
+            level: Level::from_value(resolver.level),
+            levels: resolver.slots,
+        }
+    }
+
+    /// Returns the text of the paragraph
+    #[must_use]
+    pub fn as_str(&self) -> &'slice str {
+        self.text
+    }
+
+    /// Returns the paragraph embedding level, odd for right to left paragraphs
+    #[must_use]
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Returns the resolved level of every byte of the paragraph
+    #[must_use]
+    pub fn levels(&self) -> &'slice [Level] {
+        self.levels
+    }
+
+    /// Returns the runs of a line of the paragraph in visual order, from left to right.
+    ///
+    /// To display a paragraph it is broken into lines by their logical byte ranges, and every line
+    /// is reordered on its own. Whitespace at the end of the line takes the paragraph level, so it
+    /// ends up on the side of the line the paragraph ends on.
+    ///
+    /// # Panics
+    /// Panics if `line` is out of bounds, or does not lie on char boundaries
+    ///
+    /// ```
+    /// use utf8char::bidi::{Level, Paragraph};
+    ///
+    /// let text = "car \u{5D0}\u{5D1}\u{5D2} 123";
+    /// let mut levels = [Level::LTR; 32];
+    /// let paragraph = Paragraph::new(text, None, &mut levels);
+    ///
+    /// // the number belongs to the Hebrew word, and is displayed to its left
+    /// let runs = paragraph.visual_runs(0..text.len());
+    /// let runs = runs.map(|run| (run.as_str(), run.is_rtl())).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(runs, [("car ", false), ("123", false), ("\u{5D0}\u{5D1}\u{5D2} ", true)]);
+    /// ```
+    #[must_use]
+    pub fn visual_runs(&self, line: Range<usize>) -> VisualRuns<'slice> {
+        assert!(
+            line.start <= line.end
+                && self.text.is_char_boundary(line.start)
+                && self.text.is_char_boundary(line.end),
+            "line is out of bounds or not on char boundaries"
+        );
+
+        // L1: whitespace at the end of the line takes the paragraph level
+        let mut trailing = line.end;
+
+        for ch in Utf8CharIter::new(&self.text[line.clone()]).rev() {
+            if !is_whitespace_like(ch.bidi_class()) {
+                break;
+            }
+
+            trailing -= ch.len_utf8() as usize;
+        }
+
+        let mut runs = VisualRuns {
+            text: self.text,
+            levels: self.levels,
+            level: self.level.value,
+            trailing,
+            frames: [Frame::default(); MAX_DEPTH as usize + 2],
+            depth: 0,
+        };
+
+        let level = (line.start..line.end)
+            .map(|idx| runs.level_at(idx))
+            .min()
+            .unwrap_or(0);
+
+        runs.frames[0] = Frame {
+            start: line.start,
+            end: line.end,
+            level,
+        };
+        runs.depth = usize::from(!line.is_empty());
+
+        runs
+    }
+}
+
+/// A maximal part of a line at one embedding level
+#[derive(Clone, Debug)]
+pub struct VisualRun<'slice> {
+    /// The text of the run, in logical order
+    text: &'slice str,
+    /// The byte offset of the run in its paragraph
+    offset: usize,
+    /// The embedding level of the run
+    level: Level,
+}
+
+impl<'slice> VisualRun<'slice> {
+    /// Returns the text of the run in logical order
+    #[must_use]
+    pub fn as_str(&self) -> &'slice str {
+        self.text
+    }
+
+    /// Returns the byte offset of the run in its paragraph
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the embedding level of the run
+    #[must_use]
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Returns whether the run is displayed right to left
+    #[must_use]
+    pub fn is_rtl(&self) -> bool {
+        self.level.is_rtl()
+    }
+}
+
+/// Writes the run in visual order: right to left runs are written backwards by grapheme cluster,
+/// so combining marks stay after their base, with mirrored codepoints replaced by their
+/// [mirroring glyph][Utf8Char::bidi_mirroring_glyph]
+impl fmt::Display for VisualRun<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.is_rtl() {
+            return f.write_str(self.text);
+        }
+
+        graphemes(self.text).rev().try_for_each(|grapheme| {
+            Utf8CharIter::new(grapheme)
+                .try_for_each(|ch| f.write_str(&ch.bidi_mirroring_glyph().unwrap_or(ch)))
+        })
+    }
+}
+
+/// A part of a line that is yet to be visited by [`VisualRuns`], at or above a level
+#[derive(Copy, Clone, Debug, Default)]
+struct Frame {
+    /// The start of the part that is left
+    start: usize,
+    /// The end of the part that is left
+    end: usize,
+    /// The lowest level in the part, which orders the runs and higher parts within it
+    level: u8,
+}
+
+/// An iterator over the runs of a line in visual order, applying rule L2
+///
+/// Created by [`Paragraph::visual_runs`]. Its `Display` writes the whole line as it is displayed.
+#[derive(Clone)]
+pub struct VisualRuns<'slice> {
+    /// The text of the paragraph
+    text: &'slice str,
+    /// The resolved levels of the paragraph
+    levels: &'slice [Level],
+    /// The paragraph embedding level
+    level: u8,
+    /// The start of the whitespace at the end of the line
+    trailing: usize,
+    /// The parts of the line left to visit, every one nested in the one before it
+    frames: [Frame; MAX_DEPTH as usize + 2],
+    /// The amount of frames in use
+    depth: usize,
+}
+
+impl VisualRuns<'_> {
+    /// Returns the level of a byte of the line
+    fn level_at(&self, idx: usize) -> u8 {
+        if idx >= self.trailing {
+            self.level
+        } else {
+            self.levels[idx].value
+        }
+    }
+}
+
+impl<'slice> Iterator for VisualRuns<'slice> {
+    type Item = VisualRun<'slice>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let frame = *self.frames[..self.depth].last()?;
+
+            if frame.start == frame.end {
+                self.depth -= 1;
+                continue;
+            }
+
+            // runs and higher parts are visited forwards at even levels and backwards at odd ones
+            let (start, end, lowest) = if frame.level % 2 == 0 {
+                let flat = self.level_at(frame.start) == frame.level;
+                let (mut end, mut lowest) = (frame.start, u8::MAX);
+
+                while end < frame.end && (self.level_at(end) == frame.level) == flat {
+                    lowest = lowest.min(self.level_at(end));
+                    end += 1;
+                }
+
+                self.frames[self.depth - 1].start = end;
+                (frame.start, end, lowest)
+            } else {
+                let flat = self.level_at(frame.end - 1) == frame.level;
+                let (mut start, mut lowest) = (frame.end, u8::MAX);
+
+                while start > frame.start && (self.level_at(start - 1) == frame.level) == flat {
+                    lowest = lowest.min(self.level_at(start - 1));
+                    start -= 1;
+                }
+
+                self.frames[self.depth - 1].end = start;
+                (start, frame.end, lowest)
+            };
+
+            if lowest == frame.level {
+                return Some(VisualRun {
+                    text: &self.text[start..end],
+                    offset: start,
+                    level: Level::from_value(lowest),
+                });
+            }
+
+            self.frames[self.depth] = Frame {
+                start,
+                end,
+                level: lowest,
+            };
+            self.depth += 1;
+        }
+    }
+}
+
+impl FusedIterator for VisualRuns<'_> {}
+
+impl fmt::Display for VisualRuns<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.clone().try_for_each(|run| run.fmt(f))
+    }
+}
+
+#[test]
+fn bidi_properties() {
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let ch = Utf8Char::from_char(c);
+
+        // every codepoint with a mirroring glyph is mirrored, and so is the glyph
+        if let Some(glyph) = ch.bidi_mirroring_glyph() {
+            assert!(ch.is_bidi_mirrored(), "{ch:?}");
+            assert!(glyph.is_bidi_mirrored(), "{glyph:?}");
+        }
+
+        if let Some((pair, _)) = ch.bidi_paired_bracket() {
+            assert!(ch.is_bidi_mirrored(), "{ch:?}");
+            assert_eq!(ch.bidi_class(), ON, "{ch:?}");
+            assert!(pair.bidi_paired_bracket().is_some(), "{ch:?}");
+        }
+    });
+
+    // unassigned codepoints in right to left blocks default to right to left
+    assert_eq!(Utf8Char::from_char('\u{5FF}').bidi_class(), R);
+    assert_eq!(Utf8Char::from_char('\u{10FFFF}').bidi_class(), BN);
+}
+
+#[test]
+fn paragraphs() {
+    use alloc::string::ToString;
+
+    for (text, direction, visual) in [
+        ("hello", None, "hello"),
+        ("", None, ""),
+        // an LTR paragraph with an RTL word and a number after it
+        ("a \u{5D0}\u{5D1} 12 b", None, "a 12 \u{5D1}\u{5D0} b"),
+        // numbers after Arabic letters are Arabic numbers, and stay left to right
+        ("\u{627}\u{628} 12", None, "12 \u{628}\u{627}"),
+        // brackets around opposite text take the direction of the text before them
+        ("abc (\u{5D0}\u{5D1}) def", None, "abc (\u{5D1}\u{5D0}) def"),
+        // and brackets in RTL text are mirrored
+        ("\u{5D0}(\u{5D1})", None, "(\u{5D1})\u{5D0}"),
+        ("\u{5D0}(a)", None, "(a)\u{5D0}"),
+        // trailing whitespace stays at the end of the line in the paragraph direction
+        (
+            "\u{5D0}\u{5D1}  ",
+            Some(Direction::LeftToRight),
+            "\u{5D1}\u{5D0}  ",
+        ),
+        // combining marks stay after their base
+        ("\u{5D0}\u{5B7}\u{5D1}", None, "\u{5D1}\u{5D0}\u{5B7}"),
+        // an override reverses Latin letters
+        ("\u{202E}abc\u{202C}", None, "\u{202E}cba\u{202C}"),
+        // an isolate keeps its contents from affecting the text around it
+        (
+            "\u{5D0} \u{2066}a\u{2069} 1",
+            None,
+            "1 \u{2069}a\u{2066} \u{5D0}",
+        ),
+        (
+            "1 \u{2067}a b\u{2069}",
+            Some(Direction::LeftToRight),
+            "1 \u{2067}a b\u{2069}",
+        ),
+        (
+            "\u{2068}\u{5D0}\u{5D1}\u{2069} a",
+            None,
+            "\u{2068}\u{5D1}\u{5D0}\u{2069} a",
+        ),
+    ] {
+        let mut levels = [Level::LTR; 64];
+        let paragraph = Paragraph::new(text, direction, &mut levels);
+
+        assert_eq!(
+            paragraph.visual_runs(0..text.len()).to_string(),
+            visual,
+            "{text:?}"
+        );
+    }
+}
+
+#[test]
+fn levels() {
+    let levels_of = |text: &str, direction| {
+        let mut levels = [Level::LTR; 128];
+        let paragraph = Paragraph::new(text, direction, &mut levels);
+
+        // one level per codepoint
+        Utf8CharIter::new(text)
+            .scan(0, |pos, ch| {
+                let level = paragraph.levels()[*pos].value();
+                *pos += ch.len_utf8() as usize;
+
+                Some(level)
+            })
+            .collect::<alloc::vec::Vec<_>>()
+    };
+
+    assert_eq!(levels_of("ab", None), [0, 0]);
+    assert_eq!(levels_of("ab", Some(Direction::RightToLeft)), [2, 2]);
+    assert_eq!(levels_of("\u{5D0} 1", None), [1, 1, 2]);
+    assert_eq!(levels_of("a\u{202B}b\u{202C}c", None), [0, 0, 2, 2, 0]);
+    assert_eq!(
+        levels_of("\u{5D0}\u{2066}a\u{5D1}\u{2069}", None),
+        [1, 1, 2, 3, 1]
+    );
+    // the first strong codepoint inside an isolate does not decide the paragraph direction
+    assert_eq!(levels_of("\u{2067}\u{5D0}\u{2069}a", None), [0, 1, 0, 0]);
+
+    // a closing bracket still pairs when as many brackets as BD16 keeps are open, and takes the
+    // direction of the Latin text before and inside the pair rather than of the Hebrew after it
+    let text = ["a(", &"[".repeat(62), "b)\u{5D0}"].concat();
+    assert_eq!(
+        levels_of(&text, Some(Direction::RightToLeft))[63..],
+        [2, 2, 2, 1]
+    );
+}
+
+#[test]
+fn visual_runs_of_lines() {
+    use alloc::{string::ToString, vec::Vec};
+
+    let text = "one \u{5D0}\u{5D1} two \u{5D2}\u{5D3}";
+    let mut levels = [Level::LTR; 64];
+    let paragraph = Paragraph::new(text, None, &mut levels);
+
+    // a line break after the first Hebrew word and its space
+    let split = text.find("two").unwrap();
+    let first = paragraph.visual_runs(0..split);
+    let runs = first.clone().map(|run| run.offset()).collect::<Vec<_>>();
+
+    assert_eq!(first.to_string(), "one \u{5D1}\u{5D0} ");
+    assert_eq!(runs, [0, 4, 8]);
+    assert_eq!(
+        paragraph.visual_runs(split..text.len()).to_string(),
+        "two \u{5D3}\u{5D2}"
+    );
+    assert_eq!(paragraph.visual_runs(3..3).count(), 0);
+}
+
+#[test]
+fn conformance() {
+    use alloc::{string::String, vec, vec::Vec};
+
+    use crate::tables::conformance::{BIDI_CHARACTER_TEST, BIDI_TEST, BIDI_TEST_CHARS};
+
+    // the paragraph level, the level of every codepoint and the logical indexes of the codepoints
+    // in visual order, leaving out the codepoints removed by rule X9 like the test files do
+    let resolve = |text: &str, direction| {
+        let mut levels = vec![Level::LTR; text.len()];
+        let paragraph = Paragraph::new(text, direction, &mut levels);
+        let offsets = text
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .collect::<Vec<_>>();
+        let removed = |idx: usize| {
+            Utf8Char::containing(text, offsets[idx])
+                .1
+                .bidi_class()
+                .is_removed()
+        };
+
+        let mut resolved = vec![None; offsets.len()];
+        let mut order = Vec::new();
+
+        for run in paragraph.visual_runs(0..text.len()) {
+            let range = run.offset()..run.offset() + run.as_str().len();
+            let start = offsets.partition_point(|offset| *offset < range.start);
+            let end = offsets.partition_point(|offset| *offset < range.end);
+            let kept = (start..end).filter(|&idx| !removed(idx));
+
+            for idx in kept.clone() {
+                resolved[idx] = Some(run.level().value());
+            }
+
+            if run.is_rtl() {
+                order.extend(kept.rev());
+            } else {
+                order.extend(kept);
+            }
+        }
+
+        (paragraph.level().value(), resolved, order)
+    };
+
+    let parse = |levels: &str, order: &str| {
+        let levels = levels.split(' ').map(|level| level.parse::<u8>().ok());
+        let order = order
+            .split_whitespace()
+            .map(|idx| idx.parse::<usize>().unwrap());
+
+        (levels.collect::<Vec<_>>(), order.collect::<Vec<_>>())
+    };
+
+    // every letter of BidiTest.txt cases stands for a codepoint of its class
+    for (idx, &c) in BIDI_TEST_CHARS.iter().enumerate() {
+        assert_eq!(Utf8Char::from_char(c).bidi_class() as usize, idx, "{c:?}");
+    }
+
+    for &(levels, order, cases) in BIDI_TEST {
+        let expected = parse(levels, order);
+
+        for &(directions, cases) in cases {
+            for case in cases.split(' ') {
+                let text = case
+                    .bytes()
+                    .map(|letter| BIDI_TEST_CHARS[usize::from(letter - b'A')])
+                    .collect::<String>();
+
+                for (bit, direction) in [
+                    (1, None),
+                    (2, Some(Direction::LeftToRight)),
+                    (4, Some(Direction::RightToLeft)),
+                ] {
+                    if directions & bit != 0 {
+                        let (_, levels, order) = resolve(&text, direction);
+                        assert_eq!((levels, order), expected, "{case} {direction:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    for &(text, direction, level, levels, order) in BIDI_CHARACTER_TEST {
+        let direction = match direction {
+            0 => Some(Direction::LeftToRight),
+            1 => Some(Direction::RightToLeft),
+            _ => None,
+        };
+        let (levels, order) = parse(levels, order);
+
+        assert_eq!(
+            resolve(text, direction),
+            (level, levels, order),
+            "{text:?} {direction:?}"
+        );
+    }
+}
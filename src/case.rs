@@ -0,0 +1,371 @@
+//! Full unicode case mapping, and simple case folding, of `Utf8Char`'s
+
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    inline::{inline_str_iter, InlineStr},
+    tables::{
+        case::{
+            CASE_FOLD, FOLDS, MAPPINGS, MAX_MAPPING_LEN, TO_LOWERCASE, TO_TITLECASE, TO_UPPERCASE,
+        },
+        Utf8Trie, LEVEL,
+    },
+    Utf8Char, Utf8CharInner, Utf8CharIter,
+};
+
+/// An iterator over the `Utf8Char`'s a codepoint maps to when changing its case.
+///
+/// Returned by [`Utf8Char::to_lowercase`], [`Utf8Char::to_uppercase`] and
+/// [`Utf8Char::to_titlecase`]. A mapping may expand to up to 3 codepoints, for instance `'ß'`
+/// uppercases to `"SS"`.
+///
+/// The codepoints that have not been iterated over yet are available as a `&str` through `Deref`.
+#[derive(Clone)]
+pub struct CaseMapping(InlineStr<MAX_MAPPING_LEN>);
+
+impl CaseMapping {
+    /// Looks up a codepoint in a case table, ascii must already be handled
+    const fn lookup(ch: Utf8Char, table: &Utf8Trie<[u16; LEVEL]>) -> Self {
+        match table.get(ch) {
+            0 => Self(InlineStr::new(ch.as_str())),
+            idx => Self(InlineStr::new(MAPPINGS[idx as usize])),
+        }
+    }
+}
+
+inline_str_iter!(CaseMapping);
+
+impl Utf8Char {
+    /// equivalent to [`char::to_lowercase`] for `Utf8Char`
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(&*utf8char!("Σ").to_lowercase(), "σ");
+    /// assert_eq!(&*utf8char!("İ").to_lowercase(), "i\u{307}");
+    /// ```
+    #[must_use]
+    pub const fn to_lowercase(self) -> CaseMapping {
+        if self.is_ascii() {
+            return CaseMapping(InlineStr::new(self.to_ascii_lowercase().as_str()));
+        }
+
+        CaseMapping::lookup(self, &TO_LOWERCASE)
+    }
+
+    /// equivalent to [`char::to_uppercase`] for `Utf8Char`
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(&*utf8char!("ß").to_uppercase(), "SS");
+    /// ```
+    #[must_use]
+    pub const fn to_uppercase(self) -> CaseMapping {
+        if self.is_ascii() {
+            return CaseMapping(InlineStr::new(self.to_ascii_uppercase().as_str()));
+        }
+
+        CaseMapping::lookup(self, &TO_UPPERCASE)
+    }
+
+    /// Returns an iterator that yields the titlecase mapping of this `Utf8Char` as one or more
+    /// `Utf8Char`'s.
+    ///
+    /// This is the mapping used for the first letter of a word, for most codepoints it is the same as
+    /// [`to_uppercase`][Self::to_uppercase]. Like `char`'s case mappings, only the unconditional
+    /// mappings from `SpecialCasing.txt` are applied.
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(&*utf8char!("ǆ").to_titlecase(), "ǅ");
+    /// assert_eq!(&*utf8char!("ß").to_titlecase(), "Ss");
+    /// ```
+    #[must_use]
+    pub const fn to_titlecase(self) -> CaseMapping {
+        if self.is_ascii() {
+            return CaseMapping(InlineStr::new(self.to_ascii_uppercase().as_str()));
+        }
+
+        CaseMapping::lookup(self, &TO_TITLECASE)
+    }
+
+    /// Returns the simple case folding of this codepoint, as defined by the `C` and `S` entries of
+    /// `CaseFolding.txt`.
+    ///
+    /// Case folding maps every codepoint that only differs by case to the same codepoint, which makes
+    /// it suitable for caseless comparison. Simple folding always maps to a single codepoint, so
+    /// `'ß'` stays `'ß'` (the full folding would be `"ss"`).
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(utf8char!("A").case_fold(), utf8char!("a"));
+    /// assert_eq!(utf8char!("Σ").case_fold(), utf8char!("σ"));
+    /// assert_eq!(utf8char!("ς").case_fold(), utf8char!("σ"));
+    /// assert_eq!(utf8char!("ẞ").case_fold(), utf8char!("ß"));
+    /// ```
+    #[must_use]
+    pub const fn case_fold(self) -> Self {
+        if self.is_ascii() {
+            return self.to_ascii_lowercase();
+        }
+
+        match CASE_FOLD.get(self) {
+            0 => self,
+            // SAFETY: FOLDS holds the to_bits representation of valid codepoints
+            idx => Self(unsafe {
+                Utf8CharInner::from_utf8char_array(FOLDS[idx as usize].to_be_bytes())
+            }),
+        }
+    }
+
+    /// Returns whether two codepoints are equal ignoring case, that is when their
+    /// [`case_fold`][Self::case_fold]s are equal
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert!(utf8char!("ǅ").eq_ignore_case(utf8char!("ǆ")));
+    /// assert!(!utf8char!("a").eq_ignore_case(utf8char!("b")));
+    /// ```
+    #[must_use]
+    pub const fn eq_ignore_case(self, other: Self) -> bool {
+        self.case_fold().to_bits() == other.case_fold().to_bits()
+    }
+}
+
+/// A wrapper that compares, orders and hashes strings (or a `Utf8Char`) by their simple case
+/// folding, see [`Utf8Char::case_fold`].
+///
+/// Any `T: AsRef<str>` can be wrapped. Folding happens on the fly, so it is usable as a
+/// `HashMap`/`BTreeMap` key without allocating a folded copy of every key. Owned keys can be looked
+/// up by a borrowed `&CaseInsensitive<str>`, made by [`CaseInsensitive::from_ref`].
+///
+/// ```
+/// use std::collections::HashMap;
+/// use utf8char::case::CaseInsensitive;
+///
+/// let mut keywords = HashMap::new();
+/// keywords.insert(CaseInsensitive(String::from("Straße")), 1);
+///
+/// assert_eq!(keywords.get(CaseInsensitive::from_ref("STRAẞE")), Some(&1));
+/// assert_eq!(CaseInsensitive("ΣΊΣΥΦΟΣ"), CaseInsensitive("σίσυφος"));
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(transparent)]
+pub struct CaseInsensitive<T: ?Sized>(pub T);
+
+impl<T: ?Sized> CaseInsensitive<T> {
+    /// Wraps a reference, without requiring ownership of the value
+    #[must_use]
+    pub const fn from_ref(value: &T) -> &Self {
+        // SAFETY: CaseInsensitive is repr(transparent) over T
+        unsafe { &*(core::ptr::from_ref(value) as *const Self) }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> CaseInsensitive<T> {
+    /// Returns the case folded codepoints of the wrapped string
+    fn folded(&self) -> impl Iterator<Item = Utf8Char> + '_ {
+        Utf8CharIter::new(self.0.as_ref()).map(Utf8Char::case_fold)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized, U: AsRef<str> + ?Sized> PartialEq<CaseInsensitive<U>>
+    for CaseInsensitive<T>
+{
+    fn eq(&self, other: &CaseInsensitive<U>) -> bool {
+        self.folded().eq(other.folded())
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Eq for CaseInsensitive<T> {}
+
+impl<T: AsRef<str> + ?Sized, U: AsRef<str> + ?Sized> PartialOrd<CaseInsensitive<U>>
+    for CaseInsensitive<T>
+{
+    fn partial_cmp(&self, other: &CaseInsensitive<U>) -> Option<Ordering> {
+        Some(self.folded().cmp(other.folded()))
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Ord for CaseInsensitive<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.folded().cmp(other.folded())
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Hash for CaseInsensitive<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // hashed like the folded str would be
+        for ch in self.folded() {
+            state.write(ch.as_bytes());
+        }
+        state.write_u8(0xFF);
+    }
+}
+
+impl<T: AsRef<str>> Borrow<CaseInsensitive<str>> for CaseInsensitive<T> {
+    fn borrow(&self) -> &CaseInsensitive<str> {
+        CaseInsensitive::from_ref(self.0.as_ref())
+    }
+}
+
+#[test]
+fn case_matches_std() {
+    use alloc::string::String;
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let utf8 = Utf8Char::from_char(c);
+
+        macro_rules! identical {
+            ($($fn:ident),+) => {$({
+                let mapping = utf8.$fn();
+
+                assert_eq!(mapping.as_str(), c.$fn().collect::<String>(), "{c:?}");
+                assert_eq!(mapping.len(), c.$fn().len(), "{c:?}");
+                assert!(
+                    mapping.clone().map(Utf8Char::to_char).eq(c.$fn()),
+                    "{c:?}"
+                );
+                assert!(
+                    mapping.rev().map(Utf8Char::to_char).eq(c.$fn().rev()),
+                    "{c:?}"
+                );
+            })+};
+        }
+
+        identical!(to_lowercase, to_uppercase);
+
+        // std has no titlecase mapping, but it only differs from uppercase for digraphs, the
+        // expansions of ligatures and greek letters with iota subscripts, and georgian letters
+        // which titlecase to themselves
+        let title = utf8.to_titlecase();
+        let upper = utf8.to_uppercase();
+        if title.as_str() != upper.as_str() {
+            assert!(
+                title
+                    .as_str()
+                    .starts_with(upper.clone().next().unwrap().as_str())
+                    || title.clone().next().unwrap().general_category()
+                        == crate::GeneralCategory::TitlecaseLetter
+                    || title.as_str() == utf8.as_str(),
+                "{c:?}"
+            );
+        }
+    });
+}
+
+#[test]
+fn titlecase() {
+    use crate::utf8char;
+
+    for (ch, title) in [
+        (utf8char!("a"), "A"),
+        (utf8char!("ǆ"), "ǅ"),
+        (utf8char!("ǅ"), "ǅ"),
+        (utf8char!("Ǆ"), "ǅ"),
+        (utf8char!("ß"), "Ss"),
+        (utf8char!("ﬃ"), "Ffi"),
+        (utf8char!("և"), "Եւ"),
+        (utf8char!("ᾳ"), "ᾼ"),
+        (utf8char!("ῼ"), "ῼ"),
+        (utf8char!("ǈ"), "ǈ"),
+    ] {
+        assert_eq!(ch.to_titlecase().as_str(), title, "{ch:?}");
+    }
+}
+
+#[test]
+fn case_fold() {
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let utf8 = Utf8Char::from_char(c);
+        let folded = utf8.case_fold();
+
+        assert_eq!(folded.case_fold(), folded, "{c:?}");
+        assert!(utf8.eq_ignore_case(folded), "{c:?}");
+        assert_eq!(
+            utf8.eq_ignore_ascii_case(Utf8Char::from_char('a')),
+            c.is_ascii() && folded.as_str() == "a"
+        );
+
+        // folding only happens between codepoints that case map to each other, and the folding of
+        // a cased codepoint is usually its lowercase
+        if folded != utf8 {
+            let lower = utf8.to_lowercase();
+            let upper = folded.to_uppercase();
+
+            assert!(
+                lower.as_str() == folded.as_str()
+                    || upper.as_str() == utf8.as_str()
+                    || folded.to_uppercase().as_str() == utf8.to_uppercase().as_str(),
+                "{c:?}"
+            );
+        }
+    });
+}
+
+#[test]
+fn case_insensitive() {
+    use alloc::{string::String, vec::Vec};
+
+    use crate::utf8char;
+
+    /// Records what is hashed, so equal hashes can be checked without randomness
+    #[derive(Default)]
+    struct Recorder(Vec<u8>);
+
+    impl Hasher for Recorder {
+        fn finish(&self) -> u64 {
+            0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            self.0.extend_from_slice(bytes);
+        }
+    }
+
+    fn hashed<T: Hash + ?Sized>(value: &T) -> Vec<u8> {
+        let mut recorder = Recorder::default();
+        value.hash(&mut recorder);
+        recorder.0
+    }
+
+    for (a, b) in [
+        ("hello", "HELLO"),
+        ("Straße", "STRAẞE"),
+        ("ΣΊΣΥΦΟΣ", "σίσυφος"),
+        ("ǅemal", "ǆEMAL"),
+        ("", ""),
+    ] {
+        assert_eq!(CaseInsensitive(a), CaseInsensitive(b));
+        assert_eq!(CaseInsensitive(a).cmp(&CaseInsensitive(b)), Ordering::Equal);
+        assert_eq!(
+            hashed(&CaseInsensitive(a)),
+            hashed(&CaseInsensitive(String::from(b)))
+        );
+        assert_eq!(
+            hashed(&CaseInsensitive(a)),
+            hashed(CaseInsensitive::from_ref(b))
+        );
+    }
+
+    assert_ne!(CaseInsensitive("a"), CaseInsensitive("ab"));
+    assert!(CaseInsensitive("B") > CaseInsensitive("a"));
+    assert_eq!(CaseInsensitive(utf8char!("Ä")), CaseInsensitive("ä"));
+    assert_eq!(
+        hashed(&CaseInsensitive(utf8char!("Ä"))),
+        hashed(&CaseInsensitive("ä"))
+    );
+    // hashed like the folded str
+    assert_eq!(hashed(&CaseInsensitive("ΣΑ")), hashed("σα"));
+}
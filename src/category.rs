@@ -0,0 +1,240 @@
+//! The unicode [`GeneralCategory`] of a `Utf8Char`
+
+use crate::{tables::properties::GENERAL_CATEGORY, Utf8Char};
+
+/// The `General_Category` property of a codepoint, as defined in
+/// [UAX #44](https://www.unicode.org/reports/tr44/#General_Category_Values).
+///
+/// Variants are named after the long property value aliases. There is no `Surrogate` variant as a
+/// [`Utf8Char`] can never hold a surrogate codepoint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GeneralCategory {
+    /// `Lu`: an uppercase letter
+    UppercaseLetter,
+    /// `Ll`: a lowercase letter
+    LowercaseLetter,
+    /// `Lt`: a digraphic character, with first part uppercase
+    TitlecaseLetter,
+    /// `Lm`: a modifier letter
+    ModifierLetter,
+    /// `Lo`: other letters, including syllables and ideographs
+    OtherLetter,
+    /// `Mn`: a nonspacing combining mark (zero advance width)
+    NonspacingMark,
+    /// `Mc`: a spacing combining mark (positive advance width)
+    SpacingMark,
+    /// `Me`: an enclosing combining mark
+    EnclosingMark,
+    /// `Nd`: a decimal digit
+    DecimalNumber,
+    /// `Nl`: a letterlike numeric character
+    LetterNumber,
+    /// `No`: a numeric character of other type
+    OtherNumber,
+    /// `Pc`: a connecting punctuation mark, like a tie
+    ConnectorPunctuation,
+    /// `Pd`: a dash or hyphen punctuation mark
+    DashPunctuation,
+    /// `Ps`: an opening punctuation mark (of a pair)
+    OpenPunctuation,
+    /// `Pe`: a closing punctuation mark (of a pair)
+    ClosePunctuation,
+    /// `Pi`: an initial quotation mark
+    InitialPunctuation,
+    /// `Pf`: a final quotation mark
+    FinalPunctuation,
+    /// `Po`: a punctuation mark of other type
+    OtherPunctuation,
+    /// `Sm`: a symbol of mathematical use
+    MathSymbol,
+    /// `Sc`: a currency sign
+    CurrencySymbol,
+    /// `Sk`: a non-letterlike modifier symbol
+    ModifierSymbol,
+    /// `So`: a symbol of other type
+    OtherSymbol,
+    /// `Zs`: a space character (of various non-zero widths)
+    SpaceSeparator,
+    /// `Zl`: U+2028 LINE SEPARATOR only
+    LineSeparator,
+    /// `Zp`: U+2029 PARAGRAPH SEPARATOR only
+    ParagraphSeparator,
+    /// `Cc`: a C0 or C1 control code
+    Control,
+    /// `Cf`: a format control character
+    Format,
+    /// `Co`: a private-use character
+    PrivateUse,
+    /// `Cn`: a reserved unassigned code point or a noncharacter
+    Unassigned,
+}
+
+impl GeneralCategory {
+    /// Returns the short property value alias of this category, e.g. `"Lu"`
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            Self::UppercaseLetter => "Lu",
+            Self::LowercaseLetter => "Ll",
+            Self::TitlecaseLetter => "Lt",
+            Self::ModifierLetter => "Lm",
+            Self::OtherLetter => "Lo",
+            Self::NonspacingMark => "Mn",
+            Self::SpacingMark => "Mc",
+            Self::EnclosingMark => "Me",
+            Self::DecimalNumber => "Nd",
+            Self::LetterNumber => "Nl",
+            Self::OtherNumber => "No",
+            Self::ConnectorPunctuation => "Pc",
+            Self::DashPunctuation => "Pd",
+            Self::OpenPunctuation => "Ps",
+            Self::ClosePunctuation => "Pe",
+            Self::InitialPunctuation => "Pi",
+            Self::FinalPunctuation => "Pf",
+            Self::OtherPunctuation => "Po",
+            Self::MathSymbol => "Sm",
+            Self::CurrencySymbol => "Sc",
+            Self::ModifierSymbol => "Sk",
+            Self::OtherSymbol => "So",
+            Self::SpaceSeparator => "Zs",
+            Self::LineSeparator => "Zl",
+            Self::ParagraphSeparator => "Zp",
+            Self::Control => "Cc",
+            Self::Format => "Cf",
+            Self::PrivateUse => "Co",
+            Self::Unassigned => "Cn",
+        }
+    }
+
+    /// Returns whether this is a letter category (`L`)
+    #[must_use]
+    pub const fn is_letter(self) -> bool {
+        matches!(
+            self,
+            Self::UppercaseLetter
+                | Self::LowercaseLetter
+                | Self::TitlecaseLetter
+                | Self::ModifierLetter
+                | Self::OtherLetter
+        )
+    }
+
+    /// Returns whether this is a cased letter category (`LC`)
+    #[must_use]
+    pub const fn is_cased_letter(self) -> bool {
+        matches!(
+            self,
+            Self::UppercaseLetter | Self::LowercaseLetter | Self::TitlecaseLetter
+        )
+    }
+
+    /// Returns whether this is a mark category (`M`)
+    #[must_use]
+    pub const fn is_mark(self) -> bool {
+        matches!(
+            self,
+            Self::NonspacingMark | Self::SpacingMark | Self::EnclosingMark
+        )
+    }
+
+    /// Returns whether this is a number category (`N`)
+    #[must_use]
+    pub const fn is_number(self) -> bool {
+        matches!(
+            self,
+            Self::DecimalNumber | Self::LetterNumber | Self::OtherNumber
+        )
+    }
+
+    /// Returns whether this is a punctuation category (`P`)
+    #[must_use]
+    pub const fn is_punctuation(self) -> bool {
+        matches!(
+            self,
+            Self::ConnectorPunctuation
+                | Self::DashPunctuation
+                | Self::OpenPunctuation
+                | Self::ClosePunctuation
+                | Self::InitialPunctuation
+                | Self::FinalPunctuation
+                | Self::OtherPunctuation
+        )
+    }
+
+    /// Returns whether this is a symbol category (`S`)
+    #[must_use]
+    pub const fn is_symbol(self) -> bool {
+        matches!(
+            self,
+            Self::MathSymbol | Self::CurrencySymbol | Self::ModifierSymbol | Self::OtherSymbol
+        )
+    }
+
+    /// Returns whether this is a separator category (`Z`)
+    #[must_use]
+    pub const fn is_separator(self) -> bool {
+        matches!(
+            self,
+            Self::SpaceSeparator | Self::LineSeparator | Self::ParagraphSeparator
+        )
+    }
+
+    /// Returns whether this is an other category (`C`)
+    #[must_use]
+    pub const fn is_other(self) -> bool {
+        matches!(
+            self,
+            Self::Control | Self::Format | Self::PrivateUse | Self::Unassigned
+        )
+    }
+}
+
+impl Utf8Char {
+    /// Returns the [`GeneralCategory`] of this codepoint
+    ///
+    /// ```
+    /// use utf8char::{utf8char, GeneralCategory};
+    ///
+    /// assert_eq!(utf8char!("A").general_category(), GeneralCategory::UppercaseLetter);
+    /// assert_eq!(utf8char!("٣").general_category(), GeneralCategory::DecimalNumber);
+    /// ```
+    #[must_use]
+    pub const fn general_category(self) -> GeneralCategory {
+        GENERAL_CATEGORY.get(self)
+    }
+}
+
+#[test]
+fn general_category_matches_std() {
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let category = Utf8Char::from_char(c).general_category();
+
+        // std does not expose the general category, check it against the predicates derived from it
+        assert_eq!(category.is_number(), c.is_numeric(), "{c:?}");
+        assert_eq!(
+            category == GeneralCategory::Control,
+            c.is_control(),
+            "{c:?}"
+        );
+
+        match category {
+            GeneralCategory::UppercaseLetter => assert!(c.is_uppercase(), "{c:?}"),
+            GeneralCategory::LowercaseLetter => assert!(c.is_lowercase(), "{c:?}"),
+            GeneralCategory::SpaceSeparator
+            | GeneralCategory::LineSeparator
+            | GeneralCategory::ParagraphSeparator => assert!(c.is_whitespace(), "{c:?}"),
+            _ => {}
+        }
+    });
+
+    assert_eq!(
+        Utf8Char::from_char('\u{E000}').general_category(),
+        GeneralCategory::PrivateUse
+    );
+    assert_eq!(
+        Utf8Char::from_char('\u{FFFF}').general_category(),
+        GeneralCategory::Unassigned
+    );
+}
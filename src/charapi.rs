@@ -1,14 +1,19 @@
 //! Containing file for API's that mimic `char` behaviour
 
-use core::fmt;
+use core::fmt::{self, Write};
 
 use super::Utf8Char;
+use crate::{
+    tables::properties::{ALPHABETIC, LOWERCASE, UPPERCASE, WHITE_SPACE},
+    GeneralCategory,
+};
 
 impl fmt::Debug for Utf8Char {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // copying the implementation deemed too
-        // much of a maintenance burden for debug printing
-        fmt::Debug::fmt(&self.to_char(), f)
+        // identical to char's Debug implementation, which does not escape double quotes
+        f.write_char('\'')?;
+        f.write_str(self.escape_debug_ext(true, false).as_str())?;
+        f.write_char('\'')
     }
 }
 
@@ -46,7 +51,7 @@ macro_rules! project_ascii {
 impl Utf8Char {
     /// Returns first byte of utf8char, more compact than writing `self.0.first_byte().0`
     #[must_use]
-    const fn ascii(self) -> u8 {
+    pub(crate) const fn ascii(self) -> u8 {
         self.0.first_byte().0 as u8
     }
 
@@ -112,12 +117,54 @@ impl Utf8Char {
         self
     }
 
+    /// equivalent to [`char::is_alphabetic`] for `Utf8Char`
+    #[must_use]
+    pub const fn is_alphabetic(self) -> bool {
+        ALPHABETIC.contains(self)
+    }
+    /// equivalent to [`char::is_alphanumeric`] for `Utf8Char`
+    #[must_use]
+    pub const fn is_alphanumeric(self) -> bool {
+        self.is_alphabetic() || self.is_numeric()
+    }
+    /// equivalent to [`char::is_control`] for `Utf8Char`
+    #[must_use]
+    pub const fn is_control(self) -> bool {
+        matches!(self.general_category(), GeneralCategory::Control)
+    }
+    /// equivalent to [`char::is_lowercase`] for `Utf8Char`
+    #[must_use]
+    pub const fn is_lowercase(self) -> bool {
+        LOWERCASE.contains(self)
+    }
+    /// equivalent to [`char::is_numeric`] for `Utf8Char`
+    #[must_use]
+    pub const fn is_numeric(self) -> bool {
+        self.general_category().is_number()
+    }
+    /// equivalent to [`char::is_uppercase`] for `Utf8Char`
+    #[must_use]
+    pub const fn is_uppercase(self) -> bool {
+        UPPERCASE.contains(self)
+    }
+    /// equivalent to [`char::is_whitespace`] for `Utf8Char`
+    #[must_use]
+    pub const fn is_whitespace(self) -> bool {
+        WHITE_SPACE.contains(self)
+    }
+
     /// equivalent to [`char::is_digit`] for `Utf8Char`
+    ///
+    /// # Panics
+    /// Panics if given a radix smaller than 2 or larger than 36.
     #[must_use]
     pub const fn is_digit(self, radix: u8) -> bool {
         self.to_digit(radix).is_some()
     }
     /// equivalent to [`char::to_digit`] for `Utf8Char`
+    ///
+    /// # Panics
+    /// Panics if given a radix smaller than 2 or larger than 36.
     #[must_use]
     pub const fn to_digit(self, radix: u8) -> Option<u8> {
         // Copied completely from char::to_digit with slight tweaks to support a u8 based api
@@ -175,6 +222,16 @@ fn charapi_matches() {
             is_ascii_whitespace
         );
 
+        identical!(
+            is_alphabetic,
+            is_alphanumeric,
+            is_control,
+            is_lowercase,
+            is_numeric,
+            is_uppercase,
+            is_whitespace
+        );
+
         assert_eq!(utf8.to_ascii_lowercase().to_char(), c.to_ascii_lowercase());
         assert_eq!(utf8.to_ascii_uppercase().to_char(), c.to_ascii_uppercase());
 
@@ -187,11 +244,11 @@ fn charapi_matches() {
         assert_eq!(newch, utf8.to_ascii_uppercase());
 
         for radix in 2..=36 {
-            assert_eq!(utf8.is_digit(radix), c.is_digit(radix as u32));
+            assert_eq!(utf8.is_digit(radix), c.is_digit(u32::from(radix)));
             assert_eq!(
-                utf8.to_digit(radix),
-                c.to_digit(radix as u32).map(|n| n as u8)
+                utf8.to_digit(radix).map(u32::from),
+                c.to_digit(u32::from(radix))
             );
         }
-    })
+    });
 }
@@ -0,0 +1,535 @@
+//! Emoji properties of `Utf8Char`'s, and recognising emoji sequences in strings, as defined in
+//! [UTS #51](https://www.unicode.org/reports/tr51/)
+
+use core::{fmt, iter::FusedIterator};
+
+use crate::{
+    tables::{
+        emoji::{EMOJI, EMOJI_MODIFIER_BASE, EMOJI_PRESENTATION},
+        grapheme::EXTENDED_PICTOGRAPHIC,
+    },
+    Utf8Char, Utf8CharIter,
+};
+
+/// VARIATION SELECTOR-15, requests the text presentation of the emoji before it
+const VS15: Utf8Char = Utf8Char::from_char('\u{FE0E}');
+/// VARIATION SELECTOR-16, requests the emoji presentation of the emoji before it
+const VS16: Utf8Char = Utf8Char::from_char('\u{FE0F}');
+/// ZERO WIDTH JOINER, joins emoji into a single one
+const ZWJ: Utf8Char = Utf8Char::from_char('\u{200D}');
+/// COMBINING ENCLOSING KEYCAP
+const KEYCAP: Utf8Char = Utf8Char::from_char('\u{20E3}');
+/// WAVING BLACK FLAG, the base of subdivision flags
+const TAG_BASE: Utf8Char = Utf8Char::from_char('\u{1F3F4}');
+/// CANCEL TAG, ends a tag sequence
+const CANCEL_TAG: Utf8Char = Utf8Char::from_char('\u{E007F}');
+
+impl Utf8Char {
+    /// Returns whether this codepoint has the `Emoji` property
+    ///
+    /// Note that this includes codepoints usually displayed as text, like the ascii digits and
+    /// `'©'`, see [`is_emoji_presentation`][Self::is_emoji_presentation].
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert!(utf8char!("😀").is_emoji());
+    /// assert!(utf8char!("©").is_emoji());
+    /// assert!(!utf8char!("a").is_emoji());
+    /// ```
+    #[must_use]
+    pub const fn is_emoji(self) -> bool {
+        EMOJI.contains(self)
+    }
+
+    /// Returns whether this codepoint is displayed as an emoji, rather than as text, by default
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert!(utf8char!("😀").is_emoji_presentation());
+    /// assert!(!utf8char!("©").is_emoji_presentation());
+    /// ```
+    #[must_use]
+    pub const fn is_emoji_presentation(self) -> bool {
+        EMOJI_PRESENTATION.contains(self)
+    }
+
+    /// Returns whether this codepoint is one of the five skin tone modifiers, `U+1F3FB..=U+1F3FF`
+    #[must_use]
+    pub const fn is_emoji_modifier(self) -> bool {
+        // U+1F3FB..=U+1F3FF
+        matches!(self.to_bits(), 0xF09F_8FBB..=0xF09F_8FBF)
+    }
+
+    /// Returns whether this codepoint is an emoji whose appearance a following skin tone modifier
+    /// changes
+    #[must_use]
+    pub const fn is_emoji_modifier_base(self) -> bool {
+        EMOJI_MODIFIER_BASE.contains(self)
+    }
+
+    /// Returns whether this codepoint has the `Extended_Pictographic` property, the emoji and the
+    /// codepoints reserved for future emoji
+    #[must_use]
+    pub const fn is_extended_pictographic(self) -> bool {
+        EXTENDED_PICTOGRAPHIC.contains(self)
+    }
+
+    /// Returns whether this codepoint is one of the 26 regional indicator symbols,
+    /// `U+1F1E6..=U+1F1FF`, which pair up into flags
+    #[must_use]
+    pub const fn is_regional_indicator(self) -> bool {
+        // U+1F1E6..=U+1F1FF
+        matches!(self.to_bits(), 0xF09F_87A6..=0xF09F_87BF)
+    }
+
+    /// Returns whether this codepoint can start a keycap sequence
+    const fn is_keycap_base(self) -> bool {
+        matches!(self.to_bytes()[0], b'0'..=b'9' | b'#' | b'*')
+    }
+
+    /// Returns whether this codepoint is a tag, `U+E0020..=U+E007E`
+    const fn is_tag(self) -> bool {
+        // U+E0020..=U+E007E
+        matches!(self.to_bits(), 0xF3A0_80A0..=0xF3A0_81BE)
+    }
+}
+
+/// An ISO 3166-1 alpha-2 country code, what the regional indicators of a flag spell out
+///
+/// ```
+/// use utf8char::emoji::CountryCode;
+///
+/// let code = CountryCode::new("jp").unwrap();
+///
+/// assert_eq!(code.as_str(), "JP");
+/// assert_eq!(code.to_flag().map(|ch| ch.to_char()), ['🇯', '🇵']);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CountryCode([u8; 2]);
+
+impl CountryCode {
+    /// Returns the country code of two ascii letters, in either case
+    ///
+    /// Whether it is assigned to a country is not checked.
+    #[must_use]
+    pub const fn new(code: &str) -> Option<Self> {
+        match *code.as_bytes() {
+            [a, b] if a.is_ascii_alphabetic() && b.is_ascii_alphabetic() => {
+                Some(Self([a.to_ascii_uppercase(), b.to_ascii_uppercase()]))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the country code spelled by two regional indicators
+    const fn from_flag(first: Utf8Char, second: Utf8Char) -> Self {
+        /// The letter a regional indicator stands for
+        const fn letter(ch: Utf8Char) -> u8 {
+            ch.to_bytes()[3] - 0xA6 + b'A'
+        }
+
+        Self([letter(first), letter(second)])
+    }
+
+    /// Returns the two uppercase letters of this country code
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: both bytes are ascii letters
+        unsafe { core::str::from_utf8_unchecked(&self.0) }
+    }
+
+    /// Returns the regional indicators spelling out the flag of this country code
+    #[must_use]
+    pub const fn to_flag(self) -> [Utf8Char; 2] {
+        /// The regional indicator of an uppercase letter
+        const fn indicator(letter: u8) -> Utf8Char {
+            // SAFETY: U+1F1E6 + 0..=25 are the regional indicators
+            unsafe { Utf8Char::from_u32_unchecked(0x1_F1E6 + (letter - b'A') as u32) }
+        }
+
+        [indicator(self.0[0]), indicator(self.0[1])]
+    }
+}
+
+impl fmt::Debug for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The kind of an [`EmojiSequence`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EmojiKind {
+    /// A single emoji, which is either displayed as an emoji by default or followed by VS16
+    Basic,
+    /// A digit, `#` or `*`, then VS16 and a combining enclosing keycap, like `5️⃣`
+    Keycap,
+    /// Two regional indicators, like `🇫🇷`
+    Flag,
+    /// A black flag followed by tags and a cancel tag, like the flag of Scotland
+    Tag,
+    /// An emoji followed by a skin tone modifier, like `👍🏽`
+    Modifier,
+    /// Emoji joined by zero width joiners, like `👩‍🚀`
+    Zwj,
+}
+
+/// An emoji sequence found in a string, yielded by [`Emojis`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EmojiSequence<'slice> {
+    /// The codepoints of the sequence
+    s: &'slice str,
+    /// The byte offset of the sequence in the searched string
+    offset: usize,
+    /// What kind of sequence it is
+    kind: EmojiKind,
+}
+
+impl<'slice> EmojiSequence<'slice> {
+    /// Returns the codepoints of this sequence
+    #[must_use]
+    pub const fn as_str(&self) -> &'slice str {
+        self.s
+    }
+
+    /// Returns the byte offset this sequence starts at in the searched string
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns what kind of sequence this is
+    #[must_use]
+    pub const fn kind(&self) -> EmojiKind {
+        self.kind
+    }
+
+    /// Returns the country code a flag spells out, or None if this is not a flag
+    ///
+    /// ```
+    /// use utf8char::emoji::emojis;
+    ///
+    /// let flag = emojis("🇨🇦").next().unwrap();
+    ///
+    /// assert_eq!(flag.country_code().unwrap().as_str(), "CA");
+    /// ```
+    #[must_use]
+    pub fn country_code(&self) -> Option<CountryCode> {
+        let mut iter = Utf8CharIter::new(self.s);
+
+        match (self.kind, iter.next(), iter.next()) {
+            (EmojiKind::Flag, Some(first), Some(second)) => {
+                Some(CountryCode::from_flag(first, second))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the first skin tone modifier in this sequence
+    #[must_use]
+    pub fn skin_tone(&self) -> Option<Utf8Char> {
+        Utf8CharIter::new(self.s).find(|ch| ch.is_emoji_modifier())
+    }
+
+    /// Returns the codepoints of this sequence without its skin tone modifiers, so that the same
+    /// emoji in different skin tones can be grouped together
+    ///
+    /// ```
+    /// use utf8char::emoji::emojis;
+    ///
+    /// let thumbs_up = emojis("👍🏿").next().unwrap();
+    ///
+    /// assert_eq!(thumbs_up.without_skin_tones().to_string(), "👍");
+    /// ```
+    #[must_use]
+    pub fn without_skin_tones(&self) -> WithoutSkinTones<'slice> {
+        WithoutSkinTones(Utf8CharIter::new(self.s))
+    }
+}
+
+impl fmt::Display for EmojiSequence<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.s)
+    }
+}
+
+/// An iterator over the codepoints of an emoji sequence, skipping skin tone modifiers
+///
+/// Created by [`EmojiSequence::without_skin_tones`].
+#[derive(Clone, Debug)]
+pub struct WithoutSkinTones<'slice>(Utf8CharIter<'slice>);
+
+impl Iterator for WithoutSkinTones<'_> {
+    type Item = Utf8Char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.find(|ch| !ch.is_emoji_modifier())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
+    }
+}
+
+impl DoubleEndedIterator for WithoutSkinTones<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.rfind(|ch| !ch.is_emoji_modifier())
+    }
+}
+
+impl FusedIterator for WithoutSkinTones<'_> {}
+
+impl fmt::Display for WithoutSkinTones<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.clone().try_for_each(|ch| f.write_str(&ch))
+    }
+}
+
+/// Consumes the next codepoint if it matches
+fn eat(iter: &mut Utf8CharIter<'_>, pred: impl FnOnce(Utf8Char) -> bool) -> bool {
+    let matched = iter.clone().next().is_some_and(pred);
+
+    if matched {
+        iter.next();
+    }
+
+    matched
+}
+
+/// Parses a single emoji, with its presentation selector, modifier, keycap or tags, from the front
+/// of `iter`. Emoji displayed as text by default are only accepted without VS16 within a ZWJ
+/// sequence.
+fn element(iter: &mut Utf8CharIter<'_>, in_zwj: bool) -> Option<EmojiKind> {
+    let ch = iter.next()?;
+
+    if ch.is_regional_indicator() {
+        return eat(iter, Utf8Char::is_regional_indicator).then_some(EmojiKind::Flag);
+    }
+
+    if ch.is_keycap_base() {
+        // the VS16 is sometimes left out
+        eat(iter, |next| next == VS16);
+        return eat(iter, |next| next == KEYCAP).then_some(EmojiKind::Keycap);
+    }
+
+    if !ch.is_emoji() || eat(iter, |next| next == VS15) {
+        return None;
+    }
+
+    if ch.is_emoji_modifier_base() && eat(iter, Utf8Char::is_emoji_modifier) {
+        return Some(EmojiKind::Modifier);
+    }
+
+    if eat(iter, |next| next == VS16) {
+        return Some(EmojiKind::Basic);
+    }
+
+    if ch == TAG_BASE && eat(iter, Utf8Char::is_tag) {
+        while eat(iter, Utf8Char::is_tag) {}
+
+        return eat(iter, |next| next == CANCEL_TAG).then_some(EmojiKind::Tag);
+    }
+
+    (ch.is_emoji_presentation() || in_zwj).then_some(EmojiKind::Basic)
+}
+
+/// Parses a full emoji sequence from the front of `iter`, leaving `iter` in an unspecified state if
+/// there is none
+fn sequence(iter: &mut Utf8CharIter<'_>) -> Option<EmojiKind> {
+    let mut kind = element(iter, false)?;
+
+    loop {
+        let mut joined = iter.clone();
+
+        if joined.next() != Some(ZWJ) || element(&mut joined, true).is_none() {
+            break Some(kind);
+        }
+
+        *iter = joined;
+        kind = EmojiKind::Zwj;
+    }
+}
+
+/// An iterator over the emoji sequences in a string
+///
+/// Created by [`emojis`].
+#[derive(Clone, Debug)]
+pub struct Emojis<'slice> {
+    /// The whole string being searched
+    s: &'slice str,
+    /// The part of the string after the last sequence found
+    iter: Utf8CharIter<'slice>,
+}
+
+impl<'slice> Iterator for Emojis<'slice> {
+    type Item = EmojiSequence<'slice>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let rest = self.iter.as_str();
+
+            if rest.is_empty() {
+                return None;
+            }
+
+            let mut cursor = self.iter.clone();
+
+            if let Some(kind) = sequence(&mut cursor) {
+                let len = rest.len() - cursor.as_str().len();
+                let offset = self.s.len() - rest.len();
+
+                self.iter = cursor;
+                return Some(EmojiSequence {
+                    s: &rest[..len],
+                    offset,
+                    kind,
+                });
+            }
+
+            self.iter.next();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.iter.as_str().len()))
+    }
+}
+
+impl FusedIterator for Emojis<'_> {}
+
+/// Returns an iterator over the emoji sequences in a string, skipping everything else
+///
+/// A sequence is the longest run of codepoints that displays as a single emoji: a ZWJ sequence, a
+/// keycap, a flag, an emoji with a skin tone, or a single emoji. Emoji that are displayed as text
+/// by default, like `'©'` or `'❤'`, are only recognised when followed by VS16 (`U+FE0F`), and any
+/// emoji followed by VS15 (`U+FE0E`) is skipped, as it requests to be displayed as text.
+///
+/// ```
+/// use utf8char::emoji::{emojis, EmojiKind};
+///
+/// let found = emojis("hi 👋🏽 ❤ ❤️ 1️⃣ 🇳🇴 👨‍👩‍👧!")
+///     .map(|emoji| (emoji.as_str(), emoji.kind()))
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     found,
+///     [
+///         ("👋🏽", EmojiKind::Modifier),
+///         ("❤️", EmojiKind::Basic),
+///         ("1️⃣", EmojiKind::Keycap),
+///         ("🇳🇴", EmojiKind::Flag),
+///         ("👨‍👩‍👧", EmojiKind::Zwj),
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn emojis(s: &str) -> Emojis<'_> {
+    Emojis {
+        s,
+        iter: Utf8CharIter::new(s),
+    }
+}
+
+#[test]
+fn emoji_properties() {
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let utf8 = Utf8Char::from_char(c);
+
+        assert_eq!(
+            utf8.is_emoji_modifier(),
+            ('\u{1F3FB}'..='\u{1F3FF}').contains(&c)
+        );
+        assert_eq!(
+            utf8.is_regional_indicator(),
+            ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+        );
+        assert_eq!(utf8.is_tag(), ('\u{E0020}'..='\u{E007E}').contains(&c));
+
+        if utf8.is_emoji_presentation() || utf8.is_emoji_modifier_base() {
+            assert!(utf8.is_emoji(), "{c:?}");
+        }
+
+        // every emoji is pictographic, except the ones only used to build sequences
+        if utf8.is_emoji() && !utf8.is_extended_pictographic() {
+            assert!(
+                utf8.is_keycap_base() || utf8.is_regional_indicator() || utf8.is_emoji_modifier(),
+                "{c:?}"
+            );
+        }
+    });
+}
+
+#[test]
+fn emoji_sequences() {
+    use alloc::{string::ToString, vec::Vec};
+
+    let found = |s| {
+        emojis(s)
+            .map(|emoji| (emoji.offset(), emoji.as_str(), emoji.kind()))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(found(""), []);
+    assert_eq!(found("plain text, 123 # * © ❤"), []);
+    assert_eq!(
+        found("😀\u{FE0E} ©\u{FE0E} 🇦 5\u{20E3}"),
+        [(19, "5\u{20E3}", EmojiKind::Keycap)]
+    );
+    assert_eq!(
+        found("a😀b©\u{FE0F}"),
+        [
+            (1, "😀", EmojiKind::Basic),
+            (6, "©\u{FE0F}", EmojiKind::Basic)
+        ]
+    );
+    assert_eq!(
+        found("🇺🇸🇬🇧🇫"),
+        [(0, "🇺🇸", EmojiKind::Flag), (8, "🇬🇧", EmojiKind::Flag)]
+    );
+    assert_eq!(
+        found("🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}🏴"),
+        [
+            (
+                0,
+                "🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+                EmojiKind::Tag
+            ),
+            (28, "🏴", EmojiKind::Basic)
+        ]
+    );
+    // a rainbow flag, joining a text default emoji, and a dangling ZWJ
+    assert_eq!(
+        found("🏳\u{FE0F}\u{200D}🌈 👩\u{200D}❤\u{FE0F}\u{200D}👨 😀\u{200D}"),
+        [
+            (0, "🏳\u{FE0F}\u{200D}🌈", EmojiKind::Zwj),
+            (15, "👩\u{200D}❤\u{FE0F}\u{200D}👨", EmojiKind::Zwj),
+            (36, "😀", EmojiKind::Basic)
+        ]
+    );
+    assert_eq!(found("🧑🏾\u{200D}🚀"), [(0, "🧑🏾\u{200D}🚀", EmojiKind::Zwj)]);
+
+    let astronaut = emojis("🧑🏾\u{200D}🚀").next().unwrap();
+    assert_eq!(astronaut.skin_tone(), Some(Utf8Char::from_char('🏾')));
+    assert_eq!(astronaut.without_skin_tones().to_string(), "🧑\u{200D}🚀");
+    assert_eq!(astronaut.country_code(), None);
+
+    for code in ["AC", "ZZ", "gb", "Us"] {
+        let code = CountryCode::new(code).unwrap();
+        let flag = code.to_flag().map(|ch| ch.to_string()).concat();
+
+        assert_eq!(emojis(&flag).next().unwrap().country_code(), Some(code));
+    }
+
+    assert_eq!(CountryCode::new("USA"), None);
+    assert_eq!(CountryCode::new("1A"), None);
+}
@@ -0,0 +1,174 @@
+//! Error types returned by fallible `Utf8Char` constructors
+
+use core::{error, fmt};
+
+/// The reason a byte slice could not be decoded into a [`Utf8Char`][crate::Utf8Char]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Utf8CharErrorKind {
+    /// The input contained no bytes
+    Empty,
+    /// The byte can never start a utf8 encoded codepoint (a continuation byte, or `0xF8..=0xFF`)
+    InvalidLeadByte,
+    /// The input ended, or a non continuation byte was found, before the codepoint was complete
+    Truncated,
+    /// The codepoint was encoded using more bytes than necessary
+    Overlong,
+    /// The codepoint is a UTF-16 surrogate (`U+D800..=U+DFFF`), which is not a unicode scalar value
+    Surrogate,
+    /// The codepoint is larger than `U+10FFFF`
+    OutOfRange,
+    /// A codepoint was decoded, but more bytes followed it when exactly one codepoint was expected
+    TrailingBytes,
+}
+
+impl Utf8CharErrorKind {
+    /// Returns a short human readable description of this error kind
+    const fn description(self) -> &'static str {
+        match self {
+            Self::Empty => "input was empty",
+            Self::InvalidLeadByte => "invalid utf8 lead byte",
+            Self::Truncated => "truncated utf8 sequence",
+            Self::Overlong => "overlong utf8 encoding",
+            Self::Surrogate => "utf8 encoded surrogate codepoint",
+            Self::OutOfRange => "utf8 encoded codepoint above U+10FFFF",
+            Self::TrailingBytes => "trailing bytes after codepoint",
+        }
+    }
+}
+
+/// An error returned when decoding a [`Utf8Char`][crate::Utf8Char] from bytes fails
+///
+/// Contains what went wrong, and the offset of the byte at which it went wrong.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Utf8CharError {
+    /// What went wrong
+    kind: Utf8CharErrorKind,
+    /// Byte offset into the input where the error was detected
+    offset: usize,
+}
+
+impl Utf8CharError {
+    /// Constructs a new error from its parts
+    pub(crate) const fn new(kind: Utf8CharErrorKind, offset: usize) -> Self {
+        Self { kind, offset }
+    }
+
+    /// Returns what went wrong while decoding
+    #[must_use]
+    pub const fn kind(&self) -> Utf8CharErrorKind {
+        self.kind
+    }
+
+    /// Returns the offset of the byte that caused the error.
+    ///
+    /// For [`Truncated`][Utf8CharErrorKind::Truncated] errors this may be equal to the input length,
+    /// signalling that more bytes were expected.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for Utf8CharError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at byte offset {}",
+            self.kind.description(),
+            self.offset
+        )
+    }
+}
+
+impl error::Error for Utf8CharError {}
+
+/// The error returned when a `u32` fails to convert to a [`Utf8Char`][crate::Utf8Char]
+///
+/// This is the `Utf8Char` equivalent of [`core::char::CharTryFromError`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Utf8CharTryFromError(pub(crate) ());
+
+impl fmt::Display for Utf8CharTryFromError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("converted integer out of range for `Utf8Char`")
+    }
+}
+
+impl error::Error for Utf8CharTryFromError {}
+
+/// The reason a string could not be parsed into a [`Utf8Char`][crate::Utf8Char]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseUtf8CharErrorKind {
+    /// The string was empty
+    EmptyString,
+    /// The string contained more than one codepoint
+    TooManyChars,
+}
+
+/// An error returned when parsing a [`Utf8Char`][crate::Utf8Char] from a string fails
+///
+/// This is the `Utf8Char` equivalent of [`core::char::ParseCharError`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParseUtf8CharError {
+    /// What went wrong
+    kind: ParseUtf8CharErrorKind,
+}
+
+impl ParseUtf8CharError {
+    /// Constructs a new error from its kind
+    pub(crate) const fn new(kind: ParseUtf8CharErrorKind) -> Self {
+        Self { kind }
+    }
+
+    /// Returns why the string could not be parsed
+    #[must_use]
+    pub const fn kind(&self) -> ParseUtf8CharErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for ParseUtf8CharError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // identical messages to ParseCharError
+        f.write_str(match self.kind {
+            ParseUtf8CharErrorKind::EmptyString => "cannot parse char from empty string",
+            ParseUtf8CharErrorKind::TooManyChars => "too many characters in string",
+        })
+    }
+}
+
+impl error::Error for ParseUtf8CharError {}
+
+/// An error returned by [`DecodeUtf16`][crate::utf16::DecodeUtf16] when it meets a UTF-16
+/// surrogate that is not part of a surrogate pair
+///
+/// This is the `Utf8Char` equivalent of [`core::char::DecodeUtf16Error`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DecodeUtf16Error {
+    /// The unpaired surrogate
+    code: u16,
+}
+
+impl DecodeUtf16Error {
+    /// Constructs a new error from the surrogate that was found
+    pub(crate) const fn new(code: u16) -> Self {
+        Self { code }
+    }
+
+    /// Returns the unpaired surrogate which caused this error
+    #[must_use]
+    pub const fn unpaired_surrogate(&self) -> u16 {
+        self.code
+    }
+}
+
+impl fmt::Display for DecodeUtf16Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // identical message to char::DecodeUtf16Error
+        write!(f, "unpaired surrogate found: {:x}", self.code)
+    }
+}
+
+impl error::Error for DecodeUtf16Error {}
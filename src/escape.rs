@@ -0,0 +1,186 @@
+//! Escaping `Utf8Char`'s the way `char`'s `escape_*` methods and `Debug` do
+
+use crate::{
+    inline::{inline_str_iter, InlineStr},
+    tables::properties::GRAPHEME_EXTEND,
+    GeneralCategory, Utf8Char,
+};
+
+/// The longest escape, `\u{10ffff}`
+const MAX_ESCAPE_LEN: usize = 10;
+
+/// An iterator over the `Utf8Char`'s of an escaped codepoint.
+///
+/// Returned by [`Utf8Char::escape_debug`], [`Utf8Char::escape_default`] and
+/// [`Utf8Char::escape_unicode`]. The codepoints that have not been iterated over yet are available
+/// as a `&str` through `Deref`, and `Display` writes them out.
+#[derive(Clone)]
+pub struct Escape(InlineStr<MAX_ESCAPE_LEN>);
+
+inline_str_iter!(Escape);
+
+impl Escape {
+    /// An escape of a codepoint that needs none
+    const fn printable(ch: Utf8Char) -> Self {
+        Self(InlineStr::new(ch.as_str()))
+    }
+
+    /// A backslash escape, like `\n`
+    const fn backslash(escaped: u8) -> Self {
+        // SAFETY: both bytes are ascii, and 2 is less than MAX_ESCAPE_LEN
+        Self(unsafe { InlineStr::from_raw_parts([b'\\', escaped, 0, 0, 0, 0, 0, 0, 0, 0], 2) })
+    }
+
+    /// A unicode escape of the form `\u{NNNN}`, with lowercase hex digits and no leading zeros
+    const fn unicode(ch: Utf8Char) -> Self {
+        let code = ch.to_u32();
+        let mut buf = [0; MAX_ESCAPE_LEN];
+        // one hex digit per nibble, zero still needs a digit
+        let digits = if code == 0 {
+            1
+        } else {
+            (u32::BITS - code.leading_zeros()).div_ceil(4) as usize
+        };
+
+        buf[0] = b'\\';
+        buf[1] = b'u';
+        buf[2] = b'{';
+
+        let mut idx = 0;
+        while idx < digits {
+            let nibble = (code >> ((digits - 1 - idx) * 4)) & 0xF;
+            buf[3 + idx] = b"0123456789abcdef"[nibble as usize];
+            idx += 1;
+        }
+
+        buf[3 + digits] = b'}';
+
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "at most 6 digits are needed for any codepoint"
+        )]
+        // SAFETY: the buffer is ascii up to the closing brace, which lies within MAX_ESCAPE_LEN as a
+        // codepoint has at most 6 hex digits
+        unsafe {
+            Self(InlineStr::from_raw_parts(buf, (4 + digits) as u8))
+        }
+    }
+}
+
+impl Utf8Char {
+    /// Returns whether this codepoint has the `Grapheme_Extend` property, such codepoints are
+    /// escaped by `Debug` as they would otherwise combine with the preceding quote
+    pub(crate) const fn is_grapheme_extended(self) -> bool {
+        GRAPHEME_EXTEND.contains(self)
+    }
+
+    /// Returns whether this codepoint is printed as is by `Debug`: anything but separators (except
+    /// for the ascii space), control, format, private use and unassigned codepoints
+    pub(crate) const fn is_printable(self) -> bool {
+        match self.general_category() {
+            GeneralCategory::SpaceSeparator => self.ascii() == b' ',
+            GeneralCategory::LineSeparator
+            | GeneralCategory::ParagraphSeparator
+            | GeneralCategory::Control
+            | GeneralCategory::Format
+            | GeneralCategory::PrivateUse
+            | GeneralCategory::Unassigned => false,
+            _ => true,
+        }
+    }
+
+    /// The shared implementation of [`escape_debug`][Self::escape_debug] and `Debug`, which differ in
+    /// which quotes they escape
+    pub(crate) const fn escape_debug_ext(
+        self,
+        escape_single_quote: bool,
+        escape_double_quote: bool,
+    ) -> Escape {
+        match self.ascii() {
+            b'\0' => Escape::backslash(b'0'),
+            b'\t' => Escape::backslash(b't'),
+            b'\r' => Escape::backslash(b'r'),
+            b'\n' => Escape::backslash(b'n'),
+            b'\\' => Escape::backslash(b'\\'),
+            b'"' if escape_double_quote => Escape::backslash(b'"'),
+            b'\'' if escape_single_quote => Escape::backslash(b'\''),
+            _ if self.is_grapheme_extended() => Escape::unicode(self),
+            _ if self.is_printable() => Escape::printable(self),
+            _ => Escape::unicode(self),
+        }
+    }
+
+    /// equivalent to [`char::escape_debug`] for `Utf8Char`
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(utf8char!("\n").escape_debug().as_str(), "\\n");
+    /// assert_eq!(utf8char!("\u{301}").escape_debug().as_str(), "\\u{301}");
+    /// assert_eq!(utf8char!("é").escape_debug().as_str(), "é");
+    /// ```
+    #[must_use]
+    pub const fn escape_debug(self) -> Escape {
+        self.escape_debug_ext(true, true)
+    }
+
+    /// equivalent to [`char::escape_default`] for `Utf8Char`
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(utf8char!("\"").escape_default().as_str(), "\\\"");
+    /// assert_eq!(utf8char!("é").escape_default().as_str(), "\\u{e9}");
+    /// ```
+    #[must_use]
+    pub const fn escape_default(self) -> Escape {
+        match self.ascii() {
+            b'\t' => Escape::backslash(b't'),
+            b'\r' => Escape::backslash(b'r'),
+            b'\n' => Escape::backslash(b'n'),
+            b'\\' | b'\'' | b'"' => Escape::backslash(self.ascii()),
+            b'\x20'..=b'\x7e' => Escape::printable(self),
+            _ => Escape::unicode(self),
+        }
+    }
+
+    /// equivalent to [`char::escape_unicode`] for `Utf8Char`
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(utf8char!("❤").escape_unicode().as_str(), "\\u{2764}");
+    /// ```
+    #[must_use]
+    pub const fn escape_unicode(self) -> Escape {
+        Escape::unicode(self)
+    }
+}
+
+#[test]
+fn escapes_match_std() {
+    use alloc::{format, string::ToString};
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let utf8 = Utf8Char::from_char(c);
+
+        macro_rules! identical {
+            ($($fn:ident),+) => {$({
+                let escape = utf8.$fn();
+
+                assert_eq!(escape.as_str(), c.$fn().to_string(), "{c:?}");
+                assert_eq!(escape.to_string(), c.$fn().to_string(), "{c:?}");
+                assert_eq!(escape.len(), c.$fn().len(), "{c:?}");
+                assert!(escape.clone().map(Utf8Char::to_char).eq(c.$fn()), "{c:?}");
+                // std's escapes are not double ended
+                let escaped = c.$fn().to_string();
+                assert!(escape.rev().map(Utf8Char::to_char).eq(escaped.chars().rev()), "{c:?}");
+            })+};
+        }
+
+        identical!(escape_debug, escape_default, escape_unicode);
+
+        assert_eq!(format!("{utf8:?}"), format!("{c:?}"));
+    });
+}
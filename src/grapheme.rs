@@ -0,0 +1,588 @@
+//! Extended grapheme cluster segmentation of strings, as defined in
+//! [UAX #29](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries)
+
+use core::iter::FusedIterator;
+
+use crate::{
+    tables::grapheme::{EXTENDED_PICTOGRAPHIC, GRAPHEME_BREAK, INDIC_CONJUNCT_BREAK},
+    Utf8Char, Utf8CharIter,
+};
+
+/// The `Grapheme_Cluster_Break` property of a codepoint, which grapheme cluster boundaries are
+/// determined by
+///
+/// Variants are named after the long property value aliases.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GraphemeBreak {
+    /// `XX`: any codepoint without another value
+    Other,
+    /// `CR`: U+000D CARRIAGE RETURN only
+    CR,
+    /// `LF`: U+000A LINE FEED only
+    LF,
+    /// `CN`: controls, line and paragraph separators and most other format codepoints
+    Control,
+    /// `EX`: combining marks, emoji modifiers and other codepoints that extend the one before them
+    Extend,
+    /// `ZWJ`: U+200D ZERO WIDTH JOINER only
+    ZWJ,
+    /// `RI`: the regional indicator symbols, which pair up into flags
+    RegionalIndicator,
+    /// `PP`: codepoints that combine with the one after them, like some Arabic number signs
+    Prepend,
+    /// `SM`: spacing combining marks that are not `Extend`
+    SpacingMark,
+    /// `L`: Hangul leading jamo
+    L,
+    /// `V`: Hangul vowel jamo
+    V,
+    /// `T`: Hangul trailing jamo
+    T,
+    /// `LV`: Hangul syllables without a trailing consonant
+    LV,
+    /// `LVT`: Hangul syllables with a trailing consonant
+    LVT,
+}
+
+/// The `Indic_Conjunct_Break` property of a codepoint, which keeps conjuncts like `क्ष` together
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum IndicConjunctBreak {
+    /// Not part of a conjunct
+    None,
+    /// A consonant that can start or end a conjunct
+    Consonant,
+    /// A mark that can appear within a conjunct
+    Extend,
+    /// A virama that links consonants
+    Linker,
+}
+
+/// What the properties of two adjacent codepoints decide about the boundary between them
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Rule {
+    /// There is a boundary
+    Break,
+    /// There is no boundary
+    Keep,
+    /// `GB9c`: no boundary if the first codepoint ends a consonant followed by at least one linker,
+    /// and any amount of extending marks
+    Conjunct,
+    /// `GB11`: no boundary if the first codepoint, a ZWJ, ends an `Extended_Pictographic Extend* ZWJ`
+    /// sequence
+    Emoji,
+    /// `GB12` and `GB13`: no boundary if the first codepoint ends an odd run of regional indicators
+    RegionalIndicators,
+}
+
+impl Rule {
+    /// Returns the rule applying to the boundary between two codepoints
+    const fn between(prev: Utf8Char, next: Utf8Char) -> Self {
+        use GraphemeBreak as G;
+
+        #[expect(
+            clippy::match_same_arms,
+            reason = "the arms follow the order of the rules, which matters"
+        )]
+        match (prev.grapheme_break(), next.grapheme_break()) {
+            // GB3
+            (G::CR, G::LF) => Self::Keep,
+            // GB4 and GB5
+            (G::CR | G::LF | G::Control, _) | (_, G::CR | G::LF | G::Control) => Self::Break,
+            // GB6, GB7 and GB8
+            (G::L, G::L | G::V | G::LV | G::LVT)
+            | (G::LV | G::V, G::V | G::T)
+            | (G::LVT | G::T, G::T) => Self::Keep,
+            // GB9, GB9a and GB9b
+            (_, G::Extend | G::ZWJ | G::SpacingMark) | (G::Prepend, _) => Self::Keep,
+            (G::RegionalIndicator, G::RegionalIndicator) => Self::RegionalIndicators,
+            (prev_break, _) => {
+                if matches!(
+                    INDIC_CONJUNCT_BREAK.get(next),
+                    IndicConjunctBreak::Consonant
+                ) && matches!(
+                    INDIC_CONJUNCT_BREAK.get(prev),
+                    IndicConjunctBreak::Extend | IndicConjunctBreak::Linker
+                ) {
+                    Self::Conjunct
+                } else if matches!(prev_break, G::ZWJ) && EXTENDED_PICTOGRAPHIC.contains(next) {
+                    Self::Emoji
+                } else {
+                    // GB999
+                    Self::Break
+                }
+            }
+        }
+    }
+
+    /// Returns whether there is a boundary at a byte offset of a string, where a boundary depends
+    /// on what precedes it, by looking back from there
+    ///
+    /// `known` is a later offset known to be a boundary, regional indicators pair up from it
+    /// rather than from the start of their run.
+    fn breaks_after(self, s: &str, offset: usize, known: usize) -> bool {
+        let mut before = Utf8CharIter::new(&s[..offset]).rev();
+
+        match self {
+            Self::Break => true,
+            Self::Keep => false,
+            Self::Conjunct => {
+                let mut linked = false;
+
+                for ch in before {
+                    match INDIC_CONJUNCT_BREAK.get(ch) {
+                        IndicConjunctBreak::Linker => linked = true,
+                        IndicConjunctBreak::Extend => {}
+                        IndicConjunctBreak::Consonant => return !linked,
+                        IndicConjunctBreak::None => return true,
+                    }
+                }
+
+                true
+            }
+            Self::Emoji => {
+                // skip the ZWJ itself
+                before.next();
+
+                !before
+                    .find(|ch| ch.grapheme_break() != GraphemeBreak::Extend)
+                    .is_some_and(|ch| EXTENDED_PICTOGRAPHIC.contains(ch))
+            }
+            Self::RegionalIndicators => {
+                let regional =
+                    |ch: &Utf8Char| ch.grapheme_break() == GraphemeBreak::RegionalIndicator;
+
+                // an even amount comes before a boundary within the run, take away the ones after
+                // `offset`
+                let (between, after) = s[offset..].split_at(known - offset);
+                let run = if Utf8CharIter::new(between).all(|ch| regional(&ch))
+                    && Utf8CharIter::new(after)
+                        .next()
+                        .as_ref()
+                        .is_some_and(regional)
+                {
+                    Utf8CharIter::new(between).count()
+                } else {
+                    before.take_while(regional).count()
+                };
+
+                run % 2 == 0
+            }
+        }
+    }
+}
+
+/// The sequences that boundaries depend on, tracked while iterating forward so that boundaries
+/// never require looking back
+#[derive(Copy, Clone, Debug, Default)]
+struct Context {
+    /// Within a `Consonant [Extend Linker]*` sequence, and whether it contained a linker
+    conjunct: Option<bool>,
+    /// Within an `Extended_Pictographic Extend*` sequence, and whether a ZWJ ended it
+    emoji: Option<bool>,
+    /// Whether an odd amount of regional indicators was just seen
+    odd_regional: bool,
+}
+
+impl Context {
+    /// Updates the context with the next codepoint
+    fn push(&mut self, ch: Utf8Char) {
+        let grapheme_break = ch.grapheme_break();
+
+        self.conjunct = match (INDIC_CONJUNCT_BREAK.get(ch), self.conjunct) {
+            (IndicConjunctBreak::Consonant, _) => Some(false),
+            (IndicConjunctBreak::Linker, Some(_)) => Some(true),
+            (IndicConjunctBreak::Extend, linked) => linked,
+            _ => None,
+        };
+
+        self.emoji = match (grapheme_break, self.emoji) {
+            _ if EXTENDED_PICTOGRAPHIC.contains(ch) => Some(false),
+            (GraphemeBreak::Extend, Some(false)) => Some(false),
+            (GraphemeBreak::ZWJ, Some(false)) => Some(true),
+            _ => None,
+        };
+
+        self.odd_regional =
+            grapheme_break == GraphemeBreak::RegionalIndicator && !self.odd_regional;
+    }
+
+    /// Returns whether there is a boundary between the codepoints pushed so far, ending with
+    /// `prev`, and `next`
+    fn breaks(self, prev: Utf8Char, next: Utf8Char) -> bool {
+        match Rule::between(prev, next) {
+            Rule::Break => true,
+            Rule::Keep => false,
+            Rule::Conjunct => self.conjunct != Some(true),
+            Rule::Emoji => self.emoji != Some(true),
+            Rule::RegionalIndicators => !self.odd_regional,
+        }
+    }
+}
+
+/// An iterator over the extended grapheme clusters of a string, as `&str` slices
+///
+/// Created by [`graphemes`]. Iterating from the back finds the same clusters as iterating from the
+/// front.
+#[derive(Clone, Debug)]
+pub struct Graphemes<'slice> {
+    /// The whole string being segmented
+    s: &'slice str,
+    /// The byte offset of the next cluster from the front, always a boundary
+    front: usize,
+    /// The byte offset after the next cluster from the back, always a boundary
+    back: usize,
+}
+
+impl<'slice> Graphemes<'slice> {
+    /// Returns the part of the string that has not been iterated over yet
+    #[must_use]
+    pub fn as_str(&self) -> &'slice str {
+        &self.s[self.front..self.back]
+    }
+}
+
+impl<'slice> Iterator for Graphemes<'slice> {
+    type Item = &'slice str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.as_str();
+        let mut iter = Utf8CharIter::new(rest);
+        let mut prev = iter.next()?;
+        let mut context = Context::default();
+
+        context.push(prev);
+
+        let len = loop {
+            let after = iter.as_str();
+
+            let Some(next) = iter.next() else {
+                break rest.len();
+            };
+
+            if context.breaks(prev, next) {
+                break rest.len() - after.len();
+            }
+
+            context.push(next);
+            prev = next;
+        };
+
+        self.front += len;
+        Some(&rest[..len])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        // a cluster can extend over the whole string, and every cluster is at least one byte
+        (usize::from(len > 0), Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Graphemes<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let rest = self.as_str();
+        let mut iter = Utf8CharIter::new(rest);
+        let mut next = iter.next_back()?;
+
+        let start = loop {
+            let before = iter.as_str();
+
+            let Some(prev) = iter.next_back() else {
+                break 0;
+            };
+
+            let rule = Rule::between(prev, next);
+
+            if rule.breaks_after(&self.s[self.front..], before.len(), rest.len()) {
+                break before.len();
+            }
+
+            next = prev;
+        };
+
+        self.back = self.front + start;
+        Some(&rest[start..])
+    }
+}
+
+impl FusedIterator for Graphemes<'_> {}
+
+/// An iterator over the extended grapheme clusters of a string and their byte offsets
+///
+/// Created by [`grapheme_indices`].
+#[derive(Clone, Debug)]
+pub struct GraphemeIndices<'slice>(Graphemes<'slice>);
+
+impl<'slice> GraphemeIndices<'slice> {
+    /// Returns the part of the string that has not been iterated over yet
+    #[must_use]
+    pub fn as_str(&self) -> &'slice str {
+        self.0.as_str()
+    }
+}
+
+impl<'slice> Iterator for GraphemeIndices<'slice> {
+    type Item = (usize, &'slice str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.0.front;
+
+        self.0.next().map(|grapheme| (offset, grapheme))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for GraphemeIndices<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let grapheme = self.0.next_back()?;
+
+        Some((self.0.back, grapheme))
+    }
+}
+
+impl FusedIterator for GraphemeIndices<'_> {}
+
+/// Returns an iterator over the extended grapheme clusters of a string, what users perceive as
+/// single characters
+///
+/// ```
+/// use utf8char::grapheme::graphemes;
+///
+/// let clusters = graphemes("e\u{301}🇫🇷👨‍👩‍👧\r\n").collect::<Vec<_>>();
+///
+/// assert_eq!(clusters, ["e\u{301}", "🇫🇷", "👨‍👩‍👧", "\r\n"]);
+/// assert_eq!(graphemes("क्षि").count(), 1);
+/// assert_eq!(graphemes("abc").rev().collect::<String>(), "cba");
+/// ```
+#[must_use]
+pub fn graphemes(s: &str) -> Graphemes<'_> {
+    Graphemes {
+        s,
+        front: 0,
+        back: s.len(),
+    }
+}
+
+/// Returns an iterator over the extended grapheme clusters of a string, along with the byte
+/// offsets they start at
+///
+/// ```
+/// use utf8char::grapheme::grapheme_indices;
+///
+/// let text = "ña🇯🇵";
+///
+/// // truncating to a cluster boundary never cuts through a codepoint or a flag
+/// let truncate = |clusters| {
+///     grapheme_indices(text)
+///         .nth(clusters)
+///         .map_or(text.len(), |(offset, _)| offset)
+/// };
+/// assert_eq!(&text[..truncate(2)], "ña");
+/// assert_eq!(&text[..truncate(3)], "ña🇯🇵");
+///
+/// assert_eq!(grapheme_indices(text).next_back(), Some((3, "🇯🇵")));
+/// ```
+#[must_use]
+pub fn grapheme_indices(s: &str) -> GraphemeIndices<'_> {
+    GraphemeIndices(graphemes(s))
+}
+
+impl Utf8Char {
+    /// Returns the [`GraphemeBreak`] property of this codepoint
+    ///
+    /// ```
+    /// use utf8char::{utf8char, GraphemeBreak};
+    ///
+    /// assert_eq!(utf8char!("a").grapheme_break(), GraphemeBreak::Other);
+    /// assert_eq!(utf8char!("\u{301}").grapheme_break(), GraphemeBreak::Extend);
+    /// assert_eq!(utf8char!("🇦").grapheme_break(), GraphemeBreak::RegionalIndicator);
+    /// assert_eq!(utf8char!("각").grapheme_break(), GraphemeBreak::LVT);
+    /// ```
+    #[must_use]
+    pub const fn grapheme_break(self) -> GraphemeBreak {
+        GRAPHEME_BREAK.get(self)
+    }
+}
+
+#[test]
+fn grapheme_breaks() {
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let utf8 = Utf8Char::from_char(c);
+        let grapheme_break = utf8.grapheme_break();
+
+        match c {
+            '\r' => assert_eq!(grapheme_break, GraphemeBreak::CR),
+            '\n' => assert_eq!(grapheme_break, GraphemeBreak::LF),
+            '\u{200D}' => assert_eq!(grapheme_break, GraphemeBreak::ZWJ),
+            _ if c.is_control() => assert_eq!(grapheme_break, GraphemeBreak::Control, "{c:?}"),
+            _ => {}
+        }
+
+        if utf8.is_grapheme_extended() {
+            assert_eq!(grapheme_break, GraphemeBreak::Extend, "{c:?}");
+        }
+    });
+}
+
+#[test]
+fn segmentation() {
+    use alloc::vec::Vec;
+
+    for expected in [
+        &[][..],
+        &["a", "b"],
+        &["\r\n", "\n", "\r"],
+        &["a\u{308}", "\u{1}", "\u{308}"],
+        // Hangul syllables out of conjoining jamo
+        &["\u{1100}\u{1161}\u{11A8}", "\u{1100}"],
+        &["가\u{11A8}", "각\u{11A8}\u{11A8}", "\u{1100}"],
+        // flags pair up
+        &["🇦🇧", "🇨🇩", "🇪"],
+        &["a", "🇦🇧\u{200D}", "🇨"],
+        // emoji ZWJ sequences, with and without modifiers
+        &["👨\u{200D}👩\u{200D}👧"],
+        &["👍🏽", "👍"],
+        &["👨🏽\u{200D}🚀", "a\u{200D}", "🚀"],
+        // spacing marks and prepended concatenation marks
+        &["क\u{903}", "\u{600}1", "2"],
+        // Indic conjuncts, linked by a virama
+        &["क\u{94D}ष\u{93F}"],
+        &["क\u{94D}\u{93C}\u{94D}ष"],
+        &["क\u{93C}", "ष"],
+        &["a\u{94D}", "ष"],
+    ] {
+        let s = expected.concat();
+        let forward = graphemes(&s).collect::<Vec<_>>();
+        let mut backward = graphemes(&s).rev().collect::<Vec<_>>();
+        backward.reverse();
+
+        assert_eq!(forward, expected, "{s:?}");
+        assert_eq!(backward, expected, "{s:?}");
+    }
+}
+
+#[test]
+fn conformance() {
+    use alloc::vec::Vec;
+
+    for &expected in crate::tables::conformance::GRAPHEME_BREAK_TEST {
+        let s = expected.concat();
+        let forward = graphemes(&s).collect::<Vec<_>>();
+        let mut backward = graphemes(&s).rev().collect::<Vec<_>>();
+        backward.reverse();
+
+        assert_eq!(forward, expected, "{s:?}");
+        assert_eq!(backward, expected, "{s:?}");
+    }
+}
+
+#[test]
+fn long_runs() {
+    use alloc::vec::Vec;
+
+    /// Long enough that rescanning a run for every codepoint in it would take minutes
+    const RUN: usize = 100_000;
+
+    for (s, len) in [
+        // flags pair up from the start of a run of regional indicators
+        ("\u{1F1E6}".repeat(RUN), RUN / 2),
+        (["a", &"\u{1F1E6}".repeat(RUN)].concat(), RUN / 2 + 1),
+        ("\u{1F1E6}".repeat(RUN + 1), RUN / 2 + 1),
+        (["a", &"\u{301}".repeat(RUN)].concat(), 1),
+    ] {
+        let forward = graphemes(&s).collect::<Vec<_>>();
+        let mut backward = graphemes(&s).rev().collect::<Vec<_>>();
+        backward.reverse();
+
+        assert_eq!(forward.len(), len);
+        assert_eq!(forward, backward);
+    }
+}
+
+#[test]
+fn size_hint() {
+    use alloc::string::String;
+
+    for s in ["", "a", "e\u{301}", "abc", "🇦🇧🇨", "👨\u{200D}👩\u{200D}👧"]
+        .into_iter()
+        .map(String::from)
+        .chain([core::iter::once('e')
+            .chain(core::iter::repeat_n('\u{301}', 50))
+            .collect()])
+    {
+        let mut iter = graphemes(&s);
+
+        loop {
+            let (lower, upper) = iter.size_hint();
+            let len = iter.clone().count();
+
+            assert!(lower <= len, "{s:?}");
+            assert!(upper.is_some_and(|upper| len <= upper), "{s:?}");
+
+            if iter.next().is_none() {
+                break;
+            }
+        }
+    }
+}
+
+#[test]
+fn segmentation_is_symmetric() {
+    use alloc::{string::String, vec::Vec};
+    use itertools::Itertools;
+
+    // one codepoint of every property the rules distinguish
+    let chars = [
+        'a',
+        '\r',
+        '\n',
+        '\u{1}',
+        '\u{300}',
+        '\u{200D}',
+        '🇦',
+        '\u{600}',
+        '\u{903}',
+        '\u{1100}',
+        '\u{1161}',
+        '\u{11A8}',
+        '가',
+        '각',
+        '👍',
+        '\u{1F3FB}',
+        'क',
+        '\u{94D}',
+        '\u{93C}',
+        '©',
+    ];
+
+    for len in 0..=4 {
+        for stream in (0..len).map(|_| chars).multi_cartesian_product() {
+            let s = stream.into_iter().collect::<String>();
+
+            let forward = grapheme_indices(&s).collect::<Vec<_>>();
+            let mut backward = grapheme_indices(&s).rev().collect::<Vec<_>>();
+            backward.reverse();
+
+            assert_eq!(forward, backward, "{s:?}");
+            assert_eq!(forward.iter().map(|(_, g)| *g).collect::<String>(), s);
+            assert!(forward
+                .iter()
+                .all(|(offset, g)| s[*offset..].starts_with(g)));
+
+            // iterating from both ends meets in the middle
+            let mut both = grapheme_indices(&s);
+            let (front, back) = (both.next(), both.next_back());
+            assert_eq!(front, forward.first().copied(), "{s:?}");
+            if forward.len() > 1 {
+                assert_eq!(back, forward.last().copied(), "{s:?}");
+            }
+            assert_eq!(both.count(), forward.len().saturating_sub(2), "{s:?}");
+        }
+    }
+}
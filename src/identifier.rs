@@ -0,0 +1,198 @@
+//! Identifier and pattern syntax predicates, as defined in
+//! [UAX #31](https://www.unicode.org/reports/tr31/)
+
+use crate::{
+    normalization::is_nfc,
+    tables::properties::{PATTERN_SYNTAX, XID_CONTINUE, XID_START},
+    Utf8Char, Utf8CharIter,
+};
+
+impl Utf8Char {
+    /// Returns whether this codepoint can start an identifier, it has the `XID_Start` property
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert!(utf8char!("a").is_xid_start());
+    /// assert!(utf8char!("λ").is_xid_start());
+    /// assert!(!utf8char!("1").is_xid_start());
+    /// assert!(!utf8char!("_").is_xid_start());
+    /// ```
+    #[must_use]
+    pub const fn is_xid_start(self) -> bool {
+        if self.is_ascii() {
+            return self.is_ascii_alphabetic();
+        }
+
+        XID_START.contains(self)
+    }
+
+    /// Returns whether this codepoint can continue an identifier, it has the `XID_Continue`
+    /// property
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert!(utf8char!("1").is_xid_continue());
+    /// assert!(utf8char!("_").is_xid_continue());
+    /// assert!(utf8char!("\u{301}").is_xid_continue());
+    /// assert!(!utf8char!("-").is_xid_continue());
+    /// ```
+    #[must_use]
+    pub const fn is_xid_continue(self) -> bool {
+        if self.is_ascii() {
+            return self.is_ascii_alphanumeric() || self.to_bytes()[0] == b'_';
+        }
+
+        XID_CONTINUE.contains(self)
+    }
+
+    /// Returns whether this codepoint has the `Pattern_White_Space` property, the whitespace
+    /// syntaxes like programming languages should skip between tokens
+    ///
+    /// The property is stable, it will never change in future versions of unicode.
+    #[must_use]
+    pub const fn is_pattern_whitespace(self) -> bool {
+        matches!(
+            self.to_bits(),
+            // U+0009..=U+000D
+            0x0980_8080
+                ..=0x0D80_8080
+            // U+0020
+            | 0x2080_8080
+            // U+0085
+            | 0xC285_8080
+            // U+200E and U+200F
+            | 0xE280_8E80
+            | 0xE280_8F80
+            // U+2028 and U+2029
+            | 0xE280_A880
+            | 0xE280_A980
+        )
+    }
+
+    /// Returns whether this codepoint has the `Pattern_Syntax` property, the codepoints syntaxes
+    /// like programming languages may use as operators and punctuation
+    ///
+    /// The property is stable, it will never change in future versions of unicode, and no
+    /// codepoint with it can be part of an identifier.
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert!(utf8char!("+").is_pattern_syntax());
+    /// assert!(utf8char!("→").is_pattern_syntax());
+    /// assert!(!utf8char!("_").is_pattern_syntax());
+    /// ```
+    #[must_use]
+    pub const fn is_pattern_syntax(self) -> bool {
+        PATTERN_SYNTAX.contains(self)
+    }
+}
+
+/// Returns whether a string is an identifier following the default identifier syntax of UAX #31,
+/// an `XID_Start` codepoint followed by any amount of `XID_Continue` codepoints
+///
+/// Languages that allow identifiers to start with `_`, like Rust, have to check for that on top of
+/// this.
+///
+/// ```
+/// use utf8char::identifier::is_identifier;
+///
+/// assert!(is_identifier("größe"));
+/// assert!(is_identifier("e\u{301}tat"));
+/// assert!(!is_identifier("1x"));
+/// assert!(!is_identifier("a-b"));
+/// assert!(!is_identifier(""));
+/// ```
+#[must_use]
+pub fn is_identifier(s: &str) -> bool {
+    let mut iter = Utf8CharIter::new(s);
+
+    iter.next().is_some_and(Utf8Char::is_xid_start) && iter.all(Utf8Char::is_xid_continue)
+}
+
+/// Returns whether a string is an identifier, like [`is_identifier`], that is also in NFC
+///
+/// UAX #31 recommends comparing identifiers in NFC, so that canonically equivalent spellings, like
+/// a precomposed `'é'` and an `'e'` followed by a combining acute accent, are the same identifier.
+/// Accepting only identifiers that are already in NFC lets them be compared bytewise.
+///
+/// ```
+/// use utf8char::identifier::is_identifier_nfc;
+///
+/// assert!(is_identifier_nfc("caf\u{E9}"));
+/// assert!(!is_identifier_nfc("cafe\u{301}"));
+/// // nothing combines with x, so it is in NFC as is
+/// assert!(is_identifier_nfc("x\u{301}"));
+/// ```
+#[must_use]
+pub fn is_identifier_nfc(s: &str) -> bool {
+    is_identifier(s) && is_nfc(s)
+}
+
+#[test]
+fn identifier_properties() {
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let utf8 = Utf8Char::from_char(c);
+
+        if utf8.is_xid_start() {
+            assert!(utf8.is_xid_continue(), "{c:?}");
+            // besides the Other_ID_Start codepoints, kept for stability
+            assert!(
+                c.is_alphabetic()
+                    || c.is_numeric()
+                    || matches!(c, '\u{1885}' | '\u{1886}' | '℘' | '℮' | '゛' | '゜'),
+                "{c:?}"
+            );
+        }
+
+        if utf8.is_pattern_syntax() || utf8.is_pattern_whitespace() {
+            assert!(!utf8.is_xid_continue(), "{c:?}");
+        }
+
+        if utf8.is_pattern_whitespace() {
+            assert!(
+                c.is_whitespace() || matches!(c, '\u{200E}' | '\u{200F}'),
+                "{c:?}"
+            );
+        }
+
+        if c.is_ascii() {
+            assert_eq!(utf8.is_xid_start(), c.is_ascii_alphabetic(), "{c:?}");
+            assert_eq!(
+                utf8.is_pattern_whitespace(),
+                c.is_ascii_whitespace() || c == '\u{B}'
+            );
+            assert_eq!(
+                utf8.is_pattern_syntax(),
+                c.is_ascii_punctuation() && c != '_',
+                "{c:?}"
+            );
+        }
+    });
+}
+
+#[test]
+fn identifiers() {
+    for (s, identifier, nfc) in [
+        ("a", true, true),
+        ("_", false, false),
+        ("a_1", true, true),
+        ("変数", true, true),
+        ("Ω", true, true),
+        // OHM SIGN, which normalizes to Ω
+        ("\u{2126}", true, false),
+        ("a\u{300}\u{301}", true, false),
+        ("x\u{301}", true, true),
+        ("a b", false, false),
+        // joiners continue identifiers since unicode 15.1
+        ("a\u{200D}", true, true),
+        ("\u{200D}a", false, false),
+    ] {
+        assert_eq!(is_identifier(s), identifier, "{s:?}");
+        assert_eq!(is_identifier_nfc(s), nfc, "{s:?}");
+    }
+}
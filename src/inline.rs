@@ -0,0 +1,153 @@
+//! A small inline string buffer, backing iterators that yield the `Utf8Char`'s of a short computed
+//! string while also exposing the remainder as a `&str`
+
+use crate::{iter::is_continuation, Utf8Char};
+
+/// Up to `N` bytes of utf8, of which `start..end` are yet to be yielded
+#[derive(Clone)]
+pub(crate) struct InlineStr<const N: usize> {
+    /// utf8 bytes, only `..end` is initialized
+    buf: [u8; N],
+    /// index of the first byte not yet yielded from the front
+    start: u8,
+    /// index after the last byte not yet yielded from the back
+    end: u8,
+}
+
+impl<const N: usize> InlineStr<N> {
+    /// Constructs a buffer from raw parts
+    ///
+    /// # Safety
+    /// `buf[..end]` must be valid utf8, and `end` must not be larger than `N`
+    pub(crate) const unsafe fn from_raw_parts(buf: [u8; N], end: u8) -> Self {
+        Self { buf, start: 0, end }
+    }
+
+    /// Constructs a buffer holding a copy of the passed string
+    ///
+    /// # Panics
+    /// Panics if the string is longer than `N` bytes
+    pub(crate) const fn new(s: &str) -> Self {
+        let bytes = s.as_bytes();
+        let mut buf = [0; N];
+        let mut idx = 0;
+
+        assert!(bytes.len() <= N && N <= u8::MAX as usize);
+
+        while idx < bytes.len() {
+            buf[idx] = bytes[idx];
+            idx += 1;
+        }
+
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "asserted to be at most u8::MAX above"
+        )]
+        // SAFETY: buf is a copy of a str up to its length, which fits in buf
+        unsafe {
+            Self::from_raw_parts(buf, bytes.len() as u8)
+        }
+    }
+
+    /// Returns the part of the buffer that has not been iterated over yet
+    pub(crate) const fn as_str(&self) -> &str {
+        let (init, _) = self.buf.split_at(self.end as usize);
+        let (_, rest) = init.split_at(self.start as usize);
+
+        // SAFETY: buf always holds utf8 up to end, and start and end only move over whole codepoints
+        unsafe { core::str::from_utf8_unchecked(rest) }
+    }
+
+    /// Yields the next codepoint from the front
+    pub(crate) fn next(&mut self) -> Option<Utf8Char> {
+        let ch = Utf8Char::from_first_char(self.as_str())?;
+        self.start += ch.len_utf8();
+
+        Some(ch)
+    }
+
+    /// Yields the next codepoint from the back
+    pub(crate) fn next_back(&mut self) -> Option<Utf8Char> {
+        let ch = Utf8Char::from_last_char(self.as_str())?;
+        self.end -= ch.len_utf8();
+
+        Some(ch)
+    }
+
+    /// Returns the amount of codepoints left to yield
+    pub(crate) fn len(&self) -> usize {
+        self.as_str()
+            .bytes()
+            .filter(|&b| !is_continuation(b))
+            .count()
+    }
+}
+
+/// Implements the iterator and string traits for a newtype over an `InlineStr` in field `.0`
+macro_rules! inline_str_iter {
+    ($name:ident) => {
+        impl $name {
+            /// Returns the part of the string that has not been iterated over yet
+            #[must_use]
+            pub const fn as_str(&self) -> &str {
+                self.0.as_str()
+            }
+        }
+
+        impl ::core::iter::Iterator for $name {
+            type Item = $crate::Utf8Char;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.0.len();
+
+                (len, Some(len))
+            }
+        }
+
+        impl ::core::iter::DoubleEndedIterator for $name {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.0.next_back()
+            }
+        }
+
+        impl ::core::iter::ExactSizeIterator for $name {
+            fn len(&self) -> usize {
+                self.0.len()
+            }
+        }
+
+        impl ::core::iter::FusedIterator for $name {}
+
+        impl ::core::ops::Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &Self::Target {
+                self.as_str()
+            }
+        }
+
+        impl ::core::convert::AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, concat!(stringify!($name), "({:?})"), self.as_str())
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+pub(crate) use inline_str_iter;
@@ -5,7 +5,7 @@ use core::{fmt, iter::FusedIterator, slice};
 use crate::{representation::Utf8FirstByte, Utf8Char, Utf8CharInner, TAG_CONTINUATION};
 
 /// Returns whether a given utf8 byte is a continuation byte
-fn is_continuation(b: u8) -> bool {
+pub(crate) const fn is_continuation(b: u8) -> bool {
     const TAG_MASK: u8 = 0b1100_0000;
 
     (b & TAG_MASK) == TAG_CONTINUATION
@@ -199,11 +199,11 @@ impl FusedIterator for Utf8CharIter<'_> {}
 /// `.chars()`
 pub trait IntoUtf8Chars {
     /// Returns a `Utf8CharIter` over the string
-    fn utf8_chars(&self) -> Utf8CharIter;
+    fn utf8_chars(&self) -> Utf8CharIter<'_>;
 }
 
 impl IntoUtf8Chars for str {
-    fn utf8_chars(&self) -> Utf8CharIter {
+    fn utf8_chars(&self) -> Utf8CharIter<'_> {
         Utf8CharIter::new(self)
     }
 }
@@ -19,20 +19,62 @@ use core::{
     hash::{Hash, Hasher},
     hint::assert_unchecked as assume,
     ops::Deref,
+    str::FromStr,
 };
 
 use representation::{codepoint_len_lut, Utf8CharInner};
 use std_at_home::TAG_CONTINUATION;
 
+pub mod bidi;
+pub mod case;
+pub mod category;
 mod charapi;
+pub mod emoji;
+mod error;
+pub mod escape;
+pub mod grapheme;
+pub mod identifier;
+mod inline;
 pub mod iter;
+pub mod line;
+mod macros;
+pub mod name;
+pub mod normalization;
+pub mod numeric;
+pub mod range;
 mod representation;
+pub mod script;
+pub mod security;
+mod segment;
+pub mod sentence;
 mod std_at_home;
+mod tables;
 #[cfg(test)]
 mod tests;
-
-pub use iter::{Utf8CharIter, IntoUtf8Chars};
-
+pub mod utf16;
+pub mod width;
+pub mod word;
+
+pub use bidi::BidiClass;
+pub use case::{CaseInsensitive, CaseMapping};
+pub use category::GeneralCategory;
+pub use error::{
+    DecodeUtf16Error, ParseUtf8CharError, ParseUtf8CharErrorKind, Utf8CharError, Utf8CharErrorKind,
+    Utf8CharTryFromError,
+};
+pub use escape::Escape;
+pub use grapheme::GraphemeBreak;
+pub use iter::{IntoUtf8Chars, Utf8CharIter};
+pub use line::LineBreak;
+#[doc(hidden)]
+pub use macros::private as __private;
+pub use normalization::Normalize;
+pub use numeric::NumericValue;
+pub use range::Utf8CharRange;
+pub use script::Script;
+pub use sentence::SentenceBreak;
+pub use utf16::DecodeUtf16;
+pub use word::WordBreak;
 
 /// A single unicode codepoint encoded in utf8.
 ///
@@ -62,6 +104,16 @@ pub use iter::{Utf8CharIter, IntoUtf8Chars};
 pub struct Utf8Char(Utf8CharInner);
 
 impl Utf8Char {
+    /// `U+FFFD REPLACEMENT CHARACTER` (�) is used in Unicode to represent a decoding error.
+    ///
+    /// See [`char::REPLACEMENT_CHARACTER`]
+    pub const REPLACEMENT_CHARACTER: Self = Self::from_char(char::REPLACEMENT_CHARACTER);
+
+    /// The version of Unicode that the unicode parts of `Utf8Char` methods are based on.
+    ///
+    /// See [`char::UNICODE_VERSION`]
+    pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
     /// Returns the length of a UTF-8 encoded codepoint based on the first bytes
     /// encoding (returns 1..=4).
     ///
@@ -148,6 +200,193 @@ impl Utf8Char {
         Self(unsafe { Utf8CharInner::from_utf8char_array(out) })
     }
 
+    /// returns a `Utf8Char` from the last char of a passed `&str`. Returns None if the string
+    /// contained no characters (was empty)
+    #[must_use]
+    pub const fn from_last_char(s: &str) -> Option<Self> {
+        let false = s.is_empty() else {
+            return None;
+        };
+
+        Some(Self::containing(s, s.len() - 1).1)
+    }
+
+    /// returns the `Utf8Char` starting at byte index `idx` of a passed `&str`. Returns None if `idx`
+    /// is out of bounds or does not lie on a char boundary.
+    ///
+    /// See [`containing`][Self::containing] for a version that accepts any in bounds index.
+    #[must_use]
+    pub const fn at(s: &str, idx: usize) -> Option<Self> {
+        let bytes = s.as_bytes();
+
+        if idx >= bytes.len() || iter::is_continuation(bytes[idx]) {
+            return None;
+        }
+
+        let tail = bytes.split_at(idx).1;
+
+        // SAFETY: idx lies on a char boundary so tail is valid utf8, and it is nonempty because idx
+        // is in bounds
+        Some(unsafe { Self::from_first_char_unchecked(core::str::from_utf8_unchecked(tail)) })
+    }
+
+    /// returns the `Utf8Char` whose encoding contains byte index `idx` of a passed `&str`, along
+    /// with the byte index it starts at.
+    ///
+    /// If `idx` does not lie on a char boundary it is snapped back to the start of the codepoint
+    /// it points into.
+    ///
+    /// # Panics
+    /// Panics if `idx` is out of bounds of the string
+    #[must_use]
+    pub const fn containing(s: &str, idx: usize) -> (usize, Self) {
+        let bytes = s.as_bytes();
+
+        assert!(idx < bytes.len(), "byte index is out of bounds of string");
+
+        let mut start = idx;
+
+        // a str always starts on a char boundary, so this can never go below 0
+        while iter::is_continuation(bytes[start]) {
+            start -= 1;
+        }
+
+        let Some(ch) = Self::at(s, start) else {
+            unreachable!()
+        };
+
+        (start, ch)
+    }
+
+    /// Decodes the first codepoint of a utf8 encoded byte slice, returning it along with the amount
+    /// of bytes it took up.
+    ///
+    /// Bytes after the first codepoint are not inspected, see [`from_utf8_exact`][Self::from_utf8_exact]
+    /// for a version that requires the slice to contain exactly one codepoint.
+    ///
+    /// # Errors
+    /// Returns a [`Utf8CharError`] describing why, and at what byte offset, the slice does not start
+    /// with a valid utf8 encoded codepoint.
+    pub const fn from_utf8(bytes: &[u8]) -> Result<(Self, usize), Utf8CharError> {
+        const PAD: u8 = TAG_CONTINUATION;
+
+        let [first, ..] = *bytes else {
+            return Err(Utf8CharError::new(Utf8CharErrorKind::Empty, 0));
+        };
+
+        // these are the bytes excluded from the first byte niche (see Utf8CharInner::from_utf8char_array),
+        // sorted by why they can never start a codepoint
+        let kind = match first {
+            0x80..=0xBF | 0xF8..=0xFF => Some(Utf8CharErrorKind::InvalidLeadByte),
+            0xC0 | 0xC1 => Some(Utf8CharErrorKind::Overlong),
+            0xF5..=0xF7 => Some(Utf8CharErrorKind::OutOfRange),
+            _ => None,
+        };
+
+        if let Some(kind) = kind {
+            return Err(Utf8CharError::new(kind, 0));
+        }
+
+        let len = Self::codepoint_len(first) as usize;
+
+        let mut out = [first, PAD, PAD, PAD];
+        let mut idx = 1;
+
+        while idx < len {
+            if idx >= bytes.len() || !iter::is_continuation(bytes[idx]) {
+                return Err(Utf8CharError::new(Utf8CharErrorKind::Truncated, idx));
+            }
+
+            out[idx] = bytes[idx];
+
+            // the second byte decides whether a 3 or 4 byte sequence encodes a scalar value within
+            // range, this is checked before reading further to report the earliest offset possible
+            let kind = match (idx, first, out[idx]) {
+                (1, 0xE0, ..0xA0) | (1, 0xF0, ..0x90) => Some(Utf8CharErrorKind::Overlong),
+                (1, 0xED, 0xA0..) => Some(Utf8CharErrorKind::Surrogate),
+                (1, 0xF4, 0x90..) => Some(Utf8CharErrorKind::OutOfRange),
+                _ => None,
+            };
+
+            if let Some(kind) = kind {
+                return Err(Utf8CharError::new(kind, idx));
+            }
+
+            idx += 1;
+        }
+
+        // SAFETY: first is a valid first byte, followed by len-1 continuation bytes that encode a
+        // unicode scalar value in its shortest form, followed by TAG_CONTINUATION padding
+        Ok((
+            Self(unsafe { Utf8CharInner::from_utf8char_array(out) }),
+            len,
+        ))
+    }
+
+    /// Decodes a byte slice that must contain exactly one utf8 encoded codepoint
+    ///
+    /// # Errors
+    /// Returns the same errors as [`from_utf8`][Self::from_utf8], and additionally an error of kind
+    /// [`TrailingBytes`][Utf8CharErrorKind::TrailingBytes] if bytes follow the first codepoint.
+    pub const fn from_utf8_exact(bytes: &[u8]) -> Result<Self, Utf8CharError> {
+        match Self::from_utf8(bytes) {
+            Ok((ch, len)) if len == bytes.len() => Ok(ch),
+            Ok((_, len)) => Err(Utf8CharError::new(Utf8CharErrorKind::TrailingBytes, len)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// returns a `Utf8Char` from a string that must contain exactly one codepoint.
+    ///
+    /// Unlike [`from_first_char`][Self::from_first_char] this does not ignore trailing codepoints,
+    /// it is the const equivalent of this types [`FromStr`] implementation.
+    ///
+    /// # Errors
+    /// Returns a [`ParseUtf8CharError`] if the string is empty or contains more than one codepoint
+    pub const fn from_str_exact(s: &str) -> Result<Self, ParseUtf8CharError> {
+        let Some(ch) = Self::from_first_char(s) else {
+            return Err(ParseUtf8CharError::new(ParseUtf8CharErrorKind::EmptyString));
+        };
+
+        if ch.len_utf8() as usize == s.len() {
+            Ok(ch)
+        } else {
+            Err(ParseUtf8CharError::new(
+                ParseUtf8CharErrorKind::TooManyChars,
+            ))
+        }
+    }
+
+    /// Decodes the first codepoint of a byte slice, substituting invalid utf8 with
+    /// [`REPLACEMENT_CHARACTER`][Self::REPLACEMENT_CHARACTER]. Returns the codepoint along with the
+    /// amount of bytes consumed.
+    ///
+    /// On invalid input exactly one "maximal subpart" of the invalid sequence is consumed, matching
+    /// the WHATWG Encoding Standard and [`String::from_utf8_lossy`]. Calling this in a loop
+    /// therefore produces the same codepoints as `String::from_utf8_lossy` would.
+    ///
+    /// An empty slice returns `REPLACEMENT_CHARACTER` with a length of 0, which is the only case
+    /// where less than one byte is consumed.
+    ///
+    /// [`String::from_utf8_lossy`]: https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf8_lossy
+    #[must_use]
+    pub const fn from_utf8_lossy_prefix(bytes: &[u8]) -> (Self, usize) {
+        match Self::from_utf8(bytes) {
+            Ok(decoded) => decoded,
+            // from_utf8 reports errors at the first byte that cannot be part of the sequence, so the
+            // maximal subpart is every byte before it, or the lead byte alone if it was the offender
+            Err(e) => {
+                let consumed = if e.offset() == 0 && !bytes.is_empty() {
+                    1
+                } else {
+                    e.offset()
+                };
+
+                (Self::REPLACEMENT_CHARACTER, consumed)
+            }
+        }
+    }
+
     /// Creates a `Utf8Char` from a `char`
     #[must_use]
     pub const fn from_char(code: char) -> Self {
@@ -167,6 +406,94 @@ impl Utf8Char {
         std_at_home::to_char(self)
     }
 
+    /// Creates a `Utf8Char` from a unicode scalar value, returns None if `code` is a surrogate or
+    /// larger than `U+10FFFF`.
+    ///
+    /// Equivalent to [`char::from_u32`], but encodes straight to utf8 without producing a `char`
+    #[must_use]
+    pub const fn from_u32(code: u32) -> Option<Self> {
+        match code {
+            0..0xD800 | 0xE000..=0x10_FFFF => {
+                // SAFETY: we have just checked code is a unicode scalar value
+                Some(unsafe { Self::from_u32_unchecked(code) })
+            }
+            _ => None,
+        }
+    }
+
+    /// Creates a `Utf8Char` from a unicode scalar value without checking its validity
+    ///
+    /// # Safety
+    /// `code` must be a unicode scalar value, that is, it must not be a surrogate
+    /// (`0xD800..=0xDFFF`) or larger than `0x10FFFF`. See [`char::from_u32_unchecked`].
+    #[must_use]
+    pub const unsafe fn from_u32_unchecked(code: u32) -> Self {
+        // SAFETY: the caller has promised code is a unicode scalar value
+        unsafe { assume(matches!(code, 0..0xD800 | 0xE000..=0x10_FFFF)) };
+
+        // SAFETY: encode_utf8 produces the valid utf8char representation for any scalar value
+        Self(unsafe { Utf8CharInner::from_utf8char_array(std_at_home::encode_utf8(code)) })
+    }
+
+    /// Returns the unicode scalar value of this codepoint, equivalent to `self.to_char() as u32`
+    #[must_use]
+    pub const fn to_u32(self) -> u32 {
+        std_at_home::to_u32(self)
+    }
+
+    /// Returns the canonical byte representation of this `Utf8Char`
+    ///
+    /// The representation is the utf8 encoding of the codepoint, padded to 4 bytes with
+    /// `0b1000_0000` (an empty continuation byte):
+    /// - 1 byte codepoints: `[utf8, 0x80, 0x80, 0x80]`
+    /// - 2 byte codepoints: `[utf8, utf8, 0x80, 0x80]`
+    /// - 3 byte codepoints: `[utf8, utf8, utf8, 0x80]`
+    /// - 4 byte codepoints: `[utf8, utf8, utf8, utf8]`
+    ///
+    /// This representation is stable and may be stored, [`from_bytes`][Self::from_bytes] converts
+    /// it back to a `Utf8Char`.
+    #[must_use]
+    pub const fn to_bytes(self) -> [u8; 4] {
+        *self.0.as_array()
+    }
+
+    /// Returns the canonical byte representation (see [`to_bytes`][Self::to_bytes]) as a big
+    /// endian `u32`.
+    ///
+    /// The returned bits are order preserving: for any two `Utf8Char`'s `a` and `b`,
+    /// `a.cmp(&b) == a.to_bits().cmp(&b.to_bits())`. Because utf8 is itself order preserving this is
+    /// also the order of the codepoints scalar values, making the bits (or [`to_bytes`][Self::to_bytes])
+    /// suitable as memcomparable keys.
+    #[must_use]
+    pub const fn to_bits(self) -> u32 {
+        u32::from_be_bytes(self.to_bytes())
+    }
+
+    /// Creates a `Utf8Char` from its canonical byte representation (see [`to_bytes`][Self::to_bytes]).
+    ///
+    /// Returns None if the bytes are not valid utf8, or the padding bytes are not `0x80`.
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; 4]) -> Option<Self> {
+        let Ok((ch, _)) = Self::from_utf8(&bytes) else {
+            return None;
+        };
+
+        // from_utf8 only validated the utf8 portion, the padding must still match
+        if ch.to_bits() == u32::from_be_bytes(bytes) {
+            Some(ch)
+        } else {
+            None
+        }
+    }
+
+    /// Creates a `Utf8Char` from its canonical bit representation (see [`to_bits`][Self::to_bits]).
+    ///
+    /// Returns None if the bits are not a valid `Utf8Char` representation.
+    #[must_use]
+    pub const fn from_bits(bits: u32) -> Option<Self> {
+        Self::from_bytes(bits.to_be_bytes())
+    }
+
     /// Returns a string reference to the codepoint
     #[must_use]
     pub const fn as_str(&self) -> &str {
@@ -192,6 +519,36 @@ impl From<Utf8Char> for char {
     }
 }
 
+impl FromStr for Utf8Char {
+    type Err = ParseUtf8CharError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_exact(s)
+    }
+}
+
+impl TryFrom<&str> for Utf8Char {
+    type Error = ParseUtf8CharError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_str_exact(value)
+    }
+}
+
+impl TryFrom<u32> for Utf8Char {
+    type Error = Utf8CharTryFromError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Self::from_u32(value).ok_or(Utf8CharTryFromError(()))
+    }
+}
+
+impl From<Utf8Char> for u32 {
+    fn from(value: Utf8Char) -> Self {
+        value.to_u32()
+    }
+}
+
 impl AsRef<str> for Utf8Char {
     fn as_ref(&self) -> &str {
         self.as_str()
@@ -276,9 +633,259 @@ fn roundtrip() {
     });
 }
 
+#[test]
+fn from_utf8_matches_std() {
+    use rayon::iter::ParallelIterator;
+
+    tests::all_chars().for_each(|ch| {
+        let mut buf = [0; 4];
+        let s = ch.encode_utf8(&mut buf);
+
+        assert_eq!(
+            Utf8Char::from_utf8(s.as_bytes()),
+            Ok((Utf8Char::from_char(ch), s.len()))
+        );
+        assert_eq!(
+            Utf8Char::from_utf8_exact(s.as_bytes()),
+            Ok(Utf8Char::from_char(ch))
+        );
+    });
+
+    let check = |bytes: &[u8]| match Utf8Char::from_utf8(bytes) {
+        Ok((ch, len)) => {
+            assert_eq!(core::str::from_utf8(&bytes[..len]), Ok(ch.as_str()));
+        }
+        Err(e) => {
+            let std = core::str::from_utf8(bytes).expect_err("std accepted bytes we rejected");
+
+            assert_eq!(std.valid_up_to(), 0);
+
+            if e.kind() == Utf8CharErrorKind::Truncated && e.offset() == bytes.len() {
+                assert_eq!(std.error_len(), None, "{bytes:x?}");
+            } else {
+                assert_eq!(std.error_len(), Some(e.offset().max(1)), "{bytes:x?}");
+            }
+        }
+    };
+
+    tests::all_byte_triples().for_each(|bytes| {
+        check(&bytes);
+        check(&bytes[..2]);
+        check(&bytes[..1]);
+    });
+
+    assert_eq!(
+        Utf8Char::from_utf8(b""),
+        Err(Utf8CharError::new(Utf8CharErrorKind::Empty, 0))
+    );
+    assert_eq!(
+        Utf8Char::from_utf8_exact(b"ab"),
+        Err(Utf8CharError::new(Utf8CharErrorKind::TrailingBytes, 1))
+    );
+    assert_eq!(
+        Utf8Char::from_utf8(b"\xF4\x90\x80\x80").map_err(|e| e.kind()),
+        Err(Utf8CharErrorKind::OutOfRange)
+    );
+    assert_eq!(
+        Utf8Char::from_utf8(b"\xED\xA0\x80").map_err(|e| e.kind()),
+        Err(Utf8CharErrorKind::Surrogate)
+    );
+    assert_eq!(
+        Utf8Char::from_utf8(b"\xE0\x80\x80").map_err(|e| e.kind()),
+        Err(Utf8CharErrorKind::Overlong)
+    );
+}
+
+#[test]
+fn from_u32_matches_std() {
+    use rayon::iter::ParallelIterator;
+
+    tests::all_u32s().for_each(|n| {
+        let utf8 = Utf8Char::from_u32(n);
+
+        assert_eq!(utf8.map(Utf8Char::to_char), char::from_u32(n), "{n:x}");
+        assert_eq!(Utf8Char::try_from(n).ok(), utf8);
+
+        if let Some(utf8) = utf8 {
+            assert_eq!(utf8, Utf8Char::from_char(utf8.to_char()));
+            assert_eq!(utf8.to_u32(), n);
+            assert_eq!(u32::from(utf8), n);
+            assert_eq!(unsafe { Utf8Char::from_u32_unchecked(n) }, utf8);
+        }
+    });
+}
+
+#[test]
+fn bits_roundtrip() {
+    use rayon::iter::ParallelIterator;
+
+    tests::all_chars().for_each(|ch| {
+        let utf8 = Utf8Char::from_char(ch);
+
+        let mut buf = [TAG_CONTINUATION; 4];
+        ch.encode_utf8(&mut buf);
+
+        assert_eq!(utf8.to_bytes(), buf);
+        assert_eq!(utf8.to_bits(), u32::from_be_bytes(buf));
+        assert_eq!(Utf8Char::from_bytes(buf), Some(utf8));
+        assert_eq!(Utf8Char::from_bits(utf8.to_bits()), Some(utf8));
+    });
+
+    tests::all_char_pairs().for_each(|(a, b)| {
+        let (a, b) = (Utf8Char::from_char(a), Utf8Char::from_char(b));
+
+        assert_eq!(a.cmp(&b), a.to_bits().cmp(&b.to_bits()));
+    });
+
+    // any accepted representation must be utf8 followed by padding
+    tests::all_byte_triples().for_each(|[a, b, c]| {
+        for last in [0, TAG_CONTINUATION, 0xBF, 0xFF] {
+            let bytes = [a, b, c, last];
+
+            if let Some(utf8) = Utf8Char::from_bytes(bytes) {
+                let len = utf8.len_utf8() as usize;
+
+                assert_eq!(utf8.to_bytes(), bytes);
+                assert!(core::str::from_utf8(&bytes[..len]).is_ok());
+                assert!(bytes[len..].iter().all(|&b| b == TAG_CONTINUATION));
+            }
+        }
+    });
+}
+
+#[test]
+fn lossy_matches_std() {
+    use rayon::iter::ParallelIterator;
+
+    let check = |bytes: &[u8]| {
+        let expected = bytes.utf8_chunks().flat_map(|chunk| {
+            let replacement = (!chunk.invalid().is_empty()).then_some(char::REPLACEMENT_CHARACTER);
+
+            chunk.valid().chars().chain(replacement)
+        });
+
+        let mut rest = bytes;
+        let actual = core::iter::from_fn(|| {
+            let (ch, len) = Utf8Char::from_utf8_lossy_prefix(rest);
+            rest = &rest[len..];
+
+            (len != 0).then_some(ch.to_char())
+        });
+
+        assert!(actual.eq(expected), "{bytes:x?}");
+    };
+
+    tests::all_byte_triples().for_each(|triple| {
+        check(&triple[..1]);
+        check(&triple[..2]);
+        check(&triple);
+    });
+    // the first codepoint of every 4 byte input, the rest is at most 3 bytes and checked above
+    tests::all_byte_quads().for_each(|quad| {
+        let chunk = quad.utf8_chunks().next().unwrap();
+        let expected = match chunk.valid().chars().next() {
+            Some(c) => (Utf8Char::from_char(c), c.len_utf8()),
+            None => (Utf8Char::REPLACEMENT_CHARACTER, chunk.invalid().len()),
+        };
+
+        assert_eq!(
+            Utf8Char::from_utf8_lossy_prefix(&quad),
+            expected,
+            "{quad:x?}"
+        );
+    });
+
+    assert_eq!(
+        Utf8Char::from_utf8_lossy_prefix(b""),
+        (Utf8Char::REPLACEMENT_CHARACTER, 0)
+    );
+}
+
+#[test]
+fn positional() {
+    use alloc::string::String;
+
+    #[cfg(not(miri))]
+    let allchars = (char::MIN..=char::MAX).collect::<String>();
+    #[cfg(miri)]
+    let allchars = (char::MIN..=char::MAX).step_by(4_999).collect::<String>();
+
+    let mut indices = allchars.char_indices().peekable();
+
+    while let Some((start, ch)) = indices.next() {
+        let end = indices.peek().map_or(allchars.len(), |&(idx, _)| idx);
+        let utf8 = Utf8Char::from_char(ch);
+
+        assert_eq!(Utf8Char::at(&allchars, start), Some(utf8));
+        assert_eq!(Utf8Char::from_last_char(&allchars[..end]), Some(utf8));
+
+        for idx in start..end {
+            assert_eq!(Utf8Char::containing(&allchars, idx), (start, utf8));
+
+            if idx != start {
+                assert_eq!(Utf8Char::at(&allchars, idx), None);
+            }
+        }
+    }
+
+    assert_eq!(Utf8Char::at(&allchars, allchars.len()), None);
+    assert_eq!(Utf8Char::from_last_char(""), None);
+}
+
+#[test]
+fn macros() {
+    const SINGLE: Utf8Char = utf8char!("é");
+    const MANY: [Utf8Char; 5] = utf8chars!("a\u{80}\u{800}\u{10000}\u{10FFFF}");
+
+    assert_eq!(SINGLE, Utf8Char::from_char('é'));
+    assert_eq!(utf8char!("\u{10FFFF}"), Utf8Char::from_char(char::MAX));
+
+    assert_eq!(
+        MANY.map(Utf8Char::to_char),
+        ['a', '\u{80}', '\u{800}', '\u{10000}', '\u{10FFFF}']
+    );
+    assert_eq!(utf8chars!("").len(), 0);
+}
+
 #[test]
 fn empty_string() {
     assert!(Utf8Char::from_first_char("").is_none());
+    assert_eq!(
+        "".parse::<Utf8Char>().map_err(|e| e.kind()),
+        Err(ParseUtf8CharErrorKind::EmptyString)
+    );
+}
+
+#[test]
+fn parse_matches_std() {
+    use alloc::string::ToString;
+    use rayon::iter::ParallelIterator;
+
+    tests::all_char_pairs().for_each(|(a, b)| {
+        let utf8 = Utf8Char::from_char(a);
+        let mut buf = [0; 8];
+        let single = a.encode_utf8(&mut buf).len();
+        let double = single + b.encode_utf8(&mut buf[single..]).len();
+
+        let single = core::str::from_utf8(&buf[..single]).unwrap();
+        let double = core::str::from_utf8(&buf[..double]).unwrap();
+
+        assert_eq!(single.parse::<Utf8Char>(), Ok(utf8));
+        assert_eq!(Utf8Char::try_from(single), Ok(utf8));
+
+        let err = double.parse::<Utf8Char>().unwrap_err();
+        assert_eq!(err.kind(), ParseUtf8CharErrorKind::TooManyChars);
+        assert_eq!(Utf8Char::try_from(double), Err(err));
+    });
+
+    assert_eq!(
+        "ab".parse::<Utf8Char>().unwrap_err().to_string(),
+        "ab".parse::<char>().unwrap_err().to_string()
+    );
+    assert_eq!(
+        "".parse::<Utf8Char>().unwrap_err().to_string(),
+        "".parse::<char>().unwrap_err().to_string()
+    );
 }
 
 #[test]
@@ -344,3 +951,16 @@ fn total_ordering() {
         );
     });
 }
+
+#[test]
+fn unicode_version_matches_std() {
+    let (major, minor, update) = char::UNICODE_VERSION;
+
+    // the tests comparing against `char` methods only hold on a toolchain whose std has the same
+    // unicode version as the tables, which older ones down to the MSRV do not
+    assert_eq!(
+        Utf8Char::UNICODE_VERSION,
+        (major, minor, update),
+        "the tests comparing against std need a toolchain with the unicode version of the tables"
+    );
+}
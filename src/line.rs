@@ -0,0 +1,972 @@
+//! Line breaking of strings, as defined in [UAX #14](https://www.unicode.org/reports/tr14/), and
+//! wrapping of text to a display width
+//!
+//! Lines can be broken after spaces and hyphens, but also between ideographs and around most
+//! punctuation, so text without spaces still wraps:
+//!
+//! ```
+//! use utf8char::line::wrap;
+//!
+//! let help = "Usage: 日本語のテキストを折り返す";
+//!
+//! for line in wrap(help, 12) {
+//!     println!("{line}");
+//! }
+//! ```
+
+use core::iter::FusedIterator;
+
+use crate::{
+    grapheme::graphemes,
+    tables::{
+        grapheme::EXTENDED_PICTOGRAPHIC,
+        line::{EAST_ASIAN, LINE_BREAK},
+    },
+    width::display_width,
+    GeneralCategory, Utf8Char, Utf8CharIter,
+};
+
+/// The `Line_Break` property of a codepoint, which line break opportunities are determined by
+///
+/// Variants are named after the long property value aliases.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LineBreak {
+    /// `BK`: form feeds, vertical tabs and the line and paragraph separators, which end a line
+    MandatoryBreak,
+    /// `CR`: U+000D CARRIAGE RETURN only
+    CarriageReturn,
+    /// `LF`: U+000A LINE FEED only
+    LineFeed,
+    /// `CM`: combining marks and most controls, which take the class of the codepoint before them
+    CombiningMark,
+    /// `NL`: U+0085 NEXT LINE only
+    NextLine,
+    /// `SG`: surrogates, which no `Utf8Char` is
+    Surrogate,
+    /// `WJ`: the word joiner and the zero width no-break space, which prevent breaks on both sides
+    WordJoiner,
+    /// `ZW`: U+200B ZERO WIDTH SPACE only, which is a break opportunity
+    ZWSpace,
+    /// `GL`: non-breaking spaces and other glue, which prevent breaks on both sides
+    Glue,
+    /// `SP`: U+0020 SPACE only
+    Space,
+    /// `ZWJ`: U+200D ZERO WIDTH JOINER only
+    ZWJ,
+    /// `B2`: em dashes, which allow a break before and after them, but not between them
+    BreakBoth,
+    /// `BA`: breaking spaces, tabs and dashes, which allow a break after them
+    BreakAfter,
+    /// `BB`: punctuation that allows a break before it, like the Tibetan head marks
+    BreakBefore,
+    /// `HY`: U+002D HYPHEN-MINUS only, which is a hyphen or a minus sign
+    Hyphen,
+    /// `HH`: hyphens that are never a minus sign, like U+2010 HYPHEN
+    UnambiguousHyphen,
+    /// `CB`: objects whose break opportunities depend on what they are, like U+FFFC
+    ContingentBreak,
+    /// `CL`: closing punctuation other than parentheses
+    ClosePunctuation,
+    /// `CP`: closing parentheses and square brackets
+    CloseParenthesis,
+    /// `EX`: exclamation and question marks, and other punctuation that is not broken before
+    Exclamation,
+    /// `IN`: ellipses and leaders
+    Inseparable,
+    /// `NS`: small kana, iteration marks and other codepoints that do not start a line
+    Nonstarter,
+    /// `OP`: opening punctuation
+    OpenPunctuation,
+    /// `QU`: quotation marks, which can open or close a quote
+    Quotation,
+    /// `IS`: commas, periods and other separators within numbers
+    InfixNumeric,
+    /// `NU`: digits
+    Numeric,
+    /// `PO`: percent signs and other codepoints that follow numbers
+    PostfixNumeric,
+    /// `PR`: currency symbols and other codepoints that precede numbers
+    PrefixNumeric,
+    /// `SY`: U+002F SOLIDUS only, which allows a break after it except before digits
+    BreakSymbols,
+    /// `AI`: codepoints that are ideographic or alphabetic depending on the context, alphabetic
+    /// unless resolved otherwise
+    Ambiguous,
+    /// `AK`: aksaras, the consonants and independent vowels of some Brahmic scripts
+    Aksara,
+    /// `AL`: letters and symbols of alphabetic scripts
+    Alphabetic,
+    /// `AP`: prebase aksaras, which come before the aksara they belong to
+    AksaraPrebase,
+    /// `AS`: aksaras that can start a cluster, like U+25CC DOTTED CIRCLE
+    AksaraStart,
+    /// `CJ`: small kana and prolonged sound marks, which are nonstarters in strict line breaking
+    ConditionalJapaneseStarter,
+    /// `EB`: emoji that emoji modifiers apply to
+    EBase,
+    /// `EM`: the emoji modifiers
+    EModifier,
+    /// `H2`: Hangul syllables without a trailing consonant
+    H2,
+    /// `H3`: Hangul syllables with a trailing consonant
+    H3,
+    /// `HL`: Hebrew letters
+    HebrewLetter,
+    /// `ID`: ideographs, kana and most emoji, which allow a break before and after them
+    Ideographic,
+    /// `JL`: Hangul leading jamo
+    JL,
+    /// `JV`: Hangul vowel jamo
+    JV,
+    /// `JT`: Hangul trailing jamo
+    JT,
+    /// `RI`: the regional indicator symbols, which pair up into flags
+    RegionalIndicator,
+    /// `SA`: codepoints of scripts like Thai that break lines between words, which takes a
+    /// dictionary to find
+    ComplexContext,
+    /// `VF`: viramas that end an orthographic syllable
+    ViramaFinal,
+    /// `VI`: viramas that join aksaras into a cluster
+    Virama,
+    /// `XX`: unassigned and private use codepoints, and any codepoint without another class
+    Unknown,
+}
+
+impl Utf8Char {
+    /// Returns the [`LineBreak`] class of this codepoint
+    ///
+    /// ```
+    /// use utf8char::{line::LineBreak, utf8char};
+    ///
+    /// assert_eq!(utf8char!("a").line_break_class(), LineBreak::Alphabetic);
+    /// assert_eq!(utf8char!(" ").line_break_class(), LineBreak::Space);
+    /// assert_eq!(utf8char!("字").line_break_class(), LineBreak::Ideographic);
+    /// assert_eq!(utf8char!("(").line_break_class(), LineBreak::OpenPunctuation);
+    /// assert_eq!(utf8char!("\u{A0}").line_break_class(), LineBreak::Glue);
+    /// ```
+    #[must_use]
+    pub const fn line_break_class(self) -> LineBreak {
+        LINE_BREAK.get(self)
+    }
+}
+
+/// U+25CC DOTTED CIRCLE, which `LB28a` treats like an aksara
+const DOTTED_CIRCLE: Utf8Char = Utf8Char::from_char('\u{25CC}');
+
+/// The kind of a line break opportunity
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BreakOpportunity {
+    /// The line must be broken here, after a newline or other line terminator
+    Mandatory,
+    /// The line can be broken here if it does not fit
+    Allowed,
+}
+
+/// Returns the class of a codepoint as resolved by `LB1`, without the contextual classes the rules
+/// do not handle
+const fn resolve(ch: Utf8Char) -> LineBreak {
+    use LineBreak as L;
+
+    match ch.line_break_class() {
+        L::Ambiguous | L::Surrogate | L::Unknown => L::Alphabetic,
+        L::ComplexContext => match ch.general_category() {
+            GeneralCategory::NonspacingMark | GeneralCategory::SpacingMark => L::CombiningMark,
+            _ => L::Alphabetic,
+        },
+        L::ConditionalJapaneseStarter => L::Nonstarter,
+        class => class,
+    }
+}
+
+/// A codepoint together with the combining marks and zero width joiners `LB9` attaches to it, what
+/// the rules apply to
+#[derive(Copy, Clone, Debug)]
+struct Unit {
+    /// The resolved class of the codepoint, `AL` for lone combining marks as per `LB10`
+    class: LineBreak,
+    /// The codepoint, whose properties the unit has
+    base: Utf8Char,
+    /// Whether the unit ends with a zero width joiner, which `LB8a` never breaks after
+    zwj: bool,
+}
+
+impl Unit {
+    /// Splits the next unit off the front of `iter`
+    fn next(iter: &mut Utf8CharIter<'_>) -> Option<Self> {
+        use LineBreak as L;
+
+        let base = iter.next()?;
+        let mut unit = Self {
+            class: resolve(base),
+            base,
+            zwj: base.line_break_class() == L::ZWJ,
+        };
+
+        match unit.class {
+            L::MandatoryBreak
+            | L::CarriageReturn
+            | L::LineFeed
+            | L::NextLine
+            | L::Space
+            | L::ZWSpace => return Some(unit),
+            L::CombiningMark | L::ZWJ => unit.class = L::Alphabetic,
+            _ => {}
+        }
+
+        loop {
+            let rest = iter.clone();
+
+            match iter.next().map(resolve) {
+                Some(L::CombiningMark) => unit.zwj = false,
+                Some(L::ZWJ) => unit.zwj = true,
+                _ => {
+                    *iter = rest;
+                    return Some(unit);
+                }
+            }
+        }
+    }
+
+    /// Returns whether the unit is in `$EastAsian`, it is fullwidth, wide or halfwidth
+    const fn is_east_asian(self) -> bool {
+        EAST_ASIAN.contains(self.base)
+    }
+
+    /// Returns whether the unit is an aksara or U+25CC DOTTED CIRCLE, which `LB28a` treats alike
+    fn is_aksara(self) -> bool {
+        matches!(self.class, LineBreak::Aksara | LineBreak::AksaraStart)
+            || self.base == DOTTED_CIRCLE
+    }
+
+    /// Returns whether the unit is a quotation mark of general category `category`
+    fn is_quotation(self, category: GeneralCategory) -> bool {
+        self.class == LineBreak::Quotation && self.base.general_category() == category
+    }
+}
+
+/// How far into a number `LB25` is
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Number {
+    /// Not within a number
+    None,
+    /// Within `NU (SY | IS)*`
+    Open,
+    /// After `NU (SY | IS)* (CL | CP)`
+    Closed,
+}
+
+/// The sequences that break opportunities depend on, tracked while iterating so that opportunities
+/// never require looking back
+#[derive(Copy, Clone, Debug)]
+struct Context {
+    /// The last unit
+    prev: Unit,
+    /// The unit before `prev`, or None at the start of the text
+    before: Option<Unit>,
+    /// The last unit that is not a space, only followed by spaces since
+    spaced: Option<Unit>,
+    /// Whether `spaced` is an initial quotation mark that `LB15a` applies to
+    initial_quote: bool,
+    /// Where `prev` is within a number
+    number: Number,
+    /// Whether an odd amount of regional indicators was just seen
+    odd_regional: bool,
+}
+
+impl Context {
+    /// Returns the context after `unit`, following the units `context` was tracking if any
+    fn after(context: Option<Self>, unit: Unit) -> Self {
+        use LineBreak as L;
+
+        let before = context.map(|context| context.prev);
+
+        let (spaced, initial_quote) = match context {
+            Some(context) if unit.class == L::Space => (context.spaced, context.initial_quote),
+            _ => {
+                let initial = unit.is_quotation(GeneralCategory::InitialPunctuation)
+                    && before.is_none_or(|before| {
+                        matches!(
+                            before.class,
+                            L::MandatoryBreak
+                                | L::CarriageReturn
+                                | L::LineFeed
+                                | L::NextLine
+                                | L::OpenPunctuation
+                                | L::Quotation
+                                | L::Glue
+                                | L::Space
+                                | L::ZWSpace
+                        )
+                    });
+
+                (Some(unit).filter(|unit| unit.class != L::Space), initial)
+            }
+        };
+
+        let number = match (
+            context.map_or(Number::None, |context| context.number),
+            unit.class,
+        ) {
+            (_, L::Numeric) | (Number::Open, L::BreakSymbols | L::InfixNumeric) => Number::Open,
+            (Number::Open, L::ClosePunctuation | L::CloseParenthesis) => Number::Closed,
+            _ => Number::None,
+        };
+
+        Self {
+            prev: unit,
+            before,
+            spaced,
+            initial_quote,
+            number,
+            odd_regional: unit.class == L::RegionalIndicator
+                && !context.is_some_and(|context| context.odd_regional),
+        }
+    }
+
+    /// Returns the break opportunity between the units tracked so far and `next`, if any, with
+    /// `after` iterating over what follows `next`
+    fn breaks(self, next: Unit, after: &Utf8CharIter<'_>) -> Option<BreakOpportunity> {
+        use LineBreak as L;
+
+        match (self.prev.class, next.class) {
+            // LB5
+            (L::CarriageReturn, L::LineFeed) => None,
+            // LB4 and LB5
+            (L::MandatoryBreak | L::CarriageReturn | L::LineFeed | L::NextLine, _) => {
+                Some(BreakOpportunity::Mandatory)
+            }
+            // LB6 and LB7
+            (
+                _,
+                L::MandatoryBreak
+                | L::CarriageReturn
+                | L::LineFeed
+                | L::NextLine
+                | L::Space
+                | L::ZWSpace,
+            ) => None,
+            // LB8
+            _ if self.spaced.is_some_and(|unit| unit.class == L::ZWSpace) => {
+                Some(BreakOpportunity::Allowed)
+            }
+            // LB8a
+            _ if self.prev.zwj => None,
+            _ => (!self.keeps(next, after)).then_some(BreakOpportunity::Allowed),
+        }
+    }
+
+    /// Returns whether `LB11` to `LB30b` keep `next` on the same line as the units tracked so far,
+    /// with `after` iterating over what follows `next`
+    fn keeps(self, next: Unit, after: &Utf8CharIter<'_>) -> bool {
+        use LineBreak as L;
+
+        let prev = self.prev;
+        // the units after `next`, for the rules that look ahead
+        let mut after = after.clone();
+        let mut lookahead = || Unit::next(&mut after);
+
+        #[expect(
+            clippy::match_same_arms,
+            reason = "the arms follow the order of the rules, which matters"
+        )]
+        match (prev.class, next.class) {
+            // LB11 and LB12
+            (L::WordJoiner | L::Glue, _) | (_, L::WordJoiner) => return true,
+            // LB12a
+            (L::Space | L::BreakAfter | L::Hyphen | L::UnambiguousHyphen, L::Glue) => {}
+            (_, L::Glue) => return true,
+            // LB13
+            (_, L::ClosePunctuation | L::CloseParenthesis | L::Exclamation | L::BreakSymbols) => {
+                return true
+            }
+            _ => {}
+        }
+
+        // LB14
+        if self
+            .spaced
+            .is_some_and(|unit| unit.class == L::OpenPunctuation)
+            || self.initial_quote
+        {
+            // and LB15a
+            return true;
+        }
+
+        // LB15b
+        if next.is_quotation(GeneralCategory::FinalPunctuation)
+            && lookahead().is_none_or(|unit| {
+                matches!(
+                    unit.class,
+                    L::Space
+                        | L::Glue
+                        | L::WordJoiner
+                        | L::ClosePunctuation
+                        | L::Quotation
+                        | L::CloseParenthesis
+                        | L::Exclamation
+                        | L::InfixNumeric
+                        | L::BreakSymbols
+                        | L::MandatoryBreak
+                        | L::CarriageReturn
+                        | L::LineFeed
+                        | L::NextLine
+                        | L::ZWSpace
+                )
+            })
+        {
+            return true;
+        }
+
+        if next.class == L::InfixNumeric {
+            // LB15c and LB15d
+            return prev.class != L::Space
+                || !lookahead().is_some_and(|unit| unit.class == L::Numeric);
+        }
+
+        let spaced = self.spaced.map(|unit| unit.class);
+
+        // LB16 and LB17
+        if matches!(
+            (spaced, next.class),
+            (
+                Some(L::ClosePunctuation | L::CloseParenthesis),
+                L::Nonstarter
+            ) | (Some(L::BreakBoth), L::BreakBoth)
+        ) {
+            return true;
+        }
+
+        // LB18
+        if prev.class == L::Space {
+            return false;
+        }
+
+        self.keeps_quotations(next, &mut lookahead)
+            .unwrap_or_else(|| self.keeps_within_words(next, &mut lookahead))
+    }
+
+    /// Returns whether `LB19` to `LB20` decide if `next` is kept on the same line, and what they
+    /// decide
+    fn keeps_quotations(
+        self,
+        next: Unit,
+        lookahead: &mut impl FnMut() -> Option<Unit>,
+    ) -> Option<bool> {
+        use LineBreak as L;
+
+        let prev = self.prev;
+
+        // LB19
+        if next.class == L::Quotation && !next.is_quotation(GeneralCategory::InitialPunctuation)
+            || prev.class == L::Quotation && !prev.is_quotation(GeneralCategory::FinalPunctuation)
+        {
+            return Some(true);
+        }
+
+        // LB19a
+        if next.class == L::Quotation
+            && (!prev.is_east_asian() || lookahead().is_none_or(|unit| !unit.is_east_asian()))
+            || prev.class == L::Quotation
+                && (!next.is_east_asian() || self.before.is_none_or(|unit| !unit.is_east_asian()))
+        {
+            return Some(true);
+        }
+
+        // LB20
+        if prev.class == L::ContingentBreak || next.class == L::ContingentBreak {
+            return Some(false);
+        }
+
+        None
+    }
+
+    /// Returns whether `LB20a` to `LB30b` keep `next` on the same line
+    fn keeps_within_words(self, next: Unit, lookahead: &mut impl FnMut() -> Option<Unit>) -> bool {
+        use LineBreak as L;
+
+        let prev = self.prev;
+        let before = self.before.map(|unit| unit.class);
+
+        #[expect(
+            clippy::match_same_arms,
+            reason = "the arms follow the order of the rules, which matters"
+        )]
+        match (prev.class, next.class) {
+            // LB20a
+            (L::Hyphen | L::UnambiguousHyphen, L::Alphabetic | L::HebrewLetter)
+                if matches!(
+                    before,
+                    None | Some(
+                        L::MandatoryBreak
+                            | L::CarriageReturn
+                            | L::LineFeed
+                            | L::NextLine
+                            | L::Space
+                            | L::ZWSpace
+                            | L::ContingentBreak
+                            | L::Glue
+                    )
+                ) =>
+            {
+                true
+            }
+            // LB21
+            (_, L::BreakAfter | L::UnambiguousHyphen | L::Hyphen | L::Nonstarter)
+            | (L::BreakBefore, _) => true,
+            // LB21a
+            (L::Hyphen | L::UnambiguousHyphen, _)
+                if before == Some(L::HebrewLetter) && next.class != L::HebrewLetter =>
+            {
+                true
+            }
+            // LB21b and LB22
+            (L::BreakSymbols, L::HebrewLetter) | (_, L::Inseparable) => true,
+            // LB23
+            (L::Alphabetic | L::HebrewLetter, L::Numeric)
+            | (L::Numeric, L::Alphabetic | L::HebrewLetter) => true,
+            // LB23a
+            (L::PrefixNumeric, L::Ideographic | L::EBase | L::EModifier)
+            | (L::Ideographic | L::EBase | L::EModifier, L::PostfixNumeric) => true,
+            // LB24
+            (L::PrefixNumeric | L::PostfixNumeric, L::Alphabetic | L::HebrewLetter)
+            | (L::Alphabetic | L::HebrewLetter, L::PrefixNumeric | L::PostfixNumeric) => true,
+            // LB25
+            (_, L::PostfixNumeric | L::PrefixNumeric) if self.number != Number::None => true,
+            (_, L::Numeric) if self.number == Number::Open => true,
+            (L::PostfixNumeric | L::PrefixNumeric, L::OpenPunctuation) => {
+                match lookahead().map(|unit| unit.class) {
+                    Some(L::Numeric) => true,
+                    Some(L::InfixNumeric) => {
+                        lookahead().is_some_and(|unit| unit.class == L::Numeric)
+                    }
+                    _ => false,
+                }
+            }
+            (L::PostfixNumeric | L::PrefixNumeric | L::Hyphen | L::InfixNumeric, L::Numeric) => {
+                true
+            }
+            // LB26
+            (L::JL, L::JL | L::JV | L::H2 | L::H3)
+            | (L::JV | L::H2, L::JV | L::JT)
+            | (L::JT | L::H3, L::JT) => true,
+            // LB27
+            (L::JL | L::JV | L::JT | L::H2 | L::H3, L::PostfixNumeric)
+            | (L::PrefixNumeric, L::JL | L::JV | L::JT | L::H2 | L::H3) => true,
+            // LB28
+            (L::Alphabetic | L::HebrewLetter, L::Alphabetic | L::HebrewLetter) => true,
+            // LB28a
+            (L::AksaraPrebase, _) if next.is_aksara() => true,
+            (_, L::ViramaFinal | L::Virama) if prev.is_aksara() => true,
+            (L::Virama, _)
+                if self.before.is_some_and(Unit::is_aksara)
+                    && (next.class == L::Aksara || next.base == DOTTED_CIRCLE) =>
+            {
+                true
+            }
+            _ if prev.is_aksara() && next.is_aksara() => {
+                lookahead().is_some_and(|unit| unit.class == L::ViramaFinal)
+            }
+            // LB29
+            (L::InfixNumeric, L::Alphabetic | L::HebrewLetter) => true,
+            // LB30
+            (L::Alphabetic | L::HebrewLetter | L::Numeric, L::OpenPunctuation) => {
+                !next.is_east_asian()
+            }
+            (L::CloseParenthesis, L::Alphabetic | L::HebrewLetter | L::Numeric) => {
+                !prev.is_east_asian()
+            }
+            // LB30a
+            (L::RegionalIndicator, L::RegionalIndicator) => self.odd_regional,
+            // LB30b
+            (L::EBase, L::EModifier) => true,
+            (_, L::EModifier) => {
+                EXTENDED_PICTOGRAPHIC.contains(prev.base)
+                    && prev.base.general_category() == GeneralCategory::Unassigned
+            }
+            // LB31
+            _ => false,
+        }
+    }
+}
+
+/// An iterator over the line break opportunities of a string, as their byte offsets and kinds
+///
+/// Created by [`line_breaks`].
+#[derive(Clone, Debug)]
+pub struct LineBreaks<'slice> {
+    /// The whole string being broken
+    s: &'slice str,
+    /// The codepoints after the last unit
+    iter: Utf8CharIter<'slice>,
+    /// The context of the units so far, None before the first one and after the end of the string
+    context: Option<Context>,
+}
+
+impl Iterator for LineBreaks<'_> {
+    type Item = (usize, BreakOpportunity);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let offset = self.s.len() - self.iter.as_str().len();
+
+            let Some(unit) = Unit::next(&mut self.iter) else {
+                // LB3
+                return self
+                    .context
+                    .take()
+                    .map(|_| (offset, BreakOpportunity::Mandatory));
+            };
+
+            // LB2
+            let opportunity = self
+                .context
+                .and_then(|context| context.breaks(unit, &self.iter));
+
+            self.context = Some(Context::after(self.context, unit));
+
+            if let Some(opportunity) = opportunity {
+                return Some((offset, opportunity));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.as_str().len();
+        let end = usize::from(self.context.is_some() || len > 0);
+
+        // every codepoint is at least a byte, and there is an opportunity at the end
+        (end, Some(len + end))
+    }
+}
+
+impl FusedIterator for LineBreaks<'_> {}
+
+/// Returns an iterator over the line break opportunities of a string, as the byte offsets where the
+/// next line would start and whether the line must be broken there
+///
+/// Every non-empty string ends with a mandatory break. Lines are broken after spaces, but not
+/// before closing punctuation, and between ideographs:
+///
+/// ```
+/// use utf8char::line::{line_breaks, BreakOpportunity::{Allowed, Mandatory}};
+///
+/// let breaks = line_breaks("Hi (you)! 日本\nok").collect::<Vec<_>>();
+///
+/// assert_eq!(breaks, [(3, Allowed), (10, Allowed), (13, Allowed), (17, Mandatory), (19, Mandatory)]);
+/// assert_eq!(line_breaks("").next(), None);
+/// ```
+#[must_use]
+pub fn line_breaks(s: &str) -> LineBreaks<'_> {
+    LineBreaks {
+        s,
+        iter: Utf8CharIter::new(s),
+        context: None,
+    }
+}
+
+/// Returns whether a codepoint is left out at the end of a wrapped line
+fn is_trailing(ch: Utf8Char) -> bool {
+    matches!(
+        ch.line_break_class(),
+        LineBreak::Space
+            | LineBreak::MandatoryBreak
+            | LineBreak::CarriageReturn
+            | LineBreak::LineFeed
+            | LineBreak::NextLine
+    )
+}
+
+/// Returns `s` without the spaces and line terminators at its end
+fn trim_end(s: &str) -> &str {
+    let mut iter = Utf8CharIter::new(s);
+
+    while iter.clone().next_back().is_some_and(is_trailing) {
+        iter.next_back();
+    }
+
+    iter.as_str()
+}
+
+/// An iterator over the lines of a string wrapped to a display width
+///
+/// Created by [`wrap`].
+#[derive(Clone, Debug)]
+pub struct Wrap<'slice> {
+    /// The part of the string that has not been wrapped yet
+    s: &'slice str,
+    /// The amount of columns lines fit in
+    max_width: usize,
+}
+
+impl<'slice> Wrap<'slice> {
+    /// Returns the part of the string that has not been wrapped yet
+    #[must_use]
+    pub fn as_str(&self) -> &'slice str {
+        self.s
+    }
+
+    /// Returns the length of the next line, up to the last break opportunity that fits
+    fn line_len(&self) -> usize {
+        // the width of the string up to the last opportunity, with and without the spaces it ends
+        // with
+        let mut width = 0;
+        let mut trimmed = 0;
+        // the last opportunity that fits
+        let mut last = 0;
+
+        for (offset, opportunity) in line_breaks(self.s) {
+            let segment = &self.s[last..offset];
+            let visible = trim_end(segment);
+
+            if !visible.is_empty() {
+                trimmed = width + display_width(visible);
+            }
+
+            width += display_width(segment);
+
+            if trimmed > self.max_width {
+                return if last > 0 {
+                    last
+                } else {
+                    self.split_graphemes()
+                };
+            }
+
+            if opportunity == BreakOpportunity::Mandatory {
+                return offset;
+            }
+
+            // leading spaces stay with the first word
+            if trimmed > 0 {
+                last = offset;
+            }
+        }
+
+        self.s.len()
+    }
+
+    /// Returns the length of the longest run of whole grapheme clusters at the start of the string
+    /// that fits, for words too wide to fit on a line of their own, and at least one cluster
+    fn split_graphemes(&self) -> usize {
+        let mut width = 0;
+        let mut len = 0;
+
+        for grapheme in graphemes(self.s) {
+            width += display_width(grapheme);
+
+            if len > 0 && width > self.max_width {
+                break;
+            }
+
+            len += grapheme.len();
+        }
+
+        len
+    }
+}
+
+impl<'slice> Iterator for Wrap<'slice> {
+    type Item = &'slice str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.s.is_empty() {
+            return None;
+        }
+
+        let (line, rest) = self.s.split_at(self.line_len());
+
+        self.s = rest;
+        Some(trim_end(line))
+    }
+}
+
+impl FusedIterator for Wrap<'_> {}
+
+/// Returns an iterator over the lines of a string wrapped to fit in `max_width` terminal columns,
+/// as measured by [`display_width`]
+///
+/// Lines are broken at the last [break opportunity](line_breaks) that fits, and at every mandatory
+/// one, without the spaces and line terminators they end with. Words too wide for a line of their
+/// own are broken between grapheme clusters.
+///
+/// ```
+/// use utf8char::line::wrap;
+///
+/// let lines = wrap("The quick brown fox", 10).collect::<Vec<_>>();
+/// assert_eq!(lines, ["The quick", "brown fox"]);
+///
+/// // ideographs take up two columns, and lines can be broken between any two of them
+/// let lines = wrap("設定ファイルが見つかりません", 10).collect::<Vec<_>>();
+/// assert_eq!(lines, ["設定ファイ", "ルが見つか", "りません"]);
+///
+/// let lines = wrap("first\n\nthird", 80).collect::<Vec<_>>();
+/// assert_eq!(lines, ["first", "", "third"]);
+/// ```
+#[must_use]
+pub fn wrap(s: &str, max_width: usize) -> Wrap<'_> {
+    Wrap { s, max_width }
+}
+
+#[test]
+fn line_break_classes() {
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let utf8 = Utf8Char::from_char(c);
+        let class = utf8.line_break_class();
+
+        match c {
+            '\r' => assert_eq!(class, LineBreak::CarriageReturn),
+            '\n' => assert_eq!(class, LineBreak::LineFeed),
+            ' ' => assert_eq!(class, LineBreak::Space),
+            '\u{200D}' => assert_eq!(class, LineBreak::ZWJ),
+            '0'..='9' => assert_eq!(class, LineBreak::Numeric),
+            'a'..='z' | 'A'..='Z' => assert_eq!(class, LineBreak::Alphabetic),
+            _ => {}
+        }
+
+        if matches!(class, LineBreak::H2 | LineBreak::H3) {
+            assert!(('\u{AC00}'..='\u{D7A3}').contains(&c), "{c:?}");
+        }
+
+        // the rules only ever see resolved classes
+        assert!(
+            !matches!(
+                resolve(utf8),
+                LineBreak::Ambiguous
+                    | LineBreak::Surrogate
+                    | LineBreak::Unknown
+                    | LineBreak::ComplexContext
+                    | LineBreak::ConditionalJapaneseStarter
+            ),
+            "{c:?}"
+        );
+    });
+}
+
+#[test]
+fn line_breaking() {
+    use alloc::string::String;
+
+    // `÷` marks allowed breaks, `!` mandatory ones
+    for expected in [
+        "",
+        "a!",
+        "Hello, ÷world!",
+        "a  ÷b  !",
+        "a\r\n!b\r!c\n!\n!",
+        // hyphens, but not minus signs
+        "well-÷known ÷-1 ÷x-!",
+        // no breaks before closing punctuation, or after opening punctuation
+        "(a) ÷[ b ]!",
+        "\u{201C}Hi\u{201D} ÷she ÷said.!",
+        "x ÷— ÷y÷——÷z!",
+        // numbers stay together with their prefixes and suffixes
+        "$(12.35) ÷12.35% ÷1,000!",
+        "x ÷.5 ÷http://÷a.b/÷c!",
+        // glue, word joiners and zero width spaces
+        "a\u{A0}b ÷c\u{2060}d!",
+        "a\u{200B}÷b\u{200B} ÷c!",
+        // combining marks and joiners stay with the codepoint before them
+        "e\u{301}x ÷\u{301}!",
+        "a\u{200D}\u{5B57}!",
+        // ideographs, except before small kana and closing punctuation
+        "\u{304C}÷\u{300C}\u{65E5}÷\u{672C}\u{300D}\u{3002}!",
+        "\u{30C6}\u{30A3}\u{30FC}÷\u{30C6}!",
+        // Hangul syllables break like ideographs, but conjoining jamo do not
+        "\u{D55C}÷\u{AD6D}÷\u{C5B4} ÷\u{1112}\u{1161}\u{11AB}!",
+        // flags pair up, emoji modifiers stay with their emoji
+        "🇦🇧÷🇨🇩÷🇪!",
+        "👍🏽÷👍!",
+        // Thai words take a dictionary to find
+        "\u{E44}\u{E17}\u{E22}!",
+    ] {
+        let s = expected.replace(['÷', '!'], "");
+        let mut actual = String::new();
+        let mut start = 0;
+
+        for (offset, opportunity) in line_breaks(&s) {
+            actual.push_str(&s[start..offset]);
+            actual.push(match opportunity {
+                BreakOpportunity::Mandatory => '!',
+                BreakOpportunity::Allowed => '÷',
+            });
+            start = offset;
+        }
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn conformance() {
+    use alloc::vec::Vec;
+
+    for &expected in crate::tables::conformance::LINE_BREAK_TEST {
+        let s = expected.concat();
+        let offsets = line_breaks(&s)
+            .map(|(offset, _)| offset)
+            .collect::<Vec<_>>();
+
+        // every break opportunity, which the test cases do not tell mandatory ones apart from
+        let expected = expected
+            .iter()
+            .scan(0, |offset, segment| {
+                *offset += segment.len();
+                Some(*offset)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(offsets, expected, "{s:?}");
+    }
+}
+
+#[test]
+fn wrapping() {
+    use alloc::vec::Vec;
+
+    for (s, max_width, expected) in [
+        ("", 10, &[][..]),
+        ("The quick brown fox", 10, &["The quick", "brown fox"]),
+        ("The quick brown fox", 100, &["The quick brown fox"]),
+        ("a\n\nb\r\n", 10, &["a", "", "b"]),
+        // spaces do not count towards the width at the end of a line
+        ("abc   def", 3, &["abc", "def"]),
+        // words too wide are broken between grapheme clusters
+        (
+            "supercalifragilistic",
+            6,
+            &["superc", "alifra", "gilist", "ic"],
+        ),
+        (
+            "e\u{301}e\u{301}e\u{301}",
+            2,
+            &["e\u{301}e\u{301}", "e\u{301}"],
+        ),
+        ("ab", 0, &["a", "b"]),
+        // wide codepoints take two columns
+        (
+            "\u{65E5}\u{672C}\u{8A9E}\u{306E}\u{30C6}\u{30AD}\u{30B9}\u{30C8}\u{3002}",
+            7,
+            &[
+                "\u{65E5}\u{672C}\u{8A9E}",
+                "\u{306E}\u{30C6}\u{30AD}",
+                "\u{30B9}\u{30C8}\u{3002}",
+            ],
+        ),
+        (
+            "\u{65E5}\u{672C} (abc) def",
+            10,
+            &["\u{65E5}\u{672C} (abc)", "def"],
+        ),
+    ] {
+        let lines = wrap(s, max_width).collect::<Vec<_>>();
+
+        assert_eq!(lines, expected, "{s:?}");
+
+        for line in lines {
+            assert!(display_width(line) <= max_width.max(1), "{line:?}");
+        }
+    }
+}
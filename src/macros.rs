@@ -0,0 +1,117 @@
+//! Compile time constructors for `Utf8Char`'s from string literals
+
+/// Creates a [`Utf8Char`][crate::Utf8Char] from a string literal containing exactly one codepoint.
+///
+/// The string is checked at compile time, failing to compile if it is empty or contains more than
+/// one codepoint. The macro expands to a constant, so it may be used in `const` and `static` items.
+///
+/// ```
+/// use utf8char::{utf8char, Utf8Char};
+///
+/// const E_ACUTE: Utf8Char = utf8char!("é");
+///
+/// assert_eq!(E_ACUTE, Utf8Char::from_char('é'));
+/// ```
+///
+/// ```compile_fail
+/// let _ = utf8char::utf8char!("ab");
+/// ```
+///
+/// ```compile_fail
+/// let _ = utf8char::utf8char!("");
+/// ```
+#[macro_export]
+macro_rules! utf8char {
+    ($s:expr $(,)?) => {
+        const {
+            match $crate::Utf8Char::from_str_exact($s) {
+                ::core::result::Result::Ok(ch) => ch,
+                ::core::result::Result::Err(e) => match e.kind() {
+                    $crate::ParseUtf8CharErrorKind::EmptyString => {
+                        ::core::panic!("utf8char! requires a codepoint, but the string was empty")
+                    }
+                    _ => ::core::panic!(
+                        "utf8char! requires exactly one codepoint, but the string contained more"
+                    ),
+                },
+            }
+        }
+    };
+}
+
+/// Creates a `[Utf8Char; N]` array of every codepoint in a string literal at compile time.
+///
+/// `N` is the amount of codepoints in the string. The macro expands to a constant, so it may be
+/// used in `const` and `static` items.
+///
+/// ```
+/// use utf8char::{utf8chars, Utf8Char};
+///
+/// const DELIMITERS: [Utf8Char; 4] = utf8chars!("(),→");
+///
+/// assert_eq!(DELIMITERS[3], Utf8Char::from_char('→'));
+/// assert_eq!(utf8chars!("").len(), 0);
+/// ```
+#[macro_export]
+macro_rules! utf8chars {
+    ($s:expr $(,)?) => {
+        const {
+            const S: &::core::primitive::str = $s;
+            const OUT: [$crate::Utf8Char; $crate::__private::count_codepoints(S)] =
+                $crate::__private::to_array(S);
+            OUT
+        }
+    };
+}
+
+/// Implementation details of the exported macros, not public API
+#[doc(hidden)]
+pub mod private {
+    use crate::{iter::is_continuation, Utf8Char};
+
+    /// Returns the amount of codepoints in a string
+    #[must_use]
+    pub const fn count_codepoints(s: &str) -> usize {
+        let bytes = s.as_bytes();
+        let mut idx = 0;
+        let mut count = 0;
+
+        while idx < bytes.len() {
+            if !is_continuation(bytes[idx]) {
+                count += 1;
+            }
+            idx += 1;
+        }
+
+        count
+    }
+
+    /// Splits a string into an array of its codepoints
+    ///
+    /// # Panics
+    /// Panics if `N` is not the amount of codepoints in `s`
+    #[must_use]
+    pub const fn to_array<const N: usize>(s: &str) -> [Utf8Char; N] {
+        assert!(
+            N == count_codepoints(s),
+            "array length must match codepoint count"
+        );
+
+        let mut out = [Utf8Char::from_char('\0'); N];
+        let mut rest = s.as_bytes();
+        let mut idx = 0;
+
+        while idx < N {
+            // SAFETY: rest always starts at a char boundary of s, there are N codepoints in s and we
+            // have consumed less than N of them so rest is not empty
+            let ch = unsafe {
+                Utf8Char::from_first_char_unchecked(core::str::from_utf8_unchecked(rest))
+            };
+            out[idx] = ch;
+            rest = rest.split_at(ch.len_utf8() as usize).1;
+            idx += 1;
+        }
+
+        out
+    }
+}
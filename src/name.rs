@@ -0,0 +1,478 @@
+//! Unicode character names, and looking codepoints up by name with the loose matching of
+//! [UAX #44](https://www.unicode.org/reports/tr44/#Matching_Names)
+
+use core::fmt::{self, Write};
+
+use crate::{
+    normalization::{S_BASE, S_COUNT, T_COUNT, V_COUNT},
+    tables::name::{
+        ALIASES, MAX_NAME_LEN, NAMES, NAME_BLOCK, NAME_BLOCKS, NAME_RUNS, PREFIXED, WORDS,
+        WORD_OFFSETS,
+    },
+    Utf8Char,
+};
+
+/// The short names of the leading consonant jamo, which start Hangul syllable names
+const JAMO_L: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+/// The short names of the vowel jamo
+const JAMO_V: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE",
+    "WI", "YU", "EU", "YI", "I",
+];
+/// The short names of the trailing consonant jamo, the first for syllables without one
+const JAMO_T: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+/// The `Name` property of a codepoint, written out by its `Display` implementation
+///
+/// Created by [`Utf8Char::name`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Name(NameInner);
+
+/// Where a name comes from
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum NameInner {
+    /// A name in `NAMES`, by where it starts
+    Listed(usize),
+    /// The name of a Hangul syllable, by its index from U+AC00
+    Hangul(u32),
+    /// A prefix followed by the hex codepoint
+    Prefixed(&'static str, u32),
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            NameInner::Listed(pos) => {
+                for (idx, word) in (Words { pos }).enumerate() {
+                    if idx > 0 {
+                        f.write_char(' ')?;
+                    }
+
+                    f.write_str(word)?;
+                }
+
+                Ok(())
+            }
+            NameInner::Hangul(syllable) => {
+                let lead = syllable / (V_COUNT * T_COUNT);
+                let vowel = syllable / T_COUNT % V_COUNT;
+                let tail = syllable % T_COUNT;
+
+                f.write_str("HANGUL SYLLABLE ")?;
+                f.write_str(JAMO_L[lead as usize])?;
+                f.write_str(JAMO_V[vowel as usize])?;
+                f.write_str(JAMO_T[tail as usize])
+            }
+            NameInner::Prefixed(prefix, code) => write!(f, "{prefix}{code:04X}"),
+        }
+    }
+}
+
+impl fmt::Debug for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Name")
+            .field(&format_args!("{self}"))
+            .finish()
+    }
+}
+
+/// An iterator over the words of a name in `NAMES`
+struct Words {
+    /// The next word id, or the 0 that ends the name
+    pos: usize,
+}
+
+impl Words {
+    /// Moves to the start of the next name
+    fn skip_name(&mut self) {
+        // only the end of a name is a 0 byte
+        while NAMES[self.pos] != 0 {
+            self.pos += 1;
+        }
+
+        self.pos += 1;
+    }
+}
+
+impl Iterator for Words {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = match NAMES[self.pos] {
+            0 => return None,
+            b @ 1..0x80 => {
+                self.pos += 1;
+                usize::from(b)
+            }
+            b => {
+                self.pos += 2;
+                0x80 + usize::from(b & 0x7F) * 255 + usize::from(NAMES[self.pos - 1]) - 1
+            }
+        };
+
+        Some(&WORDS[WORD_OFFSETS[id - 1] as usize..WORD_OFFSETS[id] as usize])
+    }
+}
+
+/// Normalizes a name for loose matching (UAX44-LM2), byte by byte: case, whitespace, underscores
+/// and medial hyphens are ignored
+struct Loose {
+    /// The byte before the current one
+    prev: u8,
+    /// Whether the previous byte is a hyphen after a letter or digit, which is medial and dropped
+    /// if a letter or digit follows it
+    hyphen: bool,
+}
+
+impl Loose {
+    /// Creates a normalizer at the start of a name
+    const fn new() -> Self {
+        Self {
+            prev: b' ',
+            hyphen: false,
+        }
+    }
+
+    /// Feeds a byte of the name, passing the normalized bytes on to `emit`. Returns false as soon
+    /// as `emit` does
+    fn push(&mut self, b: u8, emit: &mut impl FnMut(u8) -> bool) -> bool {
+        let prev = core::mem::replace(&mut self.prev, b);
+
+        if core::mem::take(&mut self.hyphen) && !b.is_ascii_alphanumeric() && !emit(b'-') {
+            return false;
+        }
+
+        match b {
+            b'-' if prev.is_ascii_alphanumeric() => {
+                self.hyphen = true;
+                true
+            }
+            b'_' => true,
+            _ if b.is_ascii_whitespace() => true,
+            _ => emit(b.to_ascii_uppercase()),
+        }
+    }
+
+    /// Ends the name, a hyphen at the end is not medial
+    fn finish(&mut self, emit: &mut impl FnMut(u8) -> bool) -> bool {
+        !core::mem::take(&mut self.hyphen) || emit(b'-')
+    }
+}
+
+/// Compares a name written to it against a normalized key, failing as soon as they differ
+struct Matcher<'key> {
+    /// The normalized name looked for
+    key: &'key [u8],
+    /// How much of the key has been matched
+    matched: usize,
+    /// The normalizer of the written name
+    loose: Loose,
+}
+
+impl<'key> Matcher<'key> {
+    /// Creates a matcher at the start of a name
+    const fn new(key: &'key [u8]) -> Self {
+        Self {
+            key,
+            matched: 0,
+            loose: Loose::new(),
+        }
+    }
+
+    /// Returns whether a name loosely matches the key
+    fn matches(key: &[u8], name: impl fmt::Display) -> bool {
+        let mut matcher = Matcher::new(key);
+
+        write!(matcher, "{name}").is_ok()
+            && matcher
+                .loose
+                .finish(&mut compare(matcher.key, &mut matcher.matched))
+            && matcher.matched == key.len()
+    }
+}
+
+/// Returns an `emit` function for `Loose` that compares against the next byte of a key
+fn compare<'key>(key: &'key [u8], matched: &'key mut usize) -> impl FnMut(u8) -> bool + 'key {
+    |b| {
+        let equal = key.get(*matched) == Some(&b);
+        *matched += 1;
+        equal
+    }
+}
+
+impl Write for Matcher<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut emit = compare(self.key, &mut self.matched);
+
+        if s.bytes().all(|b| self.loose.push(b, &mut emit)) {
+            Ok(())
+        } else {
+            Err(fmt::Error)
+        }
+    }
+}
+
+/// Normalizes a name for loose matching into `buf`, returns None if it is empty or too long to
+/// match any name
+fn normalize<'buf>(name: &str, buf: &'buf mut [u8; MAX_NAME_LEN]) -> Option<&'buf [u8]> {
+    let mut len = 0;
+    let mut loose = Loose::new();
+    let mut emit = |b| {
+        let Some(slot) = buf.get_mut(len) else {
+            return false;
+        };
+
+        *slot = b;
+        len += 1;
+        true
+    };
+
+    if !name.bytes().all(|b| loose.push(b, &mut emit)) || !loose.finish(&mut emit) || len == 0 {
+        return None;
+    }
+
+    Some(&buf[..len])
+}
+
+/// Strips a prefix from a normalized key, the prefix being normalized too
+fn strip_prefix<'key>(key: &'key [u8], prefix: &str) -> Option<&'key [u8]> {
+    prefix
+        .bytes()
+        .filter(u8::is_ascii_alphanumeric)
+        .try_fold(key, |key, b| key.strip_prefix(&[b]))
+}
+
+impl Utf8Char {
+    /// Returns the `Name` of this codepoint, None for codepoints without one like control
+    /// codepoints, private use codepoints and unassigned codepoints
+    ///
+    /// This includes the names derived from the codepoint, of Hangul syllables and of CJK ideographs
+    /// and the like.
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// let name = |ch: utf8char::Utf8Char| ch.name().map(|name| name.to_string());
+    ///
+    /// assert_eq!(name(utf8char!("\u{3B1}")).as_deref(), Some("GREEK SMALL LETTER ALPHA"));
+    /// assert_eq!(name(utf8char!("\u{200B}")).as_deref(), Some("ZERO WIDTH SPACE"));
+    /// assert_eq!(name(utf8char!("\u{AC01}")).as_deref(), Some("HANGUL SYLLABLE GAG"));
+    /// assert_eq!(name(utf8char!("\u{4E00}")).as_deref(), Some("CJK UNIFIED IDEOGRAPH-4E00"));
+    /// assert_eq!(name(utf8char!("\0")), None);
+    /// ```
+    #[must_use]
+    pub fn name(self) -> Option<Name> {
+        let code = self.to_u32();
+
+        if let Some(s) = code.checked_sub(S_BASE).filter(|&s| s < S_COUNT) {
+            return Some(Name(NameInner::Hangul(s)));
+        }
+
+        let bits = self.to_bits();
+
+        if let Some(&(.., prefix)) = PREFIXED
+            .iter()
+            .find(|&&(first, last, _)| (first..=last).contains(&bits))
+        {
+            return Some(Name(NameInner::Prefixed(prefix, code)));
+        }
+
+        // the run this codepoint is in, if it is in any
+        let run = NAME_RUNS.partition_point(|&(first, _)| first <= code);
+        let (first, start) = NAME_RUNS[run.checked_sub(1)?];
+        let (_, end) = *NAME_RUNS.get(run)?;
+        let ordinal = usize::from(start) + (code - first) as usize;
+
+        if ordinal >= usize::from(end) {
+            return None;
+        }
+
+        let mut words = Words {
+            pos: NAME_BLOCKS[ordinal / NAME_BLOCK] as usize,
+        };
+
+        for _ in 0..ordinal % NAME_BLOCK {
+            words.skip_name();
+        }
+
+        Some(Name(NameInner::Listed(words.pos)))
+    }
+
+    /// Looks a codepoint up by its name or one of its formal aliases, like the `\N{...}` escapes
+    /// of some languages
+    ///
+    /// Names are matched loosely, as UAX #44 recommends: case, whitespace, underscores and hyphens
+    /// between letters or digits are ignored.
+    ///
+    /// ```
+    /// use utf8char::{utf8char, Utf8Char};
+    ///
+    /// assert_eq!(Utf8Char::from_name("GREEK SMALL LETTER ALPHA"), Some(utf8char!("\u{3B1}")));
+    /// assert_eq!(Utf8Char::from_name("greek_small_letter_alpha"), Some(utf8char!("\u{3B1}")));
+    /// assert_eq!(Utf8Char::from_name("zero width space"), Some(utf8char!("\u{200B}")));
+    /// // aliases work too
+    /// assert_eq!(Utf8Char::from_name("ZWSP"), Some(utf8char!("\u{200B}")));
+    /// assert_eq!(Utf8Char::from_name("NULL"), Some(utf8char!("\0")));
+    /// assert_eq!(Utf8Char::from_name("cjk unified ideograph-4e00"), Some(utf8char!("\u{4E00}")));
+    /// assert_eq!(Utf8Char::from_name("GREEK SMALL LETTER ALPH"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let mut buf = [0; MAX_NAME_LEN];
+        let key = normalize(name, &mut buf)?;
+
+        // the one hyphen loose matching keeps, as U+1180 would collide with U+116C without it
+        if key == b"HANGULJUNGSEONGOE" {
+            let hyphenated = name
+                .bytes()
+                .rev()
+                .filter(|&b| b != b'_' && !b.is_ascii_whitespace())
+                .take(3)
+                .map(|b| b.to_ascii_uppercase())
+                .eq(*b"E-O");
+
+            return Some(Self::from_char(if hyphenated {
+                '\u{1180}'
+            } else {
+                '\u{116C}'
+            }));
+        }
+
+        if let Some(ch) = Self::from_derived_name(key) {
+            return Some(ch);
+        }
+
+        let mut words = Words { pos: 0 };
+
+        for run in NAME_RUNS.windows(2) {
+            let ((first, start), (_, end)) = (run[0], run[1]);
+
+            for code in first..first + u32::from(end - start) {
+                let pos = words.pos;
+
+                // most names can be told apart by their first word
+                if words
+                    .next()
+                    .is_some_and(|word| Matcher::new(key).write_str(word).is_ok())
+                    && Matcher::matches(key, Name(NameInner::Listed(pos)))
+                {
+                    return Self::from_u32(code);
+                }
+
+                words.skip_name();
+            }
+        }
+
+        ALIASES
+            .iter()
+            .find(|(alias, _)| Matcher::matches(key, alias))
+            .and_then(|&(_, bits)| Self::from_bits(bits))
+    }
+
+    /// Looks a codepoint up by the normalized form of a name derived from the codepoint, of a
+    /// Hangul syllable or a prefix followed by the hex codepoint
+    fn from_derived_name(key: &[u8]) -> Option<Self> {
+        if let Some(jamo) = strip_prefix(key, "HANGUL SYLLABLE") {
+            // the leading consonant can be left out, so try every split
+            let syllable = (0..).zip(JAMO_L).find_map(|(lead, name)| {
+                let jamo = jamo.strip_prefix(name.as_bytes())?;
+
+                (0..).zip(JAMO_V).find_map(|(vowel, name)| {
+                    let jamo = jamo.strip_prefix(name.as_bytes())?;
+                    let (tail, _) = (0..)
+                        .zip(JAMO_T)
+                        .find(|(_, name)| name.as_bytes() == jamo)?;
+
+                    Some((lead * V_COUNT + vowel) * T_COUNT + tail)
+                })
+            });
+
+            return syllable.and_then(|s| Self::from_u32(S_BASE + s));
+        }
+
+        PREFIXED.iter().find_map(|&(first, last, prefix)| {
+            let hex = strip_prefix(key, prefix)?;
+
+            // the codepoint is written with at least 4 digits, and without leading zeros beyond
+            if hex.len() < 4
+                || hex.len() > 4 && hex[0] == b'0'
+                || !hex.iter().all(u8::is_ascii_hexdigit)
+            {
+                return None;
+            }
+
+            let code = u32::from_str_radix(core::str::from_utf8(hex).ok()?, 16).ok()?;
+            let ch = Self::from_u32(code)?;
+
+            (first..=last).contains(&ch.to_bits()).then_some(ch)
+        })
+    }
+}
+
+#[test]
+fn round_trip() {
+    use alloc::string::ToString;
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let ch = Utf8Char::from_char(c);
+
+        if let Some(name) = ch.name() {
+            assert_eq!(Utf8Char::from_name(&name.to_string()), Some(ch), "{name:?}");
+        }
+    });
+}
+
+#[test]
+fn loose_matching() {
+    for (name, ch) in [
+        ("LATIN SMALL LETTER A", Some('a')),
+        ("latin small letter a", Some('a')),
+        ("LatinSmallLetterA", Some('a')),
+        ("  latin_small_letter_a  ", Some('a')),
+        ("LATIN SMALL LETTER", None),
+        ("LATIN SMALL LETTER AB", None),
+        ("", None),
+        // medial hyphens are ignored, others are not
+        ("HYPHEN-MINUS", Some('-')),
+        ("hyphen minus", Some('-')),
+        ("TIBETAN LETTER -A", Some('\u{F60}')),
+        ("TIBETAN LETTER A", Some('\u{F68}')),
+        ("TIBETAN LETTER A-", None),
+        // except the one telling U+1180 and U+116C apart
+        ("HANGUL JUNGSEONG O-E", Some('\u{1180}')),
+        ("hangul jungseong o-e", Some('\u{1180}')),
+        ("HANGUL JUNGSEONG OE", Some('\u{116C}')),
+        // derived names
+        ("HANGUL SYLLABLE GA", Some('\u{AC00}')),
+        ("hangul syllable a", Some('\u{C544}')),
+        ("HANGUL SYLLABLE GX", None),
+        ("CJK UNIFIED IDEOGRAPH-20000", Some('\u{20000}')),
+        ("CJK UNIFIED IDEOGRAPH-020000", None),
+        ("CJK UNIFIED IDEOGRAPH-+4E00", None),
+        ("CJK UNIFIED IDEOGRAPH-0041", None),
+        ("TANGUT IDEOGRAPH-17000", Some('\u{17000}')),
+        // aliases, of control codepoints and corrections
+        ("LINE FEED", Some('\n')),
+        ("lf", Some('\n')),
+        ("BYTE ORDER MARK", Some('\u{FEFF}')),
+        ("LATIN CAPITAL LETTER GHA", Some('\u{1A2}')),
+        ("LATIN CAPITAL LETTER OI", Some('\u{1A2}')),
+    ] {
+        assert_eq!(
+            Utf8Char::from_name(name),
+            ch.map(Utf8Char::from_char),
+            "{name:?}"
+        );
+    }
+
+    assert_eq!(Utf8Char::from_char('\0').name(), None);
+    assert_eq!(Utf8Char::from_char('\u{E000}').name(), None);
+    assert_eq!(Utf8Char::from_char('\u{10FFFF}').name(), None);
+}
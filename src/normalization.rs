@@ -0,0 +1,987 @@
+//! Unicode normalization of strings, as defined in [UAX #15](https://www.unicode.org/reports/tr15/)
+//!
+//! Single codepoints are decomposed with [`Utf8Char::decompose_canonical`] and
+//! [`Utf8Char::decompose_compatible`], and composed with [`Utf8Char::compose`]. Whole strings are
+//! normalized by the adapters of [`Normalize`], which turn any iterator of `Utf8Char`'s, like a
+//! [`Utf8CharIter`], into a stream of its normalized codepoints:
+//!
+//! ```
+//! use utf8char::{normalization::Normalize, IntoUtf8Chars};
+//!
+//! assert_eq!("e\u{301}".utf8_chars().nfc().to_string(), "\u{E9}");
+//! assert_eq!("\u{E9}".utf8_chars().nfd().to_string(), "e\u{301}");
+//! assert_eq!("\u{FB01}".utf8_chars().nfkc().to_string(), "fi");
+//! assert_eq!("Stra\u{DF}e".utf8_chars().nfkc_casefold().to_string(), "strasse");
+//! ```
+//!
+//! Normalizing allocates nothing, which bounds how many combining marks can be reordered at once:
+//! the output is in the Stream-Safe Text Format, a U+034F COMBINING GRAPHEME JOINER is inserted
+//! before a codepoint that would make a run of more than 30 non-starters. Such runs never occur in
+//! text of any real language.
+//!
+//! Checking whether a string is already normalized, with [`is_nfc`] and friends, is cheap for the
+//! common case: ascii and most other codepoints are settled by the quick check properties alone.
+
+use core::{
+    fmt,
+    iter::{Fuse, FusedIterator},
+};
+
+use crate::{
+    inline::{inline_str_iter, InlineStr},
+    tables::{
+        normalization::{
+            CANONICAL_COMBINING_CLASS, CANONICAL_DECOMPOSITION, COMPATIBLE_DECOMPOSITION,
+            COMPOSITIONS, DECOMPOSITIONS, MAX_DECOMPOSITION_LEN, NFC_QUICK_CHECK, NFKC_CASEFOLD,
+            NFKC_CASEFOLDS, NFKC_QUICK_CHECK,
+        },
+        Utf8Trie, LEVEL,
+    },
+    Utf8Char, Utf8CharInner, Utf8CharIter,
+};
+
+/// The first precomposed Hangul syllable, U+AC00
+pub(crate) const S_BASE: u32 = 0xAC00;
+/// The first leading consonant jamo, U+1100
+const L_BASE: u32 = 0x1100;
+/// The first vowel jamo, U+1161
+const V_BASE: u32 = 0x1161;
+/// One before the first trailing consonant jamo, U+11A8, as syllables without one count as 0
+const T_BASE: u32 = 0x11A7;
+/// The amount of leading consonant jamo
+const L_COUNT: u32 = 19;
+/// The amount of vowel jamo
+pub(crate) const V_COUNT: u32 = 21;
+/// The amount of trailing consonant jamo, plus one for syllables without one
+pub(crate) const T_COUNT: u32 = 28;
+/// The amount of precomposed Hangul syllables
+pub(crate) const S_COUNT: u32 = L_COUNT * V_COUNT * T_COUNT;
+
+/// The most non-starters the Stream-Safe Text Format allows in a row
+const MAX_NON_STARTERS: usize = 30;
+
+/// U+034F COMBINING GRAPHEME JOINER, inserted to break up overly long runs of non-starters
+const CGJ: Utf8Char = Utf8Char::from_char('\u{34F}');
+
+/// The result of a normalization quick check
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum QuickCheck {
+    /// The string is normalized
+    Yes,
+    /// The string is not normalized
+    No,
+    /// The string may or may not be normalized, only normalizing it can tell
+    Maybe,
+}
+
+/// An iterator over the `Utf8Char`'s a codepoint decomposes to.
+///
+/// Returned by [`Utf8Char::decompose_canonical`] and [`Utf8Char::decompose_compatible`]. The
+/// decomposition is full, every codepoint in it is itself fully decomposed, and in canonical order.
+/// Codepoints without a decomposition decompose to themselves.
+///
+/// The codepoints that have not been iterated over yet are available as a `&str` through `Deref`.
+#[derive(Clone)]
+pub struct Decomposition(InlineStr<MAX_DECOMPOSITION_LEN>);
+
+impl Decomposition {
+    /// Looks up a codepoint in a decomposition table, ascii must already be handled
+    const fn lookup(ch: Utf8Char, table: &Utf8Trie<[u16; LEVEL]>) -> Self {
+        if let Some(decomposition) = Self::hangul(ch) {
+            return decomposition;
+        }
+
+        match table.get(ch) {
+            0 => Self(InlineStr::new(ch.as_str())),
+            idx => Self(InlineStr::new(DECOMPOSITIONS[idx as usize])),
+        }
+    }
+
+    /// Decomposes a precomposed Hangul syllable algorithmically into its 2 or 3 jamo
+    const fn hangul(ch: Utf8Char) -> Option<Self> {
+        let Some(s) = ch.to_u32().checked_sub(S_BASE) else {
+            return None;
+        };
+
+        if s >= S_COUNT {
+            return None;
+        }
+
+        let jamo = [
+            L_BASE + s / (V_COUNT * T_COUNT),
+            V_BASE + s % (V_COUNT * T_COUNT) / T_COUNT,
+            T_BASE + s % T_COUNT,
+        ];
+        let (count, len) = if jamo[2] == T_BASE { (2, 6) } else { (3, 9) };
+
+        let mut buf = [0; MAX_DECOMPOSITION_LEN];
+        let mut idx = 0;
+
+        while idx < count {
+            // SAFETY: every jamo is in U+1100..=U+11FF, and so a valid 3 byte codepoint
+            let bytes = unsafe { Utf8Char::from_u32_unchecked(jamo[idx]) }.to_bytes();
+
+            buf[idx * 3] = bytes[0];
+            buf[idx * 3 + 1] = bytes[1];
+            buf[idx * 3 + 2] = bytes[2];
+            idx += 1;
+        }
+
+        // SAFETY: buf holds len bytes of whole jamo, and MAX_DECOMPOSITION_LEN is at least 9
+        Some(Self(unsafe { InlineStr::from_raw_parts(buf, len) }))
+    }
+}
+
+inline_str_iter!(Decomposition);
+
+impl Utf8Char {
+    /// Returns the `Canonical_Combining_Class` of this codepoint.
+    ///
+    /// Starters, the codepoints that never reorder during normalization, have class 0. Combining
+    /// marks with a non-zero class are sorted by it when normalizing, for instance a cedilla (202)
+    /// always comes before an acute accent (230).
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(utf8char!("a").canonical_combining_class(), 0);
+    /// assert_eq!(utf8char!("\u{327}").canonical_combining_class(), 202);
+    /// assert_eq!(utf8char!("\u{301}").canonical_combining_class(), 230);
+    /// ```
+    #[must_use]
+    pub const fn canonical_combining_class(self) -> u8 {
+        if self.is_ascii() {
+            return 0;
+        }
+
+        CANONICAL_COMBINING_CLASS.get(self)
+    }
+
+    /// Returns an iterator over the full canonical decomposition of this codepoint, its NFD.
+    ///
+    /// Hangul syllables decompose to their jamo.
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(&*utf8char!("\u{E9}").decompose_canonical(), "e\u{301}");
+    /// // ANGSTROM SIGN
+    /// assert_eq!(&*utf8char!("\u{212B}").decompose_canonical(), "A\u{30A}");
+    /// assert_eq!(&*utf8char!("\u{D55C}").decompose_canonical(), "\u{1112}\u{1161}\u{11AB}");
+    /// // compatibility decompositions are left alone
+    /// assert_eq!(&*utf8char!("\u{FB01}").decompose_canonical(), "\u{FB01}");
+    /// ```
+    #[must_use]
+    pub const fn decompose_canonical(self) -> Decomposition {
+        if self.is_ascii() {
+            return Decomposition(InlineStr::new(self.as_str()));
+        }
+
+        Decomposition::lookup(self, &CANONICAL_DECOMPOSITION)
+    }
+
+    /// Returns an iterator over the full compatibility decomposition of this codepoint, its NFKD.
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(&*utf8char!("\u{FB01}").decompose_compatible(), "fi");
+    /// assert_eq!(&*utf8char!("\u{2460}").decompose_compatible(), "1");
+    /// assert_eq!(&*utf8char!("\u{1E9B}").decompose_compatible(), "s\u{307}");
+    /// ```
+    #[must_use]
+    pub const fn decompose_compatible(self) -> Decomposition {
+        if self.is_ascii() {
+            return Decomposition(InlineStr::new(self.as_str()));
+        }
+
+        Decomposition::lookup(self, &COMPATIBLE_DECOMPOSITION)
+    }
+
+    /// Returns the primary composite of this codepoint followed by `next`, if there is one.
+    ///
+    /// This is the pairwise composition of the canonical composition algorithm, so codepoints
+    /// excluded from composition are never returned. Hangul jamo compose algorithmically.
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(utf8char!("e").compose(utf8char!("\u{301}")), Some(utf8char!("\u{E9}")));
+    /// assert_eq!(utf8char!("\u{1112}").compose(utf8char!("\u{1161}")), Some(utf8char!("\u{D558}")));
+    /// assert_eq!(utf8char!("\u{D558}").compose(utf8char!("\u{11AB}")), Some(utf8char!("\u{D55C}")));
+    /// assert_eq!(utf8char!("e").compose(utf8char!("f")), None);
+    /// ```
+    #[must_use]
+    pub const fn compose(self, next: Self) -> Option<Self> {
+        // no composition has an ascii second half
+        if next.is_ascii() {
+            return None;
+        }
+
+        let (first, second) = (self.to_u32(), next.to_u32());
+
+        // a leading consonant and a vowel make an LV syllable
+        if first.wrapping_sub(L_BASE) < L_COUNT && second.wrapping_sub(V_BASE) < V_COUNT {
+            let lv = S_BASE + ((first - L_BASE) * V_COUNT + second - V_BASE) * T_COUNT;
+
+            // SAFETY: lv is a precomposed Hangul syllable
+            return Some(unsafe { Self::from_u32_unchecked(lv) });
+        }
+
+        // an LV syllable and a trailing consonant make an LVT syllable
+        if first.wrapping_sub(S_BASE) < S_COUNT
+            && (first - S_BASE) % T_COUNT == 0
+            && second.wrapping_sub(T_BASE + 1) < T_COUNT - 1
+        {
+            // SAFETY: adding a trailing consonant to an LV syllable stays within the syllables
+            return Some(unsafe { Self::from_u32_unchecked(first + second - T_BASE) });
+        }
+
+        let (first, second) = (self.to_bits(), next.to_bits());
+        let (mut lo, mut hi) = (0, COMPOSITIONS.len());
+
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            let (a, b, composite) = COMPOSITIONS[mid];
+
+            if a == first && b == second {
+                // SAFETY: COMPOSITIONS holds the to_bits representation of valid codepoints
+                return Some(Self(unsafe {
+                    Utf8CharInner::from_utf8char_array(composite.to_be_bytes())
+                }));
+            } else if a < first || (a == first && b < second) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+
+        None
+    }
+}
+
+/// The decomposition a [`Decompositions`] applies
+#[derive(Copy, Clone, Debug)]
+enum Form {
+    /// Canonical decomposition, NFD
+    Canonical,
+    /// Compatibility decomposition, NFKD
+    Compatible,
+}
+
+/// The amount of codepoints a [`Decompositions`] can buffer, which must fit a run of
+/// [`MAX_NON_STARTERS`] plus the longest decomposition and a [`CGJ`]
+const BUFFER_LEN: usize = 64;
+
+/// An iterator that decomposes and canonically orders a stream of codepoints, to NFD or NFKD.
+///
+/// Created by [`Normalize::nfd`] and [`Normalize::nfkd`].
+#[derive(Clone)]
+pub struct Decompositions<I> {
+    /// The codepoints left to decompose
+    iter: Fuse<I>,
+    /// Which decomposition to apply
+    form: Form,
+    /// Decomposed codepoints with their canonical combining class, `..ready` are in their final
+    /// order and `ready..len` are non-starters waiting for the next starter
+    buffer: [(Utf8Char, u8); BUFFER_LEN],
+    /// The amount of codepoints in the buffer
+    len: usize,
+    /// The amount of codepoints at the start of the buffer that are in their final order
+    ready: usize,
+    /// The amount of ready codepoints that have already been yielded
+    emitted: usize,
+    /// The amount of non-starters at the end of the output so far
+    non_starters: usize,
+}
+
+impl<I: Iterator<Item = Utf8Char>> Decompositions<I> {
+    /// Creates the adapter over `iter`
+    fn new(iter: I, form: Form) -> Self {
+        Self {
+            iter: iter.fuse(),
+            form,
+            buffer: [(Utf8Char::MIN, 0); BUFFER_LEN],
+            len: 0,
+            ready: 0,
+            emitted: 0,
+            non_starters: 0,
+        }
+    }
+
+    /// Adds one decomposed codepoint to the buffer, inserting non-starters in canonical order
+    fn push(&mut self, ch: Utf8Char, class: u8) {
+        if class == 0 {
+            self.buffer[self.len] = (ch, 0);
+            self.len += 1;
+            self.ready = self.len;
+            self.non_starters = 0;
+            return;
+        }
+
+        // a stable insertion after every pending non-starter of a lower or equal class
+        let mut idx = self.len;
+
+        while idx > self.ready && self.buffer[idx - 1].1 > class {
+            idx -= 1;
+        }
+
+        self.buffer.copy_within(idx..self.len, idx + 1);
+        self.buffer[idx] = (ch, class);
+        self.len += 1;
+        self.non_starters += 1;
+    }
+
+    /// Adds the decomposition of a codepoint to the buffer
+    fn push_decomposition(&mut self, ch: Utf8Char) {
+        let decomposition = match self.form {
+            Form::Canonical => ch.decompose_canonical(),
+            Form::Compatible => ch.decompose_compatible(),
+        };
+
+        let leading = decomposition
+            .clone()
+            .take_while(|ch| ch.canonical_combining_class() != 0)
+            .count();
+
+        if self.non_starters + leading > MAX_NON_STARTERS {
+            self.push(CGJ, 0);
+        }
+
+        for ch in decomposition {
+            self.push(ch, ch.canonical_combining_class());
+        }
+    }
+}
+
+impl<I: Iterator<Item = Utf8Char>> Iterator for Decompositions<I> {
+    type Item = Utf8Char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.emitted < self.ready {
+                self.emitted += 1;
+
+                return Some(self.buffer[self.emitted - 1].0);
+            }
+
+            // everything ready is yielded, move the pending non-starters to the front
+            self.buffer.copy_within(self.ready..self.len, 0);
+            self.len -= self.ready;
+            self.ready = 0;
+            self.emitted = 0;
+
+            let Some(ch) = self.iter.next() else {
+                if self.len == 0 {
+                    return None;
+                }
+
+                self.ready = self.len;
+                continue;
+            };
+
+            // nothing can reorder before a starter, so lone ascii passes straight through
+            if ch.is_ascii() && self.len == 0 {
+                self.non_starters = 0;
+                return Some(ch);
+            }
+
+            self.push_decomposition(ch);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // every codepoint decomposes to at least one codepoint
+        let (lower, _) = self.iter.size_hint();
+
+        (lower.saturating_add(self.len - self.emitted), None)
+    }
+}
+
+impl<I: Iterator<Item = Utf8Char>> FusedIterator for Decompositions<I> {}
+
+impl<I: Iterator<Item = Utf8Char> + Clone> fmt::Display for Decompositions<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.clone().try_for_each(|ch| f.write_str(&ch))
+    }
+}
+
+/// An iterator that canonically composes a canonically decomposed stream of codepoints, to NFC or
+/// NFKC.
+///
+/// Created by [`Normalize::nfc`] and [`Normalize::nfkc`].
+#[derive(Clone)]
+pub struct Recompositions<I> {
+    /// The decomposed codepoints left to compose
+    iter: Fuse<I>,
+    /// Composed codepoints, `..ready` are final and `ready..len` are the last starter and the
+    /// non-starters after it that did not combine with it
+    buffer: [Utf8Char; BUFFER_LEN],
+    /// The amount of codepoints in the buffer
+    len: usize,
+    /// The amount of codepoints at the start of the buffer that are final
+    ready: usize,
+    /// The amount of final codepoints that have already been yielded
+    emitted: usize,
+    /// The index of the starter later codepoints may combine with
+    starter: Option<usize>,
+    /// The canonical combining class of the last codepoint in the buffer
+    last_class: u8,
+}
+
+impl<I: Iterator<Item = Utf8Char>> Recompositions<I> {
+    /// Creates the adapter over `iter`, which must be canonically decomposed
+    fn new(iter: I) -> Self {
+        Self {
+            iter: iter.fuse(),
+            buffer: [Utf8Char::MIN; BUFFER_LEN],
+            len: 0,
+            ready: 0,
+            emitted: 0,
+            starter: None,
+            last_class: 0,
+        }
+    }
+}
+
+impl<I: Iterator<Item = Utf8Char>> Iterator for Recompositions<I> {
+    type Item = Utf8Char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.emitted < self.ready {
+                self.emitted += 1;
+
+                return Some(self.buffer[self.emitted - 1]);
+            }
+
+            // everything final is yielded, move the last starter and its marks to the front
+            self.buffer.copy_within(self.ready..self.len, 0);
+            self.len -= self.ready;
+            self.starter = self.starter.map(|idx| idx - self.ready);
+            self.ready = 0;
+            self.emitted = 0;
+
+            // only input that is not stream safe can fill the buffer, flush it before taking the
+            // next codepoint rather than overflow
+            if self.len == BUFFER_LEN {
+                self.ready = self.len;
+                self.starter = None;
+                continue;
+            }
+
+            let Some(ch) = self.iter.next() else {
+                if self.len == 0 {
+                    return None;
+                }
+
+                self.ready = self.len;
+                self.starter = None;
+                continue;
+            };
+
+            let class = ch.canonical_combining_class();
+
+            if let Some(starter) = self.starter {
+                // a codepoint is blocked from the starter by any codepoint in between of a class
+                // that is 0 or not lower than its own
+                let blocked =
+                    self.len > starter + 1 && (self.last_class == 0 || self.last_class >= class);
+
+                if !blocked {
+                    if let Some(composite) = self.buffer[starter].compose(ch) {
+                        self.buffer[starter] = composite;
+                        continue;
+                    }
+                }
+            }
+
+            if class == 0 {
+                // a new starter finalizes everything before it
+                self.ready = self.len;
+                self.starter = Some(self.len);
+            }
+
+            self.buffer[self.len] = ch;
+            self.len += 1;
+            self.last_class = class;
+        }
+    }
+}
+
+impl<I: Iterator<Item = Utf8Char>> FusedIterator for Recompositions<I> {}
+
+impl<I: Iterator<Item = Utf8Char> + Clone> fmt::Display for Recompositions<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.clone().try_for_each(|ch| f.write_str(&ch))
+    }
+}
+
+/// An iterator that replaces every codepoint of a canonically decomposed stream with its
+/// `NFKC_Casefold` mapping
+#[derive(Clone)]
+struct CaseFolded<I> {
+    /// The codepoints left to map
+    iter: I,
+    /// The rest of the mapping of the last codepoint
+    mapping: Utf8CharIter<'static>,
+}
+
+impl<I: Iterator<Item = Utf8Char>> Iterator for CaseFolded<I> {
+    type Item = Utf8Char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ch) = self.mapping.next() {
+                return Some(ch);
+            }
+
+            let ch = self.iter.next()?;
+
+            if ch.is_ascii() {
+                return Some(ch.to_ascii_lowercase());
+            }
+
+            match NFKC_CASEFOLD.get(ch) {
+                0 => return Some(ch),
+                idx => self.mapping = Utf8CharIter::new(NFKC_CASEFOLDS[idx as usize]),
+            }
+        }
+    }
+}
+
+/// An iterator that normalizes a stream of codepoints to `NFKC_Casefold`, the NFKC of its full case
+/// folding with default ignorable codepoints removed.
+///
+/// Created by [`Normalize::nfkc_casefold`].
+#[derive(Clone)]
+pub struct NfkcCasefold<I>(
+    /// `NFC(NFKC_Casefold(NFD(iter)))`, as `toNFKC_Casefold` is defined
+    Recompositions<Decompositions<CaseFolded<Decompositions<I>>>>,
+);
+
+impl<I: Iterator<Item = Utf8Char>> Iterator for NfkcCasefold<I> {
+    type Item = Utf8Char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl<I: Iterator<Item = Utf8Char>> FusedIterator for NfkcCasefold<I> {}
+
+impl<I: Iterator<Item = Utf8Char> + Clone> fmt::Display for NfkcCasefold<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Normalization adapters for iterators of `Utf8Char`'s, like [`Utf8CharIter`]
+///
+/// ```
+/// use utf8char::{normalization::Normalize, IntoUtf8Chars};
+///
+/// // the same name, typed as a precomposed codepoint and as a combining mark
+/// let (a, b) = ("Ren\u{E9}e", "Rene\u{301}e");
+///
+/// assert_ne!(a, b);
+/// assert!(a.utf8_chars().nfc().eq(b.utf8_chars().nfc()));
+/// ```
+pub trait Normalize: Iterator<Item = Utf8Char> + Sized {
+    /// Returns an iterator over the canonical decomposition of these codepoints, Normalization
+    /// Form D
+    fn nfd(self) -> Decompositions<Self> {
+        Decompositions::new(self, Form::Canonical)
+    }
+
+    /// Returns an iterator over the compatibility decomposition of these codepoints, Normalization
+    /// Form KD
+    fn nfkd(self) -> Decompositions<Self> {
+        Decompositions::new(self, Form::Compatible)
+    }
+
+    /// Returns an iterator over the canonical composition of these codepoints, Normalization Form C
+    fn nfc(self) -> Recompositions<Decompositions<Self>> {
+        Recompositions::new(self.nfd())
+    }
+
+    /// Returns an iterator over the compatibility composition of these codepoints, Normalization
+    /// Form KC
+    fn nfkc(self) -> Recompositions<Decompositions<Self>> {
+        Recompositions::new(self.nfkd())
+    }
+
+    /// Returns an iterator over the `NFKC_Casefold` normalization of these codepoints, which is
+    /// suited for caseless matching of identifiers (see UAX #31)
+    fn nfkc_casefold(self) -> NfkcCasefold<Self> {
+        let folded = CaseFolded {
+            iter: self.nfd(),
+            mapping: Utf8CharIter::new(""),
+        };
+
+        NfkcCasefold(Recompositions::new(Decompositions::new(
+            folded,
+            Form::Canonical,
+        )))
+    }
+}
+
+impl<I: Iterator<Item = Utf8Char>> Normalize for I {}
+
+/// Runs the quick check algorithm of UAX #15 over a string, with the quick check property of a
+/// single non-ascii codepoint
+fn quick_check(s: &str, property: impl Fn(Utf8Char) -> QuickCheck) -> QuickCheck {
+    // ascii is normalized in every form, and resets the canonical ordering
+    let start = s.bytes().position(|b| !b.is_ascii()).unwrap_or(s.len());
+
+    let mut result = QuickCheck::Yes;
+    let mut last_class = 0;
+
+    for ch in Utf8CharIter::new(&s[start..]) {
+        if ch.is_ascii() {
+            last_class = 0;
+            continue;
+        }
+
+        let class = ch.canonical_combining_class();
+
+        // combining marks out of canonical order
+        if last_class > class && class != 0 {
+            return QuickCheck::No;
+        }
+
+        match property(ch) {
+            QuickCheck::Yes => {}
+            QuickCheck::No => return QuickCheck::No,
+            QuickCheck::Maybe => result = QuickCheck::Maybe,
+        }
+
+        last_class = class;
+    }
+
+    result
+}
+
+/// Runs the NFC quick check algorithm of UAX #15 over a string.
+///
+/// `Maybe` is only returned for strings containing codepoints that may combine with the codepoint
+/// before them, use [`is_nfc`] for a definite answer.
+///
+/// ```
+/// use utf8char::normalization::{quick_check_nfc, QuickCheck};
+///
+/// assert_eq!(quick_check_nfc("caf\u{E9}"), QuickCheck::Yes);
+/// assert_eq!(quick_check_nfc("cafe\u{301}"), QuickCheck::Maybe);
+/// assert_eq!(quick_check_nfc("\u{212B}"), QuickCheck::No);
+/// ```
+#[must_use]
+pub fn quick_check_nfc(s: &str) -> QuickCheck {
+    quick_check(s, |ch| NFC_QUICK_CHECK.get(ch))
+}
+
+/// Runs the NFKC quick check algorithm of UAX #15 over a string, see [`quick_check_nfc`]
+#[must_use]
+pub fn quick_check_nfkc(s: &str) -> QuickCheck {
+    quick_check(s, |ch| NFKC_QUICK_CHECK.get(ch))
+}
+
+/// Runs the NFD quick check algorithm of UAX #15 over a string, which never returns `Maybe`
+#[must_use]
+pub fn quick_check_nfd(s: &str) -> QuickCheck {
+    quick_check(s, |ch| {
+        if Decomposition::hangul(ch).is_some() || CANONICAL_DECOMPOSITION.get(ch) != 0 {
+            QuickCheck::No
+        } else {
+            QuickCheck::Yes
+        }
+    })
+}
+
+/// Runs the NFKD quick check algorithm of UAX #15 over a string, which never returns `Maybe`
+#[must_use]
+pub fn quick_check_nfkd(s: &str) -> QuickCheck {
+    quick_check(s, |ch| {
+        if Decomposition::hangul(ch).is_some() || COMPATIBLE_DECOMPOSITION.get(ch) != 0 {
+            QuickCheck::No
+        } else {
+            QuickCheck::Yes
+        }
+    })
+}
+
+/// Returns whether a string is in Normalization Form C.
+///
+/// The quick check settles most strings, only those it answers `Maybe` for are normalized to
+/// compare against.
+///
+/// ```
+/// use utf8char::normalization::is_nfc;
+///
+/// assert!(is_nfc("caf\u{E9}"));
+/// assert!(!is_nfc("cafe\u{301}"));
+/// // U+0301 is Maybe, but nothing precedes it to combine with
+/// assert!(is_nfc("\u{301}"));
+/// ```
+#[must_use]
+pub fn is_nfc(s: &str) -> bool {
+    match quick_check_nfc(s) {
+        QuickCheck::Yes => true,
+        QuickCheck::No => false,
+        QuickCheck::Maybe => Utf8CharIter::new(s).nfc().eq(Utf8CharIter::new(s)),
+    }
+}
+
+/// Returns whether a string is in Normalization Form KC, see [`is_nfc`]
+#[must_use]
+pub fn is_nfkc(s: &str) -> bool {
+    match quick_check_nfkc(s) {
+        QuickCheck::Yes => true,
+        QuickCheck::No => false,
+        QuickCheck::Maybe => Utf8CharIter::new(s).nfkc().eq(Utf8CharIter::new(s)),
+    }
+}
+
+/// Returns whether a string is in Normalization Form D
+#[must_use]
+pub fn is_nfd(s: &str) -> bool {
+    quick_check_nfd(s) == QuickCheck::Yes
+}
+
+/// Returns whether a string is in Normalization Form KD
+#[must_use]
+pub fn is_nfkd(s: &str) -> bool {
+    quick_check_nfkd(s) == QuickCheck::Yes
+}
+
+#[test]
+fn quick_check_forms() {
+    for (s, nfc, nfd) in [
+        ("", QuickCheck::Yes, QuickCheck::Yes),
+        ("hello", QuickCheck::Yes, QuickCheck::Yes),
+        ("\u{E9}", QuickCheck::Yes, QuickCheck::No),
+        // a singleton decomposition, never in NFC
+        ("\u{212B}", QuickCheck::No, QuickCheck::No),
+        // combining marks out of canonical order
+        ("a\u{301}\u{327}", QuickCheck::No, QuickCheck::No),
+        ("e\u{301}", QuickCheck::Maybe, QuickCheck::Yes),
+        ("\u{1100}\u{1161}", QuickCheck::Maybe, QuickCheck::Yes),
+        ("\u{D55C}", QuickCheck::Yes, QuickCheck::No),
+    ] {
+        assert_eq!(quick_check_nfc(s), nfc, "{s:?}");
+        assert_eq!(quick_check_nfd(s), nfd, "{s:?}");
+    }
+
+    assert_eq!(quick_check_nfkc("\u{FB01}"), QuickCheck::No);
+    assert_eq!(quick_check_nfkd("\u{FB01}"), QuickCheck::No);
+    assert_eq!(quick_check_nfkc("\u{E9}"), QuickCheck::Yes);
+    // a canonical decomposition containing a compatibility one
+    assert_eq!(quick_check_nfkc("\u{385}"), QuickCheck::No);
+}
+
+#[test]
+fn normalize() {
+    use alloc::string::ToString;
+
+    let forms = |s: &str| {
+        let chars = || Utf8CharIter::new(s);
+
+        [
+            chars().nfc().to_string(),
+            chars().nfd().to_string(),
+            chars().nfkc().to_string(),
+            chars().nfkd().to_string(),
+            chars().nfkc_casefold().to_string(),
+        ]
+    };
+
+    for (s, [nfc, nfd, nfkc, nfkd, casefold]) in [
+        ("abc", ["abc", "abc", "abc", "abc", "abc"]),
+        (
+            "\u{212B}",
+            ["\u{C5}", "A\u{30A}", "\u{C5}", "A\u{30A}", "\u{E5}"],
+        ),
+        // LATIN SMALL LETTER E WITH CEDILLA AND BREVE
+        (
+            "\u{1E1D}",
+            [
+                "\u{1E1D}",
+                "e\u{327}\u{306}",
+                "\u{1E1D}",
+                "e\u{327}\u{306}",
+                "\u{1E1D}",
+            ],
+        ),
+        // marks out of order compose with whichever is unblocked
+        (
+            "e\u{306}\u{327}",
+            [
+                "\u{1E1D}",
+                "e\u{327}\u{306}",
+                "\u{1E1D}",
+                "e\u{327}\u{306}",
+                "\u{1E1D}",
+            ],
+        ),
+        (
+            "\u{D55C}",
+            [
+                "\u{D55C}",
+                "\u{1112}\u{1161}\u{11AB}",
+                "\u{D55C}",
+                "\u{1112}\u{1161}\u{11AB}",
+                "\u{D55C}",
+            ],
+        ),
+        ("\u{FB01}", ["\u{FB01}", "\u{FB01}", "fi", "fi", "fi"]),
+        // a compatibility decomposition containing a Hangul syllable
+        (
+            "\u{320E}",
+            [
+                "\u{320E}",
+                "\u{320E}",
+                "(\u{AC00})",
+                "(\u{1100}\u{1161})",
+                "(\u{AC00})",
+            ],
+        ),
+        (
+            "\u{1E9E}",
+            ["\u{1E9E}", "\u{1E9E}", "\u{1E9E}", "\u{1E9E}", "ss"],
+        ),
+        // default ignorables are removed by NFKC_Casefold
+        (
+            "soft\u{AD}hyphen",
+            [
+                "soft\u{AD}hyphen",
+                "soft\u{AD}hyphen",
+                "soft\u{AD}hyphen",
+                "soft\u{AD}hyphen",
+                "softhyphen",
+            ],
+        ),
+        // the ypogegrammeni is reordered before it is folded to a starter
+        (
+            "\u{3B1}\u{345}\u{301}",
+            [
+                "\u{1FB4}",
+                "\u{3B1}\u{301}\u{345}",
+                "\u{1FB4}",
+                "\u{3B1}\u{301}\u{345}",
+                "\u{3AC}\u{3B9}",
+            ],
+        ),
+        // a composition exclusion
+        (
+            "\u{915}\u{93C}",
+            [
+                "\u{915}\u{93C}",
+                "\u{915}\u{93C}",
+                "\u{915}\u{93C}",
+                "\u{915}\u{93C}",
+                "\u{915}\u{93C}",
+            ],
+        ),
+    ] {
+        assert_eq!(forms(s), [nfc, nfd, nfkc, nfkd, casefold], "{s:?}");
+    }
+}
+
+#[test]
+fn conformance() {
+    use alloc::string::{String, ToString};
+
+    // NFC, NFD, NFKC and NFKD, in the order of the columns of NormalizationTest.txt
+    let forms: [fn(&str) -> String; 4] = [
+        |s| Utf8CharIter::new(s).nfc().to_string(),
+        |s| Utf8CharIter::new(s).nfd().to_string(),
+        |s| Utf8CharIter::new(s).nfkc().to_string(),
+        |s| Utf8CharIter::new(s).nfkd().to_string(),
+    ];
+    let quick_checks: [fn(&str) -> QuickCheck; 4] = [
+        quick_check_nfc,
+        quick_check_nfd,
+        quick_check_nfkc,
+        quick_check_nfkd,
+    ];
+    let checks: [fn(&str) -> bool; 4] = [is_nfc, is_nfd, is_nfkc, is_nfkd];
+
+    for columns in crate::tables::conformance::NORMALIZATION_TEST {
+        let [c1, c2, c3, c4, c5] = *columns;
+
+        // the invariants of NormalizationTest.txt, by the form, its expected column and the
+        // columns normalizing to it
+        for (form, expected, sources) in [
+            (0, c2, &[c1, c2, c3][..]),
+            (0, c4, &[c4, c5]),
+            (1, c3, &[c1, c2, c3]),
+            (1, c5, &[c4, c5]),
+            (2, c4, columns),
+            (3, c5, columns),
+        ] {
+            for &source in sources {
+                assert_eq!(forms[form](source), expected, "form {form} of {source:?}");
+            }
+        }
+
+        // a quick check may only answer yes for a string already in its form
+        let checks = forms.iter().zip(quick_checks).zip(checks);
+
+        for (form, ((normalize, quick_check), is_normalized)) in checks.enumerate() {
+            for &source in columns {
+                let normalized = normalize(source) == source;
+
+                if quick_check(source) == QuickCheck::Yes {
+                    assert!(normalized, "form {form} of {source:?}");
+                }
+
+                assert_eq!(
+                    is_normalized(source),
+                    normalized,
+                    "form {form} of {source:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn stream_safe() {
+    use alloc::string::{String, ToString};
+
+    let marks = "a"
+        .chars()
+        .chain(core::iter::repeat_n('\u{301}', 40))
+        .collect::<String>();
+    let nfd = Utf8CharIter::new(&marks).nfd().to_string();
+
+    assert_eq!(nfd.chars().nth(31), Some('\u{34F}'));
+    assert_eq!(nfd.chars().count(), 42);
+
+    // recomposing input that is not stream safe flushes the buffer rather than overflow it
+    let marks = "a"
+        .chars()
+        .chain(core::iter::repeat_n('\u{301}', 3 * BUFFER_LEN))
+        .collect::<String>();
+    let nfc = Recompositions::new(Utf8CharIter::new(&marks)).to_string();
+
+    assert_eq!(nfc.chars().next(), Some('á'));
+    assert_eq!(nfc.chars().count(), 3 * BUFFER_LEN);
+}
+
+#[test]
+fn round_trip() {
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let ch = Utf8Char::from_char(c);
+        let (canonical, compatible) = (ch.decompose_canonical(), ch.decompose_compatible());
+
+        assert!(is_nfd(&canonical), "{ch:?}");
+        assert!(is_nfkd(&compatible), "{ch:?}");
+
+        // composing a decomposition gives back the codepoint, unless it is excluded
+        if is_nfc(&ch) {
+            assert!(canonical.nfc().eq([ch]), "{ch:?}");
+        }
+    });
+}
@@ -0,0 +1,350 @@
+//! Unicode numeric values of `Utf8Char`'s, and translating decimal digits between scripts
+
+use core::{fmt, iter::FusedIterator};
+
+use crate::{
+    tables::numeric::{NUMERIC_VALUE, NUMERIC_VALUES},
+    GeneralCategory, Utf8Char, Utf8CharIter,
+};
+
+/// The `Numeric_Value` property of a codepoint, a rational number
+///
+/// Fractions are kept exactly as the Unicode Character Database lists them, so `'𐧷'` (`MEROITIC
+/// CURSIVE FRACTION TWO TWELFTHS`) is `2/12` rather than `1/6`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NumericValue {
+    /// The numerator, carrying the sign
+    numerator: i64,
+    /// The denominator, never zero
+    denominator: u32,
+}
+
+impl NumericValue {
+    /// Returns the numerator of this value, which carries its sign
+    #[must_use]
+    pub const fn numerator(self) -> i64 {
+        self.numerator
+    }
+
+    /// Returns the denominator of this value, which is 1 for integers
+    #[must_use]
+    pub const fn denominator(self) -> u32 {
+        self.denominator
+    }
+
+    /// Returns this value if it is an integer
+    #[must_use]
+    pub const fn to_integer(self) -> Option<i64> {
+        if self.denominator == 1 {
+            Some(self.numerator)
+        } else {
+            None
+        }
+    }
+
+    /// Returns this value as a floating point number
+    #[must_use]
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "every numeric value in unicode is representable"
+    )]
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / f64::from(self.denominator)
+    }
+}
+
+impl fmt::Display for NumericValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// The ten decimal digits of a script, identified by its zero digit
+///
+/// Every run of `Nd` codepoints in unicode is a contiguous `0..=9` sequence, so the zero is enough
+/// to find the rest of the digits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DecimalDigits {
+    /// The digit with value 0
+    zero: Utf8Char,
+}
+
+impl DecimalDigits {
+    /// `0123456789`
+    pub const ASCII: Self = Self::from_char('0');
+    /// `٠١٢٣٤٥٦٧٨٩`
+    pub const ARABIC_INDIC: Self = Self::from_char('\u{660}');
+    /// `۰۱۲۳۴۵۶۷۸۹`, used for Persian and Urdu
+    pub const EXTENDED_ARABIC_INDIC: Self = Self::from_char('\u{6F0}');
+    /// `०१२३४५६७८९`
+    pub const DEVANAGARI: Self = Self::from_char('\u{966}');
+    /// `০১২৩৪৫৬৭৮৯`
+    pub const BENGALI: Self = Self::from_char('\u{9E6}');
+    /// `๐๑๒๓๔๕๖๗๘๙`
+    pub const THAI: Self = Self::from_char('\u{E50}');
+    /// `０１２３４５６７８９`
+    pub const FULLWIDTH: Self = Self::from_char('\u{FF10}');
+
+    /// Constructor for the associated consts, which are checked to be zeros at compile time
+    const fn from_char(zero: char) -> Self {
+        match Self::from_zero(Utf8Char::from_char(zero)) {
+            Some(digits) => digits,
+            None => panic!("not a decimal zero"),
+        }
+    }
+
+    /// Returns the digits whose zero is the passed codepoint, or None if it is not a decimal digit
+    /// with value 0
+    ///
+    /// ```
+    /// use utf8char::{numeric::DecimalDigits, utf8char};
+    ///
+    /// let mongolian = DecimalDigits::from_zero(utf8char!("᠐")).unwrap();
+    ///
+    /// assert_eq!(mongolian.digit(7), Some(utf8char!("᠗")));
+    /// assert_eq!(DecimalDigits::from_zero(utf8char!("᠗")), None);
+    /// ```
+    #[must_use]
+    pub const fn from_zero(zero: Utf8Char) -> Option<Self> {
+        match zero.to_decimal_digit() {
+            Some(0) => Some(Self { zero }),
+            _ => None,
+        }
+    }
+
+    /// Returns the digits of the same script as any of its decimal digits
+    #[must_use]
+    pub const fn from_digit(digit: Utf8Char) -> Option<Self> {
+        match digit.to_decimal_digit() {
+            // SAFETY: the value of a digit is its offset from the zero of its run, which is also a
+            // scalar value
+            Some(value) => Some(Self {
+                zero: unsafe { Utf8Char::from_u32_unchecked(digit.to_u32() - value as u32) },
+            }),
+            None => None,
+        }
+    }
+
+    /// Returns the digit with the passed value, or None if it is not in `0..=9`
+    #[must_use]
+    pub const fn digit(self, value: u8) -> Option<Utf8Char> {
+        if value > 9 {
+            return None;
+        }
+
+        // SAFETY: digits 0..=9 of a run are contiguous scalar values
+        Some(unsafe { Utf8Char::from_u32_unchecked(self.zero.to_u32() + value as u32) })
+    }
+
+    /// Returns the zero digit of this script
+    #[must_use]
+    pub const fn zero(self) -> Utf8Char {
+        self.zero
+    }
+}
+
+/// An iterator over the codepoints of a string with every decimal digit replaced by the digit of
+/// the same value in a chosen script
+///
+/// Created by [`translate_digits`].
+#[derive(Clone, Debug)]
+pub struct TranslateDigits<'slice> {
+    /// The codepoints left to translate
+    iter: Utf8CharIter<'slice>,
+    /// The digits to translate to
+    to: DecimalDigits,
+}
+
+impl TranslateDigits<'_> {
+    /// Translates a single codepoint
+    const fn translate(&self, ch: Utf8Char) -> Utf8Char {
+        match ch.to_decimal_digit() {
+            // SAFETY: decimal digit values are always 0..=9
+            Some(value) => unsafe { self.to.digit(value).unwrap_unchecked() },
+            None => ch,
+        }
+    }
+}
+
+impl Iterator for TranslateDigits<'_> {
+    type Item = Utf8Char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|ch| self.translate(ch))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for TranslateDigits<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|ch| self.translate(ch))
+    }
+}
+
+impl FusedIterator for TranslateDigits<'_> {}
+
+impl fmt::Display for TranslateDigits<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.clone().try_for_each(|ch| f.write_str(&ch))
+    }
+}
+
+/// Returns an iterator over the codepoints of a string with every decimal digit, of any script,
+/// replaced by the digit of the same value in `to`
+///
+/// ```
+/// use utf8char::numeric::{translate_digits, DecimalDigits};
+///
+/// // normalise input typed on a Persian keyboard
+/// assert_eq!(translate_digits("۱۲۳٫۵", DecimalDigits::ASCII).to_string(), "123٫5");
+/// // and display ascii numbers in Devanagari
+/// assert_eq!(translate_digits("42", DecimalDigits::DEVANAGARI).to_string(), "४२");
+/// ```
+#[must_use]
+pub fn translate_digits(s: &str, to: DecimalDigits) -> TranslateDigits<'_> {
+    TranslateDigits {
+        iter: Utf8CharIter::new(s),
+        to,
+    }
+}
+
+impl Utf8Char {
+    /// Returns the value of this codepoint if it is a decimal digit (`Nd`) of any script
+    ///
+    /// Unlike [`to_digit`][Self::to_digit] this is not limited to ascii, but does not support other
+    /// radixes.
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(utf8char!("7").to_decimal_digit(), Some(7));
+    /// assert_eq!(utf8char!("٣").to_decimal_digit(), Some(3));
+    /// assert_eq!(utf8char!("５").to_decimal_digit(), Some(5));
+    /// assert_eq!(utf8char!("½").to_decimal_digit(), None);
+    /// ```
+    #[must_use]
+    pub const fn to_decimal_digit(self) -> Option<u8> {
+        if self.is_ascii() {
+            return self.to_digit(10);
+        }
+
+        if !matches!(self.general_category(), GeneralCategory::DecimalNumber) {
+            return None;
+        }
+
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "decimal digits are 0..=9"
+        )]
+        Some(NUMERIC_VALUES[NUMERIC_VALUE.get(self) as usize].0 as u8)
+    }
+
+    /// Returns the `Numeric_Value` of this codepoint, covering decimal digits, other digits like
+    /// `'²'`, letterlike numbers like `'Ⅻ'`, fractions and numeric ideographs
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// let half = utf8char!("½").numeric_value().unwrap();
+    /// assert_eq!((half.numerator(), half.denominator()), (1, 2));
+    ///
+    /// assert_eq!(utf8char!("Ⅻ").numeric_value().unwrap().to_integer(), Some(12));
+    /// assert_eq!(utf8char!("a").numeric_value(), None);
+    /// ```
+    #[must_use]
+    pub const fn numeric_value(self) -> Option<NumericValue> {
+        match NUMERIC_VALUE.get(self) {
+            0 => None,
+            idx => {
+                let (numerator, denominator) = NUMERIC_VALUES[idx as usize];
+
+                Some(NumericValue {
+                    numerator,
+                    denominator,
+                })
+            }
+        }
+    }
+}
+
+#[test]
+fn numeric_matches_std() {
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let utf8 = Utf8Char::from_char(c);
+        let digit = utf8.to_decimal_digit();
+
+        assert_eq!(
+            digit.is_some(),
+            utf8.general_category() == GeneralCategory::DecimalNumber
+        );
+        if c.is_ascii() {
+            assert_eq!(digit.map(u32::from), c.to_digit(10));
+        }
+
+        // every N* codepoint has a numeric value, the rest that do are ideographs
+        if c.is_numeric() {
+            assert!(utf8.numeric_value().is_some(), "{c:?}");
+        } else if utf8.numeric_value().is_some() {
+            assert_eq!(
+                utf8.general_category(),
+                GeneralCategory::OtherLetter,
+                "{c:?}"
+            );
+        }
+
+        if let Some(digit) = digit {
+            let digits = DecimalDigits::from_digit(utf8).unwrap();
+
+            assert_eq!(digits.digit(digit), Some(utf8));
+            assert_eq!(digits.zero().to_decimal_digit(), Some(0));
+            assert_eq!(
+                utf8.numeric_value().unwrap().to_integer(),
+                Some(i64::from(digit))
+            );
+            assert_eq!(
+                translate_digits(&utf8, DecimalDigits::ASCII).next(),
+                Some(Utf8Char::from_char(char::from(b'0' + digit)))
+            );
+        }
+    });
+}
+
+#[test]
+fn numeric_values() {
+    use alloc::string::ToString;
+
+    for (ch, value) in [
+        ('½', "1/2"),
+        ('Ⅻ', "12"),
+        ('༳', "-1/2"),
+        ('万', "10000"),
+        ('兆', "1000000000000"),
+        ('𐧷', "2/12"),
+    ] {
+        let value_of = Utf8Char::from_char(ch).numeric_value().unwrap();
+
+        assert_eq!(value_of.to_string(), value, "{ch:?}");
+    }
+
+    assert_eq!(
+        translate_digits("a٠b१c", DecimalDigits::THAI).to_string(),
+        "a๐b๑c"
+    );
+    assert_eq!(
+        translate_digits("a٠b१c", DecimalDigits::FULLWIDTH)
+            .rev()
+            .map(|ch| ch.to_string())
+            .collect::<alloc::string::String>(),
+        "c１b０a"
+    );
+}
@@ -0,0 +1,559 @@
+//! Stepping between `Utf8Char`'s, and iterating ranges of them without decoding
+
+use core::{iter::FusedIterator, ops::RangeInclusive};
+
+use crate::{Utf8Char, Utf8CharInner, TAG_CONTINUATION};
+
+/// The largest continuation byte
+const CONT_MAX: u8 = 0xBF;
+
+/// The first byte of the surrogate range `U+D800..=U+DFFF` in utf8 is `ED A0`, the last `ED BF`
+const SURROGATE_LEAD: u8 = 0xED;
+
+/// The amount of surrogate codepoints, which are skipped over when stepping
+const SURROGATES: u32 = 0x800;
+
+impl Utf8Char {
+    /// The lowest valid codepoint a `Utf8Char` can hold, `'\0'`.
+    ///
+    /// See [`char::MIN`]
+    pub const MIN: Self = Self::from_char(char::MIN);
+
+    /// The highest valid codepoint a `Utf8Char` can hold, `'\u{10FFFF}'`.
+    ///
+    /// See [`char::MAX`]
+    pub const MAX: Self = Self::from_char(char::MAX);
+
+    /// Constructs a `Utf8Char` from bytes produced by stepping a valid one
+    ///
+    /// # Safety
+    /// The bytes must follow the `Utf8Char` representation
+    const unsafe fn from_stepped(bytes: [u8; 4]) -> Self {
+        // SAFETY: caller upholds the representation
+        Self(unsafe { Utf8CharInner::from_utf8char_array(bytes) })
+    }
+
+    /// Returns the next unicode scalar value after this one, skipping over the surrogate range, or
+    /// None if this is [`Utf8Char::MAX`].
+    ///
+    /// This works on the utf8 bytes directly, incrementing the last byte and carrying into the
+    /// previous ones, so no decoding takes place.
+    ///
+    /// ```
+    /// use utf8char::{utf8char, Utf8Char};
+    ///
+    /// assert_eq!(utf8char!("a").next_scalar(), Some(utf8char!("b")));
+    /// assert_eq!(utf8char!("\u{D7FF}").next_scalar(), Some(utf8char!("\u{E000}")));
+    /// assert_eq!(Utf8Char::MAX.next_scalar(), None);
+    /// ```
+    #[must_use]
+    pub const fn next_scalar(self) -> Option<Self> {
+        const PAD: u8 = TAG_CONTINUATION;
+
+        let mut bytes = self.to_bytes();
+        let len = self.len_utf8() as usize;
+        let mut idx = len - 1;
+
+        while idx > 0 {
+            if bytes[idx] < CONT_MAX {
+                bytes[idx] += 1;
+
+                // the only continuation bytes that end up out of range by incrementing are the
+                // second bytes of ED (surrogates) and F4 (above U+10FFFF)
+                return match bytes {
+                    [SURROGATE_LEAD, 0xA0, ..] => {
+                        // SAFETY: U+E000 in the padded utf8 representation
+                        Some(unsafe { Self::from_stepped([0xEE, 0x80, 0x80, PAD]) })
+                    }
+                    [0xF4, 0x90, ..] => None,
+                    // SAFETY: the incremented byte is in range for its lead byte, and the bytes
+                    // after it were reset to the lowest continuation byte
+                    _ => Some(unsafe { Self::from_stepped(bytes) }),
+                };
+            }
+
+            bytes[idx] = TAG_CONTINUATION;
+            idx -= 1;
+        }
+
+        // every continuation byte overflowed, move on to the next lead byte
+        let next = match bytes[0] {
+            0x7F => [0xC2, 0x80, PAD, PAD],
+            0xDF => [0xE0, 0xA0, 0x80, PAD],
+            0xEF => [0xF0, 0x90, 0x80, 0x80],
+            lead => [lead + 1, bytes[1], bytes[2], bytes[3]],
+        };
+
+        // SAFETY: lead bytes are incremented within a length class, where all continuation bytes
+        // start at 0x80, or the first codepoint of the next length class is returned. F4 never
+        // reaches here, as stepping past F4 8F BF BF returns above
+        Some(unsafe { Self::from_stepped(next) })
+    }
+
+    /// Returns the unicode scalar value before this one, skipping over the surrogate range, or
+    /// None if this is [`Utf8Char::MIN`].
+    ///
+    /// Like [`next_scalar`][Self::next_scalar] this works on the utf8 bytes directly.
+    ///
+    /// ```
+    /// use utf8char::{utf8char, Utf8Char};
+    ///
+    /// assert_eq!(utf8char!("\u{80}").prev_scalar(), Some(utf8char!("\u{7F}")));
+    /// assert_eq!(utf8char!("\u{E000}").prev_scalar(), Some(utf8char!("\u{D7FF}")));
+    /// assert_eq!(Utf8Char::MIN.prev_scalar(), None);
+    /// ```
+    #[must_use]
+    pub const fn prev_scalar(self) -> Option<Self> {
+        const PAD: u8 = TAG_CONTINUATION;
+
+        let mut bytes = self.to_bytes();
+        let len = self.len_utf8() as usize;
+        let mut idx = len - 1;
+
+        while idx > 0 {
+            // the second byte of E0 and F0 sequences has a raised lower bound to avoid overlongs
+            let lowest = match (bytes[0], idx) {
+                (0xE0, 1) => 0xA0,
+                (0xF0, 1) => 0x90,
+                _ => TAG_CONTINUATION,
+            };
+
+            if bytes[idx] > lowest {
+                bytes[idx] -= 1;
+
+                // SAFETY: the decremented byte is still in range for its lead byte, and the bytes
+                // after it were reset to the highest continuation byte
+                return Some(unsafe { Self::from_stepped(bytes) });
+            }
+
+            bytes[idx] = CONT_MAX;
+            idx -= 1;
+        }
+
+        // every continuation byte underflowed, move on to the previous lead byte
+        let prev = match bytes[0] {
+            0x00 => return None,
+            0xC2 => [0x7F, PAD, PAD, PAD],
+            0xE0 => [0xDF, CONT_MAX, PAD, PAD],
+            0xF0 => [0xEF, CONT_MAX, CONT_MAX, PAD],
+            0xEE => [SURROGATE_LEAD, 0x9F, CONT_MAX, PAD],
+            lead => [lead - 1, bytes[1], bytes[2], bytes[3]],
+        };
+
+        // SAFETY: lead bytes are decremented within a length class, where all continuation bytes
+        // end at 0xBF (except ED, handled above), or the last codepoint of the previous length
+        // class is returned
+        Some(unsafe { Self::from_stepped(prev) })
+    }
+
+    /// Returns the scalar value `n` steps after this one, skipping over the surrogate range, or None
+    /// if that is past [`Utf8Char::MAX`].
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(utf8char!("a").checked_add(25), Some(utf8char!("z")));
+    /// assert_eq!(utf8char!("\u{D7FF}").checked_add(1), Some(utf8char!("\u{E000}")));
+    /// ```
+    #[must_use]
+    pub const fn checked_add(self, n: u32) -> Option<Self> {
+        let start = self.to_u32();
+
+        let Some(mut code) = start.checked_add(n) else {
+            return None;
+        };
+
+        if start < 0xD800 && code >= 0xD800 {
+            code = match code.checked_add(SURROGATES) {
+                Some(code) => code,
+                None => return None,
+            };
+        }
+
+        Self::from_u32(code)
+    }
+
+    /// Returns the scalar value `n` steps before this one, skipping over the surrogate range, or
+    /// None if that is before [`Utf8Char::MIN`].
+    #[must_use]
+    pub const fn checked_sub(self, n: u32) -> Option<Self> {
+        let start = self.to_u32();
+
+        let Some(mut code) = start.checked_sub(n) else {
+            return None;
+        };
+
+        if start >= 0xE000 && code < 0xE000 {
+            code = match code.checked_sub(SURROGATES) {
+                Some(code) => code,
+                None => return None,
+            };
+        }
+
+        Self::from_u32(code)
+    }
+
+    /// Returns the amount of steps between two scalar values, not counting the surrogate range.
+    ///
+    /// This is the `n` for which `a.checked_add(n) == Some(b)` (or the other way around if `b` is
+    /// lower than `a`).
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(utf8char!("a").distance(utf8char!("z")), 25);
+    /// assert_eq!(utf8char!("\u{E000}").distance(utf8char!("\u{D7FF}")), 1);
+    /// ```
+    #[must_use]
+    pub const fn distance(self, other: Self) -> u32 {
+        let (low, high) = if self.to_bits() <= other.to_bits() {
+            (self.to_u32(), other.to_u32())
+        } else {
+            (other.to_u32(), self.to_u32())
+        };
+
+        let diff = high - low;
+
+        if low < 0xD800 && high >= 0xE000 {
+            diff - SURROGATES
+        } else {
+            diff
+        }
+    }
+
+    /// Returns a range over every unicode scalar value, `Utf8Char::MIN..=Utf8Char::MAX`.
+    ///
+    /// ```
+    /// use utf8char::Utf8Char;
+    ///
+    /// assert_eq!(Utf8Char::all().len(), 0x110000 - 0x800);
+    /// assert!(Utf8Char::all().map(Utf8Char::to_char).eq('\0'..=char::MAX));
+    /// ```
+    #[must_use]
+    pub const fn all() -> Utf8CharRange {
+        Utf8CharRange::new(Self::MIN, Self::MAX)
+    }
+
+    /// Returns a parallel iterator over every unicode scalar value.
+    ///
+    /// See [`Utf8CharRange::par_iter`]
+    #[cfg(feature = "rayon")]
+    #[must_use]
+    pub fn par_all() -> impl rayon::iter::IndexedParallelIterator<Item = Self> {
+        Self::all().par_iter()
+    }
+}
+
+/// An inclusive range of `Utf8Char`'s, that iterates every scalar value in it in order.
+///
+/// This is the `Utf8Char` equivalent of `RangeInclusive<char>`, and can be created from a
+/// `RangeInclusive<Utf8Char>`. Stepping is done on the utf8 bytes directly, see
+/// [`Utf8Char::next_scalar`].
+///
+/// ```
+/// use utf8char::{range::Utf8CharRange, utf8char};
+///
+/// let greek = Utf8CharRange::from(utf8char!("α")..=utf8char!("ε"));
+///
+/// assert_eq!(greek.len(), 5);
+/// assert_eq!(greek.rev().map(|ch| ch.to_char()).collect::<String>(), "εδγβα");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Utf8CharRange {
+    /// The next codepoint to yield from the front
+    start: Utf8Char,
+    /// The next codepoint to yield from the back
+    end: Utf8Char,
+    /// Whether start and end have met, and been yielded, like `RangeInclusive`
+    exhausted: bool,
+}
+
+impl Utf8CharRange {
+    /// Creates a range containing every scalar value from `start` up to and including `end`.
+    ///
+    /// The range is empty if `start` is greater than `end`.
+    #[must_use]
+    pub const fn new(start: Utf8Char, end: Utf8Char) -> Self {
+        Self {
+            start,
+            end,
+            exhausted: false,
+        }
+    }
+
+    /// Returns the lower bound of the range, which may have moved by iterating
+    #[must_use]
+    pub const fn start(&self) -> Utf8Char {
+        self.start
+    }
+
+    /// Returns the upper bound of the range, which may have moved by iterating
+    #[must_use]
+    pub const fn end(&self) -> Utf8Char {
+        self.end
+    }
+
+    /// Returns whether no codepoints are left in the range
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.exhausted || self.start.to_bits() > self.end.to_bits()
+    }
+
+    /// Returns whether the codepoint is left in the range
+    #[must_use]
+    pub const fn contains(&self, ch: Utf8Char) -> bool {
+        !self.exhausted
+            && self.start.to_bits() <= ch.to_bits()
+            && ch.to_bits() <= self.end.to_bits()
+    }
+
+    /// Returns a parallel iterator over the codepoints left in the range, in order
+    #[cfg(feature = "rayon")]
+    #[must_use]
+    pub fn par_iter(&self) -> impl rayon::iter::IndexedParallelIterator<Item = Utf8Char> {
+        use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+        let start = self.start;
+        let len = if self.is_empty() {
+            0
+        } else {
+            self.start.distance(self.end) + 1
+        };
+
+        // SAFETY: every step stays within the range, which only holds scalar values
+        (0..len)
+            .into_par_iter()
+            .map(move |n| unsafe { start.checked_add(n).unwrap_unchecked() })
+    }
+}
+
+impl From<RangeInclusive<Utf8Char>> for Utf8CharRange {
+    fn from(range: RangeInclusive<Utf8Char>) -> Self {
+        let (start, end) = range.into_inner();
+
+        Self::new(start, end)
+    }
+}
+
+impl Iterator for Utf8CharRange {
+    type Item = Utf8Char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if Self::is_empty(self) {
+            return None;
+        }
+
+        let ch = self.start;
+
+        if ch == self.end {
+            self.exhausted = true;
+        } else {
+            // SAFETY: start is lower than end, so there is a scalar value after it
+            self.start = unsafe { ch.next_scalar().unwrap_unchecked() };
+        }
+
+        Some(ch)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if Self::is_empty(self) {
+            return None;
+        }
+
+        if let Ok(n) = u32::try_from(n) {
+            if let Some(ch) = self.start.checked_add(n) {
+                if ch < self.end {
+                    self.start = ch;
+                    return self.next();
+                }
+
+                if ch == self.end {
+                    self.start = ch;
+                    self.exhausted = true;
+                    return Some(ch);
+                }
+            }
+        }
+
+        self.start = self.end;
+        self.exhausted = true;
+
+        None
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn min(mut self) -> Option<Self::Item> {
+        self.next()
+    }
+
+    fn max(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Utf8CharRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if Self::is_empty(self) {
+            return None;
+        }
+
+        let ch = self.end;
+
+        if ch == self.start {
+            self.exhausted = true;
+        } else {
+            // SAFETY: end is higher than start, so there is a scalar value before it
+            self.end = unsafe { ch.prev_scalar().unwrap_unchecked() };
+        }
+
+        Some(ch)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if Self::is_empty(self) {
+            return None;
+        }
+
+        if let Ok(n) = u32::try_from(n) {
+            if let Some(ch) = self.end.checked_sub(n) {
+                if ch > self.start {
+                    self.end = ch;
+                    return self.next_back();
+                }
+
+                if ch == self.start {
+                    self.end = ch;
+                    self.exhausted = true;
+                    return Some(ch);
+                }
+            }
+        }
+
+        self.end = self.start;
+        self.exhausted = true;
+
+        None
+    }
+}
+
+impl ExactSizeIterator for Utf8CharRange {
+    fn len(&self) -> usize {
+        if Self::is_empty(self) {
+            0
+        } else {
+            self.start.distance(self.end) as usize + 1
+        }
+    }
+}
+
+impl FusedIterator for Utf8CharRange {}
+
+#[test]
+fn stepping_matches_std() {
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let utf8 = Utf8Char::from_char(c);
+
+        let next = char::from_u32(c as u32 + 1).or_else(|| (c < char::MAX).then_some('\u{E000}'));
+        let prev = (c as u32)
+            .checked_sub(1)
+            .map(|code| char::from_u32(code).unwrap_or('\u{D7FF}'));
+
+        assert_eq!(utf8.next_scalar().map(Utf8Char::to_char), next, "{c:?}");
+        assert_eq!(utf8.prev_scalar().map(Utf8Char::to_char), prev, "{c:?}");
+        assert_eq!(utf8.checked_add(1), utf8.next_scalar(), "{c:?}");
+        assert_eq!(utf8.checked_sub(1), utf8.prev_scalar(), "{c:?}");
+
+        for other in ['a', '\u{D7FF}', '\u{E000}', '\u{10FFFF}'] {
+            let other = Utf8Char::from_char(other);
+            let distance = utf8.distance(other);
+
+            assert_eq!(distance, other.distance(utf8));
+            assert_eq!(
+                utf8.min(other).checked_add(distance),
+                Some(utf8.max(other)),
+                "{c:?}"
+            );
+            assert_eq!(
+                utf8.max(other).checked_sub(distance),
+                Some(utf8.min(other)),
+                "{c:?}"
+            );
+        }
+    });
+}
+
+#[test]
+fn range_matches_std() {
+    use crate::utf8char;
+
+    assert!(Utf8Char::all().eq(('\0'..=char::MAX).map(Utf8Char::from_char)));
+    assert!(Utf8Char::all()
+        .rev()
+        .eq(('\0'..=char::MAX).rev().map(Utf8Char::from_char)));
+
+    let bounds = [
+        '\0',
+        'a',
+        '\u{7F}',
+        '\u{80}',
+        '\u{7FF}',
+        '\u{800}',
+        '\u{D7FF}',
+        '\u{E000}',
+        '\u{FFFF}',
+        '\u{10000}',
+        '\u{10FFFF}',
+    ];
+
+    for &start in &bounds {
+        for &end in &bounds {
+            let ours =
+                || Utf8CharRange::from(Utf8Char::from_char(start)..=Utf8Char::from_char(end));
+            let theirs = || (start..=end).map(Utf8Char::from_char);
+
+            assert_eq!(ours().len(), theirs().count());
+            assert_eq!(ours().is_empty(), (start..=end).is_empty());
+
+            for n in [0, 1, 2, 0x7F, 0x800, 0x1_0000] {
+                let (mut ours, mut theirs) = (ours(), theirs());
+                assert_eq!(ours.nth(n), theirs.nth(n), "{start:?} {end:?} {n}");
+                assert!(ours.clone().eq(theirs.clone()));
+                assert_eq!(
+                    ours.nth_back(n),
+                    theirs.nth_back(n),
+                    "{start:?} {end:?} {n}"
+                );
+                assert!(ours.eq(theirs));
+            }
+        }
+    }
+
+    let mut range = Utf8CharRange::from(utf8char!("a")..=utf8char!("c"));
+    assert_eq!(range.next(), Some(utf8char!("a")));
+    assert_eq!(range.next_back(), Some(utf8char!("c")));
+    assert!(range.contains(utf8char!("b")) && !range.contains(utf8char!("a")));
+    assert_eq!(range.next(), Some(utf8char!("b")));
+    assert!(range.is_empty() && range.next_back().is_none());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_all() {
+    use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+    assert_eq!(Utf8Char::par_all().count(), Utf8Char::all().len());
+    assert!(Utf8Char::par_all()
+        .zip(('\0'..=char::MAX).into_par_iter())
+        .all(|(utf8, c)| utf8.to_char() == c));
+}
@@ -57,9 +57,13 @@ fn identical_codepoint_len() {
     dead_code,
     reason = "we transmute into/outof these values, rust cant see it"
 )]
-#[expect(
-    clippy::missing_docs_in_private_items,
-    reason = "its 1..=4, each variant is unspecial"
+// clippy does not check private items for docs in test builds
+#[cfg_attr(
+    not(test),
+    expect(
+        clippy::missing_docs_in_private_items,
+        reason = "its 1..=4, each variant is unspecial"
+    )
 )]
 /// An enum representing all of the valid lengths of a utf8 encoded codepoint
 pub(crate) enum EncodedLength {
@@ -0,0 +1,525 @@
+//! The unicode [`Script`] and `Script_Extensions` of `Utf8Char`'s, and the scripts of strings
+
+use core::{fmt, iter::FusedIterator};
+
+pub use crate::tables::script::Script;
+use crate::{
+    tables::script::{NAMES, SCRIPT, SCRIPT_EXTENSION, SCRIPT_EXTENSIONS},
+    Utf8Char, Utf8CharIter,
+};
+
+/// The amount of `u64`'s in a `ScriptSet`, enough to have a bit for every script
+const WORDS: usize = 3;
+
+const _: () = assert!(NAMES.len() <= WORDS * 64);
+
+impl Script {
+    /// Returns the script with the passed discriminant
+    const fn from_index(idx: usize) -> Self {
+        assert!(idx < NAMES.len());
+
+        #[expect(clippy::cast_possible_truncation, reason = "checked against NAMES")]
+        // SAFETY: Script is a repr(u8) enum with a variant for every index of NAMES
+        unsafe {
+            core::mem::transmute::<u8, Self>(idx as u8)
+        }
+    }
+
+    /// Returns the ISO 15924 code of this script, its short property value alias
+    ///
+    /// ```
+    /// use utf8char::Script;
+    ///
+    /// assert_eq!(Script::Latin.short_name(), "Latn");
+    /// assert_eq!(Script::Inherited.short_name(), "Zinh");
+    /// ```
+    #[must_use]
+    pub const fn short_name(self) -> &'static str {
+        NAMES[self as usize].0
+    }
+
+    /// Returns the long property value alias of this script, as used in `Scripts.txt`
+    ///
+    /// ```
+    /// use utf8char::Script;
+    ///
+    /// assert_eq!(Script::OldItalic.name(), "Old_Italic");
+    /// ```
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        NAMES[self as usize].1
+    }
+
+    /// Returns the script with the passed short or long property value alias, ignoring case
+    ///
+    /// ```
+    /// use utf8char::Script;
+    ///
+    /// assert_eq!(Script::from_name("cyrl"), Some(Script::Cyrillic));
+    /// assert_eq!(Script::from_name("Old_Italic"), Some(Script::OldItalic));
+    /// assert_eq!(Script::from_name("Klingon"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMES
+            .iter()
+            .position(|(short, long)| {
+                short.eq_ignore_ascii_case(name) || long.eq_ignore_ascii_case(name)
+            })
+            .map(Self::from_index)
+    }
+}
+
+impl fmt::Display for Script {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A set of [`Script`]s, as returned by [`Utf8Char::script_extensions`]
+///
+/// ```
+/// use utf8char::{utf8char, Script};
+///
+/// let set = utf8char!("ー").script_extensions();
+///
+/// assert!(set.contains(Script::Hiragana) && set.contains(Script::Katakana));
+/// assert_eq!(set.iter().collect::<Vec<_>>(), [Script::Hiragana, Script::Katakana]);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ScriptSet([u64; WORDS]);
+
+impl ScriptSet {
+    /// The set without any scripts
+    pub const EMPTY: Self = Self([0; WORDS]);
+
+    /// The set of every script
+    pub const ALL: Self = {
+        let mut set = Self::EMPTY;
+        let mut idx = 0;
+
+        while idx < NAMES.len() {
+            set.insert(Script::from_index(idx));
+            idx += 1;
+        }
+
+        set
+    };
+
+    /// Constructor for the generated tables
+    pub(crate) const fn from_words(words: [u64; WORDS]) -> Self {
+        Self(words)
+    }
+
+    /// Returns the set containing only `script`
+    #[must_use]
+    pub const fn single(script: Script) -> Self {
+        let mut set = Self::EMPTY;
+        set.insert(script);
+        set
+    }
+
+    /// Adds a script to this set
+    pub const fn insert(&mut self, script: Script) {
+        let idx = script as usize;
+        self.0[idx / 64] |= 1 << (idx % 64);
+    }
+
+    /// Returns whether this set contains a script
+    #[must_use]
+    pub const fn contains(self, script: Script) -> bool {
+        let idx = script as usize;
+        (self.0[idx / 64] >> (idx % 64)) & 1 == 1
+    }
+
+    /// Returns the scripts in either set
+    #[must_use]
+    pub const fn union(mut self, other: Self) -> Self {
+        let mut idx = 0;
+
+        while idx < WORDS {
+            self.0[idx] |= other.0[idx];
+            idx += 1;
+        }
+
+        self
+    }
+
+    /// Returns the scripts in both sets
+    #[must_use]
+    pub const fn intersection(mut self, other: Self) -> Self {
+        let mut idx = 0;
+
+        while idx < WORDS {
+            self.0[idx] &= other.0[idx];
+            idx += 1;
+        }
+
+        self
+    }
+
+    /// Returns whether this set contains no scripts
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        matches!(self.0, [0, 0, 0])
+    }
+
+    /// Returns the amount of scripts in this set
+    #[must_use]
+    pub const fn len(self) -> usize {
+        let [a, b, c] = self.0;
+
+        (a.count_ones() + b.count_ones() + c.count_ones()) as usize
+    }
+
+    /// Returns an iterator over the scripts in this set, in declaration order
+    #[must_use]
+    pub const fn iter(self) -> ScriptSetIter {
+        ScriptSetIter(self)
+    }
+}
+
+impl From<Script> for ScriptSet {
+    fn from(script: Script) -> Self {
+        Self::single(script)
+    }
+}
+
+impl FromIterator<Script> for ScriptSet {
+    fn from_iter<I: IntoIterator<Item = Script>>(iter: I) -> Self {
+        let mut set = Self::EMPTY;
+        iter.into_iter().for_each(|script| set.insert(script));
+        set
+    }
+}
+
+impl IntoIterator for ScriptSet {
+    type Item = Script;
+    type IntoIter = ScriptSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl fmt::Debug for ScriptSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the scripts in a [`ScriptSet`]
+///
+/// Created by [`ScriptSet::iter`].
+#[derive(Clone, Debug)]
+pub struct ScriptSetIter(ScriptSet);
+
+impl Iterator for ScriptSetIter {
+    type Item = Script;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (word, bits) = self.0 .0.iter_mut().enumerate().find(|(_, w)| **w != 0)?;
+        let bit = bits.trailing_zeros() as usize;
+
+        *bits &= *bits - 1;
+        Some(Script::from_index(word * 64 + bit))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len(), Some(self.0.len()))
+    }
+}
+
+impl DoubleEndedIterator for ScriptSetIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (word, bits) = self.0 .0.iter_mut().enumerate().rfind(|(_, w)| **w != 0)?;
+        let bit = 63 - bits.leading_zeros() as usize;
+
+        *bits &= !(1 << bit);
+        Some(Script::from_index(word * 64 + bit))
+    }
+}
+
+impl ExactSizeIterator for ScriptSetIter {}
+
+impl FusedIterator for ScriptSetIter {}
+
+/// The scripts used by a string, as reported by [`script_summary`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ScriptSummary {
+    /// The script most codepoints belong to
+    dominant: Option<Script>,
+    /// The intersection of the script extensions of every codepoint
+    resolved: ScriptSet,
+    /// Whether no single writing system covers every codepoint
+    mixed: bool,
+}
+
+impl ScriptSummary {
+    /// Returns the script the most codepoints of the string belong to, ignoring `Common`,
+    /// `Inherited` and `Unknown` codepoints. Ties go to the script declared first in [`Script`].
+    ///
+    /// None if there are no codepoints of a specific script, like in `"123 !?"`.
+    #[must_use]
+    pub const fn dominant(self) -> Option<Script> {
+        self.dominant
+    }
+
+    /// Returns the resolved script set of the string, as defined in
+    /// [UTS #39](https://www.unicode.org/reports/tr39/#Mixed_Script_Detection): the intersection
+    /// of the [`script_extensions`][Utf8Char::script_extensions] of its codepoints, where
+    /// `Common` and `Inherited` codepoints count as having every script.
+    #[must_use]
+    pub const fn resolved(self) -> ScriptSet {
+        self.resolved
+    }
+
+    /// Returns whether the string mixes scripts, meaning no single writing system covers it
+    ///
+    /// This is the case when the [`resolved`][Self::resolved] set is empty, except for the
+    /// combinations UTS #39 treats as one writing system: Han with Hiragana and Katakana
+    /// (Japanese), Han with Hangul (Korean) and Han with Bopomofo.
+    #[must_use]
+    pub const fn is_mixed(self) -> bool {
+        self.mixed
+    }
+}
+
+/// Scans a string and reports its dominant script, and whether it mixes scripts
+///
+/// ```
+/// use utf8char::{script::script_summary, Script};
+///
+/// let latin = script_summary("Hello, world!");
+/// assert_eq!(latin.dominant(), Some(Script::Latin));
+/// assert!(!latin.is_mixed());
+///
+/// // a Cyrillic 'а' among latin letters
+/// let spoofed = script_summary("pаypal");
+/// assert_eq!(spoofed.dominant(), Some(Script::Latin));
+/// assert!(spoofed.is_mixed());
+///
+/// let japanese = script_summary("日本語のテキスト");
+/// assert_eq!(japanese.dominant(), Some(Script::Katakana));
+/// assert!(!japanese.is_mixed());
+/// ```
+#[must_use]
+pub fn script_summary(s: &str) -> ScriptSummary {
+    let mut counts = [0usize; NAMES.len()];
+    let mut resolver = ScriptResolver::new();
+
+    for ch in Utf8CharIter::new(s) {
+        let script = ch.script();
+
+        if !matches!(script, Script::Common | Script::Inherited | Script::Unknown) {
+            counts[script as usize] += 1;
+        }
+
+        resolver.push(ch);
+    }
+
+    // the first of the highest counts
+    let dominant = counts
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|&(_, count)| count)
+        .filter(|&(_, &count)| count > 0)
+        .map(|(idx, _)| Script::from_index(idx));
+
+    ScriptSummary {
+        dominant,
+        resolved: resolver.resolved,
+        mixed: resolver.is_mixed(),
+    }
+}
+
+/// Computes the resolved script set of a string one codepoint at a time, along with whether the
+/// combinations of scripts UTS #39 treats as one writing system cover it
+#[derive(Copy, Clone)]
+pub(crate) struct ScriptResolver {
+    /// The intersection of the script extensions so far
+    resolved: ScriptSet,
+    /// Whether Japanese, Korean and Chinese, in that order, cover every codepoint so far
+    writing_systems: [bool; 3],
+}
+
+impl ScriptResolver {
+    /// Creates a resolver for the empty string, which every script covers
+    pub(crate) const fn new() -> Self {
+        Self {
+            resolved: ScriptSet::ALL,
+            writing_systems: [true; 3],
+        }
+    }
+
+    /// Adds a codepoint to the string
+    pub(crate) fn push(&mut self, ch: Utf8Char) {
+        /// The writing systems UTS #39 allows to combine Han with
+        const JAPANESE: [Script; 4] = [
+            Script::Han,
+            Script::Hiragana,
+            Script::Katakana,
+            Script::KatakanaOrHiragana,
+        ];
+        /// See `JAPANESE`
+        const KOREAN: [Script; 2] = [Script::Han, Script::Hangul];
+        /// See `JAPANESE`
+        const CHINESE: [Script; 2] = [Script::Han, Script::Bopomofo];
+
+        let extensions = ch.script_extensions();
+
+        if extensions.contains(Script::Common) || extensions.contains(Script::Inherited) {
+            return;
+        }
+
+        self.resolved = self.resolved.intersection(extensions);
+
+        for (covered, system) in
+            self.writing_systems
+                .iter_mut()
+                .zip([&JAPANESE[..], &KOREAN, &CHINESE])
+        {
+            *covered &= system.iter().any(|&script| extensions.contains(script));
+        }
+    }
+
+    /// Returns the resolved script set
+    pub(crate) const fn resolved(self) -> ScriptSet {
+        self.resolved
+    }
+
+    /// Returns whether one of the writing systems combining Han with other scripts covers the
+    /// string
+    pub(crate) fn is_writing_system(self) -> bool {
+        self.writing_systems.contains(&true)
+    }
+
+    /// Returns whether no single writing system covers the string
+    pub(crate) fn is_mixed(self) -> bool {
+        self.resolved.is_empty() && !self.is_writing_system()
+    }
+}
+
+impl Utf8Char {
+    /// Returns the [`Script`] of this codepoint
+    ///
+    /// ```
+    /// use utf8char::{utf8char, Script};
+    ///
+    /// assert_eq!(utf8char!("a").script(), Script::Latin);
+    /// assert_eq!(utf8char!("я").script(), Script::Cyrillic);
+    /// assert_eq!(utf8char!("1").script(), Script::Common);
+    /// assert_eq!(utf8char!("\u{301}").script(), Script::Inherited);
+    /// ```
+    #[must_use]
+    pub const fn script(self) -> Script {
+        SCRIPT.get(self)
+    }
+
+    /// Returns the `Script_Extensions` of this codepoint, the scripts it is used with
+    ///
+    /// For most codepoints that is just their [`script`][Self::script], but codepoints shared by a
+    /// few scripts list those instead of `Common` or `Inherited`.
+    ///
+    /// ```
+    /// use utf8char::{script::ScriptSet, utf8char, Script};
+    ///
+    /// assert_eq!(utf8char!("a").script_extensions(), ScriptSet::single(Script::Latin));
+    ///
+    /// // the ideographic comma
+    /// let comma = utf8char!("、").script_extensions();
+    /// assert_eq!(utf8char!("、").script(), Script::Common);
+    /// assert!(comma.contains(Script::Han) && comma.contains(Script::Hiragana));
+    /// assert!(!comma.contains(Script::Common));
+    /// ```
+    #[must_use]
+    pub const fn script_extensions(self) -> ScriptSet {
+        match SCRIPT_EXTENSION.get(self) {
+            0 => ScriptSet::single(self.script()),
+            idx => SCRIPT_EXTENSIONS[idx as usize],
+        }
+    }
+}
+
+#[test]
+fn scripts() {
+    use rayon::iter::ParallelIterator;
+
+    use crate::GeneralCategory;
+
+    crate::tests::all_chars().for_each(|c| {
+        let utf8 = Utf8Char::from_char(c);
+        let (script, extensions) = (utf8.script(), utf8.script_extensions());
+
+        assert!(!extensions.is_empty(), "{c:?}");
+        assert!(
+            extensions.contains(script) || matches!(script, Script::Common | Script::Inherited),
+            "{c:?}"
+        );
+        assert_eq!(
+            script == Script::Unknown,
+            matches!(
+                utf8.general_category(),
+                GeneralCategory::Unassigned | GeneralCategory::PrivateUse
+            ),
+            "{c:?}"
+        );
+    });
+}
+
+#[test]
+fn script_sets() {
+    use alloc::vec::Vec;
+
+    for (idx, (short, long)) in NAMES.iter().enumerate() {
+        let script = Script::from_index(idx);
+
+        assert_eq!(script as usize, idx);
+        assert_eq!(Script::from_name(short), Some(script));
+        assert_eq!(Script::from_name(long), Some(script));
+        assert_eq!(
+            ScriptSet::single(script).iter().collect::<Vec<_>>(),
+            [script]
+        );
+        assert!(ScriptSet::ALL.contains(script));
+    }
+
+    let all = ScriptSet::ALL.iter().collect::<Vec<_>>();
+    let mut reversed = ScriptSet::ALL.iter().rev().collect::<Vec<_>>();
+    reversed.reverse();
+
+    assert_eq!(all.len(), NAMES.len());
+    assert_eq!(ScriptSet::ALL.len(), NAMES.len());
+    assert_eq!(all, reversed);
+    assert_eq!(all.into_iter().collect::<ScriptSet>(), ScriptSet::ALL);
+
+    let set = [Script::Greek, Script::Latin]
+        .into_iter()
+        .collect::<ScriptSet>();
+
+    assert_eq!(set.union(Script::Cyrillic.into()).len(), 3);
+    assert_eq!(set.intersection(Script::Greek.into()), Script::Greek.into());
+    assert!(set.intersection(Script::Cyrillic.into()).is_empty());
+}
+
+#[test]
+fn summaries() {
+    for (s, dominant, mixed) in [
+        ("", None, false),
+        ("123 !?", None, false),
+        ("Straße", Some(Script::Latin), false),
+        ("Ελληνικά", Some(Script::Greek), false),
+        ("Hello мир", Some(Script::Latin), true),
+        ("мир hello", Some(Script::Latin), true),
+        ("한국어 漢字", Some(Script::Hangul), false),
+        ("ひらがな、カタカナ", Some(Script::Hiragana), false),
+        ("한국어 ひら", Some(Script::Hangul), true),
+        ("a\u{301}", Some(Script::Latin), false),
+    ] {
+        let summary = script_summary(s);
+
+        assert_eq!(summary.dominant(), dominant, "{s:?}");
+        assert_eq!(summary.is_mixed(), mixed, "{s:?}");
+    }
+}
@@ -0,0 +1,328 @@
+//! Confusable detection and restriction levels of identifiers, as defined in
+//! [UTS #39](https://www.unicode.org/reports/tr39/)
+//!
+//! These are meant for strings people pick to be recognized by, like usernames and domain names,
+//! where a lookalike can impersonate someone else:
+//!
+//! ```
+//! use utf8char::security::{is_confusable, restriction_level, RestrictionLevel};
+//!
+//! // a Cyrillic 'а' among Latin letters
+//! let spoofed = "p\u{430}ypal";
+//!
+//! assert!(is_confusable(spoofed, "paypal"));
+//! assert_eq!(restriction_level(spoofed), RestrictionLevel::MinimallyRestrictive);
+//! ```
+
+use core::{fmt, iter::FusedIterator};
+
+use crate::{
+    normalization::{Decompositions, Normalize},
+    script::ScriptResolver,
+    tables::security::{CONFUSABLE, DEFAULT_IGNORABLE, IDENTIFIER_ALLOWED, PROTOTYPES},
+    Script, Utf8Char, Utf8CharIter,
+};
+
+/// The scripts UAX #31 recommends for identifiers, besides `Common` and `Inherited`
+const RECOMMENDED: [Script; 29] = [
+    Script::Arabic,
+    Script::Armenian,
+    Script::Bengali,
+    Script::Bopomofo,
+    Script::Cyrillic,
+    Script::Devanagari,
+    Script::Ethiopic,
+    Script::Georgian,
+    Script::Greek,
+    Script::Gujarati,
+    Script::Gurmukhi,
+    Script::Hangul,
+    Script::Han,
+    Script::Hebrew,
+    Script::Hiragana,
+    Script::Kannada,
+    Script::Katakana,
+    Script::Khmer,
+    Script::Lao,
+    Script::Latin,
+    Script::Malayalam,
+    Script::Myanmar,
+    Script::Oriya,
+    Script::Sinhala,
+    Script::Tamil,
+    Script::Telugu,
+    Script::Thaana,
+    Script::Thai,
+    Script::Tibetan,
+];
+
+impl Utf8Char {
+    /// Returns whether this codepoint has an `Identifier_Status` of `Allowed`, it is in the
+    /// General Security Profile for identifiers
+    ///
+    /// This leaves out codepoints of scripts that are not in common use, most symbols and
+    /// punctuation, and codepoints that NFKC changes.
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert!(utf8char!("a").is_identifier_allowed());
+    /// assert!(utf8char!("\u{E9}").is_identifier_allowed());
+    /// assert!(!utf8char!("\u{200B}").is_identifier_allowed());
+    /// assert!(!utf8char!("\u{FB01}").is_identifier_allowed());
+    /// ```
+    #[must_use]
+    pub const fn is_identifier_allowed(self) -> bool {
+        IDENTIFIER_ALLOWED.contains(self)
+    }
+
+    /// Returns whether this codepoint has the `Default_Ignorable_Code_Point` property, it is
+    /// invisible unless a renderer supports it, so skeletons leave it out
+    pub(crate) const fn is_default_ignorable(self) -> bool {
+        DEFAULT_IGNORABLE.contains(self)
+    }
+}
+
+/// An iterator that replaces every codepoint of a stream with its confusable prototype, leaving
+/// out default ignorable codepoints
+#[derive(Clone)]
+struct Prototypes<I> {
+    /// The codepoints left to map
+    iter: I,
+    /// The rest of the prototype of the last codepoint
+    prototype: Utf8CharIter<'static>,
+}
+
+impl<I: Iterator<Item = Utf8Char>> Iterator for Prototypes<I> {
+    type Item = Utf8Char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(ch) = self.prototype.next() {
+                return Some(ch);
+            }
+
+            let ch = self.iter.next()?;
+
+            if ch.is_default_ignorable() {
+                continue;
+            }
+
+            match CONFUSABLE.get(ch) {
+                0 => return Some(ch),
+                idx => self.prototype = Utf8CharIter::new(PROTOTYPES[idx as usize]),
+            }
+        }
+    }
+}
+
+/// An iterator over the confusable skeleton of a string
+///
+/// Created by [`skeleton`].
+#[derive(Clone)]
+pub struct Skeleton<'slice>(
+    /// `NFD(prototypes(NFD(s)))` with default ignorables removed after the first `NFD`, as
+    /// `skeleton` is defined
+    Decompositions<Prototypes<Decompositions<Utf8CharIter<'slice>>>>,
+);
+
+impl Iterator for Skeleton<'_> {
+    type Item = Utf8Char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+impl FusedIterator for Skeleton<'_> {}
+
+impl fmt::Display for Skeleton<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.clone().try_for_each(|ch| f.write_str(&ch))
+    }
+}
+
+/// Returns the confusable skeleton of a string, what it reduces to when every codepoint is replaced
+/// by the prototype of the codepoints it can be mistaken for, and invisible ones are removed
+///
+/// Skeletons are only meant to be compared with each other, and are not a readable version of
+/// the string: the skeleton of `"m"` is `"rn"`, for instance.
+///
+/// ```
+/// use utf8char::security::skeleton;
+///
+/// assert_eq!(skeleton("g00gle").to_string(), "gOOgle");
+/// assert_eq!(skeleton("m").to_string(), "rn");
+/// // the Cyrillic 'а' looks like a Latin one
+/// assert_eq!(skeleton("p\u{430}ypal").to_string(), "paypal");
+/// // a zero width space is default ignorable
+/// assert_eq!(skeleton("pay\u{200B}pal").to_string(), "paypal");
+/// ```
+#[must_use]
+pub fn skeleton(s: &str) -> Skeleton<'_> {
+    let prototypes = Prototypes {
+        iter: Utf8CharIter::new(s).nfd(),
+        prototype: Utf8CharIter::new(""),
+    };
+
+    Skeleton(prototypes.nfd())
+}
+
+/// Returns whether two strings are confusable, they have the same [`skeleton`]
+///
+/// Note that strings are confusable with themselves.
+///
+/// ```
+/// use utf8char::security::is_confusable;
+///
+/// assert!(is_confusable("paypal", "p\u{430}yp\u{430}l"));
+/// assert!(is_confusable("rn", "m"));
+/// assert!(is_confusable("I1l", "lll"));
+/// assert!(!is_confusable("apple", "appel"));
+/// ```
+#[must_use]
+pub fn is_confusable(a: &str, b: &str) -> bool {
+    skeleton(a).eq(skeleton(b))
+}
+
+/// How much a string restricts the scripts it mixes, the restriction levels of UTS #39 from most
+/// to least restrictive
+///
+/// Every level includes the ones before it, so levels compare by how restrictive they are:
+/// `restriction_level(s) <= RestrictionLevel::HighlyRestrictive` accepts strings that are
+/// highly restrictive or better.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RestrictionLevel {
+    /// Only ascii codepoints
+    AsciiOnly,
+    /// A single script, or a single writing system combining Han with other scripts, like
+    /// Japanese
+    SingleScript,
+    /// Latin combined with Han, Hiragana and Katakana, with Han and Bopomofo, or with Han and
+    /// Hangul
+    HighlyRestrictive,
+    /// Latin combined with a single other recommended script, except Cyrillic and Greek
+    ModeratelyRestrictive,
+    /// Any scripts
+    MinimallyRestrictive,
+    /// Codepoints [not allowed][Utf8Char::is_identifier_allowed] in identifiers
+    Unrestricted,
+}
+
+/// Returns the [`RestrictionLevel`] of a string, meant to be an identifier
+///
+/// ```
+/// use utf8char::security::{restriction_level, RestrictionLevel};
+///
+/// assert_eq!(restriction_level("paypal"), RestrictionLevel::AsciiOnly);
+/// assert_eq!(restriction_level("fa\u{E7}ade"), RestrictionLevel::SingleScript);
+/// assert_eq!(restriction_level("\u{6771}\u{4EAC}tokyo"), RestrictionLevel::HighlyRestrictive);
+/// assert_eq!(restriction_level("abc\u{5D0}"), RestrictionLevel::ModeratelyRestrictive);
+/// // Latin and Greek are easily confused
+/// assert_eq!(restriction_level("abc\u{3B1}"), RestrictionLevel::MinimallyRestrictive);
+/// assert_eq!(restriction_level("ab\u{200B}c"), RestrictionLevel::Unrestricted);
+/// ```
+#[must_use]
+pub fn restriction_level(s: &str) -> RestrictionLevel {
+    let mut ascii = true;
+    let mut resolver = ScriptResolver::new();
+    // the scripts of the codepoints that are not Latin
+    let mut other = ScriptResolver::new();
+
+    for ch in Utf8CharIter::new(s) {
+        if !ch.is_identifier_allowed() {
+            return RestrictionLevel::Unrestricted;
+        }
+
+        ascii &= ch.is_ascii();
+        resolver.push(ch);
+
+        if !ch.script_extensions().contains(Script::Latin) {
+            other.push(ch);
+        }
+    }
+
+    let mut scripts = other.resolved().iter();
+
+    if ascii {
+        RestrictionLevel::AsciiOnly
+    } else if !resolver.is_mixed() {
+        RestrictionLevel::SingleScript
+    } else if other.is_writing_system() {
+        RestrictionLevel::HighlyRestrictive
+    } else if let (Some(script), None) = (scripts.next(), scripts.next()) {
+        if RECOMMENDED.contains(&script) && !matches!(script, Script::Cyrillic | Script::Greek) {
+            RestrictionLevel::ModeratelyRestrictive
+        } else {
+            RestrictionLevel::MinimallyRestrictive
+        }
+    } else {
+        RestrictionLevel::MinimallyRestrictive
+    }
+}
+
+#[test]
+fn skeletons() {
+    use alloc::string::ToString;
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let ch = Utf8Char::from_char(c);
+
+        // canonically equivalent strings look the same
+        let decomposed = ch.decompose_canonical().to_string();
+
+        assert!(is_confusable(&ch, &decomposed), "{ch:?}");
+        assert!(skeleton(&ch).eq(skeleton(&decomposed)), "{ch:?}");
+    });
+}
+
+#[test]
+fn confusables() {
+    for (a, b, confusable) in [
+        ("paypal", "paypal", true),
+        ("p\u{430}yp\u{430}l", "paypal", true),
+        ("m", "rn", true),
+        ("g00gle", "gOOgle", true),
+        ("\u{3C1}", "p", true),
+        // default ignorables are invisible, wherever they are
+        ("pay\u{200B}pal", "paypal", true),
+        ("pay\u{AD}pal", "paypal", true),
+        ("\u{200D}pay\u{2060}pal\u{FE0F}", "paypal", true),
+        ("\u{200B}", "", true),
+        ("apple", "appel", false),
+        ("paypal", "pay pal", false),
+    ] {
+        assert_eq!(is_confusable(a, b), confusable, "{a:?} {b:?}");
+    }
+}
+
+#[test]
+fn restriction_levels() {
+    use RestrictionLevel::{
+        AsciiOnly, HighlyRestrictive, MinimallyRestrictive, ModeratelyRestrictive, SingleScript,
+        Unrestricted,
+    };
+
+    for (s, level) in [
+        ("", AsciiOnly),
+        ("hello_world", AsciiOnly),
+        ("caf\u{E9}", SingleScript),
+        ("\u{43F}\u{440}\u{438}\u{432}\u{435}\u{442}", SingleScript),
+        // Japanese mixes Han, Hiragana and Katakana
+        (
+            "\u{65E5}\u{672C}\u{8A9E}\u{306E}\u{30C6}\u{30AD}\u{30B9}\u{30C8}",
+            SingleScript,
+        ),
+        ("\u{65E5}\u{672C}\u{8A9E}abc", HighlyRestrictive),
+        ("\u{D55C}\u{AD6D}\u{C5B4}abc", HighlyRestrictive),
+        ("abc\u{915}", ModeratelyRestrictive),
+        ("abc\u{430}", MinimallyRestrictive),
+        ("\u{5D0}\u{430}", MinimallyRestrictive),
+        ("abc\u{5D0}\u{915}", MinimallyRestrictive),
+        ("a b", Unrestricted),
+        ("\u{1F600}", Unrestricted),
+    ] {
+        assert_eq!(restriction_level(s), level, "{s:?}");
+    }
+}
@@ -0,0 +1,109 @@
+//! The iteration the word and sentence segmentations of UAX #29 share, where whether there is a
+//! boundary depends on what is on both sides of it
+
+use core::iter::FusedIterator;
+
+use crate::Utf8CharIter;
+
+/// An iterator over the segments of a string between the boundaries a predicate finds
+///
+/// The predicate is always given the whole string and the candidate boundary, so iterating from
+/// the back finds the same segments as iterating from the front. It is also given the end of the
+/// segment being searched, which is known to be a boundary, so that it can stop looking around
+/// there rather than rescan the same run of codepoints for every segment in it.
+#[derive(Clone, Debug)]
+pub(crate) struct Segments<'slice> {
+    /// The whole string being segmented
+    s: &'slice str,
+    /// The byte offset of the next segment from the front, always a boundary
+    pub(crate) front: usize,
+    /// The byte offset after the next segment from the back, always a boundary
+    pub(crate) back: usize,
+    /// Returns whether there is a boundary at a byte offset strictly within the string, given
+    /// another offset that is known to be one
+    is_boundary: fn(&str, usize, usize) -> bool,
+}
+
+impl<'slice> Segments<'slice> {
+    /// Creates an iterator over the segments of `s`
+    pub(crate) fn new(s: &'slice str, is_boundary: fn(&str, usize, usize) -> bool) -> Self {
+        Self {
+            s,
+            front: 0,
+            back: s.len(),
+            is_boundary,
+        }
+    }
+
+    /// Returns the part of the string that has not been iterated over yet
+    pub(crate) fn as_str(&self) -> &'slice str {
+        &self.s[self.front..self.back]
+    }
+
+    /// Returns whether there is a boundary at a byte offset strictly within the string, given
+    /// another offset that is known to be one
+    fn is_boundary(&self, offset: usize, known: usize) -> bool {
+        (self.is_boundary)(self.s, offset, known)
+    }
+}
+
+impl<'slice> Iterator for Segments<'slice> {
+    type Item = &'slice str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.as_str();
+        let mut iter = Utf8CharIter::new(rest);
+        iter.next()?;
+
+        let len = loop {
+            let after = iter.as_str();
+
+            if after.is_empty() {
+                break rest.len();
+            }
+
+            if self.is_boundary(self.back - after.len(), self.front) {
+                break rest.len() - after.len();
+            }
+
+            iter.next();
+        };
+
+        self.front += len;
+        Some(&rest[..len])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        // every segment is at least one byte
+        (usize::from(len > 0), Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Segments<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let rest = self.as_str();
+        let mut iter = Utf8CharIter::new(rest);
+        iter.next_back()?;
+
+        let start = loop {
+            let before = iter.as_str();
+
+            if before.is_empty() {
+                break 0;
+            }
+
+            if self.is_boundary(self.front + before.len(), self.back) {
+                break before.len();
+            }
+
+            iter.next_back();
+        };
+
+        self.back = self.front + start;
+        Some(&rest[start..])
+    }
+}
+
+impl FusedIterator for Segments<'_> {}
@@ -0,0 +1,411 @@
+//! Sentence segmentation of strings, as defined in
+//! [UAX #29](https://www.unicode.org/reports/tr29/#Sentence_Boundaries)
+//!
+//! ```
+//! use utf8char::sentence::sentence_bounds;
+//!
+//! let text = "Mr. Smith left at 3.30 p.m. today. Why?\nNo one knew.";
+//!
+//! assert_eq!(
+//!     sentence_bounds(text).collect::<Vec<_>>(),
+//!     [
+//!         "Mr. ",
+//!         "Smith left at 3.30 p.m. today. ",
+//!         "Why?\n",
+//!         "No one knew.",
+//!     ],
+//! );
+//! ```
+//!
+//! The boundaries are heuristic: a period followed by a lowercase word does not end a sentence, but
+//! one followed by an uppercase word does, even after an abbreviation like "Mr.".
+
+use core::iter::{self, FusedIterator};
+
+use crate::{segment::Segments, tables::sentence::SENTENCE_BREAK, Utf8Char, Utf8CharIter};
+
+/// The `Sentence_Break` property of a codepoint, which sentence boundaries are determined by
+///
+/// Variants are named after the long property value aliases.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SentenceBreak {
+    /// `XX`: any codepoint without another value
+    Other,
+    /// `CR`: U+000D CARRIAGE RETURN only
+    CR,
+    /// `LF`: U+000A LINE FEED only
+    LF,
+    /// `EX`: combining marks and other codepoints that extend the one before them
+    Extend,
+    /// `SE`: the next line control and the line and paragraph separators
+    Sep,
+    /// `FO`: format codepoints, which are ignored like `Extend`
+    Format,
+    /// `SP`: spaces
+    Sp,
+    /// `LO`: lowercase letters
+    Lower,
+    /// `UP`: uppercase and titlecase letters
+    Upper,
+    /// `LE`: letters that are neither uppercase nor lowercase
+    OLetter,
+    /// `NU`: digits
+    Numeric,
+    /// `AT`: periods, which end sentences but also abbreviations and numbers
+    ATerm,
+    /// `SC`: commas, colons and other punctuation that continues a sentence
+    SContinue,
+    /// `ST`: exclamation marks, question marks and other punctuation that ends a sentence
+    STerm,
+    /// `CL`: closing punctuation and quotation marks
+    Close,
+}
+
+impl Utf8Char {
+    /// Returns the [`SentenceBreak`] property of this codepoint
+    ///
+    /// ```
+    /// use utf8char::{sentence::SentenceBreak, utf8char};
+    ///
+    /// assert_eq!(utf8char!("a").sentence_break(), SentenceBreak::Lower);
+    /// assert_eq!(utf8char!("A").sentence_break(), SentenceBreak::Upper);
+    /// assert_eq!(utf8char!(".").sentence_break(), SentenceBreak::ATerm);
+    /// assert_eq!(utf8char!("?").sentence_break(), SentenceBreak::STerm);
+    /// assert_eq!(utf8char!("。").sentence_break(), SentenceBreak::STerm);
+    /// ```
+    #[must_use]
+    pub const fn sentence_break(self) -> SentenceBreak {
+        SENTENCE_BREAK.get(self)
+    }
+}
+
+/// Returns whether `SB5` ignores a codepoint, as it extends the one before it
+const fn is_ignored(sentence_break: SentenceBreak) -> bool {
+    matches!(
+        sentence_break,
+        SentenceBreak::Extend | SentenceBreak::Format
+    )
+}
+
+/// Returns whether a `Sentence_Break` is `ParaSep`, it ends a paragraph
+const fn is_paragraph_separator(sentence_break: SentenceBreak) -> bool {
+    matches!(
+        sentence_break,
+        SentenceBreak::Sep | SentenceBreak::CR | SentenceBreak::LF
+    )
+}
+
+/// Returns whether there is a sentence boundary at a byte offset strictly within a string
+///
+/// Unlike word boundaries, these never depend on other boundaries, so the offset of one that the
+/// iterator knows of is not needed.
+fn is_boundary(s: &str, offset: usize, _known: usize) -> bool {
+    use SentenceBreak as S;
+
+    let (before, after) = s.split_at(offset);
+    let before = Utf8CharIter::new(before);
+    let after = Utf8CharIter::new(after);
+
+    let (Some(prev), Some(next)) = (before.clone().next_back(), after.clone().next()) else {
+        // SB1 and SB2
+        return true;
+    };
+
+    match (prev.sentence_break(), next.sentence_break()) {
+        // SB3
+        (S::CR, S::LF) => return false,
+        // SB4
+        (prev, _) if is_paragraph_separator(prev) => return true,
+        // SB5
+        (_, next) if is_ignored(next) => return false,
+        _ => {}
+    }
+
+    // the rules below skip over what SB5 ignores, the codepoints on either side of the boundary
+    // and further away
+    let mut before = before
+        .rev()
+        .map(Utf8Char::sentence_break)
+        .filter(|&sentence_break| !is_ignored(sentence_break))
+        .peekable();
+    let mut after = after
+        .map(Utf8Char::sentence_break)
+        .filter(|&sentence_break| !is_ignored(sentence_break));
+
+    let Some(next) = after.next() else {
+        return true;
+    };
+
+    match (before.clone().next(), next) {
+        // SB6
+        (Some(S::ATerm), S::Numeric) => return false,
+        // SB7
+        (Some(S::ATerm), S::Upper)
+            if matches!(before.clone().nth(1), Some(S::Upper | S::Lower)) =>
+        {
+            return false
+        }
+        _ => {}
+    }
+
+    // these never start a sentence, whatever comes before them: SB8a, SB9 and SB10 keep them after
+    // `SATerm Close* Sp*`, and SB998 everywhere else. Deciding them without looking back keeps
+    // long runs of spaces and closing marks from being rescanned for every codepoint in them
+    match next {
+        S::SContinue | S::ATerm | S::STerm | S::Sp => return false,
+        _ if is_paragraph_separator(next) => return false,
+        // SB9, a closing mark only starts a sentence after `SATerm Close* Sp+`
+        S::Close if before.peek() != Some(&S::Sp) => return false,
+        _ => {}
+    }
+
+    // the `SATerm Close* Sp*` the rules below apply after
+    while before.next_if_eq(&S::Sp).is_some() {}
+    while before.next_if_eq(&S::Close).is_some() {}
+
+    let Some(term @ (S::ATerm | S::STerm)) = before.next() else {
+        // SB998
+        return false;
+    };
+
+    // SB8, a period followed by a lowercase word is an abbreviation
+    let abbreviation = term == S::ATerm
+        && iter::once(next).chain(after).find(|&sentence_break| {
+            is_paragraph_separator(sentence_break)
+                || matches!(
+                    sentence_break,
+                    S::OLetter | S::Upper | S::Lower | S::ATerm | S::STerm
+                )
+        }) == Some(S::Lower);
+
+    // SB11
+    !abbreviation
+}
+
+/// An iterator over the sentences of a string, as `&str` slices
+///
+/// Created by [`sentence_bounds`]. Iterating from the back finds the same sentences as iterating
+/// from the front.
+#[derive(Clone, Debug)]
+pub struct SentenceBounds<'slice>(Segments<'slice>);
+
+impl<'slice> SentenceBounds<'slice> {
+    /// Returns the part of the string that has not been iterated over yet
+    #[must_use]
+    pub fn as_str(&self) -> &'slice str {
+        self.0.as_str()
+    }
+}
+
+impl<'slice> Iterator for SentenceBounds<'slice> {
+    type Item = &'slice str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for SentenceBounds<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl FusedIterator for SentenceBounds<'_> {}
+
+/// An iterator over the sentences of a string and their byte offsets
+///
+/// Created by [`sentence_bound_indices`].
+#[derive(Clone, Debug)]
+pub struct SentenceBoundIndices<'slice>(Segments<'slice>);
+
+impl<'slice> SentenceBoundIndices<'slice> {
+    /// Returns the part of the string that has not been iterated over yet
+    #[must_use]
+    pub fn as_str(&self) -> &'slice str {
+        self.0.as_str()
+    }
+}
+
+impl<'slice> Iterator for SentenceBoundIndices<'slice> {
+    type Item = (usize, &'slice str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.0.front;
+
+        self.0.next().map(|sentence| (offset, sentence))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for SentenceBoundIndices<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let sentence = self.0.next_back()?;
+
+        Some((self.0.back, sentence))
+    }
+}
+
+impl FusedIterator for SentenceBoundIndices<'_> {}
+
+/// Returns an iterator over the sentences of a string, each with the spaces and paragraph
+/// separator that follow it
+///
+/// ```
+/// use utf8char::sentence::sentence_bounds;
+///
+/// let sentences = sentence_bounds("Is it? (Yes.) It is e.g. fine.").collect::<Vec<_>>();
+///
+/// assert_eq!(sentences, ["Is it? ", "(Yes.) ", "It is e.g. fine."]);
+/// assert_eq!(sentence_bounds("A. B.").next_back(), Some("B."));
+/// ```
+#[must_use]
+pub fn sentence_bounds(s: &str) -> SentenceBounds<'_> {
+    SentenceBounds(Segments::new(s, is_boundary))
+}
+
+/// Returns an iterator over the sentences of a string, along with the byte offsets they start at
+///
+/// ```
+/// use utf8char::sentence::sentence_bound_indices;
+///
+/// let sentences = sentence_bound_indices("Stop. Go!").collect::<Vec<_>>();
+///
+/// assert_eq!(sentences, [(0, "Stop. "), (6, "Go!")]);
+/// assert_eq!(sentence_bound_indices("Stop. Go!").next_back(), Some((6, "Go!")));
+/// ```
+#[must_use]
+pub fn sentence_bound_indices(s: &str) -> SentenceBoundIndices<'_> {
+    SentenceBoundIndices(Segments::new(s, is_boundary))
+}
+
+#[test]
+fn sentence_breaks() {
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let utf8 = Utf8Char::from_char(c);
+        let sentence_break = utf8.sentence_break();
+
+        match c {
+            '\r' => assert_eq!(sentence_break, SentenceBreak::CR),
+            '\n' => assert_eq!(sentence_break, SentenceBreak::LF),
+            '.' => assert_eq!(sentence_break, SentenceBreak::ATerm),
+            '0'..='9' => assert_eq!(sentence_break, SentenceBreak::Numeric),
+            'a'..='z' => assert_eq!(sentence_break, SentenceBreak::Lower),
+            'A'..='Z' => assert_eq!(sentence_break, SentenceBreak::Upper),
+            _ if utf8.is_grapheme_extended() && c != '\u{200D}' => {
+                assert_eq!(sentence_break, SentenceBreak::Extend, "{c:?}");
+            }
+            _ => {}
+        }
+    });
+}
+
+#[test]
+fn segmentation() {
+    use alloc::vec::Vec;
+
+    for expected in [
+        &[][..],
+        &["Hello."],
+        &["One. ", "Two? ", "Three!"],
+        &["Line\n", "Line\r\n", "Line\u{2029}", "Line"],
+        // periods within numbers, abbreviations and initials
+        &["It costs 3.50 e.g. in U.S. stores. ", "Ok."],
+        &["Mr. ", "Smith."],
+        &["It is 5 p.m. now."],
+        // closing punctuation and spaces stay with the sentence they end
+        &["(Yes.)  ", "\"No!\" ", "Maybe."],
+        &["Wait... ", "What?!\n", "\n", "Ok"],
+        // continuing punctuation does not start a sentence
+        &["Hi!, he said. ", "Bye."],
+        // extending codepoints are ignored
+        &["A.\u{301} ", "B\u{AD}.\u{301}"],
+        &["\u{3042}\u{3002}", "\u{3044}\u{3002}"],
+    ] {
+        let s = expected.concat();
+        let forward = sentence_bounds(&s).collect::<Vec<_>>();
+        let mut backward = sentence_bounds(&s).rev().collect::<Vec<_>>();
+        backward.reverse();
+
+        assert_eq!(forward, expected, "{s:?}");
+        assert_eq!(backward, expected, "{s:?}");
+    }
+}
+
+#[test]
+fn conformance() {
+    use alloc::vec::Vec;
+
+    for &expected in crate::tables::conformance::SENTENCE_BREAK_TEST {
+        let s = expected.concat();
+        let forward = sentence_bounds(&s).collect::<Vec<_>>();
+        let mut backward = sentence_bounds(&s).rev().collect::<Vec<_>>();
+        backward.reverse();
+
+        assert_eq!(forward, expected, "{s:?}");
+        assert_eq!(backward, expected, "{s:?}");
+    }
+}
+
+#[test]
+fn long_runs() {
+    use alloc::vec::Vec;
+
+    /// Long enough that rescanning a run for every codepoint in it would take minutes
+    const RUN: usize = 100_000;
+
+    for (s, len) in [
+        // whether a sentence ends depends on what comes after the spaces and closing marks
+        (["x", &" ".repeat(RUN), "y"].concat(), 1),
+        (["x.", &" ".repeat(RUN), "Y"].concat(), 2),
+        (["x.", &")".repeat(RUN), " Y"].concat(), 2),
+        (["x.", &" ".repeat(RUN), &"1".repeat(RUN), "a"].concat(), 1),
+        // only the last closing mark is not followed by a lowercase word
+        ("x. ) ".repeat(RUN), 2),
+        ("\u{1F1E6}".repeat(RUN), 1),
+    ] {
+        let forward = sentence_bounds(&s).collect::<Vec<_>>();
+        let mut backward = sentence_bounds(&s).rev().collect::<Vec<_>>();
+        backward.reverse();
+
+        assert_eq!(forward.len(), len);
+        assert_eq!(forward, backward);
+    }
+}
+
+#[test]
+fn segmentation_is_symmetric() {
+    use alloc::{string::String, vec::Vec};
+    use itertools::Itertools;
+
+    // one codepoint of every property the rules distinguish
+    let chars = [
+        'a', 'A', '\u{5D0}', '1', '\r', '\n', '\u{2029}', ' ', '\u{301}', '\u{AD}', '.', '?', ',',
+        ')', '"',
+    ];
+
+    for len in 0..=5 {
+        for stream in (0..len).map(|_| chars).multi_cartesian_product() {
+            let s = stream.into_iter().collect::<String>();
+
+            let forward = sentence_bound_indices(&s).collect::<Vec<_>>();
+            let mut backward = sentence_bound_indices(&s).rev().collect::<Vec<_>>();
+            backward.reverse();
+
+            assert_eq!(forward, backward, "{s:?}");
+            assert_eq!(forward.iter().map(|(_, w)| *w).collect::<String>(), s);
+            assert!(forward
+                .iter()
+                .all(|(offset, w)| s[*offset..].starts_with(w)));
+        }
+    }
+}
@@ -9,6 +9,16 @@ pub(crate) const TAG_CONTINUATION: u8 = 0b1000_0000;
 /// Const modified copy of `str::chars().next().unwrap_unchecked()`
 /// see inner comments for details
 pub(crate) const fn to_char(code: Utf8Char) -> char {
+    let ch = to_u32(code);
+
+    debug_assert!(char::from_u32(ch).is_some());
+
+    // SAFETY: Utf8Char must always be valid utf8 so this must always be valid
+    unsafe { char::from_u32_unchecked(ch) }
+}
+
+/// Decodes the scalar value of a `Utf8Char`, the integer half of [`to_char`]
+pub(crate) const fn to_u32(code: Utf8Char) -> u32 {
     // this method is provably correct for all unicode characters: it is tested below
     // this entire function is copied off of the implementation of str::chars() because it is
     // highly performant
@@ -36,7 +46,7 @@ pub(crate) const fn to_char(code: Utf8Char) -> char {
     let x = arr[0];
 
     if x < 128 {
-        return x as char;
+        return x as u32;
     }
 
     let init = utf8_first_byte(x, 2);
@@ -58,8 +68,55 @@ pub(crate) const fn to_char(code: Utf8Char) -> char {
         }
     }
 
-    debug_assert!(char::from_u32(ch).is_some());
+    ch
+}
 
-    // SAFETY: Utf8Char must always be valid utf8 so this must always be valid
-    unsafe { char::from_u32_unchecked(ch) }
+/// Const modified copy of `core::char::encode_utf8_raw`, writing into the padded `Utf8Char`
+/// representation instead of a caller provided buffer
+///
+/// `code` must be a unicode scalar value for the output to be a valid `Utf8Char` representation
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "truncation is the intent, every cast is masked to the bits it keeps"
+)]
+pub(crate) const fn encode_utf8(code: u32) -> [u8; 4] {
+    /// Tag of the first byte of a 2 byte sequence
+    const TAG_TWO_B: u8 = 0b1100_0000;
+    /// Tag of the first byte of a 3 byte sequence
+    const TAG_THREE_B: u8 = 0b1110_0000;
+    /// Tag of the first byte of a 4 byte sequence
+    const TAG_FOUR_B: u8 = 0b1111_0000;
+    /// First codepoint that does not fit in a 1 byte sequence
+    const MAX_ONE_B: u32 = 0x80;
+    /// First codepoint that does not fit in a 2 byte sequence
+    const MAX_TWO_B: u32 = 0x800;
+    /// First codepoint that does not fit in a 3 byte sequence
+    const MAX_THREE_B: u32 = 0x10000;
+
+    /// Continuation byte containing the 6 bits of `code` starting at `shift`
+    const fn cont(code: u32, shift: u32) -> u8 {
+        (code >> shift) as u8 & 0x3F | TAG_CONTINUATION
+    }
+
+    const PAD: u8 = TAG_CONTINUATION;
+
+    if code < MAX_ONE_B {
+        [code as u8, PAD, PAD, PAD]
+    } else if code < MAX_TWO_B {
+        [(code >> 6) as u8 | TAG_TWO_B, cont(code, 0), PAD, PAD]
+    } else if code < MAX_THREE_B {
+        [
+            (code >> 12) as u8 | TAG_THREE_B,
+            cont(code, 6),
+            cont(code, 0),
+            PAD,
+        ]
+    } else {
+        [
+            (code >> 18) as u8 & 0x07 | TAG_FOUR_B,
+            cont(code, 12),
+            cont(code, 6),
+            cont(code, 0),
+        ]
+    }
 }
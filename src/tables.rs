@@ -0,0 +1,101 @@
+//! Unicode data tables, and the trie used to look codepoints up in them by their utf8 bytes
+//!
+//! The submodules are generated by the python scripts next to them, see `tables/ucd.py`.
+
+use crate::Utf8Char;
+
+#[rustfmt::skip]
+pub(crate) mod bidi;
+#[rustfmt::skip]
+pub(crate) mod case;
+#[cfg(test)]
+#[rustfmt::skip]
+pub(crate) mod conformance;
+#[rustfmt::skip]
+pub(crate) mod emoji;
+#[rustfmt::skip]
+pub(crate) mod grapheme;
+#[rustfmt::skip]
+pub(crate) mod line;
+#[rustfmt::skip]
+pub(crate) mod name;
+#[rustfmt::skip]
+pub(crate) mod normalization;
+#[rustfmt::skip]
+pub(crate) mod numeric;
+#[rustfmt::skip]
+pub(crate) mod properties;
+#[rustfmt::skip]
+pub(crate) mod script;
+#[rustfmt::skip]
+pub(crate) mod security;
+#[rustfmt::skip]
+pub(crate) mod sentence;
+#[rustfmt::skip]
+pub(crate) mod width;
+#[rustfmt::skip]
+pub(crate) mod word;
+
+/// The amount of entries in a trie node or leaf, one per data bit combination of a continuation byte
+pub(crate) const LEVEL: usize = 64;
+
+/// A trie keyed by the utf8 encoding of a codepoint, each level consuming one byte.
+///
+/// This lets a `Utf8Char` be looked up without first decoding it to a scalar value, every byte
+/// picks the next level by its data bits:
+/// - 1 byte: `lead` gives a leaf, indexed by the low 6 bits of the byte
+/// - 2 bytes: `lead` gives a leaf, indexed by the second byte
+/// - 3 bytes: `lead` gives a node, the second byte picks a leaf, the third byte indexes it
+/// - 4 bytes: `lead` gives a node, the second byte picks another node, the third byte picks a leaf,
+///   the fourth byte indexes it
+pub(crate) struct Utf8Trie<L: 'static> {
+    /// Leaf (1 and 2 byte sequences) or node (3 and 4 byte sequences) index, by first byte
+    pub(crate) lead: &'static [u16; 256],
+    /// Node or leaf indexes, by the data bits of a continuation byte
+    pub(crate) nodes: &'static [[u16; LEVEL]],
+    /// Values, by the data bits of the last byte
+    pub(crate) leaves: &'static [L],
+}
+
+impl<L> Utf8Trie<L> {
+    /// Returns the leaf containing a codepoints value, and the index of the value within it
+    const fn locate(&self, ch: Utf8Char) -> (&L, usize) {
+        /// Index into a level by the data bits of a continuation byte
+        const fn data(b: u8) -> usize {
+            (b & 0b0011_1111) as usize
+        }
+
+        let [a, b, c, d] = ch.to_bytes();
+        let first = self.lead[a as usize] as usize;
+
+        let (leaf, idx) = match ch.len_utf8() {
+            1 => (first, data(a)),
+            2 => (first, data(b)),
+            3 => (self.nodes[first][data(b)] as usize, data(c)),
+            _ => {
+                let node = self.nodes[first][data(b)] as usize;
+                (self.nodes[node][data(c)] as usize, data(d))
+            }
+        };
+
+        (&self.leaves[leaf], idx)
+    }
+}
+
+impl Utf8Trie<u64> {
+    /// Returns whether a codepoint is in the set this trie represents
+    pub(crate) const fn contains(&self, ch: Utf8Char) -> bool {
+        let (leaf, idx) = self.locate(ch);
+
+        (*leaf >> idx) & 1 == 1
+    }
+}
+
+impl<T: Copy> Utf8Trie<[T; LEVEL]> {
+    /// Returns the value a codepoint maps to
+    pub(crate) const fn get(&self, ch: Utf8Char) -> T {
+        let (leaf, idx) = self.locate(ch);
+
+        leaf[idx]
+    }
+}
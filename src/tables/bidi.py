@@ -0,0 +1,118 @@
+"""Generates src/tables/bidi.rs: the properties the Unicode Bidirectional Algorithm (UAX #9) is
+defined in terms of.
+
+    python3 src/tables/bidi.py <path to UCD> > src/tables/bidi.rs
+
+`MIRRORING_GLYPHS` and `BRACKETS` are sorted by their first column, and searched with a binary search
+as they are too sparse for a trie to pay off.
+"""
+
+from ucd import CODEPOINTS, bool_trie, header, parse_codepoints, parse_lines, parse_range, ucd_dir, unicode_data, utf8char_bits, value_trie, wrap
+
+# the `@missing` defaults of DerivedBidiClass.txt, unassigned codepoints in the blocks of right to
+# left scripts and of currency symbols default to their class, the rest to L
+MISSING = [
+    ("0590..05FF", "R"),
+    ("0600..07BF", "AL"),
+    ("07C0..085F", "R"),
+    ("0860..08FF", "AL"),
+    ("20A0..20CF", "ET"),
+    ("FB1D..FB4F", "R"),
+    ("FB50..FDCF", "AL"),
+    ("FDF0..FDFF", "AL"),
+    ("FE70..FEFF", "AL"),
+    ("10800..10CFF", "R"),
+    ("10D00..10D3F", "AL"),
+    ("10D40..10EBF", "R"),
+    ("10EC0..10EFF", "AL"),
+    ("10F00..10F2F", "R"),
+    ("10F30..10F6F", "AL"),
+    ("10F70..10FFF", "R"),
+    ("1E800..1EC6F", "R"),
+    ("1EC70..1ECBF", "AL"),
+    ("1ECC0..1ECFF", "R"),
+    ("1ED00..1ED4F", "AL"),
+    ("1ED50..1EDFF", "R"),
+    ("1EE00..1EEFF", "AL"),
+    ("1EF00..1EFFF", "R"),
+]
+
+# `BidiClass` variant by short alias
+BIDI_CLASSES = {
+    "L": "LeftToRight",
+    "R": "RightToLeft",
+    "AL": "ArabicLetter",
+    "EN": "EuropeanNumber",
+    "ES": "EuropeanSeparator",
+    "ET": "EuropeanTerminator",
+    "AN": "ArabicNumber",
+    "CS": "CommonSeparator",
+    "NSM": "NonspacingMark",
+    "BN": "BoundaryNeutral",
+    "B": "ParagraphSeparator",
+    "S": "SegmentSeparator",
+    "WS": "WhiteSpace",
+    "ON": "OtherNeutral",
+    "LRE": "LeftToRightEmbedding",
+    "LRO": "LeftToRightOverride",
+    "RLE": "RightToLeftEmbedding",
+    "RLO": "RightToLeftOverride",
+    "PDF": "PopDirectionalFormat",
+    "LRI": "LeftToRightIsolate",
+    "RLI": "RightToLeftIsolate",
+    "FSI": "FirstStrongIsolate",
+    "PDI": "PopDirectionalIsolate",
+}
+
+
+def main() -> None:
+    ucd = ucd_dir()
+
+    classes = ["L"] * CODEPOINTS
+
+    for missing, value in MISSING:
+        for cp in parse_range(missing):
+            classes[cp] = value
+
+    for fields in parse_lines(ucd / "extracted/DerivedBidiClass.txt"):
+        for cp in parse_range(fields[0]):
+            classes[cp] = fields[1]
+
+    mirrored = [False] * CODEPOINTS
+
+    for cp, fields in unicode_data(ucd / "UnicodeData.txt").items():
+        mirrored[cp] = fields[9] == "Y"
+
+    glyphs = sorted((utf8char_bits(int(fields[0], 16)), utf8char_bits(*parse_codepoints(fields[1]))) for fields in parse_lines(ucd / "BidiMirroring.txt"))
+    brackets = sorted((utf8char_bits(int(fields[0], 16)), utf8char_bits(*parse_codepoints(fields[1])), fields[2] == "o") for fields in parse_lines(ucd / "BidiBrackets.txt"))
+
+    def bits(value: int) -> str:
+        digits = f"{value:08X}"
+        return f"0x{digits[:4]}_{digits[4:]}"
+
+    print(header("bidi.py", ["extracted/DerivedBidiClass.txt", "UnicodeData.txt", "BidiMirroring.txt", "BidiBrackets.txt"]))
+    print("use super::Utf8Trie;")
+    print("use crate::bidi::BidiClass;")
+    print()
+
+    for short, variant in BIDI_CLASSES.items():
+        print(f"/// `BidiClass::{variant}`, shortened to keep the table below readable")
+        print(f"const {short}: BidiClass = BidiClass::{variant};")
+
+    print()
+    print("/// The `Bidi_Mirroring_Glyph` of codepoints that have one, as `Utf8Char::to_bits`")
+    print(f"pub(crate) static MIRRORING_GLYPHS: [(u32, u32); {len(glyphs)}] = [")
+    print("\n".join(wrap((f"({bits(a)}, {bits(b)})," for a, b in glyphs), indent=4)))
+    print("];")
+    print()
+    print("/// The `Bidi_Paired_Bracket` of paired brackets as `Utf8Char::to_bits`, and whether they open")
+    print(f"pub(crate) static BRACKETS: [(u32, u32, bool); {len(brackets)}] = [")
+    print("\n".join(wrap((f"({bits(a)}, {bits(b)}, {str(o).lower()})," for a, b, o in brackets), indent=4)))
+    print("];")
+    print()
+    print(value_trie("BIDI_CLASS", "The `Bidi_Class` of every codepoint", "BidiClass", classes))
+    print(bool_trie("BIDI_MIRRORED", "Codepoints with the `Bidi_Mirrored` property", mirrored), end="")
+
+
+if __name__ == "__main__":
+    main()
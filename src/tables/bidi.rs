@@ -0,0 +1,1853 @@
+//! Generated by `src/tables/bidi.py` from the Unicode 17.0.0 files
+//! extracted/DerivedBidiClass.txt, UnicodeData.txt, BidiMirroring.txt, BidiBrackets.txt. Do not edit by hand.
+
+use super::Utf8Trie;
+use crate::bidi::BidiClass;
+
+/// `BidiClass::LeftToRight`, shortened to keep the table below readable
+const L: BidiClass = BidiClass::LeftToRight;
+/// `BidiClass::RightToLeft`, shortened to keep the table below readable
+const R: BidiClass = BidiClass::RightToLeft;
+/// `BidiClass::ArabicLetter`, shortened to keep the table below readable
+const AL: BidiClass = BidiClass::ArabicLetter;
+/// `BidiClass::EuropeanNumber`, shortened to keep the table below readable
+const EN: BidiClass = BidiClass::EuropeanNumber;
+/// `BidiClass::EuropeanSeparator`, shortened to keep the table below readable
+const ES: BidiClass = BidiClass::EuropeanSeparator;
+/// `BidiClass::EuropeanTerminator`, shortened to keep the table below readable
+const ET: BidiClass = BidiClass::EuropeanTerminator;
+/// `BidiClass::ArabicNumber`, shortened to keep the table below readable
+const AN: BidiClass = BidiClass::ArabicNumber;
+/// `BidiClass::CommonSeparator`, shortened to keep the table below readable
+const CS: BidiClass = BidiClass::CommonSeparator;
+/// `BidiClass::NonspacingMark`, shortened to keep the table below readable
+const NSM: BidiClass = BidiClass::NonspacingMark;
+/// `BidiClass::BoundaryNeutral`, shortened to keep the table below readable
+const BN: BidiClass = BidiClass::BoundaryNeutral;
+/// `BidiClass::ParagraphSeparator`, shortened to keep the table below readable
+const B: BidiClass = BidiClass::ParagraphSeparator;
+/// `BidiClass::SegmentSeparator`, shortened to keep the table below readable
+const S: BidiClass = BidiClass::SegmentSeparator;
+/// `BidiClass::WhiteSpace`, shortened to keep the table below readable
+const WS: BidiClass = BidiClass::WhiteSpace;
+/// `BidiClass::OtherNeutral`, shortened to keep the table below readable
+const ON: BidiClass = BidiClass::OtherNeutral;
+/// `BidiClass::LeftToRightEmbedding`, shortened to keep the table below readable
+const LRE: BidiClass = BidiClass::LeftToRightEmbedding;
+/// `BidiClass::LeftToRightOverride`, shortened to keep the table below readable
+const LRO: BidiClass = BidiClass::LeftToRightOverride;
+/// `BidiClass::RightToLeftEmbedding`, shortened to keep the table below readable
+const RLE: BidiClass = BidiClass::RightToLeftEmbedding;
+/// `BidiClass::RightToLeftOverride`, shortened to keep the table below readable
+const RLO: BidiClass = BidiClass::RightToLeftOverride;
+/// `BidiClass::PopDirectionalFormat`, shortened to keep the table below readable
+const PDF: BidiClass = BidiClass::PopDirectionalFormat;
+/// `BidiClass::LeftToRightIsolate`, shortened to keep the table below readable
+const LRI: BidiClass = BidiClass::LeftToRightIsolate;
+/// `BidiClass::RightToLeftIsolate`, shortened to keep the table below readable
+const RLI: BidiClass = BidiClass::RightToLeftIsolate;
+/// `BidiClass::FirstStrongIsolate`, shortened to keep the table below readable
+const FSI: BidiClass = BidiClass::FirstStrongIsolate;
+/// `BidiClass::PopDirectionalIsolate`, shortened to keep the table below readable
+const PDI: BidiClass = BidiClass::PopDirectionalIsolate;
+
+/// The `Bidi_Mirroring_Glyph` of codepoints that have one, as `Utf8Char::to_bits`
+pub(crate) static MIRRORING_GLYPHS: [(u32, u32); 428] = [
+    (0x2880_8080, 0x2980_8080), (0x2980_8080, 0x2880_8080), (0x3C80_8080, 0x3E80_8080),
+    (0x3E80_8080, 0x3C80_8080), (0x5B80_8080, 0x5D80_8080), (0x5D80_8080, 0x5B80_8080),
+    (0x7B80_8080, 0x7D80_8080), (0x7D80_8080, 0x7B80_8080), (0xC2AB_8080, 0xC2BB_8080),
+    (0xC2BB_8080, 0xC2AB_8080), (0xE0BC_BA80, 0xE0BC_BB80), (0xE0BC_BB80, 0xE0BC_BA80),
+    (0xE0BC_BC80, 0xE0BC_BD80), (0xE0BC_BD80, 0xE0BC_BC80), (0xE19A_9B80, 0xE19A_9C80),
+    (0xE19A_9C80, 0xE19A_9B80), (0xE280_B980, 0xE280_BA80), (0xE280_BA80, 0xE280_B980),
+    (0xE281_8580, 0xE281_8680), (0xE281_8680, 0xE281_8580), (0xE281_BD80, 0xE281_BE80),
+    (0xE281_BE80, 0xE281_BD80), (0xE282_8D80, 0xE282_8E80), (0xE282_8E80, 0xE282_8D80),
+    (0xE288_8880, 0xE288_8B80), (0xE288_8980, 0xE288_8C80), (0xE288_8A80, 0xE288_8D80),
+    (0xE288_8B80, 0xE288_8880), (0xE288_8C80, 0xE288_8980), (0xE288_8D80, 0xE288_8A80),
+    (0xE288_9580, 0xE2A7_B580), (0xE288_9F80, 0xE2AF_BE80), (0xE288_A080, 0xE2A6_A380),
+    (0xE288_A180, 0xE2A6_9B80), (0xE288_A280, 0xE2A6_A080), (0xE288_A480, 0xE2AB_AE80),
+    (0xE288_BC80, 0xE288_BD80), (0xE288_BD80, 0xE288_BC80), (0xE289_8380, 0xE28B_8D80),
+    (0xE289_8580, 0xE289_8C80), (0xE289_8C80, 0xE289_8580), (0xE289_9280, 0xE289_9380),
+    (0xE289_9380, 0xE289_9280), (0xE289_9480, 0xE289_9580), (0xE289_9580, 0xE289_9480),
+    (0xE289_A480, 0xE289_A580), (0xE289_A580, 0xE289_A480), (0xE289_A680, 0xE289_A780),
+    (0xE289_A780, 0xE289_A680), (0xE289_A880, 0xE289_A980), (0xE289_A980, 0xE289_A880),
+    (0xE289_AA80, 0xE289_AB80), (0xE289_AB80, 0xE289_AA80), (0xE289_AE80, 0xE289_AF80),
+    (0xE289_AF80, 0xE289_AE80), (0xE289_B080, 0xE289_B180), (0xE289_B180, 0xE289_B080),
+    (0xE289_B280, 0xE289_B380), (0xE289_B380, 0xE289_B280), (0xE289_B480, 0xE289_B580),
+    (0xE289_B580, 0xE289_B480), (0xE289_B680, 0xE289_B780), (0xE289_B780, 0xE289_B680),
+    (0xE289_B880, 0xE289_B980), (0xE289_B980, 0xE289_B880), (0xE289_BA80, 0xE289_BB80),
+    (0xE289_BB80, 0xE289_BA80), (0xE289_BC80, 0xE289_BD80), (0xE289_BD80, 0xE289_BC80),
+    (0xE289_BE80, 0xE289_BF80), (0xE289_BF80, 0xE289_BE80), (0xE28A_8080, 0xE28A_8180),
+    (0xE28A_8180, 0xE28A_8080), (0xE28A_8280, 0xE28A_8380), (0xE28A_8380, 0xE28A_8280),
+    (0xE28A_8480, 0xE28A_8580), (0xE28A_8580, 0xE28A_8480), (0xE28A_8680, 0xE28A_8780),
+    (0xE28A_8780, 0xE28A_8680), (0xE28A_8880, 0xE28A_8980), (0xE28A_8980, 0xE28A_8880),
+    (0xE28A_8A80, 0xE28A_8B80), (0xE28A_8B80, 0xE28A_8A80), (0xE28A_8F80, 0xE28A_9080),
+    (0xE28A_9080, 0xE28A_8F80), (0xE28A_9180, 0xE28A_9280), (0xE28A_9280, 0xE28A_9180),
+    (0xE28A_9880, 0xE2A6_B880), (0xE28A_A280, 0xE28A_A380), (0xE28A_A380, 0xE28A_A280),
+    (0xE28A_A680, 0xE2AB_9E80), (0xE28A_A880, 0xE2AB_A480), (0xE28A_A980, 0xE2AB_A380),
+    (0xE28A_AB80, 0xE2AB_A580), (0xE28A_B080, 0xE28A_B180), (0xE28A_B180, 0xE28A_B080),
+    (0xE28A_B280, 0xE28A_B380), (0xE28A_B380, 0xE28A_B280), (0xE28A_B480, 0xE28A_B580),
+    (0xE28A_B580, 0xE28A_B480), (0xE28A_B680, 0xE28A_B780), (0xE28A_B780, 0xE28A_B680),
+    (0xE28A_B880, 0xE29F_9C80), (0xE28B_8980, 0xE28B_8A80), (0xE28B_8A80, 0xE28B_8980),
+    (0xE28B_8B80, 0xE28B_8C80), (0xE28B_8C80, 0xE28B_8B80), (0xE28B_8D80, 0xE289_8380),
+    (0xE28B_9080, 0xE28B_9180), (0xE28B_9180, 0xE28B_9080), (0xE28B_9680, 0xE28B_9780),
+    (0xE28B_9780, 0xE28B_9680), (0xE28B_9880, 0xE28B_9980), (0xE28B_9980, 0xE28B_9880),
+    (0xE28B_9A80, 0xE28B_9B80), (0xE28B_9B80, 0xE28B_9A80), (0xE28B_9C80, 0xE28B_9D80),
+    (0xE28B_9D80, 0xE28B_9C80), (0xE28B_9E80, 0xE28B_9F80), (0xE28B_9F80, 0xE28B_9E80),
+    (0xE28B_A080, 0xE28B_A180), (0xE28B_A180, 0xE28B_A080), (0xE28B_A280, 0xE28B_A380),
+    (0xE28B_A380, 0xE28B_A280), (0xE28B_A480, 0xE28B_A580), (0xE28B_A580, 0xE28B_A480),
+    (0xE28B_A680, 0xE28B_A780), (0xE28B_A780, 0xE28B_A680), (0xE28B_A880, 0xE28B_A980),
+    (0xE28B_A980, 0xE28B_A880), (0xE28B_AA80, 0xE28B_AB80), (0xE28B_AB80, 0xE28B_AA80),
+    (0xE28B_AC80, 0xE28B_AD80), (0xE28B_AD80, 0xE28B_AC80), (0xE28B_B080, 0xE28B_B180),
+    (0xE28B_B180, 0xE28B_B080), (0xE28B_B280, 0xE28B_BA80), (0xE28B_B380, 0xE28B_BB80),
+    (0xE28B_B480, 0xE28B_BC80), (0xE28B_B680, 0xE28B_BD80), (0xE28B_B780, 0xE28B_BE80),
+    (0xE28B_BA80, 0xE28B_B280), (0xE28B_BB80, 0xE28B_B380), (0xE28B_BC80, 0xE28B_B480),
+    (0xE28B_BD80, 0xE28B_B680), (0xE28B_BE80, 0xE28B_B780), (0xE28C_8880, 0xE28C_8980),
+    (0xE28C_8980, 0xE28C_8880), (0xE28C_8A80, 0xE28C_8B80), (0xE28C_8B80, 0xE28C_8A80),
+    (0xE28C_A980, 0xE28C_AA80), (0xE28C_AA80, 0xE28C_A980), (0xE29D_A880, 0xE29D_A980),
+    (0xE29D_A980, 0xE29D_A880), (0xE29D_AA80, 0xE29D_AB80), (0xE29D_AB80, 0xE29D_AA80),
+    (0xE29D_AC80, 0xE29D_AD80), (0xE29D_AD80, 0xE29D_AC80), (0xE29D_AE80, 0xE29D_AF80),
+    (0xE29D_AF80, 0xE29D_AE80), (0xE29D_B080, 0xE29D_B180), (0xE29D_B180, 0xE29D_B080),
+    (0xE29D_B280, 0xE29D_B380), (0xE29D_B380, 0xE29D_B280), (0xE29D_B480, 0xE29D_B580),
+    (0xE29D_B580, 0xE29D_B480), (0xE29F_8380, 0xE29F_8480), (0xE29F_8480, 0xE29F_8380),
+    (0xE29F_8580, 0xE29F_8680), (0xE29F_8680, 0xE29F_8580), (0xE29F_8880, 0xE29F_8980),
+    (0xE29F_8980, 0xE29F_8880), (0xE29F_8B80, 0xE29F_8D80), (0xE29F_8D80, 0xE29F_8B80),
+    (0xE29F_9580, 0xE29F_9680), (0xE29F_9680, 0xE29F_9580), (0xE29F_9C80, 0xE28A_B880),
+    (0xE29F_9D80, 0xE29F_9E80), (0xE29F_9E80, 0xE29F_9D80), (0xE29F_A280, 0xE29F_A380),
+    (0xE29F_A380, 0xE29F_A280), (0xE29F_A480, 0xE29F_A580), (0xE29F_A580, 0xE29F_A480),
+    (0xE29F_A680, 0xE29F_A780), (0xE29F_A780, 0xE29F_A680), (0xE29F_A880, 0xE29F_A980),
+    (0xE29F_A980, 0xE29F_A880), (0xE29F_AA80, 0xE29F_AB80), (0xE29F_AB80, 0xE29F_AA80),
+    (0xE29F_AC80, 0xE29F_AD80), (0xE29F_AD80, 0xE29F_AC80), (0xE29F_AE80, 0xE29F_AF80),
+    (0xE29F_AF80, 0xE29F_AE80), (0xE2A6_8380, 0xE2A6_8480), (0xE2A6_8480, 0xE2A6_8380),
+    (0xE2A6_8580, 0xE2A6_8680), (0xE2A6_8680, 0xE2A6_8580), (0xE2A6_8780, 0xE2A6_8880),
+    (0xE2A6_8880, 0xE2A6_8780), (0xE2A6_8980, 0xE2A6_8A80), (0xE2A6_8A80, 0xE2A6_8980),
+    (0xE2A6_8B80, 0xE2A6_8C80), (0xE2A6_8C80, 0xE2A6_8B80), (0xE2A6_8D80, 0xE2A6_9080),
+    (0xE2A6_8E80, 0xE2A6_8F80), (0xE2A6_8F80, 0xE2A6_8E80), (0xE2A6_9080, 0xE2A6_8D80),
+    (0xE2A6_9180, 0xE2A6_9280), (0xE2A6_9280, 0xE2A6_9180), (0xE2A6_9380, 0xE2A6_9480),
+    (0xE2A6_9480, 0xE2A6_9380), (0xE2A6_9580, 0xE2A6_9680), (0xE2A6_9680, 0xE2A6_9580),
+    (0xE2A6_9780, 0xE2A6_9880), (0xE2A6_9880, 0xE2A6_9780), (0xE2A6_9B80, 0xE288_A180),
+    (0xE2A6_A080, 0xE288_A280), (0xE2A6_A380, 0xE288_A080), (0xE2A6_A480, 0xE2A6_A580),
+    (0xE2A6_A580, 0xE2A6_A480), (0xE2A6_A880, 0xE2A6_A980), (0xE2A6_A980, 0xE2A6_A880),
+    (0xE2A6_AA80, 0xE2A6_AB80), (0xE2A6_AB80, 0xE2A6_AA80), (0xE2A6_AC80, 0xE2A6_AD80),
+    (0xE2A6_AD80, 0xE2A6_AC80), (0xE2A6_AE80, 0xE2A6_AF80), (0xE2A6_AF80, 0xE2A6_AE80),
+    (0xE2A6_B880, 0xE28A_9880), (0xE2A7_8080, 0xE2A7_8180), (0xE2A7_8180, 0xE2A7_8080),
+    (0xE2A7_8480, 0xE2A7_8580), (0xE2A7_8580, 0xE2A7_8480), (0xE2A7_8F80, 0xE2A7_9080),
+    (0xE2A7_9080, 0xE2A7_8F80), (0xE2A7_9180, 0xE2A7_9280), (0xE2A7_9280, 0xE2A7_9180),
+    (0xE2A7_9480, 0xE2A7_9580), (0xE2A7_9580, 0xE2A7_9480), (0xE2A7_9880, 0xE2A7_9980),
+    (0xE2A7_9980, 0xE2A7_9880), (0xE2A7_9A80, 0xE2A7_9B80), (0xE2A7_9B80, 0xE2A7_9A80),
+    (0xE2A7_A880, 0xE2A7_A980), (0xE2A7_A980, 0xE2A7_A880), (0xE2A7_B580, 0xE288_9580),
+    (0xE2A7_B880, 0xE2A7_B980), (0xE2A7_B980, 0xE2A7_B880), (0xE2A7_BC80, 0xE2A7_BD80),
+    (0xE2A7_BD80, 0xE2A7_BC80), (0xE2A8_AB80, 0xE2A8_AC80), (0xE2A8_AC80, 0xE2A8_AB80),
+    (0xE2A8_AD80, 0xE2A8_AE80), (0xE2A8_AE80, 0xE2A8_AD80), (0xE2A8_B480, 0xE2A8_B580),
+    (0xE2A8_B580, 0xE2A8_B480), (0xE2A8_BC80, 0xE2A8_BD80), (0xE2A8_BD80, 0xE2A8_BC80),
+    (0xE2A9_A480, 0xE2A9_A580), (0xE2A9_A580, 0xE2A9_A480), (0xE2A9_B980, 0xE2A9_BA80),
+    (0xE2A9_BA80, 0xE2A9_B980), (0xE2A9_BB80, 0xE2A9_BC80), (0xE2A9_BC80, 0xE2A9_BB80),
+    (0xE2A9_BD80, 0xE2A9_BE80), (0xE2A9_BE80, 0xE2A9_BD80), (0xE2A9_BF80, 0xE2AA_8080),
+    (0xE2AA_8080, 0xE2A9_BF80), (0xE2AA_8180, 0xE2AA_8280), (0xE2AA_8280, 0xE2AA_8180),
+    (0xE2AA_8380, 0xE2AA_8480), (0xE2AA_8480, 0xE2AA_8380), (0xE2AA_8580, 0xE2AA_8680),
+    (0xE2AA_8680, 0xE2AA_8580), (0xE2AA_8780, 0xE2AA_8880), (0xE2AA_8880, 0xE2AA_8780),
+    (0xE2AA_8980, 0xE2AA_8A80), (0xE2AA_8A80, 0xE2AA_8980), (0xE2AA_8B80, 0xE2AA_8C80),
+    (0xE2AA_8C80, 0xE2AA_8B80), (0xE2AA_8D80, 0xE2AA_8E80), (0xE2AA_8E80, 0xE2AA_8D80),
+    (0xE2AA_8F80, 0xE2AA_9080), (0xE2AA_9080, 0xE2AA_8F80), (0xE2AA_9180, 0xE2AA_9280),
+    (0xE2AA_9280, 0xE2AA_9180), (0xE2AA_9380, 0xE2AA_9480), (0xE2AA_9480, 0xE2AA_9380),
+    (0xE2AA_9580, 0xE2AA_9680), (0xE2AA_9680, 0xE2AA_9580), (0xE2AA_9780, 0xE2AA_9880),
+    (0xE2AA_9880, 0xE2AA_9780), (0xE2AA_9980, 0xE2AA_9A80), (0xE2AA_9A80, 0xE2AA_9980),
+    (0xE2AA_9B80, 0xE2AA_9C80), (0xE2AA_9C80, 0xE2AA_9B80), (0xE2AA_9D80, 0xE2AA_9E80),
+    (0xE2AA_9E80, 0xE2AA_9D80), (0xE2AA_9F80, 0xE2AA_A080), (0xE2AA_A080, 0xE2AA_9F80),
+    (0xE2AA_A180, 0xE2AA_A280), (0xE2AA_A280, 0xE2AA_A180), (0xE2AA_A680, 0xE2AA_A780),
+    (0xE2AA_A780, 0xE2AA_A680), (0xE2AA_A880, 0xE2AA_A980), (0xE2AA_A980, 0xE2AA_A880),
+    (0xE2AA_AA80, 0xE2AA_AB80), (0xE2AA_AB80, 0xE2AA_AA80), (0xE2AA_AC80, 0xE2AA_AD80),
+    (0xE2AA_AD80, 0xE2AA_AC80), (0xE2AA_AF80, 0xE2AA_B080), (0xE2AA_B080, 0xE2AA_AF80),
+    (0xE2AA_B180, 0xE2AA_B280), (0xE2AA_B280, 0xE2AA_B180), (0xE2AA_B380, 0xE2AA_B480),
+    (0xE2AA_B480, 0xE2AA_B380), (0xE2AA_B580, 0xE2AA_B680), (0xE2AA_B680, 0xE2AA_B580),
+    (0xE2AA_B780, 0xE2AA_B880), (0xE2AA_B880, 0xE2AA_B780), (0xE2AA_B980, 0xE2AA_BA80),
+    (0xE2AA_BA80, 0xE2AA_B980), (0xE2AA_BB80, 0xE2AA_BC80), (0xE2AA_BC80, 0xE2AA_BB80),
+    (0xE2AA_BD80, 0xE2AA_BE80), (0xE2AA_BE80, 0xE2AA_BD80), (0xE2AA_BF80, 0xE2AB_8080),
+    (0xE2AB_8080, 0xE2AA_BF80), (0xE2AB_8180, 0xE2AB_8280), (0xE2AB_8280, 0xE2AB_8180),
+    (0xE2AB_8380, 0xE2AB_8480), (0xE2AB_8480, 0xE2AB_8380), (0xE2AB_8580, 0xE2AB_8680),
+    (0xE2AB_8680, 0xE2AB_8580), (0xE2AB_8780, 0xE2AB_8880), (0xE2AB_8880, 0xE2AB_8780),
+    (0xE2AB_8980, 0xE2AB_8A80), (0xE2AB_8A80, 0xE2AB_8980), (0xE2AB_8B80, 0xE2AB_8C80),
+    (0xE2AB_8C80, 0xE2AB_8B80), (0xE2AB_8D80, 0xE2AB_8E80), (0xE2AB_8E80, 0xE2AB_8D80),
+    (0xE2AB_8F80, 0xE2AB_9080), (0xE2AB_9080, 0xE2AB_8F80), (0xE2AB_9180, 0xE2AB_9280),
+    (0xE2AB_9280, 0xE2AB_9180), (0xE2AB_9380, 0xE2AB_9480), (0xE2AB_9480, 0xE2AB_9380),
+    (0xE2AB_9580, 0xE2AB_9680), (0xE2AB_9680, 0xE2AB_9580), (0xE2AB_9E80, 0xE28A_A680),
+    (0xE2AB_A380, 0xE28A_A980), (0xE2AB_A480, 0xE28A_A880), (0xE2AB_A580, 0xE28A_AB80),
+    (0xE2AB_AC80, 0xE2AB_AD80), (0xE2AB_AD80, 0xE2AB_AC80), (0xE2AB_AE80, 0xE288_A480),
+    (0xE2AB_B780, 0xE2AB_B880), (0xE2AB_B880, 0xE2AB_B780), (0xE2AB_B980, 0xE2AB_BA80),
+    (0xE2AB_BA80, 0xE2AB_B980), (0xE2AF_BE80, 0xE288_9F80), (0xE2B8_8280, 0xE2B8_8380),
+    (0xE2B8_8380, 0xE2B8_8280), (0xE2B8_8480, 0xE2B8_8580), (0xE2B8_8580, 0xE2B8_8480),
+    (0xE2B8_8980, 0xE2B8_8A80), (0xE2B8_8A80, 0xE2B8_8980), (0xE2B8_8C80, 0xE2B8_8D80),
+    (0xE2B8_8D80, 0xE2B8_8C80), (0xE2B8_9C80, 0xE2B8_9D80), (0xE2B8_9D80, 0xE2B8_9C80),
+    (0xE2B8_A080, 0xE2B8_A180), (0xE2B8_A180, 0xE2B8_A080), (0xE2B8_A280, 0xE2B8_A380),
+    (0xE2B8_A380, 0xE2B8_A280), (0xE2B8_A480, 0xE2B8_A580), (0xE2B8_A580, 0xE2B8_A480),
+    (0xE2B8_A680, 0xE2B8_A780), (0xE2B8_A780, 0xE2B8_A680), (0xE2B8_A880, 0xE2B8_A980),
+    (0xE2B8_A980, 0xE2B8_A880), (0xE2B9_9580, 0xE2B9_9680), (0xE2B9_9680, 0xE2B9_9580),
+    (0xE2B9_9780, 0xE2B9_9880), (0xE2B9_9880, 0xE2B9_9780), (0xE2B9_9980, 0xE2B9_9A80),
+    (0xE2B9_9A80, 0xE2B9_9980), (0xE2B9_9B80, 0xE2B9_9C80), (0xE2B9_9C80, 0xE2B9_9B80),
+    (0xE380_8880, 0xE380_8980), (0xE380_8980, 0xE380_8880), (0xE380_8A80, 0xE380_8B80),
+    (0xE380_8B80, 0xE380_8A80), (0xE380_8C80, 0xE380_8D80), (0xE380_8D80, 0xE380_8C80),
+    (0xE380_8E80, 0xE380_8F80), (0xE380_8F80, 0xE380_8E80), (0xE380_9080, 0xE380_9180),
+    (0xE380_9180, 0xE380_9080), (0xE380_9480, 0xE380_9580), (0xE380_9580, 0xE380_9480),
+    (0xE380_9680, 0xE380_9780), (0xE380_9780, 0xE380_9680), (0xE380_9880, 0xE380_9980),
+    (0xE380_9980, 0xE380_9880), (0xE380_9A80, 0xE380_9B80), (0xE380_9B80, 0xE380_9A80),
+    (0xEFB9_9980, 0xEFB9_9A80), (0xEFB9_9A80, 0xEFB9_9980), (0xEFB9_9B80, 0xEFB9_9C80),
+    (0xEFB9_9C80, 0xEFB9_9B80), (0xEFB9_9D80, 0xEFB9_9E80), (0xEFB9_9E80, 0xEFB9_9D80),
+    (0xEFB9_A480, 0xEFB9_A580), (0xEFB9_A580, 0xEFB9_A480), (0xEFBC_8880, 0xEFBC_8980),
+    (0xEFBC_8980, 0xEFBC_8880), (0xEFBC_9C80, 0xEFBC_9E80), (0xEFBC_9E80, 0xEFBC_9C80),
+    (0xEFBC_BB80, 0xEFBC_BD80), (0xEFBC_BD80, 0xEFBC_BB80), (0xEFBD_9B80, 0xEFBD_9D80),
+    (0xEFBD_9D80, 0xEFBD_9B80), (0xEFBD_9F80, 0xEFBD_A080), (0xEFBD_A080, 0xEFBD_9F80),
+    (0xEFBD_A280, 0xEFBD_A380), (0xEFBD_A380, 0xEFBD_A280),
+];
+
+/// The `Bidi_Paired_Bracket` of paired brackets as `Utf8Char::to_bits`, and whether they open
+pub(crate) static BRACKETS: [(u32, u32, bool); 128] = [
+    (0x2880_8080, 0x2980_8080, true), (0x2980_8080, 0x2880_8080, false),
+    (0x5B80_8080, 0x5D80_8080, true), (0x5D80_8080, 0x5B80_8080, false),
+    (0x7B80_8080, 0x7D80_8080, true), (0x7D80_8080, 0x7B80_8080, false),
+    (0xE0BC_BA80, 0xE0BC_BB80, true), (0xE0BC_BB80, 0xE0BC_BA80, false),
+    (0xE0BC_BC80, 0xE0BC_BD80, true), (0xE0BC_BD80, 0xE0BC_BC80, false),
+    (0xE19A_9B80, 0xE19A_9C80, true), (0xE19A_9C80, 0xE19A_9B80, false),
+    (0xE281_8580, 0xE281_8680, true), (0xE281_8680, 0xE281_8580, false),
+    (0xE281_BD80, 0xE281_BE80, true), (0xE281_BE80, 0xE281_BD80, false),
+    (0xE282_8D80, 0xE282_8E80, true), (0xE282_8E80, 0xE282_8D80, false),
+    (0xE28C_8880, 0xE28C_8980, true), (0xE28C_8980, 0xE28C_8880, false),
+    (0xE28C_8A80, 0xE28C_8B80, true), (0xE28C_8B80, 0xE28C_8A80, false),
+    (0xE28C_A980, 0xE28C_AA80, true), (0xE28C_AA80, 0xE28C_A980, false),
+    (0xE29D_A880, 0xE29D_A980, true), (0xE29D_A980, 0xE29D_A880, false),
+    (0xE29D_AA80, 0xE29D_AB80, true), (0xE29D_AB80, 0xE29D_AA80, false),
+    (0xE29D_AC80, 0xE29D_AD80, true), (0xE29D_AD80, 0xE29D_AC80, false),
+    (0xE29D_AE80, 0xE29D_AF80, true), (0xE29D_AF80, 0xE29D_AE80, false),
+    (0xE29D_B080, 0xE29D_B180, true), (0xE29D_B180, 0xE29D_B080, false),
+    (0xE29D_B280, 0xE29D_B380, true), (0xE29D_B380, 0xE29D_B280, false),
+    (0xE29D_B480, 0xE29D_B580, true), (0xE29D_B580, 0xE29D_B480, false),
+    (0xE29F_8580, 0xE29F_8680, true), (0xE29F_8680, 0xE29F_8580, false),
+    (0xE29F_A680, 0xE29F_A780, true), (0xE29F_A780, 0xE29F_A680, false),
+    (0xE29F_A880, 0xE29F_A980, true), (0xE29F_A980, 0xE29F_A880, false),
+    (0xE29F_AA80, 0xE29F_AB80, true), (0xE29F_AB80, 0xE29F_AA80, false),
+    (0xE29F_AC80, 0xE29F_AD80, true), (0xE29F_AD80, 0xE29F_AC80, false),
+    (0xE29F_AE80, 0xE29F_AF80, true), (0xE29F_AF80, 0xE29F_AE80, false),
+    (0xE2A6_8380, 0xE2A6_8480, true), (0xE2A6_8480, 0xE2A6_8380, false),
+    (0xE2A6_8580, 0xE2A6_8680, true), (0xE2A6_8680, 0xE2A6_8580, false),
+    (0xE2A6_8780, 0xE2A6_8880, true), (0xE2A6_8880, 0xE2A6_8780, false),
+    (0xE2A6_8980, 0xE2A6_8A80, true), (0xE2A6_8A80, 0xE2A6_8980, false),
+    (0xE2A6_8B80, 0xE2A6_8C80, true), (0xE2A6_8C80, 0xE2A6_8B80, false),
+    (0xE2A6_8D80, 0xE2A6_9080, true), (0xE2A6_8E80, 0xE2A6_8F80, false),
+    (0xE2A6_8F80, 0xE2A6_8E80, true), (0xE2A6_9080, 0xE2A6_8D80, false),
+    (0xE2A6_9180, 0xE2A6_9280, true), (0xE2A6_9280, 0xE2A6_9180, false),
+    (0xE2A6_9380, 0xE2A6_9480, true), (0xE2A6_9480, 0xE2A6_9380, false),
+    (0xE2A6_9580, 0xE2A6_9680, true), (0xE2A6_9680, 0xE2A6_9580, false),
+    (0xE2A6_9780, 0xE2A6_9880, true), (0xE2A6_9880, 0xE2A6_9780, false),
+    (0xE2A7_9880, 0xE2A7_9980, true), (0xE2A7_9980, 0xE2A7_9880, false),
+    (0xE2A7_9A80, 0xE2A7_9B80, true), (0xE2A7_9B80, 0xE2A7_9A80, false),
+    (0xE2A7_BC80, 0xE2A7_BD80, true), (0xE2A7_BD80, 0xE2A7_BC80, false),
+    (0xE2B8_A280, 0xE2B8_A380, true), (0xE2B8_A380, 0xE2B8_A280, false),
+    (0xE2B8_A480, 0xE2B8_A580, true), (0xE2B8_A580, 0xE2B8_A480, false),
+    (0xE2B8_A680, 0xE2B8_A780, true), (0xE2B8_A780, 0xE2B8_A680, false),
+    (0xE2B8_A880, 0xE2B8_A980, true), (0xE2B8_A980, 0xE2B8_A880, false),
+    (0xE2B9_9580, 0xE2B9_9680, true), (0xE2B9_9680, 0xE2B9_9580, false),
+    (0xE2B9_9780, 0xE2B9_9880, true), (0xE2B9_9880, 0xE2B9_9780, false),
+    (0xE2B9_9980, 0xE2B9_9A80, true), (0xE2B9_9A80, 0xE2B9_9980, false),
+    (0xE2B9_9B80, 0xE2B9_9C80, true), (0xE2B9_9C80, 0xE2B9_9B80, false),
+    (0xE380_8880, 0xE380_8980, true), (0xE380_8980, 0xE380_8880, false),
+    (0xE380_8A80, 0xE380_8B80, true), (0xE380_8B80, 0xE380_8A80, false),
+    (0xE380_8C80, 0xE380_8D80, true), (0xE380_8D80, 0xE380_8C80, false),
+    (0xE380_8E80, 0xE380_8F80, true), (0xE380_8F80, 0xE380_8E80, false),
+    (0xE380_9080, 0xE380_9180, true), (0xE380_9180, 0xE380_9080, false),
+    (0xE380_9480, 0xE380_9580, true), (0xE380_9580, 0xE380_9480, false),
+    (0xE380_9680, 0xE380_9780, true), (0xE380_9780, 0xE380_9680, false),
+    (0xE380_9880, 0xE380_9980, true), (0xE380_9980, 0xE380_9880, false),
+    (0xE380_9A80, 0xE380_9B80, true), (0xE380_9B80, 0xE380_9A80, false),
+    (0xEFB9_9980, 0xEFB9_9A80, true), (0xEFB9_9A80, 0xEFB9_9980, false),
+    (0xEFB9_9B80, 0xEFB9_9C80, true), (0xEFB9_9C80, 0xEFB9_9B80, false),
+    (0xEFB9_9D80, 0xEFB9_9E80, true), (0xEFB9_9E80, 0xEFB9_9D80, false),
+    (0xEFBC_8880, 0xEFBC_8980, true), (0xEFBC_8980, 0xEFBC_8880, false),
+    (0xEFBC_BB80, 0xEFBC_BD80, true), (0xEFBC_BD80, 0xEFBC_BB80, false),
+    (0xEFBD_9B80, 0xEFBD_9D80, true), (0xEFBD_9D80, 0xEFBD_9B80, false),
+    (0xEFBD_9F80, 0xEFBD_A080, true), (0xEFBD_A080, 0xEFBD_9F80, false),
+    (0xEFBD_A280, 0xEFBD_A380, true), (0xEFBD_A380, 0xEFBD_A280, false),
+];
+
+/// The `Bidi_Class` of every codepoint
+pub(crate) static BIDI_CLASS: Utf8Trie<[BidiClass; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 4, 4, 4, 4, 4, 5, 6, 7, 8, 9, 10, 4, 4, 11, 4, 4, 4, 12,
+        13, 14, 15, 16, 17, 18, 19, 20, 21, 0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 5, 5, 5, 5, 7, 18, 19,
+        19, 21, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 6, 7, 8, 9, 10, 4, 4, 11, 4, 4, 4, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 30, 32, 33, 34, 35, 36, 37,
+            38, 28, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51,
+        ],
+        [
+            52, 53, 54, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 55, 56, 4, 57, 4, 4, 4, 4, 4, 4, 4, 4, 4, 58,
+            4, 59, 60, 61, 62, 63, 4, 64, 4, 65, 66, 4, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 4,
+            4, 77, 4, 4, 4, 7, 4, 4, 4, 4, 4, 4, 78, 79,
+        ],
+        [
+            80, 81, 82, 83, 84, 85, 86, 87, 88, 87, 87, 87, 89, 90, 91, 87, 92, 93, 94, 95, 87, 87,
+            87, 87, 87, 87, 96, 87, 87, 87, 87, 87, 4, 4, 4, 4, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            97, 87, 87, 4, 4, 4, 98, 4, 99, 4, 100, 87, 101, 102, 103, 87, 87, 87, 104,
+        ],
+        [
+            105, 4, 106, 107, 4, 4, 4, 108, 109, 110, 111, 112, 4, 113, 4, 114, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 87, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 115, 116, 4, 4, 4, 4, 117, 118,
+            119, 120, 121, 4, 122, 4, 123, 124, 4, 125, 126, 127, 128, 129, 130, 131, 132, 133, 4,
+            134, 4, 135, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 136, 137, 16, 138, 16, 16, 16, 16, 139,
+            140, 141, 142, 143, 144, 16, 145, 146, 147, 4, 148,
+        ],
+        [
+            4, 4, 4, 4, 149, 87, 150, 151, 4, 4, 4, 152, 4, 153, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 154, 154, 154, 154, 155, 154, 154, 154, 156, 154, 154, 157, 158, 154,
+            154, 154, 154, 154, 154, 154, 159, 160, 154, 154, 154, 161, 162, 163, 164, 165, 166,
+            154,
+        ],
+        [
+            167, 168, 169, 35, 170, 171, 172, 173, 174, 42, 4, 175, 40, 176, 177, 178, 179, 180,
+            181, 182, 4, 4, 183, 184, 185, 186, 187, 4, 188, 4, 4, 4, 189, 4, 4, 4, 190, 191, 4,
+            192, 193, 194, 195, 4, 4, 196, 4, 4, 197, 4, 198, 4, 199, 200, 201, 4, 4, 4, 4, 202,
+            203, 204, 4, 205,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 206, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 207, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 208, 209, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 210, 211, 212,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 213, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 87, 87, 87, 214, 87, 87, 87,
+            87, 87, 87, 215, 216, 217, 218, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 219, 220, 221, 87, 222, 4, 4, 87, 223, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 224, 225, 226, 227, 228, 4, 4, 4, 4, 4, 4, 4, 4, 229, 230, 231, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            232, 4, 210, 4, 209, 4, 4, 4, 4, 4, 233, 234, 4, 4, 4, 4, 4, 4, 4, 235, 4, 4, 4, 236, 4,
+            4, 4, 237, 4, 4, 4, 4, 154, 154, 154, 238, 154, 239, 154, 154, 154, 154, 154, 154, 154,
+            154, 154, 154, 154, 164, 16, 154, 16, 240, 154, 154, 16, 16, 16, 241, 154, 154, 154,
+            154,
+        ],
+        [
+            242, 87, 243, 244, 245, 246, 247, 4, 4, 248, 4, 4, 87, 87, 87, 87, 87, 87, 87, 87, 87,
+            87, 87, 87, 87, 87, 87, 249, 87, 87, 87, 250, 251, 252, 253, 254, 87, 87, 87, 87, 87,
+            255, 256, 257, 87, 87, 258, 259, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 260,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 260,
+        ],
+        [
+            0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 5, 5, 5, 5, 7, 8, 9, 5, 10, 5, 5, 11, 5, 5, 5, 5, 12,
+            13, 14, 15, 16, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 17, 5, 5, 5, 5, 5, 5, 5, 5,
+            5, 5, 5, 5, 5, 5, 5, 17,
+        ],
+        [
+            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 17, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+            5, 5, 17, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 17, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+            5, 5, 5, 5, 5, 17,
+        ],
+        [
+            261, 261, 261, 261, 7, 7, 7, 262, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261,
+            261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261,
+            261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261,
+            261, 261, 261, 261, 261, 261, 261, 261, 261, 261, 261,
+        ],
+        [
+            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 17, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+            5, 5, 17, 20, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 17, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+            5, 5, 5, 5, 5, 5, 17,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 17, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22,
+            22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22,
+            22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22, 22,
+        ],
+    ],
+    leaves: &[
+        [
+            BN, BN, BN, BN, BN, BN, BN, BN, BN, S, B, S, WS, B, BN, BN, BN, BN, BN, BN, BN, BN, BN,
+            BN, BN, BN, BN, BN, B, B, B, S, WS, ON, ON, ET, ET, ET, ON, ON, ON, ON, ON, ES, CS, ES,
+            CS, CS, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, CS, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON,
+            ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, ON, ON, ON, ON, BN,
+        ],
+        [
+            BN, BN, BN, BN, BN, B, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN,
+            BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, CS, ON, ET, ET, ET, ET, ON, ON, ON, ON, L, ON,
+            ON, BN, ON, ON, ET, ET, EN, EN, ON, L, ON, ON, ON, EN, L, ON, ON, ON, ON, ON,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON,
+            ON, L, L, L, L, L,
+        ],
+        [
+            L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+        ],
+        [
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, L, ON,
+            ON, L, L, L, L, L, L, L, L, ON, L,
+        ],
+        [
+            L, L, L, L, ON, ON, L, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, ON, L, L, ON, ON, ET, R, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, R, NSM,
+        ],
+        [
+            R, NSM, NSM, R, NSM, NSM, R, NSM, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R,
+        ],
+        [
+            AN, AN, AN, AN, AN, AN, ON, ON, AL, ET, ET, AL, CS, AL, ON, ON, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, AN, AN, AN, AN, AN, AN, AN,
+            AN, AN, AN, ET, AN, AN, AL, AL, AL, NSM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, AN, ON, NSM, NSM, NSM, NSM, NSM, NSM, AL, AL, NSM,
+            NSM, ON, NSM, NSM, NSM, NSM, AL, AL, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, AL, AL, AL,
+            AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, NSM, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM,
+        ],
+        [
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL,
+        ],
+        [
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            R, R, ON, ON, ON, ON, R, R, R, NSM, R, R,
+        ],
+        [
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, NSM, NSM, NSM, NSM, R,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, R, NSM, NSM, NSM, R, NSM, NSM, NSM, NSM,
+            NSM, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+        ],
+        [
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, NSM, NSM,
+            NSM, R, R, R, R, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AN, AN, AL, AL, AL, AL,
+            AL, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, AN, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+        ],
+        [
+            NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, NSM, L, NSM, L, L, L,
+        ],
+        [
+            L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, L, NSM, L, L, L, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, NSM, L, L, L,
+        ],
+        [
+            L, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ET, ET, L,
+            L, L, L, L, L, L, ET, L, L, NSM, L,
+        ],
+        [
+            L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, NSM, L, L, L,
+        ],
+        [
+            L, NSM, NSM, L, L, L, L, NSM, NSM, L, L, NSM, NSM, NSM, L, L, L, NSM, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L,
+            L, NSM, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, NSM, NSM, NSM, NSM, NSM, L, NSM, NSM, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, ET, L, L,
+            L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM,
+        ],
+        [
+            L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, NSM, L, L, NSM,
+        ],
+        [
+            L, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, NSM, L, L, L, L, L, L, L, NSM, NSM, L, L,
+            L, L, L, L, L, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            NSM, L, L, L, L, L, L, L, L, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON,
+            ON, ET, ON, L, L, L, L, L,
+        ],
+        [
+            NSM, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, NSM, L, NSM, NSM,
+        ],
+        [
+            NSM, L, L, L, L, L, NSM, NSM, NSM, L, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, NSM, NSM,
+            L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, NSM, NSM, L, L, L,
+        ],
+        [
+            L, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, NSM, L, L, L, L, L, L, L, NSM, NSM, NSM, L, NSM, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, L, L, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, L, L, L, L, ET,
+        ],
+        [
+            L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, L, L, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, L, NSM,
+            L, NSM, ON, ON, ON, ON, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L,
+        ],
+        [
+            NSM, NSM, NSM, NSM, NSM, L, NSM, NSM, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, L, NSM, NSM, NSM,
+            NSM, NSM, NSM, L, NSM, NSM, L, L, NSM, NSM, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L,
+            L, L, NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM,
+            L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, NSM, L, L, NSM, NSM, L, L, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            WS, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON,
+            ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L, L,
+            L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, L, L,
+        ],
+        [
+            L, L, L, L, L, L, NSM, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L,
+            L, L, L, L, L, L, ET, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, NSM, NSM, NSM, BN, NSM, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, NSM, NSM, NSM, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L, NSM, L, L, L,
+            L, L, L, NSM, NSM, NSM, L, L, L, L,
+        ],
+        [
+            ON, L, L, L, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L,
+            NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, L, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, L, NSM, L, NSM, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L,
+            L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, NSM,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+        ],
+        [
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, L, NSM,
+            NSM, NSM, NSM, NSM, L, NSM, L, L, L,
+        ],
+        [
+            L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, NSM, NSM, NSM, NSM, L, L, NSM, NSM, L, NSM, NSM, NSM, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, NSM, L, NSM, NSM, L, L, L, NSM, L, NSM, NSM, NSM, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L,
+            L, NSM, NSM, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, L, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L,
+            L, L, NSM, L, L, L, L, L, L, NSM, L, L, L, NSM, NSM, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, ON, L, ON,
+        ],
+        [
+            ON, ON, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, ON, ON, L,
+        ],
+        [
+            WS, WS, WS, WS, WS, WS, WS, WS, WS, WS, WS, BN, BN, BN, L, R, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, WS, B, LRE, RLE,
+            PDF, LRO, RLO, CS, ET, ET, ET, ET, ET, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, CS, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, WS, BN, BN, BN, BN, BN, BN, LRI, RLI, FSI, PDI, BN,
+            BN, BN, BN, BN, BN, EN, L, L, L, EN, EN, EN, EN, EN, EN, ES, ES, ON, ON, ON, L,
+        ],
+        [
+            EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, ES, ES, ON, ON, ON, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET,
+            ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET,
+        ],
+        [
+            ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, ET, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L,
+        ],
+        [
+            ON, ON, L, ON, ON, ON, ON, L, ON, ON, L, L, L, L, L, L, L, L, L, L, ON, L, ON, ON, ON,
+            L, L, L, L, L, ON, ON, ON, ON, ON, ON, L, ON, L, ON, L, ON, L, L, L, L, ET, L, L, L, L,
+            L, L, L, L, L, L, L, ON, ON, L, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, L, L, L, L, L, ON, ON, ON, ON, L, L, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, ON, ON, ON, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ES, ET, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN,
+            EN, EN, EN, EN, EN, EN, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, L, L, L, L, NSM, NSM, NSM, L, L, L, L,
+            L, L, L, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, NSM,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            WS, ON, ON, ON, ON, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM,
+            L, L, ON, L, L, L, L, L, ON, ON, L, L, L, L, L, ON, ON, ON,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, ON,
+            ON, L, L, L, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, ON, L, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L,
+            L, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, ON, ON, ON, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON,
+            ON, ON, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, ON,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, ON, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, ON, ON,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, NSM, L, L, L, NSM, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, ON, ON, ON, ON, NSM, L, L, L, L, L, L, L, L,
+            L, L, L, ET, ET, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, ON, L,
+            L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, L, L, NSM,
+            NSM, NSM, NSM, L, L, NSM, NSM, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, L, L, NSM, NSM, L, L,
+            NSM, NSM, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, NSM, L, L, L, L, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, NSM, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, L, NSM, NSM, NSM, L, L,
+            NSM, NSM, L, L, L, L, L, NSM, NSM,
+        ],
+        [
+            L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L, NSM, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, NSM, L, L, NSM, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            R, NSM, R, R, R, R, R, R, R, R, R, R, ES, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R,
+        ],
+        [
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ON, ON, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, ON, ON, ON, ON, ON, ON, ON, ON, BN, BN, BN, BN, BN, BN,
+            BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN,
+            BN, BN, BN, BN, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ON, ON, ON,
+        ],
+        [
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, CS, ON, CS, L, ON, CS,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ET, ON, ON, ES, ES, ON, ON, ON, L, ON, ET, ET, ON,
+            L, L, L, L, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, BN,
+        ],
+        [
+            L, ON, ON, ET, ET, ET, ON, ON, ON, ON, ON, ES, CS, ES, CS, CS, EN, EN, EN, EN, EN, EN,
+            EN, EN, EN, EN, CS, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, ET, ET, ON, ON, ON, ET, ET, L, ON, ON, ON, ON, ON, ON, ON, L, BN, BN, BN, BN,
+            BN, BN, BN, BN, BN, ON, ON, ON, ON, ON, BN, BN,
+        ],
+        [
+            L, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, L, L, L, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, NSM, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, NSM, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN,
+            EN, EN, EN, EN, EN, EN, EN, EN, EN, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM,
+            NSM, NSM, NSM, L, L, L, L, L,
+        ],
+        [
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R,
+        ],
+        [
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, ON, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R,
+        ],
+        [
+            R, NSM, NSM, NSM, R, NSM, NSM, R, R, R, R, R, NSM, NSM, NSM, NSM, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, NSM, NSM, NSM, R, R, R, R, NSM,
+        ],
+        [
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, NSM, NSM, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R,
+        ],
+        [
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, ON,
+            ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, NSM, NSM, NSM, NSM, AL, AL, AL,
+            AL, AL, AL, AL, AL, AN, AN, AN, AN, AN, AN, AN, AN, AN, AN, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AN, AN, AN, AN, AN, AN, AN, AN, AN, AN, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, NSM, NSM, NSM, NSM, NSM, ON, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R, R, R,
+        ],
+        [
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, AN, AN, AN, AN, AN, AN, AN, AN, AN, AN, AN, AN, AN, AN, AN, AN, AN, AN, AN, AN,
+            AN, AN, AN, AN, AN, AN, AN, AN, AN, AN, AN, R,
+        ],
+        [
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, NSM, NSM, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, NSM, NSM, NSM, NSM, NSM, NSM,
+        ],
+        [
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+        ],
+        [
+            R, R, NSM, NSM, NSM, NSM, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R,
+        ],
+        [
+            L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+        ],
+        [
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L,
+            L, NSM, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, NSM,
+        ],
+        [
+            NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM,
+            L, L, NSM, NSM, L, L, L, L, L,
+        ],
+        [
+            NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, L, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, L, L, NSM, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, L, L, NSM, L, NSM,
+            NSM, L, L, L, L, L, L, NSM, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, NSM, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, NSM, NSM, NSM,
+            NSM, NSM, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, NSM, NSM, NSM, NSM, NSM,
+        ],
+        [
+            NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, L, NSM, L, NSM, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+        ],
+        [
+            L, L, NSM, NSM, NSM, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM,
+            NSM, NSM, L, NSM, L, L, L, L, NSM,
+        ],
+        [
+            NSM, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, L, L,
+            L, L, L, L, NSM, NSM, L, NSM,
+        ],
+        [
+            NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, L, L, NSM, L, NSM,
+        ],
+        [
+            NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, L, NSM, L, L, NSM, NSM, NSM, NSM, NSM,
+            NSM, L, NSM, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            NSM, L, NSM, L, L, NSM, NSM, NSM, NSM, L, NSM, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, L, NSM, NSM, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, NSM, NSM, L, NSM, L,
+        ],
+        [
+            L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, L, L,
+            NSM, NSM, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, NSM, NSM, NSM, NSM, NSM, NSM, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM,
+            NSM, NSM, NSM, NSM, NSM, L, L, NSM, NSM, NSM, NSM, L,
+        ],
+        [
+            L, L, L, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, L, L,
+            NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, L, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, NSM, L, NSM, NSM, NSM, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, L, NSM, NSM, NSM, NSM, NSM, NSM, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, L, NSM, NSM, L, NSM, NSM, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM,
+            NSM, L, L, L, NSM, L, NSM, NSM, L, NSM,
+        ],
+        [
+            NSM, NSM, NSM, NSM, NSM, NSM, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L, L, NSM, L, NSM, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L, L, L,
+            L, L, L, L, L, L, L,
+        ],
+        [
+            NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM,
+            NSM, NSM, NSM, L, L, L, L, L,
+        ],
+        [
+            NSM, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON,
+            ON, ON, ET, ET, ET, ET, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            NSM, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, NSM, NSM, NSM,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, L, L,
+            L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, ON, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            NSM, NSM, L, BN, BN, BN, BN, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, EN, EN,
+            EN, EN, EN, EN, EN, EN, EN, EN, ON, ON, ON, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+        ],
+        [
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, BN, BN, BN, BN,
+            BN, BN, BN, BN, NSM, NSM, NSM, NSM, NSM,
+        ],
+        [
+            NSM, NSM, NSM, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            ON, ON, NSM, NSM, NSM, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, ON, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, ON, L, L, L, L, L, L, L, L, L, L, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN,
+            EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN,
+            EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN,
+        ],
+        [
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, L, L, L, L, NSM, NSM, NSM, NSM, NSM,
+        ],
+        [
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, NSM, L,
+            L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM,
+            NSM, NSM, NSM, NSM, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, L, NSM, NSM, NSM, NSM, NSM, NSM, NSM, L, NSM, NSM,
+            L, NSM, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, ET,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, NSM, NSM, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, NSM, NSM, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, NSM, L, L, NSM, L, L, L, L, L, L, L, NSM, NSM, L, L, L, L, L, NSM, L,
+            L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, NSM, NSM, NSM, NSM, NSM, NSM, NSM, R, R,
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R,
+        ],
+        [
+            R, R, R, R, NSM, NSM, NSM, NSM, NSM, NSM, NSM, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R, R,
+            R, R, R, R, R, R, R, R, R, R, R,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, ON, ON, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L,
+            L, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L,
+            L, L, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L,
+        ],
+        [
+            ON, ON, L, L, L, L, L, L, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, L, L, L, L, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, L, ON, L, L, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, L, L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L,
+            L, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, L, L, L, L, L, L,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, L, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+        ],
+        [
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON, ON,
+            ON, ON, ON, ON, EN, EN, EN, EN, EN, EN, EN, EN, EN, EN, ON, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, BN, BN,
+        ],
+        [
+            BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN,
+            BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN,
+            BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN,
+        ],
+        [
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM,
+            NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, NSM, BN, BN, BN, BN,
+            BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN, BN,
+        ],
+    ],
+};
+
+/// Codepoints with the `Bidi_Mirrored` property
+pub(crate) static BIDI_MIRRORED: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+        3, 3, 3, 3, 3, 3, 3, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 7, 8, 8, 8, 10, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 4, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 5, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+        [
+            6, 7, 8, 3, 3, 9, 3, 3, 10, 11, 12, 13, 14, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 15, 3, 16, 3, 3, 3, 3, 3, 3, 17, 18, 19, 20, 21, 22, 3, 3, 3, 23, 3, 3, 3, 3, 3, 3,
+            3, 3, 24, 25, 3, 3, 3, 3, 3, 3,
+        ],
+        [
+            26, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 27,
+            3, 3, 28, 29, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 30, 31,
+            32, 33, 34, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            6, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 9, 9,
+        ],
+    ],
+    leaves: &[
+        0x5000_0300_0000_0000,
+        0x2800_0000_2800_0000,
+        0x0800_0800_0000_0000,
+        0x0000_0000_0000_0000,
+        0x3C00_0000_0000_0000,
+        0x0000_0000_1800_0000,
+        0x0600_0000_0000_0000,
+        0x6000_0000_0000_0060,
+        0x0000_0000_0000_6000,
+        0x0000_0000_0000_0001,
+        0xFA0F_F857_BC62_3F1E,
+        0xFFFF_EFF5_803C_1FFF,
+        0xC1FF_FFCC_0107_9FFF,
+        0xFFFF_3FFF_FFC3_3E00,
+        0x0000_0603_0000_0F00,
+        0x003F_FF00_0000_0000,
+        0x0000_FFFC_7078_3B79,
+        0x0100_FFFD_F9FF_FFF8,
+        0x33F0_033A_1F37_C23F,
+        0x7030_7A53_DFFF_FC00,
+        0xFE19_BC30_0180_0000,
+        0xFFFF_BFCF_FFFF_FFFF,
+        0x2F88_707C_507F_FFFF,
+        0x4000_0000_0000_0000,
+        0x0000_03FF_3000_363C,
+        0x0000_0000_1FE0_0000,
+        0x0000_0000_0FF3_FF00,
+        0x0000_0030_7E00_0000,
+        0x2800_0000_5000_0300,
+        0x0000_000D_A800_0000,
+        0x0000_0000_0800_0000,
+        0x0000_0000_0020_0000,
+        0x0000_0000_0000_8000,
+        0x0000_0000_0000_0200,
+        0x0000_0000_0000_0008,
+    ],
+};
@@ -0,0 +1,104 @@
+"""Generates src/tables/case.rs: the full lowercase, uppercase and titlecase mappings, and the
+simple case folding.
+
+    python3 src/tables/case.py <path to UCD> > src/tables/case.rs
+
+Every case mapping trie maps a codepoint to an index into `MAPPINGS`, the strings the codepoint maps
+to. Index 0 is the empty string, meaning the codepoint maps to itself. The case folding trie does the
+same with `FOLDS`, which holds single codepoints.
+"""
+
+from ucd import CODEPOINTS, header, parse_codepoints, parse_lines, ucd_dir, unicode_data, utf8char_bits, value_trie, wrap
+
+
+def to_str(field: str) -> str:
+    return "".join(map(chr, parse_codepoints(field)))
+
+
+def main() -> None:
+    ucd = ucd_dir()
+
+    lower = [""] * CODEPOINTS
+    upper = [""] * CODEPOINTS
+    title = [""] * CODEPOINTS
+
+    # simple mappings, a missing titlecase mapping is the same as the uppercase one
+    for cp, fields in unicode_data(ucd / "UnicodeData.txt").items():
+        upper[cp] = to_str(fields[12])
+        lower[cp] = to_str(fields[13])
+        title[cp] = to_str(fields[14]) or upper[cp]
+
+    # full mappings override the simple ones, conditional (language or context sensitive) mappings
+    # are not applied, just like `char`
+    for fields in parse_lines(ucd / "SpecialCasing.txt"):
+        if len(fields) > 4 and fields[4]:
+            continue
+
+        cp = int(fields[0], 16)
+        lower[cp], title[cp], upper[cp] = to_str(fields[1]), to_str(fields[2]), to_str(fields[3])
+
+    mappings = [""]
+    ids = {"": 0}
+
+    def index(cp: int, mapping: list[str]) -> str:
+        target = mapping[cp]
+
+        if target == chr(cp):
+            target = ""
+
+        if target not in ids:
+            ids[target] = len(mappings)
+            mappings.append(target)
+
+        return str(ids[target])
+
+    lower_ids = [index(cp, lower) for cp in range(CODEPOINTS)]
+    upper_ids = [index(cp, upper) for cp in range(CODEPOINTS)]
+    title_ids = [index(cp, title) for cp in range(CODEPOINTS)]
+
+    # simple case folding, `C`ommon and `S`imple statuses
+    folds = [0]
+    fold_ids = ["0"] * CODEPOINTS
+
+    for fields in parse_lines(ucd / "CaseFolding.txt"):
+        if fields[1] in ("C", "S"):
+            fold_ids[int(fields[0], 16)] = str(len(folds))
+            folds.append(utf8char_bits(int(fields[2], 16)))
+
+    max_len = max(len(m.encode("utf8")) for m in mappings)
+
+    print(header("case.py", ["UnicodeData.txt", "SpecialCasing.txt", "CaseFolding.txt"]))
+    print("use super::Utf8Trie;")
+    print()
+    print("/// The longest mapping in `MAPPINGS` in bytes, at least as long as a single codepoint")
+    print(f"pub(crate) const MAX_MAPPING_LEN: usize = {max(max_len, 4)};")
+    print()
+    print("/// The strings codepoints map to, indexed by the case tries. The empty string maps a codepoint to")
+    print("/// itself")
+    print(f"pub(crate) static MAPPINGS: [&str; {len(mappings)}] = [")
+
+    def literal(s: str) -> str:
+        return '"' + "".join(f"\\u{{{ord(c):X}}}" for c in s) + '",'
+
+    print("\n".join(wrap(map(literal, mappings), indent=4)))
+    print("];")
+    print()
+    print(value_trie("TO_LOWERCASE", "The full lowercase mapping of every codepoint", "u16", lower_ids))
+    print(value_trie("TO_UPPERCASE", "The full uppercase mapping of every codepoint", "u16", upper_ids))
+    print(value_trie("TO_TITLECASE", "The full titlecase mapping of every codepoint", "u16", title_ids))
+    print("/// The `Utf8Char::to_bits` of the codepoints others fold to, indexed by `CASE_FOLD`. Index 0 is")
+    print("/// unused, it marks codepoints that fold to themselves")
+    print(f"pub(crate) static FOLDS: [u32; {len(folds)}] = [")
+
+    def bits(value: int) -> str:
+        digits = f"{value:08X}"
+        return f"0x{digits[:4]}_{digits[4:]},"
+
+    print("\n".join(wrap(map(bits, folds), indent=4)))
+    print("];")
+    print()
+    print(value_trie("CASE_FOLD", "The simple case folding of every codepoint", "u16", fold_ids), end="")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,2307 @@
+//! Generated by `src/tables/case.py` from the Unicode 17.0.0 files
+//! UnicodeData.txt, SpecialCasing.txt, CaseFolding.txt. Do not edit by hand.
+
+use super::Utf8Trie;
+
+/// The longest mapping in `MAPPINGS` in bytes, at least as long as a single codepoint
+pub(crate) const MAX_MAPPING_LEN: usize = 6;
+
+/// The strings codepoints map to, indexed by the case tries. The empty string maps a codepoint to
+/// itself
+pub(crate) static MAPPINGS: [&str; 3057] = [
+    "", "\u{61}", "\u{62}", "\u{63}", "\u{64}", "\u{65}", "\u{66}", "\u{67}", "\u{68}", "\u{69}",
+    "\u{6A}", "\u{6B}", "\u{6C}", "\u{6D}", "\u{6E}", "\u{6F}", "\u{70}", "\u{71}", "\u{72}",
+    "\u{73}", "\u{74}", "\u{75}", "\u{76}", "\u{77}", "\u{78}", "\u{79}", "\u{7A}", "\u{E0}",
+    "\u{E1}", "\u{E2}", "\u{E3}", "\u{E4}", "\u{E5}", "\u{E6}", "\u{E7}", "\u{E8}", "\u{E9}",
+    "\u{EA}", "\u{EB}", "\u{EC}", "\u{ED}", "\u{EE}", "\u{EF}", "\u{F0}", "\u{F1}", "\u{F2}",
+    "\u{F3}", "\u{F4}", "\u{F5}", "\u{F6}", "\u{F8}", "\u{F9}", "\u{FA}", "\u{FB}", "\u{FC}",
+    "\u{FD}", "\u{FE}", "\u{101}", "\u{103}", "\u{105}", "\u{107}", "\u{109}", "\u{10B}", "\u{10D}",
+    "\u{10F}", "\u{111}", "\u{113}", "\u{115}", "\u{117}", "\u{119}", "\u{11B}", "\u{11D}",
+    "\u{11F}", "\u{121}", "\u{123}", "\u{125}", "\u{127}", "\u{129}", "\u{12B}", "\u{12D}",
+    "\u{12F}", "\u{69}\u{307}", "\u{133}", "\u{135}", "\u{137}", "\u{13A}", "\u{13C}", "\u{13E}",
+    "\u{140}", "\u{142}", "\u{144}", "\u{146}", "\u{148}", "\u{14B}", "\u{14D}", "\u{14F}",
+    "\u{151}", "\u{153}", "\u{155}", "\u{157}", "\u{159}", "\u{15B}", "\u{15D}", "\u{15F}",
+    "\u{161}", "\u{163}", "\u{165}", "\u{167}", "\u{169}", "\u{16B}", "\u{16D}", "\u{16F}",
+    "\u{171}", "\u{173}", "\u{175}", "\u{177}", "\u{FF}", "\u{17A}", "\u{17C}", "\u{17E}",
+    "\u{253}", "\u{183}", "\u{185}", "\u{254}", "\u{188}", "\u{256}", "\u{257}", "\u{18C}",
+    "\u{1DD}", "\u{259}", "\u{25B}", "\u{192}", "\u{260}", "\u{263}", "\u{269}", "\u{268}",
+    "\u{199}", "\u{26F}", "\u{272}", "\u{275}", "\u{1A1}", "\u{1A3}", "\u{1A5}", "\u{280}",
+    "\u{1A8}", "\u{283}", "\u{1AD}", "\u{288}", "\u{1B0}", "\u{28A}", "\u{28B}", "\u{1B4}",
+    "\u{1B6}", "\u{292}", "\u{1B9}", "\u{1BD}", "\u{1C6}", "\u{1C9}", "\u{1CC}", "\u{1CE}",
+    "\u{1D0}", "\u{1D2}", "\u{1D4}", "\u{1D6}", "\u{1D8}", "\u{1DA}", "\u{1DC}", "\u{1DF}",
+    "\u{1E1}", "\u{1E3}", "\u{1E5}", "\u{1E7}", "\u{1E9}", "\u{1EB}", "\u{1ED}", "\u{1EF}",
+    "\u{1F3}", "\u{1F5}", "\u{195}", "\u{1BF}", "\u{1F9}", "\u{1FB}", "\u{1FD}", "\u{1FF}",
+    "\u{201}", "\u{203}", "\u{205}", "\u{207}", "\u{209}", "\u{20B}", "\u{20D}", "\u{20F}",
+    "\u{211}", "\u{213}", "\u{215}", "\u{217}", "\u{219}", "\u{21B}", "\u{21D}", "\u{21F}",
+    "\u{19E}", "\u{223}", "\u{225}", "\u{227}", "\u{229}", "\u{22B}", "\u{22D}", "\u{22F}",
+    "\u{231}", "\u{233}", "\u{2C65}", "\u{23C}", "\u{19A}", "\u{2C66}", "\u{242}", "\u{180}",
+    "\u{289}", "\u{28C}", "\u{247}", "\u{249}", "\u{24B}", "\u{24D}", "\u{24F}", "\u{371}",
+    "\u{373}", "\u{377}", "\u{3F3}", "\u{3AC}", "\u{3AD}", "\u{3AE}", "\u{3AF}", "\u{3CC}",
+    "\u{3CD}", "\u{3CE}", "\u{3B1}", "\u{3B2}", "\u{3B3}", "\u{3B4}", "\u{3B5}", "\u{3B6}",
+    "\u{3B7}", "\u{3B8}", "\u{3B9}", "\u{3BA}", "\u{3BB}", "\u{3BC}", "\u{3BD}", "\u{3BE}",
+    "\u{3BF}", "\u{3C0}", "\u{3C1}", "\u{3C3}", "\u{3C4}", "\u{3C5}", "\u{3C6}", "\u{3C7}",
+    "\u{3C8}", "\u{3C9}", "\u{3CA}", "\u{3CB}", "\u{3D7}", "\u{3D9}", "\u{3DB}", "\u{3DD}",
+    "\u{3DF}", "\u{3E1}", "\u{3E3}", "\u{3E5}", "\u{3E7}", "\u{3E9}", "\u{3EB}", "\u{3ED}",
+    "\u{3EF}", "\u{3F8}", "\u{3F2}", "\u{3FB}", "\u{37B}", "\u{37C}", "\u{37D}", "\u{450}",
+    "\u{451}", "\u{452}", "\u{453}", "\u{454}", "\u{455}", "\u{456}", "\u{457}", "\u{458}",
+    "\u{459}", "\u{45A}", "\u{45B}", "\u{45C}", "\u{45D}", "\u{45E}", "\u{45F}", "\u{430}",
+    "\u{431}", "\u{432}", "\u{433}", "\u{434}", "\u{435}", "\u{436}", "\u{437}", "\u{438}",
+    "\u{439}", "\u{43A}", "\u{43B}", "\u{43C}", "\u{43D}", "\u{43E}", "\u{43F}", "\u{440}",
+    "\u{441}", "\u{442}", "\u{443}", "\u{444}", "\u{445}", "\u{446}", "\u{447}", "\u{448}",
+    "\u{449}", "\u{44A}", "\u{44B}", "\u{44C}", "\u{44D}", "\u{44E}", "\u{44F}", "\u{461}",
+    "\u{463}", "\u{465}", "\u{467}", "\u{469}", "\u{46B}", "\u{46D}", "\u{46F}", "\u{471}",
+    "\u{473}", "\u{475}", "\u{477}", "\u{479}", "\u{47B}", "\u{47D}", "\u{47F}", "\u{481}",
+    "\u{48B}", "\u{48D}", "\u{48F}", "\u{491}", "\u{493}", "\u{495}", "\u{497}", "\u{499}",
+    "\u{49B}", "\u{49D}", "\u{49F}", "\u{4A1}", "\u{4A3}", "\u{4A5}", "\u{4A7}", "\u{4A9}",
+    "\u{4AB}", "\u{4AD}", "\u{4AF}", "\u{4B1}", "\u{4B3}", "\u{4B5}", "\u{4B7}", "\u{4B9}",
+    "\u{4BB}", "\u{4BD}", "\u{4BF}", "\u{4CF}", "\u{4C2}", "\u{4C4}", "\u{4C6}", "\u{4C8}",
+    "\u{4CA}", "\u{4CC}", "\u{4CE}", "\u{4D1}", "\u{4D3}", "\u{4D5}", "\u{4D7}", "\u{4D9}",
+    "\u{4DB}", "\u{4DD}", "\u{4DF}", "\u{4E1}", "\u{4E3}", "\u{4E5}", "\u{4E7}", "\u{4E9}",
+    "\u{4EB}", "\u{4ED}", "\u{4EF}", "\u{4F1}", "\u{4F3}", "\u{4F5}", "\u{4F7}", "\u{4F9}",
+    "\u{4FB}", "\u{4FD}", "\u{4FF}", "\u{501}", "\u{503}", "\u{505}", "\u{507}", "\u{509}",
+    "\u{50B}", "\u{50D}", "\u{50F}", "\u{511}", "\u{513}", "\u{515}", "\u{517}", "\u{519}",
+    "\u{51B}", "\u{51D}", "\u{51F}", "\u{521}", "\u{523}", "\u{525}", "\u{527}", "\u{529}",
+    "\u{52B}", "\u{52D}", "\u{52F}", "\u{561}", "\u{562}", "\u{563}", "\u{564}", "\u{565}",
+    "\u{566}", "\u{567}", "\u{568}", "\u{569}", "\u{56A}", "\u{56B}", "\u{56C}", "\u{56D}",
+    "\u{56E}", "\u{56F}", "\u{570}", "\u{571}", "\u{572}", "\u{573}", "\u{574}", "\u{575}",
+    "\u{576}", "\u{577}", "\u{578}", "\u{579}", "\u{57A}", "\u{57B}", "\u{57C}", "\u{57D}",
+    "\u{57E}", "\u{57F}", "\u{580}", "\u{581}", "\u{582}", "\u{583}", "\u{584}", "\u{585}",
+    "\u{586}", "\u{2D00}", "\u{2D01}", "\u{2D02}", "\u{2D03}", "\u{2D04}", "\u{2D05}", "\u{2D06}",
+    "\u{2D07}", "\u{2D08}", "\u{2D09}", "\u{2D0A}", "\u{2D0B}", "\u{2D0C}", "\u{2D0D}", "\u{2D0E}",
+    "\u{2D0F}", "\u{2D10}", "\u{2D11}", "\u{2D12}", "\u{2D13}", "\u{2D14}", "\u{2D15}", "\u{2D16}",
+    "\u{2D17}", "\u{2D18}", "\u{2D19}", "\u{2D1A}", "\u{2D1B}", "\u{2D1C}", "\u{2D1D}", "\u{2D1E}",
+    "\u{2D1F}", "\u{2D20}", "\u{2D21}", "\u{2D22}", "\u{2D23}", "\u{2D24}", "\u{2D25}", "\u{2D27}",
+    "\u{2D2D}", "\u{AB70}", "\u{AB71}", "\u{AB72}", "\u{AB73}", "\u{AB74}", "\u{AB75}", "\u{AB76}",
+    "\u{AB77}", "\u{AB78}", "\u{AB79}", "\u{AB7A}", "\u{AB7B}", "\u{AB7C}", "\u{AB7D}", "\u{AB7E}",
+    "\u{AB7F}", "\u{AB80}", "\u{AB81}", "\u{AB82}", "\u{AB83}", "\u{AB84}", "\u{AB85}", "\u{AB86}",
+    "\u{AB87}", "\u{AB88}", "\u{AB89}", "\u{AB8A}", "\u{AB8B}", "\u{AB8C}", "\u{AB8D}", "\u{AB8E}",
+    "\u{AB8F}", "\u{AB90}", "\u{AB91}", "\u{AB92}", "\u{AB93}", "\u{AB94}", "\u{AB95}", "\u{AB96}",
+    "\u{AB97}", "\u{AB98}", "\u{AB99}", "\u{AB9A}", "\u{AB9B}", "\u{AB9C}", "\u{AB9D}", "\u{AB9E}",
+    "\u{AB9F}", "\u{ABA0}", "\u{ABA1}", "\u{ABA2}", "\u{ABA3}", "\u{ABA4}", "\u{ABA5}", "\u{ABA6}",
+    "\u{ABA7}", "\u{ABA8}", "\u{ABA9}", "\u{ABAA}", "\u{ABAB}", "\u{ABAC}", "\u{ABAD}", "\u{ABAE}",
+    "\u{ABAF}", "\u{ABB0}", "\u{ABB1}", "\u{ABB2}", "\u{ABB3}", "\u{ABB4}", "\u{ABB5}", "\u{ABB6}",
+    "\u{ABB7}", "\u{ABB8}", "\u{ABB9}", "\u{ABBA}", "\u{ABBB}", "\u{ABBC}", "\u{ABBD}", "\u{ABBE}",
+    "\u{ABBF}", "\u{13F8}", "\u{13F9}", "\u{13FA}", "\u{13FB}", "\u{13FC}", "\u{13FD}", "\u{1C8A}",
+    "\u{10D0}", "\u{10D1}", "\u{10D2}", "\u{10D3}", "\u{10D4}", "\u{10D5}", "\u{10D6}", "\u{10D7}",
+    "\u{10D8}", "\u{10D9}", "\u{10DA}", "\u{10DB}", "\u{10DC}", "\u{10DD}", "\u{10DE}", "\u{10DF}",
+    "\u{10E0}", "\u{10E1}", "\u{10E2}", "\u{10E3}", "\u{10E4}", "\u{10E5}", "\u{10E6}", "\u{10E7}",
+    "\u{10E8}", "\u{10E9}", "\u{10EA}", "\u{10EB}", "\u{10EC}", "\u{10ED}", "\u{10EE}", "\u{10EF}",
+    "\u{10F0}", "\u{10F1}", "\u{10F2}", "\u{10F3}", "\u{10F4}", "\u{10F5}", "\u{10F6}", "\u{10F7}",
+    "\u{10F8}", "\u{10F9}", "\u{10FA}", "\u{10FD}", "\u{10FE}", "\u{10FF}", "\u{1E01}", "\u{1E03}",
+    "\u{1E05}", "\u{1E07}", "\u{1E09}", "\u{1E0B}", "\u{1E0D}", "\u{1E0F}", "\u{1E11}", "\u{1E13}",
+    "\u{1E15}", "\u{1E17}", "\u{1E19}", "\u{1E1B}", "\u{1E1D}", "\u{1E1F}", "\u{1E21}", "\u{1E23}",
+    "\u{1E25}", "\u{1E27}", "\u{1E29}", "\u{1E2B}", "\u{1E2D}", "\u{1E2F}", "\u{1E31}", "\u{1E33}",
+    "\u{1E35}", "\u{1E37}", "\u{1E39}", "\u{1E3B}", "\u{1E3D}", "\u{1E3F}", "\u{1E41}", "\u{1E43}",
+    "\u{1E45}", "\u{1E47}", "\u{1E49}", "\u{1E4B}", "\u{1E4D}", "\u{1E4F}", "\u{1E51}", "\u{1E53}",
+    "\u{1E55}", "\u{1E57}", "\u{1E59}", "\u{1E5B}", "\u{1E5D}", "\u{1E5F}", "\u{1E61}", "\u{1E63}",
+    "\u{1E65}", "\u{1E67}", "\u{1E69}", "\u{1E6B}", "\u{1E6D}", "\u{1E6F}", "\u{1E71}", "\u{1E73}",
+    "\u{1E75}", "\u{1E77}", "\u{1E79}", "\u{1E7B}", "\u{1E7D}", "\u{1E7F}", "\u{1E81}", "\u{1E83}",
+    "\u{1E85}", "\u{1E87}", "\u{1E89}", "\u{1E8B}", "\u{1E8D}", "\u{1E8F}", "\u{1E91}", "\u{1E93}",
+    "\u{1E95}", "\u{DF}", "\u{1EA1}", "\u{1EA3}", "\u{1EA5}", "\u{1EA7}", "\u{1EA9}", "\u{1EAB}",
+    "\u{1EAD}", "\u{1EAF}", "\u{1EB1}", "\u{1EB3}", "\u{1EB5}", "\u{1EB7}", "\u{1EB9}", "\u{1EBB}",
+    "\u{1EBD}", "\u{1EBF}", "\u{1EC1}", "\u{1EC3}", "\u{1EC5}", "\u{1EC7}", "\u{1EC9}", "\u{1ECB}",
+    "\u{1ECD}", "\u{1ECF}", "\u{1ED1}", "\u{1ED3}", "\u{1ED5}", "\u{1ED7}", "\u{1ED9}", "\u{1EDB}",
+    "\u{1EDD}", "\u{1EDF}", "\u{1EE1}", "\u{1EE3}", "\u{1EE5}", "\u{1EE7}", "\u{1EE9}", "\u{1EEB}",
+    "\u{1EED}", "\u{1EEF}", "\u{1EF1}", "\u{1EF3}", "\u{1EF5}", "\u{1EF7}", "\u{1EF9}", "\u{1EFB}",
+    "\u{1EFD}", "\u{1EFF}", "\u{1F00}", "\u{1F01}", "\u{1F02}", "\u{1F03}", "\u{1F04}", "\u{1F05}",
+    "\u{1F06}", "\u{1F07}", "\u{1F10}", "\u{1F11}", "\u{1F12}", "\u{1F13}", "\u{1F14}", "\u{1F15}",
+    "\u{1F20}", "\u{1F21}", "\u{1F22}", "\u{1F23}", "\u{1F24}", "\u{1F25}", "\u{1F26}", "\u{1F27}",
+    "\u{1F30}", "\u{1F31}", "\u{1F32}", "\u{1F33}", "\u{1F34}", "\u{1F35}", "\u{1F36}", "\u{1F37}",
+    "\u{1F40}", "\u{1F41}", "\u{1F42}", "\u{1F43}", "\u{1F44}", "\u{1F45}", "\u{1F51}", "\u{1F53}",
+    "\u{1F55}", "\u{1F57}", "\u{1F60}", "\u{1F61}", "\u{1F62}", "\u{1F63}", "\u{1F64}", "\u{1F65}",
+    "\u{1F66}", "\u{1F67}", "\u{1F80}", "\u{1F81}", "\u{1F82}", "\u{1F83}", "\u{1F84}", "\u{1F85}",
+    "\u{1F86}", "\u{1F87}", "\u{1F90}", "\u{1F91}", "\u{1F92}", "\u{1F93}", "\u{1F94}", "\u{1F95}",
+    "\u{1F96}", "\u{1F97}", "\u{1FA0}", "\u{1FA1}", "\u{1FA2}", "\u{1FA3}", "\u{1FA4}", "\u{1FA5}",
+    "\u{1FA6}", "\u{1FA7}", "\u{1FB0}", "\u{1FB1}", "\u{1F70}", "\u{1F71}", "\u{1FB3}", "\u{1F72}",
+    "\u{1F73}", "\u{1F74}", "\u{1F75}", "\u{1FC3}", "\u{1FD0}", "\u{1FD1}", "\u{1F76}", "\u{1F77}",
+    "\u{1FE0}", "\u{1FE1}", "\u{1F7A}", "\u{1F7B}", "\u{1FE5}", "\u{1F78}", "\u{1F79}", "\u{1F7C}",
+    "\u{1F7D}", "\u{1FF3}", "\u{214E}", "\u{2170}", "\u{2171}", "\u{2172}", "\u{2173}", "\u{2174}",
+    "\u{2175}", "\u{2176}", "\u{2177}", "\u{2178}", "\u{2179}", "\u{217A}", "\u{217B}", "\u{217C}",
+    "\u{217D}", "\u{217E}", "\u{217F}", "\u{2184}", "\u{24D0}", "\u{24D1}", "\u{24D2}", "\u{24D3}",
+    "\u{24D4}", "\u{24D5}", "\u{24D6}", "\u{24D7}", "\u{24D8}", "\u{24D9}", "\u{24DA}", "\u{24DB}",
+    "\u{24DC}", "\u{24DD}", "\u{24DE}", "\u{24DF}", "\u{24E0}", "\u{24E1}", "\u{24E2}", "\u{24E3}",
+    "\u{24E4}", "\u{24E5}", "\u{24E6}", "\u{24E7}", "\u{24E8}", "\u{24E9}", "\u{2C30}", "\u{2C31}",
+    "\u{2C32}", "\u{2C33}", "\u{2C34}", "\u{2C35}", "\u{2C36}", "\u{2C37}", "\u{2C38}", "\u{2C39}",
+    "\u{2C3A}", "\u{2C3B}", "\u{2C3C}", "\u{2C3D}", "\u{2C3E}", "\u{2C3F}", "\u{2C40}", "\u{2C41}",
+    "\u{2C42}", "\u{2C43}", "\u{2C44}", "\u{2C45}", "\u{2C46}", "\u{2C47}", "\u{2C48}", "\u{2C49}",
+    "\u{2C4A}", "\u{2C4B}", "\u{2C4C}", "\u{2C4D}", "\u{2C4E}", "\u{2C4F}", "\u{2C50}", "\u{2C51}",
+    "\u{2C52}", "\u{2C53}", "\u{2C54}", "\u{2C55}", "\u{2C56}", "\u{2C57}", "\u{2C58}", "\u{2C59}",
+    "\u{2C5A}", "\u{2C5B}", "\u{2C5C}", "\u{2C5D}", "\u{2C5E}", "\u{2C5F}", "\u{2C61}", "\u{26B}",
+    "\u{1D7D}", "\u{27D}", "\u{2C68}", "\u{2C6A}", "\u{2C6C}", "\u{251}", "\u{271}", "\u{250}",
+    "\u{252}", "\u{2C73}", "\u{2C76}", "\u{23F}", "\u{240}", "\u{2C81}", "\u{2C83}", "\u{2C85}",
+    "\u{2C87}", "\u{2C89}", "\u{2C8B}", "\u{2C8D}", "\u{2C8F}", "\u{2C91}", "\u{2C93}", "\u{2C95}",
+    "\u{2C97}", "\u{2C99}", "\u{2C9B}", "\u{2C9D}", "\u{2C9F}", "\u{2CA1}", "\u{2CA3}", "\u{2CA5}",
+    "\u{2CA7}", "\u{2CA9}", "\u{2CAB}", "\u{2CAD}", "\u{2CAF}", "\u{2CB1}", "\u{2CB3}", "\u{2CB5}",
+    "\u{2CB7}", "\u{2CB9}", "\u{2CBB}", "\u{2CBD}", "\u{2CBF}", "\u{2CC1}", "\u{2CC3}", "\u{2CC5}",
+    "\u{2CC7}", "\u{2CC9}", "\u{2CCB}", "\u{2CCD}", "\u{2CCF}", "\u{2CD1}", "\u{2CD3}", "\u{2CD5}",
+    "\u{2CD7}", "\u{2CD9}", "\u{2CDB}", "\u{2CDD}", "\u{2CDF}", "\u{2CE1}", "\u{2CE3}", "\u{2CEC}",
+    "\u{2CEE}", "\u{2CF3}", "\u{A641}", "\u{A643}", "\u{A645}", "\u{A647}", "\u{A649}", "\u{A64B}",
+    "\u{A64D}", "\u{A64F}", "\u{A651}", "\u{A653}", "\u{A655}", "\u{A657}", "\u{A659}", "\u{A65B}",
+    "\u{A65D}", "\u{A65F}", "\u{A661}", "\u{A663}", "\u{A665}", "\u{A667}", "\u{A669}", "\u{A66B}",
+    "\u{A66D}", "\u{A681}", "\u{A683}", "\u{A685}", "\u{A687}", "\u{A689}", "\u{A68B}", "\u{A68D}",
+    "\u{A68F}", "\u{A691}", "\u{A693}", "\u{A695}", "\u{A697}", "\u{A699}", "\u{A69B}", "\u{A723}",
+    "\u{A725}", "\u{A727}", "\u{A729}", "\u{A72B}", "\u{A72D}", "\u{A72F}", "\u{A733}", "\u{A735}",
+    "\u{A737}", "\u{A739}", "\u{A73B}", "\u{A73D}", "\u{A73F}", "\u{A741}", "\u{A743}", "\u{A745}",
+    "\u{A747}", "\u{A749}", "\u{A74B}", "\u{A74D}", "\u{A74F}", "\u{A751}", "\u{A753}", "\u{A755}",
+    "\u{A757}", "\u{A759}", "\u{A75B}", "\u{A75D}", "\u{A75F}", "\u{A761}", "\u{A763}", "\u{A765}",
+    "\u{A767}", "\u{A769}", "\u{A76B}", "\u{A76D}", "\u{A76F}", "\u{A77A}", "\u{A77C}", "\u{1D79}",
+    "\u{A77F}", "\u{A781}", "\u{A783}", "\u{A785}", "\u{A787}", "\u{A78C}", "\u{265}", "\u{A791}",
+    "\u{A793}", "\u{A797}", "\u{A799}", "\u{A79B}", "\u{A79D}", "\u{A79F}", "\u{A7A1}", "\u{A7A3}",
+    "\u{A7A5}", "\u{A7A7}", "\u{A7A9}", "\u{266}", "\u{25C}", "\u{261}", "\u{26C}", "\u{26A}",
+    "\u{29E}", "\u{287}", "\u{29D}", "\u{AB53}", "\u{A7B5}", "\u{A7B7}", "\u{A7B9}", "\u{A7BB}",
+    "\u{A7BD}", "\u{A7BF}", "\u{A7C1}", "\u{A7C3}", "\u{A794}", "\u{282}", "\u{1D8E}", "\u{A7C8}",
+    "\u{A7CA}", "\u{264}", "\u{A7CD}", "\u{A7CF}", "\u{A7D1}", "\u{A7D3}", "\u{A7D5}", "\u{A7D7}",
+    "\u{A7D9}", "\u{A7DB}", "\u{19B}", "\u{A7F6}", "\u{FF41}", "\u{FF42}", "\u{FF43}", "\u{FF44}",
+    "\u{FF45}", "\u{FF46}", "\u{FF47}", "\u{FF48}", "\u{FF49}", "\u{FF4A}", "\u{FF4B}", "\u{FF4C}",
+    "\u{FF4D}", "\u{FF4E}", "\u{FF4F}", "\u{FF50}", "\u{FF51}", "\u{FF52}", "\u{FF53}", "\u{FF54}",
+    "\u{FF55}", "\u{FF56}", "\u{FF57}", "\u{FF58}", "\u{FF59}", "\u{FF5A}", "\u{10428}",
+    "\u{10429}", "\u{1042A}", "\u{1042B}", "\u{1042C}", "\u{1042D}", "\u{1042E}", "\u{1042F}",
+    "\u{10430}", "\u{10431}", "\u{10432}", "\u{10433}", "\u{10434}", "\u{10435}", "\u{10436}",
+    "\u{10437}", "\u{10438}", "\u{10439}", "\u{1043A}", "\u{1043B}", "\u{1043C}", "\u{1043D}",
+    "\u{1043E}", "\u{1043F}", "\u{10440}", "\u{10441}", "\u{10442}", "\u{10443}", "\u{10444}",
+    "\u{10445}", "\u{10446}", "\u{10447}", "\u{10448}", "\u{10449}", "\u{1044A}", "\u{1044B}",
+    "\u{1044C}", "\u{1044D}", "\u{1044E}", "\u{1044F}", "\u{104D8}", "\u{104D9}", "\u{104DA}",
+    "\u{104DB}", "\u{104DC}", "\u{104DD}", "\u{104DE}", "\u{104DF}", "\u{104E0}", "\u{104E1}",
+    "\u{104E2}", "\u{104E3}", "\u{104E4}", "\u{104E5}", "\u{104E6}", "\u{104E7}", "\u{104E8}",
+    "\u{104E9}", "\u{104EA}", "\u{104EB}", "\u{104EC}", "\u{104ED}", "\u{104EE}", "\u{104EF}",
+    "\u{104F0}", "\u{104F1}", "\u{104F2}", "\u{104F3}", "\u{104F4}", "\u{104F5}", "\u{104F6}",
+    "\u{104F7}", "\u{104F8}", "\u{104F9}", "\u{104FA}", "\u{104FB}", "\u{10597}", "\u{10598}",
+    "\u{10599}", "\u{1059A}", "\u{1059B}", "\u{1059C}", "\u{1059D}", "\u{1059E}", "\u{1059F}",
+    "\u{105A0}", "\u{105A1}", "\u{105A3}", "\u{105A4}", "\u{105A5}", "\u{105A6}", "\u{105A7}",
+    "\u{105A8}", "\u{105A9}", "\u{105AA}", "\u{105AB}", "\u{105AC}", "\u{105AD}", "\u{105AE}",
+    "\u{105AF}", "\u{105B0}", "\u{105B1}", "\u{105B3}", "\u{105B4}", "\u{105B5}", "\u{105B6}",
+    "\u{105B7}", "\u{105B8}", "\u{105B9}", "\u{105BB}", "\u{105BC}", "\u{10CC0}", "\u{10CC1}",
+    "\u{10CC2}", "\u{10CC3}", "\u{10CC4}", "\u{10CC5}", "\u{10CC6}", "\u{10CC7}", "\u{10CC8}",
+    "\u{10CC9}", "\u{10CCA}", "\u{10CCB}", "\u{10CCC}", "\u{10CCD}", "\u{10CCE}", "\u{10CCF}",
+    "\u{10CD0}", "\u{10CD1}", "\u{10CD2}", "\u{10CD3}", "\u{10CD4}", "\u{10CD5}", "\u{10CD6}",
+    "\u{10CD7}", "\u{10CD8}", "\u{10CD9}", "\u{10CDA}", "\u{10CDB}", "\u{10CDC}", "\u{10CDD}",
+    "\u{10CDE}", "\u{10CDF}", "\u{10CE0}", "\u{10CE1}", "\u{10CE2}", "\u{10CE3}", "\u{10CE4}",
+    "\u{10CE5}", "\u{10CE6}", "\u{10CE7}", "\u{10CE8}", "\u{10CE9}", "\u{10CEA}", "\u{10CEB}",
+    "\u{10CEC}", "\u{10CED}", "\u{10CEE}", "\u{10CEF}", "\u{10CF0}", "\u{10CF1}", "\u{10CF2}",
+    "\u{10D70}", "\u{10D71}", "\u{10D72}", "\u{10D73}", "\u{10D74}", "\u{10D75}", "\u{10D76}",
+    "\u{10D77}", "\u{10D78}", "\u{10D79}", "\u{10D7A}", "\u{10D7B}", "\u{10D7C}", "\u{10D7D}",
+    "\u{10D7E}", "\u{10D7F}", "\u{10D80}", "\u{10D81}", "\u{10D82}", "\u{10D83}", "\u{10D84}",
+    "\u{10D85}", "\u{118C0}", "\u{118C1}", "\u{118C2}", "\u{118C3}", "\u{118C4}", "\u{118C5}",
+    "\u{118C6}", "\u{118C7}", "\u{118C8}", "\u{118C9}", "\u{118CA}", "\u{118CB}", "\u{118CC}",
+    "\u{118CD}", "\u{118CE}", "\u{118CF}", "\u{118D0}", "\u{118D1}", "\u{118D2}", "\u{118D3}",
+    "\u{118D4}", "\u{118D5}", "\u{118D6}", "\u{118D7}", "\u{118D8}", "\u{118D9}", "\u{118DA}",
+    "\u{118DB}", "\u{118DC}", "\u{118DD}", "\u{118DE}", "\u{118DF}", "\u{16E60}", "\u{16E61}",
+    "\u{16E62}", "\u{16E63}", "\u{16E64}", "\u{16E65}", "\u{16E66}", "\u{16E67}", "\u{16E68}",
+    "\u{16E69}", "\u{16E6A}", "\u{16E6B}", "\u{16E6C}", "\u{16E6D}", "\u{16E6E}", "\u{16E6F}",
+    "\u{16E70}", "\u{16E71}", "\u{16E72}", "\u{16E73}", "\u{16E74}", "\u{16E75}", "\u{16E76}",
+    "\u{16E77}", "\u{16E78}", "\u{16E79}", "\u{16E7A}", "\u{16E7B}", "\u{16E7C}", "\u{16E7D}",
+    "\u{16E7E}", "\u{16E7F}", "\u{16EBB}", "\u{16EBC}", "\u{16EBD}", "\u{16EBE}", "\u{16EBF}",
+    "\u{16EC0}", "\u{16EC1}", "\u{16EC2}", "\u{16EC3}", "\u{16EC4}", "\u{16EC5}", "\u{16EC6}",
+    "\u{16EC7}", "\u{16EC8}", "\u{16EC9}", "\u{16ECA}", "\u{16ECB}", "\u{16ECC}", "\u{16ECD}",
+    "\u{16ECE}", "\u{16ECF}", "\u{16ED0}", "\u{16ED1}", "\u{16ED2}", "\u{16ED3}", "\u{1E922}",
+    "\u{1E923}", "\u{1E924}", "\u{1E925}", "\u{1E926}", "\u{1E927}", "\u{1E928}", "\u{1E929}",
+    "\u{1E92A}", "\u{1E92B}", "\u{1E92C}", "\u{1E92D}", "\u{1E92E}", "\u{1E92F}", "\u{1E930}",
+    "\u{1E931}", "\u{1E932}", "\u{1E933}", "\u{1E934}", "\u{1E935}", "\u{1E936}", "\u{1E937}",
+    "\u{1E938}", "\u{1E939}", "\u{1E93A}", "\u{1E93B}", "\u{1E93C}", "\u{1E93D}", "\u{1E93E}",
+    "\u{1E93F}", "\u{1E940}", "\u{1E941}", "\u{1E942}", "\u{1E943}", "\u{41}", "\u{42}", "\u{43}",
+    "\u{44}", "\u{45}", "\u{46}", "\u{47}", "\u{48}", "\u{49}", "\u{4A}", "\u{4B}", "\u{4C}",
+    "\u{4D}", "\u{4E}", "\u{4F}", "\u{50}", "\u{51}", "\u{52}", "\u{53}", "\u{54}", "\u{55}",
+    "\u{56}", "\u{57}", "\u{58}", "\u{59}", "\u{5A}", "\u{39C}", "\u{53}\u{53}", "\u{C0}", "\u{C1}",
+    "\u{C2}", "\u{C3}", "\u{C4}", "\u{C5}", "\u{C6}", "\u{C7}", "\u{C8}", "\u{C9}", "\u{CA}",
+    "\u{CB}", "\u{CC}", "\u{CD}", "\u{CE}", "\u{CF}", "\u{D0}", "\u{D1}", "\u{D2}", "\u{D3}",
+    "\u{D4}", "\u{D5}", "\u{D6}", "\u{D8}", "\u{D9}", "\u{DA}", "\u{DB}", "\u{DC}", "\u{DD}",
+    "\u{DE}", "\u{178}", "\u{100}", "\u{102}", "\u{104}", "\u{106}", "\u{108}", "\u{10A}",
+    "\u{10C}", "\u{10E}", "\u{110}", "\u{112}", "\u{114}", "\u{116}", "\u{118}", "\u{11A}",
+    "\u{11C}", "\u{11E}", "\u{120}", "\u{122}", "\u{124}", "\u{126}", "\u{128}", "\u{12A}",
+    "\u{12C}", "\u{12E}", "\u{132}", "\u{134}", "\u{136}", "\u{139}", "\u{13B}", "\u{13D}",
+    "\u{13F}", "\u{141}", "\u{143}", "\u{145}", "\u{147}", "\u{2BC}\u{4E}", "\u{14A}", "\u{14C}",
+    "\u{14E}", "\u{150}", "\u{152}", "\u{154}", "\u{156}", "\u{158}", "\u{15A}", "\u{15C}",
+    "\u{15E}", "\u{160}", "\u{162}", "\u{164}", "\u{166}", "\u{168}", "\u{16A}", "\u{16C}",
+    "\u{16E}", "\u{170}", "\u{172}", "\u{174}", "\u{176}", "\u{179}", "\u{17B}", "\u{17D}",
+    "\u{243}", "\u{182}", "\u{184}", "\u{187}", "\u{18B}", "\u{191}", "\u{1F6}", "\u{198}",
+    "\u{23D}", "\u{A7DC}", "\u{220}", "\u{1A0}", "\u{1A2}", "\u{1A4}", "\u{1A7}", "\u{1AC}",
+    "\u{1AF}", "\u{1B3}", "\u{1B5}", "\u{1B8}", "\u{1BC}", "\u{1F7}", "\u{1C4}", "\u{1C7}",
+    "\u{1CA}", "\u{1CD}", "\u{1CF}", "\u{1D1}", "\u{1D3}", "\u{1D5}", "\u{1D7}", "\u{1D9}",
+    "\u{1DB}", "\u{18E}", "\u{1DE}", "\u{1E0}", "\u{1E2}", "\u{1E4}", "\u{1E6}", "\u{1E8}",
+    "\u{1EA}", "\u{1EC}", "\u{1EE}", "\u{4A}\u{30C}", "\u{1F1}", "\u{1F4}", "\u{1F8}", "\u{1FA}",
+    "\u{1FC}", "\u{1FE}", "\u{200}", "\u{202}", "\u{204}", "\u{206}", "\u{208}", "\u{20A}",
+    "\u{20C}", "\u{20E}", "\u{210}", "\u{212}", "\u{214}", "\u{216}", "\u{218}", "\u{21A}",
+    "\u{21C}", "\u{21E}", "\u{222}", "\u{224}", "\u{226}", "\u{228}", "\u{22A}", "\u{22C}",
+    "\u{22E}", "\u{230}", "\u{232}", "\u{23B}", "\u{2C7E}", "\u{2C7F}", "\u{241}", "\u{246}",
+    "\u{248}", "\u{24A}", "\u{24C}", "\u{24E}", "\u{2C6F}", "\u{2C6D}", "\u{2C70}", "\u{181}",
+    "\u{186}", "\u{189}", "\u{18A}", "\u{18F}", "\u{190}", "\u{A7AB}", "\u{193}", "\u{A7AC}",
+    "\u{194}", "\u{A7CB}", "\u{A78D}", "\u{A7AA}", "\u{197}", "\u{196}", "\u{A7AE}", "\u{2C62}",
+    "\u{A7AD}", "\u{19C}", "\u{2C6E}", "\u{19D}", "\u{19F}", "\u{2C64}", "\u{1A6}", "\u{A7C5}",
+    "\u{1A9}", "\u{A7B1}", "\u{1AE}", "\u{244}", "\u{1B1}", "\u{1B2}", "\u{245}", "\u{1B7}",
+    "\u{A7B2}", "\u{A7B0}", "\u{399}", "\u{370}", "\u{372}", "\u{376}", "\u{3FD}", "\u{3FE}",
+    "\u{3FF}", "\u{399}\u{308}\u{301}", "\u{386}", "\u{388}", "\u{389}", "\u{38A}",
+    "\u{3A5}\u{308}\u{301}", "\u{391}", "\u{392}", "\u{393}", "\u{394}", "\u{395}", "\u{396}",
+    "\u{397}", "\u{398}", "\u{39A}", "\u{39B}", "\u{39D}", "\u{39E}", "\u{39F}", "\u{3A0}",
+    "\u{3A1}", "\u{3A3}", "\u{3A4}", "\u{3A5}", "\u{3A6}", "\u{3A7}", "\u{3A8}", "\u{3A9}",
+    "\u{3AA}", "\u{3AB}", "\u{38C}", "\u{38E}", "\u{38F}", "\u{3CF}", "\u{3D8}", "\u{3DA}",
+    "\u{3DC}", "\u{3DE}", "\u{3E0}", "\u{3E2}", "\u{3E4}", "\u{3E6}", "\u{3E8}", "\u{3EA}",
+    "\u{3EC}", "\u{3EE}", "\u{3F9}", "\u{37F}", "\u{3F7}", "\u{3FA}", "\u{410}", "\u{411}",
+    "\u{412}", "\u{413}", "\u{414}", "\u{415}", "\u{416}", "\u{417}", "\u{418}", "\u{419}",
+    "\u{41A}", "\u{41B}", "\u{41C}", "\u{41D}", "\u{41E}", "\u{41F}", "\u{420}", "\u{421}",
+    "\u{422}", "\u{423}", "\u{424}", "\u{425}", "\u{426}", "\u{427}", "\u{428}", "\u{429}",
+    "\u{42A}", "\u{42B}", "\u{42C}", "\u{42D}", "\u{42E}", "\u{42F}", "\u{400}", "\u{401}",
+    "\u{402}", "\u{403}", "\u{404}", "\u{405}", "\u{406}", "\u{407}", "\u{408}", "\u{409}",
+    "\u{40A}", "\u{40B}", "\u{40C}", "\u{40D}", "\u{40E}", "\u{40F}", "\u{460}", "\u{462}",
+    "\u{464}", "\u{466}", "\u{468}", "\u{46A}", "\u{46C}", "\u{46E}", "\u{470}", "\u{472}",
+    "\u{474}", "\u{476}", "\u{478}", "\u{47A}", "\u{47C}", "\u{47E}", "\u{480}", "\u{48A}",
+    "\u{48C}", "\u{48E}", "\u{490}", "\u{492}", "\u{494}", "\u{496}", "\u{498}", "\u{49A}",
+    "\u{49C}", "\u{49E}", "\u{4A0}", "\u{4A2}", "\u{4A4}", "\u{4A6}", "\u{4A8}", "\u{4AA}",
+    "\u{4AC}", "\u{4AE}", "\u{4B0}", "\u{4B2}", "\u{4B4}", "\u{4B6}", "\u{4B8}", "\u{4BA}",
+    "\u{4BC}", "\u{4BE}", "\u{4C1}", "\u{4C3}", "\u{4C5}", "\u{4C7}", "\u{4C9}", "\u{4CB}",
+    "\u{4CD}", "\u{4C0}", "\u{4D0}", "\u{4D2}", "\u{4D4}", "\u{4D6}", "\u{4D8}", "\u{4DA}",
+    "\u{4DC}", "\u{4DE}", "\u{4E0}", "\u{4E2}", "\u{4E4}", "\u{4E6}", "\u{4E8}", "\u{4EA}",
+    "\u{4EC}", "\u{4EE}", "\u{4F0}", "\u{4F2}", "\u{4F4}", "\u{4F6}", "\u{4F8}", "\u{4FA}",
+    "\u{4FC}", "\u{4FE}", "\u{500}", "\u{502}", "\u{504}", "\u{506}", "\u{508}", "\u{50A}",
+    "\u{50C}", "\u{50E}", "\u{510}", "\u{512}", "\u{514}", "\u{516}", "\u{518}", "\u{51A}",
+    "\u{51C}", "\u{51E}", "\u{520}", "\u{522}", "\u{524}", "\u{526}", "\u{528}", "\u{52A}",
+    "\u{52C}", "\u{52E}", "\u{531}", "\u{532}", "\u{533}", "\u{534}", "\u{535}", "\u{536}",
+    "\u{537}", "\u{538}", "\u{539}", "\u{53A}", "\u{53B}", "\u{53C}", "\u{53D}", "\u{53E}",
+    "\u{53F}", "\u{540}", "\u{541}", "\u{542}", "\u{543}", "\u{544}", "\u{545}", "\u{546}",
+    "\u{547}", "\u{548}", "\u{549}", "\u{54A}", "\u{54B}", "\u{54C}", "\u{54D}", "\u{54E}",
+    "\u{54F}", "\u{550}", "\u{551}", "\u{552}", "\u{553}", "\u{554}", "\u{555}", "\u{556}",
+    "\u{535}\u{552}", "\u{1C90}", "\u{1C91}", "\u{1C92}", "\u{1C93}", "\u{1C94}", "\u{1C95}",
+    "\u{1C96}", "\u{1C97}", "\u{1C98}", "\u{1C99}", "\u{1C9A}", "\u{1C9B}", "\u{1C9C}", "\u{1C9D}",
+    "\u{1C9E}", "\u{1C9F}", "\u{1CA0}", "\u{1CA1}", "\u{1CA2}", "\u{1CA3}", "\u{1CA4}", "\u{1CA5}",
+    "\u{1CA6}", "\u{1CA7}", "\u{1CA8}", "\u{1CA9}", "\u{1CAA}", "\u{1CAB}", "\u{1CAC}", "\u{1CAD}",
+    "\u{1CAE}", "\u{1CAF}", "\u{1CB0}", "\u{1CB1}", "\u{1CB2}", "\u{1CB3}", "\u{1CB4}", "\u{1CB5}",
+    "\u{1CB6}", "\u{1CB7}", "\u{1CB8}", "\u{1CB9}", "\u{1CBA}", "\u{1CBD}", "\u{1CBE}", "\u{1CBF}",
+    "\u{13F0}", "\u{13F1}", "\u{13F2}", "\u{13F3}", "\u{13F4}", "\u{13F5}", "\u{A64A}", "\u{1C89}",
+    "\u{A77D}", "\u{2C63}", "\u{A7C6}", "\u{1E00}", "\u{1E02}", "\u{1E04}", "\u{1E06}", "\u{1E08}",
+    "\u{1E0A}", "\u{1E0C}", "\u{1E0E}", "\u{1E10}", "\u{1E12}", "\u{1E14}", "\u{1E16}", "\u{1E18}",
+    "\u{1E1A}", "\u{1E1C}", "\u{1E1E}", "\u{1E20}", "\u{1E22}", "\u{1E24}", "\u{1E26}", "\u{1E28}",
+    "\u{1E2A}", "\u{1E2C}", "\u{1E2E}", "\u{1E30}", "\u{1E32}", "\u{1E34}", "\u{1E36}", "\u{1E38}",
+    "\u{1E3A}", "\u{1E3C}", "\u{1E3E}", "\u{1E40}", "\u{1E42}", "\u{1E44}", "\u{1E46}", "\u{1E48}",
+    "\u{1E4A}", "\u{1E4C}", "\u{1E4E}", "\u{1E50}", "\u{1E52}", "\u{1E54}", "\u{1E56}", "\u{1E58}",
+    "\u{1E5A}", "\u{1E5C}", "\u{1E5E}", "\u{1E60}", "\u{1E62}", "\u{1E64}", "\u{1E66}", "\u{1E68}",
+    "\u{1E6A}", "\u{1E6C}", "\u{1E6E}", "\u{1E70}", "\u{1E72}", "\u{1E74}", "\u{1E76}", "\u{1E78}",
+    "\u{1E7A}", "\u{1E7C}", "\u{1E7E}", "\u{1E80}", "\u{1E82}", "\u{1E84}", "\u{1E86}", "\u{1E88}",
+    "\u{1E8A}", "\u{1E8C}", "\u{1E8E}", "\u{1E90}", "\u{1E92}", "\u{1E94}", "\u{48}\u{331}",
+    "\u{54}\u{308}", "\u{57}\u{30A}", "\u{59}\u{30A}", "\u{41}\u{2BE}", "\u{1EA0}", "\u{1EA2}",
+    "\u{1EA4}", "\u{1EA6}", "\u{1EA8}", "\u{1EAA}", "\u{1EAC}", "\u{1EAE}", "\u{1EB0}", "\u{1EB2}",
+    "\u{1EB4}", "\u{1EB6}", "\u{1EB8}", "\u{1EBA}", "\u{1EBC}", "\u{1EBE}", "\u{1EC0}", "\u{1EC2}",
+    "\u{1EC4}", "\u{1EC6}", "\u{1EC8}", "\u{1ECA}", "\u{1ECC}", "\u{1ECE}", "\u{1ED0}", "\u{1ED2}",
+    "\u{1ED4}", "\u{1ED6}", "\u{1ED8}", "\u{1EDA}", "\u{1EDC}", "\u{1EDE}", "\u{1EE0}", "\u{1EE2}",
+    "\u{1EE4}", "\u{1EE6}", "\u{1EE8}", "\u{1EEA}", "\u{1EEC}", "\u{1EEE}", "\u{1EF0}", "\u{1EF2}",
+    "\u{1EF4}", "\u{1EF6}", "\u{1EF8}", "\u{1EFA}", "\u{1EFC}", "\u{1EFE}", "\u{1F08}", "\u{1F09}",
+    "\u{1F0A}", "\u{1F0B}", "\u{1F0C}", "\u{1F0D}", "\u{1F0E}", "\u{1F0F}", "\u{1F18}", "\u{1F19}",
+    "\u{1F1A}", "\u{1F1B}", "\u{1F1C}", "\u{1F1D}", "\u{1F28}", "\u{1F29}", "\u{1F2A}", "\u{1F2B}",
+    "\u{1F2C}", "\u{1F2D}", "\u{1F2E}", "\u{1F2F}", "\u{1F38}", "\u{1F39}", "\u{1F3A}", "\u{1F3B}",
+    "\u{1F3C}", "\u{1F3D}", "\u{1F3E}", "\u{1F3F}", "\u{1F48}", "\u{1F49}", "\u{1F4A}", "\u{1F4B}",
+    "\u{1F4C}", "\u{1F4D}", "\u{3A5}\u{313}", "\u{1F59}", "\u{3A5}\u{313}\u{300}", "\u{1F5B}",
+    "\u{3A5}\u{313}\u{301}", "\u{1F5D}", "\u{3A5}\u{313}\u{342}", "\u{1F5F}", "\u{1F68}",
+    "\u{1F69}", "\u{1F6A}", "\u{1F6B}", "\u{1F6C}", "\u{1F6D}", "\u{1F6E}", "\u{1F6F}", "\u{1FBA}",
+    "\u{1FBB}", "\u{1FC8}", "\u{1FC9}", "\u{1FCA}", "\u{1FCB}", "\u{1FDA}", "\u{1FDB}", "\u{1FF8}",
+    "\u{1FF9}", "\u{1FEA}", "\u{1FEB}", "\u{1FFA}", "\u{1FFB}", "\u{1F08}\u{399}",
+    "\u{1F09}\u{399}", "\u{1F0A}\u{399}", "\u{1F0B}\u{399}", "\u{1F0C}\u{399}", "\u{1F0D}\u{399}",
+    "\u{1F0E}\u{399}", "\u{1F0F}\u{399}", "\u{1F28}\u{399}", "\u{1F29}\u{399}", "\u{1F2A}\u{399}",
+    "\u{1F2B}\u{399}", "\u{1F2C}\u{399}", "\u{1F2D}\u{399}", "\u{1F2E}\u{399}", "\u{1F2F}\u{399}",
+    "\u{1F68}\u{399}", "\u{1F69}\u{399}", "\u{1F6A}\u{399}", "\u{1F6B}\u{399}", "\u{1F6C}\u{399}",
+    "\u{1F6D}\u{399}", "\u{1F6E}\u{399}", "\u{1F6F}\u{399}", "\u{1FB8}", "\u{1FB9}",
+    "\u{1FBA}\u{399}", "\u{391}\u{399}", "\u{386}\u{399}", "\u{391}\u{342}",
+    "\u{391}\u{342}\u{399}", "\u{1FCA}\u{399}", "\u{397}\u{399}", "\u{389}\u{399}",
+    "\u{397}\u{342}", "\u{397}\u{342}\u{399}", "\u{1FD8}", "\u{1FD9}", "\u{399}\u{308}\u{300}",
+    "\u{399}\u{342}", "\u{399}\u{308}\u{342}", "\u{1FE8}", "\u{1FE9}", "\u{3A5}\u{308}\u{300}",
+    "\u{3A1}\u{313}", "\u{1FEC}", "\u{3A5}\u{342}", "\u{3A5}\u{308}\u{342}", "\u{1FFA}\u{399}",
+    "\u{3A9}\u{399}", "\u{38F}\u{399}", "\u{3A9}\u{342}", "\u{3A9}\u{342}\u{399}", "\u{2132}",
+    "\u{2160}", "\u{2161}", "\u{2162}", "\u{2163}", "\u{2164}", "\u{2165}", "\u{2166}", "\u{2167}",
+    "\u{2168}", "\u{2169}", "\u{216A}", "\u{216B}", "\u{216C}", "\u{216D}", "\u{216E}", "\u{216F}",
+    "\u{2183}", "\u{24B6}", "\u{24B7}", "\u{24B8}", "\u{24B9}", "\u{24BA}", "\u{24BB}", "\u{24BC}",
+    "\u{24BD}", "\u{24BE}", "\u{24BF}", "\u{24C0}", "\u{24C1}", "\u{24C2}", "\u{24C3}", "\u{24C4}",
+    "\u{24C5}", "\u{24C6}", "\u{24C7}", "\u{24C8}", "\u{24C9}", "\u{24CA}", "\u{24CB}", "\u{24CC}",
+    "\u{24CD}", "\u{24CE}", "\u{24CF}", "\u{2C00}", "\u{2C01}", "\u{2C02}", "\u{2C03}", "\u{2C04}",
+    "\u{2C05}", "\u{2C06}", "\u{2C07}", "\u{2C08}", "\u{2C09}", "\u{2C0A}", "\u{2C0B}", "\u{2C0C}",
+    "\u{2C0D}", "\u{2C0E}", "\u{2C0F}", "\u{2C10}", "\u{2C11}", "\u{2C12}", "\u{2C13}", "\u{2C14}",
+    "\u{2C15}", "\u{2C16}", "\u{2C17}", "\u{2C18}", "\u{2C19}", "\u{2C1A}", "\u{2C1B}", "\u{2C1C}",
+    "\u{2C1D}", "\u{2C1E}", "\u{2C1F}", "\u{2C20}", "\u{2C21}", "\u{2C22}", "\u{2C23}", "\u{2C24}",
+    "\u{2C25}", "\u{2C26}", "\u{2C27}", "\u{2C28}", "\u{2C29}", "\u{2C2A}", "\u{2C2B}", "\u{2C2C}",
+    "\u{2C2D}", "\u{2C2E}", "\u{2C2F}", "\u{2C60}", "\u{23A}", "\u{23E}", "\u{2C67}", "\u{2C69}",
+    "\u{2C6B}", "\u{2C72}", "\u{2C75}", "\u{2C80}", "\u{2C82}", "\u{2C84}", "\u{2C86}", "\u{2C88}",
+    "\u{2C8A}", "\u{2C8C}", "\u{2C8E}", "\u{2C90}", "\u{2C92}", "\u{2C94}", "\u{2C96}", "\u{2C98}",
+    "\u{2C9A}", "\u{2C9C}", "\u{2C9E}", "\u{2CA0}", "\u{2CA2}", "\u{2CA4}", "\u{2CA6}", "\u{2CA8}",
+    "\u{2CAA}", "\u{2CAC}", "\u{2CAE}", "\u{2CB0}", "\u{2CB2}", "\u{2CB4}", "\u{2CB6}", "\u{2CB8}",
+    "\u{2CBA}", "\u{2CBC}", "\u{2CBE}", "\u{2CC0}", "\u{2CC2}", "\u{2CC4}", "\u{2CC6}", "\u{2CC8}",
+    "\u{2CCA}", "\u{2CCC}", "\u{2CCE}", "\u{2CD0}", "\u{2CD2}", "\u{2CD4}", "\u{2CD6}", "\u{2CD8}",
+    "\u{2CDA}", "\u{2CDC}", "\u{2CDE}", "\u{2CE0}", "\u{2CE2}", "\u{2CEB}", "\u{2CED}", "\u{2CF2}",
+    "\u{10A0}", "\u{10A1}", "\u{10A2}", "\u{10A3}", "\u{10A4}", "\u{10A5}", "\u{10A6}", "\u{10A7}",
+    "\u{10A8}", "\u{10A9}", "\u{10AA}", "\u{10AB}", "\u{10AC}", "\u{10AD}", "\u{10AE}", "\u{10AF}",
+    "\u{10B0}", "\u{10B1}", "\u{10B2}", "\u{10B3}", "\u{10B4}", "\u{10B5}", "\u{10B6}", "\u{10B7}",
+    "\u{10B8}", "\u{10B9}", "\u{10BA}", "\u{10BB}", "\u{10BC}", "\u{10BD}", "\u{10BE}", "\u{10BF}",
+    "\u{10C0}", "\u{10C1}", "\u{10C2}", "\u{10C3}", "\u{10C4}", "\u{10C5}", "\u{10C7}", "\u{10CD}",
+    "\u{A640}", "\u{A642}", "\u{A644}", "\u{A646}", "\u{A648}", "\u{A64C}", "\u{A64E}", "\u{A650}",
+    "\u{A652}", "\u{A654}", "\u{A656}", "\u{A658}", "\u{A65A}", "\u{A65C}", "\u{A65E}", "\u{A660}",
+    "\u{A662}", "\u{A664}", "\u{A666}", "\u{A668}", "\u{A66A}", "\u{A66C}", "\u{A680}", "\u{A682}",
+    "\u{A684}", "\u{A686}", "\u{A688}", "\u{A68A}", "\u{A68C}", "\u{A68E}", "\u{A690}", "\u{A692}",
+    "\u{A694}", "\u{A696}", "\u{A698}", "\u{A69A}", "\u{A722}", "\u{A724}", "\u{A726}", "\u{A728}",
+    "\u{A72A}", "\u{A72C}", "\u{A72E}", "\u{A732}", "\u{A734}", "\u{A736}", "\u{A738}", "\u{A73A}",
+    "\u{A73C}", "\u{A73E}", "\u{A740}", "\u{A742}", "\u{A744}", "\u{A746}", "\u{A748}", "\u{A74A}",
+    "\u{A74C}", "\u{A74E}", "\u{A750}", "\u{A752}", "\u{A754}", "\u{A756}", "\u{A758}", "\u{A75A}",
+    "\u{A75C}", "\u{A75E}", "\u{A760}", "\u{A762}", "\u{A764}", "\u{A766}", "\u{A768}", "\u{A76A}",
+    "\u{A76C}", "\u{A76E}", "\u{A779}", "\u{A77B}", "\u{A77E}", "\u{A780}", "\u{A782}", "\u{A784}",
+    "\u{A786}", "\u{A78B}", "\u{A790}", "\u{A792}", "\u{A7C4}", "\u{A796}", "\u{A798}", "\u{A79A}",
+    "\u{A79C}", "\u{A79E}", "\u{A7A0}", "\u{A7A2}", "\u{A7A4}", "\u{A7A6}", "\u{A7A8}", "\u{A7B4}",
+    "\u{A7B6}", "\u{A7B8}", "\u{A7BA}", "\u{A7BC}", "\u{A7BE}", "\u{A7C0}", "\u{A7C2}", "\u{A7C7}",
+    "\u{A7C9}", "\u{A7CC}", "\u{A7CE}", "\u{A7D0}", "\u{A7D2}", "\u{A7D4}", "\u{A7D6}", "\u{A7D8}",
+    "\u{A7DA}", "\u{A7F5}", "\u{A7B3}", "\u{13A0}", "\u{13A1}", "\u{13A2}", "\u{13A3}", "\u{13A4}",
+    "\u{13A5}", "\u{13A6}", "\u{13A7}", "\u{13A8}", "\u{13A9}", "\u{13AA}", "\u{13AB}", "\u{13AC}",
+    "\u{13AD}", "\u{13AE}", "\u{13AF}", "\u{13B0}", "\u{13B1}", "\u{13B2}", "\u{13B3}", "\u{13B4}",
+    "\u{13B5}", "\u{13B6}", "\u{13B7}", "\u{13B8}", "\u{13B9}", "\u{13BA}", "\u{13BB}", "\u{13BC}",
+    "\u{13BD}", "\u{13BE}", "\u{13BF}", "\u{13C0}", "\u{13C1}", "\u{13C2}", "\u{13C3}", "\u{13C4}",
+    "\u{13C5}", "\u{13C6}", "\u{13C7}", "\u{13C8}", "\u{13C9}", "\u{13CA}", "\u{13CB}", "\u{13CC}",
+    "\u{13CD}", "\u{13CE}", "\u{13CF}", "\u{13D0}", "\u{13D1}", "\u{13D2}", "\u{13D3}", "\u{13D4}",
+    "\u{13D5}", "\u{13D6}", "\u{13D7}", "\u{13D8}", "\u{13D9}", "\u{13DA}", "\u{13DB}", "\u{13DC}",
+    "\u{13DD}", "\u{13DE}", "\u{13DF}", "\u{13E0}", "\u{13E1}", "\u{13E2}", "\u{13E3}", "\u{13E4}",
+    "\u{13E5}", "\u{13E6}", "\u{13E7}", "\u{13E8}", "\u{13E9}", "\u{13EA}", "\u{13EB}", "\u{13EC}",
+    "\u{13ED}", "\u{13EE}", "\u{13EF}", "\u{46}\u{46}", "\u{46}\u{49}", "\u{46}\u{4C}",
+    "\u{46}\u{46}\u{49}", "\u{46}\u{46}\u{4C}", "\u{53}\u{54}", "\u{544}\u{546}", "\u{544}\u{535}",
+    "\u{544}\u{53B}", "\u{54E}\u{546}", "\u{544}\u{53D}", "\u{FF21}", "\u{FF22}", "\u{FF23}",
+    "\u{FF24}", "\u{FF25}", "\u{FF26}", "\u{FF27}", "\u{FF28}", "\u{FF29}", "\u{FF2A}", "\u{FF2B}",
+    "\u{FF2C}", "\u{FF2D}", "\u{FF2E}", "\u{FF2F}", "\u{FF30}", "\u{FF31}", "\u{FF32}", "\u{FF33}",
+    "\u{FF34}", "\u{FF35}", "\u{FF36}", "\u{FF37}", "\u{FF38}", "\u{FF39}", "\u{FF3A}", "\u{10400}",
+    "\u{10401}", "\u{10402}", "\u{10403}", "\u{10404}", "\u{10405}", "\u{10406}", "\u{10407}",
+    "\u{10408}", "\u{10409}", "\u{1040A}", "\u{1040B}", "\u{1040C}", "\u{1040D}", "\u{1040E}",
+    "\u{1040F}", "\u{10410}", "\u{10411}", "\u{10412}", "\u{10413}", "\u{10414}", "\u{10415}",
+    "\u{10416}", "\u{10417}", "\u{10418}", "\u{10419}", "\u{1041A}", "\u{1041B}", "\u{1041C}",
+    "\u{1041D}", "\u{1041E}", "\u{1041F}", "\u{10420}", "\u{10421}", "\u{10422}", "\u{10423}",
+    "\u{10424}", "\u{10425}", "\u{10426}", "\u{10427}", "\u{104B0}", "\u{104B1}", "\u{104B2}",
+    "\u{104B3}", "\u{104B4}", "\u{104B5}", "\u{104B6}", "\u{104B7}", "\u{104B8}", "\u{104B9}",
+    "\u{104BA}", "\u{104BB}", "\u{104BC}", "\u{104BD}", "\u{104BE}", "\u{104BF}", "\u{104C0}",
+    "\u{104C1}", "\u{104C2}", "\u{104C3}", "\u{104C4}", "\u{104C5}", "\u{104C6}", "\u{104C7}",
+    "\u{104C8}", "\u{104C9}", "\u{104CA}", "\u{104CB}", "\u{104CC}", "\u{104CD}", "\u{104CE}",
+    "\u{104CF}", "\u{104D0}", "\u{104D1}", "\u{104D2}", "\u{104D3}", "\u{10570}", "\u{10571}",
+    "\u{10572}", "\u{10573}", "\u{10574}", "\u{10575}", "\u{10576}", "\u{10577}", "\u{10578}",
+    "\u{10579}", "\u{1057A}", "\u{1057C}", "\u{1057D}", "\u{1057E}", "\u{1057F}", "\u{10580}",
+    "\u{10581}", "\u{10582}", "\u{10583}", "\u{10584}", "\u{10585}", "\u{10586}", "\u{10587}",
+    "\u{10588}", "\u{10589}", "\u{1058A}", "\u{1058C}", "\u{1058D}", "\u{1058E}", "\u{1058F}",
+    "\u{10590}", "\u{10591}", "\u{10592}", "\u{10594}", "\u{10595}", "\u{10C80}", "\u{10C81}",
+    "\u{10C82}", "\u{10C83}", "\u{10C84}", "\u{10C85}", "\u{10C86}", "\u{10C87}", "\u{10C88}",
+    "\u{10C89}", "\u{10C8A}", "\u{10C8B}", "\u{10C8C}", "\u{10C8D}", "\u{10C8E}", "\u{10C8F}",
+    "\u{10C90}", "\u{10C91}", "\u{10C92}", "\u{10C93}", "\u{10C94}", "\u{10C95}", "\u{10C96}",
+    "\u{10C97}", "\u{10C98}", "\u{10C99}", "\u{10C9A}", "\u{10C9B}", "\u{10C9C}", "\u{10C9D}",
+    "\u{10C9E}", "\u{10C9F}", "\u{10CA0}", "\u{10CA1}", "\u{10CA2}", "\u{10CA3}", "\u{10CA4}",
+    "\u{10CA5}", "\u{10CA6}", "\u{10CA7}", "\u{10CA8}", "\u{10CA9}", "\u{10CAA}", "\u{10CAB}",
+    "\u{10CAC}", "\u{10CAD}", "\u{10CAE}", "\u{10CAF}", "\u{10CB0}", "\u{10CB1}", "\u{10CB2}",
+    "\u{10D50}", "\u{10D51}", "\u{10D52}", "\u{10D53}", "\u{10D54}", "\u{10D55}", "\u{10D56}",
+    "\u{10D57}", "\u{10D58}", "\u{10D59}", "\u{10D5A}", "\u{10D5B}", "\u{10D5C}", "\u{10D5D}",
+    "\u{10D5E}", "\u{10D5F}", "\u{10D60}", "\u{10D61}", "\u{10D62}", "\u{10D63}", "\u{10D64}",
+    "\u{10D65}", "\u{118A0}", "\u{118A1}", "\u{118A2}", "\u{118A3}", "\u{118A4}", "\u{118A5}",
+    "\u{118A6}", "\u{118A7}", "\u{118A8}", "\u{118A9}", "\u{118AA}", "\u{118AB}", "\u{118AC}",
+    "\u{118AD}", "\u{118AE}", "\u{118AF}", "\u{118B0}", "\u{118B1}", "\u{118B2}", "\u{118B3}",
+    "\u{118B4}", "\u{118B5}", "\u{118B6}", "\u{118B7}", "\u{118B8}", "\u{118B9}", "\u{118BA}",
+    "\u{118BB}", "\u{118BC}", "\u{118BD}", "\u{118BE}", "\u{118BF}", "\u{16E40}", "\u{16E41}",
+    "\u{16E42}", "\u{16E43}", "\u{16E44}", "\u{16E45}", "\u{16E46}", "\u{16E47}", "\u{16E48}",
+    "\u{16E49}", "\u{16E4A}", "\u{16E4B}", "\u{16E4C}", "\u{16E4D}", "\u{16E4E}", "\u{16E4F}",
+    "\u{16E50}", "\u{16E51}", "\u{16E52}", "\u{16E53}", "\u{16E54}", "\u{16E55}", "\u{16E56}",
+    "\u{16E57}", "\u{16E58}", "\u{16E59}", "\u{16E5A}", "\u{16E5B}", "\u{16E5C}", "\u{16E5D}",
+    "\u{16E5E}", "\u{16E5F}", "\u{16EA0}", "\u{16EA1}", "\u{16EA2}", "\u{16EA3}", "\u{16EA4}",
+    "\u{16EA5}", "\u{16EA6}", "\u{16EA7}", "\u{16EA8}", "\u{16EA9}", "\u{16EAA}", "\u{16EAB}",
+    "\u{16EAC}", "\u{16EAD}", "\u{16EAE}", "\u{16EAF}", "\u{16EB0}", "\u{16EB1}", "\u{16EB2}",
+    "\u{16EB3}", "\u{16EB4}", "\u{16EB5}", "\u{16EB6}", "\u{16EB7}", "\u{16EB8}", "\u{1E900}",
+    "\u{1E901}", "\u{1E902}", "\u{1E903}", "\u{1E904}", "\u{1E905}", "\u{1E906}", "\u{1E907}",
+    "\u{1E908}", "\u{1E909}", "\u{1E90A}", "\u{1E90B}", "\u{1E90C}", "\u{1E90D}", "\u{1E90E}",
+    "\u{1E90F}", "\u{1E910}", "\u{1E911}", "\u{1E912}", "\u{1E913}", "\u{1E914}", "\u{1E915}",
+    "\u{1E916}", "\u{1E917}", "\u{1E918}", "\u{1E919}", "\u{1E91A}", "\u{1E91B}", "\u{1E91C}",
+    "\u{1E91D}", "\u{1E91E}", "\u{1E91F}", "\u{1E920}", "\u{1E921}", "\u{53}\u{73}", "\u{1C5}",
+    "\u{1C8}", "\u{1CB}", "\u{1F2}", "\u{535}\u{582}", "\u{1F88}", "\u{1F89}", "\u{1F8A}",
+    "\u{1F8B}", "\u{1F8C}", "\u{1F8D}", "\u{1F8E}", "\u{1F8F}", "\u{1F98}", "\u{1F99}", "\u{1F9A}",
+    "\u{1F9B}", "\u{1F9C}", "\u{1F9D}", "\u{1F9E}", "\u{1F9F}", "\u{1FA8}", "\u{1FA9}", "\u{1FAA}",
+    "\u{1FAB}", "\u{1FAC}", "\u{1FAD}", "\u{1FAE}", "\u{1FAF}", "\u{1FBA}\u{345}", "\u{1FBC}",
+    "\u{386}\u{345}", "\u{391}\u{342}\u{345}", "\u{1FCA}\u{345}", "\u{1FCC}", "\u{389}\u{345}",
+    "\u{397}\u{342}\u{345}", "\u{1FFA}\u{345}", "\u{1FFC}", "\u{38F}\u{345}",
+    "\u{3A9}\u{342}\u{345}", "\u{46}\u{66}", "\u{46}\u{69}", "\u{46}\u{6C}", "\u{46}\u{66}\u{69}",
+    "\u{46}\u{66}\u{6C}", "\u{53}\u{74}", "\u{544}\u{576}", "\u{544}\u{565}", "\u{544}\u{56B}",
+    "\u{54E}\u{576}", "\u{544}\u{56D}",
+];
+
+/// The full lowercase mapping of every codepoint
+pub(crate) static TO_LOWERCASE: Utf8Trie<[u16; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 3, 3, 3, 3, 3, 3, 4, 3, 3, 3, 3, 5, 10, 11, 11,
+        11, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 0, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 9, 10, 11, 12, 13, 14, 15, 16, 17, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 18, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 23,
+            24, 25, 26, 27, 28, 29, 30,
+        ],
+        [
+            0, 0, 0, 0, 31, 32, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 35, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 37, 38, 39, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0,
+            42, 43, 44, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 46, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 49, 0, 50, 51, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55,
+            56, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 3, 3, 3, 3, 3, 3, 4, 3, 3, 3, 3, 5, 6, 7, 3, 3, 3, 3, 8, 3, 3, 3, 3, 3, 3,
+            3, 9, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+    ],
+    leaves: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48,
+            49, 0, 50, 51, 52, 53, 54, 55, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            57, 0, 58, 0, 59, 0, 60, 0, 61, 0, 62, 0, 63, 0, 64, 0, 65, 0, 66, 0, 67, 0, 68, 0, 69,
+            0, 70, 0, 71, 0, 72, 0, 73, 0, 74, 0, 75, 0, 76, 0, 77, 0, 78, 0, 79, 0, 80, 0, 81, 0,
+            82, 0, 83, 0, 84, 0, 0, 85, 0, 86, 0, 87, 0, 88,
+        ],
+        [
+            0, 89, 0, 90, 0, 91, 0, 92, 0, 0, 93, 0, 94, 0, 95, 0, 96, 0, 97, 0, 98, 0, 99, 0, 100,
+            0, 101, 0, 102, 0, 103, 0, 104, 0, 105, 0, 106, 0, 107, 0, 108, 0, 109, 0, 110, 0, 111,
+            0, 112, 0, 113, 0, 114, 0, 115, 0, 116, 117, 0, 118, 0, 119, 0, 0,
+        ],
+        [
+            0, 120, 121, 0, 122, 0, 123, 124, 0, 125, 126, 127, 0, 0, 128, 129, 130, 131, 0, 132,
+            133, 0, 134, 135, 136, 0, 0, 0, 137, 138, 0, 139, 140, 0, 141, 0, 142, 0, 143, 144, 0,
+            145, 0, 0, 146, 0, 147, 148, 0, 149, 150, 151, 0, 152, 0, 153, 154, 0, 0, 0, 155, 0, 0,
+            0,
+        ],
+        [
+            0, 0, 0, 0, 156, 156, 0, 157, 157, 0, 158, 158, 0, 159, 0, 160, 0, 161, 0, 162, 0, 163,
+            0, 164, 0, 165, 0, 166, 0, 0, 167, 0, 168, 0, 169, 0, 170, 0, 171, 0, 172, 0, 173, 0,
+            174, 0, 175, 0, 0, 176, 176, 0, 177, 0, 178, 179, 180, 0, 181, 0, 182, 0, 183, 0,
+        ],
+        [
+            184, 0, 185, 0, 186, 0, 187, 0, 188, 0, 189, 0, 190, 0, 191, 0, 192, 0, 193, 0, 194, 0,
+            195, 0, 196, 0, 197, 0, 198, 0, 199, 0, 200, 0, 201, 0, 202, 0, 203, 0, 204, 0, 205, 0,
+            206, 0, 207, 0, 208, 0, 209, 0, 0, 0, 0, 0, 0, 0, 210, 211, 0, 212, 213, 0,
+        ],
+        [
+            0, 214, 0, 215, 216, 217, 218, 0, 219, 0, 220, 0, 221, 0, 222, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 223, 0, 224, 0, 0, 0, 225, 0,
+            0, 0, 0, 0, 0, 0, 0, 226,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 227, 0, 228, 229, 230, 0, 231, 0, 232, 233, 0, 234, 235, 236, 237,
+            238, 239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 0, 251, 252, 253, 254,
+            255, 256, 257, 258, 259, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 260, 0, 0, 0, 0, 0, 0, 0, 0, 261, 0, 262,
+            0, 263, 0, 264, 0, 265, 0, 266, 0, 267, 0, 268, 0, 269, 0, 270, 0, 271, 0, 272, 0, 0, 0,
+            0, 0, 241, 0, 0, 273, 0, 274, 275, 0, 0, 276, 277, 278,
+        ],
+        [
+            279, 280, 281, 282, 283, 284, 285, 286, 287, 288, 289, 290, 291, 292, 293, 294, 295,
+            296, 297, 298, 299, 300, 301, 302, 303, 304, 305, 306, 307, 308, 309, 310, 311, 312,
+            313, 314, 315, 316, 317, 318, 319, 320, 321, 322, 323, 324, 325, 326, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 327, 0, 328, 0, 329, 0, 330, 0, 331, 0, 332, 0, 333, 0, 334, 0, 335, 0, 336, 0,
+            337, 0, 338, 0, 339, 0, 340, 0, 341, 0, 342, 0,
+        ],
+        [
+            343, 0, 0, 0, 0, 0, 0, 0, 0, 0, 344, 0, 345, 0, 346, 0, 347, 0, 348, 0, 349, 0, 350, 0,
+            351, 0, 352, 0, 353, 0, 354, 0, 355, 0, 356, 0, 357, 0, 358, 0, 359, 0, 360, 0, 361, 0,
+            362, 0, 363, 0, 364, 0, 365, 0, 366, 0, 367, 0, 368, 0, 369, 0, 370, 0,
+        ],
+        [
+            371, 372, 0, 373, 0, 374, 0, 375, 0, 376, 0, 377, 0, 378, 0, 0, 379, 0, 380, 0, 381, 0,
+            382, 0, 383, 0, 384, 0, 385, 0, 386, 0, 387, 0, 388, 0, 389, 0, 390, 0, 391, 0, 392, 0,
+            393, 0, 394, 0, 395, 0, 396, 0, 397, 0, 398, 0, 399, 0, 400, 0, 401, 0, 402, 0,
+        ],
+        [
+            403, 0, 404, 0, 405, 0, 406, 0, 407, 0, 408, 0, 409, 0, 410, 0, 411, 0, 412, 0, 413, 0,
+            414, 0, 415, 0, 416, 0, 417, 0, 418, 0, 419, 0, 420, 0, 421, 0, 422, 0, 423, 0, 424, 0,
+            425, 0, 426, 0, 0, 427, 428, 429, 430, 431, 432, 433, 434, 435, 436, 437, 438, 439, 440,
+            441,
+        ],
+        [
+            442, 443, 444, 445, 446, 447, 448, 449, 450, 451, 452, 453, 454, 455, 456, 457, 458,
+            459, 460, 461, 462, 463, 464, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 465, 466, 467, 468, 469, 470, 471, 472, 473, 474, 475, 476, 477, 478, 479, 480,
+            481, 482, 483, 484, 485, 486, 487, 488, 489, 490, 491, 492, 493, 494, 495, 496,
+        ],
+        [
+            497, 498, 499, 500, 501, 502, 0, 503, 0, 0, 0, 0, 0, 504, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 505, 506, 507, 508, 509, 510, 511, 512, 513, 514, 515, 516, 517, 518, 519, 520,
+            521, 522, 523, 524, 525, 526, 527, 528, 529, 530, 531, 532, 533, 534, 535, 536,
+        ],
+        [
+            537, 538, 539, 540, 541, 542, 543, 544, 545, 546, 547, 548, 549, 550, 551, 552, 553,
+            554, 555, 556, 557, 558, 559, 560, 561, 562, 563, 564, 565, 566, 567, 568, 569, 570,
+            571, 572, 573, 574, 575, 576, 577, 578, 579, 580, 581, 582, 583, 584, 585, 586, 587,
+            588, 589, 590, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 591, 0, 0, 0, 0, 0, 0, 592, 593, 594, 595, 596, 597, 598,
+            599, 600, 601, 602, 603, 604, 605, 606, 607, 608, 609, 610, 611, 612, 613, 614, 615,
+            616, 617, 618, 619, 620, 621, 622, 623, 624, 625, 626, 627, 628, 629, 630, 631, 632,
+            633, 634, 0, 0, 635, 636, 637,
+        ],
+        [
+            638, 0, 639, 0, 640, 0, 641, 0, 642, 0, 643, 0, 644, 0, 645, 0, 646, 0, 647, 0, 648, 0,
+            649, 0, 650, 0, 651, 0, 652, 0, 653, 0, 654, 0, 655, 0, 656, 0, 657, 0, 658, 0, 659, 0,
+            660, 0, 661, 0, 662, 0, 663, 0, 664, 0, 665, 0, 666, 0, 667, 0, 668, 0, 669, 0,
+        ],
+        [
+            670, 0, 671, 0, 672, 0, 673, 0, 674, 0, 675, 0, 676, 0, 677, 0, 678, 0, 679, 0, 680, 0,
+            681, 0, 682, 0, 683, 0, 684, 0, 685, 0, 686, 0, 687, 0, 688, 0, 689, 0, 690, 0, 691, 0,
+            692, 0, 693, 0, 694, 0, 695, 0, 696, 0, 697, 0, 698, 0, 699, 0, 700, 0, 701, 0,
+        ],
+        [
+            702, 0, 703, 0, 704, 0, 705, 0, 706, 0, 707, 0, 708, 0, 709, 0, 710, 0, 711, 0, 712, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 713, 0, 714, 0, 715, 0, 716, 0, 717, 0, 718, 0, 719, 0, 720, 0,
+            721, 0, 722, 0, 723, 0, 724, 0, 725, 0, 726, 0, 727, 0, 728, 0, 729, 0,
+        ],
+        [
+            730, 0, 731, 0, 732, 0, 733, 0, 734, 0, 735, 0, 736, 0, 737, 0, 738, 0, 739, 0, 740, 0,
+            741, 0, 742, 0, 743, 0, 744, 0, 745, 0, 746, 0, 747, 0, 748, 0, 749, 0, 750, 0, 751, 0,
+            752, 0, 753, 0, 754, 0, 755, 0, 756, 0, 757, 0, 758, 0, 759, 0, 760, 0, 761, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 762, 763, 764, 765, 766, 767, 768, 769, 0, 0, 0, 0, 0, 0, 0, 0,
+            770, 771, 772, 773, 774, 775, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 776, 777, 778, 779, 780,
+            781, 782, 783, 0, 0, 0, 0, 0, 0, 0, 0, 784, 785, 786, 787, 788, 789, 790, 791,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 792, 793, 794, 795, 796, 797, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            798, 0, 799, 0, 800, 0, 801, 0, 0, 0, 0, 0, 0, 0, 0, 802, 803, 804, 805, 806, 807, 808,
+            809, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 810, 811, 812, 813, 814, 815, 816, 817, 0, 0, 0, 0, 0, 0, 0, 0,
+            818, 819, 820, 821, 822, 823, 824, 825, 0, 0, 0, 0, 0, 0, 0, 0, 826, 827, 828, 829, 830,
+            831, 832, 833, 0, 0, 0, 0, 0, 0, 0, 0, 834, 835, 836, 837, 838, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 839, 840, 841, 842, 843, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 844,
+            845, 846, 847, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 848, 849, 850, 851, 852, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 853, 854, 855, 856, 857, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 257, 0, 0, 0, 11, 32, 0, 0, 0, 0, 0, 0, 858, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 859, 860, 861, 862, 863, 864, 865, 866, 867, 868, 869, 870, 871, 872, 873, 874,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 875, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 876, 877,
+            878, 879, 880, 881, 882, 883, 884, 885,
+        ],
+        [
+            886, 887, 888, 889, 890, 891, 892, 893, 894, 895, 896, 897, 898, 899, 900, 901, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            902, 903, 904, 905, 906, 907, 908, 909, 910, 911, 912, 913, 914, 915, 916, 917, 918,
+            919, 920, 921, 922, 923, 924, 925, 926, 927, 928, 929, 930, 931, 932, 933, 934, 935,
+            936, 937, 938, 939, 940, 941, 942, 943, 944, 945, 946, 947, 948, 949, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 950, 0, 951, 952, 953, 0, 0, 954, 0, 955, 0, 956, 0, 957, 958, 959, 960, 0,
+            961, 0, 0, 962, 0, 0, 0, 0, 0, 0, 0, 0, 963, 964,
+        ],
+        [
+            965, 0, 966, 0, 967, 0, 968, 0, 969, 0, 970, 0, 971, 0, 972, 0, 973, 0, 974, 0, 975, 0,
+            976, 0, 977, 0, 978, 0, 979, 0, 980, 0, 981, 0, 982, 0, 983, 0, 984, 0, 985, 0, 986, 0,
+            987, 0, 988, 0, 989, 0, 990, 0, 991, 0, 992, 0, 993, 0, 994, 0, 995, 0, 996, 0,
+        ],
+        [
+            997, 0, 998, 0, 999, 0, 1000, 0, 1001, 0, 1002, 0, 1003, 0, 1004, 0, 1005, 0, 1006, 0,
+            1007, 0, 1008, 0, 1009, 0, 1010, 0, 1011, 0, 1012, 0, 1013, 0, 1014, 0, 0, 0, 0, 0, 0,
+            0, 0, 1015, 0, 1016, 0, 0, 0, 0, 1017, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1018, 0, 1019, 0, 1020, 0, 1021, 0, 1022, 0, 1023, 0, 1024, 0, 1025, 0, 1026, 0, 1027,
+            0, 1028, 0, 1029, 0, 1030, 0, 1031, 0, 1032, 0, 1033, 0, 1034, 0, 1035, 0, 1036, 0,
+            1037, 0, 1038, 0, 1039, 0, 1040, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0,
+        ],
+        [
+            1041, 0, 1042, 0, 1043, 0, 1044, 0, 1045, 0, 1046, 0, 1047, 0, 1048, 0, 1049, 0, 1050,
+            0, 1051, 0, 1052, 0, 1053, 0, 1054, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 1055, 0, 1056, 0, 1057, 0, 1058, 0, 1059, 0, 1060, 0, 1061, 0, 0, 0,
+            1062, 0, 1063, 0, 1064, 0, 1065, 0, 1066, 0, 1067, 0, 1068, 0,
+        ],
+        [
+            1069, 0, 1070, 0, 1071, 0, 1072, 0, 1073, 0, 1074, 0, 1075, 0, 1076, 0, 1077, 0, 1078,
+            0, 1079, 0, 1080, 0, 1081, 0, 1082, 0, 1083, 0, 1084, 0, 1085, 0, 1086, 0, 1087, 0,
+            1088, 0, 1089, 0, 1090, 0, 1091, 0, 1092, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1093, 0, 1094,
+            0, 1095, 1096, 0,
+        ],
+        [
+            1097, 0, 1098, 0, 1099, 0, 1100, 0, 0, 0, 0, 1101, 0, 1102, 0, 0, 1103, 0, 1104, 0, 0,
+            0, 1105, 0, 1106, 0, 1107, 0, 1108, 0, 1109, 0, 1110, 0, 1111, 0, 1112, 0, 1113, 0,
+            1114, 0, 1115, 1116, 1117, 1118, 1119, 0, 1120, 1121, 1122, 1123, 1124, 0, 1125, 0,
+            1126, 0, 1127, 0, 1128, 0, 1129, 0,
+        ],
+        [
+            1130, 0, 1131, 0, 1132, 1133, 1134, 1135, 0, 1136, 0, 1137, 1138, 0, 1139, 0, 1140, 0,
+            1141, 0, 1142, 0, 1143, 0, 1144, 0, 1145, 0, 1146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 1148, 1149, 1150, 1151, 1152, 1153, 1154, 1155, 1156, 1157, 1158, 1159,
+            1160, 1161, 1162, 1163, 1164, 1165, 1166, 1167, 1168, 1169, 1170, 1171, 1172, 1173, 0,
+            0, 0, 0, 0,
+        ],
+        [
+            1174, 1175, 1176, 1177, 1178, 1179, 1180, 1181, 1182, 1183, 1184, 1185, 1186, 1187,
+            1188, 1189, 1190, 1191, 1192, 1193, 1194, 1195, 1196, 1197, 1198, 1199, 1200, 1201,
+            1202, 1203, 1204, 1205, 1206, 1207, 1208, 1209, 1210, 1211, 1212, 1213, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1214, 1215, 1216, 1217, 1218,
+            1219, 1220, 1221, 1222, 1223, 1224, 1225, 1226, 1227, 1228, 1229,
+        ],
+        [
+            1230, 1231, 1232, 1233, 1234, 1235, 1236, 1237, 1238, 1239, 1240, 1241, 1242, 1243,
+            1244, 1245, 1246, 1247, 1248, 1249, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1250, 1251, 1252, 1253, 1254,
+            1255, 1256, 1257, 1258, 1259, 1260, 0, 1261, 1262, 1263, 1264,
+        ],
+        [
+            1265, 1266, 1267, 1268, 1269, 1270, 1271, 1272, 1273, 1274, 1275, 0, 1276, 1277, 1278,
+            1279, 1280, 1281, 1282, 0, 1283, 1284, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1285, 1286, 1287, 1288, 1289, 1290, 1291, 1292, 1293, 1294, 1295, 1296, 1297, 1298,
+            1299, 1300, 1301, 1302, 1303, 1304, 1305, 1306, 1307, 1308, 1309, 1310, 1311, 1312,
+            1313, 1314, 1315, 1316, 1317, 1318, 1319, 1320, 1321, 1322, 1323, 1324, 1325, 1326,
+            1327, 1328, 1329, 1330, 1331, 1332, 1333, 1334, 1335, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1336, 1337, 1338, 1339, 1340, 1341,
+            1342, 1343, 1344, 1345, 1346, 1347, 1348, 1349, 1350, 1351, 1352, 1353, 1354, 1355,
+            1356, 1357, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1358, 1359, 1360, 1361, 1362, 1363, 1364, 1365, 1366, 1367, 1368, 1369, 1370,
+            1371, 1372, 1373, 1374, 1375, 1376, 1377, 1378, 1379, 1380, 1381, 1382, 1383, 1384,
+            1385, 1386, 1387, 1388, 1389,
+        ],
+        [
+            1390, 1391, 1392, 1393, 1394, 1395, 1396, 1397, 1398, 1399, 1400, 1401, 1402, 1403,
+            1404, 1405, 1406, 1407, 1408, 1409, 1410, 1411, 1412, 1413, 1414, 1415, 1416, 1417,
+            1418, 1419, 1420, 1421, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1422, 1423, 1424, 1425, 1426, 1427, 1428, 1429, 1430, 1431, 1432, 1433, 1434,
+            1435, 1436, 1437, 1438, 1439, 1440, 1441, 1442, 1443, 1444, 1445, 1446, 0, 0, 0, 0, 0,
+            0, 0,
+        ],
+        [
+            1447, 1448, 1449, 1450, 1451, 1452, 1453, 1454, 1455, 1456, 1457, 1458, 1459, 1460,
+            1461, 1462, 1463, 1464, 1465, 1466, 1467, 1468, 1469, 1470, 1471, 1472, 1473, 1474,
+            1475, 1476, 1477, 1478, 1479, 1480, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    ],
+};
+
+/// The full uppercase mapping of every codepoint
+pub(crate) static TO_UPPERCASE: Utf8Trie<[u16; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0, 0, 11, 12, 13, 14, 15, 16, 17, 18,
+        19, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 3, 3, 3, 3, 3, 3, 4, 3, 3, 3, 3, 5, 10, 11,
+        11, 11, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0, 0, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 24, 25, 0, 26,
+            27, 28, 29, 30, 31, 32, 33,
+        ],
+        [
+            0, 0, 0, 0, 0, 34, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 39, 40, 41, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 43, 0,
+            44, 45, 46, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 49, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 51, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 53, 0, 54, 0, 0, 55, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 57, 58, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60,
+            61, 62, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 63, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 3, 3, 3, 3, 3, 3, 4, 3, 3, 3, 3, 5, 6, 7, 3, 3, 3, 3, 8, 3, 3, 3, 3, 3, 3,
+            3, 9, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+    ],
+    leaves: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 1481, 1482, 1483, 1484, 1485, 1486, 1487, 1488, 1489, 1490, 1491, 1492,
+            1493, 1494, 1495, 1496, 1497, 1498, 1499, 1500, 1501, 1502, 1503, 1504, 1505, 1506, 0,
+            0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1507, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 1508, 1509, 1510, 1511, 1512, 1513, 1514, 1515, 1516, 1517, 1518, 1519, 1520,
+            1521, 1522, 1523, 1524, 1525, 1526, 1527, 1528, 1529, 1530, 1531, 0, 1532, 1533, 1534,
+            1535, 1536, 1537, 1538, 1539,
+        ],
+        [
+            0, 1540, 0, 1541, 0, 1542, 0, 1543, 0, 1544, 0, 1545, 0, 1546, 0, 1547, 0, 1548, 0,
+            1549, 0, 1550, 0, 1551, 0, 1552, 0, 1553, 0, 1554, 0, 1555, 0, 1556, 0, 1557, 0, 1558,
+            0, 1559, 0, 1560, 0, 1561, 0, 1562, 0, 1563, 0, 1489, 0, 1564, 0, 1565, 0, 1566, 0, 0,
+            1567, 0, 1568, 0, 1569, 0,
+        ],
+        [
+            1570, 0, 1571, 0, 1572, 0, 1573, 0, 1574, 1575, 0, 1576, 0, 1577, 0, 1578, 0, 1579, 0,
+            1580, 0, 1581, 0, 1582, 0, 1583, 0, 1584, 0, 1585, 0, 1586, 0, 1587, 0, 1588, 0, 1589,
+            0, 1590, 0, 1591, 0, 1592, 0, 1593, 0, 1594, 0, 1595, 0, 1596, 0, 1597, 0, 1598, 0, 0,
+            1599, 0, 1600, 0, 1601, 1499,
+        ],
+        [
+            1602, 0, 0, 1603, 0, 1604, 0, 0, 1605, 0, 0, 0, 1606, 0, 0, 0, 0, 0, 1607, 0, 0, 1608,
+            0, 0, 0, 1609, 1610, 1611, 0, 0, 1612, 0, 0, 1613, 0, 1614, 0, 1615, 0, 0, 1616, 0, 0,
+            0, 0, 1617, 0, 0, 1618, 0, 0, 0, 1619, 0, 1620, 0, 0, 1621, 0, 0, 0, 1622, 0, 1623,
+        ],
+        [
+            0, 0, 0, 0, 0, 1624, 1624, 0, 1625, 1625, 0, 1626, 1626, 0, 1627, 0, 1628, 0, 1629, 0,
+            1630, 0, 1631, 0, 1632, 0, 1633, 0, 1634, 1635, 0, 1636, 0, 1637, 0, 1638, 0, 1639, 0,
+            1640, 0, 1641, 0, 1642, 0, 1643, 0, 1644, 1645, 0, 1646, 1646, 0, 1647, 0, 0, 0, 1648,
+            0, 1649, 0, 1650, 0, 1651,
+        ],
+        [
+            0, 1652, 0, 1653, 0, 1654, 0, 1655, 0, 1656, 0, 1657, 0, 1658, 0, 1659, 0, 1660, 0,
+            1661, 0, 1662, 0, 1663, 0, 1664, 0, 1665, 0, 1666, 0, 1667, 0, 0, 0, 1668, 0, 1669, 0,
+            1670, 0, 1671, 0, 1672, 0, 1673, 0, 1674, 0, 1675, 0, 1676, 0, 0, 0, 0, 0, 0, 0, 0,
+            1677, 0, 0, 1678,
+        ],
+        [
+            1679, 0, 1680, 0, 0, 0, 0, 1681, 0, 1682, 0, 1683, 0, 1684, 0, 1685, 1686, 1687, 1688,
+            1689, 1690, 0, 1691, 1692, 0, 1693, 0, 1694, 1695, 0, 0, 0, 1696, 1697, 0, 1698, 1699,
+            1700, 1701, 0, 1702, 1703, 1704, 1705, 1706, 0, 0, 1707, 0, 1708, 1709, 0, 0, 1710, 0,
+            0, 0, 0, 0, 0, 0, 1711, 0, 0,
+        ],
+        [
+            1712, 0, 1713, 1714, 0, 0, 0, 1715, 1716, 1717, 1718, 1719, 1720, 0, 0, 0, 0, 0, 1721,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1722, 1723, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 1724, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1725, 0, 1726, 0, 0, 0,
+            1727, 0, 0, 0, 1728, 1729, 1730, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1731, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1732, 1733, 1734, 1735, 1736, 1737,
+            1738, 1739, 1740, 1741, 1742, 1743, 1744, 1724, 1745, 1746, 1507, 1747, 1748, 1749,
+        ],
+        [
+            1750, 1751, 1752, 1752, 1753, 1754, 1755, 1756, 1757, 1758, 1759, 1760, 1761, 1762,
+            1763, 0, 1738, 1744, 0, 0, 0, 1755, 1750, 1764, 0, 1765, 0, 1766, 0, 1767, 0, 1768, 0,
+            1769, 0, 1770, 0, 1771, 0, 1772, 0, 1773, 0, 1774, 0, 1775, 0, 1776, 1745, 1751, 1777,
+            1778, 0, 1741, 0, 0, 1779, 0, 0, 1780, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1781, 1782, 1783, 1784, 1785,
+            1786, 1787, 1788, 1789, 1790, 1791, 1792, 1793, 1794, 1795, 1796,
+        ],
+        [
+            1797, 1798, 1799, 1800, 1801, 1802, 1803, 1804, 1805, 1806, 1807, 1808, 1809, 1810,
+            1811, 1812, 1813, 1814, 1815, 1816, 1817, 1818, 1819, 1820, 1821, 1822, 1823, 1824,
+            1825, 1826, 1827, 1828, 0, 1829, 0, 1830, 0, 1831, 0, 1832, 0, 1833, 0, 1834, 0, 1835,
+            0, 1836, 0, 1837, 0, 1838, 0, 1839, 0, 1840, 0, 1841, 0, 1842, 0, 1843, 0, 1844,
+        ],
+        [
+            0, 1845, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1846, 0, 1847, 0, 1848, 0, 1849, 0, 1850, 0, 1851,
+            0, 1852, 0, 1853, 0, 1854, 0, 1855, 0, 1856, 0, 1857, 0, 1858, 0, 1859, 0, 1860, 0,
+            1861, 0, 1862, 0, 1863, 0, 1864, 0, 1865, 0, 1866, 0, 1867, 0, 1868, 0, 1869, 0, 1870,
+            0, 1871, 0, 1872,
+        ],
+        [
+            0, 0, 1873, 0, 1874, 0, 1875, 0, 1876, 0, 1877, 0, 1878, 0, 1879, 1880, 0, 1881, 0,
+            1882, 0, 1883, 0, 1884, 0, 1885, 0, 1886, 0, 1887, 0, 1888, 0, 1889, 0, 1890, 0, 1891,
+            0, 1892, 0, 1893, 0, 1894, 0, 1895, 0, 1896, 0, 1897, 0, 1898, 0, 1899, 0, 1900, 0,
+            1901, 0, 1902, 0, 1903, 0, 1904,
+        ],
+        [
+            0, 1905, 0, 1906, 0, 1907, 0, 1908, 0, 1909, 0, 1910, 0, 1911, 0, 1912, 0, 1913, 0,
+            1914, 0, 1915, 0, 1916, 0, 1917, 0, 1918, 0, 1919, 0, 1920, 0, 1921, 0, 1922, 0, 1923,
+            0, 1924, 0, 1925, 0, 1926, 0, 1927, 0, 1928, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 1929, 1930, 1931, 1932, 1933, 1934, 1935, 1936, 1937, 1938, 1939, 1940,
+            1941, 1942, 1943, 1944, 1945, 1946, 1947, 1948, 1949, 1950, 1951, 1952, 1953, 1954,
+            1955, 1956, 1957, 1958, 1959,
+        ],
+        [
+            1960, 1961, 1962, 1963, 1964, 1965, 1966, 1967, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1968, 1969, 1970, 1971, 1972, 1973,
+            1974, 1975, 1976, 1977, 1978, 1979, 1980, 1981, 1982, 1983, 1984, 1985, 1986, 1987,
+            1988, 1989, 1990, 1991, 1992, 1993, 1994, 1995, 1996, 1997, 1998, 1999, 2000, 2001,
+            2002, 2003, 2004, 2005, 2006, 2007, 2008, 2009, 2010, 0, 0, 2011, 2012, 2013,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2014,
+            2015, 2016, 2017, 2018, 2019, 0, 0,
+        ],
+        [
+            1783, 1785, 1795, 1798, 1799, 1799, 1807, 1830, 2020, 0, 2021, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            2022, 0, 0, 0, 2023, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2024, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 2025, 0, 2026, 0, 2027, 0, 2028, 0, 2029, 0, 2030, 0, 2031, 0, 2032, 0, 2033, 0,
+            2034, 0, 2035, 0, 2036, 0, 2037, 0, 2038, 0, 2039, 0, 2040, 0, 2041, 0, 2042, 0, 2043,
+            0, 2044, 0, 2045, 0, 2046, 0, 2047, 0, 2048, 0, 2049, 0, 2050, 0, 2051, 0, 2052, 0,
+            2053, 0, 2054, 0, 2055, 0, 2056,
+        ],
+        [
+            0, 2057, 0, 2058, 0, 2059, 0, 2060, 0, 2061, 0, 2062, 0, 2063, 0, 2064, 0, 2065, 0,
+            2066, 0, 2067, 0, 2068, 0, 2069, 0, 2070, 0, 2071, 0, 2072, 0, 2073, 0, 2074, 0, 2075,
+            0, 2076, 0, 2077, 0, 2078, 0, 2079, 0, 2080, 0, 2081, 0, 2082, 0, 2083, 0, 2084, 0,
+            2085, 0, 2086, 0, 2087, 0, 2088,
+        ],
+        [
+            0, 2089, 0, 2090, 0, 2091, 0, 2092, 0, 2093, 0, 2094, 0, 2095, 0, 2096, 0, 2097, 0,
+            2098, 0, 2099, 2100, 2101, 2102, 2103, 2104, 2073, 0, 0, 0, 0, 0, 2105, 0, 2106, 0,
+            2107, 0, 2108, 0, 2109, 0, 2110, 0, 2111, 0, 2112, 0, 2113, 0, 2114, 0, 2115, 0, 2116,
+            0, 2117, 0, 2118, 0, 2119, 0, 2120,
+        ],
+        [
+            0, 2121, 0, 2122, 0, 2123, 0, 2124, 0, 2125, 0, 2126, 0, 2127, 0, 2128, 0, 2129, 0,
+            2130, 0, 2131, 0, 2132, 0, 2133, 0, 2134, 0, 2135, 0, 2136, 0, 2137, 0, 2138, 0, 2139,
+            0, 2140, 0, 2141, 0, 2142, 0, 2143, 0, 2144, 0, 2145, 0, 2146, 0, 2147, 0, 2148, 0,
+            2149, 0, 2150, 0, 2151, 0, 2152,
+        ],
+        [
+            2153, 2154, 2155, 2156, 2157, 2158, 2159, 2160, 0, 0, 0, 0, 0, 0, 0, 0, 2161, 2162,
+            2163, 2164, 2165, 2166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2167, 2168, 2169, 2170, 2171,
+            2172, 2173, 2174, 0, 0, 0, 0, 0, 0, 0, 0, 2175, 2176, 2177, 2178, 2179, 2180, 2181,
+            2182, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            2183, 2184, 2185, 2186, 2187, 2188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2189, 2190, 2191,
+            2192, 2193, 2194, 2195, 2196, 0, 0, 0, 0, 0, 0, 0, 0, 2197, 2198, 2199, 2200, 2201,
+            2202, 2203, 2204, 0, 0, 0, 0, 0, 0, 0, 0, 2205, 2206, 2207, 2208, 2209, 2210, 2211,
+            2212, 2213, 2214, 2215, 2216, 2217, 2218, 0, 0,
+        ],
+        [
+            2219, 2220, 2221, 2222, 2223, 2224, 2225, 2226, 2219, 2220, 2221, 2222, 2223, 2224,
+            2225, 2226, 2227, 2228, 2229, 2230, 2231, 2232, 2233, 2234, 2227, 2228, 2229, 2230,
+            2231, 2232, 2233, 2234, 2235, 2236, 2237, 2238, 2239, 2240, 2241, 2242, 2235, 2236,
+            2237, 2238, 2239, 2240, 2241, 2242, 2243, 2244, 2245, 2246, 2247, 0, 2248, 2249, 0, 0,
+            0, 0, 2246, 0, 1724, 0,
+        ],
+        [
+            0, 0, 2250, 2251, 2252, 0, 2253, 2254, 0, 0, 0, 0, 2251, 0, 0, 0, 2255, 2256, 2257,
+            1731, 0, 0, 2258, 2259, 0, 0, 0, 0, 0, 0, 0, 0, 2260, 2261, 2262, 1736, 2263, 2264,
+            2265, 2266, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2267, 2268, 2269, 0, 2270, 2271, 0, 0, 0, 0,
+            2268, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2272, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2273, 2274, 2275, 2276,
+            2277, 2278, 2279, 2280, 2281, 2282, 2283, 2284, 2285, 2286, 2287, 2288,
+        ],
+        [
+            0, 0, 0, 0, 2289, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2290, 2291, 2292, 2293, 2294, 2295,
+            2296, 2297, 2298, 2299, 2300, 2301, 2302, 2303, 2304, 2305, 2306, 2307, 2308, 2309,
+            2310, 2311, 2312, 2313, 2314, 2315, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2316, 2317, 2318, 2319, 2320,
+            2321, 2322, 2323, 2324, 2325, 2326, 2327, 2328, 2329, 2330, 2331,
+        ],
+        [
+            2332, 2333, 2334, 2335, 2336, 2337, 2338, 2339, 2340, 2341, 2342, 2343, 2344, 2345,
+            2346, 2347, 2348, 2349, 2350, 2351, 2352, 2353, 2354, 2355, 2356, 2357, 2358, 2359,
+            2360, 2361, 2362, 2363, 0, 2364, 0, 0, 0, 2365, 2366, 0, 2367, 0, 2368, 0, 2369, 0, 0,
+            0, 0, 0, 0, 2370, 0, 0, 2371, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 2372, 0, 2373, 0, 2374, 0, 2375, 0, 2376, 0, 2377, 0, 2378, 0, 2379, 0, 2380, 0,
+            2381, 0, 2382, 0, 2383, 0, 2384, 0, 2385, 0, 2386, 0, 2387, 0, 2388, 0, 2389, 0, 2390,
+            0, 2391, 0, 2392, 0, 2393, 0, 2394, 0, 2395, 0, 2396, 0, 2397, 0, 2398, 0, 2399, 0,
+            2400, 0, 2401, 0, 2402, 0, 2403,
+        ],
+        [
+            0, 2404, 0, 2405, 0, 2406, 0, 2407, 0, 2408, 0, 2409, 0, 2410, 0, 2411, 0, 2412, 0,
+            2413, 0, 2414, 0, 2415, 0, 2416, 0, 2417, 0, 2418, 0, 2419, 0, 2420, 0, 2421, 0, 0, 0,
+            0, 0, 0, 0, 0, 2422, 0, 2423, 0, 0, 0, 0, 2424, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            2425, 2426, 2427, 2428, 2429, 2430, 2431, 2432, 2433, 2434, 2435, 2436, 2437, 2438,
+            2439, 2440, 2441, 2442, 2443, 2444, 2445, 2446, 2447, 2448, 2449, 2450, 2451, 2452,
+            2453, 2454, 2455, 2456, 2457, 2458, 2459, 2460, 2461, 2462, 0, 2463, 0, 0, 0, 0, 0,
+            2464, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 2465, 0, 2466, 0, 2467, 0, 2468, 0, 2469, 0, 2020, 0, 2470, 0, 2471, 0, 2472, 0,
+            2473, 0, 2474, 0, 2475, 0, 2476, 0, 2477, 0, 2478, 0, 2479, 0, 2480, 0, 2481, 0, 2482,
+            0, 2483, 0, 2484, 0, 2485, 0, 2486, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0,
+        ],
+        [
+            0, 2487, 0, 2488, 0, 2489, 0, 2490, 0, 2491, 0, 2492, 0, 2493, 0, 2494, 0, 2495, 0,
+            2496, 0, 2497, 0, 2498, 0, 2499, 0, 2500, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 2501, 0, 2502, 0, 2503, 0, 2504, 0, 2505, 0, 2506, 0, 2507, 0, 0, 0,
+            2508, 0, 2509, 0, 2510, 0, 2511, 0, 2512, 0, 2513, 0, 2514,
+        ],
+        [
+            0, 2515, 0, 2516, 0, 2517, 0, 2518, 0, 2519, 0, 2520, 0, 2521, 0, 2522, 0, 2523, 0,
+            2524, 0, 2525, 0, 2526, 0, 2527, 0, 2528, 0, 2529, 0, 2530, 0, 2531, 0, 2532, 0, 2533,
+            0, 2534, 0, 2535, 0, 2536, 0, 2537, 0, 2538, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2539, 0,
+            2540, 0, 0, 2541,
+        ],
+        [
+            0, 2542, 0, 2543, 0, 2544, 0, 2545, 0, 0, 0, 0, 2546, 0, 0, 0, 0, 2547, 0, 2548, 2549,
+            0, 0, 2550, 0, 2551, 0, 2552, 0, 2553, 0, 2554, 0, 2555, 0, 2556, 0, 2557, 0, 2558, 0,
+            2559, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2560, 0, 2561, 0, 2562, 0, 2563, 0, 2564, 0,
+            2565,
+        ],
+        [
+            0, 2566, 0, 2567, 0, 0, 0, 0, 2568, 0, 2569, 0, 0, 2570, 0, 2571, 0, 2572, 0, 2573, 0,
+            2574, 0, 2575, 0, 2576, 0, 2577, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 2578, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2579, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2580, 2581, 2582, 2583,
+            2584, 2585, 2586, 2587, 2588, 2589, 2590, 2591, 2592, 2593, 2594, 2595,
+        ],
+        [
+            2596, 2597, 2598, 2599, 2600, 2601, 2602, 2603, 2604, 2605, 2606, 2607, 2608, 2609,
+            2610, 2611, 2612, 2613, 2614, 2615, 2616, 2617, 2618, 2619, 2620, 2621, 2622, 2623,
+            2624, 2625, 2626, 2627, 2628, 2629, 2630, 2631, 2632, 2633, 2634, 2635, 2636, 2637,
+            2638, 2639, 2640, 2641, 2642, 2643, 2644, 2645, 2646, 2647, 2648, 2649, 2650, 2651,
+            2652, 2653, 2654, 2655, 2656, 2657, 2658, 2659,
+        ],
+        [
+            2660, 2661, 2662, 2663, 2664, 2665, 2665, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2666,
+            2667, 2668, 2669, 2670, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 2671, 2672, 2673, 2674, 2675, 2676, 2677, 2678, 2679, 2680, 2681, 2682, 2683, 2684,
+            2685, 2686, 2687, 2688, 2689, 2690, 2691, 2692, 2693, 2694, 2695, 2696, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2697, 2698, 2699, 2700, 2701, 2702, 2703, 2704, 2705,
+            2706, 2707, 2708, 2709, 2710, 2711, 2712, 2713, 2714, 2715, 2716, 2717, 2718, 2719,
+            2720,
+        ],
+        [
+            2721, 2722, 2723, 2724, 2725, 2726, 2727, 2728, 2729, 2730, 2731, 2732, 2733, 2734,
+            2735, 2736, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2737, 2738,
+            2739, 2740, 2741, 2742, 2743, 2744, 2745, 2746, 2747, 2748, 2749, 2750, 2751, 2752,
+            2753, 2754, 2755, 2756, 2757, 2758, 2759, 2760, 2761, 2762, 2763, 2764, 2765, 2766,
+            2767, 2768, 2769, 2770, 2771, 2772, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2773, 2774, 2775,
+            2776, 2777, 2778, 2779, 2780, 2781, 2782, 2783, 0, 2784, 2785, 2786, 2787, 2788, 2789,
+            2790, 2791, 2792, 2793, 2794, 2795, 2796, 2797, 2798, 0, 2799, 2800, 2801, 2802, 2803,
+            2804, 2805, 0, 2806, 2807, 0, 0, 0,
+        ],
+        [
+            2808, 2809, 2810, 2811, 2812, 2813, 2814, 2815, 2816, 2817, 2818, 2819, 2820, 2821,
+            2822, 2823, 2824, 2825, 2826, 2827, 2828, 2829, 2830, 2831, 2832, 2833, 2834, 2835,
+            2836, 2837, 2838, 2839, 2840, 2841, 2842, 2843, 2844, 2845, 2846, 2847, 2848, 2849,
+            2850, 2851, 2852, 2853, 2854, 2855, 2856, 2857, 2858, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2859, 2860, 2861, 2862, 2863,
+            2864, 2865, 2866, 2867, 2868, 2869, 2870, 2871, 2872, 2873, 2874,
+        ],
+        [
+            2875, 2876, 2877, 2878, 2879, 2880, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            2881, 2882, 2883, 2884, 2885, 2886, 2887, 2888, 2889, 2890, 2891, 2892, 2893, 2894,
+            2895, 2896, 2897, 2898, 2899, 2900, 2901, 2902, 2903, 2904, 2905, 2906, 2907, 2908,
+            2909, 2910, 2911, 2912, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 2913, 2914, 2915, 2916, 2917, 2918, 2919, 2920, 2921, 2922, 2923, 2924, 2925,
+            2926, 2927, 2928, 2929, 2930, 2931, 2932, 2933, 2934, 2935, 2936, 2937, 2938, 2939,
+            2940, 2941, 2942, 2943, 2944,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 2945, 2946, 2947, 2948, 2949,
+        ],
+        [
+            2950, 2951, 2952, 2953, 2954, 2955, 2956, 2957, 2958, 2959, 2960, 2961, 2962, 2963,
+            2964, 2965, 2966, 2967, 2968, 2969, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 2970, 2971, 2972, 2973, 2974, 2975, 2976, 2977, 2978, 2979, 2980, 2981,
+            2982, 2983, 2984, 2985, 2986, 2987, 2988, 2989, 2990, 2991, 2992, 2993, 2994, 2995,
+            2996, 2997, 2998, 2999,
+        ],
+        [
+            3000, 3001, 3002, 3003, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    ],
+};
+
+/// The full titlecase mapping of every codepoint
+pub(crate) static TO_TITLECASE: Utf8Trie<[u16; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0, 0, 11, 12, 13, 14, 15, 16, 17, 18,
+        19, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 3, 3, 3, 3, 3, 3, 4, 3, 3, 3, 3, 5, 10, 11,
+        11, 11, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0, 0, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 23, 24, 0, 25,
+            26, 27, 28, 29, 30, 31, 32,
+        ],
+        [
+            0, 0, 0, 0, 0, 33, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 37, 38, 39, 40, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 42, 0,
+            43, 44, 45, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 48, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 50, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 52, 0, 53, 0, 0, 54, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 56, 57, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59,
+            60, 61, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 62, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 3, 3, 3, 3, 3, 3, 4, 3, 3, 3, 3, 5, 6, 7, 3, 3, 3, 3, 8, 3, 3, 3, 3, 3, 3,
+            3, 9, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+    ],
+    leaves: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 1481, 1482, 1483, 1484, 1485, 1486, 1487, 1488, 1489, 1490, 1491, 1492,
+            1493, 1494, 1495, 1496, 1497, 1498, 1499, 1500, 1501, 1502, 1503, 1504, 1505, 1506, 0,
+            0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1507, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 3004, 1509, 1510, 1511, 1512, 1513, 1514, 1515, 1516, 1517, 1518, 1519, 1520,
+            1521, 1522, 1523, 1524, 1525, 1526, 1527, 1528, 1529, 1530, 1531, 0, 1532, 1533, 1534,
+            1535, 1536, 1537, 1538, 1539,
+        ],
+        [
+            0, 1540, 0, 1541, 0, 1542, 0, 1543, 0, 1544, 0, 1545, 0, 1546, 0, 1547, 0, 1548, 0,
+            1549, 0, 1550, 0, 1551, 0, 1552, 0, 1553, 0, 1554, 0, 1555, 0, 1556, 0, 1557, 0, 1558,
+            0, 1559, 0, 1560, 0, 1561, 0, 1562, 0, 1563, 0, 1489, 0, 1564, 0, 1565, 0, 1566, 0, 0,
+            1567, 0, 1568, 0, 1569, 0,
+        ],
+        [
+            1570, 0, 1571, 0, 1572, 0, 1573, 0, 1574, 1575, 0, 1576, 0, 1577, 0, 1578, 0, 1579, 0,
+            1580, 0, 1581, 0, 1582, 0, 1583, 0, 1584, 0, 1585, 0, 1586, 0, 1587, 0, 1588, 0, 1589,
+            0, 1590, 0, 1591, 0, 1592, 0, 1593, 0, 1594, 0, 1595, 0, 1596, 0, 1597, 0, 1598, 0, 0,
+            1599, 0, 1600, 0, 1601, 1499,
+        ],
+        [
+            1602, 0, 0, 1603, 0, 1604, 0, 0, 1605, 0, 0, 0, 1606, 0, 0, 0, 0, 0, 1607, 0, 0, 1608,
+            0, 0, 0, 1609, 1610, 1611, 0, 0, 1612, 0, 0, 1613, 0, 1614, 0, 1615, 0, 0, 1616, 0, 0,
+            0, 0, 1617, 0, 0, 1618, 0, 0, 0, 1619, 0, 1620, 0, 0, 1621, 0, 0, 0, 1622, 0, 1623,
+        ],
+        [
+            0, 0, 0, 0, 3005, 0, 3005, 3006, 0, 3006, 3007, 0, 3007, 0, 1627, 0, 1628, 0, 1629, 0,
+            1630, 0, 1631, 0, 1632, 0, 1633, 0, 1634, 1635, 0, 1636, 0, 1637, 0, 1638, 0, 1639, 0,
+            1640, 0, 1641, 0, 1642, 0, 1643, 0, 1644, 1645, 3008, 0, 3008, 0, 1647, 0, 0, 0, 1648,
+            0, 1649, 0, 1650, 0, 1651,
+        ],
+        [
+            0, 1652, 0, 1653, 0, 1654, 0, 1655, 0, 1656, 0, 1657, 0, 1658, 0, 1659, 0, 1660, 0,
+            1661, 0, 1662, 0, 1663, 0, 1664, 0, 1665, 0, 1666, 0, 1667, 0, 0, 0, 1668, 0, 1669, 0,
+            1670, 0, 1671, 0, 1672, 0, 1673, 0, 1674, 0, 1675, 0, 1676, 0, 0, 0, 0, 0, 0, 0, 0,
+            1677, 0, 0, 1678,
+        ],
+        [
+            1679, 0, 1680, 0, 0, 0, 0, 1681, 0, 1682, 0, 1683, 0, 1684, 0, 1685, 1686, 1687, 1688,
+            1689, 1690, 0, 1691, 1692, 0, 1693, 0, 1694, 1695, 0, 0, 0, 1696, 1697, 0, 1698, 1699,
+            1700, 1701, 0, 1702, 1703, 1704, 1705, 1706, 0, 0, 1707, 0, 1708, 1709, 0, 0, 1710, 0,
+            0, 0, 0, 0, 0, 0, 1711, 0, 0,
+        ],
+        [
+            1712, 0, 1713, 1714, 0, 0, 0, 1715, 1716, 1717, 1718, 1719, 1720, 0, 0, 0, 0, 0, 1721,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1722, 1723, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 1724, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1725, 0, 1726, 0, 0, 0,
+            1727, 0, 0, 0, 1728, 1729, 1730, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1731, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1732, 1733, 1734, 1735, 1736, 1737,
+            1738, 1739, 1740, 1741, 1742, 1743, 1744, 1724, 1745, 1746, 1507, 1747, 1748, 1749,
+        ],
+        [
+            1750, 1751, 1752, 1752, 1753, 1754, 1755, 1756, 1757, 1758, 1759, 1760, 1761, 1762,
+            1763, 0, 1738, 1744, 0, 0, 0, 1755, 1750, 1764, 0, 1765, 0, 1766, 0, 1767, 0, 1768, 0,
+            1769, 0, 1770, 0, 1771, 0, 1772, 0, 1773, 0, 1774, 0, 1775, 0, 1776, 1745, 1751, 1777,
+            1778, 0, 1741, 0, 0, 1779, 0, 0, 1780, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1781, 1782, 1783, 1784, 1785,
+            1786, 1787, 1788, 1789, 1790, 1791, 1792, 1793, 1794, 1795, 1796,
+        ],
+        [
+            1797, 1798, 1799, 1800, 1801, 1802, 1803, 1804, 1805, 1806, 1807, 1808, 1809, 1810,
+            1811, 1812, 1813, 1814, 1815, 1816, 1817, 1818, 1819, 1820, 1821, 1822, 1823, 1824,
+            1825, 1826, 1827, 1828, 0, 1829, 0, 1830, 0, 1831, 0, 1832, 0, 1833, 0, 1834, 0, 1835,
+            0, 1836, 0, 1837, 0, 1838, 0, 1839, 0, 1840, 0, 1841, 0, 1842, 0, 1843, 0, 1844,
+        ],
+        [
+            0, 1845, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1846, 0, 1847, 0, 1848, 0, 1849, 0, 1850, 0, 1851,
+            0, 1852, 0, 1853, 0, 1854, 0, 1855, 0, 1856, 0, 1857, 0, 1858, 0, 1859, 0, 1860, 0,
+            1861, 0, 1862, 0, 1863, 0, 1864, 0, 1865, 0, 1866, 0, 1867, 0, 1868, 0, 1869, 0, 1870,
+            0, 1871, 0, 1872,
+        ],
+        [
+            0, 0, 1873, 0, 1874, 0, 1875, 0, 1876, 0, 1877, 0, 1878, 0, 1879, 1880, 0, 1881, 0,
+            1882, 0, 1883, 0, 1884, 0, 1885, 0, 1886, 0, 1887, 0, 1888, 0, 1889, 0, 1890, 0, 1891,
+            0, 1892, 0, 1893, 0, 1894, 0, 1895, 0, 1896, 0, 1897, 0, 1898, 0, 1899, 0, 1900, 0,
+            1901, 0, 1902, 0, 1903, 0, 1904,
+        ],
+        [
+            0, 1905, 0, 1906, 0, 1907, 0, 1908, 0, 1909, 0, 1910, 0, 1911, 0, 1912, 0, 1913, 0,
+            1914, 0, 1915, 0, 1916, 0, 1917, 0, 1918, 0, 1919, 0, 1920, 0, 1921, 0, 1922, 0, 1923,
+            0, 1924, 0, 1925, 0, 1926, 0, 1927, 0, 1928, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 1929, 1930, 1931, 1932, 1933, 1934, 1935, 1936, 1937, 1938, 1939, 1940,
+            1941, 1942, 1943, 1944, 1945, 1946, 1947, 1948, 1949, 1950, 1951, 1952, 1953, 1954,
+            1955, 1956, 1957, 1958, 1959,
+        ],
+        [
+            1960, 1961, 1962, 1963, 1964, 1965, 1966, 3009, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2014,
+            2015, 2016, 2017, 2018, 2019, 0, 0,
+        ],
+        [
+            1783, 1785, 1795, 1798, 1799, 1799, 1807, 1830, 2020, 0, 2021, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            2022, 0, 0, 0, 2023, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2024, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 2025, 0, 2026, 0, 2027, 0, 2028, 0, 2029, 0, 2030, 0, 2031, 0, 2032, 0, 2033, 0,
+            2034, 0, 2035, 0, 2036, 0, 2037, 0, 2038, 0, 2039, 0, 2040, 0, 2041, 0, 2042, 0, 2043,
+            0, 2044, 0, 2045, 0, 2046, 0, 2047, 0, 2048, 0, 2049, 0, 2050, 0, 2051, 0, 2052, 0,
+            2053, 0, 2054, 0, 2055, 0, 2056,
+        ],
+        [
+            0, 2057, 0, 2058, 0, 2059, 0, 2060, 0, 2061, 0, 2062, 0, 2063, 0, 2064, 0, 2065, 0,
+            2066, 0, 2067, 0, 2068, 0, 2069, 0, 2070, 0, 2071, 0, 2072, 0, 2073, 0, 2074, 0, 2075,
+            0, 2076, 0, 2077, 0, 2078, 0, 2079, 0, 2080, 0, 2081, 0, 2082, 0, 2083, 0, 2084, 0,
+            2085, 0, 2086, 0, 2087, 0, 2088,
+        ],
+        [
+            0, 2089, 0, 2090, 0, 2091, 0, 2092, 0, 2093, 0, 2094, 0, 2095, 0, 2096, 0, 2097, 0,
+            2098, 0, 2099, 2100, 2101, 2102, 2103, 2104, 2073, 0, 0, 0, 0, 0, 2105, 0, 2106, 0,
+            2107, 0, 2108, 0, 2109, 0, 2110, 0, 2111, 0, 2112, 0, 2113, 0, 2114, 0, 2115, 0, 2116,
+            0, 2117, 0, 2118, 0, 2119, 0, 2120,
+        ],
+        [
+            0, 2121, 0, 2122, 0, 2123, 0, 2124, 0, 2125, 0, 2126, 0, 2127, 0, 2128, 0, 2129, 0,
+            2130, 0, 2131, 0, 2132, 0, 2133, 0, 2134, 0, 2135, 0, 2136, 0, 2137, 0, 2138, 0, 2139,
+            0, 2140, 0, 2141, 0, 2142, 0, 2143, 0, 2144, 0, 2145, 0, 2146, 0, 2147, 0, 2148, 0,
+            2149, 0, 2150, 0, 2151, 0, 2152,
+        ],
+        [
+            2153, 2154, 2155, 2156, 2157, 2158, 2159, 2160, 0, 0, 0, 0, 0, 0, 0, 0, 2161, 2162,
+            2163, 2164, 2165, 2166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2167, 2168, 2169, 2170, 2171,
+            2172, 2173, 2174, 0, 0, 0, 0, 0, 0, 0, 0, 2175, 2176, 2177, 2178, 2179, 2180, 2181,
+            2182, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            2183, 2184, 2185, 2186, 2187, 2188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2189, 2190, 2191,
+            2192, 2193, 2194, 2195, 2196, 0, 0, 0, 0, 0, 0, 0, 0, 2197, 2198, 2199, 2200, 2201,
+            2202, 2203, 2204, 0, 0, 0, 0, 0, 0, 0, 0, 2205, 2206, 2207, 2208, 2209, 2210, 2211,
+            2212, 2213, 2214, 2215, 2216, 2217, 2218, 0, 0,
+        ],
+        [
+            3010, 3011, 3012, 3013, 3014, 3015, 3016, 3017, 0, 0, 0, 0, 0, 0, 0, 0, 3018, 3019,
+            3020, 3021, 3022, 3023, 3024, 3025, 0, 0, 0, 0, 0, 0, 0, 0, 3026, 3027, 3028, 3029,
+            3030, 3031, 3032, 3033, 0, 0, 0, 0, 0, 0, 0, 0, 2243, 2244, 3034, 3035, 3036, 0, 2248,
+            3037, 0, 0, 0, 0, 0, 0, 1724, 0,
+        ],
+        [
+            0, 0, 3038, 3039, 3040, 0, 2253, 3041, 0, 0, 0, 0, 0, 0, 0, 0, 2255, 2256, 2257, 1731,
+            0, 0, 2258, 2259, 0, 0, 0, 0, 0, 0, 0, 0, 2260, 2261, 2262, 1736, 2263, 2264, 2265,
+            2266, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3042, 3043, 3044, 0, 2270, 3045, 0, 0, 0, 0, 0, 0,
+            0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2272, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2273, 2274, 2275, 2276,
+            2277, 2278, 2279, 2280, 2281, 2282, 2283, 2284, 2285, 2286, 2287, 2288,
+        ],
+        [
+            0, 0, 0, 0, 2289, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2290, 2291, 2292, 2293, 2294, 2295,
+            2296, 2297, 2298, 2299, 2300, 2301, 2302, 2303, 2304, 2305, 2306, 2307, 2308, 2309,
+            2310, 2311, 2312, 2313, 2314, 2315, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2316, 2317, 2318, 2319, 2320,
+            2321, 2322, 2323, 2324, 2325, 2326, 2327, 2328, 2329, 2330, 2331,
+        ],
+        [
+            2332, 2333, 2334, 2335, 2336, 2337, 2338, 2339, 2340, 2341, 2342, 2343, 2344, 2345,
+            2346, 2347, 2348, 2349, 2350, 2351, 2352, 2353, 2354, 2355, 2356, 2357, 2358, 2359,
+            2360, 2361, 2362, 2363, 0, 2364, 0, 0, 0, 2365, 2366, 0, 2367, 0, 2368, 0, 2369, 0, 0,
+            0, 0, 0, 0, 2370, 0, 0, 2371, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 2372, 0, 2373, 0, 2374, 0, 2375, 0, 2376, 0, 2377, 0, 2378, 0, 2379, 0, 2380, 0,
+            2381, 0, 2382, 0, 2383, 0, 2384, 0, 2385, 0, 2386, 0, 2387, 0, 2388, 0, 2389, 0, 2390,
+            0, 2391, 0, 2392, 0, 2393, 0, 2394, 0, 2395, 0, 2396, 0, 2397, 0, 2398, 0, 2399, 0,
+            2400, 0, 2401, 0, 2402, 0, 2403,
+        ],
+        [
+            0, 2404, 0, 2405, 0, 2406, 0, 2407, 0, 2408, 0, 2409, 0, 2410, 0, 2411, 0, 2412, 0,
+            2413, 0, 2414, 0, 2415, 0, 2416, 0, 2417, 0, 2418, 0, 2419, 0, 2420, 0, 2421, 0, 0, 0,
+            0, 0, 0, 0, 0, 2422, 0, 2423, 0, 0, 0, 0, 2424, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            2425, 2426, 2427, 2428, 2429, 2430, 2431, 2432, 2433, 2434, 2435, 2436, 2437, 2438,
+            2439, 2440, 2441, 2442, 2443, 2444, 2445, 2446, 2447, 2448, 2449, 2450, 2451, 2452,
+            2453, 2454, 2455, 2456, 2457, 2458, 2459, 2460, 2461, 2462, 0, 2463, 0, 0, 0, 0, 0,
+            2464, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 2465, 0, 2466, 0, 2467, 0, 2468, 0, 2469, 0, 2020, 0, 2470, 0, 2471, 0, 2472, 0,
+            2473, 0, 2474, 0, 2475, 0, 2476, 0, 2477, 0, 2478, 0, 2479, 0, 2480, 0, 2481, 0, 2482,
+            0, 2483, 0, 2484, 0, 2485, 0, 2486, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0,
+        ],
+        [
+            0, 2487, 0, 2488, 0, 2489, 0, 2490, 0, 2491, 0, 2492, 0, 2493, 0, 2494, 0, 2495, 0,
+            2496, 0, 2497, 0, 2498, 0, 2499, 0, 2500, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 2501, 0, 2502, 0, 2503, 0, 2504, 0, 2505, 0, 2506, 0, 2507, 0, 0, 0,
+            2508, 0, 2509, 0, 2510, 0, 2511, 0, 2512, 0, 2513, 0, 2514,
+        ],
+        [
+            0, 2515, 0, 2516, 0, 2517, 0, 2518, 0, 2519, 0, 2520, 0, 2521, 0, 2522, 0, 2523, 0,
+            2524, 0, 2525, 0, 2526, 0, 2527, 0, 2528, 0, 2529, 0, 2530, 0, 2531, 0, 2532, 0, 2533,
+            0, 2534, 0, 2535, 0, 2536, 0, 2537, 0, 2538, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2539, 0,
+            2540, 0, 0, 2541,
+        ],
+        [
+            0, 2542, 0, 2543, 0, 2544, 0, 2545, 0, 0, 0, 0, 2546, 0, 0, 0, 0, 2547, 0, 2548, 2549,
+            0, 0, 2550, 0, 2551, 0, 2552, 0, 2553, 0, 2554, 0, 2555, 0, 2556, 0, 2557, 0, 2558, 0,
+            2559, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2560, 0, 2561, 0, 2562, 0, 2563, 0, 2564, 0,
+            2565,
+        ],
+        [
+            0, 2566, 0, 2567, 0, 0, 0, 0, 2568, 0, 2569, 0, 0, 2570, 0, 2571, 0, 2572, 0, 2573, 0,
+            2574, 0, 2575, 0, 2576, 0, 2577, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 2578, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2579, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2580, 2581, 2582, 2583,
+            2584, 2585, 2586, 2587, 2588, 2589, 2590, 2591, 2592, 2593, 2594, 2595,
+        ],
+        [
+            2596, 2597, 2598, 2599, 2600, 2601, 2602, 2603, 2604, 2605, 2606, 2607, 2608, 2609,
+            2610, 2611, 2612, 2613, 2614, 2615, 2616, 2617, 2618, 2619, 2620, 2621, 2622, 2623,
+            2624, 2625, 2626, 2627, 2628, 2629, 2630, 2631, 2632, 2633, 2634, 2635, 2636, 2637,
+            2638, 2639, 2640, 2641, 2642, 2643, 2644, 2645, 2646, 2647, 2648, 2649, 2650, 2651,
+            2652, 2653, 2654, 2655, 2656, 2657, 2658, 2659,
+        ],
+        [
+            3046, 3047, 3048, 3049, 3050, 3051, 3051, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3052,
+            3053, 3054, 3055, 3056, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 2671, 2672, 2673, 2674, 2675, 2676, 2677, 2678, 2679, 2680, 2681, 2682, 2683, 2684,
+            2685, 2686, 2687, 2688, 2689, 2690, 2691, 2692, 2693, 2694, 2695, 2696, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2697, 2698, 2699, 2700, 2701, 2702, 2703, 2704, 2705,
+            2706, 2707, 2708, 2709, 2710, 2711, 2712, 2713, 2714, 2715, 2716, 2717, 2718, 2719,
+            2720,
+        ],
+        [
+            2721, 2722, 2723, 2724, 2725, 2726, 2727, 2728, 2729, 2730, 2731, 2732, 2733, 2734,
+            2735, 2736, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2737, 2738,
+            2739, 2740, 2741, 2742, 2743, 2744, 2745, 2746, 2747, 2748, 2749, 2750, 2751, 2752,
+            2753, 2754, 2755, 2756, 2757, 2758, 2759, 2760, 2761, 2762, 2763, 2764, 2765, 2766,
+            2767, 2768, 2769, 2770, 2771, 2772, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2773, 2774, 2775,
+            2776, 2777, 2778, 2779, 2780, 2781, 2782, 2783, 0, 2784, 2785, 2786, 2787, 2788, 2789,
+            2790, 2791, 2792, 2793, 2794, 2795, 2796, 2797, 2798, 0, 2799, 2800, 2801, 2802, 2803,
+            2804, 2805, 0, 2806, 2807, 0, 0, 0,
+        ],
+        [
+            2808, 2809, 2810, 2811, 2812, 2813, 2814, 2815, 2816, 2817, 2818, 2819, 2820, 2821,
+            2822, 2823, 2824, 2825, 2826, 2827, 2828, 2829, 2830, 2831, 2832, 2833, 2834, 2835,
+            2836, 2837, 2838, 2839, 2840, 2841, 2842, 2843, 2844, 2845, 2846, 2847, 2848, 2849,
+            2850, 2851, 2852, 2853, 2854, 2855, 2856, 2857, 2858, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2859, 2860, 2861, 2862, 2863,
+            2864, 2865, 2866, 2867, 2868, 2869, 2870, 2871, 2872, 2873, 2874,
+        ],
+        [
+            2875, 2876, 2877, 2878, 2879, 2880, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            2881, 2882, 2883, 2884, 2885, 2886, 2887, 2888, 2889, 2890, 2891, 2892, 2893, 2894,
+            2895, 2896, 2897, 2898, 2899, 2900, 2901, 2902, 2903, 2904, 2905, 2906, 2907, 2908,
+            2909, 2910, 2911, 2912, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 2913, 2914, 2915, 2916, 2917, 2918, 2919, 2920, 2921, 2922, 2923, 2924, 2925,
+            2926, 2927, 2928, 2929, 2930, 2931, 2932, 2933, 2934, 2935, 2936, 2937, 2938, 2939,
+            2940, 2941, 2942, 2943, 2944,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 2945, 2946, 2947, 2948, 2949,
+        ],
+        [
+            2950, 2951, 2952, 2953, 2954, 2955, 2956, 2957, 2958, 2959, 2960, 2961, 2962, 2963,
+            2964, 2965, 2966, 2967, 2968, 2969, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 2970, 2971, 2972, 2973, 2974, 2975, 2976, 2977, 2978, 2979, 2980, 2981,
+            2982, 2983, 2984, 2985, 2986, 2987, 2988, 2989, 2990, 2991, 2992, 2993, 2994, 2995,
+            2996, 2997, 2998, 2999,
+        ],
+        [
+            3000, 3001, 3002, 3003, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    ],
+};
+
+/// The `Utf8Char::to_bits` of the codepoints others fold to, indexed by `CASE_FOLD`. Index 0 is
+/// unused, it marks codepoints that fold to themselves
+pub(crate) static FOLDS: [u32; 1513] = [
+    0x0000_0000, 0x6180_8080, 0x6280_8080, 0x6380_8080, 0x6480_8080, 0x6580_8080, 0x6680_8080,
+    0x6780_8080, 0x6880_8080, 0x6980_8080, 0x6A80_8080, 0x6B80_8080, 0x6C80_8080, 0x6D80_8080,
+    0x6E80_8080, 0x6F80_8080, 0x7080_8080, 0x7180_8080, 0x7280_8080, 0x7380_8080, 0x7480_8080,
+    0x7580_8080, 0x7680_8080, 0x7780_8080, 0x7880_8080, 0x7980_8080, 0x7A80_8080, 0xCEBC_8080,
+    0xC3A0_8080, 0xC3A1_8080, 0xC3A2_8080, 0xC3A3_8080, 0xC3A4_8080, 0xC3A5_8080, 0xC3A6_8080,
+    0xC3A7_8080, 0xC3A8_8080, 0xC3A9_8080, 0xC3AA_8080, 0xC3AB_8080, 0xC3AC_8080, 0xC3AD_8080,
+    0xC3AE_8080, 0xC3AF_8080, 0xC3B0_8080, 0xC3B1_8080, 0xC3B2_8080, 0xC3B3_8080, 0xC3B4_8080,
+    0xC3B5_8080, 0xC3B6_8080, 0xC3B8_8080, 0xC3B9_8080, 0xC3BA_8080, 0xC3BB_8080, 0xC3BC_8080,
+    0xC3BD_8080, 0xC3BE_8080, 0xC481_8080, 0xC483_8080, 0xC485_8080, 0xC487_8080, 0xC489_8080,
+    0xC48B_8080, 0xC48D_8080, 0xC48F_8080, 0xC491_8080, 0xC493_8080, 0xC495_8080, 0xC497_8080,
+    0xC499_8080, 0xC49B_8080, 0xC49D_8080, 0xC49F_8080, 0xC4A1_8080, 0xC4A3_8080, 0xC4A5_8080,
+    0xC4A7_8080, 0xC4A9_8080, 0xC4AB_8080, 0xC4AD_8080, 0xC4AF_8080, 0xC4B3_8080, 0xC4B5_8080,
+    0xC4B7_8080, 0xC4BA_8080, 0xC4BC_8080, 0xC4BE_8080, 0xC580_8080, 0xC582_8080, 0xC584_8080,
+    0xC586_8080, 0xC588_8080, 0xC58B_8080, 0xC58D_8080, 0xC58F_8080, 0xC591_8080, 0xC593_8080,
+    0xC595_8080, 0xC597_8080, 0xC599_8080, 0xC59B_8080, 0xC59D_8080, 0xC59F_8080, 0xC5A1_8080,
+    0xC5A3_8080, 0xC5A5_8080, 0xC5A7_8080, 0xC5A9_8080, 0xC5AB_8080, 0xC5AD_8080, 0xC5AF_8080,
+    0xC5B1_8080, 0xC5B3_8080, 0xC5B5_8080, 0xC5B7_8080, 0xC3BF_8080, 0xC5BA_8080, 0xC5BC_8080,
+    0xC5BE_8080, 0x7380_8080, 0xC993_8080, 0xC683_8080, 0xC685_8080, 0xC994_8080, 0xC688_8080,
+    0xC996_8080, 0xC997_8080, 0xC68C_8080, 0xC79D_8080, 0xC999_8080, 0xC99B_8080, 0xC692_8080,
+    0xC9A0_8080, 0xC9A3_8080, 0xC9A9_8080, 0xC9A8_8080, 0xC699_8080, 0xC9AF_8080, 0xC9B2_8080,
+    0xC9B5_8080, 0xC6A1_8080, 0xC6A3_8080, 0xC6A5_8080, 0xCA80_8080, 0xC6A8_8080, 0xCA83_8080,
+    0xC6AD_8080, 0xCA88_8080, 0xC6B0_8080, 0xCA8A_8080, 0xCA8B_8080, 0xC6B4_8080, 0xC6B6_8080,
+    0xCA92_8080, 0xC6B9_8080, 0xC6BD_8080, 0xC786_8080, 0xC786_8080, 0xC789_8080, 0xC789_8080,
+    0xC78C_8080, 0xC78C_8080, 0xC78E_8080, 0xC790_8080, 0xC792_8080, 0xC794_8080, 0xC796_8080,
+    0xC798_8080, 0xC79A_8080, 0xC79C_8080, 0xC79F_8080, 0xC7A1_8080, 0xC7A3_8080, 0xC7A5_8080,
+    0xC7A7_8080, 0xC7A9_8080, 0xC7AB_8080, 0xC7AD_8080, 0xC7AF_8080, 0xC7B3_8080, 0xC7B3_8080,
+    0xC7B5_8080, 0xC695_8080, 0xC6BF_8080, 0xC7B9_8080, 0xC7BB_8080, 0xC7BD_8080, 0xC7BF_8080,
+    0xC881_8080, 0xC883_8080, 0xC885_8080, 0xC887_8080, 0xC889_8080, 0xC88B_8080, 0xC88D_8080,
+    0xC88F_8080, 0xC891_8080, 0xC893_8080, 0xC895_8080, 0xC897_8080, 0xC899_8080, 0xC89B_8080,
+    0xC89D_8080, 0xC89F_8080, 0xC69E_8080, 0xC8A3_8080, 0xC8A5_8080, 0xC8A7_8080, 0xC8A9_8080,
+    0xC8AB_8080, 0xC8AD_8080, 0xC8AF_8080, 0xC8B1_8080, 0xC8B3_8080, 0xE2B1_A580, 0xC8BC_8080,
+    0xC69A_8080, 0xE2B1_A680, 0xC982_8080, 0xC680_8080, 0xCA89_8080, 0xCA8C_8080, 0xC987_8080,
+    0xC989_8080, 0xC98B_8080, 0xC98D_8080, 0xC98F_8080, 0xCEB9_8080, 0xCDB1_8080, 0xCDB3_8080,
+    0xCDB7_8080, 0xCFB3_8080, 0xCEAC_8080, 0xCEAD_8080, 0xCEAE_8080, 0xCEAF_8080, 0xCF8C_8080,
+    0xCF8D_8080, 0xCF8E_8080, 0xCEB1_8080, 0xCEB2_8080, 0xCEB3_8080, 0xCEB4_8080, 0xCEB5_8080,
+    0xCEB6_8080, 0xCEB7_8080, 0xCEB8_8080, 0xCEB9_8080, 0xCEBA_8080, 0xCEBB_8080, 0xCEBC_8080,
+    0xCEBD_8080, 0xCEBE_8080, 0xCEBF_8080, 0xCF80_8080, 0xCF81_8080, 0xCF83_8080, 0xCF84_8080,
+    0xCF85_8080, 0xCF86_8080, 0xCF87_8080, 0xCF88_8080, 0xCF89_8080, 0xCF8A_8080, 0xCF8B_8080,
+    0xCF83_8080, 0xCF97_8080, 0xCEB2_8080, 0xCEB8_8080, 0xCF86_8080, 0xCF80_8080, 0xCF99_8080,
+    0xCF9B_8080, 0xCF9D_8080, 0xCF9F_8080, 0xCFA1_8080, 0xCFA3_8080, 0xCFA5_8080, 0xCFA7_8080,
+    0xCFA9_8080, 0xCFAB_8080, 0xCFAD_8080, 0xCFAF_8080, 0xCEBA_8080, 0xCF81_8080, 0xCEB8_8080,
+    0xCEB5_8080, 0xCFB8_8080, 0xCFB2_8080, 0xCFBB_8080, 0xCDBB_8080, 0xCDBC_8080, 0xCDBD_8080,
+    0xD190_8080, 0xD191_8080, 0xD192_8080, 0xD193_8080, 0xD194_8080, 0xD195_8080, 0xD196_8080,
+    0xD197_8080, 0xD198_8080, 0xD199_8080, 0xD19A_8080, 0xD19B_8080, 0xD19C_8080, 0xD19D_8080,
+    0xD19E_8080, 0xD19F_8080, 0xD0B0_8080, 0xD0B1_8080, 0xD0B2_8080, 0xD0B3_8080, 0xD0B4_8080,
+    0xD0B5_8080, 0xD0B6_8080, 0xD0B7_8080, 0xD0B8_8080, 0xD0B9_8080, 0xD0BA_8080, 0xD0BB_8080,
+    0xD0BC_8080, 0xD0BD_8080, 0xD0BE_8080, 0xD0BF_8080, 0xD180_8080, 0xD181_8080, 0xD182_8080,
+    0xD183_8080, 0xD184_8080, 0xD185_8080, 0xD186_8080, 0xD187_8080, 0xD188_8080, 0xD189_8080,
+    0xD18A_8080, 0xD18B_8080, 0xD18C_8080, 0xD18D_8080, 0xD18E_8080, 0xD18F_8080, 0xD1A1_8080,
+    0xD1A3_8080, 0xD1A5_8080, 0xD1A7_8080, 0xD1A9_8080, 0xD1AB_8080, 0xD1AD_8080, 0xD1AF_8080,
+    0xD1B1_8080, 0xD1B3_8080, 0xD1B5_8080, 0xD1B7_8080, 0xD1B9_8080, 0xD1BB_8080, 0xD1BD_8080,
+    0xD1BF_8080, 0xD281_8080, 0xD28B_8080, 0xD28D_8080, 0xD28F_8080, 0xD291_8080, 0xD293_8080,
+    0xD295_8080, 0xD297_8080, 0xD299_8080, 0xD29B_8080, 0xD29D_8080, 0xD29F_8080, 0xD2A1_8080,
+    0xD2A3_8080, 0xD2A5_8080, 0xD2A7_8080, 0xD2A9_8080, 0xD2AB_8080, 0xD2AD_8080, 0xD2AF_8080,
+    0xD2B1_8080, 0xD2B3_8080, 0xD2B5_8080, 0xD2B7_8080, 0xD2B9_8080, 0xD2BB_8080, 0xD2BD_8080,
+    0xD2BF_8080, 0xD38F_8080, 0xD382_8080, 0xD384_8080, 0xD386_8080, 0xD388_8080, 0xD38A_8080,
+    0xD38C_8080, 0xD38E_8080, 0xD391_8080, 0xD393_8080, 0xD395_8080, 0xD397_8080, 0xD399_8080,
+    0xD39B_8080, 0xD39D_8080, 0xD39F_8080, 0xD3A1_8080, 0xD3A3_8080, 0xD3A5_8080, 0xD3A7_8080,
+    0xD3A9_8080, 0xD3AB_8080, 0xD3AD_8080, 0xD3AF_8080, 0xD3B1_8080, 0xD3B3_8080, 0xD3B5_8080,
+    0xD3B7_8080, 0xD3B9_8080, 0xD3BB_8080, 0xD3BD_8080, 0xD3BF_8080, 0xD481_8080, 0xD483_8080,
+    0xD485_8080, 0xD487_8080, 0xD489_8080, 0xD48B_8080, 0xD48D_8080, 0xD48F_8080, 0xD491_8080,
+    0xD493_8080, 0xD495_8080, 0xD497_8080, 0xD499_8080, 0xD49B_8080, 0xD49D_8080, 0xD49F_8080,
+    0xD4A1_8080, 0xD4A3_8080, 0xD4A5_8080, 0xD4A7_8080, 0xD4A9_8080, 0xD4AB_8080, 0xD4AD_8080,
+    0xD4AF_8080, 0xD5A1_8080, 0xD5A2_8080, 0xD5A3_8080, 0xD5A4_8080, 0xD5A5_8080, 0xD5A6_8080,
+    0xD5A7_8080, 0xD5A8_8080, 0xD5A9_8080, 0xD5AA_8080, 0xD5AB_8080, 0xD5AC_8080, 0xD5AD_8080,
+    0xD5AE_8080, 0xD5AF_8080, 0xD5B0_8080, 0xD5B1_8080, 0xD5B2_8080, 0xD5B3_8080, 0xD5B4_8080,
+    0xD5B5_8080, 0xD5B6_8080, 0xD5B7_8080, 0xD5B8_8080, 0xD5B9_8080, 0xD5BA_8080, 0xD5BB_8080,
+    0xD5BC_8080, 0xD5BD_8080, 0xD5BE_8080, 0xD5BF_8080, 0xD680_8080, 0xD681_8080, 0xD682_8080,
+    0xD683_8080, 0xD684_8080, 0xD685_8080, 0xD686_8080, 0xE2B4_8080, 0xE2B4_8180, 0xE2B4_8280,
+    0xE2B4_8380, 0xE2B4_8480, 0xE2B4_8580, 0xE2B4_8680, 0xE2B4_8780, 0xE2B4_8880, 0xE2B4_8980,
+    0xE2B4_8A80, 0xE2B4_8B80, 0xE2B4_8C80, 0xE2B4_8D80, 0xE2B4_8E80, 0xE2B4_8F80, 0xE2B4_9080,
+    0xE2B4_9180, 0xE2B4_9280, 0xE2B4_9380, 0xE2B4_9480, 0xE2B4_9580, 0xE2B4_9680, 0xE2B4_9780,
+    0xE2B4_9880, 0xE2B4_9980, 0xE2B4_9A80, 0xE2B4_9B80, 0xE2B4_9C80, 0xE2B4_9D80, 0xE2B4_9E80,
+    0xE2B4_9F80, 0xE2B4_A080, 0xE2B4_A180, 0xE2B4_A280, 0xE2B4_A380, 0xE2B4_A480, 0xE2B4_A580,
+    0xE2B4_A780, 0xE2B4_AD80, 0xE18F_B080, 0xE18F_B180, 0xE18F_B280, 0xE18F_B380, 0xE18F_B480,
+    0xE18F_B580, 0xD0B2_8080, 0xD0B4_8080, 0xD0BE_8080, 0xD181_8080, 0xD182_8080, 0xD182_8080,
+    0xD18A_8080, 0xD1A3_8080, 0xEA99_8B80, 0xE1B2_8A80, 0xE183_9080, 0xE183_9180, 0xE183_9280,
+    0xE183_9380, 0xE183_9480, 0xE183_9580, 0xE183_9680, 0xE183_9780, 0xE183_9880, 0xE183_9980,
+    0xE183_9A80, 0xE183_9B80, 0xE183_9C80, 0xE183_9D80, 0xE183_9E80, 0xE183_9F80, 0xE183_A080,
+    0xE183_A180, 0xE183_A280, 0xE183_A380, 0xE183_A480, 0xE183_A580, 0xE183_A680, 0xE183_A780,
+    0xE183_A880, 0xE183_A980, 0xE183_AA80, 0xE183_AB80, 0xE183_AC80, 0xE183_AD80, 0xE183_AE80,
+    0xE183_AF80, 0xE183_B080, 0xE183_B180, 0xE183_B280, 0xE183_B380, 0xE183_B480, 0xE183_B580,
+    0xE183_B680, 0xE183_B780, 0xE183_B880, 0xE183_B980, 0xE183_BA80, 0xE183_BD80, 0xE183_BE80,
+    0xE183_BF80, 0xE1B8_8180, 0xE1B8_8380, 0xE1B8_8580, 0xE1B8_8780, 0xE1B8_8980, 0xE1B8_8B80,
+    0xE1B8_8D80, 0xE1B8_8F80, 0xE1B8_9180, 0xE1B8_9380, 0xE1B8_9580, 0xE1B8_9780, 0xE1B8_9980,
+    0xE1B8_9B80, 0xE1B8_9D80, 0xE1B8_9F80, 0xE1B8_A180, 0xE1B8_A380, 0xE1B8_A580, 0xE1B8_A780,
+    0xE1B8_A980, 0xE1B8_AB80, 0xE1B8_AD80, 0xE1B8_AF80, 0xE1B8_B180, 0xE1B8_B380, 0xE1B8_B580,
+    0xE1B8_B780, 0xE1B8_B980, 0xE1B8_BB80, 0xE1B8_BD80, 0xE1B8_BF80, 0xE1B9_8180, 0xE1B9_8380,
+    0xE1B9_8580, 0xE1B9_8780, 0xE1B9_8980, 0xE1B9_8B80, 0xE1B9_8D80, 0xE1B9_8F80, 0xE1B9_9180,
+    0xE1B9_9380, 0xE1B9_9580, 0xE1B9_9780, 0xE1B9_9980, 0xE1B9_9B80, 0xE1B9_9D80, 0xE1B9_9F80,
+    0xE1B9_A180, 0xE1B9_A380, 0xE1B9_A580, 0xE1B9_A780, 0xE1B9_A980, 0xE1B9_AB80, 0xE1B9_AD80,
+    0xE1B9_AF80, 0xE1B9_B180, 0xE1B9_B380, 0xE1B9_B580, 0xE1B9_B780, 0xE1B9_B980, 0xE1B9_BB80,
+    0xE1B9_BD80, 0xE1B9_BF80, 0xE1BA_8180, 0xE1BA_8380, 0xE1BA_8580, 0xE1BA_8780, 0xE1BA_8980,
+    0xE1BA_8B80, 0xE1BA_8D80, 0xE1BA_8F80, 0xE1BA_9180, 0xE1BA_9380, 0xE1BA_9580, 0xE1B9_A180,
+    0xC39F_8080, 0xE1BA_A180, 0xE1BA_A380, 0xE1BA_A580, 0xE1BA_A780, 0xE1BA_A980, 0xE1BA_AB80,
+    0xE1BA_AD80, 0xE1BA_AF80, 0xE1BA_B180, 0xE1BA_B380, 0xE1BA_B580, 0xE1BA_B780, 0xE1BA_B980,
+    0xE1BA_BB80, 0xE1BA_BD80, 0xE1BA_BF80, 0xE1BB_8180, 0xE1BB_8380, 0xE1BB_8580, 0xE1BB_8780,
+    0xE1BB_8980, 0xE1BB_8B80, 0xE1BB_8D80, 0xE1BB_8F80, 0xE1BB_9180, 0xE1BB_9380, 0xE1BB_9580,
+    0xE1BB_9780, 0xE1BB_9980, 0xE1BB_9B80, 0xE1BB_9D80, 0xE1BB_9F80, 0xE1BB_A180, 0xE1BB_A380,
+    0xE1BB_A580, 0xE1BB_A780, 0xE1BB_A980, 0xE1BB_AB80, 0xE1BB_AD80, 0xE1BB_AF80, 0xE1BB_B180,
+    0xE1BB_B380, 0xE1BB_B580, 0xE1BB_B780, 0xE1BB_B980, 0xE1BB_BB80, 0xE1BB_BD80, 0xE1BB_BF80,
+    0xE1BC_8080, 0xE1BC_8180, 0xE1BC_8280, 0xE1BC_8380, 0xE1BC_8480, 0xE1BC_8580, 0xE1BC_8680,
+    0xE1BC_8780, 0xE1BC_9080, 0xE1BC_9180, 0xE1BC_9280, 0xE1BC_9380, 0xE1BC_9480, 0xE1BC_9580,
+    0xE1BC_A080, 0xE1BC_A180, 0xE1BC_A280, 0xE1BC_A380, 0xE1BC_A480, 0xE1BC_A580, 0xE1BC_A680,
+    0xE1BC_A780, 0xE1BC_B080, 0xE1BC_B180, 0xE1BC_B280, 0xE1BC_B380, 0xE1BC_B480, 0xE1BC_B580,
+    0xE1BC_B680, 0xE1BC_B780, 0xE1BD_8080, 0xE1BD_8180, 0xE1BD_8280, 0xE1BD_8380, 0xE1BD_8480,
+    0xE1BD_8580, 0xE1BD_9180, 0xE1BD_9380, 0xE1BD_9580, 0xE1BD_9780, 0xE1BD_A080, 0xE1BD_A180,
+    0xE1BD_A280, 0xE1BD_A380, 0xE1BD_A480, 0xE1BD_A580, 0xE1BD_A680, 0xE1BD_A780, 0xE1BE_8080,
+    0xE1BE_8180, 0xE1BE_8280, 0xE1BE_8380, 0xE1BE_8480, 0xE1BE_8580, 0xE1BE_8680, 0xE1BE_8780,
+    0xE1BE_9080, 0xE1BE_9180, 0xE1BE_9280, 0xE1BE_9380, 0xE1BE_9480, 0xE1BE_9580, 0xE1BE_9680,
+    0xE1BE_9780, 0xE1BE_A080, 0xE1BE_A180, 0xE1BE_A280, 0xE1BE_A380, 0xE1BE_A480, 0xE1BE_A580,
+    0xE1BE_A680, 0xE1BE_A780, 0xE1BE_B080, 0xE1BE_B180, 0xE1BD_B080, 0xE1BD_B180, 0xE1BE_B380,
+    0xCEB9_8080, 0xE1BD_B280, 0xE1BD_B380, 0xE1BD_B480, 0xE1BD_B580, 0xE1BF_8380, 0xCE90_8080,
+    0xE1BF_9080, 0xE1BF_9180, 0xE1BD_B680, 0xE1BD_B780, 0xCEB0_8080, 0xE1BF_A080, 0xE1BF_A180,
+    0xE1BD_BA80, 0xE1BD_BB80, 0xE1BF_A580, 0xE1BD_B880, 0xE1BD_B980, 0xE1BD_BC80, 0xE1BD_BD80,
+    0xE1BF_B380, 0xCF89_8080, 0x6B80_8080, 0xC3A5_8080, 0xE285_8E80, 0xE285_B080, 0xE285_B180,
+    0xE285_B280, 0xE285_B380, 0xE285_B480, 0xE285_B580, 0xE285_B680, 0xE285_B780, 0xE285_B880,
+    0xE285_B980, 0xE285_BA80, 0xE285_BB80, 0xE285_BC80, 0xE285_BD80, 0xE285_BE80, 0xE285_BF80,
+    0xE286_8480, 0xE293_9080, 0xE293_9180, 0xE293_9280, 0xE293_9380, 0xE293_9480, 0xE293_9580,
+    0xE293_9680, 0xE293_9780, 0xE293_9880, 0xE293_9980, 0xE293_9A80, 0xE293_9B80, 0xE293_9C80,
+    0xE293_9D80, 0xE293_9E80, 0xE293_9F80, 0xE293_A080, 0xE293_A180, 0xE293_A280, 0xE293_A380,
+    0xE293_A480, 0xE293_A580, 0xE293_A680, 0xE293_A780, 0xE293_A880, 0xE293_A980, 0xE2B0_B080,
+    0xE2B0_B180, 0xE2B0_B280, 0xE2B0_B380, 0xE2B0_B480, 0xE2B0_B580, 0xE2B0_B680, 0xE2B0_B780,
+    0xE2B0_B880, 0xE2B0_B980, 0xE2B0_BA80, 0xE2B0_BB80, 0xE2B0_BC80, 0xE2B0_BD80, 0xE2B0_BE80,
+    0xE2B0_BF80, 0xE2B1_8080, 0xE2B1_8180, 0xE2B1_8280, 0xE2B1_8380, 0xE2B1_8480, 0xE2B1_8580,
+    0xE2B1_8680, 0xE2B1_8780, 0xE2B1_8880, 0xE2B1_8980, 0xE2B1_8A80, 0xE2B1_8B80, 0xE2B1_8C80,
+    0xE2B1_8D80, 0xE2B1_8E80, 0xE2B1_8F80, 0xE2B1_9080, 0xE2B1_9180, 0xE2B1_9280, 0xE2B1_9380,
+    0xE2B1_9480, 0xE2B1_9580, 0xE2B1_9680, 0xE2B1_9780, 0xE2B1_9880, 0xE2B1_9980, 0xE2B1_9A80,
+    0xE2B1_9B80, 0xE2B1_9C80, 0xE2B1_9D80, 0xE2B1_9E80, 0xE2B1_9F80, 0xE2B1_A180, 0xC9AB_8080,
+    0xE1B5_BD80, 0xC9BD_8080, 0xE2B1_A880, 0xE2B1_AA80, 0xE2B1_AC80, 0xC991_8080, 0xC9B1_8080,
+    0xC990_8080, 0xC992_8080, 0xE2B1_B380, 0xE2B1_B680, 0xC8BF_8080, 0xC980_8080, 0xE2B2_8180,
+    0xE2B2_8380, 0xE2B2_8580, 0xE2B2_8780, 0xE2B2_8980, 0xE2B2_8B80, 0xE2B2_8D80, 0xE2B2_8F80,
+    0xE2B2_9180, 0xE2B2_9380, 0xE2B2_9580, 0xE2B2_9780, 0xE2B2_9980, 0xE2B2_9B80, 0xE2B2_9D80,
+    0xE2B2_9F80, 0xE2B2_A180, 0xE2B2_A380, 0xE2B2_A580, 0xE2B2_A780, 0xE2B2_A980, 0xE2B2_AB80,
+    0xE2B2_AD80, 0xE2B2_AF80, 0xE2B2_B180, 0xE2B2_B380, 0xE2B2_B580, 0xE2B2_B780, 0xE2B2_B980,
+    0xE2B2_BB80, 0xE2B2_BD80, 0xE2B2_BF80, 0xE2B3_8180, 0xE2B3_8380, 0xE2B3_8580, 0xE2B3_8780,
+    0xE2B3_8980, 0xE2B3_8B80, 0xE2B3_8D80, 0xE2B3_8F80, 0xE2B3_9180, 0xE2B3_9380, 0xE2B3_9580,
+    0xE2B3_9780, 0xE2B3_9980, 0xE2B3_9B80, 0xE2B3_9D80, 0xE2B3_9F80, 0xE2B3_A180, 0xE2B3_A380,
+    0xE2B3_AC80, 0xE2B3_AE80, 0xE2B3_B380, 0xEA99_8180, 0xEA99_8380, 0xEA99_8580, 0xEA99_8780,
+    0xEA99_8980, 0xEA99_8B80, 0xEA99_8D80, 0xEA99_8F80, 0xEA99_9180, 0xEA99_9380, 0xEA99_9580,
+    0xEA99_9780, 0xEA99_9980, 0xEA99_9B80, 0xEA99_9D80, 0xEA99_9F80, 0xEA99_A180, 0xEA99_A380,
+    0xEA99_A580, 0xEA99_A780, 0xEA99_A980, 0xEA99_AB80, 0xEA99_AD80, 0xEA9A_8180, 0xEA9A_8380,
+    0xEA9A_8580, 0xEA9A_8780, 0xEA9A_8980, 0xEA9A_8B80, 0xEA9A_8D80, 0xEA9A_8F80, 0xEA9A_9180,
+    0xEA9A_9380, 0xEA9A_9580, 0xEA9A_9780, 0xEA9A_9980, 0xEA9A_9B80, 0xEA9C_A380, 0xEA9C_A580,
+    0xEA9C_A780, 0xEA9C_A980, 0xEA9C_AB80, 0xEA9C_AD80, 0xEA9C_AF80, 0xEA9C_B380, 0xEA9C_B580,
+    0xEA9C_B780, 0xEA9C_B980, 0xEA9C_BB80, 0xEA9C_BD80, 0xEA9C_BF80, 0xEA9D_8180, 0xEA9D_8380,
+    0xEA9D_8580, 0xEA9D_8780, 0xEA9D_8980, 0xEA9D_8B80, 0xEA9D_8D80, 0xEA9D_8F80, 0xEA9D_9180,
+    0xEA9D_9380, 0xEA9D_9580, 0xEA9D_9780, 0xEA9D_9980, 0xEA9D_9B80, 0xEA9D_9D80, 0xEA9D_9F80,
+    0xEA9D_A180, 0xEA9D_A380, 0xEA9D_A580, 0xEA9D_A780, 0xEA9D_A980, 0xEA9D_AB80, 0xEA9D_AD80,
+    0xEA9D_AF80, 0xEA9D_BA80, 0xEA9D_BC80, 0xE1B5_B980, 0xEA9D_BF80, 0xEA9E_8180, 0xEA9E_8380,
+    0xEA9E_8580, 0xEA9E_8780, 0xEA9E_8C80, 0xC9A5_8080, 0xEA9E_9180, 0xEA9E_9380, 0xEA9E_9780,
+    0xEA9E_9980, 0xEA9E_9B80, 0xEA9E_9D80, 0xEA9E_9F80, 0xEA9E_A180, 0xEA9E_A380, 0xEA9E_A580,
+    0xEA9E_A780, 0xEA9E_A980, 0xC9A6_8080, 0xC99C_8080, 0xC9A1_8080, 0xC9AC_8080, 0xC9AA_8080,
+    0xCA9E_8080, 0xCA87_8080, 0xCA9D_8080, 0xEAAD_9380, 0xEA9E_B580, 0xEA9E_B780, 0xEA9E_B980,
+    0xEA9E_BB80, 0xEA9E_BD80, 0xEA9E_BF80, 0xEA9F_8180, 0xEA9F_8380, 0xEA9E_9480, 0xCA82_8080,
+    0xE1B6_8E80, 0xEA9F_8880, 0xEA9F_8A80, 0xC9A4_8080, 0xEA9F_8D80, 0xEA9F_9180, 0xEA9F_9780,
+    0xEA9F_9980, 0xEA9F_9B80, 0xC69B_8080, 0xEA9F_B680, 0xE18E_A080, 0xE18E_A180, 0xE18E_A280,
+    0xE18E_A380, 0xE18E_A480, 0xE18E_A580, 0xE18E_A680, 0xE18E_A780, 0xE18E_A880, 0xE18E_A980,
+    0xE18E_AA80, 0xE18E_AB80, 0xE18E_AC80, 0xE18E_AD80, 0xE18E_AE80, 0xE18E_AF80, 0xE18E_B080,
+    0xE18E_B180, 0xE18E_B280, 0xE18E_B380, 0xE18E_B480, 0xE18E_B580, 0xE18E_B680, 0xE18E_B780,
+    0xE18E_B880, 0xE18E_B980, 0xE18E_BA80, 0xE18E_BB80, 0xE18E_BC80, 0xE18E_BD80, 0xE18E_BE80,
+    0xE18E_BF80, 0xE18F_8080, 0xE18F_8180, 0xE18F_8280, 0xE18F_8380, 0xE18F_8480, 0xE18F_8580,
+    0xE18F_8680, 0xE18F_8780, 0xE18F_8880, 0xE18F_8980, 0xE18F_8A80, 0xE18F_8B80, 0xE18F_8C80,
+    0xE18F_8D80, 0xE18F_8E80, 0xE18F_8F80, 0xE18F_9080, 0xE18F_9180, 0xE18F_9280, 0xE18F_9380,
+    0xE18F_9480, 0xE18F_9580, 0xE18F_9680, 0xE18F_9780, 0xE18F_9880, 0xE18F_9980, 0xE18F_9A80,
+    0xE18F_9B80, 0xE18F_9C80, 0xE18F_9D80, 0xE18F_9E80, 0xE18F_9F80, 0xE18F_A080, 0xE18F_A180,
+    0xE18F_A280, 0xE18F_A380, 0xE18F_A480, 0xE18F_A580, 0xE18F_A680, 0xE18F_A780, 0xE18F_A880,
+    0xE18F_A980, 0xE18F_AA80, 0xE18F_AB80, 0xE18F_AC80, 0xE18F_AD80, 0xE18F_AE80, 0xE18F_AF80,
+    0xEFAC_8680, 0xEFBD_8180, 0xEFBD_8280, 0xEFBD_8380, 0xEFBD_8480, 0xEFBD_8580, 0xEFBD_8680,
+    0xEFBD_8780, 0xEFBD_8880, 0xEFBD_8980, 0xEFBD_8A80, 0xEFBD_8B80, 0xEFBD_8C80, 0xEFBD_8D80,
+    0xEFBD_8E80, 0xEFBD_8F80, 0xEFBD_9080, 0xEFBD_9180, 0xEFBD_9280, 0xEFBD_9380, 0xEFBD_9480,
+    0xEFBD_9580, 0xEFBD_9680, 0xEFBD_9780, 0xEFBD_9880, 0xEFBD_9980, 0xEFBD_9A80, 0xF090_90A8,
+    0xF090_90A9, 0xF090_90AA, 0xF090_90AB, 0xF090_90AC, 0xF090_90AD, 0xF090_90AE, 0xF090_90AF,
+    0xF090_90B0, 0xF090_90B1, 0xF090_90B2, 0xF090_90B3, 0xF090_90B4, 0xF090_90B5, 0xF090_90B6,
+    0xF090_90B7, 0xF090_90B8, 0xF090_90B9, 0xF090_90BA, 0xF090_90BB, 0xF090_90BC, 0xF090_90BD,
+    0xF090_90BE, 0xF090_90BF, 0xF090_9180, 0xF090_9181, 0xF090_9182, 0xF090_9183, 0xF090_9184,
+    0xF090_9185, 0xF090_9186, 0xF090_9187, 0xF090_9188, 0xF090_9189, 0xF090_918A, 0xF090_918B,
+    0xF090_918C, 0xF090_918D, 0xF090_918E, 0xF090_918F, 0xF090_9398, 0xF090_9399, 0xF090_939A,
+    0xF090_939B, 0xF090_939C, 0xF090_939D, 0xF090_939E, 0xF090_939F, 0xF090_93A0, 0xF090_93A1,
+    0xF090_93A2, 0xF090_93A3, 0xF090_93A4, 0xF090_93A5, 0xF090_93A6, 0xF090_93A7, 0xF090_93A8,
+    0xF090_93A9, 0xF090_93AA, 0xF090_93AB, 0xF090_93AC, 0xF090_93AD, 0xF090_93AE, 0xF090_93AF,
+    0xF090_93B0, 0xF090_93B1, 0xF090_93B2, 0xF090_93B3, 0xF090_93B4, 0xF090_93B5, 0xF090_93B6,
+    0xF090_93B7, 0xF090_93B8, 0xF090_93B9, 0xF090_93BA, 0xF090_93BB, 0xF090_9697, 0xF090_9698,
+    0xF090_9699, 0xF090_969A, 0xF090_969B, 0xF090_969C, 0xF090_969D, 0xF090_969E, 0xF090_969F,
+    0xF090_96A0, 0xF090_96A1, 0xF090_96A3, 0xF090_96A4, 0xF090_96A5, 0xF090_96A6, 0xF090_96A7,
+    0xF090_96A8, 0xF090_96A9, 0xF090_96AA, 0xF090_96AB, 0xF090_96AC, 0xF090_96AD, 0xF090_96AE,
+    0xF090_96AF, 0xF090_96B0, 0xF090_96B1, 0xF090_96B3, 0xF090_96B4, 0xF090_96B5, 0xF090_96B6,
+    0xF090_96B7, 0xF090_96B8, 0xF090_96B9, 0xF090_96BB, 0xF090_96BC, 0xF090_B380, 0xF090_B381,
+    0xF090_B382, 0xF090_B383, 0xF090_B384, 0xF090_B385, 0xF090_B386, 0xF090_B387, 0xF090_B388,
+    0xF090_B389, 0xF090_B38A, 0xF090_B38B, 0xF090_B38C, 0xF090_B38D, 0xF090_B38E, 0xF090_B38F,
+    0xF090_B390, 0xF090_B391, 0xF090_B392, 0xF090_B393, 0xF090_B394, 0xF090_B395, 0xF090_B396,
+    0xF090_B397, 0xF090_B398, 0xF090_B399, 0xF090_B39A, 0xF090_B39B, 0xF090_B39C, 0xF090_B39D,
+    0xF090_B39E, 0xF090_B39F, 0xF090_B3A0, 0xF090_B3A1, 0xF090_B3A2, 0xF090_B3A3, 0xF090_B3A4,
+    0xF090_B3A5, 0xF090_B3A6, 0xF090_B3A7, 0xF090_B3A8, 0xF090_B3A9, 0xF090_B3AA, 0xF090_B3AB,
+    0xF090_B3AC, 0xF090_B3AD, 0xF090_B3AE, 0xF090_B3AF, 0xF090_B3B0, 0xF090_B3B1, 0xF090_B3B2,
+    0xF090_B5B0, 0xF090_B5B1, 0xF090_B5B2, 0xF090_B5B3, 0xF090_B5B4, 0xF090_B5B5, 0xF090_B5B6,
+    0xF090_B5B7, 0xF090_B5B8, 0xF090_B5B9, 0xF090_B5BA, 0xF090_B5BB, 0xF090_B5BC, 0xF090_B5BD,
+    0xF090_B5BE, 0xF090_B5BF, 0xF090_B680, 0xF090_B681, 0xF090_B682, 0xF090_B683, 0xF090_B684,
+    0xF090_B685, 0xF091_A380, 0xF091_A381, 0xF091_A382, 0xF091_A383, 0xF091_A384, 0xF091_A385,
+    0xF091_A386, 0xF091_A387, 0xF091_A388, 0xF091_A389, 0xF091_A38A, 0xF091_A38B, 0xF091_A38C,
+    0xF091_A38D, 0xF091_A38E, 0xF091_A38F, 0xF091_A390, 0xF091_A391, 0xF091_A392, 0xF091_A393,
+    0xF091_A394, 0xF091_A395, 0xF091_A396, 0xF091_A397, 0xF091_A398, 0xF091_A399, 0xF091_A39A,
+    0xF091_A39B, 0xF091_A39C, 0xF091_A39D, 0xF091_A39E, 0xF091_A39F, 0xF096_B9A0, 0xF096_B9A1,
+    0xF096_B9A2, 0xF096_B9A3, 0xF096_B9A4, 0xF096_B9A5, 0xF096_B9A6, 0xF096_B9A7, 0xF096_B9A8,
+    0xF096_B9A9, 0xF096_B9AA, 0xF096_B9AB, 0xF096_B9AC, 0xF096_B9AD, 0xF096_B9AE, 0xF096_B9AF,
+    0xF096_B9B0, 0xF096_B9B1, 0xF096_B9B2, 0xF096_B9B3, 0xF096_B9B4, 0xF096_B9B5, 0xF096_B9B6,
+    0xF096_B9B7, 0xF096_B9B8, 0xF096_B9B9, 0xF096_B9BA, 0xF096_B9BB, 0xF096_B9BC, 0xF096_B9BD,
+    0xF096_B9BE, 0xF096_B9BF, 0xF09E_A4A2, 0xF09E_A4A3, 0xF09E_A4A4, 0xF09E_A4A5, 0xF09E_A4A6,
+    0xF09E_A4A7, 0xF09E_A4A8, 0xF09E_A4A9, 0xF09E_A4AA, 0xF09E_A4AB, 0xF09E_A4AC, 0xF09E_A4AD,
+    0xF09E_A4AE, 0xF09E_A4AF, 0xF09E_A4B0, 0xF09E_A4B1, 0xF09E_A4B2, 0xF09E_A4B3, 0xF09E_A4B4,
+    0xF09E_A4B5, 0xF09E_A4B6, 0xF09E_A4B7, 0xF09E_A4B8, 0xF09E_A4B9, 0xF09E_A4BA, 0xF09E_A4BB,
+    0xF09E_A4BC, 0xF09E_A4BD, 0xF09E_A4BE, 0xF09E_A4BF, 0xF09E_A580, 0xF09E_A581, 0xF09E_A582,
+    0xF09E_A583, 0xEA9F_8F80, 0xEA9F_9380, 0xEA9F_9580, 0xF096_BABB, 0xF096_BABC, 0xF096_BABD,
+    0xF096_BABE, 0xF096_BABF, 0xF096_BB80, 0xF096_BB81, 0xF096_BB82, 0xF096_BB83, 0xF096_BB84,
+    0xF096_BB85, 0xF096_BB86, 0xF096_BB87, 0xF096_BB88, 0xF096_BB89, 0xF096_BB8A, 0xF096_BB8B,
+    0xF096_BB8C, 0xF096_BB8D, 0xF096_BB8E, 0xF096_BB8F, 0xF096_BB90, 0xF096_BB91, 0xF096_BB92,
+    0xF096_BB93,
+];
+
+/// The simple case folding of every codepoint
+pub(crate) static CASE_FOLD: Utf8Trie<[u16; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 3, 3, 3, 3, 3, 3, 4, 3, 3, 3, 3, 5, 10, 11, 11,
+        11, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0, 10, 11, 12, 13, 14, 15, 16, 17, 18, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 19, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 23,
+            24, 25, 26, 27, 28, 29, 30,
+        ],
+        [
+            0, 0, 0, 0, 31, 32, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 35, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 37, 38, 39, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0,
+            42, 43, 44, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 47, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 49, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 51, 52, 0, 53, 54, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 56, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58,
+            59, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 3, 3, 3, 3, 3, 3, 4, 3, 3, 3, 3, 5, 6, 7, 3, 3, 3, 3, 8, 3, 3, 3, 3, 3, 3,
+            3, 9, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+    ],
+    leaves: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49,
+            50, 0, 51, 52, 53, 54, 55, 56, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            58, 0, 59, 0, 60, 0, 61, 0, 62, 0, 63, 0, 64, 0, 65, 0, 66, 0, 67, 0, 68, 0, 69, 0, 70,
+            0, 71, 0, 72, 0, 73, 0, 74, 0, 75, 0, 76, 0, 77, 0, 78, 0, 79, 0, 80, 0, 81, 0, 0, 0,
+            82, 0, 83, 0, 84, 0, 0, 85, 0, 86, 0, 87, 0, 88,
+        ],
+        [
+            0, 89, 0, 90, 0, 91, 0, 92, 0, 0, 93, 0, 94, 0, 95, 0, 96, 0, 97, 0, 98, 0, 99, 0, 100,
+            0, 101, 0, 102, 0, 103, 0, 104, 0, 105, 0, 106, 0, 107, 0, 108, 0, 109, 0, 110, 0, 111,
+            0, 112, 0, 113, 0, 114, 0, 115, 0, 116, 117, 0, 118, 0, 119, 0, 120,
+        ],
+        [
+            0, 121, 122, 0, 123, 0, 124, 125, 0, 126, 127, 128, 0, 0, 129, 130, 131, 132, 0, 133,
+            134, 0, 135, 136, 137, 0, 0, 0, 138, 139, 0, 140, 141, 0, 142, 0, 143, 0, 144, 145, 0,
+            146, 0, 0, 147, 0, 148, 149, 0, 150, 151, 152, 0, 153, 0, 154, 155, 0, 0, 0, 156, 0, 0,
+            0,
+        ],
+        [
+            0, 0, 0, 0, 157, 158, 0, 159, 160, 0, 161, 162, 0, 163, 0, 164, 0, 165, 0, 166, 0, 167,
+            0, 168, 0, 169, 0, 170, 0, 0, 171, 0, 172, 0, 173, 0, 174, 0, 175, 0, 176, 0, 177, 0,
+            178, 0, 179, 0, 0, 180, 181, 0, 182, 0, 183, 184, 185, 0, 186, 0, 187, 0, 188, 0,
+        ],
+        [
+            189, 0, 190, 0, 191, 0, 192, 0, 193, 0, 194, 0, 195, 0, 196, 0, 197, 0, 198, 0, 199, 0,
+            200, 0, 201, 0, 202, 0, 203, 0, 204, 0, 205, 0, 206, 0, 207, 0, 208, 0, 209, 0, 210, 0,
+            211, 0, 212, 0, 213, 0, 214, 0, 0, 0, 0, 0, 0, 0, 215, 216, 0, 217, 218, 0,
+        ],
+        [
+            0, 219, 0, 220, 221, 222, 223, 0, 224, 0, 225, 0, 226, 0, 227, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 229, 0, 230, 0, 0, 0, 231, 0,
+            0, 0, 0, 0, 0, 0, 0, 232,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 233, 0, 234, 235, 236, 0, 237, 0, 238, 239, 0, 240, 241, 242, 243,
+            244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255, 256, 0, 257, 258, 259, 260,
+            261, 262, 263, 264, 265, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 266, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 267, 268, 269, 0, 0, 0, 270, 271, 0, 272,
+            0, 273, 0, 274, 0, 275, 0, 276, 0, 277, 0, 278, 0, 279, 0, 280, 0, 281, 0, 282, 0, 283,
+            0, 284, 285, 0, 0, 286, 287, 0, 288, 0, 289, 290, 0, 0, 291, 292, 293,
+        ],
+        [
+            294, 295, 296, 297, 298, 299, 300, 301, 302, 303, 304, 305, 306, 307, 308, 309, 310,
+            311, 312, 313, 314, 315, 316, 317, 318, 319, 320, 321, 322, 323, 324, 325, 326, 327,
+            328, 329, 330, 331, 332, 333, 334, 335, 336, 337, 338, 339, 340, 341, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 342, 0, 343, 0, 344, 0, 345, 0, 346, 0, 347, 0, 348, 0, 349, 0, 350, 0, 351, 0,
+            352, 0, 353, 0, 354, 0, 355, 0, 356, 0, 357, 0,
+        ],
+        [
+            358, 0, 0, 0, 0, 0, 0, 0, 0, 0, 359, 0, 360, 0, 361, 0, 362, 0, 363, 0, 364, 0, 365, 0,
+            366, 0, 367, 0, 368, 0, 369, 0, 370, 0, 371, 0, 372, 0, 373, 0, 374, 0, 375, 0, 376, 0,
+            377, 0, 378, 0, 379, 0, 380, 0, 381, 0, 382, 0, 383, 0, 384, 0, 385, 0,
+        ],
+        [
+            386, 387, 0, 388, 0, 389, 0, 390, 0, 391, 0, 392, 0, 393, 0, 0, 394, 0, 395, 0, 396, 0,
+            397, 0, 398, 0, 399, 0, 400, 0, 401, 0, 402, 0, 403, 0, 404, 0, 405, 0, 406, 0, 407, 0,
+            408, 0, 409, 0, 410, 0, 411, 0, 412, 0, 413, 0, 414, 0, 415, 0, 416, 0, 417, 0,
+        ],
+        [
+            418, 0, 419, 0, 420, 0, 421, 0, 422, 0, 423, 0, 424, 0, 425, 0, 426, 0, 427, 0, 428, 0,
+            429, 0, 430, 0, 431, 0, 432, 0, 433, 0, 434, 0, 435, 0, 436, 0, 437, 0, 438, 0, 439, 0,
+            440, 0, 441, 0, 0, 442, 443, 444, 445, 446, 447, 448, 449, 450, 451, 452, 453, 454, 455,
+            456,
+        ],
+        [
+            457, 458, 459, 460, 461, 462, 463, 464, 465, 466, 467, 468, 469, 470, 471, 472, 473,
+            474, 475, 476, 477, 478, 479, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 480, 481, 482, 483, 484, 485, 486, 487, 488, 489, 490, 491, 492, 493, 494, 495,
+            496, 497, 498, 499, 500, 501, 502, 503, 504, 505, 506, 507, 508, 509, 510, 511,
+        ],
+        [
+            512, 513, 514, 515, 516, 517, 0, 518, 0, 0, 0, 0, 0, 519, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 520,
+            521, 522, 523, 524, 525, 0, 0,
+        ],
+        [
+            526, 527, 528, 529, 530, 531, 532, 533, 534, 535, 0, 0, 0, 0, 0, 0, 536, 537, 538, 539,
+            540, 541, 542, 543, 544, 545, 546, 547, 548, 549, 550, 551, 552, 553, 554, 555, 556,
+            557, 558, 559, 560, 561, 562, 563, 564, 565, 566, 567, 568, 569, 570, 571, 572, 573,
+            574, 575, 576, 577, 578, 0, 0, 579, 580, 581,
+        ],
+        [
+            582, 0, 583, 0, 584, 0, 585, 0, 586, 0, 587, 0, 588, 0, 589, 0, 590, 0, 591, 0, 592, 0,
+            593, 0, 594, 0, 595, 0, 596, 0, 597, 0, 598, 0, 599, 0, 600, 0, 601, 0, 602, 0, 603, 0,
+            604, 0, 605, 0, 606, 0, 607, 0, 608, 0, 609, 0, 610, 0, 611, 0, 612, 0, 613, 0,
+        ],
+        [
+            614, 0, 615, 0, 616, 0, 617, 0, 618, 0, 619, 0, 620, 0, 621, 0, 622, 0, 623, 0, 624, 0,
+            625, 0, 626, 0, 627, 0, 628, 0, 629, 0, 630, 0, 631, 0, 632, 0, 633, 0, 634, 0, 635, 0,
+            636, 0, 637, 0, 638, 0, 639, 0, 640, 0, 641, 0, 642, 0, 643, 0, 644, 0, 645, 0,
+        ],
+        [
+            646, 0, 647, 0, 648, 0, 649, 0, 650, 0, 651, 0, 652, 0, 653, 0, 654, 0, 655, 0, 656, 0,
+            0, 0, 0, 0, 0, 657, 0, 0, 658, 0, 659, 0, 660, 0, 661, 0, 662, 0, 663, 0, 664, 0, 665,
+            0, 666, 0, 667, 0, 668, 0, 669, 0, 670, 0, 671, 0, 672, 0, 673, 0, 674, 0,
+        ],
+        [
+            675, 0, 676, 0, 677, 0, 678, 0, 679, 0, 680, 0, 681, 0, 682, 0, 683, 0, 684, 0, 685, 0,
+            686, 0, 687, 0, 688, 0, 689, 0, 690, 0, 691, 0, 692, 0, 693, 0, 694, 0, 695, 0, 696, 0,
+            697, 0, 698, 0, 699, 0, 700, 0, 701, 0, 702, 0, 703, 0, 704, 0, 705, 0, 706, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 707, 708, 709, 710, 711, 712, 713, 714, 0, 0, 0, 0, 0, 0, 0, 0,
+            715, 716, 717, 718, 719, 720, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 721, 722, 723, 724, 725,
+            726, 727, 728, 0, 0, 0, 0, 0, 0, 0, 0, 729, 730, 731, 732, 733, 734, 735, 736,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 737, 738, 739, 740, 741, 742, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            743, 0, 744, 0, 745, 0, 746, 0, 0, 0, 0, 0, 0, 0, 0, 747, 748, 749, 750, 751, 752, 753,
+            754, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 755, 756, 757, 758, 759, 760, 761, 762, 0, 0, 0, 0, 0, 0, 0, 0,
+            763, 764, 765, 766, 767, 768, 769, 770, 0, 0, 0, 0, 0, 0, 0, 0, 771, 772, 773, 774, 775,
+            776, 777, 778, 0, 0, 0, 0, 0, 0, 0, 0, 779, 780, 781, 782, 783, 0, 784, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 785, 786, 787, 788, 789, 0, 0, 0, 0, 0, 0, 790, 0, 0, 0, 0, 791,
+            792, 793, 794, 0, 0, 0, 0, 0, 0, 0, 795, 0, 0, 0, 0, 796, 797, 798, 799, 800, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 801, 802, 803, 804, 805, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 806, 0, 0, 0, 807, 808, 0, 0, 0, 0, 0, 0, 809, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 810, 811, 812, 813, 814, 815, 816, 817, 818, 819, 820, 821, 822, 823, 824, 825,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 826, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 827, 828,
+            829, 830, 831, 832, 833, 834, 835, 836,
+        ],
+        [
+            837, 838, 839, 840, 841, 842, 843, 844, 845, 846, 847, 848, 849, 850, 851, 852, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            853, 854, 855, 856, 857, 858, 859, 860, 861, 862, 863, 864, 865, 866, 867, 868, 869,
+            870, 871, 872, 873, 874, 875, 876, 877, 878, 879, 880, 881, 882, 883, 884, 885, 886,
+            887, 888, 889, 890, 891, 892, 893, 894, 895, 896, 897, 898, 899, 900, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 901, 0, 902, 903, 904, 0, 0, 905, 0, 906, 0, 907, 0, 908, 909, 910, 911, 0,
+            912, 0, 0, 913, 0, 0, 0, 0, 0, 0, 0, 0, 914, 915,
+        ],
+        [
+            916, 0, 917, 0, 918, 0, 919, 0, 920, 0, 921, 0, 922, 0, 923, 0, 924, 0, 925, 0, 926, 0,
+            927, 0, 928, 0, 929, 0, 930, 0, 931, 0, 932, 0, 933, 0, 934, 0, 935, 0, 936, 0, 937, 0,
+            938, 0, 939, 0, 940, 0, 941, 0, 942, 0, 943, 0, 944, 0, 945, 0, 946, 0, 947, 0,
+        ],
+        [
+            948, 0, 949, 0, 950, 0, 951, 0, 952, 0, 953, 0, 954, 0, 955, 0, 956, 0, 957, 0, 958, 0,
+            959, 0, 960, 0, 961, 0, 962, 0, 963, 0, 964, 0, 965, 0, 0, 0, 0, 0, 0, 0, 0, 966, 0,
+            967, 0, 0, 0, 0, 968, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            969, 0, 970, 0, 971, 0, 972, 0, 973, 0, 974, 0, 975, 0, 976, 0, 977, 0, 978, 0, 979, 0,
+            980, 0, 981, 0, 982, 0, 983, 0, 984, 0, 985, 0, 986, 0, 987, 0, 988, 0, 989, 0, 990, 0,
+            991, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            992, 0, 993, 0, 994, 0, 995, 0, 996, 0, 997, 0, 998, 0, 999, 0, 1000, 0, 1001, 0, 1002,
+            0, 1003, 0, 1004, 0, 1005, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 1006, 0, 1007, 0, 1008, 0, 1009, 0, 1010, 0, 1011, 0, 1012, 0, 0, 0,
+            1013, 0, 1014, 0, 1015, 0, 1016, 0, 1017, 0, 1018, 0, 1019, 0,
+        ],
+        [
+            1020, 0, 1021, 0, 1022, 0, 1023, 0, 1024, 0, 1025, 0, 1026, 0, 1027, 0, 1028, 0, 1029,
+            0, 1030, 0, 1031, 0, 1032, 0, 1033, 0, 1034, 0, 1035, 0, 1036, 0, 1037, 0, 1038, 0,
+            1039, 0, 1040, 0, 1041, 0, 1042, 0, 1043, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1044, 0, 1045,
+            0, 1046, 1047, 0,
+        ],
+        [
+            1048, 0, 1049, 0, 1050, 0, 1051, 0, 0, 0, 0, 1052, 0, 1053, 0, 0, 1054, 0, 1055, 0, 0,
+            0, 1056, 0, 1057, 0, 1058, 0, 1059, 0, 1060, 0, 1061, 0, 1062, 0, 1063, 0, 1064, 0,
+            1065, 0, 1066, 1067, 1068, 1069, 1070, 0, 1071, 1072, 1073, 1074, 1075, 0, 1076, 0,
+            1077, 0, 1078, 0, 1079, 0, 1080, 0,
+        ],
+        [
+            1081, 0, 1082, 0, 1083, 1084, 1085, 1086, 0, 1087, 0, 1088, 1089, 0, 1485, 0, 1090, 0,
+            1486, 0, 1487, 0, 1091, 0, 1092, 0, 1093, 0, 1094, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1095, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1096, 1097, 1098, 1099, 1100,
+            1101, 1102, 1103, 1104, 1105, 1106, 1107, 1108, 1109, 1110, 1111,
+        ],
+        [
+            1112, 1113, 1114, 1115, 1116, 1117, 1118, 1119, 1120, 1121, 1122, 1123, 1124, 1125,
+            1126, 1127, 1128, 1129, 1130, 1131, 1132, 1133, 1134, 1135, 1136, 1137, 1138, 1139,
+            1140, 1141, 1142, 1143, 1144, 1145, 1146, 1147, 1148, 1149, 1150, 1151, 1152, 1153,
+            1154, 1155, 1156, 1157, 1158, 1159, 1160, 1161, 1162, 1163, 1164, 1165, 1166, 1167,
+            1168, 1169, 1170, 1171, 1172, 1173, 1174, 1175,
+        ],
+        [
+            0, 0, 0, 0, 0, 1176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 1177, 1178, 1179, 1180, 1181, 1182, 1183, 1184, 1185, 1186, 1187, 1188,
+            1189, 1190, 1191, 1192, 1193, 1194, 1195, 1196, 1197, 1198, 1199, 1200, 1201, 1202, 0,
+            0, 0, 0, 0,
+        ],
+        [
+            1203, 1204, 1205, 1206, 1207, 1208, 1209, 1210, 1211, 1212, 1213, 1214, 1215, 1216,
+            1217, 1218, 1219, 1220, 1221, 1222, 1223, 1224, 1225, 1226, 1227, 1228, 1229, 1230,
+            1231, 1232, 1233, 1234, 1235, 1236, 1237, 1238, 1239, 1240, 1241, 1242, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1243, 1244, 1245, 1246, 1247,
+            1248, 1249, 1250, 1251, 1252, 1253, 1254, 1255, 1256, 1257, 1258,
+        ],
+        [
+            1259, 1260, 1261, 1262, 1263, 1264, 1265, 1266, 1267, 1268, 1269, 1270, 1271, 1272,
+            1273, 1274, 1275, 1276, 1277, 1278, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1279, 1280, 1281, 1282, 1283,
+            1284, 1285, 1286, 1287, 1288, 1289, 0, 1290, 1291, 1292, 1293,
+        ],
+        [
+            1294, 1295, 1296, 1297, 1298, 1299, 1300, 1301, 1302, 1303, 1304, 0, 1305, 1306, 1307,
+            1308, 1309, 1310, 1311, 0, 1312, 1313, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1314, 1315, 1316, 1317, 1318, 1319, 1320, 1321, 1322, 1323, 1324, 1325, 1326, 1327,
+            1328, 1329, 1330, 1331, 1332, 1333, 1334, 1335, 1336, 1337, 1338, 1339, 1340, 1341,
+            1342, 1343, 1344, 1345, 1346, 1347, 1348, 1349, 1350, 1351, 1352, 1353, 1354, 1355,
+            1356, 1357, 1358, 1359, 1360, 1361, 1362, 1363, 1364, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1365, 1366, 1367, 1368, 1369, 1370,
+            1371, 1372, 1373, 1374, 1375, 1376, 1377, 1378, 1379, 1380, 1381, 1382, 1383, 1384,
+            1385, 1386, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1387, 1388, 1389, 1390, 1391, 1392, 1393, 1394, 1395, 1396, 1397, 1398, 1399,
+            1400, 1401, 1402, 1403, 1404, 1405, 1406, 1407, 1408, 1409, 1410, 1411, 1412, 1413,
+            1414, 1415, 1416, 1417, 1418,
+        ],
+        [
+            1419, 1420, 1421, 1422, 1423, 1424, 1425, 1426, 1427, 1428, 1429, 1430, 1431, 1432,
+            1433, 1434, 1435, 1436, 1437, 1438, 1439, 1440, 1441, 1442, 1443, 1444, 1445, 1446,
+            1447, 1448, 1449, 1450, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1488, 1489, 1490, 1491, 1492, 1493, 1494, 1495, 1496, 1497, 1498, 1499, 1500,
+            1501, 1502, 1503, 1504, 1505, 1506, 1507, 1508, 1509, 1510, 1511, 1512, 0, 0, 0, 0, 0,
+            0, 0,
+        ],
+        [
+            1451, 1452, 1453, 1454, 1455, 1456, 1457, 1458, 1459, 1460, 1461, 1462, 1463, 1464,
+            1465, 1466, 1467, 1468, 1469, 1470, 1471, 1472, 1473, 1474, 1475, 1476, 1477, 1478,
+            1479, 1480, 1481, 1482, 1483, 1484, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    ],
+};
@@ -0,0 +1,238 @@
+"""Generates src/tables/conformance.rs: the test cases of the UCD test files, which the tests of the
+algorithms they cover run against.
+
+    python3 src/tables/conformance.py <path to UCD> > src/tables/conformance.rs
+"""
+
+from pathlib import Path
+
+from bidi import BIDI_CLASSES
+from ucd import header, ucd_dir, wrap
+
+# the name of the generated constant, by the test file of a segmentation
+SEGMENTATION_TESTS = {
+    "auxiliary/GraphemeBreakTest.txt": "GRAPHEME_BREAK_TEST",
+    "auxiliary/WordBreakTest.txt": "WORD_BREAK_TEST",
+    "auxiliary/SentenceBreakTest.txt": "SENTENCE_BREAK_TEST",
+    "auxiliary/LineBreakTest.txt": "LINE_BREAK_TEST",
+}
+
+# the parts of NormalizationTest.txt to generate, leaving out the canonical closures and chained
+# primary composites of parts 4 and 5
+NORMALIZATION_PARTS = {"@Part0", "@Part1", "@Part2", "@Part3"}
+
+# a codepoint of each bidi class for the cases of BidiTest.txt, which lists classes rather than
+# codepoints, none of them a bracket
+BIDI_CLASS_CHARS = {
+    "L": "a",
+    "R": "\u05D0",
+    "AL": "\u0627",
+    "EN": "1",
+    "ES": "+",
+    "ET": "$",
+    "AN": "\u0660",
+    "CS": ",",
+    "NSM": "\u0300",
+    "BN": "\u00AD",
+    "B": "\u2029",
+    "S": "\t",
+    "WS": " ",
+    "ON": "!",
+    "LRE": "\u202A",
+    "LRO": "\u202D",
+    "RLE": "\u202B",
+    "RLO": "\u202E",
+    "PDF": "\u202C",
+    "LRI": "\u2066",
+    "RLI": "\u2067",
+    "FSI": "\u2068",
+    "PDI": "\u2069",
+}
+
+
+def segmentation_test(path: Path) -> list[list[str]]:
+    """Reads a `÷ XXXX × YYYY ÷` test file into the segments of each test case, the first marker
+    being whether there is a boundary at the start of the string"""
+    cases = []
+
+    with open(path, encoding="utf8") as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+
+            if not line:
+                continue
+
+            segments = [""]
+
+            for token in line.split()[1:]:
+                if token == "÷":
+                    segments.append("")
+                elif token != "×":
+                    segments[-1] += chr(int(token, 16))
+
+            # the boundary at the end of the string starts an empty segment
+            assert segments.pop() == ""
+            cases.append(segments)
+
+    return cases
+
+
+def normalization_test(path: Path) -> list[list[str]]:
+    """Reads the c1..c5 columns (source, NFC, NFD, NFKC, NFKD) of each test case in
+    `NORMALIZATION_PARTS` of NormalizationTest.txt"""
+    cases = []
+    part = None
+
+    with open(path, encoding="utf8") as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+
+            if not line:
+                continue
+
+            if line.startswith("@"):
+                part = line
+            elif part in NORMALIZATION_PARTS:
+                columns = line.split(";")[:5]
+                cases.append(["".join(chr(int(c, 16)) for c in column.split()) for column in columns])
+
+    return cases
+
+
+def bidi_test(path: Path) -> list[tuple[str, str, dict[int, list[str]]]]:
+    """Reads BidiTest.txt into its groups of test cases: the levels and visual order they share, and
+    the cases by the paragraph directions they hold for. A case is written as a letter per bidi
+    class, `A` for the first one of `BIDI_CLASSES`"""
+    letters = {name: chr(ord("A") + i) for i, name in enumerate(BIDI_CLASSES)}
+    groups: list[tuple[str, str, dict[int, list[str]]]] = []
+    levels = order = ""
+
+    with open(path, encoding="utf8") as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+
+            if not line:
+                continue
+
+            if line.startswith("@Levels:"):
+                levels = line.removeprefix("@Levels:").strip()
+            elif line.startswith("@Reorder:"):
+                order = line.removeprefix("@Reorder:").strip()
+                groups.append((levels, order, {}))
+            else:
+                classes, directions = line.split(";")
+                case = "".join(letters[c] for c in classes.split())
+                groups[-1][2].setdefault(int(directions), []).append(case)
+
+    return groups
+
+
+def bidi_character_test(path: Path) -> list[tuple[str, int, int, str, str]]:
+    """Reads the text, paragraph direction, resolved paragraph level, levels and visual order of
+    each test case of BidiCharacterTest.txt"""
+    cases = []
+
+    with open(path, encoding="utf8") as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+
+            if not line:
+                continue
+
+            fields = line.split(";")
+            text = "".join(chr(int(c, 16)) for c in fields[0].split())
+            cases.append((text, int(fields[1]), int(fields[2]), fields[3], fields[4]))
+
+    return cases
+
+
+def rust_str(s: str) -> str:
+    """Returns a rust string literal of `s`, every codepoint escaped"""
+    return '"' + "".join(f"\\u{{{ord(c):x}}}" for c in s) + '"'
+
+
+def rust_text(s: str) -> str:
+    """Returns a rust string literal of `s`, every codepoint but printable ascii escaped"""
+    return '"' + "".join(c if " " <= c <= "~" and c not in '"\\' else f"\\u{{{ord(c):x}}}" for c in s) + '"'
+
+
+def wrapped_str(prefix: str, words: list[str], suffix: str, indent: int) -> list[str]:
+    """Returns the lines of a rust string literal of `words` separated by spaces, continued with a
+    backslash where it is too long for a line"""
+    lines = wrap(iter([prefix + '"' + words[0], *words[1:]]), indent=indent, width=98)
+    return [line + " \\" for line in lines[:-1]] + [lines[-1] + '"' + suffix]
+
+
+def main() -> None:
+    ucd = ucd_dir()
+
+    print(
+        header(
+            "conformance.py",
+            [*SEGMENTATION_TESTS, "NormalizationTest.txt", "BidiTest.txt", "BidiCharacterTest.txt"],
+        )
+    )
+
+    for i, (source, name) in enumerate(SEGMENTATION_TESTS.items()):
+        if i:
+            print()
+
+        cases = segmentation_test(ucd / source)
+
+        print(f"/// The test cases of {source}, each a string split at its boundaries")
+        print(f"pub(crate) const {name}: &[&[&str]] = &[")
+
+        for segments in cases:
+            print("\n".join(wrap(iter(f"&[{', '.join(map(rust_str, segments))}],".split(" ")), indent=4)))
+
+        print("];")
+
+    print()
+    print("/// The test cases of parts 0 to 3 of NormalizationTest.txt, each its source, NFC, NFD, NFKC and")
+    print("/// NFKD columns")
+    print("pub(crate) const NORMALIZATION_TEST: &[[&str; 5]] = &[")
+
+    for columns in normalization_test(ucd / "NormalizationTest.txt"):
+        print("\n".join(wrap(iter(f"[{', '.join(map(rust_str, columns))}],".split(" ")), indent=4)))
+
+    print("];")
+    print()
+    print("/// A codepoint of each bidi class, in the order of `BidiClass`, which the letters of the cases of")
+    print("/// `BIDI_TEST` stand for: `A` for the first one")
+    print("pub(crate) const BIDI_TEST_CHARS: &[char] = &[")
+    print("\n".join(wrap(iter(f"'\\u{{{ord(c):x}}}'," for c in BIDI_CLASS_CHARS.values()), indent=4)))
+    print("];")
+    print()
+    print("/// A group of test cases of BidiTest.txt: their levels and visual order as written there, and")
+    print("/// the cases by the paragraph directions they hold for, 1 for auto, 2 for left to right and 4 for")
+    print("/// right to left. Cases are strings of letters standing for `BIDI_TEST_CHARS`, separated by")
+    print("/// spaces")
+    print("pub(crate) type BidiTestGroup = (&'static str, &'static str, &'static [(u8, &'static str)]);")
+    print()
+    print("/// The test cases of BidiTest.txt")
+    print("pub(crate) const BIDI_TEST: &[BidiTestGroup] = &[")
+
+    for levels, order, cases in bidi_test(ucd / "BidiTest.txt"):
+        print(f'    ("{levels}", "{order}", &[')
+
+        for directions, strings in cases.items():
+            print("\n".join(wrapped_str(f"({directions}, ", strings, "),", indent=8)))
+
+        print("    ]),")
+
+    print("];")
+    print()
+    print("/// The test cases of BidiCharacterTest.txt, each its text, paragraph direction (0 for left to")
+    print("/// right, 1 for right to left and 2 for auto), resolved paragraph level, and levels and visual")
+    print("/// order as written there")
+    print("pub(crate) const BIDI_CHARACTER_TEST: &[(&str, u8, u8, &str, &str)] = &[")
+
+    for text, direction, level, levels, order in bidi_character_test(ucd / "BidiCharacterTest.txt"):
+        fields = [f"({rust_text(text)},", f"{direction},", f"{level},", f'"{levels}",', f'"{order}"),']
+        print("\n".join(wrap(iter(fields), indent=4)))
+
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
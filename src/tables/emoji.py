@@ -0,0 +1,23 @@
+"""Generates src/tables/emoji.rs: the emoji properties of UTS #51 not covered by plain codepoint ranges.
+
+    python3 src/tables/emoji.py <path to UCD> > src/tables/emoji.rs
+
+`Extended_Pictographic` is generated by grapheme.py, as grapheme clusters need it too.
+"""
+
+from ucd import binary_property, bool_trie, header, ucd_dir
+
+
+def main() -> None:
+    data = ucd_dir() / "emoji/emoji-data.txt"
+
+    print(header("emoji.py", ["emoji/emoji-data.txt"]))
+    print("use super::Utf8Trie;")
+    print()
+    print(bool_trie("EMOJI", "Codepoints with the `Emoji` property", binary_property(data, "Emoji")))
+    print(bool_trie("EMOJI_PRESENTATION", "Codepoints with the `Emoji_Presentation` property", binary_property(data, "Emoji_Presentation")))
+    print(bool_trie("EMOJI_MODIFIER_BASE", "Codepoints with the `Emoji_Modifier_Base` property", binary_property(data, "Emoji_Modifier_Base")), end="")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,246 @@
+//! Generated by `src/tables/emoji.py` from the Unicode 17.0.0 files
+//! emoji/emoji-data.txt. Do not edit by hand.
+
+use super::Utf8Trie;
+
+/// Codepoints with the `Emoji` property
+pub(crate) static EMOJI: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 0, 1, 2, 3, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 5, 1, 1, 1, 7, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+        [
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+        [
+            3, 4, 1, 1, 5, 1, 6, 1, 1, 1, 1, 1, 7, 1, 1, 8, 1, 1, 1, 9, 1, 1, 10, 11, 12, 13, 14,
+            15, 16, 17, 18, 1, 1, 1, 1, 1, 19, 1, 1, 1, 1, 1, 1, 1, 20, 21, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        ],
+        [
+            22, 1, 1, 1, 1, 1, 1, 1, 1, 1, 23, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+        [
+            24, 1, 1, 25, 1, 26, 27, 28, 29, 30, 1, 1, 31, 32, 33, 34, 32, 32, 32, 35, 36, 37, 38,
+            39, 32, 40, 32, 41, 1, 1, 1, 42, 1, 1, 1, 1, 43, 44, 32, 32, 1, 45, 46, 47, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        ],
+        [
+            0, 1, 2, 3, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 4, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6,
+        ],
+    ],
+    leaves: &[
+        0x03FF_0408_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_4200_0000_0000,
+        0x1000_0000_0000_0000,
+        0x0000_0000_0000_0200,
+        0x0200_0004_0000_0000,
+        0x0000_0600_03F0_0000,
+        0x0000_0100_0C00_0000,
+        0x070F_FE00_0000_8000,
+        0x0000_0000_0000_0004,
+        0x0040_0C00_0000_0000,
+        0x7800_0000_0000_0001,
+        0x0700_C44D_2132_401F,
+        0xC800_0169_800F_FF05,
+        0x6003_0C83_1AFC_0000,
+        0x27BF_0600_001A_C130,
+        0x0018_0102_2054_BF24,
+        0x0000_0018_00B8_5090,
+        0x8001_0002_00E0_0000,
+        0x0030_0000_0000_0000,
+        0x0000_0000_1800_00E0,
+        0x0000_0000_0021_0000,
+        0x2001_0000_0000_0000,
+        0x0000_0000_0280_0000,
+        0x0000_0000_0000_0010,
+        0x0000_0000_0000_8000,
+        0xC003_0000_0000_0000,
+        0x0000_0000_07FE_4000,
+        0xFFFF_FFC0_0000_0000,
+        0x07FC_8000_0400_0006,
+        0x0000_0000_0003_0000,
+        0xFFFF_FFF3_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_CECF_FFFF,
+        0xFFB9_FFFF_FFFF_FFFF,
+        0xBFFF_FFFF_FFFF_FFFF,
+        0x3FFF_FFFF_FFFF_FFFF,
+        0x07F9_80FF_FFFF_7E00,
+        0x1006_0130_0061_3C80,
+        0xFC08_810A_700E_001C,
+        0x0000_0000_0000_FFFF,
+        0x1FF9_1A3F_F1E7_F83F,
+        0x0001_0FFF_0000_0000,
+        0xF7FF_FFFF_FFFF_F000,
+        0xFFFF_FFFF_FFFF_FFBF,
+        0x1FFF_0000_0000_0000,
+        0xFFFF_FFFF_FFFF_C7FF,
+        0x01FF_87FF_9FFF_E17F,
+    ],
+};
+
+/// Codepoints with the `Emoji_Presentation` property
+pub(crate) static EMOJI_PRESENTATION: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 4, 5, 6, 7, 8,
+            9, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            13, 0, 0, 14, 0, 0, 15, 16, 17, 18, 0, 0, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29,
+            30, 25, 31, 25, 32, 0, 0, 0, 33, 0, 0, 0, 0, 34, 35, 25, 25, 0, 36, 37, 38, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+    ],
+    leaves: &[
+        0x0000_0000_0000_0000,
+        0x0000_0000_0C00_0000,
+        0x0009_1E00_0000_0000,
+        0x6000_0000_0000_0000,
+        0x0000_0000_0030_0000,
+        0x8000_0000_000F_FF00,
+        0x6000_0C02_0008_0000,
+        0x242C_0400_0010_4030,
+        0x0000_0100_0000_0C20,
+        0x0000_0000_00B8_5000,
+        0x8001_0000_00E0_0000,
+        0x0000_0000_1800_0000,
+        0x0000_0000_0021_0000,
+        0x0000_0000_0000_0010,
+        0x0000_0000_0000_8000,
+        0x0000_0000_07FE_4000,
+        0xFFFF_FFC0_0000_0000,
+        0x077C_8000_0400_0002,
+        0x0000_0000_0003_0000,
+        0xFFBF_E001_FFFF_FFFF,
+        0xDFFF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_000F_FFFF,
+        0xFF11_FFFF_000F_87FF,
+        0x7FFF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FFFD,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0x9FFF_FFFF_FFFF_FFFF,
+        0x3FFF_FFFF_FFFF_FFFF,
+        0x0400_00FF_FFFF_7800,
+        0x0000_0010_0060_0000,
+        0xF800_0000_0000_0000,
+        0x0000_0000_0000_FFFF,
+        0x1FF0_1800_F1E7_103F,
+        0x0001_0FFF_0000_0000,
+        0xF7FF_FFFF_FFFF_F000,
+        0xFFFF_FFFF_FFFF_FFBF,
+        0x1FFF_0000_0000_0000,
+        0xFFFF_FFFF_FFFF_C7FF,
+        0x01FF_87FF_9FFF_E17F,
+    ],
+};
+
+/// Codepoints with the `Emoji_Modifier_Base` property
+pub(crate) static EMOJI_MODIFIER_BASE: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 2, 3,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 5, 0, 6, 7, 0, 0, 8, 9, 0, 0, 10, 11, 12,
+            0, 0, 0, 0, 0, 0, 0, 0, 13, 14, 15, 16, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+    ],
+    leaves: &[
+        0x0000_0000_0000_0000,
+        0x0000_0000_2000_0000,
+        0x0200_0000_0000_0000,
+        0x0000_0000_0000_3C00,
+        0x0000_0000_0000_0020,
+        0x0000_0000_0000_1C9C,
+        0x11FF_FFC0_0001_FFCC,
+        0x0000_0400_0002_80EE,
+        0x0430_0000_0000_0000,
+        0x0000_0000_0061_0000,
+        0x0000_0000_0000_F8E0,
+        0x0070_0008_0000_0000,
+        0x0000_0000_0000_1001,
+        0x73FF_0040_FF00_9000,
+        0x0080_0000_0000_0000,
+        0x0B60_0000_0000_0000,
+        0x0000_0000_3FFE_E000,
+        0x01FF_0000_0000_0038,
+    ],
+};
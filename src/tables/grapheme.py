@@ -0,0 +1,71 @@
+"""Generates src/tables/grapheme.rs: the properties extended grapheme cluster segmentation (UAX #29)
+is defined in terms of.
+
+    python3 src/tables/grapheme.py <path to UCD> > src/tables/grapheme.rs
+"""
+
+from ucd import CODEPOINTS, binary_property, bool_trie, enumerated_property, header, parse_lines, parse_range, ucd_dir, value_trie
+
+# short alias to `GraphemeBreak` variant, by the long alias used in GraphemeBreakProperty.txt
+GRAPHEME_BREAKS = {
+    "Other": ("XX", "Other"),
+    "CR": ("CR", "CR"),
+    "LF": ("LF", "LF"),
+    "Control": ("CN", "Control"),
+    "Extend": ("EX", "Extend"),
+    "ZWJ": ("ZWJ", "ZWJ"),
+    "Regional_Indicator": ("RI", "RegionalIndicator"),
+    "Prepend": ("PP", "Prepend"),
+    "SpacingMark": ("SM", "SpacingMark"),
+    "L": ("L", "L"),
+    "V": ("V", "V"),
+    "T": ("T", "T"),
+    "LV": ("LV", "LV"),
+    "LVT": ("LVT", "LVT"),
+}
+
+# `IndicConjunctBreak` variant by value of the `InCB` property
+INDIC_CONJUNCT_BREAKS = {
+    "None": "None",
+    "Consonant": "Consonant",
+    "Extend": "Extend",
+    "Linker": "Linker",
+}
+
+
+def main() -> None:
+    ucd = ucd_dir()
+
+    breaks = enumerated_property(ucd / "auxiliary/GraphemeBreakProperty.txt", "Other")
+
+    # InCB is the only enumerated property in DerivedCoreProperties.txt, as `range ; InCB ; value`
+    indic = ["None"] * CODEPOINTS
+
+    for fields in parse_lines(ucd / "DerivedCoreProperties.txt"):
+        if fields[1] == "InCB":
+            for cp in parse_range(fields[0]):
+                indic[cp] = fields[2]
+
+    pictographic = binary_property(ucd / "emoji/emoji-data.txt", "Extended_Pictographic")
+
+    print(header("grapheme.py", ["auxiliary/GraphemeBreakProperty.txt", "DerivedCoreProperties.txt", "emoji/emoji-data.txt"]))
+    print("use super::Utf8Trie;")
+    print("use crate::grapheme::{GraphemeBreak, IndicConjunctBreak};")
+    print()
+
+    for short, variant in GRAPHEME_BREAKS.values():
+        print(f"/// `GraphemeBreak::{variant}`, shortened to keep the table below readable")
+        print(f"const {short}: GraphemeBreak = GraphemeBreak::{variant};")
+
+    for value, variant in INDIC_CONJUNCT_BREAKS.items():
+        print(f"/// `IndicConjunctBreak::{variant}`, shortened to keep the table below readable")
+        print(f"const {value.upper()}: IndicConjunctBreak = IndicConjunctBreak::{variant};")
+
+    print()
+    print(value_trie("GRAPHEME_BREAK", "The `Grapheme_Cluster_Break` of every codepoint", "GraphemeBreak", [GRAPHEME_BREAKS[b][0] for b in breaks]))
+    print(value_trie("INDIC_CONJUNCT_BREAK", "The `Indic_Conjunct_Break` of every codepoint", "IndicConjunctBreak", [i.upper() for i in indic]))
+    print(bool_trie("EXTENDED_PICTOGRAPHIC", "Codepoints with the `Extended_Pictographic` property", pictographic), end="")
+
+
+if __name__ == "__main__":
+    main()
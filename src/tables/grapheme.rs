@@ -0,0 +1,2586 @@
+//! Generated by `src/tables/grapheme.py` from the Unicode 17.0.0 files
+//! auxiliary/GraphemeBreakProperty.txt, DerivedCoreProperties.txt, emoji/emoji-data.txt. Do not edit by hand.
+
+use super::Utf8Trie;
+use crate::grapheme::{GraphemeBreak, IndicConjunctBreak};
+
+/// `GraphemeBreak::Other`, shortened to keep the table below readable
+const XX: GraphemeBreak = GraphemeBreak::Other;
+/// `GraphemeBreak::CR`, shortened to keep the table below readable
+const CR: GraphemeBreak = GraphemeBreak::CR;
+/// `GraphemeBreak::LF`, shortened to keep the table below readable
+const LF: GraphemeBreak = GraphemeBreak::LF;
+/// `GraphemeBreak::Control`, shortened to keep the table below readable
+const CN: GraphemeBreak = GraphemeBreak::Control;
+/// `GraphemeBreak::Extend`, shortened to keep the table below readable
+const EX: GraphemeBreak = GraphemeBreak::Extend;
+/// `GraphemeBreak::ZWJ`, shortened to keep the table below readable
+const ZWJ: GraphemeBreak = GraphemeBreak::ZWJ;
+/// `GraphemeBreak::RegionalIndicator`, shortened to keep the table below readable
+const RI: GraphemeBreak = GraphemeBreak::RegionalIndicator;
+/// `GraphemeBreak::Prepend`, shortened to keep the table below readable
+const PP: GraphemeBreak = GraphemeBreak::Prepend;
+/// `GraphemeBreak::SpacingMark`, shortened to keep the table below readable
+const SM: GraphemeBreak = GraphemeBreak::SpacingMark;
+/// `GraphemeBreak::L`, shortened to keep the table below readable
+const L: GraphemeBreak = GraphemeBreak::L;
+/// `GraphemeBreak::V`, shortened to keep the table below readable
+const V: GraphemeBreak = GraphemeBreak::V;
+/// `GraphemeBreak::T`, shortened to keep the table below readable
+const T: GraphemeBreak = GraphemeBreak::T;
+/// `GraphemeBreak::LV`, shortened to keep the table below readable
+const LV: GraphemeBreak = GraphemeBreak::LV;
+/// `GraphemeBreak::LVT`, shortened to keep the table below readable
+const LVT: GraphemeBreak = GraphemeBreak::LVT;
+/// `IndicConjunctBreak::None`, shortened to keep the table below readable
+const NONE: IndicConjunctBreak = IndicConjunctBreak::None;
+/// `IndicConjunctBreak::Consonant`, shortened to keep the table below readable
+const CONSONANT: IndicConjunctBreak = IndicConjunctBreak::Consonant;
+/// `IndicConjunctBreak::Extend`, shortened to keep the table below readable
+const EXTEND: IndicConjunctBreak = IndicConjunctBreak::Extend;
+/// `IndicConjunctBreak::Linker`, shortened to keep the table below readable
+const LINKER: IndicConjunctBreak = IndicConjunctBreak::Linker;
+
+/// The `Grapheme_Cluster_Break` of every codepoint
+pub(crate) static GRAPHEME_BREAK: Utf8Trie<[GraphemeBreak; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 3, 3, 3, 3, 3, 3, 3, 3, 4, 5, 3, 3, 3, 3, 6, 3, 3, 3, 7, 8, 9,
+        10, 3, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 6, 7, 8, 4, 9, 19, 20, 20, 22,
+        24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 3, 3, 3, 3, 3, 3, 3, 3, 4, 5, 3, 3, 3, 3, 6, 3, 3, 3, 7, 8, 9, 10, 3, 11,
+            12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 24, 26, 27, 28, 29, 30, 31, 32,
+            33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46,
+        ],
+        [
+            47, 48, 49, 3, 50, 51, 52, 53, 3, 3, 3, 3, 3, 54, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 55, 56, 57, 58, 59, 3, 60, 3, 61, 3, 3, 3, 62, 63, 64, 65, 66, 67, 68, 69, 70, 3, 3,
+            71, 3, 3, 3, 4, 3, 3, 3, 3, 3, 3, 3, 3,
+        ],
+        [
+            72, 73, 3, 74, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 75, 3, 76, 3, 77,
+            3, 3, 3, 3, 3, 3, 3, 3,
+        ],
+        [
+            78, 3, 79, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 80, 81, 82,
+            3, 3, 3, 3, 83, 3, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 3, 3, 3, 94, 95, 96, 97, 98,
+            99, 100, 101, 95, 96, 97, 98, 99, 100, 101, 95, 96,
+        ],
+        [
+            97, 98, 99, 100, 101, 95, 96, 97, 98, 99, 100, 101, 95, 96, 97, 98, 99, 100, 101, 95,
+            96, 97, 98, 99, 100, 101, 95, 96, 97, 98, 99, 100, 101, 95, 96, 97, 98, 99, 100, 101,
+            95, 96, 97, 98, 99, 100, 101, 95, 96, 97, 98, 99, 100, 101, 95, 96, 97, 98, 99, 100,
+            101, 95, 96, 97,
+        ],
+        [
+            98, 99, 100, 101, 95, 96, 97, 98, 99, 100, 101, 95, 96, 97, 98, 99, 100, 101, 95, 96,
+            97, 98, 99, 100, 101, 95, 96, 97, 98, 99, 100, 101, 95, 96, 97, 98, 99, 100, 101, 95,
+            96, 97, 98, 99, 100, 101, 95, 96, 97, 98, 99, 100, 101, 95, 96, 97, 98, 99, 100, 101,
+            95, 96, 97, 98,
+        ],
+        [
+            99, 100, 101, 95, 96, 97, 98, 99, 100, 101, 95, 96, 97, 98, 99, 100, 101, 95, 96, 97,
+            98, 99, 100, 101, 95, 96, 97, 98, 99, 100, 102, 103, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 104, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 105,
+            3, 3, 1, 3, 3, 81, 106,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 107, 3, 3, 3, 108, 3, 109, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 110, 3, 3, 111, 3, 3, 3, 3, 3, 3, 3, 3, 112, 113,
+            3, 3, 3, 3, 114, 115, 3, 116, 117, 3,
+        ],
+        [
+            118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 3, 128, 35, 129, 130, 131, 132, 133,
+            134, 135, 3, 3, 136, 137, 138, 139, 140, 3, 141, 3, 3, 3, 142, 3, 3, 3, 143, 144, 3,
+            145, 146, 147, 148, 3, 3, 149, 3, 3, 150, 3, 151, 3, 152, 153, 154, 3, 3, 3, 3, 155,
+            156, 157, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 158, 159, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 160, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 161, 162, 3, 3, 3, 3, 3, 3, 3, 3, 163, 3, 3,
+            3, 3, 3, 3, 3, 164, 165, 166,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 167, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 168, 169, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 170, 171, 3, 3, 172, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 173, 174, 175, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+        ],
+        [
+            176, 3, 177, 3, 162, 3, 3, 3, 3, 3, 178, 179, 3, 3, 3, 3, 3, 3, 3, 179, 3, 3, 3, 180, 3,
+            3, 3, 181, 3, 3, 3, 3, 3, 3, 3, 182, 3, 183, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 184, 3, 3, 3, 3, 3, 3, 3, 185, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 6, 7, 8, 4, 9, 10, 11, 4, 12, 4, 4, 13, 4, 4, 4, 4, 14,
+            15, 16, 17, 18, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            186, 4, 187, 187, 4, 4, 4, 188, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187,
+            187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187,
+            187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187,
+            187, 187, 187, 187, 187, 187, 187, 187, 187, 187, 187,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 21, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23,
+            23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23,
+            23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23,
+        ],
+    ],
+    leaves: &[
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LF, CN, CN, CR, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, CN, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, EX,
+        ],
+        [
+            XX, EX, EX, XX, EX, EX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            PP, PP, PP, PP, PP, PP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, XX, CN, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            EX, EX, EX, EX, EX, EX, EX, PP, XX, EX, EX, EX, EX, EX, EX, XX, XX, EX, EX, XX, EX, EX,
+            EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, PP, XX, EX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            EX, EX, EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, EX, EX, EX, XX, EX, EX, EX,
+            EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, PP, PP, XX, XX, XX, XX,
+            XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, PP, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM, EX, XX, SM, SM,
+        ],
+        [
+            SM, EX, EX, EX, EX, EX, EX, EX, EX, SM, SM, SM, SM, EX, SM, SM, XX, EX, EX, EX, EX, EX,
+            EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, EX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, EX, SM,
+        ],
+        [
+            SM, EX, EX, EX, EX, XX, XX, SM, SM, XX, XX, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX,
+        ],
+        [
+            XX, EX, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, SM, SM,
+        ],
+        [
+            SM, EX, EX, XX, XX, XX, XX, EX, EX, XX, XX, EX, EX, EX, XX, XX, XX, EX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EX, EX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            SM, EX, EX, EX, EX, EX, XX, EX, EX, SM, XX, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            XX, EX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, EX, EX,
+        ],
+        [
+            SM, EX, EX, EX, EX, XX, XX, SM, SM, XX, XX, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, EX,
+            EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM,
+        ],
+        [
+            EX, SM, SM, XX, XX, XX, SM, SM, SM, XX, SM, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, SM, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, EX, EX,
+        ],
+        [
+            EX, SM, SM, SM, SM, XX, EX, EX, EX, XX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, EX,
+            EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, EX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, SM, EX,
+        ],
+        [
+            EX, SM, EX, SM, SM, XX, EX, EX, EX, XX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, EX,
+            EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, EX, SM,
+        ],
+        [
+            SM, EX, EX, EX, EX, XX, SM, SM, SM, XX, SM, SM, SM, EX, PP, XX, XX, XX, XX, XX, XX, XX,
+            XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, EX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, EX, SM, SM, EX, EX, EX, XX,
+            EX, XX, SM, SM, SM, SM, SM, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, EX, XX, SM, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, EX, XX, SM, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, EX, XX, EX, XX, XX, XX, XX, SM, SM,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, SM,
+        ],
+        [
+            EX, EX, EX, EX, EX, XX, EX, EX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, EX, EX, EX, EX, SM, EX, EX, EX, EX, EX, EX, XX, EX, EX, SM, SM, EX, EX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            SM, SM, EX, EX, XX, XX, XX, XX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, EX, XX, SM, EX, EX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L,
+        ],
+        [
+            L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V,
+            V, V, V, V, V, V,
+        ],
+        [
+            V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V,
+            V, V, V, V, V, V, V, V, V, V, V, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+            T, T, T, T, T, T,
+        ],
+        [
+            T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+            T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+            T, T, T, T, T, T,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, SM, EX, EX, EX, EX, EX, EX, EX, SM, SM,
+        ],
+        [
+            SM, SM, SM, SM, SM, SM, EX, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, CN, EX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, SM, SM, SM, SM, EX, EX, SM, SM, SM,
+            XX, XX, XX, XX, SM, SM, EX, SM, SM, SM, SM, SM, SM, EX, EX, EX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, EX, EX, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM,
+            EX, SM, EX, EX, EX, EX, EX, EX, EX, XX, EX, XX, EX, XX, XX, EX, EX, EX, EX, EX, EX, EX,
+            EX, SM, SM, SM, SM, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, EX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, SM, SM,
+        ],
+        [
+            SM, SM, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, EX, EX, EX, EX, SM, SM, EX, EX, EX, EX,
+            EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM, EX, EX, SM, SM,
+            SM, EX, SM, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, SM, SM, SM, SM, SM, SM,
+            EX, EX, EX, EX, EX, EX, EX, EX, SM, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, XX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, SM, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX,
+            XX, EX, XX, XX, XX, XX, XX, XX, EX, XX, XX, SM, EX, EX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CN, EX, ZWJ, CN, CN, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CN, CN, CN, CN,
+            CN, CN, CN, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX,
+            EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, EX, EX, EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, EX, XX, XX, XX, EX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, EX, EX, SM, XX, XX, XX, XX,
+            EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+        ],
+        [
+            SM, SM, SM, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX,
+            EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, SM, EX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+            L, L, L, L, L, L, L, L, L, L, L, L, L, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, EX, SM, SM, EX, EX, EX, EX, SM, SM, EX, EX, SM, SM,
+        ],
+        [
+            EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX,
+            EX, EX, EX, SM, SM, EX, EX, SM, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EX, XX, EX, EX, EX, XX, XX, EX, EX, XX, XX, XX, XX, XX, EX, EX,
+        ],
+        [
+            XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM,
+            EX, EX, SM, SM, XX, XX, XX, XX, XX, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, EX, SM, SM, EX, SM, SM, XX,
+            SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+        ],
+        [
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+        ],
+        [
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+        ],
+        [
+            LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+        ],
+        [
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+        ],
+        [
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+        ],
+        [
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+        ],
+        [
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+            LVT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, V, V, V, V, V, V, V, V, V, V, V, V,
+            V, V, V, V,
+        ],
+        [
+            V, V, V, V, V, V, V, XX, XX, XX, XX, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+            T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+            T, T, T, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, EX, EX, EX, XX, EX, EX, XX, XX, XX, XX, XX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, XX, XX, XX, XX, EX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX,
+            EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+            EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            SM, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+        ],
+        [
+            EX, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, SM, SM, SM, EX, EX, EX, EX, SM, SM, EX, EX, XX, XX, PP, XX, XX,
+        ],
+        [
+            XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, PP, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX,
+            SM, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, SM, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX, EX, SM,
+        ],
+        [
+            EX, XX, PP, PP, XX, XX, XX, XX, XX, EX, EX, EX, EX, XX, SM, EX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            SM, SM, SM, EX, EX, EX, SM, SM, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, EX, XX,
+        ],
+        [
+            XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, SM, SM, SM, SM, XX, XX, SM, SM, XX, XX, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, XX, XX, EX, EX, EX, EX, EX, EX,
+            EX, XX, XX, XX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM, SM, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, XX, EX, XX, XX, EX, XX, EX, EX, EX, SM, XX, SM, SM, EX, EX, EX, PP, EX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            SM, SM, EX, EX, EX, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EX, SM, SM, EX, EX, EX, EX, EX, EX, SM, EX, SM, SM, EX, SM, EX,
+        ],
+        [
+            EX, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, EX, SM, SM, EX, EX, EX, EX, XX, XX, SM, SM, SM, SM, EX, EX, SM, EX,
+        ],
+        [
+            EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, SM, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX, SM, SM, EX, SM, EX,
+        ],
+        [
+            EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+            SM, EX, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, EX, SM, EX, XX, XX, EX, EX, EX, EX, SM, EX, EX, EX, EX, EX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            SM, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX, EX, SM, EX, EX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EX, SM, SM, SM, SM, SM, XX, SM, SM, XX, XX, EX, EX, EX, EX, PP,
+        ],
+        [
+            SM, PP, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, SM, EX, EX,
+            EX, EX, XX, XX, EX, EX, SM, SM, SM, SM, EX, XX, XX, XX, SM, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, SM, XX, EX, EX, EX, EX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX,
+            EX, SM, SM, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, PP, PP, PP, PP, PP, PP, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM, EX, EX, EX, SM, EX, SM, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, SM, EX, EX, EX, EX, EX, EX, EX, XX, EX, EX, EX, EX, EX, EX, SM, EX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, SM, EX, EX,
+            EX, EX, EX, EX, EX, SM, EX, EX, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, XX, XX, XX, EX, XX, EX, EX, XX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, PP, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, SM, SM, SM, XX, EX, EX, XX, SM, SM, EX,
+            SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, EX, EX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, PP, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, EX, EX, EX, EX, EX, XX, XX, XX, SM, SM,
+        ],
+        [
+            EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            EX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, SM, SM,
+            SM, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, V, XX, XX, XX, V, V, V, V, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, SM, SM, SM, SM, SM,
+            SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+            SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+        ],
+        [
+            SM, SM, SM, SM, SM, SM, SM, SM, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, CN, CN, CN, CN, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, XX, XX,
+            XX, EX, EX, EX, EX, EX, EX, CN, CN, CN, CN, CN, CN, CN, CN, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX,
+            EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX, EX, EX, XX, EX, EX, EX, EX, EX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, EX, XX, XX, XX, XX, XX,
+            XX, XX, EX, EX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX,
+            EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, RI, RI, RI, RI, RI, RI,
+            RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+    ],
+};
+
+/// The `Indic_Conjunct_Break` of every codepoint
+pub(crate) static INDIC_CONJUNCT_BREAK: Utf8Trie<[IndicConjunctBreak; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 3, 0, 0, 0, 4, 5, 6,
+        7, 0, 8, 9, 10, 11, 12, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 6, 16, 17, 17, 19, 17,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 3, 0, 0, 0, 4, 5, 6, 7, 0, 8, 9,
+            10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+            32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44,
+        ],
+        [
+            45, 46, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            49, 50, 51, 52, 53, 0, 54, 0, 55, 0, 0, 0, 56, 57, 58, 59, 60, 61, 62, 63, 64, 0, 0, 65,
+            0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            66, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 69, 0, 70, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            71, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 74, 75,
+            0, 0, 0, 0, 76, 0, 0, 77, 78, 79, 80, 81, 82, 83, 84, 85, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 0,
+            0, 0, 0, 0, 74, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 90, 0, 91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 94, 95, 0, 0,
+            0, 0, 96, 97, 0, 98, 99, 0,
+        ],
+        [
+            100, 101, 102, 103, 104, 105, 106, 107, 108, 35, 0, 109, 110, 111, 112, 113, 114, 115,
+            116, 117, 0, 0, 118, 119, 120, 121, 122, 0, 123, 0, 0, 0, 124, 0, 0, 0, 125, 126, 0,
+            127, 128, 129, 130, 0, 0, 131, 0, 0, 132, 0, 133, 0, 134, 135, 136, 0, 0, 0, 0, 137,
+            138, 139, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 142, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 144, 145, 146,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 148, 149, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 150, 151, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 154, 155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            156, 0, 144, 0, 143, 0, 0, 0, 0, 0, 157, 158, 0, 0, 0, 0, 0, 0, 0, 158, 0, 0, 0, 159, 0,
+            0, 0, 160, 0, 0, 0, 0, 0, 0, 0, 161, 0, 162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 6, 7, 8, 4, 9, 4, 4, 10, 4, 4, 4, 4, 11,
+            12, 13, 14, 15, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            70, 1, 0, 0, 1, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 18, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+    ],
+    leaves: &[
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, NONE, EXTEND,
+        ],
+        [
+            NONE, EXTEND, EXTEND, NONE, EXTEND, EXTEND, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE,
+            EXTEND, EXTEND, NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, EXTEND,
+            EXTEND, EXTEND, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND,
+            EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, EXTEND, NONE, EXTEND, NONE, NONE, NONE,
+        ],
+        [
+            NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE,
+            NONE, LINKER, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+        ],
+        [
+            NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE,
+            CONSONANT, NONE, NONE, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE, NONE,
+            EXTEND, NONE, EXTEND, NONE,
+        ],
+        [
+            NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            LINKER, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE,
+            NONE, CONSONANT, CONSONANT, NONE, CONSONANT, NONE, NONE, EXTEND, EXTEND, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, CONSONANT, CONSONANT, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE,
+        ],
+        [
+            NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE,
+        ],
+        [
+            NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, EXTEND,
+            EXTEND, EXTEND, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE,
+            EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE,
+            CONSONANT, CONSONANT, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE,
+            NONE, EXTEND, NONE, NONE, NONE,
+        ],
+        [
+            NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, EXTEND, EXTEND, NONE, NONE, NONE,
+            NONE, LINKER, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, CONSONANT, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+        ],
+        [
+            NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE,
+            CONSONANT, CONSONANT, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE,
+            NONE, EXTEND, NONE, EXTEND, EXTEND,
+        ],
+        [
+            NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            LINKER, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, NONE, NONE,
+            NONE, NONE, CONSONANT, CONSONANT, NONE, CONSONANT, NONE, NONE, EXTEND, EXTEND, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, CONSONANT, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE,
+        ],
+        [
+            EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, NONE, NONE, EXTEND, NONE, EXTEND, EXTEND,
+        ],
+        [
+            EXTEND, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, NONE, EXTEND, EXTEND,
+            EXTEND, LINKER, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE,
+            CONSONANT, CONSONANT, CONSONANT, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND,
+            EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE,
+        ],
+        [
+            NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, EXTEND,
+        ],
+        [
+            EXTEND, NONE, EXTEND, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, NONE, EXTEND, EXTEND,
+            EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, EXTEND, EXTEND, NONE, EXTEND, NONE,
+        ],
+        [
+            NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            LINKER, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE,
+            NONE, EXTEND, NONE, NONE, EXTEND, EXTEND, EXTEND, NONE, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, EXTEND,
+            NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, LINKER, EXTEND, NONE, NONE,
+            EXTEND, EXTEND, CONSONANT,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE,
+            NONE, EXTEND, EXTEND, CONSONANT, CONSONANT, CONSONANT, CONSONANT, EXTEND, EXTEND,
+            EXTEND, CONSONANT, NONE, NONE, NONE, CONSONANT, CONSONANT, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, CONSONANT, CONSONANT, CONSONANT, EXTEND, EXTEND, EXTEND, EXTEND, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT,
+        ],
+        [
+            CONSONANT, CONSONANT, EXTEND, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE,
+            NONE, EXTEND, CONSONANT, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, EXTEND, EXTEND, NONE, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, LINKER, EXTEND, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND,
+            EXTEND, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, EXTEND, EXTEND,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE,
+            EXTEND, NONE, NONE, NONE, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT,
+        ],
+        [
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE, EXTEND, NONE, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, LINKER, NONE, EXTEND, NONE, NONE,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, NONE, NONE, EXTEND,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, CONSONANT,
+            CONSONANT, NONE, NONE, NONE, NONE, NONE, NONE, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE,
+        ],
+        [
+            NONE, NONE, EXTEND, EXTEND, LINKER, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, EXTEND, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, EXTEND, EXTEND, EXTEND, LINKER,
+            EXTEND, EXTEND, CONSONANT, CONSONANT, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, CONSONANT, CONSONANT, CONSONANT, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, EXTEND,
+            EXTEND, NONE, NONE, NONE, EXTEND, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE,
+            EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, EXTEND, EXTEND, EXTEND, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE,
+            NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, EXTEND, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, EXTEND, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE,
+            NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, CONSONANT, CONSONANT,
+            CONSONANT, NONE, NONE, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, EXTEND,
+            NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, EXTEND, EXTEND, NONE, NONE,
+        ],
+        [
+            LINKER, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, EXTEND,
+            NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, EXTEND, EXTEND, NONE, NONE, EXTEND,
+            EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, NONE, CONSONANT, CONSONANT, CONSONANT, NONE, NONE,
+            NONE, NONE, NONE, NONE, CONSONANT, NONE, EXTEND, NONE, CONSONANT, CONSONANT,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, EXTEND, EXTEND, EXTEND, NONE, NONE,
+            EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND,
+        ],
+        [
+            NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE, EXTEND, EXTEND,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, LINKER, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE,
+        ],
+        [
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, EXTEND, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            CONSONANT, EXTEND, EXTEND, EXTEND, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE,
+            EXTEND, EXTEND, EXTEND, EXTEND, CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE,
+            CONSONANT, CONSONANT, CONSONANT, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, NONE, NONE, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, LINKER,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, EXTEND, EXTEND,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND,
+        ],
+        [
+            EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND,
+            NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            LINKER, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, CONSONANT, NONE, NONE, CONSONANT, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE,
+        ],
+        [
+            EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND,
+            NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, NONE, NONE, EXTEND, EXTEND,
+            EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, EXTEND, EXTEND, NONE, EXTEND, NONE,
+        ],
+        [
+            EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, NONE, CONSONANT, NONE, NONE, CONSONANT, NONE, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE, NONE, EXTEND, NONE, NONE,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+        ],
+        [
+            EXTEND, NONE, EXTEND, NONE, NONE, EXTEND, NONE, EXTEND, EXTEND, EXTEND, NONE, NONE,
+            NONE, NONE, EXTEND, EXTEND, LINKER, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+        ],
+        [
+            NONE, NONE, EXTEND, EXTEND, EXTEND, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, NONE, EXTEND, NONE, NONE, EXTEND, NONE, EXTEND,
+        ],
+        [
+            EXTEND, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE,
+            NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, EXTEND,
+        ],
+        [
+            EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, EXTEND, NONE, EXTEND,
+        ],
+        [
+            EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, EXTEND, NONE, EXTEND, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, EXTEND, NONE, EXTEND, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE, NONE,
+            CONSONANT, NONE, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, NONE, CONSONANT, CONSONANT, NONE, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, LINKER, NONE,
+        ],
+        [
+            NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, EXTEND,
+            EXTEND, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            CONSONANT, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, EXTEND, EXTEND,
+            EXTEND, EXTEND, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, LINKER, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, CONSONANT, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE,
+            EXTEND, EXTEND, EXTEND, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+        ],
+        [
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, NONE, EXTEND, LINKER, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, NONE, EXTEND, EXTEND, EXTEND, NONE, EXTEND, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, EXTEND,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, NONE, EXTEND, EXTEND, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, NONE, NONE, NONE, EXTEND, NONE, EXTEND, EXTEND, NONE, EXTEND,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, EXTEND, NONE, EXTEND, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, EXTEND, NONE, NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            NONE, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT, CONSONANT,
+            CONSONANT, CONSONANT, CONSONANT, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, EXTEND, LINKER, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, EXTEND, EXTEND, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+            EXTEND, NONE, EXTEND, EXTEND, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE, NONE, EXTEND, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, EXTEND, EXTEND, NONE, NONE, NONE, NONE, NONE, EXTEND, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+        ],
+        [
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE, NONE,
+            NONE, NONE, NONE, EXTEND, EXTEND, EXTEND, EXTEND, EXTEND,
+        ],
+    ],
+};
+
+/// Codepoints with the `Extended_Pictographic` property
+pub(crate) static EXTENDED_PICTOGRAPHIC: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 5, 6, 6, 6, 6, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            2, 3, 0, 0, 4, 0, 5, 0, 0, 0, 0, 0, 6, 0, 0, 7, 0, 0, 0, 8, 0, 0, 9, 10, 11, 12, 13, 14,
+            15, 16, 17, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 19, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            23, 0, 24, 25, 0, 26, 27, 28, 29, 30, 31, 31, 32, 31, 33, 34, 31, 31, 31, 35, 36, 37,
+            38, 39, 31, 40, 31, 41, 0, 0, 0, 42, 43, 44, 45, 46, 47, 48, 31, 31, 0, 49, 31, 31, 0,
+            0, 0, 0, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 36,
+        ],
+        [
+            0, 1, 2, 3, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 4, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+        [
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+    ],
+    leaves: &[
+        0x0000_0000_0000_0000,
+        0x0000_4200_0000_0000,
+        0x1000_0000_0000_0000,
+        0x0000_0000_0000_0200,
+        0x0200_0004_0000_0000,
+        0x0000_0600_03F0_0000,
+        0x0000_0100_0C00_0000,
+        0x070F_FE00_0000_8000,
+        0x0000_0000_0000_0004,
+        0x0040_0C00_0000_0000,
+        0x7800_0000_0000_0001,
+        0x0700_C44D_2132_401F,
+        0xC800_0169_800F_FF05,
+        0x6003_0C83_1AFC_0000,
+        0x27BF_0600_001A_C130,
+        0x0018_0102_2054_BF24,
+        0x0000_0018_00B8_5090,
+        0x8001_0002_00E0_0000,
+        0x0030_0000_0000_0000,
+        0x0000_0000_1800_00E0,
+        0x0000_0000_0021_0000,
+        0x2001_0000_0000_0000,
+        0x0000_0000_0280_0000,
+        0x0000_F000_0000_0010,
+        0x0001_8000_FFF0_0000,
+        0xFFC0_0000_0001_8001,
+        0xC003_0000_0000_0000,
+        0xFFFF_C000_07FE_4000,
+        0x0000_003F_FFFF_FFFF,
+        0xF7FC_8000_0400_FFFE,
+        0xFFFF_FFC0_FFFF_FE00,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0xFFFF_FFF3_FFFF_FFFF,
+        0xFFFF_FFFF_CECF_FFFF,
+        0x07B9_FFFF_FFFF_FFFF,
+        0xBFFF_FFFF_FFFF_FFFF,
+        0x3FFF_FFFF_FFFF_FFFF,
+        0x07F9_80FF_FFFF_7E00,
+        0x1006_0130_0061_3C80,
+        0xFC08_810A_700E_001C,
+        0x0000_0000_0000_FFFF,
+        0xFFF9_FA3F_FFE7_F83F,
+        0xFFFF_FFFF_FC00_0000,
+        0x0000_0000_0000_F000,
+        0x0000_0000_FC00_FF00,
+        0xF000_C000_0000_FF00,
+        0xFFFF_FFFF_FE00_FFFC,
+        0xF7FF_FFFF_FFFF_F000,
+        0xFFFF_FFFF_FFFF_FFBF,
+        0xFFFF_C000_FF00_0000,
+    ],
+};
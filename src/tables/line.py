@@ -0,0 +1,120 @@
+"""Generates src/tables/line.rs: the properties the Unicode Line Breaking Algorithm (UAX #14) is
+defined in terms of.
+
+    python3 src/tables/line.py <path to UCD> > src/tables/line.rs
+"""
+
+from ucd import CODEPOINTS, bool_trie, header, parse_lines, parse_range, ucd_dir, value_trie
+
+# the `@missing` defaults of LineBreak.txt, unassigned codepoints in the ideograph blocks and planes
+# and in the pictographic blocks default to ID, those among the currency symbols to PR, the rest to XX
+MISSING = [
+    ("3400..4DBF", "ID"),
+    ("4E00..9FFF", "ID"),
+    ("F900..FAFF", "ID"),
+    ("20000..2FFFD", "ID"),
+    ("30000..3FFFD", "ID"),
+    ("1F000..1FAFF", "ID"),
+    ("1FC00..1FFFD", "ID"),
+    ("20A0..20CF", "PR"),
+]
+
+# the `@missing` defaults of EastAsianWidth.txt that are wide
+MISSING_WIDE = ["3400..4DBF", "4E00..9FFF", "F900..FAFF", "20000..2FFFD", "30000..3FFFD"]
+
+# `LineBreak` variant by short alias
+LINE_BREAKS = {
+    "BK": "MandatoryBreak",
+    "CR": "CarriageReturn",
+    "LF": "LineFeed",
+    "CM": "CombiningMark",
+    "NL": "NextLine",
+    "SG": "Surrogate",
+    "WJ": "WordJoiner",
+    "ZW": "ZWSpace",
+    "GL": "Glue",
+    "SP": "Space",
+    "ZWJ": "ZWJ",
+    "B2": "BreakBoth",
+    "BA": "BreakAfter",
+    "BB": "BreakBefore",
+    "HY": "Hyphen",
+    "HH": "UnambiguousHyphen",
+    "CB": "ContingentBreak",
+    "CL": "ClosePunctuation",
+    "CP": "CloseParenthesis",
+    "EX": "Exclamation",
+    "IN": "Inseparable",
+    "NS": "Nonstarter",
+    "OP": "OpenPunctuation",
+    "QU": "Quotation",
+    "IS": "InfixNumeric",
+    "NU": "Numeric",
+    "PO": "PostfixNumeric",
+    "PR": "PrefixNumeric",
+    "SY": "BreakSymbols",
+    "AI": "Ambiguous",
+    "AK": "Aksara",
+    "AL": "Alphabetic",
+    "AP": "AksaraPrebase",
+    "AS": "AksaraStart",
+    "CJ": "ConditionalJapaneseStarter",
+    "EB": "EBase",
+    "EM": "EModifier",
+    "H2": "H2",
+    "H3": "H3",
+    "HL": "HebrewLetter",
+    "ID": "Ideographic",
+    "JL": "JL",
+    "JV": "JV",
+    "JT": "JT",
+    "RI": "RegionalIndicator",
+    "SA": "ComplexContext",
+    "VF": "ViramaFinal",
+    "VI": "Virama",
+    "XX": "Unknown",
+}
+
+
+def main() -> None:
+    ucd = ucd_dir()
+
+    classes = ["XX"] * CODEPOINTS
+
+    for missing, value in MISSING:
+        for cp in parse_range(missing):
+            classes[cp] = value
+
+    for fields in parse_lines(ucd / "LineBreak.txt"):
+        for cp in parse_range(fields[0]):
+            classes[cp] = fields[1]
+
+    # `$EastAsian` of UAX #14, the fullwidth, wide and halfwidth codepoints. Unassigned codepoints
+    # default to wide in the ideograph blocks and planes, like in `width.py`
+    east_asian = [False] * CODEPOINTS
+
+    for missing in MISSING_WIDE:
+        for cp in parse_range(missing):
+            east_asian[cp] = True
+
+    for fields in parse_lines(ucd / "EastAsianWidth.txt"):
+        if fields[1] in ("F", "W", "H"):
+            for cp in parse_range(fields[0]):
+                east_asian[cp] = True
+
+    print(header("line.py", ["LineBreak.txt", "EastAsianWidth.txt"]))
+    print("use super::Utf8Trie;")
+    print("use crate::line::LineBreak;")
+    print()
+
+    for short, variant in LINE_BREAKS.items():
+        print(f"/// `LineBreak::{variant}`, shortened to keep the table below readable")
+        print(f"const {short}: LineBreak = LineBreak::{variant};")
+
+    print()
+    print(value_trie("LINE_BREAK", "The `Line_Break` class of every codepoint", "LineBreak", classes))
+    print(bool_trie("EAST_ASIAN", "Codepoints with an `East_Asian_Width` of `F`, `W` or `H`", east_asian), end="")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,2487 @@
+//! Generated by `src/tables/line.py` from the Unicode 17.0.0 files
+//! LineBreak.txt, EastAsianWidth.txt. Do not edit by hand.
+
+use super::Utf8Trie;
+use crate::line::LineBreak;
+
+/// `LineBreak::MandatoryBreak`, shortened to keep the table below readable
+const BK: LineBreak = LineBreak::MandatoryBreak;
+/// `LineBreak::CarriageReturn`, shortened to keep the table below readable
+const CR: LineBreak = LineBreak::CarriageReturn;
+/// `LineBreak::LineFeed`, shortened to keep the table below readable
+const LF: LineBreak = LineBreak::LineFeed;
+/// `LineBreak::CombiningMark`, shortened to keep the table below readable
+const CM: LineBreak = LineBreak::CombiningMark;
+/// `LineBreak::NextLine`, shortened to keep the table below readable
+const NL: LineBreak = LineBreak::NextLine;
+/// `LineBreak::Surrogate`, shortened to keep the table below readable
+const SG: LineBreak = LineBreak::Surrogate;
+/// `LineBreak::WordJoiner`, shortened to keep the table below readable
+const WJ: LineBreak = LineBreak::WordJoiner;
+/// `LineBreak::ZWSpace`, shortened to keep the table below readable
+const ZW: LineBreak = LineBreak::ZWSpace;
+/// `LineBreak::Glue`, shortened to keep the table below readable
+const GL: LineBreak = LineBreak::Glue;
+/// `LineBreak::Space`, shortened to keep the table below readable
+const SP: LineBreak = LineBreak::Space;
+/// `LineBreak::ZWJ`, shortened to keep the table below readable
+const ZWJ: LineBreak = LineBreak::ZWJ;
+/// `LineBreak::BreakBoth`, shortened to keep the table below readable
+const B2: LineBreak = LineBreak::BreakBoth;
+/// `LineBreak::BreakAfter`, shortened to keep the table below readable
+const BA: LineBreak = LineBreak::BreakAfter;
+/// `LineBreak::BreakBefore`, shortened to keep the table below readable
+const BB: LineBreak = LineBreak::BreakBefore;
+/// `LineBreak::Hyphen`, shortened to keep the table below readable
+const HY: LineBreak = LineBreak::Hyphen;
+/// `LineBreak::UnambiguousHyphen`, shortened to keep the table below readable
+const HH: LineBreak = LineBreak::UnambiguousHyphen;
+/// `LineBreak::ContingentBreak`, shortened to keep the table below readable
+const CB: LineBreak = LineBreak::ContingentBreak;
+/// `LineBreak::ClosePunctuation`, shortened to keep the table below readable
+const CL: LineBreak = LineBreak::ClosePunctuation;
+/// `LineBreak::CloseParenthesis`, shortened to keep the table below readable
+const CP: LineBreak = LineBreak::CloseParenthesis;
+/// `LineBreak::Exclamation`, shortened to keep the table below readable
+const EX: LineBreak = LineBreak::Exclamation;
+/// `LineBreak::Inseparable`, shortened to keep the table below readable
+const IN: LineBreak = LineBreak::Inseparable;
+/// `LineBreak::Nonstarter`, shortened to keep the table below readable
+const NS: LineBreak = LineBreak::Nonstarter;
+/// `LineBreak::OpenPunctuation`, shortened to keep the table below readable
+const OP: LineBreak = LineBreak::OpenPunctuation;
+/// `LineBreak::Quotation`, shortened to keep the table below readable
+const QU: LineBreak = LineBreak::Quotation;
+/// `LineBreak::InfixNumeric`, shortened to keep the table below readable
+const IS: LineBreak = LineBreak::InfixNumeric;
+/// `LineBreak::Numeric`, shortened to keep the table below readable
+const NU: LineBreak = LineBreak::Numeric;
+/// `LineBreak::PostfixNumeric`, shortened to keep the table below readable
+const PO: LineBreak = LineBreak::PostfixNumeric;
+/// `LineBreak::PrefixNumeric`, shortened to keep the table below readable
+const PR: LineBreak = LineBreak::PrefixNumeric;
+/// `LineBreak::BreakSymbols`, shortened to keep the table below readable
+const SY: LineBreak = LineBreak::BreakSymbols;
+/// `LineBreak::Ambiguous`, shortened to keep the table below readable
+const AI: LineBreak = LineBreak::Ambiguous;
+/// `LineBreak::Aksara`, shortened to keep the table below readable
+const AK: LineBreak = LineBreak::Aksara;
+/// `LineBreak::Alphabetic`, shortened to keep the table below readable
+const AL: LineBreak = LineBreak::Alphabetic;
+/// `LineBreak::AksaraPrebase`, shortened to keep the table below readable
+const AP: LineBreak = LineBreak::AksaraPrebase;
+/// `LineBreak::AksaraStart`, shortened to keep the table below readable
+const AS: LineBreak = LineBreak::AksaraStart;
+/// `LineBreak::ConditionalJapaneseStarter`, shortened to keep the table below readable
+const CJ: LineBreak = LineBreak::ConditionalJapaneseStarter;
+/// `LineBreak::EBase`, shortened to keep the table below readable
+const EB: LineBreak = LineBreak::EBase;
+/// `LineBreak::EModifier`, shortened to keep the table below readable
+const EM: LineBreak = LineBreak::EModifier;
+/// `LineBreak::H2`, shortened to keep the table below readable
+const H2: LineBreak = LineBreak::H2;
+/// `LineBreak::H3`, shortened to keep the table below readable
+const H3: LineBreak = LineBreak::H3;
+/// `LineBreak::HebrewLetter`, shortened to keep the table below readable
+const HL: LineBreak = LineBreak::HebrewLetter;
+/// `LineBreak::Ideographic`, shortened to keep the table below readable
+const ID: LineBreak = LineBreak::Ideographic;
+/// `LineBreak::JL`, shortened to keep the table below readable
+const JL: LineBreak = LineBreak::JL;
+/// `LineBreak::JV`, shortened to keep the table below readable
+const JV: LineBreak = LineBreak::JV;
+/// `LineBreak::JT`, shortened to keep the table below readable
+const JT: LineBreak = LineBreak::JT;
+/// `LineBreak::RegionalIndicator`, shortened to keep the table below readable
+const RI: LineBreak = LineBreak::RegionalIndicator;
+/// `LineBreak::ComplexContext`, shortened to keep the table below readable
+const SA: LineBreak = LineBreak::ComplexContext;
+/// `LineBreak::ViramaFinal`, shortened to keep the table below readable
+const VF: LineBreak = LineBreak::ViramaFinal;
+/// `LineBreak::Virama`, shortened to keep the table below readable
+const VI: LineBreak = LineBreak::Virama;
+/// `LineBreak::Unknown`, shortened to keep the table below readable
+const XX: LineBreak = LineBreak::Unknown;
+
+/// The `Line_Break` class of every codepoint
+pub(crate) static LINE_BREAK: Utf8Trie<[LineBreak; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 4, 4, 4, 4, 4, 4, 5, 6, 7, 8, 4, 4, 4, 9, 4, 10, 11, 12,
+        13, 14, 15, 4, 16, 17, 18, 19, 20, 0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 7, 8, 9, 10, 11, 26, 27,
+        27, 29, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 5, 6, 7, 8, 4, 4, 4, 9, 4, 10, 11, 12, 13, 14, 15, 4,
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37,
+            38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52,
+        ],
+        [
+            53, 54, 55, 56, 57, 58, 59, 60, 4, 61, 62, 63, 64, 65, 66, 67, 68, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 69, 70, 71, 72, 53, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87,
+            88, 89, 90, 91, 92, 93, 4, 4, 4, 94, 4, 4, 4, 4, 95, 96, 97, 98,
+        ],
+        [
+            99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 4, 4, 112, 113, 114,
+            115, 116, 115, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127, 128, 4, 4, 4, 4,
+            4, 129, 130, 4, 4, 4, 4, 4, 131, 4, 4, 4, 4, 4, 132, 133, 134, 135, 136, 137, 138, 139,
+            140, 141, 141, 141, 142,
+        ],
+        [
+            143, 144, 145, 146, 147, 141, 148, 149, 150, 151, 141, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+        ],
+        [
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 4, 141, 141, 141, 141, 141, 141, 141, 141,
+        ],
+        [
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+        ],
+        [
+            152, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+            141, 153, 154, 4, 4, 4, 4, 155, 156, 157, 158, 4, 4, 4, 159, 160, 161, 162, 163, 164,
+            165, 166, 167, 168, 169, 53, 170, 171, 172, 4, 173, 174, 175, 176, 177, 178, 179, 180,
+            174, 175, 176, 177, 178, 179, 180, 174, 175,
+        ],
+        [
+            176, 177, 178, 179, 180, 174, 175, 176, 177, 178, 179, 180, 174, 175, 176, 177, 178,
+            179, 180, 174, 175, 176, 177, 178, 179, 180, 174, 175, 176, 177, 178, 179, 180, 174,
+            175, 176, 177, 178, 179, 180, 174, 175, 176, 177, 178, 179, 180, 174, 175, 176, 177,
+            178, 179, 180, 174, 175, 176, 177, 178, 179, 180, 174, 175, 176,
+        ],
+        [
+            177, 178, 179, 180, 174, 175, 176, 177, 178, 179, 180, 174, 175, 176, 177, 178, 179,
+            180, 174, 175, 176, 177, 178, 179, 180, 174, 175, 176, 177, 178, 179, 180, 174, 175,
+            176, 177, 178, 179, 180, 174, 175, 176, 177, 178, 179, 180, 174, 175, 176, 177, 178,
+            179, 180, 174, 175, 176, 177, 178, 179, 180, 174, 175, 176, 177,
+        ],
+        [
+            178, 179, 180, 174, 175, 176, 177, 178, 179, 180, 174, 175, 176, 177, 178, 179, 180,
+            174, 175, 176, 177, 178, 179, 180, 174, 175, 176, 177, 178, 179, 181, 182, 183, 183,
+            183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183,
+            183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183, 183,
+        ],
+        [
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+        ],
+        [
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 141, 141, 141, 141, 141, 141, 141, 141, 185, 186, 4, 4, 4, 4, 4, 4, 187, 4, 4,
+            188, 189, 190, 4, 191, 192, 193, 194, 195,
+        ],
+        [
+            196, 197, 4, 198, 199, 4, 200, 201, 184, 184, 202, 203, 204, 205, 206, 207, 4, 4, 208,
+            209, 210, 211, 212, 213, 4, 4, 4, 4, 214, 215, 216, 184, 217, 218, 219, 220, 221, 222,
+            223, 224, 225, 226, 227, 228, 229, 230, 231, 184, 4, 232, 233, 234, 235, 236, 237, 184,
+            184, 238, 239, 240, 210, 241, 242, 243,
+        ],
+        [
+            244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255, 256, 257, 258, 259, 260,
+            261, 262, 263, 184, 184, 264, 265, 262, 266, 267, 268, 269, 270, 184, 184, 271, 184,
+            272, 273, 274, 275, 276, 277, 278, 279, 280, 75, 281, 282, 184, 283, 284, 285, 286, 184,
+            287, 288, 289, 290, 184, 184, 184, 291, 292, 293, 294, 295,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 222, 184, 4, 296, 4, 4, 4, 297, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 298, 233,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 299, 300, 4, 4, 301, 4, 4, 302, 303, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 198, 4, 4, 4, 4, 4, 4, 4, 304, 4, 305, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184,
+        ],
+        [
+            184, 184, 184, 184, 306, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 4, 4, 4, 4,
+            4, 4, 4, 4, 75, 307, 308, 309, 310, 311, 312, 184, 184, 184, 184, 184, 184, 313, 184,
+            184, 184, 4, 314, 315, 4, 316, 317, 318,
+        ],
+        [
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 4, 4, 4, 4, 4, 4, 4, 319, 320, 184,
+            141, 321, 184, 184, 184, 184, 184, 184, 184, 184,
+        ],
+        [
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 322,
+        ],
+        [
+            141, 141, 141, 141, 323, 324, 141, 141, 141, 141, 141, 325, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 4, 326, 327, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+        ],
+        [
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 4, 4, 4, 328, 4,
+            4, 4, 4, 4, 4, 329, 330, 331, 332, 4, 297,
+        ],
+        [
+            4, 4, 4, 77, 333, 334, 335, 336, 4, 337, 184, 338, 4, 339, 184, 184, 4, 340, 341, 342,
+            343, 344, 4, 4, 4, 4, 345, 4, 4, 4, 4, 346, 4, 4, 4, 4, 4, 4, 4, 4, 347, 348, 349, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 350,
+            184, 184, 184,
+        ],
+        [
+            351, 352, 353, 184, 354, 355, 184, 184, 184, 184, 356, 357, 184, 184, 184, 184, 184,
+            184, 184, 358, 184, 184, 184, 359, 184, 184, 184, 360, 184, 184, 184, 361, 4, 4, 4, 362,
+            4, 363, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 364, 365, 184, 366, 184,
+            184, 184, 367, 368, 369, 370, 184, 184, 184, 184,
+        ],
+        [
+            141, 141, 141, 141, 371, 372, 373, 374, 141, 141, 141, 141, 141, 141, 375, 376, 141,
+            377, 378, 141, 379, 380, 381, 382, 141, 383, 384, 385, 4, 386, 4, 387, 388, 389, 390,
+            391, 392, 393, 394, 395, 4, 396, 141, 397, 4, 4, 398, 399, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 400,
+        ],
+        [
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141,
+            141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 141, 400,
+        ],
+        [
+            0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 10, 17, 5, 18, 10,
+            19, 20, 21, 22, 23, 24, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 25, 5, 5, 5, 5, 5,
+            5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 25,
+        ],
+        [
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+        ],
+        [
+            401, 6, 184, 184, 6, 6, 6, 402, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+            184, 184, 184, 184, 184, 184, 184, 184, 184, 184, 184,
+        ],
+        [
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 28, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+        ],
+    ],
+    leaves: &[
+        [
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, BA, LF, BK, BK, CR, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, SP, EX, QU, AL, PR, PO, AL, QU, OP, CP, AL, PR,
+            IS, HY, IS, SY, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, IS, IS, AL, AL, AL, EX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, OP, PR, CP, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, OP, BA, CL, AL, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, NL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, GL, OP, PO, PR, PR, PR, AL, AI, AI, AL, AI, QU,
+            AL, BA, AL, AL, PO, PR, AI, AI, BB, AL, AI, AI, AI, AI, AI, QU, AI, AI, AI, OP,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AI, BB, AI, AI, AI, BB, AI, AL, AL, AI, AL, AL, AL, AL, AL,
+            AL, AL, AI, AI, AI, AI, AL, AI, AL, BB, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, GL, GL, GL, GL, GL, GL, GL, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, IS, AL,
+        ],
+        [
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, IS, HH, XX, XX, AL, AL, PR, XX, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, HH, CM,
+        ],
+        [
+            AL, CM, CM, AL, CM, CM, EX, CM, XX, XX, XX, XX, XX, XX, XX, XX, HL, HL, HL, HL, HL, HL,
+            HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, XX,
+            XX, XX, XX, HL, HL, HL, HL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, AL, AL, AL, PO, PO, PO, IS, IS, AL, AL, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, EX, CM, EX, EX, EX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, PO, NU,
+            NU, AL, AL, AL, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, EX, AL,
+            CM, CM, CM, CM, CM, CM, CM, NU, AL, CM, CM, CM, CM, CM, CM, AL, AL, CM, CM, AL, CM, CM,
+            CM, CM, AL, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, CM, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, IS, EX, AL, XX, XX, CM, PR, PR,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            CM, CM, CM, CM, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, CM, CM, CM, AL, CM, CM, CM,
+            CM, CM, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, CM, CM, CM, XX, XX, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, NU, NU, XX, XX, XX, XX,
+            XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, NU, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, AL, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, CM, CM, CM, CM, CM,
+            CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, BA, BA, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, CM, CM, CM, XX, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, XX, XX, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL,
+            AL, AL, AL, AL, AL, XX, AL, XX, XX, XX, AL, AL, AL, AL, XX, XX, CM, AL, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, XX, XX, CM, CM, XX, XX, CM, CM, CM, AL, XX, XX, XX, XX, XX, XX, XX,
+            XX, CM, XX, XX, XX, XX, AL, AL, XX, AL, AL, AL, CM, CM, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, AL, AL, PO, PO, AL, AL, AL, AL, AL, PO, AL, PR, AL, AL, CM, XX,
+        ],
+        [
+            XX, CM, CM, CM, XX, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, AL, AL, XX, XX, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL,
+            AL, AL, AL, AL, AL, XX, AL, AL, XX, AL, AL, XX, AL, AL, XX, XX, CM, XX, CM, CM,
+        ],
+        [
+            CM, CM, CM, XX, XX, XX, XX, CM, CM, XX, XX, CM, CM, CM, XX, XX, XX, CM, XX, XX, XX, XX,
+            XX, XX, XX, AL, AL, AL, AL, XX, AL, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, CM, CM, AL, AL, AL, CM, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, CM, CM, CM, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, XX, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL,
+            AL, AL, AL, AL, AL, XX, AL, AL, XX, AL, AL, AL, AL, AL, XX, XX, CM, AL, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, XX, CM, CM, CM, XX, CM, CM, CM, XX, XX, AL, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, CM, CM, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, AL, PR, XX, XX, XX, XX, XX, XX, XX, AL, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            XX, CM, CM, CM, XX, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, XX, XX, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL,
+            AL, AL, AL, AL, AL, XX, AL, AL, XX, AL, AL, AL, AL, AL, XX, XX, CM, AL, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, XX, XX, CM, CM, XX, XX, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, CM,
+            CM, CM, XX, XX, XX, XX, AL, AL, XX, AL, AL, AL, CM, CM, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, CM, AL, XX, AL, AL, AL, AL, AL, AL, XX, XX, XX, AL, AL, AL, XX, AL, AL, AL, AL,
+            XX, XX, XX, AL, AL, XX, AL, XX, AL, AL, XX, XX, XX, AL, AL, XX, XX, XX, AL, AL, AL, XX,
+            XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, CM, CM,
+        ],
+        [
+            CM, CM, CM, XX, XX, XX, CM, CM, CM, XX, CM, CM, CM, CM, XX, XX, AL, XX, XX, XX, XX, XX,
+            XX, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, AL, AL, AL, AL, AL, AL, AL, AL, AL, PR, AL, XX, XX, XX, XX, XX,
+        ],
+        [
+            CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, XX, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, CM, AL, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, XX, CM, CM, CM, XX, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, CM,
+            CM, XX, AL, AL, AL, XX, AL, AL, XX, XX, AL, AL, CM, CM, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, BB, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, CM, CM, CM, BB, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, XX, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, XX, XX, CM, AL, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, XX, CM, CM, CM, XX, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, CM,
+            CM, XX, XX, XX, XX, XX, AL, AL, AL, XX, AL, AL, CM, CM, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, AL, AL, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, XX, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, AL, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, XX, CM, CM, CM, XX, CM, CM, CM, CM, AL, AL, XX, XX, XX, XX, AL, AL,
+            AL, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, AL, AL, AL, AL, AL, AL, AL, AL, AL, PO, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            XX, CM, CM, CM, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, CM, XX, XX, XX, XX, CM, CM, CM, CM, CM, CM, XX,
+            CM, XX, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, CM, CM, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, XX, XX, XX, XX, PR,
+        ],
+        [
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, AL, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, BA, BA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, SA, SA, XX, SA, XX, SA, SA, SA, SA, SA, XX, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, XX, SA, XX, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, XX, XX,
+        ],
+        [
+            SA, SA, SA, SA, SA, XX, SA, XX, SA, SA, SA, SA, SA, SA, SA, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, SA, SA, SA, SA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, BB, BB, BB, BB, AL, BB, BB, GL, BB, BB, BA, GL, EX, EX, EX, EX, EX, GL, AL, EX, AL,
+            AL, AL, CM, CM, AL, AL, AL, AL, AL, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, BA, CM, AL, CM, AL, CM, OP, CL, OP, CL, CM, CM,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, XX, XX, XX, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, BA,
+        ],
+        [
+            CM, CM, CM, CM, CM, BA, CM, CM, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, BA, BA,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, CM, AL, AL, AL, AL, AL, AL, XX, AL, AL, BB, BB, BA, BB, AL, AL,
+            AL, AL, AL, GL, GL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, BA, BA, AL, AL, AL, AL, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        ],
+        [
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, SA, SA, SA, SA, SA, SA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, XX, AL, XX, XX, XX, XX, XX, AL, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL,
+            JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL,
+            JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL,
+        ],
+        [
+            JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL,
+            JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV,
+            JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV,
+        ],
+        [
+            JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV,
+            JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JT, JT, JT, JT,
+            JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT,
+        ],
+        [
+            JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT,
+            JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT,
+            JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, XX, AL, XX, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, XX,
+        ],
+        [
+            AL, XX, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+            XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, XX, XX, CM, CM, CM, AL, BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, XX, XX,
+        ],
+        [
+            HH, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, OP, CL, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, BA,
+            BA, BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, CM, CM, CM, BA, BA, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, XX, AL, AL, AL, XX, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, BA, BA,
+            NS, SA, BA, AL, BA, PR, SA, SA, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, EX, EX, BA, BA, BB, AL, EX, EX, AL, CM, CM, CM, GL, CM, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, AL, XX,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            XX, XX, XX, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX,
+        ],
+        [
+            AL, XX, XX, XX, EX, EX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, XX, XX, SA, SA, SA, SA, SA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            XX, XX, XX, XX, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        ],
+        [
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, XX, XX, XX, SA, SA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, CM, CM, CM, CM, CM, XX, XX, AL, AL, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        ],
+        [
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, XX, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, XX, XX, CM,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, XX, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, GL,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            CM, CM, CM, CM, CM, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK,
+            AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK,
+            AK, AK, AK, AK, AK, AK, AK, AK, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, VI, AK, AK, AK, AK, AK, AK, AK, AK, XX, BA, BA, AS, AS, AS, AS, AS, AS,
+            AS, AS, AS, AS, BA, BA, ID, BA, BA, BA, BA, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, ID, ID, ID, ID, ID, ID, ID, ID, ID, BA, BA, BA,
+        ],
+        [
+            CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, AL, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS,
+            AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, VF, VF, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, BA, BA, BA, BA, BA,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, AL, AL, AL, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, BA, BA,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, CM, CM, CM, AL, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL,
+            AL, CM, AL, AL, AL, AL, AL, AL, CM, AL, AL, CM, CM, CM, AL, XX, XX, XX, XX, XX,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, GL, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, GL, CM, CM, CM,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            XX, XX, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, XX, AL, XX, AL, XX, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX,
+            AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, XX, XX, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, BB, AL, XX,
+        ],
+        [
+            BA, BA, BA, BA, BA, BA, BA, GL, BA, BA, BA, ZW, CM, ZWJ, CM, CM, HH, GL, HH, HH, B2, AI,
+            AI, AL, QU, QU, OP, QU, QU, QU, OP, QU, AI, AI, AL, AL, IN, IN, IN, BA, BK, BK, CM, CM,
+            CM, CM, CM, GL, PO, PO, PO, PO, PO, PO, PO, PO, AL, QU, QU, AI, NS, NS, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, IS, OP, CL, NS, NS, NS, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            BA, PO, BA, BA, BA, BA, AL, BA, BA, BA, WJ, AL, AL, AL, AL, XX, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, AL, AL, XX, XX, AI, AL, AL, AL, AL, AL, AL, AL, AL, OP, CL, AI,
+        ],
+        [
+            AL, AI, AI, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, OP, CL, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, PR, PR, PR, PR, PR, PR, PR, PO, PR, PR, PR, PR,
+            PR, PR, PR, PR, PR, PR, PR, PR, PR, PR, PO, PR, PR, PR, PR, PO, PR, PR, PO, PR,
+        ],
+        [
+            PO, PR, PR, PR, PR, PR, PR, PR, PR, PR, PR, PR, PR, PR, PR, PR, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, PO, AL, AI, AL, AL, AL, PO, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AL,
+            PR, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, AI,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AI, AI, AI, AI, AI,
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AL, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+            AL, AL, AL, AL, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AL, XX, XX, XX, XX, AI, AI, AI, AI, AI, AI,
+            AI, AI, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AI, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AI, AL, AI, AI, AL, AL, AL, AI, AI, AL, AL, AI, AL, AL, AL, AI, AL, AI, PR, PR, AL, AI,
+            AL, AL, AL, AL, AI, AL, AL, AI, AI, AI, AI, AL, AL, AI, AL, AI, AL, AI, AI, AI, AI, AI,
+            AI, AL, AI, AL, AL, AL, AL, AL, AI, AI, AI, AI, AL, AL, AL, AL, AI, AI, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AL, AL, AI, AL, AL, AL, AL, AL, AI, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AI, AL, AL, AI, AI, AI, AI, AL, AL, AI, AI,
+            AL, AL, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AI, AI, AL, AL, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI,
+            AL, AL, AL, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, IN, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, OP, CL, OP, CL, AL, AL, AL, AL, AL, AL, AI, AL, AL, AL,
+            AL, AL, AL, AL, ID, ID, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, OP, CL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, ID, ID, ID, ID, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        ],
+        [
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        ],
+        [
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AL,
+        ],
+        [
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AL, AL, AL, AL, AI, AI, AI, AI, AI, AI,
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AL, AL, AI, AI, AI, AI,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AI, AL, AI, AI, AI, AI, AI, AI, AI, AL, AL,
+            AL, AL, AL, AL, AL, AL, AI, AI, AL, AL, AI, AI, AL, AL, AL, AL, AI, AI, AL, AL,
+        ],
+        [
+            AI, AI, AL, AL, AL, AL, AI, AI, AI, AL, AL, AI, AL, AL, AI, AI, AI, AI, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AI, AI, AI, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            ID, ID, ID, ID, AL, AI, AI, AL, AL, AI, AL, AL, AL, AL, AI, AI, AL, AL, AL, AL, ID, ID,
+            AI, AI, ID, AL, ID, ID, ID, EB, ID, ID, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID, ID, ID, AL, AL, AL, AL,
+        ],
+        [
+            AI, AL, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI, AI, AL, AI, AI, AI, AL, AI, ID, AI, AI, AL,
+            AI, AI, AL, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, AI, AI, AI, AI, ID, AL, ID, ID, ID, AI, ID, ID, AI,
+            AI, AI, ID, ID, AI, AI, ID, AI, AI, ID, ID, ID, AL, AI, AL, AL, AL, AL, AI, AI, ID, AI,
+            AI, AI, AI, AI, AI, ID, ID, ID, ID, ID, AI, ID, ID, EB, ID, AI, AI, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, AL, AL, AL, ID, ID, EB, EB, EB, EB, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AI, AL, AL, AL, QU, QU, QU, QU, QU, QU, AL, EX, EX, ID, AL, AL, AL, OP, CL, OP, CL,
+            OP, CL, OP, CL, OP, CL, OP, CL, OP, CL, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        ],
+        [
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, OP, CL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, OP, CL, OP, CL, OP, CL,
+            OP, CL, OP, CL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, OP, CL, OP, CL, OP, CL, OP, CL, OP, CL, OP, CL, OP, CL, OP, CL, OP, CL, OP,
+            CL, OP, CL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, OP, CL, OP, CL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, OP, CL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AI,
+            AI, AI, AI, AI, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, CM, CM, CM, AL, AL, XX, XX, XX, XX, XX, EX, BA, BA, BA, AL, EX, BA,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, XX, XX, XX, XX,
+            XX, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX,
+            XX, XX, XX, AL, BA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CM,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+            AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL,
+            AL, XX, AL, AL, AL, AL, AL, AL, AL, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            QU, QU, QU, QU, QU, QU, QU, QU, QU, QU, QU, QU, QU, QU, BA, BA, BA, BA, BA, BA, BA, BA,
+            AL, HH, OP, BA, AL, AL, QU, QU, AL, AL, QU, QU, OP, CL, OP, CL, OP, CL, OP, CL, BA, BA,
+            BA, BA, EX, AL, BA, BA, AL, BA, BA, AL, AL, AL, AL, AL, B2, B2, BA, BA, BA, AL,
+        ],
+        [
+            HH, BA, OP, BA, BA, BA, BA, BA, BA, BA, BA, AL, BA, AL, BA, BA, AL, AL, AL, EX, EX, OP,
+            CP, OP, CP, OP, CP, OP, CP, HH, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, XX, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            BA, CL, CL, ID, ID, NS, ID, ID, OP, CL, OP, CL, OP, CL, OP, CL, OP, CL, ID, ID, OP, CL,
+            OP, CL, OP, CL, OP, CL, NS, OP, CL, CL, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, CM, CM,
+            CM, CM, CM, CM, ID, ID, ID, ID, ID, CM, ID, ID, ID, ID, ID, NS, NS, ID, ID, ID,
+        ],
+        [
+            XX, CJ, ID, CJ, ID, CJ, ID, CJ, ID, CJ, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, CJ, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, CJ, ID, CJ, ID, CJ, ID, ID, ID, ID, ID, ID, CJ, ID, ID, ID, ID, ID, ID, CJ,
+            CJ, XX, XX, CM, CM, NS, NS, NS, NS, ID, NS, CJ, ID, CJ, ID, CJ, ID, CJ, ID, CJ, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, CJ, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, CJ, ID, CJ, ID, CJ, ID, ID, ID, ID,
+            ID, ID, CJ, ID, ID, ID, ID, ID, ID, CJ, CJ, ID, ID, ID, ID, NS, CJ, NS, NS, ID,
+        ],
+        [
+            XX, XX, XX, XX, XX, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, XX, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, ID, CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ, CJ,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, AI, AI, AI, AI, AI, AI, AI, AI, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, NS,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, BA, BA,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, BA, EX, BA, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, CM, CM, CM, CM, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, CM, CM, AL, BA, BA, BA, BA, BA, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, CM, AL, AL, AL, CM, AL, AL, AL, AL, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, AL, AL, AL, AL,
+            CM, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, PO, AL, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, BB, BB, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, BA, BA, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, BB, AL, AL, CM,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM,
+            CM, CM, BA, BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL,
+            JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, JL, XX, XX, XX,
+        ],
+        [
+            CM, CM, CM, CM, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK,
+            AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK,
+            AK, AK, AK, AK, AK, AK, AK, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            VI, ID, ID, ID, ID, ID, ID, BA, BA, BA, ID, ID, ID, ID, XX, BA, AS, AS, AS, AS, AS, AS,
+            AS, AS, AS, AS, XX, XX, XX, XX, ID, ID, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, SA, SA, SA, SA, SA, XX,
+        ],
+        [
+            AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS,
+            AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            BA, BA, BA, CM, BA, BA, BA, BA, BA, BA, BA, BA, CM, CM, XX, XX, AS, AS, AS, AS, AS, AS,
+            AS, AS, AS, AS, XX, XX, ID, BA, BA, BA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+        ],
+        [
+            SA, SA, SA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, SA, SA, SA, SA, SA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM,
+            CM, CM, CM, CM, BA, BA, AL, AL, AL, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL,
+            AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+            AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, BA,
+            CM, CM, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+            H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3,
+        ],
+        [
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3,
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+            H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        ],
+        [
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3,
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        ],
+        [
+            H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3,
+        ],
+        [
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+            H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+            H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        ],
+        [
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3,
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+            H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        ],
+        [
+            H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3,
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+        ],
+        [
+            H3, H3, H3, H3, H3, H3, H3, H3, H2, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3,
+            H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, H3, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV, JV,
+        ],
+        [
+            JV, JV, JV, JV, JV, JV, JV, XX, XX, XX, XX, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT,
+            JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT,
+            JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, JT, XX, XX, XX, XX,
+        ],
+        [
+            SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG,
+            SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG,
+            SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG, SG,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL,
+            AL, AL, XX, XX, XX, XX, XX, HL, CM, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, AL, HL, HL,
+            HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, XX, HL, HL, HL, HL, HL, XX, HL, XX,
+        ],
+        [
+            HL, HL, XX, HL, HL, XX, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CL, OP,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, PO, AL, AL, AL,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CL, CL, CL, NS, NS, EX,
+            EX, OP, CL, IN, XX, XX, XX, XX, XX, XX, GL, CM, GL, CM, GL, CM, GL, GL, CM, GL, CM, GL,
+            CM, GL, GL, CM, ID, ID, ID, ID, ID, OP, CL, OP, CL, OP, CL, OP, CL, OP, CL, OP,
+        ],
+        [
+            CL, OP, CL, OP, CL, ID, ID, OP, CL, ID, ID, ID, ID, ID, ID, ID, CL, ID, CL, XX, NS, NS,
+            EX, EX, ID, OP, CL, OP, CL, OP, CL, ID, ID, ID, ID, ID, ID, ID, ID, XX, ID, PR, PO, ID,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, WJ,
+        ],
+        [
+            XX, EX, ID, ID, PR, PO, ID, ID, OP, CL, ID, ID, CL, ID, CL, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, NS, NS, ID, ID, ID, EX, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, OP, ID, CL, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, OP, ID, CL, ID, OP, CL, CL, OP, CL, CL, NS, ID, CJ, CJ, CJ, CJ, CJ,
+            CJ, CJ, CJ, CJ, CJ, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, NS, NS, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, XX,
+        ],
+        [
+            XX, XX, ID, ID, ID, ID, ID, ID, XX, XX, ID, ID, ID, ID, ID, ID, XX, XX, ID, ID, ID, ID,
+            ID, ID, XX, XX, ID, ID, ID, XX, XX, XX, PO, PR, ID, ID, ID, PR, PR, XX, AL, AL, AL, AL,
+            AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CM, CM, CM, CB, AI, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, XX, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX,
+        ],
+        [
+            BA, BA, BA, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, XX, BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, BA, AL, AL, AL, AL, AL,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX,
+            XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL,
+            XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, XX, XX, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, XX, XX, XX, AL, XX, XX, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            XX, BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, XX, XX, XX, BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, CM, CM, CM, XX, CM, CM, XX, XX, XX, XX, XX, CM, CM, CM, CM, AL, AL, AL, AL, XX, AL,
+            AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, CM, CM, CM, XX, XX, XX, XX, CM,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, BA, BA, BA, BA, BA, BA,
+            BA, BA, AL, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, XX, XX, XX, XX, AL,
+            AL, AL, AL, AL, BA, BA, BA, BA, BA, BA, IN, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, BA, BA, BA, BA, BA, BA, BA,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX,
+            XX, XX, XX, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL,
+            AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, XX, XX, XX, XX,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, CM, CM, CM,
+            CM, CM, HH, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, CM,
+            CM, HH, XX, XX, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, BA, AL, AL, AL, AL, AL,
+            AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, CM, CM, CM, CM, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            CM, CM, CM, AP, AP, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK,
+            AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK,
+            AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, VI, BA, BA, ID, ID, ID, ID, ID, XX, XX, XX, XX, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, AS, AS, AS, AS, AS, AS,
+            AS, AS, AS, AS, CM, AK, AK, CM, CM, AK, XX, XX, XX, XX, XX, XX, XX, XX, XX, GL,
+        ],
+        [
+            CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, NU, BA, BA,
+        ],
+        [
+            BA, BA, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU,
+        ],
+        [
+            BA, BA, BA, BA, AL, CM, CM, AL, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, CM, AL, BB, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, AL, AL, AL, AL, BA, BA, AL, BA, CM, CM, CM, CM, AL, CM, CM, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, AL, BB, AL, BA, BA, BA, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, BA, BA, AL, BA, BA, AL, CM, AL,
+        ],
+        [
+            AL, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, XX, AL, XX, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, BA, XX, XX,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            CM, CM, CM, CM, XX, AK, AK, AK, AK, AK, AK, AK, AK, XX, XX, AK, AK, XX, XX, AK, AK, AK,
+            AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, XX, AK, AK,
+            AK, AK, AK, AK, AK, XX, AK, AK, XX, AK, AK, AK, AK, AK, XX, CM, CM, BA, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, XX, XX, CM, CM, XX, XX, CM, CM, VI, XX, XX, AS, XX, XX, XX, XX, XX,
+            XX, CM, XX, XX, XX, XX, XX, BA, AS, AS, AK, AK, CM, CM, XX, XX, CM, CM, CM, CM, CM, CM,
+            CM, XX, XX, XX, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, XX, AS, XX, XX, AS, XX, AS, AS, AK, AK, AK, AK,
+            AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK,
+            AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, XX, ID, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, XX, CM, XX, XX, CM, XX, CM, CM, CM, CM, XX, CM, CM, CM, CM, VI, AP, CM, ID, ID, ID,
+            XX, ID, ID, XX, XX, XX, XX, XX, XX, XX, XX, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, BA, BA, BA, BA, AL, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, BA, BA, XX, AL, CM, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, XX, XX, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, BB, BA, BA, EX, EX, AL, AL, AL, BA, BA, BA, BA, BA, BA, BA, BA, BA, BA, BA, BA, BA,
+            BA, BA, AL, AL, AL, AL, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            CM, BA, BA, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, BB, BB, BB, BB, BB, BB, BB, BB, BB, BB, BB, BB,
+            BB, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            SA, SA, SA, SA, SA, XX, XX, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA, SA,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, SA, SA, BA, BA, BA, SA,
+        ],
+        [
+            SA, SA, SA, SA, SA, SA, SA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL,
+        ],
+        [
+            AK, AK, AK, AK, AK, AK, AK, XX, XX, AK, XX, XX, AK, AK, AK, AK, AK, AK, AK, AK, XX, AK,
+            AK, XX, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK,
+            AK, AK, AK, AK, CM, CM, CM, CM, CM, CM, XX, CM, CM, XX, XX, CM, CM, CM, VI, AP,
+        ],
+        [
+            CM, AP, CM, CM, BA, BA, BA, XX, XX, XX, XX, XX, XX, XX, XX, XX, AS, AS, AS, AS, AS, AS,
+            AS, AS, AS, AS, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM,
+            CM, CM, XX, XX, CM, CM, CM, CM, CM, CM, CM, AL, BB, AL, CM, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, AL, CM, CM, CM, CM, BB,
+        ],
+        [
+            AL, BA, BA, BA, BA, BB, AL, CM, XX, XX, XX, XX, XX, XX, XX, XX, AL, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, BA, BA, BA, AL, BB, BB, BB, BA, BA, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            BB, BB, BB, BB, BB, BB, BB, BB, BB, BB, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, XX, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            AL, BA, BA, BA, BA, BA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, XX, XX, XX, BB, EX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, XX, XX, XX, CM, XX, CM, CM, XX, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, AL, CM, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, XX, AL, AL, XX, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, XX, CM, CM, XX, CM, CM, CM,
+            CM, CM, AL, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS,
+            AS, AS, AS, AS, AS, AS, BA, CM, CM, CM, CM, BA, BA, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            CM, CM, AP, CM, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, XX, AK, AK, AK, AK,
+            AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK, AK,
+            AK, AK, AK, AK, AK, AK, AK, AK, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, CM, CM,
+        ],
+        [
+            CM, CM, VI, BA, BA, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, AS, AS, AS, AS, AS, AS,
+            AS, AS, AS, AS, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, PO, PO, PO, PO, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, BA,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, XX, BA, BA, BA, BA, BA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, OP, OP, OP, CL, CL, CL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, CL, AL, AL, AL, OP, CL, OP, CL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, OP, CL, CL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, OP, GL, GL, GL, GL, GL, GL, GL, OP, CL, GL, GL, GL, OP, CL, OP, CL,
+        ],
+        [
+            CM, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, OP, CL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS,
+            AS, AS, AS, AS, AS, AS, AS, AS, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, AS, AS, AS, AS, AS, AS, AS, AS, AS, AS, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, BA, BA, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, XX, XX, CM, CM, CM, CM, CM, BA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, BA, BA, BA, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, BA, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, BA, BA, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, BA, BA, AL, AL, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, CM, AL, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, CM, CM, CM, CM, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NS, NS, NS, NS, GL, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, CM, CM, NS, NS, ID, ID, ID, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, XX,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, CJ, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CJ, CJ, CJ, XX, XX, CJ,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CJ, CJ, CJ, CJ, XX, XX, XX, XX,
+            XX, XX, XX, XX, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, XX, XX, AL, CM, CM, BA, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, AL, AL, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, XX, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, AL, AL,
+            AL, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, CM, CM, AL, AL, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM,
+            CM, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, CM, CM, CM, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, XX, XX, AL, XX, XX, AL, AL, XX, XX, AL, AL, AL,
+            AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, XX, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+            AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, XX, AL, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, AL, AL, AL, AL, AL, AL, AL, AL, CM, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, CM, AL, AL, BA, BA, BA, BA, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, CM, CM, CM, CM, CM, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, XX, XX, CM, CM, CM, CM, CM, CM, CM, XX, CM, CM, XX, CM, CM, CM, CM, CM, XX,
+            XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CM, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, XX, XX, XX, CM, CM, CM, CM, CM, CM, CM, AL, AL, AL, AL, AL, AL, AL, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, AL, AL, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            CM, CM, CM, CM, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, PR,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            CM, CM, CM, CM, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, CM, CM, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, CM, AL, AL, CM, AL, AL, AL, AL, AL,
+            AL, AL, CM, CM, AL, AL, AL, AL, AL, CM, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL,
+            XX, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, CM, CM, CM, CM, CM, CM,
+            CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, CM, CM, CM, CM, CM, CM, CM, AL, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, OP, OP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            PO, AL, AL, AL, PO, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, XX, AL, XX, XX, AL, XX, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, XX, AL, XX, AL, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, AL, XX, XX, XX, XX, AL, XX, AL, XX, AL, XX, AL, AL, AL, XX, AL, AL, XX, AL, XX,
+            XX, AL, XX, AL, XX, AL, XX, AL, XX, AL, XX, AL, AL, XX, AL, XX, XX, AL, AL, AL, AL, XX,
+            AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, XX, AL, AL, AL, AL, XX, AL, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, AL, AL, AL, XX, AL, AL, AL, AL, AL, XX, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AL, AL, AL, AI, AI, AI, AI, AI, AI,
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+            AI, AI, AL, AL, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        ],
+        [
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AL, AL,
+            AL, AL, AL, AL, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+        ],
+        [
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+            AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI, AI,
+            AI, AL, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, RI, RI, RI, RI, RI, RI,
+            RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI,
+        ],
+        [
+            ID, ID, ID, ID, ID, EB, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, AL, AL, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, AL, AL, ID, ID, ID, ID, ID, AL, ID, ID, ID,
+        ],
+        [
+            ID, ID, EB, EB, EB, ID, ID, EB, ID, ID, EB, EB, EB, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, EM, EM, EM, EM, EM,
+        ],
+        [
+            ID, ID, EB, EB, ID, ID, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, EB, EB, EB, EB, EB, EB,
+            EB, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB, ID, ID, ID, EB, ID, ID, ID,
+        ],
+        [
+            ID, EB, EB, EB, ID, EB, EB, EB, ID, ID, ID, ID, ID, ID, ID, EB, ID, EB, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, AL, ID, AL, ID, AL, ID, ID, ID, ID, ID, EB, ID,
+            ID, ID, ID, AL, ID, AL, AL, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, EB, EB, ID, ID, ID, ID, EB, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, EB, ID, ID, ID, ID, EB,
+            EB, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, AL, AL,
+            AL, AL, AL, AL, AL, AL, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, AL, AL, AL, AL, AL, AL, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, EB, EB, EB, ID, ID, ID, EB, EB, EB, EB, EB, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, QU, QU, QU, NS, NS, NS, AL, AL, AL, AL,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, EB, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, EB, EB, EB, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            EB, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, EB, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, ID, ID, ID, AL, AL, AL, AL, ID, ID, ID, ID, ID,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, XX, XX, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, EB, ID, ID, EB, ID, ID, ID, ID, ID, ID,
+            ID, ID, EB, EB, EB, EB, EB, EB, EB, EB, ID, ID, ID, ID, ID, ID, EB, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, EB, EB, EB, EB, EB, EB, EB, EB, EB, EB, ID, ID, EB, EB, EB, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, EB, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, EB, EB, ID, EB, EB, ID, EB, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, EB, EB, EB, ID, EB, EB, EB, EB, EB,
+            EB, EB, EB, EB, EB, EB, EB, EB, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            ID, ID, ID, EB, EB, EB, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, EB, EB, EB, EB, EB, EB, EB, EB, EB, ID, ID, ID, ID, ID, ID, ID,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, XX, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+        ],
+        [
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL, AL,
+            AL, AL, AL, AL, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, AL, XX, XX, XX, XX, XX,
+        ],
+        [
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID,
+            ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, ID, XX, XX,
+        ],
+        [
+            XX, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+        ],
+        [
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM, CM,
+            CM, CM, CM, CM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+    ],
+};
+
+/// Codepoints with an `East_Asian_Width` of `F`, `W` or `H`
+pub(crate) static EAST_ASIAN: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 6, 0, 7, 15, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 6, 7, 8, 9, 10, 11,
+            12, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 16, 17, 1, 1, 1, 18,
+        ],
+        [
+            19, 20, 21, 1, 22, 1, 23, 24, 25, 26, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1,
+        ],
+        [
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+        [
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 27, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+        [
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 32,
+            0, 0, 20, 1, 19, 33,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 34,
+        ],
+        [
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 35, 36, 0, 1, 37, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 38,
+        ],
+        [
+            1, 1, 1, 1, 39, 40, 1, 1, 1, 1, 1, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            43, 0, 0, 44, 0, 0, 45, 0, 46, 47, 0, 0, 48, 49, 50, 51, 19, 52, 1, 53, 54, 55, 56, 57,
+            1, 58, 1, 59, 0, 0, 0, 60, 0, 0, 0, 0, 61, 62, 1, 1, 0, 63, 64, 65, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 54,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 6, 0, 7, 0, 0, 0, 0, 0, 0, 8, 4, 9, 0, 10, 11, 0,
+            12, 0, 13, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 14, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 14,
+        ],
+    ],
+    leaves: &[
+        0x0000_0000_0000_0000,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0x0000_0000_FFFF_FFFF,
+        0x0000_0200_0000_0000,
+        0x0000_0600_0C00_0000,
+        0x0009_1E00_0000_0000,
+        0x6000_0000_0000_0000,
+        0x00FF_0000_0030_0000,
+        0x8000_0000_000F_FF00,
+        0x6000_0C02_0008_FC00,
+        0x242C_0400_0010_4030,
+        0x0000_0100_0000_0C20,
+        0x0000_0000_00B8_5000,
+        0x8001_0000_00E0_0000,
+        0x0000_0000_1800_0000,
+        0x0000_0000_0021_0000,
+        0xFFFF_FFFF_FBFF_FFFF,
+        0x000F_FFFF_FFFF_FFFF,
+        0xFFFF_0000_003F_FFFF,
+        0x7FFF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FFFE,
+        0xFFFF_FFFF_FE7F_FFFF,
+        0xFFFE_FFFF_FFFF_FFE0,
+        0xFFFF_FFFF_FFFF_7FFF,
+        0xFFFF_803F_FFFF_FFFF,
+        0xFFFF_FFFF_7FFF_FFFF,
+        0xFFFF_FFFF_FFFF_00FF,
+        0xFFFF_FFFF_FFFF_1FFF,
+        0x0000_0000_0000_007F,
+        0x1FFF_FFFF_0000_0000,
+        0x0000_000F_FFFF_FFFF,
+        0xFFFF_0000_03FF_0000,
+        0x0000_0F7F_FFF7_FFFF,
+        0x0000_7F7F_1CFC_FCFC,
+        0x007F_001F_0000_0000,
+        0x8000_0000_003F_FFFF,
+        0x0000_0000_7FFF_FFFF,
+        0x0007_FFFF_FFFF_FFFF,
+        0x6FEF_0000_0000_0000,
+        0x0004_0007_FFFF_FFFF,
+        0xFFFF_00F0_0027_0000,
+        0x0FFF_FFFF_FFFF_FFFF,
+        0x007F_FFFF_007F_FFFF,
+        0x0000_0000_0000_0010,
+        0x0000_0000_0000_8000,
+        0x0000_0000_07FE_4000,
+        0x0FFF_FFFF_FFFF_0007,
+        0x0000_003F_0003_01FF,
+        0xFFBF_E001_FFFF_FFFF,
+        0xDFFF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_000F_FFFF,
+        0xFF11_FFFF_000F_87FF,
+        0xFFFF_FFFF_FFFF_FFFD,
+        0x9FFF_FFFF_FFFF_FFFF,
+        0x3FFF_FFFF_FFFF_FFFF,
+        0x0400_00FF_FFFF_7800,
+        0x0000_0010_0060_0000,
+        0xF800_0000_0000_0000,
+        0x0000_0000_0000_FFFF,
+        0x1FF0_1800_F1E7_103F,
+        0x0001_0FFF_0000_0000,
+        0xF7FF_FFFF_FFFF_F000,
+        0xFFFF_FFFF_FFFF_FFBF,
+        0x1FFF_0000_0000_0000,
+        0xFFFF_FFFF_FFFF_C7FF,
+        0x01FF_87FF_9FFF_E17F,
+    ],
+};
@@ -0,0 +1,174 @@
+"""Generates src/tables/name.rs: the `Name` of every codepoint, and the aliases names can be looked up
+by.
+
+    python3 src/tables/name.py <path to UCD> > src/tables/name.rs
+
+Names are compressed by splitting them into words: `WORDS` holds every distinct word once, most
+frequent first, and `NAMES` is a stream of word ids, one name after the other in codepoint order. Ids
+below 0x80 take one byte, the rest two bytes with the high bit of the first set. The second byte is
+never 0, so that the 0 byte every name ends with can be searched for.
+
+Named codepoints come in runs of consecutive codepoints, `NAME_RUNS` maps between codepoints and
+their position in `NAMES`. Names that are a prefix followed by the hex codepoint, like
+`CJK UNIFIED IDEOGRAPH-4E00`, are left out and listed in `PREFIXED` instead, and Hangul syllable
+names are derived from their decomposition.
+"""
+
+from collections import Counter
+
+from ucd import header, parse_lines, ucd_dir, unicode_data, utf8char_bits, wrap
+
+# the names of the `<..., First>` ranges of UnicodeData.txt, other ranges have no names
+RANGE_PREFIXES = {
+    "CJK Ideograph": "CJK UNIFIED IDEOGRAPH-",
+    "Tangut Ideograph": "TANGUT IDEOGRAPH-",
+}
+
+# how many names `NAME_BLOCKS` skips at a time
+BLOCK = 32
+
+
+def main() -> None:
+    ucd = ucd_dir()
+
+    names: dict[int, str] = {}
+    prefixed: list[tuple[int, int, str]] = []
+
+    def add_prefixed(cp: int, prefix: str) -> None:
+        first, last, previous = prefixed[-1] if prefixed else (0, 0, "")
+
+        if previous == prefix and last == cp - 1:
+            prefixed[-1] = (first, cp, prefix)
+        else:
+            prefixed.append((cp, cp, prefix))
+
+    for cp, fields in sorted(unicode_data(ucd / "UnicodeData.txt").items()):
+        name = fields[1]
+
+        # unassigned codepoints listed with an empty name have none
+        if not name:
+            continue
+
+        if name.startswith("<"):
+            label = name[1:].split(",")[0]
+
+            for ranged, prefix in RANGE_PREFIXES.items():
+                if label.startswith(ranged):
+                    add_prefixed(cp, prefix)
+
+            continue
+
+        if name.endswith(f"-{cp:04X}"):
+            add_prefixed(cp, name[: -len(f"{cp:04X}")])
+        else:
+            names[cp] = name
+
+    aliases = sorted((fields[1], int(fields[0], 16)) for fields in parse_lines(ucd / "NameAliases.txt"))
+
+    frequency = Counter(word for name in names.values() for word in name.split(" "))
+    words = sorted(frequency, key=lambda word: (-frequency[word], word))
+    # id 0 ends a name
+    ids = {word: i + 1 for i, word in enumerate(words)}
+    assert len(words) < 0x80 + 0x80 * 255
+
+    stream: list[int] = []
+    blocks: list[int] = []
+    runs: list[tuple[int, int]] = []
+    previous = -2
+
+    for ordinal, (cp, name) in enumerate(sorted(names.items())):
+        if ordinal % BLOCK == 0:
+            blocks.append(len(stream))
+
+        if cp != previous + 1:
+            runs.append((cp, ordinal))
+
+        previous = cp
+
+        for word in name.split(" "):
+            i = ids[word]
+
+            if i < 0x80:
+                stream.append(i)
+            else:
+                stream.extend([0x80 | (i - 0x80) // 255, (i - 0x80) % 255 + 1])
+
+        stream.append(0)
+
+    # a run past the last name, so that every run knows its length
+    runs.append((previous + 1, len(names)))
+
+    offsets = [0]
+
+    for word in words:
+        offsets.append(offsets[-1] + len(word))
+
+    longest = max(len(name) for name in [*names.values(), *(alias for alias, _ in aliases)])
+
+    def bits(cp: int) -> str:
+        digits = f"{utf8char_bits(cp):08X}"
+        return f"0x{digits[:4]}_{digits[4:]}"
+
+    print(header("name.py", ["UnicodeData.txt", "NameAliases.txt"]))
+    print("/// The length of the longest name or alias that is not derived from its codepoint")
+    print(f"pub(crate) const MAX_NAME_LEN: usize = {longest};")
+    print()
+    print("/// Every word of every name, most frequent first, split by `WORD_OFFSETS`")
+    print("pub(crate) static WORDS: &str = \"\\")
+
+    line = ""
+
+    for word in words:
+        if len(line) + len(word) > 95:
+            print(f"    {line}\\")
+            line = ""
+
+        line += word
+
+    print(f"    {line}\";")
+    print()
+    print("/// Where every word starts in `WORDS`, followed by where the last one ends. Word id `i` is")
+    print("/// `WORDS[WORD_OFFSETS[i - 1]..WORD_OFFSETS[i]]`")
+    print(f"pub(crate) static WORD_OFFSETS: [u32; {len(offsets):_}] = [")
+    print("\n".join(wrap((f"{o:_}," for o in offsets), indent=4)))
+    print("];")
+    print()
+    print("/// The names of all codepoints with a listed name in codepoint order, as word ids ending in 0")
+    print(f"pub(crate) static NAMES: [u8; {len(stream):_}] = [")
+    print("\n".join(wrap((f"{b}," for b in stream), indent=4)))
+    print("];")
+    print()
+    print(f"/// Where every `{BLOCK}`th name starts in `NAMES`")
+    print(f"pub(crate) const NAME_BLOCK: usize = {BLOCK};")
+    print()
+    print("/// Where every `NAME_BLOCK`th name starts in `NAMES`")
+    print(f"pub(crate) static NAME_BLOCKS: [u32; {len(blocks)}] = [")
+    print("\n".join(wrap((f"{b:_}," for b in blocks), indent=4)))
+    print("];")
+    print()
+    print("/// Runs of consecutive named codepoints as `(first codepoint, ordinal of its name)`, the last one")
+    print("/// marking the end of the last run")
+    print(f"pub(crate) static NAME_RUNS: [(u32, u16); {len(runs)}] = [")
+    print("\n".join(wrap((f"(0x{cp:04X}, {ordinal})," for cp, ordinal in runs), indent=4)))
+    print("];")
+    print()
+    print("/// Ranges of codepoints named by a prefix and their hex value, as `(first, last, prefix)`")
+    print("/// `Utf8Char::to_bits`")
+    print(f"pub(crate) static PREFIXED: [(u32, u32, &str); {len(prefixed)}] = [")
+
+    for first, last, prefix in prefixed:
+        print(f"    ({bits(first)}, {bits(last)}, \"{prefix}\"),")
+
+    print("];")
+    print()
+    print("/// The formal aliases of codepoints as `(alias, Utf8Char::to_bits)`, sorted by alias")
+    print(f"pub(crate) static ALIASES: [(&str, u32); {len(aliases)}] = [")
+
+    for alias, cp in aliases:
+        print(f"    (\"{alias}\", {bits(cp)}),")
+
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
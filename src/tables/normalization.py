@@ -0,0 +1,183 @@
+"""Generates src/tables/normalization.rs: the decompositions, compositions, canonical combining
+class, quick checks and `NFKC_Casefold` mapping normalization (UAX #15) is built from.
+
+    python3 src/tables/normalization.py <path to UCD> > src/tables/normalization.rs
+
+The decomposition tries map a codepoint to an index into `DECOMPOSITIONS`, its full decomposition
+in canonical order. Index 0 marks codepoints that decompose to themselves. Hangul syllables are left
+out, they decompose algorithmically. `NFKC_CASEFOLD` does the same for `NFKC_CASEFOLDS`.
+
+The quick check properties are derived the way UAX #15 defines them: codepoints excluded from
+composition can never appear in NFC (`No`), and codepoints that are the second half of a canonical
+composition may combine with the codepoint before them (`Maybe`). NFKC additionally excludes every
+codepoint whose full compatibility decomposition differs from its canonical one.
+"""
+
+from ucd import CODEPOINTS, binary_property, enumerated_property, header, parse_codepoints, parse_lines, parse_range, ucd_dir, unicode_data, utf8char_bits, value_trie, wrap
+
+# the Hangul vowel and trailing consonant jamo, which compose algorithmically
+HANGUL_V = range(0x1161, 0x1176)
+HANGUL_T = range(0x11A8, 0x11C3)
+# the precomposed Hangul syllables
+HANGUL_S = range(0xAC00, 0xD7A4)
+
+
+def main() -> None:
+    ucd = ucd_dir()
+
+    classes = [int(c) for c in enumerated_property(ucd / "extracted/DerivedCombiningClass.txt", "0")]
+    excluded = binary_property(ucd / "DerivedNormalizationProps.txt", "Full_Composition_Exclusion")
+
+    canonical: dict[int, list[int]] = {}
+    compatible: dict[int, list[int]] = {}
+
+    for cp, fields in unicode_data(ucd / "UnicodeData.txt").items():
+        if not fields[5]:
+            continue
+
+        # compatibility decompositions start with a `<tag>`
+        if fields[5].startswith("<"):
+            compatible[cp] = parse_codepoints(fields[5].split(">", 1)[1])
+        else:
+            canonical[cp] = parse_codepoints(fields[5])
+
+    def decompose(cp: int, compat: bool) -> list[int]:
+        # compatibility decompositions like U+320E PARENTHESIZED HANGUL KIYEOK A contain syllables
+        if cp in HANGUL_S:
+            s = cp - HANGUL_S.start
+            l, v, t = 0x1100 + s // 588, 0x1161 + s % 588 // 28, 0x11A7 + s % 28
+            return [l, v] if t == 0x11A7 else [l, v, t]
+
+        mapping = canonical.get(cp) or (compatible.get(cp) if compat else None)
+
+        if mapping is None:
+            return [cp]
+
+        return [d for m in mapping for d in decompose(m, compat)]
+
+    def reorder(cps: list[int]) -> list[int]:
+        # a stable sort by combining class, where starters never move
+        out: list[int] = []
+        run: list[int] = []
+
+        for cp in [*cps, 0]:
+            if classes[cp] == 0:
+                out.extend(sorted(run, key=lambda c: classes[c]))
+                out.append(cp)
+                run = []
+            else:
+                run.append(cp)
+
+        return out[:-1]
+
+    pool = [""]
+    ids = {"": 0}
+
+    def index(cps: list[int]) -> str:
+        s = "".join(map(chr, cps))
+
+        if s not in ids:
+            ids[s] = len(pool)
+            pool.append(s)
+
+        return str(ids[s])
+
+    canonical_ids = ["0"] * CODEPOINTS
+    compatible_ids = ["0"] * CODEPOINTS
+
+    for cp in sorted({*canonical, *compatible}):
+        if cp in canonical:
+            canonical_ids[cp] = index(reorder(decompose(cp, False)))
+
+        compatible_ids[cp] = index(reorder(decompose(cp, True)))
+
+    # primary composites, canonical pairs that are not excluded
+    compositions = sorted((*m, cp) for cp, m in canonical.items() if len(m) == 2 and not excluded[cp])
+
+    nfc = ["Y"] * CODEPOINTS
+
+    for _, second, _ in compositions:
+        nfc[second] = "M"
+
+    for cp in [*HANGUL_V, *HANGUL_T]:
+        nfc[cp] = "M"
+
+    for cp in range(CODEPOINTS):
+        if excluded[cp]:
+            nfc[cp] = "N"
+
+    # canonical decompositions like U+0385 GREEK DIALYTIKA TONOS contain compatibility ones
+    nfkc = nfc[:]
+
+    for cp in [*canonical, *compatible]:
+        if decompose(cp, True) != decompose(cp, False):
+            nfkc[cp] = "N"
+
+    casefolds = [""]
+    casefold_ids: dict[str, int] = {}
+    casefold_indexes = ["0"] * CODEPOINTS
+
+    for fields in parse_lines(ucd / "DerivedNormalizationProps.txt"):
+        if fields[1] != "NFKC_CF":
+            continue
+
+        # codepoints mapping to nothing get an index of their own, 0 is for unchanged codepoints
+        s = "".join(map(chr, parse_codepoints(fields[2])))
+
+        if s not in casefold_ids:
+            casefold_ids[s] = len(casefolds)
+            casefolds.append(s)
+
+        for cp in parse_range(fields[0]):
+            casefold_indexes[cp] = str(casefold_ids[s])
+
+    def literal(s: str) -> str:
+        return '"' + "".join(f"\\u{{{ord(c):X}}}" for c in s) + '",'
+
+    def bits(cp: int) -> str:
+        digits = f"{utf8char_bits(cp):08X}"
+        return f"0x{digits[:4]}_{digits[4:]}"
+
+    print(header("normalization.py", ["extracted/DerivedCombiningClass.txt", "DerivedNormalizationProps.txt", "UnicodeData.txt"]))
+    print("use super::Utf8Trie;")
+    print("use crate::normalization::QuickCheck;")
+    print()
+    print("/// `QuickCheck::Yes`, shortened to keep the tables below readable")
+    print("const Y: QuickCheck = QuickCheck::Yes;")
+    print("/// `QuickCheck::No`, shortened to keep the tables below readable")
+    print("const N: QuickCheck = QuickCheck::No;")
+    print("/// `QuickCheck::Maybe`, shortened to keep the tables below readable")
+    print("const M: QuickCheck = QuickCheck::Maybe;")
+    print()
+    print("/// The longest decomposition in `DECOMPOSITIONS` in bytes, at least as long as a Hangul syllable")
+    print("/// decomposition")
+    print(f"pub(crate) const MAX_DECOMPOSITION_LEN: usize = {max(9, *(len(s.encode('utf8')) for s in pool))};")
+    print()
+    print("/// Full decompositions in canonical order, indexed by the decomposition tries. The empty string")
+    print("/// marks codepoints that decompose to themselves")
+    print(f"pub(crate) static DECOMPOSITIONS: [&str; {len(pool)}] = [")
+    print("\n".join(wrap(map(literal, pool), indent=4)))
+    print("];")
+    print()
+    print("/// Canonical compositions as `(first, second, composite)` `Utf8Char::to_bits`, sorted by `first`")
+    print("/// then `second`")
+    print(f"pub(crate) static COMPOSITIONS: [(u32, u32, u32); {len(compositions)}] = [")
+    print("\n".join(wrap((f"({bits(a)}, {bits(b)}, {bits(c)})," for a, b, c in compositions), indent=4)))
+    print("];")
+    print()
+    print("/// `NFKC_Casefold` mappings, indexed by `NFKC_CASEFOLD`. Index 0 is unused, it marks codepoints")
+    print("/// that map to themselves")
+    print(f"pub(crate) static NFKC_CASEFOLDS: [&str; {len(casefolds)}] = [")
+    print("\n".join(wrap(map(literal, casefolds), indent=4)))
+    print("];")
+    print()
+    print(value_trie("CANONICAL_COMBINING_CLASS", "The `Canonical_Combining_Class` of every codepoint", "u8", [str(c) for c in classes]))
+    print(value_trie("CANONICAL_DECOMPOSITION", "The full canonical decomposition of every codepoint", "u16", canonical_ids))
+    print(value_trie("COMPATIBLE_DECOMPOSITION", "The full compatibility decomposition of every codepoint", "u16", compatible_ids))
+    print(value_trie("NFKC_CASEFOLD", "The `NFKC_Casefold` mapping of every codepoint", "u16", casefold_indexes))
+    print(value_trie("NFC_QUICK_CHECK", "The `NFC_Quick_Check` of every codepoint", "QuickCheck", nfc))
+    print(value_trie("NFKC_QUICK_CHECK", "The `NFKC_Quick_Check` of every codepoint", "QuickCheck", nfkc), end="")
+
+
+if __name__ == "__main__":
+    main()
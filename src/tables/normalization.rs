@@ -0,0 +1,6429 @@
+//! Generated by `src/tables/normalization.py` from the Unicode 17.0.0 files
+//! extracted/DerivedCombiningClass.txt, DerivedNormalizationProps.txt, UnicodeData.txt. Do not edit by hand.
+
+use super::Utf8Trie;
+use crate::normalization::QuickCheck;
+
+/// `QuickCheck::Yes`, shortened to keep the tables below readable
+const Y: QuickCheck = QuickCheck::Yes;
+/// `QuickCheck::No`, shortened to keep the tables below readable
+const N: QuickCheck = QuickCheck::No;
+/// `QuickCheck::Maybe`, shortened to keep the tables below readable
+const M: QuickCheck = QuickCheck::Maybe;
+
+/// The longest decomposition in `DECOMPOSITIONS` in bytes, at least as long as a Hangul syllable
+/// decomposition
+pub(crate) const MAX_DECOMPOSITION_LEN: usize = 33;
+
+/// Full decompositions in canonical order, indexed by the decomposition tries. The empty string
+/// marks codepoints that decompose to themselves
+pub(crate) static DECOMPOSITIONS: [&str; 3768] = [
+    "", "\u{20}", "\u{20}\u{308}", "\u{61}", "\u{20}\u{304}", "\u{32}", "\u{33}", "\u{20}\u{301}",
+    "\u{3BC}", "\u{20}\u{327}", "\u{31}", "\u{6F}", "\u{31}\u{2044}\u{34}", "\u{31}\u{2044}\u{32}",
+    "\u{33}\u{2044}\u{34}", "\u{41}\u{300}", "\u{41}\u{301}", "\u{41}\u{302}", "\u{41}\u{303}",
+    "\u{41}\u{308}", "\u{41}\u{30A}", "\u{43}\u{327}", "\u{45}\u{300}", "\u{45}\u{301}",
+    "\u{45}\u{302}", "\u{45}\u{308}", "\u{49}\u{300}", "\u{49}\u{301}", "\u{49}\u{302}",
+    "\u{49}\u{308}", "\u{4E}\u{303}", "\u{4F}\u{300}", "\u{4F}\u{301}", "\u{4F}\u{302}",
+    "\u{4F}\u{303}", "\u{4F}\u{308}", "\u{55}\u{300}", "\u{55}\u{301}", "\u{55}\u{302}",
+    "\u{55}\u{308}", "\u{59}\u{301}", "\u{61}\u{300}", "\u{61}\u{301}", "\u{61}\u{302}",
+    "\u{61}\u{303}", "\u{61}\u{308}", "\u{61}\u{30A}", "\u{63}\u{327}", "\u{65}\u{300}",
+    "\u{65}\u{301}", "\u{65}\u{302}", "\u{65}\u{308}", "\u{69}\u{300}", "\u{69}\u{301}",
+    "\u{69}\u{302}", "\u{69}\u{308}", "\u{6E}\u{303}", "\u{6F}\u{300}", "\u{6F}\u{301}",
+    "\u{6F}\u{302}", "\u{6F}\u{303}", "\u{6F}\u{308}", "\u{75}\u{300}", "\u{75}\u{301}",
+    "\u{75}\u{302}", "\u{75}\u{308}", "\u{79}\u{301}", "\u{79}\u{308}", "\u{41}\u{304}",
+    "\u{61}\u{304}", "\u{41}\u{306}", "\u{61}\u{306}", "\u{41}\u{328}", "\u{61}\u{328}",
+    "\u{43}\u{301}", "\u{63}\u{301}", "\u{43}\u{302}", "\u{63}\u{302}", "\u{43}\u{307}",
+    "\u{63}\u{307}", "\u{43}\u{30C}", "\u{63}\u{30C}", "\u{44}\u{30C}", "\u{64}\u{30C}",
+    "\u{45}\u{304}", "\u{65}\u{304}", "\u{45}\u{306}", "\u{65}\u{306}", "\u{45}\u{307}",
+    "\u{65}\u{307}", "\u{45}\u{328}", "\u{65}\u{328}", "\u{45}\u{30C}", "\u{65}\u{30C}",
+    "\u{47}\u{302}", "\u{67}\u{302}", "\u{47}\u{306}", "\u{67}\u{306}", "\u{47}\u{307}",
+    "\u{67}\u{307}", "\u{47}\u{327}", "\u{67}\u{327}", "\u{48}\u{302}", "\u{68}\u{302}",
+    "\u{49}\u{303}", "\u{69}\u{303}", "\u{49}\u{304}", "\u{69}\u{304}", "\u{49}\u{306}",
+    "\u{69}\u{306}", "\u{49}\u{328}", "\u{69}\u{328}", "\u{49}\u{307}", "\u{49}\u{4A}",
+    "\u{69}\u{6A}", "\u{4A}\u{302}", "\u{6A}\u{302}", "\u{4B}\u{327}", "\u{6B}\u{327}",
+    "\u{4C}\u{301}", "\u{6C}\u{301}", "\u{4C}\u{327}", "\u{6C}\u{327}", "\u{4C}\u{30C}",
+    "\u{6C}\u{30C}", "\u{4C}\u{B7}", "\u{6C}\u{B7}", "\u{4E}\u{301}", "\u{6E}\u{301}",
+    "\u{4E}\u{327}", "\u{6E}\u{327}", "\u{4E}\u{30C}", "\u{6E}\u{30C}", "\u{2BC}\u{6E}",
+    "\u{4F}\u{304}", "\u{6F}\u{304}", "\u{4F}\u{306}", "\u{6F}\u{306}", "\u{4F}\u{30B}",
+    "\u{6F}\u{30B}", "\u{52}\u{301}", "\u{72}\u{301}", "\u{52}\u{327}", "\u{72}\u{327}",
+    "\u{52}\u{30C}", "\u{72}\u{30C}", "\u{53}\u{301}", "\u{73}\u{301}", "\u{53}\u{302}",
+    "\u{73}\u{302}", "\u{53}\u{327}", "\u{73}\u{327}", "\u{53}\u{30C}", "\u{73}\u{30C}",
+    "\u{54}\u{327}", "\u{74}\u{327}", "\u{54}\u{30C}", "\u{74}\u{30C}", "\u{55}\u{303}",
+    "\u{75}\u{303}", "\u{55}\u{304}", "\u{75}\u{304}", "\u{55}\u{306}", "\u{75}\u{306}",
+    "\u{55}\u{30A}", "\u{75}\u{30A}", "\u{55}\u{30B}", "\u{75}\u{30B}", "\u{55}\u{328}",
+    "\u{75}\u{328}", "\u{57}\u{302}", "\u{77}\u{302}", "\u{59}\u{302}", "\u{79}\u{302}",
+    "\u{59}\u{308}", "\u{5A}\u{301}", "\u{7A}\u{301}", "\u{5A}\u{307}", "\u{7A}\u{307}",
+    "\u{5A}\u{30C}", "\u{7A}\u{30C}", "\u{73}", "\u{4F}\u{31B}", "\u{6F}\u{31B}", "\u{55}\u{31B}",
+    "\u{75}\u{31B}", "\u{44}\u{5A}\u{30C}", "\u{44}\u{7A}\u{30C}", "\u{64}\u{7A}\u{30C}",
+    "\u{4C}\u{4A}", "\u{4C}\u{6A}", "\u{6C}\u{6A}", "\u{4E}\u{4A}", "\u{4E}\u{6A}", "\u{6E}\u{6A}",
+    "\u{41}\u{30C}", "\u{61}\u{30C}", "\u{49}\u{30C}", "\u{69}\u{30C}", "\u{4F}\u{30C}",
+    "\u{6F}\u{30C}", "\u{55}\u{30C}", "\u{75}\u{30C}", "\u{55}\u{308}\u{304}",
+    "\u{75}\u{308}\u{304}", "\u{55}\u{308}\u{301}", "\u{75}\u{308}\u{301}", "\u{55}\u{308}\u{30C}",
+    "\u{75}\u{308}\u{30C}", "\u{55}\u{308}\u{300}", "\u{75}\u{308}\u{300}", "\u{41}\u{308}\u{304}",
+    "\u{61}\u{308}\u{304}", "\u{41}\u{307}\u{304}", "\u{61}\u{307}\u{304}", "\u{C6}\u{304}",
+    "\u{E6}\u{304}", "\u{47}\u{30C}", "\u{67}\u{30C}", "\u{4B}\u{30C}", "\u{6B}\u{30C}",
+    "\u{4F}\u{328}", "\u{6F}\u{328}", "\u{4F}\u{328}\u{304}", "\u{6F}\u{328}\u{304}",
+    "\u{1B7}\u{30C}", "\u{292}\u{30C}", "\u{6A}\u{30C}", "\u{44}\u{5A}", "\u{44}\u{7A}",
+    "\u{64}\u{7A}", "\u{47}\u{301}", "\u{67}\u{301}", "\u{4E}\u{300}", "\u{6E}\u{300}",
+    "\u{41}\u{30A}\u{301}", "\u{61}\u{30A}\u{301}", "\u{C6}\u{301}", "\u{E6}\u{301}",
+    "\u{D8}\u{301}", "\u{F8}\u{301}", "\u{41}\u{30F}", "\u{61}\u{30F}", "\u{41}\u{311}",
+    "\u{61}\u{311}", "\u{45}\u{30F}", "\u{65}\u{30F}", "\u{45}\u{311}", "\u{65}\u{311}",
+    "\u{49}\u{30F}", "\u{69}\u{30F}", "\u{49}\u{311}", "\u{69}\u{311}", "\u{4F}\u{30F}",
+    "\u{6F}\u{30F}", "\u{4F}\u{311}", "\u{6F}\u{311}", "\u{52}\u{30F}", "\u{72}\u{30F}",
+    "\u{52}\u{311}", "\u{72}\u{311}", "\u{55}\u{30F}", "\u{75}\u{30F}", "\u{55}\u{311}",
+    "\u{75}\u{311}", "\u{53}\u{326}", "\u{73}\u{326}", "\u{54}\u{326}", "\u{74}\u{326}",
+    "\u{48}\u{30C}", "\u{68}\u{30C}", "\u{41}\u{307}", "\u{61}\u{307}", "\u{45}\u{327}",
+    "\u{65}\u{327}", "\u{4F}\u{308}\u{304}", "\u{6F}\u{308}\u{304}", "\u{4F}\u{303}\u{304}",
+    "\u{6F}\u{303}\u{304}", "\u{4F}\u{307}", "\u{6F}\u{307}", "\u{4F}\u{307}\u{304}",
+    "\u{6F}\u{307}\u{304}", "\u{59}\u{304}", "\u{79}\u{304}", "\u{68}", "\u{266}", "\u{6A}",
+    "\u{72}", "\u{279}", "\u{27B}", "\u{281}", "\u{77}", "\u{79}", "\u{20}\u{306}", "\u{20}\u{307}",
+    "\u{20}\u{30A}", "\u{20}\u{328}", "\u{20}\u{303}", "\u{20}\u{30B}", "\u{263}", "\u{6C}",
+    "\u{78}", "\u{295}", "\u{300}", "\u{301}", "\u{313}", "\u{308}\u{301}", "\u{2B9}",
+    "\u{20}\u{345}", "\u{3B}", "\u{A8}\u{301}", "\u{20}\u{308}\u{301}", "\u{391}\u{301}", "\u{B7}",
+    "\u{395}\u{301}", "\u{397}\u{301}", "\u{399}\u{301}", "\u{39F}\u{301}", "\u{3A5}\u{301}",
+    "\u{3A9}\u{301}", "\u{3B9}\u{308}\u{301}", "\u{399}\u{308}", "\u{3A5}\u{308}", "\u{3B1}\u{301}",
+    "\u{3B5}\u{301}", "\u{3B7}\u{301}", "\u{3B9}\u{301}", "\u{3C5}\u{308}\u{301}", "\u{3B9}\u{308}",
+    "\u{3C5}\u{308}", "\u{3BF}\u{301}", "\u{3C5}\u{301}", "\u{3C9}\u{301}", "\u{3B2}", "\u{3B8}",
+    "\u{3A5}", "\u{3D2}\u{301}", "\u{3D2}\u{308}", "\u{3C6}", "\u{3C0}", "\u{3BA}", "\u{3C1}",
+    "\u{3C2}", "\u{398}", "\u{3B5}", "\u{3A3}", "\u{415}\u{300}", "\u{415}\u{308}",
+    "\u{413}\u{301}", "\u{406}\u{308}", "\u{41A}\u{301}", "\u{418}\u{300}", "\u{423}\u{306}",
+    "\u{418}\u{306}", "\u{438}\u{306}", "\u{435}\u{300}", "\u{435}\u{308}", "\u{433}\u{301}",
+    "\u{456}\u{308}", "\u{43A}\u{301}", "\u{438}\u{300}", "\u{443}\u{306}", "\u{474}\u{30F}",
+    "\u{475}\u{30F}", "\u{416}\u{306}", "\u{436}\u{306}", "\u{410}\u{306}", "\u{430}\u{306}",
+    "\u{410}\u{308}", "\u{430}\u{308}", "\u{415}\u{306}", "\u{435}\u{306}", "\u{4D8}\u{308}",
+    "\u{4D9}\u{308}", "\u{416}\u{308}", "\u{436}\u{308}", "\u{417}\u{308}", "\u{437}\u{308}",
+    "\u{418}\u{304}", "\u{438}\u{304}", "\u{418}\u{308}", "\u{438}\u{308}", "\u{41E}\u{308}",
+    "\u{43E}\u{308}", "\u{4E8}\u{308}", "\u{4E9}\u{308}", "\u{42D}\u{308}", "\u{44D}\u{308}",
+    "\u{423}\u{304}", "\u{443}\u{304}", "\u{423}\u{308}", "\u{443}\u{308}", "\u{423}\u{30B}",
+    "\u{443}\u{30B}", "\u{427}\u{308}", "\u{447}\u{308}", "\u{42B}\u{308}", "\u{44B}\u{308}",
+    "\u{565}\u{582}", "\u{627}\u{653}", "\u{627}\u{654}", "\u{648}\u{654}", "\u{627}\u{655}",
+    "\u{64A}\u{654}", "\u{627}\u{674}", "\u{648}\u{674}", "\u{6C7}\u{674}", "\u{64A}\u{674}",
+    "\u{6D5}\u{654}", "\u{6C1}\u{654}", "\u{6D2}\u{654}", "\u{928}\u{93C}", "\u{930}\u{93C}",
+    "\u{933}\u{93C}", "\u{915}\u{93C}", "\u{916}\u{93C}", "\u{917}\u{93C}", "\u{91C}\u{93C}",
+    "\u{921}\u{93C}", "\u{922}\u{93C}", "\u{92B}\u{93C}", "\u{92F}\u{93C}", "\u{9C7}\u{9BE}",
+    "\u{9C7}\u{9D7}", "\u{9A1}\u{9BC}", "\u{9A2}\u{9BC}", "\u{9AF}\u{9BC}", "\u{A32}\u{A3C}",
+    "\u{A38}\u{A3C}", "\u{A16}\u{A3C}", "\u{A17}\u{A3C}", "\u{A1C}\u{A3C}", "\u{A2B}\u{A3C}",
+    "\u{B47}\u{B56}", "\u{B47}\u{B3E}", "\u{B47}\u{B57}", "\u{B21}\u{B3C}", "\u{B22}\u{B3C}",
+    "\u{B92}\u{BD7}", "\u{BC6}\u{BBE}", "\u{BC7}\u{BBE}", "\u{BC6}\u{BD7}", "\u{C46}\u{C56}",
+    "\u{CBF}\u{CD5}", "\u{CC6}\u{CD5}", "\u{CC6}\u{CD6}", "\u{CC6}\u{CC2}", "\u{CC6}\u{CC2}\u{CD5}",
+    "\u{D46}\u{D3E}", "\u{D47}\u{D3E}", "\u{D46}\u{D57}", "\u{DD9}\u{DCA}", "\u{DD9}\u{DCF}",
+    "\u{DD9}\u{DCF}\u{DCA}", "\u{DD9}\u{DDF}", "\u{E4D}\u{E32}", "\u{ECD}\u{EB2}", "\u{EAB}\u{E99}",
+    "\u{EAB}\u{EA1}", "\u{F0B}", "\u{F42}\u{FB7}", "\u{F4C}\u{FB7}", "\u{F51}\u{FB7}",
+    "\u{F56}\u{FB7}", "\u{F5B}\u{FB7}", "\u{F40}\u{FB5}", "\u{F71}\u{F72}", "\u{F71}\u{F74}",
+    "\u{FB2}\u{F80}", "\u{FB2}\u{F71}\u{F80}", "\u{FB3}\u{F80}", "\u{FB3}\u{F71}\u{F80}",
+    "\u{F71}\u{F80}", "\u{F92}\u{FB7}", "\u{F9C}\u{FB7}", "\u{FA1}\u{FB7}", "\u{FA6}\u{FB7}",
+    "\u{FAB}\u{FB7}", "\u{F90}\u{FB5}", "\u{1025}\u{102E}", "\u{10DC}", "\u{1B05}\u{1B35}",
+    "\u{1B07}\u{1B35}", "\u{1B09}\u{1B35}", "\u{1B0B}\u{1B35}", "\u{1B0D}\u{1B35}",
+    "\u{1B11}\u{1B35}", "\u{1B3A}\u{1B35}", "\u{1B3C}\u{1B35}", "\u{1B3E}\u{1B35}",
+    "\u{1B3F}\u{1B35}", "\u{1B42}\u{1B35}", "\u{41}", "\u{C6}", "\u{42}", "\u{44}", "\u{45}",
+    "\u{18E}", "\u{47}", "\u{48}", "\u{49}", "\u{4A}", "\u{4B}", "\u{4C}", "\u{4D}", "\u{4E}",
+    "\u{4F}", "\u{222}", "\u{50}", "\u{52}", "\u{54}", "\u{55}", "\u{57}", "\u{250}", "\u{251}",
+    "\u{1D02}", "\u{62}", "\u{64}", "\u{65}", "\u{259}", "\u{25B}", "\u{25C}", "\u{67}", "\u{6B}",
+    "\u{6D}", "\u{14B}", "\u{254}", "\u{1D16}", "\u{1D17}", "\u{70}", "\u{74}", "\u{75}",
+    "\u{1D1D}", "\u{26F}", "\u{76}", "\u{1D25}", "\u{3B3}", "\u{3B4}", "\u{3C7}", "\u{69}",
+    "\u{43D}", "\u{252}", "\u{63}", "\u{255}", "\u{F0}", "\u{66}", "\u{25F}", "\u{261}", "\u{265}",
+    "\u{268}", "\u{269}", "\u{26A}", "\u{1D7B}", "\u{29D}", "\u{26D}", "\u{1D85}", "\u{29F}",
+    "\u{271}", "\u{270}", "\u{272}", "\u{273}", "\u{274}", "\u{275}", "\u{278}", "\u{282}",
+    "\u{283}", "\u{1AB}", "\u{289}", "\u{28A}", "\u{1D1C}", "\u{28B}", "\u{28C}", "\u{7A}",
+    "\u{290}", "\u{291}", "\u{292}", "\u{41}\u{325}", "\u{61}\u{325}", "\u{42}\u{307}",
+    "\u{62}\u{307}", "\u{42}\u{323}", "\u{62}\u{323}", "\u{42}\u{331}", "\u{62}\u{331}",
+    "\u{43}\u{327}\u{301}", "\u{63}\u{327}\u{301}", "\u{44}\u{307}", "\u{64}\u{307}",
+    "\u{44}\u{323}", "\u{64}\u{323}", "\u{44}\u{331}", "\u{64}\u{331}", "\u{44}\u{327}",
+    "\u{64}\u{327}", "\u{44}\u{32D}", "\u{64}\u{32D}", "\u{45}\u{304}\u{300}",
+    "\u{65}\u{304}\u{300}", "\u{45}\u{304}\u{301}", "\u{65}\u{304}\u{301}", "\u{45}\u{32D}",
+    "\u{65}\u{32D}", "\u{45}\u{330}", "\u{65}\u{330}", "\u{45}\u{327}\u{306}",
+    "\u{65}\u{327}\u{306}", "\u{46}\u{307}", "\u{66}\u{307}", "\u{47}\u{304}", "\u{67}\u{304}",
+    "\u{48}\u{307}", "\u{68}\u{307}", "\u{48}\u{323}", "\u{68}\u{323}", "\u{48}\u{308}",
+    "\u{68}\u{308}", "\u{48}\u{327}", "\u{68}\u{327}", "\u{48}\u{32E}", "\u{68}\u{32E}",
+    "\u{49}\u{330}", "\u{69}\u{330}", "\u{49}\u{308}\u{301}", "\u{69}\u{308}\u{301}",
+    "\u{4B}\u{301}", "\u{6B}\u{301}", "\u{4B}\u{323}", "\u{6B}\u{323}", "\u{4B}\u{331}",
+    "\u{6B}\u{331}", "\u{4C}\u{323}", "\u{6C}\u{323}", "\u{4C}\u{323}\u{304}",
+    "\u{6C}\u{323}\u{304}", "\u{4C}\u{331}", "\u{6C}\u{331}", "\u{4C}\u{32D}", "\u{6C}\u{32D}",
+    "\u{4D}\u{301}", "\u{6D}\u{301}", "\u{4D}\u{307}", "\u{6D}\u{307}", "\u{4D}\u{323}",
+    "\u{6D}\u{323}", "\u{4E}\u{307}", "\u{6E}\u{307}", "\u{4E}\u{323}", "\u{6E}\u{323}",
+    "\u{4E}\u{331}", "\u{6E}\u{331}", "\u{4E}\u{32D}", "\u{6E}\u{32D}", "\u{4F}\u{303}\u{301}",
+    "\u{6F}\u{303}\u{301}", "\u{4F}\u{303}\u{308}", "\u{6F}\u{303}\u{308}", "\u{4F}\u{304}\u{300}",
+    "\u{6F}\u{304}\u{300}", "\u{4F}\u{304}\u{301}", "\u{6F}\u{304}\u{301}", "\u{50}\u{301}",
+    "\u{70}\u{301}", "\u{50}\u{307}", "\u{70}\u{307}", "\u{52}\u{307}", "\u{72}\u{307}",
+    "\u{52}\u{323}", "\u{72}\u{323}", "\u{52}\u{323}\u{304}", "\u{72}\u{323}\u{304}",
+    "\u{52}\u{331}", "\u{72}\u{331}", "\u{53}\u{307}", "\u{73}\u{307}", "\u{53}\u{323}",
+    "\u{73}\u{323}", "\u{53}\u{301}\u{307}", "\u{73}\u{301}\u{307}", "\u{53}\u{30C}\u{307}",
+    "\u{73}\u{30C}\u{307}", "\u{53}\u{323}\u{307}", "\u{73}\u{323}\u{307}", "\u{54}\u{307}",
+    "\u{74}\u{307}", "\u{54}\u{323}", "\u{74}\u{323}", "\u{54}\u{331}", "\u{74}\u{331}",
+    "\u{54}\u{32D}", "\u{74}\u{32D}", "\u{55}\u{324}", "\u{75}\u{324}", "\u{55}\u{330}",
+    "\u{75}\u{330}", "\u{55}\u{32D}", "\u{75}\u{32D}", "\u{55}\u{303}\u{301}",
+    "\u{75}\u{303}\u{301}", "\u{55}\u{304}\u{308}", "\u{75}\u{304}\u{308}", "\u{56}\u{303}",
+    "\u{76}\u{303}", "\u{56}\u{323}", "\u{76}\u{323}", "\u{57}\u{300}", "\u{77}\u{300}",
+    "\u{57}\u{301}", "\u{77}\u{301}", "\u{57}\u{308}", "\u{77}\u{308}", "\u{57}\u{307}",
+    "\u{77}\u{307}", "\u{57}\u{323}", "\u{77}\u{323}", "\u{58}\u{307}", "\u{78}\u{307}",
+    "\u{58}\u{308}", "\u{78}\u{308}", "\u{59}\u{307}", "\u{79}\u{307}", "\u{5A}\u{302}",
+    "\u{7A}\u{302}", "\u{5A}\u{323}", "\u{7A}\u{323}", "\u{5A}\u{331}", "\u{7A}\u{331}",
+    "\u{68}\u{331}", "\u{74}\u{308}", "\u{77}\u{30A}", "\u{79}\u{30A}", "\u{61}\u{2BE}",
+    "\u{17F}\u{307}", "\u{41}\u{323}", "\u{61}\u{323}", "\u{41}\u{309}", "\u{61}\u{309}",
+    "\u{41}\u{302}\u{301}", "\u{61}\u{302}\u{301}", "\u{41}\u{302}\u{300}", "\u{61}\u{302}\u{300}",
+    "\u{41}\u{302}\u{309}", "\u{61}\u{302}\u{309}", "\u{41}\u{302}\u{303}", "\u{61}\u{302}\u{303}",
+    "\u{41}\u{323}\u{302}", "\u{61}\u{323}\u{302}", "\u{41}\u{306}\u{301}", "\u{61}\u{306}\u{301}",
+    "\u{41}\u{306}\u{300}", "\u{61}\u{306}\u{300}", "\u{41}\u{306}\u{309}", "\u{61}\u{306}\u{309}",
+    "\u{41}\u{306}\u{303}", "\u{61}\u{306}\u{303}", "\u{41}\u{323}\u{306}", "\u{61}\u{323}\u{306}",
+    "\u{45}\u{323}", "\u{65}\u{323}", "\u{45}\u{309}", "\u{65}\u{309}", "\u{45}\u{303}",
+    "\u{65}\u{303}", "\u{45}\u{302}\u{301}", "\u{65}\u{302}\u{301}", "\u{45}\u{302}\u{300}",
+    "\u{65}\u{302}\u{300}", "\u{45}\u{302}\u{309}", "\u{65}\u{302}\u{309}", "\u{45}\u{302}\u{303}",
+    "\u{65}\u{302}\u{303}", "\u{45}\u{323}\u{302}", "\u{65}\u{323}\u{302}", "\u{49}\u{309}",
+    "\u{69}\u{309}", "\u{49}\u{323}", "\u{69}\u{323}", "\u{4F}\u{323}", "\u{6F}\u{323}",
+    "\u{4F}\u{309}", "\u{6F}\u{309}", "\u{4F}\u{302}\u{301}", "\u{6F}\u{302}\u{301}",
+    "\u{4F}\u{302}\u{300}", "\u{6F}\u{302}\u{300}", "\u{4F}\u{302}\u{309}", "\u{6F}\u{302}\u{309}",
+    "\u{4F}\u{302}\u{303}", "\u{6F}\u{302}\u{303}", "\u{4F}\u{323}\u{302}", "\u{6F}\u{323}\u{302}",
+    "\u{4F}\u{31B}\u{301}", "\u{6F}\u{31B}\u{301}", "\u{4F}\u{31B}\u{300}", "\u{6F}\u{31B}\u{300}",
+    "\u{4F}\u{31B}\u{309}", "\u{6F}\u{31B}\u{309}", "\u{4F}\u{31B}\u{303}", "\u{6F}\u{31B}\u{303}",
+    "\u{4F}\u{31B}\u{323}", "\u{6F}\u{31B}\u{323}", "\u{55}\u{323}", "\u{75}\u{323}",
+    "\u{55}\u{309}", "\u{75}\u{309}", "\u{55}\u{31B}\u{301}", "\u{75}\u{31B}\u{301}",
+    "\u{55}\u{31B}\u{300}", "\u{75}\u{31B}\u{300}", "\u{55}\u{31B}\u{309}", "\u{75}\u{31B}\u{309}",
+    "\u{55}\u{31B}\u{303}", "\u{75}\u{31B}\u{303}", "\u{55}\u{31B}\u{323}", "\u{75}\u{31B}\u{323}",
+    "\u{59}\u{300}", "\u{79}\u{300}", "\u{59}\u{323}", "\u{79}\u{323}", "\u{59}\u{309}",
+    "\u{79}\u{309}", "\u{59}\u{303}", "\u{79}\u{303}", "\u{3B1}\u{313}", "\u{3B1}\u{314}",
+    "\u{3B1}\u{313}\u{300}", "\u{3B1}\u{314}\u{300}", "\u{3B1}\u{313}\u{301}",
+    "\u{3B1}\u{314}\u{301}", "\u{3B1}\u{313}\u{342}", "\u{3B1}\u{314}\u{342}", "\u{391}\u{313}",
+    "\u{391}\u{314}", "\u{391}\u{313}\u{300}", "\u{391}\u{314}\u{300}", "\u{391}\u{313}\u{301}",
+    "\u{391}\u{314}\u{301}", "\u{391}\u{313}\u{342}", "\u{391}\u{314}\u{342}", "\u{3B5}\u{313}",
+    "\u{3B5}\u{314}", "\u{3B5}\u{313}\u{300}", "\u{3B5}\u{314}\u{300}", "\u{3B5}\u{313}\u{301}",
+    "\u{3B5}\u{314}\u{301}", "\u{395}\u{313}", "\u{395}\u{314}", "\u{395}\u{313}\u{300}",
+    "\u{395}\u{314}\u{300}", "\u{395}\u{313}\u{301}", "\u{395}\u{314}\u{301}", "\u{3B7}\u{313}",
+    "\u{3B7}\u{314}", "\u{3B7}\u{313}\u{300}", "\u{3B7}\u{314}\u{300}", "\u{3B7}\u{313}\u{301}",
+    "\u{3B7}\u{314}\u{301}", "\u{3B7}\u{313}\u{342}", "\u{3B7}\u{314}\u{342}", "\u{397}\u{313}",
+    "\u{397}\u{314}", "\u{397}\u{313}\u{300}", "\u{397}\u{314}\u{300}", "\u{397}\u{313}\u{301}",
+    "\u{397}\u{314}\u{301}", "\u{397}\u{313}\u{342}", "\u{397}\u{314}\u{342}", "\u{3B9}\u{313}",
+    "\u{3B9}\u{314}", "\u{3B9}\u{313}\u{300}", "\u{3B9}\u{314}\u{300}", "\u{3B9}\u{313}\u{301}",
+    "\u{3B9}\u{314}\u{301}", "\u{3B9}\u{313}\u{342}", "\u{3B9}\u{314}\u{342}", "\u{399}\u{313}",
+    "\u{399}\u{314}", "\u{399}\u{313}\u{300}", "\u{399}\u{314}\u{300}", "\u{399}\u{313}\u{301}",
+    "\u{399}\u{314}\u{301}", "\u{399}\u{313}\u{342}", "\u{399}\u{314}\u{342}", "\u{3BF}\u{313}",
+    "\u{3BF}\u{314}", "\u{3BF}\u{313}\u{300}", "\u{3BF}\u{314}\u{300}", "\u{3BF}\u{313}\u{301}",
+    "\u{3BF}\u{314}\u{301}", "\u{39F}\u{313}", "\u{39F}\u{314}", "\u{39F}\u{313}\u{300}",
+    "\u{39F}\u{314}\u{300}", "\u{39F}\u{313}\u{301}", "\u{39F}\u{314}\u{301}", "\u{3C5}\u{313}",
+    "\u{3C5}\u{314}", "\u{3C5}\u{313}\u{300}", "\u{3C5}\u{314}\u{300}", "\u{3C5}\u{313}\u{301}",
+    "\u{3C5}\u{314}\u{301}", "\u{3C5}\u{313}\u{342}", "\u{3C5}\u{314}\u{342}", "\u{3A5}\u{314}",
+    "\u{3A5}\u{314}\u{300}", "\u{3A5}\u{314}\u{301}", "\u{3A5}\u{314}\u{342}", "\u{3C9}\u{313}",
+    "\u{3C9}\u{314}", "\u{3C9}\u{313}\u{300}", "\u{3C9}\u{314}\u{300}", "\u{3C9}\u{313}\u{301}",
+    "\u{3C9}\u{314}\u{301}", "\u{3C9}\u{313}\u{342}", "\u{3C9}\u{314}\u{342}", "\u{3A9}\u{313}",
+    "\u{3A9}\u{314}", "\u{3A9}\u{313}\u{300}", "\u{3A9}\u{314}\u{300}", "\u{3A9}\u{313}\u{301}",
+    "\u{3A9}\u{314}\u{301}", "\u{3A9}\u{313}\u{342}", "\u{3A9}\u{314}\u{342}", "\u{3B1}\u{300}",
+    "\u{3B5}\u{300}", "\u{3B7}\u{300}", "\u{3B9}\u{300}", "\u{3BF}\u{300}", "\u{3C5}\u{300}",
+    "\u{3C9}\u{300}", "\u{3B1}\u{313}\u{345}", "\u{3B1}\u{314}\u{345}",
+    "\u{3B1}\u{313}\u{300}\u{345}", "\u{3B1}\u{314}\u{300}\u{345}", "\u{3B1}\u{313}\u{301}\u{345}",
+    "\u{3B1}\u{314}\u{301}\u{345}", "\u{3B1}\u{313}\u{342}\u{345}", "\u{3B1}\u{314}\u{342}\u{345}",
+    "\u{391}\u{313}\u{345}", "\u{391}\u{314}\u{345}", "\u{391}\u{313}\u{300}\u{345}",
+    "\u{391}\u{314}\u{300}\u{345}", "\u{391}\u{313}\u{301}\u{345}", "\u{391}\u{314}\u{301}\u{345}",
+    "\u{391}\u{313}\u{342}\u{345}", "\u{391}\u{314}\u{342}\u{345}", "\u{3B7}\u{313}\u{345}",
+    "\u{3B7}\u{314}\u{345}", "\u{3B7}\u{313}\u{300}\u{345}", "\u{3B7}\u{314}\u{300}\u{345}",
+    "\u{3B7}\u{313}\u{301}\u{345}", "\u{3B7}\u{314}\u{301}\u{345}", "\u{3B7}\u{313}\u{342}\u{345}",
+    "\u{3B7}\u{314}\u{342}\u{345}", "\u{397}\u{313}\u{345}", "\u{397}\u{314}\u{345}",
+    "\u{397}\u{313}\u{300}\u{345}", "\u{397}\u{314}\u{300}\u{345}", "\u{397}\u{313}\u{301}\u{345}",
+    "\u{397}\u{314}\u{301}\u{345}", "\u{397}\u{313}\u{342}\u{345}", "\u{397}\u{314}\u{342}\u{345}",
+    "\u{3C9}\u{313}\u{345}", "\u{3C9}\u{314}\u{345}", "\u{3C9}\u{313}\u{300}\u{345}",
+    "\u{3C9}\u{314}\u{300}\u{345}", "\u{3C9}\u{313}\u{301}\u{345}", "\u{3C9}\u{314}\u{301}\u{345}",
+    "\u{3C9}\u{313}\u{342}\u{345}", "\u{3C9}\u{314}\u{342}\u{345}", "\u{3A9}\u{313}\u{345}",
+    "\u{3A9}\u{314}\u{345}", "\u{3A9}\u{313}\u{300}\u{345}", "\u{3A9}\u{314}\u{300}\u{345}",
+    "\u{3A9}\u{313}\u{301}\u{345}", "\u{3A9}\u{314}\u{301}\u{345}", "\u{3A9}\u{313}\u{342}\u{345}",
+    "\u{3A9}\u{314}\u{342}\u{345}", "\u{3B1}\u{306}", "\u{3B1}\u{304}", "\u{3B1}\u{300}\u{345}",
+    "\u{3B1}\u{345}", "\u{3B1}\u{301}\u{345}", "\u{3B1}\u{342}", "\u{3B1}\u{342}\u{345}",
+    "\u{391}\u{306}", "\u{391}\u{304}", "\u{391}\u{300}", "\u{391}\u{345}", "\u{20}\u{313}",
+    "\u{3B9}", "\u{20}\u{342}", "\u{A8}\u{342}", "\u{20}\u{308}\u{342}", "\u{3B7}\u{300}\u{345}",
+    "\u{3B7}\u{345}", "\u{3B7}\u{301}\u{345}", "\u{3B7}\u{342}", "\u{3B7}\u{342}\u{345}",
+    "\u{395}\u{300}", "\u{397}\u{300}", "\u{397}\u{345}", "\u{1FBF}\u{300}", "\u{20}\u{313}\u{300}",
+    "\u{1FBF}\u{301}", "\u{20}\u{313}\u{301}", "\u{1FBF}\u{342}", "\u{20}\u{313}\u{342}",
+    "\u{3B9}\u{306}", "\u{3B9}\u{304}", "\u{3B9}\u{308}\u{300}", "\u{3B9}\u{342}",
+    "\u{3B9}\u{308}\u{342}", "\u{399}\u{306}", "\u{399}\u{304}", "\u{399}\u{300}",
+    "\u{1FFE}\u{300}", "\u{20}\u{314}\u{300}", "\u{1FFE}\u{301}", "\u{20}\u{314}\u{301}",
+    "\u{1FFE}\u{342}", "\u{20}\u{314}\u{342}", "\u{3C5}\u{306}", "\u{3C5}\u{304}",
+    "\u{3C5}\u{308}\u{300}", "\u{3C1}\u{313}", "\u{3C1}\u{314}", "\u{3C5}\u{342}",
+    "\u{3C5}\u{308}\u{342}", "\u{3A5}\u{306}", "\u{3A5}\u{304}", "\u{3A5}\u{300}", "\u{3A1}\u{314}",
+    "\u{A8}\u{300}", "\u{20}\u{308}\u{300}", "\u{60}", "\u{3C9}\u{300}\u{345}", "\u{3C9}\u{345}",
+    "\u{3C9}\u{301}\u{345}", "\u{3C9}\u{342}", "\u{3C9}\u{342}\u{345}", "\u{39F}\u{300}",
+    "\u{3A9}\u{300}", "\u{3A9}\u{345}", "\u{B4}", "\u{20}\u{314}", "\u{2002}", "\u{2003}",
+    "\u{2010}", "\u{20}\u{333}", "\u{2E}", "\u{2E}\u{2E}", "\u{2E}\u{2E}\u{2E}", "\u{2032}\u{2032}",
+    "\u{2032}\u{2032}\u{2032}", "\u{2035}\u{2035}", "\u{2035}\u{2035}\u{2035}", "\u{21}\u{21}",
+    "\u{20}\u{305}", "\u{3F}\u{3F}", "\u{3F}\u{21}", "\u{21}\u{3F}",
+    "\u{2032}\u{2032}\u{2032}\u{2032}", "\u{30}", "\u{34}", "\u{35}", "\u{36}", "\u{37}", "\u{38}",
+    "\u{39}", "\u{2B}", "\u{2212}", "\u{3D}", "\u{28}", "\u{29}", "\u{6E}", "\u{52}\u{73}",
+    "\u{61}\u{2F}\u{63}", "\u{61}\u{2F}\u{73}", "\u{43}", "\u{B0}\u{43}", "\u{63}\u{2F}\u{6F}",
+    "\u{63}\u{2F}\u{75}", "\u{190}", "\u{B0}\u{46}", "\u{127}", "\u{4E}\u{6F}", "\u{51}",
+    "\u{53}\u{4D}", "\u{54}\u{45}\u{4C}", "\u{54}\u{4D}", "\u{5A}", "\u{3A9}", "\u{46}", "\u{5D0}",
+    "\u{5D1}", "\u{5D2}", "\u{5D3}", "\u{46}\u{41}\u{58}", "\u{393}", "\u{3A0}", "\u{2211}",
+    "\u{31}\u{2044}\u{37}", "\u{31}\u{2044}\u{39}", "\u{31}\u{2044}\u{31}\u{30}",
+    "\u{31}\u{2044}\u{33}", "\u{32}\u{2044}\u{33}", "\u{31}\u{2044}\u{35}", "\u{32}\u{2044}\u{35}",
+    "\u{33}\u{2044}\u{35}", "\u{34}\u{2044}\u{35}", "\u{31}\u{2044}\u{36}", "\u{35}\u{2044}\u{36}",
+    "\u{31}\u{2044}\u{38}", "\u{33}\u{2044}\u{38}", "\u{35}\u{2044}\u{38}", "\u{37}\u{2044}\u{38}",
+    "\u{31}\u{2044}", "\u{49}\u{49}", "\u{49}\u{49}\u{49}", "\u{49}\u{56}", "\u{56}",
+    "\u{56}\u{49}", "\u{56}\u{49}\u{49}", "\u{56}\u{49}\u{49}\u{49}", "\u{49}\u{58}", "\u{58}",
+    "\u{58}\u{49}", "\u{58}\u{49}\u{49}", "\u{69}\u{69}", "\u{69}\u{69}\u{69}", "\u{69}\u{76}",
+    "\u{76}\u{69}", "\u{76}\u{69}\u{69}", "\u{76}\u{69}\u{69}\u{69}", "\u{69}\u{78}",
+    "\u{78}\u{69}", "\u{78}\u{69}\u{69}", "\u{30}\u{2044}\u{33}", "\u{2190}\u{338}",
+    "\u{2192}\u{338}", "\u{2194}\u{338}", "\u{21D0}\u{338}", "\u{21D4}\u{338}", "\u{21D2}\u{338}",
+    "\u{2203}\u{338}", "\u{2208}\u{338}", "\u{220B}\u{338}", "\u{2223}\u{338}", "\u{2225}\u{338}",
+    "\u{222B}\u{222B}", "\u{222B}\u{222B}\u{222B}", "\u{222E}\u{222E}", "\u{222E}\u{222E}\u{222E}",
+    "\u{223C}\u{338}", "\u{2243}\u{338}", "\u{2245}\u{338}", "\u{2248}\u{338}", "\u{3D}\u{338}",
+    "\u{2261}\u{338}", "\u{224D}\u{338}", "\u{3C}\u{338}", "\u{3E}\u{338}", "\u{2264}\u{338}",
+    "\u{2265}\u{338}", "\u{2272}\u{338}", "\u{2273}\u{338}", "\u{2276}\u{338}", "\u{2277}\u{338}",
+    "\u{227A}\u{338}", "\u{227B}\u{338}", "\u{2282}\u{338}", "\u{2283}\u{338}", "\u{2286}\u{338}",
+    "\u{2287}\u{338}", "\u{22A2}\u{338}", "\u{22A8}\u{338}", "\u{22A9}\u{338}", "\u{22AB}\u{338}",
+    "\u{227C}\u{338}", "\u{227D}\u{338}", "\u{2291}\u{338}", "\u{2292}\u{338}", "\u{22B2}\u{338}",
+    "\u{22B3}\u{338}", "\u{22B4}\u{338}", "\u{22B5}\u{338}", "\u{3008}", "\u{3009}", "\u{31}\u{30}",
+    "\u{31}\u{31}", "\u{31}\u{32}", "\u{31}\u{33}", "\u{31}\u{34}", "\u{31}\u{35}", "\u{31}\u{36}",
+    "\u{31}\u{37}", "\u{31}\u{38}", "\u{31}\u{39}", "\u{32}\u{30}", "\u{28}\u{31}\u{29}",
+    "\u{28}\u{32}\u{29}", "\u{28}\u{33}\u{29}", "\u{28}\u{34}\u{29}", "\u{28}\u{35}\u{29}",
+    "\u{28}\u{36}\u{29}", "\u{28}\u{37}\u{29}", "\u{28}\u{38}\u{29}", "\u{28}\u{39}\u{29}",
+    "\u{28}\u{31}\u{30}\u{29}", "\u{28}\u{31}\u{31}\u{29}", "\u{28}\u{31}\u{32}\u{29}",
+    "\u{28}\u{31}\u{33}\u{29}", "\u{28}\u{31}\u{34}\u{29}", "\u{28}\u{31}\u{35}\u{29}",
+    "\u{28}\u{31}\u{36}\u{29}", "\u{28}\u{31}\u{37}\u{29}", "\u{28}\u{31}\u{38}\u{29}",
+    "\u{28}\u{31}\u{39}\u{29}", "\u{28}\u{32}\u{30}\u{29}", "\u{31}\u{2E}", "\u{32}\u{2E}",
+    "\u{33}\u{2E}", "\u{34}\u{2E}", "\u{35}\u{2E}", "\u{36}\u{2E}", "\u{37}\u{2E}", "\u{38}\u{2E}",
+    "\u{39}\u{2E}", "\u{31}\u{30}\u{2E}", "\u{31}\u{31}\u{2E}", "\u{31}\u{32}\u{2E}",
+    "\u{31}\u{33}\u{2E}", "\u{31}\u{34}\u{2E}", "\u{31}\u{35}\u{2E}", "\u{31}\u{36}\u{2E}",
+    "\u{31}\u{37}\u{2E}", "\u{31}\u{38}\u{2E}", "\u{31}\u{39}\u{2E}", "\u{32}\u{30}\u{2E}",
+    "\u{28}\u{61}\u{29}", "\u{28}\u{62}\u{29}", "\u{28}\u{63}\u{29}", "\u{28}\u{64}\u{29}",
+    "\u{28}\u{65}\u{29}", "\u{28}\u{66}\u{29}", "\u{28}\u{67}\u{29}", "\u{28}\u{68}\u{29}",
+    "\u{28}\u{69}\u{29}", "\u{28}\u{6A}\u{29}", "\u{28}\u{6B}\u{29}", "\u{28}\u{6C}\u{29}",
+    "\u{28}\u{6D}\u{29}", "\u{28}\u{6E}\u{29}", "\u{28}\u{6F}\u{29}", "\u{28}\u{70}\u{29}",
+    "\u{28}\u{71}\u{29}", "\u{28}\u{72}\u{29}", "\u{28}\u{73}\u{29}", "\u{28}\u{74}\u{29}",
+    "\u{28}\u{75}\u{29}", "\u{28}\u{76}\u{29}", "\u{28}\u{77}\u{29}", "\u{28}\u{78}\u{29}",
+    "\u{28}\u{79}\u{29}", "\u{28}\u{7A}\u{29}", "\u{53}", "\u{59}", "\u{71}",
+    "\u{222B}\u{222B}\u{222B}\u{222B}", "\u{3A}\u{3A}\u{3D}", "\u{3D}\u{3D}", "\u{3D}\u{3D}\u{3D}",
+    "\u{2ADD}\u{338}", "\u{2D61}", "\u{6BCD}", "\u{9F9F}", "\u{4E00}", "\u{4E28}", "\u{4E36}",
+    "\u{4E3F}", "\u{4E59}", "\u{4E85}", "\u{4E8C}", "\u{4EA0}", "\u{4EBA}", "\u{513F}", "\u{5165}",
+    "\u{516B}", "\u{5182}", "\u{5196}", "\u{51AB}", "\u{51E0}", "\u{51F5}", "\u{5200}", "\u{529B}",
+    "\u{52F9}", "\u{5315}", "\u{531A}", "\u{5338}", "\u{5341}", "\u{535C}", "\u{5369}", "\u{5382}",
+    "\u{53B6}", "\u{53C8}", "\u{53E3}", "\u{56D7}", "\u{571F}", "\u{58EB}", "\u{5902}", "\u{590A}",
+    "\u{5915}", "\u{5927}", "\u{5973}", "\u{5B50}", "\u{5B80}", "\u{5BF8}", "\u{5C0F}", "\u{5C22}",
+    "\u{5C38}", "\u{5C6E}", "\u{5C71}", "\u{5DDB}", "\u{5DE5}", "\u{5DF1}", "\u{5DFE}", "\u{5E72}",
+    "\u{5E7A}", "\u{5E7F}", "\u{5EF4}", "\u{5EFE}", "\u{5F0B}", "\u{5F13}", "\u{5F50}", "\u{5F61}",
+    "\u{5F73}", "\u{5FC3}", "\u{6208}", "\u{6236}", "\u{624B}", "\u{652F}", "\u{6534}", "\u{6587}",
+    "\u{6597}", "\u{65A4}", "\u{65B9}", "\u{65E0}", "\u{65E5}", "\u{66F0}", "\u{6708}", "\u{6728}",
+    "\u{6B20}", "\u{6B62}", "\u{6B79}", "\u{6BB3}", "\u{6BCB}", "\u{6BD4}", "\u{6BDB}", "\u{6C0F}",
+    "\u{6C14}", "\u{6C34}", "\u{706B}", "\u{722A}", "\u{7236}", "\u{723B}", "\u{723F}", "\u{7247}",
+    "\u{7259}", "\u{725B}", "\u{72AC}", "\u{7384}", "\u{7389}", "\u{74DC}", "\u{74E6}", "\u{7518}",
+    "\u{751F}", "\u{7528}", "\u{7530}", "\u{758B}", "\u{7592}", "\u{7676}", "\u{767D}", "\u{76AE}",
+    "\u{76BF}", "\u{76EE}", "\u{77DB}", "\u{77E2}", "\u{77F3}", "\u{793A}", "\u{79B8}", "\u{79BE}",
+    "\u{7A74}", "\u{7ACB}", "\u{7AF9}", "\u{7C73}", "\u{7CF8}", "\u{7F36}", "\u{7F51}", "\u{7F8A}",
+    "\u{7FBD}", "\u{8001}", "\u{800C}", "\u{8012}", "\u{8033}", "\u{807F}", "\u{8089}", "\u{81E3}",
+    "\u{81EA}", "\u{81F3}", "\u{81FC}", "\u{820C}", "\u{821B}", "\u{821F}", "\u{826E}", "\u{8272}",
+    "\u{8278}", "\u{864D}", "\u{866B}", "\u{8840}", "\u{884C}", "\u{8863}", "\u{897E}", "\u{898B}",
+    "\u{89D2}", "\u{8A00}", "\u{8C37}", "\u{8C46}", "\u{8C55}", "\u{8C78}", "\u{8C9D}", "\u{8D64}",
+    "\u{8D70}", "\u{8DB3}", "\u{8EAB}", "\u{8ECA}", "\u{8F9B}", "\u{8FB0}", "\u{8FB5}", "\u{9091}",
+    "\u{9149}", "\u{91C6}", "\u{91CC}", "\u{91D1}", "\u{9577}", "\u{9580}", "\u{961C}", "\u{96B6}",
+    "\u{96B9}", "\u{96E8}", "\u{9751}", "\u{975E}", "\u{9762}", "\u{9769}", "\u{97CB}", "\u{97ED}",
+    "\u{97F3}", "\u{9801}", "\u{98A8}", "\u{98DB}", "\u{98DF}", "\u{9996}", "\u{9999}", "\u{99AC}",
+    "\u{9AA8}", "\u{9AD8}", "\u{9ADF}", "\u{9B25}", "\u{9B2F}", "\u{9B32}", "\u{9B3C}", "\u{9B5A}",
+    "\u{9CE5}", "\u{9E75}", "\u{9E7F}", "\u{9EA5}", "\u{9EBB}", "\u{9EC3}", "\u{9ECD}", "\u{9ED1}",
+    "\u{9EF9}", "\u{9EFD}", "\u{9F0E}", "\u{9F13}", "\u{9F20}", "\u{9F3B}", "\u{9F4A}", "\u{9F52}",
+    "\u{9F8D}", "\u{9F9C}", "\u{9FA0}", "\u{3012}", "\u{5344}", "\u{5345}", "\u{304B}\u{3099}",
+    "\u{304D}\u{3099}", "\u{304F}\u{3099}", "\u{3051}\u{3099}", "\u{3053}\u{3099}",
+    "\u{3055}\u{3099}", "\u{3057}\u{3099}", "\u{3059}\u{3099}", "\u{305B}\u{3099}",
+    "\u{305D}\u{3099}", "\u{305F}\u{3099}", "\u{3061}\u{3099}", "\u{3064}\u{3099}",
+    "\u{3066}\u{3099}", "\u{3068}\u{3099}", "\u{306F}\u{3099}", "\u{306F}\u{309A}",
+    "\u{3072}\u{3099}", "\u{3072}\u{309A}", "\u{3075}\u{3099}", "\u{3075}\u{309A}",
+    "\u{3078}\u{3099}", "\u{3078}\u{309A}", "\u{307B}\u{3099}", "\u{307B}\u{309A}",
+    "\u{3046}\u{3099}", "\u{20}\u{3099}", "\u{20}\u{309A}", "\u{309D}\u{3099}", "\u{3088}\u{308A}",
+    "\u{30AB}\u{3099}", "\u{30AD}\u{3099}", "\u{30AF}\u{3099}", "\u{30B1}\u{3099}",
+    "\u{30B3}\u{3099}", "\u{30B5}\u{3099}", "\u{30B7}\u{3099}", "\u{30B9}\u{3099}",
+    "\u{30BB}\u{3099}", "\u{30BD}\u{3099}", "\u{30BF}\u{3099}", "\u{30C1}\u{3099}",
+    "\u{30C4}\u{3099}", "\u{30C6}\u{3099}", "\u{30C8}\u{3099}", "\u{30CF}\u{3099}",
+    "\u{30CF}\u{309A}", "\u{30D2}\u{3099}", "\u{30D2}\u{309A}", "\u{30D5}\u{3099}",
+    "\u{30D5}\u{309A}", "\u{30D8}\u{3099}", "\u{30D8}\u{309A}", "\u{30DB}\u{3099}",
+    "\u{30DB}\u{309A}", "\u{30A6}\u{3099}", "\u{30EF}\u{3099}", "\u{30F0}\u{3099}",
+    "\u{30F1}\u{3099}", "\u{30F2}\u{3099}", "\u{30FD}\u{3099}", "\u{30B3}\u{30C8}", "\u{1100}",
+    "\u{1101}", "\u{11AA}", "\u{1102}", "\u{11AC}", "\u{11AD}", "\u{1103}", "\u{1104}", "\u{1105}",
+    "\u{11B0}", "\u{11B1}", "\u{11B2}", "\u{11B3}", "\u{11B4}", "\u{11B5}", "\u{111A}", "\u{1106}",
+    "\u{1107}", "\u{1108}", "\u{1121}", "\u{1109}", "\u{110A}", "\u{110B}", "\u{110C}", "\u{110D}",
+    "\u{110E}", "\u{110F}", "\u{1110}", "\u{1111}", "\u{1112}", "\u{1161}", "\u{1162}", "\u{1163}",
+    "\u{1164}", "\u{1165}", "\u{1166}", "\u{1167}", "\u{1168}", "\u{1169}", "\u{116A}", "\u{116B}",
+    "\u{116C}", "\u{116D}", "\u{116E}", "\u{116F}", "\u{1170}", "\u{1171}", "\u{1172}", "\u{1173}",
+    "\u{1174}", "\u{1175}", "\u{1160}", "\u{1114}", "\u{1115}", "\u{11C7}", "\u{11C8}", "\u{11CC}",
+    "\u{11CE}", "\u{11D3}", "\u{11D7}", "\u{11D9}", "\u{111C}", "\u{11DD}", "\u{11DF}", "\u{111D}",
+    "\u{111E}", "\u{1120}", "\u{1122}", "\u{1123}", "\u{1127}", "\u{1129}", "\u{112B}", "\u{112C}",
+    "\u{112D}", "\u{112E}", "\u{112F}", "\u{1132}", "\u{1136}", "\u{1140}", "\u{1147}", "\u{114C}",
+    "\u{11F1}", "\u{11F2}", "\u{1157}", "\u{1158}", "\u{1159}", "\u{1184}", "\u{1185}", "\u{1188}",
+    "\u{1191}", "\u{1192}", "\u{1194}", "\u{119E}", "\u{11A1}", "\u{4E09}", "\u{56DB}", "\u{4E0A}",
+    "\u{4E2D}", "\u{4E0B}", "\u{7532}", "\u{4E19}", "\u{4E01}", "\u{5929}", "\u{5730}",
+    "\u{28}\u{1100}\u{29}", "\u{28}\u{1102}\u{29}", "\u{28}\u{1103}\u{29}", "\u{28}\u{1105}\u{29}",
+    "\u{28}\u{1106}\u{29}", "\u{28}\u{1107}\u{29}", "\u{28}\u{1109}\u{29}", "\u{28}\u{110B}\u{29}",
+    "\u{28}\u{110C}\u{29}", "\u{28}\u{110E}\u{29}", "\u{28}\u{110F}\u{29}", "\u{28}\u{1110}\u{29}",
+    "\u{28}\u{1111}\u{29}", "\u{28}\u{1112}\u{29}", "\u{28}\u{1100}\u{1161}\u{29}",
+    "\u{28}\u{1102}\u{1161}\u{29}", "\u{28}\u{1103}\u{1161}\u{29}", "\u{28}\u{1105}\u{1161}\u{29}",
+    "\u{28}\u{1106}\u{1161}\u{29}", "\u{28}\u{1107}\u{1161}\u{29}", "\u{28}\u{1109}\u{1161}\u{29}",
+    "\u{28}\u{110B}\u{1161}\u{29}", "\u{28}\u{110C}\u{1161}\u{29}", "\u{28}\u{110E}\u{1161}\u{29}",
+    "\u{28}\u{110F}\u{1161}\u{29}", "\u{28}\u{1110}\u{1161}\u{29}", "\u{28}\u{1111}\u{1161}\u{29}",
+    "\u{28}\u{1112}\u{1161}\u{29}", "\u{28}\u{110C}\u{116E}\u{29}",
+    "\u{28}\u{110B}\u{1169}\u{110C}\u{1165}\u{11AB}\u{29}",
+    "\u{28}\u{110B}\u{1169}\u{1112}\u{116E}\u{29}", "\u{28}\u{4E00}\u{29}", "\u{28}\u{4E8C}\u{29}",
+    "\u{28}\u{4E09}\u{29}", "\u{28}\u{56DB}\u{29}", "\u{28}\u{4E94}\u{29}", "\u{28}\u{516D}\u{29}",
+    "\u{28}\u{4E03}\u{29}", "\u{28}\u{516B}\u{29}", "\u{28}\u{4E5D}\u{29}", "\u{28}\u{5341}\u{29}",
+    "\u{28}\u{6708}\u{29}", "\u{28}\u{706B}\u{29}", "\u{28}\u{6C34}\u{29}", "\u{28}\u{6728}\u{29}",
+    "\u{28}\u{91D1}\u{29}", "\u{28}\u{571F}\u{29}", "\u{28}\u{65E5}\u{29}", "\u{28}\u{682A}\u{29}",
+    "\u{28}\u{6709}\u{29}", "\u{28}\u{793E}\u{29}", "\u{28}\u{540D}\u{29}", "\u{28}\u{7279}\u{29}",
+    "\u{28}\u{8CA1}\u{29}", "\u{28}\u{795D}\u{29}", "\u{28}\u{52B4}\u{29}", "\u{28}\u{4EE3}\u{29}",
+    "\u{28}\u{547C}\u{29}", "\u{28}\u{5B66}\u{29}", "\u{28}\u{76E3}\u{29}", "\u{28}\u{4F01}\u{29}",
+    "\u{28}\u{8CC7}\u{29}", "\u{28}\u{5354}\u{29}", "\u{28}\u{796D}\u{29}", "\u{28}\u{4F11}\u{29}",
+    "\u{28}\u{81EA}\u{29}", "\u{28}\u{81F3}\u{29}", "\u{554F}", "\u{5E7C}", "\u{7B8F}",
+    "\u{50}\u{54}\u{45}", "\u{32}\u{31}", "\u{32}\u{32}", "\u{32}\u{33}", "\u{32}\u{34}",
+    "\u{32}\u{35}", "\u{32}\u{36}", "\u{32}\u{37}", "\u{32}\u{38}", "\u{32}\u{39}", "\u{33}\u{30}",
+    "\u{33}\u{31}", "\u{33}\u{32}", "\u{33}\u{33}", "\u{33}\u{34}", "\u{33}\u{35}",
+    "\u{1100}\u{1161}", "\u{1102}\u{1161}", "\u{1103}\u{1161}", "\u{1105}\u{1161}",
+    "\u{1106}\u{1161}", "\u{1107}\u{1161}", "\u{1109}\u{1161}", "\u{110B}\u{1161}",
+    "\u{110C}\u{1161}", "\u{110E}\u{1161}", "\u{110F}\u{1161}", "\u{1110}\u{1161}",
+    "\u{1111}\u{1161}", "\u{1112}\u{1161}", "\u{110E}\u{1161}\u{11B7}\u{1100}\u{1169}",
+    "\u{110C}\u{116E}\u{110B}\u{1174}", "\u{110B}\u{116E}", "\u{4E94}", "\u{516D}", "\u{4E03}",
+    "\u{4E5D}", "\u{682A}", "\u{6709}", "\u{793E}", "\u{540D}", "\u{7279}", "\u{8CA1}", "\u{795D}",
+    "\u{52B4}", "\u{79D8}", "\u{7537}", "\u{9069}", "\u{512A}", "\u{5370}", "\u{6CE8}", "\u{9805}",
+    "\u{4F11}", "\u{5199}", "\u{6B63}", "\u{5DE6}", "\u{53F3}", "\u{533B}", "\u{5B97}", "\u{5B66}",
+    "\u{76E3}", "\u{4F01}", "\u{8CC7}", "\u{5354}", "\u{591C}", "\u{33}\u{36}", "\u{33}\u{37}",
+    "\u{33}\u{38}", "\u{33}\u{39}", "\u{34}\u{30}", "\u{34}\u{31}", "\u{34}\u{32}", "\u{34}\u{33}",
+    "\u{34}\u{34}", "\u{34}\u{35}", "\u{34}\u{36}", "\u{34}\u{37}", "\u{34}\u{38}", "\u{34}\u{39}",
+    "\u{35}\u{30}", "\u{31}\u{6708}", "\u{32}\u{6708}", "\u{33}\u{6708}", "\u{34}\u{6708}",
+    "\u{35}\u{6708}", "\u{36}\u{6708}", "\u{37}\u{6708}", "\u{38}\u{6708}", "\u{39}\u{6708}",
+    "\u{31}\u{30}\u{6708}", "\u{31}\u{31}\u{6708}", "\u{31}\u{32}\u{6708}", "\u{48}\u{67}",
+    "\u{65}\u{72}\u{67}", "\u{65}\u{56}", "\u{4C}\u{54}\u{44}", "\u{30A2}", "\u{30A4}", "\u{30A6}",
+    "\u{30A8}", "\u{30AA}", "\u{30AB}", "\u{30AD}", "\u{30AF}", "\u{30B1}", "\u{30B3}", "\u{30B5}",
+    "\u{30B7}", "\u{30B9}", "\u{30BB}", "\u{30BD}", "\u{30BF}", "\u{30C1}", "\u{30C4}", "\u{30C6}",
+    "\u{30C8}", "\u{30CA}", "\u{30CB}", "\u{30CC}", "\u{30CD}", "\u{30CE}", "\u{30CF}", "\u{30D2}",
+    "\u{30D5}", "\u{30D8}", "\u{30DB}", "\u{30DE}", "\u{30DF}", "\u{30E0}", "\u{30E1}", "\u{30E2}",
+    "\u{30E4}", "\u{30E6}", "\u{30E8}", "\u{30E9}", "\u{30EA}", "\u{30EB}", "\u{30EC}", "\u{30ED}",
+    "\u{30EF}", "\u{30F0}", "\u{30F1}", "\u{30F2}", "\u{4EE4}\u{548C}",
+    "\u{30A2}\u{30CF}\u{309A}\u{30FC}\u{30C8}", "\u{30A2}\u{30EB}\u{30D5}\u{30A1}",
+    "\u{30A2}\u{30F3}\u{30D8}\u{309A}\u{30A2}", "\u{30A2}\u{30FC}\u{30EB}",
+    "\u{30A4}\u{30CB}\u{30F3}\u{30AF}\u{3099}", "\u{30A4}\u{30F3}\u{30C1}",
+    "\u{30A6}\u{30A9}\u{30F3}", "\u{30A8}\u{30B9}\u{30AF}\u{30FC}\u{30C8}\u{3099}",
+    "\u{30A8}\u{30FC}\u{30AB}\u{30FC}", "\u{30AA}\u{30F3}\u{30B9}", "\u{30AA}\u{30FC}\u{30E0}",
+    "\u{30AB}\u{30A4}\u{30EA}", "\u{30AB}\u{30E9}\u{30C3}\u{30C8}",
+    "\u{30AB}\u{30ED}\u{30EA}\u{30FC}", "\u{30AB}\u{3099}\u{30ED}\u{30F3}",
+    "\u{30AB}\u{3099}\u{30F3}\u{30DE}", "\u{30AD}\u{3099}\u{30AB}\u{3099}",
+    "\u{30AD}\u{3099}\u{30CB}\u{30FC}", "\u{30AD}\u{30E5}\u{30EA}\u{30FC}",
+    "\u{30AD}\u{3099}\u{30EB}\u{30BF}\u{3099}\u{30FC}", "\u{30AD}\u{30ED}",
+    "\u{30AD}\u{30ED}\u{30AF}\u{3099}\u{30E9}\u{30E0}",
+    "\u{30AD}\u{30ED}\u{30E1}\u{30FC}\u{30C8}\u{30EB}", "\u{30AD}\u{30ED}\u{30EF}\u{30C3}\u{30C8}",
+    "\u{30AF}\u{3099}\u{30E9}\u{30E0}", "\u{30AF}\u{3099}\u{30E9}\u{30E0}\u{30C8}\u{30F3}",
+    "\u{30AF}\u{30EB}\u{30BB}\u{3099}\u{30A4}\u{30ED}", "\u{30AF}\u{30ED}\u{30FC}\u{30CD}",
+    "\u{30B1}\u{30FC}\u{30B9}", "\u{30B3}\u{30EB}\u{30CA}", "\u{30B3}\u{30FC}\u{30DB}\u{309A}",
+    "\u{30B5}\u{30A4}\u{30AF}\u{30EB}", "\u{30B5}\u{30F3}\u{30C1}\u{30FC}\u{30E0}",
+    "\u{30B7}\u{30EA}\u{30F3}\u{30AF}\u{3099}", "\u{30BB}\u{30F3}\u{30C1}",
+    "\u{30BB}\u{30F3}\u{30C8}", "\u{30BF}\u{3099}\u{30FC}\u{30B9}", "\u{30C6}\u{3099}\u{30B7}",
+    "\u{30C8}\u{3099}\u{30EB}", "\u{30C8}\u{30F3}", "\u{30CA}\u{30CE}", "\u{30CE}\u{30C3}\u{30C8}",
+    "\u{30CF}\u{30A4}\u{30C4}", "\u{30CF}\u{309A}\u{30FC}\u{30BB}\u{30F3}\u{30C8}",
+    "\u{30CF}\u{309A}\u{30FC}\u{30C4}", "\u{30CF}\u{3099}\u{30FC}\u{30EC}\u{30EB}",
+    "\u{30D2}\u{309A}\u{30A2}\u{30B9}\u{30C8}\u{30EB}", "\u{30D2}\u{309A}\u{30AF}\u{30EB}",
+    "\u{30D2}\u{309A}\u{30B3}", "\u{30D2}\u{3099}\u{30EB}",
+    "\u{30D5}\u{30A1}\u{30E9}\u{30C3}\u{30C8}\u{3099}", "\u{30D5}\u{30A3}\u{30FC}\u{30C8}",
+    "\u{30D5}\u{3099}\u{30C3}\u{30B7}\u{30A7}\u{30EB}", "\u{30D5}\u{30E9}\u{30F3}",
+    "\u{30D8}\u{30AF}\u{30BF}\u{30FC}\u{30EB}", "\u{30D8}\u{309A}\u{30BD}",
+    "\u{30D8}\u{309A}\u{30CB}\u{30D2}", "\u{30D8}\u{30EB}\u{30C4}",
+    "\u{30D8}\u{309A}\u{30F3}\u{30B9}", "\u{30D8}\u{309A}\u{30FC}\u{30B7}\u{3099}",
+    "\u{30D8}\u{3099}\u{30FC}\u{30BF}", "\u{30DB}\u{309A}\u{30A4}\u{30F3}\u{30C8}",
+    "\u{30DB}\u{3099}\u{30EB}\u{30C8}", "\u{30DB}\u{30F3}",
+    "\u{30DB}\u{309A}\u{30F3}\u{30C8}\u{3099}", "\u{30DB}\u{30FC}\u{30EB}",
+    "\u{30DB}\u{30FC}\u{30F3}", "\u{30DE}\u{30A4}\u{30AF}\u{30ED}", "\u{30DE}\u{30A4}\u{30EB}",
+    "\u{30DE}\u{30C3}\u{30CF}", "\u{30DE}\u{30EB}\u{30AF}",
+    "\u{30DE}\u{30F3}\u{30B7}\u{30E7}\u{30F3}", "\u{30DF}\u{30AF}\u{30ED}\u{30F3}",
+    "\u{30DF}\u{30EA}", "\u{30DF}\u{30EA}\u{30CF}\u{3099}\u{30FC}\u{30EB}",
+    "\u{30E1}\u{30AB}\u{3099}", "\u{30E1}\u{30AB}\u{3099}\u{30C8}\u{30F3}",
+    "\u{30E1}\u{30FC}\u{30C8}\u{30EB}", "\u{30E4}\u{30FC}\u{30C8}\u{3099}",
+    "\u{30E4}\u{30FC}\u{30EB}", "\u{30E6}\u{30A2}\u{30F3}", "\u{30EA}\u{30C3}\u{30C8}\u{30EB}",
+    "\u{30EA}\u{30E9}", "\u{30EB}\u{30D2}\u{309A}\u{30FC}",
+    "\u{30EB}\u{30FC}\u{30D5}\u{3099}\u{30EB}", "\u{30EC}\u{30E0}",
+    "\u{30EC}\u{30F3}\u{30C8}\u{30B1}\u{3099}\u{30F3}", "\u{30EF}\u{30C3}\u{30C8}",
+    "\u{30}\u{70B9}", "\u{31}\u{70B9}", "\u{32}\u{70B9}", "\u{33}\u{70B9}", "\u{34}\u{70B9}",
+    "\u{35}\u{70B9}", "\u{36}\u{70B9}", "\u{37}\u{70B9}", "\u{38}\u{70B9}", "\u{39}\u{70B9}",
+    "\u{31}\u{30}\u{70B9}", "\u{31}\u{31}\u{70B9}", "\u{31}\u{32}\u{70B9}", "\u{31}\u{33}\u{70B9}",
+    "\u{31}\u{34}\u{70B9}", "\u{31}\u{35}\u{70B9}", "\u{31}\u{36}\u{70B9}", "\u{31}\u{37}\u{70B9}",
+    "\u{31}\u{38}\u{70B9}", "\u{31}\u{39}\u{70B9}", "\u{32}\u{30}\u{70B9}", "\u{32}\u{31}\u{70B9}",
+    "\u{32}\u{32}\u{70B9}", "\u{32}\u{33}\u{70B9}", "\u{32}\u{34}\u{70B9}", "\u{68}\u{50}\u{61}",
+    "\u{64}\u{61}", "\u{41}\u{55}", "\u{62}\u{61}\u{72}", "\u{6F}\u{56}", "\u{70}\u{63}",
+    "\u{64}\u{6D}", "\u{64}\u{6D}\u{32}", "\u{64}\u{6D}\u{33}", "\u{49}\u{55}", "\u{5E73}\u{6210}",
+    "\u{662D}\u{548C}", "\u{5927}\u{6B63}", "\u{660E}\u{6CBB}", "\u{682A}\u{5F0F}\u{4F1A}\u{793E}",
+    "\u{70}\u{41}", "\u{6E}\u{41}", "\u{3BC}\u{41}", "\u{6D}\u{41}", "\u{6B}\u{41}", "\u{4B}\u{42}",
+    "\u{4D}\u{42}", "\u{47}\u{42}", "\u{63}\u{61}\u{6C}", "\u{6B}\u{63}\u{61}\u{6C}",
+    "\u{70}\u{46}", "\u{6E}\u{46}", "\u{3BC}\u{46}", "\u{3BC}\u{67}", "\u{6D}\u{67}",
+    "\u{6B}\u{67}", "\u{48}\u{7A}", "\u{6B}\u{48}\u{7A}", "\u{4D}\u{48}\u{7A}",
+    "\u{47}\u{48}\u{7A}", "\u{54}\u{48}\u{7A}", "\u{3BC}\u{6C}", "\u{6D}\u{6C}", "\u{64}\u{6C}",
+    "\u{6B}\u{6C}", "\u{66}\u{6D}", "\u{6E}\u{6D}", "\u{3BC}\u{6D}", "\u{6D}\u{6D}", "\u{63}\u{6D}",
+    "\u{6B}\u{6D}", "\u{6D}\u{6D}\u{32}", "\u{63}\u{6D}\u{32}", "\u{6D}\u{32}",
+    "\u{6B}\u{6D}\u{32}", "\u{6D}\u{6D}\u{33}", "\u{63}\u{6D}\u{33}", "\u{6D}\u{33}",
+    "\u{6B}\u{6D}\u{33}", "\u{6D}\u{2215}\u{73}", "\u{6D}\u{2215}\u{73}\u{32}", "\u{50}\u{61}",
+    "\u{6B}\u{50}\u{61}", "\u{4D}\u{50}\u{61}", "\u{47}\u{50}\u{61}", "\u{72}\u{61}\u{64}",
+    "\u{72}\u{61}\u{64}\u{2215}\u{73}", "\u{72}\u{61}\u{64}\u{2215}\u{73}\u{32}", "\u{70}\u{73}",
+    "\u{6E}\u{73}", "\u{3BC}\u{73}", "\u{6D}\u{73}", "\u{70}\u{56}", "\u{6E}\u{56}",
+    "\u{3BC}\u{56}", "\u{6D}\u{56}", "\u{6B}\u{56}", "\u{4D}\u{56}", "\u{70}\u{57}", "\u{6E}\u{57}",
+    "\u{3BC}\u{57}", "\u{6D}\u{57}", "\u{6B}\u{57}", "\u{4D}\u{57}", "\u{6B}\u{3A9}",
+    "\u{4D}\u{3A9}", "\u{61}\u{2E}\u{6D}\u{2E}", "\u{42}\u{71}", "\u{63}\u{63}", "\u{63}\u{64}",
+    "\u{43}\u{2215}\u{6B}\u{67}", "\u{43}\u{6F}\u{2E}", "\u{64}\u{42}", "\u{47}\u{79}",
+    "\u{68}\u{61}", "\u{48}\u{50}", "\u{69}\u{6E}", "\u{4B}\u{4B}", "\u{4B}\u{4D}", "\u{6B}\u{74}",
+    "\u{6C}\u{6D}", "\u{6C}\u{6E}", "\u{6C}\u{6F}\u{67}", "\u{6C}\u{78}", "\u{6D}\u{62}",
+    "\u{6D}\u{69}\u{6C}", "\u{6D}\u{6F}\u{6C}", "\u{50}\u{48}", "\u{70}\u{2E}\u{6D}\u{2E}",
+    "\u{50}\u{50}\u{4D}", "\u{50}\u{52}", "\u{73}\u{72}", "\u{53}\u{76}", "\u{57}\u{62}",
+    "\u{56}\u{2215}\u{6D}", "\u{41}\u{2215}\u{6D}", "\u{31}\u{65E5}", "\u{32}\u{65E5}",
+    "\u{33}\u{65E5}", "\u{34}\u{65E5}", "\u{35}\u{65E5}", "\u{36}\u{65E5}", "\u{37}\u{65E5}",
+    "\u{38}\u{65E5}", "\u{39}\u{65E5}", "\u{31}\u{30}\u{65E5}", "\u{31}\u{31}\u{65E5}",
+    "\u{31}\u{32}\u{65E5}", "\u{31}\u{33}\u{65E5}", "\u{31}\u{34}\u{65E5}", "\u{31}\u{35}\u{65E5}",
+    "\u{31}\u{36}\u{65E5}", "\u{31}\u{37}\u{65E5}", "\u{31}\u{38}\u{65E5}", "\u{31}\u{39}\u{65E5}",
+    "\u{32}\u{30}\u{65E5}", "\u{32}\u{31}\u{65E5}", "\u{32}\u{32}\u{65E5}", "\u{32}\u{33}\u{65E5}",
+    "\u{32}\u{34}\u{65E5}", "\u{32}\u{35}\u{65E5}", "\u{32}\u{36}\u{65E5}", "\u{32}\u{37}\u{65E5}",
+    "\u{32}\u{38}\u{65E5}", "\u{32}\u{39}\u{65E5}", "\u{33}\u{30}\u{65E5}", "\u{33}\u{31}\u{65E5}",
+    "\u{67}\u{61}\u{6C}", "\u{44A}", "\u{44C}", "\u{A76F}", "\u{126}", "\u{153}", "\u{A727}",
+    "\u{AB37}", "\u{26B}", "\u{AB52}", "\u{28D}", "\u{8C48}", "\u{66F4}", "\u{8CC8}", "\u{6ED1}",
+    "\u{4E32}", "\u{53E5}", "\u{5951}", "\u{5587}", "\u{5948}", "\u{61F6}", "\u{7669}", "\u{7F85}",
+    "\u{863F}", "\u{87BA}", "\u{88F8}", "\u{908F}", "\u{6A02}", "\u{6D1B}", "\u{70D9}", "\u{73DE}",
+    "\u{843D}", "\u{916A}", "\u{99F1}", "\u{4E82}", "\u{5375}", "\u{6B04}", "\u{721B}", "\u{862D}",
+    "\u{9E1E}", "\u{5D50}", "\u{6FEB}", "\u{85CD}", "\u{8964}", "\u{62C9}", "\u{81D8}", "\u{881F}",
+    "\u{5ECA}", "\u{6717}", "\u{6D6A}", "\u{72FC}", "\u{90CE}", "\u{4F86}", "\u{51B7}", "\u{52DE}",
+    "\u{64C4}", "\u{6AD3}", "\u{7210}", "\u{76E7}", "\u{8606}", "\u{865C}", "\u{8DEF}", "\u{9732}",
+    "\u{9B6F}", "\u{9DFA}", "\u{788C}", "\u{797F}", "\u{7DA0}", "\u{83C9}", "\u{9304}", "\u{8AD6}",
+    "\u{58DF}", "\u{5F04}", "\u{7C60}", "\u{807E}", "\u{7262}", "\u{78CA}", "\u{8CC2}", "\u{96F7}",
+    "\u{58D8}", "\u{5C62}", "\u{6A13}", "\u{6DDA}", "\u{6F0F}", "\u{7D2F}", "\u{7E37}", "\u{964B}",
+    "\u{52D2}", "\u{808B}", "\u{51DC}", "\u{51CC}", "\u{7A1C}", "\u{7DBE}", "\u{83F1}", "\u{9675}",
+    "\u{8B80}", "\u{62CF}", "\u{8AFE}", "\u{4E39}", "\u{5BE7}", "\u{6012}", "\u{7387}", "\u{7570}",
+    "\u{5317}", "\u{78FB}", "\u{4FBF}", "\u{5FA9}", "\u{4E0D}", "\u{6CCC}", "\u{6578}", "\u{7D22}",
+    "\u{53C3}", "\u{585E}", "\u{7701}", "\u{8449}", "\u{8AAA}", "\u{6BBA}", "\u{6C88}", "\u{62FE}",
+    "\u{82E5}", "\u{63A0}", "\u{7565}", "\u{4EAE}", "\u{5169}", "\u{51C9}", "\u{6881}", "\u{7CE7}",
+    "\u{826F}", "\u{8AD2}", "\u{91CF}", "\u{52F5}", "\u{5442}", "\u{5EEC}", "\u{65C5}", "\u{6FFE}",
+    "\u{792A}", "\u{95AD}", "\u{9A6A}", "\u{9E97}", "\u{9ECE}", "\u{66C6}", "\u{6B77}", "\u{8F62}",
+    "\u{5E74}", "\u{6190}", "\u{6200}", "\u{649A}", "\u{6F23}", "\u{7149}", "\u{7489}", "\u{79CA}",
+    "\u{7DF4}", "\u{806F}", "\u{8F26}", "\u{84EE}", "\u{9023}", "\u{934A}", "\u{5217}", "\u{52A3}",
+    "\u{54BD}", "\u{70C8}", "\u{88C2}", "\u{5EC9}", "\u{5FF5}", "\u{637B}", "\u{6BAE}", "\u{7C3E}",
+    "\u{7375}", "\u{4EE4}", "\u{56F9}", "\u{5DBA}", "\u{601C}", "\u{73B2}", "\u{7469}", "\u{7F9A}",
+    "\u{8046}", "\u{9234}", "\u{96F6}", "\u{9748}", "\u{9818}", "\u{4F8B}", "\u{79AE}", "\u{91B4}",
+    "\u{96B8}", "\u{60E1}", "\u{4E86}", "\u{50DA}", "\u{5BEE}", "\u{5C3F}", "\u{6599}", "\u{71CE}",
+    "\u{7642}", "\u{84FC}", "\u{907C}", "\u{6688}", "\u{962E}", "\u{5289}", "\u{677B}", "\u{67F3}",
+    "\u{6D41}", "\u{6E9C}", "\u{7409}", "\u{7559}", "\u{786B}", "\u{7D10}", "\u{985E}", "\u{622E}",
+    "\u{9678}", "\u{502B}", "\u{5D19}", "\u{6DEA}", "\u{8F2A}", "\u{5F8B}", "\u{6144}", "\u{6817}",
+    "\u{9686}", "\u{5229}", "\u{540F}", "\u{5C65}", "\u{6613}", "\u{674E}", "\u{68A8}", "\u{6CE5}",
+    "\u{7406}", "\u{75E2}", "\u{7F79}", "\u{88CF}", "\u{88E1}", "\u{96E2}", "\u{533F}", "\u{6EBA}",
+    "\u{541D}", "\u{71D0}", "\u{7498}", "\u{85FA}", "\u{96A3}", "\u{9C57}", "\u{9E9F}", "\u{6797}",
+    "\u{6DCB}", "\u{81E8}", "\u{7B20}", "\u{7C92}", "\u{72C0}", "\u{7099}", "\u{8B58}", "\u{4EC0}",
+    "\u{8336}", "\u{523A}", "\u{5207}", "\u{5EA6}", "\u{62D3}", "\u{7CD6}", "\u{5B85}", "\u{6D1E}",
+    "\u{66B4}", "\u{8F3B}", "\u{964D}", "\u{5ED3}", "\u{5140}", "\u{55C0}", "\u{585A}", "\u{6674}",
+    "\u{51DE}", "\u{732A}", "\u{76CA}", "\u{793C}", "\u{795E}", "\u{7965}", "\u{798F}", "\u{9756}",
+    "\u{7CBE}", "\u{8612}", "\u{8AF8}", "\u{9038}", "\u{90FD}", "\u{98EF}", "\u{98FC}", "\u{9928}",
+    "\u{9DB4}", "\u{90DE}", "\u{96B7}", "\u{4FAE}", "\u{50E7}", "\u{514D}", "\u{52C9}", "\u{52E4}",
+    "\u{5351}", "\u{559D}", "\u{5606}", "\u{5668}", "\u{5840}", "\u{58A8}", "\u{5C64}", "\u{6094}",
+    "\u{6168}", "\u{618E}", "\u{61F2}", "\u{654F}", "\u{65E2}", "\u{6691}", "\u{6885}", "\u{6D77}",
+    "\u{6E1A}", "\u{6F22}", "\u{716E}", "\u{722B}", "\u{7422}", "\u{7891}", "\u{7949}", "\u{7948}",
+    "\u{7950}", "\u{7956}", "\u{798D}", "\u{798E}", "\u{7A40}", "\u{7A81}", "\u{7BC0}", "\u{7E09}",
+    "\u{7E41}", "\u{7F72}", "\u{8005}", "\u{81ED}", "\u{8279}", "\u{8457}", "\u{8910}", "\u{8996}",
+    "\u{8B01}", "\u{8B39}", "\u{8CD3}", "\u{8D08}", "\u{8FB6}", "\u{96E3}", "\u{97FF}", "\u{983B}",
+    "\u{6075}", "\u{242EE}", "\u{8218}", "\u{4E26}", "\u{51B5}", "\u{5168}", "\u{4F80}", "\u{5145}",
+    "\u{5180}", "\u{52C7}", "\u{52FA}", "\u{5555}", "\u{5599}", "\u{55E2}", "\u{58B3}", "\u{5944}",
+    "\u{5954}", "\u{5A62}", "\u{5B28}", "\u{5ED2}", "\u{5ED9}", "\u{5F69}", "\u{5FAD}", "\u{60D8}",
+    "\u{614E}", "\u{6108}", "\u{6160}", "\u{6234}", "\u{63C4}", "\u{641C}", "\u{6452}", "\u{6556}",
+    "\u{671B}", "\u{6756}", "\u{6EDB}", "\u{6ECB}", "\u{701E}", "\u{77A7}", "\u{7235}", "\u{72AF}",
+    "\u{7471}", "\u{7506}", "\u{753B}", "\u{761D}", "\u{761F}", "\u{76DB}", "\u{76F4}", "\u{774A}",
+    "\u{7740}", "\u{78CC}", "\u{7AB1}", "\u{7C7B}", "\u{7D5B}", "\u{7F3E}", "\u{8352}", "\u{83EF}",
+    "\u{8779}", "\u{8941}", "\u{8986}", "\u{8ABF}", "\u{8ACB}", "\u{8AED}", "\u{8B8A}", "\u{8F38}",
+    "\u{9072}", "\u{9199}", "\u{9276}", "\u{967C}", "\u{97DB}", "\u{980B}", "\u{9B12}", "\u{2284A}",
+    "\u{22844}", "\u{233D5}", "\u{3B9D}", "\u{4018}", "\u{4039}", "\u{25249}", "\u{25CD0}",
+    "\u{27ED3}", "\u{9F43}", "\u{9F8E}", "\u{66}\u{66}", "\u{66}\u{69}", "\u{66}\u{6C}",
+    "\u{66}\u{66}\u{69}", "\u{66}\u{66}\u{6C}", "\u{73}\u{74}", "\u{574}\u{576}", "\u{574}\u{565}",
+    "\u{574}\u{56B}", "\u{57E}\u{576}", "\u{574}\u{56D}", "\u{5D9}\u{5B4}", "\u{5F2}\u{5B7}",
+    "\u{5E2}", "\u{5D4}", "\u{5DB}", "\u{5DC}", "\u{5DD}", "\u{5E8}", "\u{5EA}", "\u{5E9}\u{5C1}",
+    "\u{5E9}\u{5C2}", "\u{5E9}\u{5BC}\u{5C1}", "\u{5E9}\u{5BC}\u{5C2}", "\u{5D0}\u{5B7}",
+    "\u{5D0}\u{5B8}", "\u{5D0}\u{5BC}", "\u{5D1}\u{5BC}", "\u{5D2}\u{5BC}", "\u{5D3}\u{5BC}",
+    "\u{5D4}\u{5BC}", "\u{5D5}\u{5BC}", "\u{5D6}\u{5BC}", "\u{5D8}\u{5BC}", "\u{5D9}\u{5BC}",
+    "\u{5DA}\u{5BC}", "\u{5DB}\u{5BC}", "\u{5DC}\u{5BC}", "\u{5DE}\u{5BC}", "\u{5E0}\u{5BC}",
+    "\u{5E1}\u{5BC}", "\u{5E3}\u{5BC}", "\u{5E4}\u{5BC}", "\u{5E6}\u{5BC}", "\u{5E7}\u{5BC}",
+    "\u{5E8}\u{5BC}", "\u{5E9}\u{5BC}", "\u{5EA}\u{5BC}", "\u{5D5}\u{5B9}", "\u{5D1}\u{5BF}",
+    "\u{5DB}\u{5BF}", "\u{5E4}\u{5BF}", "\u{5D0}\u{5DC}", "\u{671}", "\u{67B}", "\u{67E}",
+    "\u{680}", "\u{67A}", "\u{67F}", "\u{679}", "\u{6A4}", "\u{6A6}", "\u{684}", "\u{683}",
+    "\u{686}", "\u{687}", "\u{68D}", "\u{68C}", "\u{68E}", "\u{688}", "\u{698}", "\u{691}",
+    "\u{6A9}", "\u{6AF}", "\u{6B3}", "\u{6B1}", "\u{6BA}", "\u{6BB}", "\u{6C1}", "\u{6BE}",
+    "\u{6D2}", "\u{6AD}", "\u{6C7}", "\u{6C6}", "\u{6C8}", "\u{6CB}", "\u{6C5}", "\u{6C9}",
+    "\u{6D0}", "\u{649}", "\u{64A}\u{654}\u{627}", "\u{64A}\u{654}\u{6D5}", "\u{64A}\u{654}\u{648}",
+    "\u{64A}\u{654}\u{6C7}", "\u{64A}\u{654}\u{6C6}", "\u{64A}\u{654}\u{6C8}",
+    "\u{64A}\u{654}\u{6D0}", "\u{64A}\u{654}\u{649}", "\u{6CC}", "\u{64A}\u{654}\u{62C}",
+    "\u{64A}\u{654}\u{62D}", "\u{64A}\u{654}\u{645}", "\u{64A}\u{654}\u{64A}", "\u{628}\u{62C}",
+    "\u{628}\u{62D}", "\u{628}\u{62E}", "\u{628}\u{645}", "\u{628}\u{649}", "\u{628}\u{64A}",
+    "\u{62A}\u{62C}", "\u{62A}\u{62D}", "\u{62A}\u{62E}", "\u{62A}\u{645}", "\u{62A}\u{649}",
+    "\u{62A}\u{64A}", "\u{62B}\u{62C}", "\u{62B}\u{645}", "\u{62B}\u{649}", "\u{62B}\u{64A}",
+    "\u{62C}\u{62D}", "\u{62C}\u{645}", "\u{62D}\u{62C}", "\u{62D}\u{645}", "\u{62E}\u{62C}",
+    "\u{62E}\u{62D}", "\u{62E}\u{645}", "\u{633}\u{62C}", "\u{633}\u{62D}", "\u{633}\u{62E}",
+    "\u{633}\u{645}", "\u{635}\u{62D}", "\u{635}\u{645}", "\u{636}\u{62C}", "\u{636}\u{62D}",
+    "\u{636}\u{62E}", "\u{636}\u{645}", "\u{637}\u{62D}", "\u{637}\u{645}", "\u{638}\u{645}",
+    "\u{639}\u{62C}", "\u{639}\u{645}", "\u{63A}\u{62C}", "\u{63A}\u{645}", "\u{641}\u{62C}",
+    "\u{641}\u{62D}", "\u{641}\u{62E}", "\u{641}\u{645}", "\u{641}\u{649}", "\u{641}\u{64A}",
+    "\u{642}\u{62D}", "\u{642}\u{645}", "\u{642}\u{649}", "\u{642}\u{64A}", "\u{643}\u{627}",
+    "\u{643}\u{62C}", "\u{643}\u{62D}", "\u{643}\u{62E}", "\u{643}\u{644}", "\u{643}\u{645}",
+    "\u{643}\u{649}", "\u{643}\u{64A}", "\u{644}\u{62C}", "\u{644}\u{62D}", "\u{644}\u{62E}",
+    "\u{644}\u{645}", "\u{644}\u{649}", "\u{644}\u{64A}", "\u{645}\u{62C}", "\u{645}\u{62D}",
+    "\u{645}\u{62E}", "\u{645}\u{645}", "\u{645}\u{649}", "\u{645}\u{64A}", "\u{646}\u{62C}",
+    "\u{646}\u{62D}", "\u{646}\u{62E}", "\u{646}\u{645}", "\u{646}\u{649}", "\u{646}\u{64A}",
+    "\u{647}\u{62C}", "\u{647}\u{645}", "\u{647}\u{649}", "\u{647}\u{64A}", "\u{64A}\u{62C}",
+    "\u{64A}\u{62D}", "\u{64A}\u{62E}", "\u{64A}\u{645}", "\u{64A}\u{649}", "\u{64A}\u{64A}",
+    "\u{630}\u{670}", "\u{631}\u{670}", "\u{649}\u{670}", "\u{20}\u{64C}\u{651}",
+    "\u{20}\u{64D}\u{651}", "\u{20}\u{64E}\u{651}", "\u{20}\u{64F}\u{651}", "\u{20}\u{650}\u{651}",
+    "\u{20}\u{651}\u{670}", "\u{64A}\u{654}\u{631}", "\u{64A}\u{654}\u{632}",
+    "\u{64A}\u{654}\u{646}", "\u{628}\u{631}", "\u{628}\u{632}", "\u{628}\u{646}", "\u{62A}\u{631}",
+    "\u{62A}\u{632}", "\u{62A}\u{646}", "\u{62B}\u{631}", "\u{62B}\u{632}", "\u{62B}\u{646}",
+    "\u{645}\u{627}", "\u{646}\u{631}", "\u{646}\u{632}", "\u{646}\u{646}", "\u{64A}\u{631}",
+    "\u{64A}\u{632}", "\u{64A}\u{646}", "\u{64A}\u{654}\u{62E}", "\u{64A}\u{654}\u{647}",
+    "\u{628}\u{647}", "\u{62A}\u{647}", "\u{635}\u{62E}", "\u{644}\u{647}", "\u{646}\u{647}",
+    "\u{647}\u{670}", "\u{64A}\u{647}", "\u{62B}\u{647}", "\u{633}\u{647}", "\u{634}\u{645}",
+    "\u{634}\u{647}", "\u{640}\u{64E}\u{651}", "\u{640}\u{64F}\u{651}", "\u{640}\u{650}\u{651}",
+    "\u{637}\u{649}", "\u{637}\u{64A}", "\u{639}\u{649}", "\u{639}\u{64A}", "\u{63A}\u{649}",
+    "\u{63A}\u{64A}", "\u{633}\u{649}", "\u{633}\u{64A}", "\u{634}\u{649}", "\u{634}\u{64A}",
+    "\u{62D}\u{649}", "\u{62D}\u{64A}", "\u{62C}\u{649}", "\u{62C}\u{64A}", "\u{62E}\u{649}",
+    "\u{62E}\u{64A}", "\u{635}\u{649}", "\u{635}\u{64A}", "\u{636}\u{649}", "\u{636}\u{64A}",
+    "\u{634}\u{62C}", "\u{634}\u{62D}", "\u{634}\u{62E}", "\u{634}\u{631}", "\u{633}\u{631}",
+    "\u{635}\u{631}", "\u{636}\u{631}", "\u{627}\u{64B}", "\u{62A}\u{62C}\u{645}",
+    "\u{62A}\u{62D}\u{62C}", "\u{62A}\u{62D}\u{645}", "\u{62A}\u{62E}\u{645}",
+    "\u{62A}\u{645}\u{62C}", "\u{62A}\u{645}\u{62D}", "\u{62A}\u{645}\u{62E}",
+    "\u{62C}\u{645}\u{62D}", "\u{62D}\u{645}\u{64A}", "\u{62D}\u{645}\u{649}",
+    "\u{633}\u{62D}\u{62C}", "\u{633}\u{62C}\u{62D}", "\u{633}\u{62C}\u{649}",
+    "\u{633}\u{645}\u{62D}", "\u{633}\u{645}\u{62C}", "\u{633}\u{645}\u{645}",
+    "\u{635}\u{62D}\u{62D}", "\u{635}\u{645}\u{645}", "\u{634}\u{62D}\u{645}",
+    "\u{634}\u{62C}\u{64A}", "\u{634}\u{645}\u{62E}", "\u{634}\u{645}\u{645}",
+    "\u{636}\u{62D}\u{649}", "\u{636}\u{62E}\u{645}", "\u{637}\u{645}\u{62D}",
+    "\u{637}\u{645}\u{645}", "\u{637}\u{645}\u{64A}", "\u{639}\u{62C}\u{645}",
+    "\u{639}\u{645}\u{645}", "\u{639}\u{645}\u{649}", "\u{63A}\u{645}\u{645}",
+    "\u{63A}\u{645}\u{64A}", "\u{63A}\u{645}\u{649}", "\u{641}\u{62E}\u{645}",
+    "\u{642}\u{645}\u{62D}", "\u{642}\u{645}\u{645}", "\u{644}\u{62D}\u{645}",
+    "\u{644}\u{62D}\u{64A}", "\u{644}\u{62D}\u{649}", "\u{644}\u{62C}\u{62C}",
+    "\u{644}\u{62E}\u{645}", "\u{644}\u{645}\u{62D}", "\u{645}\u{62D}\u{62C}",
+    "\u{645}\u{62D}\u{645}", "\u{645}\u{62D}\u{64A}", "\u{645}\u{62C}\u{62D}",
+    "\u{645}\u{62C}\u{645}", "\u{645}\u{62E}\u{62C}", "\u{645}\u{62E}\u{645}",
+    "\u{645}\u{62C}\u{62E}", "\u{647}\u{645}\u{62C}", "\u{647}\u{645}\u{645}",
+    "\u{646}\u{62D}\u{645}", "\u{646}\u{62D}\u{649}", "\u{646}\u{62C}\u{645}",
+    "\u{646}\u{62C}\u{649}", "\u{646}\u{645}\u{64A}", "\u{646}\u{645}\u{649}",
+    "\u{64A}\u{645}\u{645}", "\u{628}\u{62E}\u{64A}", "\u{62A}\u{62C}\u{64A}",
+    "\u{62A}\u{62C}\u{649}", "\u{62A}\u{62E}\u{64A}", "\u{62A}\u{62E}\u{649}",
+    "\u{62A}\u{645}\u{64A}", "\u{62A}\u{645}\u{649}", "\u{62C}\u{645}\u{64A}",
+    "\u{62C}\u{62D}\u{649}", "\u{62C}\u{645}\u{649}", "\u{633}\u{62E}\u{649}",
+    "\u{635}\u{62D}\u{64A}", "\u{634}\u{62D}\u{64A}", "\u{636}\u{62D}\u{64A}",
+    "\u{644}\u{62C}\u{64A}", "\u{644}\u{645}\u{64A}", "\u{64A}\u{62D}\u{64A}",
+    "\u{64A}\u{62C}\u{64A}", "\u{64A}\u{645}\u{64A}", "\u{645}\u{645}\u{64A}",
+    "\u{642}\u{645}\u{64A}", "\u{646}\u{62D}\u{64A}", "\u{639}\u{645}\u{64A}",
+    "\u{643}\u{645}\u{64A}", "\u{646}\u{62C}\u{62D}", "\u{645}\u{62E}\u{64A}",
+    "\u{644}\u{62C}\u{645}", "\u{643}\u{645}\u{645}", "\u{62C}\u{62D}\u{64A}",
+    "\u{62D}\u{62C}\u{64A}", "\u{645}\u{62C}\u{64A}", "\u{641}\u{645}\u{64A}",
+    "\u{628}\u{62D}\u{64A}", "\u{633}\u{62E}\u{64A}", "\u{646}\u{62C}\u{64A}",
+    "\u{635}\u{644}\u{6D2}", "\u{642}\u{644}\u{6D2}", "\u{627}\u{644}\u{644}\u{647}",
+    "\u{627}\u{643}\u{628}\u{631}", "\u{645}\u{62D}\u{645}\u{62F}", "\u{635}\u{644}\u{639}\u{645}",
+    "\u{631}\u{633}\u{648}\u{644}", "\u{639}\u{644}\u{64A}\u{647}", "\u{648}\u{633}\u{644}\u{645}",
+    "\u{635}\u{644}\u{649}",
+    "\u{635}\u{644}\u{649}\u{20}\u{627}\u{644}\u{644}\u{647}\u{20}\u{639}\u{644}\u{64A}\u{647}\u{20}\u{648}\u{633}\u{644}\u{645}",
+    "\u{62C}\u{644}\u{20}\u{62C}\u{644}\u{627}\u{644}\u{647}", "\u{631}\u{6CC}\u{627}\u{644}",
+    "\u{2C}", "\u{3001}", "\u{3002}", "\u{3A}", "\u{21}", "\u{3F}", "\u{3016}", "\u{3017}",
+    "\u{2014}", "\u{2013}", "\u{5F}", "\u{7B}", "\u{7D}", "\u{3014}", "\u{3015}", "\u{3010}",
+    "\u{3011}", "\u{300A}", "\u{300B}", "\u{300C}", "\u{300D}", "\u{300E}", "\u{300F}", "\u{5B}",
+    "\u{5D}", "\u{23}", "\u{26}", "\u{2A}", "\u{2D}", "\u{3C}", "\u{3E}", "\u{5C}", "\u{24}",
+    "\u{25}", "\u{40}", "\u{20}\u{64B}", "\u{640}\u{64B}", "\u{20}\u{64C}", "\u{20}\u{64D}",
+    "\u{20}\u{64E}", "\u{640}\u{64E}", "\u{20}\u{64F}", "\u{640}\u{64F}", "\u{20}\u{650}",
+    "\u{640}\u{650}", "\u{20}\u{651}", "\u{640}\u{651}", "\u{20}\u{652}", "\u{640}\u{652}",
+    "\u{621}", "\u{627}", "\u{628}", "\u{629}", "\u{62A}", "\u{62B}", "\u{62C}", "\u{62D}",
+    "\u{62E}", "\u{62F}", "\u{630}", "\u{631}", "\u{632}", "\u{633}", "\u{634}", "\u{635}",
+    "\u{636}", "\u{637}", "\u{638}", "\u{639}", "\u{63A}", "\u{641}", "\u{642}", "\u{643}",
+    "\u{644}", "\u{645}", "\u{646}", "\u{647}", "\u{648}", "\u{64A}", "\u{644}\u{627}\u{653}",
+    "\u{644}\u{627}\u{654}", "\u{644}\u{627}\u{655}", "\u{644}\u{627}", "\u{22}", "\u{27}",
+    "\u{2F}", "\u{5E}", "\u{7C}", "\u{7E}", "\u{2985}", "\u{2986}", "\u{30FB}", "\u{30A1}",
+    "\u{30A3}", "\u{30A5}", "\u{30A7}", "\u{30A9}", "\u{30E3}", "\u{30E5}", "\u{30E7}", "\u{30C3}",
+    "\u{30FC}", "\u{30F3}", "\u{3099}", "\u{309A}", "\u{A2}", "\u{A3}", "\u{AC}", "\u{A6}",
+    "\u{A5}", "\u{20A9}", "\u{2502}", "\u{2190}", "\u{2191}", "\u{2192}", "\u{2193}", "\u{25A0}",
+    "\u{25CB}", "\u{105D2}\u{307}", "\u{105DA}\u{307}", "\u{2D0}", "\u{2D1}", "\u{E6}", "\u{299}",
+    "\u{253}", "\u{2A3}", "\u{AB66}", "\u{2A5}", "\u{2A4}", "\u{256}", "\u{257}", "\u{1D91}",
+    "\u{258}", "\u{25E}", "\u{2A9}", "\u{264}", "\u{262}", "\u{260}", "\u{29B}", "\u{29C}",
+    "\u{267}", "\u{284}", "\u{2AA}", "\u{2AB}", "\u{26C}", "\u{1DF04}", "\u{A78E}", "\u{26E}",
+    "\u{1DF05}", "\u{28E}", "\u{1DF06}", "\u{F8}", "\u{276}", "\u{277}", "\u{27A}", "\u{1DF08}",
+    "\u{27D}", "\u{27E}", "\u{280}", "\u{2A8}", "\u{2A6}", "\u{AB67}", "\u{2A7}", "\u{288}",
+    "\u{2C71}", "\u{28F}", "\u{2A1}", "\u{2A2}", "\u{298}", "\u{1C0}", "\u{1C1}", "\u{1C2}",
+    "\u{1DF0A}", "\u{1DF1E}", "\u{11099}\u{110BA}", "\u{1109B}\u{110BA}", "\u{110A5}\u{110BA}",
+    "\u{11131}\u{11127}", "\u{11132}\u{11127}", "\u{11347}\u{1133E}", "\u{11347}\u{11357}",
+    "\u{11382}\u{113C9}", "\u{11384}\u{113BB}", "\u{1138B}\u{113C2}", "\u{11390}\u{113C9}",
+    "\u{113C2}\u{113C2}", "\u{113C2}\u{113B8}", "\u{113C2}\u{113C9}", "\u{114B9}\u{114BA}",
+    "\u{114B9}\u{114B0}", "\u{114B9}\u{114BD}", "\u{115B8}\u{115AF}", "\u{115B9}\u{115AF}",
+    "\u{11935}\u{11930}", "\u{1611E}\u{1611E}", "\u{1611E}\u{16129}", "\u{1611E}\u{1611F}",
+    "\u{16129}\u{1611F}", "\u{1611E}\u{16120}", "\u{1611E}\u{1611E}\u{1611F}",
+    "\u{1611E}\u{16129}\u{1611F}", "\u{1611E}\u{1611E}\u{16120}", "\u{16D67}\u{16D67}",
+    "\u{16D63}\u{16D67}", "\u{16D63}\u{16D67}\u{16D67}", "\u{1D157}\u{1D165}", "\u{1D158}\u{1D165}",
+    "\u{1D158}\u{1D165}\u{1D16E}", "\u{1D158}\u{1D165}\u{1D16F}", "\u{1D158}\u{1D165}\u{1D170}",
+    "\u{1D158}\u{1D165}\u{1D171}", "\u{1D158}\u{1D165}\u{1D172}", "\u{1D1B9}\u{1D165}",
+    "\u{1D1BA}\u{1D165}", "\u{1D1B9}\u{1D165}\u{1D16E}", "\u{1D1BA}\u{1D165}\u{1D16E}",
+    "\u{1D1B9}\u{1D165}\u{1D16F}", "\u{1D1BA}\u{1D165}\u{1D16F}", "\u{131}", "\u{237}", "\u{391}",
+    "\u{392}", "\u{394}", "\u{395}", "\u{396}", "\u{397}", "\u{399}", "\u{39A}", "\u{39B}",
+    "\u{39C}", "\u{39D}", "\u{39E}", "\u{39F}", "\u{3A1}", "\u{3A4}", "\u{3A6}", "\u{3A7}",
+    "\u{3A8}", "\u{2207}", "\u{3B1}", "\u{3B6}", "\u{3B7}", "\u{3BB}", "\u{3BD}", "\u{3BE}",
+    "\u{3BF}", "\u{3C3}", "\u{3C4}", "\u{3C5}", "\u{3C8}", "\u{3C9}", "\u{2202}", "\u{3DC}",
+    "\u{3DD}", "\u{430}", "\u{431}", "\u{432}", "\u{433}", "\u{434}", "\u{435}", "\u{436}",
+    "\u{437}", "\u{438}", "\u{43A}", "\u{43B}", "\u{43C}", "\u{43E}", "\u{43F}", "\u{440}",
+    "\u{441}", "\u{442}", "\u{443}", "\u{444}", "\u{445}", "\u{446}", "\u{447}", "\u{448}",
+    "\u{44B}", "\u{44D}", "\u{44E}", "\u{A689}", "\u{4D9}", "\u{456}", "\u{458}", "\u{4E9}",
+    "\u{4AF}", "\u{4CF}", "\u{491}", "\u{455}", "\u{45F}", "\u{4AB}", "\u{A651}", "\u{4B1}",
+    "\u{66E}", "\u{6A1}", "\u{66F}", "\u{30}\u{2E}", "\u{30}\u{2C}", "\u{31}\u{2C}", "\u{32}\u{2C}",
+    "\u{33}\u{2C}", "\u{34}\u{2C}", "\u{35}\u{2C}", "\u{36}\u{2C}", "\u{37}\u{2C}", "\u{38}\u{2C}",
+    "\u{39}\u{2C}", "\u{28}\u{41}\u{29}", "\u{28}\u{42}\u{29}", "\u{28}\u{43}\u{29}",
+    "\u{28}\u{44}\u{29}", "\u{28}\u{45}\u{29}", "\u{28}\u{46}\u{29}", "\u{28}\u{47}\u{29}",
+    "\u{28}\u{48}\u{29}", "\u{28}\u{49}\u{29}", "\u{28}\u{4A}\u{29}", "\u{28}\u{4B}\u{29}",
+    "\u{28}\u{4C}\u{29}", "\u{28}\u{4D}\u{29}", "\u{28}\u{4E}\u{29}", "\u{28}\u{4F}\u{29}",
+    "\u{28}\u{50}\u{29}", "\u{28}\u{51}\u{29}", "\u{28}\u{52}\u{29}", "\u{28}\u{53}\u{29}",
+    "\u{28}\u{54}\u{29}", "\u{28}\u{55}\u{29}", "\u{28}\u{56}\u{29}", "\u{28}\u{57}\u{29}",
+    "\u{28}\u{58}\u{29}", "\u{28}\u{59}\u{29}", "\u{28}\u{5A}\u{29}", "\u{3014}\u{53}\u{3015}",
+    "\u{43}\u{44}", "\u{57}\u{5A}", "\u{48}\u{56}", "\u{53}\u{44}", "\u{53}\u{53}",
+    "\u{50}\u{50}\u{56}", "\u{57}\u{43}", "\u{4D}\u{43}", "\u{4D}\u{44}", "\u{4D}\u{52}",
+    "\u{44}\u{4A}", "\u{307B}\u{304B}", "\u{30B3}\u{30B3}", "\u{5B57}", "\u{53CC}", "\u{591A}",
+    "\u{89E3}", "\u{4EA4}", "\u{6620}", "\u{7121}", "\u{524D}", "\u{5F8C}", "\u{518D}", "\u{65B0}",
+    "\u{521D}", "\u{7D42}", "\u{8CA9}", "\u{58F0}", "\u{5439}", "\u{6F14}", "\u{6295}", "\u{6355}",
+    "\u{904A}", "\u{6307}", "\u{6253}", "\u{7981}", "\u{7A7A}", "\u{5408}", "\u{6E80}", "\u{7533}",
+    "\u{5272}", "\u{55B6}", "\u{914D}", "\u{3014}\u{672C}\u{3015}", "\u{3014}\u{4E09}\u{3015}",
+    "\u{3014}\u{4E8C}\u{3015}", "\u{3014}\u{5B89}\u{3015}", "\u{3014}\u{70B9}\u{3015}",
+    "\u{3014}\u{6253}\u{3015}", "\u{3014}\u{76D7}\u{3015}", "\u{3014}\u{52DD}\u{3015}",
+    "\u{3014}\u{6557}\u{3015}", "\u{5F97}", "\u{53EF}", "\u{4E3D}", "\u{4E38}", "\u{4E41}",
+    "\u{20122}", "\u{4F60}", "\u{4FBB}", "\u{5002}", "\u{507A}", "\u{5099}", "\u{50CF}", "\u{349E}",
+    "\u{2063A}", "\u{5154}", "\u{5164}", "\u{5177}", "\u{2051C}", "\u{34B9}", "\u{5167}",
+    "\u{2054B}", "\u{5197}", "\u{51A4}", "\u{4ECC}", "\u{51AC}", "\u{291DF}", "\u{5203}",
+    "\u{34DF}", "\u{523B}", "\u{5246}", "\u{5277}", "\u{3515}", "\u{5305}", "\u{5306}", "\u{5349}",
+    "\u{535A}", "\u{5373}", "\u{537D}", "\u{537F}", "\u{20A2C}", "\u{7070}", "\u{53CA}", "\u{53DF}",
+    "\u{20B63}", "\u{53EB}", "\u{53F1}", "\u{5406}", "\u{549E}", "\u{5438}", "\u{5448}", "\u{5468}",
+    "\u{54A2}", "\u{54F6}", "\u{5510}", "\u{5553}", "\u{5563}", "\u{5584}", "\u{55AB}", "\u{55B3}",
+    "\u{55C2}", "\u{5716}", "\u{5717}", "\u{5651}", "\u{5674}", "\u{58EE}", "\u{57CE}", "\u{57F4}",
+    "\u{580D}", "\u{578B}", "\u{5832}", "\u{5831}", "\u{58AC}", "\u{214E4}", "\u{58F2}", "\u{58F7}",
+    "\u{5906}", "\u{5922}", "\u{5962}", "\u{216A8}", "\u{216EA}", "\u{59EC}", "\u{5A1B}",
+    "\u{5A27}", "\u{59D8}", "\u{5A66}", "\u{36EE}", "\u{36FC}", "\u{5B08}", "\u{5B3E}", "\u{219C8}",
+    "\u{5BC3}", "\u{5BD8}", "\u{5BF3}", "\u{21B18}", "\u{5BFF}", "\u{5C06}", "\u{5F53}", "\u{3781}",
+    "\u{5C60}", "\u{5CC0}", "\u{5C8D}", "\u{21DE4}", "\u{5D43}", "\u{21DE6}", "\u{5D6E}",
+    "\u{5D6B}", "\u{5D7C}", "\u{5DE1}", "\u{5DE2}", "\u{382F}", "\u{5DFD}", "\u{5E28}", "\u{5E3D}",
+    "\u{5E69}", "\u{3862}", "\u{22183}", "\u{387C}", "\u{5EB0}", "\u{5EB3}", "\u{5EB6}",
+    "\u{2A392}", "\u{22331}", "\u{8201}", "\u{5F22}", "\u{38C7}", "\u{232B8}", "\u{261DA}",
+    "\u{5F62}", "\u{5F6B}", "\u{38E3}", "\u{5F9A}", "\u{5FCD}", "\u{5FD7}", "\u{5FF9}", "\u{6081}",
+    "\u{393A}", "\u{391C}", "\u{226D4}", "\u{60C7}", "\u{6148}", "\u{614C}", "\u{617A}", "\u{61B2}",
+    "\u{61A4}", "\u{61AF}", "\u{61DE}", "\u{6210}", "\u{621B}", "\u{625D}", "\u{62B1}", "\u{62D4}",
+    "\u{6350}", "\u{22B0C}", "\u{633D}", "\u{62FC}", "\u{6368}", "\u{6383}", "\u{63E4}",
+    "\u{22BF1}", "\u{6422}", "\u{63C5}", "\u{63A9}", "\u{3A2E}", "\u{6469}", "\u{647E}", "\u{649D}",
+    "\u{6477}", "\u{3A6C}", "\u{656C}", "\u{2300A}", "\u{65E3}", "\u{66F8}", "\u{6649}", "\u{3B19}",
+    "\u{3B08}", "\u{3AE4}", "\u{5192}", "\u{5195}", "\u{6700}", "\u{669C}", "\u{80AD}", "\u{43D9}",
+    "\u{6721}", "\u{675E}", "\u{6753}", "\u{233C3}", "\u{3B49}", "\u{67FA}", "\u{6785}", "\u{6852}",
+    "\u{2346D}", "\u{688E}", "\u{681F}", "\u{6914}", "\u{6942}", "\u{69A3}", "\u{69EA}", "\u{6AA8}",
+    "\u{236A3}", "\u{6ADB}", "\u{3C18}", "\u{6B21}", "\u{238A7}", "\u{6B54}", "\u{3C4E}",
+    "\u{6B72}", "\u{6B9F}", "\u{6BBB}", "\u{23A8D}", "\u{21D0B}", "\u{23AFA}", "\u{6C4E}",
+    "\u{23CBC}", "\u{6CBF}", "\u{6CCD}", "\u{6C67}", "\u{6D16}", "\u{6D3E}", "\u{6D69}", "\u{6D78}",
+    "\u{6D85}", "\u{23D1E}", "\u{6D34}", "\u{6E2F}", "\u{6E6E}", "\u{3D33}", "\u{6EC7}",
+    "\u{23ED1}", "\u{6DF9}", "\u{6F6E}", "\u{23F5E}", "\u{23F8E}", "\u{6FC6}", "\u{7039}",
+    "\u{701B}", "\u{3D96}", "\u{704A}", "\u{707D}", "\u{7077}", "\u{70AD}", "\u{20525}", "\u{7145}",
+    "\u{24263}", "\u{719C}", "\u{243AB}", "\u{7228}", "\u{7250}", "\u{24608}", "\u{7280}",
+    "\u{7295}", "\u{24735}", "\u{24814}", "\u{737A}", "\u{738B}", "\u{3EAC}", "\u{73A5}",
+    "\u{3EB8}", "\u{7447}", "\u{745C}", "\u{7485}", "\u{74CA}", "\u{3F1B}", "\u{7524}", "\u{24C36}",
+    "\u{753E}", "\u{24C92}", "\u{2219F}", "\u{7610}", "\u{24FA1}", "\u{24FB8}", "\u{25044}",
+    "\u{3FFC}", "\u{4008}", "\u{250F3}", "\u{250F2}", "\u{25119}", "\u{25133}", "\u{771E}",
+    "\u{771F}", "\u{778B}", "\u{4046}", "\u{4096}", "\u{2541D}", "\u{784E}", "\u{40E3}",
+    "\u{25626}", "\u{2569A}", "\u{256C5}", "\u{79EB}", "\u{412F}", "\u{7A4A}", "\u{7A4F}",
+    "\u{2597C}", "\u{25AA7}", "\u{7AEE}", "\u{4202}", "\u{25BAB}", "\u{7BC6}", "\u{7BC9}",
+    "\u{4227}", "\u{25C80}", "\u{7CD2}", "\u{42A0}", "\u{7CE8}", "\u{7CE3}", "\u{7D00}",
+    "\u{25F86}", "\u{7D63}", "\u{4301}", "\u{7DC7}", "\u{7E02}", "\u{7E45}", "\u{4334}",
+    "\u{26228}", "\u{26247}", "\u{4359}", "\u{262D9}", "\u{7F7A}", "\u{2633E}", "\u{7F95}",
+    "\u{7FFA}", "\u{264DA}", "\u{26523}", "\u{8060}", "\u{265A8}", "\u{8070}", "\u{2335F}",
+    "\u{43D5}", "\u{80B2}", "\u{8103}", "\u{440B}", "\u{813E}", "\u{5AB5}", "\u{267A7}",
+    "\u{267B5}", "\u{23393}", "\u{2339C}", "\u{8204}", "\u{8F9E}", "\u{446B}", "\u{8291}",
+    "\u{828B}", "\u{829D}", "\u{52B3}", "\u{82B1}", "\u{82B3}", "\u{82BD}", "\u{82E6}", "\u{26B3C}",
+    "\u{831D}", "\u{8363}", "\u{83AD}", "\u{8323}", "\u{83BD}", "\u{83E7}", "\u{8353}", "\u{83CA}",
+    "\u{83CC}", "\u{83DC}", "\u{26C36}", "\u{26D6B}", "\u{26CD5}", "\u{452B}", "\u{84F1}",
+    "\u{84F3}", "\u{8516}", "\u{273CA}", "\u{8564}", "\u{26F2C}", "\u{455D}", "\u{4561}",
+    "\u{26FB1}", "\u{270D2}", "\u{456B}", "\u{8650}", "\u{8667}", "\u{8669}", "\u{86A9}",
+    "\u{8688}", "\u{870E}", "\u{86E2}", "\u{8728}", "\u{876B}", "\u{8786}", "\u{45D7}", "\u{87E1}",
+    "\u{8801}", "\u{45F9}", "\u{8860}", "\u{27667}", "\u{88D7}", "\u{88DE}", "\u{4635}", "\u{88FA}",
+    "\u{34BB}", "\u{278AE}", "\u{27966}", "\u{46BE}", "\u{46C7}", "\u{8AA0}", "\u{27CA8}",
+    "\u{8CAB}", "\u{8CC1}", "\u{8D1B}", "\u{8D77}", "\u{27F2F}", "\u{20804}", "\u{8DCB}",
+    "\u{8DBC}", "\u{8DF0}", "\u{208DE}", "\u{8ED4}", "\u{285D2}", "\u{285ED}", "\u{9094}",
+    "\u{90F1}", "\u{9111}", "\u{2872E}", "\u{911B}", "\u{9238}", "\u{92D7}", "\u{92D8}", "\u{927C}",
+    "\u{93F9}", "\u{9415}", "\u{28BFA}", "\u{958B}", "\u{4995}", "\u{95B7}", "\u{28D77}",
+    "\u{49E6}", "\u{96C3}", "\u{5DB2}", "\u{9723}", "\u{29145}", "\u{2921A}", "\u{4A6E}",
+    "\u{4A76}", "\u{97E0}", "\u{2940A}", "\u{4AB2}", "\u{29496}", "\u{9829}", "\u{295B6}",
+    "\u{98E2}", "\u{4B33}", "\u{9929}", "\u{99A7}", "\u{99C2}", "\u{99FE}", "\u{4BCE}", "\u{29B30}",
+    "\u{9C40}", "\u{9CFD}", "\u{4CCE}", "\u{4CED}", "\u{9D67}", "\u{2A0CE}", "\u{4CF8}",
+    "\u{2A105}", "\u{2A20E}", "\u{2A291}", "\u{4D56}", "\u{9EFE}", "\u{9F05}", "\u{9F0F}",
+    "\u{9F16}", "\u{2A600}",
+];
+
+/// Canonical compositions as `(first, second, composite)` `Utf8Char::to_bits`, sorted by `first`
+/// then `second`
+pub(crate) static COMPOSITIONS: [(u32, u32, u32); 961] = [
+    (0x3C80_8080, 0xCCB8_8080, 0xE289_AE80), (0x3D80_8080, 0xCCB8_8080, 0xE289_A080),
+    (0x3E80_8080, 0xCCB8_8080, 0xE289_AF80), (0x4180_8080, 0xCC80_8080, 0xC380_8080),
+    (0x4180_8080, 0xCC81_8080, 0xC381_8080), (0x4180_8080, 0xCC82_8080, 0xC382_8080),
+    (0x4180_8080, 0xCC83_8080, 0xC383_8080), (0x4180_8080, 0xCC84_8080, 0xC480_8080),
+    (0x4180_8080, 0xCC86_8080, 0xC482_8080), (0x4180_8080, 0xCC87_8080, 0xC8A6_8080),
+    (0x4180_8080, 0xCC88_8080, 0xC384_8080), (0x4180_8080, 0xCC89_8080, 0xE1BA_A280),
+    (0x4180_8080, 0xCC8A_8080, 0xC385_8080), (0x4180_8080, 0xCC8C_8080, 0xC78D_8080),
+    (0x4180_8080, 0xCC8F_8080, 0xC880_8080), (0x4180_8080, 0xCC91_8080, 0xC882_8080),
+    (0x4180_8080, 0xCCA3_8080, 0xE1BA_A080), (0x4180_8080, 0xCCA5_8080, 0xE1B8_8080),
+    (0x4180_8080, 0xCCA8_8080, 0xC484_8080), (0x4280_8080, 0xCC87_8080, 0xE1B8_8280),
+    (0x4280_8080, 0xCCA3_8080, 0xE1B8_8480), (0x4280_8080, 0xCCB1_8080, 0xE1B8_8680),
+    (0x4380_8080, 0xCC81_8080, 0xC486_8080), (0x4380_8080, 0xCC82_8080, 0xC488_8080),
+    (0x4380_8080, 0xCC87_8080, 0xC48A_8080), (0x4380_8080, 0xCC8C_8080, 0xC48C_8080),
+    (0x4380_8080, 0xCCA7_8080, 0xC387_8080), (0x4480_8080, 0xCC87_8080, 0xE1B8_8A80),
+    (0x4480_8080, 0xCC8C_8080, 0xC48E_8080), (0x4480_8080, 0xCCA3_8080, 0xE1B8_8C80),
+    (0x4480_8080, 0xCCA7_8080, 0xE1B8_9080), (0x4480_8080, 0xCCAD_8080, 0xE1B8_9280),
+    (0x4480_8080, 0xCCB1_8080, 0xE1B8_8E80), (0x4580_8080, 0xCC80_8080, 0xC388_8080),
+    (0x4580_8080, 0xCC81_8080, 0xC389_8080), (0x4580_8080, 0xCC82_8080, 0xC38A_8080),
+    (0x4580_8080, 0xCC83_8080, 0xE1BA_BC80), (0x4580_8080, 0xCC84_8080, 0xC492_8080),
+    (0x4580_8080, 0xCC86_8080, 0xC494_8080), (0x4580_8080, 0xCC87_8080, 0xC496_8080),
+    (0x4580_8080, 0xCC88_8080, 0xC38B_8080), (0x4580_8080, 0xCC89_8080, 0xE1BA_BA80),
+    (0x4580_8080, 0xCC8C_8080, 0xC49A_8080), (0x4580_8080, 0xCC8F_8080, 0xC884_8080),
+    (0x4580_8080, 0xCC91_8080, 0xC886_8080), (0x4580_8080, 0xCCA3_8080, 0xE1BA_B880),
+    (0x4580_8080, 0xCCA7_8080, 0xC8A8_8080), (0x4580_8080, 0xCCA8_8080, 0xC498_8080),
+    (0x4580_8080, 0xCCAD_8080, 0xE1B8_9880), (0x4580_8080, 0xCCB0_8080, 0xE1B8_9A80),
+    (0x4680_8080, 0xCC87_8080, 0xE1B8_9E80), (0x4780_8080, 0xCC81_8080, 0xC7B4_8080),
+    (0x4780_8080, 0xCC82_8080, 0xC49C_8080), (0x4780_8080, 0xCC84_8080, 0xE1B8_A080),
+    (0x4780_8080, 0xCC86_8080, 0xC49E_8080), (0x4780_8080, 0xCC87_8080, 0xC4A0_8080),
+    (0x4780_8080, 0xCC8C_8080, 0xC7A6_8080), (0x4780_8080, 0xCCA7_8080, 0xC4A2_8080),
+    (0x4880_8080, 0xCC82_8080, 0xC4A4_8080), (0x4880_8080, 0xCC87_8080, 0xE1B8_A280),
+    (0x4880_8080, 0xCC88_8080, 0xE1B8_A680), (0x4880_8080, 0xCC8C_8080, 0xC89E_8080),
+    (0x4880_8080, 0xCCA3_8080, 0xE1B8_A480), (0x4880_8080, 0xCCA7_8080, 0xE1B8_A880),
+    (0x4880_8080, 0xCCAE_8080, 0xE1B8_AA80), (0x4980_8080, 0xCC80_8080, 0xC38C_8080),
+    (0x4980_8080, 0xCC81_8080, 0xC38D_8080), (0x4980_8080, 0xCC82_8080, 0xC38E_8080),
+    (0x4980_8080, 0xCC83_8080, 0xC4A8_8080), (0x4980_8080, 0xCC84_8080, 0xC4AA_8080),
+    (0x4980_8080, 0xCC86_8080, 0xC4AC_8080), (0x4980_8080, 0xCC87_8080, 0xC4B0_8080),
+    (0x4980_8080, 0xCC88_8080, 0xC38F_8080), (0x4980_8080, 0xCC89_8080, 0xE1BB_8880),
+    (0x4980_8080, 0xCC8C_8080, 0xC78F_8080), (0x4980_8080, 0xCC8F_8080, 0xC888_8080),
+    (0x4980_8080, 0xCC91_8080, 0xC88A_8080), (0x4980_8080, 0xCCA3_8080, 0xE1BB_8A80),
+    (0x4980_8080, 0xCCA8_8080, 0xC4AE_8080), (0x4980_8080, 0xCCB0_8080, 0xE1B8_AC80),
+    (0x4A80_8080, 0xCC82_8080, 0xC4B4_8080), (0x4B80_8080, 0xCC81_8080, 0xE1B8_B080),
+    (0x4B80_8080, 0xCC8C_8080, 0xC7A8_8080), (0x4B80_8080, 0xCCA3_8080, 0xE1B8_B280),
+    (0x4B80_8080, 0xCCA7_8080, 0xC4B6_8080), (0x4B80_8080, 0xCCB1_8080, 0xE1B8_B480),
+    (0x4C80_8080, 0xCC81_8080, 0xC4B9_8080), (0x4C80_8080, 0xCC8C_8080, 0xC4BD_8080),
+    (0x4C80_8080, 0xCCA3_8080, 0xE1B8_B680), (0x4C80_8080, 0xCCA7_8080, 0xC4BB_8080),
+    (0x4C80_8080, 0xCCAD_8080, 0xE1B8_BC80), (0x4C80_8080, 0xCCB1_8080, 0xE1B8_BA80),
+    (0x4D80_8080, 0xCC81_8080, 0xE1B8_BE80), (0x4D80_8080, 0xCC87_8080, 0xE1B9_8080),
+    (0x4D80_8080, 0xCCA3_8080, 0xE1B9_8280), (0x4E80_8080, 0xCC80_8080, 0xC7B8_8080),
+    (0x4E80_8080, 0xCC81_8080, 0xC583_8080), (0x4E80_8080, 0xCC83_8080, 0xC391_8080),
+    (0x4E80_8080, 0xCC87_8080, 0xE1B9_8480), (0x4E80_8080, 0xCC8C_8080, 0xC587_8080),
+    (0x4E80_8080, 0xCCA3_8080, 0xE1B9_8680), (0x4E80_8080, 0xCCA7_8080, 0xC585_8080),
+    (0x4E80_8080, 0xCCAD_8080, 0xE1B9_8A80), (0x4E80_8080, 0xCCB1_8080, 0xE1B9_8880),
+    (0x4F80_8080, 0xCC80_8080, 0xC392_8080), (0x4F80_8080, 0xCC81_8080, 0xC393_8080),
+    (0x4F80_8080, 0xCC82_8080, 0xC394_8080), (0x4F80_8080, 0xCC83_8080, 0xC395_8080),
+    (0x4F80_8080, 0xCC84_8080, 0xC58C_8080), (0x4F80_8080, 0xCC86_8080, 0xC58E_8080),
+    (0x4F80_8080, 0xCC87_8080, 0xC8AE_8080), (0x4F80_8080, 0xCC88_8080, 0xC396_8080),
+    (0x4F80_8080, 0xCC89_8080, 0xE1BB_8E80), (0x4F80_8080, 0xCC8B_8080, 0xC590_8080),
+    (0x4F80_8080, 0xCC8C_8080, 0xC791_8080), (0x4F80_8080, 0xCC8F_8080, 0xC88C_8080),
+    (0x4F80_8080, 0xCC91_8080, 0xC88E_8080), (0x4F80_8080, 0xCC9B_8080, 0xC6A0_8080),
+    (0x4F80_8080, 0xCCA3_8080, 0xE1BB_8C80), (0x4F80_8080, 0xCCA8_8080, 0xC7AA_8080),
+    (0x5080_8080, 0xCC81_8080, 0xE1B9_9480), (0x5080_8080, 0xCC87_8080, 0xE1B9_9680),
+    (0x5280_8080, 0xCC81_8080, 0xC594_8080), (0x5280_8080, 0xCC87_8080, 0xE1B9_9880),
+    (0x5280_8080, 0xCC8C_8080, 0xC598_8080), (0x5280_8080, 0xCC8F_8080, 0xC890_8080),
+    (0x5280_8080, 0xCC91_8080, 0xC892_8080), (0x5280_8080, 0xCCA3_8080, 0xE1B9_9A80),
+    (0x5280_8080, 0xCCA7_8080, 0xC596_8080), (0x5280_8080, 0xCCB1_8080, 0xE1B9_9E80),
+    (0x5380_8080, 0xCC81_8080, 0xC59A_8080), (0x5380_8080, 0xCC82_8080, 0xC59C_8080),
+    (0x5380_8080, 0xCC87_8080, 0xE1B9_A080), (0x5380_8080, 0xCC8C_8080, 0xC5A0_8080),
+    (0x5380_8080, 0xCCA3_8080, 0xE1B9_A280), (0x5380_8080, 0xCCA6_8080, 0xC898_8080),
+    (0x5380_8080, 0xCCA7_8080, 0xC59E_8080), (0x5480_8080, 0xCC87_8080, 0xE1B9_AA80),
+    (0x5480_8080, 0xCC8C_8080, 0xC5A4_8080), (0x5480_8080, 0xCCA3_8080, 0xE1B9_AC80),
+    (0x5480_8080, 0xCCA6_8080, 0xC89A_8080), (0x5480_8080, 0xCCA7_8080, 0xC5A2_8080),
+    (0x5480_8080, 0xCCAD_8080, 0xE1B9_B080), (0x5480_8080, 0xCCB1_8080, 0xE1B9_AE80),
+    (0x5580_8080, 0xCC80_8080, 0xC399_8080), (0x5580_8080, 0xCC81_8080, 0xC39A_8080),
+    (0x5580_8080, 0xCC82_8080, 0xC39B_8080), (0x5580_8080, 0xCC83_8080, 0xC5A8_8080),
+    (0x5580_8080, 0xCC84_8080, 0xC5AA_8080), (0x5580_8080, 0xCC86_8080, 0xC5AC_8080),
+    (0x5580_8080, 0xCC88_8080, 0xC39C_8080), (0x5580_8080, 0xCC89_8080, 0xE1BB_A680),
+    (0x5580_8080, 0xCC8A_8080, 0xC5AE_8080), (0x5580_8080, 0xCC8B_8080, 0xC5B0_8080),
+    (0x5580_8080, 0xCC8C_8080, 0xC793_8080), (0x5580_8080, 0xCC8F_8080, 0xC894_8080),
+    (0x5580_8080, 0xCC91_8080, 0xC896_8080), (0x5580_8080, 0xCC9B_8080, 0xC6AF_8080),
+    (0x5580_8080, 0xCCA3_8080, 0xE1BB_A480), (0x5580_8080, 0xCCA4_8080, 0xE1B9_B280),
+    (0x5580_8080, 0xCCA8_8080, 0xC5B2_8080), (0x5580_8080, 0xCCAD_8080, 0xE1B9_B680),
+    (0x5580_8080, 0xCCB0_8080, 0xE1B9_B480), (0x5680_8080, 0xCC83_8080, 0xE1B9_BC80),
+    (0x5680_8080, 0xCCA3_8080, 0xE1B9_BE80), (0x5780_8080, 0xCC80_8080, 0xE1BA_8080),
+    (0x5780_8080, 0xCC81_8080, 0xE1BA_8280), (0x5780_8080, 0xCC82_8080, 0xC5B4_8080),
+    (0x5780_8080, 0xCC87_8080, 0xE1BA_8680), (0x5780_8080, 0xCC88_8080, 0xE1BA_8480),
+    (0x5780_8080, 0xCCA3_8080, 0xE1BA_8880), (0x5880_8080, 0xCC87_8080, 0xE1BA_8A80),
+    (0x5880_8080, 0xCC88_8080, 0xE1BA_8C80), (0x5980_8080, 0xCC80_8080, 0xE1BB_B280),
+    (0x5980_8080, 0xCC81_8080, 0xC39D_8080), (0x5980_8080, 0xCC82_8080, 0xC5B6_8080),
+    (0x5980_8080, 0xCC83_8080, 0xE1BB_B880), (0x5980_8080, 0xCC84_8080, 0xC8B2_8080),
+    (0x5980_8080, 0xCC87_8080, 0xE1BA_8E80), (0x5980_8080, 0xCC88_8080, 0xC5B8_8080),
+    (0x5980_8080, 0xCC89_8080, 0xE1BB_B680), (0x5980_8080, 0xCCA3_8080, 0xE1BB_B480),
+    (0x5A80_8080, 0xCC81_8080, 0xC5B9_8080), (0x5A80_8080, 0xCC82_8080, 0xE1BA_9080),
+    (0x5A80_8080, 0xCC87_8080, 0xC5BB_8080), (0x5A80_8080, 0xCC8C_8080, 0xC5BD_8080),
+    (0x5A80_8080, 0xCCA3_8080, 0xE1BA_9280), (0x5A80_8080, 0xCCB1_8080, 0xE1BA_9480),
+    (0x6180_8080, 0xCC80_8080, 0xC3A0_8080), (0x6180_8080, 0xCC81_8080, 0xC3A1_8080),
+    (0x6180_8080, 0xCC82_8080, 0xC3A2_8080), (0x6180_8080, 0xCC83_8080, 0xC3A3_8080),
+    (0x6180_8080, 0xCC84_8080, 0xC481_8080), (0x6180_8080, 0xCC86_8080, 0xC483_8080),
+    (0x6180_8080, 0xCC87_8080, 0xC8A7_8080), (0x6180_8080, 0xCC88_8080, 0xC3A4_8080),
+    (0x6180_8080, 0xCC89_8080, 0xE1BA_A380), (0x6180_8080, 0xCC8A_8080, 0xC3A5_8080),
+    (0x6180_8080, 0xCC8C_8080, 0xC78E_8080), (0x6180_8080, 0xCC8F_8080, 0xC881_8080),
+    (0x6180_8080, 0xCC91_8080, 0xC883_8080), (0x6180_8080, 0xCCA3_8080, 0xE1BA_A180),
+    (0x6180_8080, 0xCCA5_8080, 0xE1B8_8180), (0x6180_8080, 0xCCA8_8080, 0xC485_8080),
+    (0x6280_8080, 0xCC87_8080, 0xE1B8_8380), (0x6280_8080, 0xCCA3_8080, 0xE1B8_8580),
+    (0x6280_8080, 0xCCB1_8080, 0xE1B8_8780), (0x6380_8080, 0xCC81_8080, 0xC487_8080),
+    (0x6380_8080, 0xCC82_8080, 0xC489_8080), (0x6380_8080, 0xCC87_8080, 0xC48B_8080),
+    (0x6380_8080, 0xCC8C_8080, 0xC48D_8080), (0x6380_8080, 0xCCA7_8080, 0xC3A7_8080),
+    (0x6480_8080, 0xCC87_8080, 0xE1B8_8B80), (0x6480_8080, 0xCC8C_8080, 0xC48F_8080),
+    (0x6480_8080, 0xCCA3_8080, 0xE1B8_8D80), (0x6480_8080, 0xCCA7_8080, 0xE1B8_9180),
+    (0x6480_8080, 0xCCAD_8080, 0xE1B8_9380), (0x6480_8080, 0xCCB1_8080, 0xE1B8_8F80),
+    (0x6580_8080, 0xCC80_8080, 0xC3A8_8080), (0x6580_8080, 0xCC81_8080, 0xC3A9_8080),
+    (0x6580_8080, 0xCC82_8080, 0xC3AA_8080), (0x6580_8080, 0xCC83_8080, 0xE1BA_BD80),
+    (0x6580_8080, 0xCC84_8080, 0xC493_8080), (0x6580_8080, 0xCC86_8080, 0xC495_8080),
+    (0x6580_8080, 0xCC87_8080, 0xC497_8080), (0x6580_8080, 0xCC88_8080, 0xC3AB_8080),
+    (0x6580_8080, 0xCC89_8080, 0xE1BA_BB80), (0x6580_8080, 0xCC8C_8080, 0xC49B_8080),
+    (0x6580_8080, 0xCC8F_8080, 0xC885_8080), (0x6580_8080, 0xCC91_8080, 0xC887_8080),
+    (0x6580_8080, 0xCCA3_8080, 0xE1BA_B980), (0x6580_8080, 0xCCA7_8080, 0xC8A9_8080),
+    (0x6580_8080, 0xCCA8_8080, 0xC499_8080), (0x6580_8080, 0xCCAD_8080, 0xE1B8_9980),
+    (0x6580_8080, 0xCCB0_8080, 0xE1B8_9B80), (0x6680_8080, 0xCC87_8080, 0xE1B8_9F80),
+    (0x6780_8080, 0xCC81_8080, 0xC7B5_8080), (0x6780_8080, 0xCC82_8080, 0xC49D_8080),
+    (0x6780_8080, 0xCC84_8080, 0xE1B8_A180), (0x6780_8080, 0xCC86_8080, 0xC49F_8080),
+    (0x6780_8080, 0xCC87_8080, 0xC4A1_8080), (0x6780_8080, 0xCC8C_8080, 0xC7A7_8080),
+    (0x6780_8080, 0xCCA7_8080, 0xC4A3_8080), (0x6880_8080, 0xCC82_8080, 0xC4A5_8080),
+    (0x6880_8080, 0xCC87_8080, 0xE1B8_A380), (0x6880_8080, 0xCC88_8080, 0xE1B8_A780),
+    (0x6880_8080, 0xCC8C_8080, 0xC89F_8080), (0x6880_8080, 0xCCA3_8080, 0xE1B8_A580),
+    (0x6880_8080, 0xCCA7_8080, 0xE1B8_A980), (0x6880_8080, 0xCCAE_8080, 0xE1B8_AB80),
+    (0x6880_8080, 0xCCB1_8080, 0xE1BA_9680), (0x6980_8080, 0xCC80_8080, 0xC3AC_8080),
+    (0x6980_8080, 0xCC81_8080, 0xC3AD_8080), (0x6980_8080, 0xCC82_8080, 0xC3AE_8080),
+    (0x6980_8080, 0xCC83_8080, 0xC4A9_8080), (0x6980_8080, 0xCC84_8080, 0xC4AB_8080),
+    (0x6980_8080, 0xCC86_8080, 0xC4AD_8080), (0x6980_8080, 0xCC88_8080, 0xC3AF_8080),
+    (0x6980_8080, 0xCC89_8080, 0xE1BB_8980), (0x6980_8080, 0xCC8C_8080, 0xC790_8080),
+    (0x6980_8080, 0xCC8F_8080, 0xC889_8080), (0x6980_8080, 0xCC91_8080, 0xC88B_8080),
+    (0x6980_8080, 0xCCA3_8080, 0xE1BB_8B80), (0x6980_8080, 0xCCA8_8080, 0xC4AF_8080),
+    (0x6980_8080, 0xCCB0_8080, 0xE1B8_AD80), (0x6A80_8080, 0xCC82_8080, 0xC4B5_8080),
+    (0x6A80_8080, 0xCC8C_8080, 0xC7B0_8080), (0x6B80_8080, 0xCC81_8080, 0xE1B8_B180),
+    (0x6B80_8080, 0xCC8C_8080, 0xC7A9_8080), (0x6B80_8080, 0xCCA3_8080, 0xE1B8_B380),
+    (0x6B80_8080, 0xCCA7_8080, 0xC4B7_8080), (0x6B80_8080, 0xCCB1_8080, 0xE1B8_B580),
+    (0x6C80_8080, 0xCC81_8080, 0xC4BA_8080), (0x6C80_8080, 0xCC8C_8080, 0xC4BE_8080),
+    (0x6C80_8080, 0xCCA3_8080, 0xE1B8_B780), (0x6C80_8080, 0xCCA7_8080, 0xC4BC_8080),
+    (0x6C80_8080, 0xCCAD_8080, 0xE1B8_BD80), (0x6C80_8080, 0xCCB1_8080, 0xE1B8_BB80),
+    (0x6D80_8080, 0xCC81_8080, 0xE1B8_BF80), (0x6D80_8080, 0xCC87_8080, 0xE1B9_8180),
+    (0x6D80_8080, 0xCCA3_8080, 0xE1B9_8380), (0x6E80_8080, 0xCC80_8080, 0xC7B9_8080),
+    (0x6E80_8080, 0xCC81_8080, 0xC584_8080), (0x6E80_8080, 0xCC83_8080, 0xC3B1_8080),
+    (0x6E80_8080, 0xCC87_8080, 0xE1B9_8580), (0x6E80_8080, 0xCC8C_8080, 0xC588_8080),
+    (0x6E80_8080, 0xCCA3_8080, 0xE1B9_8780), (0x6E80_8080, 0xCCA7_8080, 0xC586_8080),
+    (0x6E80_8080, 0xCCAD_8080, 0xE1B9_8B80), (0x6E80_8080, 0xCCB1_8080, 0xE1B9_8980),
+    (0x6F80_8080, 0xCC80_8080, 0xC3B2_8080), (0x6F80_8080, 0xCC81_8080, 0xC3B3_8080),
+    (0x6F80_8080, 0xCC82_8080, 0xC3B4_8080), (0x6F80_8080, 0xCC83_8080, 0xC3B5_8080),
+    (0x6F80_8080, 0xCC84_8080, 0xC58D_8080), (0x6F80_8080, 0xCC86_8080, 0xC58F_8080),
+    (0x6F80_8080, 0xCC87_8080, 0xC8AF_8080), (0x6F80_8080, 0xCC88_8080, 0xC3B6_8080),
+    (0x6F80_8080, 0xCC89_8080, 0xE1BB_8F80), (0x6F80_8080, 0xCC8B_8080, 0xC591_8080),
+    (0x6F80_8080, 0xCC8C_8080, 0xC792_8080), (0x6F80_8080, 0xCC8F_8080, 0xC88D_8080),
+    (0x6F80_8080, 0xCC91_8080, 0xC88F_8080), (0x6F80_8080, 0xCC9B_8080, 0xC6A1_8080),
+    (0x6F80_8080, 0xCCA3_8080, 0xE1BB_8D80), (0x6F80_8080, 0xCCA8_8080, 0xC7AB_8080),
+    (0x7080_8080, 0xCC81_8080, 0xE1B9_9580), (0x7080_8080, 0xCC87_8080, 0xE1B9_9780),
+    (0x7280_8080, 0xCC81_8080, 0xC595_8080), (0x7280_8080, 0xCC87_8080, 0xE1B9_9980),
+    (0x7280_8080, 0xCC8C_8080, 0xC599_8080), (0x7280_8080, 0xCC8F_8080, 0xC891_8080),
+    (0x7280_8080, 0xCC91_8080, 0xC893_8080), (0x7280_8080, 0xCCA3_8080, 0xE1B9_9B80),
+    (0x7280_8080, 0xCCA7_8080, 0xC597_8080), (0x7280_8080, 0xCCB1_8080, 0xE1B9_9F80),
+    (0x7380_8080, 0xCC81_8080, 0xC59B_8080), (0x7380_8080, 0xCC82_8080, 0xC59D_8080),
+    (0x7380_8080, 0xCC87_8080, 0xE1B9_A180), (0x7380_8080, 0xCC8C_8080, 0xC5A1_8080),
+    (0x7380_8080, 0xCCA3_8080, 0xE1B9_A380), (0x7380_8080, 0xCCA6_8080, 0xC899_8080),
+    (0x7380_8080, 0xCCA7_8080, 0xC59F_8080), (0x7480_8080, 0xCC87_8080, 0xE1B9_AB80),
+    (0x7480_8080, 0xCC88_8080, 0xE1BA_9780), (0x7480_8080, 0xCC8C_8080, 0xC5A5_8080),
+    (0x7480_8080, 0xCCA3_8080, 0xE1B9_AD80), (0x7480_8080, 0xCCA6_8080, 0xC89B_8080),
+    (0x7480_8080, 0xCCA7_8080, 0xC5A3_8080), (0x7480_8080, 0xCCAD_8080, 0xE1B9_B180),
+    (0x7480_8080, 0xCCB1_8080, 0xE1B9_AF80), (0x7580_8080, 0xCC80_8080, 0xC3B9_8080),
+    (0x7580_8080, 0xCC81_8080, 0xC3BA_8080), (0x7580_8080, 0xCC82_8080, 0xC3BB_8080),
+    (0x7580_8080, 0xCC83_8080, 0xC5A9_8080), (0x7580_8080, 0xCC84_8080, 0xC5AB_8080),
+    (0x7580_8080, 0xCC86_8080, 0xC5AD_8080), (0x7580_8080, 0xCC88_8080, 0xC3BC_8080),
+    (0x7580_8080, 0xCC89_8080, 0xE1BB_A780), (0x7580_8080, 0xCC8A_8080, 0xC5AF_8080),
+    (0x7580_8080, 0xCC8B_8080, 0xC5B1_8080), (0x7580_8080, 0xCC8C_8080, 0xC794_8080),
+    (0x7580_8080, 0xCC8F_8080, 0xC895_8080), (0x7580_8080, 0xCC91_8080, 0xC897_8080),
+    (0x7580_8080, 0xCC9B_8080, 0xC6B0_8080), (0x7580_8080, 0xCCA3_8080, 0xE1BB_A580),
+    (0x7580_8080, 0xCCA4_8080, 0xE1B9_B380), (0x7580_8080, 0xCCA8_8080, 0xC5B3_8080),
+    (0x7580_8080, 0xCCAD_8080, 0xE1B9_B780), (0x7580_8080, 0xCCB0_8080, 0xE1B9_B580),
+    (0x7680_8080, 0xCC83_8080, 0xE1B9_BD80), (0x7680_8080, 0xCCA3_8080, 0xE1B9_BF80),
+    (0x7780_8080, 0xCC80_8080, 0xE1BA_8180), (0x7780_8080, 0xCC81_8080, 0xE1BA_8380),
+    (0x7780_8080, 0xCC82_8080, 0xC5B5_8080), (0x7780_8080, 0xCC87_8080, 0xE1BA_8780),
+    (0x7780_8080, 0xCC88_8080, 0xE1BA_8580), (0x7780_8080, 0xCC8A_8080, 0xE1BA_9880),
+    (0x7780_8080, 0xCCA3_8080, 0xE1BA_8980), (0x7880_8080, 0xCC87_8080, 0xE1BA_8B80),
+    (0x7880_8080, 0xCC88_8080, 0xE1BA_8D80), (0x7980_8080, 0xCC80_8080, 0xE1BB_B380),
+    (0x7980_8080, 0xCC81_8080, 0xC3BD_8080), (0x7980_8080, 0xCC82_8080, 0xC5B7_8080),
+    (0x7980_8080, 0xCC83_8080, 0xE1BB_B980), (0x7980_8080, 0xCC84_8080, 0xC8B3_8080),
+    (0x7980_8080, 0xCC87_8080, 0xE1BA_8F80), (0x7980_8080, 0xCC88_8080, 0xC3BF_8080),
+    (0x7980_8080, 0xCC89_8080, 0xE1BB_B780), (0x7980_8080, 0xCC8A_8080, 0xE1BA_9980),
+    (0x7980_8080, 0xCCA3_8080, 0xE1BB_B580), (0x7A80_8080, 0xCC81_8080, 0xC5BA_8080),
+    (0x7A80_8080, 0xCC82_8080, 0xE1BA_9180), (0x7A80_8080, 0xCC87_8080, 0xC5BC_8080),
+    (0x7A80_8080, 0xCC8C_8080, 0xC5BE_8080), (0x7A80_8080, 0xCCA3_8080, 0xE1BA_9380),
+    (0x7A80_8080, 0xCCB1_8080, 0xE1BA_9580), (0xC2A8_8080, 0xCC80_8080, 0xE1BF_AD80),
+    (0xC2A8_8080, 0xCC81_8080, 0xCE85_8080), (0xC2A8_8080, 0xCD82_8080, 0xE1BF_8180),
+    (0xC382_8080, 0xCC80_8080, 0xE1BA_A680), (0xC382_8080, 0xCC81_8080, 0xE1BA_A480),
+    (0xC382_8080, 0xCC83_8080, 0xE1BA_AA80), (0xC382_8080, 0xCC89_8080, 0xE1BA_A880),
+    (0xC384_8080, 0xCC84_8080, 0xC79E_8080), (0xC385_8080, 0xCC81_8080, 0xC7BA_8080),
+    (0xC386_8080, 0xCC81_8080, 0xC7BC_8080), (0xC386_8080, 0xCC84_8080, 0xC7A2_8080),
+    (0xC387_8080, 0xCC81_8080, 0xE1B8_8880), (0xC38A_8080, 0xCC80_8080, 0xE1BB_8080),
+    (0xC38A_8080, 0xCC81_8080, 0xE1BA_BE80), (0xC38A_8080, 0xCC83_8080, 0xE1BB_8480),
+    (0xC38A_8080, 0xCC89_8080, 0xE1BB_8280), (0xC38F_8080, 0xCC81_8080, 0xE1B8_AE80),
+    (0xC394_8080, 0xCC80_8080, 0xE1BB_9280), (0xC394_8080, 0xCC81_8080, 0xE1BB_9080),
+    (0xC394_8080, 0xCC83_8080, 0xE1BB_9680), (0xC394_8080, 0xCC89_8080, 0xE1BB_9480),
+    (0xC395_8080, 0xCC81_8080, 0xE1B9_8C80), (0xC395_8080, 0xCC84_8080, 0xC8AC_8080),
+    (0xC395_8080, 0xCC88_8080, 0xE1B9_8E80), (0xC396_8080, 0xCC84_8080, 0xC8AA_8080),
+    (0xC398_8080, 0xCC81_8080, 0xC7BE_8080), (0xC39C_8080, 0xCC80_8080, 0xC79B_8080),
+    (0xC39C_8080, 0xCC81_8080, 0xC797_8080), (0xC39C_8080, 0xCC84_8080, 0xC795_8080),
+    (0xC39C_8080, 0xCC8C_8080, 0xC799_8080), (0xC3A2_8080, 0xCC80_8080, 0xE1BA_A780),
+    (0xC3A2_8080, 0xCC81_8080, 0xE1BA_A580), (0xC3A2_8080, 0xCC83_8080, 0xE1BA_AB80),
+    (0xC3A2_8080, 0xCC89_8080, 0xE1BA_A980), (0xC3A4_8080, 0xCC84_8080, 0xC79F_8080),
+    (0xC3A5_8080, 0xCC81_8080, 0xC7BB_8080), (0xC3A6_8080, 0xCC81_8080, 0xC7BD_8080),
+    (0xC3A6_8080, 0xCC84_8080, 0xC7A3_8080), (0xC3A7_8080, 0xCC81_8080, 0xE1B8_8980),
+    (0xC3AA_8080, 0xCC80_8080, 0xE1BB_8180), (0xC3AA_8080, 0xCC81_8080, 0xE1BA_BF80),
+    (0xC3AA_8080, 0xCC83_8080, 0xE1BB_8580), (0xC3AA_8080, 0xCC89_8080, 0xE1BB_8380),
+    (0xC3AF_8080, 0xCC81_8080, 0xE1B8_AF80), (0xC3B4_8080, 0xCC80_8080, 0xE1BB_9380),
+    (0xC3B4_8080, 0xCC81_8080, 0xE1BB_9180), (0xC3B4_8080, 0xCC83_8080, 0xE1BB_9780),
+    (0xC3B4_8080, 0xCC89_8080, 0xE1BB_9580), (0xC3B5_8080, 0xCC81_8080, 0xE1B9_8D80),
+    (0xC3B5_8080, 0xCC84_8080, 0xC8AD_8080), (0xC3B5_8080, 0xCC88_8080, 0xE1B9_8F80),
+    (0xC3B6_8080, 0xCC84_8080, 0xC8AB_8080), (0xC3B8_8080, 0xCC81_8080, 0xC7BF_8080),
+    (0xC3BC_8080, 0xCC80_8080, 0xC79C_8080), (0xC3BC_8080, 0xCC81_8080, 0xC798_8080),
+    (0xC3BC_8080, 0xCC84_8080, 0xC796_8080), (0xC3BC_8080, 0xCC8C_8080, 0xC79A_8080),
+    (0xC482_8080, 0xCC80_8080, 0xE1BA_B080), (0xC482_8080, 0xCC81_8080, 0xE1BA_AE80),
+    (0xC482_8080, 0xCC83_8080, 0xE1BA_B480), (0xC482_8080, 0xCC89_8080, 0xE1BA_B280),
+    (0xC483_8080, 0xCC80_8080, 0xE1BA_B180), (0xC483_8080, 0xCC81_8080, 0xE1BA_AF80),
+    (0xC483_8080, 0xCC83_8080, 0xE1BA_B580), (0xC483_8080, 0xCC89_8080, 0xE1BA_B380),
+    (0xC492_8080, 0xCC80_8080, 0xE1B8_9480), (0xC492_8080, 0xCC81_8080, 0xE1B8_9680),
+    (0xC493_8080, 0xCC80_8080, 0xE1B8_9580), (0xC493_8080, 0xCC81_8080, 0xE1B8_9780),
+    (0xC58C_8080, 0xCC80_8080, 0xE1B9_9080), (0xC58C_8080, 0xCC81_8080, 0xE1B9_9280),
+    (0xC58D_8080, 0xCC80_8080, 0xE1B9_9180), (0xC58D_8080, 0xCC81_8080, 0xE1B9_9380),
+    (0xC59A_8080, 0xCC87_8080, 0xE1B9_A480), (0xC59B_8080, 0xCC87_8080, 0xE1B9_A580),
+    (0xC5A0_8080, 0xCC87_8080, 0xE1B9_A680), (0xC5A1_8080, 0xCC87_8080, 0xE1B9_A780),
+    (0xC5A8_8080, 0xCC81_8080, 0xE1B9_B880), (0xC5A9_8080, 0xCC81_8080, 0xE1B9_B980),
+    (0xC5AA_8080, 0xCC88_8080, 0xE1B9_BA80), (0xC5AB_8080, 0xCC88_8080, 0xE1B9_BB80),
+    (0xC5BF_8080, 0xCC87_8080, 0xE1BA_9B80), (0xC6A0_8080, 0xCC80_8080, 0xE1BB_9C80),
+    (0xC6A0_8080, 0xCC81_8080, 0xE1BB_9A80), (0xC6A0_8080, 0xCC83_8080, 0xE1BB_A080),
+    (0xC6A0_8080, 0xCC89_8080, 0xE1BB_9E80), (0xC6A0_8080, 0xCCA3_8080, 0xE1BB_A280),
+    (0xC6A1_8080, 0xCC80_8080, 0xE1BB_9D80), (0xC6A1_8080, 0xCC81_8080, 0xE1BB_9B80),
+    (0xC6A1_8080, 0xCC83_8080, 0xE1BB_A180), (0xC6A1_8080, 0xCC89_8080, 0xE1BB_9F80),
+    (0xC6A1_8080, 0xCCA3_8080, 0xE1BB_A380), (0xC6AF_8080, 0xCC80_8080, 0xE1BB_AA80),
+    (0xC6AF_8080, 0xCC81_8080, 0xE1BB_A880), (0xC6AF_8080, 0xCC83_8080, 0xE1BB_AE80),
+    (0xC6AF_8080, 0xCC89_8080, 0xE1BB_AC80), (0xC6AF_8080, 0xCCA3_8080, 0xE1BB_B080),
+    (0xC6B0_8080, 0xCC80_8080, 0xE1BB_AB80), (0xC6B0_8080, 0xCC81_8080, 0xE1BB_A980),
+    (0xC6B0_8080, 0xCC83_8080, 0xE1BB_AF80), (0xC6B0_8080, 0xCC89_8080, 0xE1BB_AD80),
+    (0xC6B0_8080, 0xCCA3_8080, 0xE1BB_B180), (0xC6B7_8080, 0xCC8C_8080, 0xC7AE_8080),
+    (0xC7AA_8080, 0xCC84_8080, 0xC7AC_8080), (0xC7AB_8080, 0xCC84_8080, 0xC7AD_8080),
+    (0xC8A6_8080, 0xCC84_8080, 0xC7A0_8080), (0xC8A7_8080, 0xCC84_8080, 0xC7A1_8080),
+    (0xC8A8_8080, 0xCC86_8080, 0xE1B8_9C80), (0xC8A9_8080, 0xCC86_8080, 0xE1B8_9D80),
+    (0xC8AE_8080, 0xCC84_8080, 0xC8B0_8080), (0xC8AF_8080, 0xCC84_8080, 0xC8B1_8080),
+    (0xCA92_8080, 0xCC8C_8080, 0xC7AF_8080), (0xCE91_8080, 0xCC80_8080, 0xE1BE_BA80),
+    (0xCE91_8080, 0xCC81_8080, 0xCE86_8080), (0xCE91_8080, 0xCC84_8080, 0xE1BE_B980),
+    (0xCE91_8080, 0xCC86_8080, 0xE1BE_B880), (0xCE91_8080, 0xCC93_8080, 0xE1BC_8880),
+    (0xCE91_8080, 0xCC94_8080, 0xE1BC_8980), (0xCE91_8080, 0xCD85_8080, 0xE1BE_BC80),
+    (0xCE95_8080, 0xCC80_8080, 0xE1BF_8880), (0xCE95_8080, 0xCC81_8080, 0xCE88_8080),
+    (0xCE95_8080, 0xCC93_8080, 0xE1BC_9880), (0xCE95_8080, 0xCC94_8080, 0xE1BC_9980),
+    (0xCE97_8080, 0xCC80_8080, 0xE1BF_8A80), (0xCE97_8080, 0xCC81_8080, 0xCE89_8080),
+    (0xCE97_8080, 0xCC93_8080, 0xE1BC_A880), (0xCE97_8080, 0xCC94_8080, 0xE1BC_A980),
+    (0xCE97_8080, 0xCD85_8080, 0xE1BF_8C80), (0xCE99_8080, 0xCC80_8080, 0xE1BF_9A80),
+    (0xCE99_8080, 0xCC81_8080, 0xCE8A_8080), (0xCE99_8080, 0xCC84_8080, 0xE1BF_9980),
+    (0xCE99_8080, 0xCC86_8080, 0xE1BF_9880), (0xCE99_8080, 0xCC88_8080, 0xCEAA_8080),
+    (0xCE99_8080, 0xCC93_8080, 0xE1BC_B880), (0xCE99_8080, 0xCC94_8080, 0xE1BC_B980),
+    (0xCE9F_8080, 0xCC80_8080, 0xE1BF_B880), (0xCE9F_8080, 0xCC81_8080, 0xCE8C_8080),
+    (0xCE9F_8080, 0xCC93_8080, 0xE1BD_8880), (0xCE9F_8080, 0xCC94_8080, 0xE1BD_8980),
+    (0xCEA1_8080, 0xCC94_8080, 0xE1BF_AC80), (0xCEA5_8080, 0xCC80_8080, 0xE1BF_AA80),
+    (0xCEA5_8080, 0xCC81_8080, 0xCE8E_8080), (0xCEA5_8080, 0xCC84_8080, 0xE1BF_A980),
+    (0xCEA5_8080, 0xCC86_8080, 0xE1BF_A880), (0xCEA5_8080, 0xCC88_8080, 0xCEAB_8080),
+    (0xCEA5_8080, 0xCC94_8080, 0xE1BD_9980), (0xCEA9_8080, 0xCC80_8080, 0xE1BF_BA80),
+    (0xCEA9_8080, 0xCC81_8080, 0xCE8F_8080), (0xCEA9_8080, 0xCC93_8080, 0xE1BD_A880),
+    (0xCEA9_8080, 0xCC94_8080, 0xE1BD_A980), (0xCEA9_8080, 0xCD85_8080, 0xE1BF_BC80),
+    (0xCEAC_8080, 0xCD85_8080, 0xE1BE_B480), (0xCEAE_8080, 0xCD85_8080, 0xE1BF_8480),
+    (0xCEB1_8080, 0xCC80_8080, 0xE1BD_B080), (0xCEB1_8080, 0xCC81_8080, 0xCEAC_8080),
+    (0xCEB1_8080, 0xCC84_8080, 0xE1BE_B180), (0xCEB1_8080, 0xCC86_8080, 0xE1BE_B080),
+    (0xCEB1_8080, 0xCC93_8080, 0xE1BC_8080), (0xCEB1_8080, 0xCC94_8080, 0xE1BC_8180),
+    (0xCEB1_8080, 0xCD82_8080, 0xE1BE_B680), (0xCEB1_8080, 0xCD85_8080, 0xE1BE_B380),
+    (0xCEB5_8080, 0xCC80_8080, 0xE1BD_B280), (0xCEB5_8080, 0xCC81_8080, 0xCEAD_8080),
+    (0xCEB5_8080, 0xCC93_8080, 0xE1BC_9080), (0xCEB5_8080, 0xCC94_8080, 0xE1BC_9180),
+    (0xCEB7_8080, 0xCC80_8080, 0xE1BD_B480), (0xCEB7_8080, 0xCC81_8080, 0xCEAE_8080),
+    (0xCEB7_8080, 0xCC93_8080, 0xE1BC_A080), (0xCEB7_8080, 0xCC94_8080, 0xE1BC_A180),
+    (0xCEB7_8080, 0xCD82_8080, 0xE1BF_8680), (0xCEB7_8080, 0xCD85_8080, 0xE1BF_8380),
+    (0xCEB9_8080, 0xCC80_8080, 0xE1BD_B680), (0xCEB9_8080, 0xCC81_8080, 0xCEAF_8080),
+    (0xCEB9_8080, 0xCC84_8080, 0xE1BF_9180), (0xCEB9_8080, 0xCC86_8080, 0xE1BF_9080),
+    (0xCEB9_8080, 0xCC88_8080, 0xCF8A_8080), (0xCEB9_8080, 0xCC93_8080, 0xE1BC_B080),
+    (0xCEB9_8080, 0xCC94_8080, 0xE1BC_B180), (0xCEB9_8080, 0xCD82_8080, 0xE1BF_9680),
+    (0xCEBF_8080, 0xCC80_8080, 0xE1BD_B880), (0xCEBF_8080, 0xCC81_8080, 0xCF8C_8080),
+    (0xCEBF_8080, 0xCC93_8080, 0xE1BD_8080), (0xCEBF_8080, 0xCC94_8080, 0xE1BD_8180),
+    (0xCF81_8080, 0xCC93_8080, 0xE1BF_A480), (0xCF81_8080, 0xCC94_8080, 0xE1BF_A580),
+    (0xCF85_8080, 0xCC80_8080, 0xE1BD_BA80), (0xCF85_8080, 0xCC81_8080, 0xCF8D_8080),
+    (0xCF85_8080, 0xCC84_8080, 0xE1BF_A180), (0xCF85_8080, 0xCC86_8080, 0xE1BF_A080),
+    (0xCF85_8080, 0xCC88_8080, 0xCF8B_8080), (0xCF85_8080, 0xCC93_8080, 0xE1BD_9080),
+    (0xCF85_8080, 0xCC94_8080, 0xE1BD_9180), (0xCF85_8080, 0xCD82_8080, 0xE1BF_A680),
+    (0xCF89_8080, 0xCC80_8080, 0xE1BD_BC80), (0xCF89_8080, 0xCC81_8080, 0xCF8E_8080),
+    (0xCF89_8080, 0xCC93_8080, 0xE1BD_A080), (0xCF89_8080, 0xCC94_8080, 0xE1BD_A180),
+    (0xCF89_8080, 0xCD82_8080, 0xE1BF_B680), (0xCF89_8080, 0xCD85_8080, 0xE1BF_B380),
+    (0xCF8A_8080, 0xCC80_8080, 0xE1BF_9280), (0xCF8A_8080, 0xCC81_8080, 0xCE90_8080),
+    (0xCF8A_8080, 0xCD82_8080, 0xE1BF_9780), (0xCF8B_8080, 0xCC80_8080, 0xE1BF_A280),
+    (0xCF8B_8080, 0xCC81_8080, 0xCEB0_8080), (0xCF8B_8080, 0xCD82_8080, 0xE1BF_A780),
+    (0xCF8E_8080, 0xCD85_8080, 0xE1BF_B480), (0xCF92_8080, 0xCC81_8080, 0xCF93_8080),
+    (0xCF92_8080, 0xCC88_8080, 0xCF94_8080), (0xD086_8080, 0xCC88_8080, 0xD087_8080),
+    (0xD090_8080, 0xCC86_8080, 0xD390_8080), (0xD090_8080, 0xCC88_8080, 0xD392_8080),
+    (0xD093_8080, 0xCC81_8080, 0xD083_8080), (0xD095_8080, 0xCC80_8080, 0xD080_8080),
+    (0xD095_8080, 0xCC86_8080, 0xD396_8080), (0xD095_8080, 0xCC88_8080, 0xD081_8080),
+    (0xD096_8080, 0xCC86_8080, 0xD381_8080), (0xD096_8080, 0xCC88_8080, 0xD39C_8080),
+    (0xD097_8080, 0xCC88_8080, 0xD39E_8080), (0xD098_8080, 0xCC80_8080, 0xD08D_8080),
+    (0xD098_8080, 0xCC84_8080, 0xD3A2_8080), (0xD098_8080, 0xCC86_8080, 0xD099_8080),
+    (0xD098_8080, 0xCC88_8080, 0xD3A4_8080), (0xD09A_8080, 0xCC81_8080, 0xD08C_8080),
+    (0xD09E_8080, 0xCC88_8080, 0xD3A6_8080), (0xD0A3_8080, 0xCC84_8080, 0xD3AE_8080),
+    (0xD0A3_8080, 0xCC86_8080, 0xD08E_8080), (0xD0A3_8080, 0xCC88_8080, 0xD3B0_8080),
+    (0xD0A3_8080, 0xCC8B_8080, 0xD3B2_8080), (0xD0A7_8080, 0xCC88_8080, 0xD3B4_8080),
+    (0xD0AB_8080, 0xCC88_8080, 0xD3B8_8080), (0xD0AD_8080, 0xCC88_8080, 0xD3AC_8080),
+    (0xD0B0_8080, 0xCC86_8080, 0xD391_8080), (0xD0B0_8080, 0xCC88_8080, 0xD393_8080),
+    (0xD0B3_8080, 0xCC81_8080, 0xD193_8080), (0xD0B5_8080, 0xCC80_8080, 0xD190_8080),
+    (0xD0B5_8080, 0xCC86_8080, 0xD397_8080), (0xD0B5_8080, 0xCC88_8080, 0xD191_8080),
+    (0xD0B6_8080, 0xCC86_8080, 0xD382_8080), (0xD0B6_8080, 0xCC88_8080, 0xD39D_8080),
+    (0xD0B7_8080, 0xCC88_8080, 0xD39F_8080), (0xD0B8_8080, 0xCC80_8080, 0xD19D_8080),
+    (0xD0B8_8080, 0xCC84_8080, 0xD3A3_8080), (0xD0B8_8080, 0xCC86_8080, 0xD0B9_8080),
+    (0xD0B8_8080, 0xCC88_8080, 0xD3A5_8080), (0xD0BA_8080, 0xCC81_8080, 0xD19C_8080),
+    (0xD0BE_8080, 0xCC88_8080, 0xD3A7_8080), (0xD183_8080, 0xCC84_8080, 0xD3AF_8080),
+    (0xD183_8080, 0xCC86_8080, 0xD19E_8080), (0xD183_8080, 0xCC88_8080, 0xD3B1_8080),
+    (0xD183_8080, 0xCC8B_8080, 0xD3B3_8080), (0xD187_8080, 0xCC88_8080, 0xD3B5_8080),
+    (0xD18B_8080, 0xCC88_8080, 0xD3B9_8080), (0xD18D_8080, 0xCC88_8080, 0xD3AD_8080),
+    (0xD196_8080, 0xCC88_8080, 0xD197_8080), (0xD1B4_8080, 0xCC8F_8080, 0xD1B6_8080),
+    (0xD1B5_8080, 0xCC8F_8080, 0xD1B7_8080), (0xD398_8080, 0xCC88_8080, 0xD39A_8080),
+    (0xD399_8080, 0xCC88_8080, 0xD39B_8080), (0xD3A8_8080, 0xCC88_8080, 0xD3AA_8080),
+    (0xD3A9_8080, 0xCC88_8080, 0xD3AB_8080), (0xD8A7_8080, 0xD993_8080, 0xD8A2_8080),
+    (0xD8A7_8080, 0xD994_8080, 0xD8A3_8080), (0xD8A7_8080, 0xD995_8080, 0xD8A5_8080),
+    (0xD988_8080, 0xD994_8080, 0xD8A4_8080), (0xD98A_8080, 0xD994_8080, 0xD8A6_8080),
+    (0xDB81_8080, 0xD994_8080, 0xDB82_8080), (0xDB92_8080, 0xD994_8080, 0xDB93_8080),
+    (0xDB95_8080, 0xD994_8080, 0xDB80_8080), (0xE0A4_A880, 0xE0A4_BC80, 0xE0A4_A980),
+    (0xE0A4_B080, 0xE0A4_BC80, 0xE0A4_B180), (0xE0A4_B380, 0xE0A4_BC80, 0xE0A4_B480),
+    (0xE0A7_8780, 0xE0A6_BE80, 0xE0A7_8B80), (0xE0A7_8780, 0xE0A7_9780, 0xE0A7_8C80),
+    (0xE0AD_8780, 0xE0AC_BE80, 0xE0AD_8B80), (0xE0AD_8780, 0xE0AD_9680, 0xE0AD_8880),
+    (0xE0AD_8780, 0xE0AD_9780, 0xE0AD_8C80), (0xE0AE_9280, 0xE0AF_9780, 0xE0AE_9480),
+    (0xE0AF_8680, 0xE0AE_BE80, 0xE0AF_8A80), (0xE0AF_8680, 0xE0AF_9780, 0xE0AF_8C80),
+    (0xE0AF_8780, 0xE0AE_BE80, 0xE0AF_8B80), (0xE0B1_8680, 0xE0B1_9680, 0xE0B1_8880),
+    (0xE0B2_BF80, 0xE0B3_9580, 0xE0B3_8080), (0xE0B3_8680, 0xE0B3_8280, 0xE0B3_8A80),
+    (0xE0B3_8680, 0xE0B3_9580, 0xE0B3_8780), (0xE0B3_8680, 0xE0B3_9680, 0xE0B3_8880),
+    (0xE0B3_8A80, 0xE0B3_9580, 0xE0B3_8B80), (0xE0B5_8680, 0xE0B4_BE80, 0xE0B5_8A80),
+    (0xE0B5_8680, 0xE0B5_9780, 0xE0B5_8C80), (0xE0B5_8780, 0xE0B4_BE80, 0xE0B5_8B80),
+    (0xE0B7_9980, 0xE0B7_8A80, 0xE0B7_9A80), (0xE0B7_9980, 0xE0B7_8F80, 0xE0B7_9C80),
+    (0xE0B7_9980, 0xE0B7_9F80, 0xE0B7_9E80), (0xE0B7_9C80, 0xE0B7_8A80, 0xE0B7_9D80),
+    (0xE180_A580, 0xE180_AE80, 0xE180_A680), (0xE1AC_8580, 0xE1AC_B580, 0xE1AC_8680),
+    (0xE1AC_8780, 0xE1AC_B580, 0xE1AC_8880), (0xE1AC_8980, 0xE1AC_B580, 0xE1AC_8A80),
+    (0xE1AC_8B80, 0xE1AC_B580, 0xE1AC_8C80), (0xE1AC_8D80, 0xE1AC_B580, 0xE1AC_8E80),
+    (0xE1AC_9180, 0xE1AC_B580, 0xE1AC_9280), (0xE1AC_BA80, 0xE1AC_B580, 0xE1AC_BB80),
+    (0xE1AC_BC80, 0xE1AC_B580, 0xE1AC_BD80), (0xE1AC_BE80, 0xE1AC_B580, 0xE1AD_8080),
+    (0xE1AC_BF80, 0xE1AC_B580, 0xE1AD_8180), (0xE1AD_8280, 0xE1AC_B580, 0xE1AD_8380),
+    (0xE1B8_B680, 0xCC84_8080, 0xE1B8_B880), (0xE1B8_B780, 0xCC84_8080, 0xE1B8_B980),
+    (0xE1B9_9A80, 0xCC84_8080, 0xE1B9_9C80), (0xE1B9_9B80, 0xCC84_8080, 0xE1B9_9D80),
+    (0xE1B9_A280, 0xCC87_8080, 0xE1B9_A880), (0xE1B9_A380, 0xCC87_8080, 0xE1B9_A980),
+    (0xE1BA_A080, 0xCC82_8080, 0xE1BA_AC80), (0xE1BA_A080, 0xCC86_8080, 0xE1BA_B680),
+    (0xE1BA_A180, 0xCC82_8080, 0xE1BA_AD80), (0xE1BA_A180, 0xCC86_8080, 0xE1BA_B780),
+    (0xE1BA_B880, 0xCC82_8080, 0xE1BB_8680), (0xE1BA_B980, 0xCC82_8080, 0xE1BB_8780),
+    (0xE1BB_8C80, 0xCC82_8080, 0xE1BB_9880), (0xE1BB_8D80, 0xCC82_8080, 0xE1BB_9980),
+    (0xE1BC_8080, 0xCC80_8080, 0xE1BC_8280), (0xE1BC_8080, 0xCC81_8080, 0xE1BC_8480),
+    (0xE1BC_8080, 0xCD82_8080, 0xE1BC_8680), (0xE1BC_8080, 0xCD85_8080, 0xE1BE_8080),
+    (0xE1BC_8180, 0xCC80_8080, 0xE1BC_8380), (0xE1BC_8180, 0xCC81_8080, 0xE1BC_8580),
+    (0xE1BC_8180, 0xCD82_8080, 0xE1BC_8780), (0xE1BC_8180, 0xCD85_8080, 0xE1BE_8180),
+    (0xE1BC_8280, 0xCD85_8080, 0xE1BE_8280), (0xE1BC_8380, 0xCD85_8080, 0xE1BE_8380),
+    (0xE1BC_8480, 0xCD85_8080, 0xE1BE_8480), (0xE1BC_8580, 0xCD85_8080, 0xE1BE_8580),
+    (0xE1BC_8680, 0xCD85_8080, 0xE1BE_8680), (0xE1BC_8780, 0xCD85_8080, 0xE1BE_8780),
+    (0xE1BC_8880, 0xCC80_8080, 0xE1BC_8A80), (0xE1BC_8880, 0xCC81_8080, 0xE1BC_8C80),
+    (0xE1BC_8880, 0xCD82_8080, 0xE1BC_8E80), (0xE1BC_8880, 0xCD85_8080, 0xE1BE_8880),
+    (0xE1BC_8980, 0xCC80_8080, 0xE1BC_8B80), (0xE1BC_8980, 0xCC81_8080, 0xE1BC_8D80),
+    (0xE1BC_8980, 0xCD82_8080, 0xE1BC_8F80), (0xE1BC_8980, 0xCD85_8080, 0xE1BE_8980),
+    (0xE1BC_8A80, 0xCD85_8080, 0xE1BE_8A80), (0xE1BC_8B80, 0xCD85_8080, 0xE1BE_8B80),
+    (0xE1BC_8C80, 0xCD85_8080, 0xE1BE_8C80), (0xE1BC_8D80, 0xCD85_8080, 0xE1BE_8D80),
+    (0xE1BC_8E80, 0xCD85_8080, 0xE1BE_8E80), (0xE1BC_8F80, 0xCD85_8080, 0xE1BE_8F80),
+    (0xE1BC_9080, 0xCC80_8080, 0xE1BC_9280), (0xE1BC_9080, 0xCC81_8080, 0xE1BC_9480),
+    (0xE1BC_9180, 0xCC80_8080, 0xE1BC_9380), (0xE1BC_9180, 0xCC81_8080, 0xE1BC_9580),
+    (0xE1BC_9880, 0xCC80_8080, 0xE1BC_9A80), (0xE1BC_9880, 0xCC81_8080, 0xE1BC_9C80),
+    (0xE1BC_9980, 0xCC80_8080, 0xE1BC_9B80), (0xE1BC_9980, 0xCC81_8080, 0xE1BC_9D80),
+    (0xE1BC_A080, 0xCC80_8080, 0xE1BC_A280), (0xE1BC_A080, 0xCC81_8080, 0xE1BC_A480),
+    (0xE1BC_A080, 0xCD82_8080, 0xE1BC_A680), (0xE1BC_A080, 0xCD85_8080, 0xE1BE_9080),
+    (0xE1BC_A180, 0xCC80_8080, 0xE1BC_A380), (0xE1BC_A180, 0xCC81_8080, 0xE1BC_A580),
+    (0xE1BC_A180, 0xCD82_8080, 0xE1BC_A780), (0xE1BC_A180, 0xCD85_8080, 0xE1BE_9180),
+    (0xE1BC_A280, 0xCD85_8080, 0xE1BE_9280), (0xE1BC_A380, 0xCD85_8080, 0xE1BE_9380),
+    (0xE1BC_A480, 0xCD85_8080, 0xE1BE_9480), (0xE1BC_A580, 0xCD85_8080, 0xE1BE_9580),
+    (0xE1BC_A680, 0xCD85_8080, 0xE1BE_9680), (0xE1BC_A780, 0xCD85_8080, 0xE1BE_9780),
+    (0xE1BC_A880, 0xCC80_8080, 0xE1BC_AA80), (0xE1BC_A880, 0xCC81_8080, 0xE1BC_AC80),
+    (0xE1BC_A880, 0xCD82_8080, 0xE1BC_AE80), (0xE1BC_A880, 0xCD85_8080, 0xE1BE_9880),
+    (0xE1BC_A980, 0xCC80_8080, 0xE1BC_AB80), (0xE1BC_A980, 0xCC81_8080, 0xE1BC_AD80),
+    (0xE1BC_A980, 0xCD82_8080, 0xE1BC_AF80), (0xE1BC_A980, 0xCD85_8080, 0xE1BE_9980),
+    (0xE1BC_AA80, 0xCD85_8080, 0xE1BE_9A80), (0xE1BC_AB80, 0xCD85_8080, 0xE1BE_9B80),
+    (0xE1BC_AC80, 0xCD85_8080, 0xE1BE_9C80), (0xE1BC_AD80, 0xCD85_8080, 0xE1BE_9D80),
+    (0xE1BC_AE80, 0xCD85_8080, 0xE1BE_9E80), (0xE1BC_AF80, 0xCD85_8080, 0xE1BE_9F80),
+    (0xE1BC_B080, 0xCC80_8080, 0xE1BC_B280), (0xE1BC_B080, 0xCC81_8080, 0xE1BC_B480),
+    (0xE1BC_B080, 0xCD82_8080, 0xE1BC_B680), (0xE1BC_B180, 0xCC80_8080, 0xE1BC_B380),
+    (0xE1BC_B180, 0xCC81_8080, 0xE1BC_B580), (0xE1BC_B180, 0xCD82_8080, 0xE1BC_B780),
+    (0xE1BC_B880, 0xCC80_8080, 0xE1BC_BA80), (0xE1BC_B880, 0xCC81_8080, 0xE1BC_BC80),
+    (0xE1BC_B880, 0xCD82_8080, 0xE1BC_BE80), (0xE1BC_B980, 0xCC80_8080, 0xE1BC_BB80),
+    (0xE1BC_B980, 0xCC81_8080, 0xE1BC_BD80), (0xE1BC_B980, 0xCD82_8080, 0xE1BC_BF80),
+    (0xE1BD_8080, 0xCC80_8080, 0xE1BD_8280), (0xE1BD_8080, 0xCC81_8080, 0xE1BD_8480),
+    (0xE1BD_8180, 0xCC80_8080, 0xE1BD_8380), (0xE1BD_8180, 0xCC81_8080, 0xE1BD_8580),
+    (0xE1BD_8880, 0xCC80_8080, 0xE1BD_8A80), (0xE1BD_8880, 0xCC81_8080, 0xE1BD_8C80),
+    (0xE1BD_8980, 0xCC80_8080, 0xE1BD_8B80), (0xE1BD_8980, 0xCC81_8080, 0xE1BD_8D80),
+    (0xE1BD_9080, 0xCC80_8080, 0xE1BD_9280), (0xE1BD_9080, 0xCC81_8080, 0xE1BD_9480),
+    (0xE1BD_9080, 0xCD82_8080, 0xE1BD_9680), (0xE1BD_9180, 0xCC80_8080, 0xE1BD_9380),
+    (0xE1BD_9180, 0xCC81_8080, 0xE1BD_9580), (0xE1BD_9180, 0xCD82_8080, 0xE1BD_9780),
+    (0xE1BD_9980, 0xCC80_8080, 0xE1BD_9B80), (0xE1BD_9980, 0xCC81_8080, 0xE1BD_9D80),
+    (0xE1BD_9980, 0xCD82_8080, 0xE1BD_9F80), (0xE1BD_A080, 0xCC80_8080, 0xE1BD_A280),
+    (0xE1BD_A080, 0xCC81_8080, 0xE1BD_A480), (0xE1BD_A080, 0xCD82_8080, 0xE1BD_A680),
+    (0xE1BD_A080, 0xCD85_8080, 0xE1BE_A080), (0xE1BD_A180, 0xCC80_8080, 0xE1BD_A380),
+    (0xE1BD_A180, 0xCC81_8080, 0xE1BD_A580), (0xE1BD_A180, 0xCD82_8080, 0xE1BD_A780),
+    (0xE1BD_A180, 0xCD85_8080, 0xE1BE_A180), (0xE1BD_A280, 0xCD85_8080, 0xE1BE_A280),
+    (0xE1BD_A380, 0xCD85_8080, 0xE1BE_A380), (0xE1BD_A480, 0xCD85_8080, 0xE1BE_A480),
+    (0xE1BD_A580, 0xCD85_8080, 0xE1BE_A580), (0xE1BD_A680, 0xCD85_8080, 0xE1BE_A680),
+    (0xE1BD_A780, 0xCD85_8080, 0xE1BE_A780), (0xE1BD_A880, 0xCC80_8080, 0xE1BD_AA80),
+    (0xE1BD_A880, 0xCC81_8080, 0xE1BD_AC80), (0xE1BD_A880, 0xCD82_8080, 0xE1BD_AE80),
+    (0xE1BD_A880, 0xCD85_8080, 0xE1BE_A880), (0xE1BD_A980, 0xCC80_8080, 0xE1BD_AB80),
+    (0xE1BD_A980, 0xCC81_8080, 0xE1BD_AD80), (0xE1BD_A980, 0xCD82_8080, 0xE1BD_AF80),
+    (0xE1BD_A980, 0xCD85_8080, 0xE1BE_A980), (0xE1BD_AA80, 0xCD85_8080, 0xE1BE_AA80),
+    (0xE1BD_AB80, 0xCD85_8080, 0xE1BE_AB80), (0xE1BD_AC80, 0xCD85_8080, 0xE1BE_AC80),
+    (0xE1BD_AD80, 0xCD85_8080, 0xE1BE_AD80), (0xE1BD_AE80, 0xCD85_8080, 0xE1BE_AE80),
+    (0xE1BD_AF80, 0xCD85_8080, 0xE1BE_AF80), (0xE1BD_B080, 0xCD85_8080, 0xE1BE_B280),
+    (0xE1BD_B480, 0xCD85_8080, 0xE1BF_8280), (0xE1BD_BC80, 0xCD85_8080, 0xE1BF_B280),
+    (0xE1BE_B680, 0xCD85_8080, 0xE1BE_B780), (0xE1BE_BF80, 0xCC80_8080, 0xE1BF_8D80),
+    (0xE1BE_BF80, 0xCC81_8080, 0xE1BF_8E80), (0xE1BE_BF80, 0xCD82_8080, 0xE1BF_8F80),
+    (0xE1BF_8680, 0xCD85_8080, 0xE1BF_8780), (0xE1BF_B680, 0xCD85_8080, 0xE1BF_B780),
+    (0xE1BF_BE80, 0xCC80_8080, 0xE1BF_9D80), (0xE1BF_BE80, 0xCC81_8080, 0xE1BF_9E80),
+    (0xE1BF_BE80, 0xCD82_8080, 0xE1BF_9F80), (0xE286_9080, 0xCCB8_8080, 0xE286_9A80),
+    (0xE286_9280, 0xCCB8_8080, 0xE286_9B80), (0xE286_9480, 0xCCB8_8080, 0xE286_AE80),
+    (0xE287_9080, 0xCCB8_8080, 0xE287_8D80), (0xE287_9280, 0xCCB8_8080, 0xE287_8F80),
+    (0xE287_9480, 0xCCB8_8080, 0xE287_8E80), (0xE288_8380, 0xCCB8_8080, 0xE288_8480),
+    (0xE288_8880, 0xCCB8_8080, 0xE288_8980), (0xE288_8B80, 0xCCB8_8080, 0xE288_8C80),
+    (0xE288_A380, 0xCCB8_8080, 0xE288_A480), (0xE288_A580, 0xCCB8_8080, 0xE288_A680),
+    (0xE288_BC80, 0xCCB8_8080, 0xE289_8180), (0xE289_8380, 0xCCB8_8080, 0xE289_8480),
+    (0xE289_8580, 0xCCB8_8080, 0xE289_8780), (0xE289_8880, 0xCCB8_8080, 0xE289_8980),
+    (0xE289_8D80, 0xCCB8_8080, 0xE289_AD80), (0xE289_A180, 0xCCB8_8080, 0xE289_A280),
+    (0xE289_A480, 0xCCB8_8080, 0xE289_B080), (0xE289_A580, 0xCCB8_8080, 0xE289_B180),
+    (0xE289_B280, 0xCCB8_8080, 0xE289_B480), (0xE289_B380, 0xCCB8_8080, 0xE289_B580),
+    (0xE289_B680, 0xCCB8_8080, 0xE289_B880), (0xE289_B780, 0xCCB8_8080, 0xE289_B980),
+    (0xE289_BA80, 0xCCB8_8080, 0xE28A_8080), (0xE289_BB80, 0xCCB8_8080, 0xE28A_8180),
+    (0xE289_BC80, 0xCCB8_8080, 0xE28B_A080), (0xE289_BD80, 0xCCB8_8080, 0xE28B_A180),
+    (0xE28A_8280, 0xCCB8_8080, 0xE28A_8480), (0xE28A_8380, 0xCCB8_8080, 0xE28A_8580),
+    (0xE28A_8680, 0xCCB8_8080, 0xE28A_8880), (0xE28A_8780, 0xCCB8_8080, 0xE28A_8980),
+    (0xE28A_9180, 0xCCB8_8080, 0xE28B_A280), (0xE28A_9280, 0xCCB8_8080, 0xE28B_A380),
+    (0xE28A_A280, 0xCCB8_8080, 0xE28A_AC80), (0xE28A_A880, 0xCCB8_8080, 0xE28A_AD80),
+    (0xE28A_A980, 0xCCB8_8080, 0xE28A_AE80), (0xE28A_AB80, 0xCCB8_8080, 0xE28A_AF80),
+    (0xE28A_B280, 0xCCB8_8080, 0xE28B_AA80), (0xE28A_B380, 0xCCB8_8080, 0xE28B_AB80),
+    (0xE28A_B480, 0xCCB8_8080, 0xE28B_AC80), (0xE28A_B580, 0xCCB8_8080, 0xE28B_AD80),
+    (0xE381_8680, 0xE382_9980, 0xE382_9480), (0xE381_8B80, 0xE382_9980, 0xE381_8C80),
+    (0xE381_8D80, 0xE382_9980, 0xE381_8E80), (0xE381_8F80, 0xE382_9980, 0xE381_9080),
+    (0xE381_9180, 0xE382_9980, 0xE381_9280), (0xE381_9380, 0xE382_9980, 0xE381_9480),
+    (0xE381_9580, 0xE382_9980, 0xE381_9680), (0xE381_9780, 0xE382_9980, 0xE381_9880),
+    (0xE381_9980, 0xE382_9980, 0xE381_9A80), (0xE381_9B80, 0xE382_9980, 0xE381_9C80),
+    (0xE381_9D80, 0xE382_9980, 0xE381_9E80), (0xE381_9F80, 0xE382_9980, 0xE381_A080),
+    (0xE381_A180, 0xE382_9980, 0xE381_A280), (0xE381_A480, 0xE382_9980, 0xE381_A580),
+    (0xE381_A680, 0xE382_9980, 0xE381_A780), (0xE381_A880, 0xE382_9980, 0xE381_A980),
+    (0xE381_AF80, 0xE382_9980, 0xE381_B080), (0xE381_AF80, 0xE382_9A80, 0xE381_B180),
+    (0xE381_B280, 0xE382_9980, 0xE381_B380), (0xE381_B280, 0xE382_9A80, 0xE381_B480),
+    (0xE381_B580, 0xE382_9980, 0xE381_B680), (0xE381_B580, 0xE382_9A80, 0xE381_B780),
+    (0xE381_B880, 0xE382_9980, 0xE381_B980), (0xE381_B880, 0xE382_9A80, 0xE381_BA80),
+    (0xE381_BB80, 0xE382_9980, 0xE381_BC80), (0xE381_BB80, 0xE382_9A80, 0xE381_BD80),
+    (0xE382_9D80, 0xE382_9980, 0xE382_9E80), (0xE382_A680, 0xE382_9980, 0xE383_B480),
+    (0xE382_AB80, 0xE382_9980, 0xE382_AC80), (0xE382_AD80, 0xE382_9980, 0xE382_AE80),
+    (0xE382_AF80, 0xE382_9980, 0xE382_B080), (0xE382_B180, 0xE382_9980, 0xE382_B280),
+    (0xE382_B380, 0xE382_9980, 0xE382_B480), (0xE382_B580, 0xE382_9980, 0xE382_B680),
+    (0xE382_B780, 0xE382_9980, 0xE382_B880), (0xE382_B980, 0xE382_9980, 0xE382_BA80),
+    (0xE382_BB80, 0xE382_9980, 0xE382_BC80), (0xE382_BD80, 0xE382_9980, 0xE382_BE80),
+    (0xE382_BF80, 0xE382_9980, 0xE383_8080), (0xE383_8180, 0xE382_9980, 0xE383_8280),
+    (0xE383_8480, 0xE382_9980, 0xE383_8580), (0xE383_8680, 0xE382_9980, 0xE383_8780),
+    (0xE383_8880, 0xE382_9980, 0xE383_8980), (0xE383_8F80, 0xE382_9980, 0xE383_9080),
+    (0xE383_8F80, 0xE382_9A80, 0xE383_9180), (0xE383_9280, 0xE382_9980, 0xE383_9380),
+    (0xE383_9280, 0xE382_9A80, 0xE383_9480), (0xE383_9580, 0xE382_9980, 0xE383_9680),
+    (0xE383_9580, 0xE382_9A80, 0xE383_9780), (0xE383_9880, 0xE382_9980, 0xE383_9980),
+    (0xE383_9880, 0xE382_9A80, 0xE383_9A80), (0xE383_9B80, 0xE382_9980, 0xE383_9C80),
+    (0xE383_9B80, 0xE382_9A80, 0xE383_9D80), (0xE383_AF80, 0xE382_9980, 0xE383_B780),
+    (0xE383_B080, 0xE382_9980, 0xE383_B880), (0xE383_B180, 0xE382_9980, 0xE383_B980),
+    (0xE383_B280, 0xE382_9980, 0xE383_BA80), (0xE383_BD80, 0xE382_9980, 0xE383_BE80),
+    (0xF090_9792, 0xCC87_8080, 0xF090_9789), (0xF090_979A, 0xCC87_8080, 0xF090_97A4),
+    (0xF091_8299, 0xF091_82BA, 0xF091_829A), (0xF091_829B, 0xF091_82BA, 0xF091_829C),
+    (0xF091_82A5, 0xF091_82BA, 0xF091_82AB), (0xF091_84B1, 0xF091_84A7, 0xF091_84AE),
+    (0xF091_84B2, 0xF091_84A7, 0xF091_84AF), (0xF091_8D87, 0xF091_8CBE, 0xF091_8D8B),
+    (0xF091_8D87, 0xF091_8D97, 0xF091_8D8C), (0xF091_8E82, 0xF091_8F89, 0xF091_8E83),
+    (0xF091_8E84, 0xF091_8EBB, 0xF091_8E85), (0xF091_8E8B, 0xF091_8F82, 0xF091_8E8E),
+    (0xF091_8E90, 0xF091_8F89, 0xF091_8E91), (0xF091_8F82, 0xF091_8EB8, 0xF091_8F87),
+    (0xF091_8F82, 0xF091_8F82, 0xF091_8F85), (0xF091_8F82, 0xF091_8F89, 0xF091_8F88),
+    (0xF091_92B9, 0xF091_92B0, 0xF091_92BC), (0xF091_92B9, 0xF091_92BA, 0xF091_92BB),
+    (0xF091_92B9, 0xF091_92BD, 0xF091_92BE), (0xF091_96B8, 0xF091_96AF, 0xF091_96BA),
+    (0xF091_96B9, 0xF091_96AF, 0xF091_96BB), (0xF091_A4B5, 0xF091_A4B0, 0xF091_A4B8),
+    (0xF096_849E, 0xF096_849E, 0xF096_84A1), (0xF096_849E, 0xF096_849F, 0xF096_84A3),
+    (0xF096_849E, 0xF096_84A0, 0xF096_84A5), (0xF096_849E, 0xF096_84A9, 0xF096_84A2),
+    (0xF096_84A1, 0xF096_849F, 0xF096_84A6), (0xF096_84A1, 0xF096_84A0, 0xF096_84A8),
+    (0xF096_84A2, 0xF096_849F, 0xF096_84A7), (0xF096_84A9, 0xF096_849F, 0xF096_84A4),
+    (0xF096_B5A3, 0xF096_B5A7, 0xF096_B5A9), (0xF096_B5A7, 0xF096_B5A7, 0xF096_B5A8),
+    (0xF096_B5A9, 0xF096_B5A7, 0xF096_B5AA),
+];
+
+/// `NFKC_Casefold` mappings, indexed by `NFKC_CASEFOLD`. Index 0 is unused, it marks codepoints
+/// that map to themselves
+pub(crate) static NFKC_CASEFOLDS: [&str; 3974] = [
+    "", "\u{61}", "\u{62}", "\u{63}", "\u{64}", "\u{65}", "\u{66}", "\u{67}", "\u{68}", "\u{69}",
+    "\u{6A}", "\u{6B}", "\u{6C}", "\u{6D}", "\u{6E}", "\u{6F}", "\u{70}", "\u{71}", "\u{72}",
+    "\u{73}", "\u{74}", "\u{75}", "\u{76}", "\u{77}", "\u{78}", "\u{79}", "\u{7A}", "\u{20}",
+    "\u{20}\u{308}", "", "\u{20}\u{304}", "\u{32}", "\u{33}", "\u{20}\u{301}", "\u{3BC}",
+    "\u{20}\u{327}", "\u{31}", "\u{31}\u{2044}\u{34}", "\u{31}\u{2044}\u{32}",
+    "\u{33}\u{2044}\u{34}", "\u{E0}", "\u{E1}", "\u{E2}", "\u{E3}", "\u{E4}", "\u{E5}", "\u{E6}",
+    "\u{E7}", "\u{E8}", "\u{E9}", "\u{EA}", "\u{EB}", "\u{EC}", "\u{ED}", "\u{EE}", "\u{EF}",
+    "\u{F0}", "\u{F1}", "\u{F2}", "\u{F3}", "\u{F4}", "\u{F5}", "\u{F6}", "\u{F8}", "\u{F9}",
+    "\u{FA}", "\u{FB}", "\u{FC}", "\u{FD}", "\u{FE}", "\u{73}\u{73}", "\u{101}", "\u{103}",
+    "\u{105}", "\u{107}", "\u{109}", "\u{10B}", "\u{10D}", "\u{10F}", "\u{111}", "\u{113}",
+    "\u{115}", "\u{117}", "\u{119}", "\u{11B}", "\u{11D}", "\u{11F}", "\u{121}", "\u{123}",
+    "\u{125}", "\u{127}", "\u{129}", "\u{12B}", "\u{12D}", "\u{12F}", "\u{69}\u{307}",
+    "\u{69}\u{6A}", "\u{135}", "\u{137}", "\u{13A}", "\u{13C}", "\u{13E}", "\u{6C}\u{B7}",
+    "\u{142}", "\u{144}", "\u{146}", "\u{148}", "\u{2BC}\u{6E}", "\u{14B}", "\u{14D}", "\u{14F}",
+    "\u{151}", "\u{153}", "\u{155}", "\u{157}", "\u{159}", "\u{15B}", "\u{15D}", "\u{15F}",
+    "\u{161}", "\u{163}", "\u{165}", "\u{167}", "\u{169}", "\u{16B}", "\u{16D}", "\u{16F}",
+    "\u{171}", "\u{173}", "\u{175}", "\u{177}", "\u{FF}", "\u{17A}", "\u{17C}", "\u{17E}",
+    "\u{253}", "\u{183}", "\u{185}", "\u{254}", "\u{188}", "\u{256}", "\u{257}", "\u{18C}",
+    "\u{1DD}", "\u{259}", "\u{25B}", "\u{192}", "\u{260}", "\u{263}", "\u{269}", "\u{268}",
+    "\u{199}", "\u{26F}", "\u{272}", "\u{275}", "\u{1A1}", "\u{1A3}", "\u{1A5}", "\u{280}",
+    "\u{1A8}", "\u{283}", "\u{1AD}", "\u{288}", "\u{1B0}", "\u{28A}", "\u{28B}", "\u{1B4}",
+    "\u{1B6}", "\u{292}", "\u{1B9}", "\u{1BD}", "\u{64}\u{17E}", "\u{6C}\u{6A}", "\u{6E}\u{6A}",
+    "\u{1CE}", "\u{1D0}", "\u{1D2}", "\u{1D4}", "\u{1D6}", "\u{1D8}", "\u{1DA}", "\u{1DC}",
+    "\u{1DF}", "\u{1E1}", "\u{1E3}", "\u{1E5}", "\u{1E7}", "\u{1E9}", "\u{1EB}", "\u{1ED}",
+    "\u{1EF}", "\u{64}\u{7A}", "\u{1F5}", "\u{195}", "\u{1BF}", "\u{1F9}", "\u{1FB}", "\u{1FD}",
+    "\u{1FF}", "\u{201}", "\u{203}", "\u{205}", "\u{207}", "\u{209}", "\u{20B}", "\u{20D}",
+    "\u{20F}", "\u{211}", "\u{213}", "\u{215}", "\u{217}", "\u{219}", "\u{21B}", "\u{21D}",
+    "\u{21F}", "\u{19E}", "\u{223}", "\u{225}", "\u{227}", "\u{229}", "\u{22B}", "\u{22D}",
+    "\u{22F}", "\u{231}", "\u{233}", "\u{2C65}", "\u{23C}", "\u{19A}", "\u{2C66}", "\u{242}",
+    "\u{180}", "\u{289}", "\u{28C}", "\u{247}", "\u{249}", "\u{24B}", "\u{24D}", "\u{24F}",
+    "\u{266}", "\u{279}", "\u{27B}", "\u{281}", "\u{20}\u{306}", "\u{20}\u{307}", "\u{20}\u{30A}",
+    "\u{20}\u{328}", "\u{20}\u{303}", "\u{20}\u{30B}", "\u{295}", "\u{300}", "\u{301}", "\u{313}",
+    "\u{308}\u{301}", "\u{3B9}", "\u{371}", "\u{373}", "\u{2B9}", "\u{377}", "\u{20}\u{3B9}",
+    "\u{3B}", "\u{3F3}", "\u{20}\u{308}\u{301}", "\u{3AC}", "\u{B7}", "\u{3AD}", "\u{3AE}",
+    "\u{3AF}", "\u{3CC}", "\u{3CD}", "\u{3CE}", "\u{3B1}", "\u{3B2}", "\u{3B3}", "\u{3B4}",
+    "\u{3B5}", "\u{3B6}", "\u{3B7}", "\u{3B8}", "\u{3BA}", "\u{3BB}", "\u{3BD}", "\u{3BE}",
+    "\u{3BF}", "\u{3C0}", "\u{3C1}", "\u{3C3}", "\u{3C4}", "\u{3C5}", "\u{3C6}", "\u{3C7}",
+    "\u{3C8}", "\u{3C9}", "\u{3CA}", "\u{3CB}", "\u{3D7}", "\u{3D9}", "\u{3DB}", "\u{3DD}",
+    "\u{3DF}", "\u{3E1}", "\u{3E3}", "\u{3E5}", "\u{3E7}", "\u{3E9}", "\u{3EB}", "\u{3ED}",
+    "\u{3EF}", "\u{3F8}", "\u{3FB}", "\u{37B}", "\u{37C}", "\u{37D}", "\u{450}", "\u{451}",
+    "\u{452}", "\u{453}", "\u{454}", "\u{455}", "\u{456}", "\u{457}", "\u{458}", "\u{459}",
+    "\u{45A}", "\u{45B}", "\u{45C}", "\u{45D}", "\u{45E}", "\u{45F}", "\u{430}", "\u{431}",
+    "\u{432}", "\u{433}", "\u{434}", "\u{435}", "\u{436}", "\u{437}", "\u{438}", "\u{439}",
+    "\u{43A}", "\u{43B}", "\u{43C}", "\u{43D}", "\u{43E}", "\u{43F}", "\u{440}", "\u{441}",
+    "\u{442}", "\u{443}", "\u{444}", "\u{445}", "\u{446}", "\u{447}", "\u{448}", "\u{449}",
+    "\u{44A}", "\u{44B}", "\u{44C}", "\u{44D}", "\u{44E}", "\u{44F}", "\u{461}", "\u{463}",
+    "\u{465}", "\u{467}", "\u{469}", "\u{46B}", "\u{46D}", "\u{46F}", "\u{471}", "\u{473}",
+    "\u{475}", "\u{477}", "\u{479}", "\u{47B}", "\u{47D}", "\u{47F}", "\u{481}", "\u{48B}",
+    "\u{48D}", "\u{48F}", "\u{491}", "\u{493}", "\u{495}", "\u{497}", "\u{499}", "\u{49B}",
+    "\u{49D}", "\u{49F}", "\u{4A1}", "\u{4A3}", "\u{4A5}", "\u{4A7}", "\u{4A9}", "\u{4AB}",
+    "\u{4AD}", "\u{4AF}", "\u{4B1}", "\u{4B3}", "\u{4B5}", "\u{4B7}", "\u{4B9}", "\u{4BB}",
+    "\u{4BD}", "\u{4BF}", "\u{4CF}", "\u{4C2}", "\u{4C4}", "\u{4C6}", "\u{4C8}", "\u{4CA}",
+    "\u{4CC}", "\u{4CE}", "\u{4D1}", "\u{4D3}", "\u{4D5}", "\u{4D7}", "\u{4D9}", "\u{4DB}",
+    "\u{4DD}", "\u{4DF}", "\u{4E1}", "\u{4E3}", "\u{4E5}", "\u{4E7}", "\u{4E9}", "\u{4EB}",
+    "\u{4ED}", "\u{4EF}", "\u{4F1}", "\u{4F3}", "\u{4F5}", "\u{4F7}", "\u{4F9}", "\u{4FB}",
+    "\u{4FD}", "\u{4FF}", "\u{501}", "\u{503}", "\u{505}", "\u{507}", "\u{509}", "\u{50B}",
+    "\u{50D}", "\u{50F}", "\u{511}", "\u{513}", "\u{515}", "\u{517}", "\u{519}", "\u{51B}",
+    "\u{51D}", "\u{51F}", "\u{521}", "\u{523}", "\u{525}", "\u{527}", "\u{529}", "\u{52B}",
+    "\u{52D}", "\u{52F}", "\u{561}", "\u{562}", "\u{563}", "\u{564}", "\u{565}", "\u{566}",
+    "\u{567}", "\u{568}", "\u{569}", "\u{56A}", "\u{56B}", "\u{56C}", "\u{56D}", "\u{56E}",
+    "\u{56F}", "\u{570}", "\u{571}", "\u{572}", "\u{573}", "\u{574}", "\u{575}", "\u{576}",
+    "\u{577}", "\u{578}", "\u{579}", "\u{57A}", "\u{57B}", "\u{57C}", "\u{57D}", "\u{57E}",
+    "\u{57F}", "\u{580}", "\u{581}", "\u{582}", "\u{583}", "\u{584}", "\u{585}", "\u{586}",
+    "\u{565}\u{582}", "\u{627}\u{674}", "\u{648}\u{674}", "\u{6C7}\u{674}", "\u{64A}\u{674}",
+    "\u{915}\u{93C}", "\u{916}\u{93C}", "\u{917}\u{93C}", "\u{91C}\u{93C}", "\u{921}\u{93C}",
+    "\u{922}\u{93C}", "\u{92B}\u{93C}", "\u{92F}\u{93C}", "\u{9A1}\u{9BC}", "\u{9A2}\u{9BC}",
+    "\u{9AF}\u{9BC}", "\u{A32}\u{A3C}", "\u{A38}\u{A3C}", "\u{A16}\u{A3C}", "\u{A17}\u{A3C}",
+    "\u{A1C}\u{A3C}", "\u{A2B}\u{A3C}", "\u{B21}\u{B3C}", "\u{B22}\u{B3C}", "\u{E4D}\u{E32}",
+    "\u{ECD}\u{EB2}", "\u{EAB}\u{E99}", "\u{EAB}\u{EA1}", "\u{F0B}", "\u{F42}\u{FB7}",
+    "\u{F4C}\u{FB7}", "\u{F51}\u{FB7}", "\u{F56}\u{FB7}", "\u{F5B}\u{FB7}", "\u{F40}\u{FB5}",
+    "\u{F71}\u{F72}", "\u{F71}\u{F74}", "\u{FB2}\u{F80}", "\u{FB2}\u{F71}\u{F80}", "\u{FB3}\u{F80}",
+    "\u{FB3}\u{F71}\u{F80}", "\u{F71}\u{F80}", "\u{F92}\u{FB7}", "\u{F9C}\u{FB7}", "\u{FA1}\u{FB7}",
+    "\u{FA6}\u{FB7}", "\u{FAB}\u{FB7}", "\u{F90}\u{FB5}", "\u{2D00}", "\u{2D01}", "\u{2D02}",
+    "\u{2D03}", "\u{2D04}", "\u{2D05}", "\u{2D06}", "\u{2D07}", "\u{2D08}", "\u{2D09}", "\u{2D0A}",
+    "\u{2D0B}", "\u{2D0C}", "\u{2D0D}", "\u{2D0E}", "\u{2D0F}", "\u{2D10}", "\u{2D11}", "\u{2D12}",
+    "\u{2D13}", "\u{2D14}", "\u{2D15}", "\u{2D16}", "\u{2D17}", "\u{2D18}", "\u{2D19}", "\u{2D1A}",
+    "\u{2D1B}", "\u{2D1C}", "\u{2D1D}", "\u{2D1E}", "\u{2D1F}", "\u{2D20}", "\u{2D21}", "\u{2D22}",
+    "\u{2D23}", "\u{2D24}", "\u{2D25}", "\u{2D27}", "\u{2D2D}", "\u{10DC}", "\u{13F0}", "\u{13F1}",
+    "\u{13F2}", "\u{13F3}", "\u{13F4}", "\u{13F5}", "\u{A64B}", "\u{1C8A}", "\u{10D0}", "\u{10D1}",
+    "\u{10D2}", "\u{10D3}", "\u{10D4}", "\u{10D5}", "\u{10D6}", "\u{10D7}", "\u{10D8}", "\u{10D9}",
+    "\u{10DA}", "\u{10DB}", "\u{10DD}", "\u{10DE}", "\u{10DF}", "\u{10E0}", "\u{10E1}", "\u{10E2}",
+    "\u{10E3}", "\u{10E4}", "\u{10E5}", "\u{10E6}", "\u{10E7}", "\u{10E8}", "\u{10E9}", "\u{10EA}",
+    "\u{10EB}", "\u{10EC}", "\u{10ED}", "\u{10EE}", "\u{10EF}", "\u{10F0}", "\u{10F1}", "\u{10F2}",
+    "\u{10F3}", "\u{10F4}", "\u{10F5}", "\u{10F6}", "\u{10F7}", "\u{10F8}", "\u{10F9}", "\u{10FA}",
+    "\u{10FD}", "\u{10FE}", "\u{10FF}", "\u{250}", "\u{251}", "\u{1D02}", "\u{25C}", "\u{1D16}",
+    "\u{1D17}", "\u{1D1D}", "\u{1D25}", "\u{252}", "\u{255}", "\u{25F}", "\u{261}", "\u{265}",
+    "\u{26A}", "\u{1D7B}", "\u{29D}", "\u{26D}", "\u{1D85}", "\u{29F}", "\u{271}", "\u{270}",
+    "\u{273}", "\u{274}", "\u{278}", "\u{282}", "\u{1AB}", "\u{1D1C}", "\u{290}", "\u{291}",
+    "\u{1E01}", "\u{1E03}", "\u{1E05}", "\u{1E07}", "\u{1E09}", "\u{1E0B}", "\u{1E0D}", "\u{1E0F}",
+    "\u{1E11}", "\u{1E13}", "\u{1E15}", "\u{1E17}", "\u{1E19}", "\u{1E1B}", "\u{1E1D}", "\u{1E1F}",
+    "\u{1E21}", "\u{1E23}", "\u{1E25}", "\u{1E27}", "\u{1E29}", "\u{1E2B}", "\u{1E2D}", "\u{1E2F}",
+    "\u{1E31}", "\u{1E33}", "\u{1E35}", "\u{1E37}", "\u{1E39}", "\u{1E3B}", "\u{1E3D}", "\u{1E3F}",
+    "\u{1E41}", "\u{1E43}", "\u{1E45}", "\u{1E47}", "\u{1E49}", "\u{1E4B}", "\u{1E4D}", "\u{1E4F}",
+    "\u{1E51}", "\u{1E53}", "\u{1E55}", "\u{1E57}", "\u{1E59}", "\u{1E5B}", "\u{1E5D}", "\u{1E5F}",
+    "\u{1E61}", "\u{1E63}", "\u{1E65}", "\u{1E67}", "\u{1E69}", "\u{1E6B}", "\u{1E6D}", "\u{1E6F}",
+    "\u{1E71}", "\u{1E73}", "\u{1E75}", "\u{1E77}", "\u{1E79}", "\u{1E7B}", "\u{1E7D}", "\u{1E7F}",
+    "\u{1E81}", "\u{1E83}", "\u{1E85}", "\u{1E87}", "\u{1E89}", "\u{1E8B}", "\u{1E8D}", "\u{1E8F}",
+    "\u{1E91}", "\u{1E93}", "\u{1E95}", "\u{61}\u{2BE}", "\u{1EA1}", "\u{1EA3}", "\u{1EA5}",
+    "\u{1EA7}", "\u{1EA9}", "\u{1EAB}", "\u{1EAD}", "\u{1EAF}", "\u{1EB1}", "\u{1EB3}", "\u{1EB5}",
+    "\u{1EB7}", "\u{1EB9}", "\u{1EBB}", "\u{1EBD}", "\u{1EBF}", "\u{1EC1}", "\u{1EC3}", "\u{1EC5}",
+    "\u{1EC7}", "\u{1EC9}", "\u{1ECB}", "\u{1ECD}", "\u{1ECF}", "\u{1ED1}", "\u{1ED3}", "\u{1ED5}",
+    "\u{1ED7}", "\u{1ED9}", "\u{1EDB}", "\u{1EDD}", "\u{1EDF}", "\u{1EE1}", "\u{1EE3}", "\u{1EE5}",
+    "\u{1EE7}", "\u{1EE9}", "\u{1EEB}", "\u{1EED}", "\u{1EEF}", "\u{1EF1}", "\u{1EF3}", "\u{1EF5}",
+    "\u{1EF7}", "\u{1EF9}", "\u{1EFB}", "\u{1EFD}", "\u{1EFF}", "\u{1F00}", "\u{1F01}", "\u{1F02}",
+    "\u{1F03}", "\u{1F04}", "\u{1F05}", "\u{1F06}", "\u{1F07}", "\u{1F10}", "\u{1F11}", "\u{1F12}",
+    "\u{1F13}", "\u{1F14}", "\u{1F15}", "\u{1F20}", "\u{1F21}", "\u{1F22}", "\u{1F23}", "\u{1F24}",
+    "\u{1F25}", "\u{1F26}", "\u{1F27}", "\u{1F30}", "\u{1F31}", "\u{1F32}", "\u{1F33}", "\u{1F34}",
+    "\u{1F35}", "\u{1F36}", "\u{1F37}", "\u{1F40}", "\u{1F41}", "\u{1F42}", "\u{1F43}", "\u{1F44}",
+    "\u{1F45}", "\u{1F51}", "\u{1F53}", "\u{1F55}", "\u{1F57}", "\u{1F60}", "\u{1F61}", "\u{1F62}",
+    "\u{1F63}", "\u{1F64}", "\u{1F65}", "\u{1F66}", "\u{1F67}", "\u{1F00}\u{3B9}",
+    "\u{1F01}\u{3B9}", "\u{1F02}\u{3B9}", "\u{1F03}\u{3B9}", "\u{1F04}\u{3B9}", "\u{1F05}\u{3B9}",
+    "\u{1F06}\u{3B9}", "\u{1F07}\u{3B9}", "\u{1F20}\u{3B9}", "\u{1F21}\u{3B9}", "\u{1F22}\u{3B9}",
+    "\u{1F23}\u{3B9}", "\u{1F24}\u{3B9}", "\u{1F25}\u{3B9}", "\u{1F26}\u{3B9}", "\u{1F27}\u{3B9}",
+    "\u{1F60}\u{3B9}", "\u{1F61}\u{3B9}", "\u{1F62}\u{3B9}", "\u{1F63}\u{3B9}", "\u{1F64}\u{3B9}",
+    "\u{1F65}\u{3B9}", "\u{1F66}\u{3B9}", "\u{1F67}\u{3B9}", "\u{1F70}\u{3B9}", "\u{3B1}\u{3B9}",
+    "\u{3AC}\u{3B9}", "\u{1FB6}\u{3B9}", "\u{1FB0}", "\u{1FB1}", "\u{1F70}", "\u{20}\u{313}",
+    "\u{20}\u{342}", "\u{20}\u{308}\u{342}", "\u{1F74}\u{3B9}", "\u{3B7}\u{3B9}", "\u{3AE}\u{3B9}",
+    "\u{1FC6}\u{3B9}", "\u{1F72}", "\u{1F74}", "\u{20}\u{313}\u{300}", "\u{20}\u{313}\u{301}",
+    "\u{20}\u{313}\u{342}", "\u{390}", "\u{1FD0}", "\u{1FD1}", "\u{1F76}", "\u{20}\u{314}\u{300}",
+    "\u{20}\u{314}\u{301}", "\u{20}\u{314}\u{342}", "\u{3B0}", "\u{1FE0}", "\u{1FE1}", "\u{1F7A}",
+    "\u{1FE5}", "\u{20}\u{308}\u{300}", "\u{60}", "\u{1F7C}\u{3B9}", "\u{3C9}\u{3B9}",
+    "\u{3CE}\u{3B9}", "\u{1FF6}\u{3B9}", "\u{1F78}", "\u{1F7C}", "\u{20}\u{314}", "\u{2010}",
+    "\u{20}\u{333}", "\u{2E}", "\u{2E}\u{2E}", "\u{2E}\u{2E}\u{2E}", "\u{2032}\u{2032}",
+    "\u{2032}\u{2032}\u{2032}", "\u{2035}\u{2035}", "\u{2035}\u{2035}\u{2035}", "\u{21}\u{21}",
+    "\u{20}\u{305}", "\u{3F}\u{3F}", "\u{3F}\u{21}", "\u{21}\u{3F}",
+    "\u{2032}\u{2032}\u{2032}\u{2032}", "\u{30}", "\u{34}", "\u{35}", "\u{36}", "\u{37}", "\u{38}",
+    "\u{39}", "\u{2B}", "\u{2212}", "\u{3D}", "\u{28}", "\u{29}", "\u{72}\u{73}",
+    "\u{61}\u{2F}\u{63}", "\u{61}\u{2F}\u{73}", "\u{B0}\u{63}", "\u{63}\u{2F}\u{6F}",
+    "\u{63}\u{2F}\u{75}", "\u{B0}\u{66}", "\u{6E}\u{6F}", "\u{73}\u{6D}", "\u{74}\u{65}\u{6C}",
+    "\u{74}\u{6D}", "\u{214E}", "\u{5D0}", "\u{5D1}", "\u{5D2}", "\u{5D3}", "\u{66}\u{61}\u{78}",
+    "\u{2211}", "\u{31}\u{2044}\u{37}", "\u{31}\u{2044}\u{39}", "\u{31}\u{2044}\u{31}\u{30}",
+    "\u{31}\u{2044}\u{33}", "\u{32}\u{2044}\u{33}", "\u{31}\u{2044}\u{35}", "\u{32}\u{2044}\u{35}",
+    "\u{33}\u{2044}\u{35}", "\u{34}\u{2044}\u{35}", "\u{31}\u{2044}\u{36}", "\u{35}\u{2044}\u{36}",
+    "\u{31}\u{2044}\u{38}", "\u{33}\u{2044}\u{38}", "\u{35}\u{2044}\u{38}", "\u{37}\u{2044}\u{38}",
+    "\u{31}\u{2044}", "\u{69}\u{69}", "\u{69}\u{69}\u{69}", "\u{69}\u{76}", "\u{76}\u{69}",
+    "\u{76}\u{69}\u{69}", "\u{76}\u{69}\u{69}\u{69}", "\u{69}\u{78}", "\u{78}\u{69}",
+    "\u{78}\u{69}\u{69}", "\u{2184}", "\u{30}\u{2044}\u{33}", "\u{222B}\u{222B}",
+    "\u{222B}\u{222B}\u{222B}", "\u{222E}\u{222E}", "\u{222E}\u{222E}\u{222E}", "\u{3008}",
+    "\u{3009}", "\u{31}\u{30}", "\u{31}\u{31}", "\u{31}\u{32}", "\u{31}\u{33}", "\u{31}\u{34}",
+    "\u{31}\u{35}", "\u{31}\u{36}", "\u{31}\u{37}", "\u{31}\u{38}", "\u{31}\u{39}", "\u{32}\u{30}",
+    "\u{28}\u{31}\u{29}", "\u{28}\u{32}\u{29}", "\u{28}\u{33}\u{29}", "\u{28}\u{34}\u{29}",
+    "\u{28}\u{35}\u{29}", "\u{28}\u{36}\u{29}", "\u{28}\u{37}\u{29}", "\u{28}\u{38}\u{29}",
+    "\u{28}\u{39}\u{29}", "\u{28}\u{31}\u{30}\u{29}", "\u{28}\u{31}\u{31}\u{29}",
+    "\u{28}\u{31}\u{32}\u{29}", "\u{28}\u{31}\u{33}\u{29}", "\u{28}\u{31}\u{34}\u{29}",
+    "\u{28}\u{31}\u{35}\u{29}", "\u{28}\u{31}\u{36}\u{29}", "\u{28}\u{31}\u{37}\u{29}",
+    "\u{28}\u{31}\u{38}\u{29}", "\u{28}\u{31}\u{39}\u{29}", "\u{28}\u{32}\u{30}\u{29}",
+    "\u{31}\u{2E}", "\u{32}\u{2E}", "\u{33}\u{2E}", "\u{34}\u{2E}", "\u{35}\u{2E}", "\u{36}\u{2E}",
+    "\u{37}\u{2E}", "\u{38}\u{2E}", "\u{39}\u{2E}", "\u{31}\u{30}\u{2E}", "\u{31}\u{31}\u{2E}",
+    "\u{31}\u{32}\u{2E}", "\u{31}\u{33}\u{2E}", "\u{31}\u{34}\u{2E}", "\u{31}\u{35}\u{2E}",
+    "\u{31}\u{36}\u{2E}", "\u{31}\u{37}\u{2E}", "\u{31}\u{38}\u{2E}", "\u{31}\u{39}\u{2E}",
+    "\u{32}\u{30}\u{2E}", "\u{28}\u{61}\u{29}", "\u{28}\u{62}\u{29}", "\u{28}\u{63}\u{29}",
+    "\u{28}\u{64}\u{29}", "\u{28}\u{65}\u{29}", "\u{28}\u{66}\u{29}", "\u{28}\u{67}\u{29}",
+    "\u{28}\u{68}\u{29}", "\u{28}\u{69}\u{29}", "\u{28}\u{6A}\u{29}", "\u{28}\u{6B}\u{29}",
+    "\u{28}\u{6C}\u{29}", "\u{28}\u{6D}\u{29}", "\u{28}\u{6E}\u{29}", "\u{28}\u{6F}\u{29}",
+    "\u{28}\u{70}\u{29}", "\u{28}\u{71}\u{29}", "\u{28}\u{72}\u{29}", "\u{28}\u{73}\u{29}",
+    "\u{28}\u{74}\u{29}", "\u{28}\u{75}\u{29}", "\u{28}\u{76}\u{29}", "\u{28}\u{77}\u{29}",
+    "\u{28}\u{78}\u{29}", "\u{28}\u{79}\u{29}", "\u{28}\u{7A}\u{29}",
+    "\u{222B}\u{222B}\u{222B}\u{222B}", "\u{3A}\u{3A}\u{3D}", "\u{3D}\u{3D}", "\u{3D}\u{3D}\u{3D}",
+    "\u{2ADD}\u{338}", "\u{2C30}", "\u{2C31}", "\u{2C32}", "\u{2C33}", "\u{2C34}", "\u{2C35}",
+    "\u{2C36}", "\u{2C37}", "\u{2C38}", "\u{2C39}", "\u{2C3A}", "\u{2C3B}", "\u{2C3C}", "\u{2C3D}",
+    "\u{2C3E}", "\u{2C3F}", "\u{2C40}", "\u{2C41}", "\u{2C42}", "\u{2C43}", "\u{2C44}", "\u{2C45}",
+    "\u{2C46}", "\u{2C47}", "\u{2C48}", "\u{2C49}", "\u{2C4A}", "\u{2C4B}", "\u{2C4C}", "\u{2C4D}",
+    "\u{2C4E}", "\u{2C4F}", "\u{2C50}", "\u{2C51}", "\u{2C52}", "\u{2C53}", "\u{2C54}", "\u{2C55}",
+    "\u{2C56}", "\u{2C57}", "\u{2C58}", "\u{2C59}", "\u{2C5A}", "\u{2C5B}", "\u{2C5C}", "\u{2C5D}",
+    "\u{2C5E}", "\u{2C5F}", "\u{2C61}", "\u{26B}", "\u{1D7D}", "\u{27D}", "\u{2C68}", "\u{2C6A}",
+    "\u{2C6C}", "\u{2C73}", "\u{2C76}", "\u{23F}", "\u{240}", "\u{2C81}", "\u{2C83}", "\u{2C85}",
+    "\u{2C87}", "\u{2C89}", "\u{2C8B}", "\u{2C8D}", "\u{2C8F}", "\u{2C91}", "\u{2C93}", "\u{2C95}",
+    "\u{2C97}", "\u{2C99}", "\u{2C9B}", "\u{2C9D}", "\u{2C9F}", "\u{2CA1}", "\u{2CA3}", "\u{2CA5}",
+    "\u{2CA7}", "\u{2CA9}", "\u{2CAB}", "\u{2CAD}", "\u{2CAF}", "\u{2CB1}", "\u{2CB3}", "\u{2CB5}",
+    "\u{2CB7}", "\u{2CB9}", "\u{2CBB}", "\u{2CBD}", "\u{2CBF}", "\u{2CC1}", "\u{2CC3}", "\u{2CC5}",
+    "\u{2CC7}", "\u{2CC9}", "\u{2CCB}", "\u{2CCD}", "\u{2CCF}", "\u{2CD1}", "\u{2CD3}", "\u{2CD5}",
+    "\u{2CD7}", "\u{2CD9}", "\u{2CDB}", "\u{2CDD}", "\u{2CDF}", "\u{2CE1}", "\u{2CE3}", "\u{2CEC}",
+    "\u{2CEE}", "\u{2CF3}", "\u{2D61}", "\u{6BCD}", "\u{9F9F}", "\u{4E00}", "\u{4E28}", "\u{4E36}",
+    "\u{4E3F}", "\u{4E59}", "\u{4E85}", "\u{4E8C}", "\u{4EA0}", "\u{4EBA}", "\u{513F}", "\u{5165}",
+    "\u{516B}", "\u{5182}", "\u{5196}", "\u{51AB}", "\u{51E0}", "\u{51F5}", "\u{5200}", "\u{529B}",
+    "\u{52F9}", "\u{5315}", "\u{531A}", "\u{5338}", "\u{5341}", "\u{535C}", "\u{5369}", "\u{5382}",
+    "\u{53B6}", "\u{53C8}", "\u{53E3}", "\u{56D7}", "\u{571F}", "\u{58EB}", "\u{5902}", "\u{590A}",
+    "\u{5915}", "\u{5927}", "\u{5973}", "\u{5B50}", "\u{5B80}", "\u{5BF8}", "\u{5C0F}", "\u{5C22}",
+    "\u{5C38}", "\u{5C6E}", "\u{5C71}", "\u{5DDB}", "\u{5DE5}", "\u{5DF1}", "\u{5DFE}", "\u{5E72}",
+    "\u{5E7A}", "\u{5E7F}", "\u{5EF4}", "\u{5EFE}", "\u{5F0B}", "\u{5F13}", "\u{5F50}", "\u{5F61}",
+    "\u{5F73}", "\u{5FC3}", "\u{6208}", "\u{6236}", "\u{624B}", "\u{652F}", "\u{6534}", "\u{6587}",
+    "\u{6597}", "\u{65A4}", "\u{65B9}", "\u{65E0}", "\u{65E5}", "\u{66F0}", "\u{6708}", "\u{6728}",
+    "\u{6B20}", "\u{6B62}", "\u{6B79}", "\u{6BB3}", "\u{6BCB}", "\u{6BD4}", "\u{6BDB}", "\u{6C0F}",
+    "\u{6C14}", "\u{6C34}", "\u{706B}", "\u{722A}", "\u{7236}", "\u{723B}", "\u{723F}", "\u{7247}",
+    "\u{7259}", "\u{725B}", "\u{72AC}", "\u{7384}", "\u{7389}", "\u{74DC}", "\u{74E6}", "\u{7518}",
+    "\u{751F}", "\u{7528}", "\u{7530}", "\u{758B}", "\u{7592}", "\u{7676}", "\u{767D}", "\u{76AE}",
+    "\u{76BF}", "\u{76EE}", "\u{77DB}", "\u{77E2}", "\u{77F3}", "\u{793A}", "\u{79B8}", "\u{79BE}",
+    "\u{7A74}", "\u{7ACB}", "\u{7AF9}", "\u{7C73}", "\u{7CF8}", "\u{7F36}", "\u{7F51}", "\u{7F8A}",
+    "\u{7FBD}", "\u{8001}", "\u{800C}", "\u{8012}", "\u{8033}", "\u{807F}", "\u{8089}", "\u{81E3}",
+    "\u{81EA}", "\u{81F3}", "\u{81FC}", "\u{820C}", "\u{821B}", "\u{821F}", "\u{826E}", "\u{8272}",
+    "\u{8278}", "\u{864D}", "\u{866B}", "\u{8840}", "\u{884C}", "\u{8863}", "\u{897E}", "\u{898B}",
+    "\u{89D2}", "\u{8A00}", "\u{8C37}", "\u{8C46}", "\u{8C55}", "\u{8C78}", "\u{8C9D}", "\u{8D64}",
+    "\u{8D70}", "\u{8DB3}", "\u{8EAB}", "\u{8ECA}", "\u{8F9B}", "\u{8FB0}", "\u{8FB5}", "\u{9091}",
+    "\u{9149}", "\u{91C6}", "\u{91CC}", "\u{91D1}", "\u{9577}", "\u{9580}", "\u{961C}", "\u{96B6}",
+    "\u{96B9}", "\u{96E8}", "\u{9751}", "\u{975E}", "\u{9762}", "\u{9769}", "\u{97CB}", "\u{97ED}",
+    "\u{97F3}", "\u{9801}", "\u{98A8}", "\u{98DB}", "\u{98DF}", "\u{9996}", "\u{9999}", "\u{99AC}",
+    "\u{9AA8}", "\u{9AD8}", "\u{9ADF}", "\u{9B25}", "\u{9B2F}", "\u{9B32}", "\u{9B3C}", "\u{9B5A}",
+    "\u{9CE5}", "\u{9E75}", "\u{9E7F}", "\u{9EA5}", "\u{9EBB}", "\u{9EC3}", "\u{9ECD}", "\u{9ED1}",
+    "\u{9EF9}", "\u{9EFD}", "\u{9F0E}", "\u{9F13}", "\u{9F20}", "\u{9F3B}", "\u{9F4A}", "\u{9F52}",
+    "\u{9F8D}", "\u{9F9C}", "\u{9FA0}", "\u{3012}", "\u{5344}", "\u{5345}", "\u{20}\u{3099}",
+    "\u{20}\u{309A}", "\u{3088}\u{308A}", "\u{30B3}\u{30C8}", "\u{1100}", "\u{1101}", "\u{11AA}",
+    "\u{1102}", "\u{11AC}", "\u{11AD}", "\u{1103}", "\u{1104}", "\u{1105}", "\u{11B0}", "\u{11B1}",
+    "\u{11B2}", "\u{11B3}", "\u{11B4}", "\u{11B5}", "\u{111A}", "\u{1106}", "\u{1107}", "\u{1108}",
+    "\u{1121}", "\u{1109}", "\u{110A}", "\u{110B}", "\u{110C}", "\u{110D}", "\u{110E}", "\u{110F}",
+    "\u{1110}", "\u{1111}", "\u{1112}", "\u{1161}", "\u{1162}", "\u{1163}", "\u{1164}", "\u{1165}",
+    "\u{1166}", "\u{1167}", "\u{1168}", "\u{1169}", "\u{116A}", "\u{116B}", "\u{116C}", "\u{116D}",
+    "\u{116E}", "\u{116F}", "\u{1170}", "\u{1171}", "\u{1172}", "\u{1173}", "\u{1174}", "\u{1175}",
+    "\u{1114}", "\u{1115}", "\u{11C7}", "\u{11C8}", "\u{11CC}", "\u{11CE}", "\u{11D3}", "\u{11D7}",
+    "\u{11D9}", "\u{111C}", "\u{11DD}", "\u{11DF}", "\u{111D}", "\u{111E}", "\u{1120}", "\u{1122}",
+    "\u{1123}", "\u{1127}", "\u{1129}", "\u{112B}", "\u{112C}", "\u{112D}", "\u{112E}", "\u{112F}",
+    "\u{1132}", "\u{1136}", "\u{1140}", "\u{1147}", "\u{114C}", "\u{11F1}", "\u{11F2}", "\u{1157}",
+    "\u{1158}", "\u{1159}", "\u{1184}", "\u{1185}", "\u{1188}", "\u{1191}", "\u{1192}", "\u{1194}",
+    "\u{119E}", "\u{11A1}", "\u{4E09}", "\u{56DB}", "\u{4E0A}", "\u{4E2D}", "\u{4E0B}", "\u{7532}",
+    "\u{4E19}", "\u{4E01}", "\u{5929}", "\u{5730}", "\u{28}\u{1100}\u{29}", "\u{28}\u{1102}\u{29}",
+    "\u{28}\u{1103}\u{29}", "\u{28}\u{1105}\u{29}", "\u{28}\u{1106}\u{29}", "\u{28}\u{1107}\u{29}",
+    "\u{28}\u{1109}\u{29}", "\u{28}\u{110B}\u{29}", "\u{28}\u{110C}\u{29}", "\u{28}\u{110E}\u{29}",
+    "\u{28}\u{110F}\u{29}", "\u{28}\u{1110}\u{29}", "\u{28}\u{1111}\u{29}", "\u{28}\u{1112}\u{29}",
+    "\u{28}\u{AC00}\u{29}", "\u{28}\u{B098}\u{29}", "\u{28}\u{B2E4}\u{29}", "\u{28}\u{B77C}\u{29}",
+    "\u{28}\u{B9C8}\u{29}", "\u{28}\u{BC14}\u{29}", "\u{28}\u{C0AC}\u{29}", "\u{28}\u{C544}\u{29}",
+    "\u{28}\u{C790}\u{29}", "\u{28}\u{CC28}\u{29}", "\u{28}\u{CE74}\u{29}", "\u{28}\u{D0C0}\u{29}",
+    "\u{28}\u{D30C}\u{29}", "\u{28}\u{D558}\u{29}", "\u{28}\u{C8FC}\u{29}",
+    "\u{28}\u{C624}\u{C804}\u{29}", "\u{28}\u{C624}\u{D6C4}\u{29}", "\u{28}\u{4E00}\u{29}",
+    "\u{28}\u{4E8C}\u{29}", "\u{28}\u{4E09}\u{29}", "\u{28}\u{56DB}\u{29}", "\u{28}\u{4E94}\u{29}",
+    "\u{28}\u{516D}\u{29}", "\u{28}\u{4E03}\u{29}", "\u{28}\u{516B}\u{29}", "\u{28}\u{4E5D}\u{29}",
+    "\u{28}\u{5341}\u{29}", "\u{28}\u{6708}\u{29}", "\u{28}\u{706B}\u{29}", "\u{28}\u{6C34}\u{29}",
+    "\u{28}\u{6728}\u{29}", "\u{28}\u{91D1}\u{29}", "\u{28}\u{571F}\u{29}", "\u{28}\u{65E5}\u{29}",
+    "\u{28}\u{682A}\u{29}", "\u{28}\u{6709}\u{29}", "\u{28}\u{793E}\u{29}", "\u{28}\u{540D}\u{29}",
+    "\u{28}\u{7279}\u{29}", "\u{28}\u{8CA1}\u{29}", "\u{28}\u{795D}\u{29}", "\u{28}\u{52B4}\u{29}",
+    "\u{28}\u{4EE3}\u{29}", "\u{28}\u{547C}\u{29}", "\u{28}\u{5B66}\u{29}", "\u{28}\u{76E3}\u{29}",
+    "\u{28}\u{4F01}\u{29}", "\u{28}\u{8CC7}\u{29}", "\u{28}\u{5354}\u{29}", "\u{28}\u{796D}\u{29}",
+    "\u{28}\u{4F11}\u{29}", "\u{28}\u{81EA}\u{29}", "\u{28}\u{81F3}\u{29}", "\u{554F}", "\u{5E7C}",
+    "\u{7B8F}", "\u{70}\u{74}\u{65}", "\u{32}\u{31}", "\u{32}\u{32}", "\u{32}\u{33}",
+    "\u{32}\u{34}", "\u{32}\u{35}", "\u{32}\u{36}", "\u{32}\u{37}", "\u{32}\u{38}", "\u{32}\u{39}",
+    "\u{33}\u{30}", "\u{33}\u{31}", "\u{33}\u{32}", "\u{33}\u{33}", "\u{33}\u{34}", "\u{33}\u{35}",
+    "\u{AC00}", "\u{B098}", "\u{B2E4}", "\u{B77C}", "\u{B9C8}", "\u{BC14}", "\u{C0AC}", "\u{C544}",
+    "\u{C790}", "\u{CC28}", "\u{CE74}", "\u{D0C0}", "\u{D30C}", "\u{D558}", "\u{CC38}\u{ACE0}",
+    "\u{C8FC}\u{C758}", "\u{C6B0}", "\u{4E94}", "\u{516D}", "\u{4E03}", "\u{4E5D}", "\u{682A}",
+    "\u{6709}", "\u{793E}", "\u{540D}", "\u{7279}", "\u{8CA1}", "\u{795D}", "\u{52B4}", "\u{79D8}",
+    "\u{7537}", "\u{9069}", "\u{512A}", "\u{5370}", "\u{6CE8}", "\u{9805}", "\u{4F11}", "\u{5199}",
+    "\u{6B63}", "\u{5DE6}", "\u{53F3}", "\u{533B}", "\u{5B97}", "\u{5B66}", "\u{76E3}", "\u{4F01}",
+    "\u{8CC7}", "\u{5354}", "\u{591C}", "\u{33}\u{36}", "\u{33}\u{37}", "\u{33}\u{38}",
+    "\u{33}\u{39}", "\u{34}\u{30}", "\u{34}\u{31}", "\u{34}\u{32}", "\u{34}\u{33}", "\u{34}\u{34}",
+    "\u{34}\u{35}", "\u{34}\u{36}", "\u{34}\u{37}", "\u{34}\u{38}", "\u{34}\u{39}", "\u{35}\u{30}",
+    "\u{31}\u{6708}", "\u{32}\u{6708}", "\u{33}\u{6708}", "\u{34}\u{6708}", "\u{35}\u{6708}",
+    "\u{36}\u{6708}", "\u{37}\u{6708}", "\u{38}\u{6708}", "\u{39}\u{6708}", "\u{31}\u{30}\u{6708}",
+    "\u{31}\u{31}\u{6708}", "\u{31}\u{32}\u{6708}", "\u{68}\u{67}", "\u{65}\u{72}\u{67}",
+    "\u{65}\u{76}", "\u{6C}\u{74}\u{64}", "\u{30A2}", "\u{30A4}", "\u{30A6}", "\u{30A8}",
+    "\u{30AA}", "\u{30AB}", "\u{30AD}", "\u{30AF}", "\u{30B1}", "\u{30B3}", "\u{30B5}", "\u{30B7}",
+    "\u{30B9}", "\u{30BB}", "\u{30BD}", "\u{30BF}", "\u{30C1}", "\u{30C4}", "\u{30C6}", "\u{30C8}",
+    "\u{30CA}", "\u{30CB}", "\u{30CC}", "\u{30CD}", "\u{30CE}", "\u{30CF}", "\u{30D2}", "\u{30D5}",
+    "\u{30D8}", "\u{30DB}", "\u{30DE}", "\u{30DF}", "\u{30E0}", "\u{30E1}", "\u{30E2}", "\u{30E4}",
+    "\u{30E6}", "\u{30E8}", "\u{30E9}", "\u{30EA}", "\u{30EB}", "\u{30EC}", "\u{30ED}", "\u{30EF}",
+    "\u{30F0}", "\u{30F1}", "\u{30F2}", "\u{4EE4}\u{548C}", "\u{30A2}\u{30D1}\u{30FC}\u{30C8}",
+    "\u{30A2}\u{30EB}\u{30D5}\u{30A1}", "\u{30A2}\u{30F3}\u{30DA}\u{30A2}",
+    "\u{30A2}\u{30FC}\u{30EB}", "\u{30A4}\u{30CB}\u{30F3}\u{30B0}", "\u{30A4}\u{30F3}\u{30C1}",
+    "\u{30A6}\u{30A9}\u{30F3}", "\u{30A8}\u{30B9}\u{30AF}\u{30FC}\u{30C9}",
+    "\u{30A8}\u{30FC}\u{30AB}\u{30FC}", "\u{30AA}\u{30F3}\u{30B9}", "\u{30AA}\u{30FC}\u{30E0}",
+    "\u{30AB}\u{30A4}\u{30EA}", "\u{30AB}\u{30E9}\u{30C3}\u{30C8}",
+    "\u{30AB}\u{30ED}\u{30EA}\u{30FC}", "\u{30AC}\u{30ED}\u{30F3}", "\u{30AC}\u{30F3}\u{30DE}",
+    "\u{30AE}\u{30AC}", "\u{30AE}\u{30CB}\u{30FC}", "\u{30AD}\u{30E5}\u{30EA}\u{30FC}",
+    "\u{30AE}\u{30EB}\u{30C0}\u{30FC}", "\u{30AD}\u{30ED}",
+    "\u{30AD}\u{30ED}\u{30B0}\u{30E9}\u{30E0}", "\u{30AD}\u{30ED}\u{30E1}\u{30FC}\u{30C8}\u{30EB}",
+    "\u{30AD}\u{30ED}\u{30EF}\u{30C3}\u{30C8}", "\u{30B0}\u{30E9}\u{30E0}",
+    "\u{30B0}\u{30E9}\u{30E0}\u{30C8}\u{30F3}", "\u{30AF}\u{30EB}\u{30BC}\u{30A4}\u{30ED}",
+    "\u{30AF}\u{30ED}\u{30FC}\u{30CD}", "\u{30B1}\u{30FC}\u{30B9}", "\u{30B3}\u{30EB}\u{30CA}",
+    "\u{30B3}\u{30FC}\u{30DD}", "\u{30B5}\u{30A4}\u{30AF}\u{30EB}",
+    "\u{30B5}\u{30F3}\u{30C1}\u{30FC}\u{30E0}", "\u{30B7}\u{30EA}\u{30F3}\u{30B0}",
+    "\u{30BB}\u{30F3}\u{30C1}", "\u{30BB}\u{30F3}\u{30C8}", "\u{30C0}\u{30FC}\u{30B9}",
+    "\u{30C7}\u{30B7}", "\u{30C9}\u{30EB}", "\u{30C8}\u{30F3}", "\u{30CA}\u{30CE}",
+    "\u{30CE}\u{30C3}\u{30C8}", "\u{30CF}\u{30A4}\u{30C4}",
+    "\u{30D1}\u{30FC}\u{30BB}\u{30F3}\u{30C8}", "\u{30D1}\u{30FC}\u{30C4}",
+    "\u{30D0}\u{30FC}\u{30EC}\u{30EB}", "\u{30D4}\u{30A2}\u{30B9}\u{30C8}\u{30EB}",
+    "\u{30D4}\u{30AF}\u{30EB}", "\u{30D4}\u{30B3}", "\u{30D3}\u{30EB}",
+    "\u{30D5}\u{30A1}\u{30E9}\u{30C3}\u{30C9}", "\u{30D5}\u{30A3}\u{30FC}\u{30C8}",
+    "\u{30D6}\u{30C3}\u{30B7}\u{30A7}\u{30EB}", "\u{30D5}\u{30E9}\u{30F3}",
+    "\u{30D8}\u{30AF}\u{30BF}\u{30FC}\u{30EB}", "\u{30DA}\u{30BD}", "\u{30DA}\u{30CB}\u{30D2}",
+    "\u{30D8}\u{30EB}\u{30C4}", "\u{30DA}\u{30F3}\u{30B9}", "\u{30DA}\u{30FC}\u{30B8}",
+    "\u{30D9}\u{30FC}\u{30BF}", "\u{30DD}\u{30A4}\u{30F3}\u{30C8}", "\u{30DC}\u{30EB}\u{30C8}",
+    "\u{30DB}\u{30F3}", "\u{30DD}\u{30F3}\u{30C9}", "\u{30DB}\u{30FC}\u{30EB}",
+    "\u{30DB}\u{30FC}\u{30F3}", "\u{30DE}\u{30A4}\u{30AF}\u{30ED}", "\u{30DE}\u{30A4}\u{30EB}",
+    "\u{30DE}\u{30C3}\u{30CF}", "\u{30DE}\u{30EB}\u{30AF}",
+    "\u{30DE}\u{30F3}\u{30B7}\u{30E7}\u{30F3}", "\u{30DF}\u{30AF}\u{30ED}\u{30F3}",
+    "\u{30DF}\u{30EA}", "\u{30DF}\u{30EA}\u{30D0}\u{30FC}\u{30EB}", "\u{30E1}\u{30AC}",
+    "\u{30E1}\u{30AC}\u{30C8}\u{30F3}", "\u{30E1}\u{30FC}\u{30C8}\u{30EB}",
+    "\u{30E4}\u{30FC}\u{30C9}", "\u{30E4}\u{30FC}\u{30EB}", "\u{30E6}\u{30A2}\u{30F3}",
+    "\u{30EA}\u{30C3}\u{30C8}\u{30EB}", "\u{30EA}\u{30E9}", "\u{30EB}\u{30D4}\u{30FC}",
+    "\u{30EB}\u{30FC}\u{30D6}\u{30EB}", "\u{30EC}\u{30E0}",
+    "\u{30EC}\u{30F3}\u{30C8}\u{30B2}\u{30F3}", "\u{30EF}\u{30C3}\u{30C8}", "\u{30}\u{70B9}",
+    "\u{31}\u{70B9}", "\u{32}\u{70B9}", "\u{33}\u{70B9}", "\u{34}\u{70B9}", "\u{35}\u{70B9}",
+    "\u{36}\u{70B9}", "\u{37}\u{70B9}", "\u{38}\u{70B9}", "\u{39}\u{70B9}", "\u{31}\u{30}\u{70B9}",
+    "\u{31}\u{31}\u{70B9}", "\u{31}\u{32}\u{70B9}", "\u{31}\u{33}\u{70B9}", "\u{31}\u{34}\u{70B9}",
+    "\u{31}\u{35}\u{70B9}", "\u{31}\u{36}\u{70B9}", "\u{31}\u{37}\u{70B9}", "\u{31}\u{38}\u{70B9}",
+    "\u{31}\u{39}\u{70B9}", "\u{32}\u{30}\u{70B9}", "\u{32}\u{31}\u{70B9}", "\u{32}\u{32}\u{70B9}",
+    "\u{32}\u{33}\u{70B9}", "\u{32}\u{34}\u{70B9}", "\u{68}\u{70}\u{61}", "\u{64}\u{61}",
+    "\u{61}\u{75}", "\u{62}\u{61}\u{72}", "\u{6F}\u{76}", "\u{70}\u{63}", "\u{64}\u{6D}",
+    "\u{64}\u{6D}\u{32}", "\u{64}\u{6D}\u{33}", "\u{69}\u{75}", "\u{5E73}\u{6210}",
+    "\u{662D}\u{548C}", "\u{5927}\u{6B63}", "\u{660E}\u{6CBB}", "\u{682A}\u{5F0F}\u{4F1A}\u{793E}",
+    "\u{70}\u{61}", "\u{6E}\u{61}", "\u{3BC}\u{61}", "\u{6D}\u{61}", "\u{6B}\u{61}", "\u{6B}\u{62}",
+    "\u{6D}\u{62}", "\u{67}\u{62}", "\u{63}\u{61}\u{6C}", "\u{6B}\u{63}\u{61}\u{6C}",
+    "\u{70}\u{66}", "\u{6E}\u{66}", "\u{3BC}\u{66}", "\u{3BC}\u{67}", "\u{6D}\u{67}",
+    "\u{6B}\u{67}", "\u{68}\u{7A}", "\u{6B}\u{68}\u{7A}", "\u{6D}\u{68}\u{7A}",
+    "\u{67}\u{68}\u{7A}", "\u{74}\u{68}\u{7A}", "\u{3BC}\u{6C}", "\u{6D}\u{6C}", "\u{64}\u{6C}",
+    "\u{6B}\u{6C}", "\u{66}\u{6D}", "\u{6E}\u{6D}", "\u{3BC}\u{6D}", "\u{6D}\u{6D}", "\u{63}\u{6D}",
+    "\u{6B}\u{6D}", "\u{6D}\u{6D}\u{32}", "\u{63}\u{6D}\u{32}", "\u{6D}\u{32}",
+    "\u{6B}\u{6D}\u{32}", "\u{6D}\u{6D}\u{33}", "\u{63}\u{6D}\u{33}", "\u{6D}\u{33}",
+    "\u{6B}\u{6D}\u{33}", "\u{6D}\u{2215}\u{73}", "\u{6D}\u{2215}\u{73}\u{32}",
+    "\u{6B}\u{70}\u{61}", "\u{6D}\u{70}\u{61}", "\u{67}\u{70}\u{61}", "\u{72}\u{61}\u{64}",
+    "\u{72}\u{61}\u{64}\u{2215}\u{73}", "\u{72}\u{61}\u{64}\u{2215}\u{73}\u{32}", "\u{70}\u{73}",
+    "\u{6E}\u{73}", "\u{3BC}\u{73}", "\u{6D}\u{73}", "\u{70}\u{76}", "\u{6E}\u{76}",
+    "\u{3BC}\u{76}", "\u{6D}\u{76}", "\u{6B}\u{76}", "\u{70}\u{77}", "\u{6E}\u{77}",
+    "\u{3BC}\u{77}", "\u{6D}\u{77}", "\u{6B}\u{77}", "\u{6B}\u{3C9}", "\u{6D}\u{3C9}",
+    "\u{61}\u{2E}\u{6D}\u{2E}", "\u{62}\u{71}", "\u{63}\u{63}", "\u{63}\u{64}",
+    "\u{63}\u{2215}\u{6B}\u{67}", "\u{63}\u{6F}\u{2E}", "\u{64}\u{62}", "\u{67}\u{79}",
+    "\u{68}\u{61}", "\u{68}\u{70}", "\u{69}\u{6E}", "\u{6B}\u{6B}", "\u{6B}\u{74}", "\u{6C}\u{6D}",
+    "\u{6C}\u{6E}", "\u{6C}\u{6F}\u{67}", "\u{6C}\u{78}", "\u{6D}\u{69}\u{6C}",
+    "\u{6D}\u{6F}\u{6C}", "\u{70}\u{68}", "\u{70}\u{2E}\u{6D}\u{2E}", "\u{70}\u{70}\u{6D}",
+    "\u{70}\u{72}", "\u{73}\u{72}", "\u{73}\u{76}", "\u{77}\u{62}", "\u{76}\u{2215}\u{6D}",
+    "\u{61}\u{2215}\u{6D}", "\u{31}\u{65E5}", "\u{32}\u{65E5}", "\u{33}\u{65E5}", "\u{34}\u{65E5}",
+    "\u{35}\u{65E5}", "\u{36}\u{65E5}", "\u{37}\u{65E5}", "\u{38}\u{65E5}", "\u{39}\u{65E5}",
+    "\u{31}\u{30}\u{65E5}", "\u{31}\u{31}\u{65E5}", "\u{31}\u{32}\u{65E5}", "\u{31}\u{33}\u{65E5}",
+    "\u{31}\u{34}\u{65E5}", "\u{31}\u{35}\u{65E5}", "\u{31}\u{36}\u{65E5}", "\u{31}\u{37}\u{65E5}",
+    "\u{31}\u{38}\u{65E5}", "\u{31}\u{39}\u{65E5}", "\u{32}\u{30}\u{65E5}", "\u{32}\u{31}\u{65E5}",
+    "\u{32}\u{32}\u{65E5}", "\u{32}\u{33}\u{65E5}", "\u{32}\u{34}\u{65E5}", "\u{32}\u{35}\u{65E5}",
+    "\u{32}\u{36}\u{65E5}", "\u{32}\u{37}\u{65E5}", "\u{32}\u{38}\u{65E5}", "\u{32}\u{39}\u{65E5}",
+    "\u{33}\u{30}\u{65E5}", "\u{33}\u{31}\u{65E5}", "\u{67}\u{61}\u{6C}", "\u{A641}", "\u{A643}",
+    "\u{A645}", "\u{A647}", "\u{A649}", "\u{A64D}", "\u{A64F}", "\u{A651}", "\u{A653}", "\u{A655}",
+    "\u{A657}", "\u{A659}", "\u{A65B}", "\u{A65D}", "\u{A65F}", "\u{A661}", "\u{A663}", "\u{A665}",
+    "\u{A667}", "\u{A669}", "\u{A66B}", "\u{A66D}", "\u{A681}", "\u{A683}", "\u{A685}", "\u{A687}",
+    "\u{A689}", "\u{A68B}", "\u{A68D}", "\u{A68F}", "\u{A691}", "\u{A693}", "\u{A695}", "\u{A697}",
+    "\u{A699}", "\u{A69B}", "\u{A723}", "\u{A725}", "\u{A727}", "\u{A729}", "\u{A72B}", "\u{A72D}",
+    "\u{A72F}", "\u{A733}", "\u{A735}", "\u{A737}", "\u{A739}", "\u{A73B}", "\u{A73D}", "\u{A73F}",
+    "\u{A741}", "\u{A743}", "\u{A745}", "\u{A747}", "\u{A749}", "\u{A74B}", "\u{A74D}", "\u{A74F}",
+    "\u{A751}", "\u{A753}", "\u{A755}", "\u{A757}", "\u{A759}", "\u{A75B}", "\u{A75D}", "\u{A75F}",
+    "\u{A761}", "\u{A763}", "\u{A765}", "\u{A767}", "\u{A769}", "\u{A76B}", "\u{A76D}", "\u{A76F}",
+    "\u{A77A}", "\u{A77C}", "\u{1D79}", "\u{A77F}", "\u{A781}", "\u{A783}", "\u{A785}", "\u{A787}",
+    "\u{A78C}", "\u{A791}", "\u{A793}", "\u{A797}", "\u{A799}", "\u{A79B}", "\u{A79D}", "\u{A79F}",
+    "\u{A7A1}", "\u{A7A3}", "\u{A7A5}", "\u{A7A7}", "\u{A7A9}", "\u{26C}", "\u{29E}", "\u{287}",
+    "\u{AB53}", "\u{A7B5}", "\u{A7B7}", "\u{A7B9}", "\u{A7BB}", "\u{A7BD}", "\u{A7BF}", "\u{A7C1}",
+    "\u{A7C3}", "\u{A794}", "\u{1D8E}", "\u{A7C8}", "\u{A7CA}", "\u{264}", "\u{A7CD}", "\u{A7CF}",
+    "\u{A7D1}", "\u{A7D3}", "\u{A7D5}", "\u{A7D7}", "\u{A7D9}", "\u{A7DB}", "\u{19B}", "\u{A7F6}",
+    "\u{AB37}", "\u{AB52}", "\u{28D}", "\u{13A0}", "\u{13A1}", "\u{13A2}", "\u{13A3}", "\u{13A4}",
+    "\u{13A5}", "\u{13A6}", "\u{13A7}", "\u{13A8}", "\u{13A9}", "\u{13AA}", "\u{13AB}", "\u{13AC}",
+    "\u{13AD}", "\u{13AE}", "\u{13AF}", "\u{13B0}", "\u{13B1}", "\u{13B2}", "\u{13B3}", "\u{13B4}",
+    "\u{13B5}", "\u{13B6}", "\u{13B7}", "\u{13B8}", "\u{13B9}", "\u{13BA}", "\u{13BB}", "\u{13BC}",
+    "\u{13BD}", "\u{13BE}", "\u{13BF}", "\u{13C0}", "\u{13C1}", "\u{13C2}", "\u{13C3}", "\u{13C4}",
+    "\u{13C5}", "\u{13C6}", "\u{13C7}", "\u{13C8}", "\u{13C9}", "\u{13CA}", "\u{13CB}", "\u{13CC}",
+    "\u{13CD}", "\u{13CE}", "\u{13CF}", "\u{13D0}", "\u{13D1}", "\u{13D2}", "\u{13D3}", "\u{13D4}",
+    "\u{13D5}", "\u{13D6}", "\u{13D7}", "\u{13D8}", "\u{13D9}", "\u{13DA}", "\u{13DB}", "\u{13DC}",
+    "\u{13DD}", "\u{13DE}", "\u{13DF}", "\u{13E0}", "\u{13E1}", "\u{13E2}", "\u{13E3}", "\u{13E4}",
+    "\u{13E5}", "\u{13E6}", "\u{13E7}", "\u{13E8}", "\u{13E9}", "\u{13EA}", "\u{13EB}", "\u{13EC}",
+    "\u{13ED}", "\u{13EE}", "\u{13EF}", "\u{8C48}", "\u{66F4}", "\u{8CC8}", "\u{6ED1}", "\u{4E32}",
+    "\u{53E5}", "\u{5951}", "\u{5587}", "\u{5948}", "\u{61F6}", "\u{7669}", "\u{7F85}", "\u{863F}",
+    "\u{87BA}", "\u{88F8}", "\u{908F}", "\u{6A02}", "\u{6D1B}", "\u{70D9}", "\u{73DE}", "\u{843D}",
+    "\u{916A}", "\u{99F1}", "\u{4E82}", "\u{5375}", "\u{6B04}", "\u{721B}", "\u{862D}", "\u{9E1E}",
+    "\u{5D50}", "\u{6FEB}", "\u{85CD}", "\u{8964}", "\u{62C9}", "\u{81D8}", "\u{881F}", "\u{5ECA}",
+    "\u{6717}", "\u{6D6A}", "\u{72FC}", "\u{90CE}", "\u{4F86}", "\u{51B7}", "\u{52DE}", "\u{64C4}",
+    "\u{6AD3}", "\u{7210}", "\u{76E7}", "\u{8606}", "\u{865C}", "\u{8DEF}", "\u{9732}", "\u{9B6F}",
+    "\u{9DFA}", "\u{788C}", "\u{797F}", "\u{7DA0}", "\u{83C9}", "\u{9304}", "\u{8AD6}", "\u{58DF}",
+    "\u{5F04}", "\u{7C60}", "\u{807E}", "\u{7262}", "\u{78CA}", "\u{8CC2}", "\u{96F7}", "\u{58D8}",
+    "\u{5C62}", "\u{6A13}", "\u{6DDA}", "\u{6F0F}", "\u{7D2F}", "\u{7E37}", "\u{964B}", "\u{52D2}",
+    "\u{808B}", "\u{51DC}", "\u{51CC}", "\u{7A1C}", "\u{7DBE}", "\u{83F1}", "\u{9675}", "\u{8B80}",
+    "\u{62CF}", "\u{8AFE}", "\u{4E39}", "\u{5BE7}", "\u{6012}", "\u{7387}", "\u{7570}", "\u{5317}",
+    "\u{78FB}", "\u{4FBF}", "\u{5FA9}", "\u{4E0D}", "\u{6CCC}", "\u{6578}", "\u{7D22}", "\u{53C3}",
+    "\u{585E}", "\u{7701}", "\u{8449}", "\u{8AAA}", "\u{6BBA}", "\u{6C88}", "\u{62FE}", "\u{82E5}",
+    "\u{63A0}", "\u{7565}", "\u{4EAE}", "\u{5169}", "\u{51C9}", "\u{6881}", "\u{7CE7}", "\u{826F}",
+    "\u{8AD2}", "\u{91CF}", "\u{52F5}", "\u{5442}", "\u{5EEC}", "\u{65C5}", "\u{6FFE}", "\u{792A}",
+    "\u{95AD}", "\u{9A6A}", "\u{9E97}", "\u{9ECE}", "\u{66C6}", "\u{6B77}", "\u{8F62}", "\u{5E74}",
+    "\u{6190}", "\u{6200}", "\u{649A}", "\u{6F23}", "\u{7149}", "\u{7489}", "\u{79CA}", "\u{7DF4}",
+    "\u{806F}", "\u{8F26}", "\u{84EE}", "\u{9023}", "\u{934A}", "\u{5217}", "\u{52A3}", "\u{54BD}",
+    "\u{70C8}", "\u{88C2}", "\u{5EC9}", "\u{5FF5}", "\u{637B}", "\u{6BAE}", "\u{7C3E}", "\u{7375}",
+    "\u{4EE4}", "\u{56F9}", "\u{5DBA}", "\u{601C}", "\u{73B2}", "\u{7469}", "\u{7F9A}", "\u{8046}",
+    "\u{9234}", "\u{96F6}", "\u{9748}", "\u{9818}", "\u{4F8B}", "\u{79AE}", "\u{91B4}", "\u{96B8}",
+    "\u{60E1}", "\u{4E86}", "\u{50DA}", "\u{5BEE}", "\u{5C3F}", "\u{6599}", "\u{71CE}", "\u{7642}",
+    "\u{84FC}", "\u{907C}", "\u{6688}", "\u{962E}", "\u{5289}", "\u{677B}", "\u{67F3}", "\u{6D41}",
+    "\u{6E9C}", "\u{7409}", "\u{7559}", "\u{786B}", "\u{7D10}", "\u{985E}", "\u{622E}", "\u{9678}",
+    "\u{502B}", "\u{5D19}", "\u{6DEA}", "\u{8F2A}", "\u{5F8B}", "\u{6144}", "\u{6817}", "\u{9686}",
+    "\u{5229}", "\u{540F}", "\u{5C65}", "\u{6613}", "\u{674E}", "\u{68A8}", "\u{6CE5}", "\u{7406}",
+    "\u{75E2}", "\u{7F79}", "\u{88CF}", "\u{88E1}", "\u{96E2}", "\u{533F}", "\u{6EBA}", "\u{541D}",
+    "\u{71D0}", "\u{7498}", "\u{85FA}", "\u{96A3}", "\u{9C57}", "\u{9E9F}", "\u{6797}", "\u{6DCB}",
+    "\u{81E8}", "\u{7B20}", "\u{7C92}", "\u{72C0}", "\u{7099}", "\u{8B58}", "\u{4EC0}", "\u{8336}",
+    "\u{523A}", "\u{5207}", "\u{5EA6}", "\u{62D3}", "\u{7CD6}", "\u{5B85}", "\u{6D1E}", "\u{66B4}",
+    "\u{8F3B}", "\u{964D}", "\u{5ED3}", "\u{5140}", "\u{55C0}", "\u{585A}", "\u{6674}", "\u{51DE}",
+    "\u{732A}", "\u{76CA}", "\u{793C}", "\u{795E}", "\u{7965}", "\u{798F}", "\u{9756}", "\u{7CBE}",
+    "\u{8612}", "\u{8AF8}", "\u{9038}", "\u{90FD}", "\u{98EF}", "\u{98FC}", "\u{9928}", "\u{9DB4}",
+    "\u{90DE}", "\u{96B7}", "\u{4FAE}", "\u{50E7}", "\u{514D}", "\u{52C9}", "\u{52E4}", "\u{5351}",
+    "\u{559D}", "\u{5606}", "\u{5668}", "\u{5840}", "\u{58A8}", "\u{5C64}", "\u{6094}", "\u{6168}",
+    "\u{618E}", "\u{61F2}", "\u{654F}", "\u{65E2}", "\u{6691}", "\u{6885}", "\u{6D77}", "\u{6E1A}",
+    "\u{6F22}", "\u{716E}", "\u{722B}", "\u{7422}", "\u{7891}", "\u{7949}", "\u{7948}", "\u{7950}",
+    "\u{7956}", "\u{798D}", "\u{798E}", "\u{7A40}", "\u{7A81}", "\u{7BC0}", "\u{7E09}", "\u{7E41}",
+    "\u{7F72}", "\u{8005}", "\u{81ED}", "\u{8279}", "\u{8457}", "\u{8910}", "\u{8996}", "\u{8B01}",
+    "\u{8B39}", "\u{8CD3}", "\u{8D08}", "\u{8FB6}", "\u{96E3}", "\u{97FF}", "\u{983B}", "\u{6075}",
+    "\u{242EE}", "\u{8218}", "\u{4E26}", "\u{51B5}", "\u{5168}", "\u{4F80}", "\u{5145}", "\u{5180}",
+    "\u{52C7}", "\u{52FA}", "\u{5555}", "\u{5599}", "\u{55E2}", "\u{58B3}", "\u{5944}", "\u{5954}",
+    "\u{5A62}", "\u{5B28}", "\u{5ED2}", "\u{5ED9}", "\u{5F69}", "\u{5FAD}", "\u{60D8}", "\u{614E}",
+    "\u{6108}", "\u{6160}", "\u{6234}", "\u{63C4}", "\u{641C}", "\u{6452}", "\u{6556}", "\u{671B}",
+    "\u{6756}", "\u{6EDB}", "\u{6ECB}", "\u{701E}", "\u{77A7}", "\u{7235}", "\u{72AF}", "\u{7471}",
+    "\u{7506}", "\u{753B}", "\u{761D}", "\u{761F}", "\u{76DB}", "\u{76F4}", "\u{774A}", "\u{7740}",
+    "\u{78CC}", "\u{7AB1}", "\u{7C7B}", "\u{7D5B}", "\u{7F3E}", "\u{8352}", "\u{83EF}", "\u{8779}",
+    "\u{8941}", "\u{8986}", "\u{8ABF}", "\u{8ACB}", "\u{8AED}", "\u{8B8A}", "\u{8F38}", "\u{9072}",
+    "\u{9199}", "\u{9276}", "\u{967C}", "\u{97DB}", "\u{980B}", "\u{9B12}", "\u{2284A}",
+    "\u{22844}", "\u{233D5}", "\u{3B9D}", "\u{4018}", "\u{4039}", "\u{25249}", "\u{25CD0}",
+    "\u{27ED3}", "\u{9F43}", "\u{9F8E}", "\u{66}\u{66}", "\u{66}\u{69}", "\u{66}\u{6C}",
+    "\u{66}\u{66}\u{69}", "\u{66}\u{66}\u{6C}", "\u{73}\u{74}", "\u{574}\u{576}", "\u{574}\u{565}",
+    "\u{574}\u{56B}", "\u{57E}\u{576}", "\u{574}\u{56D}", "\u{5D9}\u{5B4}", "\u{5F2}\u{5B7}",
+    "\u{5E2}", "\u{5D4}", "\u{5DB}", "\u{5DC}", "\u{5DD}", "\u{5E8}", "\u{5EA}", "\u{5E9}\u{5C1}",
+    "\u{5E9}\u{5C2}", "\u{5E9}\u{5BC}\u{5C1}", "\u{5E9}\u{5BC}\u{5C2}", "\u{5D0}\u{5B7}",
+    "\u{5D0}\u{5B8}", "\u{5D0}\u{5BC}", "\u{5D1}\u{5BC}", "\u{5D2}\u{5BC}", "\u{5D3}\u{5BC}",
+    "\u{5D4}\u{5BC}", "\u{5D5}\u{5BC}", "\u{5D6}\u{5BC}", "\u{5D8}\u{5BC}", "\u{5D9}\u{5BC}",
+    "\u{5DA}\u{5BC}", "\u{5DB}\u{5BC}", "\u{5DC}\u{5BC}", "\u{5DE}\u{5BC}", "\u{5E0}\u{5BC}",
+    "\u{5E1}\u{5BC}", "\u{5E3}\u{5BC}", "\u{5E4}\u{5BC}", "\u{5E6}\u{5BC}", "\u{5E7}\u{5BC}",
+    "\u{5E8}\u{5BC}", "\u{5E9}\u{5BC}", "\u{5EA}\u{5BC}", "\u{5D5}\u{5B9}", "\u{5D1}\u{5BF}",
+    "\u{5DB}\u{5BF}", "\u{5E4}\u{5BF}", "\u{5D0}\u{5DC}", "\u{671}", "\u{67B}", "\u{67E}",
+    "\u{680}", "\u{67A}", "\u{67F}", "\u{679}", "\u{6A4}", "\u{6A6}", "\u{684}", "\u{683}",
+    "\u{686}", "\u{687}", "\u{68D}", "\u{68C}", "\u{68E}", "\u{688}", "\u{698}", "\u{691}",
+    "\u{6A9}", "\u{6AF}", "\u{6B3}", "\u{6B1}", "\u{6BA}", "\u{6BB}", "\u{6C0}", "\u{6C1}",
+    "\u{6BE}", "\u{6D2}", "\u{6D3}", "\u{6AD}", "\u{6C7}", "\u{6C6}", "\u{6C8}", "\u{6CB}",
+    "\u{6C5}", "\u{6C9}", "\u{6D0}", "\u{649}", "\u{626}\u{627}", "\u{626}\u{6D5}",
+    "\u{626}\u{648}", "\u{626}\u{6C7}", "\u{626}\u{6C6}", "\u{626}\u{6C8}", "\u{626}\u{6D0}",
+    "\u{626}\u{649}", "\u{6CC}", "\u{626}\u{62C}", "\u{626}\u{62D}", "\u{626}\u{645}",
+    "\u{626}\u{64A}", "\u{628}\u{62C}", "\u{628}\u{62D}", "\u{628}\u{62E}", "\u{628}\u{645}",
+    "\u{628}\u{649}", "\u{628}\u{64A}", "\u{62A}\u{62C}", "\u{62A}\u{62D}", "\u{62A}\u{62E}",
+    "\u{62A}\u{645}", "\u{62A}\u{649}", "\u{62A}\u{64A}", "\u{62B}\u{62C}", "\u{62B}\u{645}",
+    "\u{62B}\u{649}", "\u{62B}\u{64A}", "\u{62C}\u{62D}", "\u{62C}\u{645}", "\u{62D}\u{62C}",
+    "\u{62D}\u{645}", "\u{62E}\u{62C}", "\u{62E}\u{62D}", "\u{62E}\u{645}", "\u{633}\u{62C}",
+    "\u{633}\u{62D}", "\u{633}\u{62E}", "\u{633}\u{645}", "\u{635}\u{62D}", "\u{635}\u{645}",
+    "\u{636}\u{62C}", "\u{636}\u{62D}", "\u{636}\u{62E}", "\u{636}\u{645}", "\u{637}\u{62D}",
+    "\u{637}\u{645}", "\u{638}\u{645}", "\u{639}\u{62C}", "\u{639}\u{645}", "\u{63A}\u{62C}",
+    "\u{63A}\u{645}", "\u{641}\u{62C}", "\u{641}\u{62D}", "\u{641}\u{62E}", "\u{641}\u{645}",
+    "\u{641}\u{649}", "\u{641}\u{64A}", "\u{642}\u{62D}", "\u{642}\u{645}", "\u{642}\u{649}",
+    "\u{642}\u{64A}", "\u{643}\u{627}", "\u{643}\u{62C}", "\u{643}\u{62D}", "\u{643}\u{62E}",
+    "\u{643}\u{644}", "\u{643}\u{645}", "\u{643}\u{649}", "\u{643}\u{64A}", "\u{644}\u{62C}",
+    "\u{644}\u{62D}", "\u{644}\u{62E}", "\u{644}\u{645}", "\u{644}\u{649}", "\u{644}\u{64A}",
+    "\u{645}\u{62C}", "\u{645}\u{62D}", "\u{645}\u{62E}", "\u{645}\u{645}", "\u{645}\u{649}",
+    "\u{645}\u{64A}", "\u{646}\u{62C}", "\u{646}\u{62D}", "\u{646}\u{62E}", "\u{646}\u{645}",
+    "\u{646}\u{649}", "\u{646}\u{64A}", "\u{647}\u{62C}", "\u{647}\u{645}", "\u{647}\u{649}",
+    "\u{647}\u{64A}", "\u{64A}\u{62C}", "\u{64A}\u{62D}", "\u{64A}\u{62E}", "\u{64A}\u{645}",
+    "\u{64A}\u{649}", "\u{64A}\u{64A}", "\u{630}\u{670}", "\u{631}\u{670}", "\u{649}\u{670}",
+    "\u{20}\u{64C}\u{651}", "\u{20}\u{64D}\u{651}", "\u{20}\u{64E}\u{651}", "\u{20}\u{64F}\u{651}",
+    "\u{20}\u{650}\u{651}", "\u{20}\u{651}\u{670}", "\u{626}\u{631}", "\u{626}\u{632}",
+    "\u{626}\u{646}", "\u{628}\u{631}", "\u{628}\u{632}", "\u{628}\u{646}", "\u{62A}\u{631}",
+    "\u{62A}\u{632}", "\u{62A}\u{646}", "\u{62B}\u{631}", "\u{62B}\u{632}", "\u{62B}\u{646}",
+    "\u{645}\u{627}", "\u{646}\u{631}", "\u{646}\u{632}", "\u{646}\u{646}", "\u{64A}\u{631}",
+    "\u{64A}\u{632}", "\u{64A}\u{646}", "\u{626}\u{62E}", "\u{626}\u{647}", "\u{628}\u{647}",
+    "\u{62A}\u{647}", "\u{635}\u{62E}", "\u{644}\u{647}", "\u{646}\u{647}", "\u{647}\u{670}",
+    "\u{64A}\u{647}", "\u{62B}\u{647}", "\u{633}\u{647}", "\u{634}\u{645}", "\u{634}\u{647}",
+    "\u{640}\u{64E}\u{651}", "\u{640}\u{64F}\u{651}", "\u{640}\u{650}\u{651}", "\u{637}\u{649}",
+    "\u{637}\u{64A}", "\u{639}\u{649}", "\u{639}\u{64A}", "\u{63A}\u{649}", "\u{63A}\u{64A}",
+    "\u{633}\u{649}", "\u{633}\u{64A}", "\u{634}\u{649}", "\u{634}\u{64A}", "\u{62D}\u{649}",
+    "\u{62D}\u{64A}", "\u{62C}\u{649}", "\u{62C}\u{64A}", "\u{62E}\u{649}", "\u{62E}\u{64A}",
+    "\u{635}\u{649}", "\u{635}\u{64A}", "\u{636}\u{649}", "\u{636}\u{64A}", "\u{634}\u{62C}",
+    "\u{634}\u{62D}", "\u{634}\u{62E}", "\u{634}\u{631}", "\u{633}\u{631}", "\u{635}\u{631}",
+    "\u{636}\u{631}", "\u{627}\u{64B}", "\u{62A}\u{62C}\u{645}", "\u{62A}\u{62D}\u{62C}",
+    "\u{62A}\u{62D}\u{645}", "\u{62A}\u{62E}\u{645}", "\u{62A}\u{645}\u{62C}",
+    "\u{62A}\u{645}\u{62D}", "\u{62A}\u{645}\u{62E}", "\u{62C}\u{645}\u{62D}",
+    "\u{62D}\u{645}\u{64A}", "\u{62D}\u{645}\u{649}", "\u{633}\u{62D}\u{62C}",
+    "\u{633}\u{62C}\u{62D}", "\u{633}\u{62C}\u{649}", "\u{633}\u{645}\u{62D}",
+    "\u{633}\u{645}\u{62C}", "\u{633}\u{645}\u{645}", "\u{635}\u{62D}\u{62D}",
+    "\u{635}\u{645}\u{645}", "\u{634}\u{62D}\u{645}", "\u{634}\u{62C}\u{64A}",
+    "\u{634}\u{645}\u{62E}", "\u{634}\u{645}\u{645}", "\u{636}\u{62D}\u{649}",
+    "\u{636}\u{62E}\u{645}", "\u{637}\u{645}\u{62D}", "\u{637}\u{645}\u{645}",
+    "\u{637}\u{645}\u{64A}", "\u{639}\u{62C}\u{645}", "\u{639}\u{645}\u{645}",
+    "\u{639}\u{645}\u{649}", "\u{63A}\u{645}\u{645}", "\u{63A}\u{645}\u{64A}",
+    "\u{63A}\u{645}\u{649}", "\u{641}\u{62E}\u{645}", "\u{642}\u{645}\u{62D}",
+    "\u{642}\u{645}\u{645}", "\u{644}\u{62D}\u{645}", "\u{644}\u{62D}\u{64A}",
+    "\u{644}\u{62D}\u{649}", "\u{644}\u{62C}\u{62C}", "\u{644}\u{62E}\u{645}",
+    "\u{644}\u{645}\u{62D}", "\u{645}\u{62D}\u{62C}", "\u{645}\u{62D}\u{645}",
+    "\u{645}\u{62D}\u{64A}", "\u{645}\u{62C}\u{62D}", "\u{645}\u{62C}\u{645}",
+    "\u{645}\u{62E}\u{62C}", "\u{645}\u{62E}\u{645}", "\u{645}\u{62C}\u{62E}",
+    "\u{647}\u{645}\u{62C}", "\u{647}\u{645}\u{645}", "\u{646}\u{62D}\u{645}",
+    "\u{646}\u{62D}\u{649}", "\u{646}\u{62C}\u{645}", "\u{646}\u{62C}\u{649}",
+    "\u{646}\u{645}\u{64A}", "\u{646}\u{645}\u{649}", "\u{64A}\u{645}\u{645}",
+    "\u{628}\u{62E}\u{64A}", "\u{62A}\u{62C}\u{64A}", "\u{62A}\u{62C}\u{649}",
+    "\u{62A}\u{62E}\u{64A}", "\u{62A}\u{62E}\u{649}", "\u{62A}\u{645}\u{64A}",
+    "\u{62A}\u{645}\u{649}", "\u{62C}\u{645}\u{64A}", "\u{62C}\u{62D}\u{649}",
+    "\u{62C}\u{645}\u{649}", "\u{633}\u{62E}\u{649}", "\u{635}\u{62D}\u{64A}",
+    "\u{634}\u{62D}\u{64A}", "\u{636}\u{62D}\u{64A}", "\u{644}\u{62C}\u{64A}",
+    "\u{644}\u{645}\u{64A}", "\u{64A}\u{62D}\u{64A}", "\u{64A}\u{62C}\u{64A}",
+    "\u{64A}\u{645}\u{64A}", "\u{645}\u{645}\u{64A}", "\u{642}\u{645}\u{64A}",
+    "\u{646}\u{62D}\u{64A}", "\u{639}\u{645}\u{64A}", "\u{643}\u{645}\u{64A}",
+    "\u{646}\u{62C}\u{62D}", "\u{645}\u{62E}\u{64A}", "\u{644}\u{62C}\u{645}",
+    "\u{643}\u{645}\u{645}", "\u{62C}\u{62D}\u{64A}", "\u{62D}\u{62C}\u{64A}",
+    "\u{645}\u{62C}\u{64A}", "\u{641}\u{645}\u{64A}", "\u{628}\u{62D}\u{64A}",
+    "\u{633}\u{62E}\u{64A}", "\u{646}\u{62C}\u{64A}", "\u{635}\u{644}\u{6D2}",
+    "\u{642}\u{644}\u{6D2}", "\u{627}\u{644}\u{644}\u{647}", "\u{627}\u{643}\u{628}\u{631}",
+    "\u{645}\u{62D}\u{645}\u{62F}", "\u{635}\u{644}\u{639}\u{645}", "\u{631}\u{633}\u{648}\u{644}",
+    "\u{639}\u{644}\u{64A}\u{647}", "\u{648}\u{633}\u{644}\u{645}", "\u{635}\u{644}\u{649}",
+    "\u{635}\u{644}\u{649}\u{20}\u{627}\u{644}\u{644}\u{647}\u{20}\u{639}\u{644}\u{64A}\u{647}\u{20}\u{648}\u{633}\u{644}\u{645}",
+    "\u{62C}\u{644}\u{20}\u{62C}\u{644}\u{627}\u{644}\u{647}", "\u{631}\u{6CC}\u{627}\u{644}",
+    "\u{2C}", "\u{3001}", "\u{3002}", "\u{3A}", "\u{21}", "\u{3F}", "\u{3016}", "\u{3017}",
+    "\u{2014}", "\u{2013}", "\u{5F}", "\u{7B}", "\u{7D}", "\u{3014}", "\u{3015}", "\u{3010}",
+    "\u{3011}", "\u{300A}", "\u{300B}", "\u{300C}", "\u{300D}", "\u{300E}", "\u{300F}", "\u{5B}",
+    "\u{5D}", "\u{23}", "\u{26}", "\u{2A}", "\u{2D}", "\u{3C}", "\u{3E}", "\u{5C}", "\u{24}",
+    "\u{25}", "\u{40}", "\u{20}\u{64B}", "\u{640}\u{64B}", "\u{20}\u{64C}", "\u{20}\u{64D}",
+    "\u{20}\u{64E}", "\u{640}\u{64E}", "\u{20}\u{64F}", "\u{640}\u{64F}", "\u{20}\u{650}",
+    "\u{640}\u{650}", "\u{20}\u{651}", "\u{640}\u{651}", "\u{20}\u{652}", "\u{640}\u{652}",
+    "\u{621}", "\u{622}", "\u{623}", "\u{624}", "\u{625}", "\u{626}", "\u{627}", "\u{628}",
+    "\u{629}", "\u{62A}", "\u{62B}", "\u{62C}", "\u{62D}", "\u{62E}", "\u{62F}", "\u{630}",
+    "\u{631}", "\u{632}", "\u{633}", "\u{634}", "\u{635}", "\u{636}", "\u{637}", "\u{638}",
+    "\u{639}", "\u{63A}", "\u{641}", "\u{642}", "\u{643}", "\u{644}", "\u{645}", "\u{646}",
+    "\u{647}", "\u{648}", "\u{64A}", "\u{644}\u{622}", "\u{644}\u{623}", "\u{644}\u{625}",
+    "\u{644}\u{627}", "\u{22}", "\u{27}", "\u{2F}", "\u{5E}", "\u{7C}", "\u{7E}", "\u{2985}",
+    "\u{2986}", "\u{30FB}", "\u{30A1}", "\u{30A3}", "\u{30A5}", "\u{30A7}", "\u{30A9}", "\u{30E3}",
+    "\u{30E5}", "\u{30E7}", "\u{30C3}", "\u{30FC}", "\u{30F3}", "\u{3099}", "\u{309A}", "\u{A2}",
+    "\u{A3}", "\u{AC}", "\u{A6}", "\u{A5}", "\u{20A9}", "\u{2502}", "\u{2190}", "\u{2191}",
+    "\u{2192}", "\u{2193}", "\u{25A0}", "\u{25CB}", "\u{10428}", "\u{10429}", "\u{1042A}",
+    "\u{1042B}", "\u{1042C}", "\u{1042D}", "\u{1042E}", "\u{1042F}", "\u{10430}", "\u{10431}",
+    "\u{10432}", "\u{10433}", "\u{10434}", "\u{10435}", "\u{10436}", "\u{10437}", "\u{10438}",
+    "\u{10439}", "\u{1043A}", "\u{1043B}", "\u{1043C}", "\u{1043D}", "\u{1043E}", "\u{1043F}",
+    "\u{10440}", "\u{10441}", "\u{10442}", "\u{10443}", "\u{10444}", "\u{10445}", "\u{10446}",
+    "\u{10447}", "\u{10448}", "\u{10449}", "\u{1044A}", "\u{1044B}", "\u{1044C}", "\u{1044D}",
+    "\u{1044E}", "\u{1044F}", "\u{104D8}", "\u{104D9}", "\u{104DA}", "\u{104DB}", "\u{104DC}",
+    "\u{104DD}", "\u{104DE}", "\u{104DF}", "\u{104E0}", "\u{104E1}", "\u{104E2}", "\u{104E3}",
+    "\u{104E4}", "\u{104E5}", "\u{104E6}", "\u{104E7}", "\u{104E8}", "\u{104E9}", "\u{104EA}",
+    "\u{104EB}", "\u{104EC}", "\u{104ED}", "\u{104EE}", "\u{104EF}", "\u{104F0}", "\u{104F1}",
+    "\u{104F2}", "\u{104F3}", "\u{104F4}", "\u{104F5}", "\u{104F6}", "\u{104F7}", "\u{104F8}",
+    "\u{104F9}", "\u{104FA}", "\u{104FB}", "\u{10597}", "\u{10598}", "\u{10599}", "\u{1059A}",
+    "\u{1059B}", "\u{1059C}", "\u{1059D}", "\u{1059E}", "\u{1059F}", "\u{105A0}", "\u{105A1}",
+    "\u{105A3}", "\u{105A4}", "\u{105A5}", "\u{105A6}", "\u{105A7}", "\u{105A8}", "\u{105A9}",
+    "\u{105AA}", "\u{105AB}", "\u{105AC}", "\u{105AD}", "\u{105AE}", "\u{105AF}", "\u{105B0}",
+    "\u{105B1}", "\u{105B3}", "\u{105B4}", "\u{105B5}", "\u{105B6}", "\u{105B7}", "\u{105B8}",
+    "\u{105B9}", "\u{105BB}", "\u{105BC}", "\u{2D0}", "\u{2D1}", "\u{299}", "\u{2A3}", "\u{AB66}",
+    "\u{2A5}", "\u{2A4}", "\u{1D91}", "\u{258}", "\u{25E}", "\u{2A9}", "\u{262}", "\u{29B}",
+    "\u{29C}", "\u{267}", "\u{284}", "\u{2AA}", "\u{2AB}", "\u{1DF04}", "\u{A78E}", "\u{26E}",
+    "\u{1DF05}", "\u{28E}", "\u{1DF06}", "\u{276}", "\u{277}", "\u{27A}", "\u{1DF08}", "\u{27E}",
+    "\u{2A8}", "\u{2A6}", "\u{AB67}", "\u{2A7}", "\u{2C71}", "\u{28F}", "\u{2A1}", "\u{2A2}",
+    "\u{298}", "\u{1C0}", "\u{1C1}", "\u{1C2}", "\u{1DF0A}", "\u{1DF1E}", "\u{10CC0}", "\u{10CC1}",
+    "\u{10CC2}", "\u{10CC3}", "\u{10CC4}", "\u{10CC5}", "\u{10CC6}", "\u{10CC7}", "\u{10CC8}",
+    "\u{10CC9}", "\u{10CCA}", "\u{10CCB}", "\u{10CCC}", "\u{10CCD}", "\u{10CCE}", "\u{10CCF}",
+    "\u{10CD0}", "\u{10CD1}", "\u{10CD2}", "\u{10CD3}", "\u{10CD4}", "\u{10CD5}", "\u{10CD6}",
+    "\u{10CD7}", "\u{10CD8}", "\u{10CD9}", "\u{10CDA}", "\u{10CDB}", "\u{10CDC}", "\u{10CDD}",
+    "\u{10CDE}", "\u{10CDF}", "\u{10CE0}", "\u{10CE1}", "\u{10CE2}", "\u{10CE3}", "\u{10CE4}",
+    "\u{10CE5}", "\u{10CE6}", "\u{10CE7}", "\u{10CE8}", "\u{10CE9}", "\u{10CEA}", "\u{10CEB}",
+    "\u{10CEC}", "\u{10CED}", "\u{10CEE}", "\u{10CEF}", "\u{10CF0}", "\u{10CF1}", "\u{10CF2}",
+    "\u{10D70}", "\u{10D71}", "\u{10D72}", "\u{10D73}", "\u{10D74}", "\u{10D75}", "\u{10D76}",
+    "\u{10D77}", "\u{10D78}", "\u{10D79}", "\u{10D7A}", "\u{10D7B}", "\u{10D7C}", "\u{10D7D}",
+    "\u{10D7E}", "\u{10D7F}", "\u{10D80}", "\u{10D81}", "\u{10D82}", "\u{10D83}", "\u{10D84}",
+    "\u{10D85}", "\u{118C0}", "\u{118C1}", "\u{118C2}", "\u{118C3}", "\u{118C4}", "\u{118C5}",
+    "\u{118C6}", "\u{118C7}", "\u{118C8}", "\u{118C9}", "\u{118CA}", "\u{118CB}", "\u{118CC}",
+    "\u{118CD}", "\u{118CE}", "\u{118CF}", "\u{118D0}", "\u{118D1}", "\u{118D2}", "\u{118D3}",
+    "\u{118D4}", "\u{118D5}", "\u{118D6}", "\u{118D7}", "\u{118D8}", "\u{118D9}", "\u{118DA}",
+    "\u{118DB}", "\u{118DC}", "\u{118DD}", "\u{118DE}", "\u{118DF}", "\u{16E60}", "\u{16E61}",
+    "\u{16E62}", "\u{16E63}", "\u{16E64}", "\u{16E65}", "\u{16E66}", "\u{16E67}", "\u{16E68}",
+    "\u{16E69}", "\u{16E6A}", "\u{16E6B}", "\u{16E6C}", "\u{16E6D}", "\u{16E6E}", "\u{16E6F}",
+    "\u{16E70}", "\u{16E71}", "\u{16E72}", "\u{16E73}", "\u{16E74}", "\u{16E75}", "\u{16E76}",
+    "\u{16E77}", "\u{16E78}", "\u{16E79}", "\u{16E7A}", "\u{16E7B}", "\u{16E7C}", "\u{16E7D}",
+    "\u{16E7E}", "\u{16E7F}", "\u{16EBB}", "\u{16EBC}", "\u{16EBD}", "\u{16EBE}", "\u{16EBF}",
+    "\u{16EC0}", "\u{16EC1}", "\u{16EC2}", "\u{16EC3}", "\u{16EC4}", "\u{16EC5}", "\u{16EC6}",
+    "\u{16EC7}", "\u{16EC8}", "\u{16EC9}", "\u{16ECA}", "\u{16ECB}", "\u{16ECC}", "\u{16ECD}",
+    "\u{16ECE}", "\u{16ECF}", "\u{16ED0}", "\u{16ED1}", "\u{16ED2}", "\u{16ED3}",
+    "\u{1D157}\u{1D165}", "\u{1D158}\u{1D165}", "\u{1D158}\u{1D165}\u{1D16E}",
+    "\u{1D158}\u{1D165}\u{1D16F}", "\u{1D158}\u{1D165}\u{1D170}", "\u{1D158}\u{1D165}\u{1D171}",
+    "\u{1D158}\u{1D165}\u{1D172}", "\u{1D1B9}\u{1D165}", "\u{1D1BA}\u{1D165}",
+    "\u{1D1B9}\u{1D165}\u{1D16E}", "\u{1D1BA}\u{1D165}\u{1D16E}", "\u{1D1B9}\u{1D165}\u{1D16F}",
+    "\u{1D1BA}\u{1D165}\u{1D16F}", "\u{131}", "\u{237}", "\u{2207}", "\u{2202}", "\u{1E922}",
+    "\u{1E923}", "\u{1E924}", "\u{1E925}", "\u{1E926}", "\u{1E927}", "\u{1E928}", "\u{1E929}",
+    "\u{1E92A}", "\u{1E92B}", "\u{1E92C}", "\u{1E92D}", "\u{1E92E}", "\u{1E92F}", "\u{1E930}",
+    "\u{1E931}", "\u{1E932}", "\u{1E933}", "\u{1E934}", "\u{1E935}", "\u{1E936}", "\u{1E937}",
+    "\u{1E938}", "\u{1E939}", "\u{1E93A}", "\u{1E93B}", "\u{1E93C}", "\u{1E93D}", "\u{1E93E}",
+    "\u{1E93F}", "\u{1E940}", "\u{1E941}", "\u{1E942}", "\u{1E943}", "\u{66E}", "\u{6A1}",
+    "\u{66F}", "\u{30}\u{2E}", "\u{30}\u{2C}", "\u{31}\u{2C}", "\u{32}\u{2C}", "\u{33}\u{2C}",
+    "\u{34}\u{2C}", "\u{35}\u{2C}", "\u{36}\u{2C}", "\u{37}\u{2C}", "\u{38}\u{2C}", "\u{39}\u{2C}",
+    "\u{3014}\u{73}\u{3015}", "\u{77}\u{7A}", "\u{68}\u{76}", "\u{73}\u{64}", "\u{70}\u{70}\u{76}",
+    "\u{77}\u{63}", "\u{6D}\u{63}", "\u{6D}\u{64}", "\u{6D}\u{72}", "\u{64}\u{6A}",
+    "\u{307B}\u{304B}", "\u{30B3}\u{30B3}", "\u{5B57}", "\u{53CC}", "\u{30C7}", "\u{591A}",
+    "\u{89E3}", "\u{4EA4}", "\u{6620}", "\u{7121}", "\u{524D}", "\u{5F8C}", "\u{518D}", "\u{65B0}",
+    "\u{521D}", "\u{7D42}", "\u{8CA9}", "\u{58F0}", "\u{5439}", "\u{6F14}", "\u{6295}", "\u{6355}",
+    "\u{904A}", "\u{6307}", "\u{6253}", "\u{7981}", "\u{7A7A}", "\u{5408}", "\u{6E80}", "\u{7533}",
+    "\u{5272}", "\u{55B6}", "\u{914D}", "\u{3014}\u{672C}\u{3015}", "\u{3014}\u{4E09}\u{3015}",
+    "\u{3014}\u{4E8C}\u{3015}", "\u{3014}\u{5B89}\u{3015}", "\u{3014}\u{70B9}\u{3015}",
+    "\u{3014}\u{6253}\u{3015}", "\u{3014}\u{76D7}\u{3015}", "\u{3014}\u{52DD}\u{3015}",
+    "\u{3014}\u{6557}\u{3015}", "\u{5F97}", "\u{53EF}", "\u{4E3D}", "\u{4E38}", "\u{4E41}",
+    "\u{20122}", "\u{4F60}", "\u{4FBB}", "\u{5002}", "\u{507A}", "\u{5099}", "\u{50CF}", "\u{349E}",
+    "\u{2063A}", "\u{5154}", "\u{5164}", "\u{5177}", "\u{2051C}", "\u{34B9}", "\u{5167}",
+    "\u{2054B}", "\u{5197}", "\u{51A4}", "\u{4ECC}", "\u{51AC}", "\u{291DF}", "\u{5203}",
+    "\u{34DF}", "\u{523B}", "\u{5246}", "\u{5277}", "\u{3515}", "\u{5305}", "\u{5306}", "\u{5349}",
+    "\u{535A}", "\u{5373}", "\u{537D}", "\u{537F}", "\u{20A2C}", "\u{7070}", "\u{53CA}", "\u{53DF}",
+    "\u{20B63}", "\u{53EB}", "\u{53F1}", "\u{5406}", "\u{549E}", "\u{5438}", "\u{5448}", "\u{5468}",
+    "\u{54A2}", "\u{54F6}", "\u{5510}", "\u{5553}", "\u{5563}", "\u{5584}", "\u{55AB}", "\u{55B3}",
+    "\u{55C2}", "\u{5716}", "\u{5717}", "\u{5651}", "\u{5674}", "\u{58EE}", "\u{57CE}", "\u{57F4}",
+    "\u{580D}", "\u{578B}", "\u{5832}", "\u{5831}", "\u{58AC}", "\u{214E4}", "\u{58F2}", "\u{58F7}",
+    "\u{5906}", "\u{5922}", "\u{5962}", "\u{216A8}", "\u{216EA}", "\u{59EC}", "\u{5A1B}",
+    "\u{5A27}", "\u{59D8}", "\u{5A66}", "\u{36EE}", "\u{36FC}", "\u{5B08}", "\u{5B3E}", "\u{219C8}",
+    "\u{5BC3}", "\u{5BD8}", "\u{5BF3}", "\u{21B18}", "\u{5BFF}", "\u{5C06}", "\u{5F53}", "\u{3781}",
+    "\u{5C60}", "\u{5CC0}", "\u{5C8D}", "\u{21DE4}", "\u{5D43}", "\u{21DE6}", "\u{5D6E}",
+    "\u{5D6B}", "\u{5D7C}", "\u{5DE1}", "\u{5DE2}", "\u{382F}", "\u{5DFD}", "\u{5E28}", "\u{5E3D}",
+    "\u{5E69}", "\u{3862}", "\u{22183}", "\u{387C}", "\u{5EB0}", "\u{5EB3}", "\u{5EB6}",
+    "\u{2A392}", "\u{22331}", "\u{8201}", "\u{5F22}", "\u{38C7}", "\u{232B8}", "\u{261DA}",
+    "\u{5F62}", "\u{5F6B}", "\u{38E3}", "\u{5F9A}", "\u{5FCD}", "\u{5FD7}", "\u{5FF9}", "\u{6081}",
+    "\u{393A}", "\u{391C}", "\u{226D4}", "\u{60C7}", "\u{6148}", "\u{614C}", "\u{617A}", "\u{61B2}",
+    "\u{61A4}", "\u{61AF}", "\u{61DE}", "\u{6210}", "\u{621B}", "\u{625D}", "\u{62B1}", "\u{62D4}",
+    "\u{6350}", "\u{22B0C}", "\u{633D}", "\u{62FC}", "\u{6368}", "\u{6383}", "\u{63E4}",
+    "\u{22BF1}", "\u{6422}", "\u{63C5}", "\u{63A9}", "\u{3A2E}", "\u{6469}", "\u{647E}", "\u{649D}",
+    "\u{6477}", "\u{3A6C}", "\u{656C}", "\u{2300A}", "\u{65E3}", "\u{66F8}", "\u{6649}", "\u{3B19}",
+    "\u{3B08}", "\u{3AE4}", "\u{5192}", "\u{5195}", "\u{6700}", "\u{669C}", "\u{80AD}", "\u{43D9}",
+    "\u{6721}", "\u{675E}", "\u{6753}", "\u{233C3}", "\u{3B49}", "\u{67FA}", "\u{6785}", "\u{6852}",
+    "\u{2346D}", "\u{688E}", "\u{681F}", "\u{6914}", "\u{6942}", "\u{69A3}", "\u{69EA}", "\u{6AA8}",
+    "\u{236A3}", "\u{6ADB}", "\u{3C18}", "\u{6B21}", "\u{238A7}", "\u{6B54}", "\u{3C4E}",
+    "\u{6B72}", "\u{6B9F}", "\u{6BBB}", "\u{23A8D}", "\u{21D0B}", "\u{23AFA}", "\u{6C4E}",
+    "\u{23CBC}", "\u{6CBF}", "\u{6CCD}", "\u{6C67}", "\u{6D16}", "\u{6D3E}", "\u{6D69}", "\u{6D78}",
+    "\u{6D85}", "\u{23D1E}", "\u{6D34}", "\u{6E2F}", "\u{6E6E}", "\u{3D33}", "\u{6EC7}",
+    "\u{23ED1}", "\u{6DF9}", "\u{6F6E}", "\u{23F5E}", "\u{23F8E}", "\u{6FC6}", "\u{7039}",
+    "\u{701B}", "\u{3D96}", "\u{704A}", "\u{707D}", "\u{7077}", "\u{70AD}", "\u{20525}", "\u{7145}",
+    "\u{24263}", "\u{719C}", "\u{243AB}", "\u{7228}", "\u{7250}", "\u{24608}", "\u{7280}",
+    "\u{7295}", "\u{24735}", "\u{24814}", "\u{737A}", "\u{738B}", "\u{3EAC}", "\u{73A5}",
+    "\u{3EB8}", "\u{7447}", "\u{745C}", "\u{7485}", "\u{74CA}", "\u{3F1B}", "\u{7524}", "\u{24C36}",
+    "\u{753E}", "\u{24C92}", "\u{2219F}", "\u{7610}", "\u{24FA1}", "\u{24FB8}", "\u{25044}",
+    "\u{3FFC}", "\u{4008}", "\u{250F3}", "\u{250F2}", "\u{25119}", "\u{25133}", "\u{771E}",
+    "\u{771F}", "\u{778B}", "\u{4046}", "\u{4096}", "\u{2541D}", "\u{784E}", "\u{40E3}",
+    "\u{25626}", "\u{2569A}", "\u{256C5}", "\u{79EB}", "\u{412F}", "\u{7A4A}", "\u{7A4F}",
+    "\u{2597C}", "\u{25AA7}", "\u{7AEE}", "\u{4202}", "\u{25BAB}", "\u{7BC6}", "\u{7BC9}",
+    "\u{4227}", "\u{25C80}", "\u{7CD2}", "\u{42A0}", "\u{7CE8}", "\u{7CE3}", "\u{7D00}",
+    "\u{25F86}", "\u{7D63}", "\u{4301}", "\u{7DC7}", "\u{7E02}", "\u{7E45}", "\u{4334}",
+    "\u{26228}", "\u{26247}", "\u{4359}", "\u{262D9}", "\u{7F7A}", "\u{2633E}", "\u{7F95}",
+    "\u{7FFA}", "\u{264DA}", "\u{26523}", "\u{8060}", "\u{265A8}", "\u{8070}", "\u{2335F}",
+    "\u{43D5}", "\u{80B2}", "\u{8103}", "\u{440B}", "\u{813E}", "\u{5AB5}", "\u{267A7}",
+    "\u{267B5}", "\u{23393}", "\u{2339C}", "\u{8204}", "\u{8F9E}", "\u{446B}", "\u{8291}",
+    "\u{828B}", "\u{829D}", "\u{52B3}", "\u{82B1}", "\u{82B3}", "\u{82BD}", "\u{82E6}", "\u{26B3C}",
+    "\u{831D}", "\u{8363}", "\u{83AD}", "\u{8323}", "\u{83BD}", "\u{83E7}", "\u{8353}", "\u{83CA}",
+    "\u{83CC}", "\u{83DC}", "\u{26C36}", "\u{26D6B}", "\u{26CD5}", "\u{452B}", "\u{84F1}",
+    "\u{84F3}", "\u{8516}", "\u{273CA}", "\u{8564}", "\u{26F2C}", "\u{455D}", "\u{4561}",
+    "\u{26FB1}", "\u{270D2}", "\u{456B}", "\u{8650}", "\u{8667}", "\u{8669}", "\u{86A9}",
+    "\u{8688}", "\u{870E}", "\u{86E2}", "\u{8728}", "\u{876B}", "\u{8786}", "\u{45D7}", "\u{87E1}",
+    "\u{8801}", "\u{45F9}", "\u{8860}", "\u{27667}", "\u{88D7}", "\u{88DE}", "\u{4635}", "\u{88FA}",
+    "\u{34BB}", "\u{278AE}", "\u{27966}", "\u{46BE}", "\u{46C7}", "\u{8AA0}", "\u{27CA8}",
+    "\u{8CAB}", "\u{8CC1}", "\u{8D1B}", "\u{8D77}", "\u{27F2F}", "\u{20804}", "\u{8DCB}",
+    "\u{8DBC}", "\u{8DF0}", "\u{208DE}", "\u{8ED4}", "\u{285D2}", "\u{285ED}", "\u{9094}",
+    "\u{90F1}", "\u{9111}", "\u{2872E}", "\u{911B}", "\u{9238}", "\u{92D7}", "\u{92D8}", "\u{927C}",
+    "\u{93F9}", "\u{9415}", "\u{28BFA}", "\u{958B}", "\u{4995}", "\u{95B7}", "\u{28D77}",
+    "\u{49E6}", "\u{96C3}", "\u{5DB2}", "\u{9723}", "\u{29145}", "\u{2921A}", "\u{4A6E}",
+    "\u{4A76}", "\u{97E0}", "\u{2940A}", "\u{4AB2}", "\u{29496}", "\u{9829}", "\u{295B6}",
+    "\u{98E2}", "\u{4B33}", "\u{9929}", "\u{99A7}", "\u{99C2}", "\u{99FE}", "\u{4BCE}", "\u{29B30}",
+    "\u{9C40}", "\u{9CFD}", "\u{4CCE}", "\u{4CED}", "\u{9D67}", "\u{2A0CE}", "\u{4CF8}",
+    "\u{2A105}", "\u{2A20E}", "\u{2A291}", "\u{4D56}", "\u{9EFE}", "\u{9F05}", "\u{9F0F}",
+    "\u{9F16}", "\u{2A600}",
+];
+
+/// The `Canonical_Combining_Class` of every codepoint
+pub(crate) static CANONICAL_COMBINING_CLASS: Utf8Trie<[u8; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 3, 0, 0, 0, 4, 5, 6,
+        7, 0, 8, 9, 10, 0, 11, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 6, 13, 14, 14, 14, 14,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 3, 0, 0, 0, 4, 5, 6, 7, 0, 8, 9,
+            10, 0, 11, 12, 13, 14, 15, 16, 17, 16, 18, 16, 19, 16, 19, 16, 19, 0, 19, 16, 20, 16,
+            19, 21, 19, 0, 22, 23, 24, 25, 26, 27, 28, 29, 30,
+        ],
+        [
+            31, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            34, 0, 0, 35, 0, 0, 36, 0, 37, 0, 0, 0, 38, 39, 40, 41, 42, 43, 44, 45, 46, 0, 0, 47, 0,
+            0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 51, 0, 52, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            53, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 56, 57,
+            0, 0, 0, 0, 58, 0, 0, 59, 60, 61, 62, 63, 0, 0, 64, 65, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 70, 0, 71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 73, 0, 0, 0, 0, 0, 0, 0, 0, 74, 75, 0, 0,
+            0, 0, 76, 77, 0, 78, 79, 0,
+        ],
+        [
+            0, 80, 81, 0, 82, 62, 0, 83, 84, 0, 0, 85, 86, 87, 0, 88, 0, 89, 0, 90, 0, 0, 51, 91,
+            51, 0, 92, 0, 93, 0, 0, 0, 81, 0, 0, 0, 94, 95, 0, 96, 97, 98, 99, 0, 0, 0, 0, 0, 51, 0,
+            0, 0, 0, 100, 101, 0, 0, 0, 0, 0, 0, 102, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 106,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 108, 109, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            111, 0, 112, 0, 105, 0, 0, 0, 0, 0, 113, 114, 0, 0, 0, 0, 0, 0, 0, 115, 0, 0, 0, 116, 0,
+            0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 118, 0, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 6, 7, 8, 4, 4, 4, 4, 9, 4, 4, 4, 4, 10, 4,
+            11, 12, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+    ],
+    leaves: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230,
+            230, 230, 230, 230, 232, 220, 220, 220, 220, 232, 216, 220, 220, 220, 220, 220, 202,
+            202, 220, 220, 220, 220, 202, 202, 220, 220, 220, 220, 220, 220, 220, 220, 220, 220,
+            220, 1, 1, 1, 1, 1, 220, 220, 220, 220, 230, 230, 230,
+        ],
+        [
+            230, 230, 230, 230, 230, 240, 230, 220, 220, 220, 230, 230, 230, 220, 220, 0, 230, 230,
+            230, 220, 220, 220, 220, 230, 232, 220, 220, 230, 233, 234, 234, 233, 234, 234, 233,
+            230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 230, 230, 230, 230, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 230, 230, 230, 230, 220, 230,
+            230, 230, 222, 220, 230, 230, 230, 230, 230, 230, 220, 220, 220, 220, 220, 220, 230,
+            230, 220, 230, 230, 222, 228, 230, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 19, 20, 21,
+            22, 0, 23,
+        ],
+        [
+            0, 24, 25, 0, 230, 220, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 230, 230, 230, 230, 230, 230,
+            30, 31, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 28, 29, 30, 31, 32, 33, 34, 230, 230, 220, 220,
+            230, 230, 230, 230, 230, 220, 230, 230, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 230, 230,
+            230, 230, 230, 0, 0, 230, 230, 230, 230, 220, 230, 0, 0, 230, 230, 0, 220, 230, 230,
+            220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 220, 230, 230, 220, 230,
+            230, 220, 220, 220, 230, 220, 220, 230, 220, 230,
+        ],
+        [
+            230, 230, 220, 230, 220, 230, 220, 230, 220, 230, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 230, 230, 230, 230, 230, 220, 230,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 230, 230, 0,
+            230, 230, 230, 230, 230, 230, 230, 230, 230, 0, 230, 230, 230, 0, 230, 230, 230, 230,
+            230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 220,
+            220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 220, 220,
+            220, 230, 230, 230, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 230, 230, 230, 220, 220, 220, 220, 220, 230,
+            230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 0, 220, 230, 230, 220,
+            230, 230, 220, 230, 230, 230, 220, 220, 220, 27, 28, 29, 230, 230, 230, 220, 230, 230,
+            220, 220, 230, 230, 230, 230, 230,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 7, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 230, 220, 230, 230, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 230, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 84, 91, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 9, 9, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103,
+            103, 9, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 107, 107, 107, 107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 118,
+            118, 9, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 122, 122, 122, 122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 220, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0, 220,
+            0, 216, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 130, 0, 132, 0, 0, 0,
+            0, 0, 130, 130, 130, 130, 0, 0,
+        ],
+        [
+            130, 0, 230, 230, 9, 0, 230, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 9,
+            9, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            230, 230, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 9, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 222,
+            230, 220, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 220, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 230,
+            230, 230, 230, 230, 230, 0, 0, 220,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 230, 230, 230, 220,
+            220, 220, 220, 220, 220, 230, 230, 220, 0, 220,
+        ],
+        [
+            220, 230, 230, 220, 220, 230, 230, 230, 230, 230, 220, 230, 230, 230, 230, 230, 230,
+            230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 220, 0, 0, 230, 230, 230,
+            230, 230, 230, 220, 230, 230, 230, 230, 234, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 220, 230, 230, 230, 230, 230, 230, 230,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 9, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 230, 0, 1, 220, 220, 220, 220,
+            220, 230, 230, 220, 220, 220, 220, 230, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 220, 0, 0,
+            0, 0, 0, 0, 230, 0, 0, 0, 230, 230, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            230, 230, 220, 230, 230, 230, 230, 230, 230, 230, 220, 230, 230, 234, 214, 220, 202,
+            230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230,
+            230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230,
+            230, 230, 230, 232, 228, 228, 220, 218, 230, 233, 220, 230, 220,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 1, 1, 230, 230, 230, 230, 1,
+            1, 1, 230, 230, 0, 0, 0, 0, 230, 0, 0, 0, 1, 1, 230, 220, 230, 1, 1, 220, 220, 220, 220,
+            230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 230, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 9,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230,
+            230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 218, 228, 232, 222, 224, 224, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 8, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 0, 0, 0, 0, 230, 230, 230,
+            230, 230, 230, 230, 230, 230, 230, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 230, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230,
+            230, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 220, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 0, 230, 230, 220, 0, 0,
+            230, 230, 0, 0, 0, 0, 0, 230, 230,
+        ],
+        [
+            0, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 230, 230, 230, 230, 230, 230, 230, 220, 220, 220, 220, 220, 220, 220, 230, 230,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 220, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230,
+            230, 230, 230, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230,
+            1, 220, 0, 0, 0, 0, 9,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 230, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 230, 230, 230, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 230, 230, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            220, 220, 0, 220, 220, 220,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 220, 220, 230, 230, 230, 220, 230, 220, 220, 220, 220, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 230, 220, 230, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 9,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9,
+            7, 0, 0, 0, 0, 0,
+        ],
+        [
+            230, 230, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 9, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 7, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 7, 7, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 230, 230, 230, 230, 230, 0, 0, 0, 230, 230, 230,
+            230, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 9, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 9, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 9, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 7, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 9, 9, 0,
+        ],
+        [
+            0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 7, 0, 9, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 9, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 230, 230, 230, 230,
+            230, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 6, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 216, 216, 1, 1, 1, 0, 0, 0, 226, 216, 216, 216, 216, 216, 0, 0,
+            0, 0, 0, 0, 0, 0, 220, 220, 220, 220, 220,
+        ],
+        [
+            220, 220, 220, 0, 0, 230, 230, 230, 230, 230, 220, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 230, 230, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 230, 230, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            230, 230, 230, 230, 230, 230, 230, 0, 230, 230, 230, 230, 230, 230, 230, 230, 230, 230,
+            230, 230, 230, 230, 230, 230, 230, 0, 0, 230, 230, 230, 230, 230, 230, 230, 0, 230, 230,
+            0, 230, 230, 230, 230, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 230, 230, 230, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 232, 232, 220, 230, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 230, 0, 0, 230, 0, 0, 0, 0, 0, 0, 0, 230, 230, 0, 0, 0, 0, 0, 230, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 220, 220, 220, 220, 220, 220, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 230, 230, 230, 230, 230, 230, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    ],
+};
+
+/// The full canonical decomposition of every codepoint
+pub(crate) static CANONICAL_DECOMPOSITION: Utf8Trie<[u16; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 0, 0, 0, 0, 7, 8, 9, 10, 11, 0, 12, 0, 0, 0, 0,
+        13, 0, 0, 14, 0, 0, 0, 0, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 11, 12, 12, 12,
+        12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 0, 0, 1, 2, 3, 4, 5, 6, 0, 0, 0, 0, 7, 8, 9, 10, 11, 0, 12, 0, 0, 0, 0, 13, 0, 0, 14,
+            0, 0, 0, 0, 0, 0, 0, 0, 15, 16, 0, 17, 18, 19, 0, 0, 0, 20, 21, 22, 0, 23, 0, 24, 0, 25,
+            0, 26, 0, 0, 0, 0, 0, 27, 28, 0,
+        ],
+        [
+            29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32,
+            33, 34, 35, 36, 37, 38, 39,
+        ],
+        [
+            40, 0, 0, 0, 41, 0, 42, 43, 44, 45, 46, 47, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 50, 51, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 64, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 66, 67, 68, 0, 0, 69, 0, 0, 0, 70, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 74, 75, 76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 77, 78, 79, 80, 81, 82, 83, 84, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 7, 4, 4, 4, 4, 8, 4, 4, 4, 4, 4, 4,
+            9, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 10, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+    ],
+    leaves: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            15, 16, 17, 18, 19, 20, 0, 21, 22, 23, 24, 25, 26, 27, 28, 29, 0, 30, 31, 32, 33, 34,
+            35, 0, 0, 36, 37, 38, 39, 40, 0, 0, 41, 42, 43, 44, 45, 46, 0, 47, 48, 49, 50, 51, 52,
+            53, 54, 55, 0, 56, 57, 58, 59, 60, 61, 0, 0, 62, 63, 64, 65, 66, 0, 67,
+        ],
+        [
+            68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 0, 0, 84, 85, 86, 87,
+            88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 0, 0, 104, 105, 106,
+            107, 108, 109, 110, 111, 112, 0, 0, 0, 115, 116, 117, 118, 0, 119, 120, 121, 122, 123,
+            124, 0,
+        ],
+        [
+            0, 0, 0, 127, 128, 129, 130, 131, 132, 0, 0, 0, 134, 135, 136, 137, 138, 139, 0, 0, 140,
+            141, 142, 143, 144, 145, 146, 147, 148, 149, 150, 151, 152, 153, 154, 155, 156, 157, 0,
+            0, 158, 159, 160, 161, 162, 163, 164, 165, 166, 167, 168, 169, 170, 171, 172, 173, 174,
+            175, 176, 177, 178, 179, 180, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 182, 183, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 184, 185, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 195, 196, 197, 198, 199, 200, 201, 202, 203, 204,
+            205, 206, 207, 208, 209, 210, 0, 211, 212, 213, 214, 215, 216, 0, 0, 217, 218, 219, 220,
+            221, 222, 223, 224, 225, 226, 227, 0, 0, 0, 231, 232, 0, 0, 233, 234, 235, 236, 237,
+            238, 239, 240,
+        ],
+        [
+            241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255, 256, 257,
+            258, 259, 260, 261, 262, 263, 264, 265, 266, 267, 268, 0, 0, 269, 270, 0, 0, 0, 0, 0, 0,
+            271, 272, 273, 274, 275, 276, 277, 278, 279, 280, 281, 282, 283, 284, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            304, 305, 0, 306, 307, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 308, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 310, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 311, 313, 314, 315, 316, 317, 0, 318, 0, 319, 320, 321, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 322, 323, 324, 325, 326, 327,
+            328, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 329, 330, 331, 332, 333, 0, 0, 0, 0, 337, 338, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            347, 348, 0, 349, 0, 0, 0, 350, 0, 0, 0, 0, 351, 352, 353, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            354, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 355, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 356, 357, 0, 358, 0, 0, 0, 359, 0, 0, 0,
+            0, 360, 361, 362, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            363, 364, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 365, 366, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 367, 368, 369, 370, 0, 0, 371, 372,
+            0, 0, 373, 374, 375, 376, 377, 378, 0, 0, 379, 380, 381, 382, 383, 384, 0, 0, 385, 386,
+            387, 388, 389, 390, 391, 392, 393, 394, 395, 396, 0, 0, 397, 398, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 400, 401, 402, 403, 404, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            409, 0, 410, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 411, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 412, 0, 0, 0, 0, 0, 0, 0, 413, 0, 0, 414, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 415, 416, 417,
+            418, 419, 420, 421, 422, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 423, 424, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            425, 426, 0, 427, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 428, 0, 0, 429, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 430, 431,
+            432, 0, 0, 433, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 434, 0, 0, 435, 436, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 437, 438, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 439, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 440, 441, 442, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 443, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            444, 0, 0, 0, 0, 0, 0, 445, 446, 0, 447, 448, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 449, 450, 451, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 452, 0,
+            453, 454, 455, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 461, 0, 0, 0, 0, 0, 0, 0, 0, 0, 462, 0, 0, 0, 0, 463, 0, 0, 0, 0, 464, 0, 0, 0,
+            0, 465, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 466, 0, 0, 0, 0, 0, 0, 0, 0, 0, 467, 0, 468,
+            469, 0, 471, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 473, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 474, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 475, 0, 0, 0, 0, 476, 0, 0, 0, 0, 477, 0, 0, 0, 0, 478, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 479, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 480, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 482, 0, 483, 0, 484, 0, 485, 0, 486, 0, 0, 0, 487, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 488, 0, 489, 0, 0,
+        ],
+        [
+            490, 491, 0, 492, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            577, 578, 579, 580, 581, 582, 583, 584, 585, 586, 587, 588, 589, 590, 591, 592, 593,
+            594, 595, 596, 597, 598, 599, 600, 601, 602, 603, 604, 605, 606, 607, 608, 609, 610,
+            611, 612, 613, 614, 615, 616, 617, 618, 619, 620, 621, 622, 623, 624, 625, 626, 627,
+            628, 629, 630, 631, 632, 633, 634, 635, 636, 637, 638, 639, 640,
+        ],
+        [
+            641, 642, 643, 644, 645, 646, 647, 648, 649, 650, 651, 652, 653, 654, 655, 656, 657,
+            658, 659, 660, 661, 662, 663, 664, 665, 666, 667, 668, 669, 670, 671, 672, 673, 674,
+            675, 676, 677, 678, 679, 680, 681, 682, 683, 684, 685, 686, 687, 688, 689, 690, 691,
+            692, 693, 694, 695, 696, 697, 698, 699, 700, 701, 702, 703, 704,
+        ],
+        [
+            705, 706, 707, 708, 709, 710, 711, 712, 713, 714, 715, 716, 717, 718, 719, 720, 721,
+            722, 723, 724, 725, 726, 727, 728, 729, 730, 0, 732, 0, 0, 0, 0, 733, 734, 735, 736,
+            737, 738, 739, 740, 741, 742, 743, 744, 745, 746, 747, 748, 749, 750, 751, 752, 753,
+            754, 755, 756, 757, 758, 759, 760, 761, 762, 763, 764,
+        ],
+        [
+            765, 766, 767, 768, 769, 770, 771, 772, 773, 774, 775, 776, 777, 778, 779, 780, 781,
+            782, 783, 784, 785, 786, 787, 788, 789, 790, 791, 792, 793, 794, 795, 796, 797, 798,
+            799, 800, 801, 802, 803, 804, 805, 806, 807, 808, 809, 810, 811, 812, 813, 814, 815,
+            816, 817, 818, 819, 820, 821, 822, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            823, 824, 825, 826, 827, 828, 829, 830, 831, 832, 833, 834, 835, 836, 837, 838, 839,
+            840, 841, 842, 843, 844, 0, 0, 845, 846, 847, 848, 849, 850, 0, 0, 851, 852, 853, 854,
+            855, 856, 857, 858, 859, 860, 861, 862, 863, 864, 865, 866, 867, 868, 869, 870, 871,
+            872, 873, 874, 875, 876, 877, 878, 879, 880, 881, 882,
+        ],
+        [
+            883, 884, 885, 886, 887, 888, 0, 0, 889, 890, 891, 892, 893, 894, 0, 0, 895, 896, 897,
+            898, 899, 900, 901, 902, 0, 903, 0, 904, 0, 905, 0, 906, 907, 908, 909, 910, 911, 912,
+            913, 914, 915, 916, 917, 918, 919, 920, 921, 922, 923, 324, 924, 325, 925, 326, 926,
+            327, 927, 331, 928, 332, 929, 333, 0, 0,
+        ],
+        [
+            930, 931, 932, 933, 934, 935, 936, 937, 938, 939, 940, 941, 942, 943, 944, 945, 946,
+            947, 948, 949, 950, 951, 952, 953, 954, 955, 956, 957, 958, 959, 960, 961, 962, 963,
+            964, 965, 966, 967, 968, 969, 970, 971, 972, 973, 974, 975, 976, 977, 978, 979, 980,
+            981, 982, 0, 983, 984, 985, 986, 987, 313, 988, 0, 990, 0,
+        ],
+        [
+            0, 992, 994, 995, 996, 0, 997, 998, 999, 315, 1000, 316, 1001, 1002, 1004, 1006, 1008,
+            1009, 1010, 321, 0, 0, 1011, 1012, 1013, 1014, 1015, 317, 0, 1016, 1018, 1020, 1022,
+            1023, 1024, 328, 1025, 1026, 1027, 1028, 1029, 1030, 1031, 319, 1032, 1033, 311, 1035,
+            0, 0, 1036, 1037, 1038, 0, 1039, 1040, 1041, 318, 1042, 320, 1043, 1044, 0, 0,
+        ],
+        [
+            1046, 1047, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1092, 0, 0, 0, 503, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1139,
+            1140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1141, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1142, 1143, 1144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 1145, 0, 0, 0, 0, 1146, 0, 0, 1147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1148, 0, 1149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1154, 0, 0, 1155, 0, 0, 1156, 0, 1157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 1158, 0, 1159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1160, 1161, 1162,
+            1163, 1164, 0, 0, 1165, 1166, 0, 0, 1167, 1168, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1169, 1170, 0, 0, 1171, 1172, 0, 0, 1173, 1174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1175, 1176, 1177, 1178,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1179, 1180, 1181, 1182, 0, 0, 0, 0, 0, 0, 1183, 1184, 1185, 1186, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1187, 1188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1273, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1494, 0, 1495, 0, 1496, 0, 1497, 0, 1498, 0, 1499,
+            0, 1500, 0, 1501, 0, 1502, 0, 1503, 0, 1504, 0, 1505, 0, 0, 1506, 0, 1507, 0, 1508, 0,
+            0, 0, 0, 0, 0, 1509, 1510, 0, 1511, 1512, 0, 1513, 1514, 0, 1515, 1516, 0, 1517, 1518,
+            0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1519, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 1522, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1524, 0, 1525, 0, 1526, 0, 1527, 0,
+            1528, 0, 1529, 0, 1530, 0, 1531, 0, 1532, 0, 1533, 0,
+        ],
+        [
+            1534, 0, 1535, 0, 0, 1536, 0, 1537, 0, 1538, 0, 0, 0, 0, 0, 0, 1539, 1540, 0, 1541,
+            1542, 0, 1543, 1544, 0, 1545, 1546, 0, 1547, 1548, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1549, 0, 0, 1550, 1551, 1552, 1553, 0, 0, 0, 1554, 0,
+        ],
+        [
+            2140, 2141, 1435, 2142, 2143, 2144, 2145, 1489, 1489, 2146, 1443, 2147, 2148, 2149,
+            2150, 2151, 2152, 2153, 2154, 2155, 2156, 2157, 2158, 2159, 2160, 2161, 2162, 2163,
+            2164, 2165, 2166, 2167, 2168, 2169, 2170, 2171, 2172, 2173, 2174, 2175, 2176, 2177,
+            2178, 2179, 2180, 2181, 2182, 2183, 2184, 2185, 2186, 2187, 1401, 2188, 2189, 2190,
+            2191, 2192, 2193, 2194, 2195, 2196, 2197, 2198,
+        ],
+        [
+            1474, 2199, 2200, 2201, 2202, 2203, 2204, 2205, 2206, 2207, 2208, 2209, 2210, 2211,
+            2212, 2213, 2214, 2215, 2216, 2217, 2218, 2219, 2220, 2221, 2222, 2223, 2224, 2225,
+            2156, 2226, 2227, 2228, 2229, 2230, 2231, 2232, 2233, 2234, 2235, 2236, 2237, 2238,
+            2239, 2240, 2241, 2242, 2243, 2244, 2245, 1437, 2246, 2247, 2248, 2249, 2250, 2251,
+            2252, 2253, 2254, 2255, 2256, 2257, 2258, 2259,
+        ],
+        [
+            2260, 1314, 2261, 2262, 2263, 2264, 2265, 2266, 2267, 2268, 1295, 2269, 2270, 2271,
+            2272, 2273, 2274, 2275, 2276, 2277, 2278, 2279, 2280, 2281, 2282, 2283, 2284, 2285,
+            2286, 2287, 2288, 2289, 2290, 2244, 2291, 2292, 2293, 2294, 2295, 2296, 2297, 2298,
+            2228, 2299, 2300, 2301, 2302, 2303, 2304, 2305, 2306, 2307, 2308, 2309, 2310, 2311,
+            2312, 2313, 2314, 2315, 2316, 2317, 2318, 2156,
+        ],
+        [
+            2319, 2320, 2321, 2322, 1488, 2323, 2324, 2325, 2326, 2327, 2328, 2329, 2330, 2331,
+            2332, 2333, 2334, 1764, 2335, 2336, 2337, 2338, 2339, 2340, 2341, 2342, 2343, 2230,
+            2344, 2345, 2346, 2347, 2348, 2349, 2350, 2351, 2352, 2353, 2354, 2355, 2356, 1442,
+            2357, 2358, 2359, 2360, 2361, 2362, 2363, 2364, 2365, 2366, 2367, 2368, 2369, 1393,
+            2370, 2371, 2372, 2373, 2374, 2375, 2376, 2377,
+        ],
+        [
+            2378, 2379, 2380, 2381, 2382, 2383, 2384, 2385, 1420, 2386, 1423, 2387, 2388, 2389, 0,
+            0, 2390, 0, 2391, 0, 0, 2392, 2393, 2394, 2395, 2396, 2397, 2398, 2399, 2400, 1400, 0,
+            2401, 0, 2402, 0, 0, 2403, 2404, 0, 0, 0, 2405, 2406, 2407, 2408, 2409, 2410, 2411,
+            2412, 2413, 2414, 2415, 2416, 2417, 2418, 2419, 2420, 2421, 2422, 1321, 2423, 2424,
+            2425,
+        ],
+        [
+            2426, 2427, 2428, 2429, 2430, 2431, 2432, 2433, 2434, 2435, 2436, 2437, 1769, 2438,
+            2439, 2440, 2441, 1773, 2442, 2443, 2444, 2445, 2446, 2280, 2447, 2448, 2449, 2450,
+            2451, 2452, 2452, 2453, 2454, 2455, 2456, 2457, 2458, 2459, 2460, 2403, 2461, 2462,
+            2463, 2464, 2465, 2466, 0, 0, 2467, 2468, 2469, 2470, 2471, 2472, 2473, 2474, 2417,
+            2475, 2476, 2477, 2390, 2478, 2479, 2480,
+        ],
+        [
+            2481, 2482, 2483, 2484, 2485, 2486, 2487, 2488, 2489, 2425, 2490, 2426, 2491, 2492,
+            2493, 2494, 2495, 2391, 2177, 2496, 2497, 1354, 2245, 2328, 2498, 2499, 2433, 2500,
+            2434, 2501, 2502, 2503, 2393, 2504, 2505, 2506, 2507, 2508, 2394, 2509, 2510, 2511,
+            2512, 2513, 2514, 2446, 2515, 2516, 2280, 2517, 2450, 2518, 2519, 2520, 2521, 2522,
+            2455, 2523, 2402, 2524, 2456, 2226, 2525, 2457,
+        ],
+        [
+            2526, 2459, 2527, 2528, 2529, 2530, 2531, 2461, 2399, 2532, 2462, 2533, 2463, 2534,
+            1489, 2535, 2536, 2537, 2538, 2539, 2540, 2541, 2542, 2543, 2544, 2545, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            2557, 0, 2558, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2566, 2567, 2568, 2569, 2570, 2571, 2572,
+            2573, 2574, 2575, 2576, 2577, 2578, 0, 2579, 2580, 2581, 2582, 2583, 0, 2584, 0,
+        ],
+        [
+            2585, 2586, 0, 2587, 2588, 0, 2589, 2590, 2591, 2592, 2593, 2594, 2595, 2596, 2597, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 3032, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 3033, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3088, 0,
+            3089, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3090, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3091, 3092, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3093, 3094, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 3095, 0, 3096, 0, 0, 0, 0, 0, 0, 0, 0, 3097, 0, 0, 3098, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 3099, 0, 3100, 3101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 3102, 3103, 0, 3104, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            3105, 3106, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3107,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 3108, 3109, 3110, 3111, 3112, 3113, 3114, 3115, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3116, 3117, 3118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 3119, 3120, 3121, 3122, 3123, 3124, 3125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 3126, 3127, 3128, 3129, 3130,
+        ],
+        [
+            3131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            3302, 3303, 3304, 3305, 3306, 2411, 3307, 3308, 3309, 3310, 2412, 3311, 3312, 3313,
+            2413, 3314, 3315, 3316, 3317, 3318, 3319, 3270, 3320, 3321, 3322, 3323, 3324, 2468,
+            3325, 1293, 3326, 3327, 3328, 3329, 3288, 3330, 3331, 2473, 2414, 2415, 2474, 3332,
+            3333, 2232, 3334, 2416, 3335, 3336, 3337, 3338, 3338, 3338, 3339, 3340, 3341, 3342,
+            3343, 3344, 3345, 3346, 3347, 3348, 3349, 3350,
+        ],
+        [
+            3351, 3352, 3353, 3354, 3355, 3356, 3356, 2476, 3357, 3358, 3359, 3360, 2418, 3361,
+            3362, 3363, 2378, 3364, 3365, 3366, 3367, 3368, 3369, 3370, 3371, 3372, 3373, 3374,
+            3375, 3263, 3376, 3377, 3378, 3379, 3380, 3381, 3382, 3383, 3384, 3385, 3386, 3387,
+            3388, 3388, 3389, 3390, 3391, 2228, 3392, 3393, 3394, 3395, 3396, 1319, 3397, 3398,
+            1321, 3399, 3400, 3401, 3402, 3403, 3404, 3405,
+        ],
+        [
+            3406, 3407, 3408, 3409, 3410, 3411, 3412, 3413, 3414, 3415, 3416, 3417, 3418, 3419,
+            2176, 3420, 1331, 3421, 3421, 3422, 3423, 3423, 3424, 3425, 3426, 3427, 3428, 3429,
+            3430, 3431, 3432, 3433, 3434, 3435, 3436, 2423, 3437, 3438, 3439, 3440, 2488, 3440,
+            3441, 2425, 3442, 3443, 3444, 3445, 2426, 2149, 3446, 3447, 3448, 3449, 3450, 3451,
+            3452, 3453, 3454, 3455, 3456, 3457, 3458, 3459,
+        ],
+        [
+            3460, 3461, 3462, 3463, 3464, 3465, 3466, 3467, 2427, 3468, 3469, 3470, 3471, 3472,
+            3473, 2429, 3474, 3475, 3476, 3477, 3478, 3479, 3480, 3481, 2177, 2496, 3482, 3483,
+            3484, 3485, 3486, 3487, 3488, 3489, 2430, 3490, 3491, 3492, 3493, 2538, 3494, 3495,
+            3496, 3497, 3498, 3499, 3500, 3501, 3502, 3503, 3504, 3505, 3506, 2245, 3507, 3508,
+            3509, 3510, 3511, 3512, 3513, 3514, 3515, 3516,
+        ],
+        [
+            3517, 2431, 2328, 3518, 3519, 3520, 3521, 3522, 3523, 3524, 3525, 2499, 3526, 3527,
+            3528, 3529, 3530, 3531, 3532, 3533, 2500, 3534, 3535, 3536, 3537, 3538, 3539, 3540,
+            3541, 3542, 3543, 3544, 3545, 2502, 3546, 3547, 3548, 3549, 3550, 3551, 3552, 3553,
+            3554, 3555, 3556, 3556, 3557, 3558, 2504, 3559, 3560, 3561, 3562, 3563, 3564, 3565,
+            2231, 3566, 3567, 3568, 3569, 3570, 3571, 3572,
+        ],
+        [
+            2510, 3573, 3574, 3575, 3576, 3577, 3578, 3578, 2511, 2540, 3579, 3580, 3581, 3582,
+            3583, 2194, 2513, 3584, 3585, 2441, 3586, 3587, 2398, 3588, 3589, 2444, 3590, 3591,
+            3592, 3593, 3593, 3594, 3595, 3596, 3597, 3598, 3599, 3600, 3601, 3602, 3603, 3604,
+            3605, 3606, 3607, 3608, 3609, 3610, 3611, 3612, 3613, 3614, 3615, 3616, 3617, 3618,
+            3619, 3620, 2450, 3621, 3622, 3623, 3624, 3625,
+        ],
+        [
+            3626, 3627, 3628, 3629, 3630, 3631, 3632, 3633, 3634, 3635, 3636, 3422, 3637, 3638,
+            3639, 3640, 3641, 3642, 3643, 3644, 3645, 3646, 3647, 3648, 2248, 3649, 3650, 3651,
+            3652, 3653, 3654, 2453, 3655, 3656, 3657, 3658, 3659, 3660, 3661, 3662, 3663, 3664,
+            3665, 3666, 3667, 3668, 3669, 3670, 3671, 3672, 3673, 3674, 2189, 3675, 3676, 3677,
+            3678, 3679, 3680, 2520, 3681, 3682, 3683, 3684,
+        ],
+        [
+            3685, 3686, 3687, 3688, 1421, 3689, 3690, 3691, 3692, 3693, 3694, 3695, 3696, 3697,
+            3698, 3699, 2525, 2526, 1428, 3700, 3701, 3702, 3703, 3704, 3705, 3706, 3707, 3708,
+            3709, 3710, 3711, 2527, 3712, 3713, 3714, 3715, 3716, 3717, 3718, 3719, 3720, 3721,
+            3722, 3723, 3724, 3725, 3726, 3727, 3728, 3729, 3730, 3731, 3732, 3733, 3734, 3735,
+            3736, 3737, 3738, 3739, 3740, 3741, 2533, 2533,
+        ],
+        [
+            3742, 3743, 3744, 3745, 3746, 3747, 3748, 3749, 3750, 3751, 2534, 3752, 3753, 3754,
+            3755, 3756, 3757, 3758, 3759, 3760, 3761, 1476, 3762, 1480, 3763, 3764, 3765, 3766,
+            1485, 3767, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    ],
+};
+
+/// The full compatibility decomposition of every codepoint
+pub(crate) static COMPATIBLE_DECOMPOSITION: Utf8Trie<[u16; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 0, 8, 9, 0, 10, 11, 12, 13, 14, 0, 15, 0, 0,
+        16, 0, 17, 18, 0, 19, 0, 0, 0, 0, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 6, 15, 16,
+        16, 16, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 0, 1, 2, 3, 4, 5, 6, 7, 0, 8, 9, 0, 10, 11, 12, 13, 14, 0, 15, 0, 0, 16, 0, 17, 18,
+            0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 20, 21, 0, 22, 23, 24, 0, 0, 0, 25, 26, 27, 0, 28, 0, 29,
+            0, 30, 0, 31, 32, 0, 33, 34, 35, 36, 37, 0,
+        ],
+        [
+            38, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0, 0, 0, 0, 42, 43, 44, 0,
+            45, 46, 47, 48, 49, 50, 51, 52,
+        ],
+        [
+            53, 54, 55, 0, 56, 57, 58, 59, 60, 61, 62, 63, 64, 0, 0, 0, 0, 65, 66, 67, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 69, 0, 70, 0, 0, 0, 0, 0, 71, 0, 0,
+            0, 72, 0, 0, 0, 0, 73, 74, 75, 76, 77, 78,
+        ],
+        [
+            79, 80, 81, 82, 83, 84, 85, 0, 86, 87, 88, 89, 90, 91, 92, 93, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 94, 0, 0,
+            95, 0, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 98, 99, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111,
+            112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0,
+            0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 128, 0, 129, 0, 0, 0, 0, 0, 0, 0, 0, 130, 131, 132, 0, 0, 133, 0, 0, 0, 134, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 137, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 139, 140, 141, 0, 0, 0, 0, 0, 0, 0, 0, 142, 143, 144, 145, 146, 147, 148,
+            149, 150, 151, 152, 153, 154, 155, 156, 157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            158, 159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 160,
+            161, 162, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 163, 164, 165, 0, 166, 167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 169, 170, 171, 172, 173, 174, 175, 176, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 6, 7, 8, 4, 4, 4, 4, 9, 4, 4, 4, 4, 4, 10,
+            11, 12, 13, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 14, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+    ],
+    leaves: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 4, 0, 0, 5, 6, 7, 8, 0, 0, 9, 10,
+            11, 0, 12, 13, 14, 0,
+        ],
+        [
+            15, 16, 17, 18, 19, 20, 0, 21, 22, 23, 24, 25, 26, 27, 28, 29, 0, 30, 31, 32, 33, 34,
+            35, 0, 0, 36, 37, 38, 39, 40, 0, 0, 41, 42, 43, 44, 45, 46, 0, 47, 48, 49, 50, 51, 52,
+            53, 54, 55, 0, 56, 57, 58, 59, 60, 61, 0, 0, 62, 63, 64, 65, 66, 0, 67,
+        ],
+        [
+            68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 0, 0, 84, 85, 86, 87,
+            88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 0, 0, 104, 105, 106,
+            107, 108, 109, 110, 111, 112, 0, 113, 114, 115, 116, 117, 118, 0, 119, 120, 121, 122,
+            123, 124, 125,
+        ],
+        [
+            126, 0, 0, 127, 128, 129, 130, 131, 132, 133, 0, 0, 134, 135, 136, 137, 138, 139, 0, 0,
+            140, 141, 142, 143, 144, 145, 146, 147, 148, 149, 150, 151, 152, 153, 154, 155, 156,
+            157, 0, 0, 158, 159, 160, 161, 162, 163, 164, 165, 166, 167, 168, 169, 170, 171, 172,
+            173, 174, 175, 176, 177, 178, 179, 180, 181,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 182, 183, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 184, 185, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 186, 187, 188, 189, 190, 191, 192, 193, 194, 195, 196, 197, 198, 199, 200,
+            201, 202, 203, 204, 205, 206, 207, 208, 209, 210, 0, 211, 212, 213, 214, 215, 216, 0, 0,
+            217, 218, 219, 220, 221, 222, 223, 224, 225, 226, 227, 228, 229, 230, 231, 232, 0, 0,
+            233, 234, 235, 236, 237, 238, 239, 240,
+        ],
+        [
+            241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255, 256, 257,
+            258, 259, 260, 261, 262, 263, 264, 265, 266, 267, 268, 0, 0, 269, 270, 0, 0, 0, 0, 0, 0,
+            271, 272, 273, 274, 275, 276, 277, 278, 279, 280, 281, 282, 283, 284, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 285, 286, 287, 288, 289, 290,
+            291, 292, 293, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 294, 295, 296,
+            297, 298, 299, 0, 0, 300, 301, 181, 302, 303, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            304, 305, 0, 306, 307, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 308, 0, 0, 0,
+            0, 0, 309, 0, 0, 0, 310, 0,
+        ],
+        [
+            0, 0, 0, 0, 7, 312, 313, 314, 315, 316, 317, 0, 318, 0, 319, 320, 321, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 322, 323, 324, 325, 326, 327,
+            328, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 329, 330, 331, 332, 333, 0, 334, 335, 336, 319, 323, 339,
+            340, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 341,
+            342, 343, 0, 344, 345, 0, 0, 0, 346, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            347, 348, 0, 349, 0, 0, 0, 350, 0, 0, 0, 0, 351, 352, 353, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            354, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 355, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 356, 357, 0, 358, 0, 0, 0, 359, 0, 0, 0,
+            0, 360, 361, 362, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            363, 364, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 365, 366, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 367, 368, 369, 370, 0, 0, 371, 372,
+            0, 0, 373, 374, 375, 376, 377, 378, 0, 0, 379, 380, 381, 382, 383, 384, 0, 0, 385, 386,
+            387, 388, 389, 390, 391, 392, 393, 394, 395, 396, 0, 0, 397, 398, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 399, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 400, 401, 402, 403, 404, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 405, 406, 407,
+            408, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            409, 0, 410, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 411, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 412, 0, 0, 0, 0, 0, 0, 0, 413, 0, 0, 414, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 415, 416, 417,
+            418, 419, 420, 421, 422, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 423, 424, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            425, 426, 0, 427, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 428, 0, 0, 429, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 430, 431,
+            432, 0, 0, 433, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 434, 0, 0, 435, 436, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 437, 438, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 439, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 440, 441, 442, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 443, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            444, 0, 0, 0, 0, 0, 0, 445, 446, 0, 447, 448, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 449, 450, 451, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 452, 0,
+            453, 454, 455, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 456, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 457, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 458,
+            459, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 460, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 461, 0, 0, 0, 0, 0, 0, 0, 0, 0, 462, 0, 0, 0, 0, 463, 0, 0, 0, 0, 464, 0, 0, 0,
+            0, 465, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 466, 0, 0, 0, 0, 0, 0, 0, 0, 0, 467, 0, 468,
+            469, 470, 471, 472, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 473, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 474, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 475, 0, 0, 0, 0, 476, 0, 0, 0, 0, 477, 0, 0, 0, 0, 478, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 479, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 480, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 481, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 482, 0, 483, 0, 484, 0, 485, 0, 486, 0, 0, 0, 487, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 488, 0, 489, 0, 0,
+        ],
+        [
+            490, 491, 0, 492, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 493, 494, 495, 0, 496, 497, 498, 499, 500,
+            501, 502, 503, 504, 505, 506, 0, 507, 508, 509, 510,
+        ],
+        [
+            511, 512, 513, 3, 514, 515, 516, 517, 518, 519, 520, 521, 522, 523, 0, 524, 525, 526,
+            11, 527, 528, 529, 530, 531, 532, 533, 534, 535, 536, 334, 537, 538, 339, 539, 540, 288,
+            532, 535, 334, 537, 342, 339, 539, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 541, 0, 0, 0,
+            0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 542,
+            543, 544, 545, 522, 546, 547, 548, 549, 550, 551, 552, 553, 554, 555, 556, 557, 558,
+            559, 560, 561, 562, 563, 564, 565, 566, 567, 568, 569, 570, 571, 572, 573, 574, 575,
+            576, 335,
+        ],
+        [
+            577, 578, 579, 580, 581, 582, 583, 584, 585, 586, 587, 588, 589, 590, 591, 592, 593,
+            594, 595, 596, 597, 598, 599, 600, 601, 602, 603, 604, 605, 606, 607, 608, 609, 610,
+            611, 612, 613, 614, 615, 616, 617, 618, 619, 620, 621, 622, 623, 624, 625, 626, 627,
+            628, 629, 630, 631, 632, 633, 634, 635, 636, 637, 638, 639, 640,
+        ],
+        [
+            641, 642, 643, 644, 645, 646, 647, 648, 649, 650, 651, 652, 653, 654, 655, 656, 657,
+            658, 659, 660, 661, 662, 663, 664, 665, 666, 667, 668, 669, 670, 671, 672, 673, 674,
+            675, 676, 677, 678, 679, 680, 681, 682, 683, 684, 685, 686, 687, 688, 689, 690, 691,
+            692, 693, 694, 695, 696, 697, 698, 699, 700, 701, 702, 703, 704,
+        ],
+        [
+            705, 706, 707, 708, 709, 710, 711, 712, 713, 714, 715, 716, 717, 718, 719, 720, 721,
+            722, 723, 724, 725, 726, 727, 728, 729, 730, 731, 674, 0, 0, 0, 0, 733, 734, 735, 736,
+            737, 738, 739, 740, 741, 742, 743, 744, 745, 746, 747, 748, 749, 750, 751, 752, 753,
+            754, 755, 756, 757, 758, 759, 760, 761, 762, 763, 764,
+        ],
+        [
+            765, 766, 767, 768, 769, 770, 771, 772, 773, 774, 775, 776, 777, 778, 779, 780, 781,
+            782, 783, 784, 785, 786, 787, 788, 789, 790, 791, 792, 793, 794, 795, 796, 797, 798,
+            799, 800, 801, 802, 803, 804, 805, 806, 807, 808, 809, 810, 811, 812, 813, 814, 815,
+            816, 817, 818, 819, 820, 821, 822, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            823, 824, 825, 826, 827, 828, 829, 830, 831, 832, 833, 834, 835, 836, 837, 838, 839,
+            840, 841, 842, 843, 844, 0, 0, 845, 846, 847, 848, 849, 850, 0, 0, 851, 852, 853, 854,
+            855, 856, 857, 858, 859, 860, 861, 862, 863, 864, 865, 866, 867, 868, 869, 870, 871,
+            872, 873, 874, 875, 876, 877, 878, 879, 880, 881, 882,
+        ],
+        [
+            883, 884, 885, 886, 887, 888, 0, 0, 889, 890, 891, 892, 893, 894, 0, 0, 895, 896, 897,
+            898, 899, 900, 901, 902, 0, 903, 0, 904, 0, 905, 0, 906, 907, 908, 909, 910, 911, 912,
+            913, 914, 915, 916, 917, 918, 919, 920, 921, 922, 923, 324, 924, 325, 925, 326, 926,
+            327, 927, 331, 928, 332, 929, 333, 0, 0,
+        ],
+        [
+            930, 931, 932, 933, 934, 935, 936, 937, 938, 939, 940, 941, 942, 943, 944, 945, 946,
+            947, 948, 949, 950, 951, 952, 953, 954, 955, 956, 957, 958, 959, 960, 961, 962, 963,
+            964, 965, 966, 967, 968, 969, 970, 971, 972, 973, 974, 975, 976, 977, 978, 979, 980,
+            981, 982, 0, 983, 984, 985, 986, 987, 313, 988, 989, 990, 989,
+        ],
+        [
+            991, 993, 994, 995, 996, 0, 997, 998, 999, 315, 1000, 316, 1001, 1003, 1005, 1007, 1008,
+            1009, 1010, 321, 0, 0, 1011, 1012, 1013, 1014, 1015, 317, 0, 1017, 1019, 1021, 1022,
+            1023, 1024, 328, 1025, 1026, 1027, 1028, 1029, 1030, 1031, 319, 1032, 1034, 312, 1035,
+            0, 0, 1036, 1037, 1038, 0, 1039, 1040, 1041, 318, 1042, 320, 1043, 7, 1045, 0,
+        ],
+        [
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1048, 0, 0, 0, 0, 0, 1049, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1050, 1051, 1052, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1053,
+            1054, 0, 1055, 1056, 0, 0, 0, 0, 1057, 0, 1058, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 1059, 1060, 1061, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1062, 0,
+            0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1063, 540, 0, 0,
+            1064, 1065, 1066, 1067, 1068, 1069, 1070, 1071, 1072, 1073, 1074, 1075,
+        ],
+        [
+            1063, 10, 5, 6, 1064, 1065, 1066, 1067, 1068, 1069, 1070, 1071, 1072, 1073, 1074, 0, 3,
+            519, 11, 302, 520, 285, 524, 301, 525, 1075, 530, 181, 531, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 1076, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1077, 1078, 1079, 1080, 0, 1081, 1082, 1083, 0, 1084, 523, 500, 500, 500, 285, 1085,
+            501, 501, 504, 301, 0, 506, 1086, 0, 0, 509, 1087, 510, 510, 510, 0, 0, 1088, 1089,
+            1090, 0, 1091, 0, 1092, 0, 1091, 0, 503, 20, 495, 1079, 0, 519, 497, 1093, 0, 505, 11,
+            1094, 1095, 1096, 1097, 540, 0, 1098, 340, 537, 1099, 1100,
+        ],
+        [
+            1101, 0, 0, 0, 0, 496, 518, 519, 540, 287, 0, 0, 0, 0, 0, 0, 1102, 1103, 1104, 1105,
+            1106, 1107, 1108, 1109, 1110, 1111, 1112, 1113, 1114, 1115, 1116, 1117, 501, 1118, 1119,
+            1120, 1121, 1122, 1123, 1124, 1125, 1126, 1127, 1128, 504, 1079, 496, 505, 540, 1129,
+            1130, 1131, 535, 1132, 1133, 1134, 1135, 302, 1136, 1137, 301, 543, 518, 525,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1139,
+            1140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1141, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1142, 1143, 1144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 1145, 0, 0, 0, 0, 1146, 0, 0, 1147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1148, 0, 1149, 0, 0, 0, 0, 0, 1150, 1151, 0, 1152, 1153,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1154, 0, 0, 1155, 0, 0, 1156, 0, 1157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 1158, 0, 1159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1160, 1161, 1162,
+            1163, 1164, 0, 0, 1165, 1166, 0, 0, 1167, 1168, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1169, 1170, 0, 0, 1171, 1172, 0, 0, 1173, 1174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1175, 1176, 1177, 1178,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1179, 1180, 1181, 1182, 0, 0, 0, 0, 0, 0, 1183, 1184, 1185, 1186, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1187, 1188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 10, 5, 6, 1064, 1065, 1066, 1067, 1068, 1069, 1189, 1190, 1191, 1192, 1193,
+            1194, 1195, 1196, 1197, 1198, 1199, 1200, 1201, 1202, 1203, 1204, 1205, 1206, 1207,
+            1208, 1209, 1210, 1211,
+        ],
+        [
+            1212, 1213, 1214, 1215, 1216, 1217, 1218, 1219, 1220, 1221, 1222, 1223, 1224, 1225,
+            1226, 1227, 1228, 1229, 1230, 1231, 1232, 1233, 1234, 1235, 1236, 1237, 1238, 1239,
+            1240, 1241, 1242, 1243, 1244, 1245, 1246, 1247, 1248, 1249, 1250, 1251, 1252, 1253,
+            1254, 1255, 1256, 1257, 1258, 1259, 1260, 1261, 1262, 1263, 1264, 1265, 493, 495, 1079,
+            496, 497, 1093, 499, 500, 501, 502,
+        ],
+        [
+            503, 504, 505, 506, 507, 509, 1087, 510, 1266, 511, 512, 1121, 513, 1126, 1267, 1091, 3,
+            517, 543, 518, 519, 546, 523, 285, 540, 287, 524, 301, 525, 1075, 11, 530, 1268, 288,
+            181, 531, 532, 535, 292, 302, 293, 573, 1063, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1269, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1270, 1271, 1272,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1273, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 287, 1121, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1274, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 1275, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1276, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1277, 1278, 1279, 1280, 1281, 1282, 1283, 1284, 1285, 1286, 1287, 1288, 1289, 1290,
+            1291, 1292, 1293, 1294, 1295, 1296, 1297, 1298, 1299, 1300, 1301, 1302, 1303, 1304,
+            1305, 1306, 1307, 1308, 1309, 1310, 1311, 1312, 1313, 1314, 1315, 1316, 1317, 1318,
+            1319, 1320, 1321, 1322, 1323, 1324, 1325, 1326, 1327, 1328, 1329, 1330, 1331, 1332,
+            1333, 1334, 1335, 1336, 1337, 1338, 1339, 1340,
+        ],
+        [
+            1341, 1342, 1343, 1344, 1345, 1346, 1347, 1348, 1349, 1350, 1351, 1352, 1353, 1354,
+            1355, 1356, 1357, 1358, 1359, 1360, 1361, 1362, 1363, 1364, 1365, 1366, 1367, 1368,
+            1369, 1370, 1371, 1372, 1373, 1374, 1375, 1376, 1377, 1378, 1379, 1380, 1381, 1382,
+            1383, 1384, 1385, 1386, 1387, 1388, 1389, 1390, 1391, 1392, 1393, 1394, 1395, 1396,
+            1397, 1398, 1399, 1400, 1401, 1402, 1403, 1404,
+        ],
+        [
+            1405, 1406, 1407, 1408, 1409, 1410, 1411, 1412, 1413, 1414, 1415, 1416, 1417, 1418,
+            1419, 1420, 1421, 1422, 1423, 1424, 1425, 1426, 1427, 1428, 1429, 1430, 1431, 1432,
+            1433, 1434, 1435, 1436, 1437, 1438, 1439, 1440, 1441, 1442, 1443, 1444, 1445, 1446,
+            1447, 1448, 1449, 1450, 1451, 1452, 1453, 1454, 1455, 1456, 1457, 1458, 1459, 1460,
+            1461, 1462, 1463, 1464, 1465, 1466, 1467, 1468,
+        ],
+        [
+            1469, 1470, 1471, 1472, 1473, 1474, 1475, 1476, 1477, 1478, 1479, 1480, 1481, 1482,
+            1483, 1484, 1485, 1486, 1487, 1488, 1489, 1490, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1491, 0,
+            1300, 1492, 1493, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1494, 0, 1495, 0, 1496, 0, 1497, 0, 1498, 0, 1499,
+            0, 1500, 0, 1501, 0, 1502, 0, 1503, 0, 1504, 0, 1505, 0, 0, 1506, 0, 1507, 0, 1508, 0,
+            0, 0, 0, 0, 0, 1509, 1510, 0, 1511, 1512, 0, 1513, 1514, 0, 1515, 1516, 0, 1517, 1518,
+            0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1519, 0, 0, 0, 0, 0, 0,
+            1520, 1521, 0, 1522, 1523, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1524, 0, 1525, 0, 1526,
+            0, 1527, 0, 1528, 0, 1529, 0, 1530, 0, 1531, 0, 1532, 0, 1533, 0,
+        ],
+        [
+            1534, 0, 1535, 0, 0, 1536, 0, 1537, 0, 1538, 0, 0, 0, 0, 0, 0, 1539, 1540, 0, 1541,
+            1542, 0, 1543, 1544, 0, 1545, 1546, 0, 1547, 1548, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1549, 0, 0, 1550, 1551, 1552, 1553, 0, 0, 0, 1554, 1555,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1556, 1557, 1558, 1559,
+            1560, 1561, 1562, 1563, 1564, 1565, 1566, 1567, 1568, 1569, 1570,
+        ],
+        [
+            1571, 1572, 1573, 1574, 1575, 1576, 1577, 1578, 1579, 1580, 1581, 1582, 1583, 1584,
+            1585, 1586, 1587, 1588, 1589, 1590, 1591, 1592, 1593, 1594, 1595, 1596, 1597, 1598,
+            1599, 1600, 1601, 1602, 1603, 1604, 1605, 1606, 1607, 1608, 1609, 1610, 1611, 1612,
+            1613, 1614, 1615, 1616, 1617, 1618, 1619, 1620, 1621, 1622, 1623, 1624, 1625, 1626,
+            1627, 1628, 1629, 1630, 1631, 1632, 1633, 1634,
+        ],
+        [
+            1635, 1636, 1637, 1638, 1639, 1640, 1641, 1642, 1643, 1644, 1645, 1646, 1647, 1648,
+            1649, 0, 0, 0, 1277, 1283, 1650, 1651, 1652, 1653, 1654, 1655, 1281, 1656, 1657, 1658,
+            1659, 1285, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1660, 1661, 1662, 1663, 1664, 1665, 1666, 1667, 1668, 1669, 1670, 1671, 1672, 1673,
+            1674, 1675, 1676, 1677, 1678, 1679, 1680, 1681, 1682, 1683, 1684, 1685, 1686, 1687,
+            1688, 1689, 1690, 0, 1691, 1692, 1693, 1694, 1695, 1696, 1697, 1698, 1699, 1700, 1701,
+            1702, 1703, 1704, 1705, 1706, 1707, 1708, 1709, 1710, 1711, 1712, 1713, 1714, 1715,
+            1716, 1717, 1718, 1719, 1720, 1721, 1722,
+        ],
+        [
+            1723, 1724, 1725, 1726, 1727, 1728, 1343, 1729, 0, 0, 0, 0, 0, 0, 0, 0, 1730, 1731,
+            1732, 1733, 1734, 1735, 1736, 1737, 1738, 1739, 1740, 1741, 1742, 1743, 1744, 1745,
+            1556, 1559, 1562, 1564, 1572, 1573, 1576, 1578, 1579, 1581, 1582, 1583, 1584, 1585,
+            1746, 1747, 1748, 1749, 1750, 1751, 1752, 1753, 1754, 1755, 1756, 1757, 1758, 1759,
+            1760, 1761, 1762, 0,
+        ],
+        [
+            1277, 1283, 1650, 1651, 1763, 1764, 1765, 1288, 1766, 1300, 1350, 1362, 1361, 1351,
+            1443, 1308, 1348, 1767, 1768, 1769, 1770, 1771, 1772, 1773, 1774, 1775, 1776, 1314,
+            1777, 1778, 1779, 1780, 1781, 1782, 1783, 1784, 1652, 1653, 1654, 1785, 1786, 1787,
+            1788, 1789, 1790, 1791, 1792, 1793, 1794, 1795, 1796, 1797, 1798, 1799, 1800, 1801,
+            1802, 1803, 1804, 1805, 1806, 1807, 1808, 1809,
+        ],
+        [
+            1810, 1811, 1812, 1813, 1814, 1815, 1816, 1817, 1818, 1819, 1820, 1821, 1822, 1823,
+            1824, 1825, 1826, 1827, 1828, 1829, 1830, 1831, 1832, 1833, 1834, 1835, 1836, 1837,
+            1838, 1839, 1840, 1841, 1842, 1843, 1844, 1845, 1846, 1847, 1848, 1849, 1850, 1851,
+            1852, 1853, 1854, 1855, 1856, 1857, 1858, 1859, 1860, 1861, 1862, 1863, 1864, 1865,
+            1866, 1867, 1868, 1869, 1870, 1871, 1872, 1873,
+        ],
+        [
+            1874, 1875, 1876, 1877, 1878, 1879, 1880, 1881, 1882, 1883, 1884, 1885, 1886, 1887,
+            1888, 1889, 1890, 1891, 1892, 1893, 1894, 1895, 1896, 1897, 1898, 1899, 1900, 1901,
+            1902, 1903, 1904, 1905, 1906, 1907, 1908, 1909, 1910, 1911, 1912, 1913, 1914, 1915,
+            1916, 1917, 1918, 1919, 1920, 1921, 1922, 1923, 1924, 1925, 1926, 1927, 1928, 1929,
+            1930, 1931, 1932, 1933, 1934, 1935, 1936, 1937,
+        ],
+        [
+            1938, 1939, 1940, 1941, 1942, 1943, 1944, 1945, 1946, 1947, 1948, 1949, 1950, 1951,
+            1952, 1953, 1954, 1955, 1956, 1957, 1958, 1959, 1960, 1961, 1962, 1963, 1964, 1965,
+            1966, 1967, 1968, 1969, 1970, 1971, 1972, 1973, 1974, 1975, 1976, 1977, 1978, 1979,
+            1980, 1981, 1982, 1983, 1984, 1985, 1986, 1987, 1988, 1989, 1990, 1991, 1992, 1993,
+            1994, 1995, 1996, 1997, 1998, 1999, 2000, 2001,
+        ],
+        [
+            2002, 2003, 2004, 2005, 2006, 2007, 2008, 2009, 2010, 2011, 2012, 2013, 2014, 2015,
+            2016, 2017, 2018, 2019, 2020, 2021, 2022, 2023, 2024, 2025, 2026, 2027, 2028, 2029,
+            2030, 2031, 2032, 2033, 2034, 2035, 2036, 2037, 2038, 2039, 2040, 2041, 2042, 2043,
+            2044, 2045, 2046, 2047, 2048, 2049, 2050, 2051, 2052, 2053, 2054, 2055, 2056, 2057,
+            2058, 2059, 2060, 2061, 2062, 2063, 2064, 2065,
+        ],
+        [
+            2066, 2067, 2068, 2069, 2070, 2071, 2072, 2073, 2074, 2075, 2076, 2077, 2078, 2079,
+            2080, 2081, 2082, 2083, 2084, 2085, 2086, 2087, 2088, 2089, 2090, 2091, 2092, 2093,
+            2094, 2095, 2096, 2097, 2098, 2099, 2100, 2101, 2102, 2103, 2104, 2105, 2106, 2107,
+            2108, 2109, 2110, 2111, 2112, 2113, 2114, 2115, 2116, 2117, 2118, 2119, 2120, 2121,
+            2122, 2123, 2124, 2125, 2126, 2127, 2128, 2129,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            2130, 2131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2132, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1266, 1079, 1093, 1087, 0,
+            0, 0, 2133, 2134, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            2135, 2136, 2137, 2138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            2140, 2141, 1435, 2142, 2143, 2144, 2145, 1489, 1489, 2146, 1443, 2147, 2148, 2149,
+            2150, 2151, 2152, 2153, 2154, 2155, 2156, 2157, 2158, 2159, 2160, 2161, 2162, 2163,
+            2164, 2165, 2166, 2167, 2168, 2169, 2170, 2171, 2172, 2173, 2174, 2175, 2176, 2177,
+            2178, 2179, 2180, 2181, 2182, 2183, 2184, 2185, 2186, 2187, 1401, 2188, 2189, 2190,
+            2191, 2192, 2193, 2194, 2195, 2196, 2197, 2198,
+        ],
+        [
+            1474, 2199, 2200, 2201, 2202, 2203, 2204, 2205, 2206, 2207, 2208, 2209, 2210, 2211,
+            2212, 2213, 2214, 2215, 2216, 2217, 2218, 2219, 2220, 2221, 2222, 2223, 2224, 2225,
+            2156, 2226, 2227, 2228, 2229, 2230, 2231, 2232, 2233, 2234, 2235, 2236, 2237, 2238,
+            2239, 2240, 2241, 2242, 2243, 2244, 2245, 1437, 2246, 2247, 2248, 2249, 2250, 2251,
+            2252, 2253, 2254, 2255, 2256, 2257, 2258, 2259,
+        ],
+        [
+            2260, 1314, 2261, 2262, 2263, 2264, 2265, 2266, 2267, 2268, 1295, 2269, 2270, 2271,
+            2272, 2273, 2274, 2275, 2276, 2277, 2278, 2279, 2280, 2281, 2282, 2283, 2284, 2285,
+            2286, 2287, 2288, 2289, 2290, 2244, 2291, 2292, 2293, 2294, 2295, 2296, 2297, 2298,
+            2228, 2299, 2300, 2301, 2302, 2303, 2304, 2305, 2306, 2307, 2308, 2309, 2310, 2311,
+            2312, 2313, 2314, 2315, 2316, 2317, 2318, 2156,
+        ],
+        [
+            2319, 2320, 2321, 2322, 1488, 2323, 2324, 2325, 2326, 2327, 2328, 2329, 2330, 2331,
+            2332, 2333, 2334, 1764, 2335, 2336, 2337, 2338, 2339, 2340, 2341, 2342, 2343, 2230,
+            2344, 2345, 2346, 2347, 2348, 2349, 2350, 2351, 2352, 2353, 2354, 2355, 2356, 1442,
+            2357, 2358, 2359, 2360, 2361, 2362, 2363, 2364, 2365, 2366, 2367, 2368, 2369, 1393,
+            2370, 2371, 2372, 2373, 2374, 2375, 2376, 2377,
+        ],
+        [
+            2378, 2379, 2380, 2381, 2382, 2383, 2384, 2385, 1420, 2386, 1423, 2387, 2388, 2389, 0,
+            0, 2390, 0, 2391, 0, 0, 2392, 2393, 2394, 2395, 2396, 2397, 2398, 2399, 2400, 1400, 0,
+            2401, 0, 2402, 0, 0, 2403, 2404, 0, 0, 0, 2405, 2406, 2407, 2408, 2409, 2410, 2411,
+            2412, 2413, 2414, 2415, 2416, 2417, 2418, 2419, 2420, 2421, 2422, 1321, 2423, 2424,
+            2425,
+        ],
+        [
+            2426, 2427, 2428, 2429, 2430, 2431, 2432, 2433, 2434, 2435, 2436, 2437, 1769, 2438,
+            2439, 2440, 2441, 1773, 2442, 2443, 2444, 2445, 2446, 2280, 2447, 2448, 2449, 2450,
+            2451, 2452, 2452, 2453, 2454, 2455, 2456, 2457, 2458, 2459, 2460, 2403, 2461, 2462,
+            2463, 2464, 2465, 2466, 0, 0, 2467, 2468, 2469, 2470, 2471, 2472, 2473, 2474, 2417,
+            2475, 2476, 2477, 2390, 2478, 2479, 2480,
+        ],
+        [
+            2481, 2482, 2483, 2484, 2485, 2486, 2487, 2488, 2489, 2425, 2490, 2426, 2491, 2492,
+            2493, 2494, 2495, 2391, 2177, 2496, 2497, 1354, 2245, 2328, 2498, 2499, 2433, 2500,
+            2434, 2501, 2502, 2503, 2393, 2504, 2505, 2506, 2507, 2508, 2394, 2509, 2510, 2511,
+            2512, 2513, 2514, 2446, 2515, 2516, 2280, 2517, 2450, 2518, 2519, 2520, 2521, 2522,
+            2455, 2523, 2402, 2524, 2456, 2226, 2525, 2457,
+        ],
+        [
+            2526, 2459, 2527, 2528, 2529, 2530, 2531, 2461, 2399, 2532, 2462, 2533, 2463, 2534,
+            1489, 2535, 2536, 2537, 2538, 2539, 2540, 2541, 2542, 2543, 2544, 2545, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ],
+        [
+            2546, 2547, 2548, 2549, 2550, 2551, 2551, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2552,
+            2553, 2554, 2555, 2556, 0, 0, 0, 0, 0, 2557, 0, 2558, 2559, 1094, 1097, 2560, 2561,
+            2562, 2563, 2564, 2565, 1070, 2566, 2567, 2568, 2569, 2570, 2571, 2572, 2573, 2574,
+            2575, 2576, 2577, 2578, 0, 2579, 2580, 2581, 2582, 2583, 0, 2584, 0,
+        ],
+        [
+            2585, 2586, 0, 2587, 2588, 0, 2589, 2590, 2591, 2592, 2593, 2594, 2595, 2596, 2597,
+            2598, 2599, 2599, 2600, 2600, 2600, 2600, 2601, 2601, 2601, 2601, 2602, 2602, 2602,
+            2602, 2603, 2603, 2603, 2603, 2604, 2604, 2604, 2604, 2605, 2605, 2605, 2605, 2606,
+            2606, 2606, 2606, 2607, 2607, 2607, 2607, 2608, 2608, 2608, 2608, 2609, 2609, 2609,
+            2609, 2610, 2610, 2610, 2610, 2611, 2611,
+        ],
+        [
+            2611, 2611, 2612, 2612, 2613, 2613, 2614, 2614, 2615, 2615, 2616, 2616, 2617, 2617,
+            2618, 2618, 2618, 2618, 2619, 2619, 2619, 2619, 2620, 2620, 2620, 2620, 2621, 2621,
+            2621, 2621, 2622, 2622, 2623, 2623, 2623, 2623, 409, 409, 2624, 2624, 2624, 2624, 2625,
+            2625, 2625, 2625, 2626, 2626, 411, 411, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2627, 2627, 2627, 2627, 2628,
+            2628, 2629, 2629, 2630, 2630, 407, 2631, 2631, 2632, 2632, 2633, 2633, 2634, 2634, 2634,
+            2634, 2635, 2635, 2636, 2636, 2637, 2637, 2638, 2638, 2639, 2639, 2640, 2640, 2641,
+            2641, 2642, 2642, 2642, 2643, 2643, 2643, 2644, 2644, 2644, 2644,
+        ],
+        [
+            2645, 2646, 2647, 2643, 2648, 2649, 2650, 2651, 2652, 2653, 2654, 2655, 2656, 2657,
+            2658, 2659, 2660, 2661, 2662, 2663, 2664, 2665, 2666, 2667, 2668, 2669, 2670, 2671,
+            2672, 2673, 2674, 2675, 2676, 2677, 2678, 2679, 2680, 2681, 2682, 2683, 2684, 2685,
+            2686, 2687, 2688, 2689, 2690, 2691, 2692, 2693, 2694, 2695, 2696, 2697, 2698, 2699,
+            2700, 2701, 2702, 2703, 2704, 2705, 2706, 2707,
+        ],
+        [
+            2708, 2709, 2710, 2711, 2712, 2713, 2714, 2715, 2716, 2717, 2718, 2719, 2720, 2721,
+            2722, 2723, 2724, 2725, 2726, 2727, 2728, 2729, 2730, 2731, 2732, 2733, 2734, 2735,
+            2736, 2737, 2738, 2739, 2740, 2741, 2742, 2743, 2744, 2745, 2647, 2746, 2643, 2648,
+            2747, 2748, 2652, 2749, 2653, 2654, 2750, 2751, 2658, 2752, 2659, 2660, 2753, 2754,
+            2662, 2755, 2663, 2664, 2693, 2694, 2697, 2698,
+        ],
+        [
+            2699, 2703, 2704, 2705, 2706, 2710, 2711, 2712, 2756, 2716, 2757, 2758, 2722, 2759,
+            2723, 2724, 2737, 2760, 2761, 2732, 2762, 2733, 2734, 2645, 2646, 2763, 2647, 2764,
+            2649, 2650, 2651, 2652, 2765, 2655, 2656, 2657, 2658, 2766, 2662, 2665, 2666, 2667,
+            2668, 2669, 2671, 2672, 2673, 2674, 2675, 2676, 2767, 2677, 2678, 2679, 2680, 2681,
+            2682, 2684, 2685, 2686, 2687, 2688, 2689, 2690,
+        ],
+        [
+            2691, 2692, 2695, 2696, 2700, 2701, 2702, 2703, 2704, 2707, 2708, 2709, 2710, 2768,
+            2713, 2714, 2715, 2716, 2719, 2720, 2721, 2722, 2769, 2725, 2726, 2770, 2729, 2730,
+            2731, 2732, 2771, 2647, 2764, 2652, 2765, 2658, 2766, 2662, 2772, 2675, 2773, 2774,
+            2775, 2703, 2704, 2710, 2722, 2769, 2732, 2771, 2776, 2777, 2778, 2779, 2780, 2781,
+            2782, 2783, 2784, 2785, 2786, 2787, 2788, 2789,
+        ],
+        [
+            2790, 2791, 2792, 2793, 2794, 2795, 2796, 2797, 2798, 2799, 2800, 2801, 2774, 2802,
+            2803, 2804, 2805, 2779, 2780, 2781, 2782, 2783, 2784, 2785, 2786, 2787, 2788, 2789,
+            2790, 2791, 2792, 2793, 2794, 2795, 2796, 2797, 2798, 2799, 2800, 2801, 2774, 2802,
+            2803, 2804, 2805, 2799, 2800, 2801, 2774, 2773, 2775, 2683, 2672, 2673, 2674, 2799,
+            2800, 2801, 2683, 2684, 2806, 2806, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2807, 2808, 2808, 2809, 2810, 2811,
+            2812, 2813, 2814, 2814, 2815, 2816, 2817, 2818, 2819, 2820, 2820, 2821, 2822, 2822,
+            2823, 2823, 2824, 2825, 2825, 2826, 2827, 2827, 2828, 2828, 2829, 2830, 2830, 2831,
+            2831, 2832, 2833, 2834, 2835, 2835, 2836, 2837, 2838, 2839, 2840, 2840, 2841, 2842,
+        ],
+        [
+            2843, 2844, 2845, 2846, 2846, 2847, 2847, 2848, 2848, 2849, 2850, 2851, 2852, 2853,
+            2854, 2855, 0, 0, 2856, 2857, 2858, 2859, 2860, 2861, 2861, 2862, 2863, 2864, 2865,
+            2865, 2866, 2867, 2868, 2869, 2870, 2871, 2872, 2873, 2874, 2875, 2876, 2877, 2878,
+            2879, 2880, 2881, 2882, 2883, 2884, 2885, 2886, 2887, 2841, 2843, 2888, 2889, 2890,
+            2891, 2892, 2893, 2892, 2890, 2894, 2895,
+        ],
+        [
+            2896, 2897, 2898, 2893, 2834, 2824, 2899, 2900, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2901,
+            2902, 2903, 2904, 2905, 2906, 2907, 2908, 2909, 2910, 2911, 2912, 2913, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2914, 2915, 2916, 2917, 310, 2918, 2919,
+            2920, 2921, 1052, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1051, 2922, 2923, 2924, 2924, 1073, 1074, 2925, 2926, 2927, 2928, 2929, 2930, 2931,
+            2932, 1187,
+        ],
+        [
+            1188, 2933, 2934, 2935, 2936, 0, 0, 2937, 2938, 1058, 1058, 1058, 1058, 2924, 2924,
+            2924, 2914, 2915, 1050, 0, 310, 2917, 2919, 2918, 2922, 1073, 1074, 2925, 2926, 2927,
+            2928, 2939, 2940, 2941, 1070, 2942, 2943, 2944, 1072, 0, 2945, 2946, 2947, 2948, 0, 0,
+            0, 0, 2949, 2950, 2951, 0, 2952, 0, 2953, 2954, 2955, 2956, 2957, 2958, 2959, 2960,
+            2961, 2962,
+        ],
+        [
+            2963, 400, 400, 401, 401, 402, 402, 403, 403, 404, 404, 404, 404, 2964, 2964, 2965,
+            2965, 2965, 2965, 2966, 2966, 2967, 2967, 2967, 2967, 2968, 2968, 2968, 2968, 2969,
+            2969, 2969, 2969, 2970, 2970, 2970, 2970, 2971, 2971, 2971, 2971, 2972, 2972, 2973,
+            2973, 2974, 2974, 2975, 2975, 2976, 2976, 2976, 2976, 2977, 2977, 2977, 2977, 2978,
+            2978, 2978, 2978, 2979, 2979, 2979,
+        ],
+        [
+            2979, 2980, 2980, 2980, 2980, 2981, 2981, 2981, 2981, 2982, 2982, 2982, 2982, 2983,
+            2983, 2983, 2983, 2984, 2984, 2984, 2984, 2985, 2985, 2985, 2985, 2986, 2986, 2986,
+            2986, 2987, 2987, 2987, 2987, 2988, 2988, 2988, 2988, 2989, 2989, 2989, 2989, 2990,
+            2990, 2990, 2990, 2991, 2991, 2635, 2635, 2992, 2992, 2992, 2992, 2993, 2993, 2994,
+            2994, 2995, 2995, 2996, 2996, 0, 0, 0,
+        ],
+        [
+            0, 2918, 2997, 2939, 2946, 2947, 2940, 2998, 1073, 1074, 2941, 1070, 2914, 2942, 1050,
+            2999, 1063, 10, 5, 6, 1064, 1065, 1066, 1067, 1068, 1069, 2917, 310, 2943, 1072, 2944,
+            2919, 2948, 493, 495, 1079, 496, 497, 1093, 499, 500, 501, 502, 503, 504, 505, 506, 507,
+            509, 1087, 510, 1266, 511, 512, 1121, 513, 1126, 1267, 1091, 2937, 2945, 2938, 3000,
+            2924,
+        ],
+        [
+            1035, 3, 517, 543, 518, 519, 546, 523, 285, 540, 287, 524, 301, 525, 1075, 11, 530,
+            1268, 288, 181, 531, 532, 535, 292, 302, 293, 573, 2925, 3001, 2926, 3002, 3003, 3004,
+            2916, 2933, 2934, 2915, 3005, 1872, 3006, 3007, 3008, 3009, 3010, 3011, 3012, 3013,
+            3014, 3015, 1826, 1827, 1828, 1829, 1830, 1831, 1832, 1833, 1834, 1835, 1836, 1837,
+            1838, 1839, 1840,
+        ],
+        [
+            1841, 1842, 1843, 1844, 1845, 1846, 1847, 1848, 1849, 1850, 1851, 1852, 1853, 1854,
+            1855, 1856, 1857, 1858, 1859, 1860, 1861, 1862, 1863, 1864, 1865, 1866, 1867, 1868,
+            1869, 3016, 3017, 3018, 1607, 1556, 1557, 1558, 1559, 1560, 1561, 1562, 1563, 1564,
+            1565, 1566, 1567, 1568, 1569, 1570, 1571, 1572, 1573, 1574, 1575, 1576, 1577, 1578,
+            1579, 1580, 1581, 1582, 1583, 1584, 1585, 0,
+        ],
+        [
+            0, 0, 1586, 1587, 1588, 1589, 1590, 1591, 0, 0, 1592, 1593, 1594, 1595, 1596, 1597, 0,
+            0, 1598, 1599, 1600, 1601, 1602, 1603, 0, 0, 1604, 1605, 1606, 0, 0, 0, 3019, 3020,
+            3021, 4, 3022, 3023, 3024, 0, 3025, 3026, 3027, 3028, 3029, 3030, 3031, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 3032, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 3033, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 3034, 3035, 3036, 3037, 3038, 0, 3039, 3040, 3041, 3042, 3043, 3044, 3045, 3046,
+            3047, 3048, 3049, 3050, 3051, 3052, 1085, 3053, 3054, 3055, 3056, 3057, 3058, 3059,
+            3060, 3061, 3062, 3063, 3064, 3065, 3066, 3067, 1268, 3068, 3069, 3070, 3071, 3072,
+            3073, 3074, 3075, 3076, 3077, 3078, 0, 3079, 3080, 3081, 3082, 3083, 3084, 3085, 3086,
+            3087, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3088, 0,
+            3089, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3090, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3091, 3092, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3093, 3094, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 3095, 0, 3096, 0, 0, 0, 0, 0, 0, 0, 0, 3097, 0, 0, 3098, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 3099, 0, 3100, 3101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 3102, 3103, 0, 3104, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            3105, 3106, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3107,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 3108, 3109, 3110, 3111, 3112, 3113, 3114, 3115, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3116, 3117, 3118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 493, 495, 1079, 496,
+            497, 1093, 499, 500, 501, 502, 503, 504, 505, 506, 507, 509, 1087, 510, 1266, 511, 512,
+            1121, 513, 1126, 1267, 1091, 1063, 10, 5, 6, 1064, 1065, 1066, 1067, 1068, 1069, 0, 0,
+            0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 3119, 3120, 3121, 3122, 3123, 3124, 3125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 3126, 3127, 3128, 3129, 3130,
+        ],
+        [
+            3131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            493, 495, 1079, 496, 497, 1093, 499, 500, 501, 502, 503, 504, 505, 506, 507, 509, 1087,
+            510, 1266, 511, 512, 1121, 513, 1126, 1267, 1091, 3, 517, 543, 518, 519, 546, 523, 285,
+            540, 287, 524, 301, 525, 1075, 11, 530, 1268, 288, 181, 531, 532, 535, 292, 302, 293,
+            573, 493, 495, 1079, 496, 497, 1093, 499, 500, 501, 502, 503, 504,
+        ],
+        [
+            505, 506, 507, 509, 1087, 510, 1266, 511, 512, 1121, 513, 1126, 1267, 1091, 3, 517, 543,
+            518, 519, 546, 523, 0, 540, 287, 524, 301, 525, 1075, 11, 530, 1268, 288, 181, 531, 532,
+            535, 292, 302, 293, 573, 493, 495, 1079, 496, 497, 1093, 499, 500, 501, 502, 503, 504,
+            505, 506, 507, 509, 1087, 510, 1266, 511, 512, 1121, 513, 1126,
+        ],
+        [
+            1267, 1091, 3, 517, 543, 518, 519, 546, 523, 285, 540, 287, 524, 301, 525, 1075, 11,
+            530, 1268, 288, 181, 531, 532, 535, 292, 302, 293, 573, 493, 0, 1079, 496, 0, 0, 499, 0,
+            0, 502, 503, 0, 0, 506, 507, 509, 1087, 0, 1266, 511, 512, 1121, 513, 1126, 1267, 1091,
+            3, 517, 543, 518, 0, 546, 0, 285, 540, 287,
+        ],
+        [
+            524, 301, 525, 1075, 0, 530, 1268, 288, 181, 531, 532, 535, 292, 302, 293, 573, 493,
+            495, 1079, 496, 497, 1093, 499, 500, 501, 502, 503, 504, 505, 506, 507, 509, 1087, 510,
+            1266, 511, 512, 1121, 513, 1126, 1267, 1091, 3, 517, 543, 518, 519, 546, 523, 285, 540,
+            287, 524, 301, 525, 1075, 11, 530, 1268, 288, 181, 531, 532, 535,
+        ],
+        [
+            292, 302, 293, 573, 493, 495, 0, 496, 497, 1093, 499, 0, 0, 502, 503, 504, 505, 506,
+            507, 509, 1087, 0, 1266, 511, 512, 1121, 513, 1126, 1267, 0, 3, 517, 543, 518, 519, 546,
+            523, 285, 540, 287, 524, 301, 525, 1075, 11, 530, 1268, 288, 181, 531, 532, 535, 292,
+            302, 293, 573, 493, 495, 0, 496, 497, 1093, 499, 0,
+        ],
+        [
+            501, 502, 503, 504, 505, 0, 507, 0, 0, 0, 1266, 511, 512, 1121, 513, 1126, 1267, 0, 3,
+            517, 543, 518, 519, 546, 523, 285, 540, 287, 524, 301, 525, 1075, 11, 530, 1268, 288,
+            181, 531, 532, 535, 292, 302, 293, 573, 493, 495, 1079, 496, 497, 1093, 499, 500, 501,
+            502, 503, 504, 505, 506, 507, 509, 1087, 510, 1266, 511,
+        ],
+        [
+            512, 1121, 513, 1126, 1267, 1091, 3, 517, 543, 518, 519, 546, 523, 285, 540, 287, 524,
+            301, 525, 1075, 11, 530, 1268, 288, 181, 531, 532, 535, 292, 302, 293, 573, 493, 495,
+            1079, 496, 497, 1093, 499, 500, 501, 502, 503, 504, 505, 506, 507, 509, 1087, 510, 1266,
+            511, 512, 1121, 513, 1126, 1267, 1091, 3, 517, 543, 518, 519, 546,
+        ],
+        [
+            523, 285, 540, 287, 524, 301, 525, 1075, 11, 530, 1268, 288, 181, 531, 532, 535, 292,
+            302, 293, 573, 493, 495, 1079, 496, 497, 1093, 499, 500, 501, 502, 503, 504, 505, 506,
+            507, 509, 1087, 510, 1266, 511, 512, 1121, 513, 1126, 1267, 1091, 3, 517, 543, 518, 519,
+            546, 523, 285, 540, 287, 524, 301, 525, 1075, 11, 530, 1268, 288,
+        ],
+        [
+            181, 531, 532, 535, 292, 302, 293, 573, 493, 495, 1079, 496, 497, 1093, 499, 500, 501,
+            502, 503, 504, 505, 506, 507, 509, 1087, 510, 1266, 511, 512, 1121, 513, 1126, 1267,
+            1091, 3, 517, 543, 518, 519, 546, 523, 285, 540, 287, 524, 301, 525, 1075, 11, 530,
+            1268, 288, 181, 531, 532, 535, 292, 302, 293, 573, 493, 495, 1079, 496,
+        ],
+        [
+            497, 1093, 499, 500, 501, 502, 503, 504, 505, 506, 507, 509, 1087, 510, 1266, 511, 512,
+            1121, 513, 1126, 1267, 1091, 3, 517, 543, 518, 519, 546, 523, 285, 540, 287, 524, 301,
+            525, 1075, 11, 530, 1268, 288, 181, 531, 532, 535, 292, 302, 293, 573, 493, 495, 1079,
+            496, 497, 1093, 499, 500, 501, 502, 503, 504, 505, 506, 507, 509,
+        ],
+        [
+            1087, 510, 1266, 511, 512, 1121, 513, 1126, 1267, 1091, 3, 517, 543, 518, 519, 546, 523,
+            285, 540, 287, 524, 301, 525, 1075, 11, 530, 1268, 288, 181, 531, 532, 535, 292, 302,
+            293, 573, 3132, 3133, 0, 0, 3134, 3135, 1099, 3136, 3137, 3138, 3139, 344, 3140, 3141,
+            3142, 3143, 3144, 3145, 3146, 1100, 3147, 344, 346, 3148, 336, 3149, 3150, 3151,
+        ],
+        [
+            1092, 3152, 3153, 334, 537, 538, 345, 3154, 3155, 335, 990, 341, 3156, 8, 3157, 3158,
+            3159, 340, 342, 343, 3160, 3161, 3162, 339, 539, 3163, 3164, 3165, 345, 335, 341, 339,
+            342, 340, 3134, 3135, 1099, 3136, 3137, 3138, 3139, 344, 3140, 3141, 3142, 3143, 3144,
+            3145, 3146, 1100, 3147, 344, 346, 3148, 336, 3149, 3150, 3151, 1092, 3152, 3153, 334,
+            537, 538,
+        ],
+        [
+            345, 3154, 3155, 335, 990, 341, 3156, 8, 3157, 3158, 3159, 340, 342, 343, 3160, 3161,
+            3162, 339, 539, 3163, 3164, 3165, 345, 335, 341, 339, 342, 340, 3134, 3135, 1099, 3136,
+            3137, 3138, 3139, 344, 3140, 3141, 3142, 3143, 3144, 3145, 3146, 1100, 3147, 344, 346,
+            3148, 336, 3149, 3150, 3151, 1092, 3152, 3153, 334, 537, 538, 345, 3154, 3155, 335, 990,
+            341,
+        ],
+        [
+            3156, 8, 3157, 3158, 3159, 340, 342, 343, 3160, 3161, 3162, 339, 539, 3163, 3164, 3165,
+            345, 335, 341, 339, 342, 340, 3134, 3135, 1099, 3136, 3137, 3138, 3139, 344, 3140, 3141,
+            3142, 3143, 3144, 3145, 3146, 1100, 3147, 344, 346, 3148, 336, 3149, 3150, 3151, 1092,
+            3152, 3153, 334, 537, 538, 345, 3154, 3155, 335, 990, 341, 3156, 8, 3157, 3158, 3159,
+            340,
+        ],
+        [
+            342, 343, 3160, 3161, 3162, 339, 539, 3163, 3164, 3165, 345, 335, 341, 339, 342, 340,
+            3134, 3135, 1099, 3136, 3137, 3138, 3139, 344, 3140, 3141, 3142, 3143, 3144, 3145, 3146,
+            1100, 3147, 344, 346, 3148, 336, 3149, 3150, 3151, 1092, 3152, 3153, 334, 537, 538, 345,
+            3154, 3155, 335, 990, 341, 3156, 8, 3157, 3158, 3159, 340, 342, 343, 3160, 3161, 3162,
+            339,
+        ],
+        [
+            539, 3163, 3164, 3165, 345, 335, 341, 339, 342, 340, 3166, 3167, 0, 0, 1063, 10, 5, 6,
+            1064, 1065, 1066, 1067, 1068, 1069, 1063, 10, 5, 6, 1064, 1065, 1066, 1067, 1068, 1069,
+            1063, 10, 5, 6, 1064, 1065, 1066, 1067, 1068, 1069, 1063, 10, 5, 6, 1064, 1065, 1066,
+            1067, 1068, 1069, 1063, 10, 5, 6, 1064, 1065, 1066, 1067, 1068, 1069,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3168, 3169, 3170, 3171, 3172,
+            3173, 3174, 3175, 3176, 3177, 3178, 3179, 3180, 3181, 3182, 3183,
+        ],
+        [
+            3184, 3185, 3186, 3187, 3188, 3189, 3190, 3191, 3192, 3193, 3194, 3195, 3196, 3197,
+            3198, 3199, 3200, 3168, 3169, 3170, 3171, 3172, 3173, 3174, 3175, 3176, 3177, 3178,
+            3180, 3181, 3183, 3185, 3186, 3187, 3188, 3189, 3190, 2130, 3191, 3201, 3196, 3202,
+            3203, 3204, 3205, 3206, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            2964, 2965, 2969, 2972, 0, 2991, 2975, 2970, 2980, 2992, 2986, 2987, 2988, 2989, 2976,
+            2982, 2984, 2978, 2985, 2974, 2977, 2967, 2968, 2971, 2973, 2979, 2981, 2983, 3207,
+            2622, 3208, 3209, 0, 2965, 2969, 0, 2990, 0, 0, 2970, 0, 2992, 2986, 2987, 2988, 2989,
+            2976, 2982, 2984, 2978, 2985, 0, 2977, 2967, 2968, 2971, 0, 2979, 0, 2983, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 2969, 0, 0, 0, 0, 2970, 0, 2992, 0, 2987, 0, 2989, 2976, 2982, 0, 2978, 2985, 0,
+            2977, 0, 0, 2971, 0, 2979, 0, 2983, 0, 2622, 0, 3209, 0, 2965, 2969, 0, 2990, 0, 0,
+            2970, 2980, 2992, 2986, 0, 2988, 2989, 2976, 2982, 2984, 2978, 2985, 0, 2977, 2967,
+            2968, 2971, 0, 2979, 2981, 2983, 3207, 0, 3208, 0,
+        ],
+        [
+            2964, 2965, 2969, 2972, 2990, 2991, 2975, 2970, 2980, 2992, 0, 2987, 2988, 2989, 2976,
+            2982, 2984, 2978, 2985, 2974, 2977, 2967, 2968, 2971, 2973, 2979, 2981, 2983, 0, 0, 0,
+            0, 0, 2965, 2969, 2972, 0, 2991, 2975, 2970, 2980, 2992, 0, 2987, 2988, 2989, 2976,
+            2982, 2984, 2978, 2985, 2974, 2977, 2967, 2968, 2971, 2973, 2979, 2981, 2983, 0, 0, 0,
+            0,
+        ],
+        [
+            3210, 3211, 3212, 3213, 3214, 3215, 3216, 3217, 3218, 3219, 3220, 0, 0, 0, 0, 0, 3221,
+            3222, 3223, 3224, 3225, 3226, 3227, 3228, 3229, 3230, 3231, 3232, 3233, 3234, 3235,
+            3236, 3237, 3238, 3239, 3240, 3241, 3242, 3243, 3244, 3245, 3246, 3247, 1079, 510, 3248,
+            3249, 0, 493, 495, 1079, 496, 497, 1093, 499, 500, 501, 502, 503, 504, 505, 506, 507,
+            509,
+        ],
+        [
+            1087, 510, 1266, 511, 512, 1121, 513, 1126, 1267, 1091, 3250, 2059, 3251, 3252, 3253,
+            3254, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            3255, 3256, 3257, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3258, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            3259, 3260, 1836, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1340, 3261, 3262, 1537, 1283,
+            3263, 3264, 1658, 3265, 3266, 3267, 2318, 3268, 3269, 3270, 3271, 3272, 3273, 1376,
+            3274, 3275, 3276, 3277, 3278, 3279, 1277, 1650, 3280, 1785, 1653, 1786, 3281, 1432,
+            3282, 3283, 3284, 3285, 3286, 1768, 1350, 3287, 3288, 3289, 3290, 0, 0, 0, 0,
+        ],
+        [
+            3291, 3292, 3293, 3294, 3295, 3296, 3297, 3298, 3299, 0, 0, 0, 0, 0, 0, 0, 3300, 3301,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1063, 10, 5, 6, 1064, 1065,
+            1066, 1067, 1068, 1069, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            3302, 3303, 3304, 3305, 3306, 2411, 3307, 3308, 3309, 3310, 2412, 3311, 3312, 3313,
+            2413, 3314, 3315, 3316, 3317, 3318, 3319, 3270, 3320, 3321, 3322, 3323, 3324, 2468,
+            3325, 1293, 3326, 3327, 3328, 3329, 3288, 3330, 3331, 2473, 2414, 2415, 2474, 3332,
+            3333, 2232, 3334, 2416, 3335, 3336, 3337, 3338, 3338, 3338, 3339, 3340, 3341, 3342,
+            3343, 3344, 3345, 3346, 3347, 3348, 3349, 3350,
+        ],
+        [
+            3351, 3352, 3353, 3354, 3355, 3356, 3356, 2476, 3357, 3358, 3359, 3360, 2418, 3361,
+            3362, 3363, 2378, 3364, 3365, 3366, 3367, 3368, 3369, 3370, 3371, 3372, 3373, 3374,
+            3375, 3263, 3376, 3377, 3378, 3379, 3380, 3381, 3382, 3383, 3384, 3385, 3386, 3387,
+            3388, 3388, 3389, 3390, 3391, 2228, 3392, 3393, 3394, 3395, 3396, 1319, 3397, 3398,
+            1321, 3399, 3400, 3401, 3402, 3403, 3404, 3405,
+        ],
+        [
+            3406, 3407, 3408, 3409, 3410, 3411, 3412, 3413, 3414, 3415, 3416, 3417, 3418, 3419,
+            2176, 3420, 1331, 3421, 3421, 3422, 3423, 3423, 3424, 3425, 3426, 3427, 3428, 3429,
+            3430, 3431, 3432, 3433, 3434, 3435, 3436, 2423, 3437, 3438, 3439, 3440, 2488, 3440,
+            3441, 2425, 3442, 3443, 3444, 3445, 2426, 2149, 3446, 3447, 3448, 3449, 3450, 3451,
+            3452, 3453, 3454, 3455, 3456, 3457, 3458, 3459,
+        ],
+        [
+            3460, 3461, 3462, 3463, 3464, 3465, 3466, 3467, 2427, 3468, 3469, 3470, 3471, 3472,
+            3473, 2429, 3474, 3475, 3476, 3477, 3478, 3479, 3480, 3481, 2177, 2496, 3482, 3483,
+            3484, 3485, 3486, 3487, 3488, 3489, 2430, 3490, 3491, 3492, 3493, 2538, 3494, 3495,
+            3496, 3497, 3498, 3499, 3500, 3501, 3502, 3503, 3504, 3505, 3506, 2245, 3507, 3508,
+            3509, 3510, 3511, 3512, 3513, 3514, 3515, 3516,
+        ],
+        [
+            3517, 2431, 2328, 3518, 3519, 3520, 3521, 3522, 3523, 3524, 3525, 2499, 3526, 3527,
+            3528, 3529, 3530, 3531, 3532, 3533, 2500, 3534, 3535, 3536, 3537, 3538, 3539, 3540,
+            3541, 3542, 3543, 3544, 3545, 2502, 3546, 3547, 3548, 3549, 3550, 3551, 3552, 3553,
+            3554, 3555, 3556, 3556, 3557, 3558, 2504, 3559, 3560, 3561, 3562, 3563, 3564, 3565,
+            2231, 3566, 3567, 3568, 3569, 3570, 3571, 3572,
+        ],
+        [
+            2510, 3573, 3574, 3575, 3576, 3577, 3578, 3578, 2511, 2540, 3579, 3580, 3581, 3582,
+            3583, 2194, 2513, 3584, 3585, 2441, 3586, 3587, 2398, 3588, 3589, 2444, 3590, 3591,
+            3592, 3593, 3593, 3594, 3595, 3596, 3597, 3598, 3599, 3600, 3601, 3602, 3603, 3604,
+            3605, 3606, 3607, 3608, 3609, 3610, 3611, 3612, 3613, 3614, 3615, 3616, 3617, 3618,
+            3619, 3620, 2450, 3621, 3622, 3623, 3624, 3625,
+        ],
+        [
+            3626, 3627, 3628, 3629, 3630, 3631, 3632, 3633, 3634, 3635, 3636, 3422, 3637, 3638,
+            3639, 3640, 3641, 3642, 3643, 3644, 3645, 3646, 3647, 3648, 2248, 3649, 3650, 3651,
+            3652, 3653, 3654, 2453, 3655, 3656, 3657, 3658, 3659, 3660, 3661, 3662, 3663, 3664,
+            3665, 3666, 3667, 3668, 3669, 3670, 3671, 3672, 3673, 3674, 2189, 3675, 3676, 3677,
+            3678, 3679, 3680, 2520, 3681, 3682, 3683, 3684,
+        ],
+        [
+            3685, 3686, 3687, 3688, 1421, 3689, 3690, 3691, 3692, 3693, 3694, 3695, 3696, 3697,
+            3698, 3699, 2525, 2526, 1428, 3700, 3701, 3702, 3703, 3704, 3705, 3706, 3707, 3708,
+            3709, 3710, 3711, 2527, 3712, 3713, 3714, 3715, 3716, 3717, 3718, 3719, 3720, 3721,
+            3722, 3723, 3724, 3725, 3726, 3727, 3728, 3729, 3730, 3731, 3732, 3733, 3734, 3735,
+            3736, 3737, 3738, 3739, 3740, 3741, 2533, 2533,
+        ],
+        [
+            3742, 3743, 3744, 3745, 3746, 3747, 3748, 3749, 3750, 3751, 2534, 3752, 3753, 3754,
+            3755, 3756, 3757, 3758, 3759, 3760, 3761, 1476, 3762, 1480, 3763, 3764, 3765, 3766,
+            1485, 3767, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    ],
+};
+
+/// The `NFKC_Casefold` mapping of every codepoint
+pub(crate) static NFKC_CASEFOLD: Utf8Trie<[u16; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 0, 12, 13, 14, 15, 16, 17, 18, 19,
+        20, 21, 0, 22, 23, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 6, 16, 17,
+        17, 19, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 0, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 0, 22,
+            23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 25, 26, 27, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 29, 0, 30, 31, 32, 33, 34, 0,
+        ],
+        [
+            0, 0, 35, 36, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 39, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 42, 43, 44,
+            0, 45, 46, 47, 48, 49, 50, 51, 52,
+        ],
+        [
+            53, 54, 55, 0, 56, 57, 58, 0, 59, 0, 0, 0, 60, 0, 0, 0, 0, 61, 62, 63, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 65, 0, 66, 0, 0, 0, 0, 67, 68, 69, 70, 0,
+            71, 0, 0, 0, 0, 72, 73, 74, 75, 76, 77,
+        ],
+        [
+            78, 0, 79, 80, 81, 82, 83, 0, 84, 85, 86, 87, 88, 89, 90, 91, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 0,
+            94, 95, 96, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 98, 99, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112,
+            113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125, 126, 127,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 129, 130, 0, 131, 132, 0, 0, 0,
+            0, 0, 0, 0, 133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0, 0,
+            135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 137,
+            138, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 141, 142, 143, 0, 0, 0, 0, 0, 0, 0, 0, 144, 145, 146, 147, 148, 149, 150,
+            151, 152, 153, 154, 155, 156, 157, 158, 159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            160, 161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            163, 164, 165, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 166, 167, 168, 0, 169, 170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 172, 173, 174, 175, 176, 177, 178, 179, 180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 6, 7, 8, 4, 4, 4, 4, 9, 4, 4, 4, 4, 10, 11,
+            12, 13, 14, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 15, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181,
+            181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181,
+            181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181,
+            181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181, 181,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 18, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+    ],
+    leaves: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 28, 0, 1, 0, 0, 29, 0, 30, 0, 0, 31, 32, 33, 34, 0, 0,
+            35, 36, 15, 0, 37, 38, 39, 0,
+        ],
+        [
+            40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61,
+            62, 0, 63, 64, 65, 66, 67, 68, 69, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            71, 0, 72, 0, 73, 0, 74, 0, 75, 0, 76, 0, 77, 0, 78, 0, 79, 0, 80, 0, 81, 0, 82, 0, 83,
+            0, 84, 0, 85, 0, 86, 0, 87, 0, 88, 0, 89, 0, 90, 0, 91, 0, 92, 0, 93, 0, 94, 0, 95, 0,
+            96, 96, 97, 0, 98, 0, 0, 99, 0, 100, 0, 101, 0, 102,
+        ],
+        [
+            102, 103, 0, 104, 0, 105, 0, 106, 0, 107, 108, 0, 109, 0, 110, 0, 111, 0, 112, 0, 113,
+            0, 114, 0, 115, 0, 116, 0, 117, 0, 118, 0, 119, 0, 120, 0, 121, 0, 122, 0, 123, 0, 124,
+            0, 125, 0, 126, 0, 127, 0, 128, 0, 129, 0, 130, 0, 131, 132, 0, 133, 0, 134, 0, 19,
+        ],
+        [
+            0, 135, 136, 0, 137, 0, 138, 139, 0, 140, 141, 142, 0, 0, 143, 144, 145, 146, 0, 147,
+            148, 0, 149, 150, 151, 0, 0, 0, 152, 153, 0, 154, 155, 0, 156, 0, 157, 0, 158, 159, 0,
+            160, 0, 0, 161, 0, 162, 163, 0, 164, 165, 166, 0, 167, 0, 168, 169, 0, 0, 0, 170, 0, 0,
+            0,
+        ],
+        [
+            0, 0, 0, 0, 171, 171, 171, 172, 172, 172, 173, 173, 173, 174, 0, 175, 0, 176, 0, 177, 0,
+            178, 0, 179, 0, 180, 0, 181, 0, 0, 182, 0, 183, 0, 184, 0, 185, 0, 186, 0, 187, 0, 188,
+            0, 189, 0, 190, 0, 0, 191, 191, 191, 192, 0, 193, 194, 195, 0, 196, 0, 197, 0, 198, 0,
+        ],
+        [
+            199, 0, 200, 0, 201, 0, 202, 0, 203, 0, 204, 0, 205, 0, 206, 0, 207, 0, 208, 0, 209, 0,
+            210, 0, 211, 0, 212, 0, 213, 0, 214, 0, 215, 0, 216, 0, 217, 0, 218, 0, 219, 0, 220, 0,
+            221, 0, 222, 0, 223, 0, 224, 0, 0, 0, 0, 0, 0, 0, 225, 226, 0, 227, 228, 0,
+        ],
+        [
+            0, 229, 0, 230, 231, 232, 233, 0, 234, 0, 235, 0, 236, 0, 237, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 238, 10, 18, 239, 240, 241,
+            23, 25, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 242, 243, 244,
+            245, 246, 247, 0, 0, 148, 12, 19, 24, 248, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            249, 250, 0, 251, 252, 253, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 254, 0, 255, 0, 256,
+            0, 257, 0, 0, 0, 258, 0, 0, 0, 259, 260,
+        ],
+        [
+            0, 0, 0, 0, 33, 261, 262, 263, 264, 265, 266, 0, 267, 0, 268, 269, 0, 270, 271, 272,
+            273, 274, 275, 276, 277, 253, 278, 279, 34, 280, 281, 282, 283, 284, 0, 285, 286, 287,
+            288, 289, 290, 291, 292, 293, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0,
+        ],
+        [
+            0, 0, 285, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 294, 271, 277, 287, 268, 293, 288, 283,
+            0, 295, 0, 296, 0, 297, 0, 298, 0, 299, 0, 300, 0, 301, 0, 302, 0, 303, 0, 304, 0, 305,
+            0, 306, 0, 278, 284, 285, 0, 277, 274, 0, 307, 0, 285, 308, 0, 0, 309, 310, 311,
+        ],
+        [
+            312, 313, 314, 315, 316, 317, 318, 319, 320, 321, 322, 323, 324, 325, 326, 327, 328,
+            329, 330, 331, 332, 333, 334, 335, 336, 337, 338, 339, 340, 341, 342, 343, 344, 345,
+            346, 347, 348, 349, 350, 351, 352, 353, 354, 355, 356, 357, 358, 359, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 360, 0, 361, 0, 362, 0, 363, 0, 364, 0, 365, 0, 366, 0, 367, 0, 368, 0, 369, 0,
+            370, 0, 371, 0, 372, 0, 373, 0, 374, 0, 375, 0,
+        ],
+        [
+            376, 0, 0, 0, 0, 0, 0, 0, 0, 0, 377, 0, 378, 0, 379, 0, 380, 0, 381, 0, 382, 0, 383, 0,
+            384, 0, 385, 0, 386, 0, 387, 0, 388, 0, 389, 0, 390, 0, 391, 0, 392, 0, 393, 0, 394, 0,
+            395, 0, 396, 0, 397, 0, 398, 0, 399, 0, 400, 0, 401, 0, 402, 0, 403, 0,
+        ],
+        [
+            404, 405, 0, 406, 0, 407, 0, 408, 0, 409, 0, 410, 0, 411, 0, 0, 412, 0, 413, 0, 414, 0,
+            415, 0, 416, 0, 417, 0, 418, 0, 419, 0, 420, 0, 421, 0, 422, 0, 423, 0, 424, 0, 425, 0,
+            426, 0, 427, 0, 428, 0, 429, 0, 430, 0, 431, 0, 432, 0, 433, 0, 434, 0, 435, 0,
+        ],
+        [
+            436, 0, 437, 0, 438, 0, 439, 0, 440, 0, 441, 0, 442, 0, 443, 0, 444, 0, 445, 0, 446, 0,
+            447, 0, 448, 0, 449, 0, 450, 0, 451, 0, 452, 0, 453, 0, 454, 0, 455, 0, 456, 0, 457, 0,
+            458, 0, 459, 0, 0, 460, 461, 462, 463, 464, 465, 466, 467, 468, 469, 470, 471, 472, 473,
+            474,
+        ],
+        [
+            475, 476, 477, 478, 479, 480, 481, 482, 483, 484, 485, 486, 487, 488, 489, 490, 491,
+            492, 493, 494, 495, 496, 497, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 498, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 499, 500, 501,
+            502, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 503, 504, 505,
+            506, 507, 508, 509, 510, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 511,
+            512, 0, 513, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 514, 0, 0, 515, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 516, 517,
+            518, 0, 0, 519, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 520,
+            521, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 522, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 523, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 524,
+            525, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 526, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 527, 0, 0, 0, 0, 0, 0, 0, 0, 0, 528, 0, 0, 0, 0, 529, 0, 0, 0, 0, 530, 0, 0, 0,
+            0, 531, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 532, 0, 0, 0, 0, 0, 0, 0, 0, 0, 533, 0, 534,
+            535, 536, 537, 538, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 539, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 540, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 541, 0, 0, 0, 0, 542, 0, 0, 0, 0, 543, 0, 0, 0, 0, 544, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 545, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 546, 547, 548, 549, 550, 551, 552, 553, 554, 555, 556, 557, 558, 559, 560, 561,
+            562, 563, 564, 565, 566, 567, 568, 569, 570, 571, 572, 573, 574, 575, 576, 577,
+        ],
+        [
+            578, 579, 580, 581, 582, 583, 0, 584, 0, 0, 0, 0, 0, 585, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 586, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 29, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 587,
+            588, 589, 590, 591, 592, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 29, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 29, 29, 29, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            330, 332, 342, 345, 346, 346, 354, 361, 593, 594, 0, 0, 0, 0, 0, 0, 595, 596, 597, 598,
+            599, 600, 601, 602, 603, 604, 605, 606, 586, 607, 608, 609, 610, 611, 612, 613, 614,
+            615, 616, 617, 618, 619, 620, 621, 622, 623, 624, 625, 626, 627, 628, 629, 630, 631,
+            632, 633, 634, 635, 636, 0, 0, 637, 638, 639,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 46, 2, 0, 4, 5, 143, 7, 8, 9, 10, 11,
+            12, 13, 14, 0, 15, 216, 16, 18,
+        ],
+        [
+            20, 21, 23, 1, 640, 641, 642, 2, 4, 5, 144, 145, 643, 7, 0, 11, 13, 108, 15, 138, 644,
+            645, 16, 20, 21, 646, 152, 22, 647, 271, 272, 273, 288, 289, 9, 18, 21, 22, 271, 272,
+            284, 288, 289, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 341, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 648, 3,
+            649, 56, 643, 6, 650, 651, 652, 150, 149, 653, 654, 655, 656, 657, 658, 659, 660, 153,
+            661, 662, 154, 663, 664, 160, 665, 231, 164, 666, 165, 232, 26, 667, 668, 168, 277,
+        ],
+        [
+            669, 0, 670, 0, 671, 0, 672, 0, 673, 0, 674, 0, 675, 0, 676, 0, 677, 0, 678, 0, 679, 0,
+            680, 0, 681, 0, 682, 0, 683, 0, 684, 0, 685, 0, 686, 0, 687, 0, 688, 0, 689, 0, 690, 0,
+            691, 0, 692, 0, 693, 0, 694, 0, 695, 0, 696, 0, 697, 0, 698, 0, 699, 0, 700, 0,
+        ],
+        [
+            701, 0, 702, 0, 703, 0, 704, 0, 705, 0, 706, 0, 707, 0, 708, 0, 709, 0, 710, 0, 711, 0,
+            712, 0, 713, 0, 714, 0, 715, 0, 716, 0, 717, 0, 718, 0, 719, 0, 720, 0, 721, 0, 722, 0,
+            723, 0, 724, 0, 725, 0, 726, 0, 727, 0, 728, 0, 729, 0, 730, 0, 731, 0, 732, 0,
+        ],
+        [
+            733, 0, 734, 0, 735, 0, 736, 0, 737, 0, 738, 0, 739, 0, 740, 0, 741, 0, 742, 0, 743, 0,
+            0, 0, 0, 0, 744, 717, 0, 0, 70, 0, 745, 0, 746, 0, 747, 0, 748, 0, 749, 0, 750, 0, 751,
+            0, 752, 0, 753, 0, 754, 0, 755, 0, 756, 0, 757, 0, 758, 0, 759, 0, 760, 0,
+        ],
+        [
+            761, 0, 762, 0, 763, 0, 764, 0, 765, 0, 766, 0, 767, 0, 768, 0, 769, 0, 770, 0, 771, 0,
+            772, 0, 773, 0, 774, 0, 775, 0, 776, 0, 777, 0, 778, 0, 779, 0, 780, 0, 781, 0, 782, 0,
+            783, 0, 784, 0, 785, 0, 786, 0, 787, 0, 788, 0, 789, 0, 790, 0, 791, 0, 792, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 793, 794, 795, 796, 797, 798, 799, 800, 0, 0, 0, 0, 0, 0, 0, 0,
+            801, 802, 803, 804, 805, 806, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 807, 808, 809, 810, 811,
+            812, 813, 814, 0, 0, 0, 0, 0, 0, 0, 0, 815, 816, 817, 818, 819, 820, 821, 822,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 823, 824, 825, 826, 827, 828, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            829, 0, 830, 0, 831, 0, 832, 0, 0, 0, 0, 0, 0, 0, 0, 833, 834, 835, 836, 837, 838, 839,
+            840, 0, 262, 0, 264, 0, 265, 0, 266, 0, 267, 0, 268, 0, 269, 0, 0,
+        ],
+        [
+            841, 842, 843, 844, 845, 846, 847, 848, 841, 842, 843, 844, 845, 846, 847, 848, 849,
+            850, 851, 852, 853, 854, 855, 856, 849, 850, 851, 852, 853, 854, 855, 856, 857, 858,
+            859, 860, 861, 862, 863, 864, 857, 858, 859, 860, 861, 862, 863, 864, 0, 0, 865, 866,
+            867, 0, 0, 868, 869, 870, 871, 262, 866, 872, 253, 872,
+        ],
+        [
+            873, 874, 875, 876, 877, 0, 0, 878, 879, 264, 880, 265, 876, 881, 882, 883, 0, 0, 0,
+            884, 0, 0, 0, 0, 885, 886, 887, 266, 0, 888, 889, 890, 0, 0, 0, 891, 0, 0, 0, 0, 892,
+            893, 894, 268, 895, 896, 261, 897, 0, 0, 898, 899, 900, 0, 0, 901, 902, 267, 903, 269,
+            899, 33, 904, 0,
+        ],
+        [
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 29, 29, 29, 29, 29, 0, 905, 0, 0, 0, 0, 0,
+            906, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 907, 908, 909, 0, 0, 0, 29, 29, 29, 29, 29, 27,
+            0, 0, 0, 910, 911, 0, 912, 913, 0, 0, 0, 0, 914, 0, 915, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 916, 917, 918, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 919, 0, 0, 0,
+            0, 0, 0, 0, 27, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 920, 9,
+            0, 0, 921, 922, 923, 924, 925, 926, 927, 928, 929, 930, 931, 14,
+        ],
+        [
+            920, 36, 31, 32, 921, 922, 923, 924, 925, 926, 927, 928, 929, 930, 931, 0, 1, 5, 15, 24,
+            144, 8, 11, 12, 13, 14, 16, 19, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 932, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            933, 934, 3, 935, 0, 936, 937, 145, 0, 938, 7, 8, 8, 8, 8, 90, 9, 9, 12, 12, 0, 14, 939,
+            0, 0, 16, 17, 18, 18, 18, 0, 0, 940, 941, 942, 0, 26, 0, 291, 0, 26, 0, 11, 45, 2, 3, 0,
+            5, 5, 6, 943, 13, 15, 944, 945, 946, 947, 9, 0, 948, 283, 272, 272, 283,
+        ],
+        [
+            949, 0, 0, 0, 0, 4, 4, 5, 9, 10, 0, 0, 0, 0, 0, 0, 950, 951, 952, 953, 954, 955, 956,
+            957, 958, 959, 960, 961, 962, 963, 964, 965, 9, 966, 967, 968, 22, 969, 970, 971, 972,
+            24, 973, 974, 12, 3, 4, 13, 9, 966, 967, 968, 22, 969, 970, 971, 972, 24, 973, 974, 12,
+            3, 4, 13,
+        ],
+        [
+            0, 0, 0, 975, 0, 0, 0, 0, 0, 976, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 977, 978, 0, 979, 980, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 981, 982, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 36, 31, 32, 921, 922, 923, 924, 925, 926, 983, 984, 985, 986, 987, 988, 989,
+            990, 991, 992, 993, 994, 995, 996, 997, 998, 999, 1000, 1001, 1002, 1003, 1004, 1005,
+        ],
+        [
+            1006, 1007, 1008, 1009, 1010, 1011, 1012, 1013, 1014, 1015, 1016, 1017, 1018, 1019,
+            1020, 1021, 1022, 1023, 1024, 1025, 1026, 1027, 1028, 1029, 1030, 1031, 1032, 1033,
+            1034, 1035, 1036, 1037, 1038, 1039, 1040, 1041, 1042, 1043, 1044, 1045, 1046, 1047,
+            1048, 1049, 1050, 1051, 1052, 1053, 1054, 1055, 1056, 1057, 1058, 1059, 1, 2, 3, 4, 5,
+            6, 7, 8, 9, 10,
+        ],
+        [
+            11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 1, 2, 3, 4, 5, 6, 7, 8,
+            9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 920, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1060, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1061, 1062, 1063,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1064, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1065, 1066, 1067, 1068, 1069, 1070, 1071, 1072, 1073, 1074, 1075, 1076, 1077, 1078,
+            1079, 1080, 1081, 1082, 1083, 1084, 1085, 1086, 1087, 1088, 1089, 1090, 1091, 1092,
+            1093, 1094, 1095, 1096, 1097, 1098, 1099, 1100, 1101, 1102, 1103, 1104, 1105, 1106,
+            1107, 1108, 1109, 1110, 1111, 1112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1113, 0, 1114, 1115, 1116, 0, 0, 1117, 0, 1118, 0, 1119, 0, 641, 659, 640, 648,
+            0, 1120, 0, 0, 1121, 0, 0, 0, 0, 0, 0, 10, 22, 1122, 1123,
+        ],
+        [
+            1124, 0, 1125, 0, 1126, 0, 1127, 0, 1128, 0, 1129, 0, 1130, 0, 1131, 0, 1132, 0, 1133,
+            0, 1134, 0, 1135, 0, 1136, 0, 1137, 0, 1138, 0, 1139, 0, 1140, 0, 1141, 0, 1142, 0,
+            1143, 0, 1144, 0, 1145, 0, 1146, 0, 1147, 0, 1148, 0, 1149, 0, 1150, 0, 1151, 0, 1152,
+            0, 1153, 0, 1154, 0, 1155, 0,
+        ],
+        [
+            1156, 0, 1157, 0, 1158, 0, 1159, 0, 1160, 0, 1161, 0, 1162, 0, 1163, 0, 1164, 0, 1165,
+            0, 1166, 0, 1167, 0, 1168, 0, 1169, 0, 1170, 0, 1171, 0, 1172, 0, 1173, 0, 0, 0, 0, 0,
+            0, 0, 0, 1174, 0, 1175, 0, 0, 0, 0, 1176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1177, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 1178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1179, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1180, 1181, 1182, 1183, 1184, 1185, 1186, 1187, 1188, 1189, 1190, 1191, 1192, 1193,
+            1194, 1195, 1196, 1197, 1198, 1199, 1200, 1201, 1202, 1203, 1204, 1205, 1206, 1207,
+            1208, 1209, 1210, 1211, 1212, 1213, 1214, 1215, 1216, 1217, 1218, 1219, 1220, 1221,
+            1222, 1223, 1224, 1225, 1226, 1227, 1228, 1229, 1230, 1231, 1232, 1233, 1234, 1235,
+            1236, 1237, 1238, 1239, 1240, 1241, 1242, 1243,
+        ],
+        [
+            1244, 1245, 1246, 1247, 1248, 1249, 1250, 1251, 1252, 1253, 1254, 1255, 1256, 1257,
+            1258, 1259, 1260, 1261, 1262, 1263, 1264, 1265, 1266, 1267, 1268, 1269, 1270, 1271,
+            1272, 1273, 1274, 1275, 1276, 1277, 1278, 1279, 1280, 1281, 1282, 1283, 1284, 1285,
+            1286, 1287, 1288, 1289, 1290, 1291, 1292, 1293, 1294, 1295, 1296, 1297, 1298, 1299,
+            1300, 1301, 1302, 1303, 1304, 1305, 1306, 1307,
+        ],
+        [
+            1308, 1309, 1310, 1311, 1312, 1313, 1314, 1315, 1316, 1317, 1318, 1319, 1320, 1321,
+            1322, 1323, 1324, 1325, 1326, 1327, 1328, 1329, 1330, 1331, 1332, 1333, 1334, 1335,
+            1336, 1337, 1338, 1339, 1340, 1341, 1342, 1343, 1344, 1345, 1346, 1347, 1348, 1349,
+            1350, 1351, 1352, 1353, 1354, 1355, 1356, 1357, 1358, 1359, 1360, 1361, 1362, 1363,
+            1364, 1365, 1366, 1367, 1368, 1369, 1370, 1371,
+        ],
+        [
+            1372, 1373, 1374, 1375, 1376, 1377, 1378, 1379, 1380, 1381, 1382, 1383, 1384, 1385,
+            1386, 1387, 1388, 1389, 1390, 1391, 1392, 1393, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1394, 0,
+            1203, 1395, 1396, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1397,
+            1398, 0, 0, 1399, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 1400,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1401, 1402, 1403, 1404,
+            1405, 1406, 1407, 1408, 1409, 1410, 1411, 1412, 1413, 1414, 1415,
+        ],
+        [
+            1416, 1417, 1418, 1419, 1420, 1421, 1422, 1423, 1424, 1425, 1426, 1427, 1428, 1429,
+            1430, 1431, 1432, 1433, 1434, 1435, 1436, 1437, 1438, 1439, 1440, 1441, 1442, 1443,
+            1444, 1445, 1446, 1447, 1448, 1449, 1450, 1451, 29, 1452, 1453, 1454, 1455, 1456, 1457,
+            1458, 1459, 1460, 1461, 1462, 1463, 1464, 1465, 1466, 1467, 1468, 1469, 1470, 1471,
+            1472, 1473, 1474, 1475, 1476, 1477, 1478,
+        ],
+        [
+            1479, 1480, 1481, 1482, 1483, 1484, 1485, 1486, 1487, 1488, 1489, 1490, 1491, 1492,
+            1493, 0, 0, 0, 1180, 1186, 1494, 1495, 1496, 1497, 1498, 1499, 1184, 1500, 1501, 1502,
+            1503, 1188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1504, 1505, 1506, 1507, 1508, 1509, 1510, 1511, 1512, 1513, 1514, 1515, 1516, 1517,
+            1518, 1519, 1520, 1521, 1522, 1523, 1524, 1525, 1526, 1527, 1528, 1529, 1530, 1531,
+            1532, 1533, 1534, 0, 1535, 1536, 1537, 1538, 1539, 1540, 1541, 1542, 1543, 1544, 1545,
+            1546, 1547, 1548, 1549, 1550, 1551, 1552, 1553, 1554, 1555, 1556, 1557, 1558, 1559,
+            1560, 1561, 1562, 1563, 1564, 1565, 1566,
+        ],
+        [
+            1567, 1568, 1569, 1570, 1571, 1572, 1246, 1573, 0, 0, 0, 0, 0, 0, 0, 0, 1574, 1575,
+            1576, 1577, 1578, 1579, 1580, 1581, 1582, 1583, 1584, 1585, 1586, 1587, 1588, 1589,
+            1401, 1404, 1407, 1409, 1417, 1418, 1421, 1423, 1424, 1426, 1427, 1428, 1429, 1430,
+            1590, 1591, 1592, 1593, 1594, 1595, 1596, 1597, 1598, 1599, 1600, 1601, 1602, 1603,
+            1604, 1605, 1606, 0,
+        ],
+        [
+            1180, 1186, 1494, 1495, 1607, 1608, 1609, 1191, 1610, 1203, 1253, 1265, 1264, 1254,
+            1346, 1211, 1251, 1611, 1612, 1613, 1614, 1615, 1616, 1617, 1618, 1619, 1620, 1217,
+            1621, 1622, 1623, 1624, 1625, 1626, 1627, 1628, 1496, 1497, 1498, 1629, 1630, 1631,
+            1632, 1633, 1634, 1635, 1636, 1637, 1638, 1639, 1640, 1641, 1642, 1643, 1644, 1645,
+            1646, 1647, 1648, 1649, 1650, 1651, 1652, 1653,
+        ],
+        [
+            1654, 1655, 1656, 1657, 1658, 1659, 1660, 1661, 1662, 1663, 1664, 1665, 1666, 1667,
+            1668, 1669, 1670, 1671, 1672, 1673, 1674, 1675, 1676, 1677, 1678, 1679, 1680, 1681,
+            1682, 1683, 1684, 1685, 1686, 1687, 1688, 1689, 1690, 1691, 1692, 1693, 1694, 1695,
+            1696, 1697, 1698, 1699, 1700, 1701, 1702, 1703, 1704, 1705, 1706, 1707, 1708, 1709,
+            1710, 1711, 1712, 1713, 1714, 1715, 1716, 1717,
+        ],
+        [
+            1718, 1719, 1720, 1721, 1722, 1723, 1724, 1725, 1726, 1727, 1728, 1729, 1730, 1731,
+            1732, 1733, 1734, 1735, 1736, 1737, 1738, 1739, 1740, 1741, 1742, 1743, 1744, 1745,
+            1746, 1747, 1748, 1749, 1750, 1751, 1752, 1753, 1754, 1755, 1756, 1757, 1758, 1759,
+            1760, 1761, 1762, 1763, 1764, 1765, 1766, 1767, 1768, 1769, 1770, 1771, 1772, 1773,
+            1774, 1775, 1776, 1777, 1778, 1779, 1780, 1781,
+        ],
+        [
+            1782, 1783, 1784, 1785, 1786, 1787, 1788, 1789, 1790, 1791, 1792, 1793, 1794, 1795,
+            1796, 1797, 1798, 1799, 1800, 1801, 1802, 1803, 1804, 1805, 1806, 1807, 1808, 1809,
+            1810, 1811, 1812, 1813, 1814, 1815, 1816, 1817, 1818, 1819, 1820, 1821, 1822, 1823,
+            1824, 1825, 1826, 1827, 1828, 1829, 1830, 1831, 1832, 1833, 1834, 1835, 1836, 1837,
+            1838, 1839, 1840, 1841, 1842, 1843, 1844, 1845,
+        ],
+        [
+            1846, 1847, 1848, 1849, 1850, 1851, 1852, 1853, 1854, 1855, 1856, 1857, 1858, 1859,
+            1860, 1861, 1862, 1863, 1864, 1865, 1866, 1867, 1868, 1869, 1870, 1871, 1872, 1873,
+            1874, 1875, 1876, 1877, 1878, 1879, 1880, 1881, 1882, 1883, 1884, 1885, 1886, 1846,
+            1887, 1888, 1889, 1890, 1891, 1892, 1893, 1894, 1895, 1896, 1897, 1898, 1899, 1900,
+            1901, 1900, 1902, 1903, 1904, 1905, 1906, 1905,
+        ],
+        [
+            1907, 1908, 1909, 1910, 1911, 1912, 1913, 1914, 1915, 1916, 1917, 1918, 1919, 1920,
+            1876, 1921, 1922, 1923, 1924, 1925, 1852, 1926, 1927, 1928, 1929, 1930, 1931, 1932,
+            1933, 1934, 1935, 1936, 1937, 1938, 1939, 1940, 1941, 1942, 1943, 1944, 1945, 1946,
+            1947, 1948, 1949, 1950, 1951, 1952, 1953, 1954, 1955, 1956, 1957, 1958, 1959, 1960,
+            1961, 1962, 1963, 1964, 1965, 1966, 1967, 1968,
+        ],
+        [
+            1969, 0, 1970, 0, 1971, 0, 1972, 0, 1973, 0, 593, 0, 1974, 0, 1975, 0, 1976, 0, 1977, 0,
+            1978, 0, 1979, 0, 1980, 0, 1981, 0, 1982, 0, 1983, 0, 1984, 0, 1985, 0, 1986, 0, 1987,
+            0, 1988, 0, 1989, 0, 1990, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1991, 0, 1992, 0, 1993, 0, 1994, 0, 1995, 0, 1996, 0, 1997, 0, 1998, 0, 1999, 0, 2000,
+            0, 2001, 0, 2002, 0, 2003, 0, 2004, 0, 354, 356, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 2005, 0, 2006, 0, 2007, 0, 2008, 0, 2009, 0, 2010, 0, 2011, 0, 0, 0,
+            2012, 0, 2013, 0, 2014, 0, 2015, 0, 2016, 0, 2017, 0, 2018, 0,
+        ],
+        [
+            2019, 0, 2020, 0, 2021, 0, 2022, 0, 2023, 0, 2024, 0, 2025, 0, 2026, 0, 2027, 0, 2028,
+            0, 2029, 0, 2030, 0, 2031, 0, 2032, 0, 2033, 0, 2034, 0, 2035, 0, 2036, 0, 2037, 0,
+            2038, 0, 2039, 0, 2040, 0, 2041, 0, 2042, 0, 2042, 0, 0, 0, 0, 0, 0, 0, 0, 2043, 0,
+            2044, 0, 2045, 2046, 0,
+        ],
+        [
+            2047, 0, 2048, 0, 2049, 0, 2050, 0, 0, 0, 0, 2051, 0, 652, 0, 0, 2052, 0, 2053, 0, 0, 0,
+            2054, 0, 2055, 0, 2056, 0, 2057, 0, 2058, 0, 2059, 0, 2060, 0, 2061, 0, 2062, 0, 2063,
+            0, 238, 643, 651, 2064, 653, 0, 2065, 2066, 655, 2067, 2068, 0, 2069, 0, 2070, 0, 2071,
+            0, 2072, 0, 2073, 0,
+        ],
+        [
+            2074, 0, 2075, 0, 2076, 664, 2077, 2078, 0, 2079, 0, 2080, 2081, 0, 2082, 0, 2083, 0,
+            2084, 0, 2085, 0, 2086, 0, 2087, 0, 2088, 0, 2089, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 19, 3, 6, 17, 2090, 0, 0, 90, 112, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            2007, 2091, 1114, 2092, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2093, 0, 0, 0, 0, 0, 0, 2094, 2095,
+            2096, 2097, 2098, 2099, 2100, 2101, 2102, 2103, 2104, 2105, 2106, 2107, 2108, 2109,
+        ],
+        [
+            2110, 2111, 2112, 2113, 2114, 2115, 2116, 2117, 2118, 2119, 2120, 2121, 2122, 2123,
+            2124, 2125, 2126, 2127, 2128, 2129, 2130, 2131, 2132, 2133, 2134, 2135, 2136, 2137,
+            2138, 2139, 2140, 2141, 2142, 2143, 2144, 2145, 2146, 2147, 2148, 2149, 2150, 2151,
+            2152, 2153, 2154, 2155, 2156, 2157, 2158, 2159, 2160, 2161, 2162, 2163, 2164, 2165,
+            2166, 2167, 2168, 2169, 2170, 2171, 2172, 2173,
+        ],
+        [
+            2174, 2175, 1338, 2176, 2177, 2178, 2179, 1392, 1392, 2180, 1346, 2181, 2182, 2183,
+            2184, 2185, 2186, 2187, 2188, 2189, 2190, 2191, 2192, 2193, 2194, 2195, 2196, 2197,
+            2198, 2199, 2200, 2201, 2202, 2203, 2204, 2205, 2206, 2207, 2208, 2209, 2210, 2211,
+            2212, 2213, 2214, 2215, 2216, 2217, 2218, 2219, 2220, 2221, 1304, 2222, 2223, 2224,
+            2225, 2226, 2227, 2228, 2229, 2230, 2231, 2232,
+        ],
+        [
+            1377, 2233, 2234, 2235, 2236, 2237, 2238, 2239, 2240, 2241, 2242, 2243, 2244, 2245,
+            2246, 2247, 2248, 2249, 2250, 2251, 2252, 2253, 2254, 2255, 2256, 2257, 2258, 2259,
+            2190, 2260, 2261, 2262, 2263, 2264, 2265, 2266, 2267, 2268, 2269, 2270, 2271, 2272,
+            2273, 2274, 2275, 2276, 2277, 2278, 2279, 1340, 2280, 2281, 2282, 2283, 2284, 2285,
+            2286, 2287, 2288, 2289, 2290, 2291, 2292, 2293,
+        ],
+        [
+            2294, 1217, 2295, 2296, 2297, 2298, 2299, 2300, 2301, 2302, 1198, 2303, 2304, 2305,
+            2306, 2307, 2308, 2309, 2310, 2311, 2312, 2313, 2314, 2315, 2316, 2317, 2318, 2319,
+            2320, 2321, 2322, 2323, 2324, 2278, 2325, 2326, 2327, 2328, 2329, 2330, 2331, 2332,
+            2262, 2333, 2334, 2335, 2336, 2337, 2338, 2339, 2340, 2341, 2342, 2343, 2344, 2345,
+            2346, 2347, 2348, 2349, 2350, 2351, 2352, 2190,
+        ],
+        [
+            2353, 2354, 2355, 2356, 1391, 2357, 2358, 2359, 2360, 2361, 2362, 2363, 2364, 2365,
+            2366, 2367, 2368, 1608, 2369, 2370, 2371, 2372, 2373, 2374, 2375, 2376, 2377, 2264,
+            2378, 2379, 2380, 2381, 2382, 2383, 2384, 2385, 2386, 2387, 2388, 2389, 2390, 1345,
+            2391, 2392, 2393, 2394, 2395, 2396, 2397, 2398, 2399, 2400, 2401, 2402, 2403, 1296,
+            2404, 2405, 2406, 2407, 2408, 2409, 2410, 2411,
+        ],
+        [
+            2412, 2413, 2414, 2415, 2416, 2417, 2418, 2419, 1323, 2420, 1326, 2421, 2422, 2423, 0,
+            0, 2424, 0, 2425, 0, 0, 2426, 2427, 2428, 2429, 2430, 2431, 2432, 2433, 2434, 1303, 0,
+            2435, 0, 2436, 0, 0, 2437, 2438, 0, 0, 0, 2439, 2440, 2441, 2442, 2443, 2444, 2445,
+            2446, 2447, 2448, 2449, 2450, 2451, 2452, 2453, 2454, 2455, 2456, 1224, 2457, 2458,
+            2459,
+        ],
+        [
+            2460, 2461, 2462, 2463, 2464, 2465, 2466, 2467, 2468, 2469, 2470, 2471, 1613, 2472,
+            2473, 2474, 2475, 1617, 2476, 2477, 2478, 2479, 2480, 2314, 2481, 2482, 2483, 2484,
+            2485, 2486, 2486, 2487, 2488, 2489, 2490, 2491, 2492, 2493, 2494, 2437, 2495, 2496,
+            2497, 2498, 2499, 2500, 0, 0, 2501, 2502, 2503, 2504, 2505, 2506, 2507, 2508, 2451,
+            2509, 2510, 2511, 2424, 2512, 2513, 2514,
+        ],
+        [
+            2515, 2516, 2517, 2518, 2519, 2520, 2521, 2522, 2523, 2459, 2524, 2460, 2525, 2526,
+            2527, 2528, 2529, 2425, 2211, 2530, 2531, 1257, 2279, 2362, 2532, 2533, 2467, 2534,
+            2468, 2535, 2536, 2537, 2427, 2538, 2539, 2540, 2541, 2542, 2428, 2543, 2544, 2545,
+            2546, 2547, 2548, 2480, 2549, 2550, 2314, 2551, 2484, 2552, 2553, 2554, 2555, 2556,
+            2489, 2557, 2436, 2558, 2490, 2260, 2559, 2491,
+        ],
+        [
+            2560, 2493, 2561, 2562, 2563, 2564, 2565, 2495, 2433, 2566, 2496, 2567, 2497, 2568,
+            1392, 2569, 2570, 2571, 2572, 2573, 2574, 2575, 2576, 2577, 2578, 2579, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ],
+        [
+            2580, 2581, 2582, 2583, 2584, 2585, 2585, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2586,
+            2587, 2588, 2589, 2590, 0, 0, 0, 0, 0, 2591, 0, 2592, 2593, 944, 947, 2594, 2595, 2596,
+            2597, 2598, 2599, 927, 2600, 2601, 2602, 2603, 2604, 2605, 2606, 2607, 2608, 2609, 2610,
+            2611, 2612, 0, 2613, 2614, 2615, 2616, 2617, 0, 2618, 0,
+        ],
+        [
+            2619, 2620, 0, 2621, 2622, 0, 2623, 2624, 2625, 2626, 2627, 2628, 2629, 2630, 2631,
+            2632, 2633, 2633, 2634, 2634, 2634, 2634, 2635, 2635, 2635, 2635, 2636, 2636, 2636,
+            2636, 2637, 2637, 2637, 2637, 2638, 2638, 2638, 2638, 2639, 2639, 2639, 2639, 2640,
+            2640, 2640, 2640, 2641, 2641, 2641, 2641, 2642, 2642, 2642, 2642, 2643, 2643, 2643,
+            2643, 2644, 2644, 2644, 2644, 2645, 2645,
+        ],
+        [
+            2645, 2645, 2646, 2646, 2647, 2647, 2648, 2648, 2649, 2649, 2650, 2650, 2651, 2651,
+            2652, 2652, 2652, 2652, 2653, 2653, 2653, 2653, 2654, 2654, 2654, 2654, 2655, 2655,
+            2655, 2655, 2656, 2656, 2657, 2657, 2657, 2657, 2658, 2658, 2659, 2659, 2659, 2659,
+            2660, 2660, 2660, 2660, 2661, 2661, 2662, 2662, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2663, 2663, 2663, 2663, 2664,
+            2664, 2665, 2665, 2666, 2666, 501, 2667, 2667, 2668, 2668, 2669, 2669, 2670, 2670, 2670,
+            2670, 2671, 2671, 2672, 2672, 2673, 2673, 2674, 2674, 2675, 2675, 2676, 2676, 2677,
+            2677, 2678, 2678, 2678, 2679, 2679, 2679, 2680, 2680, 2680, 2680,
+        ],
+        [
+            2681, 2682, 2683, 2679, 2684, 2685, 2686, 2687, 2688, 2689, 2690, 2691, 2692, 2693,
+            2694, 2695, 2696, 2697, 2698, 2699, 2700, 2701, 2702, 2703, 2704, 2705, 2706, 2707,
+            2708, 2709, 2710, 2711, 2712, 2713, 2714, 2715, 2716, 2717, 2718, 2719, 2720, 2721,
+            2722, 2723, 2724, 2725, 2726, 2727, 2728, 2729, 2730, 2731, 2732, 2733, 2734, 2735,
+            2736, 2737, 2738, 2739, 2740, 2741, 2742, 2743,
+        ],
+        [
+            2744, 2745, 2746, 2747, 2748, 2749, 2750, 2751, 2752, 2753, 2754, 2755, 2756, 2757,
+            2758, 2759, 2760, 2761, 2762, 2763, 2764, 2765, 2766, 2767, 2768, 2769, 2770, 2771,
+            2772, 2773, 2774, 2775, 2776, 2777, 2778, 2779, 2780, 2781, 2683, 2782, 2679, 2684,
+            2783, 2784, 2688, 2785, 2689, 2690, 2786, 2787, 2694, 2788, 2695, 2696, 2789, 2790,
+            2698, 2791, 2699, 2700, 2729, 2730, 2733, 2734,
+        ],
+        [
+            2735, 2739, 2740, 2741, 2742, 2746, 2747, 2748, 2792, 2752, 2793, 2794, 2758, 2795,
+            2759, 2760, 2773, 2796, 2797, 2768, 2798, 2769, 2770, 2681, 2682, 2799, 2683, 2800,
+            2685, 2686, 2687, 2688, 2801, 2691, 2692, 2693, 2694, 2802, 2698, 2701, 2702, 2703,
+            2704, 2705, 2707, 2708, 2709, 2710, 2711, 2712, 2803, 2713, 2714, 2715, 2716, 2717,
+            2718, 2720, 2721, 2722, 2723, 2724, 2725, 2726,
+        ],
+        [
+            2727, 2728, 2731, 2732, 2736, 2737, 2738, 2739, 2740, 2743, 2744, 2745, 2746, 2804,
+            2749, 2750, 2751, 2752, 2755, 2756, 2757, 2758, 2805, 2761, 2762, 2806, 2765, 2766,
+            2767, 2768, 2807, 2683, 2800, 2688, 2801, 2694, 2802, 2698, 2808, 2711, 2809, 2810,
+            2811, 2739, 2740, 2746, 2758, 2805, 2768, 2807, 2812, 2813, 2814, 2815, 2816, 2817,
+            2818, 2819, 2820, 2821, 2822, 2823, 2824, 2825,
+        ],
+        [
+            2826, 2827, 2828, 2829, 2830, 2831, 2832, 2833, 2834, 2835, 2836, 2837, 2810, 2838,
+            2839, 2840, 2841, 2815, 2816, 2817, 2818, 2819, 2820, 2821, 2822, 2823, 2824, 2825,
+            2826, 2827, 2828, 2829, 2830, 2831, 2832, 2833, 2834, 2835, 2836, 2837, 2810, 2838,
+            2839, 2840, 2841, 2835, 2836, 2837, 2810, 2809, 2811, 2719, 2708, 2709, 2710, 2835,
+            2836, 2837, 2719, 2720, 2842, 2842, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2843, 2844, 2844, 2845, 2846, 2847,
+            2848, 2849, 2850, 2850, 2851, 2852, 2853, 2854, 2855, 2856, 2856, 2857, 2858, 2858,
+            2859, 2859, 2860, 2861, 2861, 2862, 2863, 2863, 2864, 2864, 2865, 2866, 2866, 2867,
+            2867, 2868, 2869, 2870, 2871, 2871, 2872, 2873, 2874, 2875, 2876, 2876, 2877, 2878,
+        ],
+        [
+            2879, 2880, 2881, 2882, 2882, 2883, 2883, 2884, 2884, 2885, 2886, 2887, 2888, 2889,
+            2890, 2891, 0, 0, 2892, 2893, 2894, 2895, 2896, 2897, 2897, 2898, 2899, 2900, 2901,
+            2901, 2902, 2903, 2904, 2905, 2906, 2907, 2908, 2909, 2910, 2911, 2912, 2913, 2914,
+            2915, 2916, 2917, 2918, 2919, 2920, 2921, 2922, 2923, 2877, 2879, 2924, 2925, 2926,
+            2927, 2928, 2929, 2928, 2926, 2930, 2931,
+        ],
+        [
+            2932, 2933, 2934, 2929, 2870, 2860, 2935, 2936, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2937,
+            2938, 2939, 2940, 2941, 2942, 2943, 2944, 2945, 2946, 2947, 2948, 2949, 0, 0, 0,
+        ],
+        [
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 2950, 2951, 2952, 2953,
+            259, 2954, 2955, 2956, 2957, 909, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 908, 2958, 2959, 2960, 2960, 930, 931, 2961, 2962, 2963, 2964, 2965, 2966,
+            2967, 2968, 981,
+        ],
+        [
+            982, 2969, 2970, 2971, 2972, 0, 0, 2973, 2974, 915, 915, 915, 915, 2960, 2960, 2960,
+            2950, 2951, 907, 0, 259, 2953, 2955, 2954, 2958, 930, 931, 2961, 2962, 2963, 2964, 2975,
+            2976, 2977, 927, 2978, 2979, 2980, 929, 0, 2981, 2982, 2983, 2984, 0, 0, 0, 0, 2985,
+            2986, 2987, 0, 2988, 0, 2989, 2990, 2991, 2992, 2993, 2994, 2995, 2996, 2997, 2998,
+        ],
+        [
+            2999, 3000, 3000, 3001, 3001, 3002, 3002, 3003, 3003, 3004, 3004, 3004, 3004, 3005,
+            3005, 3006, 3006, 3006, 3006, 3007, 3007, 3008, 3008, 3008, 3008, 3009, 3009, 3009,
+            3009, 3010, 3010, 3010, 3010, 3011, 3011, 3011, 3011, 3012, 3012, 3012, 3012, 3013,
+            3013, 3014, 3014, 3015, 3015, 3016, 3016, 3017, 3017, 3017, 3017, 3018, 3018, 3018,
+            3018, 3019, 3019, 3019, 3019, 3020, 3020, 3020,
+        ],
+        [
+            3020, 3021, 3021, 3021, 3021, 3022, 3022, 3022, 3022, 3023, 3023, 3023, 3023, 3024,
+            3024, 3024, 3024, 3025, 3025, 3025, 3025, 3026, 3026, 3026, 3026, 3027, 3027, 3027,
+            3027, 3028, 3028, 3028, 3028, 3029, 3029, 3029, 3029, 3030, 3030, 3030, 3030, 3031,
+            3031, 3031, 3031, 3032, 3032, 2671, 2671, 3033, 3033, 3033, 3033, 3034, 3034, 3035,
+            3035, 3036, 3036, 3037, 3037, 0, 0, 29,
+        ],
+        [
+            0, 2954, 3038, 2975, 2982, 2983, 2976, 3039, 930, 931, 2977, 927, 2950, 2978, 907, 3040,
+            920, 36, 31, 32, 921, 922, 923, 924, 925, 926, 2953, 259, 2979, 929, 2980, 2955, 2984,
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 2973, 2981, 2974, 3041, 2960,
+        ],
+        [
+            897, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 2961, 3042, 2962, 3043, 3044, 3045, 2952, 2969, 2970, 2951, 3046, 1716,
+            3047, 3048, 3049, 3050, 3051, 3052, 3053, 3054, 3055, 3056, 1670, 1671, 1672, 1673,
+            1674, 1675, 1676, 1677, 1678, 1679, 1680, 1681, 1682, 1683, 1684,
+        ],
+        [
+            1685, 1686, 1687, 1688, 1689, 1690, 1691, 1692, 1693, 1694, 1695, 1696, 1697, 1698,
+            1699, 1700, 1701, 1702, 1703, 1704, 1705, 1706, 1707, 1708, 1709, 1710, 1711, 1712,
+            1713, 3057, 3058, 3059, 29, 1401, 1402, 1403, 1404, 1405, 1406, 1407, 1408, 1409, 1410,
+            1411, 1412, 1413, 1414, 1415, 1416, 1417, 1418, 1419, 1420, 1421, 1422, 1423, 1424,
+            1425, 1426, 1427, 1428, 1429, 1430, 0,
+        ],
+        [
+            0, 0, 1431, 1432, 1433, 1434, 1435, 1436, 0, 0, 1437, 1438, 1439, 1440, 1441, 1442, 0,
+            0, 1443, 1444, 1445, 1446, 1447, 1448, 0, 0, 1449, 1450, 1451, 0, 0, 0, 3060, 3061,
+            3062, 30, 3063, 3064, 3065, 0, 3066, 3067, 3068, 3069, 3070, 3071, 3072, 0, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            3073, 3074, 3075, 3076, 3077, 3078, 3079, 3080, 3081, 3082, 3083, 3084, 3085, 3086,
+            3087, 3088, 3089, 3090, 3091, 3092, 3093, 3094, 3095, 3096, 3097, 3098, 3099, 3100,
+            3101, 3102, 3103, 3104, 3105, 3106, 3107, 3108, 3109, 3110, 3111, 3112, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3113, 3114, 3115, 3116, 3117,
+            3118, 3119, 3120, 3121, 3122, 3123, 3124, 3125, 3126, 3127, 3128,
+        ],
+        [
+            3129, 3130, 3131, 3132, 3133, 3134, 3135, 3136, 3137, 3138, 3139, 3140, 3141, 3142,
+            3143, 3144, 3145, 3146, 3147, 3148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3149, 3150, 3151, 3152, 3153,
+            3154, 3155, 3156, 3157, 3158, 3159, 0, 3160, 3161, 3162, 3163,
+        ],
+        [
+            3164, 3165, 3166, 3167, 3168, 3169, 3170, 3171, 3172, 3173, 3174, 0, 3175, 3176, 3177,
+            3178, 3179, 3180, 3181, 0, 3182, 3183, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 3184, 3185, 46, 3186, 135, 0, 3187, 3188, 3189, 3190, 140, 141, 3191, 3192, 3193,
+            3194, 2080, 3195, 147, 3196, 90, 3197, 3198, 3199, 3200, 3201, 2064, 3202, 3203, 3204,
+            3205, 3206, 3207, 63, 3208, 3209, 17, 3210, 3211, 1116, 3212, 158, 3213, 3214, 3215,
+            3216, 162, 3217, 0, 3218, 3219, 3220, 3221, 3222, 3223, 3224, 3225, 3226, 0, 0, 0, 0, 0,
+        ],
+        [
+            3227, 3228, 3229, 3230, 3231, 3232, 3233, 3234, 3235, 3236, 3237, 3238, 3239, 3240,
+            3241, 3242, 3243, 3244, 3245, 3246, 3247, 3248, 3249, 3250, 3251, 3252, 3253, 3254,
+            3255, 3256, 3257, 3258, 3259, 3260, 3261, 3262, 3263, 3264, 3265, 3266, 3267, 3268,
+            3269, 3270, 3271, 3272, 3273, 3274, 3275, 3276, 3277, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3278, 3279, 3280, 3281, 3282, 3283,
+            3284, 3285, 3286, 3287, 3288, 3289, 3290, 3291, 3292, 3293, 3294, 3295, 3296, 3297,
+            3298, 3299, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 3300, 3301, 3302, 3303, 3304, 3305, 3306, 3307, 3308, 3309, 3310, 3311, 3312,
+            3313, 3314, 3315, 3316, 3317, 3318, 3319, 3320, 3321, 3322, 3323, 3324, 3325, 3326,
+            3327, 3328, 3329, 3330, 3331,
+        ],
+        [
+            3332, 3333, 3334, 3335, 3336, 3337, 3338, 3339, 3340, 3341, 3342, 3343, 3344, 3345,
+            3346, 3347, 3348, 3349, 3350, 3351, 3352, 3353, 3354, 3355, 3356, 3357, 3358, 3359,
+            3360, 3361, 3362, 3363, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 3364, 3365, 3366, 3367, 3368, 3369, 3370, 3371, 3372, 3373, 3374, 3375, 3376,
+            3377, 3378, 3379, 3380, 3381, 3382, 3383, 3384, 3385, 3386, 3387, 3388, 0, 0, 0, 0, 0,
+            0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 29, 29, 29, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7,
+            8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 920, 36, 31,
+            32, 921, 922, 923, 924, 925, 926, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 3389, 3390, 3391, 3392, 3393, 3394, 3395, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            29, 29, 29, 29, 29, 29, 29, 29, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 3396, 3397, 3398, 3399, 3400,
+        ],
+        [
+            3401, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+            25, 26, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+            23, 24, 25, 26, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12,
+        ],
+        [
+            13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 1, 2, 3, 4, 5, 6, 7, 0, 9, 10,
+            11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 1, 2, 3, 4, 5, 6, 7, 8,
+            9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        ],
+        [
+            25, 26, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+            23, 24, 25, 26, 1, 0, 3, 4, 0, 0, 7, 0, 0, 10, 11, 0, 0, 14, 15, 16, 17, 0, 19, 20, 21,
+            22, 23, 24, 25, 26, 1, 2, 3, 4, 0, 6, 0, 8, 9, 10,
+        ],
+        [
+            11, 12, 13, 14, 0, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 1, 2, 3, 4, 5, 6, 7, 8,
+            9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 1, 2, 3, 4, 5, 6,
+            7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+        ],
+        [
+            23, 24, 25, 26, 1, 2, 0, 4, 5, 6, 7, 0, 0, 10, 11, 12, 13, 14, 15, 16, 17, 0, 19, 20,
+            21, 22, 23, 24, 25, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+            19, 20, 21, 22, 23, 24, 25, 26, 1, 2, 0, 4, 5, 6, 7, 0,
+        ],
+        [
+            9, 10, 11, 12, 13, 0, 15, 0, 0, 0, 19, 20, 21, 22, 23, 24, 25, 0, 1, 2, 3, 4, 5, 6, 7,
+            8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 1, 2, 3, 4, 5,
+            6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+        ],
+        [
+            21, 22, 23, 24, 25, 26, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+            19, 20, 21, 22, 23, 24, 25, 26, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 1, 2, 3, 4, 5, 6,
+        ],
+        [
+            7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 1, 2, 3, 4,
+            5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 1, 2,
+            3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
+        ],
+        [
+            19, 20, 21, 22, 23, 24, 25, 26, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14,
+            15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 1, 2, 3, 4,
+        ],
+        [
+            5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 1, 2,
+            3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+        ],
+        [
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14,
+            15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 3402, 3403, 0, 0, 270, 271, 272, 273,
+            274, 275, 276, 277, 253, 278, 279, 34, 280, 281, 282, 283, 284, 277, 285, 286, 287, 288,
+            289, 290,
+        ],
+        [
+            291, 3404, 270, 271, 272, 273, 274, 275, 276, 277, 253, 278, 279, 34, 280, 281, 282,
+            283, 284, 285, 285, 286, 287, 288, 289, 290, 291, 3405, 274, 277, 278, 288, 284, 283,
+            270, 271, 272, 273, 274, 275, 276, 277, 253, 278, 279, 34, 280, 281, 282, 283, 284, 277,
+            285, 286, 287, 288, 289, 290, 291, 3404, 270, 271, 272, 273,
+        ],
+        [
+            274, 275, 276, 277, 253, 278, 279, 34, 280, 281, 282, 283, 284, 285, 285, 286, 287, 288,
+            289, 290, 291, 3405, 274, 277, 278, 288, 284, 283, 270, 271, 272, 273, 274, 275, 276,
+            277, 253, 278, 279, 34, 280, 281, 282, 283, 284, 277, 285, 286, 287, 288, 289, 290, 291,
+            3404, 270, 271, 272, 273, 274, 275, 276, 277, 253, 278,
+        ],
+        [
+            279, 34, 280, 281, 282, 283, 284, 285, 285, 286, 287, 288, 289, 290, 291, 3405, 274,
+            277, 278, 288, 284, 283, 270, 271, 272, 273, 274, 275, 276, 277, 253, 278, 279, 34, 280,
+            281, 282, 283, 284, 277, 285, 286, 287, 288, 289, 290, 291, 3404, 270, 271, 272, 273,
+            274, 275, 276, 277, 253, 278, 279, 34, 280, 281, 282, 283,
+        ],
+        [
+            284, 285, 285, 286, 287, 288, 289, 290, 291, 3405, 274, 277, 278, 288, 284, 283, 270,
+            271, 272, 273, 274, 275, 276, 277, 253, 278, 279, 34, 280, 281, 282, 283, 284, 277, 285,
+            286, 287, 288, 289, 290, 291, 3404, 270, 271, 272, 273, 274, 275, 276, 277, 253, 278,
+            279, 34, 280, 281, 282, 283, 284, 285, 285, 286, 287, 288,
+        ],
+        [
+            289, 290, 291, 3405, 274, 277, 278, 288, 284, 283, 297, 297, 0, 0, 920, 36, 31, 32, 921,
+            922, 923, 924, 925, 926, 920, 36, 31, 32, 921, 922, 923, 924, 925, 926, 920, 36, 31, 32,
+            921, 922, 923, 924, 925, 926, 920, 36, 31, 32, 921, 922, 923, 924, 925, 926, 920, 36,
+            31, 32, 921, 922, 923, 924, 925, 926,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 328, 329, 330, 331, 332, 333,
+            334, 335, 336, 338, 339, 340, 342, 343, 344, 345,
+        ],
+        [
+            346, 347, 348, 349, 350, 351, 352, 355, 357, 358, 1995, 416, 318, 320, 424, 395, 404,
+            328, 329, 330, 331, 332, 333, 334, 335, 336, 338, 339, 342, 343, 345, 347, 348, 349,
+            350, 351, 352, 354, 355, 380, 318, 317, 327, 393, 1976, 396, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            3406, 3407, 3408, 3409, 3410, 3411, 3412, 3413, 3414, 3415, 3416, 3417, 3418, 3419,
+            3420, 3421, 3422, 3423, 3424, 3425, 3426, 3427, 3428, 3429, 3430, 3431, 3432, 3433,
+            3434, 3435, 3436, 3437, 3438, 3439, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            3005, 3006, 3010, 3013, 0, 3032, 3016, 3011, 3021, 3033, 3027, 3028, 3029, 3030, 3017,
+            3023, 3025, 3019, 3026, 3015, 3018, 3008, 3009, 3012, 3014, 3020, 3022, 3024, 3440,
+            2656, 3441, 3442, 0, 3006, 3010, 0, 3031, 0, 0, 3011, 0, 3033, 3027, 3028, 3029, 3030,
+            3017, 3023, 3025, 3019, 3026, 0, 3018, 3008, 3009, 3012, 0, 3020, 0, 3024, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 3010, 0, 0, 0, 0, 3011, 0, 3033, 0, 3028, 0, 3030, 3017, 3023, 0, 3019, 3026, 0,
+            3018, 0, 0, 3012, 0, 3020, 0, 3024, 0, 2656, 0, 3442, 0, 3006, 3010, 0, 3031, 0, 0,
+            3011, 3021, 3033, 3027, 0, 3029, 3030, 3017, 3023, 3025, 3019, 3026, 0, 3018, 3008,
+            3009, 3012, 0, 3020, 3022, 3024, 3440, 0, 3441, 0,
+        ],
+        [
+            3005, 3006, 3010, 3013, 3031, 3032, 3016, 3011, 3021, 3033, 0, 3028, 3029, 3030, 3017,
+            3023, 3025, 3019, 3026, 3015, 3018, 3008, 3009, 3012, 3014, 3020, 3022, 3024, 0, 0, 0,
+            0, 0, 3006, 3010, 3013, 0, 3032, 3016, 3011, 3021, 3033, 0, 3028, 3029, 3030, 3017,
+            3023, 3025, 3019, 3026, 3015, 3018, 3008, 3009, 3012, 3014, 3020, 3022, 3024, 0, 0, 0,
+            0,
+        ],
+        [
+            3443, 3444, 3445, 3446, 3447, 3448, 3449, 3450, 3451, 3452, 3453, 0, 0, 0, 0, 0, 1034,
+            1035, 1036, 1037, 1038, 1039, 1040, 1041, 1042, 1043, 1044, 1045, 1046, 1047, 1048,
+            1049, 1050, 1051, 1052, 1053, 1054, 1055, 1056, 1057, 1058, 1059, 3454, 3, 18, 1912,
+            3455, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+        ],
+        [
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 3456, 1900, 3457, 70, 3458, 3459, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3460, 3461, 3462, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3463, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            3464, 3465, 1680, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1243, 3466, 3467, 3468, 1186,
+            3469, 3470, 1502, 3471, 3472, 3473, 2352, 3474, 3475, 3476, 3477, 3478, 3479, 1279,
+            3480, 3481, 3482, 3483, 3484, 3485, 1180, 1494, 3486, 1629, 1497, 1630, 3487, 1335,
+            3488, 3489, 3490, 3491, 3492, 1612, 1253, 3493, 3494, 3495, 3496, 0, 0, 0, 0,
+        ],
+        [
+            3497, 3498, 3499, 3500, 3501, 3502, 3503, 3504, 3505, 0, 0, 0, 0, 0, 0, 0, 3506, 3507,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 920, 36, 31, 32, 921, 922, 923,
+            924, 925, 926, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            3508, 3509, 3510, 3511, 3512, 2445, 3513, 3514, 3515, 3516, 2446, 3517, 3518, 3519,
+            2447, 3520, 3521, 3522, 3523, 3524, 3525, 3476, 3526, 3527, 3528, 3529, 3530, 2502,
+            3531, 1196, 3532, 3533, 3534, 3535, 3494, 3536, 3537, 2507, 2448, 2449, 2508, 3538,
+            3539, 2266, 3540, 2450, 3541, 3542, 3543, 3544, 3544, 3544, 3545, 3546, 3547, 3548,
+            3549, 3550, 3551, 3552, 3553, 3554, 3555, 3556,
+        ],
+        [
+            3557, 3558, 3559, 3560, 3561, 3562, 3562, 2510, 3563, 3564, 3565, 3566, 2452, 3567,
+            3568, 3569, 2412, 3570, 3571, 3572, 3573, 3574, 3575, 3576, 3577, 3578, 3579, 3580,
+            3581, 3469, 3582, 3583, 3584, 3585, 3586, 3587, 3588, 3589, 3590, 3591, 3592, 3593,
+            3594, 3594, 3595, 3596, 3597, 2262, 3598, 3599, 3600, 3601, 3602, 1222, 3603, 3604,
+            1224, 3605, 3606, 3607, 3608, 3609, 3610, 3611,
+        ],
+        [
+            3612, 3613, 3614, 3615, 3616, 3617, 3618, 3619, 3620, 3621, 3622, 3623, 3624, 3625,
+            2210, 3626, 1234, 3627, 3627, 3628, 3629, 3629, 3630, 3631, 3632, 3633, 3634, 3635,
+            3636, 3637, 3638, 3639, 3640, 3641, 3642, 2457, 3643, 3644, 3645, 3646, 2522, 3646,
+            3647, 2459, 3648, 3649, 3650, 3651, 2460, 2183, 3652, 3653, 3654, 3655, 3656, 3657,
+            3658, 3659, 3660, 3661, 3662, 3663, 3664, 3665,
+        ],
+        [
+            3666, 3667, 3668, 3669, 3670, 3671, 3672, 3673, 2461, 3674, 3675, 3676, 3677, 3678,
+            3679, 2463, 3680, 3681, 3682, 3683, 3684, 3685, 3686, 3687, 2211, 2530, 3688, 3689,
+            3690, 3691, 3692, 3693, 3694, 3695, 2464, 3696, 3697, 3698, 3699, 2572, 3700, 3701,
+            3702, 3703, 3704, 3705, 3706, 3707, 3708, 3709, 3710, 3711, 3712, 2279, 3713, 3714,
+            3715, 3716, 3717, 3718, 3719, 3720, 3721, 3722,
+        ],
+        [
+            3723, 2465, 2362, 3724, 3725, 3726, 3727, 3728, 3729, 3730, 3731, 2533, 3732, 3733,
+            3734, 3735, 3736, 3737, 3738, 3739, 2534, 3740, 3741, 3742, 3743, 3744, 3745, 3746,
+            3747, 3748, 3749, 3750, 3751, 2536, 3752, 3753, 3754, 3755, 3756, 3757, 3758, 3759,
+            3760, 3761, 3762, 3762, 3763, 3764, 2538, 3765, 3766, 3767, 3768, 3769, 3770, 3771,
+            2265, 3772, 3773, 3774, 3775, 3776, 3777, 3778,
+        ],
+        [
+            2544, 3779, 3780, 3781, 3782, 3783, 3784, 3784, 2545, 2574, 3785, 3786, 3787, 3788,
+            3789, 2228, 2547, 3790, 3791, 2475, 3792, 3793, 2432, 3794, 3795, 2478, 3796, 3797,
+            3798, 3799, 3799, 3800, 3801, 3802, 3803, 3804, 3805, 3806, 3807, 3808, 3809, 3810,
+            3811, 3812, 3813, 3814, 3815, 3816, 3817, 3818, 3819, 3820, 3821, 3822, 3823, 3824,
+            3825, 3826, 2484, 3827, 3828, 3829, 3830, 3831,
+        ],
+        [
+            3832, 3833, 3834, 3835, 3836, 3837, 3838, 3839, 3840, 3841, 3842, 3628, 3843, 3844,
+            3845, 3846, 3847, 3848, 3849, 3850, 3851, 3852, 3853, 3854, 2282, 3855, 3856, 3857,
+            3858, 3859, 3860, 2487, 3861, 3862, 3863, 3864, 3865, 3866, 3867, 3868, 3869, 3870,
+            3871, 3872, 3873, 3874, 3875, 3876, 3877, 3878, 3879, 3880, 2223, 3881, 3882, 3883,
+            3884, 3885, 3886, 2554, 3887, 3888, 3889, 3890,
+        ],
+        [
+            3891, 3892, 3893, 3894, 1324, 3895, 3896, 3897, 3898, 3899, 3900, 3901, 3902, 3903,
+            3904, 3905, 2559, 2560, 1331, 3906, 3907, 3908, 3909, 3910, 3911, 3912, 3913, 3914,
+            3915, 3916, 3917, 2561, 3918, 3919, 3920, 3921, 3922, 3923, 3924, 3925, 3926, 3927,
+            3928, 3929, 3930, 3931, 3932, 3933, 3934, 3935, 3936, 3937, 3938, 3939, 3940, 3941,
+            3942, 3943, 3944, 3945, 3946, 3947, 2567, 2567,
+        ],
+        [
+            3948, 3949, 3950, 3951, 3952, 3953, 3954, 3955, 3956, 3957, 2568, 3958, 3959, 3960,
+            3961, 3962, 3963, 3964, 3965, 3966, 3967, 1379, 3968, 1383, 3969, 3970, 3971, 3972,
+            1388, 3973, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+            29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29, 29,
+        ],
+    ],
+};
+
+/// The `NFC_Quick_Check` of every codepoint
+pub(crate) static NFC_QUICK_CHECK: Utf8Trie<[QuickCheck; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        4, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 10, 11, 11, 11, 11, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 5, 6, 7, 8, 9, 10, 0, 0, 7, 11, 7, 12, 0, 13, 0, 14, 7, 12, 0, 15,
+            0, 0, 0, 0, 0, 16, 17, 0,
+        ],
+        [
+            18, 0, 0, 0, 0, 19, 20, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 23, 24, 25,
+        ],
+        [
+            26, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 31, 31, 31, 31, 32, 33, 31, 34, 35, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 37, 0, 38, 0, 0, 0, 0, 0, 0, 0, 7, 12, 39, 40, 0, 0, 41, 0, 0, 0, 42, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 45, 46, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 31, 31, 31, 31, 31, 31, 31, 31, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 4, 6, 4, 4, 4, 4, 7, 4, 4, 4, 4, 4, 4,
+            8, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 9, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+    ],
+    leaves: &[
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            M, M, M, M, M, Y, M, M, M, M, M, M, M, Y, Y, M, Y, M, Y, M, M, Y, Y, Y, Y, Y, Y, M, Y,
+            Y, Y, Y, Y, Y, Y, M, M, M, M, M, M, Y, Y, Y, Y, M, M, Y, M, M, Y, Y, Y, Y, Y, Y, M, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            N, N, M, N, N, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, N, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, M, M, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, M, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N,
+            N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, M, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, N,
+            N, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, N, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, Y,
+            Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, M, Y, Y, Y, Y, N,
+            N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, M, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, N, Y, Y, Y, Y, N, Y, Y, Y, Y, N,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, N, N, Y, N, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            N, Y, Y, Y, Y, N, Y, Y, Y, Y, N, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M,
+            M, M, M, M, M, M,
+        ],
+        [
+            M, M, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, N, Y, N, Y, N, Y, N,
+            Y, N, Y, N, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, N, Y, Y, N, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, N, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, N, Y,
+            Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, N,
+            Y, N, Y, N, Y, Y,
+        ],
+        [
+            N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, M, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, N, Y, N, Y, Y, N, N, N, N, N, N, N, N,
+            N, N, Y, N, Y, N, Y, Y, N, N, Y, Y, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            N, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, N, N,
+            N, N, N, Y, N, Y,
+        ],
+        [
+            N, N, Y, N, N, Y, N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            M, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y,
+            Y, M, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, M, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            M, Y, Y, M, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, M, M, M, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, N, N, N, N, N,
+        ],
+        [
+            N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+    ],
+};
+
+/// The `NFKC_Quick_Check` of every codepoint
+pub(crate) static NFKC_QUICK_CHECK: Utf8Trie<[QuickCheck; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 3, 0, 4, 0, 0, 5, 6, 7, 8, 9, 10, 0, 0, 0, 0, 0, 0, 11, 0,
+        0, 12, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 6, 15, 16, 16, 16, 16,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 0, 1, 0, 2, 3, 0, 4, 0, 0, 5, 6, 7, 8, 9, 10, 0, 0, 0, 0, 0, 0, 11, 0, 0, 12, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 13, 14, 15, 16, 17, 18, 0, 0, 15, 19, 15, 20, 0, 21, 0, 22, 15,
+            20, 0, 23, 24, 0, 24, 25, 26, 27, 28, 0,
+        ],
+        [
+            29, 0, 0, 30, 0, 31, 32, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 35, 36, 37, 0,
+            0, 0, 38, 0, 0, 39, 40, 41,
+        ],
+        [
+            42, 43, 44, 0, 45, 46, 47, 0, 48, 0, 0, 0, 49, 0, 0, 0, 0, 50, 51, 52, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 53, 0, 54, 0, 0, 0, 0, 0, 55, 0, 0, 0, 56,
+            0, 0, 0, 0, 57, 24, 51, 51, 51, 58,
+        ],
+        [
+            59, 0, 60, 61, 62, 51, 63, 0, 64, 65, 51, 51, 51, 51, 51, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0,
+            66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 51, 51, 51, 51, 69, 70, 51, 71, 72, 73, 74, 75, 51, 51, 51, 51, 76,
+            77, 78, 79, 80, 81, 51, 82, 83, 51, 84, 85,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 87, 0, 88, 0, 0, 0, 0, 0, 0, 0, 15, 20, 89, 90, 0, 0, 91, 0, 0, 0, 92, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 96, 97, 98, 0, 0, 0, 0, 0, 0, 0, 0, 51, 99, 100, 101, 102, 103, 51, 51,
+            51, 51, 104, 51, 51, 51, 51, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            106, 107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 108,
+            109, 110, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 111, 112, 113, 0, 114, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 51, 51, 51, 51, 51, 51, 51, 51, 117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 6, 7, 8, 4, 4, 4, 4, 9, 4, 4, 4, 4, 4, 10,
+            11, 12, 13, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 14, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+    ],
+    leaves: &[
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, N, Y, N, Y, Y, Y, Y, N, Y, Y, N, N, N, N, Y, Y, N, N,
+            N, Y, N, N, N, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, N,
+        ],
+        [
+            N, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, N,
+        ],
+        [
+            Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N,
+            N, Y, Y, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            M, M, M, M, M, Y, M, M, M, M, M, M, M, Y, Y, M, Y, M, Y, M, M, Y, Y, Y, Y, Y, Y, M, Y,
+            Y, Y, Y, Y, Y, Y, M, M, M, M, M, M, Y, Y, Y, Y, M, M, Y, M, M, Y, Y, Y, Y, Y, Y, M, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            N, N, M, N, N, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y,
+            N, Y, Y, Y, N, Y,
+        ],
+        [
+            Y, Y, Y, Y, N, N, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, Y, N, N, Y, Y, Y, N,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, M, M, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, M, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N,
+            N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, M, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, N,
+            N, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, N, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, Y,
+            Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, M, Y, Y, Y, Y, N,
+            N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, M, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N,
+            N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, N, Y, Y, Y, Y, N, Y, Y, Y, Y, N,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, N, N, N, N, N,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            N, Y, Y, Y, Y, N, Y, Y, Y, Y, N, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, N, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M, M,
+            M, M, M, M, M, M,
+        ],
+        [
+            M, M, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, Y, N, N, N, N, N, N, N, N, N, N,
+            N, Y, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, N, Y, N, Y, N, Y, N,
+            Y, N, Y, N, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, N, Y, N, N, N,
+        ],
+        [
+            N, N, Y, Y, Y, Y, Y, Y, Y, N, Y, N, Y, N, N, N, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, N, Y,
+            N, N, N, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, N, Y, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, N,
+            Y, N, Y, N, N, Y,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, N, N, Y, N, N, Y, Y,
+            Y, Y, N, Y, N, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y,
+            Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, Y, Y, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            N, N, N, N, Y, N, N, N, Y, N, N, N, N, N, N, N, N, N, N, N, Y, N, N, Y, Y, N, N, N, N,
+            N, Y, Y, N, N, N, Y, N, Y, N, Y, N, Y, N, N, N, N, Y, N, N, N, Y, N, N, N, N, N, N, N,
+            Y, N, N, N, N, N,
+        ],
+        [
+            N, Y, Y, Y, Y, N, N, N, N, N, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, Y, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, N, N, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, N, N,
+            N, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, M, N, N,
+            Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, N,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, Y, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, Y, Y, Y, N, N,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N,
+            N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, N, Y, N, Y, Y, N, N, N, N, N, N, N, N,
+            N, N, Y, N, Y, N, Y, Y, N, N, Y, Y, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, Y, Y, Y, Y, Y,
+            N, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, N, N,
+            N, N, N, Y, N, Y,
+        ],
+        [
+            N, N, Y, N, N, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, N, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, Y, Y, N, N, N, N, N, N, N, N, N, N, N, N, Y, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, Y, N, N, N, N, Y, Y, Y, Y, N, N, N, Y, N, Y, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, Y, Y, Y,
+        ],
+        [
+            Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, Y,
+        ],
+        [
+            Y, Y, N, N, N, N, N, N, Y, Y, N, N, N, N, N, N, Y, Y, N, N, N, N, N, N, Y, Y, N, N, N,
+            Y, Y, Y, N, N, N, N, N, N, N, Y, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, N, N, N, N, N, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, N, N, N, N, N, N, N, N,
+            N, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            M, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y,
+            Y, M, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, M, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            M, Y, Y, M, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, M, M, M, Y, Y, Y, Y, Y, Y, Y, Y, M, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, N, N, N, N, N,
+        ],
+        [
+            N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            Y, N, N, Y, Y, N, Y, Y, N, N, Y, Y, N, N, N, N, Y, N, N, N, N, N, N, N, N, N, N, N, N,
+            Y, N, Y, N, N, N,
+        ],
+        [
+            N, N, N, N, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, Y, N, N, N, N, Y, Y, N, N, N, N, N, N, N, N, Y, N, N, N, N, N, N, N,
+            Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            Y, N, N, N, N, Y,
+        ],
+        [
+            N, N, N, N, N, Y, N, Y, Y, Y, N, N, N, N, N, N, N, Y, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, Y, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            N, N, N, N, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, Y, N, N, Y, N, Y, Y, N, Y, N, N, N, N, N, N, N, N, N, N, Y, N, N, N, N, Y, N,
+            Y, N, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, N, Y, Y, Y, Y, N, Y, N, Y, N, Y, N, N, N, Y, N, N, Y, N, Y, Y, N, Y, N, Y, N, Y,
+            N, Y, N, Y, N, N, Y, N, Y, Y, N, N, N, N, Y, N, N, N, N, N, N, N, Y, N, N, N, N, Y, N,
+            N, N, N, Y, N, Y,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y,
+            Y, Y, Y, Y, N, N, N, Y, N, N, N, N, N, Y, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, Y, Y, Y, Y,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            N, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, Y, Y, Y, Y,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, Y, Y, Y, Y, Y, Y, Y, N, N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, N, N, N, N, N, N, N, N, N, N,
+            Y, Y, Y, Y, Y, Y,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y, Y,
+            Y, Y, Y, Y, Y, Y,
+        ],
+    ],
+};
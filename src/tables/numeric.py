@@ -0,0 +1,45 @@
+"""Generates src/tables/numeric.rs: the `Numeric_Value` of every codepoint.
+
+    python3 src/tables/numeric.py <path to UCD> > src/tables/numeric.rs
+
+The trie maps a codepoint to an index into `NUMERIC_VALUES`, index 0 marks codepoints without a
+numeric value.
+"""
+
+from ucd import CODEPOINTS, header, parse_lines, parse_range, ucd_dir, value_trie, wrap
+
+
+def main() -> None:
+    ucd = ucd_dir()
+
+    values: list[tuple[int, int]] = [(0, 1)]
+    ids: dict[tuple[int, int], int] = {}
+    indexes = ["0"] * CODEPOINTS
+
+    for fields in parse_lines(ucd / "extracted/DerivedNumericValues.txt"):
+        numerator, _, denominator = fields[3].partition("/")
+        value = (int(numerator), int(denominator or "1"))
+
+        if value not in ids:
+            ids[value] = len(values)
+            values.append(value)
+
+        for cp in parse_range(fields[0]):
+            indexes[cp] = str(ids[value])
+
+    assert len(values) <= 0x100
+
+    print(header("numeric.py", ["extracted/DerivedNumericValues.txt"]))
+    print("use super::Utf8Trie;")
+    print()
+    print("/// Numeric values as `(numerator, denominator)`, indexed by `NUMERIC_VALUE`. Index 0 is unused,")
+    print("/// it marks codepoints without a numeric value")
+    print(f"pub(crate) static NUMERIC_VALUES: [(i64, u32); {len(values)}] = [")
+    print("\n".join(wrap((f"({n:_}, {d:_})," for n, d in values), indent=4)))
+    print("];")
+    print()
+    print(value_trie("NUMERIC_VALUE", "The index of the `Numeric_Value` of every codepoint", "u8", indexes), end="")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,75 @@
+"""Generates src/tables/properties.rs: the general category and the binary properties backing the
+`char` like `is_*` predicates, and the identifier predicates.
+
+    python3 src/tables/properties.py <path to UCD> > src/tables/properties.rs
+"""
+
+from ucd import binary_property, bool_trie, enumerated_property, header, ucd_dir, value_trie
+
+# short alias to `GeneralCategory` variant, in the order of PropertyValueAliases.txt
+CATEGORIES = {
+    "Lu": "UppercaseLetter",
+    "Ll": "LowercaseLetter",
+    "Lt": "TitlecaseLetter",
+    "Lm": "ModifierLetter",
+    "Lo": "OtherLetter",
+    "Mn": "NonspacingMark",
+    "Mc": "SpacingMark",
+    "Me": "EnclosingMark",
+    "Nd": "DecimalNumber",
+    "Nl": "LetterNumber",
+    "No": "OtherNumber",
+    "Pc": "ConnectorPunctuation",
+    "Pd": "DashPunctuation",
+    "Ps": "OpenPunctuation",
+    "Pe": "ClosePunctuation",
+    "Pi": "InitialPunctuation",
+    "Pf": "FinalPunctuation",
+    "Po": "OtherPunctuation",
+    "Sm": "MathSymbol",
+    "Sc": "CurrencySymbol",
+    "Sk": "ModifierSymbol",
+    "So": "OtherSymbol",
+    "Zs": "SpaceSeparator",
+    "Zl": "LineSeparator",
+    "Zp": "ParagraphSeparator",
+    "Cc": "Control",
+    "Cf": "Format",
+    "Co": "PrivateUse",
+    "Cn": "Unassigned",
+}
+
+
+def main() -> None:
+    ucd = ucd_dir()
+
+    categories = enumerated_property(ucd / "extracted/DerivedGeneralCategory.txt", "Cn")
+    # surrogates are never a `Utf8Char`, keep their leaves identical to the unassigned ones
+    categories[0xD800:0xE000] = ["Cn"] * 0x800
+
+    core = ucd / "DerivedCoreProperties.txt"
+    props = ucd / "PropList.txt"
+
+    print(header("properties.py", ["extracted/DerivedGeneralCategory.txt", "DerivedCoreProperties.txt", "PropList.txt"]))
+    print("use super::Utf8Trie;")
+    print("use crate::GeneralCategory;")
+    print()
+
+    for short, long in CATEGORIES.items():
+        print(f"/// `{short}`, shortened to keep the table below readable")
+        print(f"const {short.upper()}: GeneralCategory = GeneralCategory::{long};")
+
+    print()
+    print(value_trie("GENERAL_CATEGORY", "The `General_Category` of every codepoint", "GeneralCategory", [c.upper() for c in categories]))
+    print(bool_trie("ALPHABETIC", "Codepoints with the `Alphabetic` property", binary_property(core, "Alphabetic")))
+    print(bool_trie("GRAPHEME_EXTEND", "Codepoints with the `Grapheme_Extend` property", binary_property(core, "Grapheme_Extend")))
+    print(bool_trie("LOWERCASE", "Codepoints with the `Lowercase` property", binary_property(core, "Lowercase")))
+    print(bool_trie("UPPERCASE", "Codepoints with the `Uppercase` property", binary_property(core, "Uppercase")))
+    print(bool_trie("WHITE_SPACE", "Codepoints with the `White_Space` property", binary_property(props, "White_Space")))
+    print(bool_trie("XID_START", "Codepoints with the `XID_Start` property", binary_property(core, "XID_Start")))
+    print(bool_trie("XID_CONTINUE", "Codepoints with the `XID_Continue` property", binary_property(core, "XID_Continue")))
+    print(bool_trie("PATTERN_SYNTAX", "Codepoints with the `Pattern_Syntax` property", binary_property(props, "Pattern_Syntax")), end="")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,4579 @@
+//! Generated by `src/tables/properties.py` from the Unicode 17.0.0 files
+//! extracted/DerivedGeneralCategory.txt, DerivedCoreProperties.txt, PropList.txt. Do not edit by hand.
+
+use super::Utf8Trie;
+use crate::GeneralCategory;
+
+/// `Lu`, shortened to keep the table below readable
+const LU: GeneralCategory = GeneralCategory::UppercaseLetter;
+/// `Ll`, shortened to keep the table below readable
+const LL: GeneralCategory = GeneralCategory::LowercaseLetter;
+/// `Lt`, shortened to keep the table below readable
+const LT: GeneralCategory = GeneralCategory::TitlecaseLetter;
+/// `Lm`, shortened to keep the table below readable
+const LM: GeneralCategory = GeneralCategory::ModifierLetter;
+/// `Lo`, shortened to keep the table below readable
+const LO: GeneralCategory = GeneralCategory::OtherLetter;
+/// `Mn`, shortened to keep the table below readable
+const MN: GeneralCategory = GeneralCategory::NonspacingMark;
+/// `Mc`, shortened to keep the table below readable
+const MC: GeneralCategory = GeneralCategory::SpacingMark;
+/// `Me`, shortened to keep the table below readable
+const ME: GeneralCategory = GeneralCategory::EnclosingMark;
+/// `Nd`, shortened to keep the table below readable
+const ND: GeneralCategory = GeneralCategory::DecimalNumber;
+/// `Nl`, shortened to keep the table below readable
+const NL: GeneralCategory = GeneralCategory::LetterNumber;
+/// `No`, shortened to keep the table below readable
+const NO: GeneralCategory = GeneralCategory::OtherNumber;
+/// `Pc`, shortened to keep the table below readable
+const PC: GeneralCategory = GeneralCategory::ConnectorPunctuation;
+/// `Pd`, shortened to keep the table below readable
+const PD: GeneralCategory = GeneralCategory::DashPunctuation;
+/// `Ps`, shortened to keep the table below readable
+const PS: GeneralCategory = GeneralCategory::OpenPunctuation;
+/// `Pe`, shortened to keep the table below readable
+const PE: GeneralCategory = GeneralCategory::ClosePunctuation;
+/// `Pi`, shortened to keep the table below readable
+const PI: GeneralCategory = GeneralCategory::InitialPunctuation;
+/// `Pf`, shortened to keep the table below readable
+const PF: GeneralCategory = GeneralCategory::FinalPunctuation;
+/// `Po`, shortened to keep the table below readable
+const PO: GeneralCategory = GeneralCategory::OtherPunctuation;
+/// `Sm`, shortened to keep the table below readable
+const SM: GeneralCategory = GeneralCategory::MathSymbol;
+/// `Sc`, shortened to keep the table below readable
+const SC: GeneralCategory = GeneralCategory::CurrencySymbol;
+/// `Sk`, shortened to keep the table below readable
+const SK: GeneralCategory = GeneralCategory::ModifierSymbol;
+/// `So`, shortened to keep the table below readable
+const SO: GeneralCategory = GeneralCategory::OtherSymbol;
+/// `Zs`, shortened to keep the table below readable
+const ZS: GeneralCategory = GeneralCategory::SpaceSeparator;
+/// `Zl`, shortened to keep the table below readable
+const ZL: GeneralCategory = GeneralCategory::LineSeparator;
+/// `Zp`, shortened to keep the table below readable
+const ZP: GeneralCategory = GeneralCategory::ParagraphSeparator;
+/// `Cc`, shortened to keep the table below readable
+const CC: GeneralCategory = GeneralCategory::Control;
+/// `Cf`, shortened to keep the table below readable
+const CF: GeneralCategory = GeneralCategory::Format;
+/// `Co`, shortened to keep the table below readable
+const CO: GeneralCategory = GeneralCategory::PrivateUse;
+/// `Cn`, shortened to keep the table below readable
+const CN: GeneralCategory = GeneralCategory::Unassigned;
+
+/// The `General_Category` of every codepoint
+pub(crate) static GENERAL_CATEGORY: Utf8Trie<[GeneralCategory; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+        21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 5, 5, 7, 8, 9,
+        31, 32, 32, 35, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45,
+            46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63,
+        ],
+        [
+            64, 65, 66, 67, 26, 26, 26, 26, 26, 68, 69, 70, 71, 72, 73, 74, 75, 26, 26, 26, 26, 26,
+            26, 26, 26, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94,
+            95, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 12, 106, 106, 107, 106, 108, 109, 110,
+            111,
+        ],
+        [
+            112, 113, 114, 115, 116, 117, 118, 119, 120, 120, 120, 120, 121, 122, 123, 124, 125,
+            126, 127, 128, 129, 129, 130, 131, 129, 132, 129, 129, 129, 133, 134, 135, 129, 129,
+            129, 129, 120, 120, 136, 137, 120, 120, 120, 120, 138, 139, 129, 129, 16, 140, 106, 141,
+            142, 143, 144, 145, 146, 147, 148, 149, 129, 129, 129, 150,
+        ],
+        [
+            151, 152, 153, 154, 155, 26, 156, 157, 158, 159, 160, 129, 129, 129, 129, 129, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 129, 26, 26, 26, 26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+        ],
+        [
+            161, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 162, 163, 26,
+            26, 26, 26, 164, 165, 166, 167, 168, 169, 170, 171, 172, 173, 174, 175, 176, 177, 178,
+            179, 180, 181, 182, 183, 184, 185, 186, 187, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 188, 189, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190,
+        ],
+        [
+            191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191,
+            191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191,
+            191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191,
+            191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191,
+        ],
+        [
+            191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191,
+            191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191,
+            191, 191, 26, 26, 26, 26, 26, 192, 26, 193, 194, 195, 196, 197, 26, 26, 26, 26, 198,
+            199, 200, 201, 202, 203, 26, 204, 205, 206, 207, 208,
+        ],
+        [
+            209, 210, 26, 211, 212, 213, 214, 215, 190, 190, 216, 217, 218, 219, 220, 221, 222, 223,
+            224, 225, 226, 227, 228, 229, 26, 26, 26, 26, 230, 231, 232, 190, 233, 234, 235, 236,
+            237, 193, 238, 239, 240, 241, 242, 243, 244, 245, 246, 190, 26, 247, 248, 249, 250, 251,
+            252, 190, 190, 253, 254, 255, 256, 257, 258, 259,
+        ],
+        [
+            260, 261, 262, 263, 264, 265, 266, 267, 268, 269, 270, 271, 272, 273, 274, 275, 276,
+            277, 278, 279, 190, 190, 280, 281, 282, 283, 284, 285, 286, 287, 190, 190, 288, 190,
+            289, 290, 291, 292, 293, 294, 295, 296, 297, 298, 299, 300, 190, 301, 302, 303, 304,
+            190, 305, 306, 307, 308, 190, 190, 190, 309, 310, 311, 312, 313,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 193, 190, 314, 315, 26, 26, 26,
+            316, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 317, 318,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 319, 320, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 211, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 287, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190,
+        ],
+        [
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+        ],
+        [
+            190, 190, 190, 190, 321, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 26, 26, 26,
+            26, 26, 26, 26, 26, 298, 322, 323, 324, 325, 326, 327, 190, 190, 190, 190, 190, 190,
+            328, 190, 190, 190, 329, 330, 331, 26, 332, 333, 334,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 335, 336, 190, 26, 337, 190, 190, 190, 190, 190, 190, 190,
+            190,
+        ],
+        [
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 338,
+        ],
+        [
+            26, 26, 26, 26, 339, 340, 26, 26, 26, 26, 26, 341, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 26, 342, 343, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+        ],
+        [
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 129, 129, 129,
+            344, 129, 129, 129, 129, 129, 129, 345, 346, 347, 348, 129, 349,
+        ],
+        [
+            129, 129, 129, 350, 351, 352, 353, 354, 129, 355, 190, 356, 129, 357, 190, 190, 358,
+            359, 360, 361, 362, 363, 364, 365, 366, 367, 368, 369, 370, 371, 372, 373, 129, 129,
+            129, 129, 129, 129, 129, 129, 374, 375, 376, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 377, 190, 190, 190,
+        ],
+        [
+            378, 379, 380, 190, 381, 382, 190, 190, 190, 190, 383, 384, 190, 190, 190, 190, 190,
+            190, 190, 385, 190, 190, 190, 386, 190, 190, 190, 387, 190, 190, 190, 388, 26, 26, 26,
+            389, 390, 391, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 392, 393, 190,
+            394, 190, 190, 190, 395, 396, 397, 398, 190, 190, 190, 190,
+        ],
+        [
+            399, 129, 400, 401, 402, 129, 403, 404, 405, 406, 190, 190, 129, 129, 129, 407, 129,
+            129, 129, 129, 129, 129, 129, 129, 129, 129, 129, 408, 129, 129, 129, 409, 410, 411,
+            412, 413, 129, 129, 129, 129, 129, 414, 415, 416, 129, 129, 417, 418, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 419, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 420, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 192, 26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 421, 26, 26, 26, 26, 26, 26, 26, 26, 26, 422, 190, 190, 190, 190, 190, 190,
+        ],
+        [
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 26, 26, 26,
+            26, 26, 26, 26, 26, 422, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 423, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 424, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190,
+        ],
+        [
+            0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 5, 5, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 5, 17, 15,
+            18, 19, 20, 21, 22, 23, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 24, 25, 26, 5, 27, 28, 5, 29, 5,
+            30, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15,
+        ],
+        [
+            15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15,
+            15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15,
+            15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15,
+        ],
+        [
+            425, 426, 190, 190, 12, 12, 12, 427, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+            190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190,
+        ],
+        [
+            191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191,
+            191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191,
+            191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191,
+            191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 191, 428,
+        ],
+        [
+            15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15,
+            15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 33, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15,
+            15, 15, 15, 15, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 34,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 34, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+        ],
+    ],
+    leaves: &[
+        [
+            CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC,
+            CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, ZS, PO, PO, PO, SC, PO, PO, PO, PS, PE, PO, SM,
+            PO, PD, PO, PO, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, PO, PO, SM, SM, SM, PO,
+        ],
+        [
+            PO, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, PS, PO, PE, SK, PC, SK, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, PS, SM, PE, SM, CC,
+        ],
+        [
+            CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, CC,
+            CC, CC, CC, CC, CC, CC, CC, CC, CC, CC, ZS, PO, SC, SC, SC, SC, SO, PO, SK, SO, LO, PI,
+            SM, CF, SO, SK, SO, SM, NO, NO, SK, LL, PO, PO, SK, NO, LO, PF, NO, NO, NO, PO,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, SM, LU, LU, LU, LU, LU, LU, LU, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, SM, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LL, LU, LL, LU, LL, LU, LL, LU,
+        ],
+        [
+            LL, LU, LL, LU, LL, LU, LL, LU, LL, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LU, LL, LU, LL, LU, LL, LL,
+        ],
+        [
+            LL, LU, LU, LL, LU, LL, LU, LU, LL, LU, LU, LU, LL, LL, LU, LU, LU, LU, LL, LU, LU, LL,
+            LU, LU, LU, LL, LL, LL, LU, LU, LL, LU, LU, LL, LU, LL, LU, LL, LU, LU, LL, LU, LL, LL,
+            LU, LL, LU, LU, LL, LU, LU, LU, LL, LU, LL, LU, LU, LL, LL, LO, LU, LL, LL, LL,
+        ],
+        [
+            LO, LO, LO, LO, LU, LT, LL, LU, LT, LL, LU, LT, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU,
+            LL, LU, LL, LU, LL, LU, LL, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LL, LU, LT, LL, LU, LL, LU, LU, LU, LL, LU, LL, LU, LL, LU, LL,
+        ],
+        [
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LL, LL, LL, LL, LL, LL, LU, LU, LL, LU, LU, LL,
+        ],
+        [
+            LL, LU, LL, LU, LU, LU, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LO, LO,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM,
+        ],
+        [
+            LM, LM, SK, SK, SK, SK, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, SK, SK, SK, SK,
+            SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, LM, LM, LM, LM, LM, SK, SK, SK, SK, SK, SK, SK,
+            LM, SK, LM, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK,
+        ],
+        [
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+        ],
+        [
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, LU, LL, LU, LL, LM, SK, LU, LL, CN, CN, LM, LL, LL, LL, PO, LU,
+        ],
+        [
+            CN, CN, CN, CN, SK, SK, LU, PO, LU, LU, LU, CN, LU, CN, LU, LU, LL, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, CN, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LU, LL, LL, LU, LU, LU, LL,
+            LL, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LL, LL, LL, LL, LU, LL, SM, LU, LL, LU, LU, LL, LL, LU, LU, LU,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+        ],
+        [
+            LU, LL, SO, MN, MN, MN, MN, MN, ME, ME, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+        ],
+        [
+            LU, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+        ],
+        [
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, CN, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, CN, CN, LM, PO, PO, PO, PO, PO, PO, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, PO, PD, CN, CN, SO, SO, SC, CN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, PD, MN,
+        ],
+        [
+            PO, MN, MN, PO, MN, MN, PO, MN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN,
+            CN, CN, CN, LO, LO, LO, LO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CF, CF, CF, CF, CF, CF, SM, SM, SM, PO, PO, SC, PO, PO, SO, SO, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, PO, CF, PO, PO, PO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LM, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, PO, PO,
+            PO, PO, LO, LO, MN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, PO, LO,
+            MN, MN, MN, MN, MN, MN, MN, CF, SO, MN, MN, MN, MN, MN, MN, LM, LM, MN, MN, SO, MN, MN,
+            MN, MN, LO, LO, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, LO, LO, LO, SO, SO, LO,
+        ],
+        [
+            PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, CN, CF, LO, MN, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+        ],
+        [
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, LM, LM, SO, PO, PO, PO, LM, CN, CN, MN, SC, SC,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            MN, MN, MN, MN, LM, MN, MN, MN, MN, MN, MN, MN, MN, MN, LM, MN, MN, MN, LM, MN, MN, MN,
+            MN, MN, CN, CN, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, MN, MN, MN, CN, CN, PO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, SK, LO, LO, LO, LO, LO, LO, LO, CF, CF, CN, CN, CN, CN,
+            CN, MN, MN, MN, MN, MN, MN, MN, MN, MN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LM, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, CF, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+        ],
+        [
+            MN, MN, MN, MC, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MC, MN, LO, MC, MC,
+        ],
+        [
+            MC, MN, MN, MN, MN, MN, MN, MN, MN, MC, MC, MC, MC, MN, MC, MC, LO, MN, MN, MN, MN, MN,
+            MN, MN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, PO, PO, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, PO, LM, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, MN, MC, MC, CN, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, LO, LO, CN, CN, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO,
+            LO, LO, LO, LO, LO, CN, LO, CN, CN, CN, LO, LO, LO, LO, CN, CN, MN, LO, MC, MC,
+        ],
+        [
+            MC, MN, MN, MN, MN, CN, CN, MC, MC, CN, CN, MC, MC, MN, LO, CN, CN, CN, CN, CN, CN, CN,
+            CN, MC, CN, CN, CN, CN, LO, LO, CN, LO, LO, LO, MN, MN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, LO, LO, SC, SC, NO, NO, NO, NO, NO, NO, SO, SC, LO, PO, MN, CN,
+        ],
+        [
+            CN, MN, MN, MC, CN, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, LO, LO, CN, CN, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO,
+            LO, LO, LO, LO, LO, CN, LO, LO, CN, LO, LO, CN, LO, LO, CN, CN, MN, CN, MC, MC,
+        ],
+        [
+            MC, MN, MN, CN, CN, CN, CN, MN, MN, CN, CN, MN, MN, MN, CN, CN, CN, MN, CN, CN, CN, CN,
+            CN, CN, CN, LO, LO, LO, LO, CN, LO, CN, CN, CN, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, MN, MN, LO, LO, LO, MN, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, MN, MN, MC, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, CN, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO,
+            LO, LO, LO, LO, LO, CN, LO, LO, CN, LO, LO, LO, LO, LO, CN, CN, MN, LO, MC, MC,
+        ],
+        [
+            MC, MN, MN, MN, MN, MN, CN, MN, MN, MC, CN, MC, MC, MN, CN, CN, LO, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, MN, MN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, PO, SC, CN, CN, CN, CN, CN, CN, CN, LO, MN, MN, MN, MN, MN, MN,
+        ],
+        [
+            CN, MN, MC, MC, CN, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, LO, LO, CN, CN, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO,
+            LO, LO, LO, LO, LO, CN, LO, LO, CN, LO, LO, LO, LO, LO, CN, CN, MN, LO, MC, MN,
+        ],
+        [
+            MC, MN, MN, MN, MN, CN, CN, MC, MC, CN, CN, MC, MC, MN, CN, CN, CN, CN, CN, CN, CN, MN,
+            MN, MC, CN, CN, CN, CN, LO, LO, CN, LO, LO, LO, MN, MN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, SO, LO, NO, NO, NO, NO, NO, NO, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, MN, LO, CN, LO, LO, LO, LO, LO, LO, CN, CN, CN, LO, LO, LO, CN, LO, LO, LO, LO,
+            CN, CN, CN, LO, LO, CN, LO, CN, LO, LO, CN, CN, CN, LO, LO, CN, CN, CN, LO, LO, LO, CN,
+            CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, MC, MC,
+        ],
+        [
+            MN, MC, MC, CN, CN, CN, MC, MC, MC, CN, MC, MC, MC, MN, CN, CN, LO, CN, CN, CN, CN, CN,
+            CN, MC, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, NO, NO, NO, SO, SO, SO, SO, SO, SO, SC, SO, CN, CN, CN, CN, CN,
+        ],
+        [
+            MN, MC, MC, MC, MN, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, CN, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, MN, LO, MN, MN,
+        ],
+        [
+            MN, MC, MC, MC, MC, CN, MN, MN, MN, CN, MN, MN, MN, MN, CN, CN, CN, CN, CN, CN, CN, MN,
+            MN, CN, LO, LO, LO, CN, LO, LO, CN, CN, LO, LO, MN, MN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, CN, CN, CN, CN, CN, CN, PO, NO, NO, NO, NO, NO, NO, NO, SO,
+        ],
+        [
+            LO, MN, MC, MC, PO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, CN, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, CN, CN, MN, LO, MC, MN,
+        ],
+        [
+            MC, MC, MC, MC, MC, CN, MN, MC, MC, CN, MC, MC, MN, MN, CN, CN, CN, CN, CN, CN, CN, MC,
+            MC, CN, CN, CN, CN, CN, LO, LO, LO, CN, LO, LO, MN, MN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, LO, LO, MC, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            MN, MN, MC, MC, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, CN, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, LO, MC, MC,
+        ],
+        [
+            MC, MN, MN, MN, MN, CN, MC, MC, MC, CN, MC, MC, MC, MN, LO, SO, CN, CN, CN, CN, LO, LO,
+            LO, MC, NO, NO, NO, NO, NO, NO, NO, LO, LO, LO, MN, MN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, NO, NO, NO, NO, NO, NO, NO, NO, NO, SO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            CN, MN, MC, MC, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, MN, CN, CN, CN, CN, MC, MC, MC, MN, MN, MN, CN,
+            MN, CN, MC, MC, MC, MC, MC, MC, MC, MC, CN, CN, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, CN, MC, MC, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, MN, LO, LO, MN, MN, MN, MN, MN, MN, MN, CN, CN, CN, CN, SC,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LM, MN, MN, MN, MN, MN, MN, MN, MN, PO, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, LO, LO, CN, LO, CN, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, CN, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, MN, LO, LO, MN, MN, MN, MN, MN, MN, MN, MN, MN, LO, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, CN, LM, CN, MN, MN, MN, MN, MN, MN, MN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, CN, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, SO, SO, SO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, SO, PO, SO,
+            SO, SO, MN, MN, SO, SO, SO, SO, SO, SO, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, SO, MN, SO, MN, SO, MN, PS, PE, PS, PE, MC, MC,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, CN, CN, CN, CN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MC,
+        ],
+        [
+            MN, MN, MN, MN, MN, PO, MN, MN, LO, LO, LO, LO, LO, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, CN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, CN, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, MN, SO, SO, SO, SO, SO, SO, CN, SO, SO, PO, PO, PO, PO, PO, SO,
+            SO, SO, SO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MC,
+            MC, MN, MN, MN, MN, MC, MN, MN, MN, MN, MN, MN, MC, MN, MN, MC, MC, MN, MN, LO,
+        ],
+        [
+            ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, PO, PO, PO, PO, PO, PO, LO, LO, LO, LO, LO, LO,
+            MC, MC, MN, MN, LO, LO, LO, LO, MN, MN, MN, LO, MC, MC, MC, LO, LO, MC, MC, MC, MC, MC,
+            MC, MC, LO, LO, LO, MN, MN, MN, MN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, MN, MC, MC, MN, MN, MC, MC, MC, MC, MC, MC, MN, LO, MC, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, MC, MC, MC, MN, SO, SO, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, CN, LU, CN, CN, CN, CN, CN, LU, CN, CN, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, PO, LM, LL, LL, LL,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, CN, LO, CN, LO, LO, LO, LO, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, CN, CN, LO, LO, LO, LO, LO, LO, LO, CN,
+        ],
+        [
+            LO, CN, LO, LO, LO, LO, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO,
+            CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, CN, CN, MN, MN, MN, PO, PO, PO, PO, PO, PO, PO, PO, PO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, CN, CN, LL, LL, LL, LL, LL, LL, CN, CN,
+        ],
+        [
+            PD, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, SO, PO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            ZS, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, PS, PE, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, PO,
+            PO, PO, NL, NL, NL, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, MN, MC,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, MN, MN, MC, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, CN, LO, LO, LO, CN, MN, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, MC, MN, MN, MN, MN, MN, MN, MN, MC, MC,
+        ],
+        [
+            MC, MC, MC, MC, MC, MC, MN, MC, MC, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, PO, PO,
+            PO, LM, PO, PO, PO, SC, LO, MN, CN, CN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN,
+            CN, CN, CN, CN, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            PO, PO, PO, PO, PO, PO, PD, PO, PO, PO, PO, MN, MN, MN, CF, MN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LM, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, MN, MN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, LO, CN,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, MN, MN, MN, MC, MC, MC, MC, MN, MN, MC, MC, MC,
+            CN, CN, CN, CN, MC, MC, MN, MC, MC, MC, MC, MC, MC, MN, MN, MN, CN, CN, CN, CN,
+        ],
+        [
+            SO, CN, CN, CN, PO, PO, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, CN, CN, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, NO, CN, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, MN, MN, MC, MC, MN, CN, CN, PO, PO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MC,
+            MN, MC, MN, MN, MN, MN, MN, MN, MN, CN, MN, MC, MN, MC, MC, MN, MN, MN, MN, MN, MN, MN,
+            MN, MC, MC, MC, MC, MC, MC, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, CN, CN, MN,
+        ],
+        [
+            ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, CN, CN, CN, CN, CN, PO, PO, PO, PO, PO, PO, PO, LM, PO, PO, PO, PO,
+            PO, PO, CN, CN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, ME, MN,
+        ],
+        [
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, CN, CN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            MN, MN, MN, MN, MC, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, MN, MC, MN, MN, MN, MN, MN, MC, MN, MC, MC, MC,
+        ],
+        [
+            MC, MC, MN, MC, MC, LO, LO, LO, LO, LO, LO, LO, LO, CN, PO, PO, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, PO, PO, PO, PO, PO, PO, PO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, SO, SO, SO, SO, SO, SO, SO, SO, SO, PO, PO, PO,
+        ],
+        [
+            MN, MN, MC, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MC, MN, MN, MN, MN, MC, MC, MN, MN, MC, MN,
+            MN, MN, LO, LO, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MC, MN, MN, MC, MC,
+            MC, MN, MC, MN, MN, MN, MC, MC, CN, CN, CN, CN, CN, CN, CN, CN, PO, PO, PO, PO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MC, MC, MC, MC, MC, MC, MC, MC,
+            MN, MN, MN, MN, MN, MN, MN, MN, MC, MC, MN, MN, CN, CN, CN, PO, PO, PO, PO, PO,
+        ],
+        [
+            ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, LO, LO, LO, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LM, LM, LM, LM, LM, LM, PO, PO,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LU, LL, CN, CN, CN, CN, CN, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, CN, CN, LU, LU, LU,
+        ],
+        [
+            PO, PO, PO, PO, PO, PO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, MN, MN, MN, PO, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MC, MN, MN, MN, MN, MN, MN, MN, LO, LO, LO,
+            LO, MN, LO, LO, LO, LO, LO, LO, MN, LO, LO, MC, MN, MN, LO, CN, CN, CN, CN, CN,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM,
+        ],
+        [
+            LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM,
+            LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LM, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM,
+            LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM,
+        ],
+        [
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+        ],
+        [
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LU, LU, LU, LU, LU, LU, LU, LU, LL, LL, LL, LL, LL, LL,
+            CN, CN, LU, LU, LU, LU, LU, LU, CN, CN, LL, LL, LL, LL, LL, LL, LL, LL, LU, LU, LU, LU,
+            LU, LU, LU, LU, LL, LL, LL, LL, LL, LL, LL, LL, LU, LU, LU, LU, LU, LU, LU, LU,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, CN, CN, LU, LU, LU, LU, LU, LU, CN, CN, LL, LL, LL, LL, LL, LL,
+            LL, LL, CN, LU, CN, LU, CN, LU, CN, LU, LL, LL, LL, LL, LL, LL, LL, LL, LU, LU, LU, LU,
+            LU, LU, LU, LU, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, CN, CN,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LT, LT, LT, LT, LT, LT, LT, LT, LL, LL, LL, LL, LL, LL,
+            LL, LL, LT, LT, LT, LT, LT, LT, LT, LT, LL, LL, LL, LL, LL, LL, LL, LL, LT, LT, LT, LT,
+            LT, LT, LT, LT, LL, LL, LL, LL, LL, CN, LL, LL, LU, LU, LU, LU, LT, SK, LL, SK,
+        ],
+        [
+            SK, SK, LL, LL, LL, CN, LL, LL, LU, LU, LU, LU, LT, SK, SK, SK, LL, LL, LL, LL, CN, CN,
+            LL, LL, LU, LU, LU, LU, CN, SK, SK, SK, LL, LL, LL, LL, LL, LL, LL, LL, LU, LU, LU, LU,
+            LU, SK, SK, SK, CN, CN, LL, LL, LL, CN, LL, LL, LU, LU, LU, LU, LT, SK, SK, CN,
+        ],
+        [
+            ZS, ZS, ZS, ZS, ZS, ZS, ZS, ZS, ZS, ZS, ZS, CF, CF, CF, CF, CF, PD, PD, PD, PD, PD, PD,
+            PO, PO, PI, PF, PS, PI, PI, PF, PS, PI, PO, PO, PO, PO, PO, PO, PO, PO, ZL, ZP, CF, CF,
+            CF, CF, CF, ZS, PO, PO, PO, PO, PO, PO, PO, PO, PO, PI, PF, PO, PO, PO, PO, PC,
+        ],
+        [
+            PC, PO, PO, PO, SM, PS, PE, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, SM, PO, PC, PO,
+            PO, PO, PO, PO, PO, PO, PO, PO, PO, ZS, CF, CF, CF, CF, CF, CN, CF, CF, CF, CF, CF, CF,
+            CF, CF, CF, CF, NO, LM, CN, CN, NO, NO, NO, NO, NO, NO, SM, SM, SM, PS, PE, LM,
+        ],
+        [
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, SM, SM, SM, PS, PE, CN, LM, LM, LM, LM, LM, LM,
+            LM, LM, LM, LM, LM, LM, LM, CN, CN, CN, SC, SC, SC, SC, SC, SC, SC, SC, SC, SC, SC, SC,
+            SC, SC, SC, SC, SC, SC, SC, SC, SC, SC, SC, SC, SC, SC, SC, SC, SC, SC, SC, SC,
+        ],
+        [
+            SC, SC, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, ME, ME, ME, ME, MN, ME, ME, ME, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, LU, SO, SO, SO, SO, LU, SO, SO, LL, LU, LU, LU, LL, LL, LU, LU, LU, LL, SO, LU,
+            SO, SO, SM, LU, LU, LU, LU, LU, SO, SO, SO, SO, SO, SO, LU, SO, LU, SO, LU, SO, LU, LU,
+            LU, LU, SO, LL, LU, LU, LU, LU, LL, LO, LO, LO, LO, LL, SO, SO, LL, LL, LU, LU,
+        ],
+        [
+            SM, SM, SM, SM, SM, LU, LL, LL, LL, LL, SO, SM, SO, SO, LL, SO, NO, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL,
+            NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL,
+        ],
+        [
+            NL, NL, NL, LU, LL, NL, NL, NL, NL, NO, SO, SO, CN, CN, CN, CN, SM, SM, SM, SM, SM, SO,
+            SO, SO, SO, SO, SM, SM, SO, SO, SO, SO, SM, SO, SO, SM, SO, SO, SM, SO, SO, SO, SO, SO,
+            SO, SO, SM, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SM, SM, SO, SO, SM, SO, SM, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+        ],
+        [
+            SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+            SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+            SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, PS, PE, PS, PE, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SM, SM, SO, SO, SO, SO, SO, SO, SO, PS, PE, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SM, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+            SM, SM, SM, SM, SM, SM, SM, SM, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SM, SM, SM, SM, SM, SM, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+        ],
+        [
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SM, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SM, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SM, SM, SM, SM, SM, SM, SM, SM,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SM, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, PS, PE, PS, PE,
+            PS, PE, PS, PE, PS, PE, PS, PE, PS, PE, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+        ],
+        [
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SM, SM, SM, SM, SM, PS, PE, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+            SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, PS, PE, PS, PE, PS, PE,
+            PS, PE, PS, PE, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+        ],
+        [
+            SM, SM, SM, PS, PE, PS, PE, PS, PE, PS, PE, PS, PE, PS, PE, PS, PE, PS, PE, PS, PE, PS,
+            PE, PS, PE, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+            SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+        ],
+        [
+            SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+            SM, SM, PS, PE, PS, PE, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+            SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, PS, PE, SM, SM,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+        ],
+        [
+            SM, SM, SM, SM, SM, SO, SO, SM, SM, SM, SM, SM, SM, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LU, LL, LU, LU, LU, LL, LL, LU, LL, LU, LL, LU,
+            LL, LU, LU, LU, LU, LL, LU, LL, LL, LU, LL, LL, LL, LL, LL, LL, LM, LM, LU, LU,
+        ],
+        [
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LL, SO, SO, SO, SO, SO, SO, LU,
+            LL, LU, LL, MN, MN, MN, LU, LL, CN, CN, CN, CN, CN, PO, PO, PO, PO, NO, PO, PO,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, CN, LL, CN, CN, CN, CN,
+            CN, LL, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN,
+            CN, CN, CN, LM, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, MN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO,
+            LO, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, LO,
+            LO, CN, LO, LO, LO, LO, LO, LO, LO, CN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+        ],
+        [
+            PO, PO, PI, PF, PI, PF, PO, PO, PO, PI, PF, PO, PI, PF, PO, PO, PO, PO, PO, PO, PO, PO,
+            PO, PD, PO, PO, PD, PO, PI, PF, PO, PO, PI, PF, PS, PE, PS, PE, PS, PE, PS, PE, PO, PO,
+            PO, PO, PO, LM, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PD, PD, PO, PO, PO, PO,
+        ],
+        [
+            PD, PO, PS, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, SO, SO, PO, PO, PO, PS,
+            PE, PS, PE, PS, PE, PS, PE, PD, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            ZS, PO, PO, PO, SO, LM, LO, NL, PS, PE, PS, PE, PS, PE, PS, PE, PS, PE, SO, SO, PS, PE,
+            PS, PE, PS, PE, PS, PE, PD, PS, PE, PE, SO, NL, NL, NL, NL, NL, NL, NL, NL, NL, MN, MN,
+            MN, MN, MC, MC, PD, LM, LM, LM, LM, LM, SO, SO, NL, NL, NL, LM, LO, PO, SO, SO,
+        ],
+        [
+            CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, CN, CN, MN, MN, SK, SK, LM, LM, LO, PD, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, PO, LM, LM, LM, LO,
+        ],
+        [
+            CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, SO, SO, NO, NO, NO, NO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, SO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, NO, NO, NO, NO, NO, NO, NO, NO, SO, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LM,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LM, LM, LM, LM, LM, LM, PO, PO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LM, PO, PO, PO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, LO, LO,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LO, MN, ME, ME, ME, PO, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, PO, LM,
+        ],
+        [
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LM, LM, MN, MN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, NL, NL, NL, NL, NL, NL,
+            NL, NL, NL, NL, MN, MN, PO, PO, PO, PO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK,
+            SK, LM, LM, LM, LM, LM, LM, LM, LM, LM, SK, SK, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LL, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+        ],
+        [
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LM, LL, LL, LL, LL, LL, LL, LL, LL, LU, LL, LU, LL, LU, LU, LL,
+        ],
+        [
+            LU, LL, LU, LL, LU, LL, LU, LL, LM, SK, SK, LU, LL, LU, LL, LO, LU, LL, LU, LL, LL, LL,
+            LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LU,
+            LU, LU, LU, LL, LU, LU, LU, LU, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+        ],
+        [
+            LU, LL, LU, LL, LU, LU, LU, LU, LL, LU, LL, LU, LU, LL, LU, LL, LU, LL, LU, LL, LU, LL,
+            LU, LL, LU, LL, LU, LL, LU, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, LM, LM, LM, LM, LU, LL, LO, LM, LM, LL, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, MN, LO, LO, LO, MN, LO, LO, LO, LO, MN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MC, MC, MN, MN, MC, SO, SO, SO, SO,
+            MN, CN, CN, CN, NO, NO, NO, NO, NO, NO, SO, SO, SC, SO, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, PO, PO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            MC, MC, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC,
+        ],
+        [
+            MC, MC, MC, MC, MN, MN, CN, CN, CN, CN, CN, CN, CN, CN, PO, PO, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, CN, CN, CN, CN, CN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, LO, LO, LO, LO, LO, LO, PO, PO, PO, LO, PO, LO, LO, MN,
+        ],
+        [
+            ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, MN, MN, MN, MN,
+            MN, MN, PO, PO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MC, MC, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, PO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN,
+        ],
+        [
+            MN, MN, MN, MC, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, MN, MC, MC, MN, MN, MN, MN, MC, MC, MN, MN, MC, MC,
+        ],
+        [
+            MC, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, CN, LM, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, CN, CN, CN, PO, PO, LO, LO, LO, LO, LO, MN, LM, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, LO, LO, LO, LO, LO, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, MN,
+            MN, MN, MN, MC, MC, MN, MN, MC, MC, MN, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, MN, LO, LO, LO, LO, LO, LO, LO, LO, MN, MC, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, CN, PO, PO, PO, PO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LM, LO, LO, LO, LO, LO, LO, SO, SO, SO, LO, MC, MN, MC, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, MN, LO, MN, MN, MN, LO, LO, MN, MN, LO, LO, LO, LO, LO, MN, MN,
+        ],
+        [
+            LO, MN, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, LO, LO, LM, PO, PO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MC,
+            MN, MN, MC, MC, PO, PO, LO, LM, LM, MC, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, LO, LO, LO, LO, LO, LO, CN, CN, LO, LO, LO, LO, LO, LO, CN, CN, LO, LO, LO, LO, LO,
+            LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO,
+            LO, LO, LO, CN, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, SK, LM, LM, LM, LM, LL, LL, LL, LL, LL, LL, LL, LL, LL, LM, SK, SK,
+            CN, CN, CN, CN, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MC, MC, MN, MC, MC, MN, MC, MC, PO,
+            MC, MN, CN, CN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO,
+            CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO,
+            CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LL, LL, LL,
+            LL, LL, CN, CN, CN, CN, CN, LO, MN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, SM, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, CN, LO, CN,
+        ],
+        [
+            LO, LO, CN, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK, SK,
+        ],
+        [
+            SK, SK, SK, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, PE, PS,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, SO, SO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, SC, SO, SO, SO,
+        ],
+        [
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, PO, PO, PO, PO, PO, PO,
+            PO, PS, PE, PO, CN, CN, CN, CN, CN, CN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, PO, PD, PD, PC, PC, PS, PE, PS, PE, PS, PE, PS, PE, PS, PE, PS,
+        ],
+        [
+            PE, PS, PE, PS, PE, PO, PO, PS, PE, PO, PO, PO, PO, PC, PC, PC, PO, PO, PO, CN, PO, PO,
+            PO, PO, PD, PS, PE, PS, PE, PS, PE, PO, PO, PO, SM, PD, SM, SM, SM, CN, PO, SC, PO, PO,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CF,
+        ],
+        [
+            CN, PO, PO, PO, SC, PO, PO, PO, PS, PE, PO, SM, PO, PD, PO, PO, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, PO, PO, SM, SM, SM, PO, PO, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, PS, PO, PE, SK, PC,
+        ],
+        [
+            SK, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, PS, SM, PE, SM, PS, PE, PO, PS, PE, PO, PO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LM, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LM, LM, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN,
+        ],
+        [
+            CN, CN, LO, LO, LO, LO, LO, LO, CN, CN, LO, LO, LO, LO, LO, LO, CN, CN, LO, LO, LO, LO,
+            LO, LO, CN, CN, LO, LO, LO, CN, CN, CN, SC, SC, SM, SK, SO, SC, SC, CN, SO, SM, SM, SM,
+            SM, SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CF, CF, CF, SO, SO, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, CN, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN,
+        ],
+        [
+            PO, PO, PO, CN, CN, CN, CN, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, CN, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL,
+            NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL,
+            NL, NL, NL, NL, NL, NL, NL, NL, NL, NO, NO, NO, NO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, NO, NO, SO, SO, SO, CN, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, MN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, MN, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, NO, NO, NO, NO, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, NL, LO, LO, LO, LO, LO, LO, LO, LO, NL, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, MN, MN, MN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, CN, PO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, PO, NL, NL, NL, NL, NL,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN,
+            CN, CN, CN, CN, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, CN, CN,
+            CN, CN, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, PO, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, CN, LU, LU, LU, LU,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, CN, LU, LU, LU, LU, LU, LU, LU, CN, LU, LU,
+            CN, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, CN, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, CN, LL, LL, LL, LL, LL, LL, LL, CN, LL, LL, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LM, LM, LM, LM, LM, LM, CN, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM,
+            LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM,
+            LM, LM, LM, LM, LM, CN, LM, LM, LM, LM, LM, LM, LM, LM, LM, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, CN, CN, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, CN, CN, CN, LO, CN, CN, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            CN, PO, NO, NO, NO, NO, NO, NO, NO, NO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, SO, SO, NO, NO, NO, NO, NO, NO, NO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, CN, CN, CN, CN, CN, NO, NO, NO, NO, NO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            NO, NO, NO, NO, NO, NO, CN, CN, CN, PO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, PO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, NO, NO, LO, LO,
+        ],
+        [
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, CN, CN, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+        ],
+        [
+            LO, MN, MN, MN, CN, MN, MN, CN, CN, CN, CN, CN, MN, MN, MN, MN, LO, LO, LO, LO, CN, LO,
+            LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, MN, MN, MN, CN, CN, CN, CN, MN,
+        ],
+        [
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, CN, CN, CN, CN, CN, CN, CN, PO, PO, PO, PO, PO, PO,
+            PO, PO, PO, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, NO, NO, PO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, NO, NO, NO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, SO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, CN, CN, CN, CN, NO,
+            NO, NO, NO, NO, PO, PO, PO, PO, PO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, PO, PO, PO, PO, PO, PO, PO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            CN, CN, NO, NO, NO, NO, NO, NO, NO, NO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, NO, NO, NO, NO, NO, NO, NO, NO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN,
+            CN, CN, CN, PO, PO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, NO, NO, NO,
+            NO, NO, NO, NO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, CN, CN, CN, CN, CN, CN, CN, NO, NO, NO, NO, NO, NO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, MN, MN, CN, CN, CN, CN,
+            CN, CN, CN, CN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, LO, LO, LO, LO, LM, LO, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, CN, CN, CN, MN, MN, MN,
+            MN, MN, PD, LM, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, CN, CN, CN, CN, CN, CN, CN, CN, SM, SM, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, MN,
+            MN, PD, CN, CN, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, LO, LO, LO, LM, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, PO, SO, SO, SO, SO, SO,
+            SO, SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, MN, MN, MN, MN, MN, MN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, LO, CN, CN, CN, CN,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, NO, NO, NO, NO, PO,
+            PO, PO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, MN, MN, MN, MN, PO, PO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, NO, NO, NO, NO, NO, NO, NO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            MC, MN, MC, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, MN, MN, MN, MN, MN, MN,
+        ],
+        [
+            MN, MN, MN, MN, MN, MN, MN, PO, PO, PO, PO, PO, PO, PO, CN, CN, CN, CN, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, MN, LO, LO, MN, MN, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, MN,
+        ],
+        [
+            MN, MN, MC, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, MC, MC, MC, MN, MN, MN, MN, MC, MC, MN, MN, PO, PO, CF, PO, PO,
+        ],
+        [
+            PO, PO, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CF, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN,
+            CN, CN, CN, CN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            MN, MN, MN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, MN, MN, MN,
+            MC, MN, MN, MN, MN, MN, MN, MN, MN, CN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND,
+        ],
+        [
+            PO, PO, PO, PO, LO, MC, MC, LO, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, MN, PO, PO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            MN, MN, MC, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, MC, MC, MC, MN, MN, MN, MN, MN, MN, MN, MN, MN, MC,
+        ],
+        [
+            MC, LO, LO, LO, LO, PO, PO, PO, PO, MN, MN, MN, MN, PO, MC, MN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, LO, PO, LO, PO, PO, PO, CN, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            MC, MC, MC, MN, MN, MN, MC, MC, MN, MC, MN, MN, PO, PO, PO, PO, PO, PO, MN, LO,
+        ],
+        [
+            LO, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, CN, LO, CN, LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, PO, CN, CN,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MC, MC, MC, MN, MN, MN, MN, MN, MN, MN, MN, CN,
+            CN, CN, CN, CN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            MN, MN, MC, MC, CN, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, LO, LO, CN, CN, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO,
+            LO, LO, LO, LO, LO, CN, LO, LO, CN, LO, LO, LO, LO, LO, CN, MN, MN, LO, MC, MC,
+        ],
+        [
+            MN, MC, MC, MC, MC, CN, CN, MC, MC, CN, CN, MC, MC, MC, CN, CN, LO, CN, CN, CN, CN, CN,
+            CN, MC, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, MC, MC, CN, CN, MN, MN, MN, MN, MN, MN,
+            MN, CN, CN, CN, MN, MN, MN, MN, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, CN, CN, LO, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, MC, MC, MC, MN, MN, MN, MN, MN,
+        ],
+        [
+            MN, CN, MC, CN, CN, MC, CN, MC, MC, MC, MC, CN, MC, MC, MN, MC, MN, LO, MN, LO, PO, PO,
+            CN, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, MN, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, MC, MC, MC, MN, MN, MN, MN, MN, MN, MN, MN,
+        ],
+        [
+            MC, MC, MN, MN, MN, MC, MN, LO, LO, LO, LO, PO, PO, PO, PO, PO, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, PO, PO, CN, PO, MN, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, MC, MC, MC, MN, MN, MN, MN, MN, MN, MC, MN, MC, MC, MC, MC, MN,
+        ],
+        [
+            MN, MC, MN, MN, LO, LO, PO, LO, CN, CN, CN, CN, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, MC, MC, MC, MN, MN, MN, MN, CN, CN, MC, MC, MC, MC, MN, MN, MC, MN,
+        ],
+        [
+            MN, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO,
+            PO, PO, LO, LO, LO, LO, MN, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, MC, MC, MC, MN, MN, MN, MN, MN, MN, MN, MN, MC, MC, MN, MC, MN,
+        ],
+        [
+            MN, PO, PO, PO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, CN, CN, CN, CN, CN, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO,
+            PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN,
+            MC, MN, MC, MC, MN, MN, MN, MN, MN, MN, MC, MN, LO, PO, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, CN, CN, MN, MC, MN, MC, MC, MN, MN, MN, MN, MC, MN, MN, MN, MN, MN,
+            CN, CN, CN, CN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, NO, NO, PO, PO, PO, SO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            MC, MC, MC, MN, MN, MN, MN, MN, MN, MN, MN, MN, MC, MN, MN, PO, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, CN, CN, LO, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO,
+            LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, MC, MC, MC, MC, MC, MC, CN, MC, MC, CN, CN, MN, MN, MC, MN, LO,
+        ],
+        [
+            MC, LO, MC, MN, PO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MC, MC, MC, MN, MN,
+            MN, MN, CN, CN, MN, MN, MC, MC, MC, MC, MN, LO, PO, LO, MC, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, MN, MN, MN, MN, MN, MN, MC, LO, MN, MN, MN, MN, PO,
+        ],
+        [
+            PO, PO, PO, PO, PO, PO, PO, MN, CN, CN, CN, CN, CN, CN, CN, CN, LO, MN, MN, MN, MN, MN,
+            MN, MC, MC, MN, MN, MN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MC, MN, MN, PO, PO, PO, LO, PO, PO, PO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, MN, MC, MN, MN, MN, MC, MN, MC, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, MC, MN, MN, MN, MN, MN, MN, MN, CN, MN, MN, MN, MN, MN, MN, MC, MN,
+        ],
+        [
+            LO, PO, PO, PO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, CN, CN, CN, PO, PO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, CN, MC, MN, MN,
+            MN, MN, MN, MN, MN, MC, MN, MN, MC, MN, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, MN, MN, MN, MN, MN, MN, CN, CN, CN, MN, CN, MN, MN, CN, MN,
+        ],
+        [
+            MN, MN, MN, MN, MN, MN, LO, MN, CN, CN, CN, CN, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, CN, LO, LO, CN, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, MC, MC, MC, MC, MC, CN, MN, MN, CN, MC, MC, MN,
+            MC, MN, LO, CN, CN, CN, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LM, LO, LO, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, MN, MN, MC, MC, PO, PO, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            MN, MN, LO, MC, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, MC, MC, MN, MN, MN, MN, MN, CN, CN, CN, MC, MC,
+        ],
+        [
+            MN, MC, MN, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, PO, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SC, SC, SC, SC, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, PO,
+        ],
+        [
+            NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL,
+            NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL,
+            NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL,
+        ],
+        [
+            NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL,
+            NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL, NL,
+            NL, NL, NL, CN, PO, PO, PO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF,
+        ],
+        [
+            MN, LO, LO, LO, LO, LO, LO, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MC, MC,
+            MC, MN, MN, MN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN,
+            CN, CN, PO, PO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN,
+        ],
+        [
+            ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, CN, CN, MN, MN, MN, MN, MN, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, MN, MN, MN, MN, MN, MN, MN, PO, PO, PO, PO, PO, SO, SO, SO, SO,
+        ],
+        [
+            LM, LM, LM, LM, PO, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, NO, NO, NO, NO, NO, NO, NO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LM, LM, LM, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LM,
+            LM, PO, PO, PO, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, PO, PO, PO, PO, CN, CN, CN, CN, CN, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, CN, CN, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, MN, LO, MC, MC, MC, MC, MC,
+            MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC,
+            MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC, MC,
+        ],
+        [
+            MC, MC, MC, MC, MC, MC, MC, MC, CN, CN, CN, CN, CN, CN, CN, MN, MN, MN, MN, LM, LM, LM,
+            LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LM, LM, PO, LM, MN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, MC, MC, LM, LM, NL, NL, NL, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, LM, LM, LM, LM, CN, LM, LM, LM, LM, LM, LM, LM, CN, LM, LM, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, CN, CN, LO,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, CN, CN, CN, CN,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, CN, CN, SO, MN, MN, PO, CF, CF, CF, CF, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, SO, SO, SO, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SM, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, CN, CN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+        ],
+        [
+            MN, MN, MN, MN, MN, MN, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, MC, MC, MN, MN, MN, SO, SO,
+            SO, MC, MC, MC, MC, MC, MC, CF, CF, CF, CF, CF, CF, CF, CF, MN, MN, MN, MN, MN,
+        ],
+        [
+            MN, MN, MN, SO, SO, MN, MN, MN, MN, MN, MN, MN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, MN, MN,
+            MN, MN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, MN, MN, MN, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LL, LL, LL, LL, LL, LL, LL, CN,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+        ],
+        [
+            LU, LU, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LU, CN, LU, LU, CN, CN, LU, CN, CN, LU, LU, CN, CN, LU, LU, LU,
+            LU, CN, LU, LU, LU, LU, LU, LU, LU, LU, LL, LL, LL, LL, CN, LL, CN, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, CN, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LU, LU, CN, LU, LU, LU, LU, CN, CN, LU, LU, LU, LU, LU, LU, LU, LU, CN,
+            LU, LU, LU, LU, LU, LU, LU, CN, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LU, LU, CN, LU, LU, LU, LU, CN,
+        ],
+        [
+            LU, LU, LU, LU, LU, CN, LU, CN, CN, CN, LU, LU, LU, LU, LU, LU, LU, CN, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LU, LU, LU, LU,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, CN, CN, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+        ],
+        [
+            LU, SM, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, SM, LL, LL, LL, LL, LL, LL, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, SM, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, SM,
+            LL, LL, LL, LL, LL, LL, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, SM, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, SM, LL, LL, LL, LL, LL, LL,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, SM, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, SM, LL, LL, LL, LL, LL, LL, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, SM, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, SM, LL, LL, LL, LL, LL, LL, LU, LL, CN, CN, ND, ND, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND,
+        ],
+        [
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, SO, SO, SO, SO, MN, MN, MN, MN, MN,
+        ],
+        [
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, SO, SO, SO, SO, SO, SO, SO, SO, MN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, MN, SO, SO, PO, PO, PO, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, MN, MN, MN, MN, MN, CN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LO, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, CN, CN, CN, CN, CN, CN, LL, LL, LL, LL, LL, LL, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            MN, MN, MN, MN, MN, MN, MN, CN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, CN, CN, MN, MN, MN, MN, MN, MN, MN, CN, MN, MN, CN, MN, MN, MN, MN, MN, CN,
+            CN, CN, CN, CN, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM,
+        ],
+        [
+            LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM,
+            LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM, LM,
+            LM, LM, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, MN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, CN, CN, CN, MN, MN, MN, MN, MN, MN, MN, LM, LM, LM, LM, LM, LM, LM, CN, CN,
+        ],
+        [
+            ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, LO, SO, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            MN, MN, MN, MN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, CN, SC,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LM,
+            MN, MN, MN, MN, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, MN, MN, LO, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, CN, CN, CN, CN, PO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, MN, LO, LO, MN, LO, LO, LO, LO, LO,
+            LO, LO, MN, MN, LO, LO, LO, LO, LO, MN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LM,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO,
+            CN, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, CN, CN, NO, NO, NO, NO, NO, NO, NO, NO, NO, MN, MN, MN, MN, MN, MN,
+            MN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU,
+            LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LU, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+            LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL, LL,
+        ],
+        [
+            LL, LL, LL, LL, MN, MN, MN, MN, MN, MN, MN, LM, CN, CN, CN, CN, ND, ND, ND, ND, ND, ND,
+            ND, ND, ND, ND, CN, CN, CN, CN, PO, PO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+        ],
+        [
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            SO, NO, NO, NO, SC, NO, NO, NO, NO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO,
+            NO, NO, SO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, CN, LO, CN, CN, LO, CN, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, CN, LO, CN, LO, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, LO, CN, CN, CN, CN, LO, CN, LO, CN, LO, CN, LO, LO, LO, CN, LO, LO, CN, LO, CN,
+            CN, LO, CN, LO, CN, LO, CN, LO, CN, LO, CN, LO, LO, CN, LO, CN, CN, LO, LO, LO, LO, CN,
+            LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, CN, LO, LO, LO, LO, CN, LO, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, LO, LO, LO, CN, LO, LO, LO, LO, LO, CN, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, SM, SM, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            CN, CN, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, NO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, CN, SO, SO, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SK, SK, SK, SK, SK,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, CN, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, CN, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            CN, CN, CN, CN, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, SM, SM, SM, SM, SM, SM,
+            SM, SM, SM, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, CN, CN, CN, CN, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, CN, SO, CN, CN, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN,
+            CN, CN, CN, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, CN, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+        ],
+        [
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO, SO,
+            SO, SO, SO, SO, ND, ND, ND, ND, ND, ND, ND, ND, ND, ND, SO, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CN, CF, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+            CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF,
+            CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF,
+        ],
+        [
+            CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF,
+            CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF,
+            CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF, CF,
+        ],
+        [
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN, MN,
+            MN, MN, MN, MN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+        ],
+        [
+            CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO,
+            CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO,
+            CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CO, CN, CN,
+        ],
+    ],
+};
+
+/// Codepoints with the `Alphabetic` property
+pub(crate) static ALPHABETIC: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 4, 4, 4, 4, 4, 4, 5, 0, 6, 7, 8, 4, 4, 9, 4, 10, 11, 12,
+        13, 14, 15, 4, 16, 17, 18, 19, 20, 0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 5, 5, 7, 8, 9, 29, 30,
+        30, 30, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 5, 0, 6, 7, 8, 4, 4, 9, 4, 10, 11, 12, 13, 14, 15, 4,
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37,
+            38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 0,
+        ],
+        [
+            52, 17, 53, 54, 4, 4, 4, 4, 4, 55, 56, 57, 58, 59, 60, 61, 62, 4, 4, 4, 4, 4, 4, 4, 4,
+            63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
+            85, 86, 87, 88, 89, 4, 4, 4, 90, 4, 4, 4, 4, 91, 92, 93, 94,
+        ],
+        [
+            0, 95, 96, 0, 97, 98, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 101, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 4, 4, 102, 103,
+            104, 105, 106, 107, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            108, 62, 109, 110, 111, 4, 112, 113, 0, 0, 0, 0, 0, 0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 114, 115, 4, 4, 4, 4, 116, 117, 4,
+            118, 119, 4, 120, 121, 122, 123, 4, 124, 125, 126, 127, 128, 86, 129, 130, 131, 132,
+            133, 4, 134, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 135, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 4, 4, 4, 4, 4, 137, 4, 138, 139, 140, 19, 141, 4, 4, 4, 4, 142, 17,
+            143, 144, 0, 145, 4, 146, 147, 148, 130, 149,
+        ],
+        [
+            150, 151, 4, 152, 0, 153, 0, 0, 0, 0, 154, 155, 156, 157, 158, 159, 4, 4, 160, 161, 162,
+            163, 164, 123, 4, 4, 4, 4, 86, 165, 166, 0, 167, 168, 169, 170, 171, 138, 172, 0, 173,
+            174, 175, 176, 73, 177, 178, 0, 4, 99, 179, 179, 180, 181, 182, 0, 0, 0, 183, 184, 185,
+            186, 187, 188,
+        ],
+        [
+            4, 189, 71, 190, 179, 191, 4, 192, 193, 194, 195, 196, 197, 198, 199, 200, 4, 201, 4,
+            202, 0, 0, 203, 204, 130, 205, 206, 0, 207, 208, 0, 0, 71, 0, 70, 209, 210, 211, 212,
+            213, 214, 17, 215, 71, 0, 216, 0, 217, 218, 219, 220, 0, 221, 222, 223, 224, 0, 0, 0,
+            225, 226, 49, 227, 0,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 138, 0, 4, 228, 4, 4, 4, 229, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 17, 230,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 118, 231, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 152, 4, 4, 4, 4, 4, 4, 4, 4, 4, 208, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 71, 232, 130, 233, 118, 234, 235, 0, 0, 0, 0, 0, 0,
+            236, 0, 0, 0, 4, 237, 238, 4, 239, 240, 241,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 242, 169, 0, 4, 179,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 243,
+        ],
+        [
+            4, 4, 4, 4, 244, 245, 4, 4, 4, 4, 4, 246, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 247, 248, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 249, 250, 251, 252, 253, 4, 4, 4, 4,
+            254, 255, 256, 257, 258, 259, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 260, 0, 0, 0,
+        ],
+        [
+            261, 262, 263, 0, 264, 265, 0, 0, 0, 0, 233, 266, 0, 0, 0, 0, 0, 0, 0, 267, 0, 0, 0,
+            268, 0, 0, 0, 269, 0, 0, 0, 270, 4, 4, 4, 271, 4, 272, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 273, 274, 275, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 113, 276, 77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 277, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 158, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            137, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 278, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            279, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 279, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 280, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 281, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 5, 5, 7, 8, 9, 10, 11, 12, 13, 14, 8, 15, 5, 16, 8, 17,
+            18, 8, 19, 20, 21, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 22, 23, 24, 5, 25, 26, 5, 27, 5, 28, 8,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+        ],
+        [
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 8,
+        ],
+    ],
+    leaves: &[
+        0x0000_0000_0000_0000,
+        0x07FF_FFFE_07FF_FFFE,
+        0x0420_0400_0000_0000,
+        0xFF7F_FFFF_FF7F_FFFF,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0x0000_501F_0003_FFC3,
+        0xBCDF_FFF8_0000_0020,
+        0xFFFF_FFFB_FFFF_D740,
+        0xFFBF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FC03,
+        0xFFFE_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_027F_FFFF,
+        0xBFFF_0000_0000_01FF,
+        0x0007_87FF_FFFF_00B6,
+        0xFFFF_FFFF_07FF_0000,
+        0xFFFF_C000_FEFF_FFFF,
+        0x9C00_E1FE_1FEF_FFFF,
+        0xFFFF_FFFF_FFFF_0000,
+        0xFFFF_FFFF_FFFF_E000,
+        0x0003_FFFF_FFFF_FFFF,
+        0x0430_07FF_FFFF_FC00,
+        0x0000_1FFF_FCFF_FFFF,
+        0xFFFF_07FF_01FF_FFFF,
+        0xFFFF_FFFF_0080_FEFF,
+        0xFFFF_03F8_FFF0_03FF,
+        0xEFFF_FFFF_FFFF_FFFF,
+        0xFFFE_000F_FFE1_DFFF,
+        0xE3C5_FDFF_FFF9_9FEF,
+        0x1003_000F_B080_599F,
+        0xC36D_FDFF_FFF9_87EE,
+        0x003F_0000_5E02_1987,
+        0xE3ED_FDFF_FFFB_BFEE,
+        0x1E00_000F_0001_1BBF,
+        0xE3ED_FDFF_FFF9_9FEE,
+        0x0002_000F_B0C0_199F,
+        0xC3FF_C718_D63D_C7EC,
+        0x0000_0000_0081_1DC7,
+        0xE3FF_FDFF_FFFD_DFFF,
+        0x0000_000F_3760_1DDF,
+        0xE3EF_FDFF_FFFD_DFEF,
+        0x000E_000F_7060_1DDF,
+        0xE7FF_FFFF_FFFD_DFFF,
+        0xFC00_000F_80F0_5DDF,
+        0x2FFB_FFFF_FC7F_FFEE,
+        0x000C_0000_FF5F_807F,
+        0x07FF_FFFF_FFFF_FFFE,
+        0x0000_0000_0000_207F,
+        0x3BFF_FFAF_FFFF_F7D6,
+        0x0000_0000_F000_205F,
+        0x0000_0000_0000_0001,
+        0xFFFE_1FFF_FFFF_FEFF,
+        0x1FFF_FFFF_FEFF_FF0F,
+        0xF97F_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_3C00_FFFF,
+        0xF7FF_FFFF_FFFF_20BF,
+        0xFFFF_FFFF_3D7F_3DFF,
+        0x7F3D_FFFF_FFFF_3DFF,
+        0xFFFF_FFFF_FF7F_FF3D,
+        0xFFFF_FFFF_FF3D_FFFF,
+        0x0000_0000_07FF_FFFF,
+        0xFFFF_FFFF_0000_FFFF,
+        0x3F3F_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FFFE,
+        0xFFFF_9FFF_FFFF_FFFF,
+        0xFFFF_FFFF_07FF_FFFE,
+        0x01FF_C7FF_FFFF_FFFF,
+        0x000F_FFFF_800F_FFFF,
+        0x000D_DFFF_000F_FFFF,
+        0xFFCF_FFFF_FFFF_FFFF,
+        0x0000_0000_1080_01FF,
+        0xFFFF_FFFF_0000_0000,
+        0x01FF_FFFF_FFFF_FFFF,
+        0xFFFF_07FF_FFFF_FFFF,
+        0x003F_FFFF_FFFF_FFFF,
+        0x01FF_0FFF_7FFF_FFFF,
+        0x001F_3FFF_FFFF_0000,
+        0xFFFF_0FFF_FFFF_FFFF,
+        0x0000_0000_0000_03FF,
+        0xFFFF_FFFF_0FFF_FFFF,
+        0x001F_FFFE_7FFF_FFFF,
+        0x8000_0080_0000_0000,
+        0x0000_0000_0000_7001,
+        0xFFEF_FFFF_FFFF_FFFF,
+        0x0000_0000_0000_1FEF,
+        0xFC00_F3FF_FFFF_FFFF,
+        0x0003_FFBF_FFFF_FFFF,
+        0x007F_FFFF_FFFF_FFFF,
+        0x3FFF_FFFF_FC00_E000,
+        0xE7FF_FFFF_FFFF_07FF,
+        0x046F_DE00_0000_0000,
+        0x001F_FFFF_FFF8_0000,
+        0xFFFF_FFFF_3F3F_FFFF,
+        0x3FFF_FFFF_AAFF_3F3F,
+        0x5FDF_FFFF_FFFF_FFFF,
+        0x1FDC_1FFF_0FCF_1FDC,
+        0x8002_0000_0000_0000,
+        0x0000_0000_1FFF_0000,
+        0xF3FF_BD50_3E2F_FC84,
+        0xFFFF_FFFF_0000_43E0,
+        0x0000_0000_0000_01FF,
+        0xFFC0_0000_0000_0000,
+        0x0000_03FF_FFFF_FFFF,
+        0x000C_781F_FFFF_FFFF,
+        0xFFFF_20BF_FFFF_FFFF,
+        0x0000_80FF_FFFF_FFFF,
+        0x7F7F_7F7F_007F_FFFF,
+        0xFFFF_FFFF_7F7F_7F7F,
+        0x0000_8000_0000_0000,
+        0x1F3E_03FE_0000_00E0,
+        0xFFFF_FFFE_E07F_FFFF,
+        0xF7FF_FFFF_FFFF_FFFF,
+        0xFFFE_FFFF_FFFF_FFE0,
+        0xFFFF_FFFF_0000_7FFF,
+        0xFFFF_0000_0000_0000,
+        0x0000_0000_0000_1FFF,
+        0x3FFF_FFFF_FFFF_0000,
+        0x0000_0C00_FFFF_1FFF,
+        0x8FF0_7FFF_FFFF_FFFF,
+        0x0000_FFFF_FFFF_FFFF,
+        0xFFFF_FFFC_FF80_0000,
+        0xFFFF_FFFF_FFFF_F9FF,
+        0xFFFE_0000_1FFF_FFFF,
+        0x0000_00FF_FFFF_FFBF,
+        0x000F_FFFF_FFFF_FFFF,
+        0xE8FC_0000_0000_002F,
+        0xFFFF_07FF_FFFF_FC00,
+        0x1FFF_FFFF_0007_FFFF,
+        0xFFF7_FFFF_FFFF_FFFF,
+        0x7C00_FFFF_0000_8000,
+        0xFC7F_FFFF_0000_3FFF,
+        0x7FFF_FFFF_FFFF_FFFF,
+        0x003C_FFFF_3800_0005,
+        0xFFFF_7F7F_007E_7E7E,
+        0xFFFF_03FF_F7FF_FFFF,
+        0x0000_07FF_FFFF_FFFF,
+        0xFFFF_000F_FFFF_FFFF,
+        0x0FFF_FFFF_FFFF_F87F,
+        0xFFFF_3FFF_FFFF_FFFF,
+        0x0000_0000_03FF_FFFF,
+        0x5F7F_FDFF_E0F8_007F,
+        0xFFFF_FFFF_FFFF_FFDB,
+        0xFFFF_FFFF_FFF8_0000,
+        0x3FFF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFC_FFFF,
+        0x0FFF_0000_0000_00FF,
+        0xFFDF_0000_0000_0000,
+        0x1FFF_FFFF_FFFF_FFFF,
+        0x07FF_FFFE_0000_0000,
+        0xFFFF_FFC0_07FF_FFFE,
+        0x0000_0000_1CFC_FCFC,
+        0xB7FF_FF7F_FFFF_EFFF,
+        0x0000_0000_3FFF_3FFF,
+        0x07FF_FFFF_FFFF_FFFF,
+        0x001F_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_1FFF_FFFF,
+        0x0000_0000_0001_FFFF,
+        0xFFFF_E000_FFFF_FFFF,
+        0x07FF_FFFF_FFFF_07FF,
+        0xFFFF_FFFF_3FFF_FFFF,
+        0x0000_0000_003E_FF0F,
+        0xFFFF_0000_3FFF_FFFF,
+        0x0FFF_FFFF_FF0F_FFFF,
+        0xFFFF_00FF_FFFF_FFFF,
+        0xF7FF_000F_FFFF_FFFF,
+        0x1BFB_FFFB_FFB7_F7FF,
+        0x0000_00FF_003F_FFFF,
+        0x07FD_FFFF_FFFF_FFBF,
+        0x91BF_FFFF_FFFF_FD3F,
+        0x007F_FFFF_003F_FFFF,
+        0x0000_0000_7FFF_FFFF,
+        0x0037_FFFF_0000_0000,
+        0x03FF_FFFF_003F_FFFF,
+        0xC0FF_FFFF_FFFF_FFFF,
+        0x003F_FFFF_FEEF_F06F,
+        0x1FFF_FFFF_0000_0000,
+        0x0000_0000_1FFF_FFFF,
+        0x0000_001F_FFFF_FEFF,
+        0x0007_FFFF_003F_FFFF,
+        0x0000_0000_0003_FFFF,
+        0x0007_FFFF_FFFF_FFFF,
+        0x0000_00FF_FFFF_FFFF,
+        0xFFFF_823F_FFFF_FC00,
+        0x0000_0000_0000_003F,
+        0x0003_1BFF_FFFF_FFFF,
+        0x1C00_0000_0000_00FC,
+        0xFFFF_0080_1FFF_FFFF,
+        0xFFFF_0000_0000_003F,
+        0xFFFF_0000_0000_0003,
+        0x007F_FFFF_0000_001F,
+        0x003E_0000_0000_003F,
+        0x0000_01FF_FFFF_0004,
+        0x0047_FFFF_FFFF_00F0,
+        0x0000_0000_1400_C01E,
+        0xC09F_FFFF_FFFB_FFFF,
+        0x0000_0000_0000_0003,
+        0xFFFF_01FF_BFFF_BD7F,
+        0x0000_01FF_FFFF_FFFF,
+        0xE3ED_FDFF_FFF9_9FEF,
+        0x0000_000F_E081_199F,
+        0xFFBF_FFFF_FFFF_4BFF,
+        0x0000_0000_000A_37A5,
+        0x0000_0003_8000_07BB,
+        0x0000_0000_0000_00B3,
+        0x7F3F_FFFF_FFFF_FFFF,
+        0x0000_0000_3F00_0000,
+        0x0000_0000_0000_0011,
+        0x013F_FFFF_FFFF_FFFF,
+        0x0000_07FF_E7FF_FFFF,
+        0x0000_0000_0000_007F,
+        0x8000_0000_FFFF_FFFF,
+        0x99BF_FFFF_FF6F_F27F,
+        0x0000_0000_0000_0007,
+        0xFFFF_FCFF_0000_0000,
+        0x0000_001A_FCFF_FFFF,
+        0x7FE7_FFFF_FFFF_FFFF,
+        0xFFFF_0000_20FF_FFFF,
+        0x0000_00FF_0000_0000,
+        0x0000_0001_FFFF_FFFF,
+        0x7F7F_FFFF_FFFF_FDFF,
+        0xFFFC_0000_0000_0001,
+        0x007F_FEFF_FFFC_FFFF,
+        0xB47F_FFFF_FFFF_FB7F,
+        0xFFFF_FDBF_0000_00CB,
+        0xFFFF_0000_017B_7FFF,
+        0x0000_0000_0FFF_FFFF,
+        0x007F_FFFF_0000_0000,
+        0xC7FF_FFFF_FFFD_FFFF,
+        0x0001_0000_0000_0000,
+        0x0000_7FFF_FFFF_FFFF,
+        0x0000_0000_0000_000F,
+        0x0001_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_0000_007E,
+        0xFFFF_0000_7FFF_FFFF,
+        0x0000_3FFF_FFFF_0000,
+        0xE0FF_FFF8_0000_000F,
+        0x0000_0000_0000_FFFF,
+        0x0000_1FFF_FFFF_FFFF,
+        0xF9FF_FFFF_0000_0000,
+        0x0000_0000_000F_FFFF,
+        0xFFFF_FFFF_FFFF_87FF,
+        0x0000_0000_FFFF_80FF,
+        0x007F_000B_0000_0000,
+        0x8000_0000_003F_FFFF,
+        0x6FEF_0000_0000_0000,
+        0x0004_0007_FFFF_FFFF,
+        0xFFFF_00F0_0027_0000,
+        0x0FFF_FFFF_FFFF_FFFF,
+        0x1FFF_07FF_FFFF_FFFF,
+        0x0000_0000_43FF_01FF,
+        0xFFFF_FFFF_FFDF_FFFF,
+        0xEBFF_DE64_DFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FFEF,
+        0x7BFF_FFFF_DFDF_E7BF,
+        0xFFFF_FFFF_FFFD_FC5F,
+        0xFFFF_FF3F_FFFF_FFFF,
+        0xF7FF_FFFF_F7FF_FFFD,
+        0xFFDF_FFFF_FFDF_FFFF,
+        0xFFFF_7FFF_FFFF_7FFF,
+        0xFFFF_FDFF_FFFF_FDFF,
+        0x0000_0000_0000_0FF7,
+        0x0000_07E0_7FFF_FFFF,
+        0xFFFF_07DB_F9FF_FF7F,
+        0x0000_3FFF_FFFF_FFFF,
+        0x0000_0000_0000_8000,
+        0x3F80_1FFF_FFFF_FFFF,
+        0x0000_0000_0000_4000,
+        0x0000_0FFF_FFFF_FFFF,
+        0x0000_0FFF_FFFF_0000,
+        0x0001_3FFF_FFFF_0000,
+        0xC03F_FFFF_7FFF_FFFF,
+        0x7FFF_6F7F_0000_0000,
+        0x0000_0000_0000_001F,
+        0x0000_0000_0000_088F,
+        0x0AF7_FE96_FFFF_FFEF,
+        0x5EF7_F796_AA96_EA84,
+        0x0FFF_FBEE_0FFF_FBFF,
+        0xFFFF_03FF_FFFF_03FF,
+        0x0000_0000_FFFF_FFFF,
+        0xFFFF_0001_FFFF_FFFF,
+        0x0000_0000_3FFF_FFFF,
+        0xFFFF_FFFF_FFFF_07FF,
+        0x03FF_FFFF_FFFF_FFFF,
+    ],
+};
+
+/// Codepoints with the `Grapheme_Extend` property
+pub(crate) static GRAPHEME_EXTEND: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 3, 0, 0, 0, 4, 5, 6,
+        7, 0, 8, 9, 10, 11, 12, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 6, 15, 16, 16, 18, 16,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 3, 0, 0, 0, 4, 5, 6, 7, 0, 8, 9,
+            10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 21, 23, 24, 25, 26, 27, 28, 29, 30,
+            31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43,
+        ],
+        [
+            44, 45, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            48, 49, 50, 51, 52, 0, 53, 0, 54, 0, 0, 0, 55, 56, 57, 58, 59, 60, 61, 62, 63, 0, 0, 64,
+            0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            65, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 0, 68, 0, 69, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            70, 0, 71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 73, 74,
+            0, 0, 0, 0, 75, 0, 0, 76, 77, 78, 79, 80, 81, 82, 83, 84, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 87, 0,
+            0, 0, 0, 0, 73, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 89, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 93, 94, 0, 0,
+            0, 0, 95, 96, 0, 97, 98, 0,
+        ],
+        [
+            99, 100, 101, 102, 103, 104, 105, 106, 107, 34, 0, 108, 32, 109, 110, 111, 112, 113,
+            114, 115, 0, 0, 116, 117, 118, 119, 120, 0, 121, 0, 0, 0, 122, 0, 0, 0, 123, 124, 0,
+            125, 126, 127, 128, 0, 0, 129, 0, 0, 130, 0, 131, 0, 132, 133, 134, 0, 0, 0, 0, 135,
+            136, 137, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 140, 141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 142, 143, 144,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 146, 147, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 148, 149, 0, 0, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 152, 153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            154, 0, 142, 0, 141, 0, 0, 0, 0, 0, 155, 156, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 157, 0,
+            0, 0, 158, 0, 0, 0, 0, 0, 0, 0, 159, 0, 160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 6, 7, 8, 4, 9, 4, 4, 10, 4, 4, 4, 4, 11,
+            12, 13, 14, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            69, 1, 0, 0, 1, 1, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 17, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+    ],
+    leaves: &[
+        0x0000_0000_0000_0000,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0x0000_FFFF_FFFF_FFFF,
+        0x0000_0000_0000_03F8,
+        0xBFFF_FFFF_FFFE_0000,
+        0x0000_0000_0000_00B6,
+        0x0000_0000_07FF_0000,
+        0x0001_0000_FFFF_F800,
+        0x0000_3D9F_9FC0_0000,
+        0xFFFF_0000_0002_0000,
+        0x0000_0000_0000_07FF,
+        0x0001_FFC0_0000_0000,
+        0x200F_F800_0000_0000,
+        0x0000_3EEF_FBC0_0000,
+        0x0000_0000_0E00_0000,
+        0x0000_0000_FF80_0000,
+        0xFFFF_FFFB_FFFF_FC00,
+        0x1400_0000_0000_0007,
+        0x0000_000C_00FE_21FE,
+        0x5000_0000_0000_0002,
+        0x4000_000C_0080_201E,
+        0x1000_0000_0000_0006,
+        0x0023_0000_0002_3986,
+        0xFC00_000C_0000_21BE,
+        0xD000_0000_0000_0002,
+        0x0000_000C_00E0_201E,
+        0x4000_0000_0000_0004,
+        0x0000_0000_0080_2001,
+        0xD000_0000_0000_0011,
+        0x0000_000C_0060_3DC1,
+        0x9000_0000_0000_0002,
+        0x0000_000C_0060_3DC5,
+        0x5800_0000_0000_0003,
+        0x0000_000C_0080_201E,
+        0x0000_0000_0000_0002,
+        0x0000_0000_805C_8400,
+        0x07F2_0000_0000_0000,
+        0x0000_0000_0000_7F80,
+        0x1FF2_0000_0000_0000,
+        0x0000_0000_0000_7F00,
+        0x02A0_0000_0300_0000,
+        0x7FFE_0000_0000_0000,
+        0x1FFF_FFFF_FEFF_E0DF,
+        0x0000_0000_0000_0040,
+        0x66FD_E000_0000_0000,
+        0x001E_0001_C300_0000,
+        0x0000_0000_2000_2064,
+        0x0000_0000_E000_0000,
+        0x001C_0000_003C_0000,
+        0x000C_0000_000C_0000,
+        0x3FB0_0000_0000_0000,
+        0x0000_0000_200F_FE40,
+        0x0000_0000_0000_B800,
+        0x0000_0200_0000_0060,
+        0x0E04_0187_0000_0000,
+        0x0000_0000_0980_0000,
+        0x9FF8_1FE5_7F40_0000,
+        0xFFFF_0000_0000_0000,
+        0x0000_0FFF_3FFF_FFFF,
+        0x3FF0_0000_0000_000F,
+        0x000F_F800_0000_001C,
+        0x0000_3F3C_0000_0003,
+        0x000F_A340_0000_0000,
+        0x00CF_F000_0000_0000,
+        0x0310_21FD_FFF7_0000,
+        0x0000_0000_0000_1000,
+        0x0001_FFFF_FFFF_0000,
+        0x0003_8000_0000_0000,
+        0x8000_0000_0000_0000,
+        0xFFFF_FFFF_0000_0000,
+        0x0000_FC00_0000_0000,
+        0x0000_0000_0600_0000,
+        0x3FF7_8000_0000_0000,
+        0x0000_0000_C000_0000,
+        0x0003_0000_0000_0000,
+        0x0000_1060_0000_0844,
+        0x8003_FFFF_0000_0030,
+        0x0000_3FC0_0000_0000,
+        0x0000_0000_000B_FF80,
+        0x33C8_0000_0000_0007,
+        0x0000_0020_0000_0001,
+        0x0066_7E00_0000_0000,
+        0x1000_0000_0000_1008,
+        0xC19D_0000_0000_0000,
+        0x0040_3000_0000_0002,
+        0x0000_2120_0000_0000,
+        0x0000_0000_4000_0000,
+        0x0000_FFFF_0000_FFFF,
+        0x2000_0000_0000_0000,
+        0x0000_0001_0000_0000,
+        0x07C0_0000_0000_0000,
+        0x8700_0000_0000_F06E,
+        0x0000_0060_0000_0000,
+        0x0000_00F0_0000_0000,
+        0x0000_3E00_0000_0000,
+        0x0000_1800_0000_0000,
+        0xFC00_0000_0000_0000,
+        0x0000_0000_0001_FFC0,
+        0x0000_0000_0000_003C,
+        0xFF00_0000_0000_0002,
+        0x8019_0000_0000_007F,
+        0x0678_0000_0000_0003,
+        0x0000_0000_0000_0004,
+        0x001F_EF80_0000_0007,
+        0x0008_0000_0000_0000,
+        0x7FC0_0000_0000_0003,
+        0x0000_0000_0000_9E01,
+        0x40F3_8000_0000_0000,
+        0x0000_07F8_8000_0000,
+        0x001F_1FC0_0080_2001,
+        0xF900_0000_0000_0000,
+        0x0000_0006_0005_C3A5,
+        0xFF00_0000_0000_0000,
+        0x0000_0000_4000_005C,
+        0xA5F9_0000_0000_0000,
+        0x0000_0000_0000_000D,
+        0xB03C_8000_0000_0000,
+        0x0000_0000_3000_0001,
+        0xA7F8_0000_0000_0000,
+        0x0000_0000_0000_0001,
+        0x00FF_2800_0000_0000,
+        0x0000_0FBC_A000_0000,
+        0x06FF_8000_0000_0000,
+        0x7801_0000_0000_0000,
+        0x0000_0000_0000_0008,
+        0x0000_0001_0CF0_0000,
+        0x79F8_0000_0000_07FE,
+        0x0000_0000_0E7E_0080,
+        0x0000_0000_037F_FC00,
+        0x0000_005D_0000_0000,
+        0xBF7F_0000_0000_0000,
+        0x006D_FCFF_FFFC_0000,
+        0xB47E_0000_0000_0000,
+        0x0000_0000_0000_00BF,
+        0x0000_0000_00A3_0000,
+        0x0018_0000_0000_0000,
+        0x07C0_0000_0000_0003,
+        0x0000_0000_0400_0007,
+        0x0000_0000_003F_FF81,
+        0x0000_E3FF_C000_0000,
+        0x001F_0000_0000_0000,
+        0x007F_0000_0000_0000,
+        0x0000_0000_0000_8000,
+        0x0000_0000_0007_8000,
+        0x0003_0010_0000_0000,
+        0x0000_0000_6000_0000,
+        0xFFFF_3FFF_FFFF_FFFF,
+        0x0000_0000_0000_007F,
+        0xF807_E3E0_0000_0000,
+        0x0000_3C00_0000_0FE7,
+        0x0000_0000_0000_001C,
+        0xF87F_FFFF_FFFF_FFFF,
+        0x0020_1FFF_FFFF_FFFF,
+        0x0000_FFFE_F800_0010,
+        0x0000_07DB_F9FF_FF7F,
+        0x0000_4000_0000_0000,
+        0x0000_F000_0000_0000,
+        0x0000_C000_0000_0000,
+        0x0020_C048_0000_0000,
+        0x0000_0000_007F_0000,
+        0x0000_0000_0000_07F0,
+    ],
+};
+
+/// Codepoints with the `Lowercase` property
+pub(crate) static LOWERCASE: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 0, 12, 13, 14, 15, 16, 17, 18, 19,
+        20, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 3, 3, 3, 3, 3, 3, 4, 3, 3, 3, 3, 5, 11, 12,
+        12, 12, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 0, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 25, 25, 25, 0, 26,
+            26, 27, 26, 28, 29, 30, 31,
+        ],
+        [
+            0, 32, 33, 0, 34, 35, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 38, 26, 39, 40, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 42, 0,
+            43, 44, 45, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 47, 25, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 49, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 51, 0, 52, 0, 0, 53, 0, 0, 0, 0, 0,
+            0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 15, 56, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20,
+            58, 59, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69,
+            70, 71, 72, 73, 74, 75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0,
+        ],
+        [
+            15, 77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 78, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 3, 3, 3, 3, 3, 3, 4, 3, 3, 3, 3, 5, 6, 7, 3, 3, 3, 3, 8, 3, 3, 3, 3, 3, 3,
+            9, 10, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+    ],
+    leaves: &[
+        0x0000_0000_0000_0000,
+        0x07FF_FFFE_0000_0000,
+        0x0420_0400_0000_0000,
+        0xFF7F_FFFF_8000_0000,
+        0x55AA_AAAA_AAAA_AAAA,
+        0xD4AA_AAAA_AAAA_AB55,
+        0xE651_2D2A_4E24_3129,
+        0xAA29_AAAA_B555_5240,
+        0x93FA_AAAA_AAAA_AAAA,
+        0xFFFF_FFFF_FFFF_AA85,
+        0x01FF_FFFF_FFCF_FFFF,
+        0x0000_001F_0000_0003,
+        0x3C8A_0000_0000_0020,
+        0xFFFF_F000_0001_0000,
+        0x192F_AAAA_AAE3_7FFF,
+        0xFFFF_0000_0000_0000,
+        0xAAAA_AAAA_FFFF_FFFF,
+        0xAAAA_AAAA_AAAA_A802,
+        0xAAAA_AAAA_AAAA_D554,
+        0x0000_AAAA_AAAA_AAAA,
+        0xFFFF_FFFF_0000_0000,
+        0x0000_0000_0000_01FF,
+        0xF7FF_FFFF_FFFF_0000,
+        0x3F00_0000_0000_0000,
+        0x0000_0000_0000_05FF,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0xAAAA_AAAA_AAAA_AAAA,
+        0xAAAA_AAAA_BFEA_AAAA,
+        0x00FF_00FF_003F_00FF,
+        0x3FFF_00FF_00FF_003F,
+        0x40DF_00FF_00FF_00FF,
+        0x00DC_00FF_00CF_00DC,
+        0x8002_0000_0000_0000,
+        0x0000_0000_1FFF_0000,
+        0x3210_8000_0008_C400,
+        0xFFFF_0000_0000_43C0,
+        0x0000_0000_0000_0010,
+        0x0000_03FF_FFFF_0000,
+        0x3FDA_1562_FFFF_FFFF,
+        0x0008_501A_AAAA_AAAA,
+        0x0000_20BF_FFFF_FFFF,
+        0x0000_2AAA_AAAA_AAAA,
+        0x0000_0000_3AAA_AAAA,
+        0xAAAB_AAA8_0000_0000,
+        0x95FF_AAAA_AAAA_AAAA,
+        0xAAA0_82AA_AABA_50AA,
+        0x075E_0000_0AAA_A50A,
+        0xFFFF_03FF_F7FF_FFFF,
+        0x0000_0000_00F8_007F,
+        0x0000_0000_07FF_FFFE,
+        0xFFFF_FF00_0000_0000,
+        0x0000_0000_0000_FFFF,
+        0x0FFF_FFFF_FF00_0000,
+        0x1BFB_FFFB_FF80_0000,
+        0x07FD_FFFF_FFFF_FFB9,
+        0x0007_FFFF_FFFF_FFFF,
+        0x0000_0000_0000_003F,
+        0x0000_0000_FFFF_FFFF,
+        0xF800_0000_0000_0000,
+        0x0000_0000_000F_FFFF,
+        0x000F_FFFF_FC00_0000,
+        0x0000_00FF_FFDF_C000,
+        0xEBC0_0000_0FFF_FFFC,
+        0xFFFF_FC00_0000_FFEF,
+        0x00FF_FFFF_C000_000F,
+        0x0000_0FFF_FFFC_0000,
+        0xFC00_0000_FFFF_FFC0,
+        0xFFFF_C000_000F_FFFF,
+        0x0FFF_FFFC_0000_00FF,
+        0x0000_FFFF_FFC0_0000,
+        0x0000_003F_FFFF_FC00,
+        0xF000_0003_F7FF_FFFC,
+        0xFFC0_0000_0FDF_FFFF,
+        0xFFFF_0000_003F_7FFF,
+        0xFFFF_FC00_0000_FDFF,
+        0x0000_0000_0000_0BF7,
+        0x0000_07E0_7FFF_FBFF,
+        0x0000_3FFF_FFFF_FFFF,
+        0xFFFF_FFFC_0000_0000,
+        0x0000_0000_0000_000F,
+    ],
+};
+
+/// Codepoints with the `Uppercase` property
+pub(crate) static UPPERCASE: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 3, 3, 3, 3, 3, 3, 4, 3, 3, 3, 3, 5, 12, 13, 13,
+        13, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 0, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 9, 10, 11, 12, 13, 14, 15, 16, 17, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 18, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 22,
+            22, 23, 22, 24, 25, 26, 27,
+        ],
+        [
+            0, 0, 0, 0, 28, 29, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 32, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 33, 22, 34, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 36, 0,
+            37, 38, 39, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 41, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 43, 44, 0, 45, 46, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 48, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
+            50, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60,
+            61, 62, 63, 64, 65, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 43, 68, 69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 3, 3, 3, 3, 3, 3, 4, 3, 3, 3, 3, 5, 6, 7, 3, 3, 3, 3, 8, 3, 3, 3, 3, 3, 3,
+            9, 10, 11, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+        [
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            3, 3, 3, 3, 3, 3,
+        ],
+    ],
+    leaves: &[
+        0x0000_0000_0000_0000,
+        0x0000_0000_07FF_FFFE,
+        0x0000_0000_7F7F_FFFF,
+        0xAA55_5555_5555_5555,
+        0x2B55_5555_5555_54AA,
+        0x11AE_D2D5_B1DB_CED6,
+        0x55D2_5555_4AAA_A490,
+        0x6C05_5555_5555_5555,
+        0x0000_0000_0000_557A,
+        0x8045_0000_0000_0000,
+        0x0000_0FFB_FFFE_D740,
+        0xE690_5555_551C_8000,
+        0x0000_FFFF_FFFF_FFFF,
+        0x5555_5555_0000_0000,
+        0x5555_5555_5555_5401,
+        0x5555_5555_5555_2AAB,
+        0xFFFE_5555_5555_5555,
+        0x0000_0000_007F_FFFF,
+        0xFFFF_FFFF_0000_0000,
+        0x0000_0000_0000_20BF,
+        0x003F_FFFF_FFFF_FFFF,
+        0xE7FF_FFFF_FFFF_0200,
+        0x5555_5555_5555_5555,
+        0x5555_5555_4015_5555,
+        0xFF00_FF00_3F00_FF00,
+        0x0000_FF00_AA00_3F00,
+        0x0F00_0000_0000_0000,
+        0x0F00_1F00_0F00_0F00,
+        0xC00F_3D50_3E27_3884,
+        0x0000_FFFF_0000_0020,
+        0x0000_0000_0000_0008,
+        0xFFC0_0000_0000_0000,
+        0x0000_0000_0000_FFFF,
+        0xC025_EA9D_0000_0000,
+        0x0004_2805_5555_5555,
+        0x0000_1555_5555_5555,
+        0x0000_0000_0555_5555,
+        0x5554_5554_0000_0000,
+        0x6A00_5555_5555_5555,
+        0x555F_7D55_5545_2855,
+        0x0020_0000_1555_5AF5,
+        0x07FF_FFFE_0000_0000,
+        0x0000_00FF_FFFF_FFFF,
+        0xFFFF_0000_0000_0000,
+        0x0000_0000_000F_FFFF,
+        0xF7FF_0000_0000_0000,
+        0x0000_0000_0037_F7FF,
+        0x0007_FFFF_FFFF_FFFF,
+        0x0000_003F_FFFF_0000,
+        0x0000_0000_FFFF_FFFF,
+        0x01FF_FFFF_0000_0000,
+        0xFFF0_0000_03FF_FFFF,
+        0xFFFF_FF00_0000_3FFF,
+        0x003F_DE64_D000_0003,
+        0x0000_03FF_FFFF_0000,
+        0x7B00_0000_1FDF_E7B0,
+        0xFFFF_F000_0001_FC5F,
+        0x03FF_FFFF_0000_003F,
+        0x0000_3FFF_FFF0_0000,
+        0xF000_0003_FFFF_FF00,
+        0xFFFF_0000_003F_FFFF,
+        0xFFFF_FF00_0000_03FF,
+        0x07FF_FFFC_0000_0001,
+        0x001F_FFFF_F000_0000,
+        0x0000_7FFF_FFC0_0000,
+        0x0000_01FF_FFFF_0000,
+        0x0000_0000_0000_0400,
+        0x0000_0003_FFFF_FFFF,
+        0xFFFF_03FF_FFFF_03FF,
+        0x0000_0000_0000_03FF,
+    ],
+};
+
+/// Codepoints with the `White_Space` property
+pub(crate) static WHITE_SPACE: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 6, 6, 8, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+        [
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 3, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+        [
+            4, 5, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+        [
+            3, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+        [
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 7, 7, 7, 7,
+        ],
+    ],
+    leaves: &[
+        0x0000_0001_0000_3E00,
+        0x0000_0000_0000_0000,
+        0x0000_0001_0000_0020,
+        0x0000_0000_0000_0001,
+        0x0000_8300_0000_07FF,
+        0x0000_0000_8000_0000,
+    ],
+};
+
+/// Codepoints with the `XID_Start` property
+pub(crate) static XID_START: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 4, 4, 4, 4, 4, 4, 5, 0, 6, 7, 8, 4, 4, 9, 4, 10, 11, 12,
+        13, 14, 15, 4, 16, 17, 18, 19, 20, 0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 5, 5, 7, 8, 9, 28, 29,
+        29, 29, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 5, 0, 6, 7, 8, 4, 4, 9, 4, 10, 11, 12, 13, 14, 15, 4,
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37,
+            38, 39, 40, 41, 42, 43, 44, 45, 44, 46, 47, 48, 49, 50, 0,
+        ],
+        [
+            51, 52, 53, 54, 4, 4, 4, 4, 4, 55, 56, 57, 58, 59, 60, 61, 62, 4, 4, 4, 4, 4, 4, 4, 4,
+            63, 64, 65, 66, 67, 68, 69, 14, 70, 71, 72, 73, 74, 75, 24, 76, 77, 78, 0, 79, 80, 81,
+            82, 83, 84, 85, 86, 4, 4, 4, 0, 4, 4, 4, 4, 87, 88, 89, 90,
+        ],
+        [
+            0, 91, 92, 0, 93, 94, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 4, 4, 95, 96, 97, 98, 99,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            100, 62, 101, 102, 103, 4, 104, 105, 0, 0, 0, 0, 0, 0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 106, 107, 4, 4, 4, 4, 108, 109,
+            110, 111, 112, 4, 113, 114, 115, 68, 116, 117, 118, 119, 120, 121, 122, 123, 124, 125,
+            126, 127, 4, 128, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 4, 4, 4, 4, 4, 131, 4, 132, 133, 134, 135, 136, 4, 137, 4, 4, 138,
+            139, 140, 141, 0, 142, 4, 143, 144, 145, 146, 147,
+        ],
+        [
+            148, 149, 4, 150, 0, 151, 0, 0, 0, 0, 152, 153, 154, 155, 110, 156, 4, 4, 157, 158, 159,
+            160, 161, 68, 4, 4, 4, 4, 162, 163, 164, 0, 165, 166, 73, 167, 168, 132, 169, 0, 170,
+            171, 172, 173, 72, 174, 175, 0, 4, 12, 176, 176, 83, 177, 178, 0, 0, 0, 179, 180, 181,
+            182, 183, 184,
+        ],
+        [
+            185, 186, 187, 188, 189, 190, 191, 192, 193, 48, 194, 73, 33, 195, 196, 197, 151, 198,
+            111, 199, 0, 0, 200, 201, 111, 202, 203, 0, 59, 44, 0, 0, 204, 0, 14, 205, 206, 207,
+            208, 209, 210, 211, 212, 70, 0, 0, 0, 213, 214, 215, 216, 0, 217, 218, 219, 220, 0, 0,
+            0, 221, 222, 0, 223, 0,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 132, 0, 4, 200, 4, 4, 4, 224, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 139, 225,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 111, 226, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 150, 4, 4, 4, 4, 4, 4, 4, 4, 4, 44, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 227, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 70, 228, 229, 230, 111, 231, 216, 0, 0, 0, 0, 0, 0,
+            232, 0, 0, 0, 4, 233, 234, 4, 235, 236, 237,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 238, 73, 0, 4, 176, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 239,
+        ],
+        [
+            4, 4, 4, 4, 240, 241, 4, 4, 4, 4, 4, 242, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 243, 244, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 245, 246, 247, 248, 249, 4, 4, 4, 4,
+            250, 251, 252, 253, 254, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 256, 0, 0, 0,
+        ],
+        [
+            105, 257, 0, 0, 258, 259, 0, 0, 0, 0, 230, 204, 0, 0, 0, 0, 0, 0, 0, 260, 0, 0, 0, 261,
+            0, 0, 0, 262, 0, 0, 0, 263, 4, 4, 4, 264, 4, 265, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 266, 267, 268, 0, 0, 0, 0, 0,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 269, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 110, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            131, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 270, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            227, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 227, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 271, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 272, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 5, 5, 7, 8, 9, 10, 11, 12, 13, 14, 8, 15, 5, 16, 8, 17,
+            18, 8, 19, 20, 8, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 21, 22, 23, 5, 24, 25, 5, 26, 5, 27, 8,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+        ],
+        [
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 8,
+        ],
+    ],
+    leaves: &[
+        0x0000_0000_0000_0000,
+        0x07FF_FFFE_07FF_FFFE,
+        0x0420_0400_0000_0000,
+        0xFF7F_FFFF_FF7F_FFFF,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0x0000_501F_0003_FFC3,
+        0xB8DF_0000_0000_0000,
+        0xFFFF_FFFB_FFFF_D740,
+        0xFFBF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FC03,
+        0xFFFE_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_027F_FFFF,
+        0x0000_0000_0000_01FF,
+        0x0007_87FF_FFFF_0000,
+        0xFFFF_FFFF_0000_0000,
+        0xFFFE_C000_0000_07FF,
+        0x9C00_C060_002F_FFFF,
+        0x0000_FFFF_FFFD_0000,
+        0xFFFF_FFFF_FFFF_E000,
+        0x0002_003F_FFFF_FFFF,
+        0x0430_07FF_FFFF_FC00,
+        0x0000_0110_043F_FFFF,
+        0xFFFF_07FF_01FF_FFFF,
+        0xFFFF_FFFF_0000_FEFF,
+        0x0000_0000_0000_03FF,
+        0x23FF_FFFF_FFFF_FFF0,
+        0xFFFE_0003_FF01_0000,
+        0x23C5_FDFF_FFF9_9FE1,
+        0x1003_0003_B000_4000,
+        0x036D_FDFF_FFF9_87E0,
+        0x001C_0000_5E00_0000,
+        0x23ED_FDFF_FFFB_BFE0,
+        0x0200_0003_0001_0000,
+        0x23ED_FDFF_FFF9_9FE0,
+        0x0002_0003_B000_0000,
+        0x03FF_C718_D63D_C7E8,
+        0x0000_0000_0001_0000,
+        0x23FF_FDFF_FFFD_DFE0,
+        0x0000_0003_3700_0000,
+        0x23EF_FDFF_FFFD_DFE1,
+        0x0006_0003_7000_0000,
+        0x27FF_FFFF_FFFD_DFF0,
+        0xFC00_0003_8070_4000,
+        0x2FFB_FFFF_FC7F_FFE0,
+        0x0000_0000_0000_007F,
+        0x0005_FFFF_FFFF_FFFE,
+        0x2005_FFAF_FFFF_F7D6,
+        0x0000_0000_F000_005F,
+        0x0000_0000_0000_0001,
+        0x0000_1FFF_FFFF_FEFF,
+        0x0000_0000_0000_1F00,
+        0x8000_07FF_FFFF_FFFF,
+        0xFFE1_C062_3C3F_0000,
+        0xFFFF_FFFF_0000_4003,
+        0xF7FF_FFFF_FFFF_20BF,
+        0xFFFF_FFFF_3D7F_3DFF,
+        0x7F3D_FFFF_FFFF_3DFF,
+        0xFFFF_FFFF_FF7F_FF3D,
+        0xFFFF_FFFF_FF3D_FFFF,
+        0x0000_0000_07FF_FFFF,
+        0xFFFF_FFFF_0000_FFFF,
+        0x3F3F_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FFFE,
+        0xFFFF_9FFF_FFFF_FFFF,
+        0xFFFF_FFFF_07FF_FFFE,
+        0x01FF_C7FF_FFFF_FFFF,
+        0x0003_FFFF_8003_FFFF,
+        0x0001_DFFF_0003_FFFF,
+        0x000F_FFFF_FFFF_FFFF,
+        0x0000_0000_1080_0000,
+        0x01FF_FFFF_FFFF_FFFF,
+        0xFFFF_05FF_FFFF_FFFF,
+        0x003F_FFFF_FFFF_FFFF,
+        0x0000_0000_7FFF_FFFF,
+        0x001F_3FFF_FFFF_0000,
+        0xFFFF_0FFF_FFFF_FFFF,
+        0xFFFF_FFFF_007F_FFFF,
+        0x0000_0000_001F_FFFF,
+        0x0000_0080_0000_0000,
+        0x000F_FFFF_FFFF_FFE0,
+        0x0000_0000_0000_1FE0,
+        0xFC00_C001_FFFF_FFF8,
+        0x0000_003F_FFFF_FFFF,
+        0x0000_000F_FFFF_FFFF,
+        0x3FFF_FFFF_FC00_E000,
+        0xE7FF_FFFF_FFFF_07FF,
+        0x046F_DE00_0000_0000,
+        0xFFFF_FFFF_3F3F_FFFF,
+        0x3FFF_FFFF_AAFF_3F3F,
+        0x5FDF_FFFF_FFFF_FFFF,
+        0x1FDC_1FFF_0FCF_1FDC,
+        0x8002_0000_0000_0000,
+        0x0000_0000_1FFF_0000,
+        0xF3FF_FD50_3F2F_FC84,
+        0xFFFF_FFFF_0000_43E0,
+        0x000C_781F_FFFF_FFFF,
+        0xFFFF_20BF_FFFF_FFFF,
+        0x0000_80FF_FFFF_FFFF,
+        0x7F7F_7F7F_007F_FFFF,
+        0x0000_0000_7F7F_7F7F,
+        0x1F3E_03FE_0000_00E0,
+        0xFFFF_FFFE_E07F_FFFF,
+        0xF7FF_FFFF_FFFF_FFFF,
+        0xFFFE_FFFF_FFFF_FFE0,
+        0xFFFF_FFFF_0000_7FFF,
+        0xFFFF_0000_0000_0000,
+        0x0000_0000_0000_1FFF,
+        0x3FFF_FFFF_FFFF_0000,
+        0x0000_0C00_FFFF_1FFF,
+        0x8000_7FFF_FFFF_FFFF,
+        0xFFFF_FFFF_3FFF_FFFF,
+        0x0000_FFFF_FFFF_FFFF,
+        0xFFFF_FFFC_FF80_0000,
+        0xFFFF_FFFF_FFFF_F9FF,
+        0xFFFE_0000_1FFF_FFFF,
+        0x0000_0007_FFFF_F7BB,
+        0x000F_FFFF_FFFF_FFFC,
+        0x68FC_0000_0000_0000,
+        0xFFFF_003F_FFFF_FC00,
+        0x1FFF_FFFF_0000_007F,
+        0x0007_FFFF_FFFF_FFF0,
+        0x7C00_FFDF_0000_8000,
+        0x0000_01FF_FFFF_FFFF,
+        0xC47F_FFFF_0000_0FF7,
+        0x3E62_FFFF_FFFF_FFFF,
+        0x001C_07FF_3800_0005,
+        0xFFFF_7F7F_007E_7E7E,
+        0xFFFF_03FF_F7FF_FFFF,
+        0x0000_0007_FFFF_FFFF,
+        0xFFFF_000F_FFFF_FFFF,
+        0x0FFF_FFFF_FFFF_F87F,
+        0xFFFF_3FFF_FFFF_FFFF,
+        0x0000_0000_03FF_FFFF,
+        0x5F7F_FDFF_A0F8_007F,
+        0xFFFF_FFFF_FFFF_FFDB,
+        0x0003_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFF8_0000,
+        0xFFFF_FFF0_3FFF_FFFF,
+        0x3FFF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_0000,
+        0xFFFF_FFFF_FFFC_FFFF,
+        0x03FF_0000_0000_00FF,
+        0xAA8A_0000_0000_0000,
+        0x1FFF_FFFF_FFFF_FFFF,
+        0x07FF_FFFE_0000_0000,
+        0xFFFF_FFC0_07FF_FFFE,
+        0x7FFF_FFFF_3FFF_FFFF,
+        0x0000_0000_1CFC_FCFC,
+        0xB7FF_FF7F_FFFF_EFFF,
+        0x0000_0000_3FFF_3FFF,
+        0x07FF_FFFF_FFFF_FFFF,
+        0x001F_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_1FFF_FFFF,
+        0x0000_0000_0001_FFFF,
+        0xFFFF_E000_FFFF_FFFF,
+        0x003F_FFFF_FFFF_07FF,
+        0x0000_0000_003E_FF0F,
+        0xFFFF_0000_3FFF_FFFF,
+        0x0FFF_FFFF_FF0F_FFFF,
+        0xFFFF_00FF_FFFF_FFFF,
+        0xF7FF_000F_FFFF_FFFF,
+        0x1BFB_FFFB_FFB7_F7FF,
+        0x007F_FFFF_FFFF_FFFF,
+        0x0000_00FF_003F_FFFF,
+        0x07FD_FFFF_FFFF_FFBF,
+        0x91BF_FFFF_FFFF_FD3F,
+        0x007F_FFFF_003F_FFFF,
+        0x0037_FFFF_0000_0000,
+        0x03FF_FFFF_003F_FFFF,
+        0xC0FF_FFFF_FFFF_FFFF,
+        0x003F_FFFF_FEEF_0001,
+        0x1FFF_FFFF_0000_0000,
+        0x0000_0000_1FFF_FFFF,
+        0x0000_001F_FFFF_FEFF,
+        0x0007_FFFF_003F_FFFF,
+        0x0000_0000_0003_FFFF,
+        0x0007_FFFF_FFFF_FFFF,
+        0xFFFF_803F_FFFF_FC00,
+        0x0000_0000_0000_003F,
+        0x0003_03FF_FFFF_FFFF,
+        0x0000_0000_0000_00FC,
+        0xFFFF_0080_1FFF_FFFF,
+        0xFFFF_0000_0000_003F,
+        0xFFFF_0000_0000_0003,
+        0x007F_FFFF_0000_001F,
+        0x00FF_FFFF_FFFF_FFF8,
+        0x0026_0000_0000_0000,
+        0x0000_FFFF_FFFF_FFF8,
+        0x0000_01FF_FFFF_0000,
+        0x0000_007F_FFFF_FFF8,
+        0x0047_FFFF_FFFF_0090,
+        0x0007_FFFF_FFFF_FFF8,
+        0x0000_0000_1400_001E,
+        0x8000_0FFF_FFFB_FFFF,
+        0xFFFF_01FF_BFFF_BD7F,
+        0x0000_0003_E001_0000,
+        0x00BF_FFFF_FFFF_4BFF,
+        0x0000_0000_000A_0000,
+        0x0000_0003_8000_0780,
+        0x0000_0000_0000_00B0,
+        0x0000_7FFF_FFFF_FFFF,
+        0x0000_0000_0F00_0000,
+        0x0000_0000_0000_0010,
+        0x0100_07FF_FFFF_FFFF,
+        0x0000_0FFF_FFFF_FFFF,
+        0x8000_0000_FFFF_FFFF,
+        0x8000_FFFF_FF6F_F27F,
+        0x0000_0000_0000_0002,
+        0xFFFF_FCFF_0000_0000,
+        0x0000_000A_0001_FFFF,
+        0x0407_FFFF_FFFF_F801,
+        0xFFFF_FFFF_F001_0000,
+        0xFFFF_0000_2000_03FF,
+        0x0000_0001_FFFF_FFFF,
+        0x0000_7FFF_FFFF_FDFF,
+        0xFFFC_0000_0000_0001,
+        0x0000_0000_0000_FFFF,
+        0x0001_FFFF_FFFF_FB7F,
+        0xFFFF_FDBF_0000_0040,
+        0xFFFF_0000_0100_03FF,
+        0x0000_0000_0FFF_FFFF,
+        0x0007_FFFF_0000_0000,
+        0x000F_FFFF_FFFD_FFF4,
+        0x0001_0000_0000_0000,
+        0x0000_0000_0000_000F,
+        0x0001_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_0000_007E,
+        0x0000_0000_3FFF_FFFF,
+        0xFFFF_0000_7FFF_FFFF,
+        0x7FFF_FFFF_FFFF_FFFF,
+        0x0000_3FFF_FFFF_0000,
+        0xE0FF_FFF8_0000_000F,
+        0x0000_1FFF_FFFF_FFFF,
+        0xF9FF_FFFF_0000_0000,
+        0x0000_0000_000F_FFFF,
+        0x0000_0000_0001_07FF,
+        0x0000_0000_FFF8_0000,
+        0x007C_000B_0000_0000,
+        0x8000_0000_003F_FFFF,
+        0x6FEF_0000_0000_0000,
+        0x0004_0007_FFFF_FFFF,
+        0xFFFF_00F0_0027_0000,
+        0x0FFF_FFFF_FFFF_FFFF,
+        0x1FFF_07FF_FFFF_FFFF,
+        0x0000_0000_03FF_01FF,
+        0xFFFF_FFFF_FFDF_FFFF,
+        0xEBFF_DE64_DFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FFEF,
+        0x7BFF_FFFF_DFDF_E7BF,
+        0xFFFF_FFFF_FFFD_FC5F,
+        0xFFFF_FF3F_FFFF_FFFF,
+        0xF7FF_FFFF_F7FF_FFFD,
+        0xFFDF_FFFF_FFDF_FFFF,
+        0xFFFF_7FFF_FFFF_7FFF,
+        0xFFFF_FDFF_FFFF_FDFF,
+        0x0000_0000_0000_0FF7,
+        0x0000_07E0_7FFF_FFFF,
+        0x0000_3FFF_FFFF_FFFF,
+        0x3F80_1FFF_FFFF_FFFF,
+        0x0000_0000_0000_4000,
+        0x0000_0FFF_FFFF_0000,
+        0x0001_3FFF_FFFF_0000,
+        0xC01F_3FB7_7FFF_FFFF,
+        0x7FFF_6F7F_0000_0000,
+        0x0000_0000_0000_001F,
+        0x0000_0000_0000_080F,
+        0x0AF7_FE96_FFFF_FFEF,
+        0x5EF7_F796_AA96_EA84,
+        0x0FFF_FBEE_0FFF_FBFF,
+        0x0000_0000_FFFF_FFFF,
+        0xFFFF_0001_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_07FF,
+        0x03FF_FFFF_FFFF_FFFF,
+    ],
+};
+
+/// Codepoints with the `XID_Continue` property
+pub(crate) static XID_CONTINUE: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 4, 4, 4, 4, 4, 4, 5, 4, 6, 7, 8, 4, 4, 9, 4, 10, 11, 12,
+        13, 14, 15, 4, 16, 17, 18, 19, 20, 0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 5, 5, 7, 8, 9, 30, 31,
+        31, 33, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 5, 4, 6, 7, 8, 4, 4, 9, 4, 10, 11, 12, 13, 14, 15, 4,
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 4, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36,
+            37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51,
+        ],
+        [
+            4, 52, 53, 54, 4, 4, 4, 4, 4, 55, 56, 57, 58, 59, 60, 61, 62, 4, 4, 4, 4, 4, 4, 4, 4,
+            63, 64, 65, 66, 67, 4, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 4, 81, 4, 82,
+            83, 84, 85, 86, 4, 4, 4, 4, 4, 4, 4, 4, 87, 88, 89, 90,
+        ],
+        [
+            91, 92, 93, 94, 95, 96, 97, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 4, 4, 4, 99, 100, 101, 102, 103, 98, 98, 98, 98, 98, 98, 98, 98,
+        ],
+        [
+            104, 62, 105, 4, 106, 4, 107, 108, 98, 98, 98, 98, 98, 98, 98, 98, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 98, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 109, 110, 4, 4, 4, 4, 111, 112, 4,
+            19, 113, 4, 114, 115, 116, 82, 4, 117, 118, 119, 4, 120, 121, 122, 4, 123, 124, 125, 4,
+            126, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 127, 128, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+        ],
+        [
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+        ],
+        [
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 4, 4, 4, 4, 4, 118, 4, 129, 130,
+            131, 19, 132, 4, 133, 4, 4, 134, 17, 135, 136, 137, 138, 4, 139, 140, 141, 142, 143,
+        ],
+        [
+            144, 145, 4, 146, 98, 147, 98, 148, 98, 98, 149, 150, 151, 152, 53, 153, 4, 4, 154, 155,
+            156, 157, 158, 82, 4, 4, 4, 4, 121, 159, 160, 98, 161, 162, 163, 164, 165, 129, 166, 98,
+            167, 168, 169, 170, 72, 171, 172, 98, 4, 97, 173, 173, 174, 175, 176, 98, 98, 98, 177,
+            178, 179, 180, 181, 182,
+        ],
+        [
+            4, 183, 146, 184, 185, 186, 4, 187, 188, 189, 190, 191, 192, 193, 194, 195, 4, 196, 4,
+            197, 98, 98, 198, 199, 4, 200, 70, 201, 202, 203, 98, 98, 146, 98, 204, 205, 206, 207,
+            208, 209, 142, 210, 211, 70, 98, 212, 98, 213, 214, 215, 216, 98, 217, 218, 219, 220,
+            98, 98, 98, 221, 222, 223, 224, 98,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 129, 98, 4, 225, 4, 4, 4, 226, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 17, 227,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 228, 229, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 146, 4, 4, 4, 4, 4, 4, 4, 4, 4, 203, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+        ],
+        [
+            98, 98, 98, 98, 230, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 4, 4, 4, 4, 4, 4, 4, 4, 70, 231, 142, 232, 121,
+            233, 234, 98, 98, 98, 98, 98, 98, 235, 98, 98, 98, 4, 236, 237, 4, 238, 239, 240,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 241, 163, 98, 4, 173,
+            98, 98, 98, 98, 98, 98, 98, 98,
+        ],
+        [
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 242,
+        ],
+        [
+            4, 4, 4, 4, 243, 244, 4, 4, 4, 4, 4, 245, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 4, 246, 247, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+        ],
+        [
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 0, 98, 98, 98, 98, 98, 98, 98, 98, 118, 203, 98, 98,
+        ],
+        [
+            98, 98, 98, 98, 98, 248, 249, 98, 98, 250, 98, 98, 98, 98, 98, 98, 4, 251, 252, 253,
+            254, 255, 4, 4, 4, 4, 256, 257, 258, 259, 260, 261, 98, 98, 98, 98, 98, 98, 98, 98, 262,
+            263, 264, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 265, 98,
+            98, 98,
+        ],
+        [
+            266, 21, 267, 98, 268, 269, 98, 98, 98, 98, 270, 230, 98, 98, 98, 98, 98, 98, 98, 271,
+            98, 98, 98, 272, 98, 98, 98, 273, 98, 98, 98, 274, 4, 4, 4, 275, 4, 276, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 277, 278, 279, 98, 98, 98, 98,
+            98,
+        ],
+        [
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 0, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 280, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 53, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            118, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 281, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            282, 98, 98, 98, 98, 98, 98,
+        ],
+        [
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 4, 4, 4, 4, 4, 4, 4, 4, 282, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 283, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 230, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+        ],
+        [
+            0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 5, 5, 7, 8, 9, 10, 11, 12, 13, 14, 8, 15, 5, 16, 8, 17,
+            18, 19, 20, 21, 22, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 23, 24, 25, 5, 26, 27, 5, 28, 5, 29,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+        ],
+        [
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 8,
+        ],
+        [
+            98, 98, 98, 98, 4, 4, 4, 228, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+            98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98, 98,
+        ],
+        [
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 32, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 8,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34,
+        ],
+    ],
+    leaves: &[
+        0x03FF_0000_0000_0000,
+        0x07FF_FFFE_87FF_FFFE,
+        0x04A0_0400_0000_0000,
+        0xFF7F_FFFF_FF7F_FFFF,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0x0000_501F_0003_FFC3,
+        0xB8DF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFB_FFFF_D7C0,
+        0xFFBF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FCFB,
+        0xFFFE_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_027F_FFFF,
+        0xBFFF_FFFF_FFFE_01FF,
+        0x0007_87FF_FFFF_00B6,
+        0xFFFF_FFFF_07FF_0000,
+        0xFFFF_C3FF_FFFF_FFFF,
+        0x9FFF_FDFF_9FEF_FFFF,
+        0xFFFF_FFFF_FFFF_0000,
+        0xFFFF_FFFF_FFFF_E7FF,
+        0x0003_FFFF_FFFF_FFFF,
+        0x243F_FFFF_FFFF_FFFF,
+        0x0000_3FFF_FFFF_FFFF,
+        0xFFFF_07FF_0FFF_FFFF,
+        0xFFFF_FFFF_FF80_FEFF,
+        0xFFFF_FFFB_FFFF_FFFF,
+        0xFFFE_FFCF_FFFF_FFFF,
+        0xF3C5_FDFF_FFF9_9FEF,
+        0x5003_FFCF_B080_799F,
+        0xD36D_FDFF_FFF9_87EE,
+        0x003F_FFC0_5E02_3987,
+        0xF3ED_FDFF_FFFB_BFEE,
+        0xFE00_FFCF_0001_3BBF,
+        0xF3ED_FDFF_FFF9_9FEE,
+        0x0002_FFCF_B0E0_399F,
+        0xC3FF_C718_D63D_C7EC,
+        0x0000_FFC0_0081_3DC7,
+        0xF3FF_FDFF_FFFD_DFFF,
+        0x0000_FFCF_3760_3DDF,
+        0xF3EF_FDFF_FFFD_DFEF,
+        0x000E_FFCF_7060_3DDF,
+        0xFFFF_FFFF_FFFD_DFFF,
+        0xFC00_FFCF_80F0_7DDF,
+        0x2FFB_FFFF_FC7F_FFEE,
+        0x000C_FFC0_FF5F_847F,
+        0x07FF_FFFF_FFFF_FFFE,
+        0x0000_0000_03FF_7FFF,
+        0x3FFF_FFAF_FFFF_F7D6,
+        0x0000_0000_F3FF_7F5F,
+        0xC2A0_03FF_0300_0001,
+        0xFFFE_1FFF_FFFF_FEFF,
+        0x1FFF_FFFF_FEFF_FFDF,
+        0x0000_0000_0000_0040,
+        0xFFFF_FFFF_FFFF_03FF,
+        0xFFFF_FFFF_3FFF_FFFF,
+        0xF7FF_FFFF_FFFF_20BF,
+        0xFFFF_FFFF_3D7F_3DFF,
+        0x7F3D_FFFF_FFFF_3DFF,
+        0xFFFF_FFFF_FF7F_FF3D,
+        0xFFFF_FFFF_FF3D_FFFF,
+        0x0003_FE00_E7FF_FFFF,
+        0xFFFF_FFFF_0000_FFFF,
+        0x3F3F_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FFFE,
+        0xFFFF_9FFF_FFFF_FFFF,
+        0xFFFF_FFFF_07FF_FFFE,
+        0x01FF_C7FF_FFFF_FFFF,
+        0x001F_FFFF_803F_FFFF,
+        0x000D_DFFF_000F_FFFF,
+        0x0000_03FF_308F_FFFF,
+        0xFFFF_FFFF_03FF_B800,
+        0x01FF_FFFF_FFFF_FFFF,
+        0xFFFF_07FF_FFFF_FFFF,
+        0x003F_FFFF_FFFF_FFFF,
+        0x0FFF_0FFF_7FFF_FFFF,
+        0x001F_3FFF_FFFF_FFC0,
+        0xFFFF_0FFF_FFFF_FFFF,
+        0x0000_0000_07FF_03FF,
+        0xFFFF_FFFF_0FFF_FFFF,
+        0x9FFF_FFFF_7FFF_FFFF,
+        0xBFFF_0080_03FF_03FF,
+        0x0000_0FFF_3FFF_FFFF,
+        0x000F_F800_03FF_1FFF,
+        0x000F_FFFF_FFFF_FFFF,
+        0x00FF_FFFF_FFFF_FFFF,
+        0x3FFF_FFFF_FFFF_E3FF,
+        0xE7FF_FFFF_FFFF_07FF,
+        0x07FF_FFFF_FFF7_0000,
+        0xFFFF_FFFF_3F3F_FFFF,
+        0x3FFF_FFFF_AAFF_3F3F,
+        0x5FDF_FFFF_FFFF_FFFF,
+        0x1FDC_1FFF_0FCF_1FDC,
+        0x8000_0000_0000_3000,
+        0x8002_0000_0010_0001,
+        0x0000_0000_1FFF_0000,
+        0x0001_FFE2_1FFF_0000,
+        0xF3FF_FD50_3F2F_FC84,
+        0xFFFF_FFFF_0000_43E0,
+        0x0000_0000_0000_01FF,
+        0x0000_0000_0000_0000,
+        0x000F_F81F_FFFF_FFFF,
+        0xFFFF_20BF_FFFF_FFFF,
+        0x8000_80FF_FFFF_FFFF,
+        0x7F7F_7F7F_007F_FFFF,
+        0xFFFF_FFFF_7F7F_7F7F,
+        0x1F3E_FFFE_0000_00E0,
+        0xFFFF_FFFE_E67F_FFFF,
+        0xFFFE_FFFF_FFFF_FFE0,
+        0xFFFF_FFFF_0000_7FFF,
+        0xFFFF_0000_0000_0000,
+        0x0000_0000_0000_1FFF,
+        0x3FFF_FFFF_FFFF_0000,
+        0x0000_0FFF_FFFF_1FFF,
+        0xBFF0_FFFF_FFFF_FFFF,
+        0xFFFF_FFFC_FF80_0000,
+        0xFFFF_FFFF_FFFF_F9FF,
+        0xFFFE_0000_1FFF_FFFF,
+        0x0000_10FF_FFFF_FFFF,
+        0xE8FF_FFFF_03FF_003F,
+        0xFFFF_3FFF_FFFF_FFFF,
+        0x1FFF_FFFF_000F_FFFF,
+        0x7FFF_FFFF_03FF_8001,
+        0x007F_FFFF_FFFF_FFFF,
+        0xFC7F_FFFF_03FF_3FFF,
+        0x007C_FFFF_3800_0007,
+        0xFFFF_7F7F_007E_7E7E,
+        0xFFFF_03FF_F7FF_FFFF,
+        0x03FF_37FF_FFFF_FFFF,
+        0xFFFF_000F_FFFF_FFFF,
+        0x0FFF_FFFF_FFFF_F87F,
+        0x0000_0000_03FF_FFFF,
+        0x5F7F_FDFF_E0F8_007F,
+        0xFFFF_FFFF_FFFF_FFDB,
+        0xFFFF_FFFF_FFF8_0000,
+        0xFFFF_FFF0_3FFF_FFFF,
+        0x3FFF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFFC_FFFF,
+        0x03FF_0000_0000_00FF,
+        0x0018_FFFF_0000_FFFF,
+        0xAA8A_0000_0000_E000,
+        0x1FFF_FFFF_FFFF_FFFF,
+        0x87FF_FFFE_03FF_0000,
+        0xFFFF_FFE0_07FF_FFFE,
+        0x7FFF_FFFF_FFFF_FFFF,
+        0x0000_0000_1CFC_FCFC,
+        0xB7FF_FF7F_FFFF_EFFF,
+        0x0000_0000_3FFF_3FFF,
+        0x07FF_FFFF_FFFF_FFFF,
+        0x001F_FFFF_FFFF_FFFF,
+        0x2000_0000_0000_0000,
+        0xFFFF_FFFF_1FFF_FFFF,
+        0x0000_0001_0001_FFFF,
+        0xFFFF_E000_FFFF_FFFF,
+        0x07FF_FFFF_FFFF_07FF,
+        0x0000_0000_003E_FF0F,
+        0xFFFF_03FF_3FFF_FFFF,
+        0x0FFF_FFFF_FF0F_FFFF,
+        0xFFFF_00FF_FFFF_FFFF,
+        0xF7FF_000F_FFFF_FFFF,
+        0x1BFB_FFFB_FFB7_F7FF,
+        0x0000_00FF_003F_FFFF,
+        0x07FD_FFFF_FFFF_FFBF,
+        0x91BF_FFFF_FFFF_FD3F,
+        0x007F_FFFF_003F_FFFF,
+        0x0000_0000_7FFF_FFFF,
+        0x0037_FFFF_0000_0000,
+        0x03FF_FFFF_003F_FFFF,
+        0xC0FF_FFFF_FFFF_FFFF,
+        0x873F_FFFF_FEEF_F06F,
+        0x1FFF_FFFF_0000_0000,
+        0x0000_0000_1FFF_FFFF,
+        0x0000_007F_FFFF_FEFF,
+        0x0007_FFFF_003F_FFFF,
+        0x0000_0000_0003_FFFF,
+        0x0007_FFFF_FFFF_FFFF,
+        0x03FF_00FF_FFFF_FFFF,
+        0xFFFF_BE3F_FFFF_FFFF,
+        0x0000_0000_0000_003F,
+        0x0003_1BFF_FFFF_FFFF,
+        0xFC00_0000_0000_00FC,
+        0xFFFF_0080_1FFF_FFFF,
+        0xFFFF_0000_0001_FFFF,
+        0xFFFF_0000_0000_003F,
+        0x007F_FFFF_0000_001F,
+        0x803F_FFC0_0000_007F,
+        0x03FF_01FF_FFFF_0004,
+        0xFFDF_FFFF_FFFF_FFFF,
+        0x004F_FFFF_FFFF_00F0,
+        0x0000_0000_17FF_DE1F,
+        0xC0FF_FFFF_FFFB_FFFF,
+        0x0000_0000_0000_0003,
+        0xFFFF_01FF_BFFF_BD7F,
+        0x03FF_07FF_FFFF_FFFF,
+        0xFBED_FDFF_FFF9_9FEF,
+        0x001F_1FCF_E081_399F,
+        0xFFBF_FFFF_FFFF_4BFF,
+        0x0000_0006_000F_F7A5,
+        0x0000_0003_C3FF_07FF,
+        0x0000_0000_03FF_00BF,
+        0xFF3F_FFFF_FFFF_FFFF,
+        0x0000_0000_3F00_0001,
+        0x0000_0000_03FF_0011,
+        0x0000_000F_FFFF_03FF,
+        0x03FF_0FFF_E7FF_FFFF,
+        0x0000_0000_0000_007F,
+        0xFFFF_FFFF_0000_0000,
+        0x8000_03FF_FFFF_FFFF,
+        0xF9BF_FFFF_FF6F_F27F,
+        0x0000_0000_03FF_000F,
+        0xFFFF_FCFF_0000_0000,
+        0x0000_001B_FCFF_FFFF,
+        0xFFFF_FFFF_FFFF_0080,
+        0xFFFF_0000_23FF_FFFF,
+        0x0000_00FF_0000_0000,
+        0x03FF_0001_FFFF_FFFF,
+        0xFF7F_FFFF_FFFF_FDFF,
+        0xFFFC_0000_03FF_0001,
+        0x007F_FEFF_FFFC_FFFF,
+        0xB47F_FFFF_FFFF_FB7F,
+        0xFFFF_FDBF_03FF_00FF,
+        0xFFFF_03FF_01FB_7FFF,
+        0x0000_03FF_0FFF_FFFF,
+        0x007F_FFFF_0000_0000,
+        0xC7FF_FFFF_FFFD_FFFF,
+        0x0000_0000_07FF_0007,
+        0x0001_0000_0000_0000,
+        0x0000_7FFF_FFFF_FFFF,
+        0x0000_0000_0000_000F,
+        0x0001_FFFF_FFFF_FFFF,
+        0x0000_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_003F_FFFF,
+        0x03FF_FFFF_FFFF_FFFF,
+        0xFFFF_03FF_7FFF_FFFF,
+        0x001F_3FFF_FFFF_03FF,
+        0xE0FF_FFF8_03FF_000F,
+        0x0000_0000_0000_FFFF,
+        0x03FF_1FFF_FFFF_FFFF,
+        0xF9FF_FFFF_0000_0000,
+        0x0000_0000_000F_FFFF,
+        0xFFFF_FFFF_FFFF_87FF,
+        0x0000_0000_FFFF_80FF,
+        0x007F_001B_0000_0000,
+        0x8000_0000_003F_FFFF,
+        0x6FEF_0000_0000_0000,
+        0x0004_0007_FFFF_FFFF,
+        0xFFFF_00F0_0027_0000,
+        0x0FFF_FFFF_FFFF_FFFF,
+        0x1FFF_07FF_FFFF_FFFF,
+        0x0000_0000_63FF_01FF,
+        0xF807_E3E0_0000_0000,
+        0x0000_3C00_0000_0FE7,
+        0x0000_0000_0000_001C,
+        0xFFFF_FFFF_FFDF_FFFF,
+        0xEBFF_DE64_DFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FFEF,
+        0x7BFF_FFFF_DFDF_E7BF,
+        0xFFFF_FFFF_FFFD_FC5F,
+        0xFFFF_FF3F_FFFF_FFFF,
+        0xF7FF_FFFF_F7FF_FFFD,
+        0xFFDF_FFFF_FFDF_FFFF,
+        0xFFFF_7FFF_FFFF_7FFF,
+        0xFFFF_FDFF_FFFF_FDFF,
+        0xFFFF_FFFF_FFFF_CFF7,
+        0xF87F_FFFF_FFFF_FFFF,
+        0x0020_1FFF_FFFF_FFFF,
+        0x0000_FFFE_F800_0010,
+        0x0000_07E0_7FFF_FFFF,
+        0xFFFF_07DB_F9FF_FF7F,
+        0x0000_0000_0000_8000,
+        0x3FFF_1FFF_FFFF_FFFF,
+        0x0000_0000_0000_43FF,
+        0x0000_7FFF_FFFF_0000,
+        0x03FF_FFFF_FFFF_0000,
+        0x07FF_FFFF_FFFF_0000,
+        0xC03F_FFFF_7FFF_FFFF,
+        0x7FFF_6F7F_0000_0000,
+        0x0000_0000_007F_001F,
+        0x0000_0000_03FF_0FFF,
+        0x0AF7_FE96_FFFF_FFEF,
+        0x5EF7_F796_AA96_EA84,
+        0x0FFF_FBEE_0FFF_FBFF,
+        0x0000_0000_FFFF_FFFF,
+        0xFFFF_0001_FFFF_FFFF,
+        0x0000_0000_3FFF_FFFF,
+        0xFFFF_FFFF_FFFF_07FF,
+    ],
+};
+
+/// Codepoints with the `Pattern_Syntax` property
+pub(crate) static PATTERN_SYNTAX: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+        4, 4, 4, 4, 4, 4, 4, 0, 1, 2, 3, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 4, 5, 6, 6, 6, 8, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            5, 6, 4, 4, 4, 4, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 9, 4, 4, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            10, 11, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 4, 4, 4, 4, 4, 4, 4, 4, 8, 8,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            12, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 13, 4, 4, 4, 4, 14,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            0, 1, 2, 3, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 4, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+        [
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+            1, 1, 1, 1, 1, 1,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+            7, 7, 7, 7, 7, 7,
+        ],
+    ],
+    leaves: &[
+        0xFC00_FFFE_0000_0000,
+        0x7800_0001_7800_0001,
+        0x8843_5AFE_0000_0000,
+        0x0080_0000_0080_0000,
+        0x0000_0000_0000_0000,
+        0x7FFF_00FF_FFFF_0000,
+        0x0000_0000_7FEF_FFFE,
+        0xFFFF_FFFF_FFFF_0000,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0x0000_0000_FFFF_FFFF,
+        0x003F_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_FFF0_0000,
+        0x0001_0001_FFFF_FF0E,
+        0xC000_0000_0000_0000,
+        0x0000_0000_0000_0060,
+    ],
+};
@@ -0,0 +1,102 @@
+"""Generates src/tables/script.rs: the `Script` enum, and the `Script` and `Script_Extensions`
+properties of every codepoint (UAX #24).
+
+    python3 src/tables/script.py <path to UCD> > src/tables/script.rs
+
+The script extensions trie maps a codepoint to an index into `SCRIPT_EXTENSIONS`, index 0 marks
+codepoints whose extensions are just their script.
+"""
+
+from ucd import CODEPOINTS, enumerated_property, header, parse_lines, parse_range, ucd_dir, value_trie, wrap
+
+# `ScriptSet` words, enough bits for every script
+WORDS = 3
+
+
+def main() -> None:
+    ucd = ucd_dir()
+
+    # (short, long) aliases, in the order of PropertyValueAliases.txt
+    names = [(fields[1], fields[2]) for fields in parse_lines(ucd / "PropertyValueAliases.txt") if fields[0] == "sc"]
+    assert len(names) <= WORDS * 64
+
+    variants = {long: long.replace("_", "") for _, long in names}
+    index = {short: i for i, (short, _) in enumerate(names)}
+
+    scripts = enumerated_property(ucd / "Scripts.txt", "Unknown")
+    short_of = {long: short for short, long in names}
+    scripts = [short_of[long] for long in scripts]
+
+    sets = [(0,) * WORDS]
+    set_ids = {sets[0]: 0}
+    extension_ids = ["0"] * CODEPOINTS
+
+    for fields in parse_lines(ucd / "ScriptExtensions.txt"):
+        words = [0] * WORDS
+
+        for short in fields[1].split():
+            words[index[short] // 64] |= 1 << (index[short] % 64)
+
+        words = tuple(words)
+
+        if words not in set_ids:
+            set_ids[words] = len(sets)
+            sets.append(words)
+
+        for cp in parse_range(fields[0]):
+            extension_ids[cp] = str(set_ids[words])
+
+    assert len(sets) <= 0x100
+
+    print(header("script.py", ["PropertyValueAliases.txt", "Scripts.txt", "ScriptExtensions.txt"]))
+    print("use super::Utf8Trie;")
+    print("use crate::script::ScriptSet;")
+    print()
+    print("/// The `Script` property of a codepoint, as defined in [UAX #24](https://www.unicode.org/reports/tr24/).")
+    print("///")
+    print("/// Variants are named after the long property value aliases, their ISO 15924 codes are")
+    print("/// [`short_name`][Self::short_name]. `Common` is used by codepoints shared by many scripts, like")
+    print("/// punctuation and digits, `Inherited` by combining marks that take on the script of the codepoint")
+    print("/// they combine with, and `Unknown` by unassigned, private use and noncharacter codepoints.")
+    print("#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]")
+    print("#[non_exhaustive]")
+    print("#[repr(u8)]")
+    print("pub enum Script {")
+
+    for short, long in names:
+        print(f"    /// `{short}`")
+        print(f"    {variants[long]},")
+
+    print("}")
+    print()
+    print("/// The `(short, long)` names of every `Script`, in declaration order")
+    print(f"pub(crate) static NAMES: [(&str, &str); {len(names)}] = [")
+    print("\n".join(wrap((f'("{short}", "{long}"),' for short, long in names), indent=4)))
+    print("];")
+    print()
+
+    for short, long in names:
+        if short in scripts:
+            print(f"/// `Script::{variants[long]}`, shortened to keep the table below readable")
+            print(f"const {short.upper()}: Script = Script::{variants[long]};")
+
+    print()
+    print(value_trie("SCRIPT", "The `Script` of every codepoint", "Script", [s.upper() for s in scripts]))
+    print("/// The distinct `Script_Extensions` sets, indexed by `SCRIPT_EXTENSION`. Index 0 is unused, it")
+    print("/// marks codepoints whose extensions are just their script")
+    print(f"pub(crate) static SCRIPT_EXTENSIONS: [ScriptSet; {len(sets)}] = [")
+
+    def word(value: int) -> str:
+        digits = f"{value:016X}"
+        return "0x" + "_".join(digits[i : i + 4] for i in range(0, 16, 4))
+
+    for words in sets:
+        print(f"    ScriptSet::from_words([{', '.join(map(word, words))}]),")
+
+    print("];")
+    print()
+    print(value_trie("SCRIPT_EXTENSION", "The index of the `Script_Extensions` of every codepoint", "u8", extension_ids), end="")
+
+
+if __name__ == "__main__":
+    main()
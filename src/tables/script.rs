@@ -0,0 +1,4357 @@
+//! Generated by `src/tables/script.py` from the Unicode 17.0.0 files
+//! PropertyValueAliases.txt, Scripts.txt, ScriptExtensions.txt. Do not edit by hand.
+
+use super::Utf8Trie;
+use crate::script::ScriptSet;
+
+/// The `Script` property of a codepoint, as defined in [UAX #24](https://www.unicode.org/reports/tr24/).
+///
+/// Variants are named after the long property value aliases, their ISO 15924 codes are
+/// [`short_name`][Self::short_name]. `Common` is used by codepoints shared by many scripts, like
+/// punctuation and digits, `Inherited` by combining marks that take on the script of the codepoint
+/// they combine with, and `Unknown` by unassigned, private use and noncharacter codepoints.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum Script {
+    /// `Adlm`
+    Adlam,
+    /// `Aghb`
+    CaucasianAlbanian,
+    /// `Ahom`
+    Ahom,
+    /// `Arab`
+    Arabic,
+    /// `Armi`
+    ImperialAramaic,
+    /// `Armn`
+    Armenian,
+    /// `Avst`
+    Avestan,
+    /// `Bali`
+    Balinese,
+    /// `Bamu`
+    Bamum,
+    /// `Bass`
+    BassaVah,
+    /// `Batk`
+    Batak,
+    /// `Beng`
+    Bengali,
+    /// `Berf`
+    BeriaErfe,
+    /// `Bhks`
+    Bhaiksuki,
+    /// `Bopo`
+    Bopomofo,
+    /// `Brah`
+    Brahmi,
+    /// `Brai`
+    Braille,
+    /// `Bugi`
+    Buginese,
+    /// `Buhd`
+    Buhid,
+    /// `Cakm`
+    Chakma,
+    /// `Cans`
+    CanadianAboriginal,
+    /// `Cari`
+    Carian,
+    /// `Cham`
+    Cham,
+    /// `Cher`
+    Cherokee,
+    /// `Chrs`
+    Chorasmian,
+    /// `Copt`
+    Coptic,
+    /// `Cpmn`
+    CyproMinoan,
+    /// `Cprt`
+    Cypriot,
+    /// `Cyrl`
+    Cyrillic,
+    /// `Deva`
+    Devanagari,
+    /// `Diak`
+    DivesAkuru,
+    /// `Dogr`
+    Dogra,
+    /// `Dsrt`
+    Deseret,
+    /// `Dupl`
+    Duployan,
+    /// `Egyp`
+    EgyptianHieroglyphs,
+    /// `Elba`
+    Elbasan,
+    /// `Elym`
+    Elymaic,
+    /// `Ethi`
+    Ethiopic,
+    /// `Gara`
+    Garay,
+    /// `Geor`
+    Georgian,
+    /// `Glag`
+    Glagolitic,
+    /// `Gong`
+    GunjalaGondi,
+    /// `Gonm`
+    MasaramGondi,
+    /// `Goth`
+    Gothic,
+    /// `Gran`
+    Grantha,
+    /// `Grek`
+    Greek,
+    /// `Gujr`
+    Gujarati,
+    /// `Gukh`
+    GurungKhema,
+    /// `Guru`
+    Gurmukhi,
+    /// `Hang`
+    Hangul,
+    /// `Hani`
+    Han,
+    /// `Hano`
+    Hanunoo,
+    /// `Hatr`
+    Hatran,
+    /// `Hebr`
+    Hebrew,
+    /// `Hira`
+    Hiragana,
+    /// `Hluw`
+    AnatolianHieroglyphs,
+    /// `Hmng`
+    PahawhHmong,
+    /// `Hmnp`
+    NyiakengPuachueHmong,
+    /// `Hrkt`
+    KatakanaOrHiragana,
+    /// `Hung`
+    OldHungarian,
+    /// `Ital`
+    OldItalic,
+    /// `Java`
+    Javanese,
+    /// `Kali`
+    KayahLi,
+    /// `Kana`
+    Katakana,
+    /// `Kawi`
+    Kawi,
+    /// `Khar`
+    Kharoshthi,
+    /// `Khmr`
+    Khmer,
+    /// `Khoj`
+    Khojki,
+    /// `Kits`
+    KhitanSmallScript,
+    /// `Knda`
+    Kannada,
+    /// `Krai`
+    KiratRai,
+    /// `Kthi`
+    Kaithi,
+    /// `Lana`
+    TaiTham,
+    /// `Laoo`
+    Lao,
+    /// `Latn`
+    Latin,
+    /// `Lepc`
+    Lepcha,
+    /// `Limb`
+    Limbu,
+    /// `Lina`
+    LinearA,
+    /// `Linb`
+    LinearB,
+    /// `Lisu`
+    Lisu,
+    /// `Lyci`
+    Lycian,
+    /// `Lydi`
+    Lydian,
+    /// `Mahj`
+    Mahajani,
+    /// `Maka`
+    Makasar,
+    /// `Mand`
+    Mandaic,
+    /// `Mani`
+    Manichaean,
+    /// `Marc`
+    Marchen,
+    /// `Medf`
+    Medefaidrin,
+    /// `Mend`
+    MendeKikakui,
+    /// `Merc`
+    MeroiticCursive,
+    /// `Mero`
+    MeroiticHieroglyphs,
+    /// `Mlym`
+    Malayalam,
+    /// `Modi`
+    Modi,
+    /// `Mong`
+    Mongolian,
+    /// `Mroo`
+    Mro,
+    /// `Mtei`
+    MeeteiMayek,
+    /// `Mult`
+    Multani,
+    /// `Mymr`
+    Myanmar,
+    /// `Nagm`
+    NagMundari,
+    /// `Nand`
+    Nandinagari,
+    /// `Narb`
+    OldNorthArabian,
+    /// `Nbat`
+    Nabataean,
+    /// `Newa`
+    Newa,
+    /// `Nkoo`
+    Nko,
+    /// `Nshu`
+    Nushu,
+    /// `Ogam`
+    Ogham,
+    /// `Olck`
+    OlChiki,
+    /// `Onao`
+    OlOnal,
+    /// `Orkh`
+    OldTurkic,
+    /// `Orya`
+    Oriya,
+    /// `Osge`
+    Osage,
+    /// `Osma`
+    Osmanya,
+    /// `Ougr`
+    OldUyghur,
+    /// `Palm`
+    Palmyrene,
+    /// `Pauc`
+    PauCinHau,
+    /// `Perm`
+    OldPermic,
+    /// `Phag`
+    PhagsPa,
+    /// `Phli`
+    InscriptionalPahlavi,
+    /// `Phlp`
+    PsalterPahlavi,
+    /// `Phnx`
+    Phoenician,
+    /// `Plrd`
+    Miao,
+    /// `Prti`
+    InscriptionalParthian,
+    /// `Rjng`
+    Rejang,
+    /// `Rohg`
+    HanifiRohingya,
+    /// `Runr`
+    Runic,
+    /// `Samr`
+    Samaritan,
+    /// `Sarb`
+    OldSouthArabian,
+    /// `Saur`
+    Saurashtra,
+    /// `Sgnw`
+    SignWriting,
+    /// `Shaw`
+    Shavian,
+    /// `Shrd`
+    Sharada,
+    /// `Sidd`
+    Siddham,
+    /// `Sidt`
+    Sidetic,
+    /// `Sind`
+    Khudawadi,
+    /// `Sinh`
+    Sinhala,
+    /// `Sogd`
+    Sogdian,
+    /// `Sogo`
+    OldSogdian,
+    /// `Sora`
+    SoraSompeng,
+    /// `Soyo`
+    Soyombo,
+    /// `Sund`
+    Sundanese,
+    /// `Sunu`
+    Sunuwar,
+    /// `Sylo`
+    SylotiNagri,
+    /// `Syrc`
+    Syriac,
+    /// `Tagb`
+    Tagbanwa,
+    /// `Takr`
+    Takri,
+    /// `Tale`
+    TaiLe,
+    /// `Talu`
+    NewTaiLue,
+    /// `Taml`
+    Tamil,
+    /// `Tang`
+    Tangut,
+    /// `Tavt`
+    TaiViet,
+    /// `Tayo`
+    TaiYo,
+    /// `Telu`
+    Telugu,
+    /// `Tfng`
+    Tifinagh,
+    /// `Tglg`
+    Tagalog,
+    /// `Thaa`
+    Thaana,
+    /// `Thai`
+    Thai,
+    /// `Tibt`
+    Tibetan,
+    /// `Tirh`
+    Tirhuta,
+    /// `Tnsa`
+    Tangsa,
+    /// `Todr`
+    Todhri,
+    /// `Tols`
+    TolongSiki,
+    /// `Toto`
+    Toto,
+    /// `Tutg`
+    TuluTigalari,
+    /// `Ugar`
+    Ugaritic,
+    /// `Vaii`
+    Vai,
+    /// `Vith`
+    Vithkuqi,
+    /// `Wara`
+    WarangCiti,
+    /// `Wcho`
+    Wancho,
+    /// `Xpeo`
+    OldPersian,
+    /// `Xsux`
+    Cuneiform,
+    /// `Yezi`
+    Yezidi,
+    /// `Yiii`
+    Yi,
+    /// `Zanb`
+    ZanabazarSquare,
+    /// `Zinh`
+    Inherited,
+    /// `Zyyy`
+    Common,
+    /// `Zzzz`
+    Unknown,
+}
+
+/// The `(short, long)` names of every `Script`, in declaration order
+pub(crate) static NAMES: [(&str, &str); 176] = [
+    ("Adlm", "Adlam"), ("Aghb", "Caucasian_Albanian"), ("Ahom", "Ahom"), ("Arab", "Arabic"),
+    ("Armi", "Imperial_Aramaic"), ("Armn", "Armenian"), ("Avst", "Avestan"), ("Bali", "Balinese"),
+    ("Bamu", "Bamum"), ("Bass", "Bassa_Vah"), ("Batk", "Batak"), ("Beng", "Bengali"),
+    ("Berf", "Beria_Erfe"), ("Bhks", "Bhaiksuki"), ("Bopo", "Bopomofo"), ("Brah", "Brahmi"),
+    ("Brai", "Braille"), ("Bugi", "Buginese"), ("Buhd", "Buhid"), ("Cakm", "Chakma"),
+    ("Cans", "Canadian_Aboriginal"), ("Cari", "Carian"), ("Cham", "Cham"), ("Cher", "Cherokee"),
+    ("Chrs", "Chorasmian"), ("Copt", "Coptic"), ("Cpmn", "Cypro_Minoan"), ("Cprt", "Cypriot"),
+    ("Cyrl", "Cyrillic"), ("Deva", "Devanagari"), ("Diak", "Dives_Akuru"), ("Dogr", "Dogra"),
+    ("Dsrt", "Deseret"), ("Dupl", "Duployan"), ("Egyp", "Egyptian_Hieroglyphs"),
+    ("Elba", "Elbasan"), ("Elym", "Elymaic"), ("Ethi", "Ethiopic"), ("Gara", "Garay"),
+    ("Geor", "Georgian"), ("Glag", "Glagolitic"), ("Gong", "Gunjala_Gondi"),
+    ("Gonm", "Masaram_Gondi"), ("Goth", "Gothic"), ("Gran", "Grantha"), ("Grek", "Greek"),
+    ("Gujr", "Gujarati"), ("Gukh", "Gurung_Khema"), ("Guru", "Gurmukhi"), ("Hang", "Hangul"),
+    ("Hani", "Han"), ("Hano", "Hanunoo"), ("Hatr", "Hatran"), ("Hebr", "Hebrew"),
+    ("Hira", "Hiragana"), ("Hluw", "Anatolian_Hieroglyphs"), ("Hmng", "Pahawh_Hmong"),
+    ("Hmnp", "Nyiakeng_Puachue_Hmong"), ("Hrkt", "Katakana_Or_Hiragana"), ("Hung", "Old_Hungarian"),
+    ("Ital", "Old_Italic"), ("Java", "Javanese"), ("Kali", "Kayah_Li"), ("Kana", "Katakana"),
+    ("Kawi", "Kawi"), ("Khar", "Kharoshthi"), ("Khmr", "Khmer"), ("Khoj", "Khojki"),
+    ("Kits", "Khitan_Small_Script"), ("Knda", "Kannada"), ("Krai", "Kirat_Rai"), ("Kthi", "Kaithi"),
+    ("Lana", "Tai_Tham"), ("Laoo", "Lao"), ("Latn", "Latin"), ("Lepc", "Lepcha"), ("Limb", "Limbu"),
+    ("Lina", "Linear_A"), ("Linb", "Linear_B"), ("Lisu", "Lisu"), ("Lyci", "Lycian"),
+    ("Lydi", "Lydian"), ("Mahj", "Mahajani"), ("Maka", "Makasar"), ("Mand", "Mandaic"),
+    ("Mani", "Manichaean"), ("Marc", "Marchen"), ("Medf", "Medefaidrin"), ("Mend", "Mende_Kikakui"),
+    ("Merc", "Meroitic_Cursive"), ("Mero", "Meroitic_Hieroglyphs"), ("Mlym", "Malayalam"),
+    ("Modi", "Modi"), ("Mong", "Mongolian"), ("Mroo", "Mro"), ("Mtei", "Meetei_Mayek"),
+    ("Mult", "Multani"), ("Mymr", "Myanmar"), ("Nagm", "Nag_Mundari"), ("Nand", "Nandinagari"),
+    ("Narb", "Old_North_Arabian"), ("Nbat", "Nabataean"), ("Newa", "Newa"), ("Nkoo", "Nko"),
+    ("Nshu", "Nushu"), ("Ogam", "Ogham"), ("Olck", "Ol_Chiki"), ("Onao", "Ol_Onal"),
+    ("Orkh", "Old_Turkic"), ("Orya", "Oriya"), ("Osge", "Osage"), ("Osma", "Osmanya"),
+    ("Ougr", "Old_Uyghur"), ("Palm", "Palmyrene"), ("Pauc", "Pau_Cin_Hau"), ("Perm", "Old_Permic"),
+    ("Phag", "Phags_Pa"), ("Phli", "Inscriptional_Pahlavi"), ("Phlp", "Psalter_Pahlavi"),
+    ("Phnx", "Phoenician"), ("Plrd", "Miao"), ("Prti", "Inscriptional_Parthian"),
+    ("Rjng", "Rejang"), ("Rohg", "Hanifi_Rohingya"), ("Runr", "Runic"), ("Samr", "Samaritan"),
+    ("Sarb", "Old_South_Arabian"), ("Saur", "Saurashtra"), ("Sgnw", "SignWriting"),
+    ("Shaw", "Shavian"), ("Shrd", "Sharada"), ("Sidd", "Siddham"), ("Sidt", "Sidetic"),
+    ("Sind", "Khudawadi"), ("Sinh", "Sinhala"), ("Sogd", "Sogdian"), ("Sogo", "Old_Sogdian"),
+    ("Sora", "Sora_Sompeng"), ("Soyo", "Soyombo"), ("Sund", "Sundanese"), ("Sunu", "Sunuwar"),
+    ("Sylo", "Syloti_Nagri"), ("Syrc", "Syriac"), ("Tagb", "Tagbanwa"), ("Takr", "Takri"),
+    ("Tale", "Tai_Le"), ("Talu", "New_Tai_Lue"), ("Taml", "Tamil"), ("Tang", "Tangut"),
+    ("Tavt", "Tai_Viet"), ("Tayo", "Tai_Yo"), ("Telu", "Telugu"), ("Tfng", "Tifinagh"),
+    ("Tglg", "Tagalog"), ("Thaa", "Thaana"), ("Thai", "Thai"), ("Tibt", "Tibetan"),
+    ("Tirh", "Tirhuta"), ("Tnsa", "Tangsa"), ("Todr", "Todhri"), ("Tols", "Tolong_Siki"),
+    ("Toto", "Toto"), ("Tutg", "Tulu_Tigalari"), ("Ugar", "Ugaritic"), ("Vaii", "Vai"),
+    ("Vith", "Vithkuqi"), ("Wara", "Warang_Citi"), ("Wcho", "Wancho"), ("Xpeo", "Old_Persian"),
+    ("Xsux", "Cuneiform"), ("Yezi", "Yezidi"), ("Yiii", "Yi"), ("Zanb", "Zanabazar_Square"),
+    ("Zinh", "Inherited"), ("Zyyy", "Common"), ("Zzzz", "Unknown"),
+];
+
+/// `Script::Adlam`, shortened to keep the table below readable
+const ADLM: Script = Script::Adlam;
+/// `Script::CaucasianAlbanian`, shortened to keep the table below readable
+const AGHB: Script = Script::CaucasianAlbanian;
+/// `Script::Ahom`, shortened to keep the table below readable
+const AHOM: Script = Script::Ahom;
+/// `Script::Arabic`, shortened to keep the table below readable
+const ARAB: Script = Script::Arabic;
+/// `Script::ImperialAramaic`, shortened to keep the table below readable
+const ARMI: Script = Script::ImperialAramaic;
+/// `Script::Armenian`, shortened to keep the table below readable
+const ARMN: Script = Script::Armenian;
+/// `Script::Avestan`, shortened to keep the table below readable
+const AVST: Script = Script::Avestan;
+/// `Script::Balinese`, shortened to keep the table below readable
+const BALI: Script = Script::Balinese;
+/// `Script::Bamum`, shortened to keep the table below readable
+const BAMU: Script = Script::Bamum;
+/// `Script::BassaVah`, shortened to keep the table below readable
+const BASS: Script = Script::BassaVah;
+/// `Script::Batak`, shortened to keep the table below readable
+const BATK: Script = Script::Batak;
+/// `Script::Bengali`, shortened to keep the table below readable
+const BENG: Script = Script::Bengali;
+/// `Script::BeriaErfe`, shortened to keep the table below readable
+const BERF: Script = Script::BeriaErfe;
+/// `Script::Bhaiksuki`, shortened to keep the table below readable
+const BHKS: Script = Script::Bhaiksuki;
+/// `Script::Bopomofo`, shortened to keep the table below readable
+const BOPO: Script = Script::Bopomofo;
+/// `Script::Brahmi`, shortened to keep the table below readable
+const BRAH: Script = Script::Brahmi;
+/// `Script::Braille`, shortened to keep the table below readable
+const BRAI: Script = Script::Braille;
+/// `Script::Buginese`, shortened to keep the table below readable
+const BUGI: Script = Script::Buginese;
+/// `Script::Buhid`, shortened to keep the table below readable
+const BUHD: Script = Script::Buhid;
+/// `Script::Chakma`, shortened to keep the table below readable
+const CAKM: Script = Script::Chakma;
+/// `Script::CanadianAboriginal`, shortened to keep the table below readable
+const CANS: Script = Script::CanadianAboriginal;
+/// `Script::Carian`, shortened to keep the table below readable
+const CARI: Script = Script::Carian;
+/// `Script::Cham`, shortened to keep the table below readable
+const CHAM: Script = Script::Cham;
+/// `Script::Cherokee`, shortened to keep the table below readable
+const CHER: Script = Script::Cherokee;
+/// `Script::Chorasmian`, shortened to keep the table below readable
+const CHRS: Script = Script::Chorasmian;
+/// `Script::Coptic`, shortened to keep the table below readable
+const COPT: Script = Script::Coptic;
+/// `Script::CyproMinoan`, shortened to keep the table below readable
+const CPMN: Script = Script::CyproMinoan;
+/// `Script::Cypriot`, shortened to keep the table below readable
+const CPRT: Script = Script::Cypriot;
+/// `Script::Cyrillic`, shortened to keep the table below readable
+const CYRL: Script = Script::Cyrillic;
+/// `Script::Devanagari`, shortened to keep the table below readable
+const DEVA: Script = Script::Devanagari;
+/// `Script::DivesAkuru`, shortened to keep the table below readable
+const DIAK: Script = Script::DivesAkuru;
+/// `Script::Dogra`, shortened to keep the table below readable
+const DOGR: Script = Script::Dogra;
+/// `Script::Deseret`, shortened to keep the table below readable
+const DSRT: Script = Script::Deseret;
+/// `Script::Duployan`, shortened to keep the table below readable
+const DUPL: Script = Script::Duployan;
+/// `Script::EgyptianHieroglyphs`, shortened to keep the table below readable
+const EGYP: Script = Script::EgyptianHieroglyphs;
+/// `Script::Elbasan`, shortened to keep the table below readable
+const ELBA: Script = Script::Elbasan;
+/// `Script::Elymaic`, shortened to keep the table below readable
+const ELYM: Script = Script::Elymaic;
+/// `Script::Ethiopic`, shortened to keep the table below readable
+const ETHI: Script = Script::Ethiopic;
+/// `Script::Garay`, shortened to keep the table below readable
+const GARA: Script = Script::Garay;
+/// `Script::Georgian`, shortened to keep the table below readable
+const GEOR: Script = Script::Georgian;
+/// `Script::Glagolitic`, shortened to keep the table below readable
+const GLAG: Script = Script::Glagolitic;
+/// `Script::GunjalaGondi`, shortened to keep the table below readable
+const GONG: Script = Script::GunjalaGondi;
+/// `Script::MasaramGondi`, shortened to keep the table below readable
+const GONM: Script = Script::MasaramGondi;
+/// `Script::Gothic`, shortened to keep the table below readable
+const GOTH: Script = Script::Gothic;
+/// `Script::Grantha`, shortened to keep the table below readable
+const GRAN: Script = Script::Grantha;
+/// `Script::Greek`, shortened to keep the table below readable
+const GREK: Script = Script::Greek;
+/// `Script::Gujarati`, shortened to keep the table below readable
+const GUJR: Script = Script::Gujarati;
+/// `Script::GurungKhema`, shortened to keep the table below readable
+const GUKH: Script = Script::GurungKhema;
+/// `Script::Gurmukhi`, shortened to keep the table below readable
+const GURU: Script = Script::Gurmukhi;
+/// `Script::Hangul`, shortened to keep the table below readable
+const HANG: Script = Script::Hangul;
+/// `Script::Han`, shortened to keep the table below readable
+const HANI: Script = Script::Han;
+/// `Script::Hanunoo`, shortened to keep the table below readable
+const HANO: Script = Script::Hanunoo;
+/// `Script::Hatran`, shortened to keep the table below readable
+const HATR: Script = Script::Hatran;
+/// `Script::Hebrew`, shortened to keep the table below readable
+const HEBR: Script = Script::Hebrew;
+/// `Script::Hiragana`, shortened to keep the table below readable
+const HIRA: Script = Script::Hiragana;
+/// `Script::AnatolianHieroglyphs`, shortened to keep the table below readable
+const HLUW: Script = Script::AnatolianHieroglyphs;
+/// `Script::PahawhHmong`, shortened to keep the table below readable
+const HMNG: Script = Script::PahawhHmong;
+/// `Script::NyiakengPuachueHmong`, shortened to keep the table below readable
+const HMNP: Script = Script::NyiakengPuachueHmong;
+/// `Script::OldHungarian`, shortened to keep the table below readable
+const HUNG: Script = Script::OldHungarian;
+/// `Script::OldItalic`, shortened to keep the table below readable
+const ITAL: Script = Script::OldItalic;
+/// `Script::Javanese`, shortened to keep the table below readable
+const JAVA: Script = Script::Javanese;
+/// `Script::KayahLi`, shortened to keep the table below readable
+const KALI: Script = Script::KayahLi;
+/// `Script::Katakana`, shortened to keep the table below readable
+const KANA: Script = Script::Katakana;
+/// `Script::Kawi`, shortened to keep the table below readable
+const KAWI: Script = Script::Kawi;
+/// `Script::Kharoshthi`, shortened to keep the table below readable
+const KHAR: Script = Script::Kharoshthi;
+/// `Script::Khmer`, shortened to keep the table below readable
+const KHMR: Script = Script::Khmer;
+/// `Script::Khojki`, shortened to keep the table below readable
+const KHOJ: Script = Script::Khojki;
+/// `Script::KhitanSmallScript`, shortened to keep the table below readable
+const KITS: Script = Script::KhitanSmallScript;
+/// `Script::Kannada`, shortened to keep the table below readable
+const KNDA: Script = Script::Kannada;
+/// `Script::KiratRai`, shortened to keep the table below readable
+const KRAI: Script = Script::KiratRai;
+/// `Script::Kaithi`, shortened to keep the table below readable
+const KTHI: Script = Script::Kaithi;
+/// `Script::TaiTham`, shortened to keep the table below readable
+const LANA: Script = Script::TaiTham;
+/// `Script::Lao`, shortened to keep the table below readable
+const LAOO: Script = Script::Lao;
+/// `Script::Latin`, shortened to keep the table below readable
+const LATN: Script = Script::Latin;
+/// `Script::Lepcha`, shortened to keep the table below readable
+const LEPC: Script = Script::Lepcha;
+/// `Script::Limbu`, shortened to keep the table below readable
+const LIMB: Script = Script::Limbu;
+/// `Script::LinearA`, shortened to keep the table below readable
+const LINA: Script = Script::LinearA;
+/// `Script::LinearB`, shortened to keep the table below readable
+const LINB: Script = Script::LinearB;
+/// `Script::Lisu`, shortened to keep the table below readable
+const LISU: Script = Script::Lisu;
+/// `Script::Lycian`, shortened to keep the table below readable
+const LYCI: Script = Script::Lycian;
+/// `Script::Lydian`, shortened to keep the table below readable
+const LYDI: Script = Script::Lydian;
+/// `Script::Mahajani`, shortened to keep the table below readable
+const MAHJ: Script = Script::Mahajani;
+/// `Script::Makasar`, shortened to keep the table below readable
+const MAKA: Script = Script::Makasar;
+/// `Script::Mandaic`, shortened to keep the table below readable
+const MAND: Script = Script::Mandaic;
+/// `Script::Manichaean`, shortened to keep the table below readable
+const MANI: Script = Script::Manichaean;
+/// `Script::Marchen`, shortened to keep the table below readable
+const MARC: Script = Script::Marchen;
+/// `Script::Medefaidrin`, shortened to keep the table below readable
+const MEDF: Script = Script::Medefaidrin;
+/// `Script::MendeKikakui`, shortened to keep the table below readable
+const MEND: Script = Script::MendeKikakui;
+/// `Script::MeroiticCursive`, shortened to keep the table below readable
+const MERC: Script = Script::MeroiticCursive;
+/// `Script::MeroiticHieroglyphs`, shortened to keep the table below readable
+const MERO: Script = Script::MeroiticHieroglyphs;
+/// `Script::Malayalam`, shortened to keep the table below readable
+const MLYM: Script = Script::Malayalam;
+/// `Script::Modi`, shortened to keep the table below readable
+const MODI: Script = Script::Modi;
+/// `Script::Mongolian`, shortened to keep the table below readable
+const MONG: Script = Script::Mongolian;
+/// `Script::Mro`, shortened to keep the table below readable
+const MROO: Script = Script::Mro;
+/// `Script::MeeteiMayek`, shortened to keep the table below readable
+const MTEI: Script = Script::MeeteiMayek;
+/// `Script::Multani`, shortened to keep the table below readable
+const MULT: Script = Script::Multani;
+/// `Script::Myanmar`, shortened to keep the table below readable
+const MYMR: Script = Script::Myanmar;
+/// `Script::NagMundari`, shortened to keep the table below readable
+const NAGM: Script = Script::NagMundari;
+/// `Script::Nandinagari`, shortened to keep the table below readable
+const NAND: Script = Script::Nandinagari;
+/// `Script::OldNorthArabian`, shortened to keep the table below readable
+const NARB: Script = Script::OldNorthArabian;
+/// `Script::Nabataean`, shortened to keep the table below readable
+const NBAT: Script = Script::Nabataean;
+/// `Script::Newa`, shortened to keep the table below readable
+const NEWA: Script = Script::Newa;
+/// `Script::Nko`, shortened to keep the table below readable
+const NKOO: Script = Script::Nko;
+/// `Script::Nushu`, shortened to keep the table below readable
+const NSHU: Script = Script::Nushu;
+/// `Script::Ogham`, shortened to keep the table below readable
+const OGAM: Script = Script::Ogham;
+/// `Script::OlChiki`, shortened to keep the table below readable
+const OLCK: Script = Script::OlChiki;
+/// `Script::OlOnal`, shortened to keep the table below readable
+const ONAO: Script = Script::OlOnal;
+/// `Script::OldTurkic`, shortened to keep the table below readable
+const ORKH: Script = Script::OldTurkic;
+/// `Script::Oriya`, shortened to keep the table below readable
+const ORYA: Script = Script::Oriya;
+/// `Script::Osage`, shortened to keep the table below readable
+const OSGE: Script = Script::Osage;
+/// `Script::Osmanya`, shortened to keep the table below readable
+const OSMA: Script = Script::Osmanya;
+/// `Script::OldUyghur`, shortened to keep the table below readable
+const OUGR: Script = Script::OldUyghur;
+/// `Script::Palmyrene`, shortened to keep the table below readable
+const PALM: Script = Script::Palmyrene;
+/// `Script::PauCinHau`, shortened to keep the table below readable
+const PAUC: Script = Script::PauCinHau;
+/// `Script::OldPermic`, shortened to keep the table below readable
+const PERM: Script = Script::OldPermic;
+/// `Script::PhagsPa`, shortened to keep the table below readable
+const PHAG: Script = Script::PhagsPa;
+/// `Script::InscriptionalPahlavi`, shortened to keep the table below readable
+const PHLI: Script = Script::InscriptionalPahlavi;
+/// `Script::PsalterPahlavi`, shortened to keep the table below readable
+const PHLP: Script = Script::PsalterPahlavi;
+/// `Script::Phoenician`, shortened to keep the table below readable
+const PHNX: Script = Script::Phoenician;
+/// `Script::Miao`, shortened to keep the table below readable
+const PLRD: Script = Script::Miao;
+/// `Script::InscriptionalParthian`, shortened to keep the table below readable
+const PRTI: Script = Script::InscriptionalParthian;
+/// `Script::Rejang`, shortened to keep the table below readable
+const RJNG: Script = Script::Rejang;
+/// `Script::HanifiRohingya`, shortened to keep the table below readable
+const ROHG: Script = Script::HanifiRohingya;
+/// `Script::Runic`, shortened to keep the table below readable
+const RUNR: Script = Script::Runic;
+/// `Script::Samaritan`, shortened to keep the table below readable
+const SAMR: Script = Script::Samaritan;
+/// `Script::OldSouthArabian`, shortened to keep the table below readable
+const SARB: Script = Script::OldSouthArabian;
+/// `Script::Saurashtra`, shortened to keep the table below readable
+const SAUR: Script = Script::Saurashtra;
+/// `Script::SignWriting`, shortened to keep the table below readable
+const SGNW: Script = Script::SignWriting;
+/// `Script::Shavian`, shortened to keep the table below readable
+const SHAW: Script = Script::Shavian;
+/// `Script::Sharada`, shortened to keep the table below readable
+const SHRD: Script = Script::Sharada;
+/// `Script::Siddham`, shortened to keep the table below readable
+const SIDD: Script = Script::Siddham;
+/// `Script::Sidetic`, shortened to keep the table below readable
+const SIDT: Script = Script::Sidetic;
+/// `Script::Khudawadi`, shortened to keep the table below readable
+const SIND: Script = Script::Khudawadi;
+/// `Script::Sinhala`, shortened to keep the table below readable
+const SINH: Script = Script::Sinhala;
+/// `Script::Sogdian`, shortened to keep the table below readable
+const SOGD: Script = Script::Sogdian;
+/// `Script::OldSogdian`, shortened to keep the table below readable
+const SOGO: Script = Script::OldSogdian;
+/// `Script::SoraSompeng`, shortened to keep the table below readable
+const SORA: Script = Script::SoraSompeng;
+/// `Script::Soyombo`, shortened to keep the table below readable
+const SOYO: Script = Script::Soyombo;
+/// `Script::Sundanese`, shortened to keep the table below readable
+const SUND: Script = Script::Sundanese;
+/// `Script::Sunuwar`, shortened to keep the table below readable
+const SUNU: Script = Script::Sunuwar;
+/// `Script::SylotiNagri`, shortened to keep the table below readable
+const SYLO: Script = Script::SylotiNagri;
+/// `Script::Syriac`, shortened to keep the table below readable
+const SYRC: Script = Script::Syriac;
+/// `Script::Tagbanwa`, shortened to keep the table below readable
+const TAGB: Script = Script::Tagbanwa;
+/// `Script::Takri`, shortened to keep the table below readable
+const TAKR: Script = Script::Takri;
+/// `Script::TaiLe`, shortened to keep the table below readable
+const TALE: Script = Script::TaiLe;
+/// `Script::NewTaiLue`, shortened to keep the table below readable
+const TALU: Script = Script::NewTaiLue;
+/// `Script::Tamil`, shortened to keep the table below readable
+const TAML: Script = Script::Tamil;
+/// `Script::Tangut`, shortened to keep the table below readable
+const TANG: Script = Script::Tangut;
+/// `Script::TaiViet`, shortened to keep the table below readable
+const TAVT: Script = Script::TaiViet;
+/// `Script::TaiYo`, shortened to keep the table below readable
+const TAYO: Script = Script::TaiYo;
+/// `Script::Telugu`, shortened to keep the table below readable
+const TELU: Script = Script::Telugu;
+/// `Script::Tifinagh`, shortened to keep the table below readable
+const TFNG: Script = Script::Tifinagh;
+/// `Script::Tagalog`, shortened to keep the table below readable
+const TGLG: Script = Script::Tagalog;
+/// `Script::Thaana`, shortened to keep the table below readable
+const THAA: Script = Script::Thaana;
+/// `Script::Thai`, shortened to keep the table below readable
+const THAI: Script = Script::Thai;
+/// `Script::Tibetan`, shortened to keep the table below readable
+const TIBT: Script = Script::Tibetan;
+/// `Script::Tirhuta`, shortened to keep the table below readable
+const TIRH: Script = Script::Tirhuta;
+/// `Script::Tangsa`, shortened to keep the table below readable
+const TNSA: Script = Script::Tangsa;
+/// `Script::Todhri`, shortened to keep the table below readable
+const TODR: Script = Script::Todhri;
+/// `Script::TolongSiki`, shortened to keep the table below readable
+const TOLS: Script = Script::TolongSiki;
+/// `Script::Toto`, shortened to keep the table below readable
+const TOTO: Script = Script::Toto;
+/// `Script::TuluTigalari`, shortened to keep the table below readable
+const TUTG: Script = Script::TuluTigalari;
+/// `Script::Ugaritic`, shortened to keep the table below readable
+const UGAR: Script = Script::Ugaritic;
+/// `Script::Vai`, shortened to keep the table below readable
+const VAII: Script = Script::Vai;
+/// `Script::Vithkuqi`, shortened to keep the table below readable
+const VITH: Script = Script::Vithkuqi;
+/// `Script::WarangCiti`, shortened to keep the table below readable
+const WARA: Script = Script::WarangCiti;
+/// `Script::Wancho`, shortened to keep the table below readable
+const WCHO: Script = Script::Wancho;
+/// `Script::OldPersian`, shortened to keep the table below readable
+const XPEO: Script = Script::OldPersian;
+/// `Script::Cuneiform`, shortened to keep the table below readable
+const XSUX: Script = Script::Cuneiform;
+/// `Script::Yezidi`, shortened to keep the table below readable
+const YEZI: Script = Script::Yezidi;
+/// `Script::Yi`, shortened to keep the table below readable
+const YIII: Script = Script::Yi;
+/// `Script::ZanabazarSquare`, shortened to keep the table below readable
+const ZANB: Script = Script::ZanabazarSquare;
+/// `Script::Inherited`, shortened to keep the table below readable
+const ZINH: Script = Script::Inherited;
+/// `Script::Common`, shortened to keep the table below readable
+const ZYYY: Script = Script::Common;
+/// `Script::Unknown`, shortened to keep the table below readable
+const ZZZZ: Script = Script::Unknown;
+
+/// The `Script` of every codepoint
+pub(crate) static SCRIPT: Utf8Trie<[Script; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 4, 4, 4, 4, 4, 5, 6, 7, 8, 9, 10, 11, 11, 12, 11, 13, 14,
+        15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 7, 7, 8, 9, 10, 32,
+        33, 33, 35, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 6, 7, 8, 9, 10, 11, 11, 12, 11, 13, 14, 15, 16, 17, 18,
+            19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40,
+            41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56,
+        ],
+        [
+            57, 57, 58, 59, 60, 60, 60, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 69, 69, 69, 69, 69,
+            69, 69, 69, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87,
+            88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 7, 4, 4, 4, 4, 99, 100, 101, 102,
+        ],
+        [
+            103, 104, 105, 106, 107, 108, 109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 111, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 112, 112, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0,
+            114, 115, 116, 117, 118, 119, 120, 121, 0, 122, 123, 124, 125, 125, 125, 126,
+        ],
+        [
+            127, 128, 129, 130, 131, 60, 132, 133, 134, 135, 0, 136, 137, 138, 0, 0, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+        ],
+        [
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 0, 125, 125, 125, 125, 125, 125, 125, 125,
+        ],
+        [
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+        ],
+        [
+            139, 139, 139, 139, 139, 139, 139, 139, 139, 139, 139, 139, 139, 139, 139, 139, 139,
+            139, 140, 141, 142, 142, 142, 142, 143, 11, 144, 145, 146, 4, 147, 148, 149, 150, 151,
+            152, 153, 154, 155, 156, 157, 158, 159, 160, 161, 162, 163, 164, 60, 60, 60, 60, 60, 60,
+            60, 60, 60, 60, 60, 60, 60, 60, 60, 60,
+        ],
+        [
+            60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60,
+            60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60,
+            60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60,
+        ],
+        [
+            60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60, 60,
+            60, 60, 60, 60, 60, 60, 60, 60, 165, 166, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167,
+        ],
+        [
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+        ],
+        [
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 125, 125, 125, 125, 125, 168, 125, 169, 170, 171, 19, 19, 19, 19, 19, 19, 172,
+            19, 19, 173, 174, 175, 19, 176, 177, 178, 179, 180,
+        ],
+        [
+            181, 182, 183, 184, 185, 186, 187, 188, 167, 167, 189, 190, 191, 192, 193, 194, 195,
+            196, 197, 198, 199, 200, 201, 202, 203, 203, 203, 203, 204, 205, 206, 167, 207, 208,
+            209, 210, 211, 212, 213, 214, 215, 216, 217, 218, 219, 220, 221, 167, 222, 223, 224,
+            225, 226, 227, 228, 167, 167, 229, 230, 231, 232, 233, 234, 235,
+        ],
+        [
+            236, 237, 238, 239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252,
+            253, 254, 255, 167, 167, 256, 257, 258, 259, 260, 261, 262, 263, 167, 167, 264, 167,
+            265, 266, 267, 268, 269, 270, 271, 272, 273, 274, 275, 276, 167, 277, 278, 279, 280,
+            167, 281, 282, 283, 284, 167, 167, 167, 285, 286, 287, 288, 289,
+        ],
+        [
+            290, 290, 290, 290, 290, 290, 290, 290, 290, 290, 290, 290, 290, 290, 291, 167, 290,
+            292, 290, 290, 290, 293, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 294, 295,
+        ],
+        [
+            296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296,
+            297, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296,
+            296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296,
+            296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296,
+        ],
+        [
+            296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 296, 298, 299,
+            299, 299, 299, 299, 299, 299, 299, 299, 300, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+        ],
+        [
+            167, 167, 167, 167, 301, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 302, 302,
+            302, 302, 302, 302, 302, 302, 303, 304, 305, 306, 307, 308, 309, 167, 167, 167, 167,
+            167, 167, 310, 167, 167, 167, 311, 312, 313, 314, 315, 316, 317,
+        ],
+        [
+            318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318,
+            318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318,
+            318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318,
+            318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318,
+        ],
+        [
+            318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318,
+            318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 318,
+            318, 318, 318, 318, 318, 318, 318, 318, 318, 318, 319, 319, 319, 319, 319, 319, 319,
+            320, 321, 167, 318, 322, 167, 167, 167, 167, 167, 167, 167, 167,
+        ],
+        [
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 323,
+        ],
+        [
+            324, 325, 325, 325, 326, 327, 328, 328, 328, 328, 328, 329, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 330, 331, 332,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+        ],
+        [
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 0, 0, 0, 333, 0,
+            0, 0, 0, 0, 0, 334, 335, 336, 337, 0, 338,
+        ],
+        [
+            0, 0, 0, 339, 340, 341, 342, 343, 186, 344, 167, 345, 0, 346, 167, 167, 0, 347, 348,
+            349, 350, 351, 0, 0, 0, 0, 352, 0, 0, 0, 0, 353, 354, 354, 354, 354, 354, 354, 354, 354,
+            354, 354, 355, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 356, 167, 167, 167,
+        ],
+        [
+            357, 358, 359, 167, 360, 361, 167, 167, 167, 167, 362, 363, 167, 167, 167, 167, 167,
+            167, 167, 364, 167, 167, 167, 365, 167, 167, 167, 366, 167, 167, 167, 367, 368, 368,
+            368, 369, 370, 371, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 372, 373,
+            167, 374, 167, 167, 167, 375, 376, 377, 378, 167, 167, 167, 167,
+        ],
+        [
+            379, 0, 380, 381, 0, 0, 382, 383, 384, 385, 167, 167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 386, 0, 0, 0, 387, 388, 389, 390, 391, 0, 0, 0, 0, 0, 392, 393, 394, 0, 0,
+            395, 396, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167,
+        ],
+        [
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 397, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+        ],
+        [
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 398, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+        ],
+        [
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 168, 125, 125, 125, 125, 125,
+        ],
+        [
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 399, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 400, 167, 167, 167, 167, 167, 167,
+        ],
+        [
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 125, 125,
+            125, 125, 125, 125, 125, 125, 400, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+        ],
+        [
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 401, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+        ],
+        [
+            125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125, 125,
+            402, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+        ],
+        [
+            0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 7, 7, 8, 9, 10, 11, 12, 13, 14, 15, 9, 16, 17, 18, 9,
+            19, 20, 21, 22, 23, 24, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 25, 26, 27, 5, 28, 29, 5, 30, 5,
+            31, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+        ],
+        [
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 9, 9,
+        ],
+        [
+            403, 0, 167, 167, 7, 7, 7, 404, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+            167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167,
+        ],
+        [
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 34, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9,
+            9, 9, 9, 9, 9, 9,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+        ],
+    ],
+    leaves: &[
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            LATN, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, LATN, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, ZYYY, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, ZYYY,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+        ],
+        [
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+        ],
+        [
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, LATN, LATN, LATN, LATN, LATN, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            BOPO, BOPO, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+        ],
+        [
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, GREK, GREK, GREK, GREK, ZYYY, GREK, GREK, GREK,
+            ZZZZ, ZZZZ, GREK, GREK, GREK, GREK, ZYYY, GREK,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, GREK, ZYYY, GREK, ZYYY, GREK, GREK, GREK, ZZZZ, GREK, ZZZZ,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, ZZZZ, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+        ],
+        [
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT,
+            COPT, COPT, COPT, COPT, COPT, COPT, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+        ],
+        [
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+        ],
+        [
+            CYRL, CYRL, CYRL, CYRL, CYRL, ZINH, ZINH, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+        ],
+        [
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, ZZZZ, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN,
+            ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN,
+        ],
+        [
+            ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN,
+            ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ZZZZ, ZZZZ, ARMN, ARMN, ARMN,
+            ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN,
+            ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN,
+            ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN,
+        ],
+        [
+            ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ARMN, ZZZZ, ZZZZ, ARMN,
+            ARMN, ARMN, ZZZZ, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR,
+            HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR,
+            HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR,
+            HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR,
+        ],
+        [
+            HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR,
+            HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR,
+            HEBR, ZZZZ, ZZZZ, ZZZZ, ZZZZ, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ARAB, ARAB, ARAB, ARAB, ARAB, ZYYY, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ZYYY, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ZYYY,
+            ARAB, ARAB, ARAB, ZYYY, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+        ],
+        [
+            ZYYY, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ZINH, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+        ],
+        [
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+        ],
+        [
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ZYYY, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+        ],
+        [
+            SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC,
+            ZZZZ, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC,
+            SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC,
+            SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC,
+            SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC,
+        ],
+        [
+            SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, ZZZZ, ZZZZ, SYRC,
+            SYRC, SYRC, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+        ],
+        [
+            THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA,
+            THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA,
+            THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA,
+            THAA, THAA, THAA, THAA, THAA, THAA, THAA, THAA, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO,
+            NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO,
+            NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO,
+            NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO, NKOO,
+            NKOO, NKOO, NKOO, ZZZZ, ZZZZ, NKOO, NKOO, NKOO,
+        ],
+        [
+            SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR,
+            SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR,
+            SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR,
+            SAMR, SAMR, SAMR, SAMR, ZZZZ, ZZZZ, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR,
+            SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, SAMR, ZZZZ,
+        ],
+        [
+            MAND, MAND, MAND, MAND, MAND, MAND, MAND, MAND, MAND, MAND, MAND, MAND, MAND, MAND,
+            MAND, MAND, MAND, MAND, MAND, MAND, MAND, MAND, MAND, MAND, MAND, MAND, MAND, MAND,
+            ZZZZ, ZZZZ, MAND, ZZZZ, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC, SYRC,
+            SYRC, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+        ],
+        [
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+        ],
+        [
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ZYYY, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+        ],
+        [
+            DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA,
+            DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA,
+            DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA,
+            DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA,
+            DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA,
+        ],
+        [
+            DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA,
+            DEVA, DEVA, DEVA, ZINH, ZINH, ZINH, ZINH, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA,
+            DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, ZYYY, ZYYY, DEVA, DEVA, DEVA, DEVA,
+            DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA,
+            DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA,
+        ],
+        [
+            BENG, BENG, BENG, BENG, ZZZZ, BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG, ZZZZ,
+            ZZZZ, BENG, BENG, ZZZZ, ZZZZ, BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG,
+            BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG, ZZZZ,
+            BENG, BENG, BENG, BENG, BENG, BENG, BENG, ZZZZ, BENG, ZZZZ, ZZZZ, ZZZZ, BENG, BENG,
+            BENG, BENG, ZZZZ, ZZZZ, BENG, BENG, BENG, BENG,
+        ],
+        [
+            BENG, BENG, BENG, BENG, BENG, ZZZZ, ZZZZ, BENG, BENG, ZZZZ, ZZZZ, BENG, BENG, BENG,
+            BENG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, BENG, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            BENG, BENG, ZZZZ, BENG, BENG, BENG, BENG, BENG, ZZZZ, ZZZZ, BENG, BENG, BENG, BENG,
+            BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG, BENG,
+            BENG, BENG, BENG, BENG, BENG, BENG, BENG, ZZZZ,
+        ],
+        [
+            ZZZZ, GURU, GURU, GURU, ZZZZ, GURU, GURU, GURU, GURU, GURU, GURU, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, GURU, GURU, ZZZZ, ZZZZ, GURU, GURU, GURU, GURU, GURU, GURU, GURU, GURU, GURU,
+            GURU, GURU, GURU, GURU, GURU, GURU, GURU, GURU, GURU, GURU, GURU, GURU, GURU, ZZZZ,
+            GURU, GURU, GURU, GURU, GURU, GURU, GURU, ZZZZ, GURU, GURU, ZZZZ, GURU, GURU, ZZZZ,
+            GURU, GURU, ZZZZ, ZZZZ, GURU, ZZZZ, GURU, GURU,
+        ],
+        [
+            GURU, GURU, GURU, ZZZZ, ZZZZ, ZZZZ, ZZZZ, GURU, GURU, ZZZZ, ZZZZ, GURU, GURU, GURU,
+            ZZZZ, ZZZZ, ZZZZ, GURU, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, GURU, GURU, GURU,
+            GURU, ZZZZ, GURU, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, GURU, GURU, GURU, GURU,
+            GURU, GURU, GURU, GURU, GURU, GURU, GURU, GURU, GURU, GURU, GURU, GURU, GURU, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, GUJR, GUJR, GUJR, ZZZZ, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR,
+            ZZZZ, GUJR, GUJR, GUJR, ZZZZ, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR,
+            GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, ZZZZ,
+            GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, ZZZZ, GUJR, GUJR, ZZZZ, GUJR, GUJR, GUJR,
+            GUJR, GUJR, ZZZZ, ZZZZ, GUJR, GUJR, GUJR, GUJR,
+        ],
+        [
+            GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, ZZZZ, GUJR, GUJR, GUJR, ZZZZ, GUJR, GUJR, GUJR,
+            ZZZZ, ZZZZ, GUJR, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, GUJR, GUJR, GUJR, GUJR, ZZZZ, ZZZZ, GUJR, GUJR, GUJR, GUJR,
+            GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR, GUJR,
+        ],
+        [
+            ZZZZ, ORYA, ORYA, ORYA, ZZZZ, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ZZZZ,
+            ZZZZ, ORYA, ORYA, ZZZZ, ZZZZ, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA,
+            ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ZZZZ,
+            ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ZZZZ, ORYA, ORYA, ZZZZ, ORYA, ORYA, ORYA,
+            ORYA, ORYA, ZZZZ, ZZZZ, ORYA, ORYA, ORYA, ORYA,
+        ],
+        [
+            ORYA, ORYA, ORYA, ORYA, ORYA, ZZZZ, ZZZZ, ORYA, ORYA, ZZZZ, ZZZZ, ORYA, ORYA, ORYA,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ORYA, ORYA, ORYA, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ORYA, ORYA, ZZZZ, ORYA, ORYA, ORYA, ORYA, ORYA, ZZZZ, ZZZZ, ORYA, ORYA, ORYA, ORYA,
+            ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA, ORYA,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, TAML, TAML, ZZZZ, TAML, TAML, TAML, TAML, TAML, TAML, ZZZZ, ZZZZ, ZZZZ,
+            TAML, TAML, TAML, ZZZZ, TAML, TAML, TAML, TAML, ZZZZ, ZZZZ, ZZZZ, TAML, TAML, ZZZZ,
+            TAML, ZZZZ, TAML, TAML, ZZZZ, ZZZZ, ZZZZ, TAML, TAML, ZZZZ, ZZZZ, ZZZZ, TAML, TAML,
+            TAML, ZZZZ, ZZZZ, ZZZZ, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML,
+            TAML, TAML, ZZZZ, ZZZZ, ZZZZ, ZZZZ, TAML, TAML,
+        ],
+        [
+            TAML, TAML, TAML, ZZZZ, ZZZZ, ZZZZ, TAML, TAML, TAML, ZZZZ, TAML, TAML, TAML, TAML,
+            ZZZZ, ZZZZ, TAML, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, TAML, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, TAML, TAML, TAML, TAML,
+            TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML,
+            TAML, TAML, TAML, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, ZZZZ,
+            TELU, TELU, TELU, ZZZZ, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU,
+            TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, ZZZZ,
+            TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU,
+            TELU, TELU, ZZZZ, ZZZZ, TELU, TELU, TELU, TELU,
+        ],
+        [
+            TELU, TELU, TELU, TELU, TELU, ZZZZ, TELU, TELU, TELU, ZZZZ, TELU, TELU, TELU, TELU,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, TELU, TELU, ZZZZ, TELU, TELU, TELU, ZZZZ,
+            TELU, TELU, ZZZZ, ZZZZ, TELU, TELU, TELU, TELU, ZZZZ, ZZZZ, TELU, TELU, TELU, TELU,
+            TELU, TELU, TELU, TELU, TELU, TELU, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, TELU,
+            TELU, TELU, TELU, TELU, TELU, TELU, TELU, TELU,
+        ],
+        [
+            KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, ZZZZ,
+            KNDA, KNDA, KNDA, ZZZZ, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA,
+            KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, ZZZZ,
+            KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, ZZZZ, KNDA, KNDA, KNDA,
+            KNDA, KNDA, ZZZZ, ZZZZ, KNDA, KNDA, KNDA, KNDA,
+        ],
+        [
+            KNDA, KNDA, KNDA, KNDA, KNDA, ZZZZ, KNDA, KNDA, KNDA, ZZZZ, KNDA, KNDA, KNDA, KNDA,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, KNDA, KNDA, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            KNDA, KNDA, KNDA, ZZZZ, KNDA, KNDA, KNDA, KNDA, ZZZZ, ZZZZ, KNDA, KNDA, KNDA, KNDA,
+            KNDA, KNDA, KNDA, KNDA, KNDA, KNDA, ZZZZ, KNDA, KNDA, KNDA, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, ZZZZ,
+            MLYM, MLYM, MLYM, ZZZZ, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM,
+            MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM,
+            MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM,
+            MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM,
+        ],
+        [
+            MLYM, MLYM, MLYM, MLYM, MLYM, ZZZZ, MLYM, MLYM, MLYM, ZZZZ, MLYM, MLYM, MLYM, MLYM,
+            MLYM, MLYM, ZZZZ, ZZZZ, ZZZZ, ZZZZ, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM,
+            MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, ZZZZ, ZZZZ, MLYM, MLYM, MLYM, MLYM,
+            MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM,
+            MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM, MLYM,
+        ],
+        [
+            ZZZZ, SINH, SINH, SINH, ZZZZ, SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH,
+            SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, ZZZZ, ZZZZ, ZZZZ, SINH, SINH,
+            SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH,
+            SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, ZZZZ, SINH, SINH, SINH, SINH, SINH,
+            SINH, SINH, SINH, SINH, ZZZZ, SINH, ZZZZ, ZZZZ,
+        ],
+        [
+            SINH, SINH, SINH, SINH, SINH, SINH, SINH, ZZZZ, ZZZZ, ZZZZ, SINH, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, SINH, SINH, SINH, SINH, SINH, SINH, ZZZZ, SINH, ZZZZ, SINH, SINH, SINH, SINH,
+            SINH, SINH, SINH, SINH, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, SINH, SINH, SINH, SINH,
+            SINH, SINH, SINH, SINH, SINH, SINH, ZZZZ, ZZZZ, SINH, SINH, SINH, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI,
+            THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI,
+            THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI,
+            THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI,
+            THAI, THAI, THAI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY,
+        ],
+        [
+            THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI,
+            THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI, THAI,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, LAOO, LAOO, ZZZZ, LAOO, ZZZZ, LAOO, LAOO, LAOO, LAOO, LAOO, ZZZZ, LAOO, LAOO,
+            LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO,
+            LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, ZZZZ, LAOO, ZZZZ, LAOO, LAOO, LAOO,
+            LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO,
+            LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, ZZZZ, ZZZZ,
+        ],
+        [
+            LAOO, LAOO, LAOO, LAOO, LAOO, ZZZZ, LAOO, ZZZZ, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO,
+            LAOO, ZZZZ, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, LAOO, ZZZZ, ZZZZ,
+            LAOO, LAOO, LAOO, LAOO, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT,
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT,
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT,
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT,
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT,
+        ],
+        [
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, ZZZZ, TIBT, TIBT, TIBT, TIBT, TIBT,
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT,
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT,
+            TIBT, TIBT, TIBT, ZZZZ, ZZZZ, ZZZZ, ZZZZ, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT,
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT,
+        ],
+        [
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT,
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, ZZZZ, TIBT, TIBT, TIBT,
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT,
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT,
+            TIBT, TIBT, TIBT, TIBT, TIBT, ZZZZ, TIBT, TIBT,
+        ],
+        [
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, ZZZZ,
+            TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, TIBT, ZYYY, ZYYY, ZYYY, ZYYY, TIBT, TIBT, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR,
+            MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR,
+            MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR,
+            MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR,
+            MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR,
+        ],
+        [
+            MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR,
+            MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR,
+            MYMR, MYMR, MYMR, MYMR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR,
+            GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR,
+            GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR,
+        ],
+        [
+            GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, ZZZZ, GEOR, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, GEOR,
+            ZZZZ, ZZZZ, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR,
+            GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR,
+            GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR,
+            GEOR, GEOR, GEOR, ZYYY, GEOR, GEOR, GEOR, GEOR,
+        ],
+        [
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+        ],
+        [
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+        ],
+        [
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ETHI, ETHI, ETHI, ETHI,
+            ZZZZ, ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ETHI, ZZZZ, ETHI, ETHI,
+            ETHI, ETHI, ZZZZ, ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+        ],
+        [
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ETHI, ETHI, ETHI, ETHI,
+            ZZZZ, ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ETHI, ETHI, ETHI, ETHI, ZZZZ, ZZZZ,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ,
+        ],
+        [
+            ETHI, ZZZZ, ETHI, ETHI, ETHI, ETHI, ZZZZ, ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+        ],
+        [
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ZZZZ, ETHI, ETHI, ETHI, ETHI, ZZZZ, ZZZZ, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+        ],
+        [
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ,
+            ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER,
+            CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER,
+            CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER,
+        ],
+        [
+            CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER,
+            CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER,
+            CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER,
+            CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, ZZZZ, ZZZZ,
+            CHER, CHER, CHER, CHER, CHER, CHER, ZZZZ, ZZZZ,
+        ],
+        [
+            CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS,
+            CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS,
+            CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS,
+            CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS,
+            CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS,
+        ],
+        [
+            OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM,
+            OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM, OGAM,
+            OGAM, ZZZZ, ZZZZ, ZZZZ, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR,
+            RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR,
+            RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR,
+        ],
+        [
+            RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR,
+            RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR,
+            RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR,
+            RUNR, ZYYY, ZYYY, ZYYY, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR, RUNR,
+            RUNR, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            TGLG, TGLG, TGLG, TGLG, TGLG, TGLG, TGLG, TGLG, TGLG, TGLG, TGLG, TGLG, TGLG, TGLG,
+            TGLG, TGLG, TGLG, TGLG, TGLG, TGLG, TGLG, TGLG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, TGLG, HANO, HANO, HANO, HANO, HANO, HANO, HANO, HANO, HANO, HANO,
+            HANO, HANO, HANO, HANO, HANO, HANO, HANO, HANO, HANO, HANO, HANO, ZYYY, ZYYY, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            BUHD, BUHD, BUHD, BUHD, BUHD, BUHD, BUHD, BUHD, BUHD, BUHD, BUHD, BUHD, BUHD, BUHD,
+            BUHD, BUHD, BUHD, BUHD, BUHD, BUHD, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, TAGB, TAGB, TAGB, TAGB, TAGB, TAGB, TAGB, TAGB, TAGB, TAGB,
+            TAGB, TAGB, TAGB, ZZZZ, TAGB, TAGB, TAGB, ZZZZ, TAGB, TAGB, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR,
+            KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR,
+            KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR,
+            KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR,
+            KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR,
+        ],
+        [
+            KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR,
+            KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR,
+            KHMR, KHMR, ZZZZ, ZZZZ, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR,
+            KHMR, KHMR, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            MONG, MONG, ZYYY, ZYYY, MONG, ZYYY, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG,
+            MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG,
+            MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG,
+            MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG,
+        ],
+        [
+            MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG,
+            MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG,
+            MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG,
+            MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG,
+            MONG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG,
+            MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG,
+            MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG,
+            MONG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS,
+            CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS,
+        ],
+        [
+            CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS,
+            CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS,
+            CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS,
+            CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB,
+            LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB,
+            LIMB, LIMB, LIMB, ZZZZ, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB,
+            LIMB, LIMB, ZZZZ, ZZZZ, ZZZZ, ZZZZ, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB,
+            LIMB, LIMB, LIMB, LIMB, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            LIMB, ZZZZ, ZZZZ, ZZZZ, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB, LIMB,
+            LIMB, LIMB, TALE, TALE, TALE, TALE, TALE, TALE, TALE, TALE, TALE, TALE, TALE, TALE,
+            TALE, TALE, TALE, TALE, TALE, TALE, TALE, TALE, TALE, TALE, TALE, TALE, TALE, TALE,
+            TALE, TALE, TALE, TALE, ZZZZ, ZZZZ, TALE, TALE, TALE, TALE, TALE, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU,
+            TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU,
+            TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU,
+            TALU, TALU, ZZZZ, ZZZZ, ZZZZ, ZZZZ, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU,
+            TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU,
+        ],
+        [
+            TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, TALU, ZZZZ,
+            ZZZZ, ZZZZ, TALU, TALU, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR,
+            KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR,
+            KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR, KHMR,
+        ],
+        [
+            BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI,
+            BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI, BUGI,
+            ZZZZ, ZZZZ, BUGI, BUGI, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA,
+            LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA,
+            LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA,
+        ],
+        [
+            LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA,
+            LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA,
+            LANA, LANA, LANA, ZZZZ, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA,
+            LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA,
+            LANA, LANA, LANA, LANA, LANA, ZZZZ, ZZZZ, LANA,
+        ],
+        [
+            LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA, LANA,
+            LANA, LANA, LANA, LANA, ZZZZ, ZZZZ, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+        ],
+        [
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZZZZ, ZZZZ, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI,
+            BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI,
+            BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI,
+            BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI,
+            BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI,
+        ],
+        [
+            BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, ZZZZ,
+            BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI,
+            BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI,
+            BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI,
+            BALI, BALI, BALI, BALI, BALI, BALI, BALI, BALI,
+        ],
+        [
+            SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND,
+            SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND,
+            SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND,
+            SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND,
+            SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND,
+        ],
+        [
+            BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK,
+            BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK,
+            BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK,
+            BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, BATK, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, BATK, BATK, BATK, BATK,
+        ],
+        [
+            LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC,
+            LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC,
+            LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC,
+            LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC,
+            ZZZZ, ZZZZ, ZZZZ, LEPC, LEPC, LEPC, LEPC, LEPC,
+        ],
+        [
+            LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, LEPC, ZZZZ, ZZZZ, ZZZZ, LEPC,
+            LEPC, LEPC, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK,
+            OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK,
+            OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK,
+            OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK, OLCK,
+        ],
+        [
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR,
+            GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR,
+            GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR,
+            GEOR, GEOR, GEOR, ZZZZ, ZZZZ, GEOR, GEOR, GEOR,
+        ],
+        [
+            SUND, SUND, SUND, SUND, SUND, SUND, SUND, SUND, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZINH, ZINH, ZINH, ZYYY, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZYYY, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZINH, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZINH, ZYYY, ZYYY, ZYYY,
+            ZINH, ZINH, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, GREK, GREK, GREK, GREK,
+            GREK, CYRL, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+        ],
+        [
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, GREK, GREK, GREK, GREK, GREK, LATN, LATN, LATN, LATN, GREK, GREK, GREK, GREK,
+            GREK, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            CYRL, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+        ],
+        [
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, GREK,
+        ],
+        [
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, ZZZZ, ZZZZ, GREK, GREK, GREK, GREK,
+            GREK, GREK, ZZZZ, ZZZZ, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+        ],
+        [
+            GREK, GREK, GREK, GREK, GREK, GREK, ZZZZ, ZZZZ, GREK, GREK, GREK, GREK, GREK, GREK,
+            ZZZZ, ZZZZ, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, ZZZZ, GREK, ZZZZ, GREK,
+            ZZZZ, GREK, ZZZZ, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, ZZZZ, ZZZZ,
+        ],
+        [
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, ZZZZ, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+        ],
+        [
+            GREK, GREK, GREK, GREK, GREK, ZZZZ, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, ZZZZ, ZZZZ, GREK, GREK, GREK, GREK, GREK, GREK,
+            ZZZZ, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, ZZZZ, ZZZZ, GREK, GREK, GREK, ZZZZ, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZINH, ZINH,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, LATN, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, LATN,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZZZZ, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, GREK, ZYYY, ZYYY, ZYYY,
+            LATN, LATN, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, LATN, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            LATN, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+        ],
+        [
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI,
+            BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI,
+            BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI,
+            BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI,
+            BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI, BRAI,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG,
+            GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG,
+            GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG,
+            GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG,
+            GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG,
+        ],
+        [
+            GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG,
+            GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG,
+            GLAG, GLAG, GLAG, GLAG, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+        ],
+        [
+            COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT,
+            COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT,
+            COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT,
+            COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT,
+            COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT,
+        ],
+        [
+            COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT,
+            COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT,
+            COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT,
+            COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, COPT, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, COPT, COPT, COPT, COPT, COPT, COPT, COPT,
+        ],
+        [
+            GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR,
+            GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR,
+            GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, GEOR, ZZZZ, GEOR, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, GEOR, ZZZZ, ZZZZ, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG,
+            TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG,
+        ],
+        [
+            TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG,
+            TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG,
+            TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, TFNG, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, TFNG, TFNG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, TFNG,
+        ],
+        [
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ,
+        ],
+        [
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ZZZZ, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, ZZZZ, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+        ],
+        [
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+        ],
+        [
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, HANI, ZYYY, HANI, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            ZINH, ZINH, ZINH, ZINH, HANG, HANG, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            HANI, HANI, HANI, HANI, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZZZZ, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+        ],
+        [
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, ZZZZ, ZZZZ, ZINH, ZINH, ZYYY,
+            ZYYY, HIRA, HIRA, HIRA, ZYYY, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+        ],
+        [
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, ZYYY, ZYYY, KANA, KANA, KANA,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO,
+            BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO,
+            BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO,
+            BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, ZZZZ, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+        ],
+        [
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO,
+            BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO,
+            BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO, BOPO,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+        ],
+        [
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, ZYYY,
+        ],
+        [
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+        ],
+        [
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII,
+            YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII,
+            YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII,
+            YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII,
+            YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII,
+        ],
+        [
+            YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, ZZZZ,
+            ZZZZ, ZZZZ, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII,
+            YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII,
+            YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII,
+            YIII, YIII, YIII, YIII, YIII, YIII, YIII, YIII,
+        ],
+        [
+            YIII, YIII, YIII, YIII, YIII, YIII, YIII, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU,
+            LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU,
+            LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU,
+            LISU, LISU, LISU, LISU, LISU, LISU, LISU, LISU,
+        ],
+        [
+            VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII,
+            VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII,
+            VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII,
+            VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII,
+            VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII,
+        ],
+        [
+            VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII,
+            VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII,
+            VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII, VAII,
+            VAII, VAII, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+        ],
+        [
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+        ],
+        [
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, ZYYY, ZYYY, ZYYY, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+        ],
+        [
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+        ],
+        [
+            SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO,
+            SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO,
+            SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO, SYLO,
+            SYLO, SYLO, SYLO, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG,
+            PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG,
+            PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG,
+            PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG, PHAG,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR,
+            SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR,
+            SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR,
+            SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR,
+            SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR,
+        ],
+        [
+            SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, SAUR, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA,
+            DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA,
+            DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA,
+        ],
+        [
+            KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI,
+            KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI,
+            KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI, KALI,
+            KALI, KALI, KALI, KALI, ZYYY, KALI, RJNG, RJNG, RJNG, RJNG, RJNG, RJNG, RJNG, RJNG,
+            RJNG, RJNG, RJNG, RJNG, RJNG, RJNG, RJNG, RJNG,
+        ],
+        [
+            RJNG, RJNG, RJNG, RJNG, RJNG, RJNG, RJNG, RJNG, RJNG, RJNG, RJNG, RJNG, RJNG, RJNG,
+            RJNG, RJNG, RJNG, RJNG, RJNG, RJNG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, RJNG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA,
+            JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA,
+            JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA,
+            JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA,
+            JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA,
+        ],
+        [
+            JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA,
+            ZZZZ, ZYYY, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, JAVA, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, JAVA, JAVA, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR,
+            MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR,
+            MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, ZZZZ,
+        ],
+        [
+            CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM,
+            CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM,
+            CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM,
+            CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM,
+            ZZZZ, ZZZZ, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, CHAM, ZZZZ, ZZZZ,
+            CHAM, CHAM, CHAM, CHAM, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR,
+            MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR,
+            MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR,
+        ],
+        [
+            TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT,
+            TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT,
+            TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT,
+            TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT,
+            TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT, TAVT,
+        ],
+        [
+            TAVT, TAVT, TAVT, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, TAVT,
+            TAVT, TAVT, TAVT, TAVT, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI,
+            MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ZZZZ, ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+        ],
+        [
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, ZYYY,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, GREK, LATN, LATN, LATN, LATN,
+            ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER,
+            CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER,
+        ],
+        [
+            CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER,
+            CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER,
+            CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER,
+            CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER,
+            CHER, CHER, CHER, CHER, CHER, CHER, CHER, CHER,
+        ],
+        [
+            MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI,
+            MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI,
+            MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI,
+            MTEI, MTEI, MTEI, MTEI, ZZZZ, ZZZZ, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI, MTEI,
+            MTEI, MTEI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+        ],
+        [
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, ZZZZ, ZZZZ, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+        ],
+        [
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ARMN, ARMN, ARMN, ARMN, ARMN, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR,
+            HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, ZZZZ,
+            HEBR, HEBR, HEBR, HEBR, HEBR, ZZZZ, HEBR, ZZZZ,
+        ],
+        [
+            HEBR, HEBR, ZZZZ, HEBR, HEBR, ZZZZ, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR, HEBR,
+            HEBR, HEBR, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+        ],
+        [
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ZYYY, ZYYY,
+        ],
+        [
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+        ],
+        [
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, CYRL, CYRL, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ARAB, ARAB, ARAB, ARAB, ARAB, ZZZZ, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+        ],
+        [
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ZZZZ, ZZZZ, ZYYY,
+        ],
+        [
+            ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, ZYYY, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+        ],
+        [
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA, KANA,
+            KANA, KANA, ZYYY, ZYYY, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG, HANG,
+            HANG, HANG, HANG, HANG, HANG, HANG, HANG, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, HANG, HANG, HANG, HANG, HANG, HANG, ZZZZ, ZZZZ, HANG, HANG, HANG, HANG,
+            HANG, HANG, ZZZZ, ZZZZ, HANG, HANG, HANG, HANG, HANG, HANG, ZZZZ, ZZZZ, HANG, HANG,
+            HANG, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ,
+        ],
+        [
+            LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, ZZZZ, LINB,
+            LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB,
+            LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, ZZZZ, LINB, LINB,
+            LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB,
+            LINB, LINB, LINB, ZZZZ, LINB, LINB, ZZZZ, LINB,
+        ],
+        [
+            LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB,
+            ZZZZ, ZZZZ, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB,
+            LINB, LINB, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB,
+            LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB,
+            LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB,
+            LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB,
+            LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB,
+        ],
+        [
+            LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB,
+            LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB,
+            LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB,
+            LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB, LINB,
+            LINB, LINB, LINB, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+        ],
+        [
+            GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK, GREK,
+            GREK, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZZZZ, ZZZZ, ZZZZ, GREK, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZINH, ZZZZ, ZZZZ,
+        ],
+        [
+            LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI,
+            LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI, LYCI,
+            LYCI, ZZZZ, ZZZZ, ZZZZ, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI,
+            CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI,
+            CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI,
+        ],
+        [
+            CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI, CARI,
+            CARI, CARI, CARI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZINH, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL,
+            ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL,
+            ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ITAL, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ITAL, ITAL, ITAL, GOTH, GOTH, GOTH, GOTH, GOTH, GOTH, GOTH, GOTH,
+            GOTH, GOTH, GOTH, GOTH, GOTH, GOTH, GOTH, GOTH,
+        ],
+        [
+            GOTH, GOTH, GOTH, GOTH, GOTH, GOTH, GOTH, GOTH, GOTH, GOTH, GOTH, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM,
+            PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM,
+            PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM, PERM,
+            PERM, PERM, PERM, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR,
+            UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR, UGAR,
+            UGAR, UGAR, ZZZZ, UGAR, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO,
+            XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO,
+            XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO,
+        ],
+        [
+            XPEO, XPEO, XPEO, XPEO, ZZZZ, ZZZZ, ZZZZ, ZZZZ, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO,
+            XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, XPEO, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT,
+            DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT,
+            DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT,
+            DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT,
+            DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT,
+        ],
+        [
+            DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT, DSRT,
+            DSRT, DSRT, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW,
+            SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW,
+            SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW,
+            SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW, SHAW,
+        ],
+        [
+            OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA,
+            OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA,
+            OSMA, OSMA, ZZZZ, ZZZZ, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA, OSMA,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE,
+            OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE,
+        ],
+        [
+            OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE,
+            OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, ZZZZ, ZZZZ, ZZZZ, ZZZZ, OSGE, OSGE, OSGE, OSGE,
+            OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE,
+            OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE, OSGE,
+            OSGE, OSGE, OSGE, OSGE, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA,
+            ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA,
+            ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ELBA, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB,
+            AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB,
+        ],
+        [
+            AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB,
+            AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB,
+            AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, AGHB, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, AGHB, VITH, VITH, VITH, VITH, VITH, VITH, VITH, VITH,
+            VITH, VITH, VITH, ZZZZ, VITH, VITH, VITH, VITH,
+        ],
+        [
+            VITH, VITH, VITH, VITH, VITH, VITH, VITH, VITH, VITH, VITH, VITH, ZZZZ, VITH, VITH,
+            VITH, VITH, VITH, VITH, VITH, ZZZZ, VITH, VITH, ZZZZ, VITH, VITH, VITH, VITH, VITH,
+            VITH, VITH, VITH, VITH, VITH, VITH, ZZZZ, VITH, VITH, VITH, VITH, VITH, VITH, VITH,
+            VITH, VITH, VITH, VITH, VITH, VITH, VITH, VITH, ZZZZ, VITH, VITH, VITH, VITH, VITH,
+            VITH, VITH, ZZZZ, VITH, VITH, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR,
+            TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR,
+            TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR,
+            TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, TODR, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA,
+            LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA,
+            LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA,
+            LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA,
+            LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA,
+        ],
+        [
+            LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA,
+            LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA,
+            LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA,
+            LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA,
+            LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, LINA, LINA, LINA, LINA, LINA, LINA, LINA, LINA, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            LATN, LATN, LATN, LATN, LATN, LATN, ZZZZ, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, ZZZZ, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, ZZZZ, ZZZZ, CPRT, ZZZZ, CPRT, CPRT, CPRT, CPRT,
+            CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT,
+            CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT,
+            CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, CPRT, ZZZZ, CPRT,
+            CPRT, ZZZZ, ZZZZ, ZZZZ, CPRT, ZZZZ, ZZZZ, CPRT,
+        ],
+        [
+            ARMI, ARMI, ARMI, ARMI, ARMI, ARMI, ARMI, ARMI, ARMI, ARMI, ARMI, ARMI, ARMI, ARMI,
+            ARMI, ARMI, ARMI, ARMI, ARMI, ARMI, ARMI, ARMI, ZZZZ, ARMI, ARMI, ARMI, ARMI, ARMI,
+            ARMI, ARMI, ARMI, ARMI, PALM, PALM, PALM, PALM, PALM, PALM, PALM, PALM, PALM, PALM,
+            PALM, PALM, PALM, PALM, PALM, PALM, PALM, PALM, PALM, PALM, PALM, PALM, PALM, PALM,
+            PALM, PALM, PALM, PALM, PALM, PALM, PALM, PALM,
+        ],
+        [
+            NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT,
+            NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, NBAT,
+            NBAT, NBAT, NBAT, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, NBAT, NBAT, NBAT,
+            NBAT, NBAT, NBAT, NBAT, NBAT, NBAT, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, HATR, HATR, HATR, HATR, HATR, HATR, HATR, HATR, HATR, HATR,
+            HATR, HATR, HATR, HATR, HATR, HATR, HATR, HATR, HATR, ZZZZ, HATR, HATR, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, HATR, HATR, HATR, HATR, HATR,
+        ],
+        [
+            PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX,
+            PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX, PHNX,
+            ZZZZ, ZZZZ, ZZZZ, PHNX, LYDI, LYDI, LYDI, LYDI, LYDI, LYDI, LYDI, LYDI, LYDI, LYDI,
+            LYDI, LYDI, LYDI, LYDI, LYDI, LYDI, LYDI, LYDI, LYDI, LYDI, LYDI, LYDI, LYDI, LYDI,
+            LYDI, LYDI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, LYDI,
+        ],
+        [
+            SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, SIDT,
+            SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, SIDT, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            MERO, MERO, MERO, MERO, MERO, MERO, MERO, MERO, MERO, MERO, MERO, MERO, MERO, MERO,
+            MERO, MERO, MERO, MERO, MERO, MERO, MERO, MERO, MERO, MERO, MERO, MERO, MERO, MERO,
+            MERO, MERO, MERO, MERO, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC,
+            MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, MERC, MERC, MERC, MERC,
+        ],
+        [
+            MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC,
+            MERC, MERC, ZZZZ, ZZZZ, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC,
+            MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC,
+            MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC,
+            MERC, MERC, MERC, MERC, MERC, MERC, MERC, MERC,
+        ],
+        [
+            KHAR, KHAR, KHAR, KHAR, ZZZZ, KHAR, KHAR, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, KHAR, KHAR,
+            KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, ZZZZ, KHAR, KHAR, KHAR, ZZZZ, KHAR, KHAR, KHAR,
+            KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR,
+            KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, ZZZZ, ZZZZ,
+            KHAR, KHAR, KHAR, ZZZZ, ZZZZ, ZZZZ, ZZZZ, KHAR,
+        ],
+        [
+            KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, KHAR, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, SARB, SARB, SARB, SARB, SARB, SARB, SARB, SARB, SARB, SARB,
+            SARB, SARB, SARB, SARB, SARB, SARB, SARB, SARB, SARB, SARB, SARB, SARB, SARB, SARB,
+            SARB, SARB, SARB, SARB, SARB, SARB, SARB, SARB,
+        ],
+        [
+            NARB, NARB, NARB, NARB, NARB, NARB, NARB, NARB, NARB, NARB, NARB, NARB, NARB, NARB,
+            NARB, NARB, NARB, NARB, NARB, NARB, NARB, NARB, NARB, NARB, NARB, NARB, NARB, NARB,
+            NARB, NARB, NARB, NARB, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI,
+            MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI,
+            MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, MANI, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST,
+            AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST,
+            AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST,
+            AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, AVST, ZZZZ, ZZZZ,
+            ZZZZ, AVST, AVST, AVST, AVST, AVST, AVST, AVST,
+        ],
+        [
+            PRTI, PRTI, PRTI, PRTI, PRTI, PRTI, PRTI, PRTI, PRTI, PRTI, PRTI, PRTI, PRTI, PRTI,
+            PRTI, PRTI, PRTI, PRTI, PRTI, PRTI, PRTI, PRTI, ZZZZ, ZZZZ, PRTI, PRTI, PRTI, PRTI,
+            PRTI, PRTI, PRTI, PRTI, PHLI, PHLI, PHLI, PHLI, PHLI, PHLI, PHLI, PHLI, PHLI, PHLI,
+            PHLI, PHLI, PHLI, PHLI, PHLI, PHLI, PHLI, PHLI, PHLI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            PHLI, PHLI, PHLI, PHLI, PHLI, PHLI, PHLI, PHLI,
+        ],
+        [
+            PHLP, PHLP, PHLP, PHLP, PHLP, PHLP, PHLP, PHLP, PHLP, PHLP, PHLP, PHLP, PHLP, PHLP,
+            PHLP, PHLP, PHLP, PHLP, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, PHLP, PHLP, PHLP,
+            PHLP, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, PHLP,
+            PHLP, PHLP, PHLP, PHLP, PHLP, PHLP, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH,
+            ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH,
+            ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH,
+            ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH,
+            ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH,
+        ],
+        [
+            ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ORKH, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG,
+            HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG,
+            HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG,
+            HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG,
+            HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG,
+            HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG,
+            HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, HUNG, HUNG, HUNG, HUNG, HUNG, HUNG,
+        ],
+        [
+            ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG,
+            ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG,
+            ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG, ROHG,
+            ROHG, ROHG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA,
+            GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA,
+            GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, ZZZZ, ZZZZ, ZZZZ, GARA,
+            GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA,
+            GARA, GARA, GARA, GARA, GARA, GARA, GARA, GARA,
+        ],
+        [
+            GARA, GARA, GARA, GARA, GARA, GARA, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            GARA, GARA, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ZZZZ,
+        ],
+        [
+            YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI,
+            YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI,
+            YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI, YEZI,
+            ZZZZ, YEZI, YEZI, YEZI, ZZZZ, ZZZZ, YEZI, YEZI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+        ],
+        [
+            SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO,
+            SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO,
+            SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, SOGO, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD,
+            SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD,
+        ],
+        [
+            SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD,
+            SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, SOGD, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, OUGR, OUGR, OUGR, OUGR, OUGR, OUGR, OUGR, OUGR,
+            OUGR, OUGR, OUGR, OUGR, OUGR, OUGR, OUGR, OUGR,
+        ],
+        [
+            OUGR, OUGR, OUGR, OUGR, OUGR, OUGR, OUGR, OUGR, OUGR, OUGR, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, CHRS, CHRS, CHRS, CHRS, CHRS, CHRS, CHRS, CHRS,
+            CHRS, CHRS, CHRS, CHRS, CHRS, CHRS, CHRS, CHRS,
+        ],
+        [
+            CHRS, CHRS, CHRS, CHRS, CHRS, CHRS, CHRS, CHRS, CHRS, CHRS, CHRS, CHRS, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ELYM, ELYM, ELYM, ELYM, ELYM, ELYM, ELYM, ELYM, ELYM, ELYM,
+            ELYM, ELYM, ELYM, ELYM, ELYM, ELYM, ELYM, ELYM, ELYM, ELYM, ELYM, ELYM, ELYM, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH,
+            BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH,
+            BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH,
+            BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH,
+            BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH,
+        ],
+        [
+            BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH,
+            BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH,
+            BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, BRAH, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, BRAH,
+        ],
+        [
+            KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI,
+            KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI,
+            KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI,
+            KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI,
+            KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI, KTHI,
+        ],
+        [
+            KTHI, KTHI, KTHI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, KTHI,
+            ZZZZ, ZZZZ, SORA, SORA, SORA, SORA, SORA, SORA, SORA, SORA, SORA, SORA, SORA, SORA,
+            SORA, SORA, SORA, SORA, SORA, SORA, SORA, SORA, SORA, SORA, SORA, SORA, SORA, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, SORA, SORA, SORA, SORA, SORA, SORA, SORA, SORA,
+            SORA, SORA, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM,
+            CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM,
+            CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM,
+            CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, ZZZZ, CAKM, CAKM,
+            CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM,
+        ],
+        [
+            CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, CAKM, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ,
+            MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ,
+            MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, MAHJ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD,
+            SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD,
+            SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD,
+            SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD,
+            SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD,
+        ],
+        [
+            SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD,
+            SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD,
+            SHRD, SHRD, SHRD, SHRD, ZZZZ, SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH,
+            SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, SINH, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ,
+            KHOJ, KHOJ, KHOJ, KHOJ, ZZZZ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ,
+            KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ,
+            KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ,
+            KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ, KHOJ,
+        ],
+        [
+            KHOJ, KHOJ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            MULT, MULT, MULT, MULT, MULT, MULT, MULT, ZZZZ, MULT, ZZZZ, MULT, MULT, MULT, MULT,
+            ZZZZ, MULT, MULT, MULT, MULT, MULT, MULT, MULT, MULT, MULT, MULT, MULT, MULT, MULT,
+            MULT, MULT, ZZZZ, MULT, MULT, MULT, MULT, MULT, MULT, MULT, MULT, MULT, MULT, MULT,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND,
+            SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND,
+        ],
+        [
+            SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND,
+            SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND,
+            SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND,
+            SIND, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, SIND, SIND, SIND, SIND, SIND, SIND, SIND, SIND,
+            SIND, SIND, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            GRAN, GRAN, GRAN, GRAN, ZZZZ, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, ZZZZ,
+            ZZZZ, GRAN, GRAN, ZZZZ, ZZZZ, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN,
+            GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, ZZZZ,
+            GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, ZZZZ, GRAN, GRAN, ZZZZ, GRAN, GRAN, GRAN,
+            GRAN, GRAN, ZZZZ, ZINH, GRAN, GRAN, GRAN, GRAN,
+        ],
+        [
+            GRAN, GRAN, GRAN, GRAN, GRAN, ZZZZ, ZZZZ, GRAN, GRAN, ZZZZ, ZZZZ, GRAN, GRAN, GRAN,
+            ZZZZ, ZZZZ, GRAN, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, GRAN, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, GRAN, ZZZZ, ZZZZ, GRAN, GRAN, GRAN, GRAN,
+            GRAN, GRAN, GRAN, ZZZZ, ZZZZ, ZZZZ, GRAN, GRAN, GRAN, GRAN, GRAN, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, ZZZZ, TUTG, ZZZZ, ZZZZ,
+            TUTG, ZZZZ, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG,
+            TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG,
+            TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, ZZZZ, TUTG,
+            TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG,
+        ],
+        [
+            TUTG, ZZZZ, TUTG, ZZZZ, ZZZZ, TUTG, ZZZZ, TUTG, TUTG, TUTG, TUTG, ZZZZ, TUTG, TUTG,
+            TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, TUTG, ZZZZ, TUTG, TUTG, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, TUTG, TUTG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA,
+            NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA,
+            NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA,
+            NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA,
+            NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA,
+        ],
+        [
+            NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA,
+            NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA, NEWA,
+            ZZZZ, NEWA, NEWA, NEWA, NEWA, NEWA, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH,
+            TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH,
+            TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH,
+            TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH,
+            TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH,
+        ],
+        [
+            TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, TIRH, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD,
+            SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD,
+            SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD,
+            SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, ZZZZ, ZZZZ,
+            SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD,
+        ],
+        [
+            SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD,
+            SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD, SIDD,
+            SIDD, SIDD, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI,
+            MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI,
+            MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI,
+            MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI,
+            MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI,
+        ],
+        [
+            MODI, MODI, MODI, MODI, MODI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, MODI, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG, MONG,
+            MONG, MONG, MONG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR,
+            TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR,
+            TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR,
+            TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR,
+            TAKR, TAKR, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, TAKR, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR,
+            MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, MYMR, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM,
+            AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, ZZZZ,
+            ZZZZ, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM,
+            AHOM, AHOM, ZZZZ, ZZZZ, ZZZZ, ZZZZ, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM,
+            AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM,
+        ],
+        [
+            AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, AHOM, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR,
+            DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR,
+            DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR,
+            DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR, DOGR,
+            DOGR, DOGR, DOGR, DOGR, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA,
+            WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA,
+            WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA,
+        ],
+        [
+            WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA,
+            WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA,
+            WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA,
+            WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, WARA, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, WARA,
+        ],
+        [
+            DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, ZZZZ, ZZZZ, DIAK, ZZZZ, ZZZZ, DIAK, DIAK,
+            DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, ZZZZ, DIAK, DIAK, ZZZZ, DIAK, DIAK, DIAK, DIAK,
+            DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK,
+            DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, ZZZZ, DIAK,
+            DIAK, ZZZZ, ZZZZ, DIAK, DIAK, DIAK, DIAK, DIAK,
+        ],
+        [
+            DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, DIAK, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, ZZZZ, ZZZZ,
+            NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND,
+            NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND,
+        ],
+        [
+            NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND,
+            NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, ZZZZ, ZZZZ, NAND, NAND,
+            NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, NAND, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB,
+            ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB,
+            ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB,
+            ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB,
+            ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB,
+        ],
+        [
+            ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZANB, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO,
+            SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO,
+            SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO,
+            SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO,
+        ],
+        [
+            SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO,
+            SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO,
+            SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, SOYO, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS,
+            CANS, CANS, CANS, CANS, CANS, CANS, CANS, CANS,
+        ],
+        [
+            PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC,
+            PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC,
+            PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC,
+            PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC, PAUC,
+            PAUC, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, DEVA, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, SHRD, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU,
+            SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU,
+            SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU, SUNU,
+            SUNU, SUNU, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, ZZZZ, BHKS, BHKS, BHKS, BHKS,
+            BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS,
+            BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS,
+            BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, ZZZZ,
+            BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS,
+        ],
+        [
+            BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS,
+            BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS, BHKS,
+            BHKS, BHKS, BHKS, ZZZZ, ZZZZ, ZZZZ, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC,
+            MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC,
+        ],
+        [
+            MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC,
+            MARC, MARC, ZZZZ, ZZZZ, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC,
+            MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, ZZZZ, MARC,
+            MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, MARC, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            GONM, GONM, GONM, GONM, GONM, GONM, GONM, ZZZZ, GONM, GONM, ZZZZ, GONM, GONM, GONM,
+            GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM,
+            GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM,
+            GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, ZZZZ,
+            ZZZZ, ZZZZ, GONM, ZZZZ, GONM, GONM, ZZZZ, GONM,
+        ],
+        [
+            GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, GONM, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, GONG, GONG, GONG, GONG, GONG, GONG, ZZZZ, GONG, GONG, ZZZZ,
+            GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG,
+            GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG,
+        ],
+        [
+            GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG,
+            GONG, ZZZZ, GONG, GONG, ZZZZ, GONG, GONG, GONG, GONG, GONG, GONG, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG, GONG,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS,
+            TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS,
+        ],
+        [
+            TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS,
+            TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS, TOLS,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, MAKA, MAKA, MAKA, MAKA, MAKA, MAKA, MAKA, MAKA, MAKA, MAKA,
+            MAKA, MAKA, MAKA, MAKA, MAKA, MAKA, MAKA, MAKA, MAKA, MAKA, MAKA, MAKA, MAKA, MAKA,
+            MAKA, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI,
+            KAWI, KAWI, KAWI, ZZZZ, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI,
+            KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI,
+            KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI,
+            KAWI, KAWI, KAWI, ZZZZ, ZZZZ, ZZZZ, KAWI, KAWI,
+        ],
+        [
+            KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI,
+            KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, KAWI, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, LISU, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML,
+            TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML,
+            TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML,
+            TAML, TAML, TAML, TAML, TAML, TAML, TAML, TAML, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, TAML,
+        ],
+        [
+            XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX,
+            XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX,
+            XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX,
+            XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX,
+            XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX,
+        ],
+        [
+            XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX,
+            XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX,
+            XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX,
+            XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX, XSUX,
+            XSUX, XSUX, XSUX, XSUX, XSUX, ZZZZ, XSUX, XSUX, XSUX, XSUX, XSUX, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            XSUX, XSUX, XSUX, XSUX, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN,
+            CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN,
+            CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN,
+            CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN,
+        ],
+        [
+            CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN,
+            CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN,
+            CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN,
+            CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, CPMN, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP,
+            EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP,
+            EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP,
+            EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP,
+            EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP,
+        ],
+        [
+            EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP,
+            EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP,
+            EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP,
+            EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP,
+        ],
+        [
+            EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP,
+            EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP,
+            EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP,
+            EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP, EGYP,
+            EGYP, EGYP, EGYP, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW,
+            HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW,
+            HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW,
+            HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW,
+            HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW,
+        ],
+        [
+            HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, HLUW, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH,
+            GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH,
+            GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH,
+            GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH, GUKH,
+            GUKH, GUKH, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+        ],
+        [
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+            BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU, BAMU,
+            BAMU, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO,
+            MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO,
+            MROO, MROO, MROO, ZZZZ, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO, MROO,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, MROO, MROO, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA,
+            TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA,
+        ],
+        [
+            TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA,
+            TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA,
+            TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA,
+            TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA,
+            TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, ZZZZ,
+        ],
+        [
+            TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, TNSA, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, BASS, BASS, BASS, BASS, BASS, BASS, BASS, BASS, BASS, BASS, BASS, BASS,
+            BASS, BASS, BASS, BASS, BASS, BASS, BASS, BASS, BASS, BASS, BASS, BASS, BASS, BASS,
+            BASS, BASS, BASS, BASS, ZZZZ, ZZZZ, BASS, BASS, BASS, BASS, BASS, BASS, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG,
+            HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG,
+            HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG,
+            HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG,
+            HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG,
+        ],
+        [
+            HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, ZZZZ, HMNG,
+            HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, ZZZZ, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG,
+            HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, HMNG, HMNG, HMNG,
+        ],
+        [
+            HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG, HMNG,
+            HMNG, HMNG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI,
+            KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI,
+            KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI,
+            KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI, KRAI,
+            KRAI, KRAI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF,
+            MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF,
+            MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF,
+            MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF,
+            MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF,
+        ],
+        [
+            MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF,
+            MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, MEDF, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF,
+            BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF,
+            BERF, ZZZZ, ZZZZ, BERF, BERF, BERF, BERF, BERF,
+        ],
+        [
+            BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF, BERF,
+            BERF, BERF, BERF, BERF, BERF, BERF, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD,
+            PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD,
+            PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD,
+            PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD,
+            PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD,
+        ],
+        [
+            PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD,
+            PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD,
+            PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD,
+            PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD,
+        ],
+        [
+            PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD, PLRD,
+            PLRD, PLRD, PLRD, PLRD, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, TANG, NSHU, HANI, HANI, KITS, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, HANI, HANI, HANI, HANI, HANI, HANI, HANI, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG,
+            TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG,
+            TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG,
+            TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG,
+            TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG,
+        ],
+        [
+            KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS,
+            KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS,
+            KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS,
+            KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS,
+            KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS,
+        ],
+        [
+            KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS,
+            KITS, KITS, KITS, KITS, KITS, KITS, KITS, KITS, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, KITS,
+        ],
+        [
+            TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG,
+            TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG,
+            TANG, TANG, TANG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG,
+            TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG,
+            TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG,
+            TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, TANG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, KANA, KANA, KANA, KANA, ZZZZ, KANA, KANA, KANA,
+            KANA, KANA, KANA, KANA, ZZZZ, KANA, KANA, ZZZZ,
+        ],
+        [
+            KANA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+        ],
+        [
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+        ],
+        [
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA, HIRA,
+            HIRA, HIRA, HIRA, HIRA, KANA, KANA, KANA, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, HIRA, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, HIRA, HIRA, HIRA, ZZZZ, ZZZZ, KANA, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, KANA, KANA, KANA, KANA, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU,
+            NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU,
+        ],
+        [
+            NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU,
+            NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU,
+            NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU,
+            NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU,
+            NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU,
+        ],
+        [
+            NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU,
+            NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU,
+            NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU,
+            NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU, NSHU,
+            NSHU, NSHU, NSHU, NSHU, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL,
+            DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL,
+            DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL,
+            DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL,
+            DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL,
+        ],
+        [
+            DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL,
+            DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL,
+            DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL,
+            DUPL, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL,
+            DUPL, DUPL, DUPL, DUPL, DUPL, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, DUPL, ZZZZ, ZZZZ,
+            DUPL, DUPL, DUPL, DUPL, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZZZZ, ZZZZ, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+        ],
+        [
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZINH, ZINH, ZINH,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZINH, ZINH, ZINH, ZINH, ZINH,
+        ],
+        [
+            ZINH, ZINH, ZINH, ZYYY, ZYYY, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZINH, ZINH, ZINH, ZINH, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            GREK, GREK, GREK, GREK, GREK, GREK, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZZZZ, ZYYY, ZYYY, ZZZZ, ZZZZ, ZYYY, ZZZZ, ZZZZ, ZYYY, ZYYY, ZZZZ, ZZZZ, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZZZZ, ZYYY, ZZZZ, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW,
+            SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW,
+            SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW,
+            SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW,
+            SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW,
+        ],
+        [
+            SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, SGNW,
+            SGNW, SGNW, SGNW, SGNW, ZZZZ, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, SGNW,
+            SGNW, SGNW, SGNW, SGNW, SGNW, SGNW, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN, LATN,
+            LATN, LATN, LATN, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, LATN, LATN, LATN, LATN, LATN,
+            LATN, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, ZZZZ, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG,
+            GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, ZZZZ, ZZZZ, GLAG,
+            GLAG, GLAG, GLAG, GLAG, GLAG, GLAG, ZZZZ, GLAG, GLAG, ZZZZ, GLAG, GLAG, GLAG, GLAG,
+            GLAG, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+        ],
+        [
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL, CYRL,
+            CYRL, CYRL, CYRL, CYRL, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, CYRL, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP,
+            HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP,
+            HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP,
+            HMNP, HMNP, HMNP, ZZZZ, ZZZZ, ZZZZ, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP,
+            HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, ZZZZ, ZZZZ,
+        ],
+        [
+            HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, HMNP, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            HMNP, HMNP, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO,
+            TOTO, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO, TOTO,
+            TOTO, TOTO, TOTO, TOTO, TOTO, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO,
+            WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO,
+            WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO,
+            WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO, WCHO,
+            WCHO, WCHO, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, WCHO,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM,
+            NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM,
+            NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM, NAGM,
+            NAGM, NAGM, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO,
+            ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO,
+            ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO, ONAO,
+            ONAO, ONAO, ONAO, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ONAO,
+        ],
+        [
+            TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO,
+            TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO,
+            TAYO, TAYO, TAYO, ZZZZ, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO,
+            TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, TAYO, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, TAYO, TAYO,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ, ETHI, ETHI,
+            ETHI, ETHI, ZZZZ, ETHI, ETHI, ZZZZ, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI,
+            ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ETHI, ZZZZ,
+        ],
+        [
+            MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND,
+            MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND,
+            MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND,
+            MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND,
+            MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND,
+        ],
+        [
+            MEND, MEND, MEND, MEND, MEND, ZZZZ, ZZZZ, MEND, MEND, MEND, MEND, MEND, MEND, MEND,
+            MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, MEND, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM,
+            ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM,
+            ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM,
+            ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM,
+            ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM,
+        ],
+        [
+            ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ADLM, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ADLM, ADLM, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ,
+        ],
+        [
+            ARAB, ARAB, ARAB, ARAB, ZZZZ, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ZZZZ, ARAB, ARAB, ZZZZ, ARAB, ZZZZ, ZZZZ, ARAB, ZZZZ, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ZZZZ, ARAB, ARAB, ARAB, ARAB,
+            ZZZZ, ARAB, ZZZZ, ARAB, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ARAB, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ARAB, ZZZZ, ARAB, ZZZZ, ARAB, ZZZZ, ARAB,
+            ARAB, ARAB, ZZZZ, ARAB, ARAB, ZZZZ, ARAB, ZZZZ, ZZZZ, ARAB, ZZZZ, ARAB, ZZZZ, ARAB,
+            ZZZZ, ARAB, ZZZZ, ARAB, ZZZZ, ARAB, ARAB, ZZZZ, ARAB, ZZZZ, ZZZZ, ARAB, ARAB, ARAB,
+            ARAB, ZZZZ, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ZZZZ, ARAB, ARAB, ARAB, ARAB,
+            ZZZZ, ARAB, ARAB, ARAB, ARAB, ZZZZ, ARAB, ZZZZ,
+        ],
+        [
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ZZZZ, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ARAB, ARAB, ARAB, ZZZZ, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ZZZZ, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB, ARAB,
+            ARAB, ARAB, ARAB, ARAB, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ARAB, ARAB, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            HIRA, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, ZZZZ, ZZZZ, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+        ],
+        [
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+        ],
+        [
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+        ],
+        [
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI, HANI,
+            HANI, HANI, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+        [
+            ZZZZ, ZYYY, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+            ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY, ZYYY,
+        ],
+        [
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZINH,
+            ZINH, ZINH, ZINH, ZINH, ZINH, ZINH, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+            ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ, ZZZZ,
+        ],
+    ],
+};
+
+/// The distinct `Script_Extensions` sets, indexed by `SCRIPT_EXTENSION`. Index 0 is unused, it
+/// marks codepoints whose extensions are just their script
+pub(crate) static SCRIPT_EXTENSIONS: [ScriptSet; 119] = [
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0004_2B8A_0220_0040, 0x0008_0000_0006_0400, 0x0000_0000_0000_0002]),
+    ScriptSet::from_words([0x0000_0000_3000_0800, 0x0000_0000_0000_8400, 0x0000_0002_0800_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_4000, 0x0000_0000_0000_0400, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0000_0000_0000_8400, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0000_0000_0000_0400, 0x0000_0000_0800_0000]),
+    ScriptSet::from_words([0x0000_2000_1280_0000, 0x0008_0000_0000_0400, 0x0000_0000_0002_1000]),
+    ScriptSet::from_words([0x0000_2000_1080_0000, 0x0000_4000_0000_0400, 0x0000_0000_8002_1000]),
+    ScriptSet::from_words([0x0000_0000_1080_0000, 0x0000_0000_0000_0400, 0x0000_0000_0100_0000]),
+    ScriptSet::from_words([0x0000_0100_0000_0000, 0x0000_0000_0000_0400, 0x0000_0000_0800_5000]),
+    ScriptSet::from_words([0x0000_2800_1280_0002, 0x0000_4000_0000_0400, 0x0000_0000_8100_4000]),
+    ScriptSet::from_words([0x8000_0908_0200_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_2000_1000_0000, 0x0008_0000_0000_0400, 0x0000_0000_0100_0000]),
+    ScriptSet::from_words([0x0020_0002_0200_0000, 0x0008_0000_0000_0400, 0x0000_0000_8102_4000]),
+    ScriptSet::from_words([0x0020_2802_1000_0020, 0x0008_0000_0000_0400, 0x0000_0000_0102_4000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0000_0000_0000_0400, 0x0000_0000_0100_0000]),
+    ScriptSet::from_words([0x0000_0002_0000_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_4000]),
+    ScriptSet::from_words([0x0000_0000_1080_0000, 0x0000_4000_0000_0400, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0080_0000, 0x0000_0000_0000_0400, 0x0000_0000_0002_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_1000]),
+    ScriptSet::from_words([0x0000_0020_0000_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_1000_0000, 0x0000_0000_0000_0400, 0x0000_0000_8000_0000]),
+    ScriptSet::from_words([0x0000_2000_0000_0000, 0x0008_0000_0000_0400, 0x0000_0000_8000_0000]),
+    ScriptSet::from_words([0x8000_0002_0080_0000, 0x0000_0000_0000_0400, 0x0000_0000_0100_4000]),
+    ScriptSet::from_words([0x0000_0002_0080_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_4000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_4000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_5000]),
+    ScriptSet::from_words([0x0000_0000_0080_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_4000]),
+    ScriptSet::from_words([0x0000_0800_0080_0002, 0x0000_0000_0000_0400, 0x0000_0000_0800_5000]),
+    ScriptSet::from_words([0x0000_2000_0000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0000_4000_0000_0400, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0002, 0x0000_0000_0000_0400, 0x0000_0000_8000_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_2000_0200_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_1000_0000, 0x0008_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0100_1000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_1000_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0180_0000_0020, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0040_0000_0008, 0x0800_0080_0000_0000, 0x0000_0400_0400_4000]),
+    ScriptSet::from_words([0x0000_0000_0000_0008, 0x0000_0000_0000_0000, 0x0000_0000_0400_4000]),
+    ScriptSet::from_words([0x0000_0040_0000_0009, 0x0800_0080_0000_0000, 0x0000_0400_0400_4000]),
+    ScriptSet::from_words([0x0000_0000_0000_0009, 0x0841_0000_0030_0000, 0x0000_0000_0000_4080]),
+    ScriptSet::from_words([0x0000_0000_0000_0008, 0x0000_0000_0000_0000, 0x0000_0000_0000_4000]),
+    ScriptSet::from_words([0x0000_0000_0000_0008, 0x0000_0000_0000_0000, 0x0000_0400_0400_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0008, 0x0800_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0001_5000_2000_0800, 0x0000_2048_0800_0420, 0x0000_0000_2088_0004]),
+    ScriptSet::from_words([0x0001_5000_2000_0800, 0x0000_2040_0800_0420, 0x0000_0000_2088_0000]),
+    ScriptSet::from_words([0x0001_5600_A000_0800, 0x0000_2808_0804_0020, 0x0000_0000_2089_2060]),
+    ScriptSet::from_words([0x0001_D600_A000_0800, 0x0000_2808_0804_1020, 0x0000_0000_2089_2060]),
+    ScriptSet::from_words([0x0000_0000_A000_0000, 0x0000_0000_0004_0080, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0008_0800, 0x0000_0000_0000_0000, 0x0000_0000_0000_2000]),
+    ScriptSet::from_words([0x0001_0000_0000_0000, 0x0000_0001_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_4000_0000_0000, 0x0000_0000_0000_0008, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_1000_0000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0008_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0000_0008_0000_0020, 0x0000_0004_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0008_0000, 0x0000_0002_0000_0000, 0x0000_0000_0002_0000]),
+    ScriptSet::from_words([0x0000_0180_0000_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x1000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0008_0000_0004_0000, 0x0000_0000_0000_0000, 0x0000_0000_0200_8000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0010_0000_2000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_1000_2000_0800, 0x0000_0000_0000_0020, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_2000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_1000_2000_0000, 0x0000_0000_0000_0020, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_2000_0800, 0x0000_0040_0000_0000, 0x0000_0000_2080_0000]),
+    ScriptSet::from_words([0x0000_0000_2000_0800, 0x0000_0000_0000_0000, 0x0000_0000_0080_0000]),
+    ScriptSet::from_words([0x0000_0000_2000_0000, 0x0000_0040_0000_0000, 0x0000_0000_0000_0004]),
+    ScriptSet::from_words([0x0000_0000_2000_0800, 0x0000_0040_0000_0000, 0x0000_0000_0080_0000]),
+    ScriptSet::from_words([0x0000_0000_2000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0004]),
+    ScriptSet::from_words([0x0000_0000_2000_0000, 0x0000_2000_0800_0020, 0x0000_0000_0088_0000]),
+    ScriptSet::from_words([0x0000_0000_2000_0800, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_2000_0000, 0x0000_0040_0000_0000, 0x0000_0000_2000_0000]),
+    ScriptSet::from_words([0x0000_0000_2000_0000, 0x0000_0048_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_2000_0800, 0x0000_0000_0000_0000, 0x0000_0000_0000_0004]),
+    ScriptSet::from_words([0x0000_0000_2000_0000, 0x0000_0040_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_2000_0800, 0x0000_0040_0000_0000, 0x0000_0000_0000_0004]),
+    ScriptSet::from_words([0x0000_1000_2000_0800, 0x0000_2008_0800_0020, 0x0000_0004_2080_0040]),
+    ScriptSet::from_words([0x0000_1000_2000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_1000_2000_0000, 0x0000_0000_0000_0020, 0x0000_0004_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0800, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0000_0008_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_1000_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_4000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_4000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0010_0000_2000_0400, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0009, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0800_0180_0020_0000, 0x0000_1000_0001_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0800_2000_0020_0000, 0x0000_0000_0400_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_1000_2000_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0200_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0040, 0x0000_1000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0800_0080_0020_0040, 0x2000_0000_0002_0080, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0002_0000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0800_0000_0000_0009, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0004_0000_0000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0010_0000]),
+    ScriptSet::from_words([0x8046_0000_0000_4000, 0x0000_0000_2000_0000, 0x0000_0800_0000_0000]),
+    ScriptSet::from_words([0x8046_0000_0000_4000, 0x0010_0000_2000_0000, 0x0000_0800_0000_0000]),
+    ScriptSet::from_words([0x8046_0000_0000_4000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0004_0000_0000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x8046_0000_0000_4000, 0x0000_0000_2000_0000, 0x0000_0800_1000_0000]),
+    ScriptSet::from_words([0x8046_0000_0000_4000, 0x0000_0000_2000_8000, 0x0000_0800_1000_0000]),
+    ScriptSet::from_words([0x8046_0000_0000_4000, 0x0000_0000_0000_0000, 0x0000_0800_0000_0000]),
+    ScriptSet::from_words([0x0004_0000_0000_4000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x8040_0000_0000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x8044_0000_0000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0004_0000_0000_0000, 0x0000_0000_0000_0400, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0001_4000_A000_0000, 0x0000_0008_1804_00A8, 0x0000_0004_2001_0024]),
+    ScriptSet::from_words([0x0001_4000_A000_0000, 0x0000_0008_1004_00A8, 0x0000_0004_2001_0024]),
+    ScriptSet::from_words([0x0001_4000_A000_0000, 0x0000_0000_1004_0088, 0x0000_0000_2001_0020]),
+    ScriptSet::from_words([0x0001_4000_A000_0000, 0x0000_0000_1004_0088, 0x0000_0000_2001_0024]),
+    ScriptSet::from_words([0x0000_0000_2000_0800, 0x0000_0000_0000_0000, 0x0000_0004_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_2000_0000, 0x0000_0000_0000_0000, 0x0000_0000_0008_0000]),
+    ScriptSet::from_words([0x4000_0000_0000_0000, 0x0000_0002_0000_0400, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x2000_0000_0002_0000, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0008, 0x0000_0080_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0008, 0x0000_0000_0000_0000, 0x0000_0000_0400_0000]),
+    ScriptSet::from_words([0x0000_0000_0C00_0000, 0x0000_0000_0000_4000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0800_0000, 0x0000_0000_0000_4000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0800_0000, 0x0000_0000_0000_6000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0200_0008, 0x0000_0000_0000_0000, 0x0000_0000_0000_0000]),
+    ScriptSet::from_words([0x0000_0000_0000_0000, 0x0001_0000_0020_0000, 0x0000_0000_0000_0000]),
+];
+
+/// The index of the `Script_Extensions` of every codepoint
+pub(crate) static SCRIPT_EXTENSION: Utf8Trie<[u8; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 0, 0, 0, 0, 6, 0, 0, 0, 7, 0, 8,
+        9, 0, 10, 0, 0, 0, 0, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 6, 12, 13, 13, 13, 13, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 0, 0, 0, 0, 6, 0, 0, 0, 7, 0, 8, 9, 0, 10, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 12, 0, 13, 0, 14, 0, 0, 0, 15, 0, 0, 0, 16, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 17, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19,
+            20, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 23,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            24, 25, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27,
+            28, 0, 0, 0, 0, 0, 29,
+        ],
+        [
+            30, 0, 31, 32, 0, 0, 33, 34, 35, 36, 37, 38, 0, 39, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 42,
+            0, 0, 0, 43, 0, 0, 44, 45, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 48, 0,
+            49, 0, 0, 0, 50, 51, 0,
+        ],
+        [
+            0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 56,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 6, 7, 8, 4, 4, 4, 4, 4, 4, 4, 4, 4, 9, 4,
+            10, 4, 11, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+    ],
+    leaves: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 2, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 3, 0, 3, 3, 3, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 3, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 0, 19, 21, 0, 22, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 24, 25, 0, 0, 0, 0, 0, 0, 0, 26, 25, 0, 27, 28, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 29, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0,
+            0, 0, 31, 0, 0, 0, 0, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 0, 0, 0, 0,
+            33, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 34, 35, 36, 36, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38,
+            39, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 0, 0, 0, 0, 0, 0, 42, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 47, 48, 49, 49, 49, 49, 49, 49, 49, 49, 49, 49, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 50, 50, 50, 50, 50, 50, 50, 50, 50, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 51, 51, 51, 51, 51, 51, 51, 51, 51, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 52, 52, 52, 52, 52, 52, 52, 52, 52, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 54, 54, 54, 54, 54, 54, 54, 54, 54, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            55, 55, 55, 55, 55, 55, 55, 55, 55, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 56, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 57, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 58, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 59, 59, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 61, 60, 62, 61, 63, 64, 65, 66, 67,
+            68, 61, 67, 67, 61, 61, 67, 69, 70, 61, 61, 61, 61, 61, 61, 71, 72, 73, 61, 74, 61, 61,
+            61, 61, 75, 76, 77, 69, 69, 78, 76, 76, 79, 0, 0, 0, 0, 0,
+        ],
+        [
+            29, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0,
+            81, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
+            85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 89, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 90, 0, 0, 0,
+        ],
+        [
+            0, 91, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 92, 92, 92, 92, 92, 92, 92,
+            92, 92, 92, 92, 92, 92, 92, 92,
+        ],
+        [
+            0, 93, 94, 95, 0, 0, 96, 0, 97, 97, 98, 98, 99, 99, 99, 99, 99, 99, 0, 95, 99, 99, 99,
+            99, 99, 99, 99, 99, 95, 95, 95, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 100, 100, 100, 0,
+            0, 95, 101, 101, 101, 101, 101, 0, 95, 0, 0, 0, 0, 102, 102, 96, 96,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 101, 101,
+            101, 101, 0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 99, 101, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96,
+            96, 96, 96, 96, 96, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96,
+            96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96,
+            96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96,
+        ],
+        [
+            96, 96, 96, 96, 96, 96, 96, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96,
+            96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96,
+            96, 96, 96, 96, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 96,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 96, 96, 96,
+            96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 96, 96, 96, 96,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96,
+            96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            103, 103, 103, 103, 103, 103, 103, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 104, 104, 105, 105, 105,
+            106, 106, 107, 106, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 108, 0, 109, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 112, 112,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 113, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 95, 95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 99, 99, 99, 99, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 101, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            114, 114, 115, 0, 0, 0, 0, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116,
+            116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116,
+            116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 116, 0, 0, 0,
+            115, 115, 115, 115, 115, 115, 115, 115, 115,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 53, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 53, 53, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 53, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 90, 90, 90, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 96, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+    ],
+};
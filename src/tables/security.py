@@ -0,0 +1,59 @@
+"""Generates src/tables/security.rs: the confusable prototypes and identifier status of UTS #39.
+
+    python3 src/tables/security.py <path to UCD> > src/tables/security.rs
+
+UTS #39 data is not part of the UCD, the files of https://www.unicode.org/Public/security/17.0.0/
+are expected in a `security` directory next to the UCD files.
+
+The confusables trie maps a codepoint to an index into `PROTOTYPES`, index 0 marks codepoints that
+are their own prototype.
+"""
+
+from ucd import CODEPOINTS, binary_property, bool_trie, header, parse_codepoints, parse_lines, parse_range, ucd_dir, value_trie, wrap
+
+
+def main() -> None:
+    ucd = ucd_dir()
+    security = ucd / "security"
+
+    pool = [""]
+    ids = {"": 0}
+    prototypes = ["0"] * CODEPOINTS
+
+    for fields in parse_lines(security / "confusables.txt"):
+        prototype = "".join(map(chr, parse_codepoints(fields[1])))
+
+        if prototype not in ids:
+            ids[prototype] = len(pool)
+            pool.append(prototype)
+
+        prototypes[int(fields[0], 16)] = str(ids[prototype])
+
+    allowed = [False] * CODEPOINTS
+
+    for fields in parse_lines(security / "IdentifierStatus.txt"):
+        if fields[1] == "Allowed":
+            for cp in parse_range(fields[0]):
+                allowed[cp] = True
+
+    ignorable = binary_property(ucd / "DerivedCoreProperties.txt", "Default_Ignorable_Code_Point")
+
+    def literal(s: str) -> str:
+        return '"' + "".join(f"\\u{{{ord(c):X}}}" for c in s) + '",'
+
+    print(header("security.py", ["security/confusables.txt", "security/IdentifierStatus.txt", "DerivedCoreProperties.txt"]))
+    print("use super::Utf8Trie;")
+    print()
+    print("/// The confusable prototypes, indexed by `CONFUSABLE`. Index 0 is unused, it marks codepoints")
+    print("/// that are their own prototype")
+    print(f"pub(crate) static PROTOTYPES: [&str; {len(pool)}] = [")
+    print("\n".join(wrap(map(literal, pool), indent=4)))
+    print("];")
+    print()
+    print(value_trie("CONFUSABLE", "The confusable prototype of every codepoint", "u16", prototypes))
+    print(bool_trie("IDENTIFIER_ALLOWED", "Codepoints with an `Identifier_Status` of `Allowed`", allowed))
+    print(bool_trie("DEFAULT_IGNORABLE", "Codepoints with the `Default_Ignorable_Code_Point` property", ignorable), end="")
+
+
+if __name__ == "__main__":
+    main()
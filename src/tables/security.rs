@@ -0,0 +1,2912 @@
+//! Generated by `src/tables/security.py` from the Unicode 17.0.0 files
+//! security/confusables.txt, security/IdentifierStatus.txt, DerivedCoreProperties.txt. Do not edit by hand.
+
+use super::Utf8Trie;
+
+/// The confusable prototypes, indexed by `CONFUSABLE`. Index 0 is unused, it marks codepoints
+/// that are their own prototype
+pub(crate) static PROTOTYPES: [&str; 3318] = [
+    "", "\u{27}\u{27}", "\u{BA}\u{2F}\u{2080}", "\u{4F}", "\u{6C}", "\u{27}", "\u{72}\u{6E}",
+    "\u{20}", "\u{63}\u{338}", "\u{59}\u{335}", "\u{2C9}", "\u{3BC}", "\u{2C}", "\u{41}\u{45}",
+    "\u{43}\u{326}", "\u{44}\u{335}", "\u{78}", "\u{4F}\u{338}", "\u{61}\u{65}", "\u{63}\u{326}",
+    "\u{2202}\u{335}", "\u{629}", "\u{6F}\u{338}", "\u{64}\u{335}", "\u{114}", "\u{115}",
+    "\u{48}\u{335}", "\u{68}\u{335}", "\u{69}", "\u{6C}\u{4A}", "\u{69}\u{6A}", "\u{6C}\u{B7}",
+    "\u{4C}\u{338}", "\u{6C}\u{338}", "\u{272}", "\u{27}\u{6E}", "\u{D6}", "\u{4F}\u{45}",
+    "\u{6F}\u{65}", "\u{1AB}", "\u{54}\u{335}", "\u{74}\u{335}", "\u{66}", "\u{62}\u{335}",
+    "\u{27}\u{42}", "\u{62}\u{304}", "\u{62}", "\u{43}\u{27}", "\u{27}\u{44}", "\u{64}\u{304}",
+    "\u{67}", "\u{46}\u{326}", "\u{66}\u{326}", "\u{47}\u{27}", "\u{6C}\u{335}", "\u{4B}\u{27}",
+    "\u{6B}\u{314}", "\u{3BB}\u{338}", "\u{4E}\u{326}", "\u{6E}\u{329}", "\u{4F}\u{335}",
+    "\u{4F}\u{27}", "\u{6F}\u{27}", "\u{27}\u{50}", "\u{70}\u{314}", "\u{52}", "\u{32}",
+    "\u{27}\u{54}", "\u{74}\u{314}", "\u{54}\u{328}", "\u{27}\u{59}", "\u{79}\u{314}",
+    "\u{5A}\u{335}", "\u{7A}\u{335}", "\u{33}", "\u{32}\u{335}", "\u{35}", "\u{73}", "\u{FE}",
+    "\u{6C}\u{6C}", "\u{21}", "\u{44}\u{17D}", "\u{44}\u{17E}", "\u{64}\u{17E}", "\u{4C}\u{4A}",
+    "\u{4C}\u{6A}", "\u{6C}\u{6A}", "\u{4E}\u{4A}", "\u{4E}\u{6A}", "\u{6E}\u{6A}", "\u{102}",
+    "\u{103}", "\u{12C}", "\u{12D}", "\u{14E}", "\u{14F}", "\u{16C}", "\u{16D}", "\u{47}\u{335}",
+    "\u{67}\u{335}", "\u{11E}", "\u{11F}", "\u{44}\u{5A}", "\u{44}\u{7A}", "\u{64}\u{7A}",
+    "\u{123}", "\u{4F}\u{338}\u{301}", "\u{162}", "\u{38}", "\u{5A}\u{326}", "\u{7A}\u{326}",
+    "\u{C5}", "\u{E5}", "\u{54}\u{338}", "\u{3F}", "\u{55}\u{335}", "\u{45}\u{338}",
+    "\u{65}\u{338}", "\u{4A}\u{335}", "\u{6A}\u{335}", "\u{72}\u{335}", "\u{79}\u{335}", "\u{61}",
+    "\u{62}\u{314}", "\u{64}\u{328}", "\u{64}\u{314}", "\u{1DD}", "\u{1DD}\u{2DE}", "\u{A793}",
+    "\u{67}\u{314}", "\u{79}", "\u{68}\u{314}", "\u{69}\u{335}", "\u{6C}\u{334}", "\u{6C}\u{328}",
+    "\u{6C}\u{21D}", "\u{77}", "\u{72}\u{6E}\u{326}", "\u{6E}\u{328}", "\u{6F}\u{335}",
+    "\u{6F}\u{1D07}", "\u{72}\u{329}", "\u{72}\u{328}", "\u{73}\u{328}", "\u{75}", "\u{7A}\u{328}",
+    "\u{21D}", "\u{71}\u{314}", "\u{64}\u{21D}", "\u{64}\u{291}", "\u{74}\u{73}", "\u{74}\u{283}",
+    "\u{74}\u{255}", "\u{66}\u{14B}", "\u{6C}\u{73}", "\u{6C}\u{7A}", "\u{18F4}", "\u{559}",
+    "\u{3C}", "\u{3E}", "\u{5E}", "\u{3A}", "\u{2D}", "\u{2C7}", "\u{971}", "\u{B0}", "\u{7E}",
+    "\u{18F3}", "\u{18F5}", "\u{2C1}", "\u{2EA}", "\u{304}", "\u{306}", "\u{670}", "\u{306}\u{307}",
+    "\u{302}", "\u{313}", "\u{650}", "\u{331}", "\u{326}", "\u{328}", "\u{335}", "\u{338}",
+    "\u{300}", "\u{301}", "\u{303}", "\u{333}", "\u{350}", "\u{307}", "\u{30A}", "\u{2C75}",
+    "\u{2CF}", "\u{418}", "\u{1D0E}", "\u{254}", "\u{A73F}", "\u{3B}", "\u{4A}", "\u{B7}", "\u{41}",
+    "\u{42}", "\u{45}", "\u{5A}", "\u{48}", "\u{4B}", "\u{245}", "\u{4D}", "\u{4E}", "\u{50}",
+    "\u{1A9}", "\u{54}", "\u{59}", "\u{58}", "\u{DF}", "\u{1E9F}", "\u{138}", "\u{76}", "\u{6F}",
+    "\u{70}", "\u{1D1B}", "\u{278}", "\u{3C0}", "\u{3C2}", "\u{46}", "\u{1A8}", "\u{63}", "\u{6A}",
+    "\u{DE}", "\u{43}", "\u{186}", "\u{A73E}", "\u{A792}", "\u{53}", "\u{393}", "\u{40D}",
+    "\u{3A0}", "\u{3A6}", "\u{62}\u{6C}", "\u{6C}\u{4F}", "\u{36}", "\u{299}", "\u{72}", "\u{65}",
+    "\u{25C}", "\u{28D}", "\u{29C}", "\u{2C9}\u{62}", "\u{185}\u{69}", "\u{185}", "\u{1D19}",
+    "\u{439}", "\u{3A8}", "\u{3C8}", "\u{56}", "\u{460}\u{486}\u{487}", "\u{77}\u{486}\u{487}",
+    "\u{40D}\u{326}", "\u{439}\u{326}", "\u{393}\u{27}", "\u{72}\u{27}", "\u{393}\u{335}",
+    "\u{416}\u{329}", "\u{436}\u{329}", "\u{33}\u{326}", "\u{25C}\u{326}", "\u{4B}\u{329}",
+    "\u{138}\u{329}", "\u{4B}\u{335}", "\u{138}\u{335}", "\u{48}\u{329}", "\u{29C}\u{329}",
+    "\u{54}\u{329}", "\u{1D1B}\u{329}", "\u{58}\u{329}", "\u{68}", "\u{4BC}\u{328}",
+    "\u{65}\u{328}", "\u{245}\u{326}", "\u{43B}\u{326}", "\u{48}\u{326}", "\u{29C}\u{326}",
+    "\u{4B6}", "\u{4B7}", "\u{4D}\u{326}", "\u{28D}\u{326}", "\u{18F}", "\u{64}", "\u{1F6}",
+    "\u{47}", "\u{262}", "\u{190}", "\u{71}", "\u{57}", "\u{12AE}", "\u{1206}", "\u{1323}",
+    "\u{1261}", "\u{55}", "\u{237}", "\u{6E}", "\u{270}", "\u{565}\u{582}", "\u{59A}", "\u{599}",
+    "\u{596}", "\u{598}", "\u{323}", "\u{6C}\u{27}", "\u{BA}\u{2F}\u{2080}\u{2080}",
+    "\u{BA}\u{2F}\u{2080}\u{2080}\u{2080}", "\u{639}", "\u{6C}\u{674}", "\u{648}\u{674}",
+    "\u{6C}\u{655}", "\u{649}\u{674}", "\u{649}\u{6DB}", "\u{633}\u{6DB}", "\u{649}\u{302}",
+    "\u{649}", "\u{30B}", "\u{329}", "\u{312}", "\u{314}", "\u{655}", "\u{2E}", "\u{60C}", "\u{2A}",
+    "\u{6A1}", "\u{648}\u{313}\u{674}", "\u{649}\u{615}", "\u{62D}\u{654}", "\u{62D}\u{6DB}",
+    "\u{62F}\u{615}", "\u{68A}\u{615}", "\u{62F}\u{6DB}", "\u{631}\u{615}", "\u{631}\u{306}",
+    "\u{631}\u{6DB}", "\u{635}\u{6DB}", "\u{637}\u{6DB}", "\u{6A1}\u{6DB}", "\u{641}", "\u{643}",
+    "\u{643}\u{6DB}", "\u{6AF}\u{6DB}", "\u{644}\u{306}", "\u{644}\u{6DB}", "\u{6C0}",
+    "\u{648}\u{306}", "\u{648}\u{313}", "\u{648}\u{670}", "\u{648}\u{302}", "\u{648}\u{6DB}",
+    "\u{649}\u{306}", "\u{67B}", "\u{62F}\u{302}", "\u{631}\u{302}", "\u{662}", "\u{663}",
+    "\u{664}", "\u{666}", "\u{669}", "\u{621}\u{348}", "\u{645}\u{348}", "\u{6F}\u{302}", "\u{73C}",
+    "\u{628}\u{6DB}", "\u{6AC}", "\u{754}", "\u{646}\u{615}", "\u{646}\u{306}", "\u{631}\u{654}",
+    "\u{697}\u{615}", "\u{633}\u{302}", "\u{308}", "\u{5F}", "\u{628}\u{654}", "\u{6A2}\u{6DB}",
+    "\u{645}\u{6DB}", "\u{649}\u{654}", "\u{62F}\u{324}\u{323}", "\u{635}\u{324}\u{323}", "\u{6AF}",
+    "\u{648}", "\u{632}\u{302}", "\u{628}\u{6E2}", "\u{649}\u{6DB}\u{6E2}", "\u{631}\u{306}\u{307}",
+    "\u{649}\u{306}\u{307}", "\u{64C}", "\u{324}", "\u{64D}", "\u{354}", "\u{355}", "\u{352}",
+    "\u{905}\u{946}", "\u{905}\u{93E}", "\u{930}\u{94D}\u{907}", "\u{90F}\u{945}", "\u{90F}\u{946}",
+    "\u{90F}\u{947}", "\u{905}\u{949}", "\u{905}\u{93E}\u{946}", "\u{905}\u{93E}\u{947}",
+    "\u{905}\u{93E}\u{948}", "\u{964}\u{964}", "\u{985}\u{9BE}", "\u{98B}\u{9C3}", "\u{39}",
+    "\u{983}", "\u{A05}\u{A3E}", "\u{A72}\u{A3F}", "\u{A72}\u{A40}", "\u{A73}\u{A41}",
+    "\u{A73}\u{A42}", "\u{A72}\u{A47}", "\u{A05}\u{A48}", "\u{A05}\u{A4C}", "\u{946}", "\u{94D}",
+    "\u{A85}\u{ABE}", "\u{A85}\u{AC5}", "\u{A85}\u{AC7}", "\u{A85}\u{AC8}", "\u{A85}\u{ABE}\u{AC5}",
+    "\u{A85}\u{ABE}\u{AC7}", "\u{A85}\u{ABE}\u{AC8}", "\u{93D}", "\u{941}", "\u{942}", "\u{968}",
+    "\u{969}", "\u{96A}", "\u{96E}", "\u{970}", "\u{B05}\u{B3E}", "\u{B89}\u{BB3}", "\u{B90}",
+    "\u{B88}", "\u{BA9}", "\u{BC6}\u{B88}", "\u{BC7}\u{B88}", "\u{BC6}\u{BB3}", "\u{BB3}",
+    "\u{B95}", "\u{B89}", "\u{B9A}", "\u{B88}\u{BC1}", "\u{B9A}\u{BC1}", "\u{B8E}", "\u{B85}",
+    "\u{BAF}", "\u{B9A}\u{BC2}", "\u{BAE}\u{BC0}", "\u{BF3}", "\u{B8E}\u{BB5}", "\u{BB7}",
+    "\u{BA8}\u{BC0}", "\u{C12}\u{C55}", "\u{C12}\u{C4C}", "\u{C30}\u{5BC}", "\u{C21}\u{323}",
+    "\u{C27}\u{5BC}", "\u{C2C}\u{323}", "\u{C35}\u{C41}", "\u{C35}\u{323}", "\u{C35}\u{C3E}",
+    "\u{C41}\u{C3E}", "\u{C43}\u{C3E}", "\u{C0B}\u{C3E}", "\u{C0C}\u{C3E}", "\u{C05}", "\u{C06}",
+    "\u{C07}", "\u{C12}", "\u{C1C}", "\u{C1E}", "\u{C23}", "\u{C2F}", "\u{C31}", "\u{C32}",
+    "\u{C8C}\u{CBE}", "\u{C67}", "\u{C68}", "\u{C6F}", "\u{D07}\u{D57}", "\u{B89}\u{D57}",
+    "\u{D28}\u{D41}", "\u{D0E}\u{D46}", "\u{D12}\u{D3E}", "\u{D12}\u{D57}", "\u{BA3}", "\u{D30}",
+    "\u{BB4}", "\u{BB6}", "\u{B9F}\u{BBF}", "\u{BBF}", "\u{D41}", "\u{D46}\u{D46}",
+    "\u{D28}\u{D4D}\u{D2E}", "\u{6F}\u{D30}\u{6F}", "\u{D1E}", "\u{D30}\u{D4D}",
+    "\u{D26}\u{D4D}\u{D30}", "\u{D28}\u{D4D}\u{D28}", "\u{D35}\u{D4D}\u{D30}", "\u{D28}\u{D4D}",
+    "\u{D39}\u{D4D}\u{D2E}", "\u{DE8}\u{DCF}", "\u{DA2}", "\u{DAF}", "\u{DE8}\u{DD3}", "\u{E02}",
+    "\u{E0A}", "\u{E0E}", "\u{E04}", "\u{E11}", "\u{E06}", "\u{E20}", "\u{30A}\u{E32}",
+    "\u{E40}\u{E40}", "\u{E32}", "\u{E08}", "\u{E22}", "\u{E1A}", "\u{E1B}", "\u{E1D}", "\u{E1E}",
+    "\u{E1F}", "\u{30A}\u{EB2}", "\u{E38}", "\u{E39}", "\u{E48}", "\u{E49}", "\u{E4A}", "\u{E4B}",
+    "\u{EAB}\u{E99}", "\u{EAB}\u{EA1}", "\u{F68}\u{F7C}\u{F7E}", "\u{F60}\u{F74}\u{F82}\u{F7F}",
+    "\u{F60}\u{F74}\u{F82}\u{F14}", "\u{F0B}", "\u{F0D}\u{F0D}", "\u{F1A}\u{F1A}", "\u{F1D}\u{F1D}",
+    "\u{F1A}\u{F1D}", "\u{325}", "\u{F62}", "\u{FB2}\u{F71}\u{F80}", "\u{FB3}\u{F71}\u{F80}",
+    "\u{F1D}\u{F1A}", "\u{5350}", "\u{534D}", "\u{1002}\u{102C}", "\u{6F}\u{102C}",
+    "\u{1015}\u{102C}", "\u{101E}\u{103C}", "\u{101E}\u{103C}\u{1031}\u{102C}\u{103A}",
+    "\u{104A}\u{104A}", "\u{1041}", "\u{1015}\u{103E}", "\u{1015}\u{102C}\u{103E}",
+    "\u{1003}\u{103E}", "\u{107D}\u{103E}", "\u{1002}\u{103E}", "\u{1083}\u{30A}", "\u{A786}",
+    "\u{1100}\u{1100}", "\u{1103}\u{1103}", "\u{1107}\u{1107}", "\u{1109}\u{1109}",
+    "\u{110C}\u{110C}", "\u{1102}\u{1100}", "\u{1102}\u{1102}", "\u{1102}\u{1103}",
+    "\u{1102}\u{1107}", "\u{1103}\u{1100}", "\u{1105}\u{1102}", "\u{1105}\u{1105}",
+    "\u{1105}\u{1112}", "\u{1105}\u{110B}", "\u{1106}\u{1107}", "\u{1106}\u{110B}",
+    "\u{1107}\u{1100}", "\u{1107}\u{1102}", "\u{1107}\u{1103}", "\u{1107}\u{1109}",
+    "\u{1107}\u{1109}\u{1100}", "\u{1107}\u{1109}\u{1103}", "\u{1107}\u{1109}\u{1107}",
+    "\u{1107}\u{1109}\u{1109}", "\u{1107}\u{1109}\u{110C}", "\u{1107}\u{110C}", "\u{1107}\u{110E}",
+    "\u{1107}\u{1110}", "\u{1107}\u{1111}", "\u{1107}\u{110B}", "\u{1107}\u{1107}\u{110B}",
+    "\u{1109}\u{1100}", "\u{1109}\u{1102}", "\u{1109}\u{1103}", "\u{1109}\u{1105}",
+    "\u{1109}\u{1106}", "\u{1109}\u{1107}", "\u{1109}\u{1107}\u{1100}", "\u{1109}\u{1109}\u{1109}",
+    "\u{1109}\u{110B}", "\u{1109}\u{110C}", "\u{1109}\u{110E}", "\u{1109}\u{110F}",
+    "\u{1109}\u{1110}", "\u{1109}\u{1111}", "\u{113C}\u{113C}", "\u{113E}\u{113E}",
+    "\u{110B}\u{1100}", "\u{110B}\u{1103}", "\u{110B}\u{1106}", "\u{110B}\u{1107}",
+    "\u{110B}\u{1109}", "\u{110B}\u{1140}", "\u{110B}\u{110B}", "\u{110B}\u{110C}",
+    "\u{110B}\u{110E}", "\u{110B}\u{1110}", "\u{110B}\u{1111}", "\u{110C}\u{110B}",
+    "\u{114E}\u{114E}", "\u{1150}\u{1150}", "\u{110E}\u{110F}", "\u{110E}\u{1112}",
+    "\u{1111}\u{1107}", "\u{1111}\u{110B}", "\u{1112}\u{1112}", "\u{1100}\u{1103}",
+    "\u{1102}\u{1109}", "\u{1102}\u{110C}", "\u{1102}\u{1112}", "\u{1103}\u{1105}",
+    "\u{1161}\u{4E28}", "\u{1163}\u{4E28}", "\u{1165}\u{4E28}", "\u{1167}\u{4E28}",
+    "\u{1169}\u{1161}", "\u{1169}\u{1161}\u{4E28}", "\u{1169}\u{4E28}", "\u{116E}\u{1165}",
+    "\u{116E}\u{1165}\u{4E28}", "\u{116E}\u{4E28}", "\u{30FC}", "\u{30FC}\u{4E28}", "\u{4E28}",
+    "\u{1161}\u{1169}", "\u{1161}\u{116E}", "\u{1163}\u{1169}", "\u{1163}\u{116D}",
+    "\u{1165}\u{1169}", "\u{1165}\u{116E}", "\u{1165}\u{30FC}", "\u{1167}\u{1169}",
+    "\u{1167}\u{116E}", "\u{1169}\u{1165}", "\u{1169}\u{1165}\u{4E28}", "\u{1169}\u{1167}\u{4E28}",
+    "\u{1169}\u{1169}", "\u{1169}\u{116E}", "\u{116D}\u{1163}", "\u{116D}\u{1163}\u{4E28}",
+    "\u{116D}\u{1169}", "\u{116D}\u{4E28}", "\u{116E}\u{1161}", "\u{116E}\u{1161}\u{4E28}",
+    "\u{116E}\u{1165}\u{30FC}", "\u{116E}\u{1167}\u{4E28}", "\u{116E}\u{116E}", "\u{1172}\u{1161}",
+    "\u{1172}\u{1165}", "\u{1172}\u{1165}\u{4E28}", "\u{1172}\u{1167}", "\u{1172}\u{1167}\u{4E28}",
+    "\u{1172}\u{116E}", "\u{1172}\u{4E28}", "\u{30FC}\u{116E}", "\u{30FC}\u{30FC}",
+    "\u{30FC}\u{4E28}\u{116E}", "\u{4E28}\u{1161}", "\u{4E28}\u{1163}", "\u{4E28}\u{1169}",
+    "\u{4E28}\u{116E}", "\u{4E28}\u{30FC}", "\u{4E28}\u{119E}", "\u{119E}\u{1165}",
+    "\u{119E}\u{116E}", "\u{119E}\u{4E28}", "\u{119E}\u{119E}", "\u{1161}\u{30FC}",
+    "\u{1163}\u{116E}", "\u{1167}\u{1163}", "\u{1169}\u{1163}", "\u{1169}\u{1163}\u{4E28}",
+    "\u{1100}", "\u{1100}\u{1109}", "\u{1102}", "\u{1103}", "\u{1105}", "\u{1105}\u{1100}",
+    "\u{1105}\u{1106}", "\u{1105}\u{1107}", "\u{1105}\u{1109}", "\u{1105}\u{1110}",
+    "\u{1105}\u{1111}", "\u{1106}", "\u{1107}", "\u{1109}", "\u{110B}", "\u{110C}", "\u{110E}",
+    "\u{110F}", "\u{1110}", "\u{1111}", "\u{1112}", "\u{1100}\u{1105}", "\u{1100}\u{1109}\u{1100}",
+    "\u{1102}\u{1140}", "\u{1102}\u{1110}", "\u{1105}\u{1100}\u{1109}", "\u{1105}\u{1103}",
+    "\u{1105}\u{1103}\u{1112}", "\u{1105}\u{1106}\u{1100}", "\u{1105}\u{1106}\u{1109}",
+    "\u{1105}\u{1107}\u{1109}", "\u{1105}\u{1107}\u{1112}", "\u{1105}\u{1107}\u{110B}",
+    "\u{1105}\u{1109}\u{1109}", "\u{1105}\u{1140}", "\u{1105}\u{110F}", "\u{1105}\u{1159}",
+    "\u{1106}\u{1100}", "\u{1106}\u{1105}", "\u{1106}\u{1109}", "\u{1106}\u{1109}\u{1109}",
+    "\u{1106}\u{1140}", "\u{1106}\u{110E}", "\u{1106}\u{1112}", "\u{1107}\u{1105}",
+    "\u{1107}\u{1112}", "\u{1140}", "\u{110B}\u{1100}\u{1100}", "\u{110B}\u{110F}", "\u{114C}",
+    "\u{1112}\u{1102}", "\u{1112}\u{1105}", "\u{1112}\u{1106}", "\u{1112}\u{1107}", "\u{1159}",
+    "\u{1100}\u{1102}", "\u{1100}\u{1107}", "\u{1100}\u{110E}", "\u{1100}\u{110F}",
+    "\u{1100}\u{1112}", "\u{548}", "\u{571}", "\u{44}", "\u{460}", "\u{34}", "\u{4C}", "\u{3D}",
+    "\u{394}", "\u{B7}\u{1401}", "\u{1401}\u{B7}", "\u{B7}\u{394}", "\u{394}\u{B7}",
+    "\u{B7}\u{1404}", "\u{1404}\u{B7}", "\u{B7}\u{1405}", "\u{1405}\u{B7}", "\u{B7}\u{1406}",
+    "\u{1406}\u{B7}", "\u{B7}\u{140A}", "\u{140A}\u{B7}", "\u{B7}\u{140B}", "\u{140B}\u{B7}",
+    "\u{1401}\u{1420}", "\u{394}\u{1420}", "\u{1405}\u{1420}", "\u{140A}\u{1420}", "\u{B7}\u{3E}",
+    "\u{B7}\u{56}", "\u{56}\u{B7}", "\u{B7}\u{245}", "\u{245}\u{B7}", "\u{B7}\u{1432}",
+    "\u{1432}\u{B7}", "\u{3E}\u{B7}", "\u{B7}\u{1434}", "\u{1434}\u{B7}", "\u{B7}\u{3C}",
+    "\u{3C}\u{B7}", "\u{B7}\u{1439}", "\u{1439}\u{B7}", "\u{B7}\u{1450}", "\u{B7}\u{55}",
+    "\u{55}\u{B7}", "\u{B7}\u{548}", "\u{548}\u{B7}", "\u{B7}\u{144F}", "\u{144F}\u{B7}",
+    "\u{1450}\u{B7}", "\u{B7}\u{1451}", "\u{1451}\u{B7}", "\u{B7}\u{1455}", "\u{1455}\u{B7}",
+    "\u{B7}\u{1456}", "\u{1456}\u{B7}", "\u{55}\u{27}", "\u{548}\u{27}", "\u{1450}\u{27}",
+    "\u{1455}\u{27}", "\u{62}\u{307}", "\u{B7}\u{146B}", "\u{146B}\u{B7}", "\u{B7}\u{50}",
+    "\u{70}\u{B7}", "\u{B7}\u{146E}", "\u{146E}\u{B7}", "\u{B7}\u{64}", "\u{64}\u{B7}",
+    "\u{B7}\u{1470}", "\u{1470}\u{B7}", "\u{B7}\u{62}", "\u{62}\u{B7}", "\u{B7}\u{62}\u{307}",
+    "\u{62}\u{307}\u{B7}", "\u{146B}\u{27}", "\u{50}\u{27}", "\u{64}\u{27}", "\u{62}\u{27}",
+    "\u{B7}\u{1489}", "\u{1489}\u{B7}", "\u{B7}\u{148B}", "\u{148B}\u{B7}", "\u{B7}\u{148C}",
+    "\u{148C}\u{B7}", "\u{B7}\u{4A}", "\u{4A}\u{B7}", "\u{B7}\u{148E}", "\u{148E}\u{B7}",
+    "\u{B7}\u{1490}", "\u{1490}\u{B7}", "\u{B7}\u{1491}", "\u{1491}\u{B7}", "\u{B7}\u{14A3}",
+    "\u{14A3}\u{B7}", "\u{B7}\u{393}", "\u{393}\u{B7}", "\u{B7}\u{14A6}", "\u{14A6}\u{B7}",
+    "\u{B7}\u{14A7}", "\u{14A7}\u{B7}", "\u{B7}\u{14A8}", "\u{14A8}\u{B7}", "\u{B7}\u{4C}",
+    "\u{B7}\u{14AB}", "\u{14AB}\u{B7}", "\u{B7}\u{14C0}", "\u{14C0}\u{B7}", "\u{B7}\u{14C7}",
+    "\u{14C7}\u{B7}", "\u{B7}\u{14C8}", "\u{14C8}\u{B7}", "\u{1421}", "\u{B7}\u{14D3}",
+    "\u{14D3}\u{B7}", "\u{B7}\u{14D5}", "\u{14D5}\u{B7}", "\u{B7}\u{14D6}", "\u{14D6}\u{B7}",
+    "\u{B7}\u{14D7}", "\u{14D7}\u{B7}", "\u{B7}\u{14D8}", "\u{14D8}\u{B7}", "\u{B7}\u{14DA}",
+    "\u{14DA}\u{B7}", "\u{B7}\u{14DB}", "\u{14DB}\u{B7}", "\u{B7}\u{14ED}", "\u{14ED}\u{B7}",
+    "\u{B7}\u{14EF}", "\u{14EF}\u{B7}", "\u{B7}\u{14F0}", "\u{14F0}\u{B7}", "\u{B7}\u{14F1}",
+    "\u{14F1}\u{B7}", "\u{B7}\u{14F2}", "\u{14F2}\u{B7}", "\u{B7}\u{14F4}", "\u{14F4}\u{B7}",
+    "\u{B7}\u{14F5}", "\u{14F5}\u{B7}", "\u{150B}\u{3C}", "\u{150B}\u{1455}", "\u{150B}\u{62}",
+    "\u{150B}\u{1490}", "\u{B7}\u{1510}", "\u{1510}\u{B7}", "\u{B7}\u{1511}", "\u{1511}\u{B7}",
+    "\u{B7}\u{1512}", "\u{1512}\u{B7}", "\u{B7}\u{1513}", "\u{1513}\u{B7}", "\u{B7}\u{1514}",
+    "\u{1514}\u{B7}", "\u{B7}\u{1515}", "\u{1515}\u{B7}", "\u{B7}\u{1516}", "\u{1516}\u{B7}",
+    "\u{B7}\u{34}", "\u{34}\u{B7}", "\u{B7}\u{1528}", "\u{1528}\u{B7}", "\u{B7}\u{1529}",
+    "\u{1529}\u{B7}", "\u{B7}\u{152A}", "\u{152A}\u{B7}", "\u{B7}\u{152B}", "\u{152B}\u{B7}",
+    "\u{B7}\u{152D}", "\u{152D}\u{B7}", "\u{B7}\u{152E}", "\u{152E}\u{B7}", "\u{1429}",
+    "\u{B7}\u{154C}", "\u{154C}\u{B7}", "\u{B7}\u{155A}", "\u{155A}\u{B7}", "\u{B7}\u{1567}",
+    "\u{1567}\u{B7}", "\u{1550}\u{146C}", "\u{1550}\u{50}", "\u{1550}\u{146E}", "\u{1550}\u{64}",
+    "\u{1550}\u{1470}", "\u{1550}\u{62}", "\u{1550}\u{62}\u{307}", "\u{1550}\u{1483}",
+    "\u{1595}\u{148A}", "\u{1595}\u{148B}", "\u{1595}\u{148C}", "\u{1595}\u{4A}",
+    "\u{1595}\u{148E}", "\u{1595}\u{1490}", "\u{1595}\u{1491}", "\u{2132}", "\u{A7FB}", "\u{2C6F}",
+    "\u{1490}", "\u{1489}", "\u{14D3}", "\u{14DA}", "\u{1543}", "\u{1546}", "\u{154A}", "\u{1B1}",
+    "\u{3A9}", "\u{1550}\u{146B}", "\u{1595}\u{1489}", "\u{1596}\u{148B}", "\u{1596}\u{148C}",
+    "\u{1596}\u{4A}", "\u{1596}\u{148E}", "\u{1596}\u{1490}", "\u{1596}\u{1491}", "\u{15A7}\u{B7}",
+    "\u{15A8}\u{B7}", "\u{15A9}\u{B7}", "\u{15AA}\u{B7}", "\u{15AB}\u{B7}", "\u{15AC}\u{B7}",
+    "\u{15AD}\u{B7}", "\u{16BD}", "\u{16BC}", "\u{2B}", "\u{1715}", "\u{2F}", "\u{17A2}", "\u{E34}",
+    "\u{E35}", "\u{E36}", "\u{E37}", "\u{E2F}", "\u{E5A}", "\u{E4F}", "\u{E5B}", "\u{1835}",
+    "\u{185C}", "\u{B7}\u{18B1}", "\u{B7}\u{18B4}", "\u{B7}\u{18B8}", "\u{B7}\u{18C0}",
+    "\u{B7}\u{14C2}", "\u{14C2}\u{B7}", "\u{B7}\u{14C3}", "\u{14C3}\u{B7}", "\u{B7}\u{14C4}",
+    "\u{14C4}\u{B7}", "\u{B7}\u{14C5}", "\u{14C5}\u{B7}", "\u{B7}\u{1543}", "\u{B7}\u{1546}",
+    "\u{B7}\u{1547}", "\u{B7}\u{1548}", "\u{B7}\u{1549}", "\u{B7}\u{154B}", "\u{18DF}\u{141E}",
+    "\u{141E}\u{18DF}", "\u{1543}\u{B7}", "\u{155E}\u{B7}", "\u{1566}\u{B7}", "\u{156B}\u{B7}",
+    "\u{1586}\u{B7}", "\u{1597}\u{B7}", "\u{460}\u{B7}", "\u{15F4}\u{B7}", "\u{161B}\u{B7}",
+    "\u{199E}", "\u{19B1}", "\u{1A45}", "\u{1AA8}\u{1AA8}", "\u{1AAA}\u{1AA8}", "\u{6DB}",
+    "\u{1B0D}", "\u{1B11}", "\u{1B28}", "\u{1B50}", "\u{1B5E}\u{1B5E}", "\u{1C3B}\u{1C3B}",
+    "\u{1C7E}\u{1C7E}", "\u{32B}", "\u{32E}", "\u{32D}", "\u{30E}", "\u{316}", "\u{1DD}\u{6F}",
+    "\u{7A}", "\u{28C}", "\u{1D18}", "\u{43B}", "\u{18D6}", "\u{BA}", "\u{75}\u{65}",
+    "\u{66}\u{334}", "\u{72}\u{6E}\u{334}", "\u{6E}\u{334}", "\u{72}\u{334}", "\u{27E}\u{334}",
+    "\u{73}\u{334}", "\u{74}\u{334}", "\u{7A}\u{334}", "\u{1D34}", "\u{70}\u{335}", "\u{75}\u{335}",
+    "\u{28A}\u{335}", "\u{24B}", "\u{1D4B}", "\u{1D4D}", "\u{18D4}", "\u{1646}", "\u{2DEC}",
+    "\u{AB51}", "\u{1EA3}", "\u{1FF4}", "\u{13EF}", "\u{2E}\u{2E}", "\u{2E}\u{2E}\u{2E}",
+    "\u{27}\u{27}\u{27}", "\u{21}\u{21}", "\u{3F}\u{3F}", "\u{3F}\u{21}", "\u{21}\u{3F}",
+    "\u{27}\u{27}\u{27}\u{27}", "\u{2D57}", "\u{2D42}", "\u{A770}", "\u{43}\u{20EB}", "\u{A3}",
+    "\u{72}\u{6E}\u{338}", "\u{52}\u{73}", "\u{57}\u{335}", "\u{64}\u{335}\u{331}",
+    "\u{54}\u{20EB}", "\u{6C}\u{74}", "\u{554}", "\u{61}\u{2F}\u{63}", "\u{61}\u{2F}\u{73}",
+    "\u{B0}\u{43}", "\u{63}\u{2F}\u{6F}", "\u{63}\u{2F}\u{75}", "\u{42D}", "\u{B0}\u{46}",
+    "\u{4E}\u{6F}", "\u{51}", "\u{54}\u{45}\u{4C}", "\u{27F}", "\u{5D0}", "\u{5D1}", "\u{5D2}",
+    "\u{5D3}", "\u{46}\u{41}\u{58}", "\u{A4E8}", "\u{A4F6}", "\u{16F00}", "\u{6C}\u{6C}\u{6C}",
+    "\u{6C}\u{56}", "\u{56}\u{6C}", "\u{56}\u{6C}\u{6C}", "\u{56}\u{6C}\u{6C}\u{6C}",
+    "\u{6C}\u{58}", "\u{58}\u{6C}", "\u{58}\u{6C}\u{6C}", "\u{69}\u{69}", "\u{69}\u{69}\u{69}",
+    "\u{69}\u{76}", "\u{76}\u{69}", "\u{76}\u{69}\u{69}", "\u{76}\u{69}\u{69}\u{69}",
+    "\u{69}\u{78}", "\u{78}\u{69}", "\u{78}\u{69}\u{69}", "\u{16CF}", "\u{16E8}", "\u{21B2}",
+    "\u{1F10E}", "\u{16DA}", "\u{16D0}", "\u{18E}", "\u{2B}\u{307}", "\u{5C}", "\u{6F}\u{6F}",
+    "\u{283}", "\u{283}\u{283}", "\u{283}\u{283}\u{283}", "\u{222E}\u{222E}",
+    "\u{222E}\u{222E}\u{222E}", "\u{2D}\u{307}", "\u{3D}\u{307}", "\u{3D}\u{307}\u{323}",
+    "\u{3D}\u{30A}", "\u{3D}\u{302}", "\u{3D}\u{306}", "\u{3D}\u{36B}", "\u{2261}", "\u{3C}\u{3C}",
+    "\u{3E}\u{3E}", "\u{1455}", "\u{1450}", "\u{102A8}", "\u{298}", "\u{A4D5}", "\u{2227}",
+    "\u{16DC}", "\u{16DE}", "\u{3C}\u{3C}\u{3C}", "\u{3E}\u{3E}\u{3E}", "\u{B7}\u{B7}\u{B7}",
+    "\u{2205}", "\u{2324}", "\u{276C}", "\u{276D}", "\u{303C}", "\u{394}\u{332}", "\u{16DC}\u{332}",
+    "\u{B0}\u{332}", "\u{229B}", "\u{54}\u{308}", "\u{2207}\u{308}", "\u{22C6}\u{308}",
+    "\u{B0}\u{308}", "\u{7E}\u{308}", "\u{1435}", "\u{2207}\u{334}", "\u{3C9}", "\u{61}\u{332}",
+    "\u{A793}\u{332}", "\u{69}\u{332}", "\u{3C9}\u{332}", "\u{2355}", "\u{234E}", "\u{234B}",
+    "\u{236D}", "\u{2081}\u{2080}", "\u{23FB}", "\u{263E}", "\u{5C}\u{5C}", "\u{2780}", "\u{2781}",
+    "\u{2782}", "\u{2783}", "\u{2784}", "\u{2785}", "\u{2786}", "\u{2787}", "\u{2788}", "\u{2789}",
+    "\u{28}\u{6C}\u{29}", "\u{28}\u{32}\u{29}", "\u{28}\u{33}\u{29}", "\u{28}\u{34}\u{29}",
+    "\u{28}\u{35}\u{29}", "\u{28}\u{36}\u{29}", "\u{28}\u{37}\u{29}", "\u{28}\u{38}\u{29}",
+    "\u{28}\u{39}\u{29}", "\u{28}\u{6C}\u{4F}\u{29}", "\u{28}\u{6C}\u{6C}\u{29}",
+    "\u{28}\u{6C}\u{32}\u{29}", "\u{28}\u{6C}\u{33}\u{29}", "\u{28}\u{6C}\u{34}\u{29}",
+    "\u{28}\u{6C}\u{35}\u{29}", "\u{28}\u{6C}\u{36}\u{29}", "\u{28}\u{6C}\u{37}\u{29}",
+    "\u{28}\u{6C}\u{38}\u{29}", "\u{28}\u{6C}\u{39}\u{29}", "\u{28}\u{32}\u{4F}\u{29}",
+    "\u{6C}\u{2E}", "\u{32}\u{2E}", "\u{33}\u{2E}", "\u{34}\u{2E}", "\u{35}\u{2E}", "\u{36}\u{2E}",
+    "\u{37}\u{2E}", "\u{38}\u{2E}", "\u{39}\u{2E}", "\u{6C}\u{4F}\u{2E}", "\u{6C}\u{6C}\u{2E}",
+    "\u{6C}\u{32}\u{2E}", "\u{6C}\u{33}\u{2E}", "\u{6C}\u{34}\u{2E}", "\u{6C}\u{35}\u{2E}",
+    "\u{6C}\u{36}\u{2E}", "\u{6C}\u{37}\u{2E}", "\u{6C}\u{38}\u{2E}", "\u{6C}\u{39}\u{2E}",
+    "\u{32}\u{4F}\u{2E}", "\u{28}\u{61}\u{29}", "\u{28}\u{62}\u{29}", "\u{28}\u{63}\u{29}",
+    "\u{28}\u{64}\u{29}", "\u{28}\u{65}\u{29}", "\u{28}\u{66}\u{29}", "\u{28}\u{67}\u{29}",
+    "\u{28}\u{68}\u{29}", "\u{28}\u{69}\u{29}", "\u{28}\u{6A}\u{29}", "\u{28}\u{6B}\u{29}",
+    "\u{28}\u{72}\u{6E}\u{29}", "\u{28}\u{6E}\u{29}", "\u{28}\u{6F}\u{29}", "\u{28}\u{70}\u{29}",
+    "\u{28}\u{71}\u{29}", "\u{28}\u{72}\u{29}", "\u{28}\u{73}\u{29}", "\u{28}\u{74}\u{29}",
+    "\u{28}\u{75}\u{29}", "\u{28}\u{76}\u{29}", "\u{28}\u{77}\u{29}", "\u{28}\u{78}\u{29}",
+    "\u{28}\u{79}\u{29}", "\u{28}\u{7A}\u{29}", "\u{A9}", "\u{2117}", "\u{AE}", "\u{24BE}",
+    "\u{1F10D}", "\u{2502}", "\u{250C}", "\u{251C}", "\u{220E}", "\u{258C}", "\u{2596}", "\u{2598}",
+    "\u{23E5}", "\u{22B3}", "\u{25B6}", "\u{102BC}", "\u{22B2}", "\u{233E}", "\u{2312}", "\u{25A1}",
+    "\u{1099E}", "\u{2CB6}", "\u{2388}", "\u{224F}", "\u{1D158}\u{1D165}",
+    "\u{1D158}\u{1D165}\u{1D16E}", "\u{28}", "\u{29}", "\u{7B}", "\u{7D}", "\u{F7}",
+    "\u{5C}\u{1455}", "\u{1450}\u{2F}", "\u{16D0}\u{16DA}", "\u{21C3}\u{21C2}", "\u{16D0}\u{21C2}",
+    "\u{21C3}\u{16DA}", "\u{2349}", "\u{2342}", "\u{233B}", "\u{102C0}", "\u{299A}",
+    "\u{3A}\u{2192}", "\u{2F}\u{304}", "\u{2297}", "\u{228D}", "\u{228E}", "\u{2293}", "\u{2294}",
+    "\u{283}\u{283}\u{283}\u{283}", "\u{2B}\u{30A}", "\u{2B}\u{302}", "\u{2B}\u{303}",
+    "\u{2B}\u{323}", "\u{2B}\u{330}", "\u{2B}\u{2082}", "\u{2D}\u{313}", "\u{2D}\u{323}",
+    "\u{78}\u{307}", "\u{2319}", "\u{2A1F}", "\u{2210}", "\u{7E}\u{307}", "\u{3D}\u{20F0}",
+    "\u{3A}\u{3A}\u{3D}", "\u{3D}\u{3D}", "\u{3D}\u{3D}\u{3D}", "\u{3E}\u{3C}", "\u{15D5}",
+    "\u{15D2}", "\u{1450}\u{1455}", "\u{2F}\u{2F}\u{2F}", "\u{2F}\u{2F}", "\u{219E}", "\u{219F}",
+    "\u{21A0}", "\u{21A1}", "\u{3BB}", "\u{3C7}", "\u{428}", "\u{448}", "\u{29F}", "\u{3EC}",
+    "\u{3D7}", "\u{2627}", "\u{16EF}", "\u{1DDF}", "\u{368}", "\u{36F}", "\u{363}", "\u{364}",
+    "\u{2D}\u{308}", "\u{7E}\u{323}", "\u{28}\u{28}", "\u{29}\u{29}", "\u{2235}", "\u{2234}",
+    "\u{2237}", "\u{61F}", "\u{61B}", "\u{B6}", "\u{4E5B}", "\u{4E5A}", "\u{4EBB}", "\u{5202}",
+    "\u{353E}", "\u{5140}", "\u{5C23}", "\u{5C22}", "\u{5DF3}", "\u{5E7A}", "\u{5F51}", "\u{5FC4}",
+    "\u{38FA}", "\u{624C}", "\u{6535}", "\u{65E1}", "\u{6B7A}", "\u{6BCD}", "\u{6C11}", "\u{6C35}",
+    "\u{6C3A}", "\u{706C}", "\u{722B}", "\u{4E2C}", "\u{72AD}", "\u{7F52}", "\u{793B}", "\u{7CF9}",
+    "\u{7F53}", "\u{8002}", "\u{8080}", "\u{8279}", "\u{864E}", "\u{8864}", "\u{8980}", "\u{897F}",
+    "\u{89C1}", "\u{8BA0}", "\u{8D1D}", "\u{8F66}", "\u{8FB6}", "\u{961D}", "\u{9485}", "\u{9577}",
+    "\u{9578}", "\u{957F}", "\u{95E8}", "\u{9752}", "\u{97E6}", "\u{9875}", "\u{98CE}", "\u{98DE}",
+    "\u{98DF}", "\u{98E0}", "\u{9963}", "\u{9A6C}", "\u{9B3C}", "\u{9C7C}", "\u{9EA6}", "\u{9EC4}",
+    "\u{6589}", "\u{9F50}", "\u{6B6F}", "\u{9F7F}", "\u{7ADC}", "\u{9F99}", "\u{4E80}", "\u{9F9F}",
+    "\u{4E59}", "\u{4E85}", "\u{4E8C}", "\u{4EA0}", "\u{4EBA}", "\u{513F}", "\u{5165}", "\u{516B}",
+    "\u{5182}", "\u{5196}", "\u{51AB}", "\u{51E0}", "\u{51F5}", "\u{5200}", "\u{529B}", "\u{52F9}",
+    "\u{5315}", "\u{531A}", "\u{5338}", "\u{5341}", "\u{535C}", "\u{5369}", "\u{5382}", "\u{53B6}",
+    "\u{53C8}", "\u{53E3}", "\u{571F}", "\u{5902}", "\u{590A}", "\u{5915}", "\u{5927}", "\u{5973}",
+    "\u{5B50}", "\u{5B80}", "\u{5BF8}", "\u{5C0F}", "\u{5C38}", "\u{5C6E}", "\u{5C71}", "\u{5DDB}",
+    "\u{5DE5}", "\u{5DF1}", "\u{5DFE}", "\u{5E72}", "\u{5E7F}", "\u{5EF4}", "\u{5EFE}", "\u{5F0B}",
+    "\u{5F13}", "\u{5F50}", "\u{5F61}", "\u{5F73}", "\u{5FC3}", "\u{6208}", "\u{6236}", "\u{624B}",
+    "\u{652F}", "\u{6534}", "\u{6587}", "\u{6597}", "\u{65A4}", "\u{65B9}", "\u{65E0}", "\u{65E5}",
+    "\u{66F0}", "\u{6708}", "\u{6728}", "\u{6B20}", "\u{6B62}", "\u{6B79}", "\u{6BB3}", "\u{6BCB}",
+    "\u{6BD4}", "\u{6BDB}", "\u{6C0F}", "\u{6C14}", "\u{6C34}", "\u{706B}", "\u{722A}", "\u{7236}",
+    "\u{723B}", "\u{723F}", "\u{7247}", "\u{7259}", "\u{725B}", "\u{72AC}", "\u{7384}", "\u{7389}",
+    "\u{74DC}", "\u{74E6}", "\u{7518}", "\u{751F}", "\u{7528}", "\u{7530}", "\u{758B}", "\u{7592}",
+    "\u{7676}", "\u{767D}", "\u{76AE}", "\u{76BF}", "\u{76EE}", "\u{77DB}", "\u{77E2}", "\u{77F3}",
+    "\u{793A}", "\u{79B8}", "\u{79BE}", "\u{7A74}", "\u{7ACB}", "\u{7AF9}", "\u{7C73}", "\u{7CF8}",
+    "\u{7F36}", "\u{7F51}", "\u{7F8A}", "\u{7FBD}", "\u{8001}", "\u{800C}", "\u{8012}", "\u{8033}",
+    "\u{807F}", "\u{8089}", "\u{81E3}", "\u{81EA}", "\u{81F3}", "\u{81FC}", "\u{820C}", "\u{821B}",
+    "\u{821F}", "\u{826E}", "\u{8272}", "\u{8278}", "\u{864D}", "\u{866B}", "\u{8840}", "\u{884C}",
+    "\u{8863}", "\u{897E}", "\u{898B}", "\u{89D2}", "\u{8A00}", "\u{8C37}", "\u{8C46}", "\u{8C55}",
+    "\u{8C78}", "\u{8C9D}", "\u{8D64}", "\u{8D70}", "\u{8DB3}", "\u{8EAB}", "\u{8ECA}", "\u{8F9B}",
+    "\u{8FB0}", "\u{8FB5}", "\u{9091}", "\u{9149}", "\u{91C6}", "\u{91CC}", "\u{91D1}", "\u{9580}",
+    "\u{961C}", "\u{96B6}", "\u{96B9}", "\u{96E8}", "\u{9751}", "\u{975E}", "\u{9762}", "\u{9769}",
+    "\u{97CB}", "\u{97ED}", "\u{97F3}", "\u{9801}", "\u{98A8}", "\u{98DB}", "\u{9996}", "\u{9999}",
+    "\u{99AC}", "\u{9AA8}", "\u{9AD8}", "\u{9ADF}", "\u{9B25}", "\u{9B2F}", "\u{9B32}", "\u{9B5A}",
+    "\u{9CE5}", "\u{9E75}", "\u{9E7F}", "\u{9EA5}", "\u{9EBB}", "\u{9EC3}", "\u{9ECD}", "\u{9ED1}",
+    "\u{9EF9}", "\u{9EFD}", "\u{9F0E}", "\u{9F13}", "\u{9F20}", "\u{9F3B}", "\u{9F4A}", "\u{9F52}",
+    "\u{9F8D}", "\u{9F9C}", "\u{9FA0}", "\u{2F3}", "\u{20B8}", "\u{27E6}", "\u{27E7}", "\u{309}",
+    "\u{5344}", "\u{5345}", "\u{FF9E}", "\u{FF9F}", "\u{3078}", "\u{1161}", "\u{1163}", "\u{1165}",
+    "\u{1167}", "\u{1169}", "\u{116D}", "\u{116E}", "\u{1172}", "\u{1160}", "\u{119E}",
+    "\u{28}\u{1100}\u{29}", "\u{28}\u{1102}\u{29}", "\u{28}\u{1103}\u{29}", "\u{28}\u{1105}\u{29}",
+    "\u{28}\u{1106}\u{29}", "\u{28}\u{1107}\u{29}", "\u{28}\u{1109}\u{29}", "\u{28}\u{110B}\u{29}",
+    "\u{28}\u{110C}\u{29}", "\u{28}\u{110E}\u{29}", "\u{28}\u{110F}\u{29}", "\u{28}\u{1110}\u{29}",
+    "\u{28}\u{1111}\u{29}", "\u{28}\u{1112}\u{29}", "\u{28}\u{AC00}\u{29}", "\u{28}\u{B098}\u{29}",
+    "\u{28}\u{B2E4}\u{29}", "\u{28}\u{B77C}\u{29}", "\u{28}\u{B9C8}\u{29}", "\u{28}\u{BC14}\u{29}",
+    "\u{28}\u{C0AC}\u{29}", "\u{28}\u{C544}\u{29}", "\u{28}\u{C790}\u{29}", "\u{28}\u{CC28}\u{29}",
+    "\u{28}\u{CE74}\u{29}", "\u{28}\u{D0C0}\u{29}", "\u{28}\u{D30C}\u{29}", "\u{28}\u{D558}\u{29}",
+    "\u{28}\u{C8FC}\u{29}", "\u{28}\u{C624}\u{C804}\u{29}", "\u{28}\u{C624}\u{D6C4}\u{29}",
+    "\u{28}\u{30FC}\u{29}", "\u{28}\u{4E8C}\u{29}", "\u{28}\u{4E09}\u{29}", "\u{28}\u{56DB}\u{29}",
+    "\u{28}\u{4E94}\u{29}", "\u{28}\u{516D}\u{29}", "\u{28}\u{4E03}\u{29}", "\u{28}\u{516B}\u{29}",
+    "\u{28}\u{4E5D}\u{29}", "\u{28}\u{5341}\u{29}", "\u{28}\u{6708}\u{29}", "\u{28}\u{706B}\u{29}",
+    "\u{28}\u{6C34}\u{29}", "\u{28}\u{6728}\u{29}", "\u{28}\u{91D1}\u{29}", "\u{28}\u{571F}\u{29}",
+    "\u{28}\u{65E5}\u{29}", "\u{28}\u{682A}\u{29}", "\u{28}\u{6709}\u{29}", "\u{28}\u{793E}\u{29}",
+    "\u{28}\u{540D}\u{29}", "\u{28}\u{7279}\u{29}", "\u{28}\u{8CA1}\u{29}", "\u{28}\u{795D}\u{29}",
+    "\u{28}\u{52B4}\u{29}", "\u{28}\u{4EE3}\u{29}", "\u{28}\u{547C}\u{29}", "\u{28}\u{5B66}\u{29}",
+    "\u{28}\u{76E3}\u{29}", "\u{28}\u{4F01}\u{29}", "\u{28}\u{8CC7}\u{29}", "\u{28}\u{5354}\u{29}",
+    "\u{28}\u{796D}\u{29}", "\u{28}\u{4F11}\u{29}", "\u{28}\u{81EA}\u{29}", "\u{28}\u{81F3}\u{29}",
+    "\u{6C}\u{6708}", "\u{32}\u{6708}", "\u{33}\u{6708}", "\u{34}\u{6708}", "\u{35}\u{6708}",
+    "\u{36}\u{6708}", "\u{37}\u{6708}", "\u{38}\u{6708}", "\u{39}\u{6708}", "\u{6C}\u{4F}\u{6708}",
+    "\u{6C}\u{6C}\u{6708}", "\u{6C}\u{32}\u{6708}", "\u{4F}\u{70B9}", "\u{6C}\u{70B9}",
+    "\u{32}\u{70B9}", "\u{33}\u{70B9}", "\u{34}\u{70B9}", "\u{35}\u{70B9}", "\u{36}\u{70B9}",
+    "\u{37}\u{70B9}", "\u{38}\u{70B9}", "\u{39}\u{70B9}", "\u{6C}\u{4F}\u{70B9}",
+    "\u{6C}\u{6C}\u{70B9}", "\u{6C}\u{32}\u{70B9}", "\u{6C}\u{33}\u{70B9}", "\u{6C}\u{34}\u{70B9}",
+    "\u{6C}\u{35}\u{70B9}", "\u{6C}\u{36}\u{70B9}", "\u{6C}\u{37}\u{70B9}", "\u{6C}\u{38}\u{70B9}",
+    "\u{6C}\u{39}\u{70B9}", "\u{32}\u{4F}\u{70B9}", "\u{32}\u{6C}\u{70B9}", "\u{32}\u{32}\u{70B9}",
+    "\u{32}\u{33}\u{70B9}", "\u{32}\u{34}\u{70B9}", "\u{6C}\u{65E5}", "\u{32}\u{65E5}",
+    "\u{33}\u{65E5}", "\u{34}\u{65E5}", "\u{35}\u{65E5}", "\u{36}\u{65E5}", "\u{37}\u{65E5}",
+    "\u{38}\u{65E5}", "\u{39}\u{65E5}", "\u{6C}\u{4F}\u{65E5}", "\u{6C}\u{6C}\u{65E5}",
+    "\u{6C}\u{32}\u{65E5}", "\u{6C}\u{33}\u{65E5}", "\u{6C}\u{34}\u{65E5}", "\u{6C}\u{35}\u{65E5}",
+    "\u{6C}\u{36}\u{65E5}", "\u{6C}\u{37}\u{65E5}", "\u{6C}\u{38}\u{65E5}", "\u{6C}\u{39}\u{65E5}",
+    "\u{32}\u{4F}\u{65E5}", "\u{32}\u{6C}\u{65E5}", "\u{32}\u{32}\u{65E5}", "\u{32}\u{33}\u{65E5}",
+    "\u{32}\u{34}\u{65E5}", "\u{32}\u{35}\u{65E5}", "\u{32}\u{36}\u{65E5}", "\u{32}\u{37}\u{65E5}",
+    "\u{32}\u{38}\u{65E5}", "\u{32}\u{39}\u{65E5}", "\u{33}\u{4F}\u{65E5}", "\u{33}\u{6C}\u{65E5}",
+    "\u{363D}", "\u{3588}", "\u{3B3B}", "\u{4F75}", "\u{5024}", "\u{5553}", "\u{5861}", "\u{58AB}",
+    "\u{5AAF}", "\u{5E21}", "\u{3B3A}", "\u{3A41}", "\u{403F}", "\u{665A}", "\u{3ADA}", "\u{4443}",
+    "\u{676E}", "\u{3BA3}", "\u{699D}", "\u{6E88}", "\u{7814}", "\u{7D55}", "\u{670C}", "\u{6710}",
+    "\u{670F}", "\u{3B35}", "\u{6713}", "\u{6718}", "\u{80FC}", "\u{6723}", "\u{848D}", "\u{8637}",
+    "\u{46B6}", "\u{8A2E}", "\u{8B86}", "\u{8C5C}", "\u{8D7F}", "\u{8DE5}", "\u{8E97}", "\u{8EFF}",
+    "\u{90CE}", "\u{93AD}", "\u{96B7}", "\u{9E42}", "\u{4039}", "\u{A2CD}", "\u{A0C0}", "\u{A04A}",
+    "\u{A458}", "\u{A132}", "\u{A050}", "\u{A3C2}", "\u{A3BF}", "\u{A2B1}", "\u{A259}", "\u{A3AB}",
+    "\u{A3B5}", "\u{1660}", "\u{15E1}", "\u{2E}\u{2C}", "\u{2D}\u{2E}", "\u{42A}\u{6C}",
+    "\u{2C9}\u{62}\u{69}", "\u{20E9}", "\u{4F}\u{4F}", "\u{16B9}", "\u{2A1}", "\u{A6F3}\u{A6F3}",
+    "\u{2EB}", "\u{54}\u{33}", "\u{74}\u{21D}", "\u{41}\u{41}", "\u{61}\u{61}", "\u{41}\u{4F}",
+    "\u{61}\u{6F}", "\u{41}\u{55}", "\u{61}\u{75}", "\u{41}\u{56}", "\u{61}\u{76}", "\u{41}\u{59}",
+    "\u{61}\u{79}", "\u{77}\u{326}", "\u{74}\u{66}", "\u{26}", "\u{A779}", "\u{A727}", "\u{10412}",
+    "\u{1043A}", "\u{29A}", "\u{A4E4}", "\u{A64C}", "\u{245}\u{338}", "\u{964}", "\u{1103}\u{1106}",
+    "\u{1103}\u{1107}", "\u{1103}\u{1109}", "\u{1103}\u{110C}", "\u{1105}\u{1100}\u{1100}",
+    "\u{1105}\u{1103}\u{1103}", "\u{1105}\u{1107}\u{1107}", "\u{1105}\u{110C}", "\u{1106}\u{1103}",
+    "\u{1107}\u{1109}\u{1110}", "\u{1107}\u{110F}", "\u{1109}\u{1109}\u{1107}", "\u{110B}\u{1105}",
+    "\u{110B}\u{1112}", "\u{110C}\u{110C}\u{1112}", "\u{1110}\u{1110}", "\u{1111}\u{1112}",
+    "\u{1112}\u{1109}", "\u{1159}\u{1159}", "\u{2C3F}", "\u{A99D}", "\u{A9D0}", "\u{AA01}",
+    "\u{AA23}", "\u{254}\u{338}", "\u{1DD}\u{6F}\u{338}", "\u{1DD}\u{6F}\u{335}", "\u{459}",
+    "\u{254}\u{65}", "\u{75}\u{6F}", "\u{1D05}", "\u{280}", "\u{6F}\u{31B}", "\u{1D00}", "\u{1D0A}",
+    "\u{1D07}", "\u{242}", "\u{2C76}", "\u{1169}\u{1167}", "\u{1169}\u{1169}\u{4E28}",
+    "\u{116D}\u{1161}", "\u{116D}\u{1161}\u{4E28}", "\u{116D}\u{1165}", "\u{116E}\u{1167}",
+    "\u{116E}\u{4E28}\u{4E28}", "\u{1172}\u{1161}\u{4E28}", "\u{1172}\u{1169}", "\u{30FC}\u{1161}",
+    "\u{30FC}\u{1165}", "\u{30FC}\u{1165}\u{4E28}", "\u{30FC}\u{1169}", "\u{4E28}\u{1163}\u{1169}",
+    "\u{4E28}\u{1163}\u{4E28}", "\u{4E28}\u{1167}", "\u{4E28}\u{1167}\u{4E28}",
+    "\u{4E28}\u{1169}\u{4E28}", "\u{4E28}\u{116D}", "\u{4E28}\u{1172}", "\u{4E28}\u{4E28}",
+    "\u{119E}\u{1161}", "\u{119E}\u{1165}\u{4E28}", "\u{1102}\u{1105}", "\u{1102}\u{110E}",
+    "\u{1103}\u{1103}\u{1107}", "\u{1103}\u{1109}\u{1100}", "\u{1103}\u{110E}", "\u{1103}\u{1110}",
+    "\u{1105}\u{1100}\u{1112}", "\u{1105}\u{1105}\u{110F}", "\u{1105}\u{1106}\u{1112}",
+    "\u{1105}\u{1107}\u{1103}", "\u{1105}\u{1107}\u{1111}", "\u{1105}\u{114C}",
+    "\u{1105}\u{1159}\u{1112}", "\u{1106}\u{1102}", "\u{1106}\u{1102}\u{1102}", "\u{1106}\u{1106}",
+    "\u{1106}\u{1107}\u{1109}", "\u{1106}\u{110C}", "\u{1107}\u{1105}\u{1111}", "\u{1107}\u{1106}",
+    "\u{1109}\u{1107}\u{110B}", "\u{1109}\u{1109}\u{1100}", "\u{1109}\u{1109}\u{1103}",
+    "\u{1109}\u{1140}", "\u{1140}\u{1107}", "\u{1140}\u{1107}\u{110B}", "\u{114C}\u{1106}",
+    "\u{114C}\u{1112}", "\u{110C}\u{1107}", "\u{110C}\u{1107}\u{1107}", "\u{1111}\u{1109}",
+    "\u{1111}\u{1110}", "\u{8C48}", "\u{66F4}", "\u{8CC8}", "\u{6ED1}", "\u{4E32}", "\u{53E5}",
+    "\u{5951}", "\u{5587}", "\u{5948}", "\u{61F6}", "\u{7669}", "\u{7F85}", "\u{863F}", "\u{87BA}",
+    "\u{88F8}", "\u{908F}", "\u{6A02}", "\u{6D1B}", "\u{70D9}", "\u{73DE}", "\u{843D}", "\u{916A}",
+    "\u{99F1}", "\u{4E82}", "\u{5375}", "\u{6B04}", "\u{721B}", "\u{862D}", "\u{9E1E}", "\u{5D50}",
+    "\u{6FEB}", "\u{85CD}", "\u{8964}", "\u{62C9}", "\u{81D8}", "\u{881F}", "\u{5ECA}", "\u{6717}",
+    "\u{6D6A}", "\u{72FC}", "\u{4F86}", "\u{51B7}", "\u{52DE}", "\u{64C4}", "\u{6AD3}", "\u{7210}",
+    "\u{76E7}", "\u{8606}", "\u{865C}", "\u{8DEF}", "\u{9732}", "\u{9B6F}", "\u{9DFA}", "\u{788C}",
+    "\u{797F}", "\u{7DA0}", "\u{83C9}", "\u{9304}", "\u{8AD6}", "\u{58DF}", "\u{5F04}", "\u{7C60}",
+    "\u{807E}", "\u{7262}", "\u{78CA}", "\u{8CC2}", "\u{96F7}", "\u{58D8}", "\u{5C62}", "\u{6A13}",
+    "\u{6DDA}", "\u{6F0F}", "\u{7D2F}", "\u{7E37}", "\u{964B}", "\u{52D2}", "\u{808B}", "\u{51DC}",
+    "\u{51CC}", "\u{7A1C}", "\u{7DBE}", "\u{83F1}", "\u{9675}", "\u{8B80}", "\u{62CF}", "\u{8AFE}",
+    "\u{4E39}", "\u{5BE7}", "\u{6012}", "\u{7387}", "\u{7570}", "\u{5317}", "\u{78FB}", "\u{4FBF}",
+    "\u{5FA9}", "\u{4E0D}", "\u{6CCC}", "\u{6578}", "\u{7D22}", "\u{53C3}", "\u{585E}", "\u{7701}",
+    "\u{8449}", "\u{8AAA}", "\u{6BBA}", "\u{6C88}", "\u{62FE}", "\u{82E5}", "\u{63A0}", "\u{7565}",
+    "\u{4EAE}", "\u{5169}", "\u{51C9}", "\u{6881}", "\u{7CE7}", "\u{826F}", "\u{8AD2}", "\u{91CF}",
+    "\u{52F5}", "\u{5442}", "\u{5EEC}", "\u{65C5}", "\u{6FFE}", "\u{792A}", "\u{95AD}", "\u{9A6A}",
+    "\u{9E97}", "\u{9ECE}", "\u{66C6}", "\u{6B77}", "\u{8F62}", "\u{5E74}", "\u{6190}", "\u{6200}",
+    "\u{649A}", "\u{6F23}", "\u{7149}", "\u{7489}", "\u{79CA}", "\u{7DF4}", "\u{806F}", "\u{8F26}",
+    "\u{84EE}", "\u{9023}", "\u{934A}", "\u{5217}", "\u{52A3}", "\u{54BD}", "\u{70C8}", "\u{88C2}",
+    "\u{5EC9}", "\u{5FF5}", "\u{637B}", "\u{6BAE}", "\u{7C3E}", "\u{7375}", "\u{4EE4}", "\u{56F9}",
+    "\u{5DBA}", "\u{601C}", "\u{73B2}", "\u{7469}", "\u{7F9A}", "\u{8046}", "\u{9234}", "\u{96F6}",
+    "\u{9748}", "\u{9818}", "\u{4F8B}", "\u{79AE}", "\u{91B4}", "\u{60E1}", "\u{4E86}", "\u{50DA}",
+    "\u{5BEE}", "\u{5C3F}", "\u{6599}", "\u{71CE}", "\u{7642}", "\u{84FC}", "\u{907C}", "\u{6688}",
+    "\u{962E}", "\u{5289}", "\u{677B}", "\u{67F3}", "\u{6D41}", "\u{6E9C}", "\u{7409}", "\u{7559}",
+    "\u{786B}", "\u{7D10}", "\u{985E}", "\u{516D}", "\u{622E}", "\u{9678}", "\u{502B}", "\u{5D19}",
+    "\u{6DEA}", "\u{8F2A}", "\u{5F8B}", "\u{6144}", "\u{6817}", "\u{9686}", "\u{5229}", "\u{540F}",
+    "\u{5C65}", "\u{6613}", "\u{674E}", "\u{68A8}", "\u{6CE5}", "\u{7406}", "\u{75E2}", "\u{7F79}",
+    "\u{88CF}", "\u{88E1}", "\u{96E2}", "\u{533F}", "\u{6EBA}", "\u{541D}", "\u{71D0}", "\u{7498}",
+    "\u{85FA}", "\u{96A3}", "\u{9C57}", "\u{9E9F}", "\u{6797}", "\u{6DCB}", "\u{81E8}", "\u{7B20}",
+    "\u{7C92}", "\u{72C0}", "\u{7099}", "\u{8B58}", "\u{4EC0}", "\u{8336}", "\u{523A}", "\u{5207}",
+    "\u{5EA6}", "\u{62D3}", "\u{7CD6}", "\u{5B85}", "\u{6D1E}", "\u{66B4}", "\u{8F3B}", "\u{964D}",
+    "\u{5ED3}", "\u{55C0}", "\u{585A}", "\u{6674}", "\u{51DE}", "\u{732A}", "\u{76CA}", "\u{793C}",
+    "\u{795E}", "\u{7965}", "\u{798F}", "\u{9756}", "\u{7CBE}", "\u{8612}", "\u{8AF8}", "\u{9038}",
+    "\u{90FD}", "\u{98EF}", "\u{98FC}", "\u{9928}", "\u{9DB4}", "\u{4FAE}", "\u{50E7}", "\u{514D}",
+    "\u{52C9}", "\u{52E4}", "\u{5351}", "\u{559D}", "\u{5606}", "\u{5668}", "\u{5840}", "\u{58A8}",
+    "\u{5C64}", "\u{6094}", "\u{6168}", "\u{618E}", "\u{61F2}", "\u{654F}", "\u{65E2}", "\u{6691}",
+    "\u{6885}", "\u{6D77}", "\u{6E1A}", "\u{6F22}", "\u{716E}", "\u{7422}", "\u{7891}", "\u{793E}",
+    "\u{7949}", "\u{7948}", "\u{7950}", "\u{7956}", "\u{795D}", "\u{798D}", "\u{798E}", "\u{7A40}",
+    "\u{7A81}", "\u{7BC0}", "\u{7E09}", "\u{7E41}", "\u{7F72}", "\u{8005}", "\u{81ED}", "\u{8457}",
+    "\u{8910}", "\u{8996}", "\u{8B01}", "\u{8B39}", "\u{8CD3}", "\u{8D08}", "\u{96E3}", "\u{97FF}",
+    "\u{983B}", "\u{6075}", "\u{242EE}", "\u{8218}", "\u{4E26}", "\u{51B5}", "\u{5168}", "\u{4F80}",
+    "\u{5145}", "\u{5180}", "\u{52C7}", "\u{52FA}", "\u{5555}", "\u{5599}", "\u{55E2}", "\u{58B3}",
+    "\u{5944}", "\u{5954}", "\u{5A62}", "\u{5B28}", "\u{5ED2}", "\u{5ED9}", "\u{5F69}", "\u{5FAD}",
+    "\u{60D8}", "\u{614E}", "\u{6108}", "\u{6160}", "\u{6234}", "\u{63C4}", "\u{641C}", "\u{6452}",
+    "\u{6556}", "\u{671B}", "\u{6756}", "\u{6EDB}", "\u{6ECB}", "\u{701E}", "\u{77A7}", "\u{7235}",
+    "\u{72AF}", "\u{7471}", "\u{7506}", "\u{753B}", "\u{761D}", "\u{761F}", "\u{76DB}", "\u{76F4}",
+    "\u{774A}", "\u{7740}", "\u{78CC}", "\u{7AB1}", "\u{7C7B}", "\u{7D5B}", "\u{7F3E}", "\u{8352}",
+    "\u{83EF}", "\u{8779}", "\u{8941}", "\u{8986}", "\u{8ABF}", "\u{8ACB}", "\u{8AED}", "\u{8B8A}",
+    "\u{8F38}", "\u{9072}", "\u{9199}", "\u{9276}", "\u{967C}", "\u{97DB}", "\u{980B}", "\u{9B12}",
+    "\u{2284A}", "\u{22844}", "\u{233D5}", "\u{3B9D}", "\u{4018}", "\u{25249}", "\u{25CD0}",
+    "\u{27ED3}", "\u{9F43}", "\u{9F8E}", "\u{66}\u{66}", "\u{66}\u{69}", "\u{66}\u{6C}",
+    "\u{66}\u{66}\u{69}", "\u{66}\u{66}\u{6C}", "\u{73}\u{74}", "\u{574}\u{576}", "\u{574}\u{565}",
+    "\u{574}\u{56B}", "\u{57E}\u{576}", "\u{574}\u{56D}", "\u{5E2}", "\u{5D4}", "\u{5DB}",
+    "\u{5DC}", "\u{5DD}", "\u{5E8}", "\u{5EA}", "\u{FB2A}", "\u{FB2C}", "\u{FB2E}", "\u{FB1D}",
+    "\u{5D0}\u{5DC}", "\u{671}", "\u{680}", "\u{67A}", "\u{67F}", "\u{6A6}", "\u{684}", "\u{683}",
+    "\u{686}", "\u{687}", "\u{68D}", "\u{68C}", "\u{6B3}", "\u{6B1}", "\u{6D3}", "\u{6C5}",
+    "\u{649}\u{674}\u{6C}", "\u{649}\u{674}\u{6F}", "\u{649}\u{674}\u{648}",
+    "\u{649}\u{674}\u{648}\u{313}", "\u{649}\u{674}\u{648}\u{306}", "\u{649}\u{674}\u{648}\u{670}",
+    "\u{649}\u{674}\u{67B}", "\u{649}\u{674}\u{649}", "\u{649}\u{674}\u{62C}",
+    "\u{649}\u{674}\u{62D}", "\u{649}\u{674}\u{645}", "\u{628}\u{62C}", "\u{628}\u{62D}",
+    "\u{628}\u{62E}", "\u{628}\u{645}", "\u{628}\u{649}", "\u{62A}\u{62C}", "\u{62A}\u{62D}",
+    "\u{62A}\u{62E}", "\u{62A}\u{645}", "\u{62A}\u{649}", "\u{649}\u{6DB}\u{62C}",
+    "\u{649}\u{6DB}\u{645}", "\u{649}\u{6DB}\u{649}", "\u{62C}\u{62D}", "\u{62C}\u{645}",
+    "\u{62D}\u{62C}", "\u{62D}\u{645}", "\u{62E}\u{62C}", "\u{62E}\u{62D}", "\u{62E}\u{645}",
+    "\u{633}\u{62C}", "\u{633}\u{62D}", "\u{633}\u{62E}", "\u{633}\u{645}", "\u{635}\u{62D}",
+    "\u{635}\u{645}", "\u{636}\u{62C}", "\u{636}\u{62D}", "\u{636}\u{62E}", "\u{636}\u{645}",
+    "\u{637}\u{62D}", "\u{637}\u{645}", "\u{638}\u{645}", "\u{639}\u{62C}", "\u{639}\u{645}",
+    "\u{63A}\u{62C}", "\u{63A}\u{645}", "\u{641}\u{62C}", "\u{641}\u{62D}", "\u{641}\u{62E}",
+    "\u{641}\u{645}", "\u{641}\u{649}", "\u{642}\u{62D}", "\u{642}\u{645}", "\u{642}\u{649}",
+    "\u{643}\u{6C}", "\u{643}\u{62C}", "\u{643}\u{62D}", "\u{643}\u{62E}", "\u{643}\u{644}",
+    "\u{643}\u{645}", "\u{643}\u{649}", "\u{644}\u{62C}", "\u{644}\u{62D}", "\u{644}\u{62E}",
+    "\u{644}\u{645}", "\u{644}\u{649}", "\u{645}\u{62C}", "\u{645}\u{62D}", "\u{645}\u{62E}",
+    "\u{645}\u{645}", "\u{645}\u{649}", "\u{646}\u{62D}", "\u{646}\u{62E}", "\u{646}\u{645}",
+    "\u{646}\u{649}", "\u{6F}\u{62C}", "\u{6F}\u{645}", "\u{6F}\u{649}", "\u{649}\u{62C}",
+    "\u{649}\u{62D}", "\u{649}\u{62E}", "\u{649}\u{645}", "\u{649}\u{649}", "\u{630}\u{670}",
+    "\u{631}\u{670}", "\u{649}\u{670}", "\u{FE72}\u{651}", "\u{FE74}\u{651}", "\u{FE76}\u{651}",
+    "\u{FE78}\u{651}", "\u{FE7A}\u{651}", "\u{FE7C}\u{670}", "\u{649}\u{674}\u{631}",
+    "\u{649}\u{674}\u{632}", "\u{649}\u{674}\u{646}", "\u{628}\u{631}", "\u{628}\u{632}",
+    "\u{628}\u{646}", "\u{62A}\u{631}", "\u{62A}\u{632}", "\u{62A}\u{646}", "\u{649}\u{6DB}\u{631}",
+    "\u{649}\u{6DB}\u{632}", "\u{649}\u{6DB}\u{646}", "\u{645}\u{6C}", "\u{646}\u{631}",
+    "\u{646}\u{632}", "\u{646}\u{646}", "\u{649}\u{631}", "\u{649}\u{632}", "\u{649}\u{646}",
+    "\u{649}\u{674}\u{62E}", "\u{628}\u{6F}", "\u{62A}\u{6F}", "\u{635}\u{62E}", "\u{644}\u{6F}",
+    "\u{646}\u{6F}", "\u{6F}\u{670}", "\u{649}\u{6F}", "\u{649}\u{6DB}\u{6F}", "\u{633}\u{6F}",
+    "\u{633}\u{6DB}\u{645}", "\u{633}\u{6DB}\u{6F}", "\u{FE77}\u{651}", "\u{FE79}\u{651}",
+    "\u{FE7B}\u{651}", "\u{637}\u{649}", "\u{639}\u{649}", "\u{63A}\u{649}", "\u{633}\u{649}",
+    "\u{633}\u{6DB}\u{649}", "\u{62D}\u{649}", "\u{62C}\u{649}", "\u{62E}\u{649}", "\u{635}\u{649}",
+    "\u{636}\u{649}", "\u{633}\u{6DB}\u{62C}", "\u{633}\u{6DB}\u{62D}", "\u{633}\u{6DB}\u{62E}",
+    "\u{633}\u{6DB}\u{631}", "\u{633}\u{631}", "\u{635}\u{631}", "\u{636}\u{631}", "\u{6C}\u{30B}",
+    "\u{62A}\u{62C}\u{645}", "\u{62A}\u{62D}\u{62C}", "\u{62A}\u{62D}\u{645}",
+    "\u{62A}\u{62E}\u{645}", "\u{62A}\u{645}\u{62C}", "\u{62A}\u{645}\u{62D}",
+    "\u{62A}\u{645}\u{62E}", "\u{62C}\u{645}\u{62D}", "\u{62D}\u{645}\u{649}",
+    "\u{633}\u{62D}\u{62C}", "\u{633}\u{62C}\u{62D}", "\u{633}\u{62C}\u{649}",
+    "\u{633}\u{645}\u{62D}", "\u{633}\u{645}\u{62C}", "\u{633}\u{645}\u{645}",
+    "\u{635}\u{62D}\u{62D}", "\u{635}\u{645}\u{645}", "\u{633}\u{6DB}\u{62D}\u{645}",
+    "\u{633}\u{6DB}\u{62C}\u{649}", "\u{633}\u{6DB}\u{645}\u{62E}", "\u{633}\u{6DB}\u{645}\u{645}",
+    "\u{636}\u{62D}\u{649}", "\u{636}\u{62E}\u{645}", "\u{637}\u{645}\u{62D}",
+    "\u{637}\u{645}\u{645}", "\u{637}\u{645}\u{649}", "\u{639}\u{62C}\u{645}",
+    "\u{639}\u{645}\u{645}", "\u{639}\u{645}\u{649}", "\u{63A}\u{645}\u{645}",
+    "\u{63A}\u{645}\u{649}", "\u{641}\u{62E}\u{645}", "\u{642}\u{645}\u{62D}",
+    "\u{642}\u{645}\u{645}", "\u{644}\u{62D}\u{645}", "\u{644}\u{62D}\u{649}",
+    "\u{644}\u{62C}\u{62C}", "\u{644}\u{62E}\u{645}", "\u{644}\u{645}\u{62D}",
+    "\u{645}\u{62D}\u{62C}", "\u{645}\u{62D}\u{645}", "\u{645}\u{62D}\u{649}",
+    "\u{645}\u{62C}\u{62D}", "\u{645}\u{62C}\u{645}", "\u{645}\u{62E}\u{62C}",
+    "\u{645}\u{62E}\u{645}", "\u{645}\u{62C}\u{62E}", "\u{6F}\u{645}\u{62C}",
+    "\u{6F}\u{645}\u{645}", "\u{646}\u{62D}\u{645}", "\u{646}\u{62D}\u{649}",
+    "\u{646}\u{62C}\u{645}", "\u{646}\u{62C}\u{649}", "\u{646}\u{645}\u{649}",
+    "\u{649}\u{645}\u{645}", "\u{628}\u{62E}\u{649}", "\u{62A}\u{62C}\u{649}",
+    "\u{62A}\u{62E}\u{649}", "\u{62A}\u{645}\u{649}", "\u{62C}\u{645}\u{649}",
+    "\u{62C}\u{62D}\u{649}", "\u{633}\u{62E}\u{649}", "\u{635}\u{62D}\u{649}",
+    "\u{633}\u{6DB}\u{62D}\u{649}", "\u{644}\u{62C}\u{649}", "\u{644}\u{645}\u{649}",
+    "\u{649}\u{62D}\u{649}", "\u{649}\u{62C}\u{649}", "\u{649}\u{645}\u{649}",
+    "\u{645}\u{645}\u{649}", "\u{642}\u{645}\u{649}", "\u{643}\u{645}\u{649}",
+    "\u{646}\u{62C}\u{62D}", "\u{645}\u{62E}\u{649}", "\u{644}\u{62C}\u{645}",
+    "\u{643}\u{645}\u{645}", "\u{62D}\u{62C}\u{649}", "\u{645}\u{62C}\u{649}",
+    "\u{641}\u{645}\u{649}", "\u{628}\u{62D}\u{649}", "\u{635}\u{644}\u{649}",
+    "\u{642}\u{644}\u{649}", "\u{6C}\u{644}\u{644}\u{651}\u{670}\u{6F}",
+    "\u{6C}\u{643}\u{628}\u{631}", "\u{645}\u{62D}\u{645}\u{62F}", "\u{635}\u{644}\u{639}\u{645}",
+    "\u{631}\u{633}\u{648}\u{644}", "\u{639}\u{644}\u{649}\u{6F}", "\u{648}\u{633}\u{644}\u{645}",
+    "\u{635}\u{644}\u{649}\u{20}\u{6C}\u{644}\u{644}\u{6F}\u{20}\u{639}\u{644}\u{649}\u{6F}\u{20}\u{648}\u{633}\u{644}\u{645}",
+    "\u{62C}\u{644}\u{20}\u{62C}\u{644}\u{6C}\u{644}\u{6F}", "\u{631}\u{649}\u{6C}\u{644}",
+    "\u{2307}", "\u{23DC}", "\u{23DD}", "\u{23DE}", "\u{23DF}", "\u{23E0}", "\u{23E1}", "\u{621}",
+    "\u{622}", "\u{628}", "\u{62A}", "\u{62C}", "\u{62D}", "\u{62E}", "\u{62F}", "\u{630}",
+    "\u{631}", "\u{632}", "\u{633}", "\u{635}", "\u{636}", "\u{637}", "\u{638}", "\u{63A}",
+    "\u{642}", "\u{644}", "\u{645}", "\u{646}", "\u{644}\u{622}", "\u{644}\u{6C}\u{674}",
+    "\u{644}\u{6C}\u{655}", "\u{644}\u{6C}", "\u{FE3F}", "\u{301C}", "\u{25AA}", "\u{4E}\u{30A}",
+    "\u{58}\u{335}", "\u{56}\u{335}", "\u{6C}\u{335}\u{6C}\u{335}\u{53}\u{335}",
+    "\u{6C}\u{335}\u{6C}\u{335}", "\u{2CE8}", "\u{3D8}", "\u{2D40}", "\u{10382}", "\u{10393}",
+    "\u{2C70}", "\u{277}", "\u{25E}", "\u{10486}", "\u{4C3}", "\u{40B}", "\u{16E6}", "\u{37}",
+    "\u{A669}", "\u{10A56}\u{10A56}", "\u{10CA5}", "\u{10C82}", "\u{93A}", "\u{A8FC}", "\u{A8FB}",
+    "\u{2248}", "\u{11434}\u{11442}\u{11412}", "\u{11434}\u{11442}\u{11418}",
+    "\u{11434}\u{11442}\u{11423}", "\u{11434}\u{11442}\u{11429}", "\u{11434}\u{11442}\u{1142C}",
+    "\u{11434}\u{11442}\u{1142E}", "\u{1144B}\u{1144B}", "\u{998}", "\u{99A}", "\u{99C}", "\u{99E}",
+    "\u{99F}", "\u{9A1}", "\u{9B2}", "\u{9A4}", "\u{9A5}", "\u{9A6}", "\u{9A7}", "\u{9A8}",
+    "\u{9AA}", "\u{9AE}", "\u{9AF}", "\u{9AC}", "\u{9A3}", "\u{9B0}", "\u{9B7}", "\u{9B8}",
+    "\u{9BE}", "\u{9BF}", "\u{9C7}", "\u{9CB}", "\u{9D7}", "\u{9CC}", "\u{9CD}", "\u{9BD}",
+    "\u{77}\u{307}", "\u{9E7}", "\u{9E8}", "\u{9EC}", "\u{11582}", "\u{11583}", "\u{11584}",
+    "\u{115B2}", "\u{115B3}", "\u{11641}\u{11641}", "\u{2207}", "\u{11AE5}\u{11AEF}",
+    "\u{11AE5}\u{11AF0}", "\u{11AE5}\u{11AE5}", "\u{11AE5}\u{11AE5}\u{11AEF}",
+    "\u{11AE5}\u{11AE5}\u{11AF0}", "\u{11AEB}\u{11AEF}", "\u{11AEB}\u{11AEB}",
+    "\u{11AEB}\u{11AEB}\u{11AEF}", "\u{11AF3}\u{11AEF}", "\u{11AF3}\u{11AF0}", "\u{11AF3}\u{11AF3}",
+    "\u{11AF3}\u{11AF3}\u{11AEF}", "\u{11AF3}\u{11AF3}\u{11AF0}", "\u{11C41}\u{11C41}", "\u{11CAA}",
+    "\u{1039A}", "\u{A658}", "\u{4FE}", "\u{2144}", "\u{228F}", "\u{2290}", "\u{16CB}", "\u{6B}",
+    "\u{74}", "\u{39E}", "\u{3B6}", "\u{3BE}", "\u{2202}", "\u{3DD}", "\u{2220}", "\u{4F}\u{2E}",
+    "\u{4F}\u{2C}", "\u{6C}\u{2C}", "\u{32}\u{2C}", "\u{33}\u{2C}", "\u{34}\u{2C}", "\u{35}\u{2C}",
+    "\u{36}\u{2C}", "\u{37}\u{2C}", "\u{38}\u{2C}", "\u{39}\u{2C}", "\u{24}\u{20E0}",
+    "\u{28}\u{41}\u{29}", "\u{28}\u{42}\u{29}", "\u{28}\u{43}\u{29}", "\u{28}\u{44}\u{29}",
+    "\u{28}\u{45}\u{29}", "\u{28}\u{46}\u{29}", "\u{28}\u{47}\u{29}", "\u{28}\u{48}\u{29}",
+    "\u{28}\u{4A}\u{29}", "\u{28}\u{4B}\u{29}", "\u{28}\u{4C}\u{29}", "\u{28}\u{4D}\u{29}",
+    "\u{28}\u{4E}\u{29}", "\u{28}\u{4F}\u{29}", "\u{28}\u{50}\u{29}", "\u{28}\u{51}\u{29}",
+    "\u{28}\u{52}\u{29}", "\u{28}\u{53}\u{29}", "\u{28}\u{54}\u{29}", "\u{28}\u{55}\u{29}",
+    "\u{28}\u{56}\u{29}", "\u{28}\u{57}\u{29}", "\u{28}\u{58}\u{29}", "\u{28}\u{59}\u{29}",
+    "\u{28}\u{5A}\u{29}", "\u{33C4}\u{9}\u{20DD}", "\u{43}\u{20E0}", "\u{28}\u{672C}\u{29}",
+    "\u{28}\u{5B89}\u{29}", "\u{28}\u{70B9}\u{29}", "\u{28}\u{6253}\u{29}", "\u{28}\u{76D7}\u{29}",
+    "\u{28}\u{52DD}\u{29}", "\u{28}\u{6557}\u{29}", "\u{263D}", "\u{51}\u{45}", "\u{41}\u{52}",
+    "\u{56}\u{1DE4}", "\u{2629}", "\u{29DF}", "\u{22A1}", "\u{73}\u{73}\u{73}", "\u{4D}\u{42}",
+    "\u{56}\u{42}", "\u{22A0}", "\u{4E3D}", "\u{4E38}", "\u{4E41}", "\u{20122}", "\u{4F60}",
+    "\u{4FBB}", "\u{507A}", "\u{5099}", "\u{50CF}", "\u{349E}", "\u{2063A}", "\u{5154}", "\u{5164}",
+    "\u{5177}", "\u{2051C}", "\u{34B9}", "\u{5167}", "\u{518D}", "\u{2054B}", "\u{5197}",
+    "\u{51A4}", "\u{4ECC}", "\u{51AC}", "\u{291DF}", "\u{5203}", "\u{34DF}", "\u{523B}", "\u{5246}",
+    "\u{5272}", "\u{5277}", "\u{3515}", "\u{5305}", "\u{5306}", "\u{5349}", "\u{535A}", "\u{5373}",
+    "\u{537D}", "\u{537F}", "\u{20A2C}", "\u{7070}", "\u{53CA}", "\u{53DF}", "\u{20B63}",
+    "\u{53EB}", "\u{53F1}", "\u{5406}", "\u{549E}", "\u{5438}", "\u{5448}", "\u{5468}", "\u{54A2}",
+    "\u{54F6}", "\u{5510}", "\u{5563}", "\u{5584}", "\u{55AB}", "\u{55B3}", "\u{55C2}", "\u{5716}",
+    "\u{5717}", "\u{5651}", "\u{5674}", "\u{58EE}", "\u{57CE}", "\u{57F4}", "\u{580D}", "\u{578B}",
+    "\u{5832}", "\u{5831}", "\u{58AC}", "\u{214E4}", "\u{58F2}", "\u{58F7}", "\u{5906}", "\u{591A}",
+    "\u{5922}", "\u{5962}", "\u{216A8}", "\u{216EA}", "\u{59EC}", "\u{5A1B}", "\u{5A27}",
+    "\u{59D8}", "\u{5A66}", "\u{36EE}", "\u{36FC}", "\u{5B08}", "\u{5B3E}", "\u{219C8}", "\u{5BC3}",
+    "\u{5BD8}", "\u{5BF3}", "\u{21B18}", "\u{5BFF}", "\u{5C06}", "\u{5F53}", "\u{3781}", "\u{5C60}",
+    "\u{5CC0}", "\u{5C8D}", "\u{21DE4}", "\u{5D43}", "\u{21DE6}", "\u{5D6E}", "\u{5D6B}",
+    "\u{5D7C}", "\u{5DE1}", "\u{5DE2}", "\u{382F}", "\u{5DFD}", "\u{5E28}", "\u{5E3D}", "\u{5E69}",
+    "\u{3862}", "\u{22183}", "\u{387C}", "\u{5EB0}", "\u{5EB3}", "\u{5EB6}", "\u{2A392}",
+    "\u{22331}", "\u{8201}", "\u{5F22}", "\u{38C7}", "\u{232B8}", "\u{261DA}", "\u{5F62}",
+    "\u{5F6B}", "\u{38E3}", "\u{5F9A}", "\u{5FCD}", "\u{5FD7}", "\u{5FF9}", "\u{6081}", "\u{393A}",
+    "\u{391C}", "\u{226D4}", "\u{60C7}", "\u{6148}", "\u{614C}", "\u{617A}", "\u{61B2}", "\u{61A4}",
+    "\u{61AF}", "\u{61DE}", "\u{6210}", "\u{621B}", "\u{625D}", "\u{62B1}", "\u{62D4}", "\u{6350}",
+    "\u{22B0C}", "\u{633D}", "\u{62FC}", "\u{6368}", "\u{6383}", "\u{63E4}", "\u{22BF1}",
+    "\u{6422}", "\u{63C5}", "\u{63A9}", "\u{3A2E}", "\u{6469}", "\u{647E}", "\u{649D}", "\u{6477}",
+    "\u{3A6C}", "\u{656C}", "\u{2300A}", "\u{65E3}", "\u{66F8}", "\u{6649}", "\u{3B19}", "\u{3B08}",
+    "\u{3AE4}", "\u{5192}", "\u{5195}", "\u{6700}", "\u{669C}", "\u{80AD}", "\u{43D9}", "\u{6721}",
+    "\u{675E}", "\u{6753}", "\u{233C3}", "\u{3B49}", "\u{67FA}", "\u{6785}", "\u{6852}",
+    "\u{2346D}", "\u{688E}", "\u{681F}", "\u{6914}", "\u{6942}", "\u{69A3}", "\u{69EA}", "\u{6AA8}",
+    "\u{236A3}", "\u{6ADB}", "\u{3C18}", "\u{6B21}", "\u{238A7}", "\u{6B54}", "\u{3C4E}",
+    "\u{6B72}", "\u{6B9F}", "\u{6BBB}", "\u{23A8D}", "\u{21D0B}", "\u{23AFA}", "\u{6C4E}",
+    "\u{23CBC}", "\u{6CBF}", "\u{6CCD}", "\u{6C67}", "\u{6D16}", "\u{6D3E}", "\u{6D69}", "\u{6D78}",
+    "\u{6D85}", "\u{23D1E}", "\u{6D34}", "\u{6E2F}", "\u{6E6E}", "\u{3D33}", "\u{6EC7}",
+    "\u{23ED1}", "\u{6DF9}", "\u{6F6E}", "\u{23F5E}", "\u{23F8E}", "\u{6FC6}", "\u{7039}",
+    "\u{701B}", "\u{3D96}", "\u{704A}", "\u{707D}", "\u{7077}", "\u{70AD}", "\u{20525}", "\u{7145}",
+    "\u{24263}", "\u{719C}", "\u{243AB}", "\u{7228}", "\u{7250}", "\u{24608}", "\u{7280}",
+    "\u{7295}", "\u{24735}", "\u{24814}", "\u{737A}", "\u{738B}", "\u{3EAC}", "\u{73A5}",
+    "\u{3EB8}", "\u{7447}", "\u{745C}", "\u{7485}", "\u{74CA}", "\u{3F1B}", "\u{7524}", "\u{24C36}",
+    "\u{753E}", "\u{24C92}", "\u{2219F}", "\u{7610}", "\u{24FA1}", "\u{24FB8}", "\u{25044}",
+    "\u{3FFC}", "\u{4008}", "\u{250F3}", "\u{250F2}", "\u{25119}", "\u{25133}", "\u{771E}",
+    "\u{771F}", "\u{778B}", "\u{4046}", "\u{4096}", "\u{2541D}", "\u{784E}", "\u{40E3}",
+    "\u{25626}", "\u{2569A}", "\u{256C5}", "\u{79EB}", "\u{412F}", "\u{7A4A}", "\u{7A4F}",
+    "\u{2597C}", "\u{25AA7}", "\u{7AEE}", "\u{4202}", "\u{25BAB}", "\u{7BC6}", "\u{7BC9}",
+    "\u{4227}", "\u{25C80}", "\u{7CD2}", "\u{42A0}", "\u{7CE8}", "\u{7CE3}", "\u{7D00}",
+    "\u{25F86}", "\u{7D63}", "\u{4301}", "\u{7DC7}", "\u{7E02}", "\u{7E45}", "\u{4334}",
+    "\u{26228}", "\u{26247}", "\u{4359}", "\u{262D9}", "\u{7F7A}", "\u{2633E}", "\u{7F95}",
+    "\u{7FFA}", "\u{264DA}", "\u{26523}", "\u{8060}", "\u{265A8}", "\u{8070}", "\u{2335F}",
+    "\u{43D5}", "\u{80B2}", "\u{8103}", "\u{440B}", "\u{813E}", "\u{5AB5}", "\u{267A7}",
+    "\u{267B5}", "\u{23393}", "\u{2339C}", "\u{8204}", "\u{8F9E}", "\u{446B}", "\u{8291}",
+    "\u{828B}", "\u{829D}", "\u{52B3}", "\u{82B1}", "\u{82B3}", "\u{82BD}", "\u{82E6}", "\u{26B3C}",
+    "\u{831D}", "\u{8363}", "\u{83AD}", "\u{8323}", "\u{83BD}", "\u{83E7}", "\u{8353}", "\u{83CA}",
+    "\u{83CC}", "\u{83DC}", "\u{26C36}", "\u{26D6B}", "\u{26CD5}", "\u{452B}", "\u{84F1}",
+    "\u{84F3}", "\u{8516}", "\u{273CA}", "\u{8564}", "\u{26F2C}", "\u{455D}", "\u{4561}",
+    "\u{26FB1}", "\u{270D2}", "\u{456B}", "\u{8650}", "\u{8667}", "\u{8669}", "\u{86A9}",
+    "\u{8688}", "\u{870E}", "\u{86E2}", "\u{8728}", "\u{876B}", "\u{8786}", "\u{45D7}", "\u{87E1}",
+    "\u{8801}", "\u{45F9}", "\u{8860}", "\u{27667}", "\u{88D7}", "\u{88DE}", "\u{4635}", "\u{88FA}",
+    "\u{34BB}", "\u{278AE}", "\u{27966}", "\u{46BE}", "\u{46C7}", "\u{8AA0}", "\u{27CA8}",
+    "\u{8CAB}", "\u{8CC1}", "\u{8D1B}", "\u{8D77}", "\u{27F2F}", "\u{20804}", "\u{8DCB}",
+    "\u{8DBC}", "\u{8DF0}", "\u{208DE}", "\u{8ED4}", "\u{285D2}", "\u{285ED}", "\u{9094}",
+    "\u{90F1}", "\u{9111}", "\u{2872E}", "\u{911B}", "\u{9238}", "\u{92D7}", "\u{92D8}", "\u{927C}",
+    "\u{93F9}", "\u{9415}", "\u{28BFA}", "\u{958B}", "\u{4995}", "\u{95B7}", "\u{28D77}",
+    "\u{49E6}", "\u{96C3}", "\u{5DB2}", "\u{9723}", "\u{29145}", "\u{2921A}", "\u{4A6E}",
+    "\u{4A76}", "\u{97E0}", "\u{2940A}", "\u{4AB2}", "\u{29496}", "\u{9829}", "\u{295B6}",
+    "\u{98E2}", "\u{4B33}", "\u{9929}", "\u{99A7}", "\u{99C2}", "\u{99FE}", "\u{4BCE}", "\u{29B30}",
+    "\u{9C40}", "\u{9CFD}", "\u{4CCE}", "\u{4CED}", "\u{9D67}", "\u{2A0CE}", "\u{4CF8}",
+    "\u{2A105}", "\u{2A20E}", "\u{2A291}", "\u{4D56}", "\u{9EFE}", "\u{9F05}", "\u{9F0F}",
+    "\u{9F16}", "\u{2A600}",
+];
+
+/// The confusable prototype of every codepoint
+pub(crate) static CONFUSABLE: Utf8Trie<[u16; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+        21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 11, 12,
+        11, 13, 25, 26, 26, 26, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31, 30, 30, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43,
+            44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 30, 60,
+        ],
+        [
+            61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 30, 30, 73, 74, 75, 76, 77, 78, 79, 80,
+            81, 82, 83, 84, 85, 86, 87, 30, 88, 89, 90, 91, 92, 93, 30, 30, 30, 94, 30, 30, 95, 30,
+            30, 96, 30, 30, 97, 98, 30, 99, 100, 101, 102, 103, 30, 104, 105, 106, 30, 107, 108,
+            109,
+        ],
+        [
+            110, 111, 112, 113, 114, 115, 116, 30, 117, 118, 119, 120, 121, 122, 123, 124, 30, 125,
+            126, 127, 128, 129, 130, 131, 132, 133, 134, 30, 30, 135, 136, 137, 30, 30, 30, 30, 138,
+            139, 140, 141, 142, 143, 144, 145, 30, 30, 30, 146, 30, 147, 148, 149, 150, 151, 30,
+            152, 153, 154, 155, 156, 157, 158, 159, 160,
+        ],
+        [
+            161, 162, 163, 164, 165, 166, 167, 168, 169, 170, 30, 171, 30, 172, 30, 173, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 174, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30,
+        ],
+        [
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 175, 30, 176, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 177, 30, 30, 30, 30, 30, 30, 30,
+        ],
+        [
+            178, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            179, 30, 30, 30, 30, 30, 180, 30, 30, 30, 30, 30, 181, 30, 182, 30, 30, 30, 30, 30, 30,
+            30, 30, 183, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 184, 185, 30, 30, 30, 30, 30,
+            30,
+        ],
+        [
+            30, 30, 30, 30, 30, 30, 30, 30, 186, 30, 30, 30, 30, 30, 30, 30, 187, 30, 30, 30, 30,
+            30, 30, 30, 30, 188, 30, 189, 190, 30, 30, 191, 30, 30, 30, 30, 30, 30, 30, 192, 193,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 194, 30,
+            30,
+        ],
+        [
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 195, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 196, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+        ],
+        [
+            30, 30, 197, 198, 199, 200, 201, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            202, 30, 30, 30, 30, 203, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 204,
+            205, 30, 30, 30, 30, 206, 30, 30, 207, 30, 30, 30, 30, 208, 209, 30, 30, 210, 30, 211,
+            30, 30, 30,
+        ],
+        [
+            30, 30, 30, 212, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 213, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 214, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 215, 30, 216, 30, 30, 30, 217,
+        ],
+        [
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 218, 219, 30,
+            30, 30, 30, 220, 221, 222, 223, 224, 225, 226, 227, 228, 30, 30, 30, 30, 229, 230, 231,
+            30, 232, 30, 30, 233, 234, 235, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30,
+        ],
+        [
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+        ],
+        [
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 236, 237, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+        ],
+        [
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 238, 239, 240, 241, 242, 243,
+            244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254, 255, 256, 257, 258, 259, 260,
+            261, 262, 263, 30, 264,
+        ],
+        [
+            30, 30, 30, 30, 265, 30, 266, 30, 30, 30, 267, 268, 269, 30, 30, 270, 271, 272, 273,
+            274, 275, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            276, 277, 30, 30, 30, 30, 30, 30, 30, 30, 30, 278, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30,
+        ],
+        [
+            30, 30, 279, 30, 30, 30, 30, 280, 30, 30, 30, 30, 281, 30, 30, 30, 282, 283, 284, 285,
+            30, 30, 30, 286, 30, 287, 30, 30, 288, 30, 30, 30, 30, 30, 289, 290, 30, 30, 30, 30, 30,
+            30, 30, 291, 30, 30, 30, 30, 30, 292, 293, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30,
+        ],
+        [
+            294, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+        ],
+        [
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 295, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+        ],
+        [
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 296, 297, 30, 30,
+        ],
+        [
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 298, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+        ],
+        [
+            30, 30, 30, 30, 299, 300, 30, 30, 301, 302, 30, 30, 30, 30, 30, 30, 303, 304, 305, 306,
+            307, 308, 309, 310, 311, 312, 313, 314, 315, 316, 317, 318, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30,
+        ],
+        [
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 319, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 320, 321, 322, 30, 30, 30, 30, 30,
+        ],
+        [
+            30, 30, 30, 30, 323, 324, 30, 30, 30, 325, 30, 30, 326, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 327, 328, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 329, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+        ],
+        [
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 330,
+        ],
+        [
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 331, 332, 333, 334, 335, 336, 337, 338, 339, 30,
+            30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+        ],
+        [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 11, 12, 11, 13, 14, 15, 16, 17, 11, 11, 18, 11,
+            11, 11, 11, 11, 19, 20, 21, 22, 11, 23, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+            11, 24, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+        ],
+        [
+            11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+            11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+            11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+        ],
+    ],
+    leaves: &[
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 4, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 7, 0, 8, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 5, 11, 0, 0, 12,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 13, 14, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 16, 17, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 19, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 21, 0,
+            22, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 23, 0, 0, 0, 0, 0, 0, 0, 0, 24, 25,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 29, 30, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 31,
+        ],
+        [
+            31, 32, 33, 0, 0, 0, 34, 0, 0, 35, 0, 0, 0, 0, 0, 0, 36, 0, 37, 38, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 40, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 42,
+        ],
+        [
+            43, 44, 45, 45, 46, 0, 0, 47, 0, 15, 48, 0, 49, 50, 0, 0, 0, 51, 52, 53, 0, 0, 4, 54,
+            55, 56, 54, 57, 0, 58, 59, 60, 61, 62, 0, 0, 63, 64, 65, 66, 0, 0, 0, 0, 67, 68, 69, 0,
+            0, 0, 0, 70, 71, 72, 73, 74, 0, 0, 0, 75, 76, 77, 0, 78,
+        ],
+        [
+            4, 79, 0, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 98, 99, 100, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102,
+            103, 104, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 106, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 107, 39,
+            74, 0, 0, 0, 0, 0, 108, 108, 109, 110, 111, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 8, 0, 113, 0,
+        ],
+        [
+            0, 114, 0, 0, 115, 0, 116, 117, 118, 119, 0, 0, 0, 120, 9, 121, 0, 122, 0, 123, 0, 0,
+            124, 125, 0, 126, 127, 128, 0, 0, 0, 0, 129, 50, 0, 130, 0, 0, 131, 0, 132, 28, 28, 133,
+            0, 134, 135, 136, 0, 137, 0, 138, 0, 139, 140, 0, 0, 0, 0, 0, 141, 142, 0, 0,
+        ],
+        [
+            0, 0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 130, 145, 0, 146, 0, 114, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 104, 148, 149, 150, 151, 152, 153, 154, 155, 0, 0, 0, 0,
+            0, 0, 0, 156, 0, 0, 0, 0, 0, 5, 1, 5, 5, 5, 5, 157,
+        ],
+        [
+            0, 0, 158, 159, 160, 0, 160, 0, 5, 0, 5, 5, 0, 0, 0, 0, 161, 0, 0, 157, 0, 0, 0, 162,
+            163, 164, 165, 28, 166, 1, 0, 0, 0, 167, 168, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+            0, 0, 0, 0, 5, 0, 1, 0, 161, 0, 0, 170, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 171, 0, 0, 0, 0, 0, 0, 172, 173, 0, 0, 174, 175, 0, 0, 0, 176, 0, 177, 0,
+            0, 0, 0, 0, 0, 0, 0, 178, 179, 180, 0, 0, 0, 0, 179, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 181, 182, 0, 179, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            183, 184, 185, 176, 0, 180, 0, 186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 187,
+            188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 0, 0, 0, 0, 172, 0, 190, 0, 0,
+            0, 5, 191, 192, 193, 0, 0, 28, 194, 0, 195, 196, 197,
+        ],
+        [
+            0, 0, 0, 0, 5, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 199, 200, 0, 0, 201, 202, 203, 60,
+            4, 204, 205, 206, 207, 0, 3, 0, 208, 0, 209, 210, 211, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 122, 213, 130, 214, 128, 0, 59, 60, 28, 215, 0, 0, 216, 0, 217,
+        ],
+        [
+            0, 218, 0, 217, 219, 144, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 213, 60, 211, 0, 0, 220, 221,
+            0, 0, 0, 0, 222, 223, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 224, 0, 0, 0, 0, 0, 0, 215,
+            218, 225, 226, 60, 128, 0, 227, 78, 228, 206, 0, 0, 229, 0, 230,
+        ],
+        [
+            0, 0, 0, 0, 231, 232, 4, 0, 197, 0, 0, 0, 0, 0, 0, 0, 199, 45, 200, 233, 0, 201, 0, 74,
+            0, 234, 204, 205, 206, 203, 3, 235, 208, 228, 210, 211, 236, 212, 0, 0, 0, 0, 0, 237,
+            46, 0, 238, 0, 122, 239, 240, 241, 0, 242, 0, 243, 193, 0, 215, 0, 244, 245, 217, 221,
+        ],
+        [
+            218, 225, 219, 130, 220, 16, 0, 0, 0, 0, 246, 247, 248, 0, 0, 249, 0, 0, 0, 0, 128, 77,
+            28, 0, 226, 0, 0, 27, 0, 250, 0, 0, 0, 136, 43, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            251, 252, 60, 139, 253, 216, 0, 0, 0, 0, 0, 0, 254, 255, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 256, 257, 43, 43, 0, 0, 258, 259, 260, 120, 0, 0, 261,
+            262, 263, 264, 265, 266, 0, 0, 267, 268, 0, 0, 269, 270, 0, 0, 0, 0, 0, 0, 14, 19, 271,
+            272, 211, 130, 9, 121, 273, 0, 0, 0, 0, 0, 0, 0, 0, 274, 0, 242, 275, 276,
+        ],
+        [
+            4, 0, 0, 0, 0, 277, 278, 279, 280, 279, 280, 281, 282, 283, 284, 28, 0, 0, 0, 0, 13, 18,
+            0, 0, 285, 126, 0, 0, 0, 0, 0, 0, 74, 146, 0, 0, 0, 0, 0, 0, 60, 139, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 286, 0, 0, 0, 0, 0, 0, 0, 0, 287, 0, 288, 289, 0, 0, 290, 128, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 291, 292, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 293, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 294, 0, 0, 0, 0, 0, 295, 0, 296, 297, 0, 232, 0, 0, 0, 236, 0, 3, 0, 0, 0,
+            0, 5, 0, 0, 5, 0, 0, 0, 136, 0, 291, 0, 0, 291, 0, 0, 0, 0, 0, 0, 0, 214, 0, 274, 0, 0,
+            0, 0, 298, 0, 0, 299, 0, 300, 0, 299, 144, 0, 0,
+        ],
+        [
+            0, 50, 0, 0, 42, 217, 0, 301, 0, 161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 184, 184, 0, 0, 0, 0, 0, 0, 302, 0, 0, 0, 303, 0, 0, 0, 0, 304, 305, 189, 0, 0, 0, 0,
+            306, 0, 0, 0, 0, 188, 188, 0, 0, 0, 0, 0,
+        ],
+        [
+            4, 188, 188, 161, 188, 306, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 216,
+            5, 0, 0, 0, 0, 0, 4, 0, 217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 79, 307, 1, 5, 1,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 308, 309, 0, 0, 12, 0, 310, 0, 0, 0, 0, 0, 0, 0, 0, 184, 176,
+            177, 0, 0, 0, 0, 0, 0, 0, 0, 311, 312, 313, 314, 4, 0, 0, 0, 315, 0, 0, 0, 0, 0, 0, 0,
+            0, 316, 0, 0, 0, 0, 0, 0, 0, 0, 317, 0, 315,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 217, 0, 0, 318, 319, 0, 0, 184, 176, 0, 0, 189, 185, 0, 0, 320,
+            321, 172, 171, 172, 175, 306, 322, 0, 323, 324, 4, 0, 0, 0, 217, 0, 253, 205, 0, 2, 12,
+            325, 326, 318, 327, 0, 0, 311, 313, 0, 311, 312, 328, 314, 329, 0, 0, 0, 0, 315, 0,
+        ],
+        [
+            0, 330, 0, 0, 0, 331, 0, 0, 332, 0, 0, 333, 0, 0, 334, 0, 0, 335, 336, 0, 0, 0, 0, 0,
+            337, 0, 0, 0, 0, 0, 338, 339, 0, 0, 0, 0, 340, 0, 0, 341, 340, 342, 342, 0, 0, 343, 0,
+            0, 0, 0, 0, 0, 344, 345, 0, 346, 0, 0, 318, 329, 0, 315, 217, 0,
+        ],
+        [
+            0, 217, 347, 21, 0, 0, 348, 349, 350, 351, 0, 352, 318, 0, 353, 0, 354, 315, 318, 0,
+            162, 217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 0, 0, 0, 0, 0, 174, 0, 0, 0, 188, 0,
+            355, 356, 324, 4, 357, 358, 359, 217, 360, 253, 205, 361, 0, 0, 0, 362, 363, 364,
+        ],
+        [
+            0, 324, 324, 161, 161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            188, 188, 365, 0, 0, 0, 0, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 366, 0, 0, 0, 0, 353, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 367, 343, 0, 0, 0, 368, 369, 370, 0, 0, 371, 0, 0, 0, 0, 372,
+            330, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 373, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 0, 188, 175, 0, 0, 0, 0, 374, 5, 5, 0, 0,
+            0, 0, 375, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 376, 0, 0, 377, 0, 0, 378, 379, 368, 0, 0, 0, 0, 380, 381, 382, 383, 384, 0,
+            0, 0, 385, 386, 0, 387, 388, 327, 327, 318, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 389, 0, 0, 389, 0, 188, 374, 0, 306, 390, 0, 319, 389, 391, 176,
+            0, 0, 0, 0, 187, 392, 393, 0, 0, 0, 0, 187,
+        ],
+        [
+            394, 174, 188, 161, 395, 0, 396, 0, 397, 0, 0, 0, 0, 398, 399, 0, 400, 401, 402, 403,
+            404, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 306, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 183, 184, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 405, 217, 361, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 114, 0, 0,
+        ],
+        [
+            0, 174, 0, 0, 0, 0, 406, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 306, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 407, 407, 0, 0, 0, 0, 3, 0, 0, 0, 108, 0, 0, 408, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 188, 409, 0, 0, 410, 411, 412, 413, 414, 0, 0, 0, 0, 415, 416, 0, 0, 0, 417, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 306, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 418, 0, 419, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 217, 408, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 174, 188, 161, 0, 0, 420, 0, 0, 0, 0, 0, 0, 421, 0, 422, 423, 424, 0, 425, 426, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 306, 427, 0, 0,
+        ],
+        [
+            0, 428, 429, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 419, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 217, 0, 430, 431, 432, 0, 0, 0, 433, 0, 434, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 174, 0, 108, 0, 0, 435, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 306, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 408, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 189, 0, 0, 0, 0, 0, 0, 0, 436, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            437, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 438, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 438, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 439, 0, 440, 441, 442, 188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 443, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 217, 444, 445, 0, 446, 447, 448, 449, 450, 0,
+            451, 0, 452, 0, 453, 454, 0, 455, 456, 0, 457, 0, 0, 0, 0, 0,
+        ],
+        [
+            174, 0, 217, 409, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 458, 459, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 460, 0, 461, 0, 0, 462, 0, 0, 0, 0, 0, 0, 0, 463, 464, 0, 0, 0, 0, 0,
+            0, 0, 0, 465, 0, 466, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 467, 0, 468, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 469, 470, 0, 0, 0, 0, 217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 174, 217, 409, 0, 471, 472, 473, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 474, 458, 459, 0, 0,
+            0, 0, 0, 0, 0, 475, 0, 476, 0, 0, 0, 0, 477, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 478, 0,
+            479, 480, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 481, 0, 0, 0, 0, 217, 482, 483, 0, 0, 0, 0, 0, 0, 484, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 174, 217, 409, 0, 0, 0, 0, 485, 445, 486, 0, 487, 0, 0, 0, 488, 0, 0, 489, 490, 0, 0,
+            0, 0, 487, 0, 0, 437, 0, 0, 0, 217, 0, 0, 491, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            492, 0, 0, 493, 0, 494, 0, 0, 0, 495, 0, 0, 0, 0, 496,
+        ],
+        [
+            496, 0, 497, 497, 0, 0, 0, 0, 498, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            499, 0, 0, 0, 0, 500, 0, 501, 0, 0, 0, 0, 217, 0, 0, 0, 502, 503, 504, 408, 505, 506, 0,
+            0, 0, 0, 0, 0, 507, 0, 0, 487, 0, 506, 502, 0, 0, 0,
+        ],
+        [
+            0, 0, 217, 409, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 508, 509, 510, 0, 0, 0, 511, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 512, 0, 0, 0, 0, 0, 0, 0, 513, 0, 0, 0, 514, 0, 0, 0, 0, 515, 515, 0, 516, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 517, 0, 0, 0, 0, 518, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 519,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 520, 0, 0, 0, 521, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 522, 0, 0, 0, 0, 523, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 524,
+            525, 0, 526, 527, 528, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 529, 0,
+            0, 0, 0, 530, 531, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 532, 533, 534, 535, 0, 189, 0, 0, 217, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 536, 537, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            538, 0, 539, 540, 0, 0, 0, 0, 0, 0, 0, 0, 541, 0, 542, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 543, 0, 0, 544, 545, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 546, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 547, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 548, 0,
+            549, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 550, 0, 0, 0, 0, 0, 0, 551, 552, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            553, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 554, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 217, 0, 555, 0, 0, 0, 0, 0, 0, 0, 0, 0, 556, 557, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            189, 0, 409, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 558, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 559, 560, 0, 0, 0, 0, 0, 0, 0, 0, 561, 562, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 563, 0,
+        ],
+        [
+            0, 564, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 565, 0, 566, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 146, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 217,
+        ],
+        [
+            0, 567, 0, 0, 568, 0, 0, 0, 569, 0, 570, 0, 0, 571, 0, 0, 0, 0, 0, 572, 573, 574, 575,
+            576, 577, 578, 579, 580, 581, 582, 583, 584, 585, 586, 587, 588, 589, 590, 591, 592,
+            593, 594, 595, 596, 597, 598, 599, 600, 601, 602, 603, 604, 605, 606, 607, 608, 609,
+            610, 611, 579, 0, 612, 0, 613,
+        ],
+        [
+            0, 614, 615, 616, 617, 618, 619, 620, 621, 622, 623, 624, 0, 625, 0, 626, 0, 627, 628,
+            629, 0, 0, 630, 631, 632, 0, 633, 634, 635, 636, 637, 0, 0, 0, 638, 0, 639, 0, 640, 0,
+            641, 0, 642, 643, 644, 0, 0, 645, 646, 647, 0, 648, 649, 650, 651, 652, 653, 654, 655,
+            656, 657, 658, 659, 660,
+        ],
+        [
+            661, 662, 663, 664, 665, 666, 665, 667, 668, 669, 670, 671, 672, 673, 674, 675, 676,
+            677, 678, 679, 680, 681, 682, 683, 684, 685, 686, 687, 688, 689, 0, 690, 691, 692, 693,
+            694, 695, 696, 697, 698, 699, 567, 700, 701, 635, 636, 702, 703, 704, 705, 706, 707,
+            708, 709, 579, 710, 711, 586, 712, 570, 713, 714, 715, 716,
+        ],
+        [
+            717, 718, 719, 720, 721, 572, 574, 634, 722, 723, 576, 637, 724, 577, 725, 726, 578,
+            727, 728, 729, 730, 731, 732, 733, 734, 735, 736, 737, 581, 738, 739, 740, 741, 742,
+            582, 743, 595, 744, 596, 598, 600, 601, 603, 745, 614, 746, 620, 747, 748, 618, 619,
+            630, 631, 749, 750, 751, 752, 753, 754, 755, 756, 757, 758, 573,
+        ],
+        [
+            297, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 300, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            236, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 759, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 760, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 761, 65, 210, 0, 61, 28, 0, 0, 190, 211, 199, 197, 201, 0, 114, 0, 190, 233, 0,
+            292, 0, 0, 0, 206, 0, 0, 0, 203, 0, 211, 60, 39,
+        ],
+        [
+            288, 0, 274, 202, 0, 0, 0, 762, 0, 0, 0, 290, 115, 0, 763, 46, 0, 0, 65, 0, 292, 232, 0,
+            0, 0, 253, 232, 0, 0, 0, 764, 228, 0, 0, 208, 0, 0, 0, 204, 286, 0, 0, 0, 60, 0, 0, 239,
+            0, 213, 0, 131, 288, 200, 0, 0, 0, 0, 0, 0, 289, 240, 0, 0, 0,
+        ],
+        [
+            765, 0, 0, 766, 0, 0, 0, 0, 0, 0, 0, 0, 767, 768, 769, 770, 771, 772, 773, 774, 775,
+            776, 0, 777, 778, 779, 780, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 198, 0, 0, 0, 781, 782,
+            783, 784, 253, 0, 205, 0, 159, 0, 0, 0, 785, 158, 0, 786, 787, 788, 789, 790, 791,
+        ],
+        [
+            785, 792, 793, 794, 795, 796, 797, 798, 0, 0, 5, 0, 297, 0, 759, 0, 0, 0, 0, 0, 799, 0,
+            0, 800, 801, 802, 803, 804, 805, 799, 806, 807, 808, 809, 810, 811, 812, 0, 0, 813, 814,
+            815, 816, 0, 0, 208, 0, 286, 0, 0, 46, 817, 818, 819, 820, 821, 822, 823, 824, 825, 826,
+            827, 828, 829,
+        ],
+        [
+            830, 831, 0, 0, 0, 832, 833, 834, 835, 0, 0, 0, 0, 197, 0, 0, 0, 0, 836, 837, 838, 839,
+            840, 841, 842, 843, 844, 845, 846, 847, 848, 849, 0, 0, 0, 0, 0, 233, 0, 0, 0, 0, 764,
+            0, 850, 851, 852, 853, 854, 855, 856, 857, 858, 859, 860, 31, 861, 862, 0, 0, 0, 0, 0,
+            66,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 863, 864, 865, 866, 867, 868, 0, 0, 869, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 870, 871, 872, 873, 874, 875, 876, 877, 878, 879, 880, 881, 882, 883, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 884, 885, 886, 887, 888, 889, 890, 891, 892, 893,
+        ],
+        [
+            894, 895, 896, 897, 0, 0, 0, 0, 0, 0, 0, 0, 898, 899, 900, 901, 0, 0, 0, 0, 0, 0, 0,
+            902, 903, 904, 905, 906, 907, 908, 909, 910, 911, 912, 913, 914, 915, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 916, 917, 918, 919, 920, 921, 922, 923, 924, 925, 926, 927, 928, 929, 0, 0,
+            0,
+        ],
+        [
+            930, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 931, 932, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            933, 934, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 935, 936, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 214, 0, 0, 0, 0, 203, 16, 937, 938,
+        ],
+        [
+            939, 940, 941, 942, 943, 944, 0, 65, 0, 0, 0, 0, 0, 0, 945, 946, 947, 948, 949, 950,
+            951, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46,
+            0, 0, 0, 0, 223, 952, 0, 953, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 954, 199, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 761, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 761, 0, 0, 0, 0, 762, 206, 0, 0, 0, 0, 0, 0,
+            200, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 955, 956, 957, 0, 0, 958, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 959, 960, 961, 0, 0, 0, 0, 0, 0, 0, 0, 0, 962, 963, 0, 0, 0, 0,
+            962, 963, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 212, 16, 964, 965, 966, 967, 968, 969,
+            970, 971, 972, 973, 974, 975, 976, 977, 978, 0, 0,
+        ],
+        [
+            7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 158, 0, 0, 0, 0, 212, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 4, 979, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 204, 206, 0, 251, 0, 0,
+            0, 0, 0, 0, 0, 0, 980, 0, 0, 0, 0, 0, 0, 0, 0, 0, 198, 161, 981, 0, 0, 236, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 982, 983, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 984, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 985,
+            986, 987, 988, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 0, 532, 0, 0, 0, 0, 0, 0, 0, 189, 989, 990, 0, 0, 0,
+            991, 992, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 161, 0, 0, 0, 0, 0, 161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 993, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 994, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 995, 0, 0, 996, 0, 0,
+            997, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 998, 0, 0, 0, 999, 1000, 1001, 1002, 1003, 1004, 1005, 1006, 1007, 1008, 1009,
+            1010, 1011, 1012, 0, 0, 0, 0, 0, 0, 0, 168, 1013, 1014, 0, 0, 1015, 0, 0, 1016, 1017,
+            1018, 0, 0, 1019, 0, 1020, 0, 0, 1021, 0, 0, 1022, 0, 1023, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1024, 1025, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1026, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1026, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1027, 0, 1028, 0, 0, 0, 0, 0, 0, 0, 0, 1029,
+            0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1030, 1031, 0, 0, 0, 0, 1032, 0,
+            0, 0, 1033, 0, 0, 1034, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 1035, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 1036,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 175, 0, 171, 1, 0, 1037, 0, 0, 1038,
+            1039, 1040, 0, 320, 306, 390, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1041, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 225, 0, 0, 0, 243, 0, 0, 215, 0, 244, 0, 217, 194, 217, 0, 0, 1042, 0, 0, 0,
+            0, 0, 0, 0, 144, 0, 0, 0, 216, 136, 1043, 0, 224, 0, 241, 1044, 221, 1045, 0, 1046, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1047, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1048, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1049, 0, 0, 1050, 1051, 1052, 0, 1053,
+            1054, 1055, 1056, 1057, 0, 1058, 0, 0, 132, 132, 1059, 1060, 1061,
+        ],
+        [
+            0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 1062, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 1063, 0, 0, 1064, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 1065, 1066, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1067, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 1068, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1069, 0,
+            0, 42, 213, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 130,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1070, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 5, 28, 5,
+        ],
+        [
+            166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 1071, 0, 0,
+            0, 0, 0, 0, 5, 5, 0,
+        ],
+        [
+            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 0, 0, 0, 0, 0, 162, 162, 162, 162, 648, 648, 79, 0, 5,
+            5, 12, 5, 1, 1, 0, 1, 0, 0, 198, 0, 324, 1072, 1073, 198, 7, 7, 0, 0, 0, 0, 0, 7, 308,
+            309, 5, 1, 1074, 5, 1, 1074, 0, 158, 159, 0, 1075, 0, 10, 0,
+        ],
+        [
+            0, 983, 0, 162, 983, 0, 0, 1076, 1077, 1078, 0, 0, 0, 0, 326, 0, 0, 0, 2, 166, 0, 0, 0,
+            1079, 0, 0, 161, 0, 0, 1080, 1081, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1048, 0, 0, 0, 0, 0, 0, 0, 0, 1082, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 1083, 0, 0, 1084, 1085, 0, 0, 1086, 1087, 0, 1088, 231, 267, 1089, 0, 0, 0,
+            0, 0, 0, 0, 1090, 0, 0, 0, 0, 0, 0, 1091, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1029,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1092, 1093, 228, 1094, 0, 1095, 1096, 290, 1097, 1098, 50, 203, 203, 203, 274, 27, 4, 4,
+            764, 4, 0, 207, 1099, 0, 0, 208, 1100, 65, 65, 65, 0, 0, 0, 1101, 0, 0, 202, 0, 963,
+            962, 202, 1102, 204, 0, 200, 228, 242, 242, 201, 223, 0, 206, 217, 1103, 1104, 1105,
+            1106, 28, 0, 1107, 221, 130, 233, 235,
+        ],
+        [
+            209, 1108, 1109, 1110, 0, 761, 286, 242, 28, 226, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 79, 1111, 1112, 253, 1113, 1114, 1115, 1116, 212, 1117,
+            1118, 764, 228, 761, 206, 28, 1119, 1120, 1121, 216, 1122, 1123, 1124, 1125, 16, 1126,
+            1127, 4, 225, 286, 6,
+        ],
+        [
+            0, 0, 0, 229, 194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1128, 0, 0, 0, 1129, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1130,
+            0, 0, 0, 0, 1131, 0, 0, 0, 1132, 1133,
+        ],
+        [
+            954, 0, 0, 1134, 0, 0, 766, 0, 0, 0, 0, 0, 0, 0, 0, 235, 0, 209, 162, 0, 1135, 983,
+            1136, 326, 165, 198, 0, 0, 0, 0, 1137, 0, 0, 0, 0, 4, 0, 79, 0, 0, 216, 759, 297, 1138,
+            1139, 1140, 0, 1141, 1142, 0, 0, 0, 0, 0, 161, 0, 1143, 0, 0, 0, 166, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1144, 1145, 0, 0, 0, 0, 0, 1146, 0,
+            1147, 1148, 0, 0, 0, 1149, 0, 0, 0, 0, 1150, 0, 0, 0, 0, 0, 0, 1151, 1152, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 1153, 1154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1155, 60, 0, 0,
+            1156, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 210, 1157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1158, 216, 759, 297, 1159, 198, 0, 0, 1160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 796,
+            785, 1161, 1162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1080, 1163,
+            0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 201,
+        ],
+        [
+            1164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1165, 0, 0, 0, 1166, 1167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1169,
+            1170, 0, 1171, 0, 0, 1172, 0, 1173, 1174, 1175, 1176, 21, 0, 0, 1177, 1178, 0, 1179, 60,
+            0, 0, 0, 0, 0, 0, 28, 218, 1180, 1181, 1182, 1183, 1184, 122, 0, 0, 0, 0, 979,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 650,
+            0, 0, 650, 0, 0, 650, 0, 0, 650, 0, 0, 0, 0, 650, 0, 0, 0, 650, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1185, 1186, 1187, 0, 0, 1188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 1190, 4, 1191, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 1193, 1194, 1195, 1196, 1197, 1198, 1199, 1200, 1201, 1202, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 1203, 1204, 1205, 1206, 1207, 1208, 1209, 1210, 1211, 1212, 1213, 1214,
+        ],
+        [
+            1215, 1216, 1217, 1218, 1219, 1220, 1221, 1222, 1223, 1224, 1225, 1226, 1227, 1228,
+            1229, 1230, 1231, 1232, 1233, 1234, 1235, 1236, 1237, 1238, 1239, 1240, 1241, 1242,
+            1243, 1244, 1245, 1246, 1247, 1248, 1249, 1250, 1251, 1252, 1253, 1203, 1254, 1255,
+            1256, 1257, 1258, 1259, 1260, 1261, 1262, 1263, 1264, 1265, 1266, 1267, 0, 0, 1268, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 1269, 0, 1270, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1271, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1272, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            648, 648, 0, 1273, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1274, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1275, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 983, 0, 212, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 1276, 0, 0, 0, 0, 0, 0, 0, 1277, 0, 0, 0, 10, 0, 0, 1278, 0, 0,
+            0, 0, 0, 1279, 0, 0, 1276, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1280, 0, 766,
+            0, 0, 0, 1281, 1282, 0, 1282, 0, 0, 1283, 0, 0,
+        ],
+        [
+            0, 1284, 0, 0, 0, 0, 0, 1159, 0, 0, 1159, 165, 0, 0, 1285, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 1286, 0, 0, 0, 0, 0, 165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1156, 0, 0, 0, 0, 0, 0, 1287, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1288, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1289, 0, 0, 0, 0, 0,
+            0, 0, 1290, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1291, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 1159, 0, 0, 0, 0, 0, 0, 1292, 1293, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 434, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1294, 1295, 0, 0, 0, 0, 158, 159, 0, 0, 1294, 1295,
+            1296, 1297, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 981, 162, 1298, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 1157, 0, 0, 0, 0, 0, 1299, 1300, 0, 983, 0, 1136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            210, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1166, 1167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 1301, 0, 1302, 0, 0, 0, 0, 0, 0, 0, 0, 1303, 1304, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1081, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1305, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 1285, 0,
+        ],
+        [
+            0, 0, 0, 0, 1168, 1306, 0, 1307, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1308, 0, 0,
+            1309, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1310, 1136, 1311, 0, 983, 1136, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1156, 1155, 1312, 1313, 1314, 1315, 1316, 0, 0, 0, 0, 0, 1317, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 1160, 0, 0, 1152, 1132, 1318, 1319, 1320, 1321, 1322, 1323, 0,
+            1324, 1325, 0, 0, 0, 0, 16, 1326, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1327, 1328, 1329,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1330, 0, 0, 0, 1331, 0, 0, 0, 0, 0, 1332, 1333,
+            1334, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 1335, 0, 0, 0, 0, 1336, 1337, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1338, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 1339, 0, 1340, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1341, 1342, 1343, 1344, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 269, 0, 265, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 233, 241, 766, 0, 231, 128, 0, 0, 0, 0, 203, 0, 0, 0, 4, 0, 204, 215, 1345,
+            0, 206, 0, 207, 0, 0, 0, 3, 217, 235, 0, 208, 218, 228, 225, 210, 0, 211, 0, 236, 220,
+            212, 1346, 251, 0, 0, 1180, 0, 0, 796, 0, 0, 0, 0, 0, 162, 0, 1347, 1348, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 983, 0, 0, 0, 408, 0, 74, 146, 0, 0, 764, 1349, 239, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1350, 0, 0, 0, 0, 0, 0, 0, 1351, 0, 0, 0, 0, 1352, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 1192, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 205, 253,
+            201, 1134, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 17, 0, 0, 0, 0, 0, 0, 1163, 209, 0, 0, 0, 0, 0, 4, 0, 80, 0, 0, 3, 1100, 0, 0, 0,
+            1156, 0, 0, 0, 212, 0, 0, 766, 0, 0, 1353, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1354, 0, 189, 0, 0, 1355, 0, 1356, 0, 0, 0, 0, 0, 0,
+            1357, 1358, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1359, 0,
+            0, 0, 1330, 1360, 0, 0, 0, 0, 0, 0, 1153, 1154, 1361, 1362, 1363, 1364, 1365, 0, 1366,
+            0, 165, 198, 325, 0, 0, 1367, 0, 0, 0, 214, 0, 0, 0, 1081, 0, 1368,
+        ],
+        [
+            765, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 1369, 1370, 0, 1371, 0, 0, 0, 1372, 0, 1373, 0, 0, 1374, 1375, 1376, 0, 1377,
+            1378, 1379, 0, 1380, 1381, 1382, 1383, 0, 1384, 0, 0, 1385, 1386, 1387, 1388, 1389,
+            1390, 1391, 0, 1392, 0, 1393, 0, 0, 1394, 0, 1395, 0, 1396, 0, 1397, 1394, 0, 0, 0, 0,
+            0, 0, 1398, 1399, 0, 0, 0, 1400, 1400,
+        ],
+        [
+            1400, 1401, 1402, 1403, 1404, 1405, 0, 0, 1406, 1407, 0, 1408, 1409, 1409, 0, 1410,
+            1411, 1412, 1413, 1414, 1415, 0, 1410, 0, 1416, 1417, 1418, 1419, 1420, 1421, 0, 1422,
+            1423, 0, 1424, 0, 1425, 1426, 0, 0, 1427, 1428, 0, 1429, 1430, 1431, 1432, 1433, 1434,
+            0, 1435, 1436, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            648, 650, 1136, 983, 1437, 1438, 1439, 1440, 1441, 1442, 1443, 1444, 1445, 1446, 1447,
+            1448, 1449, 1450, 1451, 1452, 1453, 1454, 1455, 1456, 1457, 1458, 1459, 1460, 1461,
+            1462, 1462, 1463, 1463, 1464, 1465, 1466, 1467, 1468, 1469, 1470, 1471, 1472, 1376,
+            1473, 1474, 1475, 1476, 1477, 1478, 1479, 1480, 1378, 1481, 1482, 1483, 1484, 1485,
+            1486, 1487, 1488, 1489, 1490, 1491, 1492,
+        ],
+        [
+            1493, 1494, 1495, 1496, 1497, 1498, 1499, 1500, 1501, 1502, 1503, 1504, 1505, 1506,
+            1507, 1508, 1509, 1510, 1511, 1512, 1513, 1514, 1515, 1516, 1517, 1518, 1519, 1520,
+            1521, 1522, 1523, 1524, 1525, 1526, 1527, 1528, 1529, 1530, 1531, 1532, 1533, 1534,
+            1535, 1536, 1537, 1538, 1539, 1540, 1541, 1542, 1543, 1544, 1545, 1546, 1547, 1548,
+            1549, 1550, 1551, 1552, 1553, 1554, 1555, 1556,
+        ],
+        [
+            1557, 1558, 1559, 1560, 1561, 1562, 1563, 1564, 1565, 1566, 1567, 1568, 1569, 1570,
+            1571, 1572, 1573, 1574, 1575, 1576, 1577, 1578, 1579, 1580, 1581, 1582, 1583, 1584,
+            1585, 1586, 1587, 1588, 1589, 1590, 1591, 1592, 1593, 1594, 1595, 1412, 1596, 1597,
+            1598, 1599, 1600, 1601, 1602, 1603, 1604, 1605, 1606, 1607, 1608, 1609, 1610, 1421,
+            1611, 1612, 1613, 1614, 1615, 1616, 1617, 1618,
+        ],
+        [
+            1619, 1425, 1620, 1621, 1622, 1623, 1624, 1625, 1626, 1627, 1628, 1629, 1630, 1631,
+            1632, 1633, 1634, 1635, 1636, 1637, 1638, 1639, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 1640, 1, 0, 0, 0, 3, 1166, 1167, 0, 0, 0, 0, 0, 0, 0, 0, 1641, 0, 1294, 1295, 0,
+            0, 0, 0, 1642, 1643, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1644, 546, 0, 0, 0,
+            0, 0, 983, 0, 0, 1641, 0, 1456, 1645, 1646, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 1647,
+            1648, 0, 0, 0, 765, 0, 0, 0, 1371, 0, 0, 0, 1477, 0, 0, 1451, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1466,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 1457, 0, 0, 1439, 0, 0, 983, 1444, 0, 0, 0, 0, 0, 0, 0, 0, 1649,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1462, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 198, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1442, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 699, 567, 700, 701, 635,
+            636, 702, 568, 703, 704, 705, 706, 707, 708, 709,
+        ],
+        [
+            579, 710, 711, 569, 586, 712, 570, 713, 714, 571, 715, 716, 717, 718, 719, 1650, 638,
+            1651, 639, 1652, 640, 1653, 641, 1654, 642, 643, 644, 1655, 1656, 645, 646, 647, 1657,
+            648, 649, 650, 1658, 573, 574, 634, 722, 724, 725, 729, 733, 735, 581, 738, 740, 582,
+            583, 585, 587, 588, 592, 594, 596, 597, 598, 599, 600, 603, 607, 745,
+        ],
+        [
+            620, 748, 618, 619, 631, 632, 753, 665, 666, 668, 677, 678, 680, 1659, 692, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 648, 650, 0, 983, 1136, 0, 1369, 0, 0,
+            0, 1438, 1166, 0, 0, 0, 1370, 1437, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1660, 1661, 1662, 1663, 1664, 1665, 1666, 1667, 1668, 1669, 1670, 1671, 1672, 1673,
+            1674, 1675, 1676, 1677, 1678, 1679, 1680, 1681, 1682, 1683, 1684, 1685, 1686, 1687,
+            1688, 1689, 1690, 0, 1691, 1692, 1693, 1694, 1695, 1696, 1697, 1698, 1699, 1700, 1701,
+            1702, 1703, 1704, 1705, 1706, 1707, 1708, 1709, 1710, 1711, 1712, 1713, 1714, 1715,
+            1716, 1717, 1718, 1719, 1720, 1721, 1722,
+        ],
+        [
+            1723, 1724, 1725, 1726, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            1727, 1728, 1729, 1730, 1731, 1732, 1733, 1734, 1735, 1736, 1737, 1738, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1739, 1740,
+            1741, 1742, 1743, 1744, 1745, 1746, 1747, 1748, 1749, 1750, 1751, 1752, 1753, 1754,
+            1755, 1756, 1757, 1758, 1759, 1760, 1761, 1762, 1763, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1764, 1765, 1766, 1767, 1768, 1769, 1770, 1771, 1772, 1773, 1774, 1775, 1776,
+            1777, 1778, 1779, 1780, 1781, 1782, 1783, 1784, 1785, 1786, 1787, 1788, 1789, 1790,
+            1791, 1792, 1793, 1794, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1795, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1796,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1797, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            648, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1136, 0, 0,
+            0, 0, 0, 0, 0, 0, 983,
+        ],
+        [
+            0, 0, 1798, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1799, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 1800, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1462, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1801, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1463, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 1802,
+        ],
+        [
+            1803, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1804, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1805, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1491,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1806, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 1807, 0, 0, 0, 0, 0, 1808, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1809, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1810, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 1811,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1812, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1813, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1814, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1815, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1816, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1817, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1818, 0, 0, 0, 0, 0, 1819, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1820,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1821, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1822, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1823, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1824, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 1825,
+        ],
+        [
+            0, 1826, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 1827, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1828, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1829, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 1830, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 1831, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1832, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1833,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1834, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 1835, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1836, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1837,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 1838, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1628, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 1839, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1840, 0, 0, 0, 0, 0, 0, 0,
+            1841, 0, 1842, 0, 0, 0, 0, 0, 0, 0, 0, 1843, 1844, 0, 0, 0, 1845, 0, 0, 0, 1846, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 1847, 0, 0, 0, 1848, 1849,
+        ],
+        [
+            1850, 0, 1851, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 208, 286, 761, 210, 0, 288,
+            204, 0, 197, 228, 229, 202, 223, 952, 206, 207, 764, 232, 65, 0, 205, 253, 203, 0, 0,
+            292, 212, 211, 1852, 199, 954, 201, 1134, 4, 3, 297, 759, 0, 1853, 324, 12, 1072, 1854,
+            0, 161, 1855, 765,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 324, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 66, 224, 0, 28, 0, 0, 0, 0, 0, 1180, 0, 0, 1856, 1857, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1156, 0, 0, 0, 0, 0, 0, 1858, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 172, 0, 163, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 0, 0, 1859, 1137,
+            1155, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1860, 190, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1861, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            235, 0, 0, 0, 253, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 66, 175, 171, 0, 0,
+            1862, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1863, 0, 170, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1864, 1865, 0, 0, 0, 0, 0, 0, 0, 77, 1866, 1867,
+            1868, 1869, 1870, 1871, 1872, 1873, 1872, 1873, 1874, 1875, 0, 0,
+        ],
+        [
+            267, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 139, 0, 0, 1859, 1137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            66, 0, 0, 0, 0, 0, 0, 1876, 0, 0, 0, 0, 0, 0, 0, 0, 74, 146, 0, 0, 408, 0, 0, 0, 0, 0,
+            0, 0, 0, 1877, 1878, 0, 1879, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 5, 0, 0, 198, 0, 0, 0, 0, 0, 1880, 0, 0, 223, 42,
+            1881, 1882, 0, 1883, 1884, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0,
+            1157, 197, 212, 200, 213, 1885, 1180, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 213, 0, 0, 0, 205,
+            1345, 1886, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 648, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1887, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1888, 1889, 1890, 1891, 704, 1892, 725, 1893, 705, 706, 1894, 731, 707, 1895,
+            734, 736, 1896, 738, 1897, 1898, 744, 1899, 1900, 1901, 1902, 1903, 1904, 1905, 1906, 0,
+            0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1907, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 1908, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 1909, 0, 0, 0, 0, 0, 0, 0, 0, 357, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1910, 0, 0, 1911, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 242, 0, 0, 42, 0, 0, 0,
+            0, 0, 0, 0, 217, 22, 1912,
+        ],
+        [
+            0, 1913, 1914, 0, 0, 0, 0, 241, 241, 0, 0, 0, 0, 1138, 144, 0, 0, 0, 144, 1346, 0, 1346,
+            0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 1915, 0, 1916, 1917, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1918, 1919, 219, 0, 1920, 28, 0, 0, 0, 0, 1921, 1922, 1923, 0, 1924, 0,
+        ],
+        [
+            1925, 241, 0, 136, 0, 0, 0, 244, 0, 0, 0, 245, 0, 0, 139, 0, 289, 0, 0, 1043, 0, 0, 0,
+            0, 0, 0, 0, 128, 1060, 0, 0, 248, 0, 0, 1919, 0, 0, 0, 0, 0, 0, 216, 77, 0, 0, 0, 1349,
+            225, 0, 0, 1045, 0, 0, 0, 215, 0, 0, 0, 0, 139, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1926, 1927, 1928, 1929, 1930,
+            1931, 1932, 1933, 1934, 1935, 1936, 1937, 1938, 1939, 1940, 1941,
+        ],
+        [
+            1942, 1943, 1944, 1945, 1946, 1947, 1948, 0, 0, 0, 0, 1949, 1950, 568, 1951, 1889, 1890,
+            1952, 1891, 1953, 1954, 1892, 1955, 1956, 1957, 1958, 1959, 1960, 1961, 580, 1962, 1963,
+            1964, 1965, 1966, 585, 1967, 1968, 569, 588, 592, 593, 602, 1969, 1970, 1971, 1972, 607,
+            608, 610, 579, 1973, 1974, 1975, 1976, 1977, 1978, 571, 1979, 1980, 0, 0, 0, 0,
+        ],
+        [
+            1981, 1982, 1587, 1983, 1984, 1985, 1986, 1638, 1638, 1987, 1595, 1988, 1989, 1990,
+            1991, 1992, 1993, 1994, 1995, 1996, 1997, 1998, 1999, 2000, 2001, 2002, 2003, 2004,
+            2005, 2006, 2007, 2008, 2009, 2010, 2011, 2012, 2013, 2014, 2015, 2016, 2017, 2018,
+            2019, 2020, 1835, 2021, 2022, 2023, 2024, 2025, 2026, 2027, 1553, 2028, 2029, 2030,
+            2031, 2032, 2033, 2034, 2035, 2036, 2037, 2038,
+        ],
+        [
+            1623, 2039, 2040, 2041, 2042, 2043, 2044, 2045, 2046, 2047, 2048, 2049, 2050, 2051,
+            2052, 2053, 2054, 2055, 2056, 2057, 2058, 2059, 2060, 2061, 2062, 2063, 2064, 2065,
+            1997, 2066, 2067, 2068, 2069, 2070, 2071, 2072, 2073, 2074, 2075, 2076, 2077, 2078,
+            2079, 2080, 2081, 2082, 2083, 2084, 2085, 1589, 2086, 2087, 2088, 2089, 2090, 2091,
+            2092, 2093, 2094, 2095, 2096, 2097, 2098, 2099,
+        ],
+        [
+            2100, 1468, 2101, 2102, 2103, 2104, 2105, 2106, 2107, 2108, 1451, 2109, 2110, 2111,
+            2112, 2113, 2114, 2115, 2116, 2117, 2118, 2119, 2120, 2121, 2122, 2123, 2124, 2125,
+            2126, 2127, 2128, 2129, 2130, 2084, 2131, 2132, 2133, 2134, 2135, 2136, 2137, 2138,
+            2068, 2139, 2140, 2141, 2142, 2143, 2144, 2145, 2146, 2147, 2148, 2149, 2150, 2151,
+            1837, 2152, 2153, 2154, 2155, 2156, 2157, 1997,
+        ],
+        [
+            2158, 2159, 2160, 2161, 1637, 2162, 2163, 2164, 2165, 2166, 2167, 2168, 2169, 2170,
+            2171, 2172, 2173, 2174, 2175, 2176, 2177, 2178, 2179, 2180, 2181, 2182, 2183, 2070,
+            2184, 2185, 2186, 2187, 2188, 2189, 2190, 2191, 2192, 2193, 2194, 2195, 2196, 1594,
+            2197, 2198, 2199, 2200, 2201, 2202, 2203, 2204, 2205, 2206, 2207, 2208, 2209, 1545,
+            2210, 2211, 2212, 2213, 2214, 2215, 2216, 2217,
+        ],
+        [
+            2218, 2219, 2220, 2221, 2222, 2223, 2224, 2225, 1572, 2226, 1575, 2227, 1374, 2228, 0,
+            0, 2229, 0, 2230, 0, 0, 2231, 2232, 2233, 2234, 2235, 2236, 2237, 2238, 2239, 1552, 0,
+            2240, 0, 2241, 0, 0, 2242, 2243, 0, 0, 0, 2244, 2245, 2246, 2247, 1835, 1837, 2248,
+            2249, 2250, 2251, 2252, 2253, 2254, 2255, 2256, 2257, 2258, 2259, 1474, 2260, 2261,
+            2262,
+        ],
+        [
+            2263, 2264, 2265, 2266, 2267, 2268, 2269, 2270, 2271, 1391, 2272, 2273, 2274, 2275,
+            2276, 2277, 2278, 2279, 2280, 2281, 2282, 2283, 2284, 2120, 2285, 2286, 2287, 2288,
+            2289, 1400, 1400, 2290, 2291, 2292, 2293, 2294, 2295, 2296, 1409, 2242, 2297, 2298,
+            2299, 2300, 2301, 2302, 0, 0, 2303, 2304, 2305, 2306, 2307, 2308, 2309, 2310, 2254,
+            2311, 2312, 2313, 2229, 2314, 2315, 2316,
+        ],
+        [
+            2317, 2318, 2319, 2320, 2321, 2322, 2323, 2324, 2325, 2262, 2326, 2263, 2327, 2328,
+            2329, 2330, 2331, 2230, 2018, 2332, 2333, 1506, 2085, 2167, 2334, 2335, 2270, 2336,
+            2271, 2337, 2338, 2339, 2232, 2340, 2341, 2342, 2343, 2344, 2233, 2345, 2346, 2347,
+            2348, 2349, 2350, 2284, 2351, 2352, 2120, 2353, 2288, 2354, 2355, 2356, 2357, 2358,
+            2292, 2359, 2241, 2360, 2293, 2066, 2361, 2294,
+        ],
+        [
+            2362, 2296, 2363, 2364, 2365, 2366, 2367, 2297, 2238, 2368, 2298, 2369, 2299, 2370,
+            1638, 2371, 2372, 2373, 2374, 2375, 1839, 2376, 2377, 2378, 2379, 2380, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ],
+        [
+            2381, 2382, 2383, 2384, 2385, 0, 2386, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2387, 2388,
+            2389, 2390, 2391, 0, 0, 0, 0, 0, 0, 0, 0, 2392, 1103, 1106, 2393, 2394, 2395, 2396,
+            2397, 2398, 1143, 0, 2399, 0, 2400, 0, 2401, 2401, 0, 0, 0, 0, 0, 0, 0, 0, 2402, 0, 0,
+            0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 2399, 0, 0, 0, 0, 0, 2403, 2404, 2404, 354, 354, 354, 354,
+            315, 315, 315, 315, 2405, 2405, 2405, 2405, 2406, 2406, 2406, 2406, 2407, 2407, 2407,
+            2407, 329, 329, 329, 329, 340, 340, 340, 340, 2408, 2408, 2408, 2408, 2409, 2409, 2409,
+            2409, 2410, 2410, 2410, 2410, 2411, 2411, 2411, 2411, 2412, 2412,
+        ],
+        [
+            2412, 2412, 2413, 2413, 2414, 2414, 334, 334, 332, 332, 337, 337, 335, 335, 342, 342,
+            342, 342, 382, 382, 382, 382, 2415, 2415, 2415, 2415, 2416, 2416, 2416, 2416, 318, 318,
+            329, 329, 329, 329, 347, 347, 217, 217, 217, 217, 217, 217, 217, 217, 318, 318, 2417,
+            2417, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 343, 343, 343, 343, 349, 349,
+            348, 348, 350, 350, 328, 352, 352, 2418, 2418, 351, 351, 354, 354, 354, 354, 318, 318,
+            2419, 2419, 2420, 2420, 2421, 2421, 2422, 2422, 2423, 2423, 2424, 2424, 2425, 2425,
+            2425, 2426, 2426, 2426, 318, 318, 318, 318,
+        ],
+        [
+            2427, 2428, 2429, 2426, 2426, 2430, 2431, 2432, 2433, 2434, 2434, 2435, 2436, 2437,
+            2438, 2439, 2439, 2440, 2441, 2442, 2442, 2443, 2444, 2445, 2446, 2447, 2448, 2449,
+            2450, 2451, 2452, 2453, 2454, 2455, 2456, 2457, 2458, 2459, 2460, 2461, 2462, 2463,
+            2464, 2465, 2466, 2467, 2468, 2469, 2470, 2471, 2471, 2472, 2473, 2474, 2474, 2475,
+            2476, 2477, 2478, 2479, 2480, 2481, 2481, 2482,
+        ],
+        [
+            2483, 2484, 2485, 2486, 2486, 2487, 2488, 2489, 2490, 2491, 2491, 2432, 2492, 2493,
+            2494, 2495, 2495, 2496, 2497, 2498, 2498, 2499, 2500, 2501, 2502, 2503, 2503, 2504,
+            2505, 2506, 2507, 2508, 2509, 2510, 2511, 2512, 2513, 2514, 2429, 2515, 2426, 2426,
+            2516, 2517, 2433, 2518, 2434, 2434, 2519, 2520, 2438, 2521, 2439, 2439, 2522, 2523,
+            2441, 2524, 2442, 2442, 2471, 2471, 2474, 2474,
+        ],
+        [
+            2475, 2479, 2480, 2481, 2481, 2485, 2486, 2486, 2525, 2490, 2526, 2527, 2494, 2528,
+            2495, 2495, 2506, 2529, 2530, 2502, 2531, 2503, 2503, 2427, 2428, 2532, 2429, 2420,
+            2430, 2431, 2432, 2433, 2533, 2435, 2436, 2437, 2438, 2534, 2441, 2443, 2444, 2445,
+            2446, 2447, 2449, 2450, 2451, 2452, 2453, 2454, 2535, 2455, 2456, 2457, 2458, 2459,
+            2460, 2462, 2463, 2464, 2465, 2466, 2467, 2468,
+        ],
+        [
+            2469, 2470, 2472, 2473, 2476, 2477, 2478, 2479, 2480, 2482, 2483, 2484, 2485, 2536,
+            2487, 2488, 2489, 2490, 2432, 2492, 2493, 2494, 2537, 2496, 2497, 2538, 2499, 2500,
+            2501, 2502, 2539, 2429, 2420, 2433, 2533, 2438, 2534, 2441, 2540, 2453, 2541, 2542,
+            2543, 2479, 2480, 2485, 2494, 2537, 2502, 2539, 2544, 2545, 2546, 2547, 2547, 2548,
+            2548, 2549, 2549, 2550, 2550, 2551, 2551, 2552,
+        ],
+        [
+            2552, 2553, 2553, 2554, 2554, 2555, 2555, 2556, 2556, 2557, 2558, 2559, 2542, 2560,
+            2561, 2562, 2563, 2547, 2547, 2548, 2548, 2549, 2549, 2550, 2550, 2551, 2551, 2552,
+            2552, 2553, 2553, 2554, 2554, 2555, 2555, 2556, 2556, 2557, 2558, 2559, 2542, 2560,
+            2561, 2562, 2563, 2557, 2558, 2559, 2542, 2541, 2543, 2461, 2450, 2451, 2452, 2557,
+            2558, 2559, 2461, 2462, 2564, 2564, 1294, 1295,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2565, 2566, 2566, 2567, 2568, 2569,
+            2570, 2571, 2572, 2572, 2573, 2573, 2574, 2575, 2576, 2577, 2577, 2578, 2579, 2579,
+            2580, 2580, 2581, 2582, 2582, 2583, 2584, 2584, 2585, 2585, 2586, 2587, 2587, 2588,
+            2588, 2589, 2590, 2591, 2592, 2592, 2593, 2594, 2595, 2595, 2596, 2596, 2597, 2598,
+        ],
+        [
+            2599, 2600, 2600, 2601, 2601, 2602, 2602, 2603, 2603, 2604, 2605, 2606, 2607, 2608,
+            2609, 2610, 0, 0, 2611, 2612, 2613, 2614, 2615, 2616, 2616, 2617, 2618, 2618, 2619,
+            2619, 2620, 2621, 2621, 2622, 2622, 2623, 2623, 2624, 2625, 2624, 2626, 2627, 2628,
+            2586, 2629, 2630, 2631, 2632, 2633, 2634, 2635, 2615, 2597, 2599, 2593, 2636, 2637,
+            2638, 2639, 2640, 2639, 2637, 2625, 2641,
+        ],
+        [
+            2642, 2643, 2644, 2640, 2591, 2581, 2626, 2617, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2645,
+            2646, 2647, 2648, 2649, 2650, 2651, 2652, 2653, 2645, 2654, 2655, 2656, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1080, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 161, 1273, 0, 0, 2657, 2658,
+            2659, 2660, 2661, 2662, 2663, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 10, 10, 10, 375, 375, 375, 0, 0, 0, 0, 0, 0, 0, 0, 162,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            2664, 2665, 2665, 311, 311, 312, 312, 313, 313, 314, 314, 314, 314, 4, 4, 2666, 2666,
+            2666, 2666, 21, 21, 2667, 2667, 2667, 2667, 315, 315, 315, 315, 2668, 2668, 2668, 2668,
+            2669, 2669, 2669, 2669, 2670, 2670, 2670, 2670, 2671, 2671, 2672, 2672, 2673, 2673,
+            2674, 2674, 2675, 2675, 2675, 2675, 316, 316, 316, 316, 2676, 2676, 2676, 2676, 2677,
+            2677, 2677,
+        ],
+        [
+            2677, 2678, 2678, 2678, 2678, 2679, 2679, 2679, 2679, 310, 310, 310, 310, 2680, 2680,
+            2680, 2680, 341, 341, 341, 341, 2681, 2681, 2681, 2681, 342, 342, 342, 342, 2682, 2682,
+            2682, 2682, 2683, 2683, 2683, 2683, 2684, 2684, 2684, 2684, 217, 217, 217, 217, 383,
+            383, 318, 318, 318, 318, 318, 318, 2685, 2685, 2686, 2686, 2687, 2687, 2688, 2688, 0, 0,
+            0,
+        ],
+        [
+            0, 80, 1, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 648, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 161, 0,
+            0, 0, 0, 0, 0, 199, 200, 228, 0, 201, 0, 0, 203, 4, 197, 204, 0, 206, 207, 3, 208, 0, 0,
+            232, 210, 0, 0, 0, 212, 211, 202, 1294, 1136, 1295, 2689, 0,
+        ],
+        [
+            5, 122, 0, 225, 0, 242, 0, 50, 274, 28, 226, 0, 4, 0, 0, 217, 218, 0, 0, 77, 0, 0, 216,
+            0, 16, 130, 0, 0, 1273, 0, 2690, 0, 0, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 4, 0, 0, 0, 0, 2691, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2692, 0, 0, 0, 0, 0, 0, 0, 2693, 2694, 2695,
+            2696, 0, 0, 0, 0, 0, 0, 2697, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 200, 0, 0, 766, 201, 223, 0, 0, 4, 0, 0, 205, 0, 0, 212, 0, 3, 0, 1159, 208, 232,
+            210, 0, 0, 0, 981, 0, 0, 0, 0, 199, 200, 228, 766, 0, 223, 0, 0, 0, 0, 0, 3, 0, 2698, 0,
+            0, 206, 210, 211, 236, 212, 251, 963, 0, 2699, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 203, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 2671, 0, 0, 383, 0, 0, 0, 2678, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2676, 0, 0, 202,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 200, 228, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 206, 2698, 0, 0, 210, 0, 212, 0,
+            0, 108, 0, 0, 0, 0, 326, 4, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2700, 0, 2701, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 290, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1109, 0, 0, 0, 228, 0, 0, 0, 0, 0,
+            764, 0, 0, 0, 2702, 232, 0, 0, 229, 0, 192, 0, 0, 0, 128, 1883, 0, 217, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 225, 0, 2703,
+        ],
+        [
+            0, 0, 2704, 1349, 0, 0, 0, 0, 77, 0, 0, 194, 0, 193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 2705, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 205, 0, 0, 0, 65, 0, 0, 0,
+            0, 0, 0, 0, 2706, 0, 0, 0,
+        ],
+        [
+            0, 0, 3, 1156, 227, 0, 0, 0, 0, 0, 0, 0, 0, 2707, 297, 0, 2708, 251, 2709, 0, 0, 0, 0,
+            0, 1044, 0, 0, 1345, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 217, 2710, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 144, 0, 0, 252, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 207, 0, 0, 3, 0, 204, 0, 0, 0,
+            228, 253, 0, 0, 0, 0, 0, 0, 0, 223, 764, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            306, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 324, 0, 0, 0, 0, 0, 0, 2711, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            2712, 0, 2713, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 434, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 434, 0, 0, 306, 2714, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            2715, 2716, 0, 2717, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2718, 0, 0, 0, 0, 0, 2719, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 2720, 0, 0, 0, 0, 0, 2721, 0, 0, 2722, 0, 2723, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2724, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2725, 0, 2726, 0, 2727, 0, 2728,
+            2729, 0, 2730, 0, 2731, 2732, 2733, 2734, 2735, 2736, 2737, 0, 0, 0, 2738, 2739, 2740,
+            2741, 2742, 0, 2743, 2744, 0, 2745, 2746, 0, 0, 0, 0, 0, 0, 0, 2747, 0, 0, 2748, 2749,
+            2750, 174,
+        ],
+        [
+            0, 409, 2751, 306, 2752, 2753, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 2754, 2755, 0, 0, 0,
+            2756, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2757, 2757,
+            2758, 2759, 2760, 2761, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 2762, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            6, 0, 0, 0, 0, 0, 216, 0, 0, 0, 136, 0, 0, 0, 136, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 253, 0, 223, 764, 211, 0, 201, 0, 2763, 202, 0, 0, 408, 0, 201, 763, 0, 0, 764,
+            0, 0, 3, 0, 1159, 297, 0, 0, 76, 210, 0, 0, 0,
+        ],
+        [
+            216, 77, 223, 28, 1043, 0, 2709, 0, 217, 0, 74, 0, 408, 0, 128, 0, 0, 0, 0, 0, 0, 239,
+            408, 217, 144, 0, 0, 0, 130, 0, 0, 0, 3, 0, 0, 6, 361, 202, 292, 0, 0, 228, 0, 0, 212,
+            0, 0, 292, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 2764, 2765, 2766, 2767, 2768, 0, 2769, 2770, 2771, 0, 0, 0,
+            0, 0, 2772, 2773, 2774, 2775, 2776, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 2777, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2778, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2779,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1288, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 233, 253, 0, 210, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 764, 0, 0, 0,
+            766, 0, 2780, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1109, 0, 4, 0, 0, 0, 0, 290, 0, 0, 0, 0, 0, 0,
+            0, 65, 0, 0, 0, 0, 232, 74, 0, 205, 0, 159,
+        ],
+        [
+            199, 0, 297, 211, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 199, 200, 228, 761,
+            201, 223, 288, 203, 4, 197, 204, 764, 206, 207, 3, 208, 1100, 65, 232, 210, 297, 253,
+            292, 212, 211, 202, 3, 4, 66, 74, 763, 76, 239, 2709, 108, 408, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1296, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 324, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 2781, 0, 0, 0, 74, 0, 0, 0, 0, 192, 0, 253, 0, 1136, 0, 0, 2709, 223, 1283, 1109,
+            65, 954, 0, 0, 60, 2782, 1157, 0, 0, 0, 0, 290, 762, 0, 0, 0, 0, 0, 0, 0, 764, 1109, 0,
+            0, 0, 0, 953, 0, 0, 0, 0, 0, 158, 159, 2783, 2784, 983, 1136, 0, 0, 0, 2785,
+        ],
+        [
+            0, 0, 0, 0, 0, 759, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            199, 200, 228, 761, 201, 223, 288, 203, 4, 197, 204, 764, 206, 207, 3, 208, 1100, 65,
+            232, 210, 297, 253, 292, 212, 211, 202, 122, 46, 225, 286, 242, 42, 50, 274, 28, 226,
+            2786, 4, 6, 299, 217, 218, 291, 241, 77, 2787, 144, 216, 136, 16, 130, 1043, 199, 200,
+            228, 761, 201, 223, 288, 203, 4, 197, 204, 764,
+        ],
+        [
+            206, 207, 3, 208, 1100, 65, 232, 210, 297, 253, 292, 212, 211, 202, 122, 46, 225, 286,
+            242, 42, 50, 0, 28, 226, 2786, 4, 6, 299, 217, 218, 291, 241, 77, 2787, 144, 216, 136,
+            16, 130, 1043, 199, 200, 228, 761, 201, 223, 288, 203, 4, 197, 204, 764, 206, 207, 3,
+            208, 1100, 65, 232, 210, 297, 253, 292, 212,
+        ],
+        [
+            211, 202, 122, 46, 225, 286, 242, 42, 50, 274, 28, 226, 2786, 4, 6, 299, 217, 218, 291,
+            241, 77, 2787, 144, 216, 136, 16, 130, 1043, 199, 0, 228, 761, 0, 0, 288, 0, 0, 197,
+            204, 0, 0, 207, 3, 208, 1100, 0, 232, 210, 297, 253, 292, 212, 211, 202, 122, 46, 225,
+            286, 0, 42, 0, 274, 28, 226,
+        ],
+        [
+            2786, 4, 6, 299, 0, 218, 291, 241, 77, 2787, 144, 216, 136, 16, 130, 1043, 199, 200,
+            228, 761, 201, 223, 288, 203, 4, 197, 204, 764, 206, 207, 3, 208, 1100, 65, 232, 210,
+            297, 253, 292, 212, 211, 202, 122, 46, 225, 286, 242, 42, 50, 274, 28, 226, 2786, 4, 6,
+            299, 217, 218, 291, 241, 77, 2787, 144, 216,
+        ],
+        [
+            136, 16, 130, 1043, 199, 200, 0, 761, 201, 223, 288, 0, 0, 197, 204, 764, 206, 207, 3,
+            208, 1100, 0, 232, 210, 297, 253, 292, 212, 211, 0, 122, 46, 225, 286, 242, 42, 50, 274,
+            28, 226, 2786, 4, 6, 299, 217, 218, 291, 241, 77, 2787, 144, 216, 136, 16, 130, 1043,
+            199, 200, 0, 761, 201, 223, 288, 0,
+        ],
+        [
+            4, 197, 204, 764, 206, 0, 3, 0, 0, 0, 232, 210, 297, 253, 292, 212, 211, 0, 122, 46,
+            225, 286, 242, 42, 50, 274, 28, 226, 2786, 4, 6, 299, 217, 218, 291, 241, 77, 2787, 144,
+            216, 136, 16, 130, 1043, 199, 200, 228, 761, 201, 223, 288, 203, 4, 197, 204, 764, 206,
+            207, 3, 208, 1100, 65, 232, 210,
+        ],
+        [
+            297, 253, 292, 212, 211, 202, 122, 46, 225, 286, 242, 42, 50, 274, 28, 226, 2786, 4, 6,
+            299, 217, 218, 291, 241, 77, 2787, 144, 216, 136, 16, 130, 1043, 199, 200, 228, 761,
+            201, 223, 288, 203, 4, 197, 204, 764, 206, 207, 3, 208, 1100, 65, 232, 210, 297, 253,
+            292, 212, 211, 202, 122, 46, 225, 286, 242, 42,
+        ],
+        [
+            50, 274, 28, 226, 2786, 4, 6, 299, 217, 218, 291, 241, 77, 2787, 144, 216, 136, 16, 130,
+            1043, 199, 200, 228, 761, 201, 223, 288, 203, 4, 197, 204, 764, 206, 207, 3, 208, 1100,
+            65, 232, 210, 297, 253, 292, 212, 211, 202, 122, 46, 225, 286, 242, 42, 50, 274, 28,
+            226, 2786, 4, 6, 299, 217, 218, 291, 241,
+        ],
+        [
+            77, 2787, 144, 216, 136, 16, 130, 1043, 199, 200, 228, 761, 201, 223, 288, 203, 4, 197,
+            204, 764, 206, 207, 3, 208, 1100, 65, 232, 210, 297, 253, 292, 212, 211, 202, 122, 46,
+            225, 286, 242, 42, 50, 274, 28, 226, 2786, 4, 6, 299, 217, 218, 291, 241, 77, 2787, 144,
+            216, 136, 16, 130, 1043, 199, 200, 228, 761,
+        ],
+        [
+            201, 223, 288, 203, 4, 197, 204, 764, 206, 207, 3, 208, 1100, 65, 232, 210, 297, 253,
+            292, 212, 211, 202, 122, 46, 225, 286, 242, 42, 50, 274, 28, 226, 2786, 4, 6, 299, 217,
+            218, 291, 241, 77, 2787, 144, 216, 136, 16, 130, 1043, 199, 200, 228, 761, 201, 223,
+            288, 203, 4, 197, 204, 764, 206, 207, 3, 208,
+        ],
+        [
+            1100, 65, 232, 210, 297, 253, 292, 212, 211, 202, 122, 46, 225, 286, 242, 42, 50, 274,
+            28, 226, 2786, 4, 6, 299, 217, 218, 291, 241, 77, 2787, 144, 216, 136, 16, 130, 1043,
+            28, 298, 0, 0, 199, 200, 233, 766, 201, 202, 203, 60, 4, 204, 205, 206, 207, 2788, 3,
+            235, 208, 60, 209, 210, 211, 236, 212, 251,
+        ],
+        [
+            963, 2763, 122, 213, 130, 214, 128, 2789, 59, 60, 28, 215, 1345, 11, 216, 2790, 217,
+            221, 218, 222, 217, 219, 144, 220, 1346, 252, 1180, 2791, 128, 60, 215, 220, 218, 221,
+            199, 200, 233, 766, 201, 202, 203, 60, 4, 204, 205, 206, 207, 2788, 3, 235, 208, 60,
+            209, 210, 211, 236, 212, 251, 963, 2763, 122, 213, 130, 214,
+        ],
+        [
+            128, 2789, 59, 60, 28, 215, 1345, 11, 216, 2790, 217, 221, 218, 222, 217, 219, 144, 220,
+            1346, 252, 1180, 2791, 128, 60, 215, 220, 218, 221, 199, 200, 233, 766, 201, 202, 203,
+            60, 4, 204, 205, 206, 207, 2788, 3, 235, 208, 60, 209, 210, 211, 236, 212, 251, 963,
+            2763, 122, 213, 130, 214, 128, 2789, 59, 60, 28, 215,
+        ],
+        [
+            1345, 11, 216, 2790, 217, 221, 218, 222, 217, 219, 144, 220, 1346, 252, 1180, 2791, 128,
+            60, 215, 220, 218, 221, 199, 200, 233, 766, 201, 202, 203, 60, 4, 204, 205, 206, 207,
+            2788, 3, 235, 208, 60, 209, 210, 211, 236, 212, 251, 963, 2763, 122, 213, 130, 214, 128,
+            2789, 59, 60, 28, 215, 1345, 11, 216, 2790, 217, 221,
+        ],
+        [
+            218, 222, 217, 219, 144, 220, 1346, 252, 1180, 2791, 128, 60, 215, 220, 218, 221, 199,
+            200, 233, 766, 201, 202, 203, 60, 4, 204, 205, 206, 207, 2788, 3, 235, 208, 60, 209,
+            210, 211, 236, 212, 251, 963, 2763, 122, 213, 130, 214, 128, 2789, 59, 60, 28, 215,
+            1345, 11, 216, 2790, 217, 221, 218, 222, 217, 219, 144, 220,
+        ],
+        [
+            1346, 252, 1180, 2791, 128, 60, 215, 220, 218, 221, 223, 2792, 0, 0, 3, 4, 66, 74, 763,
+            76, 239, 2709, 108, 408, 3, 4, 66, 74, 763, 76, 239, 2709, 108, 408, 3, 4, 66, 74, 763,
+            76, 239, 2709, 108, 408, 3, 4, 66, 74, 763, 76, 239, 2709, 108, 408, 3, 4, 66, 74, 763,
+            76, 239, 2709, 108, 408,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 4, 2793, 358, 0, 108, 2791, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            4, 2666, 2668, 2671, 0, 383, 2674, 2669, 2678, 318, 342, 2682, 2683, 2684, 2675, 310,
+            341, 2676, 2681, 2673, 316, 2667, 315, 2670, 2672, 2677, 2679, 2680, 318, 318, 327, 327,
+            0, 2666, 2668, 0, 217, 0, 0, 2669, 0, 318, 342, 2682, 2683, 2684, 2675, 310, 341, 2676,
+            2681, 0, 316, 2667, 315, 2670, 0, 2677, 0, 2680, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 2668, 0, 0, 0, 0, 2669, 0, 318, 0, 2682, 0, 2684, 2675, 310, 0, 2676, 2681, 0,
+            316, 0, 0, 2670, 0, 2677, 0, 2680, 0, 318, 0, 327, 0, 2666, 2668, 0, 217, 0, 0, 2669,
+            2678, 318, 342, 0, 2683, 2684, 2675, 310, 341, 2676, 2681, 0, 316, 2667, 315, 2670, 0,
+            2677, 2679, 2680, 318, 0, 327, 0,
+        ],
+        [
+            4, 2666, 2668, 2671, 217, 383, 2674, 2669, 2678, 318, 0, 2682, 2683, 2684, 2675, 310,
+            341, 2676, 2681, 2673, 316, 2667, 315, 2670, 2672, 2677, 2679, 2680, 0, 0, 0, 0, 0,
+            2666, 2668, 2671, 0, 383, 2674, 2669, 2678, 318, 0, 2682, 2683, 2684, 2675, 310, 341,
+            2676, 2681, 2673, 316, 2667, 315, 2670, 2672, 2677, 2679, 2680, 0, 0, 0, 0,
+        ],
+        [
+            2794, 2795, 2796, 2797, 2798, 2799, 2800, 2801, 2802, 2803, 2804, 0, 0, 0, 0, 2805,
+            2806, 2807, 2808, 2809, 2810, 2811, 2812, 2813, 1203, 2814, 2815, 2816, 2817, 2818,
+            2819, 2820, 2821, 2822, 2823, 2824, 2825, 2826, 2827, 2828, 2829, 2830, 2823, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2831, 2832, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            2833, 1693, 1692, 2834, 2835, 2836, 2837, 2838, 2839, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2840, 0, 0, 0, 0, 0, 1191, 2840,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            2841, 2780, 766, 0, 1283, 0, 0, 2842, 2843, 0, 2844, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1155, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 2845, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 1159, 2846, 0, 0, 0, 0, 0,
+            0, 2847, 0, 1291, 0, 0, 0, 0, 0, 0, 0, 0, 0, 210, 0, 0, 2848, 2849, 0, 0, 0, 0, 2850, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 4, 66, 74, 763, 76, 239,
+            2709, 108, 408, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ],
+        [
+            2851, 2852, 2853, 2854, 2855, 2248, 2856, 1798, 2857, 2858, 2249, 2859, 2860, 2861,
+            2250, 2862, 2863, 2864, 2865, 2866, 2867, 2868, 2869, 2870, 2871, 2872, 2873, 2304,
+            2874, 1449, 2875, 2876, 2877, 2878, 2879, 2880, 2881, 2309, 2251, 2252, 2310, 2882,
+            2883, 2072, 2884, 2253, 2885, 2886, 2887, 2888, 2888, 2888, 2889, 2890, 2891, 2892,
+            2893, 2894, 2895, 2896, 2897, 2898, 2899, 2900,
+        ],
+        [
+            2901, 2902, 2903, 1800, 2904, 2905, 2905, 2312, 2906, 2907, 2908, 2909, 2255, 2910,
+            2911, 2912, 2218, 2913, 2914, 2915, 2916, 2917, 2918, 2919, 2920, 2921, 2922, 2923,
+            2924, 2925, 2926, 2927, 2928, 2929, 2930, 2931, 2932, 2933, 2934, 2935, 2936, 2937,
+            2938, 2938, 2939, 2940, 2941, 2068, 2942, 2943, 2944, 2945, 2946, 1376, 2947, 2948,
+            1474, 2949, 2950, 2951, 2952, 2953, 2954, 2955,
+        ],
+        [
+            2956, 2957, 2958, 2959, 2960, 2961, 2962, 2963, 2964, 2965, 2966, 2967, 2968, 2969,
+            2017, 2970, 1483, 2971, 2971, 2972, 2973, 2973, 2974, 2975, 2976, 2977, 2978, 2979,
+            2980, 2981, 2982, 2983, 2984, 2985, 2986, 2260, 2987, 2988, 2989, 2990, 2324, 2990,
+            2991, 2262, 2992, 2993, 2994, 2995, 2263, 1990, 2996, 2997, 2998, 2999, 3000, 3001,
+            3002, 3003, 3004, 3005, 3006, 3007, 3008, 3009,
+        ],
+        [
+            3010, 3011, 3012, 3013, 3014, 3015, 3016, 3017, 2264, 3018, 3019, 3020, 3021, 3022,
+            3023, 2266, 3024, 3025, 3026, 3027, 3028, 3029, 3030, 3031, 2018, 2332, 3032, 3033,
+            3034, 3035, 3036, 3037, 3038, 3039, 2267, 3040, 3041, 3042, 3043, 2374, 3044, 3045,
+            3046, 3047, 3048, 3049, 3050, 3051, 3052, 3053, 3054, 3055, 3056, 2085, 3057, 3058,
+            3059, 3060, 3061, 3062, 3063, 3064, 3065, 3066,
+        ],
+        [
+            3067, 2268, 2167, 3068, 3069, 3070, 3071, 3072, 3073, 3074, 3075, 2335, 3076, 3077,
+            3078, 3079, 3080, 3081, 3082, 3083, 2336, 3084, 3085, 3086, 3087, 3088, 3089, 3090,
+            3091, 3092, 3093, 3094, 3095, 2338, 3096, 3097, 3098, 3099, 3100, 3101, 3102, 3103,
+            3104, 3105, 3106, 3106, 3107, 3108, 2340, 3109, 3110, 3111, 3112, 3113, 3114, 3115,
+            2071, 3116, 3117, 3118, 3119, 3120, 3121, 3122,
+        ],
+        [
+            2346, 3123, 3124, 3125, 3126, 3127, 3128, 3128, 2347, 1839, 3129, 3130, 3131, 3132,
+            3133, 2034, 2349, 3134, 3135, 2278, 3136, 3137, 2237, 3138, 3139, 2282, 3140, 3141,
+            3142, 3143, 3143, 3144, 3145, 3146, 3147, 3148, 3149, 3150, 3151, 3152, 3153, 3154,
+            3155, 3156, 3157, 3158, 3159, 3160, 3161, 3162, 3163, 3164, 3165, 3166, 3167, 3168,
+            3169, 3170, 2288, 3171, 3172, 3173, 3174, 3175,
+        ],
+        [
+            3176, 3177, 3178, 3179, 3180, 3181, 3182, 3183, 3184, 3185, 3186, 2972, 3187, 3188,
+            3189, 3190, 3191, 3192, 3193, 3194, 3195, 3196, 3197, 3198, 2088, 3199, 3200, 3201,
+            3202, 3203, 3204, 2290, 3205, 3206, 3207, 3208, 3209, 3210, 3211, 3212, 3213, 3214,
+            3215, 3216, 3217, 3218, 3219, 3220, 3221, 3222, 3223, 3224, 2029, 3225, 3226, 3227,
+            3228, 3229, 3230, 2356, 3231, 3232, 3233, 3234,
+        ],
+        [
+            3235, 3236, 3237, 3238, 1573, 3239, 3240, 3241, 3242, 3243, 3244, 3245, 3246, 3247,
+            3248, 3249, 2361, 2362, 1580, 3250, 3251, 3252, 3253, 3254, 3255, 3256, 3257, 3258,
+            3259, 3260, 3261, 2363, 3262, 3263, 3264, 3265, 3266, 3267, 3268, 3269, 3270, 3271,
+            3272, 3273, 3274, 3275, 3276, 3277, 3278, 3279, 3280, 3281, 3282, 3283, 3284, 3285,
+            3286, 3287, 3288, 3289, 3290, 3291, 2369, 2369,
+        ],
+        [
+            3292, 3293, 3294, 3295, 3296, 3297, 3298, 3299, 3300, 3301, 2370, 3302, 3303, 3304,
+            3305, 3306, 3307, 3308, 3309, 3310, 3311, 1625, 3312, 1629, 3313, 3314, 3315, 3316,
+            1634, 3317, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ],
+    ],
+};
+
+/// Codepoints with an `Identifier_Status` of `Allowed`
+pub(crate) static IDENTIFIER_ALLOWED: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 16, 19,
+        20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 27, 0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 5, 5, 7, 8, 9,
+        21, 22, 22, 22, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 16, 19, 20, 21, 22,
+            23, 24, 25, 26, 27, 28, 29, 27, 27, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42,
+            43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60,
+        ],
+        [
+            16, 61, 62, 63, 27, 27, 27, 27, 16, 64, 65, 66, 67, 68, 69, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 70, 71, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 72, 27, 27, 27, 27, 27, 16, 16, 73, 74, 75, 76, 77, 78,
+        ],
+        [
+            79, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 80, 27, 81, 82, 27, 27, 27, 27, 27, 27, 27, 27,
+        ],
+        [
+            83, 33, 84, 85, 86, 27, 23, 27, 27, 27, 27, 27, 27, 27, 27, 27, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+        ],
+        [
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 27, 16, 16, 16, 16, 16, 16, 16, 16,
+        ],
+        [
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+        ],
+        [
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 87, 27, 27, 88, 27, 89, 90, 27, 27, 27, 27, 27, 27, 27, 91, 27, 92, 27, 27,
+            93, 94, 27, 27, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+        ],
+        [
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 95, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+        ],
+        [
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+        ],
+        [
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 96, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+        ],
+        [
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 97, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+        ],
+        [
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 98,
+        ],
+        [
+            27, 27, 27, 27, 99, 100, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+        ],
+        [
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 101, 27, 27, 27,
+        ],
+        [
+            27, 27, 102, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 103, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+        ],
+        [
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 17, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+        ],
+        [
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 74, 16, 16, 16, 104, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+        ],
+        [
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 105, 16, 16, 16, 16, 16,
+        ],
+        [
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 106, 16, 16, 16, 16, 16, 16, 16, 16, 16, 62, 27, 27, 27, 27, 27, 27,
+        ],
+        [
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 107, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+        ],
+        [
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 108, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+        ],
+        [
+            0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 5, 5, 7, 8, 9, 8, 10, 8, 8, 8, 8, 11, 8, 8, 8, 8, 12,
+            8, 13, 14, 8, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 15, 16, 17, 5, 18, 8, 5, 19, 20, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 8, 8, 8, 8,
+        ],
+        [
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 8,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23,
+            23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23,
+            23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23, 23,
+        ],
+    ],
+    leaves: &[
+        0x07FF_6080_0000_0000,
+        0x07FF_FFFE_87FF_FFFE,
+        0x0080_0000_0000_0000,
+        0xFF7F_FFFF_FF7F_FFFF,
+        0x7FF3_FFFF_FFFF_FFFF,
+        0x7FFF_FFFF_FFFF_FDFE,
+        0x0001_8003_0000_8000,
+        0xFF31_FFCF_DFFF_E000,
+        0x000F_FFC0_CFFF_FFFF,
+        0x0000_0000_0200_0000,
+        0x1800_0000_0000_0000,
+        0x0000_1000_0000_0000,
+        0x0323_61F8_081B_9FDF,
+        0x3820_0000_0000_0024,
+        0xFFFF_FFFB_FFFF_D740,
+        0xF000_0000_0000_7FFF,
+        0xFFFF_FFFF_FFFF_FFFF,
+        0x0000_0000_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_FC00,
+        0xFFFE_C3FF_FFFF_0000,
+        0xFFFF_FFFE_027F_FFFF,
+        0x0010_0000_0000_047F,
+        0x001F_87FF_FFFF_0000,
+        0xFFFF_FFFF_0000_0000,
+        0xFE17_03FF_003F_FFFE,
+        0xFFFF_FFFD_FFFF_BFFF,
+        0xFFFF_C060_002F_FFFF,
+        0x0000_0000_0000_0000,
+        0xFFFF_FFFF_FFFF_0000,
+        0x0003_FFFF_FFFF_FFFF,
+        0xFFFF_0000_0000_0000,
+        0xFFE4_1FFF_0000_7EFF,
+        0x0000_0000_0000_03FF,
+        0xFFFF_FFFF_FFFF_FFFE,
+        0xFEFE_FFCF_00C1_BFFF,
+        0xF3C5_FDFF_FFF9_9FEE,
+        0x4003_FFCF_0080_799F,
+        0xD325_FDFF_FFF9_87EE,
+        0x001F_FFC0_1000_3987,
+        0xF3ED_FDFF_FFFB_BFEE,
+        0xFC00_FFCF_0001_3BBF,
+        0xF3ED_FDFF_FFF9_9FEE,
+        0x0002_FFC3_80E0_398F,
+        0xC3FF_C718_D63D_C7EC,
+        0x0000_FFC0_0081_3DC7,
+        0xF3EF_FDFF_FFFD_DFFE,
+        0x0000_FFC3_2060_3DDF,
+        0xF3EF_FDFF_FFFD_DFED,
+        0x000E_FFCF_2060_3DDF,
+        0xE7FF_FFFF_FFFD_DFED,
+        0xFC00_FFC3_00F0_7DCF,
+        0x2FFB_FFBF_FC7E_7FEC,
+        0x0004_0000_7F5F_847F,
+        0x07F7_FFFF_FFFF_FFFE,
+        0x0000_0000_03FF_7FFF,
+        0x3FF7_FFAF_FFFF_F7D6,
+        0x0000_0000_C3FF_7F5F,
+        0xC0A0_03FF_0000_0801,
+        0xFC16_1DFF_EF7B_DEF7,
+        0x1DFF_EF7B_DEF7_FFDD,
+        0x0000_0000_0000_0040,
+        0xFFFF_FFFF_FFFF_03FF,
+        0x0000_0000_3FFF_FFFF,
+        0xE781_FFFF_FFFF_2080,
+        0xFFFF_FFFF_3D7F_3DFF,
+        0x7F3D_FFFF_FFFF_3DFF,
+        0xFFFF_FFFF_FF7F_FF3D,
+        0xFFFF_FFFF_FF3D_FFFF,
+        0x0000_0000_E7FF_FFFF,
+        0x0000_0000_0000_FFFF,
+        0xFFCF_FEE7_FFFF_FFFF,
+        0x0000_03FF_1085_3FFF,
+        0xE7FF_FFFF_FFFF_0000,
+        0xFFFF_FFFF_43FF_FFFF,
+        0x03FF_FFFF_FFFF_FFFF,
+        0xFFFF_FFFF_3F3F_FFFF,
+        0x1555_FFFF_AAFF_3F3F,
+        0x17DF_FFFF_FFFF_FFFF,
+        0x15DC_17F7_07C7_15DC,
+        0x0000_0080_0201_0000,
+        0x0000_2080_0000_0000,
+        0x7F7F_7F7F_007F_FFFF,
+        0x0000_0000_7F7F_7F7F,
+        0x0000_0000_0000_00E0,
+        0xFFFF_FFFF_667F_FFFF,
+        0x7FFF_FFFF_FFFF_FFFF,
+        0x0000_BFFF_FFFF_FFE0,
+        0x8000_0000_0000_0000,
+        0x0000_0000_FF80_0000,
+        0x0000_0400_000C_2100,
+        0x0000_0000_03EB_07FF,
+        0x7FFF_FF80_0000_0000,
+        0xFC7F_FFFF_0000_0000,
+        0x0000_7F7F_007E_7E7E,
+        0x0000_00C0_0000_0000,
+        0x0000_000F_FFFF_FFFF,
+        0x0000_039A_801A_C000,
+        0x1800_0000_0000_000A,
+        0x0003_0000_0000_0000,
+        0x0004_0007_8000_0000,
+        0x0000_00F0_0027_0000,
+        0x0000_07E0_7FFF_FFFF,
+        0x0000_0000_0000_8000,
+        0x7FFF_6F7F_0000_0000,
+        0xFFFF_FFFF_3FFF_FFFF,
+        0xFFFF_0003_FFFF_FFFF,
+        0xFFFF_0001_FFFF_FFFF,
+        0xFFFF_FFFF_FFFF_07FF,
+        0x0000_FFFF_FFFF_FFFF,
+    ],
+};
+
+/// Codepoints with the `Default_Ignorable_Code_Point` property
+pub(crate) static DEFAULT_IGNORABLE: Utf8Trie<u64> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3,
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 8, 9, 9, 11, 9, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 5, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            7, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0,
+            0, 11, 0, 0, 12, 13,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 6, 4,
+            7, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+            16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 10, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+    ],
+    leaves: &[
+        0x0000_0000_0000_0000,
+        0x0000_2000_0000_0000,
+        0x0000_0000_0000_8000,
+        0x0000_0000_1000_0000,
+        0x0000_0001_8000_0000,
+        0x0030_0000_0000_0000,
+        0x0000_0000_0000_F800,
+        0x0000_7C00_0000_F800,
+        0x0000_FFFF_0000_0000,
+        0x0000_0010_0000_0000,
+        0x0000_0000_0000_FFFF,
+        0x8000_0000_0000_0000,
+        0x0000_0001_0000_0000,
+        0x01FF_0000_0000_0000,
+        0x0000_000F_0000_0000,
+        0x07F8_0000_0000_0000,
+        0xFFFF_FFFF_FFFF_FFFF,
+    ],
+};
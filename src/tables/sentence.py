@@ -0,0 +1,46 @@
+"""Generates src/tables/sentence.rs: the property sentence segmentation (UAX #29) is defined in
+terms of.
+
+    python3 src/tables/sentence.py <path to UCD> > src/tables/sentence.rs
+"""
+
+from ucd import enumerated_property, header, ucd_dir, value_trie
+
+# short alias to `SentenceBreak` variant, by the long alias used in SentenceBreakProperty.txt
+SENTENCE_BREAKS = {
+    "Other": ("XX", "Other"),
+    "CR": ("CR", "CR"),
+    "LF": ("LF", "LF"),
+    "Extend": ("EX", "Extend"),
+    "Sep": ("SE", "Sep"),
+    "Format": ("FO", "Format"),
+    "Sp": ("SP", "Sp"),
+    "Lower": ("LO", "Lower"),
+    "Upper": ("UP", "Upper"),
+    "OLetter": ("LE", "OLetter"),
+    "Numeric": ("NU", "Numeric"),
+    "ATerm": ("AT", "ATerm"),
+    "SContinue": ("SC", "SContinue"),
+    "STerm": ("ST", "STerm"),
+    "Close": ("CL", "Close"),
+}
+
+
+def main() -> None:
+    breaks = enumerated_property(ucd_dir() / "auxiliary/SentenceBreakProperty.txt", "Other")
+
+    print(header("sentence.py", ["auxiliary/SentenceBreakProperty.txt"]))
+    print("use super::Utf8Trie;")
+    print("use crate::sentence::SentenceBreak;")
+    print()
+
+    for short, variant in SENTENCE_BREAKS.values():
+        print(f"/// `SentenceBreak::{variant}`, shortened to keep the table below readable")
+        print(f"const {short}: SentenceBreak = SentenceBreak::{variant};")
+
+    print()
+    print(value_trie("SENTENCE_BREAK", "The `Sentence_Break` of every codepoint", "SentenceBreak", [SENTENCE_BREAKS[b][0] for b in breaks]), end="")
+
+
+if __name__ == "__main__":
+    main()
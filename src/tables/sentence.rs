@@ -0,0 +1,2057 @@
+//! Generated by `src/tables/sentence.py` from the Unicode 17.0.0 files
+//! auxiliary/SentenceBreakProperty.txt. Do not edit by hand.
+
+use super::Utf8Trie;
+use crate::sentence::SentenceBreak;
+
+/// `SentenceBreak::Other`, shortened to keep the table below readable
+const XX: SentenceBreak = SentenceBreak::Other;
+/// `SentenceBreak::CR`, shortened to keep the table below readable
+const CR: SentenceBreak = SentenceBreak::CR;
+/// `SentenceBreak::LF`, shortened to keep the table below readable
+const LF: SentenceBreak = SentenceBreak::LF;
+/// `SentenceBreak::Extend`, shortened to keep the table below readable
+const EX: SentenceBreak = SentenceBreak::Extend;
+/// `SentenceBreak::Sep`, shortened to keep the table below readable
+const SE: SentenceBreak = SentenceBreak::Sep;
+/// `SentenceBreak::Format`, shortened to keep the table below readable
+const FO: SentenceBreak = SentenceBreak::Format;
+/// `SentenceBreak::Sp`, shortened to keep the table below readable
+const SP: SentenceBreak = SentenceBreak::Sp;
+/// `SentenceBreak::Lower`, shortened to keep the table below readable
+const LO: SentenceBreak = SentenceBreak::Lower;
+/// `SentenceBreak::Upper`, shortened to keep the table below readable
+const UP: SentenceBreak = SentenceBreak::Upper;
+/// `SentenceBreak::OLetter`, shortened to keep the table below readable
+const LE: SentenceBreak = SentenceBreak::OLetter;
+/// `SentenceBreak::Numeric`, shortened to keep the table below readable
+const NU: SentenceBreak = SentenceBreak::Numeric;
+/// `SentenceBreak::ATerm`, shortened to keep the table below readable
+const AT: SentenceBreak = SentenceBreak::ATerm;
+/// `SentenceBreak::SContinue`, shortened to keep the table below readable
+const SC: SentenceBreak = SentenceBreak::SContinue;
+/// `SentenceBreak::STerm`, shortened to keep the table below readable
+const ST: SentenceBreak = SentenceBreak::STerm;
+/// `SentenceBreak::Close`, shortened to keep the table below readable
+const CL: SentenceBreak = SentenceBreak::Close;
+
+/// The `Sentence_Break` of every codepoint
+pub(crate) static SENTENCE_BREAK: Utf8Trie<[SentenceBreak; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+        21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 5, 5, 7, 8, 9,
+        30, 31, 31, 33, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45,
+            46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63,
+        ],
+        [
+            64, 65, 66, 67, 26, 26, 26, 26, 26, 68, 69, 70, 71, 72, 73, 74, 75, 26, 26, 26, 26, 26,
+            26, 26, 26, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94,
+            95, 96, 97, 98, 99, 100, 101, 102, 103, 103, 103, 12, 104, 104, 105, 104, 106, 107, 108,
+            109,
+        ],
+        [
+            110, 111, 112, 113, 114, 115, 116, 117, 117, 117, 117, 117, 118, 117, 117, 117, 117,
+            117, 119, 120, 117, 117, 117, 117, 117, 117, 117, 117, 117, 121, 117, 122, 117, 117,
+            117, 117, 117, 117, 123, 124, 117, 117, 117, 117, 117, 117, 117, 117, 16, 125, 104, 126,
+            127, 128, 129, 130, 131, 132, 117, 117, 117, 117, 117, 117,
+        ],
+        [
+            133, 75, 134, 135, 136, 26, 137, 138, 117, 117, 117, 117, 117, 117, 117, 117, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 117, 26, 26, 26, 26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 139, 140, 26,
+            26, 26, 26, 141, 142, 143, 144, 145, 146, 147, 148, 149, 150, 151, 152, 153, 154, 155,
+            156, 157, 158, 159, 160, 161, 162, 103, 163, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 164, 165, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117,
+        ],
+        [
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+        ],
+        [
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 26, 26, 26, 26, 26, 166, 26, 167, 168, 169, 170, 171, 26, 26, 26, 26, 172,
+            173, 174, 175, 176, 177, 26, 178, 179, 180, 181, 182,
+        ],
+        [
+            183, 184, 26, 185, 117, 186, 117, 187, 117, 117, 188, 189, 190, 191, 192, 193, 194, 195,
+            196, 197, 198, 199, 200, 201, 26, 26, 26, 26, 202, 203, 204, 117, 205, 206, 207, 208,
+            209, 167, 210, 117, 211, 212, 213, 214, 86, 215, 216, 117, 26, 217, 218, 219, 220, 221,
+            222, 117, 117, 117, 223, 224, 225, 226, 227, 228,
+        ],
+        [
+            229, 230, 231, 232, 233, 234, 235, 236, 237, 238, 239, 240, 241, 242, 243, 244, 245,
+            246, 247, 248, 117, 117, 249, 250, 247, 251, 252, 253, 254, 255, 117, 117, 256, 117,
+            257, 258, 259, 260, 261, 262, 263, 264, 265, 84, 117, 266, 117, 267, 268, 269, 270, 117,
+            271, 272, 273, 274, 117, 117, 117, 275, 276, 277, 278, 117,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 167, 117, 26, 279, 26, 26, 26,
+            280, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 173, 281,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 282, 283, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 185, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 255, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117,
+        ],
+        [
+            117, 117, 117, 117, 284, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 26, 26, 26,
+            26, 26, 26, 26, 26, 84, 285, 286, 287, 288, 289, 290, 117, 117, 117, 117, 117, 117, 291,
+            117, 117, 117, 292, 293, 294, 26, 295, 296, 297,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 298, 207, 117, 26, 299, 117, 117, 117, 117, 117, 117, 117,
+            117,
+        ],
+        [
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 300,
+        ],
+        [
+            26, 26, 26, 26, 301, 302, 26, 26, 26, 26, 26, 303, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 26, 304, 305, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+        ],
+        [
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            306, 117, 117, 117, 117, 117, 117, 117, 117, 307, 308, 117, 117,
+        ],
+        [
+            117, 117, 117, 117, 117, 309, 310, 117, 117, 311, 117, 117, 117, 117, 117, 117, 312,
+            313, 314, 315, 316, 317, 318, 319, 320, 321, 322, 323, 324, 325, 326, 327, 117, 117,
+            117, 117, 117, 117, 117, 117, 328, 329, 330, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 331, 117, 117, 117,
+        ],
+        [
+            332, 333, 334, 117, 335, 336, 117, 117, 117, 117, 337, 338, 117, 117, 117, 117, 117,
+            117, 117, 339, 117, 117, 117, 340, 117, 117, 117, 341, 117, 117, 117, 342, 26, 26, 26,
+            343, 344, 345, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 346, 347, 348, 117, 117, 117, 117, 117,
+        ],
+        [
+            117, 117, 117, 117, 349, 350, 351, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 352, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 306, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 353, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 192, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 166, 26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 354, 26, 26, 26, 26, 26, 26, 26, 26, 26, 355, 117, 117, 117, 117, 117, 117,
+        ],
+        [
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 26, 26, 26,
+            26, 26, 26, 26, 26, 355, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 356, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26,
+        ],
+        [
+            26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 26, 357, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117,
+        ],
+        [
+            0, 1, 2, 3, 4, 5, 5, 5, 5, 5, 6, 5, 5, 7, 8, 9, 10, 11, 12, 13, 14, 8, 15, 5, 16, 8, 17,
+            18, 19, 20, 21, 22, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 23, 24, 25, 5, 26, 27, 5, 28, 5, 29,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+        ],
+        [
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 8,
+        ],
+        [
+            358, 12, 117, 117, 12, 12, 12, 359, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+            117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117, 117,
+        ],
+        [
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 32, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8,
+            8, 8, 8, 8, 8, 8,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34,
+        ],
+    ],
+    leaves: &[
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, SP, LF, SP, SP, CR, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SP, ST, CL, XX, XX, XX, XX, CL, CL, CL, XX, XX,
+            SC, SC, AT, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, SC, SC, XX, XX, XX, ST,
+        ],
+        [
+            XX, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, CL, XX, CL, XX, XX, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, CL, XX, CL, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, SE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SP, XX, XX, XX, XX, XX, XX, XX, XX, XX, LO, CL,
+            XX, FO, XX, XX, XX, XX, XX, XX, XX, LO, XX, XX, XX, XX, LO, CL, XX, XX, XX, XX,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, XX, UP, UP, UP, UP, UP, UP, UP, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, XX, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, LO, UP, LO, UP, LO, UP, LO, UP,
+        ],
+        [
+            LO, UP, LO, UP, LO, UP, LO, UP, LO, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, UP, LO, UP, LO, UP, LO, LO,
+        ],
+        [
+            LO, UP, UP, LO, UP, LO, UP, UP, LO, UP, UP, UP, LO, LO, UP, UP, UP, UP, LO, UP, UP, LO,
+            UP, UP, UP, LO, LO, LO, UP, UP, LO, UP, UP, LO, UP, LO, UP, LO, UP, UP, LO, UP, LO, LO,
+            UP, LO, UP, UP, LO, UP, UP, UP, LO, UP, LO, UP, UP, LO, LO, LE, UP, LO, LO, LO,
+        ],
+        [
+            LE, LE, LE, LE, UP, UP, LO, UP, UP, LO, UP, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP,
+            LO, UP, LO, UP, LO, UP, LO, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, LO, UP, UP, LO, UP, LO, UP, UP, UP, LO, UP, LO, UP, LO, UP, LO,
+        ],
+        [
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, LO, LO, LO, LO, LO, LO, UP, UP, LO, UP, UP, LO,
+        ],
+        [
+            LO, UP, LO, UP, UP, UP, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LE, LE,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LO, LO, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LO, LO, LO, LO, LO, XX, XX, XX, XX, XX, XX, XX,
+            LE, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, UP, LO, UP, LO, LE, XX, UP, LO, XX, XX, LO, LO, LO, LO, SC, UP,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, UP, XX, UP, UP, UP, XX, UP, XX, UP, UP, LO, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, XX, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, UP, LO, LO, UP, UP, UP, LO,
+            LO, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, LO, LO, LO, LO, UP, LO, XX, UP, LO, UP, UP, LO, LO, UP, UP, UP,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+        ],
+        [
+            UP, LO, XX, EX, EX, EX, EX, EX, EX, EX, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+        ],
+        [
+            UP, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+        ],
+        [
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, XX, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, XX, XX, LE, XX, XX, XX, SC, XX, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, ST, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, EX,
+        ],
+        [
+            XX, EX, EX, XX, EX, EX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+            XX, XX, XX, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, SC, SC, XX, XX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, XX, FO, ST, ST, ST, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, NU,
+            NU, XX, LE, LE, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, ST, LE,
+            EX, EX, EX, EX, EX, EX, EX, NU, XX, EX, EX, EX, EX, EX, EX, LE, LE, EX, EX, XX, EX, EX,
+            EX, EX, LE, LE, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, LE, LE, LE, XX, XX, LE,
+        ],
+        [
+            ST, ST, ST, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, FO, LE, EX, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, LE, LE, XX, XX, SC, ST, LE, XX, XX, EX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            EX, EX, EX, EX, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, LE, EX, EX, EX, LE, EX, EX, EX,
+            EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, ST, XX, ST, XX, XX, XX, ST, ST, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, EX, EX, EX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, NU, NU, XX, XX, XX, XX,
+            XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, NU, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, LE, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, LE, EX, EX, EX, EX, EX,
+            EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, ST, ST, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, EX, EX, EX, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, XX, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE,
+            LE, LE, LE, LE, LE, XX, LE, XX, XX, XX, LE, LE, LE, LE, XX, XX, EX, LE, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, XX, XX, EX, EX, XX, XX, EX, EX, EX, LE, XX, XX, XX, XX, XX, XX, XX,
+            XX, EX, XX, XX, XX, XX, LE, LE, XX, LE, LE, LE, EX, EX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, XX, EX, XX,
+        ],
+        [
+            XX, EX, EX, EX, XX, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, LE, LE, XX, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE,
+            LE, LE, LE, LE, LE, XX, LE, LE, XX, LE, LE, XX, LE, LE, XX, XX, EX, XX, EX, EX,
+        ],
+        [
+            EX, EX, EX, XX, XX, XX, XX, EX, EX, XX, XX, EX, EX, EX, XX, XX, XX, EX, XX, XX, XX, XX,
+            XX, XX, XX, LE, LE, LE, LE, XX, LE, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, EX, EX, LE, LE, LE, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, EX, EX, EX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE,
+            LE, LE, LE, LE, LE, XX, LE, LE, XX, LE, LE, LE, LE, LE, XX, XX, EX, LE, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, XX, EX, EX, EX, XX, EX, EX, EX, XX, XX, LE, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, EX, EX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            XX, EX, EX, EX, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, XX, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE,
+            LE, LE, LE, LE, LE, XX, LE, LE, XX, LE, LE, LE, LE, LE, XX, XX, EX, LE, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, XX, XX, EX, EX, XX, XX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, EX,
+            EX, EX, XX, XX, XX, XX, LE, LE, XX, LE, LE, LE, EX, EX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, EX, LE, XX, LE, LE, LE, LE, LE, LE, XX, XX, XX, LE, LE, LE, XX, LE, LE, LE, LE,
+            XX, XX, XX, LE, LE, XX, LE, XX, LE, LE, XX, XX, XX, LE, LE, XX, XX, XX, LE, LE, LE, XX,
+            XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, EX, EX,
+        ],
+        [
+            EX, EX, EX, XX, XX, XX, EX, EX, EX, XX, EX, EX, EX, EX, XX, XX, LE, XX, XX, XX, XX, XX,
+            XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, XX, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, EX, LE, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, XX, EX, EX, EX, XX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, EX,
+            EX, XX, LE, LE, LE, XX, LE, LE, XX, XX, LE, LE, EX, EX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, EX, EX, EX, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, XX, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, XX, XX, EX, LE, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, XX, EX, EX, EX, XX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, EX,
+            EX, XX, XX, XX, XX, XX, LE, LE, LE, XX, LE, LE, EX, EX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, LE, LE, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, XX, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, LE, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, XX, EX, EX, EX, XX, EX, EX, EX, EX, LE, XX, XX, XX, XX, XX, LE, LE,
+            LE, EX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, EX, EX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            XX, EX, EX, EX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, EX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, XX,
+            EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, EX, LE, LE, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, LE, LE, XX, LE, XX, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, XX, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, EX, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, LE, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, XX, LE, XX, EX, EX, EX, EX, EX, EX, EX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, EX, XX, EX, CL, CL, CL, CL, EX, EX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, XX, EX, EX, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, LE,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, ST, ST, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            EX, EX, EX, EX, LE, LE, LE, LE, EX, EX, EX, LE, EX, EX, EX, LE, LE, EX, EX, EX, EX, EX,
+            EX, EX, LE, LE, LE, EX, EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, LE, EX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, EX, EX, EX, EX, XX, XX, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, XX, UP, XX, XX, XX, XX, XX, UP, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LO, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, XX, LE, XX, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, XX,
+        ],
+        [
+            LE, XX, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+            XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, XX, XX, EX, EX, EX, XX, XX, ST, XX, XX, XX, XX, ST, ST, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, XX, XX, LO, LO, LO, LO, LO, LO, XX, XX,
+        ],
+        [
+            XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, ST, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            SP, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, CL, CL, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+            XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, EX, EX, EX, ST, ST, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, LE, LE, LE, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, ST, ST,
+            XX, LE, XX, XX, XX, XX, LE, EX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, SC, ST, XX, XX, XX, XX, SC, ST, XX, EX, EX, EX, FO, EX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, LE, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, ST, ST, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, XX, XX, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, EX, EX, EX, EX, EX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, EX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, ST, ST, ST, ST,
+            XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, XX, ST, ST, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, ST, ST, XX, XX, ST, ST, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, ST, ST, ST,
+        ],
+        [
+            EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, LE, LE, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, ST, ST, XX, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, LE, LE, LE, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, ST, ST,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, UP, LO, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, XX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, LE, LE, LE,
+            LE, EX, LE, LE, LE, LE, LE, LE, EX, LE, LE, EX, EX, EX, LE, XX, XX, XX, XX, XX,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+        ],
+        [
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP, UP, UP, UP, UP, LO, LO, LO, LO, LO, LO,
+            XX, XX, UP, UP, UP, UP, UP, UP, XX, XX, LO, LO, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP,
+            UP, UP, UP, UP, LO, LO, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP, UP, UP, UP, UP,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, XX, XX, UP, UP, UP, UP, UP, UP, XX, XX, LO, LO, LO, LO, LO, LO,
+            LO, LO, XX, UP, XX, UP, XX, UP, XX, UP, LO, LO, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP,
+            UP, UP, UP, UP, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, XX, XX,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP, UP, UP, UP, UP, LO, LO, LO, LO, LO, LO,
+            LO, LO, UP, UP, UP, UP, UP, UP, UP, UP, LO, LO, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP,
+            UP, UP, UP, UP, LO, LO, LO, LO, LO, XX, LO, LO, UP, UP, UP, UP, UP, XX, LO, XX,
+        ],
+        [
+            XX, XX, LO, LO, LO, XX, LO, LO, UP, UP, UP, UP, UP, XX, XX, XX, LO, LO, LO, LO, XX, XX,
+            LO, LO, UP, UP, UP, UP, XX, XX, XX, XX, LO, LO, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP,
+            UP, XX, XX, XX, XX, XX, LO, LO, LO, XX, LO, LO, UP, UP, UP, UP, UP, XX, XX, XX,
+        ],
+        [
+            SP, SP, SP, SP, SP, SP, SP, SP, SP, SP, SP, FO, EX, EX, FO, FO, XX, XX, XX, SC, SC, XX,
+            XX, XX, CL, CL, CL, CL, CL, CL, CL, CL, XX, XX, XX, XX, AT, XX, XX, XX, SE, SE, FO, FO,
+            FO, FO, FO, SP, XX, XX, XX, XX, XX, XX, XX, XX, XX, CL, CL, XX, ST, ST, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, CL, CL, ST, ST, ST, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, SP, FO, FO, FO, FO, FO, XX, FO, FO, FO, FO, FO, FO,
+            FO, FO, FO, FO, XX, LO, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CL, CL, LO,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CL, CL, XX, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, UP, XX, XX, XX, XX, UP, XX, XX, LO, UP, UP, UP, LO, LO, UP, UP, UP, LO, XX, UP,
+            XX, XX, XX, UP, UP, UP, UP, UP, XX, XX, XX, XX, XX, XX, UP, XX, UP, XX, UP, XX, UP, UP,
+            UP, UP, XX, LO, UP, UP, UP, UP, LO, LE, LE, LE, LE, LO, XX, XX, LO, LO, UP, UP,
+        ],
+        [
+            XX, XX, XX, XX, XX, UP, LO, LO, LO, LO, XX, XX, XX, XX, LO, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LE, LE, LE, UP, LO, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, CL, CL, CL, CL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CL, CL, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, CL, CL, CL, CL, CL, CL, XX, XX, XX, XX, XX, XX, XX, CL, CL, CL, CL,
+            CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, CL, CL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CL, CL, CL, CL, CL, CL,
+            CL, CL, CL, CL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL,
+            CL, CL, CL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, CL, CL, CL, CL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CL, CL, XX, XX,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, UP, LO, UP, UP, UP, LO, LO, UP, LO, UP, LO, UP,
+            LO, UP, UP, UP, UP, LO, UP, LO, LO, UP, LO, LO, LO, LO, LO, LO, LO, LO, UP, UP,
+        ],
+        [
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, LO, XX, XX, XX, XX, XX, XX, UP,
+            LO, UP, LO, EX, EX, EX, UP, LO, XX, XX, XX, XX, XX, ST, ST, ST, XX, XX, XX, XX,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, XX, LO, XX, XX, XX, XX,
+            XX, LO, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+            XX, XX, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+            LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE,
+            LE, XX, LE, LE, LE, LE, LE, LE, LE, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, CL, CL, XX, XX, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, XX, XX,
+            XX, XX, ST, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, ST, XX, XX, XX,
+        ],
+        [
+            XX, XX, CL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, ST, ST, CL,
+            CL, CL, CL, CL, CL, CL, CL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            SP, SC, ST, XX, XX, LE, LE, LE, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, XX, XX, CL, CL,
+            CL, CL, CL, CL, CL, CL, XX, CL, CL, CL, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX,
+            EX, EX, EX, EX, XX, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, XX, EX, EX, XX, XX, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+        ],
+        [
+            XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, ST,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, ST, ST, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, LE, LE,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, LE, EX, EX, EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, LE,
+        ],
+        [
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, LO, LO, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, EX, EX, XX, ST, XX, XX, XX, ST, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, LO, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+        ],
+        [
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, UP, LO, UP, LO, UP, UP, LO,
+        ],
+        [
+            UP, LO, UP, LO, UP, LO, UP, LO, LE, XX, XX, UP, LO, UP, LO, LE, UP, LO, UP, LO, LO, LO,
+            UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, UP,
+            UP, UP, UP, LO, UP, UP, UP, UP, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+        ],
+        [
+            UP, LO, UP, LO, UP, UP, UP, UP, LO, UP, LO, UP, UP, LO, UP, LO, UP, LO, UP, LO, UP, LO,
+            UP, LO, UP, LO, UP, LO, UP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, LO, LO, LO, LO, UP, LO, LE, LO, LO, LO, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, EX, LE, LE, LE, EX, LE, LE, LE, LE, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, XX, XX, XX, XX,
+            EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, ST, ST, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, ST, ST, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, LE, LE, LE, LE, LE, LE, XX, XX, XX, LE, XX, LE, LE, EX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX,
+            EX, EX, XX, ST, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, XX, XX, XX, XX, XX, XX, XX, ST, ST, XX, XX, XX, XX, XX, LE, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, EX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, LE, LE, LE, LE, LE, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, EX, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, ST, ST, ST, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, LE, EX, EX, EX, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, EX, LE, EX, EX, EX, LE, LE, EX, EX, LE, LE, LE, LE, LE, EX, EX,
+        ],
+        [
+            LE, EX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX,
+            EX, EX, EX, EX, ST, ST, LE, LE, LE, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE,
+            LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+            LE, LE, LE, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, XX, XX,
+            XX, XX, XX, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, ST,
+            EX, EX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LO, LO, LO,
+            LO, LO, XX, XX, XX, XX, XX, LE, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, XX, LE, XX,
+        ],
+        [
+            LE, LE, XX, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, CL, CL,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, SC, SC, ST, SC, SC, ST,
+            ST, CL, CL, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, XX, SC, SC, XX, XX, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL, CL,
+        ],
+        [
+            CL, CL, CL, CL, CL, XX, XX, CL, CL, XX, XX, XX, XX, XX, XX, XX, SC, SC, AT, XX, SC, SC,
+            ST, ST, SC, CL, CL, CL, CL, CL, CL, XX, XX, XX, XX, SC, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, FO,
+        ],
+        [
+            XX, ST, XX, XX, XX, XX, XX, XX, CL, CL, XX, XX, SC, SC, AT, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, SC, SC, XX, XX, XX, ST, XX, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, CL, XX, CL, XX, XX,
+        ],
+        [
+            XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, CL, XX, CL, XX, CL, CL, ST, CL, CL, SC, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+        ],
+        [
+            XX, XX, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE,
+            LE, LE, XX, XX, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, FO, FO, FO, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, XX, XX, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, XX, XX,
+            XX, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, XX, UP, UP, UP, UP,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, XX, UP, UP, UP, UP, UP, UP, UP, XX, UP, UP,
+            XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, XX, LO, LO, LO, LO, LO, LO, LO, XX, LO, LO, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LO, LE, LE, LO, LO, LO, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, XX, XX, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, XX, XX, LE, XX, XX, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, LE, LE,
+        ],
+        [
+            LE, EX, EX, EX, XX, EX, EX, XX, XX, XX, XX, XX, EX, EX, EX, EX, LE, LE, LE, LE, XX, LE,
+            LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, EX, EX, EX, XX, XX, XX, XX, EX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            ST, ST, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, XX, XX, XX, XX,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, LE, LE, LE, LE, LE, LE, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, XX, XX, XX, EX, EX, EX,
+            EX, EX, XX, LE, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, EX,
+            EX, XX, XX, XX, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, ST,
+            ST, ST, ST, ST, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, EX, EX, EX, EX, ST, ST, ST, ST, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, ST, ST, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, EX, LE, LE, EX, EX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+        ],
+        [
+            EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, NU, ST, ST,
+        ],
+        [
+            ST, ST, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU,
+        ],
+        [
+            XX, ST, ST, ST, LE, EX, EX, LE, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, EX, XX, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, LE, LE, LE, LE, ST, ST, XX, XX, EX, EX, EX, EX, ST, EX, EX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, LE, XX, LE, XX, ST, ST, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, ST, ST, XX, ST, ST, XX, EX, LE,
+        ],
+        [
+            LE, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, XX, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, ST, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, XX, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE,
+            LE, LE, LE, LE, LE, XX, LE, LE, XX, LE, LE, LE, LE, LE, XX, EX, EX, LE, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, XX, XX, EX, EX, XX, XX, EX, EX, EX, XX, XX, LE, XX, XX, XX, XX, XX,
+            XX, EX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, EX, EX, XX, XX, EX, EX, EX, EX, EX, EX,
+            EX, XX, XX, XX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, XX, XX, LE, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, XX, EX, XX, XX, EX, XX, EX, EX, EX, EX, XX, EX, EX, EX, EX, EX, LE, EX, LE, ST, ST,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, LE, LE, LE, LE, ST, ST, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, EX, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, LE, LE, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, XX, ST, ST, XX, XX, XX, XX, XX, ST, ST, ST, ST, ST, ST, ST, ST, ST, ST, ST, ST, ST,
+            ST, ST, LE, LE, LE, LE, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, ST, ST, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, LE, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, ST, ST, ST, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE,
+            LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, XX, EX, EX, XX, XX, EX, EX, EX, EX, LE,
+        ],
+        [
+            EX, LE, EX, EX, ST, XX, ST, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX,
+            EX, EX, XX, XX, EX, EX, EX, EX, EX, EX, EX, LE, XX, LE, EX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, LE, EX, EX, EX, EX, XX,
+        ],
+        [
+            XX, XX, ST, ST, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, LE, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, XX, ST, ST, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            LE, ST, ST, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, XX, XX, XX, EX, XX, EX, EX, XX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, LE, EX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, XX, EX, EX, XX, EX, EX, EX,
+            EX, EX, LE, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, ST, ST, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, LE, EX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, EX, EX,
+        ],
+        [
+            EX, EX, EX, ST, ST, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, FO, FO, FO, FO, FO, FO, FO, FO, FO, FO, FO, FO, FO, FO, FO, FO,
+        ],
+        [
+            EX, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, ST, ST, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, XX, XX, EX, EX, EX, EX, EX, ST, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, EX, EX, EX, EX, EX, EX, EX, ST, ST, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, ST, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, ST, ST, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, ST, XX, XX, XX, XX, XX, XX, XX, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, XX, XX, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, EX, LE, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, XX, LE, EX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EX, EX, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, XX, XX, LE,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, XX, XX, XX, EX, EX, ST, FO, FO, FO, FO, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, XX, XX,
+            XX, EX, EX, EX, EX, EX, EX, FO, FO, FO, FO, FO, FO, FO, FO, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX,
+            EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, LO, LO, LO, LO, LO, LO, LO, XX,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+        ],
+        [
+            UP, UP, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, UP, XX, UP, UP, XX, XX, UP, XX, XX, UP, UP, XX, XX, UP, UP, UP,
+            UP, XX, UP, UP, UP, UP, UP, UP, UP, UP, LO, LO, LO, LO, XX, LO, XX, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, UP, UP, XX, UP, UP, UP, UP, XX, XX, UP, UP, UP, UP, UP, UP, UP, UP, XX,
+            UP, UP, UP, UP, UP, UP, UP, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, UP, UP, XX, UP, UP, UP, UP, XX,
+        ],
+        [
+            UP, UP, UP, UP, UP, XX, UP, XX, XX, XX, UP, UP, UP, UP, UP, UP, UP, XX, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, XX, XX, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+        ],
+        [
+            UP, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, XX, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, XX, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, XX,
+            LO, LO, LO, LO, LO, LO, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, XX, LO, LO, LO, LO, LO, LO,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, XX, LO, LO, LO, LO, LO, LO, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, XX, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, XX, LO, LO, LO, LO, LO, LO, UP, LO, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, EX, XX, XX, XX, ST, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LE, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, XX, XX, XX, XX, XX, XX, LO, LO, LO, LO, LO, LO, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX, EX, EX, XX, EX, EX, EX, EX, EX, XX,
+            XX, XX, XX, XX, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, LE, LE, LE, LE, LE, LE, LE, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, LE, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            EX, EX, EX, EX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            EX, EX, EX, EX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, EX, EX, LE, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, EX, LE, LE, EX, LE, LE, LE, LE, LE,
+            LE, LE, EX, EX, LE, LE, LE, LE, LE, EX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+            XX, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX,
+            EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+            LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO, LO,
+        ],
+        [
+            LO, LO, LO, LO, EX, EX, EX, EX, EX, EX, EX, LE, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, LE, XX, XX, LE, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, XX, LE, XX, LE, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, LE, XX, XX, XX, XX, LE, XX, LE, XX, LE, XX, LE, LE, LE, XX, LE, LE, XX, LE, XX,
+            XX, LE, XX, LE, XX, LE, XX, LE, XX, LE, XX, LE, LE, XX, LE, XX, XX, LE, LE, LE, LE, XX,
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, XX, LE, LE, LE, LE, XX, LE, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, LE, LE, LE, XX, LE, LE, LE, LE, LE, XX, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, XX, XX, XX, XX, XX, XX, UP, UP, UP, UP, UP, UP,
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, XX, XX,
+            XX, XX, XX, XX, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, UP,
+        ],
+        [
+            UP, UP, UP, UP, UP, UP, UP, UP, UP, UP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CL, CL, CL, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, FO, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+        ],
+        [
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+            EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+    ],
+};
@@ -0,0 +1,247 @@
+"""Shared helpers for the unicode table generators in this directory.
+
+Every generator takes the path of an extracted Unicode Character Database
+(https://www.unicode.org/Public/17.0.0/ucd/) as its first argument, and prints the rust
+module it generates to stdout, e.g.
+
+    python3 src/tables/properties.py ~/ucd > src/tables/properties.rs
+
+The tables are tries keyed by the utf8 encoding of a codepoint (see `Utf8Trie` in
+src/tables.rs), so that `Utf8Char` can be looked up without decoding it.
+"""
+
+import sys
+from pathlib import Path
+from typing import Callable, Iterator, Sequence, TypeVar
+
+T = TypeVar("T")
+
+UNICODE_VERSION = (17, 0, 0)
+
+CODEPOINTS = 0x11_0000
+
+# the data bits of a continuation byte, which index a single trie level
+LEVEL = 64
+
+
+def ucd_dir() -> Path:
+    """Returns the UCD directory passed on the command line"""
+    if len(sys.argv) < 2:
+        sys.exit(f"usage: {sys.argv[0]} <path to UCD directory> > output.rs")
+
+    return Path(sys.argv[1])
+
+
+def parse_lines(path: Path) -> Iterator[list[str]]:
+    """Yields the semicolon separated fields of every non comment line of a UCD file"""
+    with open(path, encoding="utf8") as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+
+            if line:
+                yield [field.strip() for field in line.split(";")]
+
+
+def parse_range(field: str) -> range:
+    """Parses `XXXX` or `XXXX..YYYY` into a range of codepoints"""
+    lo, _, hi = field.partition("..")
+
+    return range(int(lo, 16), int(hi or lo, 16) + 1)
+
+
+def parse_codepoints(field: str) -> list[int]:
+    """Parses a space separated list of hex codepoints"""
+    return [int(cp, 16) for cp in field.split()]
+
+
+def enumerated_property(path: Path, default: str, field: int = 1) -> list[str]:
+    """Reads a `range ; value` file into a list holding the value of every codepoint"""
+    values = [default] * CODEPOINTS
+
+    for fields in parse_lines(path):
+        for cp in parse_range(fields[0]):
+            values[cp] = fields[field]
+
+    return values
+
+
+def binary_property(path: Path, name: str) -> list[bool]:
+    """Reads a `range ; property` file into a list of whether each codepoint has `name`"""
+    values = [False] * CODEPOINTS
+
+    for fields in parse_lines(path):
+        if fields[1] == name:
+            for cp in parse_range(fields[0]):
+                values[cp] = True
+
+    return values
+
+
+def unicode_data(path: Path) -> dict[int, list[str]]:
+    """Reads UnicodeData.txt into a map of codepoint to fields, expanding `<..., First>` ranges"""
+    data: dict[int, list[str]] = {}
+    first = None
+
+    for fields in parse_lines(path):
+        cp = int(fields[0], 16)
+
+        if fields[1].endswith(", First>"):
+            first = cp
+        elif fields[1].endswith(", Last>"):
+            assert first is not None
+
+            for ranged in range(first, cp + 1):
+                data[ranged] = fields
+        else:
+            data[cp] = fields
+
+    return data
+
+
+def utf8(cp: int) -> bytes:
+    """Encodes a codepoint (surrogates included) as utf8"""
+    return chr(cp).encode("utf8", "surrogatepass")
+
+
+def utf8char_bits(cp: int) -> int:
+    """Returns the `Utf8Char::to_bits` representation of a codepoint"""
+    return int.from_bytes(utf8(cp).ljust(4, b"\x80"), "big")
+
+
+def header(script: str, sources: Sequence[str]) -> str:
+    """Returns the module header every generated file starts with"""
+    version = ".".join(map(str, UNICODE_VERSION))
+
+    return (
+        f"//! Generated by `src/tables/{script}` from the Unicode {version} files\n"
+        f"//! {', '.join(sources)}. Do not edit by hand.\n"
+    )
+
+
+class Utf8Trie:
+    """A trie keyed by the utf8 bytes of a codepoint, each level consuming one byte.
+
+    - `lead` is indexed by the first byte. For 1 and 2 byte sequences it holds a leaf index, for 3
+      and 4 byte sequences a node index.
+    - `nodes` are indexed by the data bits of a continuation byte and hold a node index for the
+      second byte of a 4 byte sequence, or a leaf index otherwise.
+    - `leaves` are indexed by the data bits of the last byte (or of the only byte, for ascii).
+
+    Leaves and nodes are deduplicated, which is where the compression comes from.
+    """
+
+    def __init__(self, values: Sequence[T], encode_leaf: Callable[[Sequence[T]], object]):
+        self.leaves: list[object] = []
+        self.nodes: list[tuple[int, ...]] = []
+        self.lead = [0] * 256
+
+        leaf_ids: dict[object, int] = {}
+        node_ids: dict[tuple[int, ...], int] = {}
+
+        def leaf(base: int) -> int:
+            # blocks past the end of unicode are only reachable by invalid utf8, any leaf will do
+            if base >= CODEPOINTS:
+                return 0
+
+            block = encode_leaf(values[base : base + LEVEL])
+
+            if block not in leaf_ids:
+                leaf_ids[block] = len(self.leaves)
+                self.leaves.append(block)
+
+            return leaf_ids[block]
+
+        def node(entries: tuple[int, ...]) -> int:
+            if entries not in node_ids:
+                node_ids[entries] = len(self.nodes)
+                self.nodes.append(entries)
+
+            return node_ids[entries]
+
+        for byte in range(0x00, 0x80):
+            self.lead[byte] = leaf(byte & ~0x3F)
+
+        for byte in range(0xC2, 0xE0):
+            self.lead[byte] = leaf((byte & 0x1F) << 6)
+
+        for byte in range(0xE0, 0xF0):
+            self.lead[byte] = node(tuple(leaf(((byte & 0x0F) << 12) | (b << 6)) for b in range(LEVEL)))
+
+        for byte in range(0xF0, 0xF5):
+            self.lead[byte] = node(
+                tuple(
+                    node(tuple(leaf(((byte & 0x07) << 18) | (b << 12) | (c << 6)) for c in range(LEVEL)))
+                    for b in range(LEVEL)
+                )
+            )
+
+        assert len(self.leaves) <= 0xFFFF and len(self.nodes) <= 0xFFFF
+
+    def emit(self, name: str, doc: str, leaf_type: str, fmt_leaf: Callable[[object], str]) -> str:
+        """Returns the rust source of a static holding this trie"""
+        out = [f"/// {doc}"]
+        out.append(f"pub(crate) static {name}: Utf8Trie<{leaf_type}> = Utf8Trie {{")
+        out.append("    lead: &[")
+        out.extend(wrap(f"{n}," for n in self.lead))
+        out.append("    ],")
+        out.append("    nodes: &[")
+
+        for entries in self.nodes:
+            out.append("        [")
+            out.extend(wrap((f"{n}," for n in entries), indent=12))
+            out.append("        ],")
+
+        out.append("    ],")
+        out.append("    leaves: &[")
+
+        for leaf in self.leaves:
+            out.append(fmt_leaf(leaf))
+
+        out.append("    ],")
+        out.append("};")
+
+        return "\n".join(out) + "\n"
+
+
+def wrap(items: Iterator[str], indent: int = 8, width: int = 100) -> list[str]:
+    """Joins items into lines no longer than `width`"""
+    lines: list[str] = []
+    line = ""
+
+    for item in items:
+        if line and len(line) + 1 + len(item) + indent > width:
+            lines.append(" " * indent + line)
+            line = item
+        else:
+            line = f"{line} {item}" if line else item
+
+    if line:
+        lines.append(" " * indent + line)
+
+    return lines
+
+
+def bool_trie(name: str, doc: str, values: Sequence[bool]) -> str:
+    """Returns the rust source of a `Utf8Trie<u64>`, where each leaf is a 64 bit set"""
+
+    def encode(block: Sequence[bool]) -> int:
+        return sum(1 << i for i, v in enumerate(block) if v)
+
+    def fmt(bits: object) -> str:
+        assert isinstance(bits, int)
+        digits = f"{bits:016X}"
+
+        return f"        0x{'_'.join(digits[i : i + 4] for i in range(0, 16, 4))},"
+
+    return Utf8Trie(values, encode).emit(name, doc, "u64", fmt)
+
+
+def value_trie(name: str, doc: str, leaf_type: str, values: Sequence[str]) -> str:
+    """Returns the rust source of a `Utf8Trie<[leaf_type; 64]>`, where values are rust expressions"""
+
+    def fmt(block: object) -> str:
+        assert isinstance(block, tuple)
+
+        return "\n".join(["        [", *wrap((f"{v}," for v in block), indent=12), "        ],"])
+
+    return Utf8Trie(values, tuple).emit(name, doc, f"[{leaf_type}; {LEVEL}]", fmt)
@@ -0,0 +1,76 @@
+"""Generates src/tables/width.rs: the terminal display width of every codepoint.
+
+    python3 src/tables/width.py <path to UCD> > src/tables/width.rs
+
+Widths follow the `East_Asian_Width` property (UAX #11), with the zero width rules terminals apply
+on top of it: combining marks, default ignorable codepoints (which include the zero width joiner)
+and the medial and final Hangul jamo take up no columns of their own.
+"""
+
+from ucd import CODEPOINTS, binary_property, enumerated_property, header, parse_range, ucd_dir, value_trie
+
+# the `@missing` defaults of EastAsianWidth.txt, unassigned codepoints in the ideograph blocks and
+# planes are wide, the rest neutral
+MISSING_WIDE = ["3400..4DBF", "4E00..9FFF", "F900..FAFF", "20000..2FFFD", "30000..3FFFD"]
+
+# `CharWidth` variant by the short name the table below uses
+WIDTHS = {
+    "C": "Control",
+    "Z": "Zero",
+    "N": "Narrow",
+    "W": "Wide",
+    "A": "Ambiguous",
+}
+
+
+def main() -> None:
+    ucd = ucd_dir()
+
+    east_asian = ["N"] * CODEPOINTS
+
+    for missing in MISSING_WIDE:
+        for cp in parse_range(missing):
+            east_asian[cp] = "W"
+
+    for cp, value in enumerate(enumerated_property(ucd / "EastAsianWidth.txt", "")):
+        if value:
+            east_asian[cp] = value
+
+    categories = enumerated_property(ucd / "extracted/DerivedGeneralCategory.txt", "Cn")
+    ignorable = binary_property(ucd / "DerivedCoreProperties.txt", "Default_Ignorable_Code_Point")
+    jamo = enumerated_property(ucd / "HangulSyllableType.txt", "NA")
+
+    widths = []
+
+    for cp in range(CODEPOINTS):
+        if categories[cp] == "Cc":
+            width = "C"
+        elif cp == 0xAD:
+            # the soft hyphen is ignorable, but terminals display it as a hyphen
+            width = "N"
+        elif categories[cp] in ("Mn", "Me") or ignorable[cp] or jamo[cp] in ("V", "T"):
+            width = "Z"
+        elif east_asian[cp] in ("W", "F"):
+            width = "W"
+        elif east_asian[cp] == "A":
+            width = "A"
+        else:
+            width = "N"
+
+        widths.append(width)
+
+    print(header("width.py", ["EastAsianWidth.txt", "extracted/DerivedGeneralCategory.txt", "DerivedCoreProperties.txt", "HangulSyllableType.txt"]))
+    print("use super::Utf8Trie;")
+    print("use crate::width::CharWidth;")
+    print()
+
+    for short, long in WIDTHS.items():
+        print(f"/// `CharWidth::{long}`, shortened to keep the table below readable")
+        print(f"const {short}: CharWidth = CharWidth::{long};")
+
+    print()
+    print(value_trie("WIDTH", "The display width of every codepoint", "CharWidth", widths), end="")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,1441 @@
+//! Generated by `src/tables/width.py` from the Unicode 17.0.0 files
+//! EastAsianWidth.txt, extracted/DerivedGeneralCategory.txt, DerivedCoreProperties.txt, HangulSyllableType.txt. Do not edit by hand.
+
+use super::Utf8Trie;
+use crate::width::CharWidth;
+
+/// `CharWidth::Control`, shortened to keep the table below readable
+const C: CharWidth = CharWidth::Control;
+/// `CharWidth::Zero`, shortened to keep the table below readable
+const Z: CharWidth = CharWidth::Zero;
+/// `CharWidth::Narrow`, shortened to keep the table below readable
+const N: CharWidth = CharWidth::Narrow;
+/// `CharWidth::Wide`, shortened to keep the table below readable
+const W: CharWidth = CharWidth::Wide;
+/// `CharWidth::Ambiguous`, shortened to keep the table below readable
+const A: CharWidth = CharWidth::Ambiguous;
+
+/// The display width of every codepoint
+pub(crate) static WIDTH: Utf8Trie<[CharWidth; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 6, 8, 6, 9, 10, 11, 12, 13, 14, 15, 16, 6, 6, 6,
+        17, 18, 19, 20, 6, 21, 22, 23, 24, 25, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 6, 7, 8, 22,
+        23, 23, 26, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 5, 6, 7, 6, 8, 6, 9, 10, 11, 12, 13, 14, 15, 16, 6, 6, 6, 17, 18, 19, 20,
+            6, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 34, 36, 37, 38, 39, 40,
+            41, 42, 37, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55,
+        ],
+        [
+            56, 57, 58, 6, 59, 60, 10, 10, 6, 6, 6, 6, 6, 61, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 62, 63, 64, 65, 66, 6, 67, 6, 68, 6, 6, 6, 69, 70, 71, 72, 73, 74, 75, 76, 77, 6, 6,
+            78, 6, 6, 6, 10, 6, 6, 6, 6, 6, 6, 6, 6,
+        ],
+        [
+            79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 6, 90, 6, 6, 91, 6, 92, 93, 94, 93, 95, 96,
+            97, 98, 99, 100, 101, 102, 103, 104, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 105, 106, 6,
+            6, 6, 6, 6, 107, 6, 108, 6, 109, 6, 6, 110, 111, 59, 59, 59, 112,
+        ],
+        [
+            113, 114, 115, 59, 116, 117, 118, 119, 120, 121, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59,
+            59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59,
+            59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59,
+        ],
+        [
+            59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59,
+            59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59,
+            59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59,
+        ],
+        [
+            59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 122, 123, 6, 6,
+            6, 6, 6, 124, 125, 126, 6, 6, 6, 6, 127, 6, 6, 128, 129, 130, 131, 132, 133, 134, 135,
+            136, 6, 6, 6, 137, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59,
+        ],
+        [
+            59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59,
+            59, 59, 59, 59, 59, 59, 59, 59, 138, 139, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+        ],
+        [
+            93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93,
+            93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93,
+            93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93,
+        ],
+        [
+            93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93,
+            93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 59, 59, 59, 59, 59, 59, 59, 59,
+            140, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 141, 142, 6, 108, 114, 143, 144, 145,
+        ],
+        [
+            6, 6, 6, 6, 6, 6, 6, 146, 6, 6, 6, 144, 6, 147, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 148, 6, 6, 149, 6, 6, 6, 6, 6, 6, 6, 6, 150, 151,
+            6, 6, 6, 6, 152, 153, 6, 154, 155, 6,
+        ],
+        [
+            156, 157, 158, 39, 159, 160, 161, 162, 163, 46, 6, 164, 44, 165, 166, 167, 168, 169,
+            170, 171, 6, 6, 172, 173, 174, 175, 176, 6, 177, 6, 6, 6, 178, 6, 6, 6, 179, 180, 6,
+            181, 182, 183, 184, 6, 6, 185, 6, 6, 186, 6, 187, 6, 188, 189, 190, 6, 6, 6, 6, 191,
+            192, 193, 6, 6,
+        ],
+        [
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6,
+        ],
+        [
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 194, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6,
+        ],
+        [
+            6, 6, 6, 6, 195, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 196, 197, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 198, 199, 200,
+        ],
+        [
+            59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59,
+            59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59,
+            59, 59, 59, 59, 59, 59, 59, 201, 202, 6, 59, 203, 6, 6, 6, 6, 6, 6, 6, 6,
+        ],
+        [
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 204,
+        ],
+        [
+            59, 59, 59, 59, 205, 206, 59, 59, 59, 59, 59, 207, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 208, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+        ],
+        [
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 209, 210, 6, 6,
+        ],
+        [
+            6, 6, 6, 6, 6, 211, 212, 6, 6, 213, 6, 6, 59, 214, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 215, 216, 217, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+        ],
+        [
+            218, 6, 198, 6, 197, 6, 6, 6, 6, 6, 219, 220, 6, 6, 6, 6, 6, 6, 6, 220, 6, 6, 6, 221, 6,
+            6, 6, 222, 6, 6, 6, 6, 6, 6, 6, 223, 6, 224, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+        ],
+        [
+            225, 6, 6, 226, 227, 228, 229, 6, 230, 231, 6, 6, 232, 233, 234, 235, 236, 237, 59, 238,
+            239, 240, 241, 242, 59, 243, 59, 244, 6, 6, 6, 245, 6, 6, 6, 6, 246, 247, 59, 59, 6,
+            248, 249, 250, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+        ],
+        [
+            59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59,
+            59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59,
+            59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 239,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 6, 7, 8, 9, 10, 11, 12, 11, 11, 13, 4, 14, 11,
+            15, 16, 17, 18, 19, 20, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 21, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 21,
+        ],
+        [
+            11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+            11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+            11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+        ],
+        [
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+            10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10,
+        ],
+        [
+            93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93,
+            93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93,
+            93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 251,
+        ],
+        [
+            11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+            11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 24, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11, 11,
+            11, 11, 11, 11, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 25,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 25, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+        ],
+    ],
+    leaves: &[
+        [
+            C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C,
+            C, C, C, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, C,
+        ],
+        [
+            C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C, C,
+            C, C, C, N, A, N, N, A, N, N, A, A, N, A, N, N, N, A, N, A, A, A, A, A, N, A, A, A, A,
+            A, N, A, A, A, A,
+        ],
+        [
+            N, N, N, N, N, N, A, N, N, N, N, N, N, N, N, N, A, N, N, N, N, N, N, A, A, N, N, N, N,
+            N, A, A, A, A, N, N, N, N, A, N, A, A, A, N, A, A, N, N, A, N, A, A, N, N, N, A, A, A,
+            A, N, A, N, A, N,
+        ],
+        [
+            N, A, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, A, N, A, N, N, N, N, N, N, N, A, N,
+            N, N, N, N, N, N, N, N, N, A, A, N, N, N, A, N, N, N, N, N, A, A, A, N, N, N, N, A, N,
+            N, N, N, N, N, A,
+        ],
+        [
+            A, A, A, N, A, N, N, N, A, A, A, A, N, A, N, N, N, N, A, A, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, A, A, N, N, N, A, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, A, N, A, N, A, N, A, N, A, N, A, N, A, N, A,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, A, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, A, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, A, N, N, A, N, A, A, A, N, A, N, N, A, N, N, N, N, N, N, N, A, A, A, A, N,
+            A, N, A, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z,
+        ],
+        [
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, A, A, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, N, A, A, A, A, A, A, A, N, N, N, N, N, N, N, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, A,
+        ],
+        [
+            A, A, N, A, A, A, A, A, A, A, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, A, N, N, N, N, N, N, N, N, N, N, N, N, N, N, A, A, A, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, A,
+        ],
+        [
+            A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, N, A, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, N, Z,
+        ],
+        [
+            N, Z, Z, N, Z, Z, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, Z,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z,
+            N, N, Z, Z, Z, Z, Z, Z, N, N, Z, Z, N, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z,
+        ],
+        [
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N,
+            N, N, N, Z, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, N, Z, Z,
+            Z, Z, Z, Z, Z, Z, Z, N, Z, Z, Z, N, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z,
+        ],
+        [
+            Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            Z, N, Z, N, N, N,
+        ],
+        [
+            N, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, Z, N, N, N, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N,
+            N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, Z, N, N, N,
+        ],
+        [
+            N, Z, Z, Z, Z, N, N, N, N, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, Z, N,
+        ],
+        [
+            N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, Z, N, N, N,
+        ],
+        [
+            N, Z, Z, N, N, N, N, Z, Z, N, N, Z, Z, Z, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, N, N, N, Z, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, Z, Z, Z, Z, Z, N, Z, Z, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            Z, Z, Z, Z, Z, Z,
+        ],
+        [
+            N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, Z, N, N, Z,
+        ],
+        [
+            N, Z, Z, Z, Z, N, N, N, N, N, N, N, N, Z, N, N, N, N, N, N, N, Z, Z, N, N, N, N, N, N,
+            N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Z, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Z, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, Z, N, Z, Z,
+        ],
+        [
+            Z, N, N, N, N, N, Z, Z, Z, N, Z, Z, Z, Z, N, N, N, N, N, N, N, Z, Z, N, N, N, N, N, N,
+            N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, Z, N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, Z, Z, N, N, N,
+        ],
+        [
+            N, Z, Z, Z, Z, N, N, N, N, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, Z, N, N, N, N, N, N, N, Z, Z, Z, N, Z, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, N, Z, Z, Z, Z, Z, Z,
+            Z, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, N, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, Z, N, Z,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, N,
+        ],
+        [
+            Z, Z, Z, Z, Z, N, Z, Z, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, N, Z, Z, Z, Z, Z, Z, N, Z,
+            Z, N, N, Z, Z, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, N, N, N,
+            N, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, Z, N, N, Z, Z, N, N, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, N, Z, Z, Z,
+            Z, Z, Z, Z, N, N,
+        ],
+        [
+            N, N, N, N, N, N, Z, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N,
+            Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, Z, Z, Z, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, Z, N, N, N, N, N, N, Z,
+            Z, Z, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, N, N, Z, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, Z, Z, Z, Z, Z,
+            Z, Z, N, Z, N, Z, N, N, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, N, N, Z,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z,
+        ],
+        [
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, Z, Z, Z, Z,
+            Z, N, Z, N, N, N,
+        ],
+        [
+            N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, Z, Z, Z, Z, N, N, Z, Z, N, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, Z, N, Z, Z, N, N, N, Z, N, Z, Z, Z, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, N, N, Z, Z, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, N, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, N, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, Z, N, N, N, N, N, N, Z, N, N, N, Z, Z,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, A, N, N, A, A, A, A, N, A, A, N, N, A,
+            A, N, N, A, A, A, N, A, A, A, A, N, N, Z, Z, Z, Z, Z, N, A, N, A, A, N, A, N, N, N, N,
+            N, A, N, N, A, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, A, N, N, N, N, N,
+            N, N, N, N, N, A,
+        ],
+        [
+            N, A, A, A, A, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, A, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, A, N, A, N, N, N, A, N, N, N, N, N, N, N, N, N, A, N, N, A, N, N, N, N, N, N,
+            N, N, N, N, A, A, N, N, N, A, N, N, N, N, A, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, A, A, N, N, N, N, N, N, A, A,
+            A, A, N, A, A, A, A, A, A, A, A, A, A, A, A, N, N, N, N, A, A, A, A, A, A, A, A, A, A,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, A, N, N, N, N, N, N, A, A, A, A, A, A, A, A, A, A, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, A, A,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, A, N, A, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, A, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            A, N, A, A, N, N, N, A, A, N, N, A, N, N, N, A, N, A, N, N, N, A, N, N, N, N, A, N, N,
+            A, A, A, A, N, N, A, N, A, N, A, A, A, A, A, A, N, A, N, N, N, N, N, A, A, A, A, N, N,
+            N, N, A, A, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, A, N, N, N, A, N, N, N, N, N, A, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, A, A, N, N, A, A, A, A, N, N, A, A, N, N, A, A, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, A, A, N, N, A, A, N, N, N, N, N, N, N, N, N, N, N, N, N, A, N, N, N, A, N, N, N,
+            N, N, N, N, N, N, N, N, A, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, A,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, A, N, N, N, N, N, N, N, W, W, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, W, W, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, W, W, W, W, N, N, N, W, N, N, W, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, A,
+        ],
+        [
+            A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, A,
+        ],
+        [
+            A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, A, A, A, A, A, A, A, A, N, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, A,
+        ],
+        [
+            A, A, A, A, A, A, A, A, A, A, A, A, N, N, N, N, A, A, A, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, N, N, A, A, A, A, N, N, N, N, N, N, N,
+            N, N, N, A, A, N, A, A, A, A, A, A, A, N, N, N, N, N, N, N, N, A, A, N, N, A, A, N, N,
+            N, N, A, A, N, N,
+        ],
+        [
+            A, A, N, N, N, N, A, A, A, N, N, A, N, N, A, A, A, A, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, A, A, A, A, N, N, N, N, N, N, N, N, N, A, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, W, W, N,
+        ],
+        [
+            N, N, N, N, N, A, A, N, N, A, N, N, N, N, A, A, N, N, N, N, W, W, N, N, N, N, N, N, A,
+            N, A, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, W, W, W, W, W, W, W, W, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            A, N, A, N, N, N, N, N, W, W, W, W, W, W, W, W, W, W, W, W, N, N, N, N, N, N, N, N, N,
+            N, N, N, A, A, N, A, A, A, N, A, A, A, A, N, A, A, N, A, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, W,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, W, W, W, W, W, W, N, N, N, W, N, N, N, N, N, N, N, N, N,
+            N, A, A, N, W, N, N, N, N, N, N, N, N, W, W, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, W, W, A,
+        ],
+        [
+            N, N, N, N, W, W, A, A, A, A, A, A, A, A, W, A, A, A, A, A, W, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, N, A, N, N, N, N, A, A, W, A, A, A, A, A, A, A, W, W, A, W, A, A, A, A,
+            W, A, A, W, A, A,
+        ],
+        [
+            N, N, N, N, N, W, N, N, N, N, W, W, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, W, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, A, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, W, N, W, N, N, N, N, W, W, W, N, W, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, A, A, A, A,
+            A, A, A, A, A, A,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, W, W, W, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, W, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, W,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, W, W,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, W, N, N, N, N, W, A, A, A, A, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, Z,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, N, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, Z, Z, Z, Z, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, N,
+        ],
+        [
+            N, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, N, N, Z, Z, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            N, N, N, N, N, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, N, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, Z, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, N, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, N, N, N, N, N, N, N, N, N, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, N, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, A, A, A, A, A, A, A, A, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, N, N, N, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, N, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, Z, N, N, N, Z, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, Z, Z, N, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, Z,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, N, N, N,
+        ],
+        [
+            Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, N, Z, Z, Z, Z,
+            N, N, Z, Z, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, N, N, Z, Z, N, N, Z, Z, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, Z, N, N, N, N, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, Z, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, Z, Z, Z, N, N, Z, Z, N,
+            N, N, N, N, Z, Z,
+        ],
+        [
+            N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, Z, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, Z, N, N, Z, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z,
+        ],
+        [
+            Z, Z, Z, Z, Z, Z, Z, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, W, W, W, W, W, W, W, W, W, W, N, N, N,
+            N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, N, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, N, W, W, W, W, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, W, W, W, W, W, W, W, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, N,
+            N, N, N, A, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, Z, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z,
+            Z, N, N, N, N, N,
+        ],
+        [
+            N, Z, Z, Z, N, Z, Z, N, N, N, N, N, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z,
+            Z, N, N, N, N, Z,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            Z, Z, Z, Z, Z, Z,
+        ],
+        [
+            N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z,
+            Z, Z, Z, Z, Z, Z,
+        ],
+        [
+            Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, N, Z, Z, N, N, N, N, N,
+            N, N, N, N, N, Z,
+        ],
+        [
+            Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, N, N, Z,
+            Z, N, N, N, N, N,
+        ],
+        [
+            Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, N, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, N, N, Z, N, Z, Z, N, N,
+            N, N, N, N, Z, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, Z, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, N, N, N, Z, Z, Z, Z, Z, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, Z, Z, Z, Z, Z,
+        ],
+        [
+            Z, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, Z, N, Z, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z,
+            Z, Z, Z, Z, Z, Z,
+        ],
+        [
+            N, N, Z, Z, Z, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, N,
+            Z, N, N, N, N, Z,
+        ],
+        [
+            Z, N, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, N, N, N, N,
+            N, N, Z, Z, N, Z,
+        ],
+        [
+            Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z,
+            Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z,
+            Z, N, N, Z, N, Z,
+        ],
+        [
+            Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, Z, N, N, Z, Z, Z, Z, Z, Z, N, Z, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            Z, N, Z, N, N, Z, Z, Z, Z, N, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, Z,
+            Z, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, Z, Z, N, Z, N,
+        ],
+        [
+            N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, N, N, Z, Z, N,
+            N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, N,
+            N, Z, Z, Z, Z, N,
+        ],
+        [
+            N, N, N, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, N, N, Z, Z, Z, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, Z, Z, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, Z, N, Z, Z, Z, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, N, Z, Z,
+            Z, Z, Z, Z, N, Z,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, Z, Z, Z, Z, Z, Z, Z, N, Z, Z, N, Z, Z, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, N, N, N,
+            Z, N, Z, Z, N, Z,
+        ],
+        [
+            Z, Z, Z, Z, Z, Z, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, N, N, N, Z, N, Z, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z,
+            Z, N, N, N, N, N,
+        ],
+        [
+            Z, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Z, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, Z, Z, Z, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, W, W, W, W, Z, N, N, N, N, N, N, N, N, N, N, N, W, W, W, W, W, W, W, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, W, W, W, W, N, W, W, W, W, W,
+            W, W, N, W, W, N,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, W, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, W, W, W, N, N, W, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, W, W, W, W, N, N, N, N, N, N, N, N, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            Z, Z, N, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z,
+        ],
+        [
+            Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, Z, Z, Z, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z,
+        ],
+        [
+            Z, Z, Z, N, N, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, N, N, N, N, N, N,
+            N, N, N, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N,
+            N, Z, Z, Z, Z, Z,
+        ],
+        [
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z,
+            Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, Z, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z,
+            Z, Z, Z, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            Z, Z, Z, Z, Z, Z, Z, N, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, Z, N, N, Z, Z,
+            Z, Z, Z, Z, Z, N, Z, Z, N, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, Z, N, N, Z, N, N, N, N, N, N, N, Z, Z, N, N, N, N, N, Z, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, Z, Z, Z, Z, Z, Z, Z, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, W, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, W, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            A, A, A, A, A, A, A, A, A, A, A, N, N, N, N, N, A, A, A, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, N, N, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, A,
+        ],
+        [
+            A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, A, A, A, A, A, A, A, A, N, N, N, N, N, N, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, A,
+        ],
+        [
+            A, A, A, A, A, A, A, A, A, A, A, A, A, A, W, A, A, W, W, W, W, W, W, W, W, W, W, A, A,
+            A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            W, W, W, N, N, N, N, N, N, N, N, N, N, N, N, N, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, N, N, N, N,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, N, N, N, N, N, N, N, W, W, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, W, W, W, W, W, W, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, N, N, N, N, N, N, N, N, N, N, N, N, W, W, W, W, W, W, W, W, W, N, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, N, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, N, N, N, N, N, N, N, N, N,
+            N, N, N, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, N, N, N, N, W, W, W, W, W, N, N, N, N, N, N, N, N, N,
+            N, N, N, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, N, N, N, W, N, N, N, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, N,
+        ],
+        [
+            W, N, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, N, N, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, W, W, W, W, N, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            W, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, W, W, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, W, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            W, W, W, W, W, W, N, N, N, N, N, N, W, N, N, N, W, W, W, N, N, W, W, W, W, N, N, N, W,
+            W, W, W, N, N, N, N, N, N, N, N, N, N, N, W, W, N, N, N, N, N, N, N, W, W, W, W, W, W,
+            W, W, W, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, W, W, W, W, W, W, W, W, W, W, W, W, N, N, N, N, W, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, N, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, N, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
+            N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, N, N, N,
+        ],
+        [
+            W, W, W, W, W, W, W, W, W, W, W, N, N, N, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            W, W, W, W, W, W,
+        ],
+        [
+            W, W, W, W, W, W, W, N, W, N, N, N, N, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W, W,
+            N, N, W, W, W, W, W, W, W, W, W, W, W, W, N, N, N, N, W, W, W, W, W, W, W, W, W, W, N,
+            N, N, N, N, N, N,
+        ],
+        [
+            A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A, A,
+            A, A, A, A, N, N,
+        ],
+    ],
+};
@@ -0,0 +1,50 @@
+"""Generates src/tables/word.rs: the property word segmentation (UAX #29) is defined in terms of,
+besides `Extended_Pictographic`.
+
+    python3 src/tables/word.py <path to UCD> > src/tables/word.rs
+"""
+
+from ucd import enumerated_property, header, ucd_dir, value_trie
+
+# short alias to `WordBreak` variant, by the long alias used in WordBreakProperty.txt
+WORD_BREAKS = {
+    "Other": ("XX", "Other"),
+    "CR": ("CR", "CR"),
+    "LF": ("LF", "LF"),
+    "Newline": ("NL", "Newline"),
+    "Extend": ("EXT", "Extend"),
+    "ZWJ": ("ZWJ", "ZWJ"),
+    "Regional_Indicator": ("RI", "RegionalIndicator"),
+    "Format": ("FO", "Format"),
+    "Katakana": ("KA", "Katakana"),
+    "Hebrew_Letter": ("HL", "HebrewLetter"),
+    "ALetter": ("LE", "ALetter"),
+    "Single_Quote": ("SQ", "SingleQuote"),
+    "Double_Quote": ("DQ", "DoubleQuote"),
+    "MidNumLet": ("MB", "MidNumLet"),
+    "MidLetter": ("ML", "MidLetter"),
+    "MidNum": ("MN", "MidNum"),
+    "Numeric": ("NU", "Numeric"),
+    "ExtendNumLet": ("EX", "ExtendNumLet"),
+    "WSegSpace": ("WS", "WSegSpace"),
+}
+
+
+def main() -> None:
+    breaks = enumerated_property(ucd_dir() / "auxiliary/WordBreakProperty.txt", "Other")
+
+    print(header("word.py", ["auxiliary/WordBreakProperty.txt"]))
+    print("use super::Utf8Trie;")
+    print("use crate::word::WordBreak;")
+    print()
+
+    for short, variant in WORD_BREAKS.values():
+        print(f"/// `WordBreak::{variant}`, shortened to keep the table below readable")
+        print(f"const {short}: WordBreak = WordBreak::{variant};")
+
+    print()
+    print(value_trie("WORD_BREAK", "The `Word_Break` of every codepoint", "WordBreak", [WORD_BREAKS[b][0] for b in breaks]), end="")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,1893 @@
+//! Generated by `src/tables/word.py` from the Unicode 17.0.0 files
+//! auxiliary/WordBreakProperty.txt. Do not edit by hand.
+
+use super::Utf8Trie;
+use crate::word::WordBreak;
+
+/// `WordBreak::Other`, shortened to keep the table below readable
+const XX: WordBreak = WordBreak::Other;
+/// `WordBreak::CR`, shortened to keep the table below readable
+const CR: WordBreak = WordBreak::CR;
+/// `WordBreak::LF`, shortened to keep the table below readable
+const LF: WordBreak = WordBreak::LF;
+/// `WordBreak::Newline`, shortened to keep the table below readable
+const NL: WordBreak = WordBreak::Newline;
+/// `WordBreak::Extend`, shortened to keep the table below readable
+const EXT: WordBreak = WordBreak::Extend;
+/// `WordBreak::ZWJ`, shortened to keep the table below readable
+const ZWJ: WordBreak = WordBreak::ZWJ;
+/// `WordBreak::RegionalIndicator`, shortened to keep the table below readable
+const RI: WordBreak = WordBreak::RegionalIndicator;
+/// `WordBreak::Format`, shortened to keep the table below readable
+const FO: WordBreak = WordBreak::Format;
+/// `WordBreak::Katakana`, shortened to keep the table below readable
+const KA: WordBreak = WordBreak::Katakana;
+/// `WordBreak::HebrewLetter`, shortened to keep the table below readable
+const HL: WordBreak = WordBreak::HebrewLetter;
+/// `WordBreak::ALetter`, shortened to keep the table below readable
+const LE: WordBreak = WordBreak::ALetter;
+/// `WordBreak::SingleQuote`, shortened to keep the table below readable
+const SQ: WordBreak = WordBreak::SingleQuote;
+/// `WordBreak::DoubleQuote`, shortened to keep the table below readable
+const DQ: WordBreak = WordBreak::DoubleQuote;
+/// `WordBreak::MidNumLet`, shortened to keep the table below readable
+const MB: WordBreak = WordBreak::MidNumLet;
+/// `WordBreak::MidLetter`, shortened to keep the table below readable
+const ML: WordBreak = WordBreak::MidLetter;
+/// `WordBreak::MidNum`, shortened to keep the table below readable
+const MN: WordBreak = WordBreak::MidNum;
+/// `WordBreak::Numeric`, shortened to keep the table below readable
+const NU: WordBreak = WordBreak::Numeric;
+/// `WordBreak::ExtendNumLet`, shortened to keep the table below readable
+const EX: WordBreak = WordBreak::ExtendNumLet;
+/// `WordBreak::WSegSpace`, shortened to keep the table below readable
+const WS: WordBreak = WordBreak::WSegSpace;
+
+/// The `Word_Break` of every codepoint
+pub(crate) static WORD_BREAK: Utf8Trie<[WordBreak; 64]> = Utf8Trie {
+    lead: &[
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+        1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 4, 4, 4, 4, 4, 4, 5, 6, 7, 8, 9, 4, 4, 10, 4, 11, 12, 13,
+        14, 15, 16, 4, 17, 18, 19, 20, 21, 0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 6, 6, 7, 4, 8, 21, 6, 6,
+        23, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ],
+    nodes: &[
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 5, 6, 7, 8, 9, 4, 4, 10, 4, 11, 12, 13, 14, 15, 16, 4,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38,
+            39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53,
+        ],
+        [
+            54, 55, 56, 57, 4, 4, 4, 4, 4, 58, 59, 60, 61, 62, 63, 64, 65, 4, 4, 4, 4, 4, 4, 4, 4,
+            66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87,
+            88, 89, 90, 91, 92, 4, 4, 4, 6, 4, 4, 4, 4, 93, 94, 95, 96,
+        ],
+        [
+            97, 98, 99, 100, 101, 102, 103, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 104, 105,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 4, 4, 4, 106, 107, 108, 109, 110, 111, 79, 79, 79, 79, 79, 79,
+            79,
+        ],
+        [
+            112, 79, 113, 114, 115, 4, 116, 117, 79, 79, 79, 118, 119, 120, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+        ],
+        [
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 121, 122, 4, 4, 4, 4, 123, 124,
+            125, 126, 127, 4, 4, 128, 129, 130, 131, 132, 133, 134, 135, 136, 137, 138, 139, 140,
+            141, 142, 4, 143, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 144, 145, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+        ],
+        [
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            146, 147, 148, 149, 4, 4, 4, 4, 150, 151, 152, 153, 154, 155, 4, 156, 157, 158, 159,
+            160,
+        ],
+        [
+            161, 162, 4, 163, 79, 164, 79, 165, 79, 79, 166, 167, 168, 169, 170, 171, 4, 4, 172,
+            173, 174, 175, 176, 130, 4, 4, 4, 4, 177, 178, 179, 79, 180, 181, 182, 183, 184, 185,
+            186, 79, 187, 188, 189, 190, 76, 191, 192, 79, 4, 103, 193, 193, 194, 195, 196, 79, 79,
+            79, 197, 198, 199, 200, 201, 202,
+        ],
+        [
+            203, 204, 205, 206, 207, 208, 209, 210, 211, 212, 213, 214, 215, 216, 217, 218, 219,
+            220, 221, 222, 79, 79, 223, 224, 221, 225, 226, 227, 228, 79, 79, 79, 229, 79, 230, 231,
+            232, 233, 234, 235, 236, 237, 238, 74, 79, 239, 79, 240, 241, 242, 243, 79, 244, 245,
+            246, 247, 79, 79, 79, 248, 249, 250, 251, 79,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 185, 79, 4, 252, 4, 4, 4, 253, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 151, 254,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 255, 256, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 163, 4, 4, 4, 4, 4, 4, 4, 4, 4, 257, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+        ],
+        [
+            79, 79, 79, 79, 258, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 4, 4, 4, 4, 4, 4, 4, 4, 74, 259, 260, 261, 262,
+            263, 264, 79, 79, 79, 79, 79, 79, 265, 79, 79, 79, 4, 266, 267, 4, 268, 269, 270,
+        ],
+        [
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 271,
+        ],
+        [
+            272, 79, 79, 79, 273, 274, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 4, 275, 276, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+        ],
+        [
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 277, 79, 79, 79, 79, 79, 79, 79, 79, 278, 279, 79, 79,
+        ],
+        [
+            79, 79, 79, 79, 79, 280, 281, 79, 79, 282, 79, 79, 79, 79, 79, 79, 4, 283, 284, 285,
+            286, 287, 4, 4, 4, 4, 288, 289, 290, 291, 292, 293, 79, 79, 79, 79, 79, 79, 79, 79, 294,
+            295, 296, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 297, 79,
+            79, 79,
+        ],
+        [
+            298, 299, 300, 79, 301, 302, 79, 79, 79, 79, 303, 304, 79, 79, 79, 79, 79, 79, 79, 305,
+            79, 79, 79, 306, 79, 79, 79, 307, 79, 79, 79, 308, 4, 4, 4, 309, 4, 310, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 311, 312, 313, 79, 79, 79, 79,
+            79,
+        ],
+        [
+            79, 79, 79, 79, 314, 315, 316, 317, 79, 79, 79, 79, 79, 79, 79, 318, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 277, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+        ],
+        [
+            0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 5, 6, 6, 7, 4, 8, 9, 10, 11, 12, 13, 4, 14, 4, 4, 4, 15,
+            16, 17, 18, 19, 20, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4, 4, 4, 4,
+        ],
+        [
+            319, 6, 79, 79, 6, 6, 6, 320, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+            79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79, 79,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 22, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            4, 4, 4, 4, 4, 4,
+        ],
+        [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ],
+        [
+            4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24,
+            24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24,
+            24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24,
+        ],
+    ],
+    leaves: &[
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LF, NL, NL, CR, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, WS, XX, DQ, XX, XX, XX, XX, SQ, XX, XX, XX, XX,
+            MN, XX, MB, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, ML, MN, XX, XX, XX, XX,
+        ],
+        [
+            XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, XX, XX, XX, XX, EX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, NL, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, XX,
+            XX, FO, XX, XX, XX, XX, XX, XX, XX, LE, XX, ML, LE, XX, LE, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, LE, LE, LE,
+            LE, XX, LE, LE, XX, XX, LE, LE, LE, LE, MN, LE,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, LE, ML, LE, LE, LE, XX, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, XX, LE, LE, LE, LE, XX, LE, ML, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, MN, LE, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, EXT,
+        ],
+        [
+            XX, EXT, EXT, XX, EXT, EXT, XX, EXT, XX, XX, XX, XX, XX, XX, XX, XX, HL, HL, HL, HL, HL,
+            HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL,
+            XX, XX, XX, XX, HL, HL, HL, HL, LE, ML, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, MN, MN, XX, XX, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, XX, FO, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, NU, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, XX, NU, MN, XX, LE, LE, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, NU, XX, EXT, EXT, EXT, EXT, EXT, EXT, LE, LE, EXT,
+            EXT, XX, EXT, EXT, EXT, EXT, LE, LE, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, LE, LE, LE,
+            XX, XX, LE,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, EXT, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, LE, XX, XX, MN, XX, LE, XX, XX, EXT, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            EXT, EXT, EXT, EXT, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, EXT, EXT, EXT,
+            LE, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, EXT, EXT, EXT, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, NU, NU, XX, XX, XX, XX,
+            XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, NU, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, LE, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, XX, XX,
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE,
+        ],
+        [
+            LE, EXT, EXT, EXT, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, XX, XX, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE,
+            LE, LE, LE, LE, LE, LE, XX, LE, XX, XX, XX, LE, LE, LE, LE, XX, XX, EXT, LE, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, XX, XX, EXT, EXT, XX, XX, EXT, EXT, EXT, LE, XX, XX, XX, XX,
+            XX, XX, XX, XX, EXT, XX, XX, XX, XX, LE, LE, XX, LE, LE, LE, EXT, EXT, XX, XX, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, XX,
+            EXT, XX,
+        ],
+        [
+            XX, EXT, EXT, EXT, XX, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, LE, LE, XX, XX, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE,
+            LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, LE, LE, XX, LE, LE, XX, XX, EXT, XX, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, XX, XX, XX, XX, EXT, EXT, XX, XX, EXT, EXT, EXT, XX, XX, XX, EXT, XX, XX,
+            XX, XX, XX, XX, XX, LE, LE, LE, LE, XX, LE, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, EXT, EXT, LE, LE, LE, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX,
+        ],
+        [
+            XX, EXT, EXT, EXT, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, XX, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE,
+            LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, LE, LE, LE, LE, LE, XX, XX, EXT, LE, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, XX, EXT, EXT, EXT, XX, EXT, EXT, EXT, XX, XX, LE, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, EXT, EXT, XX, XX, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, EXT, EXT, EXT,
+            EXT, EXT, EXT,
+        ],
+        [
+            XX, EXT, EXT, EXT, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, XX, XX, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE,
+            LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, LE, LE, LE, LE, LE, XX, XX, EXT, LE, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, XX, XX, EXT, EXT, XX, XX, EXT, EXT, EXT, XX, XX, XX, XX, XX,
+            XX, XX, EXT, EXT, EXT, XX, XX, XX, XX, LE, LE, XX, LE, LE, LE, EXT, EXT, XX, XX, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX,
+        ],
+        [
+            XX, XX, EXT, LE, XX, LE, LE, LE, LE, LE, LE, XX, XX, XX, LE, LE, LE, XX, LE, LE, LE, LE,
+            XX, XX, XX, LE, LE, XX, LE, XX, LE, LE, XX, XX, XX, LE, LE, XX, XX, XX, LE, LE, LE, XX,
+            XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, XX, XX, XX, EXT, EXT, EXT, XX, EXT, EXT, EXT, EXT, XX, XX, LE, XX, XX,
+            XX, XX, XX, XX, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, EXT, LE, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, XX, EXT, EXT, EXT, XX, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX,
+            XX, XX, EXT, EXT, XX, LE, LE, LE, XX, LE, LE, XX, XX, LE, LE, EXT, EXT, XX, XX, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX,
+        ],
+        [
+            LE, EXT, EXT, EXT, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, XX, XX, EXT, LE, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, XX, EXT, EXT, EXT, XX, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX,
+            XX, XX, EXT, EXT, XX, XX, XX, XX, XX, LE, LE, LE, XX, LE, LE, EXT, EXT, XX, XX, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, XX, LE, LE, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX,
+        ],
+        [
+            EXT, EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, LE, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, XX, EXT, EXT, EXT, XX, EXT, EXT, EXT, EXT, LE, XX, XX, XX, XX,
+            XX, LE, LE, LE, EXT, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, EXT, EXT, XX, XX, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE,
+            LE, LE,
+        ],
+        [
+            XX, EXT, EXT, EXT, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, EXT, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT,
+            EXT, XX, EXT, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, EXT, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, EXT, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX,
+            XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, EXT, EXT, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, XX, EXT, XX, EXT, XX, XX, XX, XX, EXT, EXT,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, XX, EXT, EXT, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            EXT, EXT, EXT, EXT, XX, XX, XX, XX, EXT, EXT, EXT, XX, EXT, EXT, EXT, XX, XX, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, XX, XX, XX, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX,
+        ],
+        [
+            XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, EXT, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, EXT, EXT, EXT, EXT, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, XX, LE, XX, XX, XX, XX, XX, LE, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, XX, LE, XX, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, XX,
+        ],
+        [
+            LE, XX, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+            XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, XX, XX, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, XX, XX,
+        ],
+        [
+            XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            WS, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+            XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT,
+            EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, XX, LE, LE, LE, XX, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, FO, EXT, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, LE,
+            XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, XX, XX, EXT,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX,
+        ],
+        [
+            EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, LE, LE, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, LE, LE, LE, LE,
+            LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX,
+            XX, XX, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, LE, LE, LE, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, XX, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, LE, LE, LE, LE, EXT, LE, LE, LE, LE, LE, LE, EXT, LE, LE, EXT, EXT, EXT,
+            LE, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            XX, XX, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, XX, LE, XX, LE, XX, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, XX,
+        ],
+        [
+            XX, XX, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, LE, LE, LE, LE, XX, XX,
+            LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, XX, XX, XX, XX, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX,
+        ],
+        [
+            WS, WS, WS, WS, WS, WS, WS, XX, WS, WS, WS, XX, EXT, ZWJ, FO, FO, XX, XX, XX, XX, XX,
+            XX, XX, XX, MB, MB, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, MB, XX, XX, ML, NL, NL, FO,
+            FO, FO, FO, FO, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+        ],
+        [
+            EX, XX, XX, XX, MN, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, WS, FO, FO, FO, FO, FO, XX, FO, FO, FO, FO, FO, FO,
+            FO, FO, FO, FO, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, LE, XX, XX, XX, XX, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE,
+            XX, XX, XX, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, LE, XX, LE, XX, LE, XX, LE, LE,
+            LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE,
+        ],
+        [
+            XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, XX, XX, XX, XX, LE, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, LE,
+            LE, LE, LE, EXT, EXT, EXT, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, XX, XX, XX, XX,
+            XX, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+            XX, XX, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+            LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE,
+            LE, XX, LE, LE, LE, LE, LE, LE, LE, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            WS, XX, XX, XX, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT,
+            EXT, EXT, EXT, EXT, EXT, XX, KA, KA, KA, KA, KA, XX, XX, XX, XX, XX, LE, LE, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, EXT, EXT, KA, KA, XX, XX, XX, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA,
+            KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA,
+        ],
+        [
+            KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA,
+            KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA,
+            KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, XX, KA, KA, KA, KA,
+        ],
+        [
+            XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, KA, KA, KA, KA, KA, KA,
+            KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA,
+            KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, XX,
+        ],
+        [
+            KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA,
+            KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA,
+            KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA,
+        ],
+        [
+            KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA,
+            KA, KA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, LE, LE,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, EXT, EXT, EXT, EXT, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            XX, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, EXT, LE, LE, LE, EXT, LE, LE, LE, LE, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, XX, XX,
+            XX, XX, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, XX, XX, XX,
+            LE, XX, LE, LE, EXT,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX,
+            XX,
+        ],
+        [
+            EXT, EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT,
+        ],
+        [
+            EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX,
+        ],
+        [
+            LE, LE, LE, EXT, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, XX, XX, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EXT, XX, EXT, EXT, EXT, XX, XX, EXT, EXT, XX, XX, XX, XX, XX, EXT, EXT,
+        ],
+        [
+            XX, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT,
+            EXT, EXT, EXT, EXT, XX, XX, LE, LE, LE, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE,
+            LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+            LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, XX, EXT, EXT, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX,
+            XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE,
+            LE, LE, XX, XX, XX, XX, XX, HL, EXT, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, XX, HL, HL,
+            HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, XX, HL, HL, HL, HL, HL, XX, HL, XX,
+        ],
+        [
+            HL, HL, XX, HL, HL, XX, HL, HL, HL, HL, HL, HL, HL, HL, HL, HL, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX,
+            XX, ML, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, EX, EX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, MN, XX, MB, XX, MN, ML,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, FO,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, MB, XX, XX, XX, XX, MN, XX, MB, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, ML, MN, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, EX,
+        ],
+        [
+            XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, KA, KA, KA, KA, KA, KA,
+            KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA,
+        ],
+        [
+            KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA, KA,
+            KA, KA, KA, KA, KA, KA, KA, KA, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+        ],
+        [
+            XX, XX, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE,
+            LE, LE, XX, XX, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, FO, FO, FO, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX,
+            XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE,
+            XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, XX, XX, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, XX, XX, LE, XX, XX, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, LE, LE,
+        ],
+        [
+            LE, EXT, EXT, EXT, XX, EXT, EXT, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, LE, LE, LE, LE,
+            XX, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, EXT, EXT, EXT, XX, XX, XX, XX,
+            EXT,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, XX, XX, XX,
+            XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, EXT, EXT,
+            EXT, EXT, EXT, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, EXT,
+            EXT, XX, XX, XX, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE,
+        ],
+        [
+            LE, LE, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, EXT, LE, LE, EXT, EXT, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            EXT,
+        ],
+        [
+            EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, NU,
+            XX, XX,
+        ],
+        [
+            XX, XX, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, NU, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU,
+        ],
+        [
+            XX, XX, XX, XX, LE, EXT, EXT, LE, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, EXT, XX, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT,
+        ],
+        [
+            EXT, LE, LE, LE, LE, XX, XX, XX, XX, EXT, EXT, EXT, EXT, XX, EXT, EXT, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, LE, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, EXT,
+            LE,
+        ],
+        [
+            LE, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, XX, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX,
+            XX, XX,
+        ],
+        [
+            EXT, EXT, EXT, EXT, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, XX, XX, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE,
+            LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, LE, LE, LE, LE, LE, XX, EXT, EXT, LE, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, XX, XX, EXT, EXT, XX, XX, EXT, EXT, EXT, XX, XX, LE, XX, XX,
+            XX, XX, XX, XX, EXT, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, EXT, EXT, XX, XX, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, XX, XX, LE, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+        ],
+        [
+            EXT, XX, EXT, XX, XX, EXT, XX, EXT, EXT, EXT, EXT, XX, EXT, EXT, EXT, EXT, EXT, LE, EXT,
+            LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, LE, LE, LE, XX, XX, XX, XX, XX, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, EXT, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, LE, LE, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT,
+        ],
+        [
+            EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, LE, LE, LE, LE, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EXT, XX, XX, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, XX, XX, XX, XX, XX, XX,
+            XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX,
+            XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX,
+            XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE,
+            LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, XX, EXT, EXT, XX, XX, EXT, EXT, EXT, EXT,
+            LE,
+        ],
+        [
+            EXT, LE, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, XX, LE, EXT, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX,
+        ],
+        [
+            LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, EXT, EXT,
+            EXT, EXT, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, EXT, XX, XX, XX, XX, XX, XX, XX, XX, LE, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT,
+        ],
+        [
+            LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, EXT, XX, EXT, EXT, XX,
+            EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, LE, EXT, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, XX, EXT, EXT, XX, EXT,
+            EXT, EXT, EXT, EXT, LE, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU,
+            NU, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EXT, EXT, LE, EXT, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, EXT,
+            EXT,
+        ],
+        [
+            EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, FO, FO, FO, FO, FO, FO, FO, FO, FO, FO, FO, FO, FO, FO, FO, FO,
+        ],
+        [
+            EXT, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX,
+            XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, XX, XX, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, EXT, LE, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, XX, LE, EXT, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, KA, KA, KA, KA, XX, KA, KA, KA, KA, KA, KA, KA, XX, KA, KA, XX,
+        ],
+        [
+            KA, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, KA, KA, KA, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, KA,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, KA, KA, KA, KA, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, XX, XX, XX, EXT, EXT, XX, FO, FO, FO, FO, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, XX,
+            XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, FO, FO, FO, FO, FO, FO, FO, FO, EXT, EXT, EXT,
+            EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX,
+        ],
+        [
+            XX, XX, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, XX, LE, XX, XX, LE, LE, XX, XX, LE, LE, LE,
+            LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, XX, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, XX, LE, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, NU, NU, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX,
+            EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, EXT,
+            EXT, XX, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, LE, LE, LE, LE, LE, LE, XX, XX,
+        ],
+        [
+            NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, LE, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            EXT, EXT, EXT, EXT, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            EXT, EXT, EXT, EXT, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, EXT, EXT, LE, NU, NU, NU, NU, NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, EXT, LE, LE, EXT, LE, LE, LE, LE,
+            LE, LE, LE, EXT, EXT, LE, LE, LE, LE, LE, EXT, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE,
+            XX, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, EXT, EXT, EXT, EXT, EXT, EXT, EXT, LE, XX, XX, XX, XX, NU, NU, NU, NU,
+            NU, NU, NU, NU, NU, NU, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, XX, LE, XX, XX, LE, XX, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, XX, LE, XX, LE, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, LE, XX, XX, XX, XX, LE, XX, LE, XX, LE, XX, LE, LE, LE, XX, LE, LE, XX, LE, XX,
+            XX, LE, XX, LE, XX, LE, XX, LE, XX, LE, XX, LE, LE, XX, LE, XX, XX, LE, LE, LE, LE, XX,
+            LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, XX, LE, LE, LE, LE, XX, LE, XX,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, LE, LE, LE, XX, LE, LE, LE, LE, LE, XX, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, LE, LE, LE, LE, LE, LE,
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX,
+            XX, XX, XX, XX, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, LE,
+        ],
+        [
+            LE, LE, LE, LE, LE, LE, LE, LE, LE, LE, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, RI, RI, RI, RI, RI, RI,
+            RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI,
+        ],
+        [
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT,
+        ],
+        [
+            XX, FO, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT,
+        ],
+        [
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT,
+            EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, EXT, XX, XX, XX, XX,
+            XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+        ],
+    ],
+};
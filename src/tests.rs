@@ -1,7 +1,9 @@
 //! Module to ease testing with miri and nonmiri
 
 use itertools::Itertools;
-use rayon::iter::{IntoParallelIterator, ParallelBridge, ParallelIterator};
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelIterator, ParallelBridge, ParallelIterator,
+};
 
 // all of the step_by numbers are primes
 
@@ -29,3 +31,42 @@ pub(crate) fn all_char_pairs() -> impl Iterator<Item = (char, char)> {
         .step_by(10_007)
         .cartesian_product(('\0'..=char::MAX).step_by(10_007))
 }
+
+#[cfg(not(miri))]
+pub(crate) fn all_byte_triples() -> impl ParallelIterator<Item = [u8; 3]> {
+    (0..1u32 << 24)
+        .into_par_iter()
+        .map(|n| n.to_le_bytes()[..3].try_into().unwrap())
+}
+
+#[cfg(miri)]
+pub(crate) fn all_byte_triples() -> impl Iterator<Item = [u8; 3]> {
+    (0..1u32 << 24)
+        .step_by(65_537)
+        .map(|n| n.to_le_bytes()[..3].try_into().unwrap())
+}
+
+#[cfg(not(miri))]
+pub(crate) fn all_byte_quads() -> impl ParallelIterator<Item = [u8; 4]> {
+    (0..=u32::MAX).into_par_iter().map(u32::to_le_bytes)
+}
+
+#[cfg(miri)]
+pub(crate) fn all_byte_quads() -> impl Iterator<Item = [u8; 4]> {
+    (0..=u32::MAX).step_by(16_777_259).map(u32::to_le_bytes)
+}
+
+/// every scalar value and surrogate, and a sample of the integers above `char::MAX`
+#[cfg(not(miri))]
+pub(crate) fn all_u32s() -> impl ParallelIterator<Item = u32> {
+    (0..=0x11_0000)
+        .into_par_iter()
+        .chain((0x11_0000..u32::MAX).into_par_iter().step_by(257))
+}
+
+#[cfg(miri)]
+pub(crate) fn all_u32s() -> impl Iterator<Item = u32> {
+    (0..=0x11_0000)
+        .step_by(4_999)
+        .chain((0x11_0000..=u32::MAX).step_by(16_777_259))
+}
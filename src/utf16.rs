@@ -0,0 +1,278 @@
+//! Converting between `Utf8Char` and UTF-16 without going through `char`
+
+use core::iter::FusedIterator;
+
+use crate::{DecodeUtf16Error, Utf8Char};
+
+/// Returns whether a UTF-16 code unit is a surrogate, leading or trailing
+const fn is_surrogate(unit: u16) -> bool {
+    matches!(unit, 0xD800..=0xDFFF)
+}
+
+/// Returns whether a UTF-16 code unit is a leading (high) surrogate
+const fn is_leading_surrogate(unit: u16) -> bool {
+    matches!(unit, 0xD800..=0xDBFF)
+}
+
+/// Returns whether a UTF-16 code unit is a trailing (low) surrogate
+const fn is_trailing_surrogate(unit: u16) -> bool {
+    matches!(unit, 0xDC00..=0xDFFF)
+}
+
+/// Encodes a code unit that is not a surrogate
+const fn from_bmp(unit: u16) -> Utf8Char {
+    // SAFETY: every u16 outside of the surrogate range is a scalar value
+    unsafe { Utf8Char::from_u32_unchecked(unit as u32) }
+}
+
+/// Encodes a surrogate pair
+const fn from_surrogates(leading: u16, trailing: u16) -> Utf8Char {
+    let code = 0x1_0000 + (((leading as u32 & 0x3FF) << 10) | (trailing as u32 & 0x3FF));
+
+    // SAFETY: a surrogate pair encodes 0x10000..=0x10FFFF, which are all scalar values
+    unsafe { Utf8Char::from_u32_unchecked(code) }
+}
+
+/// An iterator that decodes UTF-16 code units into `Utf8Char`'s
+///
+/// Created by [`Utf8Char::decode_utf16`], this is the `Utf8Char` equivalent of
+/// [`core::char::DecodeUtf16`].
+#[derive(Clone, Debug)]
+pub struct DecodeUtf16<I: Iterator<Item = u16>> {
+    /// The code units left to decode
+    iter: I,
+    /// A code unit that was read while looking for a trailing surrogate, but was not one
+    buf: Option<u16>,
+}
+
+impl<I: Iterator<Item = u16>> Iterator for DecodeUtf16<I> {
+    type Item = Result<Utf8Char, DecodeUtf16Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let unit = match self.buf.take() {
+            Some(buf) => buf,
+            None => self.iter.next()?,
+        };
+
+        if !is_surrogate(unit) {
+            return Some(Ok(from_bmp(unit)));
+        }
+
+        if is_trailing_surrogate(unit) {
+            return Some(Err(DecodeUtf16Error::new(unit)));
+        }
+
+        let Some(trailing) = self.iter.next() else {
+            return Some(Err(DecodeUtf16Error::new(unit)));
+        };
+
+        if !is_trailing_surrogate(trailing) {
+            // not part of this pair, but it may start the next codepoint
+            self.buf = Some(trailing);
+            return Some(Err(DecodeUtf16Error::new(unit)));
+        }
+
+        Some(Ok(from_surrogates(unit, trailing)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // copied from core::char::DecodeUtf16
+        let (low, high) = self.iter.size_hint();
+
+        let (low_buf, high_buf) = match self.buf {
+            None => (0, 0),
+            // a non surrogate is always one more item
+            Some(unit) if !is_surrogate(unit) => (1, 1),
+            // a leading surrogate with nothing left to pair with is always one more error
+            Some(_) if high == Some(0) => (1, 1),
+            // a leading surrogate may pair with the next unit, or become an error
+            Some(_) => (0, 1),
+        };
+
+        // every item takes at most two units, and at least one
+        let low = low.div_ceil(2) + low_buf;
+        let high = high.and_then(|h| h.checked_add(high_buf));
+
+        (low, high)
+    }
+}
+
+impl<I: FusedIterator<Item = u16>> FusedIterator for DecodeUtf16<I> {}
+
+impl Utf8Char {
+    /// Returns the amount of 16-bit code units this codepoint takes up when encoded as UTF-16
+    ///
+    /// See [`char::len_utf16`]
+    #[must_use]
+    pub const fn len_utf16(self) -> usize {
+        // only codepoints outside of the BMP need a surrogate pair, which are exactly the 4 byte
+        // utf8 sequences
+        if self.len_utf8() == 4 {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Encodes this codepoint as UTF-16 into the provided buffer, and returns the subslice of the
+    /// buffer that contains the encoded codepoint.
+    ///
+    /// See [`char::encode_utf16`]
+    ///
+    /// # Panics
+    /// Panics if the buffer is not large enough, a buffer of length 2 is large enough to encode any
+    /// `Utf8Char`.
+    pub const fn encode_utf16(self, dst: &mut [u16]) -> &mut [u16] {
+        let len = self.len_utf16();
+
+        assert!(
+            dst.len() >= len,
+            "encode_utf16: buffer too small to encode codepoint"
+        );
+
+        let code = self.to_u32();
+
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "masked or checked to fit in 16 bits"
+        )]
+        if len == 1 {
+            dst[0] = code as u16;
+        } else {
+            let code = code - 0x1_0000;
+            dst[0] = 0xD800 | (code >> 10) as u16;
+            dst[1] = 0xDC00 | (code & 0x3FF) as u16;
+        }
+
+        dst.split_at_mut(len).0
+    }
+
+    /// Decodes a codepoint from exactly one UTF-16 code unit, or a surrogate pair.
+    ///
+    /// Returns None if `first` is a surrogate without a matching `second` trailing surrogate, or if
+    /// `first` is a codepoint on its own and `second` is passed anyway.
+    ///
+    /// ```
+    /// use utf8char::{utf8char, Utf8Char};
+    ///
+    /// assert_eq!(Utf8Char::from_utf16_pair(0x41, None), Some(utf8char!("A")));
+    /// assert_eq!(Utf8Char::from_utf16_pair(0xD83E, Some(0xDD80)), Some(utf8char!("🦀")));
+    /// assert_eq!(Utf8Char::from_utf16_pair(0xD83E, None), None);
+    /// ```
+    #[must_use]
+    pub const fn from_utf16_pair(first: u16, second: Option<u16>) -> Option<Self> {
+        match second {
+            None if !is_surrogate(first) => Some(from_bmp(first)),
+            Some(second) if is_leading_surrogate(first) && is_trailing_surrogate(second) => {
+                Some(from_surrogates(first, second))
+            }
+            _ => None,
+        }
+    }
+
+    /// Creates an iterator over the `Utf8Char`'s encoded in an iterator of UTF-16 code units,
+    /// returning unpaired surrogates as errors.
+    ///
+    /// See [`char::decode_utf16`]
+    ///
+    /// ```
+    /// use utf8char::Utf8Char;
+    ///
+    /// let units = [0xD834, 0xDD1E, 0x6D, 0xDD1E];
+    /// let decoded = Utf8Char::decode_utf16(units)
+    ///     .map(|r| r.map_err(|e| e.unpaired_surrogate()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(decoded[0].unwrap().as_str(), "𝄞");
+    /// assert_eq!(decoded[1].unwrap().as_str(), "m");
+    /// assert_eq!(decoded[2], Err(0xDD1E));
+    /// ```
+    pub fn decode_utf16<I: IntoIterator<Item = u16>>(iter: I) -> DecodeUtf16<I::IntoIter> {
+        DecodeUtf16 {
+            iter: iter.into_iter(),
+            buf: None,
+        }
+    }
+}
+
+#[test]
+fn encode_utf16_matches_std() {
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let utf8 = Utf8Char::from_char(c);
+        let (mut ours, mut theirs) = ([0; 2], [0; 2]);
+
+        assert_eq!(utf8.len_utf16(), c.len_utf16());
+        assert_eq!(utf8.encode_utf16(&mut ours), c.encode_utf16(&mut theirs));
+
+        let (first, second) = match *utf8.encode_utf16(&mut ours) {
+            [first] => (first, None),
+            [first, second] => (first, Some(second)),
+            _ => unreachable!(),
+        };
+        assert_eq!(Utf8Char::from_utf16_pair(first, second), Some(utf8));
+    });
+}
+
+#[test]
+fn from_utf16_pair_matches_std() {
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+    // every second unit around the surrogate range, and some outside of it
+    let seconds = || {
+        [None, Some(0), Some(0x41), Some(0xFFFF)]
+            .into_iter()
+            .chain((0xD7F0..=0xE00F).map(Some))
+    };
+
+    (0..=u16::MAX).into_par_iter().for_each(|first| {
+        for second in seconds() {
+            let mut std = char::decode_utf16([first].into_iter().chain(second));
+            let expected = match (std.next(), std.next()) {
+                (Some(Ok(c)), None) => Some(Utf8Char::from_char(c)),
+                _ => None,
+            };
+
+            assert_eq!(
+                Utf8Char::from_utf16_pair(first, second),
+                expected,
+                "{first:x} {second:x?}"
+            );
+        }
+    });
+}
+
+#[test]
+fn decode_utf16_matches_std() {
+    use itertools::Itertools;
+
+    let units = [
+        0, 0x41, 0xD7FF, 0xD800, 0xD801, 0xDBFF, 0xDC00, 0xDC37, 0xDFFF, 0xE000, 0xFFFF,
+    ];
+
+    for len in 0..=4 {
+        for stream in (0..len).map(|_| units).multi_cartesian_product() {
+            let ours = Utf8Char::decode_utf16(stream.iter().copied());
+            let theirs = char::decode_utf16(stream.iter().copied());
+
+            let (low, high) = ours.size_hint();
+            assert_eq!((low, high), theirs.size_hint());
+
+            let count = ours
+                .zip_longest(theirs)
+                .map(|pair| match pair {
+                    itertools::EitherOrBoth::Both(ours, theirs) => assert_eq!(
+                        ours.map(Utf8Char::to_char)
+                            .map_err(|e| e.unpaired_surrogate()),
+                        theirs.map_err(|e| e.unpaired_surrogate()),
+                        "{stream:x?}"
+                    ),
+                    _ => panic!("length mismatch decoding {stream:x?}"),
+                })
+                .count();
+
+            assert!(low <= count && high.is_none_or(|high| count <= high));
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Terminal display width of `Utf8Char`'s and strings, per UAX #11 (East Asian Width)
+
+use crate::{tables::width::WIDTH, Utf8Char, Utf8CharIter};
+
+/// The display width class of a codepoint, what the width table holds
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum CharWidth {
+    /// Control codepoints (`Cc`), which have no width of their own
+    Control,
+    /// Combining marks, default ignorables and medial or final Hangul jamo
+    Zero,
+    /// Every other codepoint that is not wide or ambiguous
+    Narrow,
+    /// `East_Asian_Width` `W`ide or `F`ullwidth
+    Wide,
+    /// `East_Asian_Width` `A`mbiguous, narrow unless in an East Asian context
+    Ambiguous,
+}
+
+impl Utf8Char {
+    /// Returns the width of this codepoint
+    const fn width_ext(self, ambiguous_width: u8) -> Option<u8> {
+        // printable ascii is by far the most common
+        if matches!(self.to_bytes()[0], 0x20..=0x7E) {
+            return Some(1);
+        }
+
+        match WIDTH.get(self) {
+            CharWidth::Control => None,
+            CharWidth::Zero => Some(0),
+            CharWidth::Narrow => Some(1),
+            CharWidth::Wide => Some(2),
+            CharWidth::Ambiguous => Some(ambiguous_width),
+        }
+    }
+
+    /// Returns the amount of terminal columns this codepoint takes up, or None for control
+    /// codepoints.
+    ///
+    /// Wide and fullwidth codepoints take up 2 columns, combining marks, the zero width joiner and
+    /// other default ignorable codepoints take up none, everything else 1. Codepoints with an
+    /// ambiguous East Asian Width are narrow, see [`width_cjk`][Self::width_cjk] for East Asian
+    /// contexts.
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(utf8char!("a").width(), Some(1));
+    /// assert_eq!(utf8char!("字").width(), Some(2));
+    /// assert_eq!(utf8char!("\u{301}").width(), Some(0));
+    /// assert_eq!(utf8char!("\u{200D}").width(), Some(0));
+    /// assert_eq!(utf8char!("\n").width(), None);
+    /// ```
+    #[must_use]
+    pub const fn width(self) -> Option<u8> {
+        self.width_ext(1)
+    }
+
+    /// Returns the amount of terminal columns this codepoint takes up in an East Asian (CJK)
+    /// context, or None for control codepoints.
+    ///
+    /// The same as [`width`][Self::width], except that codepoints with an ambiguous East Asian
+    /// Width, like Greek and Cyrillic letters or box drawing, are wide.
+    ///
+    /// ```
+    /// use utf8char::utf8char;
+    ///
+    /// assert_eq!(utf8char!("α").width(), Some(1));
+    /// assert_eq!(utf8char!("α").width_cjk(), Some(2));
+    /// assert_eq!(utf8char!("a").width_cjk(), Some(1));
+    /// ```
+    #[must_use]
+    pub const fn width_cjk(self) -> Option<u8> {
+        self.width_ext(2)
+    }
+}
+
+/// Returns the amount of terminal columns a string takes up, the sum of the
+/// [`width`][Utf8Char::width]s of its codepoints.
+///
+/// Control codepoints count as 0 columns, how a terminal renders them depends on the control.
+///
+/// ```
+/// use utf8char::width::display_width;
+///
+/// assert_eq!(display_width("hello"), 5);
+/// assert_eq!(display_width("日本語"), 6);
+/// assert_eq!(display_width("e\u{301}"), 1);
+/// ```
+#[must_use]
+pub fn display_width(s: &str) -> usize {
+    Utf8CharIter::new(s)
+        .map(|ch| usize::from(ch.width().unwrap_or(0)))
+        .sum()
+}
+
+/// Returns the amount of terminal columns a string takes up in an East Asian (CJK) context, the
+/// sum of the [`width_cjk`][Utf8Char::width_cjk]s of its codepoints.
+///
+/// Control codepoints count as 0 columns, like [`display_width`].
+///
+/// ```
+/// use utf8char::width::{display_width, display_width_cjk};
+///
+/// assert_eq!(display_width("±1"), 2);
+/// assert_eq!(display_width_cjk("±1"), 3);
+/// ```
+#[must_use]
+pub fn display_width_cjk(s: &str) -> usize {
+    Utf8CharIter::new(s)
+        .map(|ch| usize::from(ch.width_cjk().unwrap_or(0)))
+        .sum()
+}
+
+#[test]
+fn width() {
+    use rayon::iter::ParallelIterator;
+
+    use crate::GeneralCategory;
+
+    crate::tests::all_chars().for_each(|c| {
+        let utf8 = Utf8Char::from_char(c);
+        let (width, cjk) = (utf8.width(), utf8.width_cjk());
+
+        assert_eq!(width.is_none(), c.is_control(), "{c:?}");
+        assert_eq!(width.is_none(), cjk.is_none(), "{c:?}");
+
+        if let (Some(width), Some(cjk)) = (width, cjk) {
+            assert!(width <= 2 && cjk <= 2, "{c:?}");
+            assert!(width == cjk || (width, cjk) == (1, 2), "{c:?}");
+        }
+
+        if c.is_ascii_graphic() || c == ' ' {
+            assert_eq!(width, Some(1), "{c:?}");
+            assert_eq!(cjk, Some(1), "{c:?}");
+        }
+
+        if matches!(
+            utf8.general_category(),
+            GeneralCategory::NonspacingMark | GeneralCategory::EnclosingMark
+        ) {
+            assert_eq!(width, Some(0), "{c:?}");
+        }
+    });
+}
+
+#[test]
+fn display_widths() {
+    for (s, width, cjk) in [
+        ("", 0, 0),
+        ("abc", 3, 3),
+        ("\tab\r\n", 2, 2),
+        ("ｈｅｌｌｏ", 10, 10),
+        ("한국어", 6, 6),
+        // decomposed Hangul: a leading jamo, then zero width medial and final jamo
+        ("\u{1112}\u{1161}\u{11AB}", 2, 2),
+        ("Привет", 6, 12),
+        ("┌─┐", 3, 6),
+        ("👨\u{200D}👩", 4, 4),
+        ("\u{FEFF}a\u{AD}", 2, 2),
+        ("🦀", 2, 2),
+    ] {
+        assert_eq!(display_width(s), width, "{s:?}");
+        assert_eq!(display_width_cjk(s), cjk, "{s:?}");
+    }
+}
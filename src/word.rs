@@ -0,0 +1,584 @@
+//! Word segmentation of strings, as defined in
+//! [UAX #29](https://www.unicode.org/reports/tr29/#Word_Boundaries)
+//!
+//! Word boundaries fall between words, but also around every space and punctuation mark, and
+//! [`unicode_words`] keeps only the segments that are words:
+//!
+//! ```
+//! use utf8char::word::{unicode_words, word_bounds};
+//!
+//! let text = "Don't split 3.14, co-op or 日本!";
+//!
+//! assert_eq!(
+//!     word_bounds(text).collect::<Vec<_>>(),
+//!     ["Don't", " ", "split", " ", "3.14", ",", " ", "co", "-", "op", " ", "or", " ", "日", "本", "!"],
+//! );
+//! assert_eq!(
+//!     unicode_words(text).collect::<Vec<_>>(),
+//!     ["Don't", "split", "3.14", "co", "op", "or", "日", "本"],
+//! );
+//! ```
+
+use core::iter::FusedIterator;
+
+use crate::{
+    segment::Segments,
+    tables::{grapheme::EXTENDED_PICTOGRAPHIC, word::WORD_BREAK},
+    Utf8Char, Utf8CharIter,
+};
+
+/// The `Word_Break` property of a codepoint, which word boundaries are determined by
+///
+/// Variants are named after the long property value aliases.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WordBreak {
+    /// `XX`: any codepoint without another value
+    Other,
+    /// `CR`: U+000D CARRIAGE RETURN only
+    CR,
+    /// `LF`: U+000A LINE FEED only
+    LF,
+    /// `NL`: the other line terminators
+    Newline,
+    /// `Extend`: combining marks, emoji modifiers and other codepoints that extend the one before
+    /// them
+    Extend,
+    /// `ZWJ`: U+200D ZERO WIDTH JOINER only
+    ZWJ,
+    /// `RI`: the regional indicator symbols, which pair up into flags
+    RegionalIndicator,
+    /// `FO`: format codepoints, which are ignored like `Extend`
+    Format,
+    /// `KA`: Katakana, which forms words on its own
+    Katakana,
+    /// `HL`: Hebrew letters
+    HebrewLetter,
+    /// `LE`: letters of alphabetic scripts, and other codepoints words are made of
+    ALetter,
+    /// `SQ`: U+0027 APOSTROPHE only
+    SingleQuote,
+    /// `DQ`: U+0022 QUOTATION MARK only
+    DoubleQuote,
+    /// `MB`: punctuation within both words and numbers, like periods
+    MidNumLet,
+    /// `ML`: punctuation within words, like colons
+    MidLetter,
+    /// `MN`: punctuation within numbers, like commas
+    MidNum,
+    /// `NU`: digits
+    Numeric,
+    /// `EX`: connector punctuation, like underscores, which join words
+    ExtendNumLet,
+    /// `WSegSpace`: spaces, which are kept together
+    WSegSpace,
+}
+
+impl Utf8Char {
+    /// Returns the [`WordBreak`] property of this codepoint
+    ///
+    /// ```
+    /// use utf8char::{utf8char, word::WordBreak};
+    ///
+    /// assert_eq!(utf8char!("a").word_break(), WordBreak::ALetter);
+    /// assert_eq!(utf8char!("7").word_break(), WordBreak::Numeric);
+    /// assert_eq!(utf8char!("'").word_break(), WordBreak::SingleQuote);
+    /// assert_eq!(utf8char!("_").word_break(), WordBreak::ExtendNumLet);
+    /// assert_eq!(utf8char!("カ").word_break(), WordBreak::Katakana);
+    /// ```
+    #[must_use]
+    pub const fn word_break(self) -> WordBreak {
+        WORD_BREAK.get(self)
+    }
+}
+
+/// Returns whether `WB4` ignores a codepoint, as it extends the one before it
+const fn is_ignored(word_break: WordBreak) -> bool {
+    matches!(
+        word_break,
+        WordBreak::Extend | WordBreak::Format | WordBreak::ZWJ
+    )
+}
+
+/// Returns whether a `Word_Break` is `AHLetter`, a letter of any kind
+const fn is_letter(word_break: Option<WordBreak>) -> bool {
+    matches!(
+        word_break,
+        Some(WordBreak::ALetter | WordBreak::HebrewLetter)
+    )
+}
+
+/// Returns whether an odd amount of regional indicators, not counting what `WB4` ignores, comes
+/// right before a byte offset of a string, given another offset that is known to be a boundary
+///
+/// Regional indicators pair up from a boundary in a run of them, so the count stops at `known`
+/// rather than go back to the start of the run every time.
+fn odd_regional_indicators(s: &str, offset: usize, known: usize) -> bool {
+    let regional = |word_break: &WordBreak| *word_break == WordBreak::RegionalIndicator;
+    let breaks = |s| {
+        Utf8CharIter::new(s)
+            .map(Utf8Char::word_break)
+            .filter(|&word_break| !is_ignored(word_break))
+    };
+
+    if known < offset {
+        return breaks(&s[known..offset]).rev().take_while(regional).count() % 2 == 1;
+    }
+
+    // an even amount comes before a boundary within the run, take away the ones after `offset`
+    let (between, after) = s[offset..].split_at(known - offset);
+
+    if breaks(between).all(|word_break| regional(&word_break))
+        && breaks(after).next().as_ref().is_some_and(regional)
+    {
+        return breaks(between).count() % 2 == 1;
+    }
+
+    breaks(&s[..offset]).rev().take_while(regional).count() % 2 == 1
+}
+
+/// Returns whether there is a word boundary at a byte offset strictly within a string, given
+/// another offset that is known to be one
+fn is_boundary(s: &str, offset: usize, known: usize) -> bool {
+    use WordBreak as W;
+
+    let (before, after) = s.split_at(offset);
+    let before = Utf8CharIter::new(before);
+    let after = Utf8CharIter::new(after);
+
+    let (Some(prev), Some(next)) = (before.clone().next_back(), after.clone().next()) else {
+        // WB1 and WB2
+        return true;
+    };
+
+    match (prev.word_break(), next.word_break()) {
+        // WB3
+        (W::CR, W::LF) => return false,
+        // WB3a and WB3b
+        (W::Newline | W::CR | W::LF, _) | (_, W::Newline | W::CR | W::LF) => return true,
+        // WB3c
+        (W::ZWJ, _) if EXTENDED_PICTOGRAPHIC.contains(next) => return false,
+        // WB3d and WB4
+        (W::WSegSpace, W::WSegSpace) | (_, W::Extend | W::Format | W::ZWJ) => return false,
+        _ => {}
+    }
+
+    // the rules below skip over what WB4 ignores, the codepoints on either side of the boundary
+    // and further away
+    let mut before = before
+        .rev()
+        .map(Utf8Char::word_break)
+        .filter(|&word_break| !is_ignored(word_break));
+    let mut after = after
+        .map(Utf8Char::word_break)
+        .filter(|&word_break| !is_ignored(word_break));
+
+    let (Some(prev), Some(next)) = (before.next(), after.next()) else {
+        // WB999, extending codepoints at the start of the string are on their own
+        return true;
+    };
+
+    #[expect(
+        clippy::match_same_arms,
+        reason = "the arms follow the order of the rules"
+    )]
+    match (prev, next) {
+        // WB5
+        (W::ALetter | W::HebrewLetter, W::ALetter | W::HebrewLetter) => false,
+        // WB7a
+        (W::HebrewLetter, W::SingleQuote) => false,
+        // WB6
+        (W::ALetter | W::HebrewLetter, W::MidLetter | W::MidNumLet | W::SingleQuote) => {
+            !is_letter(after.next())
+        }
+        // WB7
+        (W::MidLetter | W::MidNumLet | W::SingleQuote, W::ALetter | W::HebrewLetter) => {
+            !is_letter(before.next())
+        }
+        // WB7b
+        (W::HebrewLetter, W::DoubleQuote) => after.next() != Some(W::HebrewLetter),
+        // WB7c
+        (W::DoubleQuote, W::HebrewLetter) => before.next() != Some(W::HebrewLetter),
+        // WB8, WB9 and WB10
+        (W::Numeric | W::ALetter | W::HebrewLetter, W::Numeric)
+        | (W::Numeric, W::ALetter | W::HebrewLetter) => false,
+        // WB11
+        (W::MidNum | W::MidNumLet | W::SingleQuote, W::Numeric) => {
+            before.next() != Some(W::Numeric)
+        }
+        // WB12
+        (W::Numeric, W::MidNum | W::MidNumLet | W::SingleQuote) => after.next() != Some(W::Numeric),
+        // WB13, WB13a and WB13b
+        (W::Katakana, W::Katakana)
+        | (
+            W::ALetter | W::HebrewLetter | W::Numeric | W::Katakana | W::ExtendNumLet,
+            W::ExtendNumLet,
+        )
+        | (W::ExtendNumLet, W::ALetter | W::HebrewLetter | W::Numeric | W::Katakana) => false,
+        // WB15 and WB16, a boundary if an even amount of regional indicators come before it
+        (W::RegionalIndicator, W::RegionalIndicator) => !odd_regional_indicators(s, offset, known),
+        // WB999
+        _ => true,
+    }
+}
+
+/// Returns whether a segment between word boundaries is a word, it has a letter or a digit
+fn is_word(segment: &str) -> bool {
+    Utf8CharIter::new(segment).any(Utf8Char::is_alphanumeric)
+}
+
+/// An iterator over the segments of a string between word boundaries, as `&str` slices
+///
+/// Created by [`word_bounds`]. Iterating from the back finds the same segments as iterating from
+/// the front.
+#[derive(Clone, Debug)]
+pub struct WordBounds<'slice>(Segments<'slice>);
+
+impl<'slice> WordBounds<'slice> {
+    /// Returns the part of the string that has not been iterated over yet
+    #[must_use]
+    pub fn as_str(&self) -> &'slice str {
+        self.0.as_str()
+    }
+}
+
+impl<'slice> Iterator for WordBounds<'slice> {
+    type Item = &'slice str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for WordBounds<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl FusedIterator for WordBounds<'_> {}
+
+/// An iterator over the segments of a string between word boundaries and their byte offsets
+///
+/// Created by [`word_bound_indices`].
+#[derive(Clone, Debug)]
+pub struct WordBoundIndices<'slice>(Segments<'slice>);
+
+impl<'slice> WordBoundIndices<'slice> {
+    /// Returns the part of the string that has not been iterated over yet
+    #[must_use]
+    pub fn as_str(&self) -> &'slice str {
+        self.0.as_str()
+    }
+}
+
+impl<'slice> Iterator for WordBoundIndices<'slice> {
+    type Item = (usize, &'slice str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.0.front;
+
+        self.0.next().map(|segment| (offset, segment))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for WordBoundIndices<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let segment = self.0.next_back()?;
+
+        Some((self.0.back, segment))
+    }
+}
+
+impl FusedIterator for WordBoundIndices<'_> {}
+
+/// An iterator over the words of a string, as `&str` slices
+///
+/// Created by [`unicode_words`].
+#[derive(Clone, Debug)]
+pub struct UnicodeWords<'slice>(WordBounds<'slice>);
+
+impl<'slice> Iterator for UnicodeWords<'slice> {
+    type Item = &'slice str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.find(|segment| is_word(segment))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
+    }
+}
+
+impl DoubleEndedIterator for UnicodeWords<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.rfind(|segment| is_word(segment))
+    }
+}
+
+impl FusedIterator for UnicodeWords<'_> {}
+
+/// An iterator over the words of a string and their byte offsets
+///
+/// Created by [`unicode_word_indices`].
+#[derive(Clone, Debug)]
+pub struct UnicodeWordIndices<'slice>(WordBoundIndices<'slice>);
+
+impl<'slice> Iterator for UnicodeWordIndices<'slice> {
+    type Item = (usize, &'slice str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.find(|(_, segment)| is_word(segment))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.0.size_hint().1)
+    }
+}
+
+impl DoubleEndedIterator for UnicodeWordIndices<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.rfind(|(_, segment)| is_word(segment))
+    }
+}
+
+impl FusedIterator for UnicodeWordIndices<'_> {}
+
+/// Returns an iterator over the segments of a string between word boundaries: words, and every
+/// space and punctuation mark between them
+///
+/// Concatenating the segments gives back the string.
+///
+/// ```
+/// use utf8char::word::word_bounds;
+///
+/// let segments = word_bounds("The fox's den.").collect::<Vec<_>>();
+///
+/// assert_eq!(segments, ["The", " ", "fox's", " ", "den", "."]);
+/// assert_eq!(word_bounds("a  b\r\n").rev().collect::<Vec<_>>(), ["\r\n", "b", "  ", "a"]);
+/// ```
+#[must_use]
+pub fn word_bounds(s: &str) -> WordBounds<'_> {
+    WordBounds(Segments::new(s, is_boundary))
+}
+
+/// Returns an iterator over the segments of a string between word boundaries, along with the byte
+/// offsets they start at
+///
+/// ```
+/// use utf8char::word::word_bound_indices;
+///
+/// let text = "naïve café";
+///
+/// // the word a cursor is in, like double-click selection picks
+/// let word_at = |cursor| {
+///     word_bound_indices(text)
+///         .find(|(offset, segment)| cursor < offset + segment.len())
+///         .map(|(_, segment)| segment)
+/// };
+///
+/// assert_eq!(word_at(2), Some("naïve"));
+/// assert_eq!(word_at(6), Some(" "));
+/// assert_eq!(word_at(9), Some("café"));
+/// assert_eq!(word_bound_indices(text).next_back(), Some((7, "café")));
+/// ```
+#[must_use]
+pub fn word_bound_indices(s: &str) -> WordBoundIndices<'_> {
+    WordBoundIndices(Segments::new(s, is_boundary))
+}
+
+/// Returns an iterator over the words of a string, the segments between word boundaries with a
+/// letter or digit in them
+///
+/// ```
+/// use utf8char::word::unicode_words;
+///
+/// let words = unicode_words("Hello, wörld! It's 9:30 a.m.").collect::<Vec<_>>();
+///
+/// assert_eq!(words, ["Hello", "wörld", "It's", "9", "30", "a.m"]);
+/// assert_eq!(unicode_words("rust_lang → 🦀").rev().collect::<Vec<_>>(), ["rust_lang"]);
+/// ```
+#[must_use]
+pub fn unicode_words(s: &str) -> UnicodeWords<'_> {
+    UnicodeWords(word_bounds(s))
+}
+
+/// Returns an iterator over the words of a string, along with the byte offsets they start at
+///
+/// ```
+/// use utf8char::word::unicode_word_indices;
+///
+/// let words = unicode_word_indices("-- a.b, c").collect::<Vec<_>>();
+///
+/// assert_eq!(words, [(3, "a.b"), (8, "c")]);
+/// ```
+#[must_use]
+pub fn unicode_word_indices(s: &str) -> UnicodeWordIndices<'_> {
+    UnicodeWordIndices(word_bound_indices(s))
+}
+
+#[test]
+fn word_breaks() {
+    use rayon::iter::ParallelIterator;
+
+    crate::tests::all_chars().for_each(|c| {
+        let utf8 = Utf8Char::from_char(c);
+        let word_break = utf8.word_break();
+
+        match c {
+            '\r' => assert_eq!(word_break, WordBreak::CR),
+            '\n' => assert_eq!(word_break, WordBreak::LF),
+            '\u{200D}' => assert_eq!(word_break, WordBreak::ZWJ),
+            '0'..='9' => assert_eq!(word_break, WordBreak::Numeric),
+            'a'..='z' | 'A'..='Z' => assert_eq!(word_break, WordBreak::ALetter),
+            _ if utf8.is_grapheme_extended() => {
+                assert_eq!(word_break, WordBreak::Extend, "{c:?}");
+            }
+            _ => {}
+        }
+    });
+}
+
+#[test]
+fn segmentation() {
+    use alloc::vec::Vec;
+
+    for expected in [
+        &[][..],
+        &["a"],
+        &["Hello", ",", " ", "world", "!"],
+        &["\r\n", "\n", "\r", "a"],
+        // spaces are kept together
+        &["a", "   ", "b", "\t", "\t"],
+        // punctuation within words and numbers
+        &["can't", " ", "e.g", ".", " ", "3.14", " ", "1,000", ","],
+        &[
+            "a", ":", " ", "a:b", " ", "a", "'", "'", "b", " ", "1", ".", "a",
+        ],
+        &["foo_bar", " ", "x1", " ", "_", "-", "_"],
+        // Hebrew letters and quotation marks
+        &["\u{5D0}\"\u{5D1}", " ", "\u{5D0}'", " ", "a", "\"", "b"],
+        // extending codepoints are ignored
+        &["e\u{301}\u{AD}x", " ", "a\u{301}'\u{301}b"],
+        &["\u{301}", "a", "\n", "\u{301}"],
+        // Katakana forms words of its own, ideographs do not
+        &["\u{30AB}\u{30BF}\u{30AB}\u{30CA}", "\u{65E5}", "\u{672C}"],
+        // flags pair up, emoji stay joined
+        &["🇦🇧", "🇨🇩", "🇪"],
+        &["a", "🇦🇧\u{200D}", "🇨"],
+        &["👨\u{200D}👩\u{200D}👧", "👍🏽", "a\u{200D}👍"],
+    ] {
+        let s = expected.concat();
+        let forward = word_bounds(&s).collect::<Vec<_>>();
+        let mut backward = word_bounds(&s).rev().collect::<Vec<_>>();
+        backward.reverse();
+
+        assert_eq!(forward, expected, "{s:?}");
+        assert_eq!(backward, expected, "{s:?}");
+    }
+}
+
+#[test]
+fn words() {
+    use alloc::vec::Vec;
+
+    for (s, expected) in [
+        ("", &[][..]),
+        ("  ...  ", &[]),
+        (
+            "The quick (\"brown\") fox",
+            &["The", "quick", "brown", "fox"],
+        ),
+        ("$3.50 = 350\u{A2}", &["3.50", "350"]),
+        ("x-ray, \u{E9}t\u{E9}", &["x", "ray", "\u{E9}t\u{E9}"]),
+        (
+            "\u{65E5}\u{672C}\u{8A9E}",
+            &["\u{65E5}", "\u{672C}", "\u{8A9E}"],
+        ),
+    ] {
+        let forward = unicode_word_indices(s).collect::<Vec<_>>();
+        let mut backward = unicode_word_indices(s).rev().collect::<Vec<_>>();
+        backward.reverse();
+
+        assert_eq!(forward, backward, "{s:?}");
+        assert_eq!(unicode_words(s).collect::<Vec<_>>(), expected, "{s:?}");
+        assert!(forward
+            .iter()
+            .all(|(offset, word)| s[*offset..].starts_with(word)));
+    }
+}
+
+#[test]
+fn conformance() {
+    use alloc::vec::Vec;
+
+    for &expected in crate::tables::conformance::WORD_BREAK_TEST {
+        let s = expected.concat();
+        let forward = word_bounds(&s).collect::<Vec<_>>();
+        let mut backward = word_bounds(&s).rev().collect::<Vec<_>>();
+        backward.reverse();
+
+        assert_eq!(forward, expected, "{s:?}");
+        assert_eq!(backward, expected, "{s:?}");
+    }
+}
+
+#[test]
+fn long_runs() {
+    use alloc::vec::Vec;
+
+    /// Long enough that rescanning a run for every codepoint in it would take minutes
+    const RUN: usize = 100_000;
+
+    for (s, len) in [
+        // flags pair up from the start of a run of regional indicators
+        ("\u{1F1E6}".repeat(RUN), RUN / 2),
+        ("\u{1F1E6}\u{301}".repeat(RUN), RUN / 2),
+        (["a", &"\u{1F1E6}".repeat(RUN)].concat(), RUN / 2 + 1),
+        (["x", &" ".repeat(RUN), "y"].concat(), 3),
+        (["a:", &"\u{301}".repeat(RUN), "b"].concat(), 1),
+        ("a. ".repeat(RUN), 3 * RUN),
+    ] {
+        let forward = word_bounds(&s).collect::<Vec<_>>();
+        let mut backward = word_bounds(&s).rev().collect::<Vec<_>>();
+        backward.reverse();
+
+        assert_eq!(forward.len(), len);
+        assert_eq!(forward, backward);
+    }
+}
+
+#[test]
+fn segmentation_is_symmetric() {
+    use alloc::{string::String, vec::Vec};
+    use itertools::Itertools;
+
+    // one codepoint of every property the rules distinguish
+    let chars = [
+        'a', '\u{5D0}', '1', '\r', '\n', '\u{85}', ' ', '\u{301}', '\u{AD}', '\u{200D}', '👍', '🇦',
+        '\u{30AB}', '\'', '"', '.', ':', ',', '_', '!',
+    ];
+
+    for len in 0..=4 {
+        for stream in (0..len).map(|_| chars).multi_cartesian_product() {
+            let s = stream.into_iter().collect::<String>();
+
+            let forward = word_bound_indices(&s).collect::<Vec<_>>();
+            let mut backward = word_bound_indices(&s).rev().collect::<Vec<_>>();
+            backward.reverse();
+
+            assert_eq!(forward, backward, "{s:?}");
+            assert_eq!(forward.iter().map(|(_, w)| *w).collect::<String>(), s);
+            assert!(forward
+                .iter()
+                .all(|(offset, w)| s[*offset..].starts_with(w)));
+        }
+    }
+}